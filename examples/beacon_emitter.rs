@@ -0,0 +1,43 @@
+//! Beacon emitter example (Windows only).
+//!
+//! Advertises an iBeacon until Enter is pressed.
+//!
+//! ```text
+//! cargo run --example beacon_emitter --features examples -- 00112233-4455-6677-8899-aabbccddeeff 1 2
+//! ```
+
+#[cfg(target_os = "windows")]
+fn main() {
+    use ble_data_struct::{beacons::ibeacon::IBeacon, windows::publisher::publish_ibeacon};
+    use uuid::Uuid;
+
+    let args: Vec<String> = std::env::args().collect();
+    let uuid = args
+        .get(1)
+        .and_then(|f| Uuid::parse_str(f).ok())
+        .unwrap_or(Uuid::nil());
+    let major = args.get(2).and_then(|f| f.parse().ok()).unwrap_or(0);
+    let minor = args.get(3).and_then(|f| f.parse().ok()).unwrap_or(0);
+    let ibeacon = IBeacon::new(&uuid, major, minor, -59);
+
+    let publisher = match publish_ibeacon(&ibeacon) {
+        Ok(publisher) => publisher,
+        Err(e) => {
+            eprintln!("{}", e);
+            std::process::exit(1);
+        }
+    };
+    println!("advertising {} major:{} minor:{}", uuid, major, minor);
+    println!("press Enter to stop");
+    let mut line = String::new();
+    let _ = std::io::stdin().read_line(&mut line);
+    if let Err(e) = publisher.Stop() {
+        eprintln!("{}", e.message());
+    }
+}
+
+#[cfg(not(target_os = "windows"))]
+fn main() {
+    eprintln!("beacon_emitter is only supported on Windows");
+    std::process::exit(1);
+}
@@ -0,0 +1,74 @@
+//! Build advertisement example.
+//!
+//! Builds a legacy advertising payload, lints it and parses it back.
+//!
+//! ```text
+//! cargo run --example build_advertisement --features examples
+//! ```
+
+use ble_data_struct::{
+    data_types::{
+        appearance::Appearance,
+        appearance_category::AppearanceSubcategory,
+        data_type_parser::DataTypeParseResults,
+        flags::{DiscoverableMode, Flags},
+    },
+    lint::advertising::{lint, LintTarget, RuleSet},
+    payload::{builder::AdvertisingPayloadBuilder, legality::AdvertisingPduType},
+    uuid_from_u16,
+    uuids::service,
+};
+
+fn main() {
+    let appearance = Appearance::from(AppearanceSubcategory::TemperatureSensor);
+    let builder = AdvertisingPayloadBuilder::new()
+        .with_shorten_local_name(true)
+        .flags(Flags::for_mode(DiscoverableMode::General, false))
+        .service_uuids_16(&[uuid_from_u16(service::ENVIRONMENTAL_SENSING)])
+        .appearance(appearance.appearance)
+        .tx_power_level(0)
+        .complete_local_name("Environmental Sensor Living Room");
+
+    let advertising_data = match builder.build_legacy() {
+        Ok(data) => data.to_vec(),
+        Err(e) => {
+            eprintln!("{}", e);
+            std::process::exit(1);
+        }
+    };
+    println!(
+        "advertising data: {}",
+        advertising_data
+            .iter()
+            .map(|f| format!("{:02x}", f))
+            .collect::<String>()
+    );
+
+    let target = LintTarget::new(
+        AdvertisingPduType::AdvInd,
+        DiscoverableMode::General,
+        &advertising_data,
+        &Vec::new(),
+    );
+    let report = lint(&target, &RuleSet::default());
+    println!("lint: {}", report.to_json());
+
+    let results = DataTypeParseResults::from(advertising_data.as_slice());
+    for result in &results.results {
+        match result.ad_structure() {
+            Some(ad_structure) => println!(
+                "  0x{:02x}: {:?}",
+                ad_structure.ad_type(),
+                ad_structure.to_bytes()
+            ),
+            None => println!("  {:?}", result),
+        }
+    }
+    if let Some(name) = results.shortened_local_name() {
+        println!("shortened local name: {}", name.shortened_local_name);
+    }
+
+    if report.has_errors() {
+        std::process::exit(1);
+    }
+}
@@ -0,0 +1,118 @@
+//! pcap analyzer example.
+//!
+//! Reads advertising PDUs from a pcap file (LINKTYPE_BLUETOOTH_LE_LL / LINKTYPE_BLUETOOTH_LE_LL_WITH_PHDR),
+//! parses the advertising data and prints per data type counts and parse errors.
+//!
+//! ```text
+//! cargo run --example pcap_analyzer --features examples -- capture.pcap
+//! ```
+
+use std::collections::BTreeMap;
+
+use ble_data_struct::scan::batch::parse_batch;
+
+/// LINKTYPE_BLUETOOTH_LE_LL
+const LINKTYPE_BLUETOOTH_LE_LL: u32 = 251;
+
+/// LINKTYPE_BLUETOOTH_LE_LL_WITH_PHDR
+const LINKTYPE_BLUETOOTH_LE_LL_WITH_PHDR: u32 = 256;
+
+/// Advertising channel access address.
+const ADVERTISING_ACCESS_ADDRESS: u32 = 0x8e89bed6;
+
+/// Get advertising data from a link layer packet(`None` if not an advertising PDU with AdvData).
+fn advertising_data(packet: &[u8]) -> Option<&[u8]> {
+    if packet.len() < 6 + 6
+        || u32::from_le_bytes(packet[0..4].try_into().unwrap()) != ADVERTISING_ACCESS_ADDRESS
+    {
+        return None;
+    }
+    // ADV_IND, ADV_NONCONN_IND, SCAN_RSP, ADV_SCAN_IND
+    if !matches!(packet[4] & 0x0f, 0x00 | 0x02 | 0x04 | 0x06) {
+        return None;
+    }
+    let length = packet[5] as usize;
+    let pdu = packet.get(6..6 + length)?;
+    pdu.get(6..)
+}
+
+/// Get link layer packets from pcap file content.
+fn read_packets(content: &[u8]) -> Result<Vec<&[u8]>, String> {
+    if content.len() < 24 {
+        return Err(format!("Invalid pcap size :{}", content.len()));
+    }
+    let read_u32: fn([u8; 4]) -> u32 = match content[0..4] {
+        [0xd4, 0xc3, 0xb2, 0xa1] | [0x4d, 0x3c, 0xb2, 0xa1] => u32::from_le_bytes,
+        [0xa1, 0xb2, 0xc3, 0xd4] | [0xa1, 0xb2, 0x3c, 0x4d] => u32::from_be_bytes,
+        _ => return Err("Invalid pcap magic number".to_string()),
+    };
+    let linktype = read_u32(content[20..24].try_into().unwrap());
+    let header_length = match linktype {
+        LINKTYPE_BLUETOOTH_LE_LL => 0,
+        LINKTYPE_BLUETOOTH_LE_LL_WITH_PHDR => 10,
+        _ => return Err(format!("Unsupported linktype :{}", linktype)),
+    };
+
+    let mut packets: Vec<&[u8]> = Vec::new();
+    let mut index = 24;
+    while index + 16 <= content.len() {
+        let captured_length = read_u32(content[index + 8..index + 12].try_into().unwrap()) as usize;
+        let start = index + 16;
+        let end = start + captured_length;
+        if end > content.len() {
+            return Err(format!("Truncated packet at :{}", index));
+        }
+        if let Some(packet) = content.get(start + header_length..end) {
+            packets.push(packet);
+        }
+        index = end;
+    }
+    Ok(packets)
+}
+
+fn main() {
+    let path = match std::env::args().nth(1) {
+        Some(path) => path,
+        None => {
+            eprintln!("usage: pcap_analyzer <pcap file>");
+            std::process::exit(2);
+        }
+    };
+    let content = match std::fs::read(&path) {
+        Ok(content) => content,
+        Err(e) => {
+            eprintln!("{}: {}", path, e);
+            std::process::exit(1);
+        }
+    };
+    let packets = match read_packets(&content) {
+        Ok(packets) => packets,
+        Err(e) => {
+            eprintln!("{}: {}", path, e);
+            std::process::exit(1);
+        }
+    };
+
+    let payloads: Vec<&[u8]> = packets.iter().filter_map(|f| advertising_data(f)).collect();
+    let results = parse_batch(&payloads);
+
+    let mut counts: BTreeMap<u8, usize> = BTreeMap::new();
+    let mut errors: BTreeMap<String, usize> = BTreeMap::new();
+    for result in results.iter().flat_map(|f| f.results.iter()) {
+        if let Some(ad_structure) = result.ad_structure() {
+            *counts.entry(ad_structure.ad_type()).or_default() += 1;
+        }
+        if let Some(error) = result.parse_error() {
+            *errors.entry(error.to_string()).or_default() += 1;
+        }
+    }
+
+    println!("packets: {}", packets.len());
+    println!("advertising payloads: {}", payloads.len());
+    for (ad_type, count) in counts {
+        println!("  0x{:02x}: {}", ad_type, count);
+    }
+    for (error, count) in errors {
+        println!("  error {}: {}", error, count);
+    }
+}
@@ -0,0 +1,110 @@
+//! Scan and decode example.
+//!
+//! Replays a recorded scan session and prints the decoded advertising data of each record.
+//!
+//! ```text
+//! cargo run --example scan_and_decode --features examples
+//! ```
+
+use std::time::Duration;
+
+use ble_data_struct::{
+    beacons::ibeacon::IBeacon,
+    data_types::{
+        appearance_category::AppearanceCategory, data_type_parser::DataTypeParseResults,
+        flags::Flags, manufacturer_specific_data::ManufacturerSpecificData,
+    },
+    payload::builder::AdvertisingPayloadBuilder,
+    scan::{
+        scan_record::ScanRecord,
+        simulator::{ScanSimulator, TimedScanRecord},
+    },
+};
+use uuid::uuid;
+
+/// Recorded scan session.
+fn recorded_session() -> Vec<TimedScanRecord> {
+    let heart_rate = AdvertisingPayloadBuilder::new()
+        .flags(Flags::new(
+            &[false, true, true, false, false, false, false, false].to_vec(),
+        ))
+        .complete_local_name("HRM")
+        .appearance(AppearanceCategory::HeartRateSensor.appearance() | 0x01)
+        .tx_power_level(-4)
+        .build()
+        .unwrap();
+
+    let ibeacon = IBeacon::new(&uuid!("00112233-4455-6677-8899-aabbccddeeff"), 1, 2, -59);
+    let ibeacon: Vec<u8> = ManufacturerSpecificData::from(&ibeacon).into();
+
+    let malformed: Vec<u8> = vec![0x05, 0x09, b'a'];
+
+    vec![
+        TimedScanRecord::new(
+            Duration::from_millis(0),
+            ScanRecord::from_payload(0x010203040506, -48, &heart_rate),
+        ),
+        TimedScanRecord::new(
+            Duration::from_millis(100),
+            ScanRecord::from_payload(0x0a0b0c0d0e0f, -71, &ibeacon),
+        ),
+        TimedScanRecord::new(
+            Duration::from_millis(250),
+            ScanRecord::from_payload(0x111213141516, -90, &malformed),
+        ),
+    ]
+}
+
+/// Print decoded advertising data.
+fn print_results(results: &DataTypeParseResults) {
+    if let Some(flags) = results.flags() {
+        println!("  flags: {:?}", flags.discoverable_mode());
+    }
+    if let Some(name) = results.complete_local_name() {
+        println!("  name: {}", name.complete_local_name);
+    }
+    if let Some(appearance) = results.appearance() {
+        println!(
+            "  appearance: 0x{:04x} ({})",
+            appearance.appearance,
+            appearance
+                .sub_category_type()
+                .map(|f| f.name())
+                .or(appearance.category_type().map(|f| f.name()))
+                .unwrap_or("unknown")
+        );
+    }
+    if let Some(tx_power_level) = results.tx_power_level() {
+        println!("  tx power level: {} dBm", tx_power_level.tx_power_level);
+    }
+    for manufacturer_specific_data in results.manufacturer_specific_data() {
+        match IBeacon::try_from(manufacturer_specific_data) {
+            Ok(ibeacon) => println!(
+                "  iBeacon: {} major:{} minor:{}",
+                ibeacon.uuid, ibeacon.major, ibeacon.minor
+            ),
+            Err(_) => println!(
+                "  manufacturer specific data: 0x{:04x}",
+                manufacturer_specific_data.company_identifier
+            ),
+        }
+    }
+    for result in &results.results {
+        if let Some(error) = result.parse_error() {
+            println!("  error: {}", error);
+        }
+    }
+    for warning in results.warnings() {
+        println!("  warning: {:?}", warning);
+    }
+}
+
+fn main() {
+    let simulator = ScanSimulator::new(recorded_session())
+        .with_speed(10.0)
+        .unwrap();
+    simulator.replay(|record| {
+        println!("{:012x} rssi:{}", record.address, record.rssi);
+        print_results(&record.results);
+    });
+}
@@ -0,0 +1,411 @@
+//! Eddystone module.
+
+use crate::{data_types::service_data_16bit_uuid::ServiceData16BitUUID, uuid_from_u16};
+
+/// Eddystone service UUID.
+pub const EDDYSTONE_UUID: u16 = 0xfeaa;
+
+/// Frame type: Eddystone-UID
+pub const FRAME_TYPE_UID: u8 = 0x00;
+
+/// Frame type: Eddystone-URL
+pub const FRAME_TYPE_URL: u8 = 0x10;
+
+/// Frame type: Eddystone-TLM
+pub const FRAME_TYPE_TLM: u8 = 0x20;
+
+/// URL scheme prefixes.
+const URL_SCHEMES: [&str; 4] = ["http://www.", "https://www.", "http://", "https://"];
+
+/// URL expansion codes.
+const URL_EXPANSIONS: [&str; 14] = [
+    ".com/", ".org/", ".edu/", ".net/", ".info/", ".biz/", ".gov/", ".com", ".org", ".edu", ".net",
+    ".info", ".biz", ".gov",
+];
+
+/// Eddystone frame.
+#[derive(Debug, PartialEq, Clone)]
+pub enum Eddystone {
+    /// Eddystone-UID
+    Uid {
+        /// Calibrated Tx power at 0 m
+        tx_power: i8,
+
+        /// 10-byte ID Namespace
+        namespace: [u8; 10],
+
+        /// 6-byte ID Instance
+        instance: [u8; 6],
+    },
+
+    /// Eddystone-URL
+    Url {
+        /// Calibrated Tx power at 0 m
+        tx_power: i8,
+
+        /// URL Scheme Prefix
+        url_scheme: u8,
+
+        /// Encoded URL
+        encoded_url: Vec<u8>,
+    },
+
+    /// Eddystone-TLM (unencrypted)
+    Tlm {
+        /// Battery voltage(mV)
+        battery_voltage: u16,
+
+        /// Beacon temperature(8.8 fixed point ℃)
+        beacon_temperature: i16,
+
+        /// Advertising PDU count
+        advertising_pdu_count: u32,
+
+        /// Time since power-on or reboot(0.1 s)
+        time_since_power_on: u32,
+    },
+}
+
+impl Eddystone {
+    /// Get decoded URL of Eddystone-URL.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ble_data_struct::beacons::eddystone::Eddystone;
+    ///
+    /// let result = Eddystone::Url {
+    ///     tx_power: -20,
+    ///     url_scheme: 0x03,
+    ///     encoded_url: [b"example".to_vec(), vec![0x07]].concat(),
+    /// };
+    /// assert_eq!(Some("https://example.com".to_string()), result.url());
+    ///
+    /// let result = Eddystone::Tlm {
+    ///     battery_voltage: 3000,
+    ///     beacon_temperature: 0x1800,
+    ///     advertising_pdu_count: 1,
+    ///     time_since_power_on: 2,
+    /// };
+    /// assert_eq!(None, result.url());
+    /// ```
+    pub fn url(&self) -> Option<String> {
+        match self {
+            Eddystone::Url {
+                url_scheme,
+                encoded_url,
+                ..
+            } => {
+                let mut url = URL_SCHEMES.get(*url_scheme as usize)?.to_string();
+                for c in encoded_url {
+                    match URL_EXPANSIONS.get(*c as usize) {
+                        Some(expansion) => url.push_str(expansion),
+                        None => url.push(*c as char),
+                    }
+                }
+                Some(url)
+            }
+            _ => None,
+        }
+    }
+}
+
+impl TryFrom<&Vec<u8>> for Eddystone {
+    type Error = String;
+    /// Create [`Eddystone`] from service data (after the UUID).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ble_data_struct::beacons::eddystone::Eddystone;
+    ///
+    /// let data: Vec<u8> = vec![0x20, 0x00, 0x0b, 0xb8, 0x18, 0x00, 0x00, 0x00, 0x00, 0x01, 0x00, 0x00, 0x00, 0x02];
+    /// let result = Eddystone::try_from(&data);
+    /// assert_eq!(
+    ///     Ok(Eddystone::Tlm {
+    ///         battery_voltage: 3000,
+    ///         beacon_temperature: 0x1800,
+    ///         advertising_pdu_count: 1,
+    ///         time_since_power_on: 2,
+    ///     }),
+    ///     result
+    /// );
+    ///
+    /// let data: Vec<u8> = Vec::new();
+    /// let result = Eddystone::try_from(&data);
+    /// assert!(result.is_err());
+    /// assert_eq!(
+    ///     format!("Invalid data size :{}", data.len()),
+    ///     result.unwrap_err()
+    /// );
+    /// ```
+    fn try_from(value: &Vec<u8>) -> Result<Self, String> {
+        let len = value.len();
+        match value.first() {
+            Some(&FRAME_TYPE_UID) if len == 18 || len == 20 => Ok(Eddystone::Uid {
+                tx_power: value[1] as i8,
+                namespace: value[2..12].try_into().unwrap(),
+                instance: value[12..18].try_into().unwrap(),
+            }),
+            Some(&FRAME_TYPE_URL) if (3..=20).contains(&len) => Ok(Eddystone::Url {
+                tx_power: value[1] as i8,
+                url_scheme: value[2],
+                encoded_url: value[3..].to_vec(),
+            }),
+            Some(&FRAME_TYPE_TLM) if len == 14 && value[1] == 0x00 => Ok(Eddystone::Tlm {
+                battery_voltage: u16::from_be_bytes([value[2], value[3]]),
+                beacon_temperature: i16::from_be_bytes([value[4], value[5]]),
+                advertising_pdu_count: u32::from_be_bytes(value[6..10].try_into().unwrap()),
+                time_since_power_on: u32::from_be_bytes(value[10..14].try_into().unwrap()),
+            }),
+            Some(&FRAME_TYPE_UID) | Some(&FRAME_TYPE_URL) | Some(&FRAME_TYPE_TLM) | None => {
+                Err(format!("Invalid data size :{}", len))
+            }
+            Some(frame_type) => Err(format!("Unknown frame type :{}", frame_type)),
+        }
+    }
+}
+
+impl TryFrom<&ServiceData16BitUUID> for Eddystone {
+    type Error = String;
+    /// Create [`Eddystone`] from [`ServiceData16BitUUID`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ble_data_struct::{
+    ///     beacons::eddystone::Eddystone,
+    ///     data_types::service_data_16bit_uuid::ServiceData16BitUUID, uuid_from_u16,
+    /// };
+    ///
+    /// let eddystone = Eddystone::Uid {
+    ///     tx_power: -20,
+    ///     namespace: [0; 10],
+    ///     instance: [1; 6],
+    /// };
+    /// let service_data = ServiceData16BitUUID::from(&eddystone);
+    /// assert_eq!(Ok(eddystone), Eddystone::try_from(&service_data));
+    ///
+    /// let service_data = ServiceData16BitUUID::new(&uuid_from_u16(0x180f), &vec![50]);
+    /// assert!(Eddystone::try_from(&service_data).is_err());
+    /// ```
+    fn try_from(value: &ServiceData16BitUUID) -> Result<Self, String> {
+        if value.uuid != uuid_from_u16(EDDYSTONE_UUID) {
+            return Err(format!("Invalid uuid :{}", value.uuid));
+        }
+        Self::try_from(&value.additional_service_data)
+    }
+}
+
+impl Into<Vec<u8>> for Eddystone {
+    /// Create service data (after the UUID) from [`Eddystone`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ble_data_struct::beacons::eddystone::Eddystone;
+    ///
+    /// let result = Eddystone::Url {
+    ///     tx_power: -20,
+    ///     url_scheme: 0x03,
+    ///     encoded_url: [b"example".to_vec(), vec![0x07]].concat(),
+    /// };
+    /// let into_data: Vec<u8> = result.into();
+    /// assert_eq!([vec![0x10, 0xec, 0x03], b"example".to_vec(), vec![0x07]].concat(), into_data);
+    /// ```
+    fn into(self) -> Vec<u8> {
+        let mut data: Vec<u8> = Vec::new();
+        match self {
+            Eddystone::Uid {
+                tx_power,
+                namespace,
+                instance,
+            } => {
+                data.push(FRAME_TYPE_UID);
+                data.push(tx_power as u8);
+                data.append(&mut namespace.to_vec());
+                data.append(&mut instance.to_vec());
+                data.append(&mut vec![0x00, 0x00]);
+            }
+            Eddystone::Url {
+                tx_power,
+                url_scheme,
+                mut encoded_url,
+            } => {
+                data.push(FRAME_TYPE_URL);
+                data.push(tx_power as u8);
+                data.push(url_scheme);
+                data.append(&mut encoded_url);
+            }
+            Eddystone::Tlm {
+                battery_voltage,
+                beacon_temperature,
+                advertising_pdu_count,
+                time_since_power_on,
+            } => {
+                data.push(FRAME_TYPE_TLM);
+                data.push(0x00);
+                data.append(&mut battery_voltage.to_be_bytes().to_vec());
+                data.append(&mut beacon_temperature.to_be_bytes().to_vec());
+                data.append(&mut advertising_pdu_count.to_be_bytes().to_vec());
+                data.append(&mut time_since_power_on.to_be_bytes().to_vec());
+            }
+        }
+        return data;
+    }
+}
+
+impl From<&Eddystone> for ServiceData16BitUUID {
+    /// Create [`ServiceData16BitUUID`] from [`Eddystone`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ble_data_struct::{
+    ///     beacons::eddystone::{Eddystone, EDDYSTONE_UUID},
+    ///     data_types::service_data_16bit_uuid::ServiceData16BitUUID, uuid_from_u16,
+    /// };
+    ///
+    /// let eddystone = Eddystone::Tlm {
+    ///     battery_voltage: 3000,
+    ///     beacon_temperature: 0x1800,
+    ///     advertising_pdu_count: 1,
+    ///     time_since_power_on: 2,
+    /// };
+    /// let result = ServiceData16BitUUID::from(&eddystone);
+    /// assert_eq!(uuid_from_u16(EDDYSTONE_UUID), result.uuid);
+    /// let data: Vec<u8> = eddystone.into();
+    /// assert_eq!(data, result.additional_service_data);
+    /// ```
+    fn from(value: &Eddystone) -> Self {
+        Self::new(&uuid_from_u16(EDDYSTONE_UUID), &value.clone().into())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        beacons::eddystone::{Eddystone, EDDYSTONE_UUID},
+        data_types::service_data_16bit_uuid::ServiceData16BitUUID,
+        uuid_from_u16,
+    };
+
+    fn uid() -> (Eddystone, Vec<u8>) {
+        (
+            Eddystone::Uid {
+                tx_power: -20,
+                namespace: [0, 1, 2, 3, 4, 5, 6, 7, 8, 9],
+                instance: [10, 11, 12, 13, 14, 15],
+            },
+            vec![
+                0x00, 0xec, 0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15, 0x00, 0x00,
+            ],
+        )
+    }
+
+    fn url() -> (Eddystone, Vec<u8>) {
+        (
+            Eddystone::Url {
+                tx_power: -20,
+                url_scheme: 0x03,
+                encoded_url: [b"example".to_vec(), vec![0x07]].concat(),
+            },
+            [vec![0x10, 0xec, 0x03], b"example".to_vec(), vec![0x07]].concat(),
+        )
+    }
+
+    fn tlm() -> (Eddystone, Vec<u8>) {
+        (
+            Eddystone::Tlm {
+                battery_voltage: 3000,
+                beacon_temperature: 0x1800,
+                advertising_pdu_count: 1,
+                time_since_power_on: 2,
+            },
+            vec![
+                0x20, 0x00, 0x0b, 0xb8, 0x18, 0x00, 0x00, 0x00, 0x00, 0x01, 0x00, 0x00, 0x00, 0x02,
+            ],
+        )
+    }
+
+    #[test]
+    fn test_url() {
+        assert_eq!(Some("https://example.com".to_string()), url().0.url());
+        let result = Eddystone::Url {
+            tx_power: -20,
+            url_scheme: 0x00,
+            encoded_url: [b"example".to_vec(), vec![0x00], b"index".to_vec()].concat(),
+        };
+        assert_eq!(
+            Some("http://www.example.com/index".to_string()),
+            result.url()
+        );
+        let result = Eddystone::Url {
+            tx_power: -20,
+            url_scheme: 0x04,
+            encoded_url: b"example".to_vec(),
+        };
+        assert_eq!(None, result.url());
+        assert_eq!(None, uid().0.url());
+        assert_eq!(None, tlm().0.url());
+    }
+
+    #[test]
+    fn test_try_from() {
+        for (eddystone, data) in [uid(), url(), tlm()] {
+            assert_eq!(Ok(eddystone), Eddystone::try_from(&data));
+        }
+        let (eddystone, data) = uid();
+        assert_eq!(Ok(eddystone), Eddystone::try_from(&data[..18].to_vec()));
+
+        assert_eq!(
+            Err("Invalid data size :17".to_string()),
+            Eddystone::try_from(&uid().1[..17].to_vec())
+        );
+        assert_eq!(
+            Err("Invalid data size :2".to_string()),
+            Eddystone::try_from(&url().1[..2].to_vec())
+        );
+        let mut data = tlm().1;
+        data[1] = 0x01;
+        assert_eq!(
+            Err("Invalid data size :14".to_string()),
+            Eddystone::try_from(&data)
+        );
+        assert_eq!(
+            Err("Invalid data size :0".to_string()),
+            Eddystone::try_from(&Vec::new())
+        );
+        assert_eq!(
+            Err("Unknown frame type :48".to_string()),
+            Eddystone::try_from(&vec![0x30])
+        );
+    }
+
+    #[test]
+    fn test_try_from_service_data() {
+        let (eddystone, data) = tlm();
+        let service_data = ServiceData16BitUUID::new(&uuid_from_u16(EDDYSTONE_UUID), &data);
+        assert_eq!(Ok(eddystone), Eddystone::try_from(&service_data));
+
+        let service_data = ServiceData16BitUUID::new(&uuid_from_u16(0x180f), &data);
+        assert!(Eddystone::try_from(&service_data).is_err());
+    }
+
+    #[test]
+    fn test_into() {
+        for (eddystone, data) in [uid(), url(), tlm()] {
+            let into_data: Vec<u8> = eddystone.into();
+            assert_eq!(data, into_data);
+        }
+    }
+
+    #[test]
+    fn test_from() {
+        let (eddystone, data) = uid();
+        assert_eq!(
+            ServiceData16BitUUID::new(&uuid_from_u16(EDDYSTONE_UUID), &data),
+            ServiceData16BitUUID::from(&eddystone)
+        );
+    }
+}
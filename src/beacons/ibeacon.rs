@@ -0,0 +1,248 @@
+//! iBeacon module.
+
+use uuid::Uuid;
+
+use crate::data_types::manufacturer_specific_data::ManufacturerSpecificData;
+
+/// Apple, Inc. company identifier.
+pub const IBEACON_COMPANY_IDENTIFIER: u16 = 0x004c;
+
+/// iBeacon type and length (`0x02`, `0x15`).
+const IBEACON_PREFIX: [u8; 2] = [0x02, 0x15];
+
+/// iBeacon.
+#[derive(Debug, PartialEq, Clone)]
+pub struct IBeacon {
+    /// Proximity UUID
+    pub uuid: Uuid,
+
+    /// Major
+    pub major: u16,
+
+    /// Minor
+    pub minor: u16,
+
+    /// Measured Power(dBm at 1m)
+    pub measured_power: i8,
+}
+
+impl IBeacon {
+    /// Create [`IBeacon`] from Parameters.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use uuid::uuid;
+    /// use ble_data_struct::beacons::ibeacon::IBeacon;
+    ///
+    /// let uuid = uuid!("00112233-4455-6677-8899-aabbccddeeff");
+    /// let result = IBeacon::new(&uuid, 1, 2, -59);
+    /// assert_eq!(uuid, result.uuid);
+    /// assert_eq!(1, result.major);
+    /// assert_eq!(2, result.minor);
+    /// assert_eq!(-59, result.measured_power);
+    /// ```
+    pub fn new(uuid: &Uuid, major: u16, minor: u16, measured_power: i8) -> Self {
+        Self {
+            uuid: *uuid,
+            major,
+            minor,
+            measured_power,
+        }
+    }
+}
+
+impl TryFrom<&Vec<u8>> for IBeacon {
+    type Error = String;
+    /// Create [`IBeacon`] from manufacturer specific data (after the company identifier).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use uuid::uuid;
+    /// use ble_data_struct::beacons::ibeacon::IBeacon;
+    ///
+    /// let mut data: Vec<u8> = vec![0x02, 0x15];
+    /// data.append(&mut uuid!("00112233-4455-6677-8899-aabbccddeeff").as_bytes().to_vec());
+    /// data.append(&mut vec![0x00, 0x01, 0x00, 0x02, 0xc5]);
+    /// let result = IBeacon::try_from(&data);
+    /// assert!(result.is_ok());
+    /// assert_eq!(
+    ///     IBeacon::new(&uuid!("00112233-4455-6677-8899-aabbccddeeff"), 1, 2, -59),
+    ///     result.unwrap()
+    /// );
+    ///
+    /// let data: Vec<u8> = Vec::new();
+    /// let result = IBeacon::try_from(&data);
+    /// assert!(result.is_err());
+    /// assert_eq!(
+    ///     format!("Invalid data size :{}", data.len()),
+    ///     result.unwrap_err()
+    /// );
+    /// ```
+    fn try_from(value: &Vec<u8>) -> Result<Self, String> {
+        let len = value.len();
+        if len != 23 {
+            return Err(format!("Invalid data size :{}", len));
+        }
+        if value[..2] != IBEACON_PREFIX {
+            return Err(format!("Invalid iBeacon type :{}", value[0]));
+        }
+        Ok(Self {
+            uuid: Uuid::from_slice(&value[2..18]).unwrap(),
+            major: u16::from_be_bytes([value[18], value[19]]),
+            minor: u16::from_be_bytes([value[20], value[21]]),
+            measured_power: value[22] as i8,
+        })
+    }
+}
+
+impl TryFrom<&ManufacturerSpecificData> for IBeacon {
+    type Error = String;
+    /// Create [`IBeacon`] from [`ManufacturerSpecificData`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use uuid::uuid;
+    /// use ble_data_struct::{
+    ///     beacons::ibeacon::IBeacon,
+    ///     data_types::manufacturer_specific_data::ManufacturerSpecificData,
+    /// };
+    ///
+    /// let ibeacon = IBeacon::new(&uuid!("00112233-4455-6677-8899-aabbccddeeff"), 1, 2, -59);
+    /// let manufacturer_specific_data = ManufacturerSpecificData::from(&ibeacon);
+    /// assert_eq!(Ok(ibeacon), IBeacon::try_from(&manufacturer_specific_data));
+    ///
+    /// let manufacturer_specific_data = ManufacturerSpecificData::new(0x0000, &Vec::new());
+    /// assert!(IBeacon::try_from(&manufacturer_specific_data).is_err());
+    /// ```
+    fn try_from(value: &ManufacturerSpecificData) -> Result<Self, String> {
+        if value.company_identifier != IBEACON_COMPANY_IDENTIFIER {
+            return Err(format!(
+                "Invalid company identifier :{}",
+                value.company_identifier
+            ));
+        }
+        Self::try_from(&value.manufacturer_specific_data)
+    }
+}
+
+impl Into<Vec<u8>> for IBeacon {
+    /// Create manufacturer specific data (after the company identifier) from [`IBeacon`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use uuid::uuid;
+    /// use ble_data_struct::beacons::ibeacon::IBeacon;
+    ///
+    /// let result = IBeacon::new(&uuid!("00112233-4455-6677-8899-aabbccddeeff"), 1, 2, -59);
+    /// let mut data: Vec<u8> = vec![0x02, 0x15];
+    /// data.append(&mut uuid!("00112233-4455-6677-8899-aabbccddeeff").as_bytes().to_vec());
+    /// data.append(&mut vec![0x00, 0x01, 0x00, 0x02, 0xc5]);
+    /// let into_data: Vec<u8> = result.into();
+    /// assert_eq!(data, into_data);
+    /// ```
+    fn into(self) -> Vec<u8> {
+        let mut data: Vec<u8> = IBEACON_PREFIX.to_vec();
+        data.append(&mut self.uuid.as_bytes().to_vec());
+        data.append(&mut self.major.to_be_bytes().to_vec());
+        data.append(&mut self.minor.to_be_bytes().to_vec());
+        data.push(self.measured_power as u8);
+        return data;
+    }
+}
+
+impl From<&IBeacon> for ManufacturerSpecificData {
+    /// Create [`ManufacturerSpecificData`] from [`IBeacon`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use uuid::uuid;
+    /// use ble_data_struct::{
+    ///     beacons::ibeacon::{IBeacon, IBEACON_COMPANY_IDENTIFIER},
+    ///     data_types::manufacturer_specific_data::ManufacturerSpecificData,
+    /// };
+    ///
+    /// let ibeacon = IBeacon::new(&uuid!("00112233-4455-6677-8899-aabbccddeeff"), 1, 2, -59);
+    /// let result = ManufacturerSpecificData::from(&ibeacon);
+    /// assert_eq!(IBEACON_COMPANY_IDENTIFIER, result.company_identifier);
+    /// let data: Vec<u8> = ibeacon.into();
+    /// assert_eq!(data, result.manufacturer_specific_data);
+    /// ```
+    fn from(value: &IBeacon) -> Self {
+        Self::new(IBEACON_COMPANY_IDENTIFIER, &value.clone().into())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use uuid::{uuid, Uuid};
+
+    use crate::{
+        beacons::ibeacon::{IBeacon, IBEACON_COMPANY_IDENTIFIER},
+        data_types::manufacturer_specific_data::ManufacturerSpecificData,
+    };
+
+    const UUID: Uuid = uuid!("00112233-4455-6677-8899-aabbccddeeff");
+
+    fn data() -> Vec<u8> {
+        let mut data: Vec<u8> = vec![0x02, 0x15];
+        data.append(&mut UUID.as_bytes().to_vec());
+        data.append(&mut vec![0x00, 0x01, 0x00, 0x02, 0xc5]);
+        data
+    }
+
+    #[test]
+    fn test_new() {
+        let result = IBeacon::new(&UUID, 1, 2, -59);
+        assert_eq!(UUID, result.uuid);
+        assert_eq!(1, result.major);
+        assert_eq!(2, result.minor);
+        assert_eq!(-59, result.measured_power);
+    }
+
+    #[test]
+    fn test_try_from() {
+        let result = IBeacon::try_from(&data());
+        assert_eq!(Ok(IBeacon::new(&UUID, 1, 2, -59)), result);
+
+        let mut data = data();
+        data[1] = 0x16;
+        let result = IBeacon::try_from(&data);
+        assert_eq!(Err("Invalid iBeacon type :2".to_string()), result);
+
+        data.pop();
+        let result = IBeacon::try_from(&data);
+        assert_eq!(Err("Invalid data size :22".to_string()), result);
+    }
+
+    #[test]
+    fn test_try_from_manufacturer_specific_data() {
+        let manufacturer_specific_data =
+            ManufacturerSpecificData::new(IBEACON_COMPANY_IDENTIFIER, &data());
+        let result = IBeacon::try_from(&manufacturer_specific_data);
+        assert_eq!(Ok(IBeacon::new(&UUID, 1, 2, -59)), result);
+
+        let manufacturer_specific_data = ManufacturerSpecificData::new(0x0000, &data());
+        let result = IBeacon::try_from(&manufacturer_specific_data);
+        assert_eq!(Err("Invalid company identifier :0".to_string()), result);
+    }
+
+    #[test]
+    fn test_into() {
+        let into_data: Vec<u8> = IBeacon::new(&UUID, 1, 2, -59).into();
+        assert_eq!(data(), into_data);
+    }
+
+    #[test]
+    fn test_from() {
+        let result = ManufacturerSpecificData::from(&IBeacon::new(&UUID, 1, 2, -59));
+        assert_eq!(
+            ManufacturerSpecificData::new(IBEACON_COMPANY_IDENTIFIER, &data()),
+            result
+        );
+    }
+}
@@ -0,0 +1,134 @@
+//! Battery Level (Attribute Type: 0x2a19) module.
+
+use crate::{uuids::characteristic, Uuid16bit};
+
+/// Battery Level.
+#[derive(Debug, PartialEq, Clone)]
+pub struct BatteryLevel {
+    /// Battery Level(%)
+    pub battery_level: u8,
+}
+
+impl BatteryLevel {
+    /// Create [`BatteryLevel`] from `Battery Level`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ble_data_struct::characteristics::battery_level::BatteryLevel;
+    ///
+    /// let result = BatteryLevel::new(50);
+    /// assert_eq!(50, result.battery_level);
+    /// ```
+    pub fn new(battery_level: u8) -> Self {
+        Self { battery_level }
+    }
+}
+
+impl TryFrom<&Vec<u8>> for BatteryLevel {
+    type Error = String;
+    /// Create [`BatteryLevel`] from [`Vec<u8>`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ble_data_struct::characteristics::battery_level::BatteryLevel;
+    ///
+    /// let result = BatteryLevel::try_from(&vec![50]);
+    /// assert!(result.is_ok());
+    /// assert_eq!(50, result.unwrap().battery_level);
+    ///
+    /// let result = BatteryLevel::try_from(&vec![101]);
+    /// assert!(result.is_err());
+    ///
+    /// let data: Vec<u8> = Vec::new();
+    /// let result = BatteryLevel::try_from(&data);
+    /// assert!(result.is_err());
+    /// assert_eq!(
+    ///     format!("Invalid data size :{}", data.len()),
+    ///     result.unwrap_err()
+    /// );
+    /// ```
+    fn try_from(value: &Vec<u8>) -> Result<Self, String> {
+        let len = value.len();
+        if len != 1 {
+            return Err(format!("Invalid data size :{}", len));
+        }
+        if value[0] > 100 {
+            return Err(format!("Invalid battery level :{}", value[0]));
+        }
+        Ok(Self {
+            battery_level: value[0],
+        })
+    }
+}
+
+impl Into<Vec<u8>> for BatteryLevel {
+    /// Create [`Vec<u8>`] from [`BatteryLevel`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ble_data_struct::characteristics::battery_level::BatteryLevel;
+    ///
+    /// let into_data: Vec<u8> = BatteryLevel::new(50).into();
+    /// assert_eq!(vec![50], into_data);
+    /// ```
+    fn into(self) -> Vec<u8> {
+        vec![self.battery_level]
+    }
+}
+
+impl Uuid16bit for BatteryLevel {
+    /// return `0x2a19`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ble_data_struct::{characteristics::battery_level::BatteryLevel, Uuid16bit};
+    ///
+    /// assert_eq!(0x2a19, BatteryLevel::uuid_16bit());
+    /// ```
+    fn uuid_16bit() -> u16 {
+        characteristic::BATTERY_LEVEL
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{characteristics::battery_level::BatteryLevel, Uuid16bit};
+
+    #[test]
+    fn test_new() {
+        let result = BatteryLevel::new(50);
+        assert_eq!(50, result.battery_level);
+    }
+
+    #[test]
+    fn test_try_from() {
+        let result = BatteryLevel::try_from(&vec![0]);
+        assert_eq!(Ok(BatteryLevel::new(0)), result);
+        let result = BatteryLevel::try_from(&vec![100]);
+        assert_eq!(Ok(BatteryLevel::new(100)), result);
+
+        let result = BatteryLevel::try_from(&vec![101]);
+        assert_eq!(Err("Invalid battery level :101".to_string()), result);
+
+        let data: Vec<u8> = Vec::new();
+        let result = BatteryLevel::try_from(&data);
+        assert_eq!(Err("Invalid data size :0".to_string()), result);
+        let result = BatteryLevel::try_from(&vec![50, 0]);
+        assert_eq!(Err("Invalid data size :2".to_string()), result);
+    }
+
+    #[test]
+    fn test_into() {
+        let into_data: Vec<u8> = BatteryLevel::new(50).into();
+        assert_eq!(vec![50], into_data);
+    }
+
+    #[test]
+    fn test_uuid_16bit() {
+        assert_eq!(0x2a19, BatteryLevel::uuid_16bit());
+    }
+}
@@ -0,0 +1,401 @@
+//! Control point framework module.
+//!
+//! Shared opcode / response / change counter handling for control point characteristics
+//! (Media Control Point, Call Control Point, ...).
+
+/// ATT application error: Invalid Change Counter.
+pub const INVALID_CHANGE_COUNTER: u8 = 0x80;
+
+/// ATT application error: Opcode Not Supported.
+pub const OPCODE_NOT_SUPPORTED: u8 = 0x81;
+
+/// Control point result code: Success.
+pub const RESULT_SUCCESS: u8 = 0x01;
+
+/// Trait for control point opcode.
+///
+/// Implemented by [`control_point_opcode!`](crate::control_point_opcode).
+pub trait ControlPointOpcode: Sized + Copy {
+    /// Opcode value
+    fn opcode(&self) -> u8;
+
+    /// Create opcode from value(`None` if not supported)
+    fn from_opcode(opcode: u8) -> Option<Self>;
+}
+
+/// Declare control point opcode enum and implement [`ControlPointOpcode`].
+///
+/// # Examples
+///
+/// ```
+/// use ble_data_struct::{characteristics::control_point::ControlPointOpcode, control_point_opcode};
+///
+/// control_point_opcode! {
+///     /// Sample opcode.
+///     pub enum SampleOpcode {
+///         /// Play
+///         Play = 0x01,
+///         /// Pause
+///         Pause = 0x02,
+///     }
+/// }
+///
+/// assert_eq!(0x02, SampleOpcode::Pause.opcode());
+/// assert_eq!(Some(SampleOpcode::Play), SampleOpcode::from_opcode(0x01));
+/// assert_eq!(None, SampleOpcode::from_opcode(0x03));
+/// ```
+#[macro_export]
+macro_rules! control_point_opcode {
+    (
+        $(#[$meta:meta])*
+        $vis:vis enum $name:ident {
+            $($(#[$variant_meta:meta])* $variant:ident = $value:expr),+ $(,)?
+        }
+    ) => {
+        $(#[$meta])*
+        #[derive(Debug, PartialEq, Eq, Clone, Copy, Hash)]
+        $vis enum $name {
+            $($(#[$variant_meta])* $variant),+
+        }
+
+        impl $crate::characteristics::control_point::ControlPointOpcode for $name {
+            fn opcode(&self) -> u8 {
+                match self {
+                    $(Self::$variant => $value),+
+                }
+            }
+
+            fn from_opcode(opcode: u8) -> Option<Self> {
+                $(if opcode == $value {
+                    return Some(Self::$variant);
+                })+
+                None
+            }
+        }
+    };
+}
+
+/// Control point request(written to the control point).
+#[derive(Debug, PartialEq, Clone)]
+pub struct ControlPointRequest<O: ControlPointOpcode> {
+    /// Opcode
+    pub opcode: O,
+
+    /// Parameter
+    pub parameter: Vec<u8>,
+}
+
+impl<O: ControlPointOpcode> ControlPointRequest<O> {
+    /// Create [`ControlPointRequest`] from `Opcode` and `Parameter`.
+    pub fn new(opcode: O, parameter: &[u8]) -> Self {
+        Self {
+            opcode,
+            parameter: parameter.to_vec(),
+        }
+    }
+}
+
+impl<O: ControlPointOpcode> TryFrom<&Vec<u8>> for ControlPointRequest<O> {
+    type Error = String;
+    /// Create [`ControlPointRequest`] from [`Vec<u8>`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ble_data_struct::{
+    ///     characteristics::control_point::{ControlPointOpcode, ControlPointRequest},
+    ///     control_point_opcode,
+    /// };
+    ///
+    /// control_point_opcode! {
+    ///     enum SampleOpcode {
+    ///         Move = 0x10,
+    ///     }
+    /// }
+    ///
+    /// let result = ControlPointRequest::<SampleOpcode>::try_from(&vec![0x10, 0x01, 0x02]);
+    /// assert_eq!(Ok(ControlPointRequest::new(SampleOpcode::Move, &[0x01, 0x02])), result);
+    ///
+    /// let result = ControlPointRequest::<SampleOpcode>::try_from(&vec![0x11]);
+    /// assert_eq!(Err("Unknown opcode :17".to_string()), result);
+    ///
+    /// let data: Vec<u8> = Vec::new();
+    /// let result = ControlPointRequest::<SampleOpcode>::try_from(&data);
+    /// assert_eq!(Err(format!("Invalid data size :{}", data.len())), result);
+    /// ```
+    fn try_from(value: &Vec<u8>) -> Result<Self, String> {
+        let len = value.len();
+        if len < 1 {
+            return Err(format!("Invalid data size :{}", len));
+        }
+        let opcode = O::from_opcode(value[0]).ok_or(format!("Unknown opcode :{}", value[0]))?;
+        Ok(Self {
+            opcode,
+            parameter: value[1..].to_vec(),
+        })
+    }
+}
+
+impl<O: ControlPointOpcode> Into<Vec<u8>> for ControlPointRequest<O> {
+    /// Create [`Vec<u8>`] from [`ControlPointRequest`].
+    fn into(self) -> Vec<u8> {
+        let mut data: Vec<u8> = Vec::new();
+        data.push(self.opcode.opcode());
+        data.append(&mut self.parameter.clone());
+        return data;
+    }
+}
+
+/// Control point response(notified by the control point).
+///
+/// `Requested Opcode`, `Result Code` and optional `Parameter`.
+#[derive(Debug, PartialEq, Clone)]
+pub struct ControlPointResponse<O: ControlPointOpcode> {
+    /// Requested Opcode
+    pub request_opcode: O,
+
+    /// Result Code
+    pub result_code: u8,
+
+    /// Parameter
+    pub parameter: Vec<u8>,
+}
+
+impl<O: ControlPointOpcode> ControlPointResponse<O> {
+    /// Create [`ControlPointResponse`] from `Requested Opcode`, `Result Code` and `Parameter`.
+    pub fn new(request_opcode: O, result_code: u8, parameter: &[u8]) -> Self {
+        Self {
+            request_opcode,
+            result_code,
+            parameter: parameter.to_vec(),
+        }
+    }
+
+    /// Check the response belongs to the request.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ble_data_struct::{
+    ///     characteristics::control_point::{
+    ///         ControlPointRequest, ControlPointResponse, RESULT_SUCCESS,
+    ///     },
+    ///     control_point_opcode,
+    /// };
+    ///
+    /// control_point_opcode! {
+    ///     enum SampleOpcode {
+    ///         Play = 0x01,
+    ///         Pause = 0x02,
+    ///     }
+    /// }
+    ///
+    /// let request = ControlPointRequest::new(SampleOpcode::Play, &[]);
+    /// let response = ControlPointResponse::new(SampleOpcode::Play, RESULT_SUCCESS, &[]);
+    /// assert!(response.matches(&request));
+    /// assert!(response.is_success());
+    ///
+    /// let response = ControlPointResponse::new(SampleOpcode::Pause, RESULT_SUCCESS, &[]);
+    /// assert!(!response.matches(&request));
+    /// ```
+    pub fn matches(&self, request: &ControlPointRequest<O>) -> bool {
+        self.request_opcode.opcode() == request.opcode.opcode()
+    }
+
+    /// Check `Result Code` is [`RESULT_SUCCESS`].
+    pub fn is_success(&self) -> bool {
+        self.result_code == RESULT_SUCCESS
+    }
+}
+
+impl<O: ControlPointOpcode> TryFrom<&Vec<u8>> for ControlPointResponse<O> {
+    type Error = String;
+    /// Create [`ControlPointResponse`] from [`Vec<u8>`].
+    fn try_from(value: &Vec<u8>) -> Result<Self, String> {
+        let len = value.len();
+        if len < 2 {
+            return Err(format!("Invalid data size :{}", len));
+        }
+        let request_opcode =
+            O::from_opcode(value[0]).ok_or(format!("Unknown opcode :{}", value[0]))?;
+        Ok(Self {
+            request_opcode,
+            result_code: value[1],
+            parameter: value[2..].to_vec(),
+        })
+    }
+}
+
+impl<O: ControlPointOpcode> Into<Vec<u8>> for ControlPointResponse<O> {
+    /// Create [`Vec<u8>`] from [`ControlPointResponse`].
+    fn into(self) -> Vec<u8> {
+        let mut data: Vec<u8> = Vec::new();
+        data.push(self.request_opcode.opcode());
+        data.push(self.result_code);
+        data.append(&mut self.parameter.clone());
+        return data;
+    }
+}
+
+/// Change Counter.
+///
+/// Server side counter that is incremented (wrapping) on every state change.
+/// Writes carrying a stale counter are rejected with [`INVALID_CHANGE_COUNTER`].
+#[derive(Debug, PartialEq, Clone, Copy, Default)]
+pub struct ChangeCounter {
+    /// Change Counter
+    pub change_counter: u8,
+}
+
+impl ChangeCounter {
+    /// Create [`ChangeCounter`] from `Change Counter`.
+    pub fn new(change_counter: u8) -> Self {
+        Self { change_counter }
+    }
+
+    /// Check the written counter matches.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ble_data_struct::characteristics::control_point::{
+    ///     ChangeCounter, INVALID_CHANGE_COUNTER,
+    /// };
+    ///
+    /// let counter = ChangeCounter::new(1);
+    /// assert_eq!(Ok(()), counter.check(1));
+    /// assert_eq!(Err(INVALID_CHANGE_COUNTER), counter.check(0));
+    /// ```
+    pub fn check(&self, change_counter: u8) -> Result<(), u8> {
+        if self.change_counter == change_counter {
+            Ok(())
+        } else {
+            Err(INVALID_CHANGE_COUNTER)
+        }
+    }
+
+    /// Increment the counter (wraps from 255 to 0) and return the new value.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ble_data_struct::characteristics::control_point::ChangeCounter;
+    ///
+    /// let mut counter = ChangeCounter::new(255);
+    /// assert_eq!(0, counter.increment());
+    /// assert_eq!(1, counter.increment());
+    /// ```
+    pub fn increment(&mut self) -> u8 {
+        self.change_counter = self.change_counter.wrapping_add(1);
+        self.change_counter
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::characteristics::control_point::{
+        ChangeCounter, ControlPointOpcode, ControlPointRequest, ControlPointResponse,
+        INVALID_CHANGE_COUNTER, RESULT_SUCCESS,
+    };
+
+    control_point_opcode! {
+        /// Test opcode.
+        enum TestOpcode {
+            /// Play
+            Play = 0x01,
+            /// Move Relative
+            MoveRelative = 0x10,
+        }
+    }
+
+    #[test]
+    fn test_opcode() {
+        assert_eq!(0x01, TestOpcode::Play.opcode());
+        assert_eq!(0x10, TestOpcode::MoveRelative.opcode());
+        assert_eq!(Some(TestOpcode::Play), TestOpcode::from_opcode(0x01));
+        assert_eq!(
+            Some(TestOpcode::MoveRelative),
+            TestOpcode::from_opcode(0x10)
+        );
+        assert_eq!(None, TestOpcode::from_opcode(0x00));
+    }
+
+    #[test]
+    fn test_request_try_from() {
+        let result = ControlPointRequest::<TestOpcode>::try_from(&vec![0x10, 0x01, 0x02]);
+        assert_eq!(
+            Ok(ControlPointRequest::new(
+                TestOpcode::MoveRelative,
+                &[0x01, 0x02]
+            )),
+            result
+        );
+        let result = ControlPointRequest::<TestOpcode>::try_from(&vec![0x01]);
+        assert_eq!(
+            Ok(ControlPointRequest::new(TestOpcode::Play, &[])),
+            result
+        );
+
+        let result = ControlPointRequest::<TestOpcode>::try_from(&vec![0x02]);
+        assert_eq!(Err("Unknown opcode :2".to_string()), result);
+        let result = ControlPointRequest::<TestOpcode>::try_from(&Vec::new());
+        assert_eq!(Err("Invalid data size :0".to_string()), result);
+    }
+
+    #[test]
+    fn test_request_into() {
+        let into_data: Vec<u8> =
+            ControlPointRequest::new(TestOpcode::MoveRelative, &[0x01, 0x02]).into();
+        assert_eq!(vec![0x10, 0x01, 0x02], into_data);
+    }
+
+    #[test]
+    fn test_response_try_from() {
+        let result = ControlPointResponse::<TestOpcode>::try_from(&vec![0x10, 0x01, 0x02]);
+        assert_eq!(
+            Ok(ControlPointResponse::new(
+                TestOpcode::MoveRelative,
+                RESULT_SUCCESS,
+                &[0x02]
+            )),
+            result
+        );
+
+        let result = ControlPointResponse::<TestOpcode>::try_from(&vec![0x02, 0x01]);
+        assert_eq!(Err("Unknown opcode :2".to_string()), result);
+        let result = ControlPointResponse::<TestOpcode>::try_from(&vec![0x01]);
+        assert_eq!(Err("Invalid data size :1".to_string()), result);
+    }
+
+    #[test]
+    fn test_response_into() {
+        let into_data: Vec<u8> =
+            ControlPointResponse::new(TestOpcode::Play, 0x02, &[]).into();
+        assert_eq!(vec![0x01, 0x02], into_data);
+    }
+
+    #[test]
+    fn test_response_matches() {
+        let request = ControlPointRequest::new(TestOpcode::Play, &[]);
+        let response = ControlPointResponse::new(TestOpcode::Play, RESULT_SUCCESS, &[]);
+        assert!(response.matches(&request));
+        assert!(response.is_success());
+
+        let response = ControlPointResponse::new(TestOpcode::MoveRelative, 0x02, &[]);
+        assert!(!response.matches(&request));
+        assert!(!response.is_success());
+    }
+
+    #[test]
+    fn test_change_counter() {
+        let mut counter = ChangeCounter::default();
+        assert_eq!(Ok(()), counter.check(0));
+        assert_eq!(Err(INVALID_CHANGE_COUNTER), counter.check(1));
+        assert_eq!(1, counter.increment());
+        assert_eq!(Ok(()), counter.check(1));
+
+        let mut counter = ChangeCounter::new(255);
+        assert_eq!(0, counter.increment());
+        assert_eq!(0, counter.change_counter);
+    }
+}
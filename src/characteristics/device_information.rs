@@ -0,0 +1,154 @@
+//! Device Information Service UTF-8 string characteristics module.
+
+use crate::{uuids::characteristic, Uuid16bit};
+
+/// UTF-8 string characteristic of Device Information Service.
+#[derive(Debug, PartialEq, Clone)]
+pub struct DeviceInformationString<const UUID: u16> {
+    /// value
+    pub value: String,
+}
+
+/// Manufacturer Name String (Attribute Type: 0x2a29).
+pub type ManufacturerNameString =
+    DeviceInformationString<{ characteristic::MANUFACTURER_NAME_STRING }>;
+
+/// Model Number String (Attribute Type: 0x2a24).
+pub type ModelNumberString = DeviceInformationString<{ characteristic::MODEL_NUMBER_STRING }>;
+
+/// Serial Number String (Attribute Type: 0x2a25).
+pub type SerialNumberString = DeviceInformationString<{ characteristic::SERIAL_NUMBER_STRING }>;
+
+/// Firmware Revision String (Attribute Type: 0x2a26).
+pub type FirmwareRevisionString =
+    DeviceInformationString<{ characteristic::FIRMWARE_REVISION_STRING }>;
+
+/// Hardware Revision String (Attribute Type: 0x2a27).
+pub type HardwareRevisionString =
+    DeviceInformationString<{ characteristic::HARDWARE_REVISION_STRING }>;
+
+/// Software Revision String (Attribute Type: 0x2a28).
+pub type SoftwareRevisionString =
+    DeviceInformationString<{ characteristic::SOFTWARE_REVISION_STRING }>;
+
+impl<const UUID: u16> DeviceInformationString<UUID> {
+    /// Create [`DeviceInformationString`] from [`str`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ble_data_struct::characteristics::device_information::ManufacturerNameString;
+    ///
+    /// let value = "manufacturer".to_string();
+    /// let result = ManufacturerNameString::new(&value);
+    /// assert_eq!(value, result.value);
+    /// ```
+    pub fn new(value: &str) -> Self {
+        Self {
+            value: value.to_string(),
+        }
+    }
+}
+
+impl<const UUID: u16> TryFrom<&Vec<u8>> for DeviceInformationString<UUID> {
+    type Error = String;
+    /// Create [`DeviceInformationString`] from [`Vec<u8>`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ble_data_struct::characteristics::device_information::ModelNumberString;
+    ///
+    /// let value = "model".to_string();
+    /// let result = ModelNumberString::try_from(&value.clone().into_bytes());
+    /// assert!(result.is_ok());
+    /// assert_eq!(value, result.unwrap().value);
+    ///
+    /// let result = ModelNumberString::try_from(&vec![0xff]);
+    /// assert!(result.is_err());
+    /// ```
+    fn try_from(value: &Vec<u8>) -> Result<Self, String> {
+        match String::from_utf8(value.clone()) {
+            Ok(value) => Ok(Self { value }),
+            Err(error) => Err(format!("Invalid UTF-8 :{}", error)),
+        }
+    }
+}
+
+impl<const UUID: u16> Into<Vec<u8>> for DeviceInformationString<UUID> {
+    /// Create [`Vec<u8>`] from [`DeviceInformationString`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ble_data_struct::characteristics::device_information::SerialNumberString;
+    ///
+    /// let value = "serial".to_string();
+    /// let into_data: Vec<u8> = SerialNumberString::new(&value).into();
+    /// assert_eq!(value.into_bytes(), into_data);
+    /// ```
+    fn into(self) -> Vec<u8> {
+        self.value.into_bytes()
+    }
+}
+
+impl<const UUID: u16> Uuid16bit for DeviceInformationString<UUID> {
+    /// return Attribute Type.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ble_data_struct::{
+    ///     characteristics::device_information::{FirmwareRevisionString, ManufacturerNameString},
+    ///     Uuid16bit,
+    /// };
+    ///
+    /// assert_eq!(0x2a29, ManufacturerNameString::uuid_16bit());
+    /// assert_eq!(0x2a26, FirmwareRevisionString::uuid_16bit());
+    /// ```
+    fn uuid_16bit() -> u16 {
+        UUID
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{characteristics::device_information::*, Uuid16bit};
+
+    #[test]
+    fn test_new() {
+        let value = "manufacturer".to_string();
+        let result = ManufacturerNameString::new(&value);
+        assert_eq!(value, result.value);
+    }
+
+    #[test]
+    fn test_try_from() {
+        let value = "model".to_string();
+        let result = ModelNumberString::try_from(&value.clone().into_bytes());
+        assert_eq!(Ok(ModelNumberString::new(&value)), result);
+
+        let result = ModelNumberString::try_from(&Vec::new());
+        assert_eq!(Ok(ModelNumberString::new("")), result);
+
+        let result = ModelNumberString::try_from(&vec![0xff]);
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn test_into() {
+        let value = "serial".to_string();
+        let into_data: Vec<u8> = SerialNumberString::new(&value).into();
+        assert_eq!(value.into_bytes(), into_data);
+    }
+
+    #[test]
+    fn test_uuid_16bit() {
+        assert_eq!(0x2a29, ManufacturerNameString::uuid_16bit());
+        assert_eq!(0x2a24, ModelNumberString::uuid_16bit());
+        assert_eq!(0x2a25, SerialNumberString::uuid_16bit());
+        assert_eq!(0x2a26, FirmwareRevisionString::uuid_16bit());
+        assert_eq!(0x2a27, HardwareRevisionString::uuid_16bit());
+        assert_eq!(0x2a28, SoftwareRevisionString::uuid_16bit());
+    }
+}
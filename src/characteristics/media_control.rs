@@ -0,0 +1,524 @@
+//! Media Control Service characteristics module.
+
+use crate::{
+    characteristics::control_point::{
+        ControlPointOpcode, ControlPointRequest, ControlPointResponse,
+    },
+    control_point_opcode,
+    uuids::characteristic,
+    Uuid16bit,
+};
+
+/// Media State: Inactive
+pub const INACTIVE: u8 = 0x00;
+
+/// Media State: Playing
+pub const PLAYING: u8 = 0x01;
+
+/// Media State: Paused
+pub const PAUSED: u8 = 0x02;
+
+/// Media State: Seeking
+pub const SEEKING: u8 = 0x03;
+
+/// Media Control Point result code: Success
+pub const SUCCESS: u8 = 0x01;
+
+/// Media Control Point result code: Opcode Not Supported
+pub const OPCODE_NOT_SUPPORTED: u8 = 0x02;
+
+/// Media Control Point result code: Media Player Inactive
+pub const MEDIA_PLAYER_INACTIVE: u8 = 0x03;
+
+/// Media Control Point result code: Command Cannot Be Completed
+pub const COMMAND_CANNOT_BE_COMPLETED: u8 = 0x04;
+
+/// Media State (Attribute Type: 0x2ba3).
+#[derive(Debug, PartialEq, Clone)]
+pub struct MediaState {
+    /// Media State
+    pub media_state: u8,
+}
+
+impl MediaState {
+    /// Create [`MediaState`] from `Media State`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ble_data_struct::characteristics::media_control::{MediaState, PLAYING};
+    ///
+    /// let result = MediaState::new(PLAYING);
+    /// assert_eq!(PLAYING, result.media_state);
+    /// ```
+    pub fn new(media_state: u8) -> Self {
+        Self { media_state }
+    }
+
+    /// check Inactive.
+    pub fn is_inactive(&self) -> bool {
+        self.media_state == INACTIVE
+    }
+
+    /// check Playing.
+    pub fn is_playing(&self) -> bool {
+        self.media_state == PLAYING
+    }
+
+    /// check Paused.
+    pub fn is_paused(&self) -> bool {
+        self.media_state == PAUSED
+    }
+
+    /// check Seeking.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ble_data_struct::characteristics::media_control::*;
+    ///
+    /// let result = MediaState::new(SEEKING);
+    /// assert!(result.is_seeking());
+    /// assert!(!result.is_inactive());
+    /// assert!(!result.is_playing());
+    /// assert!(!result.is_paused());
+    /// ```
+    pub fn is_seeking(&self) -> bool {
+        self.media_state == SEEKING
+    }
+}
+
+impl TryFrom<&Vec<u8>> for MediaState {
+    type Error = String;
+    /// Create [`MediaState`] from [`Vec<u8>`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ble_data_struct::characteristics::media_control::{MediaState, PAUSED};
+    ///
+    /// let result = MediaState::try_from(&vec![PAUSED]);
+    /// assert_eq!(Ok(MediaState::new(PAUSED)), result);
+    ///
+    /// let result = MediaState::try_from(&vec![0x04]);
+    /// assert!(result.is_err());
+    ///
+    /// let data: Vec<u8> = Vec::new();
+    /// let result = MediaState::try_from(&data);
+    /// assert_eq!(Err(format!("Invalid data size :{}", data.len())), result);
+    /// ```
+    fn try_from(value: &Vec<u8>) -> Result<Self, String> {
+        let len = value.len();
+        if len != 1 {
+            return Err(format!("Invalid data size :{}", len));
+        }
+        if value[0] > SEEKING {
+            return Err(format!("Invalid media state :{}", value[0]));
+        }
+        Ok(Self {
+            media_state: value[0],
+        })
+    }
+}
+
+impl Into<Vec<u8>> for MediaState {
+    /// Create [`Vec<u8>`] from [`MediaState`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ble_data_struct::characteristics::media_control::{MediaState, PLAYING};
+    ///
+    /// let into_data: Vec<u8> = MediaState::new(PLAYING).into();
+    /// assert_eq!(vec![PLAYING], into_data);
+    /// ```
+    fn into(self) -> Vec<u8> {
+        vec![self.media_state]
+    }
+}
+
+impl Uuid16bit for MediaState {
+    /// return `0x2ba3`.
+    fn uuid_16bit() -> u16 {
+        characteristic::MEDIA_STATE
+    }
+}
+
+/// UTF-8 string characteristic of Media Control Service.
+///
+/// Long values read with a single ATT Read may be cut in the middle of a UTF-8 sequence.
+/// The incomplete tail is dropped and `truncated` is set.
+#[derive(Debug, PartialEq, Clone)]
+pub struct MediaString<const UUID: u16> {
+    /// value
+    pub value: String,
+
+    /// `true` if the value was truncated
+    pub truncated: bool,
+}
+
+/// Media Player Name (Attribute Type: 0x2b93).
+pub type MediaPlayerName = MediaString<0x2b93>;
+
+/// Track Title (Attribute Type: 0x2b97).
+pub type TrackTitle = MediaString<0x2b97>;
+
+impl<const UUID: u16> MediaString<UUID> {
+    /// Create [`MediaString`] from [`str`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ble_data_struct::characteristics::media_control::TrackTitle;
+    ///
+    /// let value = "title".to_string();
+    /// let result = TrackTitle::new(&value);
+    /// assert_eq!(value, result.value);
+    /// assert!(!result.truncated);
+    /// ```
+    pub fn new(value: &str) -> Self {
+        Self {
+            value: value.to_string(),
+            truncated: false,
+        }
+    }
+
+    /// Truncate the value to `max_length` bytes at a character boundary.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ble_data_struct::characteristics::media_control::TrackTitle;
+    ///
+    /// let result = TrackTitle::new("t\u{e9}tle").truncate(2);
+    /// assert_eq!("t", result.value);
+    /// assert!(result.truncated);
+    ///
+    /// let result = TrackTitle::new("title").truncate(5);
+    /// assert_eq!("title", result.value);
+    /// assert!(!result.truncated);
+    /// ```
+    pub fn truncate(&self, max_length: usize) -> Self {
+        if self.value.len() <= max_length {
+            return self.clone();
+        }
+        let mut index = max_length;
+        while !self.value.is_char_boundary(index) {
+            index -= 1;
+        }
+        Self {
+            value: self.value[..index].to_string(),
+            truncated: true,
+        }
+    }
+}
+
+impl<const UUID: u16> TryFrom<&Vec<u8>> for MediaString<UUID> {
+    type Error = String;
+    /// Create [`MediaString`] from [`Vec<u8>`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ble_data_struct::characteristics::media_control::TrackTitle;
+    ///
+    /// let result = TrackTitle::try_from(&"title".to_string().into_bytes());
+    /// assert_eq!(Ok(TrackTitle::new("title")), result);
+    ///
+    /// // "té" cut in the middle of "é"
+    /// let result = TrackTitle::try_from(&vec![0x74, 0xc3]).unwrap();
+    /// assert_eq!("t", result.value);
+    /// assert!(result.truncated);
+    ///
+    /// let result = TrackTitle::try_from(&vec![0xff, 0x74]);
+    /// assert!(result.is_err());
+    /// ```
+    fn try_from(value: &Vec<u8>) -> Result<Self, String> {
+        match std::str::from_utf8(value) {
+            Ok(string) => Ok(Self::new(string)),
+            Err(error) if error.error_len().is_none() => Ok(Self {
+                value: String::from_utf8_lossy(&value[..error.valid_up_to()]).to_string(),
+                truncated: true,
+            }),
+            Err(error) => Err(format!("Invalid UTF-8 :{}", error)),
+        }
+    }
+}
+
+impl<const UUID: u16> Into<Vec<u8>> for MediaString<UUID> {
+    /// Create [`Vec<u8>`] from [`MediaString`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ble_data_struct::characteristics::media_control::MediaPlayerName;
+    ///
+    /// let value = "player".to_string();
+    /// let into_data: Vec<u8> = MediaPlayerName::new(&value).into();
+    /// assert_eq!(value.into_bytes(), into_data);
+    /// ```
+    fn into(self) -> Vec<u8> {
+        self.value.into_bytes()
+    }
+}
+
+impl<const UUID: u16> Uuid16bit for MediaString<UUID> {
+    /// return Attribute Type.
+    fn uuid_16bit() -> u16 {
+        UUID
+    }
+}
+
+control_point_opcode! {
+    /// Media Control Point opcode.
+    pub enum MediaControlPointOpcode {
+        /// Play
+        Play = 0x01,
+        /// Pause
+        Pause = 0x02,
+        /// Fast Rewind
+        FastRewind = 0x03,
+        /// Fast Forward
+        FastForward = 0x04,
+        /// Stop
+        Stop = 0x05,
+        /// Move Relative(Offset: int32, 0.01 s)
+        MoveRelative = 0x10,
+        /// Previous Segment
+        PreviousSegment = 0x20,
+        /// Next Segment
+        NextSegment = 0x21,
+        /// First Segment
+        FirstSegment = 0x22,
+        /// Last Segment
+        LastSegment = 0x23,
+        /// Goto Segment(n: int32)
+        GotoSegment = 0x24,
+        /// Previous Track
+        PreviousTrack = 0x30,
+        /// Next Track
+        NextTrack = 0x31,
+        /// First Track
+        FirstTrack = 0x32,
+        /// Last Track
+        LastTrack = 0x33,
+        /// Goto Track(n: int32)
+        GotoTrack = 0x34,
+        /// Previous Group
+        PreviousGroup = 0x40,
+        /// Next Group
+        NextGroup = 0x41,
+        /// First Group
+        FirstGroup = 0x42,
+        /// Last Group
+        LastGroup = 0x43,
+        /// Goto Group(n: int32)
+        GotoGroup = 0x44,
+    }
+}
+
+impl MediaControlPointOpcode {
+    /// check the opcode takes an int32 parameter.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ble_data_struct::characteristics::media_control::MediaControlPointOpcode;
+    ///
+    /// assert!(MediaControlPointOpcode::MoveRelative.has_parameter());
+    /// assert!(!MediaControlPointOpcode::Play.has_parameter());
+    /// ```
+    pub fn has_parameter(&self) -> bool {
+        matches!(
+            self,
+            MediaControlPointOpcode::MoveRelative
+                | MediaControlPointOpcode::GotoSegment
+                | MediaControlPointOpcode::GotoTrack
+                | MediaControlPointOpcode::GotoGroup
+        )
+    }
+}
+
+/// Media Control Point (Attribute Type: 0x2ba4).
+pub type MediaControlPoint = ControlPointRequest<MediaControlPointOpcode>;
+
+/// Media Control Point notification.
+pub type MediaControlPointNotification = ControlPointResponse<MediaControlPointOpcode>;
+
+impl MediaControlPoint {
+    /// Create [`MediaControlPoint`] from opcode and optional int32 parameter.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ble_data_struct::characteristics::media_control::{
+    ///     MediaControlPoint, MediaControlPointOpcode,
+    /// };
+    ///
+    /// let result = MediaControlPoint::create(MediaControlPointOpcode::MoveRelative, Some(-100));
+    /// assert!(result.is_ok());
+    /// let into_data: Vec<u8> = result.unwrap().into();
+    /// assert_eq!(vec![0x10, 0x9c, 0xff, 0xff, 0xff], into_data);
+    ///
+    /// assert!(MediaControlPoint::create(MediaControlPointOpcode::MoveRelative, None).is_err());
+    /// assert!(MediaControlPoint::create(MediaControlPointOpcode::Play, Some(1)).is_err());
+    /// ```
+    pub fn create(opcode: MediaControlPointOpcode, parameter: Option<i32>) -> Result<Self, String> {
+        match (opcode.has_parameter(), parameter) {
+            (true, Some(parameter)) => Ok(Self::new(opcode, &parameter.to_le_bytes())),
+            (false, None) => Ok(Self::new(opcode, &Vec::new())),
+            _ => Err(format!("Invalid parameter :{}", opcode.opcode())),
+        }
+    }
+
+    /// Returns the int32 parameter.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ble_data_struct::characteristics::media_control::{
+    ///     MediaControlPoint, MediaControlPointOpcode,
+    /// };
+    ///
+    /// let result = MediaControlPoint::try_from(&vec![0x34, 0x02, 0x00, 0x00, 0x00]).unwrap();
+    /// assert_eq!(MediaControlPointOpcode::GotoTrack, result.opcode);
+    /// assert_eq!(Some(2), result.int32_parameter());
+    ///
+    /// let result = MediaControlPoint::try_from(&vec![0x01]).unwrap();
+    /// assert_eq!(None, result.int32_parameter());
+    /// ```
+    pub fn int32_parameter(&self) -> Option<i32> {
+        if !self.opcode.has_parameter() {
+            return None;
+        }
+        Some(i32::from_le_bytes(
+            self.parameter.get(0..4)?.try_into().ok()?,
+        ))
+    }
+}
+
+impl Uuid16bit for MediaControlPoint {
+    /// return `0x2ba4`.
+    fn uuid_16bit() -> u16 {
+        characteristic::MEDIA_CONTROL_POINT
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        characteristics::{control_point::ControlPointOpcode, media_control::*},
+        Uuid16bit,
+    };
+
+    #[test]
+    fn test_media_state() {
+        for (media_state, expected) in [
+            (INACTIVE, [true, false, false, false]),
+            (PLAYING, [false, true, false, false]),
+            (PAUSED, [false, false, true, false]),
+            (SEEKING, [false, false, false, true]),
+        ] {
+            let result = MediaState::try_from(&vec![media_state]).unwrap();
+            assert_eq!(
+                expected,
+                [
+                    result.is_inactive(),
+                    result.is_playing(),
+                    result.is_paused(),
+                    result.is_seeking()
+                ]
+            );
+            let into_data: Vec<u8> = result.into();
+            assert_eq!(vec![media_state], into_data);
+        }
+        assert_eq!(
+            Err("Invalid media state :4".to_string()),
+            MediaState::try_from(&vec![0x04])
+        );
+        assert_eq!(
+            Err("Invalid data size :2".to_string()),
+            MediaState::try_from(&vec![0x00, 0x00])
+        );
+        assert_eq!(0x2ba3, MediaState::uuid_16bit());
+    }
+
+    #[test]
+    fn test_media_string_try_from() {
+        let value = "t\u{e9}tle".to_string();
+        let result = TrackTitle::try_from(&value.clone().into_bytes());
+        assert_eq!(Ok(TrackTitle::new(&value)), result);
+
+        let result = TrackTitle::try_from(&value.clone().into_bytes()[..2].to_vec());
+        assert_eq!(
+            Ok(TrackTitle {
+                value: "t".to_string(),
+                truncated: true
+            }),
+            result
+        );
+
+        let result = TrackTitle::try_from(&Vec::new());
+        assert_eq!(Ok(TrackTitle::new("")), result);
+
+        let result = TrackTitle::try_from(&vec![0xc3, 0x74]);
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn test_media_string_truncate() {
+        let value = "t\u{e9}tle".to_string();
+        let result = TrackTitle::new(&value).truncate(3);
+        assert_eq!("t\u{e9}", result.value);
+        assert!(result.truncated);
+        let result = TrackTitle::new(&value).truncate(2);
+        assert_eq!("t", result.value);
+        assert!(result.truncated);
+        let result = TrackTitle::new(&value).truncate(6);
+        assert_eq!(TrackTitle::new(&value), result);
+    }
+
+    #[test]
+    fn test_media_string_into() {
+        let value = "player".to_string();
+        let into_data: Vec<u8> = MediaPlayerName::new(&value).into();
+        assert_eq!(value.into_bytes(), into_data);
+        assert_eq!(0x2b93, MediaPlayerName::uuid_16bit());
+        assert_eq!(0x2b97, TrackTitle::uuid_16bit());
+    }
+
+    #[test]
+    fn test_media_control_point() {
+        let result =
+            MediaControlPoint::create(MediaControlPointOpcode::GotoGroup, Some(0x01020304));
+        let into_data: Vec<u8> = result.unwrap().into();
+        assert_eq!(vec![0x44, 0x04, 0x03, 0x02, 0x01], into_data);
+
+        let result = MediaControlPoint::try_from(&into_data).unwrap();
+        assert_eq!(MediaControlPointOpcode::GotoGroup, result.opcode);
+        assert_eq!(Some(0x01020304), result.int32_parameter());
+
+        let result = MediaControlPoint::create(MediaControlPointOpcode::Stop, None);
+        let into_data: Vec<u8> = result.unwrap().into();
+        assert_eq!(vec![0x05], into_data);
+
+        assert_eq!(
+            Err("Invalid parameter :5".to_string()),
+            MediaControlPoint::create(MediaControlPointOpcode::Stop, Some(0))
+        );
+        let result = MediaControlPoint::try_from(&vec![0x10, 0x01]).unwrap();
+        assert_eq!(None, result.int32_parameter());
+        assert!(MediaControlPoint::try_from(&vec![0x06]).is_err());
+        assert_eq!(0x2ba4, MediaControlPoint::uuid_16bit());
+    }
+
+    #[test]
+    fn test_media_control_point_notification() {
+        let request = MediaControlPoint::create(MediaControlPointOpcode::Play, None).unwrap();
+        let result = MediaControlPointNotification::try_from(&vec![0x01, MEDIA_PLAYER_INACTIVE]);
+        let notification = result.unwrap();
+        assert!(notification.matches(&request));
+        assert!(!notification.is_success());
+        assert_eq!(0x01, notification.request_opcode.opcode());
+    }
+}
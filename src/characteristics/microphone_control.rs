@@ -0,0 +1,179 @@
+//! Microphone Control Service characteristics module.
+
+use crate::{uuids::characteristic, Uuid16bit};
+
+/// Mute: Not Muted
+pub const NOT_MUTED: u8 = 0x00;
+
+/// Mute: Muted
+pub const MUTED: u8 = 0x01;
+
+/// Mute: Disabled
+pub const DISABLED: u8 = 0x02;
+
+/// Mute (Attribute Type: 0x2bc3).
+#[derive(Debug, PartialEq, Clone)]
+pub struct Mute {
+    /// Mute
+    pub mute: u8,
+}
+
+impl Mute {
+    /// Create [`Mute`] from `Mute`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ble_data_struct::characteristics::microphone_control::{Mute, MUTED};
+    ///
+    /// let result = Mute::new(MUTED);
+    /// assert_eq!(MUTED, result.mute);
+    /// ```
+    pub fn new(mute: u8) -> Self {
+        Self { mute }
+    }
+
+    /// check Not Muted.
+    pub fn is_not_muted(&self) -> bool {
+        self.mute == NOT_MUTED
+    }
+
+    /// check Muted.
+    pub fn is_muted(&self) -> bool {
+        self.mute == MUTED
+    }
+
+    /// check Disabled.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ble_data_struct::characteristics::microphone_control::*;
+    ///
+    /// let result = Mute::new(DISABLED);
+    /// assert!(result.is_disabled());
+    /// assert!(!result.is_muted());
+    /// assert!(!result.is_not_muted());
+    /// ```
+    pub fn is_disabled(&self) -> bool {
+        self.mute == DISABLED
+    }
+}
+
+impl TryFrom<&Vec<u8>> for Mute {
+    type Error = String;
+    /// Create [`Mute`] from [`Vec<u8>`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ble_data_struct::characteristics::microphone_control::{Mute, NOT_MUTED};
+    ///
+    /// let result = Mute::try_from(&vec![NOT_MUTED]);
+    /// assert_eq!(Ok(Mute::new(NOT_MUTED)), result);
+    ///
+    /// let result = Mute::try_from(&vec![0x03]);
+    /// assert!(result.is_err());
+    ///
+    /// let data: Vec<u8> = Vec::new();
+    /// let result = Mute::try_from(&data);
+    /// assert_eq!(Err(format!("Invalid data size :{}", data.len())), result);
+    /// ```
+    fn try_from(value: &Vec<u8>) -> Result<Self, String> {
+        let len = value.len();
+        if len != 1 {
+            return Err(format!("Invalid data size :{}", len));
+        }
+        if value[0] > DISABLED {
+            return Err(format!("Invalid mute :{}", value[0]));
+        }
+        Ok(Self { mute: value[0] })
+    }
+}
+
+impl Into<Vec<u8>> for Mute {
+    /// Create [`Vec<u8>`] from [`Mute`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ble_data_struct::characteristics::microphone_control::{Mute, MUTED};
+    ///
+    /// let into_data: Vec<u8> = Mute::new(MUTED).into();
+    /// assert_eq!(vec![MUTED], into_data);
+    /// ```
+    fn into(self) -> Vec<u8> {
+        vec![self.mute]
+    }
+}
+
+impl Uuid16bit for Mute {
+    /// return `0x2bc3`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ble_data_struct::{characteristics::microphone_control::Mute, Uuid16bit};
+    ///
+    /// assert_eq!(0x2bc3, Mute::uuid_16bit());
+    /// ```
+    fn uuid_16bit() -> u16 {
+        characteristic::MUTE
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{characteristics::microphone_control::*, Uuid16bit};
+
+    #[test]
+    fn test_new() {
+        let result = Mute::new(MUTED);
+        assert_eq!(MUTED, result.mute);
+    }
+
+    #[test]
+    fn test_is() {
+        for (mute, expected) in [
+            (NOT_MUTED, [true, false, false]),
+            (MUTED, [false, true, false]),
+            (DISABLED, [false, false, true]),
+        ] {
+            let result = Mute::new(mute);
+            assert_eq!(
+                expected,
+                [
+                    result.is_not_muted(),
+                    result.is_muted(),
+                    result.is_disabled()
+                ]
+            );
+        }
+    }
+
+    #[test]
+    fn test_try_from() {
+        for mute in [NOT_MUTED, MUTED, DISABLED] {
+            assert_eq!(Ok(Mute::new(mute)), Mute::try_from(&vec![mute]));
+        }
+        assert_eq!(
+            Err("Invalid mute :3".to_string()),
+            Mute::try_from(&vec![0x03])
+        );
+        assert_eq!(
+            Err("Invalid data size :2".to_string()),
+            Mute::try_from(&vec![0x00, 0x00])
+        );
+    }
+
+    #[test]
+    fn test_into() {
+        let into_data: Vec<u8> = Mute::new(DISABLED).into();
+        assert_eq!(vec![DISABLED], into_data);
+    }
+
+    #[test]
+    fn test_uuid_16bit() {
+        assert_eq!(0x2bc3, Mute::uuid_16bit());
+    }
+}
@@ -0,0 +1,445 @@
+//! Peripheral Preferred Connection Parameters (Attribute Type: 0x2a04) module.
+
+use crate::{
+    data_types::{
+        connection_parameters::{check_connection_parameters, ConnectionParameterViolation},
+        peripheral_connection_interval_range::PeripheralConnectionIntervalRange,
+        time_units::{Units10ms, Units1_25ms},
+    },
+    uuids::characteristic,
+    Uuid16bit,
+};
+
+/// Peripheral Preferred Connection Parameters.
+#[derive(Debug, PartialEq, Clone)]
+pub struct PreferredConnectionParameters {
+    /// Minimum connection interval
+    pub interval_min: Units1_25ms,
+
+    /// Maximum connection interval
+    pub interval_max: Units1_25ms,
+
+    /// Peripheral latency
+    pub latency: u16,
+
+    /// Connection supervision timeout multiplier
+    pub supervision_timeout: Units10ms,
+}
+
+/// no specific minimum / maximum connection interval, supervision timeout values
+pub const PREFERRED_CONNECTION_PARAMETERS_NO_SPECIFIC_VALUE: u16 = 0xffff;
+
+impl PreferredConnectionParameters {
+    /// Create [`PreferredConnectionParameters`] from Parameters.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ble_data_struct::characteristics::peripheral_preferred_connection_parameters::PreferredConnectionParameters;
+    /// use ble_data_struct::data_types::time_units::{Units10ms, Units1_25ms};
+    ///
+    /// let result = PreferredConnectionParameters::new(Units1_25ms(0x0006), Units1_25ms(0x0c80), 0x0000, Units10ms(0x0c80));
+    /// assert_eq!(Units1_25ms(0x0006), result.interval_min);
+    /// assert_eq!(Units1_25ms(0x0c80), result.interval_max);
+    /// assert_eq!(0x0000, result.latency);
+    /// assert_eq!(Units10ms(0x0c80), result.supervision_timeout);
+    /// ```
+    pub fn new(
+        interval_min: Units1_25ms,
+        interval_max: Units1_25ms,
+        latency: u16,
+        supervision_timeout: Units10ms,
+    ) -> Self {
+        Self {
+            interval_min,
+            interval_max,
+            latency,
+            supervision_timeout,
+        }
+    }
+
+    /// Validate parameter ranges and the connection supervision timeout rule.
+    ///
+    /// Connection supervision timeout must be larger than `(1 + latency) * interval_max * 2`.
+    /// [`PREFERRED_CONNECTION_PARAMETERS_NO_SPECIFIC_VALUE`] skips the checks using the value.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ble_data_struct::{
+    ///     characteristics::peripheral_preferred_connection_parameters::{
+    ///         PreferredConnectionParameters, PREFERRED_CONNECTION_PARAMETERS_NO_SPECIFIC_VALUE,
+    ///     },
+    ///     data_types::connection_parameters::ConnectionParameterViolation,
+    /// };
+    /// use ble_data_struct::data_types::time_units::{Units10ms, Units1_25ms};
+    ///
+    /// let result = PreferredConnectionParameters::new(Units1_25ms(0x0018), Units1_25ms(0x0028), 0x0000, Units10ms(0x01f4));
+    /// assert!(result.validate().is_ok());
+    ///
+    /// let result = PreferredConnectionParameters::new(
+    ///     Units1_25ms(0x0018),
+    ///     Units1_25ms(0x0028),
+    ///     0x0000,
+    ///     Units10ms(PREFERRED_CONNECTION_PARAMETERS_NO_SPECIFIC_VALUE),
+    /// );
+    /// assert!(result.validate().is_ok());
+    ///
+    /// let result = PreferredConnectionParameters::new(Units1_25ms(0x0018), Units1_25ms(0x0028), 0x0004, Units10ms(0x0032));
+    /// assert_eq!(
+    ///     Err(vec![ConnectionParameterViolation::SupervisionTimeoutTooShort {
+    ///         supervision_timeout: 0x0032,
+    ///         latency: 0x0004,
+    ///         interval_max: 0x0028
+    ///     }]),
+    ///     result.validate()
+    /// );
+    /// ```
+    pub fn validate(&self) -> Result<(), Vec<ConnectionParameterViolation>> {
+        let violations = check_connection_parameters(
+            self.interval_min.0,
+            self.interval_max.0,
+            self.latency,
+            self.supervision_timeout.0,
+        );
+        if violations.is_empty() {
+            Ok(())
+        } else {
+            Err(violations)
+        }
+    }
+}
+
+impl TryFrom<&Vec<u8>> for PreferredConnectionParameters {
+    type Error = String;
+    /// Create [`PreferredConnectionParameters`] from [`Vec<u8>`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ble_data_struct::characteristics::peripheral_preferred_connection_parameters::PreferredConnectionParameters;
+    /// use ble_data_struct::data_types::time_units::{Units10ms, Units1_25ms};
+    ///
+    /// let data: Vec<u8> = [0x06u8, 0x00u8, 0x80u8, 0x0cu8, 0x00u8, 0x00u8, 0x80u8, 0x0cu8].to_vec();
+    /// let result = PreferredConnectionParameters::try_from(&data);
+    /// assert!(result.is_ok());
+    /// let result = result.unwrap();
+    /// assert_eq!(Units1_25ms(0x0006), result.interval_min);
+    /// assert_eq!(Units1_25ms(0x0c80), result.interval_max);
+    /// assert_eq!(0x0000, result.latency);
+    /// assert_eq!(Units10ms(0x0c80), result.supervision_timeout);
+    ///
+    /// let data: Vec<u8> = Vec::new();
+    /// let result = PreferredConnectionParameters::try_from(&data);
+    /// assert!(result.is_err());
+    /// assert_eq!(
+    ///     format!("Invalid data size :{}", data.len()),
+    ///     result.unwrap_err()
+    /// );
+    /// ```
+    fn try_from(value: &Vec<u8>) -> Result<Self, String> {
+        let len = value.len();
+        if len != 8 {
+            return Err(format!("Invalid data size :{}", len).to_string());
+        }
+        Ok(Self {
+            interval_min: Units1_25ms(u16::from_le_bytes(value[0..2].try_into().unwrap())),
+            interval_max: Units1_25ms(u16::from_le_bytes(value[2..4].try_into().unwrap())),
+            latency: u16::from_le_bytes(value[4..6].try_into().unwrap()),
+            supervision_timeout: Units10ms(u16::from_le_bytes(value[6..8].try_into().unwrap())),
+        })
+    }
+}
+
+impl Into<Vec<u8>> for PreferredConnectionParameters {
+    /// Create [`Vec<u8>`] from [`PreferredConnectionParameters`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ble_data_struct::characteristics::peripheral_preferred_connection_parameters::PreferredConnectionParameters;
+    /// use ble_data_struct::data_types::time_units::{Units10ms, Units1_25ms};
+    ///
+    /// let result = PreferredConnectionParameters::new(Units1_25ms(0x0006), Units1_25ms(0x0c80), 0x0000, Units10ms(0x0c80));
+    /// let into_data: Vec<u8> = result.into();
+    /// assert_eq!(
+    ///     [0x06u8, 0x00u8, 0x80u8, 0x0cu8, 0x00u8, 0x00u8, 0x80u8, 0x0cu8].to_vec(),
+    ///     into_data
+    /// );
+    /// ```
+    fn into(self) -> Vec<u8> {
+        let mut data: Vec<u8> = Vec::new();
+        data.append(&mut self.interval_min.0.to_le_bytes().to_vec());
+        data.append(&mut self.interval_max.0.to_le_bytes().to_vec());
+        data.append(&mut self.latency.to_le_bytes().to_vec());
+        data.append(&mut self.supervision_timeout.0.to_le_bytes().to_vec());
+        return data;
+    }
+}
+
+impl From<&PeripheralConnectionIntervalRange> for PreferredConnectionParameters {
+    /// Create [`PreferredConnectionParameters`] from [`PeripheralConnectionIntervalRange`].
+    ///
+    /// Peripheral latency is `0` and connection supervision timeout multiplier is
+    /// [`PREFERRED_CONNECTION_PARAMETERS_NO_SPECIFIC_VALUE`] (placeholders to be filled by the central).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ble_data_struct::{
+    ///     characteristics::peripheral_preferred_connection_parameters::{
+    ///         PreferredConnectionParameters, PREFERRED_CONNECTION_PARAMETERS_NO_SPECIFIC_VALUE,
+    ///     },
+    ///     data_types::peripheral_connection_interval_range::PeripheralConnectionIntervalRange,
+    /// };
+    /// use ble_data_struct::data_types::time_units::{Units10ms, Units1_25ms};
+    ///
+    /// let range = PeripheralConnectionIntervalRange::new(Units1_25ms(0x0010), Units1_25ms(0x0020));
+    /// let result = PreferredConnectionParameters::from(&range);
+    /// assert_eq!(Units1_25ms(0x0010), result.interval_min);
+    /// assert_eq!(Units1_25ms(0x0020), result.interval_max);
+    /// assert_eq!(0x0000, result.latency);
+    /// assert_eq!(
+    ///     Units10ms(PREFERRED_CONNECTION_PARAMETERS_NO_SPECIFIC_VALUE),
+    ///     result.supervision_timeout
+    /// );
+    /// ```
+    fn from(value: &PeripheralConnectionIntervalRange) -> Self {
+        Self::new(
+            value.minimum_value,
+            value.maximum_value,
+            0,
+            Units10ms(PREFERRED_CONNECTION_PARAMETERS_NO_SPECIFIC_VALUE),
+        )
+    }
+}
+
+impl Uuid16bit for PreferredConnectionParameters {
+    /// return `0x2a04`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ble_data_struct::{
+    ///     characteristics::peripheral_preferred_connection_parameters::PreferredConnectionParameters,
+    ///     Uuid16bit,
+    /// };
+    ///
+    /// assert_eq!(0x2a04, PreferredConnectionParameters::uuid_16bit());
+    /// ```
+    fn uuid_16bit() -> u16 {
+        characteristic::PERIPHERAL_PREFERRED_CONNECTION_PARAMETERS
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        characteristics::peripheral_preferred_connection_parameters::{
+            PreferredConnectionParameters, PREFERRED_CONNECTION_PARAMETERS_NO_SPECIFIC_VALUE,
+        },
+        data_types::{
+            connection_parameters::{
+                ConnectionParameterViolation, PERIPHERAL_LATENCY_MAXIMUM,
+                SUPERVISION_TIMEOUT_MAXIMUM, SUPERVISION_TIMEOUT_MINIMUM,
+            },
+            peripheral_connection_interval_range::{
+                PeripheralConnectionIntervalRange, CONNECTION_INTERVAL_NO_SPECIFIC_VALUE,
+            },
+            time_units::{Units10ms, Units1_25ms},
+        },
+        Uuid16bit,
+    };
+
+    #[test]
+    fn test_new() {
+        let result = PreferredConnectionParameters::new(
+            Units1_25ms(0x0006),
+            Units1_25ms(0x0c80),
+            0x0000,
+            Units10ms(0x0c80),
+        );
+        assert_eq!(Units1_25ms(0x0006), result.interval_min);
+        assert_eq!(Units1_25ms(0x0c80), result.interval_max);
+        assert_eq!(0x0000, result.latency);
+        assert_eq!(Units10ms(0x0c80), result.supervision_timeout);
+    }
+
+    #[test]
+    fn test_validate() {
+        let result = PreferredConnectionParameters::new(
+            Units1_25ms(0x0018),
+            Units1_25ms(0x0028),
+            0x0000,
+            Units10ms(0x01f4),
+        );
+        assert!(result.validate().is_ok());
+
+        let result = PreferredConnectionParameters::new(
+            Units1_25ms(PREFERRED_CONNECTION_PARAMETERS_NO_SPECIFIC_VALUE),
+            Units1_25ms(PREFERRED_CONNECTION_PARAMETERS_NO_SPECIFIC_VALUE),
+            0x0000,
+            Units10ms(PREFERRED_CONNECTION_PARAMETERS_NO_SPECIFIC_VALUE),
+        );
+        assert!(result.validate().is_ok());
+
+        // (1 + 4) * 40 = 200 < 51 * 4
+        let result = PreferredConnectionParameters::new(
+            Units1_25ms(0x0018),
+            Units1_25ms(0x0028),
+            0x0004,
+            Units10ms(0x0033),
+        );
+        assert!(result.validate().is_ok());
+        let result = PreferredConnectionParameters::new(
+            Units1_25ms(0x0018),
+            Units1_25ms(0x0028),
+            0x0004,
+            Units10ms(0x0032),
+        );
+        assert_eq!(
+            Err(vec![
+                ConnectionParameterViolation::SupervisionTimeoutTooShort {
+                    supervision_timeout: 0x0032,
+                    latency: 0x0004,
+                    interval_max: 0x0028
+                }
+            ]),
+            result.validate()
+        );
+
+        let result = PreferredConnectionParameters::new(
+            Units1_25ms(0x0005),
+            Units1_25ms(0x0028),
+            0x0000,
+            Units10ms(0x01f4),
+        );
+        assert_eq!(
+            Err(vec![ConnectionParameterViolation::IntervalOutOfRange {
+                interval: 0x0005
+            }]),
+            result.validate()
+        );
+        let result = PreferredConnectionParameters::new(
+            Units1_25ms(0x0028),
+            Units1_25ms(0x0018),
+            0x0000,
+            Units10ms(0x01f4),
+        );
+        assert_eq!(
+            Err(vec![ConnectionParameterViolation::IntervalOrder {
+                interval_min: 0x0028,
+                interval_max: 0x0018
+            }]),
+            result.validate()
+        );
+        let result = PreferredConnectionParameters::new(
+            Units1_25ms(0x0006),
+            Units1_25ms(0x0006),
+            PERIPHERAL_LATENCY_MAXIMUM + 1,
+            Units10ms(SUPERVISION_TIMEOUT_MAXIMUM),
+        );
+        assert_eq!(
+            Err(vec![ConnectionParameterViolation::LatencyOutOfRange {
+                latency: PERIPHERAL_LATENCY_MAXIMUM + 1
+            }]),
+            result.validate()
+        );
+        let result = PreferredConnectionParameters::new(
+            Units1_25ms(0x0006),
+            Units1_25ms(0x0006),
+            0x0000,
+            Units10ms(SUPERVISION_TIMEOUT_MINIMUM - 1),
+        );
+        assert!(result.validate().is_err());
+        let result = PreferredConnectionParameters::new(
+            Units1_25ms(0x0006),
+            Units1_25ms(0x0006),
+            0x0000,
+            Units10ms(SUPERVISION_TIMEOUT_MAXIMUM + 1),
+        );
+        assert!(result.validate().is_err());
+    }
+
+    #[test]
+    fn test_try_from() {
+        let data: Vec<u8> = [
+            0x06u8, 0x00u8, 0x80u8, 0x0cu8, 0x01u8, 0x00u8, 0x80u8, 0x0cu8,
+        ]
+        .to_vec();
+        let result = PreferredConnectionParameters::try_from(&data);
+        assert!(result.is_ok());
+        assert_eq!(
+            PreferredConnectionParameters::new(
+                Units1_25ms(0x0006),
+                Units1_25ms(0x0c80),
+                0x0001,
+                Units10ms(0x0c80)
+            ),
+            result.unwrap()
+        );
+
+        let data: Vec<u8> = vec![0u8; 7];
+        let result = PreferredConnectionParameters::try_from(&data);
+        assert!(result.is_err());
+        assert_eq!(
+            format!("Invalid data size :{}", data.len()),
+            result.unwrap_err()
+        );
+    }
+
+    #[test]
+    fn test_into() {
+        let data: Vec<u8> = [
+            0x06u8, 0x00u8, 0x80u8, 0x0cu8, 0x01u8, 0x00u8, 0x80u8, 0x0cu8,
+        ]
+        .to_vec();
+        let result = PreferredConnectionParameters::new(
+            Units1_25ms(0x0006),
+            Units1_25ms(0x0c80),
+            0x0001,
+            Units10ms(0x0c80),
+        );
+        let into_data: Vec<u8> = result.into();
+        assert_eq!(data, into_data);
+
+        let into_data: Vec<u8> = PreferredConnectionParameters::try_from(&data)
+            .unwrap()
+            .into();
+        assert_eq!(data, into_data);
+    }
+
+    #[test]
+    fn test_from() {
+        let range =
+            PeripheralConnectionIntervalRange::new(Units1_25ms(0x0010), Units1_25ms(0x0020));
+        assert_eq!(
+            PreferredConnectionParameters::new(
+                Units1_25ms(0x0010),
+                Units1_25ms(0x0020),
+                0x0000,
+                Units10ms(PREFERRED_CONNECTION_PARAMETERS_NO_SPECIFIC_VALUE)
+            ),
+            PreferredConnectionParameters::from(&range)
+        );
+
+        let range = PeripheralConnectionIntervalRange::new(
+            Units1_25ms(CONNECTION_INTERVAL_NO_SPECIFIC_VALUE),
+            Units1_25ms(CONNECTION_INTERVAL_NO_SPECIFIC_VALUE),
+        );
+        let result = PreferredConnectionParameters::from(&range);
+        assert_eq!(
+            Units1_25ms(PREFERRED_CONNECTION_PARAMETERS_NO_SPECIFIC_VALUE),
+            result.interval_min
+        );
+        assert_eq!(
+            Units1_25ms(PREFERRED_CONNECTION_PARAMETERS_NO_SPECIFIC_VALUE),
+            result.interval_max
+        );
+    }
+
+    #[test]
+    fn test_uuid_16bit() {
+        assert_eq!(0x2a04, PreferredConnectionParameters::uuid_16bit());
+    }
+}
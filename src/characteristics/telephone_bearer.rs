@@ -0,0 +1,512 @@
+//! Telephone Bearer Service characteristics module.
+
+use crate::{
+    characteristics::control_point::{ControlPointOpcode, ControlPointRequest},
+    control_point_opcode,
+    uuids::characteristic,
+    Uuid16bit,
+};
+
+/// Call State: Incoming
+pub const INCOMING: u8 = 0x00;
+
+/// Call State: Dialing
+pub const DIALING: u8 = 0x01;
+
+/// Call State: Alerting
+pub const ALERTING: u8 = 0x02;
+
+/// Call State: Active
+pub const ACTIVE: u8 = 0x03;
+
+/// Call State: Locally Held
+pub const LOCALLY_HELD: u8 = 0x04;
+
+/// Call State: Remotely Held
+pub const REMOTELY_HELD: u8 = 0x05;
+
+/// Call State: Locally and Remotely Held
+pub const LOCALLY_AND_REMOTELY_HELD: u8 = 0x06;
+
+/// Call Flags: Outgoing call (0: incoming)
+pub const CALL_FLAGS_OUTGOING: u8 = 0b00000001;
+
+/// Call Flags: Information withheld by server
+pub const CALL_FLAGS_WITHHELD_BY_SERVER: u8 = 0b00000010;
+
+/// Call Flags: Information withheld by network
+pub const CALL_FLAGS_WITHHELD_BY_NETWORK: u8 = 0b00000100;
+
+/// Call Control Point result code: Success
+pub const SUCCESS: u8 = 0x00;
+
+/// Call Control Point result code: Opcode Not Supported
+pub const OPCODE_NOT_SUPPORTED: u8 = 0x01;
+
+/// Call Control Point result code: Operation Not Possible
+pub const OPERATION_NOT_POSSIBLE: u8 = 0x02;
+
+/// Call Control Point result code: Invalid Call Index
+pub const INVALID_CALL_INDEX: u8 = 0x03;
+
+/// Call Control Point result code: State Mismatch
+pub const STATE_MISMATCH: u8 = 0x04;
+
+/// Call Control Point result code: Lack Of Resources
+pub const LACK_OF_RESOURCES: u8 = 0x05;
+
+/// Call Control Point result code: Invalid Outgoing URI
+pub const INVALID_OUTGOING_URI: u8 = 0x06;
+
+/// Call State entry.
+#[derive(Debug, PartialEq, Clone)]
+pub struct CallStateEntry {
+    /// Call Index
+    pub call_index: u8,
+
+    /// State
+    pub state: u8,
+
+    /// Call Flags
+    pub call_flags: u8,
+}
+
+impl CallStateEntry {
+    /// Create [`CallStateEntry`] from `Call Index`, `State` and `Call Flags`.
+    pub fn new(call_index: u8, state: u8, call_flags: u8) -> Self {
+        Self {
+            call_index,
+            state,
+            call_flags,
+        }
+    }
+
+    /// check outgoing call.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ble_data_struct::characteristics::telephone_bearer::*;
+    ///
+    /// assert!(CallStateEntry::new(1, DIALING, CALL_FLAGS_OUTGOING).is_outgoing());
+    /// assert!(!CallStateEntry::new(1, INCOMING, 0).is_outgoing());
+    /// ```
+    pub fn is_outgoing(&self) -> bool {
+        self.call_flags & CALL_FLAGS_OUTGOING != 0
+    }
+
+    /// check information withheld by server or network.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ble_data_struct::characteristics::telephone_bearer::*;
+    ///
+    /// assert!(CallStateEntry::new(1, ACTIVE, CALL_FLAGS_WITHHELD_BY_NETWORK).is_withheld());
+    /// assert!(!CallStateEntry::new(1, ACTIVE, CALL_FLAGS_OUTGOING).is_withheld());
+    /// ```
+    pub fn is_withheld(&self) -> bool {
+        self.call_flags & (CALL_FLAGS_WITHHELD_BY_SERVER | CALL_FLAGS_WITHHELD_BY_NETWORK) != 0
+    }
+
+    /// check held (locally, remotely or both).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ble_data_struct::characteristics::telephone_bearer::*;
+    ///
+    /// assert!(CallStateEntry::new(1, REMOTELY_HELD, 0).is_held());
+    /// assert!(!CallStateEntry::new(1, ACTIVE, 0).is_held());
+    /// ```
+    pub fn is_held(&self) -> bool {
+        matches!(
+            self.state,
+            LOCALLY_HELD | REMOTELY_HELD | LOCALLY_AND_REMOTELY_HELD
+        )
+    }
+}
+
+/// Call State (Attribute Type: 0x2bbd).
+#[derive(Debug, PartialEq, Clone)]
+pub struct CallState {
+    /// Call State entries
+    pub calls: Vec<CallStateEntry>,
+}
+
+impl CallState {
+    /// Create [`CallState`] from [`CallStateEntry`] list.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ble_data_struct::characteristics::telephone_bearer::*;
+    ///
+    /// let calls = vec![CallStateEntry::new(1, ACTIVE, 0)];
+    /// let result = CallState::new(&calls);
+    /// assert_eq!(calls, result.calls);
+    /// ```
+    pub fn new(calls: &[CallStateEntry]) -> Self {
+        Self {
+            calls: calls.to_vec(),
+        }
+    }
+
+    /// Returns the entry of `call_index`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ble_data_struct::characteristics::telephone_bearer::*;
+    ///
+    /// let result = CallState::new(&[
+    ///     CallStateEntry::new(1, ACTIVE, 0),
+    ///     CallStateEntry::new(2, INCOMING, 0),
+    /// ]);
+    /// assert_eq!(Some(&CallStateEntry::new(2, INCOMING, 0)), result.call(2));
+    /// assert_eq!(None, result.call(3));
+    /// ```
+    pub fn call(&self, call_index: u8) -> Option<&CallStateEntry> {
+        self.calls.iter().find(|f| f.call_index == call_index)
+    }
+}
+
+impl TryFrom<&Vec<u8>> for CallState {
+    type Error = String;
+    /// Create [`CallState`] from [`Vec<u8>`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ble_data_struct::characteristics::telephone_bearer::*;
+    ///
+    /// let result = CallState::try_from(&vec![0x01, ACTIVE, 0x00, 0x02, INCOMING, 0x00]);
+    /// assert_eq!(
+    ///     Ok(CallState::new(&[
+    ///         CallStateEntry::new(1, ACTIVE, 0),
+    ///         CallStateEntry::new(2, INCOMING, 0),
+    ///     ])),
+    ///     result
+    /// );
+    ///
+    /// let result = CallState::try_from(&vec![0x01, ACTIVE]);
+    /// assert_eq!(Err("Invalid data size :2".to_string()), result);
+    /// ```
+    fn try_from(value: &Vec<u8>) -> Result<Self, String> {
+        let len = value.len();
+        if !len.is_multiple_of(3) {
+            return Err(format!("Invalid data size :{}", len));
+        }
+        let mut calls: Vec<CallStateEntry> = Vec::new();
+        for chunk in value.chunks(3) {
+            if chunk[1] > LOCALLY_AND_REMOTELY_HELD {
+                return Err(format!("Invalid call state :{}", chunk[1]));
+            }
+            calls.push(CallStateEntry::new(chunk[0], chunk[1], chunk[2]));
+        }
+        Ok(Self { calls })
+    }
+}
+
+impl Into<Vec<u8>> for CallState {
+    /// Create [`Vec<u8>`] from [`CallState`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ble_data_struct::characteristics::telephone_bearer::*;
+    ///
+    /// let into_data: Vec<u8> = CallState::new(&[CallStateEntry::new(1, ACTIVE, 0)]).into();
+    /// assert_eq!(vec![0x01, ACTIVE, 0x00], into_data);
+    /// ```
+    fn into(self) -> Vec<u8> {
+        let mut data: Vec<u8> = Vec::new();
+        self.calls.iter().for_each(|f| {
+            data.push(f.call_index);
+            data.push(f.state);
+            data.push(f.call_flags);
+        });
+        return data;
+    }
+}
+
+impl Uuid16bit for CallState {
+    /// return `0x2bbd`.
+    fn uuid_16bit() -> u16 {
+        characteristic::CALL_STATE
+    }
+}
+
+control_point_opcode! {
+    /// Call Control Point opcode.
+    pub enum CallControlPointOpcode {
+        /// Accept(Call Index)
+        Accept = 0x00,
+        /// Terminate(Call Index)
+        Terminate = 0x01,
+        /// Local Hold(Call Index)
+        LocalHold = 0x02,
+        /// Local Retrieve(Call Index)
+        LocalRetrieve = 0x03,
+        /// Originate(URI)
+        Originate = 0x04,
+        /// Join(list of Call Index)
+        Join = 0x05,
+    }
+}
+
+/// Call Control Point (Attribute Type: 0x2bbe).
+pub type CallControlPoint = ControlPointRequest<CallControlPointOpcode>;
+
+impl CallControlPoint {
+    /// Create Originate request from outgoing URI.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ble_data_struct::characteristics::telephone_bearer::{
+    ///     CallControlPoint, CallControlPointOpcode,
+    /// };
+    ///
+    /// let result = CallControlPoint::originate("tel:+1234");
+    /// assert_eq!(CallControlPointOpcode::Originate, result.opcode);
+    /// assert_eq!(Some("tel:+1234".to_string()), result.uri());
+    /// ```
+    pub fn originate(uri: &str) -> Self {
+        Self::new(CallControlPointOpcode::Originate, uri.as_bytes())
+    }
+
+    /// Returns the URI of Originate request.
+    pub fn uri(&self) -> Option<String> {
+        if self.opcode != CallControlPointOpcode::Originate {
+            return None;
+        }
+        String::from_utf8(self.parameter.clone()).ok()
+    }
+
+    /// Returns the Call Index list (single index except Join).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ble_data_struct::characteristics::telephone_bearer::{
+    ///     CallControlPoint, CallControlPointOpcode,
+    /// };
+    ///
+    /// let result = CallControlPoint::new(CallControlPointOpcode::Join, &vec![1, 2]);
+    /// assert_eq!(vec![1, 2], result.call_indexes());
+    ///
+    /// let result = CallControlPoint::new(CallControlPointOpcode::Accept, &vec![1]);
+    /// assert_eq!(vec![1], result.call_indexes());
+    ///
+    /// let result = CallControlPoint::originate("tel:+1234");
+    /// assert!(result.call_indexes().is_empty());
+    /// ```
+    pub fn call_indexes(&self) -> Vec<u8> {
+        match self.opcode {
+            CallControlPointOpcode::Originate => Vec::new(),
+            CallControlPointOpcode::Join => self.parameter.clone(),
+            _ => self.parameter.iter().take(1).copied().collect(),
+        }
+    }
+}
+
+impl Uuid16bit for CallControlPoint {
+    /// return `0x2bbe`.
+    fn uuid_16bit() -> u16 {
+        characteristic::CALL_CONTROL_POINT
+    }
+}
+
+/// Call Control Point notification.
+#[derive(Debug, PartialEq, Clone)]
+pub struct CallControlPointNotification {
+    /// Requested Opcode
+    pub request_opcode: CallControlPointOpcode,
+
+    /// Call Index
+    pub call_index: u8,
+
+    /// Result Code
+    pub result_code: u8,
+}
+
+impl CallControlPointNotification {
+    /// Create [`CallControlPointNotification`] from `Requested Opcode`, `Call Index` and `Result Code`.
+    pub fn new(request_opcode: CallControlPointOpcode, call_index: u8, result_code: u8) -> Self {
+        Self {
+            request_opcode,
+            call_index,
+            result_code,
+        }
+    }
+
+    /// Check the notification belongs to the request.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ble_data_struct::characteristics::telephone_bearer::*;
+    ///
+    /// let request = CallControlPoint::new(CallControlPointOpcode::Terminate, &vec![1]);
+    /// let result = CallControlPointNotification::new(CallControlPointOpcode::Terminate, 1, SUCCESS);
+    /// assert!(result.matches(&request));
+    /// assert!(result.is_success());
+    ///
+    /// let result = CallControlPointNotification::new(CallControlPointOpcode::Terminate, 2, INVALID_CALL_INDEX);
+    /// assert!(!result.matches(&request));
+    /// assert!(!result.is_success());
+    /// ```
+    pub fn matches(&self, request: &CallControlPoint) -> bool {
+        self.request_opcode == request.opcode
+            && (request.opcode == CallControlPointOpcode::Originate
+                || request.call_indexes().first() == Some(&self.call_index))
+    }
+
+    /// Check `Result Code` is [`SUCCESS`].
+    pub fn is_success(&self) -> bool {
+        self.result_code == SUCCESS
+    }
+}
+
+impl TryFrom<&Vec<u8>> for CallControlPointNotification {
+    type Error = String;
+    /// Create [`CallControlPointNotification`] from [`Vec<u8>`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ble_data_struct::characteristics::telephone_bearer::*;
+    ///
+    /// let result = CallControlPointNotification::try_from(&vec![0x04, 0x03, SUCCESS]);
+    /// assert_eq!(
+    ///     Ok(CallControlPointNotification::new(CallControlPointOpcode::Originate, 3, SUCCESS)),
+    ///     result
+    /// );
+    ///
+    /// let result = CallControlPointNotification::try_from(&vec![0x04, 0x03]);
+    /// assert_eq!(Err("Invalid data size :2".to_string()), result);
+    /// ```
+    fn try_from(value: &Vec<u8>) -> Result<Self, String> {
+        let len = value.len();
+        if len != 3 {
+            return Err(format!("Invalid data size :{}", len));
+        }
+        let request_opcode = CallControlPointOpcode::from_opcode(value[0])
+            .ok_or(format!("Unknown opcode :{}", value[0]))?;
+        Ok(Self {
+            request_opcode,
+            call_index: value[1],
+            result_code: value[2],
+        })
+    }
+}
+
+impl Into<Vec<u8>> for CallControlPointNotification {
+    /// Create [`Vec<u8>`] from [`CallControlPointNotification`].
+    fn into(self) -> Vec<u8> {
+        vec![
+            self.request_opcode.opcode(),
+            self.call_index,
+            self.result_code,
+        ]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{characteristics::telephone_bearer::*, Uuid16bit};
+
+    #[test]
+    fn test_call_state_entry() {
+        let result = CallStateEntry::new(
+            1,
+            LOCALLY_AND_REMOTELY_HELD,
+            CALL_FLAGS_OUTGOING | CALL_FLAGS_WITHHELD_BY_SERVER,
+        );
+        assert!(result.is_outgoing());
+        assert!(result.is_withheld());
+        assert!(result.is_held());
+
+        let result = CallStateEntry::new(1, ALERTING, 0);
+        assert!(!result.is_outgoing());
+        assert!(!result.is_withheld());
+        assert!(!result.is_held());
+    }
+
+    #[test]
+    fn test_call_state_try_from() {
+        let result = CallState::try_from(&vec![0x01, ACTIVE, 0x00, 0x02, LOCALLY_HELD, 0x01]);
+        assert_eq!(
+            Ok(CallState::new(&[
+                CallStateEntry::new(1, ACTIVE, 0),
+                CallStateEntry::new(2, LOCALLY_HELD, CALL_FLAGS_OUTGOING),
+            ])),
+            result
+        );
+        assert_eq!(
+            Ok(CallState::new(&Vec::new())),
+            CallState::try_from(&Vec::new())
+        );
+
+        assert_eq!(
+            Err("Invalid call state :7".to_string()),
+            CallState::try_from(&vec![0x01, 0x07, 0x00])
+        );
+        assert_eq!(
+            Err("Invalid data size :4".to_string()),
+            CallState::try_from(&vec![0x01, ACTIVE, 0x00, 0x02])
+        );
+    }
+
+    #[test]
+    fn test_call_state_into() {
+        let into_data: Vec<u8> = CallState::new(&[
+            CallStateEntry::new(1, ACTIVE, 0),
+            CallStateEntry::new(2, DIALING, CALL_FLAGS_OUTGOING),
+        ])
+        .into();
+        assert_eq!(vec![0x01, ACTIVE, 0x00, 0x02, DIALING, 0x01], into_data);
+        assert_eq!(0x2bbd, CallState::uuid_16bit());
+    }
+
+    #[test]
+    fn test_call_control_point() {
+        let result = CallControlPoint::try_from(&vec![0x00, 0x01]).unwrap();
+        assert_eq!(CallControlPointOpcode::Accept, result.opcode);
+        assert_eq!(vec![1], result.call_indexes());
+        assert_eq!(None, result.uri());
+
+        let result = CallControlPoint::originate("tel:+1234");
+        let into_data: Vec<u8> = result.into();
+        assert_eq!([vec![0x04], b"tel:+1234".to_vec()].concat(), into_data);
+        let result = CallControlPoint::try_from(&into_data).unwrap();
+        assert_eq!(Some("tel:+1234".to_string()), result.uri());
+
+        assert!(CallControlPoint::try_from(&vec![0x06]).is_err());
+        assert_eq!(0x2bbe, CallControlPoint::uuid_16bit());
+    }
+
+    #[test]
+    fn test_call_control_point_notification() {
+        let request = CallControlPoint::originate("tel:+1234");
+        let result = CallControlPointNotification::try_from(&vec![0x04, 0x05, SUCCESS]).unwrap();
+        assert!(result.matches(&request));
+        assert!(result.is_success());
+        let into_data: Vec<u8> = result.into();
+        assert_eq!(vec![0x04, 0x05, SUCCESS], into_data);
+
+        let request = CallControlPoint::new(CallControlPointOpcode::Join, &[2, 3]);
+        let result =
+            CallControlPointNotification::new(CallControlPointOpcode::Join, 2, STATE_MISMATCH);
+        assert!(result.matches(&request));
+        assert!(!result.is_success());
+        let result = CallControlPointNotification::new(CallControlPointOpcode::Accept, 2, SUCCESS);
+        assert!(!result.matches(&request));
+
+        assert_eq!(
+            Err("Unknown opcode :6".to_string()),
+            CallControlPointNotification::try_from(&vec![0x06, 0x01, SUCCESS])
+        );
+    }
+}
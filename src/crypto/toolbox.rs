@@ -0,0 +1,208 @@
+//! Security Manager cryptographic toolbox module.
+//!
+//! 128-bit values are MSB first as defined in the Core Specification (Vol 3, Part H, 2.2).
+
+use aes::{
+    cipher::{generic_array::GenericArray, BlockEncrypt, KeyInit},
+    Aes128,
+};
+use cmac::{Cmac, Mac};
+
+/// Security function e (AES-128).
+///
+/// # Examples
+///
+/// ```
+/// use ble_data_struct::crypto::toolbox::e;
+///
+/// // FIPS-197 Appendix C.1
+/// assert_eq!(
+///     0x69c4e0d86a7b0430d8cdb78070b4c55au128,
+///     e(0x000102030405060708090a0b0c0d0e0fu128, 0x00112233445566778899aabbccddeeffu128)
+/// );
+/// ```
+pub fn e(key: u128, plaintext: u128) -> u128 {
+    let cipher = Aes128::new(&GenericArray::from(key.to_be_bytes()));
+    let mut block = GenericArray::from(plaintext.to_be_bytes());
+    cipher.encrypt_block(&mut block);
+    u128::from_be_bytes(block.into())
+}
+
+/// AES-CMAC.
+///
+/// # Examples
+///
+/// ```
+/// use ble_data_struct::crypto::toolbox::aes_cmac;
+///
+/// // RFC 4493 Example 1
+/// assert_eq!(
+///     0xbb1d6929e95937287fa37d129b756746u128,
+///     aes_cmac(0x2b7e151628aed2a6abf7158809cf4f3cu128, &[])
+/// );
+/// ```
+pub fn aes_cmac(key: u128, m: &[u8]) -> u128 {
+    let mut mac = <Cmac<Aes128> as Mac>::new(&GenericArray::from(key.to_be_bytes()));
+    mac.update(m);
+    u128::from_be_bytes(mac.finalize().into_bytes().into())
+}
+
+/// Link key conversion function h6.
+///
+/// # Examples
+///
+/// ```
+/// use ble_data_struct::crypto::toolbox::h6;
+///
+/// // Core Specification Vol 3, Part H, D.5
+/// assert_eq!(
+///     0x2d9ae102e76dc91ce8d3a9e280b16399u128,
+///     h6(0xec0234a357c8ad05341010a60a397d9bu128, 0x6c656272)
+/// );
+/// ```
+pub fn h6(w: u128, key_id: u32) -> u128 {
+    aes_cmac(w, &key_id.to_be_bytes())
+}
+
+/// Link key conversion function h7.
+///
+/// # Examples
+///
+/// ```
+/// use ble_data_struct::crypto::toolbox::h7;
+///
+/// assert_eq!(
+///     0x61445c2db263cc86732ca55cddea7c45u128,
+///     h7(0x6c888391aaf5a53860370bdb5a6083beu128, 0xec0234a357c8ad05341010a60a397d9bu128)
+/// );
+/// ```
+pub fn h7(salt: u128, w: u128) -> u128 {
+    aes_cmac(salt, &w.to_be_bytes())
+}
+
+/// Key derivation function h8.
+///
+/// # Examples
+///
+/// ```
+/// use ble_data_struct::crypto::toolbox::h8;
+///
+/// assert_eq!(
+///     0xd4421d6b783c0916db94c518f2f13799u128,
+///     h8(
+///         0xec0234a357c8ad05341010a60a397d9bu128,
+///         0x1536d18de3d20df99b7044c12f9ed5bau128,
+///         0xcc030201
+///     )
+/// );
+/// ```
+pub fn h8(k: u128, s: u128, key_id: u32) -> u128 {
+    h6(aes_cmac(s, &k.to_be_bytes()), key_id)
+}
+
+/// Random address hash function ah.
+///
+/// # Examples
+///
+/// ```
+/// use ble_data_struct::crypto::toolbox::ah;
+///
+/// // Core Specification Vol 3, Part H, D.7
+/// assert_eq!(0x0dfbaa, ah(0xec0234a357c8ad05341010a60a397d9bu128, 0x708194));
+/// ```
+pub fn ah(k: u128, r: u32) -> u32 {
+    (e(k, (r & 0x00ffffff) as u128) & 0x00ffffff) as u32
+}
+
+/// Check the resolvable private address is generated from the IRK.
+///
+/// # Examples
+///
+/// ```
+/// use ble_data_struct::crypto::toolbox::resolve_private_address;
+///
+/// let irk = 0xec0234a357c8ad05341010a60a397d9bu128;
+/// assert!(resolve_private_address(irk, 0x7081940dfbaa));
+/// assert!(!resolve_private_address(irk, 0x7081940dfbab));
+///
+/// // not resolvable private address
+/// assert!(!resolve_private_address(irk, 0x3081940dfbaa));
+/// ```
+pub fn resolve_private_address(irk: u128, address: u64) -> bool {
+    let prand = ((address >> 24) & 0x00ffffff) as u32;
+    prand >> 22 == 0b01 && ah(irk, prand) == (address & 0x00ffffff) as u32
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::crypto::toolbox::{aes_cmac, ah, e, h6, h7, h8, resolve_private_address};
+
+    #[test]
+    fn test_e() {
+        assert_eq!(
+            0x69c4e0d86a7b0430d8cdb78070b4c55au128,
+            e(
+                0x000102030405060708090a0b0c0d0e0fu128,
+                0x00112233445566778899aabbccddeeffu128
+            )
+        );
+    }
+
+    #[test]
+    fn test_aes_cmac() {
+        let key = 0x2b7e151628aed2a6abf7158809cf4f3cu128;
+        assert_eq!(0xbb1d6929e95937287fa37d129b756746u128, aes_cmac(key, &[]));
+        assert_eq!(
+            0x070a16b46b4d4144f79bdd9dd04a287cu128,
+            aes_cmac(key, &0x6bc1bee22e409f96e93d7e117393172au128.to_be_bytes())
+        );
+    }
+
+    #[test]
+    fn test_h6() {
+        assert_eq!(
+            0x2d9ae102e76dc91ce8d3a9e280b16399u128,
+            h6(0xec0234a357c8ad05341010a60a397d9bu128, 0x6c656272)
+        );
+    }
+
+    #[test]
+    fn test_h7() {
+        assert_eq!(
+            0x61445c2db263cc86732ca55cddea7c45u128,
+            h7(
+                0x6c888391aaf5a53860370bdb5a6083beu128,
+                0xec0234a357c8ad05341010a60a397d9bu128
+            )
+        );
+    }
+
+    #[test]
+    fn test_h8() {
+        assert_eq!(
+            0xd4421d6b783c0916db94c518f2f13799u128,
+            h8(
+                0xec0234a357c8ad05341010a60a397d9bu128,
+                0x1536d18de3d20df99b7044c12f9ed5bau128,
+                0xcc030201
+            )
+        );
+    }
+
+    #[test]
+    fn test_ah() {
+        assert_eq!(
+            0x0dfbaa,
+            ah(0xec0234a357c8ad05341010a60a397d9bu128, 0x708194)
+        );
+    }
+
+    #[test]
+    fn test_resolve_private_address() {
+        let irk = 0xec0234a357c8ad05341010a60a397d9bu128;
+        assert!(resolve_private_address(irk, 0x7081940dfbaa));
+        assert!(!resolve_private_address(irk, 0x7081940dfbab));
+        assert!(!resolve_private_address(irk + 1, 0x7081940dfbaa));
+        assert!(!resolve_private_address(irk, 0x3081940dfbaa));
+    }
+}
@@ -0,0 +1,89 @@
+//! Object-safe EIR/AD/SRD/ACAD/OOB structure trait module.
+
+use crate::data_types::data_type::DataType;
+
+/// Object-safe counterpart of [`DataType`].
+///
+/// Typed data types and [`RawAdStructure`](crate::data_types::raw_ad_structure::RawAdStructure)
+/// can be mixed in a single `Vec<Box<dyn AdStructure>>`.
+pub trait AdStructure {
+    /// Get EIR/AD/SRD/ACAD/OOB data type
+    fn ad_type(&self) -> u8;
+
+    /// Create [`Vec<u8>`] (length, data type and data).
+    fn to_bytes(&self) -> Vec<u8>;
+}
+
+impl<T: DataType + Clone + Into<Vec<u8>>> AdStructure for T {
+    /// return [`DataType::data_type`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ble_data_struct::data_types::{
+    ///     ad_structure::AdStructure, tx_power_level::TxPowerLevel,
+    /// };
+    ///
+    /// let result: Box<dyn AdStructure> = Box::new(TxPowerLevel::new(-4));
+    /// assert_eq!(0x0a, result.ad_type());
+    /// ```
+    fn ad_type(&self) -> u8 {
+        T::data_type()
+    }
+
+    /// return [`Into<Vec<u8>>`] result.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ble_data_struct::data_types::{
+    ///     ad_structure::AdStructure, tx_power_level::TxPowerLevel,
+    /// };
+    ///
+    /// let data_type = TxPowerLevel::new(-4);
+    /// let data: Vec<u8> = data_type.clone().into();
+    /// let result: Box<dyn AdStructure> = Box::new(data_type);
+    /// assert_eq!(data, result.to_bytes());
+    /// ```
+    fn to_bytes(&self) -> Vec<u8> {
+        self.clone().into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::data_types::{
+        ad_structure::AdStructure, complete_local_name::CompleteLocalName,
+        raw_ad_structure::RawAdStructure, tx_power_level::TxPowerLevel,
+    };
+
+    #[test]
+    fn test_ad_type() {
+        let structures: Vec<Box<dyn AdStructure>> = vec![
+            Box::new(TxPowerLevel::new(-4)),
+            Box::new(CompleteLocalName::new(&"name".to_string())),
+            Box::new(RawAdStructure::new(0x2f, &[0x01]).unwrap()),
+        ];
+        assert_eq!(
+            vec![0x0a, 0x09, 0x2f],
+            structures.iter().map(|f| f.ad_type()).collect::<Vec<u8>>()
+        );
+    }
+
+    #[test]
+    fn test_to_bytes() {
+        let tx_power_level = TxPowerLevel::new(-4);
+        let raw = RawAdStructure::new(0x2f, &[0x01]).unwrap();
+        let structures: Vec<Box<dyn AdStructure>> =
+            vec![Box::new(tx_power_level.clone()), Box::new(raw.clone())];
+        let tx_power_level: Vec<u8> = tx_power_level.into();
+        let raw: Vec<u8> = raw.into();
+        assert_eq!(
+            [tx_power_level, raw].concat(),
+            structures
+                .iter()
+                .flat_map(|f| f.to_bytes())
+                .collect::<Vec<u8>>()
+        );
+    }
+}
@@ -0,0 +1,454 @@
+//! EIR/AD/SRD/ACAD/OOB data type value module.
+
+/// EIR/AD/SRD/ACAD/OOB data type value.
+///
+/// [`AdType::from_u8`] returns `None` for unknown data type values.
+/// New data types may be added in minor releases, so matches need a wildcard arm.
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[repr(u8)]
+#[non_exhaustive]
+pub enum AdType {
+    /// Flags
+    Flags = 0x01,
+
+    /// Incomplete List of 16-bit Service Class UUIDs
+    IncompleteListOf16BitServiceUuids = 0x02,
+
+    /// Complete List of 16-bit Service Class UUIDs
+    CompleteListOf16BitServiceUuids = 0x03,
+
+    /// Incomplete List of 32-bit Service Class UUIDs
+    IncompleteListOf32BitServiceUuids = 0x04,
+
+    /// Complete List of 32-bit Service Class UUIDs
+    CompleteListOf32BitServiceUuids = 0x05,
+
+    /// Incomplete List of 128-bit Service Class UUIDs
+    IncompleteListOf128BitServiceUuids = 0x06,
+
+    /// Complete List of 128-bit Service Class UUIDs
+    CompleteListOf128BitServiceUuids = 0x07,
+
+    /// Shortened Local Name
+    ShortenedLocalName = 0x08,
+
+    /// Complete Local Name
+    CompleteLocalName = 0x09,
+
+    /// Tx Power Level
+    TxPowerLevel = 0x0a,
+
+    /// Class of Device
+    ClassOfDevice = 0x0d,
+
+    /// Secure Simple Pairing Hash C-192
+    SecureSimplePairingHashC192 = 0x0e,
+
+    /// Secure Simple Pairing Randomizer R-192
+    SecureSimplePairingRandomizerR192 = 0x0f,
+
+    /// Device ID (EIR) / Security Manager TK Value (OOB)
+    DeviceIdOrSecurityManagerTkValue = 0x10,
+
+    /// Security Manager Out of Band
+    SecurityManagerOutOfBand = 0x11,
+
+    /// Peripheral Connection Interval Range
+    PeripheralConnectionIntervalRange = 0x12,
+
+    /// List of 16-bit Service Solicitation UUIDs
+    ListOf16BitServiceSolicitationUUIDs = 0x14,
+
+    /// List of 128-bit Service Solicitation UUIDs
+    ListOf128BitServiceSolicitationUUIDs = 0x15,
+
+    /// Service Data - 16-bit UUID
+    ServiceData16BitUUID = 0x16,
+
+    /// Public Target Address
+    PublicTargetAddress = 0x17,
+
+    /// Random Target Address
+    RandomTargetAddress = 0x18,
+
+    /// Appearance
+    Appearance = 0x19,
+
+    /// Advertising Interval
+    AdvertisingInterval = 0x1a,
+
+    /// LE Bluetooth Device Address
+    LeBluetoothDeviceAddress = 0x1b,
+
+    /// LE Role
+    LeRole = 0x1c,
+
+    /// Secure Simple Pairing Hash C-256
+    SecureSimplePairingHashC256 = 0x1d,
+
+    /// Secure Simple Pairing Randomizer R-256
+    SecureSimplePairingRandomizerR256 = 0x1e,
+
+    /// List of 32-bit Service Solicitation UUIDs
+    ListOf32BitServiceSolicitationUUIDs = 0x1f,
+
+    /// Service Data - 32-bit UUID
+    ServiceData32BitUUID = 0x20,
+
+    /// Service Data - 128-bit UUID
+    ServiceData128BitUUID = 0x21,
+
+    /// LE Secure Connections Confirmation Value
+    LeSecureConnectionsConfirmationValue = 0x22,
+
+    /// LE Secure Connections Random Value
+    LeSecureConnectionsRandomValue = 0x23,
+
+    /// Uniform Resource Identifier
+    UniformResourceIdentifier = 0x24,
+
+    /// LE Supported Features
+    LeSupportedFeatures = 0x27,
+
+    /// Channel Map Update Indication
+    ChannelMapUpdateIndication = 0x28,
+
+    /// PB-ADV
+    PbAdv = 0x29,
+
+    /// Mesh Message
+    MeshMessage = 0x2a,
+
+    /// Mesh Beacon
+    MeshBeacon = 0x2b,
+
+    /// BIGInfo
+    BigInfo = 0x2c,
+
+    /// Broadcast_Code
+    BroadcastCode = 0x2d,
+
+    /// Resolvable Set Identifier
+    ResolvableSetIdentifier = 0x2e,
+
+    /// Advertising Interval - long
+    AdvertisingIntervalLong = 0x2f,
+
+    /// Broadcast_Name
+    BroadcastName = 0x30,
+
+    /// Encrypted Data
+    EncryptedData = 0x31,
+
+    /// Periodic Advertising Response Timing Information
+    PeriodicAdvertisingResponseTimingInformation = 0x32,
+
+    /// Electronic Shelf Label
+    ElectronicShelfLabel = 0x34,
+
+    /// 3D Information Data
+    ThreeDInformationData = 0x3d,
+
+    /// Manufacturer Specific Data
+    ManufacturerSpecificData = 0xff,
+}
+
+impl AdType {
+    /// Create [`AdType`] from data type value.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ble_data_struct::data_types::ad_type::AdType;
+    ///
+    /// assert_eq!(Some(AdType::Flags), AdType::from_u8(0x01));
+    /// assert_eq!(Some(AdType::ManufacturerSpecificData), AdType::from_u8(0xff));
+    /// assert_eq!(None, AdType::from_u8(0x00));
+    /// ```
+    pub const fn from_u8(value: u8) -> Option<Self> {
+        match value {
+            0x01 => Some(Self::Flags),
+            0x02 => Some(Self::IncompleteListOf16BitServiceUuids),
+            0x03 => Some(Self::CompleteListOf16BitServiceUuids),
+            0x04 => Some(Self::IncompleteListOf32BitServiceUuids),
+            0x05 => Some(Self::CompleteListOf32BitServiceUuids),
+            0x06 => Some(Self::IncompleteListOf128BitServiceUuids),
+            0x07 => Some(Self::CompleteListOf128BitServiceUuids),
+            0x08 => Some(Self::ShortenedLocalName),
+            0x09 => Some(Self::CompleteLocalName),
+            0x0a => Some(Self::TxPowerLevel),
+            0x0d => Some(Self::ClassOfDevice),
+            0x0e => Some(Self::SecureSimplePairingHashC192),
+            0x0f => Some(Self::SecureSimplePairingRandomizerR192),
+            0x10 => Some(Self::DeviceIdOrSecurityManagerTkValue),
+            0x11 => Some(Self::SecurityManagerOutOfBand),
+            0x12 => Some(Self::PeripheralConnectionIntervalRange),
+            0x14 => Some(Self::ListOf16BitServiceSolicitationUUIDs),
+            0x15 => Some(Self::ListOf128BitServiceSolicitationUUIDs),
+            0x16 => Some(Self::ServiceData16BitUUID),
+            0x17 => Some(Self::PublicTargetAddress),
+            0x18 => Some(Self::RandomTargetAddress),
+            0x19 => Some(Self::Appearance),
+            0x1a => Some(Self::AdvertisingInterval),
+            0x1b => Some(Self::LeBluetoothDeviceAddress),
+            0x1c => Some(Self::LeRole),
+            0x1d => Some(Self::SecureSimplePairingHashC256),
+            0x1e => Some(Self::SecureSimplePairingRandomizerR256),
+            0x1f => Some(Self::ListOf32BitServiceSolicitationUUIDs),
+            0x20 => Some(Self::ServiceData32BitUUID),
+            0x21 => Some(Self::ServiceData128BitUUID),
+            0x22 => Some(Self::LeSecureConnectionsConfirmationValue),
+            0x23 => Some(Self::LeSecureConnectionsRandomValue),
+            0x24 => Some(Self::UniformResourceIdentifier),
+            0x27 => Some(Self::LeSupportedFeatures),
+            0x28 => Some(Self::ChannelMapUpdateIndication),
+            0x29 => Some(Self::PbAdv),
+            0x2a => Some(Self::MeshMessage),
+            0x2b => Some(Self::MeshBeacon),
+            0x2c => Some(Self::BigInfo),
+            0x2d => Some(Self::BroadcastCode),
+            0x2e => Some(Self::ResolvableSetIdentifier),
+            0x2f => Some(Self::AdvertisingIntervalLong),
+            0x30 => Some(Self::BroadcastName),
+            0x31 => Some(Self::EncryptedData),
+            0x32 => Some(Self::PeriodicAdvertisingResponseTimingInformation),
+            0x34 => Some(Self::ElectronicShelfLabel),
+            0x3d => Some(Self::ThreeDInformationData),
+            0xff => Some(Self::ManufacturerSpecificData),
+            _ => None,
+        }
+    }
+
+    /// Returns data type value.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ble_data_struct::data_types::ad_type::AdType;
+    ///
+    /// assert_eq!(0x01, AdType::Flags.value());
+    /// assert_eq!(0xff, AdType::ManufacturerSpecificData.value());
+    /// ```
+    #[inline]
+    pub const fn value(&self) -> u8 {
+        *self as u8
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::data_types::{
+        ad_type::AdType, advertising_interval::AdvertisingInterval,
+        advertising_interval_long::AdvertisingIntervalLong, appearance::Appearance,
+        big_info::BigInfo, broadcast_code::BroadcastCode, broadcast_name::BroadcastName,
+        channel_map_update_indication::ChannelMapUpdateIndication, class_of_device::ClassOfDevice,
+        complete_list_of_128bit_service_uuids::CompleteListOf128BitServiceUuids,
+        complete_list_of_16bit_service_uuids::CompleteListOf16BitServiceUuids,
+        complete_list_of_32bit_service_uuids::CompleteListOf32BitServiceUuids,
+        complete_local_name::CompleteLocalName, data_type::DataType, device_id::DeviceId,
+        electronic_shelf_label::ElectronicShelfLabel, encrypted_data::EncryptedData, flags::Flags,
+        incomplete_list_of_128bit_service_uuids::IncompleteListOf128BitServiceUuids,
+        incomplete_list_of_16bit_service_uuids::IncompleteListOf16BitServiceUuids,
+        incomplete_list_of_32bit_service_uuids::IncompleteListOf32BitServiceUuids,
+        le_bluetooth_device_address::LeBluetoothDeviceAddress, le_role::LeRole,
+        le_secure_connections_confirmation_value::LeSecureConnectionsConfirmationValue,
+        le_secure_connections_random_value::LeSecureConnectionsRandomValue,
+        le_supported_features::LeSupportedFeatures,
+        list_of_128bit_service_solicitation_uuids::ListOf128BitServiceSolicitationUUIDs,
+        list_of_16bit_service_solicitation_uuids::ListOf16BitServiceSolicitationUUIDs,
+        list_of_32bit_service_solicitation_uuids::ListOf32BitServiceSolicitationUUIDs,
+        manufacturer_specific_data::ManufacturerSpecificData, mesh_beacon::MeshBeacon,
+        mesh_message::MeshMessage, pb_adv::PbAdv,
+        periodic_advertising_response_timing_information::PeriodicAdvertisingResponseTimingInformation,
+        peripheral_connection_interval_range::PeripheralConnectionIntervalRange,
+        public_target_address::PublicTargetAddress, random_target_address::RandomTargetAddress,
+        resolvable_set_identifier::ResolvableSetIdentifier,
+        secure_simple_pairing_hash_c192::SecureSimplePairingHashC192,
+        secure_simple_pairing_hash_c256::SecureSimplePairingHashC256,
+        secure_simple_pairing_randomizer_r192::SecureSimplePairingRandomizerR192,
+        secure_simple_pairing_randomizer_r256::SecureSimplePairingRandomizerR256,
+        security_manager_oob::SecurityManagerOutOfBand,
+        security_manager_tk_value::SecurityManagerTkValue,
+        service_data_128bit_uuid::ServiceData128BitUUID,
+        service_data_16bit_uuid::ServiceData16BitUUID,
+        service_data_32bit_uuid::ServiceData32BitUUID, shortened_local_name::ShortenedLocalName,
+        three_d_information_data::ThreeDInformationData, tx_power_level::TxPowerLevel,
+        uniform_resource_identifier::UniformResourceIdentifier,
+    };
+
+    #[test]
+    fn test_from_u8() {
+        for value in 0..=u8::MAX {
+            if let Some(ad_type) = AdType::from_u8(value) {
+                assert_eq!(value, ad_type.value());
+            }
+        }
+        assert_eq!(None, AdType::from_u8(0x00));
+        assert_eq!(None, AdType::from_u8(0xfe));
+    }
+
+    #[test]
+    fn test_value() {
+        assert_eq!(Flags::data_type(), AdType::Flags.value());
+        assert_eq!(
+            IncompleteListOf16BitServiceUuids::data_type(),
+            AdType::IncompleteListOf16BitServiceUuids.value()
+        );
+        assert_eq!(
+            CompleteListOf16BitServiceUuids::data_type(),
+            AdType::CompleteListOf16BitServiceUuids.value()
+        );
+        assert_eq!(
+            IncompleteListOf32BitServiceUuids::data_type(),
+            AdType::IncompleteListOf32BitServiceUuids.value()
+        );
+        assert_eq!(
+            CompleteListOf32BitServiceUuids::data_type(),
+            AdType::CompleteListOf32BitServiceUuids.value()
+        );
+        assert_eq!(
+            IncompleteListOf128BitServiceUuids::data_type(),
+            AdType::IncompleteListOf128BitServiceUuids.value()
+        );
+        assert_eq!(
+            CompleteListOf128BitServiceUuids::data_type(),
+            AdType::CompleteListOf128BitServiceUuids.value()
+        );
+        assert_eq!(
+            ShortenedLocalName::data_type(),
+            AdType::ShortenedLocalName.value()
+        );
+        assert_eq!(
+            CompleteLocalName::data_type(),
+            AdType::CompleteLocalName.value()
+        );
+        assert_eq!(TxPowerLevel::data_type(), AdType::TxPowerLevel.value());
+        assert_eq!(ClassOfDevice::data_type(), AdType::ClassOfDevice.value());
+        assert_eq!(
+            SecureSimplePairingHashC192::data_type(),
+            AdType::SecureSimplePairingHashC192.value()
+        );
+        assert_eq!(
+            SecureSimplePairingRandomizerR192::data_type(),
+            AdType::SecureSimplePairingRandomizerR192.value()
+        );
+        assert_eq!(
+            DeviceId::data_type(),
+            AdType::DeviceIdOrSecurityManagerTkValue.value()
+        );
+        assert_eq!(
+            SecurityManagerTkValue::data_type(),
+            AdType::DeviceIdOrSecurityManagerTkValue.value()
+        );
+        assert_eq!(
+            SecurityManagerOutOfBand::data_type(),
+            AdType::SecurityManagerOutOfBand.value()
+        );
+        assert_eq!(
+            PeripheralConnectionIntervalRange::data_type(),
+            AdType::PeripheralConnectionIntervalRange.value()
+        );
+        assert_eq!(
+            ListOf16BitServiceSolicitationUUIDs::data_type(),
+            AdType::ListOf16BitServiceSolicitationUUIDs.value()
+        );
+        assert_eq!(
+            ListOf128BitServiceSolicitationUUIDs::data_type(),
+            AdType::ListOf128BitServiceSolicitationUUIDs.value()
+        );
+        assert_eq!(
+            ServiceData16BitUUID::data_type(),
+            AdType::ServiceData16BitUUID.value()
+        );
+        assert_eq!(
+            PublicTargetAddress::data_type(),
+            AdType::PublicTargetAddress.value()
+        );
+        assert_eq!(
+            RandomTargetAddress::data_type(),
+            AdType::RandomTargetAddress.value()
+        );
+        assert_eq!(Appearance::data_type(), AdType::Appearance.value());
+        assert_eq!(
+            AdvertisingInterval::data_type(),
+            AdType::AdvertisingInterval.value()
+        );
+        assert_eq!(
+            LeBluetoothDeviceAddress::data_type(),
+            AdType::LeBluetoothDeviceAddress.value()
+        );
+        assert_eq!(LeRole::data_type(), AdType::LeRole.value());
+        assert_eq!(
+            SecureSimplePairingHashC256::data_type(),
+            AdType::SecureSimplePairingHashC256.value()
+        );
+        assert_eq!(
+            SecureSimplePairingRandomizerR256::data_type(),
+            AdType::SecureSimplePairingRandomizerR256.value()
+        );
+        assert_eq!(
+            ListOf32BitServiceSolicitationUUIDs::data_type(),
+            AdType::ListOf32BitServiceSolicitationUUIDs.value()
+        );
+        assert_eq!(
+            ServiceData32BitUUID::data_type(),
+            AdType::ServiceData32BitUUID.value()
+        );
+        assert_eq!(
+            ServiceData128BitUUID::data_type(),
+            AdType::ServiceData128BitUUID.value()
+        );
+        assert_eq!(
+            LeSecureConnectionsConfirmationValue::data_type(),
+            AdType::LeSecureConnectionsConfirmationValue.value()
+        );
+        assert_eq!(
+            LeSecureConnectionsRandomValue::data_type(),
+            AdType::LeSecureConnectionsRandomValue.value()
+        );
+        assert_eq!(
+            UniformResourceIdentifier::data_type(),
+            AdType::UniformResourceIdentifier.value()
+        );
+        assert_eq!(
+            LeSupportedFeatures::data_type(),
+            AdType::LeSupportedFeatures.value()
+        );
+        assert_eq!(
+            ChannelMapUpdateIndication::data_type(),
+            AdType::ChannelMapUpdateIndication.value()
+        );
+        assert_eq!(PbAdv::data_type(), AdType::PbAdv.value());
+        assert_eq!(MeshMessage::data_type(), AdType::MeshMessage.value());
+        assert_eq!(MeshBeacon::data_type(), AdType::MeshBeacon.value());
+        assert_eq!(BigInfo::data_type(), AdType::BigInfo.value());
+        assert_eq!(BroadcastCode::data_type(), AdType::BroadcastCode.value());
+        assert_eq!(
+            ResolvableSetIdentifier::data_type(),
+            AdType::ResolvableSetIdentifier.value()
+        );
+        assert_eq!(
+            AdvertisingIntervalLong::data_type(),
+            AdType::AdvertisingIntervalLong.value()
+        );
+        assert_eq!(BroadcastName::data_type(), AdType::BroadcastName.value());
+        assert_eq!(EncryptedData::data_type(), AdType::EncryptedData.value());
+        assert_eq!(
+            PeriodicAdvertisingResponseTimingInformation::data_type(),
+            AdType::PeriodicAdvertisingResponseTimingInformation.value()
+        );
+        assert_eq!(
+            ElectronicShelfLabel::data_type(),
+            AdType::ElectronicShelfLabel.value()
+        );
+        assert_eq!(
+            ThreeDInformationData::data_type(),
+            AdType::ThreeDInformationData.value()
+        );
+        assert_eq!(
+            ManufacturerSpecificData::data_type(),
+            AdType::ManufacturerSpecificData.value()
+        );
+    }
+}
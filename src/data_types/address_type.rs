@@ -0,0 +1,262 @@
+//! Bluetooth Device Address type module.
+//!
+//! Shared by [`LeBluetoothDeviceAddress`](crate::data_types::le_bluetooth_device_address::LeBluetoothDeviceAddress) and HCI structures.
+
+/// Random Device Address sub-type (two most significant bits of the address).
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum RandomAddressType {
+    /// Non-resolvable private address (0b00)
+    NonResolvablePrivate,
+
+    /// Resolvable private address (0b01)
+    ResolvablePrivate,
+
+    /// Reserved for future use (0b10)
+    Reserved,
+
+    /// Static device address (0b11)
+    Static,
+}
+
+impl RandomAddressType {
+    /// Create [`RandomAddressType`] from Random Device Address.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ble_data_struct::data_types::address_type::RandomAddressType;
+    ///
+    /// assert_eq!(RandomAddressType::NonResolvablePrivate, RandomAddressType::from_address(0x3fffffffffffu64));
+    /// assert_eq!(RandomAddressType::ResolvablePrivate, RandomAddressType::from_address(0x400000000000u64));
+    /// assert_eq!(RandomAddressType::Reserved, RandomAddressType::from_address(0x800000000000u64));
+    /// assert_eq!(RandomAddressType::Static, RandomAddressType::from_address(0xc00000000000u64));
+    /// ```
+    pub fn from_address(address: u64) -> Self {
+        match (address >> 46) & 0b11 {
+            0b00 => Self::NonResolvablePrivate,
+            0b01 => Self::ResolvablePrivate,
+            0b10 => Self::Reserved,
+            _ => Self::Static,
+        }
+    }
+}
+
+/// Bluetooth Device Address type.
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum AddressType {
+    /// Public Device Address
+    Public,
+
+    /// Random Device Address
+    Random(RandomAddressType),
+
+    /// Public Identity Address (resolved by the Controller)
+    PublicIdentity,
+
+    /// Random (static) Identity Address (resolved by the Controller)
+    RandomIdentity,
+}
+
+/// HCI address type: Public Device Address
+pub const HCI_PUBLIC_DEVICE_ADDRESS: u8 = 0x00;
+
+/// HCI address type: Random Device Address
+pub const HCI_RANDOM_DEVICE_ADDRESS: u8 = 0x01;
+
+/// HCI address type: Public Identity Address
+pub const HCI_PUBLIC_IDENTITY_ADDRESS: u8 = 0x02;
+
+/// HCI address type: Random (static) Identity Address
+pub const HCI_RANDOM_IDENTITY_ADDRESS: u8 = 0x03;
+
+impl AddressType {
+    /// Create [`AddressType`] from random flag and Bluetooth Device Address.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ble_data_struct::data_types::address_type::{AddressType, RandomAddressType};
+    ///
+    /// assert_eq!(AddressType::Public, AddressType::from_address(false, 0xc00000000000u64));
+    /// assert_eq!(
+    ///     AddressType::Random(RandomAddressType::Static),
+    ///     AddressType::from_address(true, 0xc00000000000u64)
+    /// );
+    /// ```
+    pub fn from_address(random: bool, address: u64) -> Self {
+        if random {
+            Self::Random(RandomAddressType::from_address(address))
+        } else {
+            Self::Public
+        }
+    }
+
+    /// Create [`AddressType`] from HCI address type and Bluetooth Device Address.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ble_data_struct::data_types::address_type::{
+    ///     AddressType, RandomAddressType, HCI_PUBLIC_DEVICE_ADDRESS, HCI_RANDOM_DEVICE_ADDRESS,
+    ///     HCI_RANDOM_IDENTITY_ADDRESS,
+    /// };
+    ///
+    /// assert_eq!(
+    ///     Ok(AddressType::Public),
+    ///     AddressType::from_hci(HCI_PUBLIC_DEVICE_ADDRESS, 0x060504030201u64)
+    /// );
+    /// assert_eq!(
+    ///     Ok(AddressType::Random(RandomAddressType::ResolvablePrivate)),
+    ///     AddressType::from_hci(HCI_RANDOM_DEVICE_ADDRESS, 0x460504030201u64)
+    /// );
+    /// assert_eq!(
+    ///     Ok(AddressType::RandomIdentity),
+    ///     AddressType::from_hci(HCI_RANDOM_IDENTITY_ADDRESS, 0xc60504030201u64)
+    /// );
+    /// assert!(AddressType::from_hci(0x04, 0x060504030201u64).is_err());
+    /// ```
+    pub fn from_hci(address_type: u8, address: u64) -> Result<Self, String> {
+        match address_type {
+            HCI_PUBLIC_DEVICE_ADDRESS => Ok(Self::Public),
+            HCI_RANDOM_DEVICE_ADDRESS => Ok(Self::Random(RandomAddressType::from_address(address))),
+            HCI_PUBLIC_IDENTITY_ADDRESS => Ok(Self::PublicIdentity),
+            HCI_RANDOM_IDENTITY_ADDRESS => Ok(Self::RandomIdentity),
+            _ => Err(format!("Invalid address type :{}", address_type)),
+        }
+    }
+
+    /// Get HCI address type.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ble_data_struct::data_types::address_type::{
+    ///     AddressType, RandomAddressType, HCI_PUBLIC_DEVICE_ADDRESS, HCI_RANDOM_DEVICE_ADDRESS,
+    /// };
+    ///
+    /// assert_eq!(HCI_PUBLIC_DEVICE_ADDRESS, AddressType::Public.hci_address_type());
+    /// assert_eq!(
+    ///     HCI_RANDOM_DEVICE_ADDRESS,
+    ///     AddressType::Random(RandomAddressType::Static).hci_address_type()
+    /// );
+    /// ```
+    pub const fn hci_address_type(&self) -> u8 {
+        match self {
+            Self::Public => HCI_PUBLIC_DEVICE_ADDRESS,
+            Self::Random(_) => HCI_RANDOM_DEVICE_ADDRESS,
+            Self::PublicIdentity => HCI_PUBLIC_IDENTITY_ADDRESS,
+            Self::RandomIdentity => HCI_RANDOM_IDENTITY_ADDRESS,
+        }
+    }
+
+    /// check Random Device Address (including Random Identity Address).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ble_data_struct::data_types::address_type::{AddressType, RandomAddressType};
+    ///
+    /// assert!(!AddressType::Public.is_random());
+    /// assert!(!AddressType::PublicIdentity.is_random());
+    /// assert!(AddressType::Random(RandomAddressType::Static).is_random());
+    /// assert!(AddressType::RandomIdentity.is_random());
+    /// ```
+    pub const fn is_random(&self) -> bool {
+        matches!(self, Self::Random(_) | Self::RandomIdentity)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::data_types::address_type::*;
+
+    #[test]
+    fn test_random_address_type_from_address() {
+        assert_eq!(
+            RandomAddressType::NonResolvablePrivate,
+            RandomAddressType::from_address(0x3fffffffffffu64)
+        );
+        assert_eq!(
+            RandomAddressType::ResolvablePrivate,
+            RandomAddressType::from_address(0x400000000000u64)
+        );
+        assert_eq!(
+            RandomAddressType::Reserved,
+            RandomAddressType::from_address(0x800000000000u64)
+        );
+        assert_eq!(
+            RandomAddressType::Static,
+            RandomAddressType::from_address(0xc00000000000u64)
+        );
+    }
+
+    #[test]
+    fn test_from_address() {
+        assert_eq!(
+            AddressType::Public,
+            AddressType::from_address(false, 0xc00000000000u64)
+        );
+        assert_eq!(
+            AddressType::Random(RandomAddressType::Static),
+            AddressType::from_address(true, 0xc00000000000u64)
+        );
+        assert_eq!(
+            AddressType::Random(RandomAddressType::NonResolvablePrivate),
+            AddressType::from_address(true, 0x000000000000u64)
+        );
+    }
+
+    #[test]
+    fn test_from_hci() {
+        assert_eq!(
+            Ok(AddressType::Public),
+            AddressType::from_hci(HCI_PUBLIC_DEVICE_ADDRESS, 0x060504030201u64)
+        );
+        assert_eq!(
+            Ok(AddressType::Random(RandomAddressType::ResolvablePrivate)),
+            AddressType::from_hci(HCI_RANDOM_DEVICE_ADDRESS, 0x460504030201u64)
+        );
+        assert_eq!(
+            Ok(AddressType::PublicIdentity),
+            AddressType::from_hci(HCI_PUBLIC_IDENTITY_ADDRESS, 0x060504030201u64)
+        );
+        assert_eq!(
+            Ok(AddressType::RandomIdentity),
+            AddressType::from_hci(HCI_RANDOM_IDENTITY_ADDRESS, 0xc60504030201u64)
+        );
+        assert_eq!(
+            Err("Invalid address type :4".to_string()),
+            AddressType::from_hci(0x04, 0x060504030201u64)
+        );
+    }
+
+    #[test]
+    fn test_hci_address_type() {
+        assert_eq!(
+            HCI_PUBLIC_DEVICE_ADDRESS,
+            AddressType::Public.hci_address_type()
+        );
+        assert_eq!(
+            HCI_RANDOM_DEVICE_ADDRESS,
+            AddressType::Random(RandomAddressType::Static).hci_address_type()
+        );
+        assert_eq!(
+            HCI_PUBLIC_IDENTITY_ADDRESS,
+            AddressType::PublicIdentity.hci_address_type()
+        );
+        assert_eq!(
+            HCI_RANDOM_IDENTITY_ADDRESS,
+            AddressType::RandomIdentity.hci_address_type()
+        );
+    }
+
+    #[test]
+    fn test_is_random() {
+        assert!(!AddressType::Public.is_random());
+        assert!(!AddressType::PublicIdentity.is_random());
+        assert!(AddressType::Random(RandomAddressType::Static).is_random());
+        assert!(AddressType::RandomIdentity.is_random());
+    }
+}
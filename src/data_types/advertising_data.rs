@@ -0,0 +1,515 @@
+//! Advertising data container module.
+//!
+//! Typed view of the frequently used EIR/AD/SRD structures, so they can be read without matching [`DataTypeParseResult`].
+
+use uuid::Uuid;
+
+use crate::data_types::{
+    appearance::Appearance,
+    complete_local_name::CompleteLocalName,
+    data_type_parser::{DataTypeParseResult, DataTypeParseResults},
+    flags::Flags,
+    manufacturer_specific_data::ManufacturerSpecificData,
+    service_uuid_list::ServiceUuidList,
+    shortened_local_name::ShortenedLocalName,
+    tx_power_level::TxPowerLevel,
+};
+
+/// Advertising data.
+#[derive(Debug, PartialEq, Clone, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct AdvertisingData {
+    /// [`Flags`]
+    pub flags: Option<Flags>,
+
+    /// [`CompleteLocalName`]
+    pub complete_local_name: Option<CompleteLocalName>,
+
+    /// [`ShortenedLocalName`]
+    pub shortened_local_name: Option<ShortenedLocalName>,
+
+    /// [`TxPowerLevel`]
+    pub tx_power_level: Option<TxPowerLevel>,
+
+    /// [`Appearance`]
+    pub appearance: Option<Appearance>,
+
+    /// Service Class UUIDs of all `Incomplete/Complete List of 16/32/128-bit Service Class UUIDs`
+    pub service_uuids: Vec<Uuid>,
+
+    /// `Service Data - 16/32/128-bit UUID` (UUID, Service Data)
+    pub service_data: Vec<(Uuid, Vec<u8>)>,
+
+    /// [`ManufacturerSpecificData`]
+    pub manufacturer_specific_data: Vec<ManufacturerSpecificData>,
+
+    /// Other parse results (other data types, duplicates and errors)
+    pub others: Vec<DataTypeParseResult>,
+}
+
+impl AdvertisingData {
+    /// Returns Complete Local Name, or Shortened Local Name if Complete Local Name is not found.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ble_data_struct::data_types::{
+    ///     advertising_data::AdvertisingData, complete_local_name::CompleteLocalName,
+    ///     shortened_local_name::ShortenedLocalName,
+    /// };
+    ///
+    /// let name: Vec<u8> = ShortenedLocalName::new(&"na".to_string()).into();
+    /// assert_eq!(Some("na"), AdvertisingData::from(&name).local_name());
+    ///
+    /// let complete: Vec<u8> = CompleteLocalName::new(&"name".to_string()).into();
+    /// let result = AdvertisingData::from(&[name, complete].concat());
+    /// assert_eq!(Some("name"), result.local_name());
+    ///
+    /// assert_eq!(None, AdvertisingData::default().local_name());
+    /// ```
+    pub fn local_name(&self) -> Option<&str> {
+        match (&self.complete_local_name, &self.shortened_local_name) {
+            (Some(name), _) => Some(&name.complete_local_name),
+            (None, Some(name)) => Some(&name.shortened_local_name),
+            (None, None) => None,
+        }
+    }
+
+    /// Returns Service Class UUIDs.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use uuid::{uuid, Uuid};
+    /// use ble_data_struct::data_types::{
+    ///     advertising_data::AdvertisingData,
+    ///     complete_list_of_16bit_service_uuids::CompleteListOf16BitServiceUuids,
+    ///     incomplete_list_of_128bit_service_uuids::IncompleteListOf128BitServiceUuids,
+    /// };
+    ///
+    /// let uuid16 = uuid!("0000180f-0000-1000-8000-00805F9B34FB");
+    /// let uuid128 = uuid!("01020304-0506-0708-0900-0a0b0c0d0e0f");
+    /// let list16: Vec<u8> = CompleteListOf16BitServiceUuids::new(&[uuid16].to_vec()).into();
+    /// let list128: Vec<u8> = IncompleteListOf128BitServiceUuids::new(&[uuid128].to_vec()).into();
+    ///
+    /// let result = AdvertisingData::from(&[list16, list128].concat());
+    /// assert_eq!(&vec![uuid16, uuid128], result.service_uuids());
+    /// ```
+    pub fn service_uuids(&self) -> &Vec<Uuid> {
+        &self.service_uuids
+    }
+
+    /// Returns Service Data of the Service Class UUID.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use uuid::{uuid, Uuid};
+    /// use ble_data_struct::data_types::{
+    ///     advertising_data::AdvertisingData, service_data_16bit_uuid::ServiceData16BitUUID,
+    /// };
+    ///
+    /// let uuid = uuid!("0000180f-0000-1000-8000-00805F9B34FB");
+    /// let data: Vec<u8> = ServiceData16BitUUID::new(&uuid, &[0x64].to_vec()).into();
+    ///
+    /// let result = AdvertisingData::from(&data);
+    /// assert_eq!(Some(&vec![0x64]), result.service_data_of(&uuid));
+    /// assert_eq!(None, result.service_data_of(&Uuid::nil()));
+    /// ```
+    pub fn service_data_of(&self, uuid: &Uuid) -> Option<&Vec<u8>> {
+        self.service_data
+            .iter()
+            .find(|(service_uuid, _)| service_uuid == uuid)
+            .map(|(_, data)| data)
+    }
+
+    /// Returns Manufacturer Specific Data of the Company Identifier.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ble_data_struct::data_types::{
+    ///     advertising_data::AdvertisingData, manufacturer_specific_data::ManufacturerSpecificData,
+    /// };
+    ///
+    /// let data: Vec<u8> = ManufacturerSpecificData::new(0x004c, &[0x02, 0x15].to_vec()).into();
+    ///
+    /// let result = AdvertisingData::from(&data);
+    /// assert_eq!(Some(&vec![0x02, 0x15]), result.manufacturer_data(0x004c));
+    /// assert_eq!(None, result.manufacturer_data(0x0006));
+    /// ```
+    pub fn manufacturer_data(&self, company_id: u16) -> Option<&Vec<u8>> {
+        self.manufacturer_specific_data
+            .iter()
+            .find(|f| f.company_identifier == company_id)
+            .map(|f| &f.manufacturer_specific_data)
+    }
+
+    /// Create [`AdvertisingData`] from advertising data (e.g. `ADV_IND`) and its scan response data (`SCAN_RSP`).
+    ///
+    /// Same as [`AdvertisingData::from`] with [`DataTypeParseResults::merge`], duplicates are resolved as follows:
+    ///
+    /// - single value data types (Flags, Tx Power Level, Appearance, ...) use the advertising data,
+    ///   the scan response duplicates are kept in `others`
+    /// - [`AdvertisingData::local_name`] prefers Complete Local Name over Shortened Local Name, whichever payload contains it
+    /// - Service Class UUIDs, Service Data and Manufacturer Specific Data of both payloads are kept (advertising data first),
+    ///   so [`AdvertisingData::service_data_of`] and [`AdvertisingData::manufacturer_data`] prefer the advertising data
+    /// - data types not allowed in the scan response data (Flags) are kept in `others` as parse errors
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ble_data_struct::data_types::{
+    ///     advertising_data::AdvertisingData, complete_local_name::CompleteLocalName,
+    ///     manufacturer_specific_data::ManufacturerSpecificData,
+    ///     shortened_local_name::ShortenedLocalName, tx_power_level::TxPowerLevel,
+    /// };
+    ///
+    /// let shortened: Vec<u8> = ShortenedLocalName::new(&"na".to_string()).into();
+    /// let advertising = [shortened, TxPowerLevel::new(-4).into()].concat();
+    /// let complete: Vec<u8> = CompleteLocalName::new(&"name".to_string()).into();
+    /// let scan_response = [
+    ///     complete,
+    ///     TxPowerLevel::new(4).into(),
+    ///     ManufacturerSpecificData::new(0x004c, &[0x02].to_vec()).into(),
+    /// ]
+    /// .concat();
+    ///
+    /// let result = AdvertisingData::merge(&advertising, &scan_response);
+    /// assert_eq!(Some("name"), result.local_name());
+    /// assert_eq!(Some(TxPowerLevel::new(-4)), result.tx_power_level);
+    /// assert_eq!(Some(&vec![0x02]), result.manufacturer_data(0x004c));
+    /// assert_eq!(1, result.others.len());
+    /// ```
+    pub fn merge(advertising: &[u8], scan_response: &[u8]) -> Self {
+        Self::from(&DataTypeParseResults::merge(advertising, scan_response))
+    }
+}
+
+/// Returns Service Class UUID list of the parse result.
+fn service_uuid_list(result: &DataTypeParseResult) -> Option<&dyn ServiceUuidList> {
+    match result {
+        DataTypeParseResult::CompleteListOf128BitServiceUuidsResult(Ok(data_type)) => {
+            Some(data_type)
+        }
+        DataTypeParseResult::CompleteListOf16BitServiceUuidsResult(Ok(data_type)) => {
+            Some(data_type)
+        }
+        DataTypeParseResult::CompleteListOf32BitServiceUuidsResult(Ok(data_type)) => {
+            Some(data_type)
+        }
+        DataTypeParseResult::IncompleteListOf128BitServiceUuidsResult(Ok(data_type)) => {
+            Some(data_type)
+        }
+        DataTypeParseResult::IncompleteListOf16BitServiceUuidsResult(Ok(data_type)) => {
+            Some(data_type)
+        }
+        DataTypeParseResult::IncompleteListOf32BitServiceUuidsResult(Ok(data_type)) => {
+            Some(data_type)
+        }
+        _ => None,
+    }
+}
+
+impl From<&DataTypeParseResults> for AdvertisingData {
+    /// Create [`AdvertisingData`] from [`DataTypeParseResults`].
+    ///
+    /// The first structure is used for single value data types, the following ones are kept in `others`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ble_data_struct::data_types::{
+    ///     advertising_data::AdvertisingData, data_type_parser::DataTypeParseResults,
+    ///     tx_power_level::TxPowerLevel,
+    /// };
+    ///
+    /// let data: Vec<u8> = TxPowerLevel::new(-4).into();
+    /// let results = DataTypeParseResults::from(&[data.clone(), data].concat());
+    ///
+    /// let result = AdvertisingData::from(&results);
+    /// assert_eq!(Some(TxPowerLevel::new(-4)), result.tx_power_level);
+    /// assert_eq!(1, result.others.len());
+    /// ```
+    fn from(value: &DataTypeParseResults) -> Self {
+        let mut advertising_data = Self::default();
+        for result in &value.results {
+            if let Some(list) = service_uuid_list(result) {
+                advertising_data
+                    .service_uuids
+                    .extend(list.uuids().iter().cloned());
+                continue;
+            }
+            match result {
+                DataTypeParseResult::FlagsResult(Ok(data_type))
+                    if advertising_data.flags.is_none() =>
+                {
+                    advertising_data.flags = Some(data_type.clone());
+                }
+                DataTypeParseResult::CompleteLocalNameResult(Ok(data_type))
+                    if advertising_data.complete_local_name.is_none() =>
+                {
+                    advertising_data.complete_local_name = Some(data_type.clone());
+                }
+                DataTypeParseResult::ShortenedLocalNameResult(Ok(data_type))
+                    if advertising_data.shortened_local_name.is_none() =>
+                {
+                    advertising_data.shortened_local_name = Some(data_type.clone());
+                }
+                DataTypeParseResult::TxPowerLevelResult(Ok(data_type))
+                    if advertising_data.tx_power_level.is_none() =>
+                {
+                    advertising_data.tx_power_level = Some(data_type.clone());
+                }
+                DataTypeParseResult::AppearanceResult(Ok(data_type))
+                    if advertising_data.appearance.is_none() =>
+                {
+                    advertising_data.appearance = Some(data_type.clone());
+                }
+                DataTypeParseResult::ServiceData16BitUUIDResult(Ok(data_type)) => advertising_data
+                    .service_data
+                    .push((data_type.uuid, data_type.additional_service_data.clone())),
+                DataTypeParseResult::ServiceData32BitUUIDResult(Ok(data_type)) => advertising_data
+                    .service_data
+                    .push((data_type.uuid, data_type.additional_service_data.clone())),
+                DataTypeParseResult::ServiceData128BitUUIDResult(Ok(data_type)) => advertising_data
+                    .service_data
+                    .push((data_type.uuid, data_type.additional_service_data.clone())),
+                DataTypeParseResult::ManufacturerSpecificDataResult(Ok(data_type)) => {
+                    advertising_data
+                        .manufacturer_specific_data
+                        .push(data_type.clone())
+                }
+                _ => advertising_data.others.push(result.clone()),
+            }
+        }
+        advertising_data
+    }
+}
+
+impl From<&Vec<u8>> for AdvertisingData {
+    /// Create [`AdvertisingData`] from advertising payload.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ble_data_struct::data_types::{
+    ///     advertising_data::AdvertisingData, appearance::Appearance,
+    ///     flags::{DiscoverableMode, Flags},
+    /// };
+    ///
+    /// let flags: Vec<u8> = Flags::for_mode(DiscoverableMode::General, false).into();
+    /// let appearance: Vec<u8> = Appearance::new(0x0340).into();
+    ///
+    /// let result = AdvertisingData::from(&[flags, appearance].concat());
+    /// assert_eq!(Some(Flags::for_mode(DiscoverableMode::General, false)), result.flags);
+    /// assert_eq!(Some(Appearance::new(0x0340)), result.appearance);
+    /// assert!(result.others.is_empty());
+    /// ```
+    fn from(value: &Vec<u8>) -> Self {
+        Self::from(&DataTypeParseResults::from(value))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use uuid::{uuid, Uuid};
+
+    use crate::data_types::{
+        advertising_data::AdvertisingData,
+        appearance::Appearance,
+        complete_list_of_16bit_service_uuids::CompleteListOf16BitServiceUuids,
+        complete_local_name::CompleteLocalName,
+        data_type_parser::DataTypeParseResults,
+        flags::{DiscoverableMode, Flags},
+        incomplete_list_of_32bit_service_uuids::IncompleteListOf32BitServiceUuids,
+        manufacturer_specific_data::ManufacturerSpecificData,
+        service_data_128bit_uuid::ServiceData128BitUUID,
+        service_data_16bit_uuid::ServiceData16BitUUID,
+        shortened_local_name::ShortenedLocalName,
+        tx_power_level::TxPowerLevel,
+    };
+
+    #[test]
+    fn test_merge() {
+        let uuid16 = uuid!("0000180f-0000-1000-8000-00805F9B34FB");
+        let uuid32 = uuid!("0102180f-0000-1000-8000-00805F9B34FB");
+
+        let flags: Vec<u8> = Flags::for_mode(DiscoverableMode::General, false).into();
+        let shortened: Vec<u8> = ShortenedLocalName::new(&"na".to_string()).into();
+        let complete: Vec<u8> = CompleteLocalName::new(&"name".to_string()).into();
+        let list16: Vec<u8> = CompleteListOf16BitServiceUuids::new(&[uuid16].to_vec()).into();
+        let list32: Vec<u8> = IncompleteListOf32BitServiceUuids::new(&[uuid32].to_vec()).into();
+        let service_data1: Vec<u8> = ServiceData16BitUUID::new(&uuid16, &[1].to_vec()).into();
+        let service_data2: Vec<u8> = ServiceData16BitUUID::new(&uuid16, &[2].to_vec()).into();
+        let appearance: Vec<u8> = Appearance::new(0x0340).into();
+        let advertising = [
+            flags.clone(),
+            shortened,
+            list16,
+            service_data1,
+            TxPowerLevel::new(-4).into(),
+        ]
+        .concat();
+        let scan_response = [
+            complete,
+            list32,
+            service_data2,
+            appearance,
+            TxPowerLevel::new(4).into(),
+            flags,
+        ]
+        .concat();
+
+        let result = AdvertisingData::merge(&advertising, &scan_response);
+        assert_eq!(
+            Some(Flags::for_mode(DiscoverableMode::General, false)),
+            result.flags
+        );
+        assert_eq!(Some("name"), result.local_name());
+        assert_eq!(
+            Some(ShortenedLocalName::new(&"na".to_string())),
+            result.shortened_local_name
+        );
+        assert_eq!(&vec![uuid16, uuid32], result.service_uuids());
+        assert_eq!(2, result.service_data.len());
+        assert_eq!(Some(&vec![1]), result.service_data_of(&uuid16));
+        assert_eq!(Some(TxPowerLevel::new(-4)), result.tx_power_level);
+        assert_eq!(Some(Appearance::new(0x0340)), result.appearance);
+        assert_eq!(2, result.others.len());
+        assert!(result.others[0].is_tx_power_level());
+        assert!(result.others[1].parse_error().is_some());
+
+        // advertising data only
+        assert_eq!(
+            AdvertisingData::from(&advertising),
+            AdvertisingData::merge(&advertising, &[])
+        );
+
+        // scan response data only
+        let result = AdvertisingData::merge(&[], &scan_response);
+        assert_eq!(None, result.flags);
+        assert_eq!(Some(TxPowerLevel::new(4)), result.tx_power_level);
+        assert_eq!(Some(&vec![2]), result.service_data_of(&uuid16));
+    }
+
+    #[test]
+    fn test_from() {
+        let uuid16 = uuid!("0000180f-0000-1000-8000-00805F9B34FB");
+        let uuid32 = uuid!("0102180f-0000-1000-8000-00805F9B34FB");
+        let uuid128 = uuid!("01020304-0506-0708-0900-0a0b0c0d0e0f");
+
+        let flags: Vec<u8> = Flags::for_mode(DiscoverableMode::General, false).into();
+        let name: Vec<u8> = CompleteLocalName::new(&"name".to_string()).into();
+        let tx_power_level: Vec<u8> = TxPowerLevel::new(-4).into();
+        let appearance: Vec<u8> = Appearance::new(0x0340).into();
+        let list16: Vec<u8> = CompleteListOf16BitServiceUuids::new(&[uuid16].to_vec()).into();
+        let list32: Vec<u8> = IncompleteListOf32BitServiceUuids::new(&[uuid32].to_vec()).into();
+        let service_data16: Vec<u8> = ServiceData16BitUUID::new(&uuid16, &[1].to_vec()).into();
+        let service_data128: Vec<u8> = ServiceData128BitUUID::new(&uuid128, &[2].to_vec()).into();
+        let msd1: Vec<u8> = ManufacturerSpecificData::new(0x004c, &[3].to_vec()).into();
+        let msd2: Vec<u8> = ManufacturerSpecificData::new(0x0006, &[4].to_vec()).into();
+        let payload = [
+            flags,
+            name,
+            tx_power_level,
+            appearance,
+            list16,
+            list32,
+            service_data16,
+            service_data128,
+            msd1,
+            msd2,
+        ]
+        .concat();
+
+        let result = AdvertisingData::from(&payload);
+        assert_eq!(
+            result,
+            AdvertisingData::from(&DataTypeParseResults::from(&payload))
+        );
+        assert_eq!(
+            Some(Flags::for_mode(DiscoverableMode::General, false)),
+            result.flags
+        );
+        assert_eq!(
+            Some(CompleteLocalName::new(&"name".to_string())),
+            result.complete_local_name
+        );
+        assert_eq!(None, result.shortened_local_name);
+        assert_eq!(Some(TxPowerLevel::new(-4)), result.tx_power_level);
+        assert_eq!(Some(Appearance::new(0x0340)), result.appearance);
+        assert_eq!(vec![uuid16, uuid32], result.service_uuids);
+        assert_eq!(
+            vec![(uuid16, vec![1]), (uuid128, vec![2])],
+            result.service_data
+        );
+        assert_eq!(
+            vec![
+                ManufacturerSpecificData::new(0x004c, &[3].to_vec()),
+                ManufacturerSpecificData::new(0x0006, &[4].to_vec())
+            ],
+            result.manufacturer_specific_data
+        );
+        assert!(result.others.is_empty());
+
+        let result = AdvertisingData::from(&Vec::new());
+        assert_eq!(AdvertisingData::default(), result);
+    }
+
+    #[test]
+    fn test_from_others() {
+        let tx_power_level1: Vec<u8> = TxPowerLevel::new(-4).into();
+        let tx_power_level2: Vec<u8> = TxPowerLevel::new(4).into();
+        let payload = [tx_power_level1, tx_power_level2, vec![1, 0xfe]].concat();
+
+        let result = AdvertisingData::from(&payload);
+        assert_eq!(Some(TxPowerLevel::new(-4)), result.tx_power_level);
+        assert_eq!(2, result.others.len());
+        assert!(result.others[0].is_tx_power_level());
+        assert!(result.others[1].is_unknown());
+    }
+
+    #[test]
+    fn test_local_name() {
+        let shortened: Vec<u8> = ShortenedLocalName::new(&"na".to_string()).into();
+        let complete: Vec<u8> = CompleteLocalName::new(&"name".to_string()).into();
+
+        assert_eq!(None, AdvertisingData::default().local_name());
+        assert_eq!(Some("na"), AdvertisingData::from(&shortened).local_name());
+        assert_eq!(Some("name"), AdvertisingData::from(&complete).local_name());
+        assert_eq!(
+            Some("name"),
+            AdvertisingData::from(&[shortened, complete].concat()).local_name()
+        );
+    }
+
+    #[test]
+    fn test_service_uuids() {
+        let uuid = uuid!("0000180f-0000-1000-8000-00805F9B34FB");
+        let data: Vec<u8> = CompleteListOf16BitServiceUuids::new(&[uuid].to_vec()).into();
+
+        assert!(AdvertisingData::default().service_uuids().is_empty());
+        assert_eq!(&vec![uuid], AdvertisingData::from(&data).service_uuids());
+    }
+
+    #[test]
+    fn test_service_data_of() {
+        let uuid = uuid!("0000180f-0000-1000-8000-00805F9B34FB");
+        let data1: Vec<u8> = ServiceData16BitUUID::new(&uuid, &[1].to_vec()).into();
+        let data2: Vec<u8> = ServiceData16BitUUID::new(&uuid, &[2].to_vec()).into();
+
+        let result = AdvertisingData::from(&[data1, data2].concat());
+        assert_eq!(Some(&vec![1]), result.service_data_of(&uuid));
+        assert_eq!(None, result.service_data_of(&Uuid::nil()));
+    }
+
+    #[test]
+    fn test_manufacturer_data() {
+        let data: Vec<u8> = ManufacturerSpecificData::new(0x004c, &[1].to_vec()).into();
+
+        let result = AdvertisingData::from(&data);
+        assert_eq!(Some(&vec![1]), result.manufacturer_data(0x004c));
+        assert_eq!(None, result.manufacturer_data(0x0006));
+    }
+}
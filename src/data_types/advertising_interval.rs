@@ -1,15 +1,18 @@
 //! Advertising Interval (Data Type Value: 0x1a) module.
 
-use crate::data_types::data_type::DataType;
+use crate::data_types::{
+    ad_type::AdType, data_type::DataType, parse_error::DataTypeParseError, time_units::Slots625us,
+};
 
 /// Advertising Interval.
 #[derive(Debug, PartialEq, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct AdvertisingInterval {
     /// data length
     pub length: u8,
 
     /// Advertising Interval
-    pub advertising_interval: u16,
+    pub advertising_interval: Slots625us,
 }
 
 impl AdvertisingInterval {
@@ -19,12 +22,13 @@ impl AdvertisingInterval {
     ///
     /// ```
     /// use ble_data_struct::data_types::advertising_interval::AdvertisingInterval;
+    /// use ble_data_struct::data_types::time_units::Slots625us;
     /// let advertising_interval = 0x01;
-    /// let result = AdvertisingInterval::new(advertising_interval);
+    /// let result = AdvertisingInterval::new(Slots625us(advertising_interval));
     /// assert_eq!(3, result.length);
-    /// assert_eq!(advertising_interval, result.advertising_interval);
+    /// assert_eq!(Slots625us(advertising_interval), result.advertising_interval);
     /// ```
-    pub fn new(advertising_interval: u16) -> Self {
+    pub fn new(advertising_interval: Slots625us) -> Self {
         Self {
             length: 3,
             advertising_interval,
@@ -40,16 +44,17 @@ impl AdvertisingInterval {
     ///    advertising_interval::{AdvertisingInterval, ADVINTERVAL_VALUE},
     ///    data_type::DataType,
     /// };
+    /// use ble_data_struct::data_types::time_units::Slots625us;
     ///
     /// let advertising_interval: u16 = 0x01;
-    /// let result = AdvertisingInterval::new(advertising_interval);
+    /// let result = AdvertisingInterval::new(Slots625us(advertising_interval));
     /// assert_eq!(
     ///     advertising_interval as f32 * ADVINTERVAL_VALUE,
     ///     result.advertising_interval_millis()
     /// )
     /// ```
     pub fn advertising_interval_millis(&self) -> f32 {
-        self.advertising_interval as f32 * ADVINTERVAL_VALUE
+        self.advertising_interval.millis()
     }
 }
 
@@ -58,14 +63,35 @@ impl AdvertisingInterval {
 /// advInterval value
 pub const ADVINTERVAL_VALUE: f32 = 0.625;
 
+impl TryFrom<&[u8]> for AdvertisingInterval {
+    type Error = DataTypeParseError;
+    /// Create [`AdvertisingInterval`] from `&[u8]`.
+    fn try_from(value: &[u8]) -> Result<Self, DataTypeParseError> {
+        let len = value.len();
+        if len < 4 {
+            return Err(DataTypeParseError::InvalidLength {
+                expected: 4,
+                actual: len,
+            });
+        }
+        let length = value[0];
+        Ok(Self {
+            length,
+            advertising_interval: Slots625us(u16::from_le_bytes(value[2..4].try_into().unwrap())),
+        })
+    }
+}
+
 impl TryFrom<&Vec<u8>> for AdvertisingInterval {
-    type Error = String;
+    type Error = DataTypeParseError;
     /// Create [`AdvertisingInterval`] from [`Vec<u8>`].
     ///
     /// # Examples
     ///
     /// ```
     /// use ble_data_struct::data_types::{advertising_interval::AdvertisingInterval, data_type::DataType};
+    /// use ble_data_struct::data_types::time_units::Slots625us;
+    /// use ble_data_struct::data_types::parse_error::DataTypeParseError;
     ///
     /// let advertising_interval: u16 = 0x01;
     /// let length = 3;
@@ -78,26 +104,21 @@ impl TryFrom<&Vec<u8>> for AdvertisingInterval {
     /// assert!(result.is_ok());
     /// let data_type = result.unwrap();
     /// assert_eq!(length, data_type.length);
-    /// assert_eq!(advertising_interval, data_type.advertising_interval);
+    /// assert_eq!(Slots625us(advertising_interval), data_type.advertising_interval);
     ///
     /// let data: Vec<u8> = Vec::new();
     /// let result = AdvertisingInterval::try_from(&data);
     /// assert!(result.is_err());
     /// assert_eq!(
-    ///     format!("Invalid data size :{}", data.len()),
+    ///     DataTypeParseError::InvalidLength {
+    ///         expected: 4,
+    ///         actual: data.len()
+    ///     },
     ///     result.unwrap_err()
     /// );
     /// ```
-    fn try_from(value: &Vec<u8>) -> Result<Self, String> {
-        let len = value.len();
-        if len < 4 {
-            return Err(format!("Invalid data size :{}", len).to_string());
-        }
-        let length = value[0];
-        Ok(Self {
-            length,
-            advertising_interval: u16::from_le_bytes(value[2..4].try_into().unwrap()),
-        })
+    fn try_from(value: &Vec<u8>) -> Result<Self, DataTypeParseError> {
+        Self::try_from(value.as_slice())
     }
 }
 
@@ -108,9 +129,10 @@ impl Into<Vec<u8>> for AdvertisingInterval {
     ///
     /// ```
     /// use ble_data_struct::data_types::{advertising_interval::AdvertisingInterval, data_type::DataType};
+    /// use ble_data_struct::data_types::time_units::Slots625us;
     ///
     /// let advertising_interval: u16 = 0x01;
-    /// let result1 = AdvertisingInterval::new(advertising_interval);
+    /// let result1 = AdvertisingInterval::new(Slots625us(advertising_interval));
     ///
     /// let mut data: Vec<u8> = Vec::new();
     /// data.push(3);
@@ -130,7 +152,7 @@ impl Into<Vec<u8>> for AdvertisingInterval {
         let mut data: Vec<u8> = Vec::new();
         data.push(self.length);
         data.push(Self::data_type());
-        data.append(&mut self.advertising_interval.to_le_bytes().to_vec());
+        data.append(&mut self.advertising_interval.0.to_le_bytes().to_vec());
         return data;
     }
 }
@@ -145,6 +167,7 @@ impl DataType for AdvertisingInterval {
     ///
     /// assert_eq!(0x1a, AdvertisingInterval::data_type());
     /// ```
+    #[inline]
     fn data_type() -> u8 {
         0x1a
     }
@@ -161,26 +184,30 @@ impl DataType for AdvertisingInterval {
 /// assert!(is_advertising_interval(0x1a));
 /// assert!(!is_advertising_interval(0x00));
 /// ```
+#[inline]
 pub fn is_advertising_interval(data_type: u8) -> bool {
-    AdvertisingInterval::data_type() == data_type
+    AdType::from_u8(data_type) == Some(AdType::AdvertisingInterval)
 }
 
 #[cfg(test)]
 mod tests {
-    use crate::data_types::{advertising_interval::*, data_type::DataType};
+    use crate::data_types::{advertising_interval::*, data_type::DataType, time_units::Slots625us};
 
     #[test]
     fn test_new() {
         let advertising_interval = 0x01;
-        let result = AdvertisingInterval::new(advertising_interval);
+        let result = AdvertisingInterval::new(Slots625us(advertising_interval));
         assert_eq!(3, result.length);
-        assert_eq!(advertising_interval, result.advertising_interval);
+        assert_eq!(
+            Slots625us(advertising_interval),
+            result.advertising_interval
+        );
     }
 
     #[test]
     fn test_advertising_interval_millis() {
         let advertising_interval: u16 = 0x01;
-        let result = AdvertisingInterval::new(advertising_interval);
+        let result = AdvertisingInterval::new(Slots625us(advertising_interval));
         assert_eq!(
             advertising_interval as f32 * ADVINTERVAL_VALUE,
             result.advertising_interval_millis()
@@ -200,14 +227,20 @@ mod tests {
         assert!(result.is_ok());
         let data_type = result.unwrap();
         assert_eq!(length, data_type.length);
-        assert_eq!(advertising_interval, data_type.advertising_interval);
+        assert_eq!(
+            Slots625us(advertising_interval),
+            data_type.advertising_interval
+        );
 
         let mut data: Vec<u8> = vec![0u8; 3];
         data[0] = data.len() as u8 - 1;
         let result = AdvertisingInterval::try_from(&data);
         assert!(result.is_err());
         assert_eq!(
-            format!("Invalid data size :{}", data.len()),
+            DataTypeParseError::InvalidLength {
+                expected: 4,
+                actual: data.len()
+            },
             result.unwrap_err()
         );
     }
@@ -215,7 +248,7 @@ mod tests {
     #[test]
     fn test_into() {
         let advertising_interval: u16 = 0x01;
-        let result1 = AdvertisingInterval::new(advertising_interval);
+        let result1 = AdvertisingInterval::new(Slots625us(advertising_interval));
 
         let mut data: Vec<u8> = Vec::new();
         data.push(3);
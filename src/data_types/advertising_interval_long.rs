@@ -1,9 +1,12 @@
 //! Advertising Interval - long (Data Type Value: 0x2f) module.
 
-use crate::data_types::data_type::DataType;
+use crate::data_types::{
+    ad_type::AdType, data_type::DataType, parse_error::DataTypeParseError, time_units::Slots625us,
+};
 
 /// Advertising Interval - long.
 #[derive(Debug, PartialEq, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct AdvertisingIntervalLong {
     /// data length
     pub length: u8,
@@ -14,7 +17,7 @@ pub struct AdvertisingIntervalLong {
     pub is_u32: bool,
 
     /// Advertising Interval - long
-    pub advertising_interval_long: u32,
+    pub advertising_interval_long: Slots625us<u32>,
 }
 
 impl AdvertisingIntervalLong {
@@ -24,8 +27,9 @@ impl AdvertisingIntervalLong {
     ///
     /// ```
     /// use ble_data_struct::data_types::advertising_interval_long::AdvertisingIntervalLong;
+    /// use ble_data_struct::data_types::time_units::Slots625us;
     ///
-    /// let advertising_interval_long: u32 = 0x01020304u32;
+    /// let advertising_interval_long = Slots625us(0x01020304u32);
     /// let result = AdvertisingIntervalLong::new(true, advertising_interval_long);
     /// assert_eq!(5, result.length);
     /// assert!(result.is_u32);
@@ -35,18 +39,18 @@ impl AdvertisingIntervalLong {
     /// assert_eq!(4, result.length);
     /// assert!(!result.is_u32);
     /// assert_eq!(
-    ///     advertising_interval_long & 0x00ffffff,
+    ///     Slots625us(advertising_interval_long.0 & 0x00ffffff),
     ///     result.advertising_interval_long
     /// );
     /// ```
-    pub fn new(is_u32: bool, advertising_interval_long: u32) -> Self {
+    pub fn new(is_u32: bool, advertising_interval_long: Slots625us<u32>) -> Self {
         Self {
             length: if is_u32 { 5 } else { 4 },
             is_u32,
             advertising_interval_long: if is_u32 {
                 advertising_interval_long
             } else {
-                advertising_interval_long & 0x00ffffff
+                Slots625us(advertising_interval_long.0 & 0x00ffffff)
             },
         }
     }
@@ -60,22 +64,23 @@ impl AdvertisingIntervalLong {
     ///    advertising_interval_long::{AdvertisingIntervalLong, ADVINTERVAL_VALUE},
     ///    data_type::DataType,
     /// };
+    /// use ble_data_struct::data_types::time_units::Slots625us;
     ///
-    /// let advertising_interval_long: u32 = 0x01020304u32;
+    /// let advertising_interval_long = Slots625us(0x01020304u32);
     /// let result = AdvertisingIntervalLong::new(true, advertising_interval_long);
     /// assert_eq!(
-    ///     advertising_interval_long as f32 * ADVINTERVAL_VALUE,
+    ///     advertising_interval_long.millis(),
     ///     result.advertising_interval_long_millis()
     /// );
     ///
     /// let result = AdvertisingIntervalLong::new(false, advertising_interval_long);
     /// assert_eq!(
-    ///     (advertising_interval_long & 0x00ffffff) as f32 * ADVINTERVAL_VALUE,
+    ///     Slots625us(advertising_interval_long.0 & 0x00ffffff).millis(),
     ///     result.advertising_interval_long_millis()
     /// );
     /// ```
     pub fn advertising_interval_long_millis(&self) -> f32 {
-        self.advertising_interval_long as f32 * ADVINTERVAL_VALUE
+        self.advertising_interval_long.millis()
     }
 }
 
@@ -84,21 +89,50 @@ impl AdvertisingIntervalLong {
 /// advInterval value
 pub const ADVINTERVAL_VALUE: f32 = 0.625;
 
+impl TryFrom<&[u8]> for AdvertisingIntervalLong {
+    type Error = DataTypeParseError;
+    /// Create [`AdvertisingIntervalLong`] from `&[u8]`.
+    fn try_from(value: &[u8]) -> Result<Self, Self::Error> {
+        let len = value.len();
+        if len < 5 {
+            return Err(DataTypeParseError::InvalidLength {
+                expected: 5,
+                actual: len,
+            });
+        }
+        let mut value = value.to_vec();
+        let length = value[0];
+        let is_u32 = length == 5;
+        if !is_u32 {
+            value.push(0x00);
+        };
+        Ok(Self {
+            length,
+            is_u32,
+            advertising_interval_long: Slots625us(u32::from_le_bytes(
+                value[2..6].try_into().unwrap(),
+            )),
+        })
+    }
+}
+
 impl TryFrom<&Vec<u8>> for AdvertisingIntervalLong {
-    type Error = String;
+    type Error = DataTypeParseError;
     /// Create [`AdvertisingIntervalLong`] from [`Vec<u8>`].
     ///
     /// # Examples
     ///
     /// ```
     /// use ble_data_struct::data_types::{advertising_interval_long::AdvertisingIntervalLong, data_type::DataType};
+    /// use ble_data_struct::data_types::time_units::Slots625us;
+    /// use ble_data_struct::data_types::parse_error::DataTypeParseError;
     ///
-    /// let advertising_interval_long: u32 = 0x01020304u32;
+    /// let advertising_interval_long = Slots625us(0x01020304u32);
     /// let length = 5;
     /// let mut data: Vec<u8> = Vec::new();
     /// data.push(length);
     /// data.push(AdvertisingIntervalLong::data_type());
-    /// data.append(&mut advertising_interval_long.to_le_bytes().to_vec());
+    /// data.append(&mut advertising_interval_long.0.to_le_bytes().to_vec());
     /// let result = AdvertisingIntervalLong::try_from(&data);
     /// assert!(result.is_ok());
     /// let data_type = result.unwrap();
@@ -109,13 +143,13 @@ impl TryFrom<&Vec<u8>> for AdvertisingIntervalLong {
     /// let mut data: Vec<u8> = Vec::new();
     /// data.push(length);
     /// data.push(AdvertisingIntervalLong::data_type());
-    /// data.append(&mut advertising_interval_long.to_le_bytes()[..3].to_vec());
+    /// data.append(&mut advertising_interval_long.0.to_le_bytes()[..3].to_vec());
     /// let result = AdvertisingIntervalLong::try_from(&data);
     /// assert!(result.is_ok());
     /// let data_type = result.unwrap();
     /// assert_eq!(length, data_type.length);
     /// assert_eq!(
-    ///     advertising_interval_long & 0x00ffffff,
+    ///     Slots625us(advertising_interval_long.0 & 0x00ffffff),
     ///     data_type.advertising_interval_long
     /// );
     ///
@@ -123,26 +157,15 @@ impl TryFrom<&Vec<u8>> for AdvertisingIntervalLong {
     /// let result = AdvertisingIntervalLong::try_from(&data);
     /// assert!(result.is_err());
     /// assert_eq!(
-    ///     format!("Invalid data size :{}", data.len()),
+    ///     DataTypeParseError::InvalidLength {
+    ///         expected: 5,
+    ///         actual: data.len()
+    ///     },
     ///     result.unwrap_err()
     /// );
     /// ```
     fn try_from(value: &Vec<u8>) -> Result<Self, Self::Error> {
-        let len = value.len();
-        if len < 4 {
-            return Err(format!("Invalid data size :{}", len).to_string());
-        }
-        let mut value = value.to_vec();
-        let length = value[0];
-        let is_u32 = length == 5;
-        if !is_u32 {
-            value.push(0x00);
-        };
-        Ok(Self {
-            length,
-            is_u32,
-            advertising_interval_long: u32::from_le_bytes(value[2..6].try_into().unwrap()),
-        })
+        Self::try_from(value.as_slice())
     }
 }
 
@@ -153,14 +176,15 @@ impl Into<Vec<u8>> for AdvertisingIntervalLong {
     ///
     /// ```
     /// use ble_data_struct::data_types::{advertising_interval_long::AdvertisingIntervalLong, data_type::DataType};
+    /// use ble_data_struct::data_types::time_units::Slots625us;
     ///
-    /// let advertising_interval_long: u32 = 0x01020304u32;
+    /// let advertising_interval_long = Slots625us(0x01020304u32);
     /// let result1 = AdvertisingIntervalLong::new(true, advertising_interval_long);
     ///
     /// let mut data: Vec<u8> = Vec::new();
     /// data.push(5);
     /// data.push(AdvertisingIntervalLong::data_type());
-    /// data.append(&mut advertising_interval_long.to_le_bytes().to_vec());
+    /// data.append(&mut advertising_interval_long.0.to_le_bytes().to_vec());
     ///
     /// let into_data: Vec<u8> = result1.into();
     /// assert_eq!(data, into_data);
@@ -176,7 +200,7 @@ impl Into<Vec<u8>> for AdvertisingIntervalLong {
     /// let mut data: Vec<u8> = Vec::new();
     /// data.push(4);
     /// data.push(AdvertisingIntervalLong::data_type());
-    /// data.append(&mut advertising_interval_long.to_le_bytes()[..3].to_vec());
+    /// data.append(&mut advertising_interval_long.0.to_le_bytes()[..3].to_vec());
     ///
     /// let into_data: Vec<u8> = result1.into();
     /// assert_eq!(data, into_data);
@@ -191,9 +215,9 @@ impl Into<Vec<u8>> for AdvertisingIntervalLong {
         data.push(self.length);
         data.push(Self::data_type());
         if self.is_u32 {
-            data.append(&mut self.advertising_interval_long.to_le_bytes().to_vec());
+            data.append(&mut self.advertising_interval_long.0.to_le_bytes().to_vec());
         } else {
-            data.append(&mut self.advertising_interval_long.to_le_bytes()[..3].to_vec());
+            data.append(&mut self.advertising_interval_long.0.to_le_bytes()[..3].to_vec());
         }
         return data;
     }
@@ -209,6 +233,7 @@ impl DataType for AdvertisingIntervalLong {
     ///
     /// assert_eq!(0x2f, AdvertisingIntervalLong::data_type());
     /// ```
+    #[inline]
     fn data_type() -> u8 {
         0x2f
     }
@@ -225,17 +250,20 @@ impl DataType for AdvertisingIntervalLong {
 /// assert!(is_advertising_interval_long(0x2f));
 /// assert!(!is_advertising_interval_long(0x00));
 /// ```
+#[inline]
 pub fn is_advertising_interval_long(data_type: u8) -> bool {
-    AdvertisingIntervalLong::data_type() == data_type
+    AdType::from_u8(data_type) == Some(AdType::AdvertisingIntervalLong)
 }
 
 #[cfg(test)]
 mod tests {
-    use crate::data_types::{advertising_interval_long::*, data_type::DataType};
+    use crate::data_types::{
+        advertising_interval_long::*, data_type::DataType, time_units::Slots625us,
+    };
 
     #[test]
     fn test_new() {
-        let advertising_interval_long: u32 = 0x01020304u32;
+        let advertising_interval_long = Slots625us(0x01020304u32);
         let result = AdvertisingIntervalLong::new(true, advertising_interval_long);
         assert_eq!(5, result.length);
         assert!(result.is_u32);
@@ -245,35 +273,35 @@ mod tests {
         assert_eq!(4, result.length);
         assert!(!result.is_u32);
         assert_eq!(
-            advertising_interval_long & 0x00ffffff,
+            Slots625us(advertising_interval_long.0 & 0x00ffffff),
             result.advertising_interval_long
         );
     }
 
     #[test]
     fn test_advertising_interval_millis() {
-        let advertising_interval_long: u32 = 0x01020304u32;
+        let advertising_interval_long = Slots625us(0x01020304u32);
         let result = AdvertisingIntervalLong::new(true, advertising_interval_long);
         assert_eq!(
-            advertising_interval_long as f32 * ADVINTERVAL_VALUE,
+            advertising_interval_long.millis(),
             result.advertising_interval_long_millis()
         );
 
         let result = AdvertisingIntervalLong::new(false, advertising_interval_long);
         assert_eq!(
-            (advertising_interval_long & 0x00ffffff) as f32 * ADVINTERVAL_VALUE,
+            Slots625us(advertising_interval_long.0 & 0x00ffffff).millis(),
             result.advertising_interval_long_millis()
         );
     }
 
     #[test]
     fn test_try_from() {
-        let advertising_interval_long: u32 = 0x01020304u32;
+        let advertising_interval_long = Slots625us(0x01020304u32);
         let length = 5;
         let mut data: Vec<u8> = Vec::new();
         data.push(length);
         data.push(AdvertisingIntervalLong::data_type());
-        data.append(&mut advertising_interval_long.to_le_bytes().to_vec());
+        data.append(&mut advertising_interval_long.0.to_le_bytes().to_vec());
         let result = AdvertisingIntervalLong::try_from(&data);
         assert!(result.is_ok());
         let data_type = result.unwrap();
@@ -287,35 +315,38 @@ mod tests {
         let mut data: Vec<u8> = Vec::new();
         data.push(length);
         data.push(AdvertisingIntervalLong::data_type());
-        data.append(&mut advertising_interval_long.to_le_bytes()[..3].to_vec());
+        data.append(&mut advertising_interval_long.0.to_le_bytes()[..3].to_vec());
         let result = AdvertisingIntervalLong::try_from(&data);
         assert!(result.is_ok());
         let data_type = result.unwrap();
         assert_eq!(length, data_type.length);
         assert_eq!(
-            advertising_interval_long & 0x00ffffff,
+            Slots625us(advertising_interval_long.0 & 0x00ffffff),
             data_type.advertising_interval_long
         );
 
-        let mut data: Vec<u8> = vec![0u8; 3];
+        let mut data: Vec<u8> = vec![0u8; 4];
         data[0] = data.len() as u8 - 1;
         let result = AdvertisingIntervalLong::try_from(&data);
         assert!(result.is_err());
         assert_eq!(
-            format!("Invalid data size :{}", data.len()),
+            DataTypeParseError::InvalidLength {
+                expected: 5,
+                actual: data.len()
+            },
             result.unwrap_err()
         );
     }
 
     #[test]
     fn test_into() {
-        let advertising_interval_long: u32 = 0x01020304u32;
+        let advertising_interval_long = Slots625us(0x01020304u32);
         let result1 = AdvertisingIntervalLong::new(true, advertising_interval_long);
 
         let mut data: Vec<u8> = Vec::new();
         data.push(5);
         data.push(AdvertisingIntervalLong::data_type());
-        data.append(&mut advertising_interval_long.to_le_bytes().to_vec());
+        data.append(&mut advertising_interval_long.0.to_le_bytes().to_vec());
 
         let into_data: Vec<u8> = result1.into();
         assert_eq!(data, into_data);
@@ -331,7 +362,7 @@ mod tests {
         let mut data: Vec<u8> = Vec::new();
         data.push(4);
         data.push(AdvertisingIntervalLong::data_type());
-        data.append(&mut advertising_interval_long.to_le_bytes()[..3].to_vec());
+        data.append(&mut advertising_interval_long.0.to_le_bytes()[..3].to_vec());
 
         let into_data: Vec<u8> = result1.into();
         assert_eq!(data, into_data);
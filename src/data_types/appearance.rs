@@ -1,9 +1,16 @@
 //! Appearance (Data Type Value: 0x19) module.
 
-use crate::data_types::data_type::DataType;
+use crate::data_types::{
+    ad_type::AdType,
+    appearance_category::{AppearanceCategory, AppearanceSubcategory},
+    appearance_name::NameProvider,
+    data_type::DataType,
+    parse_error::DataTypeParseError,
+};
 
 /// Appearance.
 #[derive(Debug, PartialEq, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Appearance {
     /// data length
     pub length: u8,
@@ -73,16 +80,213 @@ impl Appearance {
     pub const fn sub_category(&self) -> u16 {
         self.appearance & 0b00111111
     }
+
+    /// Get [`AppearanceCategory`](`None` if unassigned).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ble_data_struct::data_types::{
+    ///     appearance::Appearance, appearance_category::AppearanceCategory,
+    /// };
+    ///
+    /// let result = Appearance::new(0x0341);
+    /// assert_eq!(Some(AppearanceCategory::HeartRateSensor), result.category_type());
+    /// assert_eq!("Heart Rate Sensor", result.category_type().unwrap().name());
+    /// assert_eq!(None, Appearance::new(0xffc0).category_type());
+    /// ```
+    pub const fn category_type(&self) -> Option<AppearanceCategory> {
+        AppearanceCategory::from_u16(self.category())
+    }
+
+    /// Get [`AppearanceSubcategory`](`None` if generic or unassigned).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ble_data_struct::data_types::{
+    ///     appearance::Appearance, appearance_category::AppearanceSubcategory,
+    /// };
+    ///
+    /// let result = Appearance::new(0x0341);
+    /// assert_eq!(Some(AppearanceSubcategory::HeartRateBelt), result.sub_category_type());
+    /// assert_eq!(None, Appearance::new(0x0340).sub_category_type());
+    /// ```
+    pub const fn sub_category_type(&self) -> Option<AppearanceSubcategory> {
+        AppearanceSubcategory::from_u16(self.category(), self.sub_category())
+    }
+
+    /// Get Category name.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ble_data_struct::data_types::{
+    ///     appearance::Appearance,
+    ///     appearance_name::{AssignedNumbersNameProvider, LocalizedNameProvider},
+    /// };
+    ///
+    /// let result = Appearance::new(0x00c2);
+    /// assert_eq!(Some("Watch"), result.category_name(&AssignedNumbersNameProvider));
+    ///
+    /// let provider = LocalizedNameProvider::new(AssignedNumbersNameProvider)
+    ///     .with_category_name(0x003, "Uhr");
+    /// assert_eq!(Some("Uhr"), result.category_name(&provider));
+    /// ```
+    pub fn category_name<'a, P: NameProvider + ?Sized>(&self, provider: &'a P) -> Option<&'a str> {
+        provider.category_name(self.category())
+    }
+
+    /// Get Sub-category name, or Category name if Sub-category name is unknown.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ble_data_struct::data_types::{
+    ///     appearance::Appearance, appearance_name::AssignedNumbersNameProvider,
+    /// };
+    ///
+    /// assert_eq!(Some("Smartwatch"), Appearance::new(0x00c2).name(&AssignedNumbersNameProvider));
+    /// assert_eq!(Some("Watch"), Appearance::new(0x00c0).name(&AssignedNumbersNameProvider));
+    /// assert_eq!(None, Appearance::new(0xffc0).name(&AssignedNumbersNameProvider));
+    /// ```
+    pub fn name<'a, P: NameProvider + ?Sized>(&self, provider: &'a P) -> Option<&'a str> {
+        provider
+            .sub_category_name(self.category(), self.sub_category())
+            .or_else(|| provider.category_name(self.category()))
+    }
+}
+
+/// Coarse UI icon category of [`Appearance`].
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum AppearanceIcon {
+    /// Phone
+    Phone,
+
+    /// Computer
+    Computer,
+
+    /// Watch, Eye-glasses
+    Wearable,
+
+    /// Health, fitness and environmental sensors
+    Sensor,
+
+    /// Media Player, Audio Sink / Source, Wearable Audio Device, Hearing aid
+    Audio,
+
+    /// Remote Control, Barcode Scanner, Human Interface Device, Gaming
+    Input,
+
+    /// Other categories (including Unknown)
+    Other,
+}
+
+impl From<&Appearance> for AppearanceIcon {
+    /// Create [`AppearanceIcon`] from [`Appearance`] category.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ble_data_struct::data_types::appearance::{Appearance, AppearanceIcon};
+    ///
+    /// assert_eq!(AppearanceIcon::Phone, AppearanceIcon::from(&Appearance::new(0x0040)));
+    /// assert_eq!(AppearanceIcon::Sensor, AppearanceIcon::from(&Appearance::new(0x1444)));
+    /// assert_eq!(AppearanceIcon::Other, AppearanceIcon::from(&Appearance::new(0x0000)));
+    /// ```
+    fn from(value: &Appearance) -> Self {
+        match value.category() {
+            0x001 => Self::Phone,
+            0x002 => Self::Computer,
+            0x003 | 0x007 => Self::Wearable,
+            0x00c..=0x00e | 0x010..=0x012 | 0x015 | 0x031 | 0x032 | 0x034 | 0x037 | 0x051 => {
+                Self::Sensor
+            }
+            0x00a | 0x021 | 0x022 | 0x025 | 0x029 => Self::Audio,
+            0x006 | 0x00b | 0x00f | 0x02a => Self::Input,
+            _ => Self::Other,
+        }
+    }
+}
+
+impl From<Appearance> for AppearanceIcon {
+    /// Create [`AppearanceIcon`] from [`Appearance`] category.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ble_data_struct::data_types::appearance::{Appearance, AppearanceIcon};
+    ///
+    /// assert_eq!(AppearanceIcon::Computer, AppearanceIcon::from(Appearance::new(0x0080)));
+    /// ```
+    fn from(value: Appearance) -> Self {
+        Self::from(&value)
+    }
+}
+
+impl From<AppearanceCategory> for Appearance {
+    /// Create [`Appearance`] from [`AppearanceCategory`] (generic sub-category).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ble_data_struct::data_types::{
+    ///     appearance::Appearance, appearance_category::AppearanceCategory,
+    /// };
+    ///
+    /// assert_eq!(Appearance::new(0x0540), Appearance::from(AppearanceCategory::Sensor));
+    /// ```
+    fn from(value: AppearanceCategory) -> Self {
+        Self::new(value.appearance())
+    }
+}
+
+impl From<AppearanceSubcategory> for Appearance {
+    /// Create [`Appearance`] from [`AppearanceSubcategory`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ble_data_struct::data_types::{
+    ///     appearance::Appearance, appearance_category::AppearanceSubcategory,
+    /// };
+    ///
+    /// assert_eq!(Appearance::new(0x0543), Appearance::from(AppearanceSubcategory::TemperatureSensor));
+    /// ```
+    fn from(value: AppearanceSubcategory) -> Self {
+        Self::new(value.appearance())
+    }
+}
+
+impl TryFrom<&[u8]> for Appearance {
+    type Error = DataTypeParseError;
+    /// Create [`Appearance`] from `&[u8]`.
+    fn try_from(value: &[u8]) -> Result<Self, DataTypeParseError> {
+        let len = value.len();
+        if len < 4 {
+            return Err(DataTypeParseError::InvalidLength {
+                expected: 4,
+                actual: len,
+            });
+        }
+        let length = value[0];
+        Ok(Self {
+            length,
+            appearance: u16::from_le_bytes(value[2..4].try_into().unwrap()),
+        })
+    }
 }
 
 impl TryFrom<&Vec<u8>> for Appearance {
-    type Error = String;
+    type Error = DataTypeParseError;
     /// Create [`Appearance`] from [`Vec<u8>`].
     ///
     /// # Examples
     ///
     /// ```
     /// use ble_data_struct::data_types::{appearance::Appearance, data_type::DataType};
+    /// use ble_data_struct::data_types::parse_error::DataTypeParseError;
     ///
     /// let appearance: u16 = 0x1444;
     /// let length = 3;
@@ -100,20 +304,15 @@ impl TryFrom<&Vec<u8>> for Appearance {
     /// let result = Appearance::try_from(&data);
     /// assert!(result.is_err());
     /// assert_eq!(
-    ///     format!("Invalid data size :{}", data.len()),
+    ///     DataTypeParseError::InvalidLength {
+    ///         expected: 4,
+    ///         actual: data.len()
+    ///     },
     ///     result.unwrap_err()
     /// );
     /// ```
-    fn try_from(value: &Vec<u8>) -> Result<Self, String> {
-        let len = value.len();
-        if len < 4 {
-            return Err(format!("Invalid data size :{}", len).to_string());
-        }
-        let length = value[0];
-        Ok(Self {
-            length,
-            appearance: u16::from_le_bytes(value[2..4].try_into().unwrap()),
-        })
+    fn try_from(value: &Vec<u8>) -> Result<Self, DataTypeParseError> {
+        Self::try_from(value.as_slice())
     }
 }
 
@@ -161,6 +360,7 @@ impl DataType for Appearance {
     ///
     /// assert_eq!(0x19, Appearance::data_type());
     /// ```
+    #[inline]
     fn data_type() -> u8 {
         0x19
     }
@@ -177,13 +377,18 @@ impl DataType for Appearance {
 /// assert!(is_appearance(0x19));
 /// assert!(!is_appearance(0x00));
 /// ```
+#[inline]
 pub fn is_appearance(data_type: u8) -> bool {
-    Appearance::data_type() == data_type
+    AdType::from_u8(data_type) == Some(AdType::Appearance)
 }
 
 #[cfg(test)]
 mod tests {
-    use crate::data_types::{appearance::*, data_type::DataType};
+    use crate::data_types::{
+        appearance::*,
+        appearance_name::{AssignedNumbersNameProvider, LocalizedNameProvider, NameProvider},
+        data_type::DataType,
+    };
 
     #[test]
     fn test_new() {
@@ -219,6 +424,96 @@ mod tests {
         assert_eq!(0x04, result.unwrap().sub_category());
     }
 
+    #[test]
+    fn test_category_name() {
+        let result = Appearance::new(0x00c2);
+        assert_eq!(
+            Some("Watch"),
+            result.category_name(&AssignedNumbersNameProvider)
+        );
+        let provider: Box<dyn NameProvider> = Box::new(
+            LocalizedNameProvider::new(AssignedNumbersNameProvider)
+                .with_category_name(0x003, "Uhr"),
+        );
+        assert_eq!(Some("Uhr"), result.category_name(provider.as_ref()));
+        assert_eq!(
+            None,
+            Appearance::new(0xffc0).category_name(&AssignedNumbersNameProvider)
+        );
+    }
+
+    #[test]
+    fn test_name() {
+        assert_eq!(
+            Some("Smartwatch"),
+            Appearance::new(0x00c2).name(&AssignedNumbersNameProvider)
+        );
+        assert_eq!(
+            Some("Watch"),
+            Appearance::new(0x00c0).name(&AssignedNumbersNameProvider)
+        );
+        assert_eq!(
+            None,
+            Appearance::new(0xffc0).name(&AssignedNumbersNameProvider)
+        );
+    }
+
+    #[test]
+    fn test_category_type() {
+        let result = Appearance::new(0x0541);
+        assert_eq!(Some(AppearanceCategory::Sensor), result.category_type());
+        assert_eq!(
+            Some(AppearanceSubcategory::MotionSensor),
+            result.sub_category_type()
+        );
+        assert_eq!(
+            Some("Motion Sensor"),
+            result.name(&AssignedNumbersNameProvider)
+        );
+
+        let result = Appearance::new(0x0540);
+        assert_eq!(Some(AppearanceCategory::Sensor), result.category_type());
+        assert_eq!(None, result.sub_category_type());
+
+        let result = Appearance::new(0xffff);
+        assert_eq!(None, result.category_type());
+        assert_eq!(None, result.sub_category_type());
+    }
+
+    #[test]
+    fn test_from_appearance_category() {
+        assert_eq!(
+            Appearance::new(0x0340),
+            Appearance::from(AppearanceCategory::HeartRateSensor)
+        );
+        assert_eq!(
+            Appearance::new(0x0341),
+            Appearance::from(AppearanceSubcategory::HeartRateBelt)
+        );
+    }
+
+    #[test]
+    fn test_appearance_icon_from() {
+        let icon = |category: u16| AppearanceIcon::from(Appearance::new(category << 6));
+        assert_eq!(AppearanceIcon::Other, icon(0x000));
+        assert_eq!(AppearanceIcon::Phone, icon(0x001));
+        assert_eq!(AppearanceIcon::Computer, icon(0x002));
+        assert_eq!(AppearanceIcon::Wearable, icon(0x003));
+        assert_eq!(AppearanceIcon::Wearable, icon(0x007));
+        assert_eq!(AppearanceIcon::Sensor, icon(0x00d));
+        assert_eq!(AppearanceIcon::Sensor, icon(0x051));
+        assert_eq!(AppearanceIcon::Audio, icon(0x00a));
+        assert_eq!(AppearanceIcon::Audio, icon(0x029));
+        assert_eq!(AppearanceIcon::Input, icon(0x00f));
+        assert_eq!(AppearanceIcon::Input, icon(0x02a));
+        assert_eq!(AppearanceIcon::Other, icon(0x016));
+
+        assert_eq!(
+            AppearanceIcon::Phone,
+            AppearanceIcon::from(&Appearance::new(0x0041))
+        );
+    }
+
     #[test]
     fn test_try_from() {
         let appearance: u16 = 0x1444;
@@ -238,7 +533,10 @@ mod tests {
         let result = Appearance::try_from(&data);
         assert!(result.is_err());
         assert_eq!(
-            format!("Invalid data size :{}", data.len()),
+            DataTypeParseError::InvalidLength {
+                expected: 4,
+                actual: data.len()
+            },
             result.unwrap_err()
         );
     }
@@ -0,0 +1,660 @@
+//! Appearance category / sub-category catalog module.
+//!
+//! Generated from the Assigned Numbers Appearance Values table.
+
+macro_rules! appearance_catalog {
+    (
+        $(
+            $category:ident = $category_value:literal, $category_name:literal {
+                $($sub_category:ident = $sub_category_value:literal, $sub_category_name:literal),* $(,)?
+            }
+        ),+ $(,)?
+    ) => {
+        /// Appearance Category (Assigned Numbers).
+        ///
+        /// [`AppearanceCategory::from_u16`] returns `None` for unassigned category values.
+        /// New categories may be added in minor releases, so matches need a wildcard arm.
+        #[derive(Debug, PartialEq, Eq, Clone, Copy, Hash)]
+        #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+        #[repr(u16)]
+        #[non_exhaustive]
+        pub enum AppearanceCategory {
+            $(
+                #[doc = $category_name]
+                $category = $category_value,
+            )+
+        }
+
+        impl AppearanceCategory {
+            /// Create [`AppearanceCategory`] from category value(10 bit).
+            ///
+            /// # Examples
+            ///
+            /// ```
+            /// use ble_data_struct::data_types::appearance_category::AppearanceCategory;
+            ///
+            /// assert_eq!(Some(AppearanceCategory::Watch), AppearanceCategory::from_u16(0x003));
+            /// assert_eq!(None, AppearanceCategory::from_u16(0x3ff));
+            /// ```
+            pub const fn from_u16(value: u16) -> Option<Self> {
+                match value {
+                    $($category_value => Some(Self::$category),)+
+                    _ => None,
+                }
+            }
+
+            /// Returns category value(10 bit).
+            ///
+            /// # Examples
+            ///
+            /// ```
+            /// use ble_data_struct::data_types::appearance_category::AppearanceCategory;
+            ///
+            /// assert_eq!(0x00d, AppearanceCategory::HeartRateSensor.value());
+            /// ```
+            #[inline]
+            pub const fn value(&self) -> u16 {
+                *self as u16
+            }
+
+            /// Returns Assigned Numbers category name (English).
+            ///
+            /// # Examples
+            ///
+            /// ```
+            /// use ble_data_struct::data_types::appearance_category::AppearanceCategory;
+            ///
+            /// assert_eq!("Heart Rate Sensor", AppearanceCategory::HeartRateSensor.name());
+            /// ```
+            pub const fn name(&self) -> &'static str {
+                match self {
+                    $(Self::$category => $category_name,)+
+                }
+            }
+
+            /// Returns Appearance value of the generic category (Sub-category `0x00`).
+            ///
+            /// # Examples
+            ///
+            /// ```
+            /// use ble_data_struct::data_types::appearance_category::AppearanceCategory;
+            ///
+            /// assert_eq!(0x0340, AppearanceCategory::HeartRateSensor.appearance());
+            /// ```
+            #[inline]
+            pub const fn appearance(&self) -> u16 {
+                self.value() << 6
+            }
+        }
+
+        /// Appearance Sub-category (Assigned Numbers).
+        ///
+        /// Generic sub-category (`0x00`) is represented by [`AppearanceCategory`] itself.
+        /// New sub-categories may be added in minor releases, so matches need a wildcard arm.
+        #[derive(Debug, PartialEq, Eq, Clone, Copy, Hash)]
+        #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+        #[non_exhaustive]
+        pub enum AppearanceSubcategory {
+            $($(
+                #[doc = $sub_category_name]
+                $sub_category,
+            )*)+
+        }
+
+        impl AppearanceSubcategory {
+            /// Create [`AppearanceSubcategory`] from category value(10 bit) and sub-category value(6 bit).
+            ///
+            /// # Examples
+            ///
+            /// ```
+            /// use ble_data_struct::data_types::appearance_category::AppearanceSubcategory;
+            ///
+            /// assert_eq!(
+            ///     Some(AppearanceSubcategory::Smartwatch),
+            ///     AppearanceSubcategory::from_u16(0x003, 0x02)
+            /// );
+            /// assert_eq!(None, AppearanceSubcategory::from_u16(0x003, 0x00));
+            /// assert_eq!(None, AppearanceSubcategory::from_u16(0x003, 0x3f));
+            /// ```
+            pub const fn from_u16(category: u16, sub_category: u16) -> Option<Self> {
+                match (category, sub_category) {
+                    $($(($category_value, $sub_category_value) => Some(Self::$sub_category),)*)+
+                    _ => None,
+                }
+            }
+
+            /// Returns [`AppearanceCategory`] of the sub-category.
+            ///
+            /// # Examples
+            ///
+            /// ```
+            /// use ble_data_struct::data_types::appearance_category::{
+            ///     AppearanceCategory, AppearanceSubcategory,
+            /// };
+            ///
+            /// assert_eq!(AppearanceCategory::Watch, AppearanceSubcategory::Smartwatch.category());
+            /// ```
+            pub const fn category(&self) -> AppearanceCategory {
+                match self {
+                    $($(Self::$sub_category => AppearanceCategory::$category,)*)+
+                }
+            }
+
+            /// Returns sub-category value(6 bit).
+            ///
+            /// # Examples
+            ///
+            /// ```
+            /// use ble_data_struct::data_types::appearance_category::AppearanceSubcategory;
+            ///
+            /// assert_eq!(0x02, AppearanceSubcategory::Smartwatch.value());
+            /// ```
+            pub const fn value(&self) -> u16 {
+                match self {
+                    $($(Self::$sub_category => $sub_category_value,)*)+
+                }
+            }
+
+            /// Returns Assigned Numbers sub-category name (English).
+            ///
+            /// # Examples
+            ///
+            /// ```
+            /// use ble_data_struct::data_types::appearance_category::AppearanceSubcategory;
+            ///
+            /// assert_eq!("Heart Rate Belt", AppearanceSubcategory::HeartRateBelt.name());
+            /// ```
+            pub const fn name(&self) -> &'static str {
+                match self {
+                    $($(Self::$sub_category => $sub_category_name,)*)+
+                }
+            }
+
+            /// Returns Appearance value.
+            ///
+            /// # Examples
+            ///
+            /// ```
+            /// use ble_data_struct::data_types::appearance_category::AppearanceSubcategory;
+            ///
+            /// assert_eq!(0x0341, AppearanceSubcategory::HeartRateBelt.appearance());
+            /// ```
+            #[inline]
+            pub const fn appearance(&self) -> u16 {
+                self.category().appearance() | self.value()
+            }
+        }
+    };
+}
+
+appearance_catalog! {
+    Unknown = 0x000, "Unknown" {},
+    Phone = 0x001, "Phone" {},
+    Computer = 0x002, "Computer" {
+        DesktopWorkstation = 0x01, "Desktop Workstation",
+        ServerClassComputer = 0x02, "Server-class Computer",
+        Laptop = 0x03, "Laptop",
+        HandheldPcPda = 0x04, "Handheld PC/PDA (clamshell)",
+        PalmSizePcPda = 0x05, "Palm-size PC/PDA",
+        WearableComputer = 0x06, "Wearable computer (watch size)",
+        Tablet = 0x07, "Tablet",
+        DockingStation = 0x08, "Docking Station",
+        AllInOne = 0x09, "All in One",
+        BladeServer = 0x0a, "Blade Server",
+        Convertible = 0x0b, "Convertible",
+        Detachable = 0x0c, "Detachable",
+        IotGateway = 0x0d, "IoT Gateway",
+        MiniPc = 0x0e, "Mini PC",
+        StickPc = 0x0f, "Stick PC",
+    },
+    Watch = 0x003, "Watch" {
+        SportsWatch = 0x01, "Sports Watch",
+        Smartwatch = 0x02, "Smartwatch",
+    },
+    Clock = 0x004, "Clock" {},
+    Display = 0x005, "Display" {},
+    RemoteControl = 0x006, "Remote Control" {},
+    EyeGlasses = 0x007, "Eye-glasses" {},
+    Tag = 0x008, "Tag" {},
+    Keyring = 0x009, "Keyring" {},
+    MediaPlayer = 0x00a, "Media Player" {},
+    BarcodeScanner = 0x00b, "Barcode Scanner" {},
+    Thermometer = 0x00c, "Thermometer" {
+        EarThermometer = 0x01, "Ear Thermometer",
+    },
+    HeartRateSensor = 0x00d, "Heart Rate Sensor" {
+        HeartRateBelt = 0x01, "Heart Rate Belt",
+    },
+    BloodPressure = 0x00e, "Blood Pressure" {
+        ArmBloodPressure = 0x01, "Arm Blood Pressure",
+        WristBloodPressure = 0x02, "Wrist Blood Pressure",
+    },
+    HumanInterfaceDevice = 0x00f, "Human Interface Device" {
+        Keyboard = 0x01, "Keyboard",
+        Mouse = 0x02, "Mouse",
+        Joystick = 0x03, "Joystick",
+        Gamepad = 0x04, "Gamepad",
+        DigitizerTablet = 0x05, "Digitizer Tablet",
+        CardReader = 0x06, "Card Reader",
+        DigitalPen = 0x07, "Digital Pen",
+        HidBarcodeScanner = 0x08, "Barcode Scanner",
+        Touchpad = 0x09, "Touchpad",
+        PresentationRemote = 0x0a, "Presentation Remote",
+    },
+    GlucoseMeter = 0x010, "Glucose Meter" {},
+    RunningWalkingSensor = 0x011, "Running Walking Sensor" {
+        InShoeRunningWalkingSensor = 0x01, "In-Shoe Running Walking Sensor",
+        OnShoeRunningWalkingSensor = 0x02, "On-Shoe Running Walking Sensor",
+        OnHipRunningWalkingSensor = 0x03, "On-Hip Running Walking Sensor",
+    },
+    Cycling = 0x012, "Cycling" {
+        CyclingComputer = 0x01, "Cycling Computer",
+        SpeedSensor = 0x02, "Speed Sensor",
+        CadenceSensor = 0x03, "Cadence Sensor",
+        PowerSensor = 0x04, "Power Sensor",
+        SpeedAndCadenceSensor = 0x05, "Speed and Cadence Sensor",
+    },
+    ControlDevice = 0x013, "Control Device" {
+        Switch = 0x01, "Switch",
+        MultiSwitch = 0x02, "Multi-switch",
+        Button = 0x03, "Button",
+        Slider = 0x04, "Slider",
+        RotarySwitch = 0x05, "Rotary Switch",
+        TouchPanel = 0x06, "Touch Panel",
+        SingleSwitch = 0x07, "Single Switch",
+        DoubleSwitch = 0x08, "Double Switch",
+        TripleSwitch = 0x09, "Triple Switch",
+        BatterySwitch = 0x0a, "Battery Switch",
+        EnergyHarvestingSwitch = 0x0b, "Energy Harvesting Switch",
+        PushButton = 0x0c, "Push Button",
+        Dial = 0x0d, "Dial",
+    },
+    NetworkDevice = 0x014, "Network Device" {
+        AccessPoint = 0x01, "Access Point",
+        MeshDevice = 0x02, "Mesh Device",
+        MeshNetworkProxy = 0x03, "Mesh Network Proxy",
+    },
+    Sensor = 0x015, "Sensor" {
+        MotionSensor = 0x01, "Motion Sensor",
+        AirQualitySensor = 0x02, "Air quality Sensor",
+        TemperatureSensor = 0x03, "Temperature Sensor",
+        HumiditySensor = 0x04, "Humidity Sensor",
+        LeakSensor = 0x05, "Leak Sensor",
+        SmokeSensor = 0x06, "Smoke Sensor",
+        OccupancySensor = 0x07, "Occupancy Sensor",
+        ContactSensor = 0x08, "Contact Sensor",
+        CarbonMonoxideSensor = 0x09, "Carbon Monoxide Sensor",
+        CarbonDioxideSensor = 0x0a, "Carbon Dioxide Sensor",
+        AmbientLightSensor = 0x0b, "Ambient Light Sensor",
+        EnergySensor = 0x0c, "Energy Sensor",
+        ColorLightSensor = 0x0d, "Color Light Sensor",
+        RainSensor = 0x0e, "Rain Sensor",
+        FireSensor = 0x0f, "Fire Sensor",
+        WindSensor = 0x10, "Wind Sensor",
+        ProximitySensor = 0x11, "Proximity Sensor",
+        MultiSensor = 0x12, "Multi-Sensor",
+        FlushMountedSensor = 0x13, "Flush Mounted Sensor",
+        CeilingMountedSensor = 0x14, "Ceiling Mounted Sensor",
+        WallMountedSensor = 0x15, "Wall Mounted Sensor",
+        Multisensor = 0x16, "Multisensor",
+        EnergyMeter = 0x17, "Energy Meter",
+        FlameDetector = 0x18, "Flame Detector",
+        VehicleTirePressureSensor = 0x19, "Vehicle Tire Pressure Sensor",
+    },
+    LightFixtures = 0x016, "Light Fixtures" {
+        WallLight = 0x01, "Wall Light",
+        CeilingLight = 0x02, "Ceiling Light",
+        FloorLight = 0x03, "Floor Light",
+        CabinetLight = 0x04, "Cabinet Light",
+        DeskLight = 0x05, "Desk Light",
+        TrofferLight = 0x06, "Troffer Light",
+        PendantLight = 0x07, "Pendant Light",
+        InGroundLight = 0x08, "In-ground Light",
+        FloodLight = 0x09, "Flood Light",
+        UnderwaterLight = 0x0a, "Underwater Light",
+        BollardWithLight = 0x0b, "Bollard with Light",
+        PathwayLight = 0x0c, "Pathway Light",
+        GardenLight = 0x0d, "Garden Light",
+        PoleTopLight = 0x0e, "Pole-top Light",
+        Spotlight = 0x0f, "Spotlight",
+        LinearLight = 0x10, "Linear Light",
+        StreetLight = 0x11, "Street Light",
+        ShelvesLight = 0x12, "Shelves Light",
+        BayLight = 0x13, "Bay Light",
+        EmergencyExitLight = 0x14, "Emergency Exit Light",
+        LightController = 0x15, "Light Controller",
+        LightDriver = 0x16, "Light Driver",
+        Bulb = 0x17, "Bulb",
+        LowBayLight = 0x18, "Low-bay Light",
+        HighBayLight = 0x19, "High-bay Light",
+    },
+    Fan = 0x017, "Fan" {
+        CeilingFan = 0x01, "Ceiling Fan",
+        AxialFan = 0x02, "Axial Fan",
+        ExhaustFan = 0x03, "Exhaust Fan",
+        PedestalFan = 0x04, "Pedestal Fan",
+        DeskFan = 0x05, "Desk Fan",
+        WallFan = 0x06, "Wall Fan",
+    },
+    Hvac = 0x018, "HVAC" {
+        Thermostat = 0x01, "Thermostat",
+        HvacHumidifier = 0x02, "Humidifier",
+        HvacDehumidifier = 0x03, "De-humidifier",
+        HvacHeater = 0x04, "Heater",
+        HvacRadiator = 0x05, "Radiator",
+        HvacBoiler = 0x06, "Boiler",
+        HvacHeatPump = 0x07, "Heat Pump",
+        HvacInfraredHeater = 0x08, "Infrared Heater",
+        HvacRadiantPanelHeater = 0x09, "Radiant Panel Heater",
+        HvacFanHeater = 0x0a, "Fan Heater",
+        HvacAirCurtain = 0x0b, "Air Curtain",
+    },
+    AirConditioning = 0x019, "Air Conditioning" {},
+    Humidifier = 0x01a, "Humidifier" {},
+    Heating = 0x01b, "Heating" {
+        Radiator = 0x01, "Radiator",
+        Boiler = 0x02, "Boiler",
+        HeatPump = 0x03, "Heat Pump",
+        InfraredHeater = 0x04, "Infrared Heater",
+        RadiantPanelHeater = 0x05, "Radiant Panel Heater",
+        FanHeater = 0x06, "Fan Heater",
+        AirCurtain = 0x07, "Air Curtain",
+    },
+    AccessControl = 0x01c, "Access Control" {
+        AccessDoor = 0x01, "Access Door",
+        GarageDoor = 0x02, "Garage Door",
+        EmergencyExitDoor = 0x03, "Emergency Exit Door",
+        AccessLock = 0x04, "Access Lock",
+        Elevator = 0x05, "Elevator",
+        Window = 0x06, "Window",
+        EntranceGate = 0x07, "Entrance Gate",
+        DoorLock = 0x08, "Door Lock",
+        Locker = 0x09, "Locker",
+    },
+    MotorizedDevice = 0x01d, "Motorized Device" {
+        MotorizedGate = 0x01, "Motorized Gate",
+        Awning = 0x02, "Awning",
+        BlindsOrShades = 0x03, "Blinds or Shades",
+        Curtains = 0x04, "Curtains",
+        Screen = 0x05, "Screen",
+    },
+    PowerDevice = 0x01e, "Power Device" {
+        PowerOutlet = 0x01, "Power Outlet",
+        PowerStrip = 0x02, "Power Strip",
+        Plug = 0x03, "Plug",
+        PowerSupply = 0x04, "Power Supply",
+        LedDriver = 0x05, "LED Driver",
+        FluorescentLampGear = 0x06, "Fluorescent Lamp Gear",
+        HidLampGear = 0x07, "HID Lamp Gear",
+        ChargeCase = 0x08, "Charge Case",
+        PowerBank = 0x09, "Power Bank",
+    },
+    LightSource = 0x01f, "Light Source" {
+        IncandescentLightBulb = 0x01, "Incandescent Light Bulb",
+        LedLamp = 0x02, "LED Lamp",
+        HidLamp = 0x03, "HID Lamp",
+        FluorescentLamp = 0x04, "Fluorescent Lamp",
+        LedArray = 0x05, "LED Array",
+        MultiColorLedArray = 0x06, "Multi-Color LED Array",
+        LowVoltageHalogen = 0x07, "Low voltage halogen",
+        Oled = 0x08, "Organic light emitting diode (OLED)",
+    },
+    WindowCovering = 0x020, "Window Covering" {
+        WindowShades = 0x01, "Window Shades",
+        WindowBlinds = 0x02, "Window Blinds",
+        WindowAwning = 0x03, "Window Awning",
+        WindowCurtain = 0x04, "Window Curtain",
+        ExteriorShutter = 0x05, "Exterior Shutter",
+        ExteriorScreen = 0x06, "Exterior Screen",
+    },
+    AudioSink = 0x021, "Audio Sink" {
+        StandaloneSpeaker = 0x01, "Standalone Speaker",
+        Soundbar = 0x02, "Soundbar",
+        BookshelfSpeaker = 0x03, "Bookshelf Speaker",
+        StandmountedSpeaker = 0x04, "Standmounted Speaker",
+        Speakerphone = 0x05, "Speakerphone",
+    },
+    AudioSource = 0x022, "Audio Source" {
+        Microphone = 0x01, "Microphone",
+        Alarm = 0x02, "Alarm",
+        Bell = 0x03, "Bell",
+        Horn = 0x04, "Horn",
+        BroadcastingDevice = 0x05, "Broadcasting Device",
+        ServiceDesk = 0x06, "Service Desk",
+        Kiosk = 0x07, "Kiosk",
+        BroadcastingRoom = 0x08, "Broadcasting Room",
+        Auditorium = 0x09, "Auditorium",
+    },
+    MotorizedVehicle = 0x023, "Motorized Vehicle" {
+        Car = 0x01, "Car",
+        LargeGoodsVehicle = 0x02, "Large Goods Vehicle",
+        TwoWheeledVehicle = 0x03, "2-Wheeled Vehicle",
+        Motorbike = 0x04, "Motorbike",
+        Scooter = 0x05, "Scooter",
+        Moped = 0x06, "Moped",
+        ThreeWheeledVehicle = 0x07, "3-Wheeled Vehicle",
+        LightVehicle = 0x08, "Light Vehicle",
+        QuadBike = 0x09, "Quad Bike",
+        Minibus = 0x0a, "Minibus",
+        Bus = 0x0b, "Bus",
+        Trolley = 0x0c, "Trolley",
+        AgriculturalVehicle = 0x0d, "Agricultural Vehicle",
+        CamperCaravan = 0x0e, "Camper / Caravan",
+        RecreationalVehicleMotorHome = 0x0f, "Recreational Vehicle / Motor Home",
+    },
+    DomesticAppliance = 0x024, "Domestic Appliance" {
+        Refrigerator = 0x01, "Refrigerator",
+        Freezer = 0x02, "Freezer",
+        Oven = 0x03, "Oven",
+        Microwave = 0x04, "Microwave",
+        Toaster = 0x05, "Toaster",
+        WashingMachine = 0x06, "Washing Machine",
+        Dryer = 0x07, "Dryer",
+        CoffeeMaker = 0x08, "Coffee maker",
+        ClothesIron = 0x09, "Clothes iron",
+        CurlingIron = 0x0a, "Curling iron",
+        HairDryer = 0x0b, "Hair dryer",
+        VacuumCleaner = 0x0c, "Vacuum cleaner",
+        RoboticVacuumCleaner = 0x0d, "Robotic vacuum cleaner",
+        RiceCooker = 0x0e, "Rice cooker",
+        ClothesSteamer = 0x0f, "Clothes steamer",
+    },
+    WearableAudioDevice = 0x025, "Wearable Audio Device" {
+        Earbud = 0x01, "Earbud",
+        Headset = 0x02, "Headset",
+        Headphones = 0x03, "Headphones",
+        NeckBand = 0x04, "Neck Band",
+    },
+    Aircraft = 0x026, "Aircraft" {
+        LightAircraft = 0x01, "Light Aircraft",
+        Microlight = 0x02, "Microlight",
+        Paraglider = 0x03, "Paraglider",
+        LargePassengerAircraft = 0x04, "Large Passenger Aircraft",
+    },
+    AvEquipment = 0x027, "AV Equipment" {
+        Amplifier = 0x01, "Amplifier",
+        Receiver = 0x02, "Receiver",
+        Radio = 0x03, "Radio",
+        Tuner = 0x04, "Tuner",
+        Turntable = 0x05, "Turntable",
+        CdPlayer = 0x06, "CD Player",
+        DvdPlayer = 0x07, "DVD Player",
+        BlurayPlayer = 0x08, "Bluray Player",
+        OpticalDiscPlayer = 0x09, "Optical Disc Player",
+        SetTopBox = 0x0a, "Set-Top Box",
+    },
+    DisplayEquipment = 0x028, "Display Equipment" {
+        Television = 0x01, "Television",
+        Monitor = 0x02, "Monitor",
+        Projector = 0x03, "Projector",
+    },
+    HearingAid = 0x029, "Hearing aid" {
+        InEarHearingAid = 0x01, "In-ear hearing aid",
+        BehindEarHearingAid = 0x02, "Behind-ear hearing aid",
+        CochlearImplant = 0x03, "Cochlear Implant",
+    },
+    Gaming = 0x02a, "Gaming" {
+        HomeVideoGameConsole = 0x01, "Home Video Game Console",
+        PortableHandheldConsole = 0x02, "Portable handheld console",
+    },
+    Signage = 0x02b, "Signage" {
+        DigitalSignage = 0x01, "Digital Signage",
+        ElectronicLabel = 0x02, "Electronic Label",
+    },
+    PulseOximeter = 0x031, "Pulse Oximeter" {
+        FingertipPulseOximeter = 0x01, "Fingertip Pulse Oximeter",
+        WristWornPulseOximeter = 0x02, "Wrist Worn Pulse Oximeter",
+    },
+    WeightScale = 0x032, "Weight Scale" {},
+    PersonalMobilityDevice = 0x033, "Personal Mobility Device" {
+        PoweredWheelchair = 0x01, "Powered Wheelchair",
+        MobilityScooter = 0x02, "Mobility Scooter",
+    },
+    ContinuousGlucoseMonitor = 0x034, "Continuous Glucose Monitor" {
+        CgmContinuousGlucoseMonitor = 0x01, "Continuous Glucose Monitor",
+    },
+    InsulinPump = 0x035, "Insulin Pump" {
+        InsulinPumpDurablePump = 0x01, "Insulin Pump, durable pump",
+        InsulinPumpPatchPump = 0x04, "Insulin Pump, patch pump",
+        InsulinPen = 0x08, "Insulin Pen",
+    },
+    MedicationDelivery = 0x036, "Medication Delivery" {},
+    Spirometer = 0x037, "Spirometer" {
+        HandheldSpirometer = 0x01, "Handheld Spirometer",
+    },
+    OutdoorSportsActivity = 0x051, "Outdoor Sports Activity" {
+        LocationDisplay = 0x01, "Location Display",
+        LocationAndNavigationDisplay = 0x02, "Location and Navigation Display",
+        LocationPod = 0x03, "Location Pod",
+        LocationAndNavigationPod = 0x04, "Location and Navigation Pod",
+    },
+    IndustrialMeasurementDevice = 0x052, "Industrial Measurement Device" {
+        TorqueTestingDevice = 0x01, "Torque Testing Device",
+        Caliper = 0x02, "Caliper",
+        DialIndicator = 0x03, "Dial Indicator",
+        Micrometer = 0x04, "Micrometer",
+        HeightGauge = 0x05, "Height Gauge",
+        ForceGauge = 0x06, "Force Gauge",
+    },
+    IndustrialTools = 0x053, "Industrial Tools" {
+        MachineToolHolder = 0x01, "Machine Tool Holder",
+        GenericClampingDevice = 0x02, "Generic Clamping Device",
+        ClampingJawsJawChuck = 0x03, "Clamping Jaws/Jaw Chuck",
+        ClampingColletChuck = 0x04, "Clamping (Collet) Chuck",
+        ClampingMandrel = 0x05, "Clamping Mandrel",
+        Vise = 0x06, "Vise",
+        ZeroPointClampingSystem = 0x07, "Zero-Point Clamping System",
+        TorqueWrench = 0x08, "Torque Wrench",
+        TorqueScrewdriver = 0x09, "Torque Screwdriver",
+    },
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::data_types::appearance_category::{AppearanceCategory, AppearanceSubcategory};
+
+    #[test]
+    fn test_category_from_u16() {
+        assert_eq!(
+            Some(AppearanceCategory::Unknown),
+            AppearanceCategory::from_u16(0x000)
+        );
+        assert_eq!(
+            Some(AppearanceCategory::IndustrialTools),
+            AppearanceCategory::from_u16(0x053)
+        );
+        assert_eq!(None, AppearanceCategory::from_u16(0x02c));
+        assert_eq!(None, AppearanceCategory::from_u16(0x3ff));
+    }
+
+    #[test]
+    fn test_category_value() {
+        for value in 0x000..=0x3ff {
+            if let Some(category) = AppearanceCategory::from_u16(value) {
+                assert_eq!(value, category.value());
+                assert_eq!(value << 6, category.appearance());
+            }
+        }
+    }
+
+    #[test]
+    fn test_category_name() {
+        assert_eq!("Unknown", AppearanceCategory::Unknown.name());
+        assert_eq!("HVAC", AppearanceCategory::Hvac.name());
+        assert_eq!("Sensor", AppearanceCategory::Sensor.name());
+    }
+
+    #[test]
+    fn test_sub_category_from_u16() {
+        assert_eq!(
+            Some(AppearanceSubcategory::DesktopWorkstation),
+            AppearanceSubcategory::from_u16(0x002, 0x01)
+        );
+        assert_eq!(
+            Some(AppearanceSubcategory::HidBarcodeScanner),
+            AppearanceSubcategory::from_u16(0x00f, 0x08)
+        );
+        assert_eq!(
+            Some(AppearanceSubcategory::InsulinPen),
+            AppearanceSubcategory::from_u16(0x035, 0x08)
+        );
+        assert_eq!(None, AppearanceSubcategory::from_u16(0x035, 0x02));
+        assert_eq!(None, AppearanceSubcategory::from_u16(0x001, 0x01));
+    }
+
+    #[test]
+    fn test_sub_category_value() {
+        for category in 0x000..=0x3ff {
+            for sub_category in 0x00..=0x3f {
+                if let Some(result) = AppearanceSubcategory::from_u16(category, sub_category) {
+                    assert_eq!(category, result.category().value());
+                    assert_eq!(sub_category, result.value());
+                    assert_eq!((category << 6) | sub_category, result.appearance());
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_sub_category_name() {
+        assert_eq!(
+            "Continuous Glucose Monitor",
+            AppearanceSubcategory::CgmContinuousGlucoseMonitor.name()
+        );
+        assert_eq!("Multi-Sensor", AppearanceSubcategory::MultiSensor.name());
+        assert_eq!("Multi-switch", AppearanceSubcategory::MultiSwitch.name());
+        assert_eq!("Radiator", AppearanceSubcategory::HvacRadiator.name());
+    }
+
+    #[test]
+    fn test_sub_category_appearance() {
+        for (appearance, sub_category) in [
+            (0x04c1, AppearanceSubcategory::Switch),
+            (0x04c2, AppearanceSubcategory::MultiSwitch),
+            (0x04cd, AppearanceSubcategory::Dial),
+            (0x0503, AppearanceSubcategory::MeshNetworkProxy),
+            (0x0559, AppearanceSubcategory::VehicleTirePressureSensor),
+            (0x0599, AppearanceSubcategory::HighBayLight),
+            (0x0605, AppearanceSubcategory::HvacRadiator),
+            (0x06c1, AppearanceSubcategory::Radiator),
+            (0x0709, AppearanceSubcategory::Locker),
+            (0x0789, AppearanceSubcategory::PowerBank),
+            (0x08cf, AppearanceSubcategory::RecreationalVehicleMotorHome),
+            (0x090f, AppearanceSubcategory::ClothesSteamer),
+            (0x09ca, AppearanceSubcategory::SetTopBox),
+            (0x0a01, AppearanceSubcategory::Television),
+            (0x0a02, AppearanceSubcategory::Monitor),
+            (0x0a03, AppearanceSubcategory::Projector),
+            (0x0ac2, AppearanceSubcategory::ElectronicLabel),
+            (0x0cc1, AppearanceSubcategory::PoweredWheelchair),
+            (0x1486, AppearanceSubcategory::ForceGauge),
+            (0x14c9, AppearanceSubcategory::TorqueScrewdriver),
+        ] {
+            assert_eq!(appearance, sub_category.appearance());
+            assert_eq!(
+                Some(sub_category),
+                AppearanceSubcategory::from_u16(appearance >> 6, appearance & 0x3f)
+            );
+        }
+    }
+}
@@ -0,0 +1,211 @@
+//! Appearance category / sub-category name module.
+
+use std::collections::HashMap;
+
+use crate::data_types::appearance_category::{AppearanceCategory, AppearanceSubcategory};
+
+/// Appearance category / sub-category name source.
+pub trait NameProvider {
+    /// Get category name.
+    fn category_name(&self, category: u16) -> Option<&str>;
+
+    /// Get sub-category name.
+    fn sub_category_name(&self, category: u16, sub_category: u16) -> Option<&str>;
+}
+
+/// Assigned Numbers (English) [`NameProvider`].
+///
+/// Names come from the [`AppearanceCategory`] / [`AppearanceSubcategory`] catalog.
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Default)]
+pub struct AssignedNumbersNameProvider;
+
+impl NameProvider for AssignedNumbersNameProvider {
+    /// Get Assigned Numbers category name.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ble_data_struct::data_types::appearance_name::{
+    ///     AssignedNumbersNameProvider, NameProvider,
+    /// };
+    ///
+    /// assert_eq!(Some("Phone"), AssignedNumbersNameProvider.category_name(0x001));
+    /// assert_eq!(None, AssignedNumbersNameProvider.category_name(0x3ff));
+    /// ```
+    fn category_name(&self, category: u16) -> Option<&str> {
+        AppearanceCategory::from_u16(category).map(|f| f.name())
+    }
+
+    /// Get Assigned Numbers sub-category name.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ble_data_struct::data_types::appearance_name::{
+    ///     AssignedNumbersNameProvider, NameProvider,
+    /// };
+    ///
+    /// assert_eq!(Some("Smartwatch"), AssignedNumbersNameProvider.sub_category_name(0x003, 0x02));
+    /// assert_eq!(None, AssignedNumbersNameProvider.sub_category_name(0x003, 0x3f));
+    /// ```
+    fn sub_category_name(&self, category: u16, sub_category: u16) -> Option<&str> {
+        AppearanceSubcategory::from_u16(category, sub_category).map(|f| f.name())
+    }
+}
+
+/// [`NameProvider`] with replaced names, falling back to another [`NameProvider`].
+#[derive(Debug, PartialEq, Clone)]
+pub struct LocalizedNameProvider<P: NameProvider> {
+    /// fallback provider
+    pub fallback: P,
+
+    /// category names
+    pub category_names: HashMap<u16, String>,
+
+    /// sub-category names
+    pub sub_category_names: HashMap<(u16, u16), String>,
+}
+
+impl<P: NameProvider> LocalizedNameProvider<P> {
+    /// Create [`LocalizedNameProvider`] from fallback [`NameProvider`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ble_data_struct::data_types::appearance_name::{
+    ///     AssignedNumbersNameProvider, LocalizedNameProvider,
+    /// };
+    ///
+    /// let result = LocalizedNameProvider::new(AssignedNumbersNameProvider);
+    /// assert_eq!(AssignedNumbersNameProvider, result.fallback);
+    /// assert!(result.category_names.is_empty());
+    /// assert!(result.sub_category_names.is_empty());
+    /// ```
+    pub fn new(fallback: P) -> Self {
+        Self {
+            fallback,
+            category_names: HashMap::new(),
+            sub_category_names: HashMap::new(),
+        }
+    }
+
+    /// Set category name.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ble_data_struct::data_types::appearance_name::{
+    ///     AssignedNumbersNameProvider, LocalizedNameProvider, NameProvider,
+    /// };
+    ///
+    /// let result = LocalizedNameProvider::new(AssignedNumbersNameProvider)
+    ///     .with_category_name(0x001, "Telefon");
+    /// assert_eq!(Some("Telefon"), result.category_name(0x001));
+    /// assert_eq!(Some("Computer"), result.category_name(0x002));
+    /// ```
+    pub fn with_category_name(mut self, category: u16, name: &str) -> Self {
+        self.category_names.insert(category, name.to_string());
+        self
+    }
+
+    /// Set sub-category name.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ble_data_struct::data_types::appearance_name::{
+    ///     AssignedNumbersNameProvider, LocalizedNameProvider, NameProvider,
+    /// };
+    ///
+    /// let result = LocalizedNameProvider::new(AssignedNumbersNameProvider)
+    ///     .with_sub_category_name(0x003, 0x02, "Montre connectée");
+    /// assert_eq!(Some("Montre connectée"), result.sub_category_name(0x003, 0x02));
+    /// assert_eq!(Some("Sports Watch"), result.sub_category_name(0x003, 0x01));
+    /// ```
+    pub fn with_sub_category_name(mut self, category: u16, sub_category: u16, name: &str) -> Self {
+        self.sub_category_names
+            .insert((category, sub_category), name.to_string());
+        self
+    }
+}
+
+impl<P: NameProvider> NameProvider for LocalizedNameProvider<P> {
+    /// Get replaced category name or fallback.
+    fn category_name(&self, category: u16) -> Option<&str> {
+        match self.category_names.get(&category) {
+            Some(name) => Some(name),
+            None => self.fallback.category_name(category),
+        }
+    }
+
+    /// Get replaced sub-category name or fallback.
+    fn sub_category_name(&self, category: u16, sub_category: u16) -> Option<&str> {
+        match self.sub_category_names.get(&(category, sub_category)) {
+            Some(name) => Some(name),
+            None => self.fallback.sub_category_name(category, sub_category),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::data_types::appearance_name::{
+        AssignedNumbersNameProvider, LocalizedNameProvider, NameProvider,
+    };
+
+    #[test]
+    fn test_assigned_numbers_category_name() {
+        assert_eq!(
+            Some("Unknown"),
+            AssignedNumbersNameProvider.category_name(0x000)
+        );
+        assert_eq!(
+            Some("Outdoor Sports Activity"),
+            AssignedNumbersNameProvider.category_name(0x051)
+        );
+        assert_eq!(None, AssignedNumbersNameProvider.category_name(0x3ff));
+    }
+
+    #[test]
+    fn test_assigned_numbers_sub_category_name() {
+        assert_eq!(
+            Some("Heart Rate Belt"),
+            AssignedNumbersNameProvider.sub_category_name(0x00d, 0x01)
+        );
+        assert_eq!(
+            None,
+            AssignedNumbersNameProvider.sub_category_name(0x00d, 0x02)
+        );
+    }
+
+    #[test]
+    fn test_localized_new() {
+        let result = LocalizedNameProvider::new(AssignedNumbersNameProvider);
+        assert_eq!(AssignedNumbersNameProvider, result.fallback);
+        assert!(result.category_names.is_empty());
+        assert!(result.sub_category_names.is_empty());
+    }
+
+    #[test]
+    fn test_localized_category_name() {
+        let result = LocalizedNameProvider::new(AssignedNumbersNameProvider)
+            .with_category_name(0x001, "Telefon")
+            .with_category_name(0x3ff, "Custom");
+        assert_eq!(Some("Telefon"), result.category_name(0x001));
+        assert_eq!(Some("Computer"), result.category_name(0x002));
+        assert_eq!(Some("Custom"), result.category_name(0x3ff));
+        assert_eq!(None, result.category_name(0x3fe));
+    }
+
+    #[test]
+    fn test_localized_sub_category_name() {
+        let result = LocalizedNameProvider::new(AssignedNumbersNameProvider)
+            .with_sub_category_name(0x003, 0x02, "Montre connectée");
+        assert_eq!(
+            Some("Montre connectée"),
+            result.sub_category_name(0x003, 0x02)
+        );
+        assert_eq!(Some("Sports Watch"), result.sub_category_name(0x003, 0x01));
+        assert_eq!(None, result.sub_category_name(0x003, 0x03));
+    }
+}
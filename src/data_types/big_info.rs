@@ -1,9 +1,16 @@
 //! BIGInfo (Data Type Value:0x2c) module.
 
-use crate::data_types::data_type::DataType;
+use crate::data_types::{ad_type::AdType, data_type::DataType, parse_error::DataTypeParseError};
+
+#[cfg(feature = "crypto")]
+use crate::{
+    crypto::toolbox::{h6, h7, h8},
+    data_types::broadcast_code::BroadcastCode,
+};
 
 /// BIGInfo.
 #[derive(Debug, PartialEq, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct BigInfo {
     /// data length
     pub length: u8,
@@ -76,6 +83,19 @@ pub struct BigInfo {
 }
 
 impl BigInfo {
+    /// Create [`BigInfoBuilder`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ble_data_struct::data_types::big_info::{BigInfo, BigInfoBuilder};
+    ///
+    /// assert_eq!(BigInfoBuilder::new(), BigInfo::builder());
+    /// ```
+    pub fn builder() -> BigInfoBuilder {
+        BigInfoBuilder::new()
+    }
+
     /// Create [`BigInfo`] from Parameters.
     ///
     /// # Examples
@@ -253,16 +273,735 @@ impl BigInfo {
             gskd,
         }
     }
+
+    /// Check encrypted BIG (`GIV` and `GSKD` present).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ble_data_struct::data_types::big_info::BigInfo;
+    ///
+    /// let result = BigInfo::builder().build().unwrap();
+    /// assert!(!result.is_encrypted());
+    ///
+    /// let result = BigInfo::builder().giv([0; 8]).gskd([0; 16]).build().unwrap();
+    /// assert!(result.is_encrypted());
+    /// ```
+    pub fn is_encrypted(&self) -> bool {
+        self.giv.is_some() && self.gskd.is_some()
+    }
+
+    /// Create [`DecryptableBigInfo`] from [`BroadcastCode`].
+    ///
+    /// GLTK = h6(h7("BIG1", Broadcast_Code), "BIG2"), GSK = h8(GLTK, GSKD, "BIG3").
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ble_data_struct::data_types::{big_info::BigInfo, broadcast_code::BroadcastCode};
+    ///
+    /// let big_info = BigInfo::builder()
+    ///     .giv([0; 8])
+    ///     .gskd([0x00, 0x01, 0x02, 0x03, 0x04, 0x05, 0x06, 0x07, 0x08, 0x09, 0x0a, 0x0b, 0x0c, 0x0d, 0x0e, 0x0f])
+    ///     .build()
+    ///     .unwrap();
+    /// let broadcast_code = BroadcastCode::new(&"1234".as_bytes().to_vec());
+    /// let result = big_info.decryptable(&broadcast_code);
+    /// assert!(result.is_ok());
+    /// let decryptable = result.unwrap();
+    /// assert_eq!(big_info, decryptable.big_info);
+    /// assert_eq!(0xb4ead03c97f83cef183ec06f5602d394u128, decryptable.gltk);
+    /// assert_eq!(0x867901d6c63b8c4b67437e9331e41549u128, decryptable.gsk);
+    ///
+    /// let big_info = BigInfo::builder().build().unwrap();
+    /// assert!(big_info.decryptable(&broadcast_code).is_err());
+    /// ```
+    #[cfg(feature = "crypto")]
+    pub fn decryptable(
+        &self,
+        broadcast_code: &BroadcastCode,
+    ) -> Result<DecryptableBigInfo, String> {
+        let gskd = match self.gskd {
+            Some(gskd) if self.giv.is_some() => u128::from_le_bytes(gskd),
+            _ => return Err("Unencrypted BIG".to_string()),
+        };
+        let len = broadcast_code.broadcast_code.len();
+        if len > 16 {
+            return Err(format!("Invalid Broadcast_Code size :{}", len));
+        }
+        let mut code = [0u8; 16];
+        code[..len].copy_from_slice(&broadcast_code.broadcast_code);
+        let igltk = h7(BIG1, u128::from_le_bytes(code));
+        let gltk = h6(igltk, BIG2);
+        Ok(DecryptableBigInfo {
+            big_info: self.clone(),
+            gltk,
+            gsk: h8(gltk, gskd, BIG3),
+        })
+    }
+
+    /// Get ISO_Interval(millis).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ble_data_struct::data_types::big_info::{BigInfo, ISO_INTERVAL_VALUE};
+    ///
+    /// let result = BigInfo::new(
+    ///     0, false, 8, 2, 4, 2, 1000, 0, 500, 2, 40, 0, 0, 10000, 40, 0, 0, 1, 0, false, None, None,
+    /// );
+    /// assert_eq!(8.0 * ISO_INTERVAL_VALUE, result.iso_interval_millis());
+    /// ```
+    pub fn iso_interval_millis(&self) -> f32 {
+        self.iso_interval as f32 * ISO_INTERVAL_VALUE
+    }
+
+    /// Get transmission time(micros) of a BIS PDU with Max_PDU payload (MPT).
+    ///
+    /// MIC is included when the BIG is encrypted (`GIV` and `GSKD` present).
+    /// LE Coded PHY uses S=8 coding.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ble_data_struct::data_types::big_info::BigInfo;
+    ///
+    /// let result = BigInfo::new(
+    ///     0, false, 8, 2, 4, 2, 1000, 0, 500, 2, 40, 0, 0, 10000, 40, 0, 0, 1, 0, false, None, None,
+    /// );
+    /// assert_eq!(Some(204), result.max_pdu_transmission_time());
+    ///
+    /// let result = BigInfo::new(
+    ///     0, false, 8, 2, 4, 2, 1000, 0, 500, 2, 40, 0, 0, 10000, 40, 0, 0, 7, 0, false, None, None,
+    /// );
+    /// assert_eq!(None, result.max_pdu_transmission_time());
+    /// ```
+    pub fn max_pdu_transmission_time(&self) -> Option<u32> {
+        let mut length = self.max_pdu as u32;
+        if self.giv.is_some() {
+            length += 4;
+        }
+        match self.phy {
+            PHY_LE_1M => Some((10 + length) * 8),
+            PHY_LE_2M => Some((11 + length) * 4),
+            PHY_LE_CODED => Some(400 + (5 + length) * 64),
+            _ => None,
+        }
+    }
+
+    /// Get BIG_Sync_Delay(micros).
+    ///
+    /// Duration from the BIG anchor point to the end of the last subevent of the last BIS.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ble_data_struct::data_types::big_info::BigInfo;
+    ///
+    /// let result = BigInfo::new(
+    ///     0, false, 8, 2, 4, 2, 1000, 0, 500, 2, 40, 0, 0, 10000, 40, 0, 0, 1, 0, false, None, None,
+    /// );
+    /// assert_eq!(Some(500 + 3 * 1000 + 204), result.big_sync_delay());
+    /// ```
+    pub fn big_sync_delay(&self) -> Option<u32> {
+        let mpt = self.max_pdu_transmission_time()?;
+        Some(
+            (self.num_bis.max(1) as u32 - 1) * self.bis_spacing
+                + (self.nse.max(1) as u32 - 1) * self.sub_interval
+                + mpt,
+        )
+    }
+
+    /// Get subevent start times(micros) from the BIG anchor point.
+    ///
+    /// Indexed by BIS (0 origin) then subevent (0 origin).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ble_data_struct::data_types::big_info::BigInfo;
+    ///
+    /// let result = BigInfo::new(
+    ///     0, false, 8, 2, 4, 2, 1000, 0, 500, 2, 40, 0, 0, 10000, 40, 0, 0, 1, 0, false, None, None,
+    /// );
+    /// assert_eq!(
+    ///     vec![vec![0, 1000, 2000, 3000], vec![500, 1500, 2500, 3500]],
+    ///     result.subevent_schedule()
+    /// );
+    /// ```
+    pub fn subevent_schedule(&self) -> Vec<Vec<u32>> {
+        (0..self.num_bis as u32)
+            .map(|bis| {
+                (0..self.nse as u32)
+                    .map(|subevent| bis * self.bis_spacing + subevent * self.sub_interval)
+                    .collect()
+            })
+            .collect()
+    }
+
+    /// Get Transport_Latency_BIG(micros).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ble_data_struct::data_types::big_info::BigInfo;
+    ///
+    /// let result = BigInfo::new(
+    ///     0, false, 8, 2, 4, 2, 1000, 0, 500, 2, 40, 0, 0, 10000, 40, 0, 0, 1, 0, false, None, None,
+    /// );
+    /// assert_eq!(Some(3704 + 10000 - 10000), result.transport_latency());
+    ///
+    /// let result = BigInfo::new(
+    ///     0, false, 8, 2, 4, 2, 1000, 0, 500, 2, 40, 0, 0, 10000, 40, 0, 0, 1, 0, true, None, None,
+    /// );
+    /// assert_eq!(Some(3704 + 10000 + 10000), result.transport_latency());
+    /// ```
+    pub fn transport_latency(&self) -> Option<u32> {
+        let big_sync_delay = self.big_sync_delay()?;
+        let iso_interval = self.iso_interval as u32 * ISO_INTERVAL_MICROS;
+        let pto = (self.nse.checked_div(self.bn)? as u32).checked_sub(self.irc as u32)?
+            * self.pto as u32
+            * iso_interval;
+        if self.framing {
+            Some(big_sync_delay + pto + iso_interval + self.sdu_interval)
+        } else {
+            (big_sync_delay + pto + iso_interval).checked_sub(self.sdu_interval)
+        }
+    }
+}
+
+/// Encrypted [`BigInfo`] with the keys derived from [`BroadcastCode`].
+#[cfg(feature = "crypto")]
+#[derive(Debug, PartialEq, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct DecryptableBigInfo {
+    /// BIGInfo
+    pub big_info: BigInfo,
+
+    /// Group Long Term Key
+    pub gltk: u128,
+
+    /// Group Session Key
+    pub gsk: u128,
+}
+
+/// "BIG1"
+#[cfg(feature = "crypto")]
+const BIG1: u128 = 0x42494731;
+
+/// "BIG2"
+#[cfg(feature = "crypto")]
+const BIG2: u32 = 0x42494732;
+
+/// "BIG3"
+#[cfg(feature = "crypto")]
+const BIG3: u32 = 0x42494733;
+
+/// [`BigInfo`] builder.
+///
+/// Fields not set keep the default value (single BIS, single subevent, all data channels used, LE 1M PHY, unencrypted).
+#[derive(Debug, PartialEq, Clone)]
+pub struct BigInfoBuilder {
+    /// BIG_Offset
+    big_offset: u16,
+
+    /// BIG_Offset_Units
+    big_offset_units: bool,
+
+    /// ISO_Interval
+    iso_interval: u16,
+
+    /// Num_BIS
+    num_bis: u8,
+
+    /// NSE
+    nse: u8,
+
+    /// BN
+    bn: u8,
+
+    /// Sub_Interval
+    sub_interval: u32,
+
+    /// PTO
+    pto: u8,
+
+    /// BIS_Spacing
+    bis_spacing: u32,
+
+    /// IRC
+    irc: u8,
+
+    /// Max_PDU
+    max_pdu: u8,
+
+    /// RFU
+    rfu: u8,
+
+    /// SeedAccessAddress
+    seed_access_address: u32,
+
+    /// SDU_Interval
+    sdu_interval: u32,
+
+    /// Max_SDU
+    max_sdu: u16,
+
+    /// BaseCRCInit
+    base_crc_init: u16,
+
+    /// ChM
+    ch_m: u64,
+
+    /// PHY
+    phy: u8,
+
+    /// bisPayloadCount
+    bis_payload_count: u64,
+
+    /// Framing
+    framing: bool,
+
+    /// GIV
+    giv: Option<[u8; 8]>,
+
+    /// GSKD
+    gskd: Option<[u8; 16]>,
+}
+
+impl BigInfoBuilder {
+    /// Create [`BigInfoBuilder`] with default values.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ble_data_struct::data_types::big_info::BigInfoBuilder;
+    ///
+    /// let result = BigInfoBuilder::new().build();
+    /// assert!(result.is_ok());
+    /// let big_info = result.unwrap();
+    /// assert_eq!(1, big_info.num_bis);
+    /// assert_eq!(1, big_info.nse);
+    /// assert_eq!(1, big_info.bn);
+    /// assert_eq!(1, big_info.irc);
+    /// assert_eq!(None, big_info.giv);
+    /// ```
+    pub fn new() -> Self {
+        Self {
+            big_offset: 0,
+            big_offset_units: false,
+            iso_interval: 4,
+            num_bis: 1,
+            nse: 1,
+            bn: 1,
+            sub_interval: 0,
+            pto: 0,
+            bis_spacing: 0,
+            irc: 1,
+            max_pdu: 0,
+            rfu: 0,
+            seed_access_address: 0,
+            sdu_interval: 0,
+            max_sdu: 0,
+            base_crc_init: 0,
+            ch_m: 0x1f_ffff_ffff,
+            phy: PHY_LE_1M,
+            bis_payload_count: 0,
+            framing: false,
+            giv: None,
+            gskd: None,
+        }
+    }
+
+    /// Set `BIG_Offset`.
+    pub fn big_offset(mut self, big_offset: u16) -> Self {
+        self.big_offset = big_offset;
+        self
+    }
+
+    /// Set `BIG_Offset_Units`.
+    pub fn big_offset_units(mut self, big_offset_units: bool) -> Self {
+        self.big_offset_units = big_offset_units;
+        self
+    }
+
+    /// Set `ISO_Interval`.
+    pub fn iso_interval(mut self, iso_interval: u16) -> Self {
+        self.iso_interval = iso_interval;
+        self
+    }
+
+    /// Set `Num_BIS`.
+    pub fn num_bis(mut self, num_bis: u8) -> Self {
+        self.num_bis = num_bis;
+        self
+    }
+
+    /// Set `NSE`.
+    pub fn nse(mut self, nse: u8) -> Self {
+        self.nse = nse;
+        self
+    }
+
+    /// Set `BN`.
+    pub fn bn(mut self, bn: u8) -> Self {
+        self.bn = bn;
+        self
+    }
+
+    /// Set `Sub_Interval`.
+    pub fn sub_interval(mut self, sub_interval: u32) -> Self {
+        self.sub_interval = sub_interval;
+        self
+    }
+
+    /// Set `PTO`.
+    pub fn pto(mut self, pto: u8) -> Self {
+        self.pto = pto;
+        self
+    }
+
+    /// Set `BIS_Spacing`.
+    pub fn bis_spacing(mut self, bis_spacing: u32) -> Self {
+        self.bis_spacing = bis_spacing;
+        self
+    }
+
+    /// Set `IRC`.
+    pub fn irc(mut self, irc: u8) -> Self {
+        self.irc = irc;
+        self
+    }
+
+    /// Set `Max_PDU`.
+    pub fn max_pdu(mut self, max_pdu: u8) -> Self {
+        self.max_pdu = max_pdu;
+        self
+    }
+
+    /// Set `RFU`.
+    pub fn rfu(mut self, rfu: u8) -> Self {
+        self.rfu = rfu;
+        self
+    }
+
+    /// Set `SeedAccessAddress`.
+    pub fn seed_access_address(mut self, seed_access_address: u32) -> Self {
+        self.seed_access_address = seed_access_address;
+        self
+    }
+
+    /// Set `SDU_Interval`.
+    pub fn sdu_interval(mut self, sdu_interval: u32) -> Self {
+        self.sdu_interval = sdu_interval;
+        self
+    }
+
+    /// Set `Max_SDU`.
+    pub fn max_sdu(mut self, max_sdu: u16) -> Self {
+        self.max_sdu = max_sdu;
+        self
+    }
+
+    /// Set `BaseCRCInit`.
+    pub fn base_crc_init(mut self, base_crc_init: u16) -> Self {
+        self.base_crc_init = base_crc_init;
+        self
+    }
+
+    /// Set `ChM`.
+    pub fn ch_m(mut self, ch_m: u64) -> Self {
+        self.ch_m = ch_m;
+        self
+    }
+
+    /// Set `PHY`.
+    pub fn phy(mut self, phy: u8) -> Self {
+        self.phy = phy;
+        self
+    }
+
+    /// Set `bisPayloadCount`.
+    pub fn bis_payload_count(mut self, bis_payload_count: u64) -> Self {
+        self.bis_payload_count = bis_payload_count;
+        self
+    }
+
+    /// Set `Framing`.
+    pub fn framing(mut self, framing: bool) -> Self {
+        self.framing = framing;
+        self
+    }
+
+    /// Set `GIV`.
+    pub fn giv(mut self, giv: [u8; 8]) -> Self {
+        self.giv = Some(giv);
+        self
+    }
+
+    /// Set `GSKD`.
+    pub fn gskd(mut self, gskd: [u8; 16]) -> Self {
+        self.gskd = Some(gskd);
+        self
+    }
+
+    /// Validate the fields and create [`BigInfo`].
+    ///
+    /// Field widths are checked against the BIGInfo format, and the interdependent fields must satisfy
+    ///
+    /// - `NSE` is an integer multiple of `BN`
+    /// - `IRC` ≤ `NSE` / `BN`
+    /// - `NSE` / `BN` > `IRC` if `PTO` is not 0 (pre-transmission needs spare subevents)
+    /// - `GIV` and `GSKD` are both present or both absent
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ble_data_struct::data_types::big_info::BigInfoBuilder;
+    ///
+    /// let result = BigInfoBuilder::new()
+    ///     .iso_interval(8)
+    ///     .num_bis(2)
+    ///     .nse(4)
+    ///     .bn(2)
+    ///     .irc(1)
+    ///     .pto(1)
+    ///     .build();
+    /// assert!(result.is_ok());
+    /// let big_info = result.unwrap();
+    /// assert_eq!(34, big_info.length);
+    /// assert_eq!(8, big_info.iso_interval);
+    ///
+    /// let result = BigInfoBuilder::new().nse(3).bn(2).build();
+    /// assert!(result.is_err());
+    ///
+    /// let result = BigInfoBuilder::new().giv([0; 8]).build();
+    /// assert!(result.is_err());
+    ///
+    /// let result = BigInfoBuilder::new().giv([0; 8]).gskd([0; 16]).build();
+    /// assert!(result.is_ok());
+    /// assert_eq!(58, result.unwrap().length);
+    /// ```
+    pub fn build(&self) -> Result<BigInfo, String> {
+        if self.big_offset > 0x3fff {
+            return Err(format!("Invalid BIG_Offset :{}", self.big_offset));
+        }
+        if !(0x0004..=0x0c80).contains(&self.iso_interval) {
+            return Err(format!("Invalid ISO_Interval :{}", self.iso_interval));
+        }
+        if !(1..=0x1f).contains(&self.num_bis) {
+            return Err(format!("Invalid Num_BIS :{}", self.num_bis));
+        }
+        if !(1..=0x1f).contains(&self.nse) {
+            return Err(format!("Invalid NSE :{}", self.nse));
+        }
+        if !(1..=0x07).contains(&self.bn) {
+            return Err(format!("Invalid BN :{}", self.bn));
+        }
+        if self.sub_interval > 0xfffff {
+            return Err(format!("Invalid Sub_Interval :{}", self.sub_interval));
+        }
+        if self.pto > 0x0f {
+            return Err(format!("Invalid PTO :{}", self.pto));
+        }
+        if self.bis_spacing > 0xfffff {
+            return Err(format!("Invalid BIS_Spacing :{}", self.bis_spacing));
+        }
+        if !(1..=0x0f).contains(&self.irc) {
+            return Err(format!("Invalid IRC :{}", self.irc));
+        }
+        if self.sdu_interval > 0xfffff {
+            return Err(format!("Invalid SDU_Interval :{}", self.sdu_interval));
+        }
+        if self.max_sdu > 0x0fff {
+            return Err(format!("Invalid Max_SDU :{}", self.max_sdu));
+        }
+        if self.ch_m > 0x1f_ffff_ffff {
+            return Err(format!("Invalid ChM :{}", self.ch_m));
+        }
+        if self.phy > PHY_LE_CODED {
+            return Err(format!("Invalid PHY :{}", self.phy));
+        }
+        if self.bis_payload_count > 0x7f_ffff_ffff {
+            return Err(format!(
+                "Invalid bisPayloadCount :{}",
+                self.bis_payload_count
+            ));
+        }
+        if !self.nse.is_multiple_of(self.bn) {
+            return Err(format!(
+                "NSE must be a multiple of BN :{}/{}",
+                self.nse, self.bn
+            ));
+        }
+        let groups = self.nse / self.bn;
+        if self.irc > groups {
+            return Err(format!(
+                "IRC must not exceed NSE / BN :{}/{}",
+                self.irc, groups
+            ));
+        }
+        if self.pto != 0 && self.irc == groups {
+            return Err(format!(
+                "PTO requires NSE / BN greater than IRC :{}/{}",
+                groups, self.irc
+            ));
+        }
+        if self.giv.is_some() != self.gskd.is_some() {
+            return Err("GIV and GSKD must be set together".to_string());
+        }
+        Ok(BigInfo::new(
+            self.big_offset,
+            self.big_offset_units,
+            self.iso_interval,
+            self.num_bis,
+            self.nse,
+            self.bn,
+            self.sub_interval,
+            self.pto,
+            self.bis_spacing,
+            self.irc,
+            self.max_pdu,
+            self.rfu,
+            self.seed_access_address,
+            self.sdu_interval,
+            self.max_sdu,
+            self.base_crc_init,
+            self.ch_m,
+            self.phy,
+            self.bis_payload_count,
+            self.framing,
+            self.giv,
+            self.gskd,
+        ))
+    }
+}
+
+impl Default for BigInfoBuilder {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Units: 1.25 ms
+///
+/// ISO_Interval value
+pub const ISO_INTERVAL_VALUE: f32 = 1.25;
+
+/// ISO_Interval unit(micros).
+const ISO_INTERVAL_MICROS: u32 = 1250;
+
+/// PHY: LE 1M
+pub const PHY_LE_1M: u8 = 0;
+
+/// PHY: LE 2M
+pub const PHY_LE_2M: u8 = 1;
+
+/// PHY: LE Coded
+pub const PHY_LE_CODED: u8 = 2;
+
+impl TryFrom<&[u8]> for BigInfo {
+    type Error = DataTypeParseError;
+    /// Create [`BigInfo`] from `&[u8]`.
+    fn try_from(value: &[u8]) -> Result<Self, DataTypeParseError> {
+        let len = value.len();
+        if len < 35 {
+            return Err(DataTypeParseError::InvalidLength {
+                expected: 35,
+                actual: len,
+            });
+        }
+        let length = value[0];
+        let value1 = u16::from_le_bytes(value[2..4].try_into().unwrap());
+        let big_offset = value1 & 0b00111111_11111111;
+        let big_offset_units = value1 & 0b01000000_00000000 != 0;
+
+        let value2 = u16::from_le_bytes(value[4..6].try_into().unwrap());
+        let iso_interval =
+            ((value1 & 0b1000000000000000) >> 15) | ((value2 & 0b0000011111111111) << 1);
+        let num_bis: u8 = ((value2 & 0b1111100000000000) >> 11) as u8;
+
+        let nse = value[6] & 0b00011111;
+
+        let bn = (value[6] & 0b11100000) >> 5;
+
+        let value1 = u32::from_le_bytes(value[7..11].try_into().unwrap());
+        let sub_interval = value1 & 0b00000000_00001111_11111111_11111111;
+        let pto = ((value1 & 0b00000000_11110000_00000000_00000000) >> 20) as u8;
+
+        let value1 = u32::from_le_bytes(value[10..14].try_into().unwrap())
+            & 0b00000000_11111111_11111111_11111111;
+        let bis_spacing = value1 & 0b00001111_11111111_11111111;
+        let irc = ((value1 & 0b11110000_00000000_00000000) >> 20) as u8;
+
+        let max_pdu = value[13];
+
+        let rfu = value[14];
+
+        let seed_access_address = u32::from_le_bytes(value[15..19].try_into().unwrap());
+
+        let value1 = u32::from_le_bytes(value[19..23].try_into().unwrap())
+            & 0b00000000_11111111_11111111_11111111;
+        let sdu_interval = value1 & 0b00001111_11111111_11111111;
+        let max_sdu = (value1 >> 20) as u16 | (value[22] << 4) as u16;
+
+        let base_crc_init = u16::from_le_bytes(value[23..25].try_into().unwrap());
+
+        let value1 = u64::from_le_bytes(value[25..33].try_into().unwrap()) & 0x000000ffffffffff;
+        let ch_m = value1 & 0b00011111_11111111_11111111_11111111_11111111;
+        let phy = ((value1 & 0b11100000_00000000_00000000_00000000_00000000) >> 37) as u8;
+
+        let mut tmp = value.to_vec();
+        tmp.append(&mut [0u8; 3].to_vec());
+        let value1 = u64::from_le_bytes(tmp[30..38].try_into().unwrap()) & 0x000000ffffffffff;
+        let bis_payload_count = value1 & 0b01111111_11111111_11111111_11111111_11111111;
+        let framing = value1 & 0b10000000_00000000_00000000_00000000_00000000 != 0;
+
+        let (giv, gskd) = if length == 58 {
+            (
+                Some(value[35..43].try_into().unwrap()),
+                Some(value[43..59].try_into().unwrap()),
+            )
+        } else {
+            (None, None)
+        };
+        Ok(Self {
+            length,
+            big_offset,
+            big_offset_units,
+            iso_interval,
+            num_bis,
+            nse,
+            bn,
+            sub_interval,
+            pto,
+            bis_spacing,
+            irc,
+            max_pdu,
+            rfu,
+            seed_access_address,
+            sdu_interval,
+            max_sdu,
+            base_crc_init,
+            ch_m,
+            phy,
+            bis_payload_count,
+            framing,
+            giv,
+            gskd,
+        })
+    }
 }
 
 impl TryFrom<&Vec<u8>> for BigInfo {
-    type Error = String;
+    type Error = DataTypeParseError;
     /// Create [`BigInfo`] from [`Vec<u8>`].
     ///
     /// # Examples
     ///
     /// ```
     /// use ble_data_struct::data_types::{big_info::BigInfo, data_type::DataType};
+    /// use ble_data_struct::data_types::parse_error::DataTypeParseError;
     ///
     /// let length = 34;
     /// let big_offset: u16 = 1;
@@ -456,94 +1195,15 @@ impl TryFrom<&Vec<u8>> for BigInfo {
     /// let result = BigInfo::try_from(&data);
     /// assert!(result.is_err());
     /// assert_eq!(
-    ///     format!("Invalid data size :{}", data.len()),
+    ///     DataTypeParseError::InvalidLength {
+    ///         expected: 35,
+    ///         actual: data.len()
+    ///     },
     ///     result.unwrap_err()
     /// );
     /// ```
-    fn try_from(value: &Vec<u8>) -> Result<Self, String> {
-        let len = value.len();
-        if len < 34 {
-            return Err(format!("Invalid data size :{}", len).to_string());
-        }
-        let length = value[0];
-        let value1 = u16::from_le_bytes(value[2..4].try_into().unwrap());
-        let big_offset = value1 & 0b00111111_11111111;
-        let big_offset_units = value1 & 0b01000000_00000000 != 0;
-
-        let value2 = u16::from_le_bytes(value[4..6].try_into().unwrap());
-        let iso_interval =
-            ((value1 & 0b1000000000000000) >> 15) | ((value2 & 0b0000011111111111) << 1);
-        let num_bis: u8 = ((value2 & 0b1111100000000000) >> 11) as u8;
-
-        let nse = value[6] & 0b00011111;
-
-        let bn = (value[6] & 0b11100000) >> 5;
-
-        let value1 = u32::from_le_bytes(value[7..11].try_into().unwrap());
-        let sub_interval = value1 & 0b00000000_00001111_11111111_11111111;
-        let pto = ((value1 & 0b00000000_11110000_00000000_00000000) >> 20) as u8;
-
-        let value1 = u32::from_le_bytes(value[10..14].try_into().unwrap())
-            & 0b00000000_11111111_11111111_11111111;
-        let bis_spacing = value1 & 0b00001111_11111111_11111111;
-        let irc = ((value1 & 0b11110000_00000000_00000000) >> 20) as u8;
-
-        let max_pdu = value[13];
-
-        let rfu = value[14];
-
-        let seed_access_address = u32::from_le_bytes(value[15..19].try_into().unwrap());
-
-        let value1 = u32::from_le_bytes(value[19..23].try_into().unwrap())
-            & 0b00000000_11111111_11111111_11111111;
-        let sdu_interval = value1 & 0b00001111_11111111_11111111;
-        let max_sdu = (value1 >> 20) as u16 | (value[22] << 4) as u16;
-
-        let base_crc_init = u16::from_le_bytes(value[23..25].try_into().unwrap());
-
-        let value1 = u64::from_le_bytes(value[25..33].try_into().unwrap()) & 0x000000ffffffffff;
-        let ch_m = value1 & 0b00011111_11111111_11111111_11111111_11111111;
-        let phy = ((value1 & 0b11100000_00000000_00000000_00000000_00000000) >> 37) as u8;
-
-        let mut tmp = value.clone();
-        tmp.append(&mut [0u8; 3].to_vec());
-        let value1 = u64::from_le_bytes(tmp[30..38].try_into().unwrap()) & 0x000000ffffffffff;
-        let bis_payload_count = value1 & 0b01111111_11111111_11111111_11111111_11111111;
-        let framing = value1 & 0b10000000_00000000_00000000_00000000_00000000 != 0;
-
-        let (giv, gskd) = if length == 58 {
-            (
-                Some(value[35..43].try_into().unwrap()),
-                Some(value[43..59].try_into().unwrap()),
-            )
-        } else {
-            (None, None)
-        };
-        Ok(Self {
-            length,
-            big_offset,
-            big_offset_units,
-            iso_interval,
-            num_bis,
-            nse,
-            bn,
-            sub_interval,
-            pto,
-            bis_spacing,
-            irc,
-            max_pdu,
-            rfu,
-            seed_access_address,
-            sdu_interval,
-            max_sdu,
-            base_crc_init,
-            ch_m,
-            phy,
-            bis_payload_count,
-            framing,
-            giv,
-            gskd,
-        })
+    fn try_from(value: &Vec<u8>) -> Result<Self, DataTypeParseError> {
+        Self::try_from(value.as_slice())
     }
 }
 
@@ -765,6 +1425,7 @@ impl DataType for BigInfo {
     ///
     /// assert_eq!(0x2c, BigInfo::data_type());
     /// ```
+    #[inline]
     fn data_type() -> u8 {
         0x2c
     }
@@ -781,8 +1442,9 @@ impl DataType for BigInfo {
 /// assert!(is_big_info(0x2c));
 /// assert!(!is_big_info(0x00));
 /// ```
+#[inline]
 pub fn is_big_info(data_type: u8) -> bool {
-    BigInfo::data_type() == data_type
+    AdType::from_u8(data_type) == Some(AdType::BigInfo)
 }
 
 #[cfg(test)]
@@ -1030,12 +1692,15 @@ mod tests {
         assert_eq!(giv, data_type.giv);
         assert_eq!(gskd, data_type.gskd);
 
-        let mut data: Vec<u8> = vec![0u8; 33];
+        let mut data: Vec<u8> = vec![0u8; 34];
         data[0] = data.len() as u8 - 1;
         let result = BigInfo::try_from(&data);
         assert!(result.is_err());
         assert_eq!(
-            format!("Invalid data size :{}", data.len()),
+            DataTypeParseError::InvalidLength {
+                expected: 35,
+                actual: data.len()
+            },
             result.unwrap_err()
         );
     }
@@ -1166,6 +1831,227 @@ mod tests {
         assert_eq!(data, into_data);
     }
 
+    #[test]
+    fn test_builder() {
+        assert_eq!(BigInfoBuilder::new(), BigInfo::builder());
+        assert_eq!(BigInfoBuilder::new(), BigInfoBuilder::default());
+    }
+
+    #[test]
+    fn test_builder_build() {
+        let result = BigInfo::builder()
+            .big_offset(1)
+            .big_offset_units(true)
+            .iso_interval(8)
+            .num_bis(2)
+            .nse(4)
+            .bn(2)
+            .sub_interval(1000)
+            .pto(1)
+            .bis_spacing(500)
+            .irc(1)
+            .max_pdu(40)
+            .rfu(0)
+            .seed_access_address(12)
+            .sdu_interval(10000)
+            .max_sdu(40)
+            .base_crc_init(15)
+            .ch_m(16)
+            .phy(PHY_LE_2M)
+            .bis_payload_count(18)
+            .framing(true)
+            .giv([19, 0, 0, 0, 0, 0, 0, 0])
+            .gskd([20, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0])
+            .build();
+        assert_eq!(
+            Ok(BigInfo::new(
+                1,
+                true,
+                8,
+                2,
+                4,
+                2,
+                1000,
+                1,
+                500,
+                1,
+                40,
+                0,
+                12,
+                10000,
+                40,
+                15,
+                16,
+                PHY_LE_2M,
+                18,
+                true,
+                Some([19, 0, 0, 0, 0, 0, 0, 0]),
+                Some([20, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0]),
+            )),
+            result
+        );
+
+        assert!(BigInfo::builder().big_offset(0x4000).build().is_err());
+        assert!(BigInfo::builder().iso_interval(3).build().is_err());
+        assert!(BigInfo::builder().iso_interval(0x0c81).build().is_err());
+        assert!(BigInfo::builder().num_bis(0).build().is_err());
+        assert!(BigInfo::builder().num_bis(0x20).build().is_err());
+        assert!(BigInfo::builder().nse(0).build().is_err());
+        assert!(BigInfo::builder().nse(0x20).build().is_err());
+        assert!(BigInfo::builder().bn(0).build().is_err());
+        assert!(BigInfo::builder().nse(8).bn(8).build().is_err());
+        assert!(BigInfo::builder().sub_interval(0x100000).build().is_err());
+        assert!(BigInfo::builder().nse(2).pto(0x10).build().is_err());
+        assert!(BigInfo::builder().bis_spacing(0x100000).build().is_err());
+        assert!(BigInfo::builder().irc(0).build().is_err());
+        assert!(BigInfo::builder().sdu_interval(0x100000).build().is_err());
+        assert!(BigInfo::builder().max_sdu(0x1000).build().is_err());
+        assert!(BigInfo::builder().ch_m(0x20_0000_0000).build().is_err());
+        assert!(BigInfo::builder().phy(3).build().is_err());
+        assert!(BigInfo::builder()
+            .bis_payload_count(0x80_0000_0000)
+            .build()
+            .is_err());
+
+        assert!(BigInfo::builder().nse(3).bn(2).build().is_err());
+        assert!(BigInfo::builder().nse(4).bn(2).irc(3).build().is_err());
+        assert!(BigInfo::builder()
+            .nse(4)
+            .bn(2)
+            .irc(2)
+            .pto(1)
+            .build()
+            .is_err());
+        assert!(BigInfo::builder().nse(4).bn(2).irc(2).build().is_ok());
+        assert!(BigInfo::builder().giv([0; 8]).build().is_err());
+        assert!(BigInfo::builder().gskd([0; 16]).build().is_err());
+    }
+
+    #[test]
+    fn test_is_encrypted() {
+        let result = BigInfo::builder().build().unwrap();
+        assert!(!result.is_encrypted());
+
+        let mut result = BigInfo::builder()
+            .giv([0; 8])
+            .gskd([0; 16])
+            .build()
+            .unwrap();
+        assert!(result.is_encrypted());
+        result.gskd = None;
+        assert!(!result.is_encrypted());
+    }
+
+    #[cfg(feature = "crypto")]
+    #[test]
+    fn test_decryptable() {
+        use crate::data_types::broadcast_code::BroadcastCode;
+
+        let big_info = BigInfo::builder()
+            .giv([0; 8])
+            .gskd([
+                0x00, 0x01, 0x02, 0x03, 0x04, 0x05, 0x06, 0x07, 0x08, 0x09, 0x0a, 0x0b, 0x0c, 0x0d,
+                0x0e, 0x0f,
+            ])
+            .build()
+            .unwrap();
+        let broadcast_code = BroadcastCode::new(&"1234".as_bytes().to_vec());
+        let result = big_info.decryptable(&broadcast_code);
+        assert!(result.is_ok());
+        let decryptable = result.unwrap();
+        assert_eq!(big_info, decryptable.big_info);
+        assert_eq!(0xb4ead03c97f83cef183ec06f5602d394u128, decryptable.gltk);
+        assert_eq!(0x867901d6c63b8c4b67437e9331e41549u128, decryptable.gsk);
+
+        let broadcast_code = BroadcastCode::new(&[0u8; 17].to_vec());
+        assert!(big_info.decryptable(&broadcast_code).is_err());
+
+        let broadcast_code = BroadcastCode::new(&"1234".as_bytes().to_vec());
+        let big_info = BigInfo::builder().build().unwrap();
+        assert!(big_info.decryptable(&broadcast_code).is_err());
+    }
+
+    #[test]
+    fn test_iso_interval_millis() {
+        let result = BigInfo::new(
+            0, false, 8, 2, 4, 2, 1000, 0, 500, 2, 40, 0, 0, 10000, 40, 0, 0, 1, 0, false, None,
+            None,
+        );
+        assert_eq!(8.0 * ISO_INTERVAL_VALUE, result.iso_interval_millis());
+    }
+
+    #[test]
+    fn test_max_pdu_transmission_time() {
+        let mut result = BigInfo::new(
+            0, false, 8, 2, 4, 2, 1000, 0, 500, 2, 40, 0, 0, 10000, 40, 0, 0, PHY_LE_1M, 0, false,
+            None, None,
+        );
+        assert_eq!(Some(400), result.max_pdu_transmission_time());
+        result.phy = PHY_LE_2M;
+        assert_eq!(Some(204), result.max_pdu_transmission_time());
+        result.phy = PHY_LE_CODED;
+        assert_eq!(Some(3280), result.max_pdu_transmission_time());
+        result.phy = 7;
+        assert_eq!(None, result.max_pdu_transmission_time());
+
+        result.phy = PHY_LE_1M;
+        result.giv = Some([0; 8]);
+        result.gskd = Some([0; 16]);
+        assert_eq!(Some(432), result.max_pdu_transmission_time());
+    }
+
+    #[test]
+    fn test_big_sync_delay() {
+        let mut result = BigInfo::new(
+            0, false, 8, 2, 4, 2, 1000, 0, 500, 2, 40, 0, 0, 10000, 40, 0, 0, PHY_LE_2M, 0, false,
+            None, None,
+        );
+        assert_eq!(Some(3704), result.big_sync_delay());
+        result.phy = 7;
+        assert_eq!(None, result.big_sync_delay());
+    }
+
+    #[test]
+    fn test_subevent_schedule() {
+        let result = BigInfo::new(
+            0, false, 8, 2, 4, 2, 1000, 0, 500, 2, 40, 0, 0, 10000, 40, 0, 0, PHY_LE_2M, 0, false,
+            None, None,
+        );
+        assert_eq!(
+            vec![vec![0, 1000, 2000, 3000], vec![500, 1500, 2500, 3500]],
+            result.subevent_schedule()
+        );
+
+        let result = BigInfo::new(
+            0, false, 8, 0, 4, 2, 1000, 0, 500, 2, 40, 0, 0, 10000, 40, 0, 0, PHY_LE_2M, 0, false,
+            None, None,
+        );
+        assert!(result.subevent_schedule().is_empty());
+    }
+
+    #[test]
+    fn test_transport_latency() {
+        let mut result = BigInfo::new(
+            0, false, 8, 2, 6, 2, 1000, 1, 500, 2, 40, 0, 0, 10000, 40, 0, 0, PHY_LE_2M, 0, false,
+            None, None,
+        );
+        assert_eq!(
+            Some(5704 + 10000 + 10000 - 10000),
+            result.transport_latency()
+        );
+        result.framing = true;
+        assert_eq!(
+            Some(5704 + 10000 + 10000 + 10000),
+            result.transport_latency()
+        );
+
+        result.bn = 0;
+        assert_eq!(None, result.transport_latency());
+        result.bn = 2;
+        result.irc = 4;
+        assert_eq!(None, result.transport_latency());
+    }
+
     #[test]
     fn test_data_type() {
         assert_eq!(0x2c, BigInfo::data_type());
@@ -1,9 +1,10 @@
 //! Broadcast_Code (Data Type Value: 0x2d) module.
 
-use crate::data_types::data_type::DataType;
+use crate::data_types::{ad_type::AdType, data_type::DataType, parse_error::DataTypeParseError};
 
 /// Broadcast_Code.
 #[derive(Debug, PartialEq, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct BroadcastCode {
     /// data length
     pub length: u8,
@@ -47,14 +48,34 @@ impl BroadcastCode {
     }
 }
 
+impl TryFrom<&[u8]> for BroadcastCode {
+    type Error = DataTypeParseError;
+    /// Create [`BroadcastCode`] from `&[u8]`.
+    fn try_from(value: &[u8]) -> Result<Self, Self::Error> {
+        let len = value.len();
+        if len < 6 {
+            return Err(DataTypeParseError::InvalidLength {
+                expected: 6,
+                actual: len,
+            });
+        }
+        let length = value[0];
+        Ok(Self {
+            length,
+            broadcast_code: value[2..1 + length as usize].to_vec(),
+        })
+    }
+}
+
 impl TryFrom<&Vec<u8>> for BroadcastCode {
-    type Error = String;
+    type Error = DataTypeParseError;
     /// Create [`BroadcastCode`] from [`Vec<u8>`].
     ///
     /// # Examples
     ///
     /// ```
     /// use ble_data_struct::data_types::{broadcast_code::BroadcastCode, data_type::DataType};
+    /// use ble_data_struct::data_types::parse_error::DataTypeParseError;
     ///
     /// let broadcast_code = [0x00u8; 4].to_vec();
     /// let length = broadcast_code.len() as u8 + 1;
@@ -103,20 +124,15 @@ impl TryFrom<&Vec<u8>> for BroadcastCode {
     /// let result = BroadcastCode::try_from(&data);
     /// assert!(result.is_err());
     /// assert_eq!(
-    ///     format!("Invalid data size :{}", data.len()),
+    ///     DataTypeParseError::InvalidLength {
+    ///         expected: 6,
+    ///         actual: data.len()
+    ///     },
     ///     result.unwrap_err()
     /// );
     /// ```
     fn try_from(value: &Vec<u8>) -> Result<Self, Self::Error> {
-        let len = value.len();
-        if len < 6 {
-            return Err(format!("Invalid data size :{}", len).to_string());
-        }
-        let length = value[0];
-        Ok(Self {
-            length,
-            broadcast_code: value[2..1 + length as usize].to_vec(),
-        })
+        Self::try_from(value.as_slice())
     }
 }
 
@@ -205,6 +221,7 @@ impl DataType for BroadcastCode {
     ///
     /// assert_eq!(0x2d, BroadcastCode::data_type());
     /// ```
+    #[inline]
     fn data_type() -> u8 {
         0x2d
     }
@@ -221,8 +238,9 @@ impl DataType for BroadcastCode {
 /// assert!(is_broadcast_code(0x2d));
 /// assert!(!is_broadcast_code(0x00));
 /// ```
+#[inline]
 pub fn is_broadcast_code(data_type: u8) -> bool {
-    BroadcastCode::data_type() == data_type
+    AdType::from_u8(data_type) == Some(AdType::BroadcastCode)
 }
 
 #[cfg(test)]
@@ -302,7 +320,10 @@ mod tests {
         let result = BroadcastCode::try_from(&data);
         assert!(result.is_err());
         assert_eq!(
-            format!("Invalid data size :{}", data.len()),
+            DataTypeParseError::InvalidLength {
+                expected: 6,
+                actual: data.len()
+            },
             result.unwrap_err()
         );
     }
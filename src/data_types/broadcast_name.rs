@@ -0,0 +1,315 @@
+//! Broadcast_Name (Data Type Value: 0x30) module.
+
+use crate::data_types::{
+    ad_type::AdType, data_type::DataType, local_name, parse_error::DataTypeParseError,
+};
+
+/// Broadcast_Name minimum size(octets).
+pub const BROADCAST_NAME_MIN_SIZE: usize = 4;
+
+/// Broadcast_Name maximum size(octets).
+pub const BROADCAST_NAME_MAX_SIZE: usize = 32;
+
+/// Broadcast_Name.
+#[derive(Debug, PartialEq, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct BroadcastName {
+    /// data length
+    pub length: u8,
+
+    /// Broadcast_Name
+    pub broadcast_name: String,
+}
+
+impl BroadcastName {
+    /// Create [`BroadcastName`] from `utf8`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ble_data_struct::data_types::broadcast_name::BroadcastName;
+    ///
+    /// let name = "broadcast_name".to_string();
+    /// let result = BroadcastName::new(&name);
+    /// assert_eq!(name.len() as u8 + 1, result.length);
+    /// assert_eq!(name, result.broadcast_name);
+    /// ```
+    pub fn new(broadcast_name: &String) -> Self {
+        Self {
+            length: broadcast_name.len() as u8 + 1,
+            broadcast_name: broadcast_name.to_string(),
+        }
+    }
+
+    /// Returns the name normalized to NFC without control characters.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ble_data_struct::data_types::broadcast_name::BroadcastName;
+    ///
+    /// let name = "e\u{301}\r\nname".to_string();
+    /// let result = BroadcastName::new(&name);
+    /// assert_eq!("\u{e9}name", result.sanitized_name());
+    /// ```
+    pub fn sanitized_name(&self) -> String {
+        local_name::sanitize(&self.broadcast_name)
+    }
+
+    /// Returns the name with control characters escaped for display.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ble_data_struct::data_types::broadcast_name::BroadcastName;
+    ///
+    /// let name = "na\r\nme".to_string();
+    /// let result = BroadcastName::new(&name);
+    /// assert_eq!("na\\u{d}\\u{a}me", result.escaped_name());
+    /// ```
+    pub fn escaped_name(&self) -> String {
+        local_name::escape(&self.broadcast_name)
+    }
+}
+
+impl TryFrom<&[u8]> for BroadcastName {
+    type Error = DataTypeParseError;
+    /// Create [`BroadcastName`] from `&[u8]`.
+    fn try_from(value: &[u8]) -> Result<Self, DataTypeParseError> {
+        let len = value.len();
+        if len < 3 {
+            return Err(DataTypeParseError::InvalidLength {
+                expected: 3,
+                actual: len,
+            });
+        }
+        let length = value[0];
+        if len < 1 + usize::from(length) {
+            return Err(DataTypeParseError::InvalidLength {
+                expected: 1 + usize::from(length),
+                actual: len,
+            });
+        }
+        Ok(Self {
+            length,
+            broadcast_name: String::from_utf8(value[2..1 + usize::from(length)].to_vec()).map_err(
+                |e| DataTypeParseError::InvalidUtf8 {
+                    valid_up_to: e.utf8_error().valid_up_to(),
+                },
+            )?,
+        })
+    }
+}
+
+impl TryFrom<&Vec<u8>> for BroadcastName {
+    type Error = DataTypeParseError;
+    /// Create [`BroadcastName`] from [`Vec<u8>`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ble_data_struct::data_types::{broadcast_name::BroadcastName, data_type::DataType};
+    /// use ble_data_struct::data_types::parse_error::DataTypeParseError;
+    ///
+    /// let name = "broadcast_name".to_string();
+    /// let length = name.len() as u8 + 1;
+    /// let mut data: Vec<u8> = Vec::new();
+    /// data.push(length);
+    /// data.push(BroadcastName::data_type());
+    /// data.append(&mut name.to_string().into_bytes());
+    ///
+    /// let result = BroadcastName::try_from(&data);
+    /// assert!(result.is_ok());
+    /// let data_type = result.unwrap();
+    /// assert_eq!(length, data_type.length);
+    /// assert_eq!(name, data_type.broadcast_name);
+    ///
+    /// let data: Vec<u8> = vec![3, BroadcastName::data_type(), 0x61, 0xff];
+    /// let result = BroadcastName::try_from(&data);
+    /// assert_eq!(Err(DataTypeParseError::InvalidUtf8 { valid_up_to: 1 }), result);
+    ///
+    /// let data: Vec<u8> = Vec::new();
+    /// let result = BroadcastName::try_from(&data);
+    /// assert!(result.is_err());
+    /// assert_eq!(
+    ///     DataTypeParseError::InvalidLength {
+    ///         expected: 3,
+    ///         actual: data.len()
+    ///     },
+    ///     result.unwrap_err()
+    /// );
+    /// ```
+    fn try_from(value: &Vec<u8>) -> Result<Self, DataTypeParseError> {
+        Self::try_from(value.as_slice())
+    }
+}
+
+impl Into<Vec<u8>> for BroadcastName {
+    /// Create[`Vec<u8>`] from [`BroadcastName`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ble_data_struct::data_types::{broadcast_name::BroadcastName, data_type::DataType};
+    ///
+    /// let name = "broadcast_name".to_string();
+    /// let result1 = BroadcastName::new(&name);
+    ///
+    /// let mut data: Vec<u8> = Vec::new();
+    /// data.push(name.len() as u8 + 1);
+    /// data.push(BroadcastName::data_type());
+    /// data.append(&mut name.to_string().into_bytes());
+    ///
+    /// let into_data: Vec<u8> = result1.into();
+    /// assert_eq!(data, into_data);
+    ///
+    /// let result2 = BroadcastName::try_from(&data);
+    /// assert!(result2.is_ok());
+    /// let data_type = result2.unwrap();
+    /// let into_data: Vec<u8> = data_type.into();
+    /// assert_eq!(data, into_data);
+    /// ```
+    fn into(self) -> Vec<u8> {
+        let mut data: Vec<u8> = Vec::new();
+        data.push(self.length);
+        data.push(Self::data_type());
+        data.append(&mut self.broadcast_name.clone().into_bytes());
+        return data;
+    }
+}
+
+impl DataType for BroadcastName {
+    /// return `0x30`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ble_data_struct::data_types::{broadcast_name::BroadcastName, data_type::DataType};
+    ///
+    /// assert_eq!(0x30, BroadcastName::data_type());
+    /// ```
+    #[inline]
+    fn data_type() -> u8 {
+        0x30
+    }
+}
+
+/// check `Broadcast_Name` data type.
+///
+/// # Examples
+///
+/// ```
+/// use ble_data_struct::data_types::broadcast_name::*;
+/// use ble_data_struct::data_types::data_type::DataType;
+///
+/// assert!(is_broadcast_name(0x30));
+/// assert!(!is_broadcast_name(0x00));
+/// ```
+#[inline]
+pub fn is_broadcast_name(data_type: u8) -> bool {
+    AdType::from_u8(data_type) == Some(AdType::BroadcastName)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::data_types::{broadcast_name::*, data_type::DataType};
+
+    #[test]
+    fn test_new() {
+        let name = "broadcast_name".to_string();
+        let result = BroadcastName::new(&name);
+        assert_eq!(name.len() as u8 + 1, result.length);
+        assert_eq!(name, result.broadcast_name);
+    }
+
+    #[test]
+    fn test_sanitized_name() {
+        let name = "e\u{301}\r\nname".to_string();
+        let result = BroadcastName::new(&name);
+        assert_eq!("\u{e9}name", result.sanitized_name());
+    }
+
+    #[test]
+    fn test_escaped_name() {
+        let name = "na\r\nme".to_string();
+        let result = BroadcastName::new(&name);
+        assert_eq!("na\\u{d}\\u{a}me", result.escaped_name());
+    }
+
+    #[test]
+    fn test_try_from() {
+        let name = "broadcast_name".to_string();
+        let length = name.len() as u8 + 1;
+        let mut data: Vec<u8> = Vec::new();
+        data.push(length);
+        data.push(BroadcastName::data_type());
+        data.append(&mut name.to_string().into_bytes());
+
+        let result = BroadcastName::try_from(&data);
+        assert!(result.is_ok());
+        let data_type = result.unwrap();
+        assert_eq!(length, data_type.length);
+        assert_eq!(name, data_type.broadcast_name);
+
+        let data: Vec<u8> = vec![5, BroadcastName::data_type(), 0x61, 0x62, 0xe3, 0x81];
+        let result = BroadcastName::try_from(&data);
+        assert_eq!(
+            Err(DataTypeParseError::InvalidUtf8 { valid_up_to: 2 }),
+            result
+        );
+
+        let mut data: Vec<u8> = vec![0u8; 2];
+        data[0] = data.len() as u8 - 1;
+        let result = BroadcastName::try_from(&data);
+        assert!(result.is_err());
+        assert_eq!(
+            DataTypeParseError::InvalidLength {
+                expected: 3,
+                actual: data.len()
+            },
+            result.unwrap_err()
+        );
+
+        let data: Vec<u8> = vec![5, BroadcastName::data_type(), 0x61];
+        let result = BroadcastName::try_from(&data);
+        assert!(result.is_err());
+        assert_eq!(
+            DataTypeParseError::InvalidLength {
+                expected: 6,
+                actual: data.len()
+            },
+            result.unwrap_err()
+        );
+    }
+
+    #[test]
+    fn test_into() {
+        let name = "broadcast_name".to_string();
+        let result1 = BroadcastName::new(&name);
+
+        let mut data: Vec<u8> = Vec::new();
+        data.push(name.len() as u8 + 1);
+        data.push(BroadcastName::data_type());
+        data.append(&mut name.to_string().into_bytes());
+
+        let into_data: Vec<u8> = result1.into();
+        assert_eq!(data, into_data);
+
+        let result2 = BroadcastName::try_from(&data);
+        assert!(result2.is_ok());
+        let data_type = result2.unwrap();
+        let into_data: Vec<u8> = data_type.into();
+        assert_eq!(data, into_data);
+    }
+
+    #[test]
+    fn test_data_type() {
+        assert_eq!(0x30, BroadcastName::data_type());
+    }
+
+    #[test]
+    fn test_is_broadcast_name() {
+        assert!(is_broadcast_name(0x30));
+        assert!(!is_broadcast_name(0x00));
+    }
+}
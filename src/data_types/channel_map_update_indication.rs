@@ -1,9 +1,10 @@
 //! Channel Map Update Indication (Data Type Value: 0x28) module.
 
-use crate::data_types::data_type::DataType;
+use crate::data_types::{ad_type::AdType, data_type::DataType, parse_error::DataTypeParseError};
 
 /// Channel Map Update Indication.
 #[derive(Debug, PartialEq, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct ChannelMapUpdateIndication {
     /// data length
     pub length: u8,
@@ -42,14 +43,56 @@ impl ChannelMapUpdateIndication {
     }
 }
 
+impl TryFrom<&[u8]> for ChannelMapUpdateIndication {
+    type Error = DataTypeParseError;
+    /// Create [`ChannelMapUpdateIndication`] from `&[u8]`.
+    fn try_from(value: &[u8]) -> Result<Self, DataTypeParseError> {
+        let len = value.len();
+        if len < 9 {
+            return Err(DataTypeParseError::InvalidLength {
+                expected: 9,
+                actual: len,
+            });
+        }
+        let length = value[0];
+        if length != 8 {
+            return Err(DataTypeParseError::InvalidLength {
+                expected: 9,
+                actual: 1 + length as usize,
+            });
+        }
+        let ch_m: Vec<bool> = value[2..length as usize - 1]
+            .iter()
+            .flat_map(|x| {
+                let mut data: Vec<bool> = Vec::new();
+                data.push(x & 0b0000_0001 != 0);
+                data.push(x & 0b0000_0010 != 0);
+                data.push(x & 0b0000_0100 != 0);
+                data.push(x & 0b0000_1000 != 0);
+                data.push(x & 0b0001_0000 != 0);
+                data.push(x & 0b0010_0000 != 0);
+                data.push(x & 0b0100_0000 != 0);
+                data.push(x & 0b1000_0000 != 0);
+                data
+            })
+            .collect();
+        Ok(Self {
+            length,
+            ch_m: ch_m.to_vec(),
+            instant: u16::from_le_bytes(value[7..9].try_into().unwrap()),
+        })
+    }
+}
+
 impl TryFrom<&Vec<u8>> for ChannelMapUpdateIndication {
-    type Error = String;
+    type Error = DataTypeParseError;
     /// Create [`ChannelMapUpdateIndication`] from [`Vec<u8>`].
     ///
     /// # Examples
     ///
     /// ```
     /// use ble_data_struct::data_types::{channel_map_update_indication::ChannelMapUpdateIndication, data_type::DataType};
+    /// use ble_data_struct::data_types::parse_error::DataTypeParseError;
     ///
     /// let mut ch_m = [0u8; 5].to_vec();
     ///
@@ -95,36 +138,15 @@ impl TryFrom<&Vec<u8>> for ChannelMapUpdateIndication {
     /// let result = ChannelMapUpdateIndication::try_from(&data);
     /// assert!(result.is_err());
     /// assert_eq!(
-    ///     format!("Invalid data size :{}", data.len()),
+    ///     DataTypeParseError::InvalidLength {
+    ///         expected: 9,
+    ///         actual: data.len()
+    ///     },
     ///     result.unwrap_err()
     /// );
     /// ```
-    fn try_from(value: &Vec<u8>) -> Result<Self, String> {
-        let len = value.len();
-        if len < 9 {
-            return Err(format!("Invalid data size :{}", len).to_string());
-        }
-        let length = value[0];
-        let ch_m: Vec<bool> = value[2..length as usize - 1]
-            .iter()
-            .flat_map(|x| {
-                let mut data: Vec<bool> = Vec::new();
-                data.push(x & 0b0000_0001 != 0);
-                data.push(x & 0b0000_0010 != 0);
-                data.push(x & 0b0000_0100 != 0);
-                data.push(x & 0b0000_1000 != 0);
-                data.push(x & 0b0001_0000 != 0);
-                data.push(x & 0b0010_0000 != 0);
-                data.push(x & 0b0100_0000 != 0);
-                data.push(x & 0b1000_0000 != 0);
-                data
-            })
-            .collect();
-        Ok(Self {
-            length,
-            ch_m: ch_m.to_vec(),
-            instant: u16::from_le_bytes(value[7..9].try_into().unwrap()),
-        })
+    fn try_from(value: &Vec<u8>) -> Result<Self, DataTypeParseError> {
+        Self::try_from(value.as_slice())
     }
 }
 
@@ -191,6 +213,7 @@ impl DataType for ChannelMapUpdateIndication {
     ///
     /// assert_eq!(0x28, ChannelMapUpdateIndication::data_type());
     /// ```
+    #[inline]
     fn data_type() -> u8 {
         0x28
     }
@@ -207,8 +230,9 @@ impl DataType for ChannelMapUpdateIndication {
 /// assert!(is_channel_map_update_indication(0x28));
 /// assert!(!is_channel_map_update_indication(0x00));
 /// ```
+#[inline]
 pub fn is_channel_map_update_indication(data_type: u8) -> bool {
-    ChannelMapUpdateIndication::data_type() == data_type
+    AdType::from_u8(data_type) == Some(AdType::ChannelMapUpdateIndication)
 }
 
 #[cfg(test)]
@@ -273,9 +297,23 @@ mod tests {
         let result = ChannelMapUpdateIndication::try_from(&data);
         assert!(result.is_err());
         assert_eq!(
-            format!("Invalid data size :{}", data.len()),
+            DataTypeParseError::InvalidLength {
+                expected: 9,
+                actual: data.len()
+            },
             result.unwrap_err()
         );
+
+        let mut data: Vec<u8> = vec![0u8; 11];
+        data[0] = data.len() as u8 - 1;
+        data[1] = ChannelMapUpdateIndication::data_type();
+        assert_eq!(
+            Err(DataTypeParseError::InvalidLength {
+                expected: 9,
+                actual: data.len()
+            }),
+            ChannelMapUpdateIndication::try_from(&data)
+        );
     }
 
     #[test]
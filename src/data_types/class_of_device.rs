@@ -1,9 +1,10 @@
 //! Class of Device (Data Type Value: 0x0d) module.
 
-use crate::data_types::data_type::DataType;
+use crate::data_types::{ad_type::AdType, data_type::DataType, parse_error::DataTypeParseError};
 
 /// Class of Device.
 #[derive(Debug, PartialEq, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct ClassOfDevice {
     /// data length
     pub length: u8,
@@ -106,14 +107,36 @@ pub const CLASS_OF_DEVICE_MAJOR_DEVICE_CLASS_MASK: u32 = 0b00000000_00011111_000
 /// Minor Device Class mask
 pub const CLASS_OF_DEVICE_MINOR_DEVICE_CLASS_MASK: u32 = 0b00000000_00000000_11111100;
 
+impl TryFrom<&[u8]> for ClassOfDevice {
+    type Error = DataTypeParseError;
+    /// Create [`ClassOfDevice`] from `&[u8]`.
+    fn try_from(value: &[u8]) -> Result<Self, DataTypeParseError> {
+        let len = value.len();
+        if len < 5 {
+            return Err(DataTypeParseError::InvalidLength {
+                expected: 5,
+                actual: len,
+            });
+        }
+        let mut value = value.to_vec();
+        let length = value[0];
+        value.push(0);
+        Ok(Self {
+            length,
+            class_of_device: u32::from_le_bytes(value[2..6].try_into().unwrap()) & 0x00ffffff,
+        })
+    }
+}
+
 impl TryFrom<&Vec<u8>> for ClassOfDevice {
-    type Error = String;
+    type Error = DataTypeParseError;
     /// Create [`ClassOfDevice`] from [`Vec<u8>`].
     ///
     /// # Examples
     ///
     /// ```
     /// use ble_data_struct::data_types::{class_of_device::ClassOfDevice, data_type::DataType};
+    /// use ble_data_struct::data_types::parse_error::DataTypeParseError;
     ///
     /// let major_service_classes = 0b10000000_00000000_00000000;
     /// let major_device_class = 0b00000000_00000001_00000000;
@@ -137,22 +160,15 @@ impl TryFrom<&Vec<u8>> for ClassOfDevice {
     /// let result = ClassOfDevice::try_from(&data);
     /// assert!(result.is_err());
     /// assert_eq!(
-    ///     format!("Invalid data size :{}", data.len()),
+    ///     DataTypeParseError::InvalidLength {
+    ///         expected: 5,
+    ///         actual: data.len()
+    ///     },
     ///     result.unwrap_err()
     /// );
     /// ```
-    fn try_from(value: &Vec<u8>) -> Result<Self, String> {
-        let len = value.len();
-        if len < 5 {
-            return Err(format!("Invalid data size :{}", len).to_string());
-        }
-        let mut value = value.to_vec();
-        let length = value[0];
-        value.push(0);
-        Ok(Self {
-            length,
-            class_of_device: u32::from_le_bytes(value[2..6].try_into().unwrap()) & 0x00ffffff,
-        })
+    fn try_from(value: &Vec<u8>) -> Result<Self, DataTypeParseError> {
+        Self::try_from(value.as_slice())
     }
 }
 
@@ -207,6 +223,7 @@ impl DataType for ClassOfDevice {
     ///
     /// assert_eq!(0x0d, ClassOfDevice::data_type());
     /// ```
+    #[inline]
     fn data_type() -> u8 {
         0x0d
     }
@@ -223,8 +240,9 @@ impl DataType for ClassOfDevice {
 /// assert!(is_class_of_device(0x0d));
 /// assert!(!is_class_of_device(0x00));
 /// ```
+#[inline]
 pub fn is_class_of_device(data_type: u8) -> bool {
-    ClassOfDevice::data_type() == data_type
+    AdType::from_u8(data_type) == Some(AdType::ClassOfDevice)
 }
 
 #[cfg(test)]
@@ -303,7 +321,10 @@ mod tests {
         let result = ClassOfDevice::try_from(&data);
         assert!(result.is_err());
         assert_eq!(
-            format!("Invalid data size :{}", data.len()),
+            DataTypeParseError::InvalidLength {
+                expected: 5,
+                actual: data.len()
+            },
             result.unwrap_err()
         );
     }
@@ -1,9 +1,12 @@
 //! Complete Local Name (Data Type Value: 0x09) module.
 
-use crate::data_types::data_type::DataType;
+use crate::data_types::{
+    ad_type::AdType, data_type::DataType, local_name, parse_error::DataTypeParseError,
+};
 
 /// Complete Local Name.
 #[derive(Debug, PartialEq, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct CompleteLocalName {
     /// data length
     pub length: u8,
@@ -31,16 +34,75 @@ impl CompleteLocalName {
             complete_local_name: complete_local_name.to_string(),
         }
     }
+
+    /// Returns the name normalized to NFC without control characters.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ble_data_struct::data_types::complete_local_name::CompleteLocalName;
+    ///
+    /// let name = "e\u{301}\r\nname".to_string();
+    /// let result = CompleteLocalName::new(&name);
+    /// assert_eq!("\u{e9}name", result.sanitized_name());
+    /// ```
+    pub fn sanitized_name(&self) -> String {
+        local_name::sanitize(&self.complete_local_name)
+    }
+
+    /// Returns the name with control characters escaped for display.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ble_data_struct::data_types::complete_local_name::CompleteLocalName;
+    ///
+    /// let name = "na\r\nme".to_string();
+    /// let result = CompleteLocalName::new(&name);
+    /// assert_eq!("na\\u{d}\\u{a}me", result.escaped_name());
+    /// ```
+    pub fn escaped_name(&self) -> String {
+        local_name::escape(&self.complete_local_name)
+    }
+}
+
+impl TryFrom<&[u8]> for CompleteLocalName {
+    type Error = DataTypeParseError;
+    /// Create [`CompleteLocalName`] from `&[u8]`.
+    fn try_from(value: &[u8]) -> Result<Self, DataTypeParseError> {
+        let len = value.len();
+        if len < 3 {
+            return Err(DataTypeParseError::InvalidLength {
+                expected: 3,
+                actual: len,
+            });
+        }
+        let length = value[0];
+        if len < 1 + usize::from(length) {
+            return Err(DataTypeParseError::InvalidLength {
+                expected: 1 + usize::from(length),
+                actual: len,
+            });
+        }
+        Ok(Self {
+            length,
+            complete_local_name: String::from_utf8(value[2..1 + usize::from(length)].to_vec())
+                .map_err(|e| DataTypeParseError::InvalidUtf8 {
+                    valid_up_to: e.utf8_error().valid_up_to(),
+                })?,
+        })
+    }
 }
 
 impl TryFrom<&Vec<u8>> for CompleteLocalName {
-    type Error = String;
+    type Error = DataTypeParseError;
     /// Create [`CompleteLocalName`] from [`Vec<u8>`].
     ///
     /// # Examples
     ///
     /// ```
     /// use ble_data_struct::data_types::{complete_local_name::CompleteLocalName, data_type::DataType};
+    /// use ble_data_struct::data_types::parse_error::DataTypeParseError;
     ///
     /// let name = "complete_local_name".to_string();
     /// let length = name.as_bytes().len() as u8 + 1;
@@ -59,21 +121,15 @@ impl TryFrom<&Vec<u8>> for CompleteLocalName {
     /// let result = CompleteLocalName::try_from(&data);
     /// assert!(result.is_err());
     /// assert_eq!(
-    ///     format!("Invalid data size :{}", data.len()),
+    ///     DataTypeParseError::InvalidLength {
+    ///         expected: 3,
+    ///         actual: data.len()
+    ///     },
     ///     result.unwrap_err()
     /// );
     /// ```
-    fn try_from(value: &Vec<u8>) -> Result<Self, String> {
-        let len = value.len();
-        if len < 3 {
-            return Err(format!("Invalid data size :{}", len).to_string());
-        }
-        let length = value[0];
-        Ok(Self {
-            length,
-            complete_local_name: String::from_utf8(value[2..1 + usize::from(length)].to_vec())
-                .unwrap(),
-        })
+    fn try_from(value: &Vec<u8>) -> Result<Self, DataTypeParseError> {
+        Self::try_from(value.as_slice())
     }
 }
 
@@ -121,6 +177,7 @@ impl DataType for CompleteLocalName {
     ///
     /// assert_eq!(0x09, CompleteLocalName::data_type());
     /// ```
+    #[inline]
     fn data_type() -> u8 {
         0x09
     }
@@ -137,8 +194,9 @@ impl DataType for CompleteLocalName {
 /// assert!(is_complete_local_name(0x09));
 /// assert!(!is_complete_local_name(0x00));
 /// ```
+#[inline]
 pub fn is_complete_local_name(data_type: u8) -> bool {
-    CompleteLocalName::data_type() == data_type
+    AdType::from_u8(data_type) == Some(AdType::CompleteLocalName)
 }
 
 #[cfg(test)]
@@ -153,6 +211,20 @@ mod tests {
         assert_eq!(name, result.complete_local_name);
     }
 
+    #[test]
+    fn test_sanitized_name() {
+        let name = "e\u{301}\r\nname".to_string();
+        let result = CompleteLocalName::new(&name);
+        assert_eq!("\u{e9}name", result.sanitized_name());
+    }
+
+    #[test]
+    fn test_escaped_name() {
+        let name = "na\r\nme".to_string();
+        let result = CompleteLocalName::new(&name);
+        assert_eq!("na\\u{d}\\u{a}me", result.escaped_name());
+    }
+
     #[test]
     fn test_try_from() {
         let name = "complete_local_name".to_string();
@@ -168,12 +240,28 @@ mod tests {
         assert_eq!(length, data_type.length);
         assert_eq!(name, data_type.complete_local_name);
 
+        let data = vec![0x03, CompleteLocalName::data_type(), 0x61, 0xff];
+        assert_eq!(
+            Err(DataTypeParseError::InvalidUtf8 { valid_up_to: 1 }),
+            CompleteLocalName::try_from(&data)
+        );
+        assert_eq!(
+            Err(DataTypeParseError::InvalidLength {
+                expected: 4,
+                actual: 3
+            }),
+            CompleteLocalName::try_from(&data[..3])
+        );
+
         let mut data: Vec<u8> = vec![0u8; 2];
         data[0] = data.len() as u8 - 1;
         let result = CompleteLocalName::try_from(&data);
         assert!(result.is_err());
         assert_eq!(
-            format!("Invalid data size :{}", data.len()),
+            DataTypeParseError::InvalidLength {
+                expected: 3,
+                actual: data.len()
+            },
             result.unwrap_err()
         );
     }
@@ -0,0 +1,337 @@
+//! Connection parameter validation module.
+//!
+//! Shared by [`PeripheralConnectionIntervalRange`](crate::data_types::peripheral_connection_interval_range::PeripheralConnectionIntervalRange),
+//! [`PreferredConnectionParameters`](crate::characteristics::peripheral_preferred_connection_parameters::PreferredConnectionParameters)
+//! and [`ConnectionParameterUpdateRequest`](crate::l2cap::connection_parameter_update::ConnectionParameterUpdateRequest).
+
+use crate::data_types::peripheral_connection_interval_range::{
+    CONNECTION_INTERVAL_MAXIMUM, CONNECTION_INTERVAL_MINIMUM, CONNECTION_INTERVAL_NO_SPECIFIC_VALUE,
+};
+
+/// Maximum peripheral latency value
+pub const PERIPHERAL_LATENCY_MAXIMUM: u16 = 0x01f3;
+
+/// Minimum connection supervision timeout multiplier value (100 ms)
+pub const SUPERVISION_TIMEOUT_MINIMUM: u16 = 0x000a;
+
+/// Maximum connection supervision timeout multiplier value (32 s)
+pub const SUPERVISION_TIMEOUT_MAXIMUM: u16 = 0x0c80;
+
+/// no specific connection supervision timeout multiplier value
+pub const SUPERVISION_TIMEOUT_NO_SPECIFIC_VALUE: u16 = 0xffff;
+
+/// Connection parameter violation.
+#[derive(Debug, PartialEq, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum ConnectionParameterViolation {
+    /// Connection interval is out of [`CONNECTION_INTERVAL_MINIMUM`]..=[`CONNECTION_INTERVAL_MAXIMUM`].
+    IntervalOutOfRange {
+        /// Connection interval
+        interval: u16,
+    },
+
+    /// Minimum connection interval is larger than maximum connection interval.
+    IntervalOrder {
+        /// Minimum connection interval
+        interval_min: u16,
+
+        /// Maximum connection interval
+        interval_max: u16,
+    },
+
+    /// Peripheral latency is larger than [`PERIPHERAL_LATENCY_MAXIMUM`].
+    LatencyOutOfRange {
+        /// Peripheral latency
+        latency: u16,
+    },
+
+    /// Connection supervision timeout is out of [`SUPERVISION_TIMEOUT_MINIMUM`]..=[`SUPERVISION_TIMEOUT_MAXIMUM`].
+    SupervisionTimeoutOutOfRange {
+        /// Connection supervision timeout multiplier
+        supervision_timeout: u16,
+    },
+
+    /// Connection supervision timeout is not larger than `(1 + latency) * interval_max * 2`.
+    SupervisionTimeoutTooShort {
+        /// Connection supervision timeout multiplier
+        supervision_timeout: u16,
+
+        /// Peripheral latency
+        latency: u16,
+
+        /// Maximum connection interval
+        interval_max: u16,
+    },
+}
+
+/// Check connection interval range.
+///
+/// [`CONNECTION_INTERVAL_NO_SPECIFIC_VALUE`] is accepted as minimum / maximum value.
+///
+/// # Examples
+///
+/// ```
+/// use ble_data_struct::data_types::connection_parameters::{
+///     check_connection_interval_range, ConnectionParameterViolation,
+/// };
+///
+/// assert!(check_connection_interval_range(0x0006, 0x0c80).is_empty());
+/// assert_eq!(
+///     vec![ConnectionParameterViolation::IntervalOrder {
+///         interval_min: 0x0020,
+///         interval_max: 0x0010
+///     }],
+///     check_connection_interval_range(0x0020, 0x0010)
+/// );
+/// ```
+pub fn check_connection_interval_range(
+    interval_min: u16,
+    interval_max: u16,
+) -> Vec<ConnectionParameterViolation> {
+    let mut violations: Vec<ConnectionParameterViolation> = [interval_min, interval_max]
+        .into_iter()
+        .filter(|interval| {
+            *interval != CONNECTION_INTERVAL_NO_SPECIFIC_VALUE
+                && !(CONNECTION_INTERVAL_MINIMUM..=CONNECTION_INTERVAL_MAXIMUM).contains(interval)
+        })
+        .map(|interval| ConnectionParameterViolation::IntervalOutOfRange { interval })
+        .collect();
+    if interval_min != CONNECTION_INTERVAL_NO_SPECIFIC_VALUE
+        && interval_max != CONNECTION_INTERVAL_NO_SPECIFIC_VALUE
+        && interval_min > interval_max
+    {
+        violations.push(ConnectionParameterViolation::IntervalOrder {
+            interval_min,
+            interval_max,
+        });
+    }
+    violations
+}
+
+/// Check peripheral latency.
+///
+/// # Examples
+///
+/// ```
+/// use ble_data_struct::data_types::connection_parameters::{
+///     check_peripheral_latency, ConnectionParameterViolation,
+/// };
+///
+/// assert_eq!(None, check_peripheral_latency(0x01f3));
+/// assert_eq!(
+///     Some(ConnectionParameterViolation::LatencyOutOfRange { latency: 0x01f4 }),
+///     check_peripheral_latency(0x01f4)
+/// );
+/// ```
+pub fn check_peripheral_latency(latency: u16) -> Option<ConnectionParameterViolation> {
+    if latency > PERIPHERAL_LATENCY_MAXIMUM {
+        Some(ConnectionParameterViolation::LatencyOutOfRange { latency })
+    } else {
+        None
+    }
+}
+
+/// Check connection supervision timeout against peripheral latency and maximum connection interval.
+///
+/// [`SUPERVISION_TIMEOUT_NO_SPECIFIC_VALUE`] is accepted, and no specific maximum connection interval skips the timeout rule.
+///
+/// # Examples
+///
+/// ```
+/// use ble_data_struct::data_types::connection_parameters::{
+///     check_supervision_timeout, ConnectionParameterViolation,
+/// };
+///
+/// assert!(check_supervision_timeout(0x0033, 0x0004, 0x0028).is_empty());
+/// assert_eq!(
+///     vec![ConnectionParameterViolation::SupervisionTimeoutTooShort {
+///         supervision_timeout: 0x0032,
+///         latency: 0x0004,
+///         interval_max: 0x0028
+///     }],
+///     check_supervision_timeout(0x0032, 0x0004, 0x0028)
+/// );
+/// ```
+pub fn check_supervision_timeout(
+    supervision_timeout: u16,
+    latency: u16,
+    interval_max: u16,
+) -> Vec<ConnectionParameterViolation> {
+    let mut violations: Vec<ConnectionParameterViolation> = Vec::new();
+    if supervision_timeout == SUPERVISION_TIMEOUT_NO_SPECIFIC_VALUE {
+        return violations;
+    }
+    if !(SUPERVISION_TIMEOUT_MINIMUM..=SUPERVISION_TIMEOUT_MAXIMUM).contains(&supervision_timeout) {
+        violations.push(ConnectionParameterViolation::SupervisionTimeoutOutOfRange {
+            supervision_timeout,
+        });
+    }
+    // supervision timeout(10 ms) > (1 + latency) * interval_max(1.25 ms) * 2
+    if interval_max != CONNECTION_INTERVAL_NO_SPECIFIC_VALUE
+        && supervision_timeout as u32 * 4 <= (1 + latency as u32) * interval_max as u32
+    {
+        violations.push(ConnectionParameterViolation::SupervisionTimeoutTooShort {
+            supervision_timeout,
+            latency,
+            interval_max,
+        });
+    }
+    violations
+}
+
+/// Check all connection parameters.
+///
+/// # Examples
+///
+/// ```
+/// use ble_data_struct::data_types::connection_parameters::{
+///     check_connection_parameters, ConnectionParameterViolation,
+/// };
+///
+/// assert!(check_connection_parameters(0x0018, 0x0028, 0x0000, 0x01f4).is_empty());
+/// assert_eq!(
+///     vec![
+///         ConnectionParameterViolation::IntervalOutOfRange { interval: 0x0005 },
+///         ConnectionParameterViolation::LatencyOutOfRange { latency: 0x01f4 },
+///     ],
+///     check_connection_parameters(0x0005, 0x0028, 0x01f4, 0xffff)
+/// );
+/// ```
+pub fn check_connection_parameters(
+    interval_min: u16,
+    interval_max: u16,
+    latency: u16,
+    supervision_timeout: u16,
+) -> Vec<ConnectionParameterViolation> {
+    let mut violations = check_connection_interval_range(interval_min, interval_max);
+    violations.extend(check_peripheral_latency(latency));
+    violations.extend(check_supervision_timeout(
+        supervision_timeout,
+        latency,
+        interval_max,
+    ));
+    violations
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::data_types::{
+        connection_parameters::{
+            check_connection_interval_range, check_connection_parameters, check_peripheral_latency,
+            check_supervision_timeout, ConnectionParameterViolation, PERIPHERAL_LATENCY_MAXIMUM,
+            SUPERVISION_TIMEOUT_MAXIMUM, SUPERVISION_TIMEOUT_MINIMUM,
+            SUPERVISION_TIMEOUT_NO_SPECIFIC_VALUE,
+        },
+        peripheral_connection_interval_range::{
+            CONNECTION_INTERVAL_MAXIMUM, CONNECTION_INTERVAL_MINIMUM,
+            CONNECTION_INTERVAL_NO_SPECIFIC_VALUE,
+        },
+    };
+
+    #[test]
+    fn test_check_connection_interval_range() {
+        assert!(check_connection_interval_range(
+            CONNECTION_INTERVAL_MINIMUM,
+            CONNECTION_INTERVAL_MAXIMUM
+        )
+        .is_empty());
+        assert!(check_connection_interval_range(
+            CONNECTION_INTERVAL_NO_SPECIFIC_VALUE,
+            CONNECTION_INTERVAL_NO_SPECIFIC_VALUE
+        )
+        .is_empty());
+        assert!(
+            check_connection_interval_range(0x0020, CONNECTION_INTERVAL_NO_SPECIFIC_VALUE)
+                .is_empty()
+        );
+        assert_eq!(
+            vec![
+                ConnectionParameterViolation::IntervalOutOfRange {
+                    interval: CONNECTION_INTERVAL_MAXIMUM + 1
+                },
+                ConnectionParameterViolation::IntervalOutOfRange {
+                    interval: CONNECTION_INTERVAL_MINIMUM - 1
+                },
+                ConnectionParameterViolation::IntervalOrder {
+                    interval_min: CONNECTION_INTERVAL_MAXIMUM + 1,
+                    interval_max: CONNECTION_INTERVAL_MINIMUM - 1
+                },
+            ],
+            check_connection_interval_range(
+                CONNECTION_INTERVAL_MAXIMUM + 1,
+                CONNECTION_INTERVAL_MINIMUM - 1
+            )
+        );
+    }
+
+    #[test]
+    fn test_check_peripheral_latency() {
+        assert_eq!(None, check_peripheral_latency(0));
+        assert_eq!(None, check_peripheral_latency(PERIPHERAL_LATENCY_MAXIMUM));
+        assert_eq!(
+            Some(ConnectionParameterViolation::LatencyOutOfRange {
+                latency: PERIPHERAL_LATENCY_MAXIMUM + 1
+            }),
+            check_peripheral_latency(PERIPHERAL_LATENCY_MAXIMUM + 1)
+        );
+    }
+
+    #[test]
+    fn test_check_supervision_timeout() {
+        assert!(check_supervision_timeout(0x0033, 0x0004, 0x0028).is_empty());
+        assert!(
+            check_supervision_timeout(SUPERVISION_TIMEOUT_NO_SPECIFIC_VALUE, 0x0004, 0x0028)
+                .is_empty()
+        );
+        assert!(check_supervision_timeout(
+            SUPERVISION_TIMEOUT_MINIMUM,
+            0x0004,
+            CONNECTION_INTERVAL_NO_SPECIFIC_VALUE
+        )
+        .is_empty());
+        assert_eq!(
+            vec![ConnectionParameterViolation::SupervisionTimeoutTooShort {
+                supervision_timeout: 0x0032,
+                latency: 0x0004,
+                interval_max: 0x0028
+            }],
+            check_supervision_timeout(0x0032, 0x0004, 0x0028)
+        );
+        assert_eq!(
+            vec![ConnectionParameterViolation::SupervisionTimeoutOutOfRange {
+                supervision_timeout: SUPERVISION_TIMEOUT_MAXIMUM + 1
+            }],
+            check_supervision_timeout(SUPERVISION_TIMEOUT_MAXIMUM + 1, 0x0000, 0x0006)
+        );
+        assert_eq!(
+            vec![
+                ConnectionParameterViolation::SupervisionTimeoutOutOfRange {
+                    supervision_timeout: SUPERVISION_TIMEOUT_MINIMUM - 1
+                },
+                ConnectionParameterViolation::SupervisionTimeoutTooShort {
+                    supervision_timeout: SUPERVISION_TIMEOUT_MINIMUM - 1,
+                    latency: 0x0000,
+                    interval_max: 0x0c80
+                },
+            ],
+            check_supervision_timeout(SUPERVISION_TIMEOUT_MINIMUM - 1, 0x0000, 0x0c80)
+        );
+    }
+
+    #[test]
+    fn test_check_connection_parameters() {
+        assert!(check_connection_parameters(0x0018, 0x0028, 0x0000, 0x01f4).is_empty());
+        assert_eq!(
+            vec![
+                ConnectionParameterViolation::IntervalOutOfRange { interval: 0x0005 },
+                ConnectionParameterViolation::LatencyOutOfRange { latency: 0x01f4 },
+            ],
+            check_connection_parameters(
+                0x0005,
+                0x0028,
+                0x01f4,
+                SUPERVISION_TIMEOUT_NO_SPECIFIC_VALUE
+            )
+        );
+    }
+}
@@ -0,0 +1,249 @@
+//! Custom EIR/AD/SRD/ACAD/OOB structure module.
+//!
+//! Result of the handlers registered with [`DataTypeRegistry::register_handler`](crate::data_types::data_type_registry::DataTypeRegistry::register_handler).
+
+use std::{any::Any, sync::Arc};
+
+use crate::data_types::{
+    ad_structure::AdStructure, parse_error::DataTypeParseError, raw_ad_structure::RawAdStructure,
+};
+
+/// Custom data type handler.
+///
+/// Receives the whole structure (length, data type and data).
+pub type CustomDataTypeHandler = fn(&[u8]) -> Result<CustomDataType, DataTypeParseError>;
+
+/// Custom (vendor-specific / proprietary) EIR/AD/SRD/ACAD/OOB structure.
+///
+/// Keeps the raw data and an optional value decoded by the handler.
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct CustomDataType {
+    /// data type
+    pub ad_type: u8,
+
+    /// data (without length and data type)
+    pub data: Vec<u8>,
+
+    /// decoded value
+    #[cfg_attr(feature = "serde", serde(skip))]
+    value: Option<Arc<dyn Any + Send + Sync>>,
+}
+
+impl CustomDataType {
+    /// Create [`CustomDataType`] from data type and data.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ble_data_struct::data_types::custom_data_type::CustomDataType;
+    ///
+    /// let result = CustomDataType::new(0xf0, &[0x01, 0x02]);
+    /// assert_eq!(0xf0, result.ad_type);
+    /// assert_eq!(vec![0x01, 0x02], result.data);
+    /// assert!(!result.has_value());
+    /// ```
+    pub fn new(ad_type: u8, data: &[u8]) -> Self {
+        Self {
+            ad_type,
+            data: data.to_vec(),
+            value: None,
+        }
+    }
+
+    /// Set decoded value.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ble_data_struct::data_types::custom_data_type::CustomDataType;
+    ///
+    /// let result = CustomDataType::new(0xf0, &[0x01, 0x02]).with_value(0x0201u16);
+    /// assert!(result.has_value());
+    /// assert_eq!(Some(&0x0201u16), result.value::<u16>());
+    /// ```
+    pub fn with_value<T: Any + Send + Sync>(mut self, value: T) -> Self {
+        self.value = Some(Arc::new(value));
+        self
+    }
+
+    /// Get decoded value.
+    ///
+    /// Returns [`None`] if no value is set or the value is not `T`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ble_data_struct::data_types::custom_data_type::CustomDataType;
+    ///
+    /// let result = CustomDataType::new(0xf0, &[0x01]).with_value(0x01u8);
+    /// assert_eq!(Some(&0x01u8), result.value::<u8>());
+    /// assert_eq!(None, result.value::<u16>());
+    /// ```
+    pub fn value<T: Any>(&self) -> Option<&T> {
+        self.value
+            .as_deref()
+            .and_then(|value| value.downcast_ref::<T>())
+    }
+
+    /// Check decoded value is set.
+    pub fn has_value(&self) -> bool {
+        self.value.is_some()
+    }
+}
+
+impl PartialEq for CustomDataType {
+    /// Compare data type and data (the decoded value is derived from them).
+    fn eq(&self, other: &Self) -> bool {
+        self.ad_type == other.ad_type && self.data == other.data
+    }
+}
+
+impl TryFrom<&[u8]> for CustomDataType {
+    type Error = DataTypeParseError;
+    /// Create [`CustomDataType`] (without decoded value) from `&[u8]`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ble_data_struct::data_types::custom_data_type::CustomDataType;
+    /// use ble_data_struct::data_types::parse_error::DataTypeParseError;
+    ///
+    /// let data = [0x03u8, 0xf0u8, 0x01u8, 0x02u8];
+    /// let result = CustomDataType::try_from(&data[..]);
+    /// assert_eq!(Ok(CustomDataType::new(0xf0, &[0x01, 0x02])), result);
+    ///
+    /// let result = CustomDataType::try_from(&data[..3]);
+    /// assert_eq!(
+    ///     Err(DataTypeParseError::InvalidLength {
+    ///         expected: 4,
+    ///         actual: 3
+    ///     }),
+    ///     result
+    /// );
+    /// ```
+    fn try_from(value: &[u8]) -> Result<Self, DataTypeParseError> {
+        let raw = RawAdStructure::try_from(value)?;
+        Ok(Self::new(raw.ad_type, &raw.data))
+    }
+}
+
+impl Into<Vec<u8>> for CustomDataType {
+    /// Create [`Vec<u8>`] from [`CustomDataType`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ble_data_struct::data_types::custom_data_type::CustomDataType;
+    ///
+    /// let result = CustomDataType::new(0xf0, &[0x01, 0x02]);
+    /// let into_data: Vec<u8> = result.into();
+    /// assert_eq!(vec![0x03u8, 0xf0u8, 0x01u8, 0x02u8], into_data);
+    /// ```
+    fn into(self) -> Vec<u8> {
+        RawAdStructure {
+            ad_type: self.ad_type,
+            data: self.data,
+        }
+        .into()
+    }
+}
+
+impl AdStructure for CustomDataType {
+    /// return `ad_type`.
+    fn ad_type(&self) -> u8 {
+        self.ad_type
+    }
+
+    /// return [`Into<Vec<u8>>`] result.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ble_data_struct::data_types::{
+    ///     ad_structure::AdStructure, custom_data_type::CustomDataType,
+    /// };
+    ///
+    /// let result: Box<dyn AdStructure> = Box::new(CustomDataType::new(0xf0, &[0x01]));
+    /// assert_eq!(0xf0, result.ad_type());
+    /// assert_eq!(vec![0x02u8, 0xf0u8, 0x01u8], result.to_bytes());
+    /// ```
+    fn to_bytes(&self) -> Vec<u8> {
+        self.clone().into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::data_types::{
+        ad_structure::AdStructure, custom_data_type::CustomDataType,
+        parse_error::DataTypeParseError,
+    };
+
+    #[test]
+    fn test_new() {
+        let result = CustomDataType::new(0xf0, &[0x01, 0x02]);
+        assert_eq!(0xf0, result.ad_type);
+        assert_eq!(vec![0x01, 0x02], result.data);
+        assert!(!result.has_value());
+        assert_eq!(None, result.value::<u16>());
+    }
+
+    #[test]
+    fn test_with_value() {
+        let result = CustomDataType::new(0xf0, &[0x01, 0x02]).with_value("name".to_string());
+        assert!(result.has_value());
+        assert_eq!(Some(&"name".to_string()), result.value::<String>());
+        assert_eq!(None, result.value::<u16>());
+
+        let clone = result.clone();
+        assert_eq!(Some(&"name".to_string()), clone.value::<String>());
+        assert_eq!(CustomDataType::new(0xf0, &[0x01, 0x02]), clone);
+        assert_ne!(CustomDataType::new(0xf1, &[0x01, 0x02]), clone);
+    }
+
+    #[test]
+    fn test_try_from() {
+        let data = [0x03u8, 0xf0u8, 0x01u8, 0x02u8];
+        let result = CustomDataType::try_from(&data[..]).unwrap();
+        assert_eq!(0xf0, result.ad_type);
+        assert_eq!(vec![0x01, 0x02], result.data);
+        assert!(!result.has_value());
+
+        assert_eq!(
+            Err(DataTypeParseError::InvalidLength {
+                expected: 2,
+                actual: 1
+            }),
+            CustomDataType::try_from(&data[..1])
+        );
+        assert_eq!(
+            Err(DataTypeParseError::InvalidLength {
+                expected: 4,
+                actual: 3
+            }),
+            CustomDataType::try_from(&data[..3])
+        );
+    }
+
+    #[test]
+    fn test_into() {
+        let data = vec![0x03u8, 0xf0u8, 0x01u8, 0x02u8];
+        let into_data: Vec<u8> = CustomDataType::try_from(&data[..]).unwrap().into();
+        assert_eq!(data, into_data);
+
+        let result = CustomDataType::new(0xf0, &[0x01, 0x02]).with_value(0x01u8);
+        assert_eq!(0xf0, result.ad_type());
+        assert_eq!(data, result.to_bytes());
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_serde() {
+        let result = CustomDataType::new(0xf0, &[0x01]).with_value(0x01u8);
+        let json = serde_json::to_string(&result).unwrap();
+        let deserialized: CustomDataType = serde_json::from_str(&json).unwrap();
+        assert_eq!(result, deserialized);
+        assert!(!deserialized.has_value());
+    }
+}
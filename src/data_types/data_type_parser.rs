@@ -1,218 +1,333 @@
 //! Data type parser module.
 
+use std::iter::FusedIterator;
+
 use super::{
-    advertising_interval::{is_advertising_interval, AdvertisingInterval},
-    advertising_interval_long::{is_advertising_interval_long, AdvertisingIntervalLong},
-    appearance::{is_appearance, Appearance},
-    big_info::{is_big_info, BigInfo},
-    broadcast_code::{is_broadcast_code, BroadcastCode},
-    channel_map_update_indication::{is_channel_map_update_indication, ChannelMapUpdateIndication},
-    class_of_device::{is_class_of_device, ClassOfDevice},
-    complete_list_of_128bit_service_uuids::{
-        is_complete_list_of_128bit_service_uuids, CompleteListOf128BitServiceUuids,
-    },
-    complete_list_of_16bit_service_uuids::{
-        is_complete_list_of_16bit_service_uuids, CompleteListOf16BitServiceUuids,
-    },
-    complete_list_of_32bit_service_uuids::{
-        is_complete_list_of_32bit_service_uuids, CompleteListOf32BitServiceUuids,
-    },
-    complete_local_name::{is_complete_local_name, CompleteLocalName},
-    encrypted_data::{is_encrypted_data, EncryptedData},
-    flags::{is_flags, Flags},
-    incomplete_list_of_128bit_service_uuids::{
-        is_incomplete_list_of_128bit_service_uuids, IncompleteListOf128BitServiceUuids,
-    },
-    incomplete_list_of_16bit_service_uuids::{
-        is_incomplete_list_of_16bit_service_uuids, IncompleteListOf16BitServiceUuids,
-    },
-    incomplete_list_of_32bit_service_uuids::{
-        is_incomplete_list_of_32bit_service_uuids, IncompleteListOf32BitServiceUuids,
-    },
-    le_bluetooth_device_address::{is_le_bluetooth_device_address, LeBluetoothDeviceAddress},
-    le_role::{is_le_role, LeRole},
-    le_secure_connections_confirmation_value::{
-        is_le_secure_connections_confirmation_value, LeSecureConnectionsConfirmationValue,
-    },
-    le_secure_connections_random_value::{
-        is_le_secure_connections_random_value, LeSecureConnectionsRandomValue,
-    },
-    le_supported_features::{is_le_supported_features, LeSupportedFeatures},
-    list_of_128bit_service_solicitation_uuids::{
-        is_list_of_128bit_service_solicitation_uuids, ListOf128BitServiceSolicitationUUIDs,
-    },
-    list_of_16bit_service_solicitation_uuids::{
-        is_list_of_16bit_service_solicitation_uuids, ListOf16BitServiceSolicitationUUIDs,
-    },
-    list_of_32bit_service_solicitation_uuids::{
-        is_list_of_32bit_service_solicitation_uuids, ListOf32BitServiceSolicitationUUIDs,
-    },
-    manufacturer_specific_data::{is_manufacturer_specific_data, ManufacturerSpecificData},
-    periodic_advertising_response_timing_information::{
-        is_periodic_advertising_response_timing_information,
-        PeriodicAdvertisingResponseTimingInformation,
-    },
-    peripheral_connection_interval_range::{
-        is_peripheral_connection_interval_range, PeripheralConnectionIntervalRange,
-    },
-    public_target_address::{is_public_target_address, PublicTargetAddress},
-    random_target_address::{is_random_target_address, RandomTargetAddress},
-    secure_simple_pairing_hash_c192::{
-        is_secure_simple_pairing_hash_c192, SecureSimplePairingHashC192,
-    },
-    secure_simple_pairing_hash_c256::{
-        is_secure_simple_pairing_hash_c256, SecureSimplePairingHashC256,
-    },
-    secure_simple_pairing_randomizer_r192::{
-        is_secure_simple_pairing_randomizer_r192, SecureSimplePairingRandomizerR192,
-    },
-    secure_simple_pairing_randomizer_r256::{
-        is_secure_simple_pairing_randomizer_r256, SecureSimplePairingRandomizerR256,
-    },
-    security_manager_oob::{is_security_manager_oob, SecurityManagerOutOfBand},
-    security_manager_tk_value::{is_security_manager_tk_value, SecurityManagerTkValue},
-    service_data_128bit_uuid::{is_service_data_128bit_uuid, ServiceData128BitUUID},
-    service_data_16bit_uuid::{is_service_data_16bit_uuid, ServiceData16BitUUID},
-    service_data_32bit_uuid::{is_service_data_32bit_uuid, ServiceData32BitUUID},
-    shortened_local_name::{is_shortened_local_name, ShortenedLocalName},
-    tx_power_level::{is_tx_power_level, TxPowerLevel},
-    uniform_resource_identifier::{is_uniform_resource_identifier, UniformResourceIdentifier},
+    ad_structure::AdStructure,
+    ad_type::AdType,
+    advertising_interval::AdvertisingInterval,
+    advertising_interval_long::AdvertisingIntervalLong,
+    appearance::Appearance,
+    big_info::BigInfo,
+    broadcast_code::BroadcastCode,
+    broadcast_name::{BroadcastName, BROADCAST_NAME_MAX_SIZE, BROADCAST_NAME_MIN_SIZE},
+    channel_map_update_indication::ChannelMapUpdateIndication,
+    class_of_device::ClassOfDevice,
+    complete_list_of_128bit_service_uuids::CompleteListOf128BitServiceUuids,
+    complete_list_of_16bit_service_uuids::CompleteListOf16BitServiceUuids,
+    complete_list_of_32bit_service_uuids::CompleteListOf32BitServiceUuids,
+    complete_local_name::CompleteLocalName,
+    custom_data_type::CustomDataType,
+    data_type::DataType,
+    data_type_ref::DataTypeRef,
+    device_id::DeviceId,
+    electronic_shelf_label::ElectronicShelfLabel,
+    encrypted_data::EncryptedData,
+    flags::Flags,
+    incomplete_list_of_128bit_service_uuids::IncompleteListOf128BitServiceUuids,
+    incomplete_list_of_16bit_service_uuids::IncompleteListOf16BitServiceUuids,
+    incomplete_list_of_32bit_service_uuids::IncompleteListOf32BitServiceUuids,
+    le_bluetooth_device_address::LeBluetoothDeviceAddress,
+    le_role::LeRole,
+    le_secure_connections_confirmation_value::LeSecureConnectionsConfirmationValue,
+    le_secure_connections_random_value::LeSecureConnectionsRandomValue,
+    le_supported_features::LeSupportedFeatures,
+    list_of_128bit_service_solicitation_uuids::ListOf128BitServiceSolicitationUUIDs,
+    list_of_16bit_service_solicitation_uuids::ListOf16BitServiceSolicitationUUIDs,
+    list_of_32bit_service_solicitation_uuids::ListOf32BitServiceSolicitationUUIDs,
+    manufacturer_specific_data::ManufacturerSpecificData,
+    mesh_beacon::{MeshBeacon, MESH_PRIVATE_BEACON},
+    mesh_message::MeshMessage,
+    parse_error::DataTypeParseError,
+    parse_warning::{check_fixed_length, check_multiple_length, ParseWarning},
+    pb_adv::PbAdv,
+    periodic_advertising_response_timing_information::PeriodicAdvertisingResponseTimingInformation,
+    peripheral_connection_interval_range::PeripheralConnectionIntervalRange,
+    public_target_address::PublicTargetAddress,
+    random_target_address::RandomTargetAddress,
+    raw_ad_structure::RawAdStructure,
+    resolvable_set_identifier::ResolvableSetIdentifier,
+    secure_simple_pairing_hash_c192::SecureSimplePairingHashC192,
+    secure_simple_pairing_hash_c256::SecureSimplePairingHashC256,
+    secure_simple_pairing_randomizer_r192::SecureSimplePairingRandomizerR192,
+    secure_simple_pairing_randomizer_r256::SecureSimplePairingRandomizerR256,
+    security_manager_oob::SecurityManagerOutOfBand,
+    security_manager_tk_value::SecurityManagerTkValue,
+    service_data_128bit_uuid::ServiceData128BitUUID,
+    service_data_16bit_uuid::ServiceData16BitUUID,
+    service_data_32bit_uuid::ServiceData32BitUUID,
+    shortened_local_name::ShortenedLocalName,
+    three_d_information_data::ThreeDInformationData,
+    tx_power_level::TxPowerLevel,
+    uniform_resource_identifier::UniformResourceIdentifier,
 };
 
+/// Container of the parsed data types.
+///
+/// Data Type Value `0x10` is Device ID in EIR and Security Manager TK Value in OOB data.
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum ParseContext {
+    /// Advertising data.
+    Advertising,
+
+    /// Scan response data.
+    ScanResponse,
+
+    /// Extended Inquiry Response.
+    Eir,
+
+    /// Out of Band data.
+    ///
+    /// Used by the [`From`] implementations.
+    #[default]
+    Oob,
+
+    /// Additional Controller Advertising Data.
+    Acad,
+
+    /// Periodic advertising data.
+    PeriodicAdvertising,
+}
+
+impl ParseContext {
+    /// Check the data type is allowed in the container (Core Specification Supplement, Part A, Table 1.1).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ble_data_struct::data_types::{
+    ///     big_info::BigInfo, data_type::DataType, data_type_parser::ParseContext, flags::Flags,
+    /// };
+    ///
+    /// assert!(ParseContext::Acad.is_allowed(BigInfo::data_type()));
+    /// assert!(!ParseContext::Advertising.is_allowed(BigInfo::data_type()));
+    /// assert!(ParseContext::Advertising.is_allowed(Flags::data_type()));
+    /// assert!(!ParseContext::ScanResponse.is_allowed(Flags::data_type()));
+    /// ```
+    pub fn is_allowed(&self, data_type: u8) -> bool {
+        match AdType::from_u8(data_type) {
+            Some(AdType::Flags) => matches!(self, Self::Advertising | Self::Eir | Self::Oob),
+            Some(AdType::ClassOfDevice | AdType::DeviceIdOrSecurityManagerTkValue) => {
+                matches!(self, Self::Eir | Self::Oob)
+            }
+            Some(
+                AdType::SecureSimplePairingHashC192
+                | AdType::SecureSimplePairingRandomizerR192
+                | AdType::SecurityManagerOutOfBand
+                | AdType::LeBluetoothDeviceAddress
+                | AdType::LeRole
+                | AdType::SecureSimplePairingHashC256
+                | AdType::SecureSimplePairingRandomizerR256
+                | AdType::LeSecureConnectionsConfirmationValue
+                | AdType::LeSecureConnectionsRandomValue
+                | AdType::BroadcastCode,
+            ) => *self == Self::Oob,
+            Some(AdType::ChannelMapUpdateIndication | AdType::BigInfo) => *self == Self::Acad,
+            _ => true,
+        }
+    }
+}
+
 /// Data type parse result.
+///
+/// Parsers for new data types are added as new variants in minor releases, so matches need a wildcard arm.
+/// Until then the data type is returned as [`DataTypeParseResult::Unknown`].
 #[derive(Debug, PartialEq, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[non_exhaustive]
 pub enum DataTypeParseResult {
     /// [`AdvertisingInterval`]'s [`TryFrom::try_from`] result.
-    AdvertisingIntervalResult(Result<AdvertisingInterval, String>),
+    AdvertisingIntervalResult(Result<AdvertisingInterval, DataTypeParseError>),
 
     /// [`AdvertisingIntervalLong`]'s [`TryFrom::try_from`] result.
-    AdvertisingIntervalLongResult(Result<AdvertisingIntervalLong, String>),
+    AdvertisingIntervalLongResult(Result<AdvertisingIntervalLong, DataTypeParseError>),
 
     /// [`Appearance`]'s [`TryFrom::try_from`] result.
-    AppearanceResult(Result<Appearance, String>),
+    AppearanceResult(Result<Appearance, DataTypeParseError>),
 
     /// [`BigInfo`]'s [`TryFrom::try_from`] result.
-    BigInfoResult(Result<BigInfo, String>),
+    ///
+    /// Boxed, so the rare BIGInfo (80 bytes) does not inflate every [`DataTypeParseResult`]
+    /// (80 bytes to 64 bytes on 64-bit targets).
+    BigInfoResult(Result<Box<BigInfo>, DataTypeParseError>),
 
     /// [`BroadcastCode`]'s [`TryFrom::try_from`] result.
-    BroadcastCodeResult(Result<BroadcastCode, String>),
+    BroadcastCodeResult(Result<BroadcastCode, DataTypeParseError>),
+
+    /// [`BroadcastName`]'s [`TryFrom::try_from`] result.
+    BroadcastNameResult(Result<BroadcastName, DataTypeParseError>),
 
     /// [`ChannelMapUpdateIndication`]'s [`TryFrom::try_from`] result.
-    ChannelMapUpdateIndicationResult(Result<ChannelMapUpdateIndication, String>),
+    ChannelMapUpdateIndicationResult(Result<ChannelMapUpdateIndication, DataTypeParseError>),
 
     /// [`ClassOfDevice`]'s [`TryFrom::try_from`] result.
-    ClassOfDeviceResult(Result<ClassOfDevice, String>),
+    ClassOfDeviceResult(Result<ClassOfDevice, DataTypeParseError>),
 
     /// [`CompleteListOf128BitServiceUuids`]'s [`TryFrom::try_from`] result.
-    CompleteListOf128BitServiceUuidsResult(Result<CompleteListOf128BitServiceUuids, String>),
+    CompleteListOf128BitServiceUuidsResult(
+        Result<CompleteListOf128BitServiceUuids, DataTypeParseError>,
+    ),
 
     /// [`CompleteListOf16BitServiceUuids`]'s [`TryFrom::try_from`] result.
-    CompleteListOf16BitServiceUuidsResult(Result<CompleteListOf16BitServiceUuids, String>),
+    CompleteListOf16BitServiceUuidsResult(
+        Result<CompleteListOf16BitServiceUuids, DataTypeParseError>,
+    ),
 
     /// [`CompleteListOf32BitServiceUuids`]'s [`TryFrom::try_from`] result.
-    CompleteListOf32BitServiceUuidsResult(Result<CompleteListOf32BitServiceUuids, String>),
+    CompleteListOf32BitServiceUuidsResult(
+        Result<CompleteListOf32BitServiceUuids, DataTypeParseError>,
+    ),
 
     /// [`CompleteLocalName`]'s [`TryFrom::try_from`] result.
-    CompleteLocalNameResult(Result<CompleteLocalName, String>),
+    CompleteLocalNameResult(Result<CompleteLocalName, DataTypeParseError>),
+
+    /// [`DeviceId`]'s [`TryFrom::try_from`] result.
+    DeviceIdResult(Result<DeviceId, DataTypeParseError>),
+
+    /// [`ElectronicShelfLabel`]'s [`TryFrom::try_from`] result.
+    ElectronicShelfLabelResult(Result<ElectronicShelfLabel, DataTypeParseError>),
 
     /// [`EncryptedData`]'s [`TryFrom::try_from`] result.
-    EncryptedDataResult(Result<EncryptedData, String>),
+    EncryptedDataResult(Result<EncryptedData, DataTypeParseError>),
 
     /// [`Flags`]'s [`TryFrom::try_from`] result.
-    FlagsResult(Result<Flags, String>),
+    FlagsResult(Result<Flags, DataTypeParseError>),
 
     /// [`IncompleteListOf128BitServiceUuids`]'s [`TryFrom::try_from`] result.
-    IncompleteListOf128BitServiceUuidsResult(Result<IncompleteListOf128BitServiceUuids, String>),
+    IncompleteListOf128BitServiceUuidsResult(
+        Result<IncompleteListOf128BitServiceUuids, DataTypeParseError>,
+    ),
 
     /// [`IncompleteListOf16BitServiceUuids`]'s [`TryFrom::try_from`] result.
-    IncompleteListOf16BitServiceUuidsResult(Result<IncompleteListOf16BitServiceUuids, String>),
+    IncompleteListOf16BitServiceUuidsResult(
+        Result<IncompleteListOf16BitServiceUuids, DataTypeParseError>,
+    ),
 
     /// [`IncompleteListOf32BitServiceUuids`]'s [`TryFrom::try_from`] result.
-    IncompleteListOf32BitServiceUuidsResult(Result<IncompleteListOf32BitServiceUuids, String>),
+    IncompleteListOf32BitServiceUuidsResult(
+        Result<IncompleteListOf32BitServiceUuids, DataTypeParseError>,
+    ),
 
     /// [`LeBluetoothDeviceAddress`]'s [`TryFrom::try_from`] result.
-    LeBluetoothDeviceAddressResult(Result<LeBluetoothDeviceAddress, String>),
+    LeBluetoothDeviceAddressResult(Result<LeBluetoothDeviceAddress, DataTypeParseError>),
 
     /// [`LeRole`]'s [`TryFrom::try_from`] result.
-    LeRoleResult(Result<LeRole, String>),
+    LeRoleResult(Result<LeRole, DataTypeParseError>),
 
     /// [`LeSecureConnectionsConfirmationValue`]'s [`TryFrom::try_from`] result.
     LeSecureConnectionsConfirmationValueResult(
-        Result<LeSecureConnectionsConfirmationValue, String>,
+        Result<LeSecureConnectionsConfirmationValue, DataTypeParseError>,
     ),
 
     /// [`LeSecureConnectionsRandomValue`]'s [`TryFrom::try_from`] result.
-    LeSecureConnectionsRandomValueResult(Result<LeSecureConnectionsRandomValue, String>),
+    LeSecureConnectionsRandomValueResult(
+        Result<LeSecureConnectionsRandomValue, DataTypeParseError>,
+    ),
 
     /// [`LeSupportedFeatures`]'s [`TryFrom::try_from`] result.
-    LeSupportedFeaturesResult(Result<LeSupportedFeatures, String>),
+    LeSupportedFeaturesResult(Result<LeSupportedFeatures, DataTypeParseError>),
 
     /// [`ListOf128BitServiceSolicitationUUIDs`]'s [`TryFrom::try_from`] result.
     ListOf128BitServiceSolicitationUUIDsResult(
-        Result<ListOf128BitServiceSolicitationUUIDs, String>,
+        Result<ListOf128BitServiceSolicitationUUIDs, DataTypeParseError>,
     ),
 
     /// [`ListOf16BitServiceSolicitationUUIDs`]'s [`TryFrom::try_from`] result.
-    ListOf16BitServiceSolicitationUUIDsResult(Result<ListOf16BitServiceSolicitationUUIDs, String>),
+    ListOf16BitServiceSolicitationUUIDsResult(
+        Result<ListOf16BitServiceSolicitationUUIDs, DataTypeParseError>,
+    ),
 
     /// [`ListOf32BitServiceSolicitationUUIDs`]'s [`TryFrom::try_from`] result.
-    ListOf32BitServiceSolicitationUUIDsResult(Result<ListOf32BitServiceSolicitationUUIDs, String>),
+    ListOf32BitServiceSolicitationUUIDsResult(
+        Result<ListOf32BitServiceSolicitationUUIDs, DataTypeParseError>,
+    ),
 
     /// [`ManufacturerSpecificData`]'s [`TryFrom::try_from`] result.
-    ManufacturerSpecificDataResult(Result<ManufacturerSpecificData, String>),
+    ManufacturerSpecificDataResult(Result<ManufacturerSpecificData, DataTypeParseError>),
+
+    /// [`MeshBeacon`]'s [`TryFrom::try_from`] result.
+    MeshBeaconResult(Result<MeshBeacon, DataTypeParseError>),
+
+    /// [`MeshMessage`]'s [`TryFrom::try_from`] result.
+    MeshMessageResult(Result<MeshMessage, DataTypeParseError>),
+
+    /// [`PbAdv`]'s [`TryFrom::try_from`] result.
+    PbAdvResult(Result<PbAdv, DataTypeParseError>),
 
     /// [`PeriodicAdvertisingResponseTimingInformation`]'s [`TryFrom::try_from`] result.
     PeriodicAdvertisingResponseTimingInformationResult(
-        Result<PeriodicAdvertisingResponseTimingInformation, String>,
+        Result<PeriodicAdvertisingResponseTimingInformation, DataTypeParseError>,
     ),
 
     /// [`PeripheralConnectionIntervalRange`]'s [`TryFrom::try_from`] result.
-    PeripheralConnectionIntervalRangeResult(Result<PeripheralConnectionIntervalRange, String>),
+    PeripheralConnectionIntervalRangeResult(
+        Result<PeripheralConnectionIntervalRange, DataTypeParseError>,
+    ),
 
     /// [`PublicTargetAddress`]'s [`TryFrom::try_from`] result.
-    PublicTargetAddressResult(Result<PublicTargetAddress, String>),
+    PublicTargetAddressResult(Result<PublicTargetAddress, DataTypeParseError>),
 
     /// [`RandomTargetAddress`]'s [`TryFrom::try_from`] result.
-    RandomTargetAddressResult(Result<RandomTargetAddress, String>),
+    RandomTargetAddressResult(Result<RandomTargetAddress, DataTypeParseError>),
+
+    /// [`ResolvableSetIdentifier`]'s [`TryFrom::try_from`] result.
+    ResolvableSetIdentifierResult(Result<ResolvableSetIdentifier, DataTypeParseError>),
 
     /// [`SecureSimplePairingHashC192`]'s [`TryFrom::try_from`] result.
-    SecureSimplePairingHashC192Result(Result<SecureSimplePairingHashC192, String>),
+    SecureSimplePairingHashC192Result(Result<SecureSimplePairingHashC192, DataTypeParseError>),
 
     /// [`SecureSimplePairingHashC256`]'s [`TryFrom::try_from`] result.
-    SecureSimplePairingHashC256Result(Result<SecureSimplePairingHashC256, String>),
+    SecureSimplePairingHashC256Result(Result<SecureSimplePairingHashC256, DataTypeParseError>),
 
     /// [`SecureSimplePairingRandomizerR192`]'s [`TryFrom::try_from`] result.
-    SecureSimplePairingRandomizerR192Result(Result<SecureSimplePairingRandomizerR192, String>),
+    SecureSimplePairingRandomizerR192Result(
+        Result<SecureSimplePairingRandomizerR192, DataTypeParseError>,
+    ),
 
     /// [`SecureSimplePairingRandomizerR256`]'s [`TryFrom::try_from`] result.
-    SecureSimplePairingRandomizerR256Result(Result<SecureSimplePairingRandomizerR256, String>),
+    SecureSimplePairingRandomizerR256Result(
+        Result<SecureSimplePairingRandomizerR256, DataTypeParseError>,
+    ),
 
     /// [`SecurityManagerOutOfBand`]'s [`TryFrom::try_from`] result.
-    SecurityManagerOutOfBandResult(Result<SecurityManagerOutOfBand, String>),
+    SecurityManagerOutOfBandResult(Result<SecurityManagerOutOfBand, DataTypeParseError>),
 
     /// [`SecurityManagerTkValue`]'s [`TryFrom::try_from`] result.
-    SecurityManagerTkValueResult(Result<SecurityManagerTkValue, String>),
+    SecurityManagerTkValueResult(Result<SecurityManagerTkValue, DataTypeParseError>),
 
     /// [`ServiceData128BitUUID`]'s [`TryFrom::try_from`] result.
-    ServiceData128BitUUIDResult(Result<ServiceData128BitUUID, String>),
+    ServiceData128BitUUIDResult(Result<ServiceData128BitUUID, DataTypeParseError>),
 
     /// [`ServiceData16BitUUID`]'s [`TryFrom::try_from`] result.
-    ServiceData16BitUUIDResult(Result<ServiceData16BitUUID, String>),
+    ServiceData16BitUUIDResult(Result<ServiceData16BitUUID, DataTypeParseError>),
 
     /// [`ServiceData32BitUUID`]'s [`TryFrom::try_from`] result.
-    ServiceData32BitUUIDResult(Result<ServiceData32BitUUID, String>),
+    ServiceData32BitUUIDResult(Result<ServiceData32BitUUID, DataTypeParseError>),
 
     /// [`ShortenedLocalName`]'s [`TryFrom::try_from`] result.
-    ShortenedLocalNameResult(Result<ShortenedLocalName, String>),
+    ShortenedLocalNameResult(Result<ShortenedLocalName, DataTypeParseError>),
+
+    /// [`ThreeDInformationData`]'s [`TryFrom::try_from`] result.
+    ThreeDInformationDataResult(Result<ThreeDInformationData, DataTypeParseError>),
 
     /// [`TxPowerLevel`]'s [`TryFrom::try_from`] result.
-    TxPowerLevelResult(Result<TxPowerLevel, String>),
+    TxPowerLevelResult(Result<TxPowerLevel, DataTypeParseError>),
 
     /// [`UniformResourceIdentifier`]'s [`TryFrom::try_from`] result.
-    UniformResourceIdentifierResult(Result<UniformResourceIdentifier, String>),
+    UniformResourceIdentifierResult(Result<UniformResourceIdentifier, DataTypeParseError>),
 
-    /// Occurs for unsupported data types.
-    DataTypeParseError(String),
+    /// [`RawAdStructure`]'s [`TryFrom::try_from`] result (custom data types registered to [`DataTypeRegistry`](crate::data_types::data_type_registry::DataTypeRegistry)).
+    RawAdStructureResult(Result<RawAdStructure, DataTypeParseError>),
+
+    /// [`CustomDataType`] result of the handler registered with [`DataTypeRegistry::register_handler`](crate::data_types::data_type_registry::DataTypeRegistry::register_handler).
+    CustomResult(Result<CustomDataType, DataTypeParseError>),
+
+    /// Data type without parser (raw data is kept to inspect or forward it).
+    Unknown(RawAdStructure),
+
+    /// Occurs for malformed structures and data types not allowed in the [`ParseContext`].
+    DataTypeParseError(DataTypeParseError),
 }
 
 impl DataTypeParseResult {
@@ -222,9 +337,10 @@ impl DataTypeParseResult {
     ///
     /// ```
     /// use ble_data_struct::data_types::{advertising_interval::AdvertisingInterval, data_type_parser::DataTypeParseResult};
+    /// use ble_data_struct::data_types::time_units::Slots625us;
     ///
     /// let advertising_interval = 0x01;
-    /// let data: Vec<u8> = AdvertisingInterval::new(advertising_interval).into();
+    /// let data: Vec<u8> = AdvertisingInterval::new(Slots625us(advertising_interval)).into();
     /// assert!(DataTypeParseResult::from(&data).is_advertising_interval());
     ///
     /// let data: Vec<u8> = Vec::new();
@@ -240,8 +356,9 @@ impl DataTypeParseResult {
     ///
     /// ```
     /// use ble_data_struct::data_types::{advertising_interval_long::AdvertisingIntervalLong, data_type_parser::DataTypeParseResult};
+    /// use ble_data_struct::data_types::time_units::Slots625us;
     ///
-    /// let advertising_interval_long: u32 = 0x01020304u32;
+    /// let advertising_interval_long = Slots625us(0x01020304u32);
     /// let data: Vec<u8> = AdvertisingIntervalLong::new(true, advertising_interval_long).into();
     /// assert!(DataTypeParseResult::from(&data).is_advertising_interval_long());
     ///
@@ -351,6 +468,23 @@ impl DataTypeParseResult {
         matches!(self, DataTypeParseResult::BroadcastCodeResult(_))
     }
 
+    /// Returns `true` if the result is [`DataTypeParseResult::BroadcastNameResult`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ble_data_struct::data_types::{broadcast_name::BroadcastName, data_type_parser::DataTypeParseResult};
+    ///
+    /// let data: Vec<u8> = BroadcastName::new(&"broadcast_name".to_string()).into();
+    /// assert!(DataTypeParseResult::from(&data).is_broadcast_name());
+    ///
+    /// let data: Vec<u8> = Vec::new();
+    /// assert!(!DataTypeParseResult::from(&data).is_broadcast_name());
+    /// ```
+    pub fn is_broadcast_name(&self) -> bool {
+        matches!(self, DataTypeParseResult::BroadcastNameResult(_))
+    }
+
     /// Returns `true` if the result is [`DataTypeParseResult::ChannelMapUpdateIndicationResult`].
     ///
     /// # Examples
@@ -361,7 +495,7 @@ impl DataTypeParseResult {
     /// let mut ch_m = [false; 37].to_vec();
     /// for i in 0..37 {
     ///     ch_m[i] = true;
-    ///     let data = ChannelMapUpdateIndication::new(&ch_m, i as u16).into();
+    ///     let data: Vec<u8> = ChannelMapUpdateIndication::new(&ch_m, i as u16).into();
     ///     assert!(DataTypeParseResult::from(&data).is_channel_map_update_indication());
     ///     ch_m[i] = false;
     /// }
@@ -387,7 +521,7 @@ impl DataTypeParseResult {
     /// let major_device_class = 0b00000000_00000001_00000000;
     /// let minor_device_class = 0b00000000_00000000_00000100;
     /// let class_of_device = major_service_classes | major_device_class | minor_device_class;
-    /// let data = ClassOfDevice::new(class_of_device).into();
+    /// let data: Vec<u8> = ClassOfDevice::new(class_of_device).into();
     /// assert!(DataTypeParseResult::from(&data).is_class_of_device());
     ///
     /// let data: Vec<u8> = Vec::new();
@@ -410,7 +544,7 @@ impl DataTypeParseResult {
     ///     uuid!("00000002-0000-1000-8000-00805F9B34FB"),
     /// ]
     /// .to_vec();
-    /// let data = CompleteListOf128BitServiceUuids::new(&uuids).into();
+    /// let data: Vec<u8> = CompleteListOf128BitServiceUuids::new(&uuids).into();
     /// assert!(DataTypeParseResult::from(&data).is_complete_list_of_128bit_service_uuids());
     ///
     /// let data: Vec<u8> = Vec::new();
@@ -436,7 +570,7 @@ impl DataTypeParseResult {
     ///     uuid!("00000002-0000-1000-8000-00805F9B34FB"),
     /// ]
     /// .to_vec();
-    /// let data = CompleteListOf16BitServiceUuids::new(&uuids).into();
+    /// let data: Vec<u8> = CompleteListOf16BitServiceUuids::new(&uuids).into();
     /// assert!(DataTypeParseResult::from(&data).is_complete_list_of_16bit_service_uuids());
     ///
     /// let data: Vec<u8> = Vec::new();
@@ -462,7 +596,7 @@ impl DataTypeParseResult {
     ///     uuid!("00000002-0000-1000-8000-00805F9B34FB"),
     /// ]
     /// .to_vec();
-    /// let data = CompleteListOf32BitServiceUuids::new(&uuids).into();
+    /// let data: Vec<u8> = CompleteListOf32BitServiceUuids::new(&uuids).into();
     /// assert!(DataTypeParseResult::from(&data).is_complete_list_of_32bit_service_uuids());
     ///
     /// let data: Vec<u8> = Vec::new();
@@ -483,7 +617,7 @@ impl DataTypeParseResult {
     /// use ble_data_struct::data_types::{complete_local_name::CompleteLocalName, data_type_parser::DataTypeParseResult};
     ///
     /// let name = "complete_local_name".to_string();
-    /// let data = CompleteLocalName::new(&name).into();
+    /// let data: Vec<u8> = CompleteLocalName::new(&name).into();
     /// assert!(DataTypeParseResult::from(&data).is_complete_local_name());
     ///
     /// let data: Vec<u8> = Vec::new();
@@ -493,6 +627,44 @@ impl DataTypeParseResult {
         matches!(self, DataTypeParseResult::CompleteLocalNameResult(_))
     }
 
+    /// Returns `true` if the result is [`DataTypeParseResult::DeviceIdResult`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ble_data_struct::data_types::{
+    ///     data_type_parser::{DataTypeParseResult, ParseContext},
+    ///     device_id::DeviceId,
+    /// };
+    ///
+    /// let data: Vec<u8> = DeviceId::new(0x0001, 0x004c, 0x0102, 0x0304).into();
+    /// assert!(DataTypeParseResult::from_with_context(&data, ParseContext::Eir).is_device_id());
+    /// assert!(!DataTypeParseResult::from(&data).is_device_id());
+    ///
+    /// let data: Vec<u8> = Vec::new();
+    /// assert!(!DataTypeParseResult::from_with_context(&data, ParseContext::Eir).is_device_id());
+    /// ```
+    pub fn is_device_id(&self) -> bool {
+        matches!(self, DataTypeParseResult::DeviceIdResult(_))
+    }
+
+    /// Returns `true` if the result is [`DataTypeParseResult::ElectronicShelfLabelResult`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ble_data_struct::data_types::{electronic_shelf_label::ElectronicShelfLabel, data_type_parser::DataTypeParseResult};
+    ///
+    /// let data: Vec<u8> = ElectronicShelfLabel::new(&[1, 2, 3, 4, 5], &[6].to_vec(), [7, 8, 9, 10]).into();
+    /// assert!(DataTypeParseResult::from(&data).is_electronic_shelf_label());
+    ///
+    /// let data: Vec<u8> = Vec::new();
+    /// assert!(!DataTypeParseResult::from(&data).is_electronic_shelf_label());
+    /// ```
+    pub fn is_electronic_shelf_label(&self) -> bool {
+        matches!(self, DataTypeParseResult::ElectronicShelfLabelResult(_))
+    }
+
     /// Returns `true` if the result is [`DataTypeParseResult::EncryptedDataResult`].
     ///
     /// # Examples
@@ -503,7 +675,7 @@ impl DataTypeParseResult {
     /// let randomizer: [u8; 5] = [1, 2, 3, 4, 5];
     /// let payload = [6].to_vec();
     /// let mic: [u8; 4] = [7, 8, 9, 10];
-    /// let data = EncryptedData::new(&randomizer, &payload, mic).into();
+    /// let data: Vec<u8> = EncryptedData::new(&randomizer, &payload, mic).into();
     /// assert!(DataTypeParseResult::from(&data).is_encrypted_data());
     ///
     /// let data: Vec<u8> = Vec::new();
@@ -521,7 +693,7 @@ impl DataTypeParseResult {
     /// use ble_data_struct::data_types::{flags::Flags, data_type_parser::DataTypeParseResult};
     ///
     /// let flags = [true, false, false, false, false, false, false, false].to_vec();
-    /// let data = Flags::new(&flags).into();
+    /// let data: Vec<u8> = Flags::new(&flags).into();
     /// assert!(DataTypeParseResult::from(&data).is_flags());
     ///
     /// let data: Vec<u8> = Vec::new();
@@ -544,7 +716,7 @@ impl DataTypeParseResult {
     ///     uuid!("00000002-0000-1000-8000-00805F9B34FB"),
     /// ]
     /// .to_vec();
-    /// let data = IncompleteListOf128BitServiceUuids::new(&uuids).into();
+    /// let data: Vec<u8> = IncompleteListOf128BitServiceUuids::new(&uuids).into();
     /// assert!(DataTypeParseResult::from(&data).is_incomplete_list_of_128bit_service_uuids());
     ///
     /// let data: Vec<u8> = Vec::new();
@@ -570,7 +742,7 @@ impl DataTypeParseResult {
     ///     uuid!("00000002-0000-1000-8000-00805F9B34FB"),
     /// ]
     /// .to_vec();
-    /// let data = IncompleteListOf16BitServiceUuids::new(&uuids).into();
+    /// let data: Vec<u8> = IncompleteListOf16BitServiceUuids::new(&uuids).into();
     /// assert!(DataTypeParseResult::from(&data).is_incomplete_list_of_16bit_service_uuids());
     ///
     /// let data: Vec<u8> = Vec::new();
@@ -596,7 +768,7 @@ impl DataTypeParseResult {
     ///     uuid!("00000002-0000-1000-8000-00805F9B34FB"),
     /// ]
     /// .to_vec();
-    /// let data = IncompleteListOf32BitServiceUuids::new(&uuids).into();
+    /// let data: Vec<u8> = IncompleteListOf32BitServiceUuids::new(&uuids).into();
     /// assert!(DataTypeParseResult::from(&data).is_incomplete_list_of_32bit_service_uuids());
     ///
     /// let data: Vec<u8> = Vec::new();
@@ -618,7 +790,7 @@ impl DataTypeParseResult {
     ///
     /// let le_bluetooth_device_address = 0x0000060504030201u64;
     /// let address_type = false;
-    /// let data = LeBluetoothDeviceAddress::new(le_bluetooth_device_address, address_type).into();
+    /// let data: Vec<u8> = LeBluetoothDeviceAddress::new(le_bluetooth_device_address, address_type).into();
     /// assert!(DataTypeParseResult::from(&data).is_le_bluetooth_device_address());
     ///
     /// let data: Vec<u8> = Vec::new();
@@ -636,7 +808,7 @@ impl DataTypeParseResult {
     /// use ble_data_struct::data_types::{le_role::*, data_type_parser::DataTypeParseResult};
     ///
     /// let le_role = ONLY_PERIPHERAL_ROLE_SUPPORTED;
-    /// let data = LeRole::new(le_role).into();
+    /// let data: Vec<u8> = LeRole::new(le_role).into();
     /// assert!(DataTypeParseResult::from(&data).is_le_role());
     ///
     /// let data: Vec<u8> = Vec::new();
@@ -654,7 +826,7 @@ impl DataTypeParseResult {
     /// use ble_data_struct::data_types::{le_secure_connections_confirmation_value::LeSecureConnectionsConfirmationValue, data_type_parser::DataTypeParseResult};
     ///
     /// let le_secure_connections_confirmation_value = 0x0102030405060708090a0b0c0d0e0f10u128;
-    /// let data =
+    /// let data: Vec<u8> =
     ///     LeSecureConnectionsConfirmationValue::new(le_secure_connections_confirmation_value)
     ///         .into();
     /// assert!(DataTypeParseResult::from(&data).is_le_secure_connections_confirmation_value());
@@ -678,7 +850,7 @@ impl DataTypeParseResult {
     ///
     /// let le_secure_connections_random_value = 0x0102030405060708090a0b0c0d0e0f10u128;
     ///
-    /// let data = LeSecureConnectionsRandomValue::new(le_secure_connections_random_value).into();
+    /// let data: Vec<u8> = LeSecureConnectionsRandomValue::new(le_secure_connections_random_value).into();
     /// assert!(DataTypeParseResult::from(&data).is_le_secure_connections_random_value());
     ///
     /// let data: Vec<u8> = Vec::new();
@@ -701,7 +873,7 @@ impl DataTypeParseResult {
     /// let mut le_supported_features = [false; 48].to_vec();
     /// for i in 0..44 {
     ///     le_supported_features[i] = true;
-    ///     let data = LeSupportedFeatures::new(&le_supported_features).into();
+    ///     let data: Vec<u8> = LeSupportedFeatures::new(&le_supported_features).into();
     ///     assert!(DataTypeParseResult::from(&data).is_le_supported_features());
     ///     le_supported_features[i] = false;
     /// }
@@ -726,7 +898,7 @@ impl DataTypeParseResult {
     ///     uuid!("00000002-0000-1000-8000-00805F9B34FB"),
     /// ]
     /// .to_vec();
-    /// let data = ListOf128BitServiceSolicitationUUIDs::new(&uuids).into();
+    /// let data: Vec<u8> = ListOf128BitServiceSolicitationUUIDs::new(&uuids).into();
     /// assert!(DataTypeParseResult::from(&data).is_list_of_128bit_service_solicitation_uuids());
     ///
     /// let data: Vec<u8> = Vec::new();
@@ -752,7 +924,7 @@ impl DataTypeParseResult {
     ///     uuid!("00000002-0000-1000-8000-00805F9B34FB"),
     /// ]
     /// .to_vec();
-    /// let data = ListOf16BitServiceSolicitationUUIDs::new(&uuids).into();
+    /// let data: Vec<u8> = ListOf16BitServiceSolicitationUUIDs::new(&uuids).into();
     /// assert!(DataTypeParseResult::from(&data).is_list_of_16bit_service_solicitation_uuids());
     ///
     /// let data: Vec<u8> = Vec::new();
@@ -778,7 +950,7 @@ impl DataTypeParseResult {
     ///     uuid!("00000002-0000-1000-8000-00805F9B34FB"),
     /// ]
     /// .to_vec();
-    /// let data = ListOf32BitServiceSolicitationUUIDs::new(&uuids).into();
+    /// let data: Vec<u8> = ListOf32BitServiceSolicitationUUIDs::new(&uuids).into();
     /// assert!(DataTypeParseResult::from(&data).is_list_of_32bit_service_solicitation_uuids());
     ///
     /// let data: Vec<u8> = Vec::new();
@@ -800,7 +972,7 @@ impl DataTypeParseResult {
     ///
     /// let company_identifier = 0x0ca8u16;
     /// let manufacturer_specific_data = [0x03u8].to_vec();
-    /// let data =
+    /// let data: Vec<u8> =
     ///     ManufacturerSpecificData::new(company_identifier, &manufacturer_specific_data).into();
     /// assert!(DataTypeParseResult::from(&data).is_manufacturer_specific_data());
     ///
@@ -811,23 +983,75 @@ impl DataTypeParseResult {
         matches!(self, DataTypeParseResult::ManufacturerSpecificDataResult(_))
     }
 
+    /// Returns `true` if the result is [`DataTypeParseResult::MeshBeaconResult`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ble_data_struct::data_types::{mesh_beacon::MeshBeacon, data_type_parser::DataTypeParseResult};
+    ///
+    /// let data: Vec<u8> = MeshBeacon::new(0x01, &[0x00u8, 0x01u8].to_vec()).into();
+    /// assert!(DataTypeParseResult::from(&data).is_mesh_beacon());
+    ///
+    /// let data: Vec<u8> = Vec::new();
+    /// assert!(!DataTypeParseResult::from(&data).is_mesh_beacon());
+    /// ```
+    pub fn is_mesh_beacon(&self) -> bool {
+        matches!(self, DataTypeParseResult::MeshBeaconResult(_))
+    }
+
+    /// Returns `true` if the result is [`DataTypeParseResult::MeshMessageResult`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ble_data_struct::data_types::{mesh_message::MeshMessage, data_type_parser::DataTypeParseResult};
+    ///
+    /// let data: Vec<u8> = MeshMessage::new(&[0x81u8, 0x02u8]).unwrap().into();
+    /// assert!(DataTypeParseResult::from(&data).is_mesh_message());
+    ///
+    /// let data: Vec<u8> = Vec::new();
+    /// assert!(!DataTypeParseResult::from(&data).is_mesh_message());
+    /// ```
+    pub fn is_mesh_message(&self) -> bool {
+        matches!(self, DataTypeParseResult::MeshMessageResult(_))
+    }
+
+    /// Returns `true` if the result is [`DataTypeParseResult::PbAdvResult`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ble_data_struct::data_types::{pb_adv::PbAdv, data_type_parser::DataTypeParseResult};
+    ///
+    /// let data: Vec<u8> = PbAdv::new(0x01020304, 0x00, &[0x03u8].to_vec()).into();
+    /// assert!(DataTypeParseResult::from(&data).is_pb_adv());
+    ///
+    /// let data: Vec<u8> = Vec::new();
+    /// assert!(!DataTypeParseResult::from(&data).is_pb_adv());
+    /// ```
+    pub fn is_pb_adv(&self) -> bool {
+        matches!(self, DataTypeParseResult::PbAdvResult(_))
+    }
+
     /// Returns `true` if the result is [`DataTypeParseResult::PeriodicAdvertisingResponseTimingInformationResult`].
     ///
     /// # Examples
     ///
     /// ```
     /// use ble_data_struct::data_types::{periodic_advertising_response_timing_information::PeriodicAdvertisingResponseTimingInformation, data_type_parser::DataTypeParseResult};
+    /// use ble_data_struct::data_types::time_units::Units1_25ms;
     ///
     /// let rsp_aa: [u8; 4] = [1, 2, 3, 4];
     /// let num_subevents = 6u8;
     /// let subevent_interval = 7u8;
     /// let response_slot_delay = 8u8;
     /// let response_slot_spacing = 9u8;
-    /// let data = PeriodicAdvertisingResponseTimingInformation::new(
+    /// let data: Vec<u8> = PeriodicAdvertisingResponseTimingInformation::new(
     ///     &rsp_aa,
     ///     num_subevents,
-    ///     subevent_interval,
-    ///     response_slot_delay,
+    ///     Units1_25ms(subevent_interval),
+    ///     Units1_25ms(response_slot_delay),
     ///     response_slot_spacing,
     /// )
     /// .into();
@@ -849,10 +1073,11 @@ impl DataTypeParseResult {
     ///
     /// ```
     /// use ble_data_struct::data_types::{peripheral_connection_interval_range::PeripheralConnectionIntervalRange, data_type_parser::DataTypeParseResult};
+    /// use ble_data_struct::data_types::time_units::Units1_25ms;
     ///
     /// let minimum_value = 0x0006u16;
     /// let maximum_value = 0x0C80u16;
-    /// let data = PeripheralConnectionIntervalRange::new(minimum_value, maximum_value).into();
+    /// let data: Vec<u8> = PeripheralConnectionIntervalRange::new(Units1_25ms(minimum_value), Units1_25ms(maximum_value)).into();
     /// assert!(DataTypeParseResult::from(&data).is_peripheral_connection_interval_range());
     ///
     /// let data: Vec<u8> = Vec::new();
@@ -881,7 +1106,7 @@ impl DataTypeParseResult {
     ///     ]),
     /// ]
     /// .to_vec();
-    /// let data = PublicTargetAddress::new(&public_target_address).into();
+    /// let data: Vec<u8> = PublicTargetAddress::new(&public_target_address).into();
     /// assert!(DataTypeParseResult::from(&data).is_public_target_address());
     ///
     /// let data: Vec<u8> = Vec::new();
@@ -907,7 +1132,7 @@ impl DataTypeParseResult {
     ///     ]),
     /// ]
     /// .to_vec();
-    /// let data = RandomTargetAddress::new(&random_target_address).into();
+    /// let data: Vec<u8> = RandomTargetAddress::new(&random_target_address).into();
     /// assert!(DataTypeParseResult::from(&data).is_random_target_address());
     ///
     /// let data: Vec<u8> = Vec::new();
@@ -917,6 +1142,23 @@ impl DataTypeParseResult {
         matches!(self, DataTypeParseResult::RandomTargetAddressResult(_))
     }
 
+    /// Returns `true` if the result is [`DataTypeParseResult::ResolvableSetIdentifierResult`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ble_data_struct::data_types::{resolvable_set_identifier::ResolvableSetIdentifier, data_type_parser::DataTypeParseResult};
+    ///
+    /// let data: Vec<u8> = ResolvableSetIdentifier::new(0x060504030201u64).into();
+    /// assert!(DataTypeParseResult::from(&data).is_resolvable_set_identifier());
+    ///
+    /// let data: Vec<u8> = Vec::new();
+    /// assert!(!DataTypeParseResult::from(&data).is_resolvable_set_identifier());
+    /// ```
+    pub fn is_resolvable_set_identifier(&self) -> bool {
+        matches!(self, DataTypeParseResult::ResolvableSetIdentifierResult(_))
+    }
+
     /// Returns `true` if the result is [`DataTypeParseResult::SecureSimplePairingHashC192Result`].
     ///
     /// # Examples
@@ -925,7 +1167,7 @@ impl DataTypeParseResult {
     /// use ble_data_struct::data_types::{secure_simple_pairing_hash_c192::SecureSimplePairingHashC192, data_type_parser::DataTypeParseResult};
     ///
     /// let secure_simple_pairing_hash_c192 = 0x0102030405060708090a0b0c0d0e0f10u128;
-    /// let data = SecureSimplePairingHashC192::new(secure_simple_pairing_hash_c192).into();
+    /// let data: Vec<u8> = SecureSimplePairingHashC192::new(secure_simple_pairing_hash_c192).into();
     /// assert!(DataTypeParseResult::from(&data).is_secure_simple_pairing_hash_c192());
     ///
     /// let data: Vec<u8> = Vec::new();
@@ -946,7 +1188,7 @@ impl DataTypeParseResult {
     /// use ble_data_struct::data_types::{secure_simple_pairing_hash_c256::SecureSimplePairingHashC256, data_type_parser::DataTypeParseResult};
     ///
     /// let secure_simple_pairing_hash_c256 = 0x0102030405060708090a0b0c0d0e0f10u128;
-    /// let data = SecureSimplePairingHashC256::new(secure_simple_pairing_hash_c256).into();
+    /// let data: Vec<u8> = SecureSimplePairingHashC256::new(secure_simple_pairing_hash_c256).into();
     /// assert!(DataTypeParseResult::from(&data).is_secure_simple_pairing_hash_c256());
     ///
     /// let data: Vec<u8> = Vec::new();
@@ -967,7 +1209,7 @@ impl DataTypeParseResult {
     /// use ble_data_struct::data_types::{secure_simple_pairing_randomizer_r192::SecureSimplePairingRandomizerR192, data_type_parser::DataTypeParseResult};
     ///
     /// let secure_simple_pairing_randomizer_r192 = 0x0102030405060708090a0b0c0d0e0f10u128;
-    /// let data =
+    /// let data: Vec<u8> =
     ///     SecureSimplePairingRandomizerR192::new(secure_simple_pairing_randomizer_r192).into();
     /// assert!(DataTypeParseResult::from(&data).is_secure_simple_pairing_randomizer_r192());
     ///
@@ -989,7 +1231,7 @@ impl DataTypeParseResult {
     /// use ble_data_struct::data_types::{secure_simple_pairing_randomizer_r256::SecureSimplePairingRandomizerR256, data_type_parser::DataTypeParseResult};
     ///
     /// let secure_simple_pairing_randomizer_r256 = 0x0102030405060708090a0b0c0d0e0f10u128;
-    /// let data =
+    /// let data: Vec<u8> =
     ///     SecureSimplePairingRandomizerR256::new(secure_simple_pairing_randomizer_r256).into();
     /// assert!(DataTypeParseResult::from(&data).is_secure_simple_pairing_randomizer_r256());
     ///
@@ -1011,7 +1253,7 @@ impl DataTypeParseResult {
     /// use ble_data_struct::data_types::{security_manager_oob::SecurityManagerOutOfBand, data_type_parser::DataTypeParseResult};
     ///
     /// let security_manager_oob = [true, false, false, false, false, false, false, false];
-    /// let data = SecurityManagerOutOfBand::new(&security_manager_oob).into();
+    /// let data: Vec<u8> = SecurityManagerOutOfBand::new(&security_manager_oob).into();
     /// assert!(DataTypeParseResult::from(&data).is_security_manager_oob());
     ///
     /// let data: Vec<u8> = Vec::new();
@@ -1029,7 +1271,7 @@ impl DataTypeParseResult {
     /// use ble_data_struct::data_types::{security_manager_tk_value::SecurityManagerTkValue, data_type_parser::DataTypeParseResult};
     ///
     /// let security_manager_tk_value = 0x0102030405060708090a0b0c0d0e0f10u128;
-    /// let data = SecurityManagerTkValue::new(security_manager_tk_value).into();
+    /// let data: Vec<u8> = SecurityManagerTkValue::new(security_manager_tk_value).into();
     /// assert!(DataTypeParseResult::from(&data).is_security_manager_tk_value());
     ///
     /// let data: Vec<u8> = Vec::new();
@@ -1049,7 +1291,7 @@ impl DataTypeParseResult {
     ///
     /// let uuid = uuid!("04030201-0000-1000-8000-00805F9B34FB");
     /// let additional_service_data = [0x05u8].to_vec();
-    /// let data = ServiceData128BitUUID::new(&uuid, &additional_service_data).into();
+    /// let data: Vec<u8> = ServiceData128BitUUID::new(&uuid, &additional_service_data).into();
     /// assert!(DataTypeParseResult::from(&data).is_service_data_128bit_uuid());
     ///
     /// let data: Vec<u8> = Vec::new();
@@ -1069,7 +1311,7 @@ impl DataTypeParseResult {
     ///
     /// let uuid = uuid!("00000201-0000-1000-8000-00805F9B34FB");
     /// let additional_service_data = [0x03u8].to_vec();
-    /// let data = ServiceData16BitUUID::new(&uuid, &additional_service_data).into();
+    /// let data: Vec<u8> = ServiceData16BitUUID::new(&uuid, &additional_service_data).into();
     /// assert!(DataTypeParseResult::from(&data).is_service_data_16bit_uuid());
     ///
     /// let data: Vec<u8> = Vec::new();
@@ -1089,7 +1331,7 @@ impl DataTypeParseResult {
     ///
     /// let uuid = uuid!("04030201-0000-1000-8000-00805F9B34FB");
     /// let additional_service_data = [0x05u8].to_vec();
-    /// let data = ServiceData32BitUUID::new(&uuid, &additional_service_data).into();
+    /// let data: Vec<u8> = ServiceData32BitUUID::new(&uuid, &additional_service_data).into();
     /// assert!(DataTypeParseResult::from(&data).is_service_data_32bit_uuid());
     ///
     /// let data: Vec<u8> = Vec::new();
@@ -1107,7 +1349,7 @@ impl DataTypeParseResult {
     /// use ble_data_struct::data_types::{shortened_local_name::ShortenedLocalName, data_type_parser::DataTypeParseResult};
     ///
     /// let name = "shortened_local_name".to_string();
-    /// let data = ShortenedLocalName::new(&name).into();
+    /// let data: Vec<u8> = ShortenedLocalName::new(&name).into();
     /// assert!(DataTypeParseResult::from(&data).is_shortened_local_name());
     ///
     /// let data: Vec<u8> = Vec::new();
@@ -1117,6 +1359,23 @@ impl DataTypeParseResult {
         matches!(self, DataTypeParseResult::ShortenedLocalNameResult(_))
     }
 
+    /// Returns `true` if the result is [`DataTypeParseResult::ThreeDInformationDataResult`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ble_data_struct::data_types::{three_d_information_data::ThreeDInformationData, data_type_parser::DataTypeParseResult};
+    ///
+    /// let data: Vec<u8> = ThreeDInformationData::new(false, true, false, false, 0x46).into();
+    /// assert!(DataTypeParseResult::from(&data).is_three_d_information_data());
+    ///
+    /// let data: Vec<u8> = Vec::new();
+    /// assert!(!DataTypeParseResult::from(&data).is_three_d_information_data());
+    /// ```
+    pub fn is_three_d_information_data(&self) -> bool {
+        matches!(self, DataTypeParseResult::ThreeDInformationDataResult(_))
+    }
+
     /// Returns `true` if the result is [`DataTypeParseResult::TxPowerLevelResult`].
     ///
     /// # Examples
@@ -1125,7 +1384,7 @@ impl DataTypeParseResult {
     /// use ble_data_struct::data_types::{tx_power_level::TxPowerLevel, data_type_parser::DataTypeParseResult};
     ///
     /// let tx_power_level = -127;
-    /// let data = TxPowerLevel::new(tx_power_level).into();
+    /// let data: Vec<u8> = TxPowerLevel::new(tx_power_level).into();
     /// assert!(DataTypeParseResult::from(&data).is_tx_power_level());
     ///
     /// let data: Vec<u8> = Vec::new();
@@ -1145,7 +1404,7 @@ impl DataTypeParseResult {
     /// let scheme = '\u{0016}';
     /// let body = "uniform_resource_identifier";
     /// let uri = scheme.to_string() + body;
-    /// let data = UniformResourceIdentifier::new(&uri).into();
+    /// let data: Vec<u8> = UniformResourceIdentifier::new(&uri).into();
     /// assert!(DataTypeParseResult::from(&data).is_uniform_resource_identifier());
     ///
     /// let data: Vec<u8> = Vec::new();
@@ -1157,311 +1416,1821 @@ impl DataTypeParseResult {
             DataTypeParseResult::UniformResourceIdentifierResult(_)
         )
     }
-}
 
-impl From<&Vec<u8>> for DataTypeParseResult {
-    /// Create [`DataTypeParseResult`] from [`Vec<u8>`].
+    /// Returns `true` if the result is [`DataTypeParseResult::RawAdStructureResult`].
     ///
     /// # Examples
     ///
     /// ```
-    /// use ble_data_struct::data_types::{advertising_interval::AdvertisingInterval, data_type_parser::DataTypeParseResult};
-    ///
-    /// let advertising_interval = 0x01;
-    /// let data: Vec<u8> = AdvertisingInterval::new(advertising_interval).into();
-    /// assert!(matches!(
-    ///     DataTypeParseResult::from(&data),
-    ///     DataTypeParseResult::AdvertisingIntervalResult(_)
-    /// ));
-    /// let data: Vec<u8> = Vec::new();
-    /// assert!(matches!(
-    ///     DataTypeParseResult::from(&data),
-    ///     DataTypeParseResult::DataTypeParseError(_)
-    /// ));
-    fn from(value: &Vec<u8>) -> Self {
-        if let Some(data_type) = value.get(1) {
-            if is_advertising_interval(data_type.to_owned()) {
-                DataTypeParseResult::AdvertisingIntervalResult(AdvertisingInterval::try_from(value))
-            } else if is_advertising_interval_long(data_type.to_owned()) {
-                DataTypeParseResult::AdvertisingIntervalLongResult(
-                    AdvertisingIntervalLong::try_from(value),
-                )
-            } else if is_appearance(data_type.to_owned()) {
-                DataTypeParseResult::AppearanceResult(Appearance::try_from(value))
-            } else if is_big_info(data_type.to_owned()) {
-                DataTypeParseResult::BigInfoResult(BigInfo::try_from(value))
-            } else if is_broadcast_code(data_type.to_owned()) {
-                DataTypeParseResult::BroadcastCodeResult(BroadcastCode::try_from(value))
-            } else if is_channel_map_update_indication(data_type.to_owned()) {
-                DataTypeParseResult::ChannelMapUpdateIndicationResult(
-                    ChannelMapUpdateIndication::try_from(value),
-                )
-            } else if is_class_of_device(data_type.to_owned()) {
-                DataTypeParseResult::ClassOfDeviceResult(ClassOfDevice::try_from(value))
-            } else if is_complete_list_of_128bit_service_uuids(data_type.to_owned()) {
-                DataTypeParseResult::CompleteListOf128BitServiceUuidsResult(
-                    CompleteListOf128BitServiceUuids::try_from(value),
-                )
-            } else if is_complete_list_of_16bit_service_uuids(data_type.to_owned()) {
-                DataTypeParseResult::CompleteListOf16BitServiceUuidsResult(
-                    CompleteListOf16BitServiceUuids::try_from(value),
-                )
-            } else if is_complete_list_of_32bit_service_uuids(data_type.to_owned()) {
-                DataTypeParseResult::CompleteListOf32BitServiceUuidsResult(
-                    CompleteListOf32BitServiceUuids::try_from(value),
-                )
-            } else if is_complete_local_name(data_type.to_owned()) {
-                DataTypeParseResult::CompleteLocalNameResult(CompleteLocalName::try_from(value))
-            } else if is_encrypted_data(data_type.to_owned()) {
-                DataTypeParseResult::EncryptedDataResult(EncryptedData::try_from(value))
-            } else if is_flags(data_type.to_owned()) {
-                DataTypeParseResult::FlagsResult(Flags::try_from(value))
-            } else if is_incomplete_list_of_128bit_service_uuids(data_type.to_owned()) {
-                DataTypeParseResult::IncompleteListOf128BitServiceUuidsResult(
-                    IncompleteListOf128BitServiceUuids::try_from(value),
-                )
-            } else if is_incomplete_list_of_16bit_service_uuids(data_type.to_owned()) {
-                DataTypeParseResult::IncompleteListOf16BitServiceUuidsResult(
-                    IncompleteListOf16BitServiceUuids::try_from(value),
-                )
-            } else if is_incomplete_list_of_32bit_service_uuids(data_type.to_owned()) {
-                DataTypeParseResult::IncompleteListOf32BitServiceUuidsResult(
-                    IncompleteListOf32BitServiceUuids::try_from(value),
-                )
-            } else if is_le_bluetooth_device_address(data_type.to_owned()) {
-                DataTypeParseResult::LeBluetoothDeviceAddressResult(
-                    LeBluetoothDeviceAddress::try_from(value),
-                )
-            } else if is_le_role(data_type.to_owned()) {
-                DataTypeParseResult::LeRoleResult(LeRole::try_from(value))
-            } else if is_le_secure_connections_confirmation_value(data_type.to_owned()) {
-                DataTypeParseResult::LeSecureConnectionsConfirmationValueResult(
-                    LeSecureConnectionsConfirmationValue::try_from(value),
-                )
-            } else if is_le_secure_connections_random_value(data_type.to_owned()) {
-                DataTypeParseResult::LeSecureConnectionsRandomValueResult(
-                    LeSecureConnectionsRandomValue::try_from(value),
-                )
-            } else if is_le_supported_features(data_type.to_owned()) {
-                DataTypeParseResult::LeSupportedFeaturesResult(LeSupportedFeatures::try_from(value))
-            } else if is_list_of_128bit_service_solicitation_uuids(data_type.to_owned()) {
-                DataTypeParseResult::ListOf128BitServiceSolicitationUUIDsResult(
-                    ListOf128BitServiceSolicitationUUIDs::try_from(value),
-                )
-            } else if is_list_of_16bit_service_solicitation_uuids(data_type.to_owned()) {
-                DataTypeParseResult::ListOf16BitServiceSolicitationUUIDsResult(
-                    ListOf16BitServiceSolicitationUUIDs::try_from(value),
-                )
-            } else if is_list_of_32bit_service_solicitation_uuids(data_type.to_owned()) {
-                DataTypeParseResult::ListOf32BitServiceSolicitationUUIDsResult(
-                    ListOf32BitServiceSolicitationUUIDs::try_from(value),
-                )
-            } else if is_manufacturer_specific_data(data_type.to_owned()) {
-                DataTypeParseResult::ManufacturerSpecificDataResult(
-                    ManufacturerSpecificData::try_from(value),
-                )
-            } else if is_periodic_advertising_response_timing_information(data_type.to_owned()) {
-                DataTypeParseResult::PeriodicAdvertisingResponseTimingInformationResult(
-                    PeriodicAdvertisingResponseTimingInformation::try_from(value),
-                )
-            } else if is_peripheral_connection_interval_range(data_type.to_owned()) {
-                DataTypeParseResult::PeripheralConnectionIntervalRangeResult(
-                    PeripheralConnectionIntervalRange::try_from(value),
-                )
-            } else if is_public_target_address(data_type.to_owned()) {
-                DataTypeParseResult::PublicTargetAddressResult(PublicTargetAddress::try_from(value))
-            } else if is_random_target_address(data_type.to_owned()) {
-                DataTypeParseResult::RandomTargetAddressResult(RandomTargetAddress::try_from(value))
-            } else if is_secure_simple_pairing_hash_c192(data_type.to_owned()) {
-                DataTypeParseResult::SecureSimplePairingHashC192Result(
-                    SecureSimplePairingHashC192::try_from(value),
-                )
-            } else if is_secure_simple_pairing_hash_c256(data_type.to_owned()) {
-                DataTypeParseResult::SecureSimplePairingHashC256Result(
-                    SecureSimplePairingHashC256::try_from(value),
-                )
-            } else if is_secure_simple_pairing_randomizer_r192(data_type.to_owned()) {
-                DataTypeParseResult::SecureSimplePairingRandomizerR192Result(
-                    SecureSimplePairingRandomizerR192::try_from(value),
-                )
-            } else if is_secure_simple_pairing_randomizer_r256(data_type.to_owned()) {
-                DataTypeParseResult::SecureSimplePairingRandomizerR256Result(
-                    SecureSimplePairingRandomizerR256::try_from(value),
-                )
-            } else if is_security_manager_oob(data_type.to_owned()) {
-                DataTypeParseResult::SecurityManagerOutOfBandResult(
-                    SecurityManagerOutOfBand::try_from(value),
-                )
-            } else if is_security_manager_tk_value(data_type.to_owned()) {
-                DataTypeParseResult::SecurityManagerTkValueResult(SecurityManagerTkValue::try_from(
-                    value,
-                ))
-            } else if is_service_data_128bit_uuid(data_type.to_owned()) {
-                DataTypeParseResult::ServiceData128BitUUIDResult(ServiceData128BitUUID::try_from(
-                    value,
-                ))
-            } else if is_service_data_16bit_uuid(data_type.to_owned()) {
-                DataTypeParseResult::ServiceData16BitUUIDResult(ServiceData16BitUUID::try_from(
-                    value,
-                ))
-            } else if is_service_data_32bit_uuid(data_type.to_owned()) {
-                DataTypeParseResult::ServiceData32BitUUIDResult(ServiceData32BitUUID::try_from(
-                    value,
-                ))
-            } else if is_shortened_local_name(data_type.to_owned()) {
-                DataTypeParseResult::ShortenedLocalNameResult(ShortenedLocalName::try_from(value))
-            } else if is_tx_power_level(data_type.to_owned()) {
-                DataTypeParseResult::TxPowerLevelResult(TxPowerLevel::try_from(value))
-            } else if is_uniform_resource_identifier(data_type.to_owned()) {
-                DataTypeParseResult::UniformResourceIdentifierResult(
-                    UniformResourceIdentifier::try_from(value),
-                )
-            } else {
-                DataTypeParseResult::DataTypeParseError(
-                    format!("Unknown data type :{}", data_type).to_string(),
-                )
-            }
-        } else {
-            DataTypeParseResult::DataTypeParseError("Invalid data size".to_string())
-        }
+    /// use ble_data_struct::data_types::{
+    ///     data_type_parser::{DataTypeParseResult, ParseContext},
+    ///     data_type_registry::DataTypeRegistry,
+    ///     raw_ad_structure::RawAdStructure,
+    /// };
+    ///
+    /// let mut registry = DataTypeRegistry::new();
+    /// registry.register(0x50, |value, _| {
+    ///     DataTypeParseResult::RawAdStructureResult(RawAdStructure::try_from(value))
+    /// });
+    ///
+    /// let data = [0x02, 0x50, 0x01];
+    /// assert!(registry.parse(&data, ParseContext::Advertising).is_raw_ad_structure());
+    /// assert!(!DataTypeParseResult::from(&data[..]).is_raw_ad_structure());
+    /// ```
+    pub fn is_raw_ad_structure(&self) -> bool {
+        matches!(self, DataTypeParseResult::RawAdStructureResult(_))
     }
-}
-
-// impl PartialEq for DataTypeParseResult {
-//     fn eq(&self, other: &Self) -> bool {
-//         match (self, other) {
-//             (Self::AdvertisingIntervalResult(l0), Self::AdvertisingIntervalResult(r0)) => l0 == r0,
-//             (Self::AdvertisingIntervalLongResult(l0), Self::AdvertisingIntervalLongResult(r0)) => l0 == r0,
-//             (Self::AppearanceResult(l0), Self::AppearanceResult(r0)) => l0 == r0,
-//             (Self::BigInfoResult(l0), Self::BigInfoResult(r0)) => l0 == r0,
-//             (Self::BroadcastCodeResult(l0), Self::BroadcastCodeResult(r0)) => l0 == r0,
-//             (Self::ChannelMapUpdateIndicationResult(l0), Self::ChannelMapUpdateIndicationResult(r0)) => l0 == r0,
-//             (Self::ClassOfDeviceResult(l0), Self::ClassOfDeviceResult(r0)) => l0 == r0,
-//             (Self::CompleteListOf128BitServiceUuidsResult(l0), Self::CompleteListOf128BitServiceUuidsResult(r0)) => l0 == r0,
-//             (Self::CompleteListOf16BitServiceUuidsResult(l0), Self::CompleteListOf16BitServiceUuidsResult(r0)) => l0 == r0,
-//             (Self::CompleteListOf32BitServiceUuidsResult(l0), Self::CompleteListOf32BitServiceUuidsResult(r0)) => l0 == r0,
-//             (Self::CompleteLocalNameResult(l0), Self::CompleteLocalNameResult(r0)) => l0 == r0,
-//             (Self::EncryptedDataResult(l0), Self::EncryptedDataResult(r0)) => l0 == r0,
-//             (Self::FlagsResult(l0), Self::FlagsResult(r0)) => l0 == r0,
-//             (Self::IncompleteListOf128BitServiceUuidsResult(l0), Self::IncompleteListOf128BitServiceUuidsResult(r0)) => l0 == r0,
-//             (Self::IncompleteListOf16BitServiceUuidsResult(l0), Self::IncompleteListOf16BitServiceUuidsResult(r0)) => l0 == r0,
-//             (Self::IncompleteListOf32BitServiceUuidsResult(l0), Self::IncompleteListOf32BitServiceUuidsResult(r0)) => l0 == r0,
-//             (Self::LeBluetoothDeviceAddressResult(l0), Self::LeBluetoothDeviceAddressResult(r0)) => l0 == r0,
-//             (Self::LeRoleResult(l0), Self::LeRoleResult(r0)) => l0 == r0,
-//             (Self::LeSecureConnectionsConfirmationValueResult(l0), Self::LeSecureConnectionsConfirmationValueResult(r0)) => l0 == r0,
-//             (Self::LeSecureConnectionsRandomValueResult(l0), Self::LeSecureConnectionsRandomValueResult(r0)) => l0 == r0,
-//             (Self::LeSupportedFeaturesResult(l0), Self::LeSupportedFeaturesResult(r0)) => l0 == r0,
-//             (Self::ListOf128BitServiceSolicitationUUIDsResult(l0), Self::ListOf128BitServiceSolicitationUUIDsResult(r0)) => l0 == r0,
-//             (Self::ListOf16BitServiceSolicitationUUIDsResult(l0), Self::ListOf16BitServiceSolicitationUUIDsResult(r0)) => l0 == r0,
-//             (Self::ListOf32BitServiceSolicitationUUIDsResult(l0), Self::ListOf32BitServiceSolicitationUUIDsResult(r0)) => l0 == r0,
-//             (Self::ManufacturerSpecificDataResult(l0), Self::ManufacturerSpecificDataResult(r0)) => l0 == r0,
-//             (Self::PeriodicAdvertisingResponseTimingInformationResult(l0), Self::PeriodicAdvertisingResponseTimingInformationResult(r0)) => l0 == r0,
-//             (Self::PeripheralConnectionIntervalRangeResult(l0), Self::PeripheralConnectionIntervalRangeResult(r0)) => l0 == r0,
-//             (Self::PublicTargetAddressResult(l0), Self::PublicTargetAddressResult(r0)) => l0 == r0,
-//             (Self::RandomTargetAddressResult(l0), Self::RandomTargetAddressResult(r0)) => l0 == r0,
-//             (Self::SecureSimplePairingHashC192Result(l0), Self::SecureSimplePairingHashC192Result(r0)) => l0 == r0,
-//             (Self::SecureSimplePairingHashC256Result(l0), Self::SecureSimplePairingHashC256Result(r0)) => l0 == r0,
-//             (Self::SecureSimplePairingRandomizerR192Result(l0), Self::SecureSimplePairingRandomizerR192Result(r0)) => l0 == r0,
-//             (Self::SecureSimplePairingRandomizerR256Result(l0), Self::SecureSimplePairingRandomizerR256Result(r0)) => l0 == r0,
-//             (Self::SecurityManagerOutOfBandResult(l0), Self::SecurityManagerOutOfBandResult(r0)) => l0 == r0,
-//             (Self::SecurityManagerTkValueResult(l0), Self::SecurityManagerTkValueResult(r0)) => l0 == r0,
-//             (Self::ServiceData128BitUUIDResult(l0), Self::ServiceData128BitUUIDResult(r0)) => l0 == r0,
-//             (Self::ServiceData16BitUUIDResult(l0), Self::ServiceData16BitUUIDResult(r0)) => l0 == r0,
-//             (Self::ServiceData32BitUUIDResult(l0), Self::ServiceData32BitUUIDResult(r0)) => l0 == r0,
-//             (Self::ShortenedLocalNameResult(l0), Self::ShortenedLocalNameResult(r0)) => l0 == r0,
-//             (Self::TxPowerLevelResult(l0), Self::TxPowerLevelResult(r0)) => l0 == r0,
-//             (Self::UniformResourceIdentifierResult(l0), Self::UniformResourceIdentifierResult(r0)) => l0 == r0,
-//             (Self::DataTypeParseErr(l0), Self::DataTypeParseErr(r0)) => l0 == r0,
-//             _ => false,
-//         }
-//     }
-// }
-/// Data types parse results.
-pub struct DataTypeParseResults {
-    /// Parse results.
-    pub results: Vec<DataTypeParseResult>,
-}
 
-impl DataTypeParseResults {
-    /// Create [`DataTypeParseResults`] from `Vec<DataTypeParseResult>`.
+    /// Returns `true` if the result is [`DataTypeParseResult::CustomResult`].
     ///
     /// # Examples
     ///
     /// ```
-    /// use ble_data_struct::data_types::advertising_interval_long::AdvertisingIntervalLong;
+    /// use ble_data_struct::data_types::{
+    ///     custom_data_type::CustomDataType,
+    ///     data_type_parser::{DataTypeParseResult, ParseContext},
+    ///     data_type_registry::DataTypeRegistry,
+    /// };
     ///
-    /// let advertising_interval_long: u32 = 0x01020304u32;
-    /// let result = AdvertisingIntervalLong::new(true, advertising_interval_long);
-    /// assert_eq!(5, result.length);
-    /// assert!(result.is_u32);
-    /// assert_eq!(advertising_interval_long, result.advertising_interval_long);
+    /// let registry = DataTypeRegistry::new().with_handler(0xf0, |value| CustomDataType::try_from(value));
     ///
-    /// let result = AdvertisingIntervalLong::new(false, advertising_interval_long);
-    /// assert_eq!(4, result.length);
-    /// assert!(!result.is_u32);
+    /// let data = [0x02, 0xf0, 0x01];
+    /// assert!(registry.parse(&data, ParseContext::Advertising).is_custom());
+    /// assert!(!DataTypeParseResult::from(&data[..]).is_custom());
+    /// ```
+    pub fn is_custom(&self) -> bool {
+        matches!(self, DataTypeParseResult::CustomResult(_))
+    }
+
+    /// Returns `true` if the result is [`DataTypeParseResult::Unknown`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ble_data_struct::data_types::{
+    ///     data_type_parser::DataTypeParseResult, raw_ad_structure::RawAdStructure,
+    ///     tx_power_level::TxPowerLevel,
+    /// };
+    ///
+    /// let data = [0x02, 0xfe, 0x01];
+    /// let result = DataTypeParseResult::from(&data[..]);
+    /// assert!(result.is_unknown());
     /// assert_eq!(
-    ///     advertising_interval_long & 0x00ffffff,
-    ///     result.advertising_interval_long
+    ///     DataTypeParseResult::Unknown(RawAdStructure::new(0xfe, &[0x01]).unwrap()),
+    ///     result
     /// );
+    ///
+    /// let data: Vec<u8> = TxPowerLevel::new(-4).into();
+    /// assert!(!DataTypeParseResult::from(&data).is_unknown());
     /// ```
-    pub fn new(results: Vec<DataTypeParseResult>) -> Self {
-        DataTypeParseResults { results }
+    pub fn is_unknown(&self) -> bool {
+        matches!(self, DataTypeParseResult::Unknown(_))
     }
-}
 
-impl From<&Vec<Vec<u8>>> for DataTypeParseResults {
-    /// Create [`DataTypeParseResults`] from `Vec<Vec<u8>>`.
+    /// Check OOB data block type (Class of Device, Simple Pairing and LE OOB values).
     ///
     /// # Examples
     ///
     /// ```
-    /// use ble_data_struct::data_types::{advertising_interval::AdvertisingInterval, data_type_parser::{DataTypeParseResult, DataTypeParseResults}};
+    /// use ble_data_struct::data_types::{
+    ///     data_type_parser::DataTypeParseResult, security_manager_tk_value::SecurityManagerTkValue,
+    ///     tx_power_level::TxPowerLevel,
+    /// };
     ///
-    /// let mut vec: Vec<Vec<u8>> = Vec::new();
-    /// let advertising_interval = 0x01;
-    /// let data: Vec<u8> = AdvertisingInterval::new(advertising_interval).into();
-    /// vec.push(data);
-    /// vec.push(vec![]);
+    /// let data: Vec<u8> = SecurityManagerTkValue::new(1).into();
+    /// assert!(DataTypeParseResult::from(&data).is_oob_type());
     ///
-    /// let results = DataTypeParseResults::from(&vec);
-    /// assert!(matches!(
-    ///     results.results.get(0),
-    ///     Some(DataTypeParseResult::AdvertisingIntervalResult(_))
-    /// ));
-    /// assert!(matches!(
-    ///     results.results.get(1),
-    ///     Some(DataTypeParseResult::DataTypeParseError(_))
-    /// ));
-    /// assert!(matches!(results.results.get(2), None));
+    /// let data: Vec<u8> = TxPowerLevel::new(-4).into();
+    /// assert!(!DataTypeParseResult::from(&data).is_oob_type());
     /// ```
-    fn from(value: &Vec<Vec<u8>>) -> Self {
-        Self {
-            results: value
-                .iter()
-                .map(|f| DataTypeParseResult::from(f))
-                .collect::<Vec<DataTypeParseResult>>(),
-        }
+    pub fn is_oob_type(&self) -> bool {
+        self.is_class_of_device()
+            || self.is_secure_simple_pairing_hash_c192()
+            || self.is_secure_simple_pairing_hash_c256()
+            || self.is_secure_simple_pairing_randomizer_r192()
+            || self.is_secure_simple_pairing_randomizer_r256()
+            || self.is_security_manager_tk_value()
+            || self.is_security_manager_oob()
+            || self.is_le_bluetooth_device_address()
+            || self.is_le_role()
+            || self.is_le_secure_connections_confirmation_value()
+            || self.is_le_secure_connections_random_value()
     }
-}
 
-impl From<&Vec<u8>> for DataTypeParseResults {
-    /// Create [`DataTypeParseResults`] from [`Vec<u8>`].
+    /// Check pairing / encryption related type.
     ///
     /// # Examples
     ///
     /// ```
-    /// use ble_data_struct::data_types::{advertising_interval::AdvertisingInterval, data_type_parser::{DataTypeParseResult, DataTypeParseResults}};
+    /// use ble_data_struct::data_types::{
+    ///     broadcast_code::BroadcastCode, data_type_parser::DataTypeParseResult,
+    ///     tx_power_level::TxPowerLevel,
+    /// };
     ///
-    /// let mut vec: Vec<Vec<u8>> = Vec::new();
-    /// let advertising_interval = 0x01;
-    /// vec.push(AdvertisingInterval::new(advertising_interval).into());
-    /// vec.push(vec![100]);
+    /// let data: Vec<u8> = BroadcastCode::new(&"1234".as_bytes().to_vec()).into();
+    /// assert!(DataTypeParseResult::from(&data).is_security_type());
     ///
-    /// let results = DataTypeParseResults::from(&vec);
-    /// assert!(matches!(
-    ///     results.results.get(0),
-    ///     Some(DataTypeParseResult::AdvertisingIntervalResult(_))
-    /// ));
+    /// let data: Vec<u8> = TxPowerLevel::new(-4).into();
+    /// assert!(!DataTypeParseResult::from(&data).is_security_type());
+    /// ```
+    pub fn is_security_type(&self) -> bool {
+        self.is_secure_simple_pairing_hash_c192()
+            || self.is_secure_simple_pairing_hash_c256()
+            || self.is_secure_simple_pairing_randomizer_r192()
+            || self.is_secure_simple_pairing_randomizer_r256()
+            || self.is_security_manager_tk_value()
+            || self.is_security_manager_oob()
+            || self.is_le_secure_connections_confirmation_value()
+            || self.is_le_secure_connections_random_value()
+            || self.is_encrypted_data()
+            || self.is_broadcast_code()
+    }
+
+    /// Check LE Audio broadcast type.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ble_data_struct::data_types::{
+    ///     broadcast_code::BroadcastCode, data_type_parser::DataTypeParseResult,
+    ///     tx_power_level::TxPowerLevel,
+    /// };
+    ///
+    /// let data: Vec<u8> = BroadcastCode::new(&"1234".as_bytes().to_vec()).into();
+    /// assert!(DataTypeParseResult::from(&data).is_audio_type());
+    ///
+    /// let data: Vec<u8> = TxPowerLevel::new(-4).into();
+    /// assert!(!DataTypeParseResult::from(&data).is_audio_type());
+    /// ```
+    pub fn is_audio_type(&self) -> bool {
+        self.is_big_info() || self.is_broadcast_code()
+    }
+
+    /// Check Service UUID list or Service Solicitation UUID list.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ble_data_struct::data_types::{
+    ///     complete_list_of_16bit_service_uuids::CompleteListOf16BitServiceUuids,
+    ///     data_type_parser::DataTypeParseResult, tx_power_level::TxPowerLevel,
+    /// };
+    /// use ble_data_struct::uuid_from_u16;
+    ///
+    /// let data: Vec<u8> = CompleteListOf16BitServiceUuids::new(&vec![uuid_from_u16(0x180f)]).into();
+    /// assert!(DataTypeParseResult::from(&data).is_uuid_list());
+    ///
+    /// let data: Vec<u8> = TxPowerLevel::new(-4).into();
+    /// assert!(!DataTypeParseResult::from(&data).is_uuid_list());
+    /// ```
+    pub fn is_uuid_list(&self) -> bool {
+        self.is_complete_list_of_16bit_service_uuids()
+            || self.is_complete_list_of_32bit_service_uuids()
+            || self.is_complete_list_of_128bit_service_uuids()
+            || self.is_incomplete_list_of_16bit_service_uuids()
+            || self.is_incomplete_list_of_32bit_service_uuids()
+            || self.is_incomplete_list_of_128bit_service_uuids()
+            || self.is_list_of_16bit_service_solicitation_uuids()
+            || self.is_list_of_32bit_service_solicitation_uuids()
+            || self.is_list_of_128bit_service_solicitation_uuids()
+    }
+
+    /// Returns the parse error if the result is an error.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ble_data_struct::data_types::{advertising_interval::AdvertisingInterval, data_type::DataType, data_type_parser::DataTypeParseResult, parse_error::DataTypeParseError};
+    /// use ble_data_struct::data_types::time_units::Slots625us;
+    ///
+    /// let advertising_interval = 0x01;
+    /// let data: Vec<u8> = AdvertisingInterval::new(Slots625us(advertising_interval)).into();
+    /// assert!(DataTypeParseResult::from(&data).parse_error().is_none());
+    ///
+    /// let data: Vec<u8> = vec![1, AdvertisingInterval::data_type()];
+    /// assert!(DataTypeParseResult::from(&data).parse_error().is_some());
+    ///
+    /// let data: Vec<u8> = Vec::new();
+    /// assert_eq!(
+    ///     Some(&DataTypeParseError::InvalidLength {
+    ///         expected: 2,
+    ///         actual: 0
+    ///     }),
+    ///     DataTypeParseResult::from(&data).parse_error()
+    /// );
+    /// ```
+    pub fn parse_error(&self) -> Option<&DataTypeParseError> {
+        match self {
+            DataTypeParseResult::AdvertisingIntervalResult(Err(error))
+            | DataTypeParseResult::AdvertisingIntervalLongResult(Err(error))
+            | DataTypeParseResult::AppearanceResult(Err(error))
+            | DataTypeParseResult::BigInfoResult(Err(error))
+            | DataTypeParseResult::BroadcastCodeResult(Err(error))
+            | DataTypeParseResult::BroadcastNameResult(Err(error))
+            | DataTypeParseResult::ChannelMapUpdateIndicationResult(Err(error))
+            | DataTypeParseResult::ClassOfDeviceResult(Err(error))
+            | DataTypeParseResult::CompleteListOf128BitServiceUuidsResult(Err(error))
+            | DataTypeParseResult::CompleteListOf16BitServiceUuidsResult(Err(error))
+            | DataTypeParseResult::CompleteListOf32BitServiceUuidsResult(Err(error))
+            | DataTypeParseResult::CompleteLocalNameResult(Err(error))
+            | DataTypeParseResult::DeviceIdResult(Err(error))
+            | DataTypeParseResult::ElectronicShelfLabelResult(Err(error))
+            | DataTypeParseResult::EncryptedDataResult(Err(error))
+            | DataTypeParseResult::FlagsResult(Err(error))
+            | DataTypeParseResult::IncompleteListOf128BitServiceUuidsResult(Err(error))
+            | DataTypeParseResult::IncompleteListOf16BitServiceUuidsResult(Err(error))
+            | DataTypeParseResult::IncompleteListOf32BitServiceUuidsResult(Err(error))
+            | DataTypeParseResult::LeBluetoothDeviceAddressResult(Err(error))
+            | DataTypeParseResult::LeRoleResult(Err(error))
+            | DataTypeParseResult::LeSecureConnectionsConfirmationValueResult(Err(error))
+            | DataTypeParseResult::LeSecureConnectionsRandomValueResult(Err(error))
+            | DataTypeParseResult::LeSupportedFeaturesResult(Err(error))
+            | DataTypeParseResult::ListOf128BitServiceSolicitationUUIDsResult(Err(error))
+            | DataTypeParseResult::ListOf16BitServiceSolicitationUUIDsResult(Err(error))
+            | DataTypeParseResult::ListOf32BitServiceSolicitationUUIDsResult(Err(error))
+            | DataTypeParseResult::ManufacturerSpecificDataResult(Err(error))
+            | DataTypeParseResult::MeshBeaconResult(Err(error))
+            | DataTypeParseResult::MeshMessageResult(Err(error))
+            | DataTypeParseResult::PbAdvResult(Err(error))
+            | DataTypeParseResult::PeriodicAdvertisingResponseTimingInformationResult(Err(error))
+            | DataTypeParseResult::PeripheralConnectionIntervalRangeResult(Err(error))
+            | DataTypeParseResult::PublicTargetAddressResult(Err(error))
+            | DataTypeParseResult::RandomTargetAddressResult(Err(error))
+            | DataTypeParseResult::ResolvableSetIdentifierResult(Err(error))
+            | DataTypeParseResult::SecureSimplePairingHashC192Result(Err(error))
+            | DataTypeParseResult::SecureSimplePairingHashC256Result(Err(error))
+            | DataTypeParseResult::SecureSimplePairingRandomizerR192Result(Err(error))
+            | DataTypeParseResult::SecureSimplePairingRandomizerR256Result(Err(error))
+            | DataTypeParseResult::SecurityManagerOutOfBandResult(Err(error))
+            | DataTypeParseResult::SecurityManagerTkValueResult(Err(error))
+            | DataTypeParseResult::ServiceData128BitUUIDResult(Err(error))
+            | DataTypeParseResult::ServiceData16BitUUIDResult(Err(error))
+            | DataTypeParseResult::ServiceData32BitUUIDResult(Err(error))
+            | DataTypeParseResult::ShortenedLocalNameResult(Err(error))
+            | DataTypeParseResult::ThreeDInformationDataResult(Err(error))
+            | DataTypeParseResult::TxPowerLevelResult(Err(error))
+            | DataTypeParseResult::UniformResourceIdentifierResult(Err(error))
+            | DataTypeParseResult::RawAdStructureResult(Err(error))
+            | DataTypeParseResult::CustomResult(Err(error))
+            | DataTypeParseResult::DataTypeParseError(error) => Some(error),
+            _ => None,
+        }
+    }
+
+    /// Returns the parse error code if the result is an error.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ble_data_struct::data_types::{
+    ///     data_type_parser::DataTypeParseResult,
+    ///     data_type_parser::ParseContext,
+    ///     flags::Flags,
+    ///     parse_error::{DATA_TYPE_NOT_ALLOWED, INVALID_DATA_SIZE},
+    ///     tx_power_level::TxPowerLevel,
+    /// };
+    ///
+    /// let data: Vec<u8> = TxPowerLevel::new(-4).into();
+    /// assert_eq!(None, DataTypeParseResult::from(&data).error_code());
+    ///
+    /// let data: Vec<u8> = Vec::new();
+    /// assert_eq!(Some(INVALID_DATA_SIZE), DataTypeParseResult::from(&data).error_code());
+    ///
+    /// let data: Vec<u8> = Flags::new(&vec![false, true]).into();
+    /// assert_eq!(
+    ///     Some(DATA_TYPE_NOT_ALLOWED),
+    ///     DataTypeParseResult::from_with_context(&data, ParseContext::ScanResponse).error_code()
+    /// );
+    /// ```
+    pub fn error_code(&self) -> Option<u8> {
+        self.parse_error().map(DataTypeParseError::error_code)
+    }
+
+    /// Returns the parsed data type as [`AdStructure`] if the result is not an error.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ble_data_struct::data_types::{
+    ///     data_type_parser::DataTypeParseResult, tx_power_level::TxPowerLevel,
+    /// };
+    ///
+    /// let data: Vec<u8> = TxPowerLevel::new(-4).into();
+    /// let result = DataTypeParseResult::from(&data);
+    /// assert_eq!(Some(data), result.ad_structure().map(|f| f.to_bytes()));
+    ///
+    /// let data: Vec<u8> = Vec::new();
+    /// assert!(DataTypeParseResult::from(&data).ad_structure().is_none());
+    /// ```
+    pub fn ad_structure(&self) -> Option<&dyn AdStructure> {
+        match self {
+            DataTypeParseResult::AdvertisingIntervalResult(Ok(data_type)) => Some(data_type),
+            DataTypeParseResult::AdvertisingIntervalLongResult(Ok(data_type)) => Some(data_type),
+            DataTypeParseResult::AppearanceResult(Ok(data_type)) => Some(data_type),
+            DataTypeParseResult::BigInfoResult(Ok(data_type)) => Some(data_type.as_ref()),
+            DataTypeParseResult::BroadcastCodeResult(Ok(data_type)) => Some(data_type),
+            DataTypeParseResult::BroadcastNameResult(Ok(data_type)) => Some(data_type),
+            DataTypeParseResult::ChannelMapUpdateIndicationResult(Ok(data_type)) => Some(data_type),
+            DataTypeParseResult::ClassOfDeviceResult(Ok(data_type)) => Some(data_type),
+            DataTypeParseResult::CompleteListOf128BitServiceUuidsResult(Ok(data_type)) => {
+                Some(data_type)
+            }
+            DataTypeParseResult::CompleteListOf16BitServiceUuidsResult(Ok(data_type)) => {
+                Some(data_type)
+            }
+            DataTypeParseResult::CompleteListOf32BitServiceUuidsResult(Ok(data_type)) => {
+                Some(data_type)
+            }
+            DataTypeParseResult::CompleteLocalNameResult(Ok(data_type)) => Some(data_type),
+            DataTypeParseResult::DeviceIdResult(Ok(data_type)) => Some(data_type),
+            DataTypeParseResult::ElectronicShelfLabelResult(Ok(data_type)) => Some(data_type),
+            DataTypeParseResult::EncryptedDataResult(Ok(data_type)) => Some(data_type),
+            DataTypeParseResult::FlagsResult(Ok(data_type)) => Some(data_type),
+            DataTypeParseResult::IncompleteListOf128BitServiceUuidsResult(Ok(data_type)) => {
+                Some(data_type)
+            }
+            DataTypeParseResult::IncompleteListOf16BitServiceUuidsResult(Ok(data_type)) => {
+                Some(data_type)
+            }
+            DataTypeParseResult::IncompleteListOf32BitServiceUuidsResult(Ok(data_type)) => {
+                Some(data_type)
+            }
+            DataTypeParseResult::LeBluetoothDeviceAddressResult(Ok(data_type)) => Some(data_type),
+            DataTypeParseResult::LeRoleResult(Ok(data_type)) => Some(data_type),
+            DataTypeParseResult::LeSecureConnectionsConfirmationValueResult(Ok(data_type)) => {
+                Some(data_type)
+            }
+            DataTypeParseResult::LeSecureConnectionsRandomValueResult(Ok(data_type)) => {
+                Some(data_type)
+            }
+            DataTypeParseResult::LeSupportedFeaturesResult(Ok(data_type)) => Some(data_type),
+            DataTypeParseResult::ListOf128BitServiceSolicitationUUIDsResult(Ok(data_type)) => {
+                Some(data_type)
+            }
+            DataTypeParseResult::ListOf16BitServiceSolicitationUUIDsResult(Ok(data_type)) => {
+                Some(data_type)
+            }
+            DataTypeParseResult::ListOf32BitServiceSolicitationUUIDsResult(Ok(data_type)) => {
+                Some(data_type)
+            }
+            DataTypeParseResult::ManufacturerSpecificDataResult(Ok(data_type)) => Some(data_type),
+            DataTypeParseResult::MeshBeaconResult(Ok(data_type)) => Some(data_type),
+            DataTypeParseResult::MeshMessageResult(Ok(data_type)) => Some(data_type),
+            DataTypeParseResult::PbAdvResult(Ok(data_type)) => Some(data_type),
+            DataTypeParseResult::PeriodicAdvertisingResponseTimingInformationResult(Ok(
+                data_type,
+            )) => Some(data_type),
+            DataTypeParseResult::PeripheralConnectionIntervalRangeResult(Ok(data_type)) => {
+                Some(data_type)
+            }
+            DataTypeParseResult::PublicTargetAddressResult(Ok(data_type)) => Some(data_type),
+            DataTypeParseResult::RandomTargetAddressResult(Ok(data_type)) => Some(data_type),
+            DataTypeParseResult::ResolvableSetIdentifierResult(Ok(data_type)) => Some(data_type),
+            DataTypeParseResult::SecureSimplePairingHashC192Result(Ok(data_type)) => {
+                Some(data_type)
+            }
+            DataTypeParseResult::SecureSimplePairingHashC256Result(Ok(data_type)) => {
+                Some(data_type)
+            }
+            DataTypeParseResult::SecureSimplePairingRandomizerR192Result(Ok(data_type)) => {
+                Some(data_type)
+            }
+            DataTypeParseResult::SecureSimplePairingRandomizerR256Result(Ok(data_type)) => {
+                Some(data_type)
+            }
+            DataTypeParseResult::SecurityManagerOutOfBandResult(Ok(data_type)) => Some(data_type),
+            DataTypeParseResult::SecurityManagerTkValueResult(Ok(data_type)) => Some(data_type),
+            DataTypeParseResult::ServiceData128BitUUIDResult(Ok(data_type)) => Some(data_type),
+            DataTypeParseResult::ServiceData16BitUUIDResult(Ok(data_type)) => Some(data_type),
+            DataTypeParseResult::ServiceData32BitUUIDResult(Ok(data_type)) => Some(data_type),
+            DataTypeParseResult::ShortenedLocalNameResult(Ok(data_type)) => Some(data_type),
+            DataTypeParseResult::ThreeDInformationDataResult(Ok(data_type)) => Some(data_type),
+            DataTypeParseResult::TxPowerLevelResult(Ok(data_type)) => Some(data_type),
+            DataTypeParseResult::UniformResourceIdentifierResult(Ok(data_type)) => Some(data_type),
+            DataTypeParseResult::RawAdStructureResult(Ok(data_type)) => Some(data_type),
+            DataTypeParseResult::CustomResult(Ok(data_type)) => Some(data_type),
+            DataTypeParseResult::Unknown(data_type) => Some(data_type),
+            _ => None,
+        }
+    }
+
+    /// Returns non-fatal spec deviations of the parsed data type.
+    ///
+    /// RFU bits/values, deprecated fields (`Simultaneous LE and BR/EDR` flags, "Previously Used" since CSS v10)
+    /// and unexpected data lengths are reported.
+    /// Parse errors are not included (see [`DataTypeParseResult::parse_error`]).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ble_data_struct::data_types::{
+    ///     data_type::DataType, data_type_parser::DataTypeParseResult, le_role::LeRole,
+    ///     parse_warning::ParseWarning, tx_power_level::TxPowerLevel,
+    /// };
+    ///
+    /// let data: Vec<u8> = TxPowerLevel::new(-4).into();
+    /// assert!(DataTypeParseResult::from(&data).warnings().is_empty());
+    ///
+    /// let data: Vec<u8> = vec![2, LeRole::data_type(), 0x10];
+    /// assert_eq!(
+    ///     vec![ParseWarning::ReservedForFutureUse {
+    ///         data_type: LeRole::data_type()
+    ///     }],
+    ///     DataTypeParseResult::from(&data).warnings()
+    /// );
+    ///
+    /// let data: Vec<u8> = vec![3, TxPowerLevel::data_type(), 0xfc, 0x00];
+    /// assert_eq!(
+    ///     vec![ParseWarning::UnexpectedLength {
+    ///         data_type: TxPowerLevel::data_type(),
+    ///         length: 3
+    ///     }],
+    ///     DataTypeParseResult::from(&data).warnings()
+    /// );
+    /// ```
+    pub fn warnings(&self) -> Vec<ParseWarning> {
+        let mut warnings: Vec<ParseWarning> = Vec::new();
+        match self {
+            DataTypeParseResult::AdvertisingIntervalResult(Ok(data_type)) => {
+                warnings.extend(check_fixed_length(
+                    AdvertisingInterval::data_type(),
+                    data_type.length,
+                    2,
+                ));
+            }
+            DataTypeParseResult::AppearanceResult(Ok(data_type)) => {
+                warnings.extend(check_fixed_length(
+                    Appearance::data_type(),
+                    data_type.length,
+                    2,
+                ));
+            }
+            DataTypeParseResult::BroadcastCodeResult(Ok(data_type)) => {
+                warnings.extend(check_fixed_length(
+                    BroadcastCode::data_type(),
+                    data_type.length,
+                    16,
+                ));
+            }
+            DataTypeParseResult::DeviceIdResult(Ok(data_type)) => {
+                warnings.extend(check_fixed_length(
+                    DeviceId::data_type(),
+                    data_type.length,
+                    8,
+                ));
+            }
+            DataTypeParseResult::LeBluetoothDeviceAddressResult(Ok(data_type)) => {
+                warnings.extend(check_fixed_length(
+                    LeBluetoothDeviceAddress::data_type(),
+                    data_type.length,
+                    7,
+                ));
+            }
+            DataTypeParseResult::LeSecureConnectionsConfirmationValueResult(Ok(data_type)) => {
+                warnings.extend(check_fixed_length(
+                    LeSecureConnectionsConfirmationValue::data_type(),
+                    data_type.length,
+                    16,
+                ));
+            }
+            DataTypeParseResult::LeSecureConnectionsRandomValueResult(Ok(data_type)) => {
+                warnings.extend(check_fixed_length(
+                    LeSecureConnectionsRandomValue::data_type(),
+                    data_type.length,
+                    16,
+                ));
+            }
+            DataTypeParseResult::PeripheralConnectionIntervalRangeResult(Ok(data_type)) => {
+                warnings.extend(check_fixed_length(
+                    PeripheralConnectionIntervalRange::data_type(),
+                    data_type.length,
+                    4,
+                ));
+            }
+            DataTypeParseResult::ResolvableSetIdentifierResult(Ok(data_type)) => {
+                warnings.extend(check_fixed_length(
+                    ResolvableSetIdentifier::data_type(),
+                    data_type.length,
+                    6,
+                ));
+            }
+            DataTypeParseResult::SecureSimplePairingHashC192Result(Ok(data_type)) => {
+                warnings.extend(check_fixed_length(
+                    SecureSimplePairingHashC192::data_type(),
+                    data_type.length,
+                    16,
+                ));
+            }
+            DataTypeParseResult::SecureSimplePairingHashC256Result(Ok(data_type)) => {
+                warnings.extend(check_fixed_length(
+                    SecureSimplePairingHashC256::data_type(),
+                    data_type.length,
+                    16,
+                ));
+            }
+            DataTypeParseResult::SecureSimplePairingRandomizerR192Result(Ok(data_type)) => {
+                warnings.extend(check_fixed_length(
+                    SecureSimplePairingRandomizerR192::data_type(),
+                    data_type.length,
+                    16,
+                ));
+            }
+            DataTypeParseResult::SecureSimplePairingRandomizerR256Result(Ok(data_type)) => {
+                warnings.extend(check_fixed_length(
+                    SecureSimplePairingRandomizerR256::data_type(),
+                    data_type.length,
+                    16,
+                ));
+            }
+            DataTypeParseResult::SecurityManagerOutOfBandResult(Ok(data_type)) => {
+                warnings.extend(check_fixed_length(
+                    SecurityManagerOutOfBand::data_type(),
+                    data_type.length,
+                    1,
+                ));
+            }
+            DataTypeParseResult::SecurityManagerTkValueResult(Ok(data_type)) => {
+                warnings.extend(check_fixed_length(
+                    SecurityManagerTkValue::data_type(),
+                    data_type.length,
+                    16,
+                ));
+            }
+            DataTypeParseResult::ThreeDInformationDataResult(Ok(data_type)) => {
+                warnings.extend(check_fixed_length(
+                    ThreeDInformationData::data_type(),
+                    data_type.length,
+                    2,
+                ));
+            }
+            DataTypeParseResult::TxPowerLevelResult(Ok(data_type)) => {
+                warnings.extend(check_fixed_length(
+                    TxPowerLevel::data_type(),
+                    data_type.length,
+                    1,
+                ));
+            }
+            DataTypeParseResult::CompleteListOf128BitServiceUuidsResult(Ok(data_type)) => {
+                warnings.extend(check_multiple_length(
+                    CompleteListOf128BitServiceUuids::data_type(),
+                    data_type.length,
+                    16,
+                ));
+            }
+            DataTypeParseResult::CompleteListOf16BitServiceUuidsResult(Ok(data_type)) => {
+                warnings.extend(check_multiple_length(
+                    CompleteListOf16BitServiceUuids::data_type(),
+                    data_type.length,
+                    2,
+                ));
+            }
+            DataTypeParseResult::CompleteListOf32BitServiceUuidsResult(Ok(data_type)) => {
+                warnings.extend(check_multiple_length(
+                    CompleteListOf32BitServiceUuids::data_type(),
+                    data_type.length,
+                    4,
+                ));
+            }
+            DataTypeParseResult::IncompleteListOf128BitServiceUuidsResult(Ok(data_type)) => {
+                warnings.extend(check_multiple_length(
+                    IncompleteListOf128BitServiceUuids::data_type(),
+                    data_type.length,
+                    16,
+                ));
+            }
+            DataTypeParseResult::IncompleteListOf16BitServiceUuidsResult(Ok(data_type)) => {
+                warnings.extend(check_multiple_length(
+                    IncompleteListOf16BitServiceUuids::data_type(),
+                    data_type.length,
+                    2,
+                ));
+            }
+            DataTypeParseResult::IncompleteListOf32BitServiceUuidsResult(Ok(data_type)) => {
+                warnings.extend(check_multiple_length(
+                    IncompleteListOf32BitServiceUuids::data_type(),
+                    data_type.length,
+                    4,
+                ));
+            }
+            DataTypeParseResult::ListOf128BitServiceSolicitationUUIDsResult(Ok(data_type)) => {
+                warnings.extend(check_multiple_length(
+                    ListOf128BitServiceSolicitationUUIDs::data_type(),
+                    data_type.length,
+                    16,
+                ));
+            }
+            DataTypeParseResult::ListOf16BitServiceSolicitationUUIDsResult(Ok(data_type)) => {
+                warnings.extend(check_multiple_length(
+                    ListOf16BitServiceSolicitationUUIDs::data_type(),
+                    data_type.length,
+                    2,
+                ));
+            }
+            DataTypeParseResult::ListOf32BitServiceSolicitationUUIDsResult(Ok(data_type)) => {
+                warnings.extend(check_multiple_length(
+                    ListOf32BitServiceSolicitationUUIDs::data_type(),
+                    data_type.length,
+                    4,
+                ));
+            }
+            DataTypeParseResult::PublicTargetAddressResult(Ok(data_type)) => {
+                warnings.extend(check_multiple_length(
+                    PublicTargetAddress::data_type(),
+                    data_type.length,
+                    6,
+                ));
+            }
+            DataTypeParseResult::RandomTargetAddressResult(Ok(data_type)) => {
+                warnings.extend(check_multiple_length(
+                    RandomTargetAddress::data_type(),
+                    data_type.length,
+                    6,
+                ));
+            }
+            DataTypeParseResult::BroadcastNameResult(Ok(data_type))
+                if !(BROADCAST_NAME_MIN_SIZE..=BROADCAST_NAME_MAX_SIZE)
+                    .contains(&data_type.broadcast_name.len()) =>
+            {
+                warnings.push(ParseWarning::UnexpectedLength {
+                    data_type: BroadcastName::data_type(),
+                    length: data_type.length,
+                });
+            }
+            DataTypeParseResult::ChannelMapUpdateIndicationResult(Ok(data_type)) => {
+                let code = ChannelMapUpdateIndication::data_type();
+                warnings.extend(check_fixed_length(code, data_type.length, 7));
+                if data_type.ch_m.iter().skip(37).any(|f| *f) {
+                    warnings.push(ParseWarning::ReservedForFutureUse { data_type: code });
+                }
+            }
+            DataTypeParseResult::ClassOfDeviceResult(Ok(data_type)) => {
+                let code = ClassOfDevice::data_type();
+                warnings.extend(check_fixed_length(code, data_type.length, 3));
+                if data_type.class_of_device & 0b11 != 0 {
+                    warnings.push(ParseWarning::ReservedForFutureUse { data_type: code });
+                }
+            }
+            DataTypeParseResult::FlagsResult(Ok(data_type)) => {
+                let code = Flags::data_type();
+                if data_type.flags.iter().skip(5).any(|f| *f) {
+                    warnings.push(ParseWarning::ReservedForFutureUse { data_type: code });
+                }
+                if data_type.flags.iter().skip(3).take(2).any(|f| *f) {
+                    warnings.push(ParseWarning::Deprecated { data_type: code });
+                }
+            }
+            DataTypeParseResult::LeRoleResult(Ok(data_type)) => {
+                let code = LeRole::data_type();
+                warnings.extend(check_fixed_length(code, data_type.length, 1));
+                if data_type.le_role > 0x03 {
+                    warnings.push(ParseWarning::ReservedForFutureUse { data_type: code });
+                }
+            }
+            DataTypeParseResult::MeshBeaconResult(Ok(data_type))
+                if data_type.beacon_type > MESH_PRIVATE_BEACON =>
+            {
+                warnings.push(ParseWarning::ReservedForFutureUse {
+                    data_type: MeshBeacon::data_type(),
+                });
+            }
+            _ => {}
+        }
+        warnings
+    }
+
+    /// Create [`DataTypeParseResult`] from [`Vec<u8>`] in [`ParseContext`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ble_data_struct::data_types::{
+    ///     data_type_parser::{DataTypeParseResult, ParseContext},
+    ///     device_id::DeviceId,
+    ///     parse_error::DATA_TYPE_NOT_ALLOWED,
+    /// };
+    ///
+    /// let data: Vec<u8> = DeviceId::new(0x0001, 0x004c, 0x0102, 0x0304).into();
+    /// assert!(DataTypeParseResult::from_with_context(&data, ParseContext::Eir).is_device_id());
+    /// assert!(DataTypeParseResult::from_with_context(&data, ParseContext::Oob)
+    ///     .is_security_manager_tk_value());
+    /// assert_eq!(
+    ///     Some(DATA_TYPE_NOT_ALLOWED),
+    ///     DataTypeParseResult::from_with_context(&data, ParseContext::Advertising).error_code()
+    /// );
+    /// ```
+    pub fn from_with_context(value: &[u8], context: ParseContext) -> Self {
+        parse_in_context(&DEFAULT_PARSERS, value, context)
+    }
+}
+
+impl From<&Vec<u8>> for DataTypeParseResult {
+    /// Create [`DataTypeParseResult`] from [`Vec<u8>`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ble_data_struct::data_types::{advertising_interval::AdvertisingInterval, data_type_parser::DataTypeParseResult};
+    /// use ble_data_struct::data_types::time_units::Slots625us;
+    ///
+    /// let advertising_interval = 0x01;
+    /// let data: Vec<u8> = AdvertisingInterval::new(Slots625us(advertising_interval)).into();
+    /// assert!(matches!(
+    ///     DataTypeParseResult::from(&data),
+    ///     DataTypeParseResult::AdvertisingIntervalResult(_)
+    /// ));
+    /// let data: Vec<u8> = Vec::new();
+    /// assert!(matches!(
+    ///     DataTypeParseResult::from(&data),
+    ///     DataTypeParseResult::DataTypeParseError(_)
+    /// ));
+    fn from(value: &Vec<u8>) -> Self {
+        Self::from(value.as_slice())
+    }
+}
+
+impl From<&[u8]> for DataTypeParseResult {
+    /// Create [`DataTypeParseResult`] from `&[u8]`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ble_data_struct::data_types::{advertising_interval::AdvertisingInterval, data_type_parser::DataTypeParseResult};
+    /// use ble_data_struct::data_types::time_units::Slots625us;
+    ///
+    /// let data: Vec<u8> = AdvertisingInterval::new(Slots625us(0x01)).into();
+    /// assert_eq!(
+    ///     DataTypeParseResult::from(&data),
+    ///     DataTypeParseResult::from(&data[..])
+    /// );
+    /// ```
+    fn from(value: &[u8]) -> Self {
+        let result = parse(value, ParseContext::default());
+        #[cfg(feature = "tracing")]
+        trace_result(value, &result);
+        result
+    }
+}
+
+/// Parser of single EIR/AD/SRD/ACAD/OOB structure.
+pub type DataTypeParseFn = fn(&[u8], ParseContext) -> DataTypeParseResult;
+
+/// Built-in parsers (one entry per supported data type).
+const DATA_TYPE_PARSERS: &[(AdType, DataTypeParseFn)] = &[
+    (AdType::AdvertisingInterval, |value, _| {
+        DataTypeParseResult::AdvertisingIntervalResult(AdvertisingInterval::try_from(value))
+    }),
+    (AdType::AdvertisingIntervalLong, |value, _| {
+        DataTypeParseResult::AdvertisingIntervalLongResult(AdvertisingIntervalLong::try_from(value))
+    }),
+    (AdType::Appearance, |value, _| {
+        DataTypeParseResult::AppearanceResult(Appearance::try_from(value))
+    }),
+    (AdType::BigInfo, |value, _| {
+        DataTypeParseResult::BigInfoResult(BigInfo::try_from(value).map(Box::new))
+    }),
+    (AdType::BroadcastCode, |value, _| {
+        DataTypeParseResult::BroadcastCodeResult(BroadcastCode::try_from(value))
+    }),
+    (AdType::BroadcastName, |value, _| {
+        DataTypeParseResult::BroadcastNameResult(BroadcastName::try_from(value))
+    }),
+    (AdType::ChannelMapUpdateIndication, |value, _| {
+        DataTypeParseResult::ChannelMapUpdateIndicationResult(ChannelMapUpdateIndication::try_from(
+            value,
+        ))
+    }),
+    (AdType::ClassOfDevice, |value, _| {
+        DataTypeParseResult::ClassOfDeviceResult(ClassOfDevice::try_from(value))
+    }),
+    (AdType::CompleteListOf128BitServiceUuids, |value, _| {
+        DataTypeParseResult::CompleteListOf128BitServiceUuidsResult(
+            CompleteListOf128BitServiceUuids::try_from(value),
+        )
+    }),
+    (AdType::CompleteListOf16BitServiceUuids, |value, _| {
+        DataTypeParseResult::CompleteListOf16BitServiceUuidsResult(
+            CompleteListOf16BitServiceUuids::try_from(value),
+        )
+    }),
+    (AdType::CompleteListOf32BitServiceUuids, |value, _| {
+        DataTypeParseResult::CompleteListOf32BitServiceUuidsResult(
+            CompleteListOf32BitServiceUuids::try_from(value),
+        )
+    }),
+    (AdType::CompleteLocalName, |value, _| {
+        DataTypeParseResult::CompleteLocalNameResult(CompleteLocalName::try_from(value))
+    }),
+    (
+        AdType::DeviceIdOrSecurityManagerTkValue,
+        |value, context| match context {
+            ParseContext::Eir => DataTypeParseResult::DeviceIdResult(DeviceId::try_from(value)),
+            _ => DataTypeParseResult::SecurityManagerTkValueResult(
+                SecurityManagerTkValue::try_from(value),
+            ),
+        },
+    ),
+    (AdType::ElectronicShelfLabel, |value, _| {
+        DataTypeParseResult::ElectronicShelfLabelResult(ElectronicShelfLabel::try_from(value))
+    }),
+    (AdType::EncryptedData, |value, _| {
+        DataTypeParseResult::EncryptedDataResult(EncryptedData::try_from(value))
+    }),
+    (AdType::Flags, |value, _| {
+        DataTypeParseResult::FlagsResult(Flags::try_from(value))
+    }),
+    (AdType::IncompleteListOf128BitServiceUuids, |value, _| {
+        DataTypeParseResult::IncompleteListOf128BitServiceUuidsResult(
+            IncompleteListOf128BitServiceUuids::try_from(value),
+        )
+    }),
+    (AdType::IncompleteListOf16BitServiceUuids, |value, _| {
+        DataTypeParseResult::IncompleteListOf16BitServiceUuidsResult(
+            IncompleteListOf16BitServiceUuids::try_from(value),
+        )
+    }),
+    (AdType::IncompleteListOf32BitServiceUuids, |value, _| {
+        DataTypeParseResult::IncompleteListOf32BitServiceUuidsResult(
+            IncompleteListOf32BitServiceUuids::try_from(value),
+        )
+    }),
+    (AdType::LeBluetoothDeviceAddress, |value, _| {
+        DataTypeParseResult::LeBluetoothDeviceAddressResult(LeBluetoothDeviceAddress::try_from(
+            value,
+        ))
+    }),
+    (AdType::LeRole, |value, _| {
+        DataTypeParseResult::LeRoleResult(LeRole::try_from(value))
+    }),
+    (AdType::LeSecureConnectionsConfirmationValue, |value, _| {
+        DataTypeParseResult::LeSecureConnectionsConfirmationValueResult(
+            LeSecureConnectionsConfirmationValue::try_from(value),
+        )
+    }),
+    (AdType::LeSecureConnectionsRandomValue, |value, _| {
+        DataTypeParseResult::LeSecureConnectionsRandomValueResult(
+            LeSecureConnectionsRandomValue::try_from(value),
+        )
+    }),
+    (AdType::LeSupportedFeatures, |value, _| {
+        DataTypeParseResult::LeSupportedFeaturesResult(LeSupportedFeatures::try_from(value))
+    }),
+    (AdType::ListOf128BitServiceSolicitationUUIDs, |value, _| {
+        DataTypeParseResult::ListOf128BitServiceSolicitationUUIDsResult(
+            ListOf128BitServiceSolicitationUUIDs::try_from(value),
+        )
+    }),
+    (AdType::ListOf16BitServiceSolicitationUUIDs, |value, _| {
+        DataTypeParseResult::ListOf16BitServiceSolicitationUUIDsResult(
+            ListOf16BitServiceSolicitationUUIDs::try_from(value),
+        )
+    }),
+    (AdType::ListOf32BitServiceSolicitationUUIDs, |value, _| {
+        DataTypeParseResult::ListOf32BitServiceSolicitationUUIDsResult(
+            ListOf32BitServiceSolicitationUUIDs::try_from(value),
+        )
+    }),
+    (AdType::ManufacturerSpecificData, |value, _| {
+        DataTypeParseResult::ManufacturerSpecificDataResult(ManufacturerSpecificData::try_from(
+            value,
+        ))
+    }),
+    (AdType::MeshBeacon, |value, _| {
+        DataTypeParseResult::MeshBeaconResult(MeshBeacon::try_from(value))
+    }),
+    (AdType::MeshMessage, |value, _| {
+        DataTypeParseResult::MeshMessageResult(MeshMessage::try_from(value))
+    }),
+    (AdType::PbAdv, |value, _| {
+        DataTypeParseResult::PbAdvResult(PbAdv::try_from(value))
+    }),
+    (
+        AdType::PeriodicAdvertisingResponseTimingInformation,
+        |value, _| {
+            DataTypeParseResult::PeriodicAdvertisingResponseTimingInformationResult(
+                PeriodicAdvertisingResponseTimingInformation::try_from(value),
+            )
+        },
+    ),
+    (AdType::PeripheralConnectionIntervalRange, |value, _| {
+        DataTypeParseResult::PeripheralConnectionIntervalRangeResult(
+            PeripheralConnectionIntervalRange::try_from(value),
+        )
+    }),
+    (AdType::PublicTargetAddress, |value, _| {
+        DataTypeParseResult::PublicTargetAddressResult(PublicTargetAddress::try_from(value))
+    }),
+    (AdType::RandomTargetAddress, |value, _| {
+        DataTypeParseResult::RandomTargetAddressResult(RandomTargetAddress::try_from(value))
+    }),
+    (AdType::ResolvableSetIdentifier, |value, _| {
+        DataTypeParseResult::ResolvableSetIdentifierResult(ResolvableSetIdentifier::try_from(value))
+    }),
+    (AdType::SecureSimplePairingHashC192, |value, _| {
+        DataTypeParseResult::SecureSimplePairingHashC192Result(
+            SecureSimplePairingHashC192::try_from(value),
+        )
+    }),
+    (AdType::SecureSimplePairingHashC256, |value, _| {
+        DataTypeParseResult::SecureSimplePairingHashC256Result(
+            SecureSimplePairingHashC256::try_from(value),
+        )
+    }),
+    (AdType::SecureSimplePairingRandomizerR192, |value, _| {
+        DataTypeParseResult::SecureSimplePairingRandomizerR192Result(
+            SecureSimplePairingRandomizerR192::try_from(value),
+        )
+    }),
+    (AdType::SecureSimplePairingRandomizerR256, |value, _| {
+        DataTypeParseResult::SecureSimplePairingRandomizerR256Result(
+            SecureSimplePairingRandomizerR256::try_from(value),
+        )
+    }),
+    (AdType::SecurityManagerOutOfBand, |value, _| {
+        DataTypeParseResult::SecurityManagerOutOfBandResult(SecurityManagerOutOfBand::try_from(
+            value,
+        ))
+    }),
+    (AdType::ServiceData128BitUUID, |value, _| {
+        DataTypeParseResult::ServiceData128BitUUIDResult(ServiceData128BitUUID::try_from(value))
+    }),
+    (AdType::ServiceData16BitUUID, |value, _| {
+        DataTypeParseResult::ServiceData16BitUUIDResult(ServiceData16BitUUID::try_from(value))
+    }),
+    (AdType::ServiceData32BitUUID, |value, _| {
+        DataTypeParseResult::ServiceData32BitUUIDResult(ServiceData32BitUUID::try_from(value))
+    }),
+    (AdType::ShortenedLocalName, |value, _| {
+        DataTypeParseResult::ShortenedLocalNameResult(ShortenedLocalName::try_from(value))
+    }),
+    (AdType::ThreeDInformationData, |value, _| {
+        DataTypeParseResult::ThreeDInformationDataResult(ThreeDInformationData::try_from(value))
+    }),
+    (AdType::TxPowerLevel, |value, _| {
+        DataTypeParseResult::TxPowerLevelResult(TxPowerLevel::try_from(value))
+    }),
+    (AdType::UniformResourceIdentifier, |value, _| {
+        DataTypeParseResult::UniformResourceIdentifierResult(UniformResourceIdentifier::try_from(
+            value,
+        ))
+    }),
+];
+
+/// Built-in parser table indexed by data type.
+pub(crate) static DEFAULT_PARSERS: [Option<DataTypeParseFn>; 256] = parser_table(DATA_TYPE_PARSERS);
+
+/// Create parser table from parser entries.
+const fn parser_table(entries: &[(AdType, DataTypeParseFn)]) -> [Option<DataTypeParseFn>; 256] {
+    let mut table: [Option<DataTypeParseFn>; 256] = [None; 256];
+    let mut index = 0;
+    while index < entries.len() {
+        table[entries[index].0.value() as usize] = Some(entries[index].1);
+        index += 1;
+    }
+    table
+}
+
+/// Parse single EIR/AD/SRD/ACAD/OOB structure with the built-in parsers.
+fn parse(value: &[u8], context: ParseContext) -> DataTypeParseResult {
+    parse_with(&DEFAULT_PARSERS, value, context)
+}
+
+/// Parse single EIR/AD/SRD/ACAD/OOB structure in [`ParseContext`] with parser table.
+pub(crate) fn parse_in_context(
+    parsers: &[Option<DataTypeParseFn>; 256],
+    value: &[u8],
+    context: ParseContext,
+) -> DataTypeParseResult {
+    let result = match value.get(1) {
+        Some(data_type) if !context.is_allowed(data_type.to_owned()) => {
+            DataTypeParseResult::DataTypeParseError(DataTypeParseError::DataTypeNotAllowed(
+                *data_type,
+            ))
+        }
+        _ => parse_with(parsers, value, context),
+    };
+    #[cfg(feature = "tracing")]
+    trace_result(value, &result);
+    result
+}
+
+/// Parse single EIR/AD/SRD/ACAD/OOB structure with parser table.
+///
+/// The structure is validated with [`DataTypeRef`] first, parsers get exactly `1 + length` bytes.
+pub(crate) fn parse_with(
+    parsers: &[Option<DataTypeParseFn>; 256],
+    value: &[u8],
+    context: ParseContext,
+) -> DataTypeParseResult {
+    // parsers only see the bytes covered by the length field
+    let data_type = match DataTypeRef::try_from(value) {
+        Ok(data_type) => data_type,
+        Err(error) => return DataTypeParseResult::DataTypeParseError(error),
+    };
+    match parsers[data_type.data_type as usize] {
+        Some(parser) => parser(data_type.as_bytes(), context),
+        None => DataTypeParseResult::Unknown(RawAdStructure::from(&data_type)),
+    }
+}
+
+/// Emit `tracing` events for a parsed structure.
+#[cfg(feature = "tracing")]
+fn trace_result(value: &[u8], result: &DataTypeParseResult) {
+    let data_type = value.get(1).copied();
+    match result.parse_error() {
+        None if result.is_unknown() => {
+            tracing::warn!(?data_type, length = value.len(), "unsupported data type")
+        }
+        None => tracing::trace!(?data_type, length = value.len(), "parsed data type"),
+        Some(error) if matches!(result, DataTypeParseResult::DataTypeParseError(_)) => {
+            tracing::warn!(
+                ?data_type,
+                length = value.len(),
+                %error,
+                "unsupported data type"
+            )
+        }
+        Some(error) => {
+            tracing::warn!(
+                ?data_type,
+                length = value.len(),
+                %error,
+                "invalid data type structure"
+            )
+        }
+    }
+}
+
+// impl PartialEq for DataTypeParseResult {
+//     fn eq(&self, other: &Self) -> bool {
+//         match (self, other) {
+//             (Self::AdvertisingIntervalResult(l0), Self::AdvertisingIntervalResult(r0)) => l0 == r0,
+//             (Self::AdvertisingIntervalLongResult(l0), Self::AdvertisingIntervalLongResult(r0)) => l0 == r0,
+//             (Self::AppearanceResult(l0), Self::AppearanceResult(r0)) => l0 == r0,
+//             (Self::BigInfoResult(l0), Self::BigInfoResult(r0)) => l0 == r0,
+//             (Self::BroadcastCodeResult(l0), Self::BroadcastCodeResult(r0)) => l0 == r0,
+//             (Self::BroadcastNameResult(l0), Self::BroadcastNameResult(r0)) => l0 == r0,
+//             (Self::ChannelMapUpdateIndicationResult(l0), Self::ChannelMapUpdateIndicationResult(r0)) => l0 == r0,
+//             (Self::ClassOfDeviceResult(l0), Self::ClassOfDeviceResult(r0)) => l0 == r0,
+//             (Self::CompleteListOf128BitServiceUuidsResult(l0), Self::CompleteListOf128BitServiceUuidsResult(r0)) => l0 == r0,
+//             (Self::CompleteListOf16BitServiceUuidsResult(l0), Self::CompleteListOf16BitServiceUuidsResult(r0)) => l0 == r0,
+//             (Self::CompleteListOf32BitServiceUuidsResult(l0), Self::CompleteListOf32BitServiceUuidsResult(r0)) => l0 == r0,
+//             (Self::CompleteLocalNameResult(l0), Self::CompleteLocalNameResult(r0)) => l0 == r0,
+//             (Self::DeviceIdResult(l0), Self::DeviceIdResult(r0)) => l0 == r0,
+//             (Self::ElectronicShelfLabelResult(l0), Self::ElectronicShelfLabelResult(r0)) => l0 == r0,
+//             (Self::EncryptedDataResult(l0), Self::EncryptedDataResult(r0)) => l0 == r0,
+//             (Self::FlagsResult(l0), Self::FlagsResult(r0)) => l0 == r0,
+//             (Self::IncompleteListOf128BitServiceUuidsResult(l0), Self::IncompleteListOf128BitServiceUuidsResult(r0)) => l0 == r0,
+//             (Self::IncompleteListOf16BitServiceUuidsResult(l0), Self::IncompleteListOf16BitServiceUuidsResult(r0)) => l0 == r0,
+//             (Self::IncompleteListOf32BitServiceUuidsResult(l0), Self::IncompleteListOf32BitServiceUuidsResult(r0)) => l0 == r0,
+//             (Self::LeBluetoothDeviceAddressResult(l0), Self::LeBluetoothDeviceAddressResult(r0)) => l0 == r0,
+//             (Self::LeRoleResult(l0), Self::LeRoleResult(r0)) => l0 == r0,
+//             (Self::LeSecureConnectionsConfirmationValueResult(l0), Self::LeSecureConnectionsConfirmationValueResult(r0)) => l0 == r0,
+//             (Self::LeSecureConnectionsRandomValueResult(l0), Self::LeSecureConnectionsRandomValueResult(r0)) => l0 == r0,
+//             (Self::LeSupportedFeaturesResult(l0), Self::LeSupportedFeaturesResult(r0)) => l0 == r0,
+//             (Self::ListOf128BitServiceSolicitationUUIDsResult(l0), Self::ListOf128BitServiceSolicitationUUIDsResult(r0)) => l0 == r0,
+//             (Self::ListOf16BitServiceSolicitationUUIDsResult(l0), Self::ListOf16BitServiceSolicitationUUIDsResult(r0)) => l0 == r0,
+//             (Self::ListOf32BitServiceSolicitationUUIDsResult(l0), Self::ListOf32BitServiceSolicitationUUIDsResult(r0)) => l0 == r0,
+//             (Self::ManufacturerSpecificDataResult(l0), Self::ManufacturerSpecificDataResult(r0)) => l0 == r0,
+//             (Self::MeshBeaconResult(l0), Self::MeshBeaconResult(r0)) => l0 == r0,
+//             (Self::MeshMessageResult(l0), Self::MeshMessageResult(r0)) => l0 == r0,
+//             (Self::PbAdvResult(l0), Self::PbAdvResult(r0)) => l0 == r0,
+//             (Self::PeriodicAdvertisingResponseTimingInformationResult(l0), Self::PeriodicAdvertisingResponseTimingInformationResult(r0)) => l0 == r0,
+//             (Self::PeripheralConnectionIntervalRangeResult(l0), Self::PeripheralConnectionIntervalRangeResult(r0)) => l0 == r0,
+//             (Self::PublicTargetAddressResult(l0), Self::PublicTargetAddressResult(r0)) => l0 == r0,
+//             (Self::RandomTargetAddressResult(l0), Self::RandomTargetAddressResult(r0)) => l0 == r0,
+//             (Self::ResolvableSetIdentifierResult(l0), Self::ResolvableSetIdentifierResult(r0)) => l0 == r0,
+//             (Self::SecureSimplePairingHashC192Result(l0), Self::SecureSimplePairingHashC192Result(r0)) => l0 == r0,
+//             (Self::SecureSimplePairingHashC256Result(l0), Self::SecureSimplePairingHashC256Result(r0)) => l0 == r0,
+//             (Self::SecureSimplePairingRandomizerR192Result(l0), Self::SecureSimplePairingRandomizerR192Result(r0)) => l0 == r0,
+//             (Self::SecureSimplePairingRandomizerR256Result(l0), Self::SecureSimplePairingRandomizerR256Result(r0)) => l0 == r0,
+//             (Self::SecurityManagerOutOfBandResult(l0), Self::SecurityManagerOutOfBandResult(r0)) => l0 == r0,
+//             (Self::SecurityManagerTkValueResult(l0), Self::SecurityManagerTkValueResult(r0)) => l0 == r0,
+//             (Self::ServiceData128BitUUIDResult(l0), Self::ServiceData128BitUUIDResult(r0)) => l0 == r0,
+//             (Self::ServiceData16BitUUIDResult(l0), Self::ServiceData16BitUUIDResult(r0)) => l0 == r0,
+//             (Self::ServiceData32BitUUIDResult(l0), Self::ServiceData32BitUUIDResult(r0)) => l0 == r0,
+//             (Self::ShortenedLocalNameResult(l0), Self::ShortenedLocalNameResult(r0)) => l0 == r0,
+//             (Self::ThreeDInformationDataResult(l0), Self::ThreeDInformationDataResult(r0)) => l0 == r0,
+//             (Self::TxPowerLevelResult(l0), Self::TxPowerLevelResult(r0)) => l0 == r0,
+//             (Self::UniformResourceIdentifierResult(l0), Self::UniformResourceIdentifierResult(r0)) => l0 == r0,
+//             (Self::DataTypeParseErr(l0), Self::DataTypeParseErr(r0)) => l0 == r0,
+//             _ => false,
+//         }
+//     }
+// }
+/// Data types parse results.
+#[derive(Debug, PartialEq, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct DataTypeParseResults {
+    /// Parse results.
+    pub results: Vec<DataTypeParseResult>,
+}
+
+impl DataTypeParseResults {
+    /// Create [`DataTypeParseResults`] from `Vec<DataTypeParseResult>`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ble_data_struct::data_types::advertising_interval_long::AdvertisingIntervalLong;
+    /// use ble_data_struct::data_types::time_units::Slots625us;
+    ///
+    /// let advertising_interval_long = Slots625us(0x01020304u32);
+    /// let result = AdvertisingIntervalLong::new(true, advertising_interval_long);
+    /// assert_eq!(5, result.length);
+    /// assert!(result.is_u32);
+    /// assert_eq!(advertising_interval_long, result.advertising_interval_long);
+    ///
+    /// let result = AdvertisingIntervalLong::new(false, advertising_interval_long);
+    /// assert_eq!(4, result.length);
+    /// assert!(!result.is_u32);
+    /// assert_eq!(
+    ///     Slots625us(advertising_interval_long.0 & 0x00ffffff),
+    ///     result.advertising_interval_long
+    /// );
+    /// ```
+    pub fn new(results: Vec<DataTypeParseResult>) -> Self {
+        DataTypeParseResults { results }
+    }
+
+    /// Returns non-fatal spec deviations of all parse results.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ble_data_struct::data_types::{
+    ///     data_type::DataType, data_type_parser::DataTypeParseResults, flags::Flags,
+    ///     parse_warning::ParseWarning, tx_power_level::TxPowerLevel,
+    /// };
+    ///
+    /// let mut data: Vec<u8> = vec![2, Flags::data_type(), 0b1000_0110];
+    /// data.append(&mut TxPowerLevel::new(-4).into());
+    /// let results = DataTypeParseResults::from(&data);
+    /// assert!(results.results.iter().all(|f| f.parse_error().is_none()));
+    /// assert_eq!(
+    ///     vec![ParseWarning::ReservedForFutureUse {
+    ///         data_type: Flags::data_type()
+    ///     }],
+    ///     results.warnings()
+    /// );
+    /// ```
+    pub fn warnings(&self) -> Vec<ParseWarning> {
+        self.results.iter().flat_map(|f| f.warnings()).collect()
+    }
+
+    /// Returns OOB data block results.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ble_data_struct::data_types::{
+    ///     class_of_device::ClassOfDevice, data_type_parser::DataTypeParseResults,
+    ///     tx_power_level::TxPowerLevel,
+    /// };
+    ///
+    /// let class_of_device: Vec<u8> = ClassOfDevice::new(0x000100).into();
+    /// let tx_power_level: Vec<u8> = TxPowerLevel::new(-4).into();
+    /// let results = DataTypeParseResults::from(&[class_of_device, tx_power_level].concat());
+    /// assert_eq!(vec![&results.results[0]], results.oob_types().collect::<Vec<_>>());
+    /// ```
+    pub fn oob_types(&self) -> impl Iterator<Item = &DataTypeParseResult> {
+        self.results.iter().filter(|f| f.is_oob_type())
+    }
+
+    /// Returns pairing / encryption related results.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ble_data_struct::data_types::{
+    ///     data_type_parser::DataTypeParseResults,
+    ///     security_manager_tk_value::SecurityManagerTkValue, tx_power_level::TxPowerLevel,
+    /// };
+    ///
+    /// let tx_power_level: Vec<u8> = TxPowerLevel::new(-4).into();
+    /// let tk_value: Vec<u8> = SecurityManagerTkValue::new(1).into();
+    /// let results = DataTypeParseResults::from(&[tx_power_level, tk_value].concat());
+    /// assert_eq!(vec![&results.results[1]], results.security_types().collect::<Vec<_>>());
+    /// ```
+    pub fn security_types(&self) -> impl Iterator<Item = &DataTypeParseResult> {
+        self.results.iter().filter(|f| f.is_security_type())
+    }
+
+    /// Returns LE Audio broadcast results.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ble_data_struct::data_types::{
+    ///     broadcast_code::BroadcastCode, data_type_parser::DataTypeParseResults,
+    ///     tx_power_level::TxPowerLevel,
+    /// };
+    ///
+    /// let tx_power_level: Vec<u8> = TxPowerLevel::new(-4).into();
+    /// let broadcast_code: Vec<u8> = BroadcastCode::new(&"1234".as_bytes().to_vec()).into();
+    /// let results = DataTypeParseResults::from(&[tx_power_level, broadcast_code].concat());
+    /// assert_eq!(vec![&results.results[1]], results.audio_types().collect::<Vec<_>>());
+    /// ```
+    pub fn audio_types(&self) -> impl Iterator<Item = &DataTypeParseResult> {
+        self.results.iter().filter(|f| f.is_audio_type())
+    }
+
+    /// Returns Service UUID list and Service Solicitation UUID list results.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ble_data_struct::data_types::{
+    ///     data_type_parser::DataTypeParseResults,
+    ///     incomplete_list_of_16bit_service_uuids::IncompleteListOf16BitServiceUuids,
+    ///     tx_power_level::TxPowerLevel,
+    /// };
+    /// use ble_data_struct::uuid_from_u16;
+    ///
+    /// let uuids: Vec<u8> = IncompleteListOf16BitServiceUuids::new(&vec![uuid_from_u16(0x180f)]).into();
+    /// let tx_power_level: Vec<u8> = TxPowerLevel::new(-4).into();
+    /// let results = DataTypeParseResults::from(&[uuids, tx_power_level].concat());
+    /// assert_eq!(vec![&results.results[0]], results.uuid_lists().collect::<Vec<_>>());
+    /// ```
+    pub fn uuid_lists(&self) -> impl Iterator<Item = &DataTypeParseResult> {
+        self.results.iter().filter(|f| f.is_uuid_list())
+    }
+
+    /// Returns the first [`Flags`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ble_data_struct::data_types::{
+    ///     data_type_parser::DataTypeParseResults, flags::Flags, tx_power_level::TxPowerLevel,
+    /// };
+    ///
+    /// let flags = Flags::new(&vec![false, true, true, false, false, false, false, false]);
+    /// let data: Vec<u8> = flags.clone().into();
+    /// let tx_power_level: Vec<u8> = TxPowerLevel::new(-4).into();
+    /// let results = DataTypeParseResults::from(&[data, tx_power_level.clone()].concat());
+    /// assert_eq!(Some(&flags), results.flags());
+    ///
+    /// let results = DataTypeParseResults::from(&tx_power_level);
+    /// assert_eq!(None, results.flags());
+    /// ```
+    pub fn flags(&self) -> Option<&Flags> {
+        self.results.iter().find_map(|f| match f {
+            DataTypeParseResult::FlagsResult(Ok(data_type)) => Some(data_type),
+            _ => None,
+        })
+    }
+
+    /// Returns the first [`CompleteLocalName`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ble_data_struct::data_types::{
+    ///     complete_local_name::CompleteLocalName, data_type_parser::DataTypeParseResults,
+    /// };
+    ///
+    /// let name: Vec<u8> = CompleteLocalName::new(&"name".to_string()).into();
+    /// let results = DataTypeParseResults::from(&name);
+    /// assert_eq!(
+    ///     "name",
+    ///     results.complete_local_name().unwrap().complete_local_name
+    /// );
+    /// ```
+    pub fn complete_local_name(&self) -> Option<&CompleteLocalName> {
+        self.results.iter().find_map(|f| match f {
+            DataTypeParseResult::CompleteLocalNameResult(Ok(data_type)) => Some(data_type),
+            _ => None,
+        })
+    }
+
+    /// Returns the first [`ShortenedLocalName`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ble_data_struct::data_types::{
+    ///     data_type_parser::DataTypeParseResults, shortened_local_name::ShortenedLocalName,
+    /// };
+    ///
+    /// let name: Vec<u8> = ShortenedLocalName::new(&"na".to_string()).into();
+    /// let results = DataTypeParseResults::from(&name);
+    /// assert_eq!(
+    ///     "na",
+    ///     results.shortened_local_name().unwrap().shortened_local_name
+    /// );
+    /// ```
+    pub fn shortened_local_name(&self) -> Option<&ShortenedLocalName> {
+        self.results.iter().find_map(|f| match f {
+            DataTypeParseResult::ShortenedLocalNameResult(Ok(data_type)) => Some(data_type),
+            _ => None,
+        })
+    }
+
+    /// Returns the first [`TxPowerLevel`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ble_data_struct::data_types::{
+    ///     data_type_parser::DataTypeParseResults, tx_power_level::TxPowerLevel,
+    /// };
+    ///
+    /// let tx_power_level: Vec<u8> = TxPowerLevel::new(-4).into();
+    /// let results = DataTypeParseResults::from(&tx_power_level);
+    /// assert_eq!(Some(&TxPowerLevel::new(-4)), results.tx_power_level());
+    /// ```
+    pub fn tx_power_level(&self) -> Option<&TxPowerLevel> {
+        self.results.iter().find_map(|f| match f {
+            DataTypeParseResult::TxPowerLevelResult(Ok(data_type)) => Some(data_type),
+            _ => None,
+        })
+    }
+
+    /// Returns the first [`Appearance`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ble_data_struct::data_types::{
+    ///     appearance::Appearance, data_type_parser::DataTypeParseResults,
+    /// };
+    ///
+    /// let appearance: Vec<u8> = Appearance::new(0x0340).into();
+    /// let results = DataTypeParseResults::from(&appearance);
+    /// assert_eq!(Some(&Appearance::new(0x0340)), results.appearance());
+    /// ```
+    pub fn appearance(&self) -> Option<&Appearance> {
+        self.results.iter().find_map(|f| match f {
+            DataTypeParseResult::AppearanceResult(Ok(data_type)) => Some(data_type),
+            _ => None,
+        })
+    }
+
+    /// Returns the first [`AdvertisingInterval`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ble_data_struct::data_types::{
+    ///     advertising_interval::AdvertisingInterval, data_type_parser::DataTypeParseResults,
+    ///     time_units::Slots625us,
+    /// };
+    ///
+    /// let advertising_interval: Vec<u8> = AdvertisingInterval::new(Slots625us(0x00a0)).into();
+    /// let results = DataTypeParseResults::from(&advertising_interval);
+    /// assert_eq!(
+    ///     Some(&AdvertisingInterval::new(Slots625us(0x00a0))),
+    ///     results.advertising_interval()
+    /// );
+    /// ```
+    pub fn advertising_interval(&self) -> Option<&AdvertisingInterval> {
+        self.results.iter().find_map(|f| match f {
+            DataTypeParseResult::AdvertisingIntervalResult(Ok(data_type)) => Some(data_type),
+            _ => None,
+        })
+    }
+
+    /// Returns the first [`PeripheralConnectionIntervalRange`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ble_data_struct::data_types::{
+    ///     data_type_parser::DataTypeParseResults,
+    ///     peripheral_connection_interval_range::PeripheralConnectionIntervalRange,
+    ///     time_units::Units1_25ms,
+    /// };
+    ///
+    /// let range = PeripheralConnectionIntervalRange::new(Units1_25ms(0x0006), Units1_25ms(0x0c80));
+    /// let data: Vec<u8> = range.clone().into();
+    /// let results = DataTypeParseResults::from(&data);
+    /// assert_eq!(Some(&range), results.peripheral_connection_interval_range());
+    /// ```
+    pub fn peripheral_connection_interval_range(
+        &self,
+    ) -> Option<&PeripheralConnectionIntervalRange> {
+        self.results.iter().find_map(|f| match f {
+            DataTypeParseResult::PeripheralConnectionIntervalRangeResult(Ok(data_type)) => {
+                Some(data_type)
+            }
+            _ => None,
+        })
+    }
+
+    /// Returns the first [`LeRole`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ble_data_struct::data_types::{
+    ///     data_type_parser::{DataTypeParseResults, ParseContext},
+    ///     le_role::{LeRole, ONLY_PERIPHERAL_ROLE_SUPPORTED},
+    /// };
+    ///
+    /// let le_role: Vec<u8> = LeRole::new(ONLY_PERIPHERAL_ROLE_SUPPORTED).into();
+    /// let results = DataTypeParseResults::from_with_context(&le_role, ParseContext::Oob);
+    /// assert_eq!(Some(&LeRole::new(ONLY_PERIPHERAL_ROLE_SUPPORTED)), results.le_role());
+    /// ```
+    pub fn le_role(&self) -> Option<&LeRole> {
+        self.results.iter().find_map(|f| match f {
+            DataTypeParseResult::LeRoleResult(Ok(data_type)) => Some(data_type),
+            _ => None,
+        })
+    }
+
+    /// Returns the first [`BroadcastName`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ble_data_struct::data_types::{
+    ///     broadcast_name::BroadcastName, data_type_parser::DataTypeParseResults,
+    /// };
+    ///
+    /// let broadcast_name: Vec<u8> = BroadcastName::new(&"broadcast".to_string()).into();
+    /// let results = DataTypeParseResults::from(&broadcast_name);
+    /// assert_eq!("broadcast", results.broadcast_name().unwrap().broadcast_name);
+    /// ```
+    pub fn broadcast_name(&self) -> Option<&BroadcastName> {
+        self.results.iter().find_map(|f| match f {
+            DataTypeParseResult::BroadcastNameResult(Ok(data_type)) => Some(data_type),
+            _ => None,
+        })
+    }
+
+    /// Returns all [`ServiceData16BitUUID`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ble_data_struct::data_types::{
+    ///     data_type_parser::DataTypeParseResults, service_data_16bit_uuid::ServiceData16BitUUID,
+    ///     tx_power_level::TxPowerLevel,
+    /// };
+    /// use ble_data_struct::uuid_from_u16;
+    ///
+    /// let battery = ServiceData16BitUUID::new(&uuid_from_u16(0x180f), &vec![50]);
+    /// let heart_rate = ServiceData16BitUUID::new(&uuid_from_u16(0x180d), &vec![60]);
+    /// let tx_power_level: Vec<u8> = TxPowerLevel::new(-4).into();
+    /// let data = [battery.clone().into(), tx_power_level, heart_rate.clone().into()].concat();
+    /// let results = DataTypeParseResults::from(&data);
+    /// assert_eq!(vec![&battery, &heart_rate], results.service_data_16bit());
+    /// ```
+    pub fn service_data_16bit(&self) -> Vec<&ServiceData16BitUUID> {
+        self.results
+            .iter()
+            .filter_map(|f| match f {
+                DataTypeParseResult::ServiceData16BitUUIDResult(Ok(data_type)) => Some(data_type),
+                _ => None,
+            })
+            .collect()
+    }
+
+    /// Returns all [`ServiceData32BitUUID`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ble_data_struct::data_types::{
+    ///     data_type_parser::DataTypeParseResults, service_data_32bit_uuid::ServiceData32BitUUID,
+    /// };
+    /// use ble_data_struct::uuid_from_u32;
+    ///
+    /// let service_data = ServiceData32BitUUID::new(&uuid_from_u32(0x0000180f), &vec![50]);
+    /// let data: Vec<u8> = service_data.clone().into();
+    /// let results = DataTypeParseResults::from(&data);
+    /// assert_eq!(vec![&service_data], results.service_data_32bit());
+    /// ```
+    pub fn service_data_32bit(&self) -> Vec<&ServiceData32BitUUID> {
+        self.results
+            .iter()
+            .filter_map(|f| match f {
+                DataTypeParseResult::ServiceData32BitUUIDResult(Ok(data_type)) => Some(data_type),
+                _ => None,
+            })
+            .collect()
+    }
+
+    /// Returns all [`ServiceData128BitUUID`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use uuid::uuid;
+    ///
+    /// use ble_data_struct::data_types::{
+    ///     data_type_parser::DataTypeParseResults, service_data_128bit_uuid::ServiceData128BitUUID,
+    /// };
+    ///
+    /// let service_data = ServiceData128BitUUID::new(
+    ///     &uuid!("00000001-a087-4fa3-add4-3b8a7d5d4921"),
+    ///     &vec![50],
+    /// );
+    /// let data: Vec<u8> = service_data.clone().into();
+    /// let results = DataTypeParseResults::from(&data);
+    /// assert_eq!(vec![&service_data], results.service_data_128bit());
+    /// ```
+    pub fn service_data_128bit(&self) -> Vec<&ServiceData128BitUUID> {
+        self.results
+            .iter()
+            .filter_map(|f| match f {
+                DataTypeParseResult::ServiceData128BitUUIDResult(Ok(data_type)) => Some(data_type),
+                _ => None,
+            })
+            .collect()
+    }
+
+    /// Returns all [`ManufacturerSpecificData`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ble_data_struct::data_types::{
+    ///     data_type_parser::DataTypeParseResults,
+    ///     manufacturer_specific_data::ManufacturerSpecificData,
+    /// };
+    ///
+    /// let apple = ManufacturerSpecificData::new(0x004c, &vec![0x02, 0x15]);
+    /// let google = ManufacturerSpecificData::new(0x00e0, &vec![0x01]);
+    /// let data: Vec<u8> = apple.clone().into();
+    /// let results = DataTypeParseResults::from(&[data, google.clone().into()].concat());
+    /// assert_eq!(vec![&apple, &google], results.manufacturer_specific_data());
+    /// ```
+    pub fn manufacturer_specific_data(&self) -> Vec<&ManufacturerSpecificData> {
+        self.results
+            .iter()
+            .filter_map(|f| match f {
+                DataTypeParseResult::ManufacturerSpecificDataResult(Ok(data_type)) => {
+                    Some(data_type)
+                }
+                _ => None,
+            })
+            .collect()
+    }
+
+    /// Create [`DataTypeParseResults`] from [`Vec<u8>`] in [`ParseContext`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ble_data_struct::data_types::{
+    ///     data_type_parser::{DataTypeParseResults, ParseContext},
+    ///     device_id::DeviceId,
+    ///     tx_power_level::TxPowerLevel,
+    /// };
+    ///
+    /// let device_id: Vec<u8> = DeviceId::new(0x0001, 0x004c, 0x0102, 0x0304).into();
+    /// let tx_power_level: Vec<u8> = TxPowerLevel::new(-4).into();
+    /// let results =
+    ///     DataTypeParseResults::from_with_context(&[device_id, tx_power_level].concat(), ParseContext::Eir);
+    /// assert!(results.results[0].is_device_id());
+    /// assert!(results.results[1].is_tx_power_level());
+    /// ```
+    pub fn from_with_context(value: &[u8], context: ParseContext) -> Self {
+        parse_all(value, Some(context))
+    }
+
+    /// Parse [`Vec<u8>`] in [`ParseContext`] and append the results.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ble_data_struct::data_types::{
+    ///     data_type_parser::{DataTypeParseResults, ParseContext},
+    ///     device_id::DeviceId,
+    ///     tx_power_level::TxPowerLevel,
+    /// };
+    ///
+    /// let tx_power_level: Vec<u8> = TxPowerLevel::new(-4).into();
+    /// let device_id: Vec<u8> = DeviceId::new(0x0001, 0x004c, 0x0102, 0x0304).into();
+    /// let mut results = DataTypeParseResults::from(&tx_power_level);
+    /// results.extend_with_context(&device_id, ParseContext::Eir);
+    /// assert_eq!(2, results.results.len());
+    /// assert!(results.results[0].is_tx_power_level());
+    /// assert!(results.results[1].is_device_id());
+    /// ```
+    pub fn extend_with_context(&mut self, value: &[u8], context: ParseContext) {
+        self.results
+            .append(&mut Self::from_with_context(value, context).results);
+    }
+
+    /// Create [`DataTypeParseResults`] from advertising data (e.g. `ADV_IND`) and its scan response data (`SCAN_RSP`).
+    ///
+    /// The advertising data is parsed in [`ParseContext::Advertising`] and the scan response data in [`ParseContext::ScanResponse`].
+    /// The scan response results follow the advertising results,
+    /// so the accessors returning the first structure (e.g. [`DataTypeParseResults::tx_power_level`]) prefer the advertising data.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ble_data_struct::data_types::{
+    ///     complete_local_name::CompleteLocalName, data_type_parser::DataTypeParseResults,
+    ///     flags::{DiscoverableMode, Flags},
+    ///     tx_power_level::TxPowerLevel,
+    /// };
+    ///
+    /// let flags = Flags::for_mode(DiscoverableMode::General, false);
+    /// let data: Vec<u8> = flags.clone().into();
+    /// let advertising = [data.clone(), TxPowerLevel::new(-4).into()].concat();
+    /// let name = CompleteLocalName::new(&"name".to_string());
+    /// let scan_response = [name.clone().into(), TxPowerLevel::new(4).into(), data].concat();
+    ///
+    /// let results = DataTypeParseResults::merge(&advertising, &scan_response);
+    /// assert_eq!(5, results.results.len());
+    /// assert_eq!(Some(&flags), results.flags());
+    /// assert_eq!(Some(&name), results.complete_local_name());
+    /// assert_eq!(Some(&TxPowerLevel::new(-4)), results.tx_power_level());
+    ///
+    /// // Flags are not allowed in the scan response data
+    /// assert!(results.results[4].parse_error().is_some());
+    /// ```
+    pub fn merge(advertising: &[u8], scan_response: &[u8]) -> Self {
+        let mut results = Self::from_with_context(advertising, ParseContext::Advertising);
+        results.extend_with_context(scan_response, ParseContext::ScanResponse);
+        results
+    }
+
+    /// Iterate [`DataTypeParseResult`] of EIR/AD/SRD/ACAD/OOB payload.
+    ///
+    /// Structures are parsed lazily the same way as [`DataTypeParseResults::from`],
+    /// iteration ends at a zero length or after a truncated structure.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ble_data_struct::data_types::{
+    ///     data_type_parser::DataTypeParseResults, parse_error::INVALID_DATA_SIZE,
+    ///     tx_power_level::TxPowerLevel,
+    /// };
+    ///
+    /// let tx_power_level: Vec<u8> = TxPowerLevel::new(-4).into();
+    /// let payload = [tx_power_level, vec![0x05, 0x0a, 0x00]].concat();
+    ///
+    /// let mut iter = DataTypeParseResults::iter(&payload);
+    /// assert!(iter.next().unwrap().is_tx_power_level());
+    /// assert_eq!(Some(INVALID_DATA_SIZE), iter.next().unwrap().error_code());
+    /// assert!(iter.next().is_none());
+    /// ```
+    pub fn iter(value: &[u8]) -> AdStructureIter<'_> {
+        AdStructureIter {
+            payload: value,
+            index: 0,
+            context: None,
+        }
+    }
+
+    /// Iterate [`DataTypeParseResult`] of EIR/AD/SRD/ACAD/OOB payload in [`ParseContext`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ble_data_struct::data_types::{
+    ///     data_type_parser::{DataTypeParseResults, ParseContext},
+    ///     device_id::DeviceId,
+    ///     tx_power_level::TxPowerLevel,
+    /// };
+    ///
+    /// let device_id: Vec<u8> = DeviceId::new(0x0001, 0x004c, 0x0102, 0x0304).into();
+    /// let tx_power_level: Vec<u8> = TxPowerLevel::new(-4).into();
+    /// let payload = [device_id, tx_power_level].concat();
+    /// let results: Vec<_> = DataTypeParseResults::iter_with_context(&payload, ParseContext::Eir)
+    ///     .filter(|f| f.is_device_id())
+    ///     .collect();
+    /// assert_eq!(1, results.len());
+    /// ```
+    pub fn iter_with_context(value: &[u8], context: ParseContext) -> AdStructureIter<'_> {
+        AdStructureIter {
+            payload: value,
+            index: 0,
+            context: Some(context),
+        }
+    }
+}
+
+/// Iterator returned by [`DataTypeParseResults::iter`] and [`DataTypeParseResults::iter_with_context`].
+///
+/// Use [`DataTypeRef::iter`](crate::data_types::data_type_ref::DataTypeRef::iter) for the unparsed structures.
+#[derive(Debug, Clone)]
+pub struct AdStructureIter<'a> {
+    /// payload
+    payload: &'a [u8],
+
+    /// next structure index
+    index: usize,
+
+    /// parse context ([`None`] for the [`From`] behavior)
+    context: Option<ParseContext>,
+}
+
+impl Iterator for AdStructureIter<'_> {
+    type Item = DataTypeParseResult;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let len = self.payload.len();
+        if self.index >= len {
+            return None;
+        }
+        let index = self.index;
+        if self.payload[index] == 0 {
+            // zero length terminates the significant part
+            self.index = len;
+            return None;
+        }
+        let end = index + 1 + self.payload[index] as usize;
+        if end > len {
+            self.index = len;
+            return Some(DataTypeParseResult::DataTypeParseError(
+                DataTypeParseError::InvalidLength {
+                    expected: end - index,
+                    actual: len - index,
+                },
+            ));
+        }
+        self.index = end;
+        let value = &self.payload[index..end];
+        Some(match self.context {
+            Some(context) => DataTypeParseResult::from_with_context(value, context),
+            None => DataTypeParseResult::from(value),
+        })
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let remaining = self.payload.len() - self.index;
+        (0, Some(remaining))
+    }
+}
+
+impl FusedIterator for AdStructureIter<'_> {}
+
+/// Parse EIR/AD/SRD/ACAD/OOB structures ([`None`] for the [`From`] behavior).
+fn parse_all(value: &[u8], context: Option<ParseContext>) -> DataTypeParseResults {
+    #[cfg(feature = "tracing")]
+    let _span = tracing::debug_span!("parse_data_types", length = value.len()).entered();
+    // most structures are 3 bytes or longer
+    let mut results = Vec::with_capacity(value.len() / 3 + 1);
+    results.extend(AdStructureIter {
+        payload: value,
+        index: 0,
+        context,
+    });
+    DataTypeParseResults { results }
+}
+
+impl From<&Vec<Vec<u8>>> for DataTypeParseResults {
+    /// Create [`DataTypeParseResults`] from `Vec<Vec<u8>>`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ble_data_struct::data_types::{advertising_interval::AdvertisingInterval, data_type_parser::{DataTypeParseResult, DataTypeParseResults}};
+    /// use ble_data_struct::data_types::time_units::Slots625us;
+    ///
+    /// let mut vec: Vec<Vec<u8>> = Vec::new();
+    /// let advertising_interval = 0x01;
+    /// let data: Vec<u8> = AdvertisingInterval::new(Slots625us(advertising_interval)).into();
+    /// vec.push(data);
+    /// vec.push(vec![]);
+    ///
+    /// let results = DataTypeParseResults::from(&vec);
+    /// assert!(matches!(
+    ///     results.results.get(0),
+    ///     Some(DataTypeParseResult::AdvertisingIntervalResult(_))
+    /// ));
+    /// assert!(matches!(
+    ///     results.results.get(1),
+    ///     Some(DataTypeParseResult::DataTypeParseError(_))
+    /// ));
+    /// assert!(matches!(results.results.get(2), None));
+    /// ```
+    fn from(value: &Vec<Vec<u8>>) -> Self {
+        Self {
+            results: value
+                .iter()
+                .map(|f| DataTypeParseResult::from(f))
+                .collect::<Vec<DataTypeParseResult>>(),
+        }
+    }
+}
+
+impl From<&Vec<u8>> for DataTypeParseResults {
+    /// Create [`DataTypeParseResults`] from [`Vec<u8>`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ble_data_struct::data_types::{advertising_interval::AdvertisingInterval, data_type_parser::{DataTypeParseResult, DataTypeParseResults}};
+    /// use ble_data_struct::data_types::time_units::Slots625us;
+    ///
+    /// let mut vec: Vec<Vec<u8>> = Vec::new();
+    /// let advertising_interval = 0x01;
+    /// vec.push(AdvertisingInterval::new(Slots625us(advertising_interval)).into());
+    /// vec.push(vec![100]);
+    ///
+    /// let results = DataTypeParseResults::from(&vec);
+    /// assert!(matches!(
+    ///     results.results.get(0),
+    ///     Some(DataTypeParseResult::AdvertisingIntervalResult(_))
+    /// ));
     /// assert!(matches!(
     ///     results.results.get(1),
     ///     Some(DataTypeParseResult::DataTypeParseError(_))
@@ -1469,19 +3238,67 @@ impl From<&Vec<u8>> for DataTypeParseResults {
     /// assert!(matches!(results.results.get(2), None));
     /// ```
     fn from(value: &Vec<u8>) -> Self {
-        let mut vec = Vec::new();
-        let mut index = 0;
-        let len = value.len();
-        while index < len {
-            let mut inner: Vec<u8> = Vec::new();
-            let size = value[index];
-            inner.append(&mut value[index..index + 1 + size as usize].to_vec());
-            vec.push(inner);
-
-            index += 1;
-            index += size as usize;
+        Self::from(value.as_slice())
+    }
+}
+
+impl From<&[u8]> for DataTypeParseResults {
+    /// Create [`DataTypeParseResults`] from `&[u8]`.
+    ///
+    /// A zero length ends the significant part of the payload, the remaining bytes are ignored.
+    /// A structure with its length past the end of the payload is returned as
+    /// [`DataTypeParseResult::DataTypeParseError`] and ends the results.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ble_data_struct::data_types::{advertising_interval::AdvertisingInterval, data_type_parser::DataTypeParseResults};
+    /// use ble_data_struct::data_types::time_units::Slots625us;
+    ///
+    /// let data: Vec<u8> = AdvertisingInterval::new(Slots625us(0x01)).into();
+    /// let payload = [data.clone(), data].concat();
+    /// let results = DataTypeParseResults::from(&payload[..]);
+    /// assert_eq!(2, results.results.len());
+    /// assert_eq!(DataTypeParseResults::from(&payload), results);
+    /// ```
+    fn from(value: &[u8]) -> Self {
+        parse_all(value, None)
+    }
+}
+
+impl Into<Vec<u8>> for DataTypeParseResults {
+    /// Create EIR/AD/SRD/ACAD/OOB payload from [`DataTypeParseResults`].
+    ///
+    /// Structures are written in the order of `results`.
+    /// Unknown, custom and raw structures are written back byte-exactly,
+    /// failed results (see [`DataTypeParseResult::parse_error`]) are skipped.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ble_data_struct::data_types::{
+    ///     data_type_parser::{DataTypeParseResult, DataTypeParseResults},
+    ///     tx_power_level::TxPowerLevel,
+    /// };
+    ///
+    /// let tx_power_level: Vec<u8> = TxPowerLevel::new(-4).into();
+    /// let vendor = vec![0x04, 0x50, 0x03, 0x02, 0x01];
+    /// let payload = [tx_power_level, vendor.clone()].concat();
+    ///
+    /// let mut results = DataTypeParseResults::from(&payload);
+    /// results.results[0] = DataTypeParseResult::TxPowerLevelResult(Ok(TxPowerLevel::new(4)));
+    /// let into_data: Vec<u8> = results.into();
+    /// let tx_power_level: Vec<u8> = TxPowerLevel::new(4).into();
+    /// assert_eq!([tx_power_level, vendor].concat(), into_data);
+    /// ```
+    fn into(self) -> Vec<u8> {
+        let mut data: Vec<u8> = Vec::new();
+        for result in &self.results {
+            if let Some(ad_structure) = result.ad_structure() {
+                data.append(&mut ad_structure.to_bytes());
+            }
         }
-        Self::from(&vec)
+        return data;
     }
 }
 
@@ -1495,13 +3312,19 @@ mod tests {
         appearance::Appearance,
         big_info::BigInfo,
         broadcast_code::BroadcastCode,
+        broadcast_name::BroadcastName,
         channel_map_update_indication::ChannelMapUpdateIndication,
         class_of_device::ClassOfDevice,
         complete_list_of_128bit_service_uuids::CompleteListOf128BitServiceUuids,
         complete_list_of_16bit_service_uuids::CompleteListOf16BitServiceUuids,
         complete_list_of_32bit_service_uuids::CompleteListOf32BitServiceUuids,
         complete_local_name::CompleteLocalName,
-        data_type_parser::DataTypeParseResult,
+        custom_data_type::CustomDataType,
+        data_type::DataType,
+        data_type_parser::{DataTypeParseResult, ParseContext},
+        data_type_registry::DataTypeRegistry,
+        device_id::DeviceId,
+        electronic_shelf_label::ElectronicShelfLabel,
         encrypted_data::EncryptedData,
         flags::Flags,
         incomplete_list_of_128bit_service_uuids::IncompleteListOf128BitServiceUuids,
@@ -1516,10 +3339,17 @@ mod tests {
         list_of_16bit_service_solicitation_uuids::ListOf16BitServiceSolicitationUUIDs,
         list_of_32bit_service_solicitation_uuids::ListOf32BitServiceSolicitationUUIDs,
         manufacturer_specific_data::ManufacturerSpecificData,
+        mesh_beacon::MeshBeacon,
+        mesh_message::MeshMessage,
+        parse_error::{DataTypeParseError, DATA_TYPE_NOT_ALLOWED, INVALID_DATA_SIZE},
+        parse_warning::ParseWarning,
+        pb_adv::PbAdv,
         periodic_advertising_response_timing_information::PeriodicAdvertisingResponseTimingInformation,
         peripheral_connection_interval_range::PeripheralConnectionIntervalRange,
         public_target_address::PublicTargetAddress,
         random_target_address::RandomTargetAddress,
+        raw_ad_structure::RawAdStructure,
+        resolvable_set_identifier::ResolvableSetIdentifier,
         secure_simple_pairing_hash_c192::SecureSimplePairingHashC192,
         secure_simple_pairing_hash_c256::SecureSimplePairingHashC256,
         secure_simple_pairing_randomizer_r192::SecureSimplePairingRandomizerR192,
@@ -1530,15 +3360,21 @@ mod tests {
         service_data_16bit_uuid::ServiceData16BitUUID,
         service_data_32bit_uuid::ServiceData32BitUUID,
         shortened_local_name::ShortenedLocalName,
+        three_d_information_data::ThreeDInformationData,
+        time_units::Slots625us,
+        time_units::Units1_25ms,
         tx_power_level::TxPowerLevel,
         uniform_resource_identifier::UniformResourceIdentifier,
     };
+    use crate::uuid_from_u16;
 
     use super::DataTypeParseResults;
 
     #[test]
     fn test_new() {
-        let vec = vec![DataTypeParseResult::DataTypeParseError("".to_string())];
+        let vec = vec![DataTypeParseResult::DataTypeParseError(
+            DataTypeParseError::UnknownDataType(0x00),
+        )];
         let results = DataTypeParseResults::new(vec.to_vec());
         assert_eq!(vec, results.results);
     }
@@ -1546,7 +3382,7 @@ mod tests {
     #[test]
     fn test_is_advertising_interval() {
         let advertising_interval = 0x01;
-        let data: Vec<u8> = AdvertisingInterval::new(advertising_interval).into();
+        let data: Vec<u8> = AdvertisingInterval::new(Slots625us(advertising_interval)).into();
         assert!(DataTypeParseResult::from(&data).is_advertising_interval());
 
         let data: Vec<u8> = Vec::new();
@@ -1555,7 +3391,7 @@ mod tests {
 
     #[test]
     fn test_is_advertising_interval_long() {
-        let advertising_interval_long: u32 = 0x01020304u32;
+        let advertising_interval_long = Slots625us(0x01020304u32);
         let data: Vec<u8> = AdvertisingIntervalLong::new(true, advertising_interval_long).into();
         assert!(DataTypeParseResult::from(&data).is_advertising_interval_long());
 
@@ -1638,12 +3474,21 @@ mod tests {
         assert!(!DataTypeParseResult::from(&data).is_broadcast_code());
     }
 
+    #[test]
+    fn test_is_broadcast_name() {
+        let data: Vec<u8> = BroadcastName::new(&"broadcast_name".to_string()).into();
+        assert!(DataTypeParseResult::from(&data).is_broadcast_name());
+
+        let data: Vec<u8> = Vec::new();
+        assert!(!DataTypeParseResult::from(&data).is_broadcast_name());
+    }
+
     #[test]
     fn test_is_channel_map_update_indication() {
         let mut ch_m = [false; 37].to_vec();
         for i in 0..37 {
             ch_m[i] = true;
-            let data = ChannelMapUpdateIndication::new(&ch_m, i as u16).into();
+            let data: Vec<u8> = ChannelMapUpdateIndication::new(&ch_m, i as u16).into();
             assert!(DataTypeParseResult::from(&data).is_channel_map_update_indication());
             ch_m[i] = false;
         }
@@ -1658,7 +3503,7 @@ mod tests {
         let major_device_class = 0b00000000_00000001_00000000;
         let minor_device_class = 0b00000000_00000000_00000100;
         let class_of_device = major_service_classes | major_device_class | minor_device_class;
-        let data = ClassOfDevice::new(class_of_device).into();
+        let data: Vec<u8> = ClassOfDevice::new(class_of_device).into();
         assert!(DataTypeParseResult::from(&data).is_class_of_device());
 
         let data: Vec<u8> = Vec::new();
@@ -1672,7 +3517,7 @@ mod tests {
             uuid!("00000002-0000-1000-8000-00805F9B34FB"),
         ]
         .to_vec();
-        let data = CompleteListOf128BitServiceUuids::new(&uuids).into();
+        let data: Vec<u8> = CompleteListOf128BitServiceUuids::new(&uuids).into();
         assert!(DataTypeParseResult::from(&data).is_complete_list_of_128bit_service_uuids());
 
         let data: Vec<u8> = Vec::new();
@@ -1686,7 +3531,7 @@ mod tests {
             uuid!("00000002-0000-1000-8000-00805F9B34FB"),
         ]
         .to_vec();
-        let data = CompleteListOf16BitServiceUuids::new(&uuids).into();
+        let data: Vec<u8> = CompleteListOf16BitServiceUuids::new(&uuids).into();
         assert!(DataTypeParseResult::from(&data).is_complete_list_of_16bit_service_uuids());
 
         let data: Vec<u8> = Vec::new();
@@ -1700,7 +3545,7 @@ mod tests {
             uuid!("00000002-0000-1000-8000-00805F9B34FB"),
         ]
         .to_vec();
-        let data = CompleteListOf32BitServiceUuids::new(&uuids).into();
+        let data: Vec<u8> = CompleteListOf32BitServiceUuids::new(&uuids).into();
         assert!(DataTypeParseResult::from(&data).is_complete_list_of_32bit_service_uuids());
 
         let data: Vec<u8> = Vec::new();
@@ -1710,19 +3555,157 @@ mod tests {
     #[test]
     fn test_is_complete_local_name() {
         let name = "complete_local_name".to_string();
-        let data = CompleteLocalName::new(&name).into();
+        let data: Vec<u8> = CompleteLocalName::new(&name).into();
         assert!(DataTypeParseResult::from(&data).is_complete_local_name());
 
         let data: Vec<u8> = Vec::new();
         assert!(!DataTypeParseResult::from(&data).is_complete_local_name());
     }
 
+    #[test]
+    fn test_is_device_id() {
+        let data: Vec<u8> = DeviceId::new(0x0001, 0x004c, 0x0102, 0x0304).into();
+        assert!(DataTypeParseResult::from_with_context(&data, ParseContext::Eir).is_device_id());
+        assert!(!DataTypeParseResult::from(&data).is_device_id());
+
+        let data: Vec<u8> = Vec::new();
+        assert!(!DataTypeParseResult::from_with_context(&data, ParseContext::Eir).is_device_id());
+    }
+
+    #[test]
+    fn test_from_with_context() {
+        let data: Vec<u8> = DeviceId::new(0x0001, 0x004c, 0x0102, 0x0304).into();
+        assert_eq!(
+            DataTypeParseResult::DeviceIdResult(Ok(DeviceId::new(0x0001, 0x004c, 0x0102, 0x0304))),
+            DataTypeParseResult::from_with_context(&data, ParseContext::Eir)
+        );
+        assert!(
+            DataTypeParseResult::from_with_context(&data, ParseContext::Oob)
+                .is_security_manager_tk_value()
+        );
+        assert_eq!(
+            DataTypeParseResult::from(&data),
+            DataTypeParseResult::from_with_context(&data, ParseContext::Oob)
+        );
+
+        let data: Vec<u8> = SecurityManagerTkValue::new(1).into();
+        assert_eq!(
+            DataTypeParseResult::SecurityManagerTkValueResult(Ok(SecurityManagerTkValue::new(1))),
+            DataTypeParseResult::from_with_context(&data, ParseContext::Oob)
+        );
+        assert!(matches!(
+            DataTypeParseResult::from_with_context(&data, ParseContext::Eir),
+            DataTypeParseResult::DeviceIdResult(Ok(_))
+        ));
+
+        let data: Vec<u8> = TxPowerLevel::new(-4).into();
+        assert_eq!(
+            DataTypeParseResult::from_with_context(&data, ParseContext::Eir),
+            DataTypeParseResult::from_with_context(&data, ParseContext::Oob)
+        );
+
+        let data: Vec<u8> = ChannelMapUpdateIndication::new(&vec![true; 37], 1).into();
+        assert!(
+            DataTypeParseResult::from_with_context(&data, ParseContext::Acad)
+                .is_channel_map_update_indication()
+        );
+        assert_eq!(
+            Some(DATA_TYPE_NOT_ALLOWED),
+            DataTypeParseResult::from_with_context(&data, ParseContext::PeriodicAdvertising)
+                .error_code()
+        );
+        assert!(DataTypeParseResult::from(&data).is_channel_map_update_indication());
+
+        let flags: Vec<u8> = Flags::new(&vec![false, true]).into();
+        let tx_power_level: Vec<u8> = TxPowerLevel::new(-4).into();
+        let data = [flags, tx_power_level].concat();
+        let results = DataTypeParseResults::from_with_context(&data, ParseContext::Advertising);
+        assert!(results.results[0].is_flags());
+        assert!(results.results[1].is_tx_power_level());
+        let results = DataTypeParseResults::from_with_context(&data, ParseContext::ScanResponse);
+        assert_eq!(Some(DATA_TYPE_NOT_ALLOWED), results.results[0].error_code());
+        assert!(results.results[1].is_tx_power_level());
+    }
+
+    #[test]
+    fn test_error_code() {
+        let data: Vec<u8> = TxPowerLevel::new(-4).into();
+        assert_eq!(None, DataTypeParseResult::from(&data).error_code());
+
+        let data: Vec<u8> = vec![1];
+        assert_eq!(
+            Some(INVALID_DATA_SIZE),
+            DataTypeParseResult::from(&data).error_code()
+        );
+        assert_eq!(
+            Some(INVALID_DATA_SIZE),
+            DataTypeParseResults::from(&vec![2, 0x0a]).results[0].error_code()
+        );
+
+        let data: Vec<u8> = vec![1, 0xfe];
+        assert_eq!(None, DataTypeParseResult::from(&data).error_code());
+        assert_eq!(
+            Some(INVALID_DATA_SIZE),
+            DataTypeParseResult::from(&vec![2, 0xfe]).error_code()
+        );
+
+        let data: Vec<u8> = BroadcastCode::new(&vec![0u8; 16]).into();
+        assert_eq!(
+            Some(DATA_TYPE_NOT_ALLOWED),
+            DataTypeParseResult::from_with_context(&data, ParseContext::Acad).error_code()
+        );
+    }
+
+    #[test]
+    fn test_parse_context_is_allowed() {
+        let contexts = [
+            ParseContext::Advertising,
+            ParseContext::ScanResponse,
+            ParseContext::Eir,
+            ParseContext::Oob,
+            ParseContext::Acad,
+            ParseContext::PeriodicAdvertising,
+        ];
+        for context in contexts {
+            assert!(context.is_allowed(TxPowerLevel::data_type()));
+            assert_eq!(
+                context == ParseContext::Acad,
+                context.is_allowed(BigInfo::data_type())
+            );
+            assert_eq!(
+                context == ParseContext::Oob,
+                context.is_allowed(BroadcastCode::data_type())
+            );
+            assert_eq!(
+                matches!(context, ParseContext::Eir | ParseContext::Oob),
+                context.is_allowed(DeviceId::data_type())
+            );
+            assert_eq!(
+                matches!(
+                    context,
+                    ParseContext::Advertising | ParseContext::Eir | ParseContext::Oob
+                ),
+                context.is_allowed(Flags::data_type())
+            );
+        }
+    }
+
+    #[test]
+    fn test_is_electronic_shelf_label() {
+        let data: Vec<u8> =
+            ElectronicShelfLabel::new(&[1, 2, 3, 4, 5], &[6].to_vec(), [7, 8, 9, 10]).into();
+        assert!(DataTypeParseResult::from(&data).is_electronic_shelf_label());
+
+        let data: Vec<u8> = Vec::new();
+        assert!(!DataTypeParseResult::from(&data).is_electronic_shelf_label());
+    }
+
     #[test]
     fn test_is_encrypted_data() {
         let randomizer: [u8; 5] = [1, 2, 3, 4, 5];
         let payload = [6].to_vec();
         let mic: [u8; 4] = [7, 8, 9, 10];
-        let data = EncryptedData::new(&randomizer, &payload, mic).into();
+        let data: Vec<u8> = EncryptedData::new(&randomizer, &payload, mic).into();
         assert!(DataTypeParseResult::from(&data).is_encrypted_data());
 
         let data: Vec<u8> = Vec::new();
@@ -1732,7 +3715,7 @@ mod tests {
     #[test]
     fn test_is_flags() {
         let flags = [true, false, false, false, false, false, false, false].to_vec();
-        let data = Flags::new(&flags).into();
+        let data: Vec<u8> = Flags::new(&flags).into();
         assert!(DataTypeParseResult::from(&data).is_flags());
 
         let data: Vec<u8> = Vec::new();
@@ -1746,7 +3729,7 @@ mod tests {
             uuid!("00000002-0000-1000-8000-00805F9B34FB"),
         ]
         .to_vec();
-        let data = IncompleteListOf128BitServiceUuids::new(&uuids).into();
+        let data: Vec<u8> = IncompleteListOf128BitServiceUuids::new(&uuids).into();
         assert!(DataTypeParseResult::from(&data).is_incomplete_list_of_128bit_service_uuids());
 
         let data: Vec<u8> = Vec::new();
@@ -1760,7 +3743,7 @@ mod tests {
             uuid!("00000002-0000-1000-8000-00805F9B34FB"),
         ]
         .to_vec();
-        let data = IncompleteListOf16BitServiceUuids::new(&uuids).into();
+        let data: Vec<u8> = IncompleteListOf16BitServiceUuids::new(&uuids).into();
         assert!(DataTypeParseResult::from(&data).is_incomplete_list_of_16bit_service_uuids());
 
         let data: Vec<u8> = Vec::new();
@@ -1774,7 +3757,7 @@ mod tests {
             uuid!("00000002-0000-1000-8000-00805F9B34FB"),
         ]
         .to_vec();
-        let data = IncompleteListOf32BitServiceUuids::new(&uuids).into();
+        let data: Vec<u8> = IncompleteListOf32BitServiceUuids::new(&uuids).into();
         assert!(DataTypeParseResult::from(&data).is_incomplete_list_of_32bit_service_uuids());
 
         let data: Vec<u8> = Vec::new();
@@ -1785,7 +3768,8 @@ mod tests {
     fn test_is_le_bluetooth_device_address() {
         let le_bluetooth_device_address = 0x0000060504030201u64;
         let address_type = false;
-        let data = LeBluetoothDeviceAddress::new(le_bluetooth_device_address, address_type).into();
+        let data: Vec<u8> =
+            LeBluetoothDeviceAddress::new(le_bluetooth_device_address, address_type).into();
         assert!(DataTypeParseResult::from(&data).is_le_bluetooth_device_address());
 
         let data: Vec<u8> = Vec::new();
@@ -1795,7 +3779,7 @@ mod tests {
     #[test]
     fn test_is_le_role() {
         let le_role = ONLY_PERIPHERAL_ROLE_SUPPORTED;
-        let data = LeRole::new(le_role).into();
+        let data: Vec<u8> = LeRole::new(le_role).into();
         assert!(DataTypeParseResult::from(&data).is_le_role());
 
         let data: Vec<u8> = Vec::new();
@@ -1805,7 +3789,7 @@ mod tests {
     #[test]
     fn test_is_le_secure_connections_confirmation_value() {
         let le_secure_connections_confirmation_value = 0x0102030405060708090a0b0c0d0e0f10u128;
-        let data =
+        let data: Vec<u8> =
             LeSecureConnectionsConfirmationValue::new(le_secure_connections_confirmation_value)
                 .into();
         assert!(DataTypeParseResult::from(&data).is_le_secure_connections_confirmation_value());
@@ -1818,7 +3802,8 @@ mod tests {
     fn test_is_le_secure_connections_random_value() {
         let le_secure_connections_random_value = 0x0102030405060708090a0b0c0d0e0f10u128;
 
-        let data = LeSecureConnectionsRandomValue::new(le_secure_connections_random_value).into();
+        let data: Vec<u8> =
+            LeSecureConnectionsRandomValue::new(le_secure_connections_random_value).into();
         assert!(DataTypeParseResult::from(&data).is_le_secure_connections_random_value());
 
         let data: Vec<u8> = Vec::new();
@@ -1830,7 +3815,7 @@ mod tests {
         let mut le_supported_features = [false; 48].to_vec();
         for i in 0..44 {
             le_supported_features[i] = true;
-            let data = LeSupportedFeatures::new(&le_supported_features).into();
+            let data: Vec<u8> = LeSupportedFeatures::new(&le_supported_features).into();
             assert!(DataTypeParseResult::from(&data).is_le_supported_features());
             le_supported_features[i] = false;
         }
@@ -1846,7 +3831,7 @@ mod tests {
             uuid!("00000002-0000-1000-8000-00805F9B34FB"),
         ]
         .to_vec();
-        let data = ListOf128BitServiceSolicitationUUIDs::new(&uuids).into();
+        let data: Vec<u8> = ListOf128BitServiceSolicitationUUIDs::new(&uuids).into();
         assert!(DataTypeParseResult::from(&data).is_list_of_128bit_service_solicitation_uuids());
 
         let data: Vec<u8> = Vec::new();
@@ -1860,7 +3845,7 @@ mod tests {
             uuid!("00000002-0000-1000-8000-00805F9B34FB"),
         ]
         .to_vec();
-        let data = ListOf16BitServiceSolicitationUUIDs::new(&uuids).into();
+        let data: Vec<u8> = ListOf16BitServiceSolicitationUUIDs::new(&uuids).into();
         assert!(DataTypeParseResult::from(&data).is_list_of_16bit_service_solicitation_uuids());
 
         let data: Vec<u8> = Vec::new();
@@ -1874,7 +3859,7 @@ mod tests {
             uuid!("00000002-0000-1000-8000-00805F9B34FB"),
         ]
         .to_vec();
-        let data = ListOf32BitServiceSolicitationUUIDs::new(&uuids).into();
+        let data: Vec<u8> = ListOf32BitServiceSolicitationUUIDs::new(&uuids).into();
         assert!(DataTypeParseResult::from(&data).is_list_of_32bit_service_solicitation_uuids());
 
         let data: Vec<u8> = Vec::new();
@@ -1885,7 +3870,7 @@ mod tests {
     fn test_is_manufacturer_specific_data() {
         let company_identifier = 0x0ca8u16;
         let manufacturer_specific_data = [0x03u8].to_vec();
-        let data =
+        let data: Vec<u8> =
             ManufacturerSpecificData::new(company_identifier, &manufacturer_specific_data).into();
         assert!(DataTypeParseResult::from(&data).is_manufacturer_specific_data());
 
@@ -1893,6 +3878,33 @@ mod tests {
         assert!(!DataTypeParseResult::from(&data).is_manufacturer_specific_data());
     }
 
+    #[test]
+    fn test_is_mesh_beacon() {
+        let data: Vec<u8> = MeshBeacon::new(0x01, &[0x00u8, 0x01u8].to_vec()).into();
+        assert!(DataTypeParseResult::from(&data).is_mesh_beacon());
+
+        let data: Vec<u8> = Vec::new();
+        assert!(!DataTypeParseResult::from(&data).is_mesh_beacon());
+    }
+
+    #[test]
+    fn test_is_mesh_message() {
+        let data: Vec<u8> = MeshMessage::new(&[0x81u8, 0x02u8]).unwrap().into();
+        assert!(DataTypeParseResult::from(&data).is_mesh_message());
+
+        let data: Vec<u8> = Vec::new();
+        assert!(!DataTypeParseResult::from(&data).is_mesh_message());
+    }
+
+    #[test]
+    fn test_is_pb_adv() {
+        let data: Vec<u8> = PbAdv::new(0x01020304, 0x00, &[0x03u8].to_vec()).into();
+        assert!(DataTypeParseResult::from(&data).is_pb_adv());
+
+        let data: Vec<u8> = Vec::new();
+        assert!(!DataTypeParseResult::from(&data).is_pb_adv());
+    }
+
     #[test]
     fn test_is_periodic_advertising_response_timing_information() {
         let rsp_aa: [u8; 4] = [1, 2, 3, 4];
@@ -1900,11 +3912,11 @@ mod tests {
         let subevent_interval = 7u8;
         let response_slot_delay = 8u8;
         let response_slot_spacing = 9u8;
-        let data = PeriodicAdvertisingResponseTimingInformation::new(
+        let data: Vec<u8> = PeriodicAdvertisingResponseTimingInformation::new(
             &rsp_aa,
             num_subevents,
-            subevent_interval,
-            response_slot_delay,
+            Units1_25ms(subevent_interval),
+            Units1_25ms(response_slot_delay),
             response_slot_spacing,
         )
         .into();
@@ -1922,7 +3934,11 @@ mod tests {
     fn test_is_peripheral_connection_interval_range() {
         let minimum_value = 0x0006u16;
         let maximum_value = 0x0C80u16;
-        let data = PeripheralConnectionIntervalRange::new(minimum_value, maximum_value).into();
+        let data: Vec<u8> = PeripheralConnectionIntervalRange::new(
+            Units1_25ms(minimum_value),
+            Units1_25ms(maximum_value),
+        )
+        .into();
         assert!(DataTypeParseResult::from(&data).is_peripheral_connection_interval_range());
 
         let data: Vec<u8> = Vec::new();
@@ -1940,7 +3956,7 @@ mod tests {
             ]),
         ]
         .to_vec();
-        let data = PublicTargetAddress::new(&public_target_address).into();
+        let data: Vec<u8> = PublicTargetAddress::new(&public_target_address).into();
         assert!(DataTypeParseResult::from(&data).is_public_target_address());
 
         let data: Vec<u8> = Vec::new();
@@ -1958,17 +3974,27 @@ mod tests {
             ]),
         ]
         .to_vec();
-        let data = RandomTargetAddress::new(&random_target_address).into();
+        let data: Vec<u8> = RandomTargetAddress::new(&random_target_address).into();
         assert!(DataTypeParseResult::from(&data).is_random_target_address());
 
         let data: Vec<u8> = Vec::new();
         assert!(!DataTypeParseResult::from(&data).is_random_target_address());
     }
 
+    #[test]
+    fn test_is_resolvable_set_identifier() {
+        let data: Vec<u8> = ResolvableSetIdentifier::new(0x060504030201u64).into();
+        assert!(DataTypeParseResult::from(&data).is_resolvable_set_identifier());
+
+        let data: Vec<u8> = Vec::new();
+        assert!(!DataTypeParseResult::from(&data).is_resolvable_set_identifier());
+    }
+
     #[test]
     fn test_is_secure_simple_pairing_hash_c192() {
         let secure_simple_pairing_hash_c192 = 0x0102030405060708090a0b0c0d0e0f10u128;
-        let data = SecureSimplePairingHashC192::new(secure_simple_pairing_hash_c192).into();
+        let data: Vec<u8> =
+            SecureSimplePairingHashC192::new(secure_simple_pairing_hash_c192).into();
         assert!(DataTypeParseResult::from(&data).is_secure_simple_pairing_hash_c192());
 
         let data: Vec<u8> = Vec::new();
@@ -1978,7 +4004,8 @@ mod tests {
     #[test]
     fn test_is_secure_simple_pairing_hash_c256() {
         let secure_simple_pairing_hash_c256 = 0x0102030405060708090a0b0c0d0e0f10u128;
-        let data = SecureSimplePairingHashC256::new(secure_simple_pairing_hash_c256).into();
+        let data: Vec<u8> =
+            SecureSimplePairingHashC256::new(secure_simple_pairing_hash_c256).into();
         assert!(DataTypeParseResult::from(&data).is_secure_simple_pairing_hash_c256());
 
         let data: Vec<u8> = Vec::new();
@@ -1988,7 +4015,7 @@ mod tests {
     #[test]
     fn test_is_secure_simple_pairing_randomizer_r192() {
         let secure_simple_pairing_randomizer_r192 = 0x0102030405060708090a0b0c0d0e0f10u128;
-        let data =
+        let data: Vec<u8> =
             SecureSimplePairingRandomizerR192::new(secure_simple_pairing_randomizer_r192).into();
         assert!(DataTypeParseResult::from(&data).is_secure_simple_pairing_randomizer_r192());
 
@@ -1999,7 +4026,7 @@ mod tests {
     #[test]
     fn test_is_secure_simple_pairing_randomizer_r256() {
         let secure_simple_pairing_randomizer_r256 = 0x0102030405060708090a0b0c0d0e0f10u128;
-        let data =
+        let data: Vec<u8> =
             SecureSimplePairingRandomizerR256::new(secure_simple_pairing_randomizer_r256).into();
         assert!(DataTypeParseResult::from(&data).is_secure_simple_pairing_randomizer_r256());
 
@@ -2010,7 +4037,7 @@ mod tests {
     #[test]
     fn test_is_security_manager_oob() {
         let security_manager_oob = [true, false, false, false, false, false, false, false];
-        let data = SecurityManagerOutOfBand::new(&security_manager_oob).into();
+        let data: Vec<u8> = SecurityManagerOutOfBand::new(&security_manager_oob).into();
         assert!(DataTypeParseResult::from(&data).is_security_manager_oob());
 
         let data: Vec<u8> = Vec::new();
@@ -2020,7 +4047,7 @@ mod tests {
     #[test]
     fn test_is_security_manager_tk_value() {
         let security_manager_tk_value = 0x0102030405060708090a0b0c0d0e0f10u128;
-        let data = SecurityManagerTkValue::new(security_manager_tk_value).into();
+        let data: Vec<u8> = SecurityManagerTkValue::new(security_manager_tk_value).into();
         assert!(DataTypeParseResult::from(&data).is_security_manager_tk_value());
 
         let data: Vec<u8> = Vec::new();
@@ -2031,7 +4058,7 @@ mod tests {
     fn test_is_service_data_128bit_uuid() {
         let uuid = uuid!("04030201-0000-1000-8000-00805F9B34FB");
         let additional_service_data = [0x05u8].to_vec();
-        let data = ServiceData128BitUUID::new(&uuid, &additional_service_data).into();
+        let data: Vec<u8> = ServiceData128BitUUID::new(&uuid, &additional_service_data).into();
         assert!(DataTypeParseResult::from(&data).is_service_data_128bit_uuid());
 
         let data: Vec<u8> = Vec::new();
@@ -2042,7 +4069,7 @@ mod tests {
     fn test_is_service_data_16bit_uuid() {
         let uuid = uuid!("00000201-0000-1000-8000-00805F9B34FB");
         let additional_service_data = [0x03u8].to_vec();
-        let data = ServiceData16BitUUID::new(&uuid, &additional_service_data).into();
+        let data: Vec<u8> = ServiceData16BitUUID::new(&uuid, &additional_service_data).into();
         assert!(DataTypeParseResult::from(&data).is_service_data_16bit_uuid());
 
         let data: Vec<u8> = Vec::new();
@@ -2053,7 +4080,7 @@ mod tests {
     fn test_is_service_data_32bit_uuid() {
         let uuid = uuid!("04030201-0000-1000-8000-00805F9B34FB");
         let additional_service_data = [0x05u8].to_vec();
-        let data = ServiceData32BitUUID::new(&uuid, &additional_service_data).into();
+        let data: Vec<u8> = ServiceData32BitUUID::new(&uuid, &additional_service_data).into();
         assert!(DataTypeParseResult::from(&data).is_service_data_32bit_uuid());
 
         let data: Vec<u8> = Vec::new();
@@ -2063,17 +4090,26 @@ mod tests {
     #[test]
     fn test_is_shortened_local_name() {
         let name = "shortened_local_name".to_string();
-        let data = ShortenedLocalName::new(&name).into();
+        let data: Vec<u8> = ShortenedLocalName::new(&name).into();
         assert!(DataTypeParseResult::from(&data).is_shortened_local_name());
 
         let data: Vec<u8> = Vec::new();
         assert!(!DataTypeParseResult::from(&data).is_shortened_local_name());
     }
 
+    #[test]
+    fn test_is_three_d_information_data() {
+        let data: Vec<u8> = ThreeDInformationData::new(false, true, false, false, 0x46).into();
+        assert!(DataTypeParseResult::from(&data).is_three_d_information_data());
+
+        let data: Vec<u8> = Vec::new();
+        assert!(!DataTypeParseResult::from(&data).is_three_d_information_data());
+    }
+
     #[test]
     fn test_is_tx_power_level() {
         let tx_power_level = -127;
-        let data = TxPowerLevel::new(tx_power_level).into();
+        let data: Vec<u8> = TxPowerLevel::new(tx_power_level).into();
         assert!(DataTypeParseResult::from(&data).is_tx_power_level());
 
         let data: Vec<u8> = Vec::new();
@@ -2085,17 +4121,303 @@ mod tests {
         let scheme = '\u{0016}';
         let body = "uniform_resource_identifier";
         let uri = scheme.to_string() + body;
-        let data = UniformResourceIdentifier::new(&uri).into();
+        let data: Vec<u8> = UniformResourceIdentifier::new(&uri).into();
         assert!(DataTypeParseResult::from(&data).is_uniform_resource_identifier());
 
         let data: Vec<u8> = Vec::new();
         assert!(!DataTypeParseResult::from(&data).is_uniform_resource_identifier());
     }
 
+    #[test]
+    fn test_parse_error() {
+        let advertising_interval = 0x01;
+        let data: Vec<u8> = AdvertisingInterval::new(Slots625us(advertising_interval)).into();
+        assert!(DataTypeParseResult::from(&data).parse_error().is_none());
+
+        let data: Vec<u8> = vec![1, AdvertisingInterval::data_type()];
+        assert!(DataTypeParseResult::from(&data).parse_error().is_some());
+
+        let data: Vec<u8> = Vec::new();
+        assert_eq!(
+            Some(&DataTypeParseError::InvalidLength {
+                expected: 2,
+                actual: 0
+            }),
+            DataTypeParseResult::from(&data).parse_error()
+        );
+
+        let data: Vec<u8> = vec![1, 0xfe];
+        assert_eq!(None, DataTypeParseResult::from(&data).parse_error());
+    }
+
+    #[test]
+    fn test_unknown() {
+        let data: Vec<u8> = vec![3, 0xfe, 0x01, 0x02];
+        let result = DataTypeParseResult::from(&data);
+        assert!(result.is_unknown());
+        assert_eq!(
+            DataTypeParseResult::Unknown(RawAdStructure::new(0xfe, &[0x01, 0x02]).unwrap()),
+            result
+        );
+        assert_eq!(Some(data), result.ad_structure().map(|f| f.to_bytes()));
+
+        let data: Vec<u8> = vec![3, 0xfe, 0x01];
+        assert_eq!(
+            DataTypeParseResult::DataTypeParseError(DataTypeParseError::InvalidLength {
+                expected: 4,
+                actual: 3
+            }),
+            DataTypeParseResult::from(&data)
+        );
+
+        let payload = [vec![2, 0xfe, 0x01], TxPowerLevel::new(-4).into()].concat();
+        let results = DataTypeParseResults::from(&payload);
+        assert!(results.results[0].is_unknown());
+        assert!(results.results[1].is_tx_power_level());
+    }
+
+    #[test]
+    fn test_warnings() {
+        let data: Vec<u8> = TxPowerLevel::new(-4).into();
+        assert!(DataTypeParseResult::from(&data).warnings().is_empty());
+
+        let data: Vec<u8> = vec![3, TxPowerLevel::data_type(), 0xfc, 0x00];
+        assert_eq!(
+            vec![ParseWarning::UnexpectedLength {
+                data_type: TxPowerLevel::data_type(),
+                length: 3
+            }],
+            DataTypeParseResult::from(&data).warnings()
+        );
+
+        let data: Vec<u8> = vec![
+            4,
+            CompleteListOf16BitServiceUuids::data_type(),
+            0x0f,
+            0x18,
+            0x0a,
+        ];
+        assert_eq!(
+            vec![ParseWarning::UnexpectedLength {
+                data_type: CompleteListOf16BitServiceUuids::data_type(),
+                length: 4
+            }],
+            DataTypeParseResult::from(&data).warnings()
+        );
+
+        let data: Vec<u8> = vec![2, LeRole::data_type(), 0x10];
+        assert_eq!(
+            vec![ParseWarning::ReservedForFutureUse {
+                data_type: LeRole::data_type()
+            }],
+            DataTypeParseResult::from(&data).warnings()
+        );
+
+        let data: Vec<u8> = vec![2, Flags::data_type(), 0b1001_1000];
+        assert_eq!(
+            vec![
+                ParseWarning::ReservedForFutureUse {
+                    data_type: Flags::data_type()
+                },
+                ParseWarning::Deprecated {
+                    data_type: Flags::data_type()
+                }
+            ],
+            DataTypeParseResult::from(&data).warnings()
+        );
+
+        let data: Vec<u8> = BroadcastName::new(&"abc".to_string()).into();
+        assert_eq!(
+            vec![ParseWarning::UnexpectedLength {
+                data_type: BroadcastName::data_type(),
+                length: 4
+            }],
+            DataTypeParseResult::from(&data).warnings()
+        );
+
+        let data: Vec<u8> = BroadcastName::new(&"a".repeat(33)).into();
+        assert_eq!(
+            vec![ParseWarning::UnexpectedLength {
+                data_type: BroadcastName::data_type(),
+                length: 34
+            }],
+            DataTypeParseResult::from(&data).warnings()
+        );
+
+        let data: Vec<u8> = BroadcastName::new(&"abcd".to_string()).into();
+        assert!(DataTypeParseResult::from(&data).warnings().is_empty());
+
+        let data: Vec<u8> = vec![4, ThreeDInformationData::data_type(), 0x00, 0x46, 0x00];
+        assert_eq!(
+            vec![ParseWarning::UnexpectedLength {
+                data_type: ThreeDInformationData::data_type(),
+                length: 4
+            }],
+            DataTypeParseResult::from(&data).warnings()
+        );
+
+        let data: Vec<u8> = vec![3, MeshBeacon::data_type(), 0x03, 0x00];
+        assert_eq!(
+            vec![ParseWarning::ReservedForFutureUse {
+                data_type: MeshBeacon::data_type()
+            }],
+            DataTypeParseResult::from(&data).warnings()
+        );
+
+        let data: Vec<u8> = vec![4, ClassOfDevice::data_type(), 0x01, 0x00, 0x00];
+        assert_eq!(
+            vec![ParseWarning::ReservedForFutureUse {
+                data_type: ClassOfDevice::data_type()
+            }],
+            DataTypeParseResult::from(&data).warnings()
+        );
+
+        let data: Vec<u8> = vec![
+            8,
+            ChannelMapUpdateIndication::data_type(),
+            0xff,
+            0xff,
+            0xff,
+            0xff,
+            0x5f,
+            0x02,
+            0x01,
+        ];
+        assert_eq!(
+            vec![ParseWarning::ReservedForFutureUse {
+                data_type: ChannelMapUpdateIndication::data_type()
+            }],
+            DataTypeParseResult::from(&data).warnings()
+        );
+
+        let data: Vec<u8> = Vec::new();
+        assert!(DataTypeParseResult::from(&data).warnings().is_empty());
+    }
+
+    #[test]
+    fn test_results_warnings() {
+        let mut data: Vec<u8> = vec![2, Flags::data_type(), 0b1000_0110];
+        data.append(&mut TxPowerLevel::new(-4).into());
+        data.append(&mut vec![2, LeRole::data_type(), 0x04]);
+        let results = DataTypeParseResults::from(&data);
+        assert_eq!(
+            vec![
+                ParseWarning::ReservedForFutureUse {
+                    data_type: Flags::data_type()
+                },
+                ParseWarning::ReservedForFutureUse {
+                    data_type: LeRole::data_type()
+                }
+            ],
+            results.warnings()
+        );
+    }
+
+    #[test]
+    fn test_is_oob_type() {
+        let data: Vec<u8> = ClassOfDevice::new(0x000100).into();
+        assert!(DataTypeParseResult::from(&data).is_oob_type());
+        let data: Vec<u8> = SecureSimplePairingHashC256::new(1).into();
+        assert!(DataTypeParseResult::from(&data).is_oob_type());
+        let data: Vec<u8> = LeRole::new(ONLY_PERIPHERAL_ROLE_SUPPORTED).into();
+        assert!(DataTypeParseResult::from(&data).is_oob_type());
+        let data: Vec<u8> = LeSecureConnectionsRandomValue::new(1).into();
+        assert!(DataTypeParseResult::from(&data).is_oob_type());
+
+        let data: Vec<u8> = BroadcastCode::new(&"1234".as_bytes().to_vec()).into();
+        assert!(!DataTypeParseResult::from(&data).is_oob_type());
+        let data: Vec<u8> = Vec::new();
+        assert!(!DataTypeParseResult::from(&data).is_oob_type());
+    }
+
+    #[test]
+    fn test_is_security_type() {
+        let data: Vec<u8> = SecurityManagerTkValue::new(1).into();
+        assert!(DataTypeParseResult::from(&data).is_security_type());
+        let data: Vec<u8> = EncryptedData::new(&[0; 5], &vec![1], [0; 4]).into();
+        assert!(DataTypeParseResult::from(&data).is_security_type());
+        let data: Vec<u8> = BroadcastCode::new(&"1234".as_bytes().to_vec()).into();
+        assert!(DataTypeParseResult::from(&data).is_security_type());
+
+        let data: Vec<u8> = ClassOfDevice::new(0x000100).into();
+        assert!(!DataTypeParseResult::from(&data).is_security_type());
+    }
+
+    #[test]
+    fn test_is_audio_type() {
+        let data: Vec<u8> = BigInfo::builder().build().unwrap().into();
+        assert!(DataTypeParseResult::from(&data).is_audio_type());
+        let data: Vec<u8> = BroadcastCode::new(&"1234".as_bytes().to_vec()).into();
+        assert!(DataTypeParseResult::from(&data).is_audio_type());
+
+        let data: Vec<u8> = TxPowerLevel::new(-4).into();
+        assert!(!DataTypeParseResult::from(&data).is_audio_type());
+    }
+
+    #[test]
+    fn test_is_uuid_list() {
+        let uuids = vec![uuid_from_u16(0x180f)];
+        let data: Vec<u8> = CompleteListOf16BitServiceUuids::new(&uuids).into();
+        assert!(DataTypeParseResult::from(&data).is_uuid_list());
+        let data: Vec<u8> = IncompleteListOf16BitServiceUuids::new(&uuids).into();
+        assert!(DataTypeParseResult::from(&data).is_uuid_list());
+        let data: Vec<u8> = ListOf16BitServiceSolicitationUUIDs::new(&uuids).into();
+        assert!(DataTypeParseResult::from(&data).is_uuid_list());
+        let uuids = vec![uuid!("00000000-0000-1000-8000-00805F9B34FB")];
+        let data: Vec<u8> = ListOf128BitServiceSolicitationUUIDs::new(&uuids).into();
+        assert!(DataTypeParseResult::from(&data).is_uuid_list());
+
+        let data: Vec<u8> = ServiceData16BitUUID::new(&uuid_from_u16(0x180f), &vec![1]).into();
+        assert!(!DataTypeParseResult::from(&data).is_uuid_list());
+    }
+
+    #[test]
+    fn test_results_grouped() {
+        let uuids: Vec<u8> =
+            CompleteListOf16BitServiceUuids::new(&vec![uuid_from_u16(0x180f)]).into();
+        let class_of_device: Vec<u8> = ClassOfDevice::new(0x000100).into();
+        let tk_value: Vec<u8> = SecurityManagerTkValue::new(1).into();
+        let broadcast_code: Vec<u8> = BroadcastCode::new(&"1234".as_bytes().to_vec()).into();
+        let tx_power_level: Vec<u8> = TxPowerLevel::new(-4).into();
+        let results = DataTypeParseResults::from(
+            &[
+                uuids,
+                class_of_device,
+                tk_value,
+                broadcast_code,
+                tx_power_level,
+            ]
+            .concat(),
+        );
+
+        assert_eq!(
+            vec![&results.results[1], &results.results[2]],
+            results.oob_types().collect::<Vec<_>>()
+        );
+        assert_eq!(
+            vec![&results.results[2], &results.results[3]],
+            results.security_types().collect::<Vec<_>>()
+        );
+        assert_eq!(
+            vec![&results.results[3]],
+            results.audio_types().collect::<Vec<_>>()
+        );
+        assert_eq!(
+            vec![&results.results[0]],
+            results.uuid_lists().collect::<Vec<_>>()
+        );
+
+        let results = DataTypeParseResults::new(Vec::new());
+        assert_eq!(0, results.oob_types().count());
+        assert_eq!(0, results.security_types().count());
+        assert_eq!(0, results.audio_types().count());
+        assert_eq!(0, results.uuid_lists().count());
+    }
+
     #[test]
     fn test_result_from_vec() {
         let advertising_interval = 0x01;
-        let data: Vec<u8> = AdvertisingInterval::new(advertising_interval).into();
+        let data: Vec<u8> = AdvertisingInterval::new(Slots625us(advertising_interval)).into();
         assert!(matches!(
             DataTypeParseResult::from(&data),
             DataTypeParseResult::AdvertisingIntervalResult(_)
@@ -2112,7 +4434,7 @@ mod tests {
     fn test_results_from_vec_vec() {
         let mut vec: Vec<Vec<u8>> = Vec::new();
         let advertising_interval = 0x01;
-        let data: Vec<u8> = AdvertisingInterval::new(advertising_interval).into();
+        let data: Vec<u8> = AdvertisingInterval::new(Slots625us(advertising_interval)).into();
         vec.push(data);
         vec.push(vec![]);
 
@@ -2132,7 +4454,7 @@ mod tests {
     fn test_results_from_vec() {
         let mut vec: Vec<Vec<u8>> = Vec::new();
         let advertising_interval = 0x01;
-        vec.push(AdvertisingInterval::new(advertising_interval).into());
+        vec.push(AdvertisingInterval::new(Slots625us(advertising_interval)).into());
         vec.push(vec![100]);
 
         let results = DataTypeParseResults::from(&vec);
@@ -2146,4 +4468,426 @@ mod tests {
         ));
         assert!(matches!(results.results.get(2), None));
     }
+
+    #[test]
+    fn test_result_from_slice() {
+        let data: Vec<u8> = [
+            AdvertisingInterval::new(Slots625us(0x01)).into(),
+            ManufacturerSpecificData::new(0x0ca8, &vec![0x03]).into(),
+            TxPowerLevel::new(-4).into(),
+            vec![0x00],
+        ]
+        .concat::<u8>();
+        // e.g. Data field of LE Advertising Report event
+        let buffer = [vec![0x3e, 0x00], data.clone(), vec![0xd0]].concat();
+        let slice = &buffer[2..buffer.len() - 1];
+        assert_eq!(
+            DataTypeParseResults::from(&data),
+            DataTypeParseResults::from(slice)
+        );
+        assert_eq!(
+            DataTypeParseResults::from_with_context(&data, ParseContext::ScanResponse),
+            DataTypeParseResults::from_with_context(slice, ParseContext::ScanResponse)
+        );
+
+        let data: Vec<u8> = AdvertisingInterval::new(Slots625us(0x01)).into();
+        assert_eq!(
+            DataTypeParseResult::from(&data),
+            DataTypeParseResult::from(&data[..])
+        );
+        assert_eq!(
+            DataTypeParseResult::from(&Vec::new()),
+            DataTypeParseResult::from(&[][..])
+        );
+    }
+
+    #[test]
+    fn test_results_from_payload() {
+        let advertising_interval: Vec<u8> = AdvertisingInterval::new(Slots625us(0x01)).into();
+        let tx_power_level: Vec<u8> = TxPowerLevel::new(-4).into();
+        let payload = [advertising_interval.clone(), tx_power_level.clone()].concat();
+
+        let results = DataTypeParseResults::from(&payload);
+        assert_eq!(
+            DataTypeParseResults::from(&vec![advertising_interval, tx_power_level]),
+            results
+        );
+        assert_eq!(2, results.results.len());
+        assert_eq!(
+            DataTypeParseResults::new(Vec::new()),
+            DataTypeParseResults::from(&Vec::<u8>::new())
+        );
+
+        let payload = [payload, vec![0x05, 0x0a, 0x00]].concat();
+        let results = DataTypeParseResults::from(&payload);
+        assert_eq!(3, results.results.len());
+        assert_eq!(
+            Some(&DataTypeParseResult::DataTypeParseError(
+                DataTypeParseError::InvalidLength {
+                    expected: 6,
+                    actual: 3
+                }
+            )),
+            results.results.get(2)
+        );
+    }
+
+    #[test]
+    fn test_results_from_malformed() {
+        // xorshift, fixed seed
+        let mut seed = 0x2545f491u32;
+        let mut random = || {
+            seed ^= seed << 13;
+            seed ^= seed >> 17;
+            seed ^= seed << 5;
+            seed as u8
+        };
+        let contexts = [
+            ParseContext::Advertising,
+            ParseContext::ScanResponse,
+            ParseContext::Eir,
+            ParseContext::Oob,
+            ParseContext::Acad,
+            ParseContext::PeriodicAdvertising,
+        ];
+        for (ad_type, _) in super::DATA_TYPE_PARSERS {
+            for length in 0..=u8::MAX {
+                // structure followed by 1 trailing byte
+                let mut data = vec![length, *ad_type as u8];
+                data.extend((0..length).map(|_| random()));
+                let end = 1 + length as usize;
+
+                let _ = DataTypeParseResult::from(&data[..end]);
+                for context in contexts {
+                    DataTypeParseResult::from_with_context(&data[..end], context);
+                }
+
+                let truncated = &data[..random() as usize % end];
+                assert!(DataTypeParseResult::from(truncated).parse_error().is_some());
+                let results = DataTypeParseResults::from(truncated);
+                assert!(results.results.len() <= 1);
+                assert!(results.results.iter().all(|f| f.parse_error().is_some()));
+
+                // zero length terminates the payload
+                let results = DataTypeParseResults::from(&data);
+                if length == 0 {
+                    assert!(results.results.is_empty());
+                } else if data[end] == 0 {
+                    assert_eq!(1, results.results.len());
+                } else {
+                    assert_eq!(2, results.results.len());
+                    assert!(results.results[1].parse_error().is_some());
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_results_from_zero_length() {
+        let tx_power_level: Vec<u8> = TxPowerLevel::new(-4).into();
+        let payload = [
+            tx_power_level.clone(),
+            vec![0x00, 0x00],
+            tx_power_level.clone(),
+        ]
+        .concat();
+        let results = DataTypeParseResults::from(&payload);
+        assert_eq!(1, results.results.len());
+        assert!(results.results[0].is_tx_power_level());
+        assert_eq!(
+            results.results,
+            DataTypeParseResults::iter(&payload).collect::<Vec<_>>()
+        );
+
+        let results = DataTypeParseResults::from(&[2, 1, 6, 0, 0, 0, 0, 0][..]);
+        assert_eq!(1, results.results.len());
+        assert!(results.results[0].is_flags());
+        assert!(results.results.iter().all(|f| f.parse_error().is_none()));
+
+        let results = DataTypeParseResults::from(&[0, 2, 1, 6][..]);
+        assert!(results.results.is_empty());
+        assert!(DataTypeParseResults::iter(&[0, 2, 1, 6]).next().is_none());
+
+        assert_eq!(
+            DataTypeParseResult::DataTypeParseError(DataTypeParseError::ValueOutOfRange {
+                field: "length",
+                value: 0
+            }),
+            DataTypeParseResult::from(&[0x00, TxPowerLevel::data_type()][..])
+        );
+        assert_eq!(
+            DataTypeParseResult::from(&tx_power_level),
+            DataTypeParseResult::from(&[tx_power_level.clone(), vec![0xff]].concat())
+        );
+    }
+
+    #[cfg(feature = "tracing")]
+    #[test]
+    fn test_tracing() {
+        use std::sync::{Arc, Mutex};
+
+        use tracing::{
+            field::{Field, Visit},
+            span,
+            subscriber::Interest,
+            Event, Level, Metadata, Subscriber,
+        };
+
+        /// Subscriber capturing event levels and messages
+        struct Capture(Arc<Mutex<Vec<(Level, String)>>>);
+
+        struct Message(String);
+
+        impl Visit for Message {
+            fn record_debug(&mut self, field: &Field, value: &dyn std::fmt::Debug) {
+                if field.name() == "message" {
+                    self.0 = format!("{:?}", value);
+                }
+            }
+        }
+
+        impl Subscriber for Capture {
+            fn register_callsite(&self, _: &'static Metadata<'static>) -> Interest {
+                Interest::sometimes()
+            }
+            fn enabled(&self, _: &Metadata<'_>) -> bool {
+                true
+            }
+            fn new_span(&self, _: &span::Attributes<'_>) -> span::Id {
+                span::Id::from_u64(1)
+            }
+            fn record(&self, _: &span::Id, _: &span::Record<'_>) {}
+            fn record_follows_from(&self, _: &span::Id, _: &span::Id) {}
+            fn event(&self, event: &Event<'_>) {
+                let mut message = Message(String::new());
+                event.record(&mut message);
+                self.0
+                    .lock()
+                    .unwrap()
+                    .push((*event.metadata().level(), message.0));
+            }
+            fn enter(&self, _: &span::Id) {}
+            fn exit(&self, _: &span::Id) {}
+        }
+
+        let events = Arc::new(Mutex::new(Vec::new()));
+        let tx_power_level: Vec<u8> = TxPowerLevel::new(-4).into();
+        let payload = [tx_power_level, vec![0x02, 0xfe, 0x01], vec![0x01, 0x0a]].concat();
+        tracing::subscriber::with_default(Capture(events.clone()), || {
+            let _ = DataTypeParseResults::from(&payload);
+        });
+        assert_eq!(
+            vec![
+                (Level::TRACE, "parsed data type".to_string()),
+                (Level::WARN, "unsupported data type".to_string()),
+                (Level::WARN, "invalid data type structure".to_string()),
+            ],
+            *events.lock().unwrap()
+        );
+    }
+
+    #[test]
+    fn test_size() {
+        // large variants are boxed
+        assert!(size_of::<DataTypeParseResult>() < size_of::<BigInfo>());
+
+        let big_info = BigInfo::builder().build().unwrap();
+        let data: Vec<u8> = big_info.clone().into();
+        let result = DataTypeParseResult::from_with_context(&data, ParseContext::Acad);
+        assert_eq!(
+            DataTypeParseResult::BigInfoResult(Ok(Box::new(big_info))),
+            result
+        );
+        assert_eq!(Some(data), result.ad_structure().map(|f| f.to_bytes()));
+    }
+
+    #[test]
+    fn test_results_accessors() {
+        let flags = Flags::new(&vec![false, true, true, false, false, false, false, false]);
+        let name: Vec<u8> = CompleteLocalName::new(&"name".to_string()).into();
+        let battery = ServiceData16BitUUID::new(&uuid_from_u16(0x180f), &vec![50]);
+        let heart_rate = ServiceData16BitUUID::new(&uuid_from_u16(0x180d), &vec![60]);
+        let manufacturer = ManufacturerSpecificData::new(0x004c, &vec![0x02]);
+        let payload = [
+            flags.clone().into(),
+            battery.clone().into(),
+            name,
+            vec![0x01, 0x0a],
+            manufacturer.clone().into(),
+            heart_rate.clone().into(),
+        ]
+        .concat();
+
+        let results = DataTypeParseResults::from(&payload);
+        assert_eq!(Some(&flags), results.flags());
+        assert_eq!(
+            "name",
+            results.complete_local_name().unwrap().complete_local_name
+        );
+        assert_eq!(None, results.shortened_local_name());
+        assert_eq!(None, results.tx_power_level());
+        assert_eq!(None, results.appearance());
+        assert_eq!(vec![&battery, &heart_rate], results.service_data_16bit());
+        assert!(results.service_data_32bit().is_empty());
+        assert!(results.service_data_128bit().is_empty());
+        assert_eq!(vec![&manufacturer], results.manufacturer_specific_data());
+
+        let first: Vec<u8> = TxPowerLevel::new(-4).into();
+        let second: Vec<u8> = TxPowerLevel::new(4).into();
+        let results = DataTypeParseResults::from(&[first, second].concat());
+        assert_eq!(Some(&TxPowerLevel::new(-4)), results.tx_power_level());
+    }
+
+    #[test]
+    fn test_results_into() {
+        let flags: Vec<u8> =
+            Flags::new(&vec![false, true, true, false, false, false, false, false]).into();
+        let tx_power_level: Vec<u8> = TxPowerLevel::new(-4).into();
+        let manufacturer: Vec<u8> = ManufacturerSpecificData::new(0x02e5, &vec![0x00; 20]).into();
+        // unknown vendor data types (the data is never reordered or re-encoded)
+        let vendor1 = vec![0x06, 0x50, 0x05, 0x04, 0x03, 0x02, 0x01];
+        let vendor2 = vec![0x01, 0xf0];
+        let vendor3 = [vec![0x1e, 0xfe], (0x00..0x1d).rev().collect()].concat();
+        let payload = [
+            vendor1.clone(),
+            flags.clone(),
+            vendor2.clone(),
+            tx_power_level,
+            manufacturer.clone(),
+            vendor3.clone(),
+        ]
+        .concat();
+
+        let results = DataTypeParseResults::from(&payload);
+        assert!(results.results[0].is_unknown());
+        assert!(results.results[2].is_unknown());
+        assert!(results.results[5].is_unknown());
+        let into_data: Vec<u8> = results.clone().into();
+        assert_eq!(payload, into_data);
+
+        // parse -> modify -> serialize
+        let mut results = results;
+        results.results[3] = DataTypeParseResult::TxPowerLevelResult(Ok(TxPowerLevel::new(8)));
+        results.results.remove(1);
+        let into_data: Vec<u8> = results.into();
+        let tx_power_level: Vec<u8> = TxPowerLevel::new(8).into();
+        assert_eq!(
+            [
+                vendor1.clone(),
+                vendor2.clone(),
+                tx_power_level,
+                manufacturer,
+                vendor3.clone()
+            ]
+            .concat(),
+            into_data
+        );
+
+        // custom handler results are written back as received
+        let registry = DataTypeRegistry::new().with_handler(0x50, |value| {
+            CustomDataType::try_from(value).map(|data_type| data_type.with_value(0u8))
+        });
+        let results = registry.parse_all(&payload, ParseContext::Advertising);
+        assert!(results.results[0].is_custom());
+        let into_data: Vec<u8> = results.into();
+        assert_eq!(payload, into_data);
+
+        // failed results are skipped
+        let payload = [
+            vendor1.clone(),
+            vec![0x01, 0x0a],
+            vendor2.clone(),
+            vec![0x05],
+        ]
+        .concat();
+        let into_data: Vec<u8> = DataTypeParseResults::from(&payload).into();
+        assert_eq!([vendor1, vendor2].concat(), into_data);
+    }
+
+    #[test]
+    fn test_results_merge() {
+        let flags: Vec<u8> =
+            Flags::new(&vec![false, true, true, false, false, false, false, false]).into();
+        let device_id: Vec<u8> = DeviceId::new(0x0001, 0x004c, 0x0102, 0x0304).into();
+        let tx_power_level: Vec<u8> = TxPowerLevel::new(-4).into();
+        let advertising = [flags.clone(), tx_power_level.clone()].concat();
+        let scan_response = [tx_power_level.clone(), flags.clone()].concat();
+
+        let results = DataTypeParseResults::merge(&advertising, &scan_response);
+        let mut expected =
+            DataTypeParseResults::from_with_context(&advertising, ParseContext::Advertising)
+                .results;
+        expected.append(
+            &mut DataTypeParseResults::from_with_context(
+                &scan_response,
+                ParseContext::ScanResponse,
+            )
+            .results,
+        );
+        assert_eq!(expected, results.results);
+        assert!(results.results[3].parse_error().is_some());
+        assert_eq!(
+            DataTypeParseResults::from(&advertising),
+            DataTypeParseResults::merge(&advertising, &[])
+        );
+        assert!(DataTypeParseResults::merge(&[], &[]).results.is_empty());
+
+        let mut results = DataTypeParseResults::from(&tx_power_level);
+        results.extend_with_context(&device_id, ParseContext::Advertising);
+        assert!(results.results[1].parse_error().is_some());
+        results.extend_with_context(&device_id, ParseContext::Eir);
+        assert!(results.results[2].is_device_id());
+        results.extend_with_context(&[], ParseContext::Eir);
+        assert_eq!(3, results.results.len());
+    }
+
+    #[test]
+    fn test_results_iter() {
+        let advertising_interval: Vec<u8> = AdvertisingInterval::new(Slots625us(0x01)).into();
+        let tx_power_level: Vec<u8> = TxPowerLevel::new(-4).into();
+        let payload = [advertising_interval, tx_power_level, vec![0x05, 0x0a, 0x00]].concat();
+
+        assert_eq!(
+            DataTypeParseResults::from(&payload).results,
+            DataTypeParseResults::iter(&payload).collect::<Vec<DataTypeParseResult>>()
+        );
+        assert_eq!(
+            DataTypeParseResults::from_with_context(&payload, ParseContext::Acad).results,
+            DataTypeParseResults::iter_with_context(&payload, ParseContext::Acad)
+                .collect::<Vec<DataTypeParseResult>>()
+        );
+        assert_eq!(0, DataTypeParseResults::iter(&[]).count());
+
+        let mut iter = DataTypeParseResults::iter(&payload);
+        assert!(iter.next().unwrap().is_advertising_interval());
+        assert!(iter.next().unwrap().is_tx_power_level());
+        assert_eq!(
+            Some(DataTypeParseResult::DataTypeParseError(
+                DataTypeParseError::InvalidLength {
+                    expected: 6,
+                    actual: 3
+                }
+            )),
+            iter.next()
+        );
+        assert_eq!(None, iter.next());
+        assert_eq!(None, iter.next());
+
+        let results: Vec<DataTypeParseResult> = DataTypeParseResults::iter(&[0xff, 0x0a]).collect();
+        assert_eq!(1, results.len());
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_serde() {
+        let advertising_interval: Vec<u8> = AdvertisingInterval::new(Slots625us(0x01)).into();
+        let payload = [advertising_interval, vec![0x05, 0x0a, 0x00]].concat();
+        let results = DataTypeParseResults::from(&payload);
+
+        let json = serde_json::to_string(&results).unwrap();
+        assert_eq!(
+            results,
+            serde_json::from_str::<DataTypeParseResults>(&json).unwrap()
+        );
+    }
 }
@@ -0,0 +1,272 @@
+//! Borrowed EIR/AD/SRD/ACAD/OOB structure module.
+//!
+//! Views borrow from the received payload (`&[u8]`) and parse without allocation.
+
+use crate::data_types::{parse_error::DataTypeParseError, raw_ad_structure::RawAdStructure};
+
+/// Borrowed EIR/AD/SRD/ACAD/OOB structure.
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub struct DataTypeRef<'a> {
+    /// data length
+    pub length: u8,
+
+    /// data type
+    pub data_type: u8,
+
+    /// data (without length and data type)
+    pub data: &'a [u8],
+
+    /// whole structure
+    bytes: &'a [u8],
+}
+
+impl<'a> DataTypeRef<'a> {
+    /// Iterate [`DataTypeRef`] of EIR/AD/SRD/ACAD/OOB payload.
+    ///
+    /// Structures are split the same way as [`DataTypeParseResults`](crate::data_types::data_type_parser::DataTypeParseResults),
+    /// iteration ends at a zero length field or after a truncated structure.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ble_data_struct::data_types::{
+    ///     data_type::DataType, data_type_ref::DataTypeRef, tx_power_level::TxPowerLevel,
+    /// };
+    /// use ble_data_struct::data_types::parse_error::DataTypeParseError;
+    ///
+    /// let tx_power_level: Vec<u8> = TxPowerLevel::new(-4).into();
+    /// let payload = [tx_power_level.clone(), vec![0x05, 0x0a, 0x00]].concat();
+    ///
+    /// let mut iter = DataTypeRef::iter(&payload);
+    /// let result = iter.next().unwrap().unwrap();
+    /// assert_eq!(TxPowerLevel::data_type(), result.data_type);
+    /// assert_eq!(&tx_power_level[2..], result.data);
+    /// assert_eq!(
+    ///     Some(Err(DataTypeParseError::InvalidLength {
+    ///         expected: 6,
+    ///         actual: 3
+    ///     })),
+    ///     iter.next()
+    /// );
+    /// assert_eq!(None, iter.next());
+    /// ```
+    pub fn iter(payload: &'a [u8]) -> DataTypeRefIter<'a> {
+        DataTypeRefIter { payload, index: 0 }
+    }
+
+    /// Whole structure (length, data type and data).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ble_data_struct::data_types::{data_type_ref::DataTypeRef, tx_power_level::TxPowerLevel};
+    ///
+    /// let data: Vec<u8> = TxPowerLevel::new(-4).into();
+    /// let result = DataTypeRef::try_from(data.as_slice()).unwrap();
+    /// assert_eq!(data.as_slice(), result.as_bytes());
+    /// ```
+    pub fn as_bytes(&self) -> &'a [u8] {
+        self.bytes
+    }
+}
+
+impl<'a> TryFrom<&'a [u8]> for DataTypeRef<'a> {
+    type Error = DataTypeParseError;
+    /// Create [`DataTypeRef`] from `&[u8]`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ble_data_struct::data_types::data_type_ref::DataTypeRef;
+    /// use ble_data_struct::data_types::parse_error::DataTypeParseError;
+    ///
+    /// let data = [0x02, 0x0a, 0xfc, 0x00];
+    /// let result = DataTypeRef::try_from(&data[..]).unwrap();
+    /// assert_eq!(2, result.length);
+    /// assert_eq!(0x0a, result.data_type);
+    /// assert_eq!(&[0xfc], result.data);
+    /// assert_eq!(&data[..3], result.as_bytes());
+    ///
+    /// let result = DataTypeRef::try_from(&data[..2]);
+    /// assert_eq!(
+    ///     DataTypeParseError::InvalidLength {
+    ///         expected: 3,
+    ///         actual: 2
+    ///     },
+    ///     result.unwrap_err()
+    /// );
+    /// ```
+    fn try_from(value: &'a [u8]) -> Result<Self, DataTypeParseError> {
+        let len = value.len();
+        if len < 2 {
+            return Err(DataTypeParseError::InvalidLength {
+                expected: 2,
+                actual: len,
+            });
+        }
+        let length = value[0] as usize;
+        if length == 0 {
+            return Err(DataTypeParseError::ValueOutOfRange {
+                field: "length",
+                value: 0,
+            });
+        }
+        if len < 1 + length {
+            return Err(DataTypeParseError::InvalidLength {
+                expected: 1 + length,
+                actual: len,
+            });
+        }
+        Ok(Self {
+            length: value[0],
+            data_type: value[1],
+            data: &value[2..1 + length],
+            bytes: &value[..1 + length],
+        })
+    }
+}
+
+impl From<&DataTypeRef<'_>> for RawAdStructure {
+    /// Create [`RawAdStructure`] from [`DataTypeRef`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ble_data_struct::data_types::{
+    ///     data_type_ref::DataTypeRef, raw_ad_structure::RawAdStructure,
+    /// };
+    ///
+    /// let data = [0x02, 0x2f, 0x01];
+    /// let result = RawAdStructure::from(&DataTypeRef::try_from(&data[..]).unwrap());
+    /// assert_eq!(RawAdStructure::new(0x2f, &[0x01]).unwrap(), result);
+    /// ```
+    fn from(value: &DataTypeRef<'_>) -> Self {
+        Self {
+            ad_type: value.data_type,
+            data: value.data.to_vec(),
+        }
+    }
+}
+
+/// Iterator returned by [`DataTypeRef::iter`].
+#[derive(Debug, Clone)]
+pub struct DataTypeRefIter<'a> {
+    /// payload
+    payload: &'a [u8],
+
+    /// next structure index
+    index: usize,
+}
+
+impl<'a> Iterator for DataTypeRefIter<'a> {
+    type Item = Result<DataTypeRef<'a>, DataTypeParseError>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let len = self.payload.len();
+        if self.index >= len {
+            return None;
+        }
+        let index = self.index;
+        if self.payload[index] == 0 {
+            // zero length terminates the significant part
+            self.index = len;
+            return None;
+        }
+        let end = index + 1 + self.payload[index] as usize;
+        if end > len {
+            self.index = len;
+            return Some(Err(DataTypeParseError::InvalidLength {
+                expected: end - index,
+                actual: len - index,
+            }));
+        }
+        self.index = end;
+        Some(DataTypeRef::try_from(&self.payload[index..end]))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::data_types::{
+        complete_local_name::CompleteLocalName, data_type::DataType, data_type_ref::DataTypeRef,
+        parse_error::DataTypeParseError, raw_ad_structure::RawAdStructure,
+        tx_power_level::TxPowerLevel,
+    };
+
+    #[test]
+    fn test_try_from() {
+        let data = [0x02, 0x0a, 0xfc, 0x00];
+        let result = DataTypeRef::try_from(&data[..]).unwrap();
+        assert_eq!(2, result.length);
+        assert_eq!(0x0a, result.data_type);
+        assert_eq!(&[0xfc], result.data);
+        assert_eq!(&data[..3], result.as_bytes());
+
+        assert_eq!(
+            DataTypeParseError::InvalidLength {
+                expected: 2,
+                actual: 1
+            },
+            DataTypeRef::try_from(&data[..1]).unwrap_err()
+        );
+        assert_eq!(
+            DataTypeParseError::ValueOutOfRange {
+                field: "length",
+                value: 0
+            },
+            DataTypeRef::try_from(&[0x00, 0x0a][..]).unwrap_err()
+        );
+        assert_eq!(
+            DataTypeParseError::InvalidLength {
+                expected: 3,
+                actual: 2
+            },
+            DataTypeRef::try_from(&data[..2]).unwrap_err()
+        );
+    }
+
+    #[test]
+    fn test_iter() {
+        let tx_power_level: Vec<u8> = TxPowerLevel::new(-4).into();
+        let name: Vec<u8> = CompleteLocalName::new(&"name".to_string()).into();
+        let payload = [tx_power_level.clone(), name.clone()].concat();
+        let results: Vec<DataTypeRef> = DataTypeRef::iter(&payload).map(Result::unwrap).collect();
+        assert_eq!(2, results.len());
+        assert_eq!(TxPowerLevel::data_type(), results[0].data_type);
+        assert_eq!(tx_power_level.as_slice(), results[0].as_bytes());
+        assert_eq!(CompleteLocalName::data_type(), results[1].data_type);
+        assert_eq!(&name[2..], results[1].data);
+
+        assert_eq!(0, DataTypeRef::iter(&[]).count());
+
+        // zero padded payload
+        let payload = [tx_power_level.clone(), name.clone(), vec![0x00; 8]].concat();
+        let mut iter = DataTypeRef::iter(&payload);
+        assert!(iter.next().unwrap().is_ok());
+        assert!(iter.next().unwrap().is_ok());
+        assert_eq!(None, iter.next());
+        assert_eq!(None, iter.next());
+
+        let payload = [tx_power_level.clone(), vec![0x00], name.clone()].concat();
+        assert_eq!(1, DataTypeRef::iter(&payload).count());
+
+        let payload = [tx_power_level, name[..3].to_vec()].concat();
+        let mut iter = DataTypeRef::iter(&payload);
+        assert!(iter.next().unwrap().is_ok());
+        assert_eq!(
+            Some(Err(DataTypeParseError::InvalidLength {
+                expected: 6,
+                actual: 3
+            })),
+            iter.next()
+        );
+        assert_eq!(None, iter.next());
+    }
+
+    #[test]
+    fn test_into_raw_ad_structure() {
+        let data = [0x02, 0x2f, 0x01];
+        let result = RawAdStructure::from(&DataTypeRef::try_from(&data[..]).unwrap());
+        assert_eq!(RawAdStructure::new(0x2f, &[0x01]).unwrap(), result);
+    }
+}
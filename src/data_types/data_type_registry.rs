@@ -0,0 +1,477 @@
+//! Data type registry module.
+//!
+//! Parsers are looked up by data type, so custom/vendor data types are added with a single [`DataTypeRegistry::register`] call.
+//! Handlers registered with [`DataTypeRegistry::register_handler`] return [`CustomDataType`](crate::data_types::custom_data_type::CustomDataType) as [`DataTypeParseResult::CustomResult`].
+
+use crate::data_types::{
+    custom_data_type::CustomDataTypeHandler,
+    data_type_parser::{
+        parse_in_context, DataTypeParseFn, DataTypeParseResult, DataTypeParseResults, ParseContext,
+        DEFAULT_PARSERS,
+    },
+    data_type_ref::DataTypeRef,
+};
+
+/// Data type parser registry.
+///
+/// [`DataTypeRegistry::new`] starts with the built-in parsers used by [`DataTypeParseResult::from`].
+#[derive(Debug, Clone)]
+pub struct DataTypeRegistry {
+    /// parsers indexed by data type
+    parsers: [Option<DataTypeParseFn>; 256],
+
+    /// custom data type handlers indexed by data type (take precedence over parsers)
+    handlers: [Option<CustomDataTypeHandler>; 256],
+}
+
+impl DataTypeRegistry {
+    /// Create [`DataTypeRegistry`] with the built-in parsers.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ble_data_struct::data_types::{
+    ///     data_type::DataType, data_type_registry::DataTypeRegistry, flags::Flags,
+    /// };
+    ///
+    /// let result = DataTypeRegistry::new();
+    /// assert!(result.is_registered(Flags::data_type()));
+    /// assert!(!result.is_registered(0x50));
+    /// ```
+    pub fn new() -> Self {
+        Self {
+            parsers: DEFAULT_PARSERS,
+            handlers: [None; 256],
+        }
+    }
+
+    /// Register parser of data type.
+    ///
+    /// Returns the replaced parser (built-in parsers can be overridden).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ble_data_struct::data_types::{
+    ///     data_type_parser::{DataTypeParseResult, ParseContext},
+    ///     data_type_registry::DataTypeRegistry,
+    ///     raw_ad_structure::RawAdStructure,
+    /// };
+    ///
+    /// let mut registry = DataTypeRegistry::new();
+    /// assert!(registry
+    ///     .register(0x50, |value, _| {
+    ///         DataTypeParseResult::RawAdStructureResult(RawAdStructure::try_from(value))
+    ///     })
+    ///     .is_none());
+    /// assert!(registry.is_registered(0x50));
+    ///
+    /// let result = registry.parse(&[0x02, 0x50, 0x01], ParseContext::Advertising);
+    /// assert!(matches!(
+    ///     result,
+    ///     DataTypeParseResult::RawAdStructureResult(Ok(RawAdStructure { ad_type: 0x50, .. }))
+    /// ));
+    /// ```
+    pub fn register(&mut self, data_type: u8, parser: DataTypeParseFn) -> Option<DataTypeParseFn> {
+        self.parsers[data_type as usize].replace(parser)
+    }
+
+    /// Unregister parser of data type.
+    ///
+    /// Unregistered data types are parsed as [`DataTypeParseResult::Unknown`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ble_data_struct::data_types::{
+    ///     data_type::DataType, data_type_parser::ParseContext,
+    ///     data_type_registry::DataTypeRegistry, tx_power_level::TxPowerLevel,
+    /// };
+    ///
+    /// let mut registry = DataTypeRegistry::new();
+    /// assert!(registry.unregister(TxPowerLevel::data_type()).is_some());
+    ///
+    /// let data: Vec<u8> = TxPowerLevel::new(-4).into();
+    /// assert!(registry.parse(&data, ParseContext::Advertising).is_unknown());
+    /// ```
+    pub fn unregister(&mut self, data_type: u8) -> Option<DataTypeParseFn> {
+        self.parsers[data_type as usize].take()
+    }
+
+    /// Register custom data type handler.
+    ///
+    /// The handler takes precedence over the parser of the same data type,
+    /// and its result is returned as [`DataTypeParseResult::CustomResult`].
+    /// Returns the replaced handler.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ble_data_struct::data_types::{
+    ///     custom_data_type::CustomDataType,
+    ///     data_type_parser::{DataTypeParseResult, ParseContext},
+    ///     data_type_registry::DataTypeRegistry,
+    ///     parse_error::DataTypeParseError,
+    /// };
+    ///
+    /// let mut registry = DataTypeRegistry::new();
+    /// assert!(registry
+    ///     .register_handler(0xf0, |value| {
+    ///         let data_type = CustomDataType::try_from(value)?;
+    ///         if data_type.data.len() != 2 {
+    ///             return Err(DataTypeParseError::InvalidLength {
+    ///                 expected: 4,
+    ///                 actual: value.len(),
+    ///             });
+    ///         }
+    ///         let temperature = i16::from_le_bytes([data_type.data[0], data_type.data[1]]);
+    ///         Ok(data_type.with_value(temperature))
+    ///     })
+    ///     .is_none());
+    ///
+    /// let result = registry.parse(&[0x03, 0xf0, 0xfe, 0xff], ParseContext::Advertising);
+    /// match result {
+    ///     DataTypeParseResult::CustomResult(Ok(data_type)) => {
+    ///         assert_eq!(Some(&-2i16), data_type.value::<i16>())
+    ///     }
+    ///     _ => unreachable!(),
+    /// }
+    /// assert!(registry
+    ///     .parse(&[0x02, 0xf0, 0xfe], ParseContext::Advertising)
+    ///     .parse_error()
+    ///     .is_some());
+    /// ```
+    pub fn register_handler(
+        &mut self,
+        data_type: u8,
+        handler: CustomDataTypeHandler,
+    ) -> Option<CustomDataTypeHandler> {
+        self.handlers[data_type as usize].replace(handler)
+    }
+
+    /// Create [`DataTypeRegistry`] with custom data type handler registered.
+    ///
+    /// Same as [`DataTypeRegistry::register_handler`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ble_data_struct::data_types::{
+    ///     custom_data_type::CustomDataType,
+    ///     data_type_parser::ParseContext,
+    ///     data_type_registry::DataTypeRegistry,
+    ///     tx_power_level::TxPowerLevel,
+    /// };
+    ///
+    /// let registry = DataTypeRegistry::new()
+    ///     .with_handler(0xf0, |value| CustomDataType::try_from(value))
+    ///     .with_handler(0xf1, |value| CustomDataType::try_from(value));
+    ///
+    /// let tx_power_level: Vec<u8> = TxPowerLevel::new(-4).into();
+    /// let payload = [tx_power_level, vec![0x02, 0xf0, 0x01], vec![0x01, 0xf1]].concat();
+    /// let results = registry.parse_all(&payload, ParseContext::Advertising);
+    /// assert!(results.results[0].is_tx_power_level());
+    /// assert!(results.results[1].is_custom());
+    /// assert!(results.results[2].is_custom());
+    /// ```
+    pub fn with_handler(mut self, data_type: u8, handler: CustomDataTypeHandler) -> Self {
+        self.register_handler(data_type, handler);
+        self
+    }
+
+    /// Unregister custom data type handler.
+    ///
+    /// The parser of the data type (if any) is used again.
+    pub fn unregister_handler(&mut self, data_type: u8) -> Option<CustomDataTypeHandler> {
+        self.handlers[data_type as usize].take()
+    }
+
+    /// Check parser or custom data type handler of data type is registered.
+    pub fn is_registered(&self, data_type: u8) -> bool {
+        self.parsers[data_type as usize].is_some() || self.handlers[data_type as usize].is_some()
+    }
+
+    /// Parse single EIR/AD/SRD/ACAD/OOB structure in [`ParseContext`].
+    ///
+    /// Same as [`DataTypeParseResult::from_with_context`] with the registered parsers.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ble_data_struct::data_types::{
+    ///     data_type_parser::{DataTypeParseResult, ParseContext},
+    ///     data_type_registry::DataTypeRegistry,
+    ///     tx_power_level::TxPowerLevel,
+    /// };
+    ///
+    /// let data: Vec<u8> = TxPowerLevel::new(-4).into();
+    /// assert_eq!(
+    ///     DataTypeParseResult::from_with_context(&data, ParseContext::Advertising),
+    ///     DataTypeRegistry::new().parse(&data, ParseContext::Advertising)
+    /// );
+    /// ```
+    pub fn parse(&self, value: &[u8], context: ParseContext) -> DataTypeParseResult {
+        match value
+            .get(1)
+            .filter(|data_type| context.is_allowed(**data_type))
+            .and_then(|data_type| self.handlers[*data_type as usize])
+        {
+            Some(handler) => DataTypeParseResult::CustomResult(handler(value)),
+            None => parse_in_context(&self.parsers, value, context),
+        }
+    }
+
+    /// Parse EIR/AD/SRD/ACAD/OOB structures in [`ParseContext`].
+    ///
+    /// Same as [`DataTypeParseResults::from_with_context`] with the registered parsers.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ble_data_struct::data_types::{
+    ///     data_type_parser::{DataTypeParseResult, ParseContext},
+    ///     data_type_registry::DataTypeRegistry,
+    ///     raw_ad_structure::RawAdStructure,
+    ///     tx_power_level::TxPowerLevel,
+    /// };
+    ///
+    /// let mut registry = DataTypeRegistry::new();
+    /// registry.register(0x50, |value, _| {
+    ///     DataTypeParseResult::RawAdStructureResult(RawAdStructure::try_from(value))
+    /// });
+    ///
+    /// let tx_power_level: Vec<u8> = TxPowerLevel::new(-4).into();
+    /// let payload = [tx_power_level, vec![0x02, 0x50, 0x01]].concat();
+    /// let results = registry.parse_all(&payload, ParseContext::Advertising);
+    /// assert!(results.results[0].is_tx_power_level());
+    /// assert!(results.results[1].is_raw_ad_structure());
+    /// ```
+    pub fn parse_all(&self, value: &[u8], context: ParseContext) -> DataTypeParseResults {
+        #[cfg(feature = "tracing")]
+        let _span = tracing::debug_span!("parse_data_types", length = value.len()).entered();
+        // most structures are 3 bytes or longer
+        let mut results = Vec::with_capacity(value.len() / 3 + 1);
+        results.extend(DataTypeRef::iter(value).map(|f| match f {
+            Ok(data_type) => self.parse(data_type.as_bytes(), context),
+            Err(e) => DataTypeParseResult::DataTypeParseError(e),
+        }));
+        DataTypeParseResults { results }
+    }
+}
+
+impl Default for DataTypeRegistry {
+    /// Create [`DataTypeRegistry`] with the built-in parsers.
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::data_types::{
+        ad_type::AdType,
+        custom_data_type::CustomDataType,
+        data_type::DataType,
+        data_type_parser::{DataTypeParseResult, DataTypeParseResults, ParseContext},
+        data_type_registry::DataTypeRegistry,
+        device_id::DeviceId,
+        flags::Flags,
+        parse_error::DataTypeParseError,
+        raw_ad_structure::RawAdStructure,
+        tx_power_level::TxPowerLevel,
+    };
+
+    fn raw(value: &[u8], _: ParseContext) -> DataTypeParseResult {
+        DataTypeParseResult::RawAdStructureResult(RawAdStructure::try_from(value))
+    }
+
+    fn custom(value: &[u8]) -> Result<CustomDataType, DataTypeParseError> {
+        CustomDataType::try_from(value).map(|data_type| {
+            let value = data_type.data.len();
+            data_type.with_value(value)
+        })
+    }
+
+    #[test]
+    fn test_new() {
+        let result = DataTypeRegistry::new();
+        for data_type in 0..=u8::MAX {
+            assert_eq!(
+                AdType::from_u8(data_type).is_some(),
+                result.is_registered(data_type),
+                "{}",
+                data_type
+            );
+        }
+        assert!(DataTypeRegistry::default().is_registered(Flags::data_type()));
+    }
+
+    #[test]
+    fn test_register() {
+        let mut registry = DataTypeRegistry::new();
+        assert!(registry.register(0x50, raw).is_none());
+        assert!(registry.register(0x50, raw).is_some());
+        assert!(registry.is_registered(0x50));
+        assert_eq!(
+            DataTypeParseResult::RawAdStructureResult(Ok(
+                RawAdStructure::new(0x50, &[0x01]).unwrap()
+            )),
+            registry.parse(&[0x02, 0x50, 0x01], ParseContext::Advertising)
+        );
+
+        let data: Vec<u8> = TxPowerLevel::new(-4).into();
+        assert!(registry.register(TxPowerLevel::data_type(), raw).is_some());
+        assert!(registry
+            .parse(&data, ParseContext::Advertising)
+            .is_raw_ad_structure());
+        assert!(DataTypeParseResult::from(&data).is_tx_power_level());
+    }
+
+    #[test]
+    fn test_unregister() {
+        let mut registry = DataTypeRegistry::new();
+        assert!(registry.unregister(TxPowerLevel::data_type()).is_some());
+        assert!(registry.unregister(TxPowerLevel::data_type()).is_none());
+        assert!(!registry.is_registered(TxPowerLevel::data_type()));
+
+        let data: Vec<u8> = TxPowerLevel::new(-4).into();
+        assert_eq!(
+            DataTypeParseResult::Unknown(
+                RawAdStructure::new(TxPowerLevel::data_type(), &[0xfc]).unwrap()
+            ),
+            registry.parse(&data, ParseContext::Advertising)
+        );
+    }
+
+    #[test]
+    fn test_parse() {
+        let registry = DataTypeRegistry::new();
+        let device_id: Vec<u8> = DeviceId::new(0x0001, 0x004c, 0x0102, 0x0304).into();
+        for context in [
+            ParseContext::Advertising,
+            ParseContext::ScanResponse,
+            ParseContext::Eir,
+            ParseContext::Oob,
+            ParseContext::Acad,
+            ParseContext::PeriodicAdvertising,
+        ] {
+            for data in [
+                device_id.clone(),
+                vec![0x02, 0x50, 0x01],
+                vec![0x01],
+                vec![],
+            ] {
+                assert_eq!(
+                    DataTypeParseResult::from_with_context(&data, context),
+                    registry.parse(&data, context)
+                );
+            }
+        }
+
+        let mut registry = DataTypeRegistry::new();
+        registry.register(0x50, raw);
+        assert!(registry
+            .parse(&[0x01, 0x50], ParseContext::Advertising)
+            .is_raw_ad_structure());
+        assert!(registry.parse(&device_id, ParseContext::Eir).is_device_id());
+        assert!(registry
+            .parse(&device_id, ParseContext::Oob)
+            .is_security_manager_tk_value());
+        assert_eq!(
+            DataTypeParseResult::DataTypeParseError(DataTypeParseError::DataTypeNotAllowed(
+                DeviceId::data_type()
+            )),
+            registry.parse(&device_id, ParseContext::Advertising)
+        );
+    }
+
+    #[test]
+    fn test_parse_all() {
+        let tx_power_level: Vec<u8> = TxPowerLevel::new(-4).into();
+        let payload = [tx_power_level, vec![0x02, 0x50, 0x01], vec![0x05, 0x0a]].concat();
+        assert_eq!(
+            DataTypeParseResults::from_with_context(&payload, ParseContext::Advertising),
+            DataTypeRegistry::new().parse_all(&payload, ParseContext::Advertising)
+        );
+
+        let mut registry = DataTypeRegistry::new();
+        registry.register(0x50, raw);
+        let results = registry.parse_all(&payload, ParseContext::Advertising);
+        assert_eq!(3, results.results.len());
+        assert!(results.results[0].is_tx_power_level());
+        assert!(results.results[1].is_raw_ad_structure());
+        assert!(results.results[2].parse_error().is_some());
+    }
+    #[test]
+    fn test_register_handler() {
+        let mut registry = DataTypeRegistry::new();
+        assert!(!registry.is_registered(0xf0));
+        assert!(registry.register_handler(0xf0, custom).is_none());
+        assert!(registry.register_handler(0xf0, custom).is_some());
+        assert!(registry.is_registered(0xf0));
+
+        let result = registry.parse(&[0x03, 0xf0, 0x01, 0x02], ParseContext::Advertising);
+        assert_eq!(
+            DataTypeParseResult::CustomResult(Ok(CustomDataType::new(0xf0, &[0x01, 0x02]))),
+            result
+        );
+        match result {
+            DataTypeParseResult::CustomResult(Ok(data_type)) => {
+                assert_eq!(Some(&2usize), data_type.value::<usize>())
+            }
+            _ => unreachable!(),
+        }
+        assert!(registry
+            .parse(&[0x03, 0xf0, 0x01], ParseContext::Advertising)
+            .parse_error()
+            .is_some());
+
+        // handler takes precedence over the parser
+        let data: Vec<u8> = TxPowerLevel::new(-4).into();
+        registry.register_handler(TxPowerLevel::data_type(), custom);
+        assert!(registry.parse(&data, ParseContext::Advertising).is_custom());
+        assert!(registry
+            .unregister_handler(TxPowerLevel::data_type())
+            .is_some());
+        assert!(registry
+            .unregister_handler(TxPowerLevel::data_type())
+            .is_none());
+        assert!(registry
+            .parse(&data, ParseContext::Advertising)
+            .is_tx_power_level());
+
+        // not allowed data type is not passed to the handler
+        let registry = DataTypeRegistry::new().with_handler(Flags::data_type(), custom);
+        let data: Vec<u8> = vec![0x02, Flags::data_type(), 0x06];
+        assert!(registry.parse(&data, ParseContext::Advertising).is_custom());
+        assert_eq!(
+            DataTypeParseResult::DataTypeParseError(DataTypeParseError::DataTypeNotAllowed(
+                Flags::data_type()
+            )),
+            registry.parse(&data, ParseContext::ScanResponse)
+        );
+    }
+
+    #[test]
+    fn test_with_handler() {
+        let tx_power_level: Vec<u8> = TxPowerLevel::new(-4).into();
+        let payload = [
+            tx_power_level,
+            vec![0x02, 0xf0, 0x01],
+            vec![0x02, 0xf1, 0x01],
+        ]
+        .concat();
+        let registry = DataTypeRegistry::new().with_handler(0xf0, custom);
+        let results = registry.parse_all(&payload, ParseContext::Advertising);
+        assert_eq!(3, results.results.len());
+        assert!(results.results[0].is_tx_power_level());
+        assert!(results.results[1].is_custom());
+        assert_eq!(
+            Some(0xf0),
+            results.results[1]
+                .ad_structure()
+                .map(|data_type| data_type.ad_type())
+        );
+        assert!(results.results[2].is_unknown());
+    }
+}
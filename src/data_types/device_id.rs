@@ -0,0 +1,318 @@
+//! Device ID (Data Type Value: 0x10) module.
+//!
+//! Shares the data type value with [`SecurityManagerTkValue`](crate::data_types::security_manager_tk_value::SecurityManagerTkValue).
+//! Device ID is used in EIR, Security Manager TK Value in OOB data.
+
+use crate::data_types::{ad_type::AdType, data_type::DataType, parse_error::DataTypeParseError};
+
+/// Vendor ID Source: Bluetooth SIG assigned Company Identifier.
+pub const VENDOR_ID_SOURCE_BLUETOOTH_SIG: u16 = 0x0001;
+
+/// Vendor ID Source: USB Implementer's Forum assigned Vendor ID.
+pub const VENDOR_ID_SOURCE_USB_IF: u16 = 0x0002;
+
+/// Device ID.
+#[derive(Debug, PartialEq, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct DeviceId {
+    /// data length
+    pub length: u8,
+
+    /// Vendor ID Source
+    pub vendor_id_source: u16,
+
+    /// Vendor ID
+    pub vendor_id: u16,
+
+    /// Product ID
+    pub product_id: u16,
+
+    /// Version
+    pub version: u16,
+}
+
+impl DeviceId {
+    /// Create [`DeviceId`] from Parameters.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ble_data_struct::data_types::device_id::{DeviceId, VENDOR_ID_SOURCE_BLUETOOTH_SIG};
+    ///
+    /// let result = DeviceId::new(VENDOR_ID_SOURCE_BLUETOOTH_SIG, 0x004c, 0x0102, 0x0304);
+    /// assert_eq!(9, result.length);
+    /// assert_eq!(VENDOR_ID_SOURCE_BLUETOOTH_SIG, result.vendor_id_source);
+    /// assert_eq!(0x004c, result.vendor_id);
+    /// assert_eq!(0x0102, result.product_id);
+    /// assert_eq!(0x0304, result.version);
+    /// ```
+    pub fn new(vendor_id_source: u16, vendor_id: u16, product_id: u16, version: u16) -> Self {
+        Self {
+            length: 9,
+            vendor_id_source,
+            vendor_id,
+            product_id,
+            version,
+        }
+    }
+
+    /// check Vendor ID is a Bluetooth SIG assigned Company Identifier.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ble_data_struct::data_types::device_id::*;
+    ///
+    /// assert!(DeviceId::new(VENDOR_ID_SOURCE_BLUETOOTH_SIG, 0x004c, 0, 0).is_bluetooth_sig_vendor_id());
+    /// assert!(!DeviceId::new(VENDOR_ID_SOURCE_USB_IF, 0x05ac, 0, 0).is_bluetooth_sig_vendor_id());
+    /// ```
+    pub const fn is_bluetooth_sig_vendor_id(&self) -> bool {
+        self.vendor_id_source == VENDOR_ID_SOURCE_BLUETOOTH_SIG
+    }
+
+    /// check Vendor ID is a USB Implementer's Forum assigned Vendor ID.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ble_data_struct::data_types::device_id::*;
+    ///
+    /// assert!(DeviceId::new(VENDOR_ID_SOURCE_USB_IF, 0x05ac, 0, 0).is_usb_if_vendor_id());
+    /// assert!(!DeviceId::new(VENDOR_ID_SOURCE_BLUETOOTH_SIG, 0x004c, 0, 0).is_usb_if_vendor_id());
+    /// ```
+    pub const fn is_usb_if_vendor_id(&self) -> bool {
+        self.vendor_id_source == VENDOR_ID_SOURCE_USB_IF
+    }
+}
+
+impl TryFrom<&[u8]> for DeviceId {
+    type Error = DataTypeParseError;
+    /// Create [`DeviceId`] from `&[u8]`.
+    fn try_from(value: &[u8]) -> Result<Self, DataTypeParseError> {
+        let len = value.len();
+        if len < 10 {
+            return Err(DataTypeParseError::InvalidLength {
+                expected: 10,
+                actual: len,
+            });
+        }
+        let length = value[0];
+        Ok(Self {
+            length,
+            vendor_id_source: u16::from_le_bytes(value[2..4].try_into().unwrap()),
+            vendor_id: u16::from_le_bytes(value[4..6].try_into().unwrap()),
+            product_id: u16::from_le_bytes(value[6..8].try_into().unwrap()),
+            version: u16::from_le_bytes(value[8..10].try_into().unwrap()),
+        })
+    }
+}
+
+impl TryFrom<&Vec<u8>> for DeviceId {
+    type Error = DataTypeParseError;
+    /// Create [`DeviceId`] from [`Vec<u8>`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ble_data_struct::data_types::{device_id::*, data_type::DataType};
+    /// use ble_data_struct::data_types::parse_error::DataTypeParseError;
+    ///
+    /// let length = 9;
+    /// let mut data: Vec<u8> = Vec::new();
+    /// data.push(length);
+    /// data.push(DeviceId::data_type());
+    /// data.append(&mut VENDOR_ID_SOURCE_BLUETOOTH_SIG.to_le_bytes().to_vec());
+    /// data.append(&mut 0x004cu16.to_le_bytes().to_vec());
+    /// data.append(&mut 0x0102u16.to_le_bytes().to_vec());
+    /// data.append(&mut 0x0304u16.to_le_bytes().to_vec());
+    ///
+    /// let result = DeviceId::try_from(&data);
+    /// assert!(result.is_ok());
+    /// let data_type = result.unwrap();
+    /// assert_eq!(length, data_type.length);
+    /// assert_eq!(VENDOR_ID_SOURCE_BLUETOOTH_SIG, data_type.vendor_id_source);
+    /// assert_eq!(0x004c, data_type.vendor_id);
+    /// assert_eq!(0x0102, data_type.product_id);
+    /// assert_eq!(0x0304, data_type.version);
+    ///
+    /// let data: Vec<u8> = Vec::new();
+    /// let result = DeviceId::try_from(&data);
+    /// assert!(result.is_err());
+    /// assert_eq!(
+    ///     DataTypeParseError::InvalidLength {
+    ///         expected: 10,
+    ///         actual: data.len()
+    ///     },
+    ///     result.unwrap_err()
+    /// );
+    /// ```
+    fn try_from(value: &Vec<u8>) -> Result<Self, DataTypeParseError> {
+        Self::try_from(value.as_slice())
+    }
+}
+
+impl Into<Vec<u8>> for DeviceId {
+    /// Create [`Vec<u8>`] from [`DeviceId`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ble_data_struct::data_types::{device_id::*, data_type::DataType};
+    ///
+    /// let result1 = DeviceId::new(VENDOR_ID_SOURCE_USB_IF, 0x05ac, 0x0102, 0x0304);
+    ///
+    /// let mut data: Vec<u8> = Vec::new();
+    /// data.push(9);
+    /// data.push(DeviceId::data_type());
+    /// data.append(&mut VENDOR_ID_SOURCE_USB_IF.to_le_bytes().to_vec());
+    /// data.append(&mut 0x05acu16.to_le_bytes().to_vec());
+    /// data.append(&mut 0x0102u16.to_le_bytes().to_vec());
+    /// data.append(&mut 0x0304u16.to_le_bytes().to_vec());
+    ///
+    /// let into_data: Vec<u8> = result1.into();
+    /// assert_eq!(data, into_data);
+    ///
+    /// let result2 = DeviceId::try_from(&data);
+    /// assert!(result2.is_ok());
+    /// let data_type = result2.unwrap();
+    /// let into_data: Vec<u8> = data_type.into();
+    /// assert_eq!(data, into_data);
+    /// ```
+    fn into(self) -> Vec<u8> {
+        let mut data: Vec<u8> = Vec::new();
+        data.push(self.length);
+        data.push(Self::data_type());
+        data.append(&mut self.vendor_id_source.to_le_bytes().to_vec());
+        data.append(&mut self.vendor_id.to_le_bytes().to_vec());
+        data.append(&mut self.product_id.to_le_bytes().to_vec());
+        data.append(&mut self.version.to_le_bytes().to_vec());
+        return data;
+    }
+}
+
+impl DataType for DeviceId {
+    /// return `0x10`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ble_data_struct::data_types::{device_id::DeviceId, data_type::DataType};
+    ///
+    /// assert_eq!(0x10, DeviceId::data_type());
+    /// ```
+    #[inline]
+    fn data_type() -> u8 {
+        0x10
+    }
+}
+
+/// check `Device ID` data type.
+///
+/// # Examples
+///
+/// ```
+/// use ble_data_struct::data_types::device_id::*;
+/// use ble_data_struct::data_types::data_type::DataType;
+///
+/// assert!(is_device_id(0x10));
+/// assert!(!is_device_id(0x00));
+/// ```
+#[inline]
+pub fn is_device_id(data_type: u8) -> bool {
+    AdType::from_u8(data_type) == Some(AdType::DeviceIdOrSecurityManagerTkValue)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::data_types::{data_type::DataType, device_id::*};
+
+    #[test]
+    fn test_new() {
+        let result = DeviceId::new(VENDOR_ID_SOURCE_USB_IF, 0x05ac, 0x0102, 0x0304);
+        assert_eq!(9, result.length);
+        assert_eq!(VENDOR_ID_SOURCE_USB_IF, result.vendor_id_source);
+        assert_eq!(0x05ac, result.vendor_id);
+        assert_eq!(0x0102, result.product_id);
+        assert_eq!(0x0304, result.version);
+    }
+
+    #[test]
+    fn test_is_bluetooth_sig_vendor_id() {
+        assert!(DeviceId::new(VENDOR_ID_SOURCE_BLUETOOTH_SIG, 0, 0, 0).is_bluetooth_sig_vendor_id());
+        assert!(!DeviceId::new(VENDOR_ID_SOURCE_USB_IF, 0, 0, 0).is_bluetooth_sig_vendor_id());
+        assert!(!DeviceId::new(0xffff, 0, 0, 0).is_bluetooth_sig_vendor_id());
+    }
+
+    #[test]
+    fn test_is_usb_if_vendor_id() {
+        assert!(DeviceId::new(VENDOR_ID_SOURCE_USB_IF, 0, 0, 0).is_usb_if_vendor_id());
+        assert!(!DeviceId::new(VENDOR_ID_SOURCE_BLUETOOTH_SIG, 0, 0, 0).is_usb_if_vendor_id());
+        assert!(!DeviceId::new(0xffff, 0, 0, 0).is_usb_if_vendor_id());
+    }
+
+    #[test]
+    fn test_try_from() {
+        let length = 9;
+        let mut data: Vec<u8> = Vec::new();
+        data.push(length);
+        data.push(DeviceId::data_type());
+        data.append(&mut VENDOR_ID_SOURCE_BLUETOOTH_SIG.to_le_bytes().to_vec());
+        data.append(&mut 0x004cu16.to_le_bytes().to_vec());
+        data.append(&mut 0x0102u16.to_le_bytes().to_vec());
+        data.append(&mut 0x0304u16.to_le_bytes().to_vec());
+
+        let result = DeviceId::try_from(&data);
+        assert!(result.is_ok());
+        let data_type = result.unwrap();
+        assert_eq!(length, data_type.length);
+        assert_eq!(VENDOR_ID_SOURCE_BLUETOOTH_SIG, data_type.vendor_id_source);
+        assert_eq!(0x004c, data_type.vendor_id);
+        assert_eq!(0x0102, data_type.product_id);
+        assert_eq!(0x0304, data_type.version);
+
+        let mut data: Vec<u8> = vec![0u8; 9];
+        data[0] = data.len() as u8 - 1;
+        let result = DeviceId::try_from(&data);
+        assert!(result.is_err());
+        assert_eq!(
+            DataTypeParseError::InvalidLength {
+                expected: 10,
+                actual: data.len()
+            },
+            result.unwrap_err()
+        );
+    }
+
+    #[test]
+    fn test_into() {
+        let result1 = DeviceId::new(VENDOR_ID_SOURCE_BLUETOOTH_SIG, 0x004c, 0x0102, 0x0304);
+
+        let mut data: Vec<u8> = Vec::new();
+        data.push(9);
+        data.push(DeviceId::data_type());
+        data.append(&mut VENDOR_ID_SOURCE_BLUETOOTH_SIG.to_le_bytes().to_vec());
+        data.append(&mut 0x004cu16.to_le_bytes().to_vec());
+        data.append(&mut 0x0102u16.to_le_bytes().to_vec());
+        data.append(&mut 0x0304u16.to_le_bytes().to_vec());
+
+        let into_data: Vec<u8> = result1.into();
+        assert_eq!(data, into_data);
+
+        let result2 = DeviceId::try_from(&data);
+        assert!(result2.is_ok());
+        let data_type = result2.unwrap();
+        let into_data: Vec<u8> = data_type.into();
+        assert_eq!(data, into_data);
+    }
+
+    #[test]
+    fn test_data_type() {
+        assert_eq!(0x10, DeviceId::data_type());
+    }
+
+    #[test]
+    fn test_is_device_id() {
+        assert!(is_device_id(0x10));
+        assert!(!is_device_id(0x00));
+    }
+}
@@ -0,0 +1,300 @@
+//! Electronic Shelf Label (Data Type Value: 0x34) module.
+
+use crate::data_types::{ad_type::AdType, data_type::DataType, parse_error::DataTypeParseError};
+
+/// Electronic Shelf Label.
+///
+/// ESL advertising payload encrypted with the AP Sync Key Material.
+#[derive(Debug, PartialEq, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ElectronicShelfLabel {
+    /// data length
+    pub length: u8,
+
+    /// Randomizer
+    pub randomizer: [u8; 5],
+
+    /// Encrypted ESL payload
+    pub payload: Vec<u8>,
+
+    /// MIC
+    pub mic: [u8; 4],
+}
+
+impl ElectronicShelfLabel {
+    /// Create [`ElectronicShelfLabel`] from Parameters.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ble_data_struct::data_types::electronic_shelf_label::ElectronicShelfLabel;
+    ///
+    /// let randomizer: [u8; 5] = [1, 2, 3, 4, 5];
+    /// let payload = [6].to_vec();
+    /// let mic: [u8; 4] = [7, 8, 9, 10];
+    /// let result = ElectronicShelfLabel::new(&randomizer, &payload, mic);
+    /// assert_eq!(11, result.length);
+    /// assert_eq!(randomizer, result.randomizer);
+    /// assert_eq!(payload, result.payload);
+    /// assert_eq!(mic, result.mic);
+    /// ```
+    pub fn new(randomizer: &[u8; 5], payload: &Vec<u8>, mic: [u8; 4]) -> Self {
+        Self {
+            length: 10 + payload.len() as u8,
+            randomizer: *randomizer,
+            payload: payload.clone(),
+            mic,
+        }
+    }
+}
+
+impl TryFrom<&[u8]> for ElectronicShelfLabel {
+    type Error = DataTypeParseError;
+    /// Create [`ElectronicShelfLabel`] from `&[u8]`.
+    fn try_from(value: &[u8]) -> Result<Self, DataTypeParseError> {
+        let len = value.len();
+        if len < 11 {
+            return Err(DataTypeParseError::InvalidLength {
+                expected: 11,
+                actual: len,
+            });
+        }
+        let length = value[0];
+        let end = 1 + length as usize;
+        if length < 10 {
+            return Err(DataTypeParseError::ValueOutOfRange {
+                field: "length",
+                value: length as u64,
+            });
+        }
+        if len < end {
+            return Err(DataTypeParseError::InvalidLength {
+                expected: end,
+                actual: len,
+            });
+        }
+        Ok(Self {
+            length,
+            randomizer: value[2..7].try_into().unwrap(),
+            payload: value[7..end - 4].to_vec(),
+            mic: value[end - 4..end].try_into().unwrap(),
+        })
+    }
+}
+
+impl TryFrom<&Vec<u8>> for ElectronicShelfLabel {
+    type Error = DataTypeParseError;
+    /// Create [`ElectronicShelfLabel`] from [`Vec<u8>`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ble_data_struct::data_types::{electronic_shelf_label::ElectronicShelfLabel, data_type::DataType};
+    /// use ble_data_struct::data_types::parse_error::DataTypeParseError;
+    ///
+    /// let randomizer: [u8; 5] = [1, 2, 3, 4, 5];
+    /// let payload = [6].to_vec();
+    /// let mic: [u8; 4] = [7, 8, 9, 10];
+    /// let length = 11;
+    /// let mut data: Vec<u8> = Vec::new();
+    /// data.push(length);
+    /// data.push(ElectronicShelfLabel::data_type());
+    /// data.append(&mut randomizer.to_vec());
+    /// data.append(&mut payload.clone());
+    /// data.append(&mut mic.to_vec());
+    ///
+    /// let result = ElectronicShelfLabel::try_from(&data);
+    /// assert!(result.is_ok());
+    /// let data_type = result.unwrap();
+    /// assert_eq!(length, data_type.length);
+    /// assert_eq!(randomizer, data_type.randomizer);
+    /// assert_eq!(payload, data_type.payload);
+    /// assert_eq!(mic, data_type.mic);
+    ///
+    /// let data: Vec<u8> = Vec::new();
+    /// let result = ElectronicShelfLabel::try_from(&data);
+    /// assert!(result.is_err());
+    /// assert_eq!(
+    ///     DataTypeParseError::InvalidLength {
+    ///         expected: 11,
+    ///         actual: data.len()
+    ///     },
+    ///     result.unwrap_err()
+    /// );
+    /// ```
+    fn try_from(value: &Vec<u8>) -> Result<Self, DataTypeParseError> {
+        Self::try_from(value.as_slice())
+    }
+}
+
+impl Into<Vec<u8>> for ElectronicShelfLabel {
+    /// Create [`Vec<u8>`] from [`ElectronicShelfLabel`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ble_data_struct::data_types::{electronic_shelf_label::ElectronicShelfLabel, data_type::DataType};
+    ///
+    /// let randomizer: [u8; 5] = [1, 2, 3, 4, 5];
+    /// let payload = [6].to_vec();
+    /// let mic: [u8; 4] = [7, 8, 9, 10];
+    /// let length = 11;
+    /// let result1 = ElectronicShelfLabel::new(&randomizer, &payload, mic);
+    ///
+    /// let mut data: Vec<u8> = Vec::new();
+    /// data.push(length);
+    /// data.push(ElectronicShelfLabel::data_type());
+    /// data.append(&mut randomizer.to_vec());
+    /// data.append(&mut payload.clone());
+    /// data.append(&mut mic.to_vec());
+    ///
+    /// let into_data: Vec<u8> = result1.into();
+    /// assert_eq!(data, into_data);
+    ///
+    /// let result2 = ElectronicShelfLabel::try_from(&data);
+    /// assert!(result2.is_ok());
+    /// let data_type = result2.unwrap();
+    /// let into_data: Vec<u8> = data_type.into();
+    /// assert_eq!(data, into_data);
+    /// ```
+    fn into(self) -> Vec<u8> {
+        let mut data: Vec<u8> = Vec::new();
+        data.push(self.length);
+        data.push(Self::data_type());
+        data.append(&mut self.randomizer.to_vec());
+        data.append(&mut self.payload.clone());
+        data.append(&mut self.mic.to_vec());
+        return data;
+    }
+}
+
+impl DataType for ElectronicShelfLabel {
+    /// return `0x34`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ble_data_struct::data_types::{electronic_shelf_label::ElectronicShelfLabel, data_type::DataType};
+    ///
+    /// assert_eq!(0x34, ElectronicShelfLabel::data_type());
+    /// ```
+    #[inline]
+    fn data_type() -> u8 {
+        0x34
+    }
+}
+
+/// check `Electronic Shelf Label` data type.
+///
+/// # Examples
+///
+/// ```
+/// use ble_data_struct::data_types::electronic_shelf_label::*;
+/// use ble_data_struct::data_types::data_type::DataType;
+///
+/// assert!(is_electronic_shelf_label(0x34));
+/// assert!(!is_electronic_shelf_label(0x00));
+/// ```
+#[inline]
+pub fn is_electronic_shelf_label(data_type: u8) -> bool {
+    AdType::from_u8(data_type) == Some(AdType::ElectronicShelfLabel)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::data_types::{data_type::DataType, electronic_shelf_label::*};
+
+    #[test]
+    fn test_new() {
+        let randomizer: [u8; 5] = [1, 2, 3, 4, 5];
+        let payload = [6].to_vec();
+        let mic: [u8; 4] = [7, 8, 9, 10];
+        let result = ElectronicShelfLabel::new(&randomizer, &payload, mic);
+        assert_eq!(11, result.length);
+        assert_eq!(randomizer, result.randomizer);
+        assert_eq!(payload, result.payload);
+        assert_eq!(mic, result.mic);
+    }
+
+    #[test]
+    fn test_try_from() {
+        let randomizer: [u8; 5] = [1, 2, 3, 4, 5];
+        let payload = [6].to_vec();
+        let mic: [u8; 4] = [7, 8, 9, 10];
+        let length = 11;
+        let mut data: Vec<u8> = Vec::new();
+        data.push(length);
+        data.push(ElectronicShelfLabel::data_type());
+        data.append(&mut randomizer.to_vec());
+        data.append(&mut payload.clone());
+        data.append(&mut mic.to_vec());
+
+        let result = ElectronicShelfLabel::try_from(&data);
+        assert!(result.is_ok());
+        let data_type = result.unwrap();
+        assert_eq!(length, data_type.length);
+        assert_eq!(randomizer, data_type.randomizer);
+        assert_eq!(payload, data_type.payload);
+        assert_eq!(mic, data_type.mic);
+
+        let mut data: Vec<u8> = vec![0u8; 10];
+        data[0] = data.len() as u8 - 1;
+        let result = ElectronicShelfLabel::try_from(&data);
+        assert!(result.is_err());
+        assert_eq!(
+            DataTypeParseError::InvalidLength {
+                expected: 11,
+                actual: data.len()
+            },
+            result.unwrap_err()
+        );
+
+        let mut data: Vec<u8> = vec![0u8; 11];
+        data[0] = 12;
+        let result = ElectronicShelfLabel::try_from(&data);
+        assert!(result.is_err());
+        assert_eq!(
+            DataTypeParseError::InvalidLength {
+                expected: 13,
+                actual: data.len()
+            },
+            result.unwrap_err()
+        );
+    }
+
+    #[test]
+    fn test_into() {
+        let randomizer: [u8; 5] = [1, 2, 3, 4, 5];
+        let payload = [6].to_vec();
+        let mic: [u8; 4] = [7, 8, 9, 10];
+        let length = 11;
+        let result1 = ElectronicShelfLabel::new(&randomizer, &payload, mic);
+
+        let mut data: Vec<u8> = Vec::new();
+        data.push(length);
+        data.push(ElectronicShelfLabel::data_type());
+        data.append(&mut randomizer.to_vec());
+        data.append(&mut payload.clone());
+        data.append(&mut mic.to_vec());
+
+        let into_data: Vec<u8> = result1.into();
+        assert_eq!(data, into_data);
+
+        let result2 = ElectronicShelfLabel::try_from(&data);
+        assert!(result2.is_ok());
+        let data_type = result2.unwrap();
+        let into_data: Vec<u8> = data_type.into();
+        assert_eq!(data, into_data);
+    }
+
+    #[test]
+    fn test_data_type() {
+        assert_eq!(0x34, ElectronicShelfLabel::data_type());
+    }
+
+    #[test]
+    fn test_is_electronic_shelf_label() {
+        assert!(is_electronic_shelf_label(0x34));
+        assert!(!is_electronic_shelf_label(0x00));
+    }
+}
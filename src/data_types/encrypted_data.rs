@@ -1,9 +1,10 @@
 //! Encrypted Data (Data Type Value: 0x31) module.
 
-use crate::data_types::data_type::DataType;
+use crate::data_types::{ad_type::AdType, data_type::DataType, parse_error::DataTypeParseError};
 
 /// Encrypted Data.
 #[derive(Debug, PartialEq, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct EncryptedData {
     /// data length
     pub length: u8,
@@ -45,14 +46,36 @@ impl EncryptedData {
     }
 }
 
+impl TryFrom<&[u8]> for EncryptedData {
+    type Error = DataTypeParseError;
+    /// Create [`EncryptedData`] from `&[u8]`.
+    fn try_from(value: &[u8]) -> Result<Self, DataTypeParseError> {
+        let len = value.len();
+        if len < 11 {
+            return Err(DataTypeParseError::InvalidLength {
+                expected: 11,
+                actual: len,
+            });
+        }
+        let length = value[0];
+        Ok(Self {
+            length,
+            randomizer: value[2..7].try_into().unwrap(),
+            payload: value[7..(length - 3) as usize].to_vec(),
+            mic: value[len - 4..].try_into().unwrap(),
+        })
+    }
+}
+
 impl TryFrom<&Vec<u8>> for EncryptedData {
-    type Error = String;
+    type Error = DataTypeParseError;
     /// Create [`EncryptedData`] from [`Vec<u8>`].
     ///
     /// # Examples
     ///
     /// ```
     /// use ble_data_struct::data_types::{encrypted_data::EncryptedData, data_type::DataType};
+    /// use ble_data_struct::data_types::parse_error::DataTypeParseError;
     ///
     /// let randomizer: [u8; 5] = [1, 2, 3, 4, 5];
     /// let payload = [6].to_vec();
@@ -77,32 +100,15 @@ impl TryFrom<&Vec<u8>> for EncryptedData {
     /// let result = EncryptedData::try_from(&data);
     /// assert!(result.is_err());
     /// assert_eq!(
-    ///     format!("Invalid data size :{}", data.len()),
+    ///     DataTypeParseError::InvalidLength {
+    ///         expected: 11,
+    ///         actual: data.len()
+    ///     },
     ///     result.unwrap_err()
     /// );
     /// ```
-    fn try_from(value: &Vec<u8>) -> Result<Self, String> {
-        let len = value.len();
-        if len < 11 {
-            return Err(format!("Invalid data size :{}", len).to_string());
-        }
-        let length = value[0];
-        let randomizer: [u8; 5];
-        match value[2..7].try_into() {
-            Ok(x) => randomizer = x,
-            Err(x) => return Err(x.to_string()),
-        }
-        let mic: [u8; 4];
-        match value[value.len() - 4..].try_into() {
-            Ok(x) => mic = x,
-            Err(x) => return Err(x.to_string()),
-        }
-        Ok(Self {
-            length,
-            randomizer,
-            payload: value[7..(length - 3) as usize].to_vec(),
-            mic,
-        })
+    fn try_from(value: &Vec<u8>) -> Result<Self, DataTypeParseError> {
+        Self::try_from(value.as_slice())
     }
 }
 
@@ -157,6 +163,7 @@ impl DataType for EncryptedData {
     ///
     /// assert_eq!(0x31, EncryptedData::data_type());
     /// ```
+    #[inline]
     fn data_type() -> u8 {
         0x31
     }
@@ -173,8 +180,9 @@ impl DataType for EncryptedData {
 /// assert!(is_encrypted_data(0x31));
 /// assert!(!is_encrypted_data(0x00));
 /// ```
+#[inline]
 pub fn is_encrypted_data(data_type: u8) -> bool {
-    EncryptedData::data_type() == data_type
+    AdType::from_u8(data_type) == Some(AdType::EncryptedData)
 }
 
 #[cfg(test)]
@@ -219,7 +227,10 @@ mod tests {
         let result = EncryptedData::try_from(&data);
         assert!(result.is_err());
         assert_eq!(
-            format!("Invalid data size :{}", data.len()),
+            DataTypeParseError::InvalidLength {
+                expected: 11,
+                actual: data.len()
+            },
             result.unwrap_err()
         );
     }
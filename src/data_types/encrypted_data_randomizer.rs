@@ -0,0 +1,277 @@
+//! Encrypted Data Randomizer module.
+//!
+//! Random values are supplied by the caller (use a cryptographically secure random number generator).
+
+use std::collections::{BTreeMap, VecDeque};
+
+use crate::data_types::encrypted_data::EncryptedData;
+
+/// Direction bit of the Randomizer (most significant bit, shall be set to `1`).
+pub const RANDOMIZER_DIRECTION_BIT: u64 = 0x80_0000_0000;
+
+/// Randomizer mask (40 bits).
+const RANDOMIZER_MASK: u64 = 0xff_ffff_ffff;
+
+/// Default number of Randomizers remembered per peer.
+pub const DEFAULT_RANDOMIZER_HISTORY: usize = 32;
+
+/// Create Randomizer from random value.
+///
+/// Lower 40 bits are used and the direction bit is set to `1`.
+///
+/// # Examples
+///
+/// ```
+/// use ble_data_struct::data_types::encrypted_data_randomizer::randomizer;
+///
+/// assert_eq!([0x01, 0x02, 0x03, 0x04, 0x85], randomizer(0xffff_ff05_0403_0201u64));
+/// assert_eq!([0x00, 0x00, 0x00, 0x00, 0x80], randomizer(0));
+/// ```
+pub fn randomizer(random: u64) -> [u8; 5] {
+    let value = (random & RANDOMIZER_MASK) | RANDOMIZER_DIRECTION_BIT;
+    value.to_le_bytes()[..5].try_into().unwrap()
+}
+
+/// Create next Randomizer from random value.
+///
+/// The Randomizer shall be changed whenever the encrypted payload is updated,
+/// so a random value producing the `previous` Randomizer is rejected.
+///
+/// # Examples
+///
+/// ```
+/// use ble_data_struct::data_types::encrypted_data_randomizer::{next_randomizer, randomizer};
+///
+/// let previous = randomizer(0x0504030201);
+/// assert_eq!(Ok(randomizer(0x0504030202)), next_randomizer(&previous, 0x0504030202));
+/// assert!(next_randomizer(&previous, 0x0504030201).is_err());
+/// ```
+pub fn next_randomizer(previous: &[u8; 5], random: u64) -> Result<[u8; 5], String> {
+    let next = randomizer(random);
+    if &next == previous {
+        return Err(format!("Randomizer not changed :{:?}", next));
+    }
+    Ok(next)
+}
+
+/// Check the direction bit of the Randomizer.
+///
+/// # Examples
+///
+/// ```
+/// use ble_data_struct::data_types::encrypted_data_randomizer::is_valid_randomizer;
+///
+/// assert!(is_valid_randomizer(&[0x01, 0x02, 0x03, 0x04, 0x85]));
+/// assert!(!is_valid_randomizer(&[0x01, 0x02, 0x03, 0x04, 0x05]));
+/// ```
+pub fn is_valid_randomizer(randomizer: &[u8; 5]) -> bool {
+    randomizer[4] & 0x80 != 0
+}
+
+/// Per-peer Randomizer history for replay detection.
+///
+/// Peers are keyed by identifier (e.g. identity address).
+/// Only the latest `capacity` Randomizers of each peer are remembered.
+#[derive(Debug, PartialEq, Clone)]
+pub struct RandomizerHistory {
+    /// Randomizers remembered per peer
+    pub capacity: usize,
+
+    /// Randomizers (oldest first)
+    entries: BTreeMap<u64, VecDeque<[u8; 5]>>,
+}
+
+impl RandomizerHistory {
+    /// Create [`RandomizerHistory`] from capacity.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ble_data_struct::data_types::encrypted_data_randomizer::RandomizerHistory;
+    ///
+    /// let result = RandomizerHistory::new(4);
+    /// assert_eq!(4, result.capacity);
+    /// assert!(result.is_empty());
+    /// ```
+    pub fn new(capacity: usize) -> Self {
+        Self {
+            capacity,
+            entries: BTreeMap::new(),
+        }
+    }
+
+    /// Record the Randomizer of [`EncryptedData`] received from peer.
+    ///
+    /// Returns `true` if the Randomizer has already been received from the peer (likely replay).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ble_data_struct::data_types::{
+    ///     encrypted_data::EncryptedData, encrypted_data_randomizer::RandomizerHistory,
+    /// };
+    ///
+    /// let encrypted_data = EncryptedData::new(&[1, 2, 3, 4, 0x85], &vec![6], [7, 8, 9, 10]);
+    /// let mut result = RandomizerHistory::default();
+    /// assert!(!result.is_replay(0x010203040506, &encrypted_data));
+    /// assert!(result.is_replay(0x010203040506, &encrypted_data));
+    /// assert!(!result.is_replay(0x060504030201, &encrypted_data));
+    /// ```
+    pub fn is_replay(&mut self, peer: u64, encrypted_data: &EncryptedData) -> bool {
+        self.record(peer, &encrypted_data.randomizer)
+    }
+
+    /// Record the Randomizer received from peer.
+    ///
+    /// Returns `true` if the Randomizer has already been received from the peer (likely replay).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ble_data_struct::data_types::encrypted_data_randomizer::RandomizerHistory;
+    ///
+    /// let mut result = RandomizerHistory::new(1);
+    /// assert!(!result.record(0x010203040506, &[1, 2, 3, 4, 0x85]));
+    /// assert!(result.record(0x010203040506, &[1, 2, 3, 4, 0x85]));
+    /// assert!(!result.record(0x010203040506, &[2, 2, 3, 4, 0x85]));
+    /// assert!(!result.record(0x010203040506, &[1, 2, 3, 4, 0x85]));
+    /// ```
+    pub fn record(&mut self, peer: u64, randomizer: &[u8; 5]) -> bool {
+        let history = self.entries.entry(peer).or_default();
+        if history.contains(randomizer) {
+            return true;
+        }
+        if self.capacity == 0 {
+            return false;
+        }
+        if history.len() == self.capacity {
+            history.pop_front();
+        }
+        history.push_back(*randomizer);
+        false
+    }
+
+    /// Remove all Randomizers of peer (e.g. on bond removal or key refresh).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ble_data_struct::data_types::encrypted_data_randomizer::RandomizerHistory;
+    ///
+    /// let mut result = RandomizerHistory::default();
+    /// result.record(0x010203040506, &[1, 2, 3, 4, 0x85]);
+    /// result.forget(0x010203040506);
+    /// assert!(result.is_empty());
+    /// ```
+    pub fn forget(&mut self, peer: u64) {
+        self.entries.remove(&peer);
+    }
+
+    /// Returns `true` if no Randomizer is remembered.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ble_data_struct::data_types::encrypted_data_randomizer::RandomizerHistory;
+    ///
+    /// let mut result = RandomizerHistory::default();
+    /// assert!(result.is_empty());
+    /// result.record(0x010203040506, &[1, 2, 3, 4, 0x85]);
+    /// assert!(!result.is_empty());
+    /// ```
+    pub fn is_empty(&self) -> bool {
+        self.entries.values().all(VecDeque::is_empty)
+    }
+}
+
+impl Default for RandomizerHistory {
+    /// Create [`RandomizerHistory`] with [`DEFAULT_RANDOMIZER_HISTORY`].
+    fn default() -> Self {
+        Self::new(DEFAULT_RANDOMIZER_HISTORY)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::data_types::{
+        encrypted_data::EncryptedData,
+        encrypted_data_randomizer::{
+            is_valid_randomizer, next_randomizer, randomizer, RandomizerHistory,
+            DEFAULT_RANDOMIZER_HISTORY,
+        },
+    };
+
+    #[test]
+    fn test_randomizer() {
+        assert_eq!(
+            [0x01, 0x02, 0x03, 0x04, 0x85],
+            randomizer(0xffff_ff05_0403_0201u64)
+        );
+        assert_eq!([0x00, 0x00, 0x00, 0x00, 0x80], randomizer(0));
+        assert_eq!([0xff, 0xff, 0xff, 0xff, 0xff], randomizer(u64::MAX));
+        assert!(is_valid_randomizer(&randomizer(0)));
+    }
+
+    #[test]
+    fn test_next_randomizer() {
+        let previous = randomizer(0x0504030201);
+        assert_eq!(
+            Ok(randomizer(0x0504030202)),
+            next_randomizer(&previous, 0x0504030202)
+        );
+        assert!(next_randomizer(&previous, 0x0504030201).is_err());
+        assert!(next_randomizer(&previous, 0x8504030201).is_err());
+    }
+
+    #[test]
+    fn test_is_valid_randomizer() {
+        assert!(is_valid_randomizer(&[0x01, 0x02, 0x03, 0x04, 0x85]));
+        assert!(!is_valid_randomizer(&[0x01, 0x02, 0x03, 0x04, 0x05]));
+    }
+
+    #[test]
+    fn test_new() {
+        let result = RandomizerHistory::new(4);
+        assert_eq!(4, result.capacity);
+        assert!(result.is_empty());
+        assert_eq!(
+            DEFAULT_RANDOMIZER_HISTORY,
+            RandomizerHistory::default().capacity
+        );
+    }
+
+    #[test]
+    fn test_is_replay() {
+        let encrypted_data = EncryptedData::new(&[1, 2, 3, 4, 0x85], &vec![6], [7, 8, 9, 10]);
+        let mut result = RandomizerHistory::default();
+        assert!(!result.is_replay(0x010203040506, &encrypted_data));
+        assert!(result.is_replay(0x010203040506, &encrypted_data));
+        assert!(!result.is_replay(0x060504030201, &encrypted_data));
+    }
+
+    #[test]
+    fn test_record() {
+        let mut result = RandomizerHistory::new(2);
+        assert!(!result.record(0x010203040506, &[1, 2, 3, 4, 0x85]));
+        assert!(!result.record(0x010203040506, &[2, 2, 3, 4, 0x85]));
+        assert!(result.record(0x010203040506, &[1, 2, 3, 4, 0x85]));
+        assert!(!result.record(0x010203040506, &[3, 2, 3, 4, 0x85]));
+        assert!(!result.record(0x010203040506, &[1, 2, 3, 4, 0x85]));
+
+        let mut result = RandomizerHistory::new(0);
+        assert!(!result.record(0x010203040506, &[1, 2, 3, 4, 0x85]));
+        assert!(!result.record(0x010203040506, &[1, 2, 3, 4, 0x85]));
+        assert!(result.is_empty());
+    }
+
+    #[test]
+    fn test_forget() {
+        let mut result = RandomizerHistory::default();
+        result.record(0x010203040506, &[1, 2, 3, 4, 0x85]);
+        result.record(0x060504030201, &[1, 2, 3, 4, 0x85]);
+        result.forget(0x010203040506);
+        assert!(!result.is_empty());
+        assert!(!result.record(0x010203040506, &[1, 2, 3, 4, 0x85]));
+        assert!(result.record(0x060504030201, &[1, 2, 3, 4, 0x85]));
+    }
+}
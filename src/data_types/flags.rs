@@ -1,9 +1,24 @@
 //! Flags (Data Type Value: 0x01) module.
 
-use crate::data_types::data_type::DataType;
+use crate::data_types::{ad_type::AdType, data_type::DataType, parse_error::DataTypeParseError};
+
+/// GAP discoverable mode.
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum DiscoverableMode {
+    /// General Discoverable mode
+    General,
+
+    /// Limited Discoverable mode
+    Limited,
+
+    /// Non-Discoverable mode
+    NonDiscoverable,
+}
 
 /// Flags.
 #[derive(Debug, PartialEq, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Flags {
     /// data length
     pub length: u8,
@@ -51,6 +66,60 @@ impl Flags {
         }
     }
 
+    /// Create [`Flags`] from [`DiscoverableMode`] and BR/EDR support.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ble_data_struct::data_types::flags::{DiscoverableMode, Flags};
+    ///
+    /// let result = Flags::for_mode(DiscoverableMode::General, false);
+    /// assert_eq!(
+    ///     [false, true, true, false, false, false, false, false].to_vec(),
+    ///     result.flags
+    /// );
+    ///
+    /// let result = Flags::for_mode(DiscoverableMode::Limited, true);
+    /// assert_eq!(
+    ///     [true, false, false, false, false, false, false, false].to_vec(),
+    ///     result.flags
+    /// );
+    /// ```
+    pub fn for_mode(mode: DiscoverableMode, br_edr_supported: bool) -> Self {
+        let mut flags = [false; 8].to_vec();
+        flags[0] = mode == DiscoverableMode::Limited;
+        flags[1] = mode == DiscoverableMode::General;
+        flags[2] = !br_edr_supported;
+        Self::new(&flags)
+    }
+
+    /// Get [`DiscoverableMode`].
+    ///
+    /// `None` if both LE Limited Discoverable Mode and LE General Discoverable Mode are set.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ble_data_struct::data_types::flags::{DiscoverableMode, Flags};
+    ///
+    /// let result = Flags::for_mode(DiscoverableMode::General, false);
+    /// assert_eq!(Some(DiscoverableMode::General), result.discoverable_mode());
+    ///
+    /// let result = Flags::new(&[true, true, false, false, false, false, false, false].to_vec());
+    /// assert_eq!(None, result.discoverable_mode());
+    /// ```
+    pub fn discoverable_mode(&self) -> Option<DiscoverableMode> {
+        match (
+            self.is_le_limited_discoverable_mode(),
+            self.is_le_general_discoverable_mode(),
+        ) {
+            (true, true) => None,
+            (true, false) => Some(DiscoverableMode::Limited),
+            (false, true) => Some(DiscoverableMode::General),
+            (false, false) => Some(DiscoverableMode::NonDiscoverable),
+        }
+    }
+
     /// check LE Limited Discoverable Mode.
     ///
     /// # Examples
@@ -144,14 +213,48 @@ impl Flags {
     }
 }
 
+impl TryFrom<&[u8]> for Flags {
+    type Error = DataTypeParseError;
+    /// Create [`Flags`] from `&[u8]`.
+    fn try_from(value: &[u8]) -> Result<Self, DataTypeParseError> {
+        let len = value.len();
+        if len < 2 {
+            return Err(DataTypeParseError::InvalidLength {
+                expected: 2,
+                actual: len,
+            });
+        }
+        let length = value[0];
+        Ok(Self {
+            length,
+            flags: value[2..1 + length as usize]
+                .iter()
+                .flat_map(|x| {
+                    let mut data: Vec<bool> = Vec::new();
+                    data.push((x & 0b0000_0001) != 0);
+                    data.push((x & 0b0000_0010) != 0);
+                    data.push((x & 0b0000_0100) != 0);
+                    data.push((x & 0b0000_1000) != 0);
+                    data.push((x & 0b0001_0000) != 0);
+                    data.push((x & 0b0010_0000) != 0);
+                    data.push((x & 0b0100_0000) != 0);
+                    data.push((x & 0b1000_0000) != 0);
+                    data
+                })
+                .collect(),
+        })
+    }
+}
+
 impl TryFrom<&Vec<u8>> for Flags {
-    type Error = String;
+    type Error = DataTypeParseError;
     /// Create [`Flags`] from [`Vec<u8>`].
     ///
     /// # Examples
     ///
     /// ```
     /// use ble_data_struct::data_types::{flags::Flags, data_type::DataType};
+    /// use ble_data_struct::data_types::parse_error::DataTypeParseError;
     ///
     /// let flags_bytes = [0b00000001u8].to_vec();
     /// let flags: Vec<bool> = flags_bytes
@@ -252,34 +355,15 @@ impl TryFrom<&Vec<u8>> for Flags {
     /// let result = Flags::try_from(&data);
     /// assert!(result.is_err());
     /// assert_eq!(
-    ///     format!("Invalid data size :{}", data.len()),
+    ///     DataTypeParseError::InvalidLength {
+    ///         expected: 2,
+    ///         actual: data.len()
+    ///     },
     ///     result.unwrap_err()
     /// );
     /// ```
-    fn try_from(value: &Vec<u8>) -> Result<Self, String> {
-        let len = value.len();
-        if len < 2 {
-            return Err(format!("Invalid data size :{}", len).to_string());
-        }
-        let length = value[0];
-        Ok(Self {
-            length,
-            flags: value[2..(2 + length - 1) as usize]
-                .iter()
-                .flat_map(|x| {
-                    let mut data: Vec<bool> = Vec::new();
-                    data.push((x & 0b0000_0001) != 0);
-                    data.push((x & 0b0000_0010) != 0);
-                    data.push((x & 0b0000_0100) != 0);
-                    data.push((x & 0b0000_1000) != 0);
-                    data.push((x & 0b0001_0000) != 0);
-                    data.push((x & 0b0010_0000) != 0);
-                    data.push((x & 0b0100_0000) != 0);
-                    data.push((x & 0b1000_0000) != 0);
-                    data
-                })
-                .collect(),
-        })
+    fn try_from(value: &Vec<u8>) -> Result<Self, DataTypeParseError> {
+        Self::try_from(value.as_slice())
     }
 }
 
@@ -466,6 +550,7 @@ impl DataType for Flags {
     ///
     /// assert_eq!(0x01, Flags::data_type());
     /// ```
+    #[inline]
     fn data_type() -> u8 {
         0x01
     }
@@ -482,8 +567,9 @@ impl DataType for Flags {
 /// assert!(is_flags(0x01));
 /// assert!(!is_flags(0x00));
 /// ```
+#[inline]
 pub fn is_flags(data_type: u8) -> bool {
-    Flags::data_type() == data_type
+    AdType::from_u8(data_type) == Some(AdType::Flags)
 }
 
 #[cfg(test)]
@@ -517,6 +603,46 @@ mod tests {
         assert_eq!(flags, result.flags);
     }
 
+    #[test]
+    fn test_for_mode() {
+        assert_eq!(
+            [false, true, true, false, false, false, false, false].to_vec(),
+            Flags::for_mode(DiscoverableMode::General, false).flags
+        );
+        assert_eq!(
+            [false, true, false, false, false, false, false, false].to_vec(),
+            Flags::for_mode(DiscoverableMode::General, true).flags
+        );
+        assert_eq!(
+            [true, false, true, false, false, false, false, false].to_vec(),
+            Flags::for_mode(DiscoverableMode::Limited, false).flags
+        );
+        assert_eq!(
+            [false, false, true, false, false, false, false, false].to_vec(),
+            Flags::for_mode(DiscoverableMode::NonDiscoverable, false).flags
+        );
+        assert_eq!(2, Flags::for_mode(DiscoverableMode::General, false).length);
+    }
+
+    #[test]
+    fn test_discoverable_mode() {
+        for mode in [
+            DiscoverableMode::General,
+            DiscoverableMode::Limited,
+            DiscoverableMode::NonDiscoverable,
+        ] {
+            assert_eq!(Some(mode), Flags::for_mode(mode, false).discoverable_mode());
+            assert_eq!(Some(mode), Flags::for_mode(mode, true).discoverable_mode());
+        }
+        let result = Flags::new(&[true, true, false, false, false, false, false, false].to_vec());
+        assert_eq!(None, result.discoverable_mode());
+        let result = Flags::new(&Vec::new());
+        assert_eq!(
+            Some(DiscoverableMode::NonDiscoverable),
+            result.discoverable_mode()
+        );
+    }
+
     #[test]
     fn test_is_le_limited_discoverable_mode() {
         let flags = [true, false, false, false, false, false, false, false].to_vec();
@@ -679,7 +805,10 @@ mod tests {
         let result = Flags::try_from(&data);
         assert!(result.is_err());
         assert_eq!(
-            format!("Invalid data size :{}", data.len()),
+            DataTypeParseError::InvalidLength {
+                expected: 2,
+                actual: data.len()
+            },
             result.unwrap_err()
         );
     }
@@ -1,167 +1,9 @@
 //! Incomplete List of 128-bit Service Class UUIDs (Data Type Value: 0x06) module.
 
-use uuid::Uuid;
-
-use crate::data_types::data_type::DataType;
+use crate::data_types::{ad_type::AdType, uuid_list::UuidList};
 
 /// Incomplete List of 128-bit Service Class UUIDs.
-#[derive(Debug, PartialEq, Clone)]
-pub struct IncompleteListOf128BitServiceUuids {
-    /// data length
-    pub length: u8,
-
-    /// UUIDs
-    pub uuids: Vec<Uuid>,
-}
-
-impl IncompleteListOf128BitServiceUuids {
-    /// Create [`IncompleteListOf128BitServiceUuids`] from [`Vec<Uuid>`].
-    ///
-    /// # Examples
-    ///
-    /// ```
-    /// use ble_data_struct::data_types::incomplete_list_of_128bit_service_uuids::IncompleteListOf128BitServiceUuids;
-    /// use uuid::{uuid, Uuid};
-    ///
-    /// let uuids: Vec<Uuid> = [
-    ///     uuid!("00000001-0000-1000-8000-00805F9B34FB"),
-    ///     uuid!("00000002-0000-1000-8000-00805F9B34FB"),
-    /// ]
-    /// .to_vec();
-    /// let result = IncompleteListOf128BitServiceUuids::new(&uuids);
-    /// assert_eq!(uuids.len() as u8 * 16 + 1, result.length);
-    /// assert_eq!(uuids, result.uuids);
-    /// ```
-    pub fn new(uuids: &Vec<Uuid>) -> Self {
-        Self {
-            length: (uuids.len() * 16 + 1) as u8,
-            uuids: uuids.clone(),
-        }
-    }
-}
-
-impl TryFrom<&Vec<u8>> for IncompleteListOf128BitServiceUuids {
-    type Error = String;
-    /// Create [`IncompleteListOf128BitServiceUuids`] from [`Vec<u8>`].
-    ///
-    /// # Examples
-    ///
-    /// ```
-    /// use ble_data_struct::{BASE_UUID, data_types::{incomplete_list_of_128bit_service_uuids::IncompleteListOf128BitServiceUuids, data_type::DataType}};
-    /// use uuid::{uuid, Uuid};
-    ///
-    /// let uuids = [
-    ///     uuid!("00000001-0000-1000-8000-00805F9B34FB"),
-    ///     uuid!("00000002-0000-1000-8000-00805F9B34FB"),
-    /// ]
-    /// .to_vec();
-    /// let mut uuid_bytes: Vec<u8> = Vec::new();
-    /// uuid_bytes.append(&mut uuids[0].as_u128().to_le_bytes().to_vec());
-    /// uuid_bytes.append(&mut uuids[1].as_u128().to_le_bytes().to_vec());
-    ///
-    /// let length = uuid_bytes.len() as u8 + 1;
-    /// let mut data: Vec<u8> = Vec::new();
-    /// data.push(length);
-    /// data.push(IncompleteListOf128BitServiceUuids::data_type());
-    /// data.append(&mut uuid_bytes.clone());
-    ///
-    /// let result = IncompleteListOf128BitServiceUuids::try_from(&data);
-    /// assert!(result.is_ok());
-    /// let data_type = result.unwrap();
-    /// assert_eq!(length, data_type.length);
-    /// assert_eq!(uuids, data_type.uuids);
-    ///
-    /// let mut data: Vec<u8> = vec![0u8; 17];
-    /// data[0] = data.len() as u8 - 1;
-    /// let result = IncompleteListOf128BitServiceUuids::try_from(&data);
-    /// assert!(result.is_err());
-    /// assert_eq!(
-    ///     format!("Invalid data size :{}", data.len()),
-    ///     result.unwrap_err()
-    /// );
-    /// ```
-    fn try_from(value: &Vec<u8>) -> Result<Self, String> {
-        let len = value.len();
-        if len < 18 {
-            return Err(format!("Invalid data size :{}", len).to_string());
-        }
-        let length = value[0];
-        Ok(Self {
-            length,
-            uuids: value[2..2 + length as usize - 1]
-                .windows(16)
-                .step_by(16)
-                .map(|w| Uuid::from_u128(u128::from_le_bytes(w.try_into().unwrap())))
-                .collect(),
-        })
-    }
-}
-
-impl Into<Vec<u8>> for IncompleteListOf128BitServiceUuids {
-    /// Create [`Vec<u8>`] from [`IncompleteListOf128BitServiceUuids`].
-    ///
-    /// # Examples
-    ///
-    /// ```
-    /// use ble_data_struct::{BASE_UUID, data_types::{incomplete_list_of_128bit_service_uuids::IncompleteListOf128BitServiceUuids, data_type::DataType}};
-    /// use uuid::{uuid, Uuid};
-    ///
-    /// let uuids = [
-    ///     uuid!("00000001-0000-1000-8000-00805F9B34FB"),
-    ///     uuid!("00000002-0000-1000-8000-00805F9B34FB"),
-    /// ]
-    /// .to_vec();
-    /// let mut uuid_bytes: Vec<u8> = Vec::new();
-    /// uuid_bytes.append(&mut uuids[0].as_u128().to_le_bytes().to_vec());
-    /// uuid_bytes.append(&mut uuids[1].as_u128().to_le_bytes().to_vec());
-    /// let result1 = IncompleteListOf128BitServiceUuids::new(&uuids);
-    ///
-    /// let length = uuid_bytes.len() as u8 + 1;
-    ///
-    /// let mut data: Vec<u8> = Vec::new();
-    /// data.push(length);
-    /// data.push(IncompleteListOf128BitServiceUuids::data_type());
-    /// data.append(&mut uuid_bytes.clone());
-    ///
-    /// let into_data: Vec<u8> = result1.into();
-    /// assert_eq!(data, into_data);
-    ///
-    /// let result2 = IncompleteListOf128BitServiceUuids::try_from(&data);
-    /// assert!(result2.is_ok());
-    /// let data_type = result2.unwrap();
-    /// let into_data: Vec<u8> = data_type.into();
-    /// assert_eq!(data, into_data);
-    /// ```
-    fn into(self) -> Vec<u8> {
-        let mut data: Vec<u8> = Vec::new();
-        data.push(self.length);
-        data.push(Self::data_type());
-        data.append(
-            &mut self
-                .uuids
-                .clone()
-                .iter()
-                .flat_map(|f| f.as_u128().to_le_bytes().to_vec())
-                .collect(),
-        );
-        return data;
-    }
-}
-
-impl DataType for IncompleteListOf128BitServiceUuids {
-    /// return `0x07`.
-    ///
-    /// # Examples
-    ///
-    /// ```
-    /// use ble_data_struct::data_types::{incomplete_list_of_128bit_service_uuids::IncompleteListOf128BitServiceUuids, data_type::DataType};
-    ///
-    /// assert_eq!(0x06, IncompleteListOf128BitServiceUuids::data_type());
-    /// ```
-    fn data_type() -> u8 {
-        0x06
-    }
-}
+pub type IncompleteListOf128BitServiceUuids = UuidList<128, false>;
 
 /// check `Incomplete List of 128-bit Service Class UUIDs.` data type.
 ///
@@ -174,15 +16,20 @@ impl DataType for IncompleteListOf128BitServiceUuids {
 /// assert!(is_incomplete_list_of_128bit_service_uuids(0x06));
 /// assert!(!is_incomplete_list_of_128bit_service_uuids(0x00));
 /// ```
+#[inline]
 pub fn is_incomplete_list_of_128bit_service_uuids(data_type: u8) -> bool {
-    IncompleteListOf128BitServiceUuids::data_type() == data_type
+    AdType::from_u8(data_type) == Some(AdType::IncompleteListOf128BitServiceUuids)
 }
 
 #[cfg(test)]
 mod tests {
     use uuid::{uuid, Uuid};
 
-    use crate::data_types::{data_type::DataType, incomplete_list_of_128bit_service_uuids::*};
+    use crate::data_types::{
+        complete_list_of_128bit_service_uuids::CompleteListOf128BitServiceUuids,
+        data_type::DataType, incomplete_list_of_128bit_service_uuids::*,
+        parse_error::DataTypeParseError, service_uuid_list::ServiceUuidList,
+    };
 
     #[test]
     fn test_new() {
@@ -224,7 +71,10 @@ mod tests {
         let result = IncompleteListOf128BitServiceUuids::try_from(&data);
         assert!(result.is_err());
         assert_eq!(
-            format!("Invalid data size :{}", data.len()),
+            DataTypeParseError::InvalidLength {
+                expected: 18,
+                actual: data.len()
+            },
             result.unwrap_err()
         );
     }
@@ -263,6 +113,36 @@ mod tests {
         assert_eq!(0x06, IncompleteListOf128BitServiceUuids::data_type());
     }
 
+    #[test]
+    fn test_from_complete() {
+        let uuids: Vec<Uuid> = [
+            uuid!("00000001-0000-1000-8000-00805F9B34FB"),
+            uuid!("00000002-0000-1000-8000-00805F9B34FB"),
+        ]
+        .to_vec();
+        let result = IncompleteListOf128BitServiceUuids::from(
+            &CompleteListOf128BitServiceUuids::new(&uuids),
+        );
+        assert_eq!(IncompleteListOf128BitServiceUuids::new(&uuids), result);
+    }
+
+    #[test]
+    fn test_uuids() {
+        let uuids: Vec<Uuid> = [
+            uuid!("00000001-0000-1000-8000-00805F9B34FB"),
+            uuid!("00000002-0000-1000-8000-00805F9B34FB"),
+        ]
+        .to_vec();
+        let result = IncompleteListOf128BitServiceUuids::new(&uuids);
+        assert_eq!(&uuids, result.uuids());
+    }
+
+    #[test]
+    fn test_is_complete() {
+        let uuids: Vec<Uuid> = [uuid!("00000001-0000-1000-8000-00805F9B34FB")].to_vec();
+        assert!(!IncompleteListOf128BitServiceUuids::new(&uuids).is_complete());
+    }
+
     #[test]
     fn test_is_incomplete_list_of_128bit_service_uuids() {
         assert!(is_incomplete_list_of_128bit_service_uuids(0x06));
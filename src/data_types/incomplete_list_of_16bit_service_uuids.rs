@@ -1,171 +1,9 @@
 //! Incomplete List of 16-bit Service Class UUIDs (Data Type Value: 0x02) module.
 
-use uuid::Uuid;
-
-use crate::{data_types::data_type::DataType, BASE_UUID};
+use crate::data_types::{ad_type::AdType, uuid_list::UuidList};
 
 /// Incomplete List of 16-bit Service Class UUIDs.
-#[derive(Debug, PartialEq, Clone)]
-pub struct IncompleteListOf16BitServiceUuids {
-    /// data length
-    pub length: u8,
-
-    /// UUIDs
-    pub uuids: Vec<Uuid>,
-}
-
-impl IncompleteListOf16BitServiceUuids {
-    /// Create [`IncompleteListOf16BitServiceUuids`] from [`Vec<Uuid>`].
-    ///
-    /// # Examples
-    ///
-    /// ```
-    /// use ble_data_struct::data_types::incomplete_list_of_16bit_service_uuids::IncompleteListOf16BitServiceUuids;
-    /// use uuid::{uuid, Uuid};
-    ///
-    /// let uuids: Vec<Uuid> = [
-    ///     uuid!("00000001-0000-1000-8000-00805F9B34FB"),
-    ///     uuid!("00000002-0000-1000-8000-00805F9B34FB"),
-    /// ]
-    /// .to_vec();
-    /// let result = IncompleteListOf16BitServiceUuids::new(&uuids);
-    /// assert_eq!(uuids.len() as u8 * 2 + 1, result.length);
-    /// assert_eq!(uuids, result.uuids);
-    /// ```
-    pub fn new(uuids: &Vec<Uuid>) -> Self {
-        Self {
-            length: (uuids.len() * 2 + 1) as u8,
-            uuids: uuids.clone(),
-        }
-    }
-}
-
-impl TryFrom<&Vec<u8>> for IncompleteListOf16BitServiceUuids {
-    type Error = String;
-    /// Create [`IncompleteListOf16BitServiceUuids`] from [`Vec<u8>`].
-    ///
-    /// # Examples
-    ///
-    /// ```
-    /// use ble_data_struct::{BASE_UUID, data_types::{incomplete_list_of_16bit_service_uuids::IncompleteListOf16BitServiceUuids, data_type::DataType}};
-    /// use uuid::{uuid, Uuid};
-    ///
-    /// let uuid_bytes: Vec<u8> = [0x01u8, 0x02u8, 0x03u8, 0x04u8].to_vec();
-    /// let uuids: Vec<Uuid> = uuid_bytes
-    ///     .windows(2)
-    ///     .step_by(2)
-    ///     .map(|f| {
-    ///         let (d1, d2, d3, d4) = BASE_UUID.as_fields();
-    ///         Uuid::from_fields(d1 | ((f[0] as u32) << 0) | ((f[1] as u32) << 8), d2, d3, d4)
-    ///     })
-    ///     .collect();
-    /// let length = uuid_bytes.len() as u8 + 1;
-    /// let mut data: Vec<u8> = Vec::new();
-    /// data.push(length);
-    /// data.push(IncompleteListOf16BitServiceUuids::data_type());
-    /// data.append(&mut uuid_bytes.clone());
-    ///
-    /// let result = IncompleteListOf16BitServiceUuids::try_from(&data);
-    /// assert!(result.is_ok());
-    /// let data_type = result.unwrap();
-    /// assert_eq!(length, data_type.length);
-    /// assert_eq!(uuids, data_type.uuids);
-    ///
-    /// let data: Vec<u8> = Vec::new();
-    /// let result = IncompleteListOf16BitServiceUuids::try_from(&data);
-    /// assert!(result.is_err());
-    /// assert_eq!(
-    ///     format!("Invalid data size :{}", data.len()),
-    ///     result.unwrap_err()
-    /// );
-    /// ```
-    fn try_from(value: &Vec<u8>) -> Result<Self, String> {
-        let len = value.len();
-        if len < 4 {
-            return Err(format!("Invalid data size :{}", len).to_string());
-        }
-        let length = value[0];
-        Ok(Self {
-            length,
-            uuids: value[2..2 + length as usize - 1]
-                .windows(2)
-                .step_by(2)
-                .map(|w| {
-                    let mut bytes = BASE_UUID.to_bytes_le();
-                    bytes[0] = w[0];
-                    bytes[1] = w[1];
-                    Uuid::from_bytes_le(bytes)
-                })
-                .collect(),
-        })
-    }
-}
-
-impl Into<Vec<u8>> for IncompleteListOf16BitServiceUuids {
-    /// Create [`Vec<u8>`] from [`IncompleteListOf16BitServiceUuids`].
-    ///
-    /// # Examples
-    ///
-    /// ```
-    /// use ble_data_struct::{BASE_UUID, data_types::{incomplete_list_of_16bit_service_uuids::IncompleteListOf16BitServiceUuids, data_type::DataType}};
-    /// use uuid::{uuid, Uuid};
-    ///
-    /// let uuid_bytes: Vec<u8> = [0x01u8, 0x02u8, 0x03u8, 0x04u8].to_vec();
-    /// let uuids: Vec<Uuid> = uuid_bytes
-    ///     .windows(2)
-    ///     .step_by(2)
-    ///     .map(|f| {
-    ///         let (d1, d2, d3, d4) = BASE_UUID.as_fields();
-    ///         Uuid::from_fields(d1 | ((f[0] as u32) << 0) | ((f[1] as u32) << 8), d2, d3, d4)
-    ///     })
-    ///     .collect();
-    /// let result1 = IncompleteListOf16BitServiceUuids::new(&uuids);
-    ///
-    /// let length = uuid_bytes.len() as u8 + 1;
-    /// let mut data: Vec<u8> = Vec::new();
-    /// data.push(length);
-    /// data.push(IncompleteListOf16BitServiceUuids::data_type());
-    /// data.append(&mut uuid_bytes.clone());
-    ///
-    /// let into_data: Vec<u8> = result1.into();
-    /// assert_eq!(data, into_data);
-    ///
-    /// let result2 = IncompleteListOf16BitServiceUuids::try_from(&data);
-    /// assert!(result2.is_ok());
-    /// let data_type = result2.unwrap();
-    /// let into_data: Vec<u8> = data_type.into();
-    /// assert_eq!(data, into_data);
-    /// ```
-    fn into(self) -> Vec<u8> {
-        let mut data: Vec<u8> = Vec::new();
-        data.push(self.length);
-        data.push(Self::data_type());
-        data.append(
-            &mut self
-                .uuids
-                .clone()
-                .iter()
-                .flat_map(|f| f.to_bytes_le()[..2].to_vec())
-                .collect(),
-        );
-        return data;
-    }
-}
-
-impl DataType for IncompleteListOf16BitServiceUuids {
-    /// return `0x02`.
-    ///
-    /// # Examples
-    ///
-    /// ```
-    /// use ble_data_struct::data_types::{incomplete_list_of_16bit_service_uuids::IncompleteListOf16BitServiceUuids, data_type::DataType};
-    ///
-    /// assert_eq!(0x02, IncompleteListOf16BitServiceUuids::data_type());
-    /// ```
-    fn data_type() -> u8 {
-        0x02
-    }
-}
+pub type IncompleteListOf16BitServiceUuids = UuidList<16, false>;
 
 /// check `Incomplete List of 16-bit Service Class UUIDs` data type.
 ///
@@ -178,8 +16,9 @@ impl DataType for IncompleteListOf16BitServiceUuids {
 /// assert!(is_incomplete_list_of_16bit_service_uuids(0x02));
 /// assert!(!is_incomplete_list_of_16bit_service_uuids(0x00));
 /// ```
+#[inline]
 pub fn is_incomplete_list_of_16bit_service_uuids(data_type: u8) -> bool {
-    IncompleteListOf16BitServiceUuids::data_type() == data_type
+    AdType::from_u8(data_type) == Some(AdType::IncompleteListOf16BitServiceUuids)
 }
 
 #[cfg(test)]
@@ -187,7 +26,11 @@ mod tests {
     use uuid::{uuid, Uuid};
 
     use crate::{
-        data_types::{data_type::DataType, incomplete_list_of_16bit_service_uuids::*},
+        data_types::{
+            complete_list_of_16bit_service_uuids::CompleteListOf16BitServiceUuids,
+            data_type::DataType, incomplete_list_of_16bit_service_uuids::*,
+            parse_error::DataTypeParseError, service_uuid_list::ServiceUuidList,
+        },
         BASE_UUID,
     };
 
@@ -231,7 +74,10 @@ mod tests {
         let result = IncompleteListOf16BitServiceUuids::try_from(&data);
         assert!(result.is_err());
         assert_eq!(
-            format!("Invalid data size :{}", data.len()),
+            DataTypeParseError::InvalidLength {
+                expected: 4,
+                actual: data.len()
+            },
             result.unwrap_err()
         );
     }
@@ -270,6 +116,35 @@ mod tests {
         assert_eq!(0x02, IncompleteListOf16BitServiceUuids::data_type());
     }
 
+    #[test]
+    fn test_from_complete() {
+        let uuids: Vec<Uuid> = [
+            uuid!("00000001-0000-1000-8000-00805F9B34FB"),
+            uuid!("00000002-0000-1000-8000-00805F9B34FB"),
+        ]
+        .to_vec();
+        let result =
+            IncompleteListOf16BitServiceUuids::from(&CompleteListOf16BitServiceUuids::new(&uuids));
+        assert_eq!(IncompleteListOf16BitServiceUuids::new(&uuids), result);
+    }
+
+    #[test]
+    fn test_uuids() {
+        let uuids: Vec<Uuid> = [
+            uuid!("00000001-0000-1000-8000-00805F9B34FB"),
+            uuid!("00000002-0000-1000-8000-00805F9B34FB"),
+        ]
+        .to_vec();
+        let result = IncompleteListOf16BitServiceUuids::new(&uuids);
+        assert_eq!(&uuids, result.uuids());
+    }
+
+    #[test]
+    fn test_is_complete() {
+        let uuids: Vec<Uuid> = [uuid!("00000001-0000-1000-8000-00805F9B34FB")].to_vec();
+        assert!(!IncompleteListOf16BitServiceUuids::new(&uuids).is_complete());
+    }
+
     #[test]
     fn test_is_incomplete_list_of_16bit_service_uuids() {
         assert!(is_incomplete_list_of_16bit_service_uuids(0x02));
@@ -1,10 +1,14 @@
 //! LE Bluetooth Device Address (Data Type Value:0x1b) module.
 
-use crate::data_types::data_type::DataType;
+use crate::data_types::{
+    ad_type::AdType, address_type::AddressType, data_type::DataType,
+    parse_error::DataTypeParseError,
+};
 
 /// LE Bluetooth Device Address.
 
 #[derive(Debug, PartialEq, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct LeBluetoothDeviceAddress {
     /// data length
     pub length: u8,
@@ -13,7 +17,7 @@ pub struct LeBluetoothDeviceAddress {
     pub le_bluetooth_device_address: u64,
 
     /// Address type
-    pub address_type: bool,
+    pub address_type: AddressType,
 }
 
 impl LeBluetoothDeviceAddress {
@@ -32,7 +36,7 @@ impl LeBluetoothDeviceAddress {
     ///     le_bluetooth_device_address,
     ///     result.le_bluetooth_device_address
     /// );
-    /// assert_eq!(address_type, result.address_type);
+    /// assert_eq!(address_type, result.address_type.is_random());
     ///
     /// let le_bluetooth_device_address = 0x0000060504030201u64;
     /// let address_type = true;
@@ -42,13 +46,13 @@ impl LeBluetoothDeviceAddress {
     ///     le_bluetooth_device_address,
     ///     result.le_bluetooth_device_address
     /// );
-    /// assert_eq!(address_type, result.address_type);
+    /// assert_eq!(address_type, result.address_type.is_random());
     /// ```
     pub fn new(le_bluetooth_device_address: u64, address_type: bool) -> Self {
         Self {
             length: 8,
             le_bluetooth_device_address,
-            address_type,
+            address_type: AddressType::from_address(address_type, le_bluetooth_device_address),
         }
     }
 
@@ -70,18 +74,50 @@ impl LeBluetoothDeviceAddress {
     /// assert_eq!(address_type, result.is_random_address());
     /// ```
     pub const fn is_random_address(&self) -> bool {
-        self.address_type
+        self.address_type.is_random()
+    }
+}
+
+impl TryFrom<&[u8]> for LeBluetoothDeviceAddress {
+    type Error = DataTypeParseError;
+    /// Create [`LeBluetoothDeviceAddress`] from `&[u8]`.
+    fn try_from(value: &[u8]) -> Result<Self, DataTypeParseError> {
+        let len = value.len();
+        if len < 9 {
+            return Err(DataTypeParseError::InvalidLength {
+                expected: 9,
+                actual: len,
+            });
+        }
+        let length = value[0];
+        let mut bytes = [0x00u8; 8];
+        bytes[0] = value[2];
+        bytes[1] = value[3];
+        bytes[2] = value[4];
+        bytes[3] = value[5];
+        bytes[4] = value[6];
+        bytes[5] = value[7];
+        let le_bluetooth_device_address = u64::from_le_bytes(bytes);
+        Ok(Self {
+            length,
+            le_bluetooth_device_address,
+            address_type: AddressType::from_address(
+                value[8] & ADDRESS_TYPE != 0,
+                le_bluetooth_device_address,
+            ),
+        })
     }
 }
 
 impl TryFrom<&Vec<u8>> for LeBluetoothDeviceAddress {
-    type Error = String;
+    type Error = DataTypeParseError;
     /// Create [`LeBluetoothDeviceAddress`] from [`Vec<u8>`].
     ///
     /// # Examples
     ///
     /// ```
     /// use ble_data_struct::data_types::{le_bluetooth_device_address::LeBluetoothDeviceAddress, data_type::DataType};
+    /// use ble_data_struct::data_types::parse_error::DataTypeParseError;
     ///
     /// let le_bluetooth_device_address = 0x0000060504030201u64;
     /// let address_type = false;
@@ -100,7 +136,7 @@ impl TryFrom<&Vec<u8>> for LeBluetoothDeviceAddress {
     ///     le_bluetooth_device_address,
     ///     data_type.le_bluetooth_device_address
     /// );
-    /// assert_eq!(address_type, data_type.address_type);
+    /// assert_eq!(address_type, data_type.address_type.is_random());
     ///
     /// let le_bluetooth_device_address = 0x0000060504030201u64;
     /// let address_type = true;
@@ -119,34 +155,21 @@ impl TryFrom<&Vec<u8>> for LeBluetoothDeviceAddress {
     ///     le_bluetooth_device_address,
     ///     data_type.le_bluetooth_device_address
     /// );
-    /// assert_eq!(address_type, data_type.address_type);
+    /// assert_eq!(address_type, data_type.address_type.is_random());
     ///
     /// let data: Vec<u8> = Vec::new();
     /// let result = LeBluetoothDeviceAddress::try_from(&data);
     /// assert!(result.is_err());
     /// assert_eq!(
-    ///     format!("Invalid data size :{}", data.len()),
+    ///     DataTypeParseError::InvalidLength {
+    ///         expected: 9,
+    ///         actual: data.len()
+    ///     },
     ///     result.unwrap_err()
     /// );
     /// ```
-    fn try_from(value: &Vec<u8>) -> Result<Self, String> {
-        let len = value.len();
-        if len < 8 {
-            return Err(format!("Invalid data size :{}", len).to_string());
-        }
-        let length = value[0];
-        let mut bytes = [0x00u8; 8];
-        bytes[0] = value[2];
-        bytes[1] = value[3];
-        bytes[2] = value[4];
-        bytes[3] = value[5];
-        bytes[4] = value[6];
-        bytes[5] = value[7];
-        Ok(Self {
-            length,
-            le_bluetooth_device_address: u64::from_le_bytes(bytes),
-            address_type: value[8] & ADDRESS_TYPE != 0,
-        })
+    fn try_from(value: &Vec<u8>) -> Result<Self, DataTypeParseError> {
+        Self::try_from(value.as_slice())
     }
 }
 
@@ -188,7 +211,7 @@ impl Into<Vec<u8>> for LeBluetoothDeviceAddress {
         data.push(self.length);
         data.push(Self::data_type());
         data.append(&mut self.le_bluetooth_device_address.clone().to_le_bytes()[..6].to_vec());
-        data.push(u8::from(self.address_type));
+        data.push(u8::from(self.address_type.is_random()));
         return data;
     }
 }
@@ -203,6 +226,7 @@ impl DataType for LeBluetoothDeviceAddress {
     ///
     /// assert_eq!(0x1b, LeBluetoothDeviceAddress::data_type());
     /// ```
+    #[inline]
     fn data_type() -> u8 {
         0x1b
     }
@@ -219,13 +243,16 @@ impl DataType for LeBluetoothDeviceAddress {
 /// assert!(is_le_bluetooth_device_address(0x1b));
 /// assert!(!is_le_bluetooth_device_address(0x00));
 /// ```
+#[inline]
 pub fn is_le_bluetooth_device_address(data_type: u8) -> bool {
-    LeBluetoothDeviceAddress::data_type() == data_type
+    AdType::from_u8(data_type) == Some(AdType::LeBluetoothDeviceAddress)
 }
 
 #[cfg(test)]
 mod tests {
-    use crate::data_types::{data_type::DataType, le_bluetooth_device_address::*};
+    use crate::data_types::{
+        address_type::RandomAddressType, data_type::DataType, le_bluetooth_device_address::*,
+    };
 
     #[test]
     fn test_new() {
@@ -237,7 +264,7 @@ mod tests {
             le_bluetooth_device_address,
             result.le_bluetooth_device_address
         );
-        assert_eq!(address_type, result.address_type);
+        assert_eq!(address_type, result.address_type.is_random());
 
         let le_bluetooth_device_address = 0x0000060504030201u64;
         let address_type = true;
@@ -247,7 +274,18 @@ mod tests {
             le_bluetooth_device_address,
             result.le_bluetooth_device_address
         );
-        assert_eq!(address_type, result.address_type);
+        assert_eq!(address_type, result.address_type.is_random());
+        assert_eq!(
+            AddressType::Random(RandomAddressType::NonResolvablePrivate),
+            result.address_type
+        );
+
+        let le_bluetooth_device_address = 0x0000c60504030201u64;
+        let result = LeBluetoothDeviceAddress::new(le_bluetooth_device_address, true);
+        assert_eq!(
+            AddressType::Random(RandomAddressType::Static),
+            result.address_type
+        );
     }
 
     #[test]
@@ -282,7 +320,7 @@ mod tests {
             le_bluetooth_device_address,
             data_type.le_bluetooth_device_address
         );
-        assert_eq!(address_type, data_type.address_type);
+        assert_eq!(address_type, data_type.address_type.is_random());
 
         let le_bluetooth_device_address = 0x0000060504030201u64;
         let address_type = true;
@@ -301,14 +339,17 @@ mod tests {
             le_bluetooth_device_address,
             data_type.le_bluetooth_device_address
         );
-        assert_eq!(address_type, data_type.address_type);
+        assert_eq!(address_type, data_type.address_type.is_random());
 
-        let mut data: Vec<u8> = vec![0u8; 7];
+        let mut data: Vec<u8> = vec![0u8; 8];
         data[0] = data.len() as u8 - 1;
         let result = LeBluetoothDeviceAddress::try_from(&data);
         assert!(result.is_err());
         assert_eq!(
-            format!("Invalid data size :{}", data.len()),
+            DataTypeParseError::InvalidLength {
+                expected: 9,
+                actual: data.len()
+            },
             result.unwrap_err()
         );
     }
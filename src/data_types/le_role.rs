@@ -1,9 +1,10 @@
 //! LE Role (Data Type Value: 0x1c) module.
 
-use crate::data_types::data_type::DataType;
+use crate::data_types::{ad_type::AdType, data_type::DataType, parse_error::DataTypeParseError};
 
 /// LE Role.
 #[derive(Debug, PartialEq, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct LeRole {
     /// data length
     pub length: u8,
@@ -165,14 +166,34 @@ pub const PERIPHERAL_ROLE_PREFERRED_FOR_CONNECTION_STABLISHMENT: u8 = 0x02;
 /// Peripheral and Central Role supported, Central Role preferred for connection establishment
 pub const CENTRAL_ROLE_PREFERRED_FOR_CONNECTION_STABLISHMENT: u8 = 0x03;
 
+impl TryFrom<&[u8]> for LeRole {
+    type Error = DataTypeParseError;
+    /// Create [`LeRole`] from `&[u8]`.
+    fn try_from(value: &[u8]) -> Result<Self, DataTypeParseError> {
+        let len = value.len();
+        if len < 3 {
+            return Err(DataTypeParseError::InvalidLength {
+                expected: 3,
+                actual: len,
+            });
+        }
+        let length = value[0];
+        Ok(Self {
+            length,
+            le_role: value[2],
+        })
+    }
+}
+
 impl TryFrom<&Vec<u8>> for LeRole {
-    type Error = String;
+    type Error = DataTypeParseError;
     /// Create [LE Role] from [`Vec<u8>`].
     ///
     /// # Examples
     ///
     /// ```
     /// use ble_data_struct::data_types::{le_role::*, data_type::DataType};
+    /// use ble_data_struct::data_types::parse_error::DataTypeParseError;
     ///
     /// let le_role = ONLY_PERIPHERAL_ROLE_SUPPORTED;
     /// let length = 2;
@@ -230,20 +251,15 @@ impl TryFrom<&Vec<u8>> for LeRole {
     /// let result = LeRole::try_from(&data);
     /// assert!(result.is_err());
     /// assert_eq!(
-    ///     format!("Invalid data size :{}", data.len()),
+    ///     DataTypeParseError::InvalidLength {
+    ///         expected: 3,
+    ///         actual: data.len()
+    ///     },
     ///     result.unwrap_err()
     /// );
     /// ```
-    fn try_from(value: &Vec<u8>) -> Result<Self, String> {
-        let len = value.len();
-        if len < 3 {
-            return Err(format!("Invalid data size :{}", len).to_string());
-        }
-        let length = value[0];
-        Ok(Self {
-            length,
-            le_role: value[2],
-        })
+    fn try_from(value: &Vec<u8>) -> Result<Self, DataTypeParseError> {
+        Self::try_from(value.as_slice())
     }
 }
 
@@ -346,6 +362,7 @@ impl DataType for LeRole {
     ///
     /// assert_eq!(0x1c, LeRole::data_type());
     /// ```
+    #[inline]
     fn data_type() -> u8 {
         0x1c
     }
@@ -362,8 +379,9 @@ impl DataType for LeRole {
 /// assert!(is_le_role(0x1c));
 /// assert!(!is_le_role(0x00));
 /// ```
+#[inline]
 pub fn is_le_role(data_type: u8) -> bool {
-    LeRole::data_type() == data_type
+    AdType::from_u8(data_type) == Some(AdType::LeRole)
 }
 
 #[cfg(test)]
@@ -528,7 +546,10 @@ mod tests {
         let result = LeRole::try_from(&data);
         assert!(result.is_err());
         assert_eq!(
-            format!("Invalid data size :{}", data.len()),
+            DataTypeParseError::InvalidLength {
+                expected: 3,
+                actual: data.len()
+            },
             result.unwrap_err()
         );
     }
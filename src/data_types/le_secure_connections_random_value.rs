@@ -1,10 +1,11 @@
 //! LE Secure Connections Random Value (Data Type Value: 0x23) module.
 
-use crate::data_types::data_type::DataType;
+use crate::data_types::{ad_type::AdType, data_type::DataType, parse_error::DataTypeParseError};
 
 /// LE Secure Connections Random Value.
 
 #[derive(Debug, PartialEq, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct LeSecureConnectionsRandomValue {
     /// data length
     pub length: u8,
@@ -34,14 +35,36 @@ impl LeSecureConnectionsRandomValue {
     }
 }
 
+impl TryFrom<&[u8]> for LeSecureConnectionsRandomValue {
+    type Error = DataTypeParseError;
+    /// Create [`LeSecureConnectionsRandomValue`] from `&[u8]`.
+    fn try_from(value: &[u8]) -> Result<Self, DataTypeParseError> {
+        let len = value.len();
+        if len < 18 {
+            return Err(DataTypeParseError::InvalidLength {
+                expected: 18,
+                actual: len,
+            });
+        }
+        let length = value[0];
+        Ok(Self {
+            length,
+            le_secure_connections_random_value: u128::from_le_bytes(
+                value[2..18].try_into().unwrap(),
+            ),
+        })
+    }
+}
+
 impl TryFrom<&Vec<u8>> for LeSecureConnectionsRandomValue {
-    type Error = String;
+    type Error = DataTypeParseError;
     /// Create [`LeSecureConnectionsRandomValue`] from [`Vec<u8>`].
     ///
     /// # Examples
     ///
     /// ```
     /// use ble_data_struct::{BASE_UUID, data_types::{le_secure_connections_random_value::LeSecureConnectionsRandomValue, data_type::DataType}};
+    /// use ble_data_struct::data_types::parse_error::DataTypeParseError;
     ///
     /// let le_secure_connections_random_value = 0x0102030405060708090a0b0c0d0e0f10u128;
     /// let length = 17;
@@ -63,22 +86,15 @@ impl TryFrom<&Vec<u8>> for LeSecureConnectionsRandomValue {
     /// let result = LeSecureConnectionsRandomValue::try_from(&data);
     /// assert!(result.is_err());
     /// assert_eq!(
-    ///     format!("Invalid data size :{}", data.len()),
+    ///     DataTypeParseError::InvalidLength {
+    ///         expected: 18,
+    ///         actual: data.len()
+    ///     },
     ///     result.unwrap_err()
     /// );
     /// ```
-    fn try_from(value: &Vec<u8>) -> Result<Self, String> {
-        let len = value.len();
-        if len < 18 {
-            return Err(format!("Invalid data size :{}", len).to_string());
-        }
-        let length = value[0];
-        Ok(Self {
-            length,
-            le_secure_connections_random_value: u128::from_le_bytes(
-                value[2..18].try_into().unwrap(),
-            ),
-        })
+    fn try_from(value: &Vec<u8>) -> Result<Self, DataTypeParseError> {
+        Self::try_from(value.as_slice())
     }
 }
 
@@ -132,6 +148,7 @@ impl DataType for LeSecureConnectionsRandomValue {
     ///
     /// assert_eq!(0x23, LeSecureConnectionsRandomValue::data_type());
     /// ```
+    #[inline]
     fn data_type() -> u8 {
         0x23
     }
@@ -148,8 +165,9 @@ impl DataType for LeSecureConnectionsRandomValue {
 /// assert!(is_le_secure_connections_random_value(0x23));
 /// assert!(!is_le_secure_connections_random_value(0x00));
 /// ```
+#[inline]
 pub fn is_le_secure_connections_random_value(data_type: u8) -> bool {
-    LeSecureConnectionsRandomValue::data_type() == data_type
+    AdType::from_u8(data_type) == Some(AdType::LeSecureConnectionsRandomValue)
 }
 
 #[cfg(test)]
@@ -190,7 +208,10 @@ mod tests {
         let result = LeSecureConnectionsRandomValue::try_from(&data);
         assert!(result.is_err());
         assert_eq!(
-            format!("Invalid data size :{}", data.len()),
+            DataTypeParseError::InvalidLength {
+                expected: 18,
+                actual: data.len()
+            },
             result.unwrap_err()
         );
     }
@@ -1,608 +1,133 @@
 //! LE Supported Features (Data Type Value: 0x27) module.
 
-use crate::data_types::data_type::DataType;
+use crate::data_types::{ad_type::AdType, data_type::DataType, parse_error::DataTypeParseError};
 
-/// LE Supported Features.
-#[derive(Debug, PartialEq, Clone)]
-pub struct LeSupportedFeatures {
-    /// data length
-    pub length: u8,
-
-    /// LE Supported Features
-    pub le_supported_features: Vec<bool>,
-}
-
-impl LeSupportedFeatures {
-    /// Create [`LeSupportedFeatures`] from `LE Supported Features`.
-    ///
-    /// # Examples
-    ///
-    /// ```
-    /// use ble_data_struct::data_types::le_supported_features::LeSupportedFeatures;
-    ///
-    /// let mut le_supported_features = [false; 48].to_vec();
-    /// for i in 0..44 {
-    ///     le_supported_features[i] = true;
-    ///     let result = LeSupportedFeatures::new(&le_supported_features);
-    ///     assert_eq!(7, result.length);
-    ///     assert_eq!(le_supported_features, result.le_supported_features);
-    ///     le_supported_features[i] = false;
-    /// }
-    /// ```
-    pub fn new(le_supported_features: &Vec<bool>) -> Self {
-        Self {
-            length: 1 + le_supported_features.len() as u8 / 8,
-            le_supported_features: le_supported_features.clone(),
-        }
-    }
-
-    /// check LE Encryption Feature.
-    ///
-    /// # Examples
-    ///
-    /// ```
-    /// use ble_data_struct::data_types::le_supported_features::LeSupportedFeatures;
-    ///
-    /// let mut le_supported_features = [false; 48].to_vec();
-    /// le_supported_features[0] = true;
-    /// let result = LeSupportedFeatures::new(&le_supported_features);
-    /// assert!(result.is_le_encryption_supported());
-    /// ```
-    pub fn is_le_encryption_supported(&self) -> bool {
-        *self.le_supported_features.get(0).unwrap_or(&false)
-    }
-
-    /// check Connection Parameters Request Procedure Feature.
-    ///
-    /// # Examples
-    ///
-    /// ```
-    /// use ble_data_struct::data_types::le_supported_features::LeSupportedFeatures;
-    ///
-    /// let mut le_supported_features = [false; 48].to_vec();
-    /// le_supported_features[1] = true;
-    /// let result = LeSupportedFeatures::new(&le_supported_features);
-    /// assert!(result.is_connection_parameters_request_procedure_supported());
-    /// ```
-    pub fn is_connection_parameters_request_procedure_supported(&self) -> bool {
-        *self.le_supported_features.get(1).unwrap_or(&false)
-    }
-
-    /// check Extended Reject Indication Feature.
-    ///
-    /// # Examples
-    ///
-    /// ```
-    /// use ble_data_struct::data_types::le_supported_features::LeSupportedFeatures;
-    ///
-    /// let mut le_supported_features = [false; 48].to_vec();
-    /// le_supported_features[2] = true;
-    /// let result = LeSupportedFeatures::new(&le_supported_features);
-    /// assert!(result.is_extended_reject_indication_supported());
-    /// ```
-    pub fn is_extended_reject_indication_supported(&self) -> bool {
-        *self.le_supported_features.get(2).unwrap_or(&false)
-    }
-
-    /// check Peripheral-initiated Features Exchange Feature.
-    ///
-    /// # Examples
-    ///
-    /// ```
-    /// use ble_data_struct::data_types::le_supported_features::LeSupportedFeatures;
-    ///
-    /// let mut le_supported_features = [false; 48].to_vec();
-    /// le_supported_features[3] = true;
-    /// let result = LeSupportedFeatures::new(&le_supported_features);
-    /// assert!(result.is_peripheral_initiated_features_exchange_supported());
-    /// ```
-    pub fn is_peripheral_initiated_features_exchange_supported(&self) -> bool {
-        *self.le_supported_features.get(3).unwrap_or(&false)
-    }
-
-    /// check LE Ping Feature.
-    ///
-    /// # Examples
-    ///
-    /// ```
-    /// use ble_data_struct::data_types::le_supported_features::LeSupportedFeatures;
-    ///
-    /// let mut le_supported_features = [false; 48].to_vec();
-    /// le_supported_features[4] = true;
-    /// let result = LeSupportedFeatures::new(&le_supported_features);
-    /// assert!(result.is_le_ping_supported());
-    /// ```
-    pub fn is_le_ping_supported(&self) -> bool {
-        *self.le_supported_features.get(4).unwrap_or(&false)
-    }
-
-    /// check LE Data Packet Length Extension Feature.
-    ///
-    /// # Examples
-    ///
-    /// ```
-    /// use ble_data_struct::data_types::le_supported_features::LeSupportedFeatures;
-    ///
-    /// let mut le_supported_features = [false; 48].to_vec();
-    /// le_supported_features[5] = true;
-    /// let result = LeSupportedFeatures::new(&le_supported_features);
-    /// assert!(result.is_le_data_packet_length_extension_supported());
-    /// ```
-    pub fn is_le_data_packet_length_extension_supported(&self) -> bool {
-        *self.le_supported_features.get(5).unwrap_or(&false)
-    }
-
-    /// check LL Privacy Feature.
-    ///
-    /// # Examples
-    ///
-    /// ```
-    /// use ble_data_struct::data_types::le_supported_features::LeSupportedFeatures;
-    ///
-    /// let mut le_supported_features = [false; 48].to_vec();
-    /// le_supported_features[6] = true;
-    /// let result = LeSupportedFeatures::new(&le_supported_features);
-    /// assert!(result.is_ll_privacy_supported());
-    /// ```
-    pub fn is_ll_privacy_supported(&self) -> bool {
-        *self.le_supported_features.get(6).unwrap_or(&false)
-    }
-
-    /// check Extended Scanning Filter Policies Feature.
-    ///
-    /// # Examples
-    ///
-    /// ```
-    /// use ble_data_struct::data_types::le_supported_features::LeSupportedFeatures;
-    ///
-    /// let mut le_supported_features = [false; 48].to_vec();
-    /// le_supported_features[7] = true;
-    /// let result = LeSupportedFeatures::new(&le_supported_features);
-    /// assert!(result.is_extended_scanning_filter_policies_supported());
-    /// ```
-    pub fn is_extended_scanning_filter_policies_supported(&self) -> bool {
-        *self.le_supported_features.get(7).unwrap_or(&false)
-    }
-
-    /// check LE 2M PHY Feature.
-    ///
-    /// # Examples
-    ///
-    /// ```
-    /// use ble_data_struct::data_types::le_supported_features::LeSupportedFeatures;
-    ///
-    /// let mut le_supported_features = [false; 48].to_vec();
-    /// le_supported_features[8] = true;
-    /// let result = LeSupportedFeatures::new(&le_supported_features);
-    /// assert!(result.is_le_2m_phy_supported());
-    /// ```
-    pub fn is_le_2m_phy_supported(&self) -> bool {
-        *self.le_supported_features.get(8).unwrap_or(&false)
-    }
-
-    /// check Stable Modulation Index - Transmitter Feature.
-    ///
-    /// # Examples
-    ///
-    /// ```
-    /// use ble_data_struct::data_types::le_supported_features::LeSupportedFeatures;
-    ///
-    /// let mut le_supported_features = [false; 48].to_vec();
-    /// le_supported_features[9] = true;
-    /// let result = LeSupportedFeatures::new(&le_supported_features);
-    /// assert!(result.is_stable_modulation_index_transmitter_supported());
-    /// ```
-    pub fn is_stable_modulation_index_transmitter_supported(&self) -> bool {
-        *self.le_supported_features.get(9).unwrap_or(&false)
-    }
-
-    /// check Stable Modulation Index - Receiver Feature.
-    ///
-    /// # Examples
-    ///
-    /// ```
-    /// use ble_data_struct::data_types::le_supported_features::LeSupportedFeatures;
-    ///
-    /// let mut le_supported_features = [false; 48].to_vec();
-    /// le_supported_features[10] = true;
-    /// let result = LeSupportedFeatures::new(&le_supported_features);
-    /// assert!(result.is_stable_modulation_index_receiver_supported());
-    /// ```
-    pub fn is_stable_modulation_index_receiver_supported(&self) -> bool {
-        *self.le_supported_features.get(10).unwrap_or(&false)
-    }
-
-    /// check LE Coded PHY Feature.
-    ///
-    /// # Examples
-    ///
-    /// ```
-    /// use ble_data_struct::data_types::le_supported_features::LeSupportedFeatures;
-    ///
-    /// let mut le_supported_features = [false; 48].to_vec();
-    /// le_supported_features[11] = true;
-    /// let result = LeSupportedFeatures::new(&le_supported_features);
-    /// assert!(result.is_le_coded_phy_supported());
-    /// ```
-    pub fn is_le_coded_phy_supported(&self) -> bool {
-        *self.le_supported_features.get(11).unwrap_or(&false)
-    }
-
-    /// check LE Extended Advertising Feature.
-    ///
-    /// # Examples
-    ///
-    /// ```
-    /// use ble_data_struct::data_types::le_supported_features::LeSupportedFeatures;
-    ///
-    /// let mut le_supported_features = [false; 48].to_vec();
-    /// le_supported_features[12] = true;
-    /// let result = LeSupportedFeatures::new(&le_supported_features);
-    /// assert!(result.is_le_extended_advertising_supported());
-    /// ```
-    pub fn is_le_extended_advertising_supported(&self) -> bool {
-        *self.le_supported_features.get(12).unwrap_or(&false)
-    }
-
-    /// check LE Periodic Advertising Feature.
-    ///
-    /// # Examples
-    ///
-    /// ```
-    /// use ble_data_struct::data_types::le_supported_features::LeSupportedFeatures;
-    ///
-    /// let mut le_supported_features = [false; 48].to_vec();
-    /// le_supported_features[13] = true;
-    /// let result = LeSupportedFeatures::new(&le_supported_features);
-    /// assert!(result.is_le_periodic_advertising_supported());
-    /// ```
-    pub fn is_le_periodic_advertising_supported(&self) -> bool {
-        *self.le_supported_features.get(13).unwrap_or(&false)
-    }
-
-    /// check Channel Selection Algorithm #2 Feature.
-    ///
-    /// # Examples
-    ///
-    /// ```
-    /// use ble_data_struct::data_types::le_supported_features::LeSupportedFeatures;
-    ///
-    /// let mut le_supported_features = [false; 48].to_vec();
-    /// le_supported_features[14] = true;
-    /// let result = LeSupportedFeatures::new(&le_supported_features);
-    /// assert!(result.is_channel_selection_algorithm2_supported());
-    /// ```
-    pub fn is_channel_selection_algorithm2_supported(&self) -> bool {
-        *self.le_supported_features.get(14).unwrap_or(&false)
-    }
-
-    /// check LE Power Class 1 Feature.
-    ///
-    /// # Examples
-    ///
-    /// ```
-    /// use ble_data_struct::data_types::le_supported_features::LeSupportedFeatures;
-    ///
-    /// let mut le_supported_features = [false; 48].to_vec();
-    /// le_supported_features[15] = true;
-    /// let result = LeSupportedFeatures::new(&le_supported_features);
-    /// assert!(result.is_le_power_class1_supported());
-    /// ```
-    pub fn is_le_power_class1_supported(&self) -> bool {
-        *self.le_supported_features.get(15).unwrap_or(&false)
-    }
-
-    /// check Minimum Number of Used Channels procedure Feature.
-    ///
-    /// # Examples
-    ///
-    /// ```
-    /// use ble_data_struct::data_types::le_supported_features::LeSupportedFeatures;
-    ///
-    /// let mut le_supported_features = [false; 48].to_vec();
-    /// le_supported_features[16] = true;
-    /// let result = LeSupportedFeatures::new(&le_supported_features);
-    /// assert!(result.is_minimum_number_of_used_channels_procedure_supported());
-    /// ```
-    pub fn is_minimum_number_of_used_channels_procedure_supported(&self) -> bool {
-        *self.le_supported_features.get(16).unwrap_or(&false)
-    }
-
-    /// check Connection CTE Request Feature.
-    ///
-    /// # Examples
-    ///
-    /// ```
-    /// use ble_data_struct::data_types::le_supported_features::LeSupportedFeatures;
-    ///
-    /// let mut le_supported_features = [false; 48].to_vec();
-    /// le_supported_features[17] = true;
-    /// let result = LeSupportedFeatures::new(&le_supported_features);
-    /// assert!(result.is_connection_cte_request_supported());
-    /// ```
-    pub fn is_connection_cte_request_supported(&self) -> bool {
-        *self.le_supported_features.get(17).unwrap_or(&false)
-    }
-
-    /// check Connection CTE Response Feature.
-    ///
-    /// # Examples
-    ///
-    /// ```
-    /// use ble_data_struct::data_types::le_supported_features::LeSupportedFeatures;
-    ///
-    /// let mut le_supported_features = [false; 48].to_vec();
-    /// le_supported_features[18] = true;
-    /// let result = LeSupportedFeatures::new(&le_supported_features);
-    /// assert!(result.is_connection_cte_response_supported());
-    /// ```
-    pub fn is_connection_cte_response_supported(&self) -> bool {
-        *self.le_supported_features.get(18).unwrap_or(&false)
-    }
-
-    /// check Connectionless CTE Transmitter Feature.
-    ///
-    /// # Examples
-    ///
-    /// ```
-    /// use ble_data_struct::data_types::le_supported_features::LeSupportedFeatures;
-    ///
-    /// let mut le_supported_features = [false; 48].to_vec();
-    /// le_supported_features[19] = true;
-    /// let result = LeSupportedFeatures::new(&le_supported_features);
-    /// assert!(result.is_connectionless_cte_transmitter_supported());
-    /// ```
-    pub fn is_connectionless_cte_transmitter_supported(&self) -> bool {
-        *self.le_supported_features.get(19).unwrap_or(&false)
-    }
-
-    /// check Connectionless CTE Receiver Feature.
-    ///
-    /// # Examples
-    ///
-    /// ```
-    /// use ble_data_struct::data_types::le_supported_features::LeSupportedFeatures;
-    ///
-    /// let mut le_supported_features = [false; 48].to_vec();
-    /// le_supported_features[20] = true;
-    /// let result = LeSupportedFeatures::new(&le_supported_features);
-    /// assert!(result.is_connectionless_cte_receiver_supported());
-    /// ```
-    pub fn is_connectionless_cte_receiver_supported(&self) -> bool {
-        *self.le_supported_features.get(20).unwrap_or(&false)
-    }
-
-    /// check Antenna Switching During CTE Transmission (AoD) Feature.
-    ///
-    /// # Examples
-    ///
-    /// ```
-    /// use ble_data_struct::data_types::le_supported_features::LeSupportedFeatures;
-    ///
-    /// let mut le_supported_features = [false; 48].to_vec();
-    /// le_supported_features[21] = true;
-    /// let result = LeSupportedFeatures::new(&le_supported_features);
-    /// assert!(result.is_antenna_switching_during_cte_transmission_aod_supported());
-    /// ```
-    pub fn is_antenna_switching_during_cte_transmission_aod_supported(&self) -> bool {
-        *self.le_supported_features.get(21).unwrap_or(&false)
-    }
-
-    /// check Antenna Switching During CTE Reception (AoA) Feature.
-    ///
-    /// # Examples
-    ///
-    /// ```
-    /// use ble_data_struct::data_types::le_supported_features::LeSupportedFeatures;
-    ///
-    /// let mut le_supported_features = [false; 48].to_vec();
-    /// le_supported_features[22] = true;
-    /// let result = LeSupportedFeatures::new(&le_supported_features);
-    /// assert!(result.is_antenna_switching_during_cte_reception_aoa_supported());
-    /// ```
-    pub fn is_antenna_switching_during_cte_reception_aoa_supported(&self) -> bool {
-        *self.le_supported_features.get(22).unwrap_or(&false)
-    }
-
-    /// check Receiving Constant Tone Extensions Feature.
-    ///
-    /// # Examples
-    ///
-    /// ```
-    /// use ble_data_struct::data_types::le_supported_features::LeSupportedFeatures;
-    ///
-    /// let mut le_supported_features = [false; 48].to_vec();
-    /// le_supported_features[23] = true;
-    /// let result = LeSupportedFeatures::new(&le_supported_features);
-    /// assert!(result.is_receiving_constant_tone_extensions_supported());
-    /// ```
-    pub fn is_receiving_constant_tone_extensions_supported(&self) -> bool {
-        *self.le_supported_features.get(23).unwrap_or(&false)
-    }
-
-    /// check Periodic Advertising Sync Transfer - Sender Feature.
-    ///
-    /// # Examples
-    ///
-    /// ```
-    /// use ble_data_struct::data_types::le_supported_features::LeSupportedFeatures;
-    ///
-    /// let mut le_supported_features = [false; 48].to_vec();
-    /// le_supported_features[24] = true;
-    /// let result = LeSupportedFeatures::new(&le_supported_features);
-    /// assert!(result.is_periodic_advertising_sync_transfer_sender_supported());
-    /// ```
-    pub fn is_periodic_advertising_sync_transfer_sender_supported(&self) -> bool {
-        *self.le_supported_features.get(24).unwrap_or(&false)
-    }
-
-    /// check Periodic Advertising Sync Transfer - Recipient Feature.
-    ///
-    /// # Examples
-    ///
-    /// ```
-    /// use ble_data_struct::data_types::le_supported_features::LeSupportedFeatures;
-    ///
-    /// let mut le_supported_features = [false; 48].to_vec();
-    /// le_supported_features[25] = true;
-    /// let result = LeSupportedFeatures::new(&le_supported_features);
-    /// assert!(result.is_periodic_advertising_sync_transfer_recipient_supported());
-    /// ```
-    pub fn is_periodic_advertising_sync_transfer_recipient_supported(&self) -> bool {
-        *self.le_supported_features.get(25).unwrap_or(&false)
-    }
-
-    /// check Sleep Clock Accuracy Updates Feature.
-    ///
-    /// # Examples
-    ///
-    /// ```
-    /// use ble_data_struct::data_types::le_supported_features::LeSupportedFeatures;
-    ///
-    /// let mut le_supported_features = [false; 48].to_vec();
-    /// le_supported_features[26] = true;
-    /// let result = LeSupportedFeatures::new(&le_supported_features);
-    /// assert!(result.is_sleep_clock_accuracy_updates_supported());
-    /// ```
-    pub fn is_sleep_clock_accuracy_updates_supported(&self) -> bool {
-        *self.le_supported_features.get(26).unwrap_or(&false)
-    }
-
-    /// check Remote Public Key Validation Feature.
-    ///
-    /// # Examples
-    ///
-    /// ```
-    /// use ble_data_struct::data_types::le_supported_features::LeSupportedFeatures;
-    ///
-    /// let mut le_supported_features = [false; 48].to_vec();
-    /// le_supported_features[27] = true;
-    /// let result = LeSupportedFeatures::new(&le_supported_features);
-    /// assert!(result.is_remote_public_key_validation_supported());
-    /// ```
-    pub fn is_remote_public_key_validation_supported(&self) -> bool {
-        *self.le_supported_features.get(27).unwrap_or(&false)
-    }
-
-    /// check Connected Isochronous Stream – Central Feature.
-    ///
-    /// # Examples
-    ///
-    /// ```
-    /// use ble_data_struct::data_types::le_supported_features::LeSupportedFeatures;
-    ///
-    /// let mut le_supported_features = [false; 48].to_vec();
-    /// le_supported_features[28] = true;
-    /// let result = LeSupportedFeatures::new(&le_supported_features);
-    /// assert!(result.is_connected_isochronous_stream_central_supported());
-    /// ```
-    pub fn is_connected_isochronous_stream_central_supported(&self) -> bool {
-        *self.le_supported_features.get(28).unwrap_or(&false)
-    }
+/// [`LeSupportedFeatures::diff`] result.
+#[derive(Debug, PartialEq, Eq, Clone, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct FeatureDiff {
+    /// Bit numbers of the features supported only by `other`
+    pub gained: Vec<usize>,
 
-    /// check Connected Isochronous Stream – Peripheral Feature.
-    ///
-    /// # Examples
-    ///
-    /// ```
-    /// use ble_data_struct::data_types::le_supported_features::LeSupportedFeatures;
-    ///
-    /// let mut le_supported_features = [false; 48].to_vec();
-    /// le_supported_features[29] = true;
-    /// let result = LeSupportedFeatures::new(&le_supported_features);
-    /// assert!(result.is_connected_isochronous_stream_peripheral_supported());
-    /// ```
-    pub fn is_connected_isochronous_stream_peripheral_supported(&self) -> bool {
-        *self.le_supported_features.get(29).unwrap_or(&false)
-    }
+    /// Bit numbers of the features supported only by `self`
+    pub lost: Vec<usize>,
+}
 
-    /// check Isochronous Broadcaster Feature.
+impl FeatureDiff {
+    /// check no feature is gained or lost.
     ///
     /// # Examples
     ///
     /// ```
-    /// use ble_data_struct::data_types::le_supported_features::LeSupportedFeatures;
+    /// use ble_data_struct::data_types::le_supported_features::FeatureDiff;
     ///
-    /// let mut le_supported_features = [false; 48].to_vec();
-    /// le_supported_features[30] = true;
-    /// let result = LeSupportedFeatures::new(&le_supported_features);
-    /// assert!(result.is_isochronous_broadcaster_supported());
+    /// assert!(FeatureDiff::default().is_empty());
     /// ```
-    pub fn is_isochronous_broadcaster_supported(&self) -> bool {
-        *self.le_supported_features.get(30).unwrap_or(&false)
+    pub fn is_empty(&self) -> bool {
+        self.gained.is_empty() && self.lost.is_empty()
     }
+}
 
-    /// check Synchronized Receiver Feature.
-    ///
-    /// # Examples
-    ///
-    /// ```
-    /// use ble_data_struct::data_types::le_supported_features::LeSupportedFeatures;
-    ///
-    /// let mut le_supported_features = [false; 48].to_vec();
-    /// le_supported_features[31] = true;
-    /// let result = LeSupportedFeatures::new(&le_supported_features);
-    /// assert!(result.is_synchronized_receiver_supported());
-    /// ```
-    pub fn is_synchronized_receiver_supported(&self) -> bool {
-        *self.le_supported_features.get(31).unwrap_or(&false)
-    }
+/// Declare LE Supported Features accessors and [`LE_SUPPORTED_FEATURE_NAMES`] from one table.
+///
+/// Each row is `accessor = [bit numbers], "Feature name"`; a feature spanning several bits is supported if any bit is set.
+/// New spec releases only need a new row.
+macro_rules! le_supported_features {
+    ($($accessor:ident = [$($bit:literal),+], $name:literal;)+) => {
+        /// (bit number, feature name) of the known LE Supported Features.
+        pub const LE_SUPPORTED_FEATURE_NAMES: &[(usize, &str)] = &[$($(($bit, $name),)+)+];
+
+        impl LeSupportedFeatures {
+            $(
+                #[doc = concat!("check ", $name, " Feature.")]
+                ///
+                /// # Examples
+                ///
+                /// ```
+                /// use ble_data_struct::data_types::le_supported_features::LeSupportedFeatures;
+                ///
+                #[doc = concat!("for bit in [", stringify!($($bit),+), "] {")]
+                ///     let mut le_supported_features = [false; 72].to_vec();
+                ///     le_supported_features[bit] = true;
+                ///     let result = LeSupportedFeatures::new(&le_supported_features);
+                #[doc = concat!("    assert!(result.", stringify!($accessor), "());")]
+                /// }
+                /// ```
+                pub fn $accessor(&self) -> bool {
+                    false $(| *self.le_supported_features.get($bit).unwrap_or(&false))+
+                }
+            )+
+        }
+    };
+}
 
-    /// check Connected Isochronous Stream (Host Support) Feature.
-    ///
-    /// # Examples
-    ///
-    /// ```
-    /// use ble_data_struct::data_types::le_supported_features::LeSupportedFeatures;
-    ///
-    /// let mut le_supported_features = [false; 48].to_vec();
-    /// le_supported_features[32] = true;
-    /// let result = LeSupportedFeatures::new(&le_supported_features);
-    /// assert!(result.is_connected_isochronous_stream_host_support_supported());
-    /// ```
-    pub fn is_connected_isochronous_stream_host_support_supported(&self) -> bool {
-        *self.le_supported_features.get(32).unwrap_or(&false)
-    }
+le_supported_features! {
+    is_le_encryption_supported = [0], "LE Encryption";
+    is_connection_parameters_request_procedure_supported = [1], "Connection Parameters Request Procedure";
+    is_extended_reject_indication_supported = [2], "Extended Reject Indication";
+    is_peripheral_initiated_features_exchange_supported = [3], "Peripheral-initiated Features Exchange";
+    is_le_ping_supported = [4], "LE Ping";
+    is_le_data_packet_length_extension_supported = [5], "LE Data Packet Length Extension";
+    is_ll_privacy_supported = [6], "LL Privacy";
+    is_extended_scanning_filter_policies_supported = [7], "Extended Scanning Filter Policies";
+    is_le_2m_phy_supported = [8], "LE 2M PHY";
+    is_stable_modulation_index_transmitter_supported = [9], "Stable Modulation Index - Transmitter";
+    is_stable_modulation_index_receiver_supported = [10], "Stable Modulation Index - Receiver";
+    is_le_coded_phy_supported = [11], "LE Coded PHY";
+    is_le_extended_advertising_supported = [12], "LE Extended Advertising";
+    is_le_periodic_advertising_supported = [13], "LE Periodic Advertising";
+    is_channel_selection_algorithm2_supported = [14], "Channel Selection Algorithm #2";
+    is_le_power_class1_supported = [15], "LE Power Class 1";
+    is_minimum_number_of_used_channels_procedure_supported = [16], "Minimum Number of Used Channels procedure";
+    is_connection_cte_request_supported = [17], "Connection CTE Request";
+    is_connection_cte_response_supported = [18], "Connection CTE Response";
+    is_connectionless_cte_transmitter_supported = [19], "Connectionless CTE Transmitter";
+    is_connectionless_cte_receiver_supported = [20], "Connectionless CTE Receiver";
+    is_antenna_switching_during_cte_transmission_aod_supported = [21], "Antenna Switching During CTE Transmission (AoD)";
+    is_antenna_switching_during_cte_reception_aoa_supported = [22], "Antenna Switching During CTE Reception (AoA)";
+    is_receiving_constant_tone_extensions_supported = [23], "Receiving Constant Tone Extensions";
+    is_periodic_advertising_sync_transfer_sender_supported = [24], "Periodic Advertising Sync Transfer - Sender";
+    is_periodic_advertising_sync_transfer_recipient_supported = [25], "Periodic Advertising Sync Transfer - Recipient";
+    is_sleep_clock_accuracy_updates_supported = [26], "Sleep Clock Accuracy Updates";
+    is_remote_public_key_validation_supported = [27], "Remote Public Key Validation";
+    is_connected_isochronous_stream_central_supported = [28], "Connected Isochronous Stream – Central";
+    is_connected_isochronous_stream_peripheral_supported = [29], "Connected Isochronous Stream – Peripheral";
+    is_isochronous_broadcaster_supported = [30], "Isochronous Broadcaster";
+    is_synchronized_receiver_supported = [31], "Synchronized Receiver";
+    is_connected_isochronous_stream_host_support_supported = [32], "Connected Isochronous Stream (Host Support)";
+    is_le_power_control_request_supported = [33, 34], "LE Power Control Request";
+    is_le_path_loss_monitoring_supported = [35], "LE Path Loss Monitoring";
+    is_periodic_advertising_adi_support_supported = [36], "Periodic Advertising ADI support";
+    is_connection_subrating_supported = [37], "Connection Subrating support";
+    is_connection_subrating_host_support_supported = [38], "Connection Subrating (Host Support)";
+    is_channel_classification_supported = [39], "Channel Classification";
+    is_advertising_coding_selection_supported = [40], "Advertising Coding Selection";
+    is_advertising_coding_selection_host_support_supported = [41], "Advertising Coding Selection (Host Support)";
+    is_decision_based_advertising_filtering_supported = [42], "Decision-Based Advertising Filtering";
+    is_periodic_advertising_with_responses_advertiser_supported = [43], "Periodic Advertising with Responses - Advertiser";
+    is_periodic_advertising_with_responses_scanner_supported = [44], "Periodic Advertising with Responses - Scanner";
+    is_unsegmented_framed_mode_supported = [45], "Unsegmented Framed Mode";
+    is_channel_sounding_supported = [46], "Channel Sounding";
+    is_channel_sounding_host_support_supported = [47], "Channel Sounding (Host Support)";
+    is_channel_sounding_tone_quality_indication_supported = [48], "Channel Sounding Tone Quality Indication";
+    is_ll_extended_feature_set_supported = [63], "LL Extended Feature Set";
+    is_monitoring_advertisers_supported = [64], "Monitoring Advertisers";
+    is_frame_space_update_supported = [65], "Frame Space Update";
+}
 
-    /// check LE Power Control Request Feature.
-    ///
-    /// # Examples
-    ///
-    /// ```
-    /// use ble_data_struct::data_types::le_supported_features::LeSupportedFeatures;
-    ///
-    /// let mut le_supported_features = [false; 48].to_vec();
-    /// le_supported_features[33] = true;
-    /// let result = LeSupportedFeatures::new(&le_supported_features);
-    /// assert!(result.is_le_power_control_request_supported());
-    ///
-    /// let mut le_supported_features = [false; 48].to_vec();
-    /// le_supported_features[34] = true;
-    /// let result = LeSupportedFeatures::new(&le_supported_features);
-    /// assert!(result.is_le_power_control_request_supported());
-    /// ```
-    pub fn is_le_power_control_request_supported(&self) -> bool {
-        *self.le_supported_features.get(33).unwrap_or(&false)
-            | *self.le_supported_features.get(34).unwrap_or(&false)
-    }
+/// LE Supported Features.
+#[derive(Debug, PartialEq, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct LeSupportedFeatures {
+    /// data length
+    pub length: u8,
 
-    /// check LE Path Loss Monitoring Feature.
-    ///
-    /// # Examples
-    ///
-    /// ```
-    /// use ble_data_struct::data_types::le_supported_features::LeSupportedFeatures;
-    ///
-    /// let mut le_supported_features = [false; 48].to_vec();
-    /// le_supported_features[35] = true;
-    /// let result = LeSupportedFeatures::new(&le_supported_features);
-    /// assert!(result.is_le_path_loss_monitoring_supported());
-    /// ```
-    pub fn is_le_path_loss_monitoring_supported(&self) -> bool {
-        *self.le_supported_features.get(35).unwrap_or(&false)
-    }
+    /// LE Supported Features
+    pub le_supported_features: Vec<bool>,
+}
 
-    /// check Periodic Advertising ADI support Feature.
+impl LeSupportedFeatures {
+    /// Create [`LeSupportedFeatures`] from `LE Supported Features`.
     ///
     /// # Examples
     ///
@@ -610,79 +135,70 @@ impl LeSupportedFeatures {
     /// use ble_data_struct::data_types::le_supported_features::LeSupportedFeatures;
     ///
     /// let mut le_supported_features = [false; 48].to_vec();
-    /// le_supported_features[36] = true;
-    /// let result = LeSupportedFeatures::new(&le_supported_features);
-    /// assert!(result.is_periodic_advertising_adi_support_supported());
+    /// for i in 0..44 {
+    ///     le_supported_features[i] = true;
+    ///     let result = LeSupportedFeatures::new(&le_supported_features);
+    ///     assert_eq!(7, result.length);
+    ///     assert_eq!(le_supported_features, result.le_supported_features);
+    ///     le_supported_features[i] = false;
+    /// }
     /// ```
-    pub fn is_periodic_advertising_adi_support_supported(&self) -> bool {
-        *self.le_supported_features.get(36).unwrap_or(&false)
+    pub fn new(le_supported_features: &Vec<bool>) -> Self {
+        Self {
+            length: 1 + le_supported_features.len() as u8 / 8,
+            le_supported_features: le_supported_features.clone(),
+        }
     }
 
-    /// check Connection Subrating support Feature.
+    /// Get feature name of the bit number(`None` if unknown).
     ///
     /// # Examples
     ///
     /// ```
     /// use ble_data_struct::data_types::le_supported_features::LeSupportedFeatures;
     ///
-    /// let mut le_supported_features = [false; 48].to_vec();
-    /// le_supported_features[37] = true;
-    /// let result = LeSupportedFeatures::new(&le_supported_features);
-    /// assert!(result.is_connection_subrating_supported());
+    /// assert_eq!(Some("LE Encryption"), LeSupportedFeatures::feature_name(0));
+    /// assert_eq!(Some("Channel Sounding"), LeSupportedFeatures::feature_name(46));
+    /// assert_eq!(None, LeSupportedFeatures::feature_name(62));
     /// ```
-    pub fn is_connection_subrating_supported(&self) -> bool {
-        *self.le_supported_features.get(37).unwrap_or(&false)
+    pub fn feature_name(bit: usize) -> Option<&'static str> {
+        LE_SUPPORTED_FEATURE_NAMES
+            .iter()
+            .find(|f| f.0 == bit)
+            .map(|f| f.1)
     }
 
-    /// check Connection Subrating (Host Support) Feature.
-    ///
-    /// # Examples
-    ///
-    /// ```
-    /// use ble_data_struct::data_types::le_supported_features::LeSupportedFeatures;
+    /// Get feature names of the supported features.
     ///
-    /// let mut le_supported_features = [false; 48].to_vec();
-    /// le_supported_features[38] = true;
-    /// let result = LeSupportedFeatures::new(&le_supported_features);
-    /// assert!(result.is_connection_subrating_host_support_supported());
-    /// ```
-    pub fn is_connection_subrating_host_support_supported(&self) -> bool {
-        *self.le_supported_features.get(38).unwrap_or(&false)
-    }
-
-    /// check Channel Classification Feature.
+    /// Unknown bits are skipped, and a feature spanning several bits is listed once.
     ///
     /// # Examples
     ///
     /// ```
     /// use ble_data_struct::data_types::le_supported_features::LeSupportedFeatures;
     ///
-    /// let mut le_supported_features = [false; 48].to_vec();
-    /// le_supported_features[39] = true;
+    /// let mut le_supported_features = [false; 72].to_vec();
+    /// le_supported_features[0] = true;
+    /// le_supported_features[46] = true;
+    /// le_supported_features[62] = true;
     /// let result = LeSupportedFeatures::new(&le_supported_features);
-    /// assert!(result.is_channel_classification_supported());
+    /// assert_eq!(vec!["LE Encryption", "Channel Sounding"], result.supported_feature_names());
     /// ```
-    pub fn is_channel_classification_supported(&self) -> bool {
-        *self.le_supported_features.get(39).unwrap_or(&false)
+    pub fn supported_feature_names(&self) -> Vec<&'static str> {
+        let mut names: Vec<&'static str> = self
+            .le_supported_features
+            .iter()
+            .enumerate()
+            .filter(|f| *f.1)
+            .filter_map(|f| Self::feature_name(f.0))
+            .collect();
+        names.dedup();
+        names
     }
 
-    /// check Advertising Coding Selection Feature.
-    ///
-    /// # Examples
+    /// Compare the supported features with `other`.
     ///
-    /// ```
-    /// use ble_data_struct::data_types::le_supported_features::LeSupportedFeatures;
-    ///
-    /// let mut le_supported_features = [false; 48].to_vec();
-    /// le_supported_features[40] = true;
-    /// let result = LeSupportedFeatures::new(&le_supported_features);
-    /// assert!(result.is_advertising_coding_selection_supported());
-    /// ```
-    pub fn is_advertising_coding_selection_supported(&self) -> bool {
-        *self.le_supported_features.get(40).unwrap_or(&false)
-    }
-
-    /// check Advertising Coding Selection (Host Support) Feature.
+    /// Missing bits are treated as not supported.
     ///
     /// # Examples
     ///
@@ -690,55 +206,81 @@ impl LeSupportedFeatures {
     /// use ble_data_struct::data_types::le_supported_features::LeSupportedFeatures;
     ///
     /// let mut le_supported_features = [false; 48].to_vec();
-    /// le_supported_features[41] = true;
-    /// let result = LeSupportedFeatures::new(&le_supported_features);
-    /// assert!(result.is_advertising_coding_selection_host_support_supported());
-    /// ```
-    pub fn is_advertising_coding_selection_host_support_supported(&self) -> bool {
-        *self.le_supported_features.get(41).unwrap_or(&false)
-    }
-
-    /// check Periodic Advertising with Responses - Advertiser Feature.
-    ///
-    /// # Examples
+    /// le_supported_features[0] = true;
+    /// le_supported_features[4] = true;
+    /// let old = LeSupportedFeatures::new(&le_supported_features);
     ///
-    /// ```
-    /// use ble_data_struct::data_types::le_supported_features::LeSupportedFeatures;
+    /// le_supported_features[4] = false;
+    /// le_supported_features[8] = true;
+    /// let new = LeSupportedFeatures::new(&le_supported_features);
     ///
-    /// let mut le_supported_features = [false; 48].to_vec();
-    /// le_supported_features[43] = true;
-    /// let result = LeSupportedFeatures::new(&le_supported_features);
-    /// assert!(result.is_periodic_advertising_with_responses_advertiser_supported());
+    /// let result = old.diff(&new);
+    /// assert_eq!(vec![8], result.gained);
+    /// assert_eq!(vec![4], result.lost);
+    /// assert!(old.diff(&old).is_empty());
     /// ```
-    pub fn is_periodic_advertising_with_responses_advertiser_supported(&self) -> bool {
-        *self.le_supported_features.get(43).unwrap_or(&false)
+    pub fn diff(&self, other: &Self) -> FeatureDiff {
+        let mut diff = FeatureDiff::default();
+        let len = self
+            .le_supported_features
+            .len()
+            .max(other.le_supported_features.len());
+        for i in 0..len {
+            let before = *self.le_supported_features.get(i).unwrap_or(&false);
+            let after = *other.le_supported_features.get(i).unwrap_or(&false);
+            if !before && after {
+                diff.gained.push(i);
+            } else if before && !after {
+                diff.lost.push(i);
+            }
+        }
+        diff
     }
+}
 
-    /// check Periodic Advertising with Responses - Scanner Feature.
-    ///
-    /// # Examples
-    ///
-    /// ```
-    /// use ble_data_struct::data_types::le_supported_features::LeSupportedFeatures;
-    ///
-    /// let mut le_supported_features = [false; 48].to_vec();
-    /// le_supported_features[44] = true;
-    /// let result = LeSupportedFeatures::new(&le_supported_features);
-    /// assert!(result.is_periodic_advertising_with_responses_scanner_supported());
-    /// ```
-    pub fn is_periodic_advertising_with_responses_scanner_supported(&self) -> bool {
-        *self.le_supported_features.get(44).unwrap_or(&false)
+impl TryFrom<&[u8]> for LeSupportedFeatures {
+    type Error = DataTypeParseError;
+    /// Create [`LeSupportedFeatures`] from `&[u8]`.
+    fn try_from(value: &[u8]) -> Result<Self, DataTypeParseError> {
+        let len = value.len();
+        if len < 7 {
+            return Err(DataTypeParseError::InvalidLength {
+                expected: 7,
+                actual: len,
+            });
+        }
+        let length = value[0];
+        let le_supported_features: Vec<bool> = value[2..]
+            .iter()
+            .flat_map(|x| {
+                let mut data: Vec<bool> = Vec::new();
+                data.push(x & 0b0000_0001 != 0);
+                data.push(x & 0b0000_0010 != 0);
+                data.push(x & 0b0000_0100 != 0);
+                data.push(x & 0b0000_1000 != 0);
+                data.push(x & 0b0001_0000 != 0);
+                data.push(x & 0b0010_0000 != 0);
+                data.push(x & 0b0100_0000 != 0);
+                data.push(x & 0b1000_0000 != 0);
+                data
+            })
+            .collect();
+        Ok(Self {
+            length,
+            le_supported_features: le_supported_features.to_vec(),
+        })
     }
 }
 
 impl TryFrom<&Vec<u8>> for LeSupportedFeatures {
-    type Error = String;
+    type Error = DataTypeParseError;
     /// Create [`LeSupportedFeatures`] from [`Vec<u8>`].
     ///
     /// # Examples
     ///
     /// ```
     /// use ble_data_struct::data_types::{le_supported_features::LeSupportedFeatures, data_type::DataType};
+    /// use ble_data_struct::data_types::parse_error::DataTypeParseError;
     ///
     /// let mut le_supported_features = [0u8; 6].to_vec();
     ///
@@ -781,35 +323,15 @@ impl TryFrom<&Vec<u8>> for LeSupportedFeatures {
     /// let result = LeSupportedFeatures::try_from(&data);
     /// assert!(result.is_err());
     /// assert_eq!(
-    ///     format!("Invalid data size :{}", data.len()),
+    ///     DataTypeParseError::InvalidLength {
+    ///         expected: 7,
+    ///         actual: data.len()
+    ///     },
     ///     result.unwrap_err()
     /// );
     /// ```
-    fn try_from(value: &Vec<u8>) -> Result<Self, String> {
-        let len = value.len();
-        if len < 7 {
-            return Err(format!("Invalid data size :{}", len).to_string());
-        }
-        let length = value[0];
-        let le_supported_features: Vec<bool> = value[2..]
-            .iter()
-            .flat_map(|x| {
-                let mut data: Vec<bool> = Vec::new();
-                data.push(x & 0b0000_0001 != 0);
-                data.push(x & 0b0000_0010 != 0);
-                data.push(x & 0b0000_0100 != 0);
-                data.push(x & 0b0000_1000 != 0);
-                data.push(x & 0b0001_0000 != 0);
-                data.push(x & 0b0010_0000 != 0);
-                data.push(x & 0b0100_0000 != 0);
-                data.push(x & 0b1000_0000 != 0);
-                data
-            })
-            .collect();
-        Ok(Self {
-            length,
-            le_supported_features: le_supported_features.to_vec(),
-        })
+    fn try_from(value: &Vec<u8>) -> Result<Self, DataTypeParseError> {
+        Self::try_from(value.as_slice())
     }
 }
 
@@ -885,6 +407,7 @@ impl DataType for LeSupportedFeatures {
     ///
     /// assert_eq!(0x27, LeSupportedFeatures::data_type());
     /// ```
+    #[inline]
     fn data_type() -> u8 {
         0x27
     }
@@ -901,8 +424,9 @@ impl DataType for LeSupportedFeatures {
 /// assert!(is_le_supported_features(0x27));
 /// assert!(!is_le_supported_features(0x00));
 /// ```
+#[inline]
 pub fn is_le_supported_features(data_type: u8) -> bool {
-    LeSupportedFeatures::data_type() == data_type
+    AdType::from_u8(data_type) == Some(AdType::LeSupportedFeatures)
 }
 
 #[cfg(test)]
@@ -922,6 +446,37 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_diff() {
+        let mut le_supported_features = [false; 48].to_vec();
+        le_supported_features[0] = true;
+        le_supported_features[1] = true;
+        let result1 = LeSupportedFeatures::new(&le_supported_features);
+
+        le_supported_features[1] = false;
+        le_supported_features[2] = true;
+        le_supported_features[44] = true;
+        let result2 = LeSupportedFeatures::new(&le_supported_features);
+
+        let diff = result1.diff(&result2);
+        assert_eq!(vec![2, 44], diff.gained);
+        assert_eq!(vec![1], diff.lost);
+        assert!(!diff.is_empty());
+
+        let diff = result2.diff(&result1);
+        assert_eq!(vec![1], diff.gained);
+        assert_eq!(vec![2, 44], diff.lost);
+
+        assert_eq!(FeatureDiff::default(), result1.diff(&result1));
+
+        let result3 = LeSupportedFeatures::new(
+            &[true, false, false, false, false, false, false, false].to_vec(),
+        );
+        let diff = result3.diff(&result2);
+        assert_eq!(vec![2, 44], diff.gained);
+        assert!(diff.lost.is_empty());
+    }
+
     #[test]
     fn test_is_le_encryption_supported() {
         let mut le_supported_features = [false; 48].to_vec();
@@ -1271,6 +826,108 @@ mod tests {
         assert!(result.is_periodic_advertising_with_responses_scanner_supported());
     }
 
+    #[test]
+    fn test_is_decision_based_advertising_filtering_supported() {
+        let mut le_supported_features = [false; 48].to_vec();
+        le_supported_features[42] = true;
+        let result = LeSupportedFeatures::new(&le_supported_features);
+        assert!(result.is_decision_based_advertising_filtering_supported());
+    }
+
+    #[test]
+    fn test_is_unsegmented_framed_mode_supported() {
+        let mut le_supported_features = [false; 48].to_vec();
+        le_supported_features[45] = true;
+        let result = LeSupportedFeatures::new(&le_supported_features);
+        assert!(result.is_unsegmented_framed_mode_supported());
+    }
+
+    #[test]
+    fn test_is_channel_sounding_supported() {
+        let mut le_supported_features = [false; 48].to_vec();
+        le_supported_features[46] = true;
+        let result = LeSupportedFeatures::new(&le_supported_features);
+        assert!(result.is_channel_sounding_supported());
+        assert!(!result.is_channel_sounding_host_support_supported());
+    }
+
+    #[test]
+    fn test_is_channel_sounding_host_support_supported() {
+        let mut le_supported_features = [false; 48].to_vec();
+        le_supported_features[47] = true;
+        let result = LeSupportedFeatures::new(&le_supported_features);
+        assert!(result.is_channel_sounding_host_support_supported());
+    }
+
+    #[test]
+    fn test_is_channel_sounding_tone_quality_indication_supported() {
+        let mut le_supported_features = [false; 56].to_vec();
+        le_supported_features[48] = true;
+        let result = LeSupportedFeatures::new(&le_supported_features);
+        assert!(result.is_channel_sounding_tone_quality_indication_supported());
+
+        let result = LeSupportedFeatures::new(&[false; 48].to_vec());
+        assert!(!result.is_channel_sounding_tone_quality_indication_supported());
+    }
+
+    #[test]
+    fn test_is_ll_extended_feature_set_supported() {
+        let mut le_supported_features = [false; 72].to_vec();
+        le_supported_features[63] = true;
+        let result = LeSupportedFeatures::new(&le_supported_features);
+        assert!(result.is_ll_extended_feature_set_supported());
+    }
+
+    #[test]
+    fn test_is_monitoring_advertisers_supported() {
+        let mut le_supported_features = [false; 72].to_vec();
+        le_supported_features[64] = true;
+        let result = LeSupportedFeatures::new(&le_supported_features);
+        assert!(result.is_monitoring_advertisers_supported());
+    }
+
+    #[test]
+    fn test_is_frame_space_update_supported() {
+        let mut le_supported_features = [false; 72].to_vec();
+        le_supported_features[65] = true;
+        let result = LeSupportedFeatures::new(&le_supported_features);
+        assert!(result.is_frame_space_update_supported());
+    }
+
+    #[test]
+    fn test_feature_name() {
+        assert_eq!(Some("LE Encryption"), LeSupportedFeatures::feature_name(0));
+        assert_eq!(
+            Some("LE Power Control Request"),
+            LeSupportedFeatures::feature_name(34)
+        );
+        assert_eq!(
+            Some("Frame Space Update"),
+            LeSupportedFeatures::feature_name(65)
+        );
+        assert_eq!(None, LeSupportedFeatures::feature_name(49));
+        for bit in 1..LE_SUPPORTED_FEATURE_NAMES.len() {
+            assert!(LE_SUPPORTED_FEATURE_NAMES[bit - 1].0 < LE_SUPPORTED_FEATURE_NAMES[bit].0);
+        }
+    }
+
+    #[test]
+    fn test_supported_feature_names() {
+        let mut le_supported_features = [false; 72].to_vec();
+        le_supported_features[33] = true;
+        le_supported_features[34] = true;
+        le_supported_features[49] = true;
+        le_supported_features[64] = true;
+        let result = LeSupportedFeatures::new(&le_supported_features);
+        assert_eq!(
+            vec!["LE Power Control Request", "Monitoring Advertisers"],
+            result.supported_feature_names()
+        );
+
+        let result = LeSupportedFeatures::new(&[false; 8].to_vec());
+        assert!(result.supported_feature_names().is_empty());
+    }
+
     #[test]
     fn test_try_from() {
         let mut le_supported_features = [0u8; 6].to_vec();
@@ -1315,7 +972,10 @@ mod tests {
         let result = LeSupportedFeatures::try_from(&data);
         assert!(result.is_err());
         assert_eq!(
-            format!("Invalid data size :{}", data.len()),
+            DataTypeParseError::InvalidLength {
+                expected: 7,
+                actual: data.len()
+            },
             result.unwrap_err()
         );
     }
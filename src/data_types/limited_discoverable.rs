@@ -0,0 +1,207 @@
+//! Limited Discoverable mode timeout module.
+//!
+//! A peripheral in Limited Discoverable mode must drop the LE Limited Discoverable Mode flag after `TGAP(lim_adv_timeout)`.
+
+use std::time::{Duration, Instant};
+
+use crate::data_types::flags::{DiscoverableMode, Flags};
+
+/// TGAP(lim_adv_timeout)(180 seconds).
+pub const LIMITED_DISCOVERABLE_TIMEOUT: Duration = Duration::from_secs(180);
+
+/// Limited Discoverable mode window.
+#[derive(Debug, PartialEq, Clone)]
+pub struct LimitedDiscoverableTimer {
+    /// Limited Discoverable mode start time
+    pub started_at: Instant,
+
+    /// Limited Discoverable mode duration
+    pub timeout: Duration,
+}
+
+impl LimitedDiscoverableTimer {
+    /// Create [`LimitedDiscoverableTimer`] started at `now` with [`LIMITED_DISCOVERABLE_TIMEOUT`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::time::Instant;
+    /// use ble_data_struct::data_types::limited_discoverable::{
+    ///     LimitedDiscoverableTimer, LIMITED_DISCOVERABLE_TIMEOUT,
+    /// };
+    ///
+    /// let now = Instant::now();
+    /// let result = LimitedDiscoverableTimer::new(now);
+    /// assert_eq!(now, result.started_at);
+    /// assert_eq!(LIMITED_DISCOVERABLE_TIMEOUT, result.timeout);
+    /// ```
+    pub fn new(now: Instant) -> Self {
+        Self {
+            started_at: now,
+            timeout: LIMITED_DISCOVERABLE_TIMEOUT,
+        }
+    }
+
+    /// Check the Limited Discoverable mode window is over at `now`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::time::{Duration, Instant};
+    /// use ble_data_struct::data_types::limited_discoverable::LimitedDiscoverableTimer;
+    ///
+    /// let now = Instant::now();
+    /// let result = LimitedDiscoverableTimer::new(now);
+    /// assert!(!result.is_expired(now + Duration::from_secs(179)));
+    /// assert!(result.is_expired(now + Duration::from_secs(180)));
+    /// ```
+    pub fn is_expired(&self, now: Instant) -> bool {
+        now >= self.started_at + self.timeout
+    }
+
+    /// Remaining time of the Limited Discoverable mode window at `now`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::time::{Duration, Instant};
+    /// use ble_data_struct::data_types::limited_discoverable::LimitedDiscoverableTimer;
+    ///
+    /// let now = Instant::now();
+    /// let result = LimitedDiscoverableTimer::new(now);
+    /// assert_eq!(Duration::from_secs(60), result.remaining(now + Duration::from_secs(120)));
+    /// assert_eq!(Duration::ZERO, result.remaining(now + Duration::from_secs(200)));
+    /// ```
+    pub fn remaining(&self, now: Instant) -> Duration {
+        (self.started_at + self.timeout).saturating_duration_since(now)
+    }
+
+    /// Restart the Limited Discoverable mode window at `now`.
+    pub fn restart(&mut self, now: Instant) {
+        self.started_at = now;
+    }
+
+    /// [`DiscoverableMode::Limited`] in the window, [`DiscoverableMode::NonDiscoverable`] after the window.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::time::{Duration, Instant};
+    /// use ble_data_struct::data_types::{
+    ///     flags::DiscoverableMode, limited_discoverable::LimitedDiscoverableTimer,
+    /// };
+    ///
+    /// let now = Instant::now();
+    /// let result = LimitedDiscoverableTimer::new(now);
+    /// assert_eq!(DiscoverableMode::Limited, result.mode(now));
+    /// assert_eq!(
+    ///     DiscoverableMode::NonDiscoverable,
+    ///     result.mode(now + Duration::from_secs(180))
+    /// );
+    /// ```
+    pub fn mode(&self, now: Instant) -> DiscoverableMode {
+        if self.is_expired(now) {
+            DiscoverableMode::NonDiscoverable
+        } else {
+            DiscoverableMode::Limited
+        }
+    }
+
+    /// Create [`Flags`] for the [`LimitedDiscoverableTimer::mode`] at `now`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::time::{Duration, Instant};
+    /// use ble_data_struct::data_types::limited_discoverable::LimitedDiscoverableTimer;
+    ///
+    /// let now = Instant::now();
+    /// let result = LimitedDiscoverableTimer::new(now);
+    /// assert!(result.flags(now, false).is_le_limited_discoverable_mode());
+    /// assert!(!result
+    ///     .flags(now + Duration::from_secs(180), false)
+    ///     .is_le_limited_discoverable_mode());
+    /// ```
+    pub fn flags(&self, now: Instant, br_edr_supported: bool) -> Flags {
+        Flags::for_mode(self.mode(now), br_edr_supported)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::{Duration, Instant};
+
+    use crate::data_types::{
+        flags::{DiscoverableMode, Flags},
+        limited_discoverable::{LimitedDiscoverableTimer, LIMITED_DISCOVERABLE_TIMEOUT},
+    };
+
+    #[test]
+    fn test_new() {
+        let now = Instant::now();
+        let result = LimitedDiscoverableTimer::new(now);
+        assert_eq!(now, result.started_at);
+        assert_eq!(LIMITED_DISCOVERABLE_TIMEOUT, result.timeout);
+        assert_eq!(Duration::from_secs(180), LIMITED_DISCOVERABLE_TIMEOUT);
+    }
+
+    #[test]
+    fn test_is_expired() {
+        let now = Instant::now();
+        let result = LimitedDiscoverableTimer::new(now);
+        assert!(!result.is_expired(now));
+        assert!(!result.is_expired(now + Duration::from_secs(179)));
+        assert!(result.is_expired(now + Duration::from_secs(180)));
+    }
+
+    #[test]
+    fn test_remaining() {
+        let now = Instant::now();
+        let result = LimitedDiscoverableTimer::new(now);
+        assert_eq!(LIMITED_DISCOVERABLE_TIMEOUT, result.remaining(now));
+        assert_eq!(
+            Duration::from_secs(60),
+            result.remaining(now + Duration::from_secs(120))
+        );
+        assert_eq!(
+            Duration::ZERO,
+            result.remaining(now + Duration::from_secs(200))
+        );
+    }
+
+    #[test]
+    fn test_restart() {
+        let now = Instant::now();
+        let mut result = LimitedDiscoverableTimer::new(now);
+        let later = now + Duration::from_secs(200);
+        assert!(result.is_expired(later));
+        result.restart(later);
+        assert_eq!(later, result.started_at);
+        assert!(!result.is_expired(later));
+    }
+
+    #[test]
+    fn test_mode() {
+        let now = Instant::now();
+        let result = LimitedDiscoverableTimer::new(now);
+        assert_eq!(DiscoverableMode::Limited, result.mode(now));
+        assert_eq!(
+            DiscoverableMode::NonDiscoverable,
+            result.mode(now + Duration::from_secs(180))
+        );
+    }
+
+    #[test]
+    fn test_flags() {
+        let now = Instant::now();
+        let result = LimitedDiscoverableTimer::new(now);
+        assert_eq!(
+            Flags::for_mode(DiscoverableMode::Limited, true),
+            result.flags(now, true)
+        );
+        assert_eq!(
+            Flags::for_mode(DiscoverableMode::NonDiscoverable, false),
+            result.flags(now + Duration::from_secs(180), false)
+        );
+    }
+}
@@ -1,166 +1,11 @@
 //! List of 128-bit Service Solicitation UUIDs (Data Type Value: 0x15) module.
 
-use uuid::Uuid;
-
-use crate::data_types::data_type::DataType;
+use crate::data_types::{
+    ad_type::AdType, service_solicitation_uuid_list::ServiceSolicitationUuidList,
+};
 
 /// List of 128-bit Service Solicitation UUIDs.
-#[derive(Debug, PartialEq, Clone)]
-pub struct ListOf128BitServiceSolicitationUUIDs {
-    /// data length
-    pub length: u8,
-
-    /// UUIDs
-    pub uuids: Vec<Uuid>,
-}
-
-impl ListOf128BitServiceSolicitationUUIDs {
-    /// Create [`ListOf128BitServiceSolicitationUUIDs`] from [`Vec<Uuid>`].
-    ///
-    /// # Examples
-    ///
-    /// ```
-    /// use ble_data_struct::data_types::list_of_128bit_service_solicitation_uuids::ListOf128BitServiceSolicitationUUIDs;
-    /// use uuid::{uuid, Uuid};
-    ///
-    /// let uuids: Vec<Uuid> = [
-    ///     uuid!("00000001-0000-1000-8000-00805F9B34FB"),
-    ///     uuid!("00000002-0000-1000-8000-00805F9B34FB"),
-    /// ]
-    /// .to_vec();
-    /// let result = ListOf128BitServiceSolicitationUUIDs::new(&uuids);
-    /// assert_eq!(uuids.len() as u8 * 16 + 1, result.length);
-    /// assert_eq!(uuids, result.uuids);
-    /// ```
-    pub fn new(uuids: &Vec<Uuid>) -> Self {
-        Self {
-            length: (uuids.len() * 16 + 1) as u8,
-            uuids: uuids.clone(),
-        }
-    }
-}
-
-impl TryFrom<&Vec<u8>> for ListOf128BitServiceSolicitationUUIDs {
-    type Error = String;
-    /// Create [`ListOf128BitServiceSolicitationUUIDs`] from [`Vec<u8>`].
-    ///
-    /// # Examples
-    ///
-    /// ```
-    /// use ble_data_struct::{BASE_UUID, data_types::{list_of_128bit_service_solicitation_uuids::ListOf128BitServiceSolicitationUUIDs, data_type::DataType}};
-    /// use uuid::{uuid, Uuid};
-    ///
-    /// let uuids = [
-    ///     uuid!("00000001-0000-1000-8000-00805F9B34FB"),
-    ///     uuid!("00000002-0000-1000-8000-00805F9B34FB"),
-    /// ]
-    /// .to_vec();
-    /// let mut uuid_bytes: Vec<u8> = Vec::new();
-    /// uuid_bytes.append(&mut uuids[0].as_u128().to_le_bytes().to_vec());
-    /// uuid_bytes.append(&mut uuids[1].as_u128().to_le_bytes().to_vec());
-    ///
-    /// let length = uuid_bytes.len() as u8 + 1;
-    /// let mut data: Vec<u8> = Vec::new();
-    /// data.push(length);
-    /// data.push(ListOf128BitServiceSolicitationUUIDs::data_type());
-    /// data.append(&mut uuid_bytes.clone());
-    ///
-    /// let result = ListOf128BitServiceSolicitationUUIDs::try_from(&data);
-    /// assert!(result.is_ok());
-    /// let data_type = result.unwrap();
-    /// assert_eq!(length, data_type.length);
-    /// assert_eq!(uuids, data_type.uuids);
-    ///
-    /// let mut data: Vec<u8> = vec![0u8; 17];
-    /// data[0] = data.len() as u8 - 1;
-    /// let result = ListOf128BitServiceSolicitationUUIDs::try_from(&data);
-    /// assert!(result.is_err());
-    /// assert_eq!(
-    ///     format!("Invalid data size :{}", data.len()),
-    ///     result.unwrap_err()
-    /// );
-    /// ```
-    fn try_from(value: &Vec<u8>) -> Result<Self, String> {
-        let len = value.len();
-        if len < 18 {
-            return Err(format!("Invalid data size :{}", len).to_string());
-        }
-        let length = value[0];
-        Ok(Self {
-            length,
-            uuids: value[2..2 + length as usize - 1]
-                .windows(16)
-                .step_by(16)
-                .map(|w| Uuid::from_u128(u128::from_le_bytes(w.try_into().unwrap())))
-                .collect(),
-        })
-    }
-}
-
-impl Into<Vec<u8>> for ListOf128BitServiceSolicitationUUIDs {
-    /// Create [`Vec<u8>`] from [`ListOf128BitServiceSolicitationUUIDs`].
-    ///
-    /// # Examples
-    ///
-    /// ```
-    /// use ble_data_struct::{BASE_UUID, data_types::{list_of_128bit_service_solicitation_uuids::ListOf128BitServiceSolicitationUUIDs, data_type::DataType}};
-    /// use uuid::{uuid, Uuid};
-    ///
-    /// let uuids = [
-    ///     uuid!("00000001-0000-1000-8000-00805F9B34FB"),
-    ///     uuid!("00000002-0000-1000-8000-00805F9B34FB"),
-    /// ]
-    /// .to_vec();
-    /// let mut uuid_bytes: Vec<u8> = Vec::new();
-    /// uuid_bytes.append(&mut uuids[0].as_u128().to_le_bytes().to_vec());
-    /// uuid_bytes.append(&mut uuids[1].as_u128().to_le_bytes().to_vec());
-    /// let result1 = ListOf128BitServiceSolicitationUUIDs::new(&uuids);
-    ///
-    /// let length = uuid_bytes.len() as u8 + 1;
-    /// let mut data: Vec<u8> = Vec::new();
-    /// data.push(length);
-    /// data.push(ListOf128BitServiceSolicitationUUIDs::data_type());
-    /// data.append(&mut uuid_bytes.clone());
-    ///
-    /// let into_data: Vec<u8> = result1.into();
-    /// assert_eq!(data, into_data);
-    ///
-    /// let result2 = ListOf128BitServiceSolicitationUUIDs::try_from(&data);
-    /// assert!(result2.is_ok());
-    /// let data_type = result2.unwrap();
-    /// let into_data: Vec<u8> = data_type.into();
-    /// assert_eq!(data, into_data);
-    /// ```
-    fn into(self) -> Vec<u8> {
-        let mut data: Vec<u8> = Vec::new();
-        data.push(self.length);
-        data.push(Self::data_type());
-        data.append(
-            &mut self
-                .uuids
-                .clone()
-                .iter()
-                .flat_map(|f| f.as_u128().to_le_bytes().to_vec())
-                .collect(),
-        );
-        return data;
-    }
-}
-
-impl DataType for ListOf128BitServiceSolicitationUUIDs {
-    /// return `0x15`.
-    ///
-    /// # Examples
-    ///
-    /// ```
-    /// use ble_data_struct::data_types::{list_of_128bit_service_solicitation_uuids::ListOf128BitServiceSolicitationUUIDs, data_type::DataType};
-    ///
-    /// assert_eq!(0x15, ListOf128BitServiceSolicitationUUIDs::data_type());
-    /// ```
-    fn data_type() -> u8 {
-        0x15
-    }
-}
+pub type ListOf128BitServiceSolicitationUUIDs = ServiceSolicitationUuidList<128>;
 
 /// check `List of 128-bit Service Solicitation UUIDs.` data type.
 ///
@@ -173,15 +18,19 @@ impl DataType for ListOf128BitServiceSolicitationUUIDs {
 /// assert!(is_list_of_128bit_service_solicitation_uuids(0x15));
 /// assert!(!is_list_of_128bit_service_solicitation_uuids(0x00));
 /// ```
+#[inline]
 pub fn is_list_of_128bit_service_solicitation_uuids(data_type: u8) -> bool {
-    ListOf128BitServiceSolicitationUUIDs::data_type() == data_type
+    AdType::from_u8(data_type) == Some(AdType::ListOf128BitServiceSolicitationUUIDs)
 }
 
 #[cfg(test)]
 mod tests {
     use uuid::{uuid, Uuid};
 
-    use crate::data_types::{data_type::DataType, list_of_128bit_service_solicitation_uuids::*};
+    use crate::data_types::{
+        data_type::DataType, list_of_128bit_service_solicitation_uuids::*,
+        parse_error::DataTypeParseError,
+    };
 
     #[test]
     fn test_new() {
@@ -223,7 +72,10 @@ mod tests {
         let result = ListOf128BitServiceSolicitationUUIDs::try_from(&data);
         assert!(result.is_err());
         assert_eq!(
-            format!("Invalid data size :{}", data.len()),
+            DataTypeParseError::InvalidLength {
+                expected: 18,
+                actual: data.len()
+            },
             result.unwrap_err()
         );
     }
@@ -0,0 +1,110 @@
+//! Local name display helper module.
+//!
+//! Helpers for [`ShortenedLocalName`](crate::data_types::shortened_local_name::ShortenedLocalName) and
+//! [`CompleteLocalName`](crate::data_types::complete_local_name::CompleteLocalName) parsed from untrusted advertisers.
+
+use unicode_normalization::UnicodeNormalization;
+
+/// check control or bidirectional formatting character.
+fn is_unsafe_character(c: char) -> bool {
+    c.is_control()
+        || matches!(c, '\u{200e}' | '\u{200f}' | '\u{202a}'..='\u{202e}' | '\u{2066}'..='\u{2069}')
+}
+
+/// Normalize local name to NFC.
+///
+/// # Examples
+///
+/// ```
+/// use ble_data_struct::data_types::local_name::normalize;
+///
+/// assert_eq!("\u{e9}", normalize("e\u{301}"));
+/// assert_eq!("name", normalize("name"));
+/// ```
+pub fn normalize(name: &str) -> String {
+    name.nfc().collect()
+}
+
+/// Remove control characters and bidirectional formatting characters.
+///
+/// # Examples
+///
+/// ```
+/// use ble_data_struct::data_types::local_name::strip_control_characters;
+///
+/// assert_eq!("name", strip_control_characters("na\r\nme\u{202e}"));
+/// ```
+pub fn strip_control_characters(name: &str) -> String {
+    name.chars().filter(|c| !is_unsafe_character(*c)).collect()
+}
+
+/// Escape control characters and bidirectional formatting characters as `\u{XXXX}`.
+///
+/// `\` is escaped as `\\`, so the result is not ambiguous.
+///
+/// # Examples
+///
+/// ```
+/// use ble_data_struct::data_types::local_name::escape;
+///
+/// assert_eq!("na\\u{d}\\u{a}me", escape("na\r\nme"));
+/// assert_eq!("na\\\\u{d}me", escape("na\\u{d}me"));
+/// assert_eq!("\u{e9}", escape("\u{e9}"));
+/// ```
+pub fn escape(name: &str) -> String {
+    name.chars()
+        .map(|c| {
+            if is_unsafe_character(c) {
+                c.escape_unicode().to_string()
+            } else if c == '\\' {
+                "\\\\".to_string()
+            } else {
+                c.to_string()
+            }
+        })
+        .collect()
+}
+
+/// Normalize to NFC and remove control characters.
+///
+/// # Examples
+///
+/// ```
+/// use ble_data_struct::data_types::local_name::sanitize;
+///
+/// assert_eq!("\u{e9}name", sanitize("e\u{301}\u{1b}name"));
+/// ```
+pub fn sanitize(name: &str) -> String {
+    strip_control_characters(&normalize(name))
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::data_types::local_name::{escape, normalize, sanitize, strip_control_characters};
+
+    #[test]
+    fn test_normalize() {
+        assert_eq!("\u{e9}", normalize("e\u{301}"));
+        assert_eq!("name", normalize("name"));
+    }
+
+    #[test]
+    fn test_strip_control_characters() {
+        assert_eq!("name", strip_control_characters("na\r\nme\u{202e}"));
+        assert_eq!("name", strip_control_characters("name"));
+    }
+
+    #[test]
+    fn test_escape() {
+        assert_eq!("na\\u{d}\\u{a}me", escape("na\r\nme"));
+        assert_eq!("\\u{202e}", escape("\u{202e}"));
+        assert_eq!("na\\\\u{d}me", escape("na\\u{d}me"));
+        assert_eq!("\\\\\\u{d}", escape("\\\r"));
+        assert_eq!("\u{e9}", escape("\u{e9}"));
+    }
+
+    #[test]
+    fn test_sanitize() {
+        assert_eq!("\u{e9}name", sanitize("e\u{301}\u{1b}name"));
+    }
+}
@@ -0,0 +1,165 @@
+//! Manufacturer data reconciliation module.
+//!
+//! Some platform APIs (e.g. WinRT `BluetoothLEAdvertisement`) expose Manufacturer Specific Data both as raw
+//! data sections and as parsed manufacturer data. [`reconcile_manufacturer_data`] merges both, preferring the data sections.
+
+use crate::data_types::manufacturer_specific_data::ManufacturerSpecificData;
+
+/// Manufacturer data reconciliation warning.
+#[derive(Debug, PartialEq, Clone)]
+pub enum ManufacturerDataWarning {
+    /// Found in data sections only.
+    OnlyInDataSections {
+        /// Company Identifier
+        company_identifier: u16,
+    },
+
+    /// Found in manufacturer data only (added to the result).
+    OnlyInManufacturerData {
+        /// Company Identifier
+        company_identifier: u16,
+    },
+
+    /// Same Company Identifier with different data (data sections value is used).
+    DataMismatch {
+        /// Company Identifier
+        company_identifier: u16,
+    },
+}
+
+/// Manufacturer data reconciliation result.
+#[derive(Debug, PartialEq, Clone)]
+pub struct ReconciledManufacturerData {
+    /// Merged Manufacturer Specific Data
+    pub manufacturer_specific_data: Vec<ManufacturerSpecificData>,
+
+    /// Mismatches found while merging
+    pub warnings: Vec<ManufacturerDataWarning>,
+}
+
+/// Merge Manufacturer Specific Data from data sections and manufacturer data.
+///
+/// `data_sections` entries are kept in order. `manufacturer_data` entries without a counterpart are appended.
+///
+/// # Examples
+///
+/// ```
+/// use ble_data_struct::data_types::{
+///     manufacturer_data_reconciliation::{reconcile_manufacturer_data, ManufacturerDataWarning},
+///     manufacturer_specific_data::ManufacturerSpecificData,
+/// };
+///
+/// let section = ManufacturerSpecificData::new(0x004c, &vec![0x01]);
+/// let other = ManufacturerSpecificData::new(0x0006, &vec![0x02]);
+/// let result = reconcile_manufacturer_data(&[section.clone()], &[section.clone(), other.clone()]);
+/// assert_eq!(vec![section, other], result.manufacturer_specific_data);
+/// assert_eq!(
+///     vec![ManufacturerDataWarning::OnlyInManufacturerData {
+///         company_identifier: 0x0006
+///     }],
+///     result.warnings
+/// );
+/// ```
+pub fn reconcile_manufacturer_data(
+    data_sections: &[ManufacturerSpecificData],
+    manufacturer_data: &[ManufacturerSpecificData],
+) -> ReconciledManufacturerData {
+    let mut manufacturer_specific_data = data_sections.to_vec();
+    let mut warnings: Vec<ManufacturerDataWarning> = Vec::new();
+    let mut matched = vec![false; data_sections.len()];
+    for data in manufacturer_data {
+        let company_identifier = data.company_identifier;
+        let exact = (0..data_sections.len()).find(|i| {
+            !matched[*i]
+                && data_sections[*i].company_identifier == company_identifier
+                && data_sections[*i].manufacturer_specific_data == data.manufacturer_specific_data
+        });
+        let same_company = || {
+            (0..data_sections.len()).find(|i| {
+                !matched[*i] && data_sections[*i].company_identifier == company_identifier
+            })
+        };
+        if let Some(i) = exact {
+            matched[i] = true;
+        } else if let Some(i) = same_company() {
+            matched[i] = true;
+            warnings.push(ManufacturerDataWarning::DataMismatch { company_identifier });
+        } else {
+            manufacturer_specific_data.push(data.clone());
+            warnings.push(ManufacturerDataWarning::OnlyInManufacturerData { company_identifier });
+        }
+    }
+    data_sections
+        .iter()
+        .zip(matched)
+        .filter(|(_, matched)| !matched)
+        .for_each(|(data, _)| {
+            warnings.push(ManufacturerDataWarning::OnlyInDataSections {
+                company_identifier: data.company_identifier,
+            })
+        });
+    ReconciledManufacturerData {
+        manufacturer_specific_data,
+        warnings,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        data_types::manufacturer_data_reconciliation::{
+            reconcile_manufacturer_data, ManufacturerDataWarning,
+        },
+        data_types::manufacturer_specific_data::ManufacturerSpecificData,
+    };
+
+    #[test]
+    fn test_reconcile_manufacturer_data() {
+        let apple1 = ManufacturerSpecificData::new(0x004c, &vec![0x01]);
+        let apple2 = ManufacturerSpecificData::new(0x004c, &vec![0x02]);
+        let microsoft = ManufacturerSpecificData::new(0x0006, &vec![0x03]);
+
+        let result = reconcile_manufacturer_data(
+            &[apple1.clone(), microsoft.clone()],
+            &[microsoft.clone(), apple1.clone()],
+        );
+        assert_eq!(
+            vec![apple1.clone(), microsoft.clone()],
+            result.manufacturer_specific_data
+        );
+        assert!(result.warnings.is_empty());
+
+        let result = reconcile_manufacturer_data(
+            std::slice::from_ref(&apple1),
+            std::slice::from_ref(&apple2),
+        );
+        assert_eq!(vec![apple1.clone()], result.manufacturer_specific_data);
+        assert_eq!(
+            vec![ManufacturerDataWarning::DataMismatch {
+                company_identifier: 0x004c
+            }],
+            result.warnings
+        );
+
+        let result = reconcile_manufacturer_data(&[apple1.clone(), microsoft.clone()], &[]);
+        assert_eq!(
+            vec![apple1.clone(), microsoft.clone()],
+            result.manufacturer_specific_data
+        );
+        assert_eq!(
+            vec![
+                ManufacturerDataWarning::OnlyInDataSections {
+                    company_identifier: 0x004c
+                },
+                ManufacturerDataWarning::OnlyInDataSections {
+                    company_identifier: 0x0006
+                },
+            ],
+            result.warnings
+        );
+
+        let result = reconcile_manufacturer_data(&[], &[apple1.clone(), apple2.clone()]);
+        assert_eq!(vec![apple1, apple2], result.manufacturer_specific_data);
+        assert_eq!(2, result.warnings.len());
+    }
+}
@@ -1,10 +1,14 @@
 //! Manufacturer Specific Data (Data Type Value: 0xff) module.
 
-use crate::data_types::data_type::DataType;
+use crate::data_types::{
+    ad_type::AdType, data_type::DataType, data_type_ref::DataTypeRef,
+    parse_error::DataTypeParseError,
+};
 
 /// Manufacturer Specific Data.
 
 #[derive(Debug, PartialEq, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct ManufacturerSpecificData {
     /// data length
     pub length: u8,
@@ -43,14 +47,23 @@ impl ManufacturerSpecificData {
     }
 }
 
+impl TryFrom<&[u8]> for ManufacturerSpecificData {
+    type Error = DataTypeParseError;
+    /// Create [`ManufacturerSpecificData`] from `&[u8]`.
+    fn try_from(value: &[u8]) -> Result<Self, DataTypeParseError> {
+        ManufacturerSpecificDataRef::try_from(value).map(|f| Self::from(&f))
+    }
+}
+
 impl TryFrom<&Vec<u8>> for ManufacturerSpecificData {
-    type Error = String;
+    type Error = DataTypeParseError;
     /// Create [`ManufacturerSpecificData`] from [`Vec<u8>`].
     ///
     /// # Examples
     ///
     /// ```
     /// use ble_data_struct::data_types::{manufacturer_specific_data::ManufacturerSpecificData, data_type::DataType};
+    /// use ble_data_struct::data_types::parse_error::DataTypeParseError;
     ///
     /// let company_identifier = 0x0ca8u16;
     /// let manufacturer_specific_data = [0x03u8].to_vec();
@@ -75,24 +88,129 @@ impl TryFrom<&Vec<u8>> for ManufacturerSpecificData {
     /// let result = ManufacturerSpecificData::try_from(&data);
     /// assert!(result.is_err());
     /// assert_eq!(
-    ///     format!("Invalid data size :{}", data.len()),
+    ///     DataTypeParseError::InvalidLength {
+    ///         expected: 4,
+    ///         actual: data.len()
+    ///     },
+    ///     result.unwrap_err()
+    /// );
+    /// ```
+    fn try_from(value: &Vec<u8>) -> Result<Self, DataTypeParseError> {
+        Self::try_from(value.as_slice())
+    }
+}
+
+/// Borrowed [`ManufacturerSpecificData`].
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub struct ManufacturerSpecificDataRef<'a> {
+    /// data length
+    pub length: u8,
+
+    /// Company Identifier Code
+    pub company_identifier: u16,
+
+    /// Manufacturer Specific Data
+    pub manufacturer_specific_data: &'a [u8],
+}
+
+impl<'a> TryFrom<&'a [u8]> for ManufacturerSpecificDataRef<'a> {
+    type Error = DataTypeParseError;
+    /// Create [`ManufacturerSpecificDataRef`] from `&[u8]` without allocation.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ble_data_struct::data_types::manufacturer_specific_data::{ManufacturerSpecificData, ManufacturerSpecificDataRef};
+    /// use ble_data_struct::data_types::parse_error::DataTypeParseError;
+    ///
+    /// let data = [0x04, 0xff, 0xa8, 0x0c, 0x03, 0x00];
+    /// let result = ManufacturerSpecificDataRef::try_from(&data[..]).unwrap();
+    /// assert_eq!(data[0], result.length);
+    /// assert_eq!(0x0ca8, result.company_identifier);
+    /// assert_eq!(&[0x03], result.manufacturer_specific_data);
+    ///
+    /// let result = ManufacturerSpecificDataRef::try_from(&data[..3]);
+    /// assert_eq!(
+    ///     DataTypeParseError::InvalidLength {
+    ///         expected: 4,
+    ///         actual: 3
+    ///     },
     ///     result.unwrap_err()
     /// );
     /// ```
-    fn try_from(value: &Vec<u8>) -> Result<Self, String> {
+    fn try_from(value: &'a [u8]) -> Result<Self, DataTypeParseError> {
         let len = value.len();
         if len < 4 {
-            return Err(format!("Invalid data size :{}", len).to_string());
+            return Err(DataTypeParseError::InvalidLength {
+                expected: 4,
+                actual: len,
+            });
         }
         let length = value[0];
         Ok(Self {
             length,
             company_identifier: u16::from_le_bytes(value[2..4].try_into().unwrap()),
-            manufacturer_specific_data: value[4..1 + length as usize].to_vec(),
+            manufacturer_specific_data: &value[4..1 + length as usize],
         })
     }
 }
 
+impl<'a> TryFrom<&DataTypeRef<'a>> for ManufacturerSpecificDataRef<'a> {
+    type Error = DataTypeParseError;
+    /// Create [`ManufacturerSpecificDataRef`] from [`DataTypeRef`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ble_data_struct::data_types::{data_type_ref::DataTypeRef, manufacturer_specific_data::ManufacturerSpecificDataRef};
+    /// use ble_data_struct::data_types::parse_error::DataTypeParseError;
+    ///
+    /// let data = [0x04, 0xff, 0xa8, 0x0c, 0x03, 0x00];
+    /// let result = ManufacturerSpecificDataRef::try_from(&DataTypeRef::try_from(&data[..]).unwrap());
+    /// assert!(result.is_ok());
+    ///
+    /// let data = [0x02, 0x16, 0x00];
+    /// let result = ManufacturerSpecificDataRef::try_from(&DataTypeRef::try_from(&data[..]).unwrap());
+    /// assert_eq!(
+    ///     DataTypeParseError::WrongDataType {
+    ///         expected: 0xff,
+    ///         actual: 0x16
+    ///     },
+    ///     result.unwrap_err()
+    /// );
+    /// ```
+    fn try_from(value: &DataTypeRef<'a>) -> Result<Self, DataTypeParseError> {
+        if value.data_type != ManufacturerSpecificData::data_type() {
+            return Err(DataTypeParseError::WrongDataType {
+                expected: ManufacturerSpecificData::data_type(),
+                actual: value.data_type,
+            });
+        }
+        Self::try_from(value.as_bytes())
+    }
+}
+
+impl From<&ManufacturerSpecificDataRef<'_>> for ManufacturerSpecificData {
+    /// Create [`ManufacturerSpecificData`] from [`ManufacturerSpecificDataRef`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ble_data_struct::data_types::manufacturer_specific_data::{ManufacturerSpecificData, ManufacturerSpecificDataRef};
+    ///
+    /// let data = [0x04, 0xff, 0xa8, 0x0c, 0x03, 0x00];
+    /// let result = ManufacturerSpecificData::from(&ManufacturerSpecificDataRef::try_from(&data[..]).unwrap());
+    /// assert_eq!(ManufacturerSpecificData::new(0x0ca8, &vec![0x03]), result);
+    /// ```
+    fn from(value: &ManufacturerSpecificDataRef<'_>) -> Self {
+        Self {
+            length: value.length,
+            company_identifier: value.company_identifier,
+            manufacturer_specific_data: value.manufacturer_specific_data.to_vec(),
+        }
+    }
+}
+
 impl Into<Vec<u8>> for ManufacturerSpecificData {
     /// Create [`Vec<u8>`] from [`ManufacturerSpecificData`].
     ///
@@ -142,6 +260,7 @@ impl DataType for ManufacturerSpecificData {
     ///
     /// assert_eq!(0xff, ManufacturerSpecificData::data_type());
     /// ```
+    #[inline]
     fn data_type() -> u8 {
         0xff
     }
@@ -158,14 +277,18 @@ impl DataType for ManufacturerSpecificData {
 /// assert!(is_manufacturer_specific_data(0xff));
 /// assert!(!is_manufacturer_specific_data(0x00));
 /// ```
+#[inline]
 pub fn is_manufacturer_specific_data(data_type: u8) -> bool {
-    ManufacturerSpecificData::data_type() == data_type
+    AdType::from_u8(data_type) == Some(AdType::ManufacturerSpecificData)
 }
 
 #[cfg(test)]
 mod tests {
 
-    use crate::data_types::{data_type::DataType, manufacturer_specific_data::*};
+    use crate::data_types::{
+        data_type::DataType, data_type_ref::DataTypeRef, manufacturer_specific_data::*,
+        parse_error::DataTypeParseError,
+    };
 
     #[test]
     fn test_new() {
@@ -206,7 +329,10 @@ mod tests {
         let result = ManufacturerSpecificData::try_from(&data);
         assert!(result.is_err());
         assert_eq!(
-            format!("Invalid data size :{}", data.len()),
+            DataTypeParseError::InvalidLength {
+                expected: 4,
+                actual: data.len()
+            },
             result.unwrap_err()
         );
     }
@@ -245,4 +371,55 @@ mod tests {
         assert!(is_manufacturer_specific_data(0xff));
         assert!(!is_manufacturer_specific_data(0x00));
     }
+
+    #[test]
+    fn test_ref_try_from() {
+        let data = [0x04, 0xff, 0xa8, 0x0c, 0x03, 0x00];
+        let result = ManufacturerSpecificDataRef::try_from(&data[..]).unwrap();
+        assert_eq!(data[0], result.length);
+        assert_eq!(0x0ca8, result.company_identifier);
+        assert_eq!(&[0x03], result.manufacturer_specific_data);
+
+        let result = ManufacturerSpecificDataRef::try_from(&data[..3]);
+        assert_eq!(
+            DataTypeParseError::InvalidLength {
+                expected: 4,
+                actual: 3
+            },
+            result.unwrap_err()
+        );
+    }
+
+    #[test]
+    fn test_ref_try_from_data_type_ref() {
+        let data = [0x04, 0xff, 0xa8, 0x0c, 0x03, 0x00];
+        let data_type_ref = DataTypeRef::try_from(&data[..]).unwrap();
+        assert_eq!(
+            ManufacturerSpecificDataRef::try_from(&data[..]),
+            ManufacturerSpecificDataRef::try_from(&data_type_ref)
+        );
+
+        let data = [0x02, 0x16, 0x00];
+        let data_type_ref = DataTypeRef::try_from(&data[..]).unwrap();
+        assert_eq!(
+            DataTypeParseError::WrongDataType {
+                expected: 0xff,
+                actual: 0x16
+            },
+            ManufacturerSpecificDataRef::try_from(&data_type_ref).unwrap_err()
+        );
+    }
+
+    #[test]
+    fn test_from_ref() {
+        let data = [0x04, 0xff, 0xa8, 0x0c, 0x03, 0x00];
+        let result = ManufacturerSpecificData::from(
+            &ManufacturerSpecificDataRef::try_from(&data[..]).unwrap(),
+        );
+        assert_eq!(ManufacturerSpecificData::new(0x0ca8, &vec![0x03]), result);
+        assert_eq!(
+            Ok(result),
+            ManufacturerSpecificData::try_from(&data.to_vec())
+        );
+    }
 }
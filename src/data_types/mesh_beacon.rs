@@ -0,0 +1,351 @@
+//! Mesh Beacon (Data Type Value: 0x2b) module.
+
+use crate::data_types::{ad_type::AdType, data_type::DataType, parse_error::DataTypeParseError};
+
+/// Unprovisioned Device beacon.
+pub const UNPROVISIONED_DEVICE_BEACON: u8 = 0x00;
+
+/// Secure Network beacon.
+pub const SECURE_NETWORK_BEACON: u8 = 0x01;
+
+/// Mesh Private beacon.
+pub const MESH_PRIVATE_BEACON: u8 = 0x02;
+
+/// Mesh Beacon.
+#[derive(Debug, PartialEq, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct MeshBeacon {
+    /// data length
+    pub length: u8,
+
+    /// Beacon Type
+    pub beacon_type: u8,
+
+    /// Beacon Data
+    pub beacon_data: Vec<u8>,
+}
+
+impl MeshBeacon {
+    /// Create [`MeshBeacon`] from `Beacon Type` and `Beacon Data`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ble_data_struct::data_types::mesh_beacon::{MeshBeacon, SECURE_NETWORK_BEACON};
+    ///
+    /// let beacon_data = [0x00u8, 0x01u8, 0x02u8].to_vec();
+    /// let result = MeshBeacon::new(SECURE_NETWORK_BEACON, &beacon_data);
+    /// assert_eq!(beacon_data.len() as u8 + 2, result.length);
+    /// assert_eq!(SECURE_NETWORK_BEACON, result.beacon_type);
+    /// assert_eq!(beacon_data, result.beacon_data);
+    /// ```
+    pub fn new(beacon_type: u8, beacon_data: &Vec<u8>) -> Self {
+        Self {
+            length: 2 + beacon_data.len() as u8,
+            beacon_type,
+            beacon_data: beacon_data.clone(),
+        }
+    }
+
+    /// check Unprovisioned Device beacon.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ble_data_struct::data_types::mesh_beacon::{
+    ///     MeshBeacon, SECURE_NETWORK_BEACON, UNPROVISIONED_DEVICE_BEACON,
+    /// };
+    ///
+    /// assert!(MeshBeacon::new(UNPROVISIONED_DEVICE_BEACON, &Vec::new()).is_unprovisioned_device_beacon());
+    /// assert!(!MeshBeacon::new(SECURE_NETWORK_BEACON, &Vec::new()).is_unprovisioned_device_beacon());
+    /// ```
+    pub fn is_unprovisioned_device_beacon(&self) -> bool {
+        self.beacon_type == UNPROVISIONED_DEVICE_BEACON
+    }
+
+    /// check Secure Network beacon.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ble_data_struct::data_types::mesh_beacon::{
+    ///     MeshBeacon, SECURE_NETWORK_BEACON, UNPROVISIONED_DEVICE_BEACON,
+    /// };
+    ///
+    /// assert!(MeshBeacon::new(SECURE_NETWORK_BEACON, &Vec::new()).is_secure_network_beacon());
+    /// assert!(!MeshBeacon::new(UNPROVISIONED_DEVICE_BEACON, &Vec::new()).is_secure_network_beacon());
+    /// ```
+    pub fn is_secure_network_beacon(&self) -> bool {
+        self.beacon_type == SECURE_NETWORK_BEACON
+    }
+
+    /// check Mesh Private beacon.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ble_data_struct::data_types::mesh_beacon::{
+    ///     MeshBeacon, MESH_PRIVATE_BEACON, SECURE_NETWORK_BEACON,
+    /// };
+    ///
+    /// assert!(MeshBeacon::new(MESH_PRIVATE_BEACON, &Vec::new()).is_mesh_private_beacon());
+    /// assert!(!MeshBeacon::new(SECURE_NETWORK_BEACON, &Vec::new()).is_mesh_private_beacon());
+    /// ```
+    pub fn is_mesh_private_beacon(&self) -> bool {
+        self.beacon_type == MESH_PRIVATE_BEACON
+    }
+}
+
+impl TryFrom<&[u8]> for MeshBeacon {
+    type Error = DataTypeParseError;
+    /// Create [`MeshBeacon`] from `&[u8]`.
+    fn try_from(value: &[u8]) -> Result<Self, DataTypeParseError> {
+        let len = value.len();
+        if len < 3 {
+            return Err(DataTypeParseError::InvalidLength {
+                expected: 3,
+                actual: len,
+            });
+        }
+        let length = value[0];
+        if length < 2 {
+            return Err(DataTypeParseError::ValueOutOfRange {
+                field: "length",
+                value: length as u64,
+            });
+        }
+        if len < 1 + length as usize {
+            return Err(DataTypeParseError::InvalidLength {
+                expected: 1 + length as usize,
+                actual: len,
+            });
+        }
+        Ok(Self {
+            length,
+            beacon_type: value[2],
+            beacon_data: value[3..1 + length as usize].to_vec(),
+        })
+    }
+}
+
+impl TryFrom<&Vec<u8>> for MeshBeacon {
+    type Error = DataTypeParseError;
+    /// Create [`MeshBeacon`] from [`Vec<u8>`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ble_data_struct::data_types::{
+    ///     data_type::DataType,
+    ///     mesh_beacon::{MeshBeacon, SECURE_NETWORK_BEACON},
+    /// };
+    /// use ble_data_struct::data_types::parse_error::DataTypeParseError;
+    ///
+    /// let beacon_data = [0x00u8, 0x01u8, 0x02u8].to_vec();
+    /// let length = beacon_data.len() as u8 + 2;
+    /// let mut data: Vec<u8> = Vec::new();
+    /// data.push(length);
+    /// data.push(MeshBeacon::data_type());
+    /// data.push(SECURE_NETWORK_BEACON);
+    /// data.append(&mut beacon_data.clone());
+    ///
+    /// let result = MeshBeacon::try_from(&data);
+    /// assert!(result.is_ok());
+    /// let data_type = result.unwrap();
+    /// assert_eq!(length, data_type.length);
+    /// assert_eq!(SECURE_NETWORK_BEACON, data_type.beacon_type);
+    /// assert_eq!(beacon_data, data_type.beacon_data);
+    ///
+    /// let data: Vec<u8> = Vec::new();
+    /// let result = MeshBeacon::try_from(&data);
+    /// assert!(result.is_err());
+    /// assert_eq!(
+    ///     DataTypeParseError::InvalidLength {
+    ///         expected: 3,
+    ///         actual: data.len()
+    ///     },
+    ///     result.unwrap_err()
+    /// );
+    /// ```
+    fn try_from(value: &Vec<u8>) -> Result<Self, DataTypeParseError> {
+        Self::try_from(value.as_slice())
+    }
+}
+
+impl Into<Vec<u8>> for MeshBeacon {
+    /// Create [`Vec<u8>`] from [`MeshBeacon`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ble_data_struct::data_types::{
+    ///     data_type::DataType,
+    ///     mesh_beacon::{MeshBeacon, SECURE_NETWORK_BEACON},
+    /// };
+    ///
+    /// let beacon_data = [0x00u8, 0x01u8, 0x02u8].to_vec();
+    /// let result1 = MeshBeacon::new(SECURE_NETWORK_BEACON, &beacon_data);
+    ///
+    /// let mut data: Vec<u8> = Vec::new();
+    /// data.push(beacon_data.len() as u8 + 2);
+    /// data.push(MeshBeacon::data_type());
+    /// data.push(SECURE_NETWORK_BEACON);
+    /// data.append(&mut beacon_data.clone());
+    ///
+    /// let into_data: Vec<u8> = result1.into();
+    /// assert_eq!(data, into_data);
+    ///
+    /// let result2 = MeshBeacon::try_from(&data);
+    /// assert!(result2.is_ok());
+    /// let data_type = result2.unwrap();
+    /// let into_data: Vec<u8> = data_type.into();
+    /// assert_eq!(data, into_data);
+    /// ```
+    fn into(self) -> Vec<u8> {
+        let mut data: Vec<u8> = Vec::new();
+        data.push(self.length);
+        data.push(Self::data_type());
+        data.push(self.beacon_type);
+        data.append(&mut self.beacon_data.clone());
+        return data;
+    }
+}
+
+impl DataType for MeshBeacon {
+    /// return `0x2b`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ble_data_struct::data_types::{data_type::DataType, mesh_beacon::MeshBeacon};
+    ///
+    /// assert_eq!(0x2b, MeshBeacon::data_type());
+    /// ```
+    #[inline]
+    fn data_type() -> u8 {
+        0x2b
+    }
+}
+
+/// check `Mesh Beacon` data type.
+///
+/// # Examples
+///
+/// ```
+/// use ble_data_struct::data_types::mesh_beacon::*;
+/// use ble_data_struct::data_types::data_type::DataType;
+///
+/// assert!(is_mesh_beacon(0x2b));
+/// assert!(!is_mesh_beacon(0x00));
+/// ```
+#[inline]
+pub fn is_mesh_beacon(data_type: u8) -> bool {
+    AdType::from_u8(data_type) == Some(AdType::MeshBeacon)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::data_types::{data_type::DataType, mesh_beacon::*};
+
+    #[test]
+    fn test_new() {
+        let beacon_data = [0x00u8, 0x01u8, 0x02u8].to_vec();
+        let result = MeshBeacon::new(SECURE_NETWORK_BEACON, &beacon_data);
+        assert_eq!(beacon_data.len() as u8 + 2, result.length);
+        assert_eq!(SECURE_NETWORK_BEACON, result.beacon_type);
+        assert_eq!(beacon_data, result.beacon_data);
+    }
+
+    #[test]
+    fn test_is_unprovisioned_device_beacon() {
+        assert!(MeshBeacon::new(UNPROVISIONED_DEVICE_BEACON, &Vec::new())
+            .is_unprovisioned_device_beacon());
+        assert!(
+            !MeshBeacon::new(SECURE_NETWORK_BEACON, &Vec::new()).is_unprovisioned_device_beacon()
+        );
+    }
+
+    #[test]
+    fn test_is_secure_network_beacon() {
+        assert!(MeshBeacon::new(SECURE_NETWORK_BEACON, &Vec::new()).is_secure_network_beacon());
+        assert!(!MeshBeacon::new(MESH_PRIVATE_BEACON, &Vec::new()).is_secure_network_beacon());
+    }
+
+    #[test]
+    fn test_is_mesh_private_beacon() {
+        assert!(MeshBeacon::new(MESH_PRIVATE_BEACON, &Vec::new()).is_mesh_private_beacon());
+        assert!(!MeshBeacon::new(UNPROVISIONED_DEVICE_BEACON, &Vec::new()).is_mesh_private_beacon());
+    }
+
+    #[test]
+    fn test_try_from() {
+        let beacon_data = [0x00u8, 0x01u8, 0x02u8].to_vec();
+        let length = beacon_data.len() as u8 + 2;
+        let mut data: Vec<u8> = Vec::new();
+        data.push(length);
+        data.push(MeshBeacon::data_type());
+        data.push(UNPROVISIONED_DEVICE_BEACON);
+        data.append(&mut beacon_data.clone());
+
+        let result = MeshBeacon::try_from(&data);
+        assert!(result.is_ok());
+        let data_type = result.unwrap();
+        assert_eq!(length, data_type.length);
+        assert_eq!(UNPROVISIONED_DEVICE_BEACON, data_type.beacon_type);
+        assert_eq!(beacon_data, data_type.beacon_data);
+
+        let data: Vec<u8> = vec![1, MeshBeacon::data_type()];
+        let result = MeshBeacon::try_from(&data);
+        assert!(result.is_err());
+        assert_eq!(
+            DataTypeParseError::InvalidLength {
+                expected: 3,
+                actual: data.len()
+            },
+            result.unwrap_err()
+        );
+
+        let data: Vec<u8> = vec![5, MeshBeacon::data_type(), SECURE_NETWORK_BEACON];
+        let result = MeshBeacon::try_from(&data);
+        assert!(result.is_err());
+        assert_eq!(
+            DataTypeParseError::InvalidLength {
+                expected: 6,
+                actual: data.len()
+            },
+            result.unwrap_err()
+        );
+    }
+
+    #[test]
+    fn test_into() {
+        let beacon_data = [0x00u8, 0x01u8, 0x02u8].to_vec();
+        let result1 = MeshBeacon::new(MESH_PRIVATE_BEACON, &beacon_data);
+
+        let mut data: Vec<u8> = Vec::new();
+        data.push(beacon_data.len() as u8 + 2);
+        data.push(MeshBeacon::data_type());
+        data.push(MESH_PRIVATE_BEACON);
+        data.append(&mut beacon_data.clone());
+
+        let into_data: Vec<u8> = result1.into();
+        assert_eq!(data, into_data);
+
+        let result2 = MeshBeacon::try_from(&data);
+        assert!(result2.is_ok());
+        let data_type = result2.unwrap();
+        let into_data: Vec<u8> = data_type.into();
+        assert_eq!(data, into_data);
+    }
+
+    #[test]
+    fn test_data_type() {
+        assert_eq!(0x2b, MeshBeacon::data_type());
+    }
+
+    #[test]
+    fn test_is_mesh_beacon() {
+        assert!(is_mesh_beacon(0x2b));
+        assert!(!is_mesh_beacon(0x00));
+    }
+}
@@ -0,0 +1,340 @@
+//! Mesh Message (Data Type Value: 0x2a) module.
+
+use crate::data_types::{
+    ad_type::AdType, data_type::DataType, parse_error::DataTypeParseError,
+    raw_ad_structure::MAX_DATA_LENGTH,
+};
+
+/// Mesh Message.
+#[derive(Debug, PartialEq, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct MeshMessage {
+    /// data length
+    pub length: u8,
+
+    /// Network PDU
+    pub network_pdu: Vec<u8>,
+}
+
+impl MeshMessage {
+    /// Create [`MeshMessage`] from `Network PDU`.
+    ///
+    /// `network_pdu` longer than [`MAX_DATA_LENGTH`] does not fit in the length byte.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ble_data_struct::data_types::mesh_message::MeshMessage;
+    /// use ble_data_struct::data_types::parse_error::DataTypeParseError;
+    ///
+    /// let network_pdu = [0x81u8, 0x02u8, 0x03u8, 0x04u8].to_vec();
+    /// let result = MeshMessage::new(&network_pdu).unwrap();
+    /// assert_eq!(network_pdu.len() as u8 + 1, result.length);
+    /// assert_eq!(network_pdu, result.network_pdu);
+    ///
+    /// let network_pdu = [0x00u8; 255];
+    /// assert_eq!(
+    ///     Err(DataTypeParseError::InvalidLength {
+    ///         expected: 254,
+    ///         actual: 255
+    ///     }),
+    ///     MeshMessage::new(&network_pdu)
+    /// );
+    /// ```
+    pub fn new(network_pdu: &[u8]) -> Result<Self, DataTypeParseError> {
+        if network_pdu.len() > MAX_DATA_LENGTH {
+            return Err(DataTypeParseError::InvalidLength {
+                expected: MAX_DATA_LENGTH,
+                actual: network_pdu.len(),
+            });
+        }
+        Ok(Self {
+            length: 1 + network_pdu.len() as u8,
+            network_pdu: network_pdu.to_vec(),
+        })
+    }
+
+    /// Get IVI (least significant bit of IV Index).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ble_data_struct::data_types::mesh_message::MeshMessage;
+    ///
+    /// assert_eq!(Some(1), MeshMessage::new(&[0x81u8]).unwrap().ivi());
+    /// assert_eq!(Some(0), MeshMessage::new(&[0x01u8]).unwrap().ivi());
+    /// assert_eq!(None, MeshMessage::new(&[]).unwrap().ivi());
+    /// ```
+    pub fn ivi(&self) -> Option<u8> {
+        self.network_pdu.first().map(|f| f >> 7)
+    }
+
+    /// Get NID (Network ID).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ble_data_struct::data_types::mesh_message::MeshMessage;
+    ///
+    /// assert_eq!(Some(0x01), MeshMessage::new(&[0x81u8]).unwrap().nid());
+    /// assert_eq!(None, MeshMessage::new(&[]).unwrap().nid());
+    /// ```
+    pub fn nid(&self) -> Option<u8> {
+        self.network_pdu.first().map(|f| f & 0b01111111)
+    }
+}
+
+impl TryFrom<&[u8]> for MeshMessage {
+    type Error = DataTypeParseError;
+    /// Create [`MeshMessage`] from `&[u8]`.
+    fn try_from(value: &[u8]) -> Result<Self, DataTypeParseError> {
+        let len = value.len();
+        if len < 2 {
+            return Err(DataTypeParseError::InvalidLength {
+                expected: 2,
+                actual: len,
+            });
+        }
+        let length = value[0];
+        if length < 1 {
+            return Err(DataTypeParseError::ValueOutOfRange {
+                field: "length",
+                value: length as u64,
+            });
+        }
+        if len < 1 + length as usize {
+            return Err(DataTypeParseError::InvalidLength {
+                expected: 1 + length as usize,
+                actual: len,
+            });
+        }
+        Ok(Self {
+            length,
+            network_pdu: value[2..1 + length as usize].to_vec(),
+        })
+    }
+}
+
+impl TryFrom<&Vec<u8>> for MeshMessage {
+    type Error = DataTypeParseError;
+    /// Create [`MeshMessage`] from [`Vec<u8>`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ble_data_struct::data_types::{data_type::DataType, mesh_message::MeshMessage};
+    /// use ble_data_struct::data_types::parse_error::DataTypeParseError;
+    ///
+    /// let network_pdu = [0x81u8, 0x02u8, 0x03u8, 0x04u8].to_vec();
+    /// let length = network_pdu.len() as u8 + 1;
+    /// let mut data: Vec<u8> = Vec::new();
+    /// data.push(length);
+    /// data.push(MeshMessage::data_type());
+    /// data.append(&mut network_pdu.clone());
+    ///
+    /// let result = MeshMessage::try_from(&data);
+    /// assert!(result.is_ok());
+    /// let data_type = result.unwrap();
+    /// assert_eq!(length, data_type.length);
+    /// assert_eq!(network_pdu, data_type.network_pdu);
+    ///
+    /// let data: Vec<u8> = Vec::new();
+    /// let result = MeshMessage::try_from(&data);
+    /// assert!(result.is_err());
+    /// assert_eq!(
+    ///     DataTypeParseError::InvalidLength {
+    ///         expected: 2,
+    ///         actual: data.len()
+    ///     },
+    ///     result.unwrap_err()
+    /// );
+    /// ```
+    fn try_from(value: &Vec<u8>) -> Result<Self, DataTypeParseError> {
+        Self::try_from(value.as_slice())
+    }
+}
+
+impl Into<Vec<u8>> for MeshMessage {
+    /// Create [`Vec<u8>`] from [`MeshMessage`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ble_data_struct::data_types::{data_type::DataType, mesh_message::MeshMessage};
+    ///
+    /// let network_pdu = [0x81u8, 0x02u8, 0x03u8, 0x04u8].to_vec();
+    /// let result1 = MeshMessage::new(&network_pdu).unwrap();
+    ///
+    /// let mut data: Vec<u8> = Vec::new();
+    /// data.push(network_pdu.len() as u8 + 1);
+    /// data.push(MeshMessage::data_type());
+    /// data.append(&mut network_pdu.clone());
+    ///
+    /// let into_data: Vec<u8> = result1.into();
+    /// assert_eq!(data, into_data);
+    ///
+    /// let result2 = MeshMessage::try_from(&data);
+    /// assert!(result2.is_ok());
+    /// let data_type = result2.unwrap();
+    /// let into_data: Vec<u8> = data_type.into();
+    /// assert_eq!(data, into_data);
+    /// ```
+    fn into(self) -> Vec<u8> {
+        let mut data: Vec<u8> = Vec::new();
+        data.push(self.length);
+        data.push(Self::data_type());
+        data.append(&mut self.network_pdu.clone());
+        return data;
+    }
+}
+
+impl DataType for MeshMessage {
+    /// return `0x2a`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ble_data_struct::data_types::{data_type::DataType, mesh_message::MeshMessage};
+    ///
+    /// assert_eq!(0x2a, MeshMessage::data_type());
+    /// ```
+    #[inline]
+    fn data_type() -> u8 {
+        0x2a
+    }
+}
+
+/// check `Mesh Message` data type.
+///
+/// # Examples
+///
+/// ```
+/// use ble_data_struct::data_types::mesh_message::*;
+/// use ble_data_struct::data_types::data_type::DataType;
+///
+/// assert!(is_mesh_message(0x2a));
+/// assert!(!is_mesh_message(0x00));
+/// ```
+#[inline]
+pub fn is_mesh_message(data_type: u8) -> bool {
+    AdType::from_u8(data_type) == Some(AdType::MeshMessage)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::data_types::{data_type::DataType, mesh_message::*};
+
+    #[test]
+    fn test_new() {
+        let network_pdu = [0x81u8, 0x02u8, 0x03u8, 0x04u8].to_vec();
+        let result = MeshMessage::new(&network_pdu).unwrap();
+        assert_eq!(network_pdu.len() as u8 + 1, result.length);
+        assert_eq!(network_pdu, result.network_pdu);
+
+        let network_pdu = [0x00u8; MAX_DATA_LENGTH];
+        let result = MeshMessage::new(&network_pdu).unwrap();
+        assert_eq!(0xff, result.length);
+
+        let network_pdu = [0x00u8; MAX_DATA_LENGTH + 1];
+        assert_eq!(
+            Err(DataTypeParseError::InvalidLength {
+                expected: MAX_DATA_LENGTH,
+                actual: MAX_DATA_LENGTH + 1
+            }),
+            MeshMessage::new(&network_pdu)
+        );
+    }
+
+    #[test]
+    fn test_ivi() {
+        assert_eq!(Some(1), MeshMessage::new(&[0x81u8]).unwrap().ivi());
+        assert_eq!(Some(0), MeshMessage::new(&[0x7fu8]).unwrap().ivi());
+        assert_eq!(None, MeshMessage::new(&[]).unwrap().ivi());
+    }
+
+    #[test]
+    fn test_nid() {
+        assert_eq!(Some(0x01), MeshMessage::new(&[0x81u8]).unwrap().nid());
+        assert_eq!(Some(0x7f), MeshMessage::new(&[0x7fu8]).unwrap().nid());
+        assert_eq!(None, MeshMessage::new(&[]).unwrap().nid());
+    }
+
+    #[test]
+    fn test_try_from() {
+        let network_pdu = [0x81u8, 0x02u8, 0x03u8, 0x04u8].to_vec();
+        let length = network_pdu.len() as u8 + 1;
+        let mut data: Vec<u8> = Vec::new();
+        data.push(length);
+        data.push(MeshMessage::data_type());
+        data.append(&mut network_pdu.clone());
+
+        let result = MeshMessage::try_from(&data);
+        assert!(result.is_ok());
+        let data_type = result.unwrap();
+        assert_eq!(length, data_type.length);
+        assert_eq!(network_pdu, data_type.network_pdu);
+
+        let data: Vec<u8> = Vec::new();
+        let result = MeshMessage::try_from(&data);
+        assert!(result.is_err());
+        assert_eq!(
+            DataTypeParseError::InvalidLength {
+                expected: 2,
+                actual: data.len()
+            },
+            result.unwrap_err()
+        );
+
+        let data = [0x00, MeshMessage::data_type()];
+        let result = MeshMessage::try_from(&data[..]);
+        assert_eq!(
+            Err(DataTypeParseError::ValueOutOfRange {
+                field: "length",
+                value: 0
+            }),
+            result
+        );
+
+        let data: Vec<u8> = vec![5, MeshMessage::data_type(), 0x81];
+        let result = MeshMessage::try_from(&data);
+        assert!(result.is_err());
+        assert_eq!(
+            DataTypeParseError::InvalidLength {
+                expected: 6,
+                actual: data.len()
+            },
+            result.unwrap_err()
+        );
+    }
+
+    #[test]
+    fn test_into() {
+        let network_pdu = [0x81u8, 0x02u8, 0x03u8, 0x04u8].to_vec();
+        let result1 = MeshMessage::new(&network_pdu).unwrap();
+
+        let mut data: Vec<u8> = Vec::new();
+        data.push(network_pdu.len() as u8 + 1);
+        data.push(MeshMessage::data_type());
+        data.append(&mut network_pdu.clone());
+
+        let into_data: Vec<u8> = result1.into();
+        assert_eq!(data, into_data);
+
+        let result2 = MeshMessage::try_from(&data);
+        assert!(result2.is_ok());
+        let data_type = result2.unwrap();
+        let into_data: Vec<u8> = data_type.into();
+        assert_eq!(data, into_data);
+    }
+
+    #[test]
+    fn test_data_type() {
+        assert_eq!(0x2a, MeshMessage::data_type());
+    }
+
+    #[test]
+    fn test_is_mesh_message() {
+        assert!(is_mesh_message(0x2a));
+        assert!(!is_mesh_message(0x00));
+    }
+}
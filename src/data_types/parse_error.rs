@@ -0,0 +1,355 @@
+//! Parse error module.
+//!
+//! Errors returned by the data type / descriptor parsers.
+
+use uuid::Uuid;
+
+/// Error code: Invalid data size
+pub const INVALID_DATA_SIZE: u8 = 1;
+
+/// Error code: Unknown data type
+pub const UNKNOWN_DATA_TYPE: u8 = 2;
+
+/// Error code: Data type not allowed in the parse context
+pub const DATA_TYPE_NOT_ALLOWED: u8 = 3;
+
+/// Error code: Wrong data type
+pub const WRONG_DATA_TYPE: u8 = 4;
+
+/// Error code: Invalid UTF-8
+pub const INVALID_UTF8: u8 = 5;
+
+/// Error code: Value out of range
+pub const VALUE_OUT_OF_RANGE: u8 = 6;
+
+/// Error code: Unknown descriptor UUID
+pub const UNKNOWN_UUID: u8 = 7;
+
+/// Error code: Platform API error
+pub const PLATFORM_ERROR: u8 = 8;
+
+/// Parse error.
+///
+/// [`Display`](std::fmt::Display) keeps the message format of the former `String` errors.
+/// With the `tiny-error` feature the message is the numeric error code only (e.g. for compact logs).
+/// The feature changes this [`Display`](std::fmt::Display) implementation only and does not reduce
+/// the code size: parsers still returning `String` errors format their messages as before.
+/// New errors may be added in minor releases.
+#[derive(Debug, PartialEq, Eq, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[non_exhaustive]
+pub enum DataTypeParseError {
+    /// Data is shorter(or longer) than the data type definition.
+    InvalidLength {
+        /// required data size
+        expected: usize,
+
+        /// actual data size
+        actual: usize,
+    },
+
+    /// Data type value does not match.
+    WrongDataType {
+        /// expected data type
+        expected: u8,
+
+        /// actual data type
+        actual: u8,
+    },
+
+    /// Text is not valid UTF-8.
+    InvalidUtf8 {
+        /// length of the valid UTF-8 prefix
+        valid_up_to: usize,
+    },
+
+    /// Field value is out of range.
+    ValueOutOfRange {
+        /// field name
+        // `std::primitive::str` keeps serde from borrowing the field from the input.
+        #[cfg_attr(feature = "serde", serde(deserialize_with = "deserialize_field_name"))]
+        field: &'static std::primitive::str,
+
+        /// field value
+        value: u64,
+    },
+
+    /// Data type is not supported.
+    ///
+    /// The data type parsers keep unsupported structures as [`DataTypeParseResult::Unknown`](crate::data_types::data_type_parser::DataTypeParseResult::Unknown).
+    UnknownDataType(u8),
+
+    /// Data type is not allowed in the parse context.
+    DataTypeNotAllowed(u8),
+
+    /// Descriptor UUID is not supported.
+    UnknownUuid(Uuid),
+
+    /// Platform API(e.g. WinRT) failed before parsing.
+    Platform(String),
+}
+
+/// Field names of [`DataTypeParseError::ValueOutOfRange`].
+#[cfg(feature = "serde")]
+const FIELD_NAMES: &[&str] = &["length", "address type"];
+
+/// Deserialize [`DataTypeParseError::ValueOutOfRange`] field name without borrowing from the input.
+#[cfg(feature = "serde")]
+fn deserialize_field_name<'de, D>(deserializer: D) -> Result<&'static str, D::Error>
+where
+    D: serde::Deserializer<'de>,
+{
+    let field = <String as serde::Deserialize>::deserialize(deserializer)?;
+    match FIELD_NAMES.iter().find(|name| **name == field) {
+        Some(name) => Ok(name),
+        None => Err(serde::de::Error::unknown_variant(&field, FIELD_NAMES)),
+    }
+}
+
+impl DataTypeParseError {
+    /// Returns the error code.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ble_data_struct::data_types::parse_error::*;
+    ///
+    /// assert_eq!(
+    ///     INVALID_DATA_SIZE,
+    ///     DataTypeParseError::InvalidLength {
+    ///         expected: 3,
+    ///         actual: 2
+    ///     }
+    ///     .error_code()
+    /// );
+    /// assert_eq!(
+    ///     UNKNOWN_DATA_TYPE,
+    ///     DataTypeParseError::UnknownDataType(0x00).error_code()
+    /// );
+    /// ```
+    pub const fn error_code(&self) -> u8 {
+        match self {
+            DataTypeParseError::InvalidLength { .. } => INVALID_DATA_SIZE,
+            DataTypeParseError::UnknownDataType(_) => UNKNOWN_DATA_TYPE,
+            DataTypeParseError::DataTypeNotAllowed(_) => DATA_TYPE_NOT_ALLOWED,
+            DataTypeParseError::WrongDataType { .. } => WRONG_DATA_TYPE,
+            DataTypeParseError::InvalidUtf8 { .. } => INVALID_UTF8,
+            DataTypeParseError::ValueOutOfRange { .. } => VALUE_OUT_OF_RANGE,
+            DataTypeParseError::UnknownUuid(_) => UNKNOWN_UUID,
+            DataTypeParseError::Platform(_) => PLATFORM_ERROR,
+        }
+    }
+}
+
+impl std::fmt::Display for DataTypeParseError {
+    /// Format the error message.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ble_data_struct::data_types::parse_error::DataTypeParseError;
+    ///
+    /// let error = DataTypeParseError::InvalidLength {
+    ///     expected: 3,
+    ///     actual: 2,
+    /// };
+    /// #[cfg(not(feature = "tiny-error"))]
+    /// assert_eq!("Invalid data size :2", error.to_string());
+    /// #[cfg(feature = "tiny-error")]
+    /// assert_eq!("1", error.to_string());
+    /// ```
+    #[cfg(not(feature = "tiny-error"))]
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            DataTypeParseError::InvalidLength { actual, .. } => {
+                write!(f, "Invalid data size :{}", actual)
+            }
+            DataTypeParseError::WrongDataType { actual, .. } => {
+                write!(f, "Invalid data type :{}", actual)
+            }
+            DataTypeParseError::InvalidUtf8 { valid_up_to } => {
+                write!(f, "Invalid UTF-8 :{}", valid_up_to)
+            }
+            DataTypeParseError::ValueOutOfRange { field, value } => {
+                write!(f, "Invalid {} :{}", field, value)
+            }
+            DataTypeParseError::UnknownDataType(data_type) => {
+                write!(f, "Unknown data type :{}", data_type)
+            }
+            DataTypeParseError::DataTypeNotAllowed(data_type) => {
+                write!(f, "Data type not allowed :{}", data_type)
+            }
+            DataTypeParseError::UnknownUuid(uuid) => write!(f, "Unknown uuid :{}", uuid),
+            DataTypeParseError::Platform(message) => write!(f, "{}", message),
+        }
+    }
+
+    #[cfg(feature = "tiny-error")]
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.error_code())
+    }
+}
+
+impl std::error::Error for DataTypeParseError {}
+
+impl From<DataTypeParseError> for String {
+    /// Create [`String`] from [`DataTypeParseError`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ble_data_struct::data_types::parse_error::DataTypeParseError;
+    ///
+    /// let error = DataTypeParseError::UnknownDataType(0x00);
+    /// let result: String = error.clone().into();
+    /// assert_eq!(error.to_string(), result);
+    /// ```
+    fn from(value: DataTypeParseError) -> Self {
+        value.to_string()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{data_types::parse_error::*, uuid_from_u16};
+
+    #[test]
+    fn test_error_code() {
+        assert_eq!(
+            INVALID_DATA_SIZE,
+            DataTypeParseError::InvalidLength {
+                expected: 3,
+                actual: 2
+            }
+            .error_code()
+        );
+        assert_eq!(
+            WRONG_DATA_TYPE,
+            DataTypeParseError::WrongDataType {
+                expected: 0x01,
+                actual: 0x02
+            }
+            .error_code()
+        );
+        assert_eq!(
+            INVALID_UTF8,
+            DataTypeParseError::InvalidUtf8 { valid_up_to: 1 }.error_code()
+        );
+        assert_eq!(
+            VALUE_OUT_OF_RANGE,
+            DataTypeParseError::ValueOutOfRange {
+                field: "address type",
+                value: 4
+            }
+            .error_code()
+        );
+        assert_eq!(
+            UNKNOWN_DATA_TYPE,
+            DataTypeParseError::UnknownDataType(0x00).error_code()
+        );
+        assert_eq!(
+            DATA_TYPE_NOT_ALLOWED,
+            DataTypeParseError::DataTypeNotAllowed(0x01).error_code()
+        );
+        assert_eq!(
+            UNKNOWN_UUID,
+            DataTypeParseError::UnknownUuid(uuid_from_u16(0x2a00)).error_code()
+        );
+        assert_eq!(
+            PLATFORM_ERROR,
+            DataTypeParseError::Platform("error".to_string()).error_code()
+        );
+    }
+
+    #[cfg(not(feature = "tiny-error"))]
+    #[test]
+    fn test_display() {
+        assert_eq!(
+            "Invalid data size :2",
+            DataTypeParseError::InvalidLength {
+                expected: 3,
+                actual: 2
+            }
+            .to_string()
+        );
+        assert_eq!(
+            "Invalid data type :2",
+            DataTypeParseError::WrongDataType {
+                expected: 0x01,
+                actual: 0x02
+            }
+            .to_string()
+        );
+        assert_eq!(
+            "Invalid UTF-8 :1",
+            DataTypeParseError::InvalidUtf8 { valid_up_to: 1 }.to_string()
+        );
+        assert_eq!(
+            "Invalid address type :4",
+            DataTypeParseError::ValueOutOfRange {
+                field: "address type",
+                value: 4
+            }
+            .to_string()
+        );
+        assert_eq!(
+            "Unknown data type :0",
+            DataTypeParseError::UnknownDataType(0x00).to_string()
+        );
+        assert_eq!(
+            "Data type not allowed :1",
+            DataTypeParseError::DataTypeNotAllowed(0x01).to_string()
+        );
+        assert_eq!(
+            "Unknown uuid :00002a00-0000-1000-8000-00805f9b34fb",
+            DataTypeParseError::UnknownUuid(uuid_from_u16(0x2a00)).to_string()
+        );
+        assert_eq!(
+            "error",
+            DataTypeParseError::Platform("error".to_string()).to_string()
+        );
+    }
+
+    #[cfg(feature = "tiny-error")]
+    #[test]
+    fn test_display() {
+        assert_eq!(
+            "1",
+            DataTypeParseError::InvalidLength {
+                expected: 3,
+                actual: 2
+            }
+            .to_string()
+        );
+        assert_eq!(
+            "3",
+            DataTypeParseError::DataTypeNotAllowed(0x01).to_string()
+        );
+    }
+
+    #[test]
+    fn test_into_string() {
+        let error = DataTypeParseError::UnknownDataType(0x00);
+        let result: String = error.clone().into();
+        assert_eq!(error.to_string(), result);
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_serde() {
+        let error = DataTypeParseError::ValueOutOfRange {
+            field: "length",
+            value: 1,
+        };
+        let json = serde_json::to_string(&error).unwrap();
+        assert_eq!(
+            error,
+            serde_json::from_str::<DataTypeParseError>(&json).unwrap()
+        );
+
+        assert!(serde_json::from_str::<DataTypeParseError>(
+            r#"{"ValueOutOfRange":{"field":"unknown","value":1}}"#
+        )
+        .is_err());
+    }
+}
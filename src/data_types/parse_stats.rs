@@ -0,0 +1,140 @@
+//! Parser allocation statistics module.
+//!
+//! Install [`StatsAllocator`] as the global allocator and wrap parsing with [`ParseStats::measure`]
+//! to count the heap allocations made by the current thread.
+//!
+//! ```ignore
+//! use ble_data_struct::data_types::parse_stats::StatsAllocator;
+//! use std::alloc::System;
+//!
+//! #[global_allocator]
+//! static ALLOCATOR: StatsAllocator<System> = StatsAllocator::new(System);
+//! ```
+
+use std::{
+    alloc::{GlobalAlloc, Layout},
+    cell::Cell,
+};
+
+use crate::data_types::data_type_parser::DataTypeParseResults;
+
+thread_local! {
+    /// allocation count of the current thread
+    static ALLOCATIONS: Cell<usize> = const { Cell::new(0) };
+
+    /// allocated bytes of the current thread
+    static BYTES: Cell<usize> = const { Cell::new(0) };
+}
+
+/// Count an allocation of the current thread.
+fn count(size: usize) {
+    // try_with: the thread local storage may already be destroyed on thread exit
+    let _ = ALLOCATIONS.try_with(|f| f.set(f.get().wrapping_add(1)));
+    let _ = BYTES.try_with(|f| f.set(f.get().wrapping_add(size)));
+}
+
+/// Current counters of the current thread.
+fn snapshot() -> (usize, usize) {
+    (ALLOCATIONS.with(|f| f.get()), BYTES.with(|f| f.get()))
+}
+
+/// Allocation counting [`GlobalAlloc`] wrapper.
+///
+/// `alloc`, `alloc_zeroed` and `realloc` are counted as one allocation with the requested size.
+#[derive(Debug, Default)]
+pub struct StatsAllocator<A> {
+    /// wrapped allocator
+    allocator: A,
+}
+
+impl<A> StatsAllocator<A> {
+    /// Create [`StatsAllocator`] from the allocator to wrap.
+    pub const fn new(allocator: A) -> Self {
+        Self { allocator }
+    }
+}
+
+unsafe impl<A: GlobalAlloc> GlobalAlloc for StatsAllocator<A> {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        count(layout.size());
+        self.allocator.alloc(layout)
+    }
+
+    unsafe fn alloc_zeroed(&self, layout: Layout) -> *mut u8 {
+        count(layout.size());
+        self.allocator.alloc_zeroed(layout)
+    }
+
+    unsafe fn realloc(&self, ptr: *mut u8, layout: Layout, new_size: usize) -> *mut u8 {
+        count(new_size);
+        self.allocator.realloc(ptr, layout, new_size)
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        self.allocator.dealloc(ptr, layout)
+    }
+}
+
+/// Parser allocation statistics.
+///
+/// Counters stay `0` unless [`StatsAllocator`] is the global allocator.
+#[derive(Debug, PartialEq, Clone, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ParseStats {
+    /// Heap allocation count
+    pub allocations: usize,
+
+    /// Allocated bytes
+    pub bytes: usize,
+
+    /// Parsed EIR/AD/SRD/ACAD/OOB structure count
+    pub structures: usize,
+}
+
+impl ParseStats {
+    /// Count the heap allocations made by `f` on the current thread.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ble_data_struct::data_types::parse_stats::ParseStats;
+    ///
+    /// let (result, stats) = ParseStats::measure(|| 1 + 1);
+    /// assert_eq!(2, result);
+    /// assert_eq!(0, stats.allocations);
+    /// assert_eq!(0, stats.structures);
+    /// ```
+    pub fn measure<T>(f: impl FnOnce() -> T) -> (T, ParseStats) {
+        let (allocations, bytes) = snapshot();
+        let result = f();
+        let (allocations_after, bytes_after) = snapshot();
+        (
+            result,
+            ParseStats {
+                allocations: allocations_after.wrapping_sub(allocations),
+                bytes: bytes_after.wrapping_sub(bytes),
+                structures: 0,
+            },
+        )
+    }
+
+    /// Parse the payload and count the heap allocations.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ble_data_struct::data_types::{
+    ///     parse_stats::ParseStats, tx_power_level::TxPowerLevel,
+    /// };
+    ///
+    /// let payload: Vec<u8> = TxPowerLevel::new(-4).into();
+    /// let (results, stats) = ParseStats::parse(&payload);
+    /// assert_eq!(1, results.results.len());
+    /// assert_eq!(1, stats.structures);
+    /// ```
+    pub fn parse(payload: &Vec<u8>) -> (DataTypeParseResults, ParseStats) {
+        let (results, mut stats) = Self::measure(|| DataTypeParseResults::from(payload));
+        stats.structures = results.results.len();
+        (results, stats)
+    }
+}
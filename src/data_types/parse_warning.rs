@@ -0,0 +1,133 @@
+//! Parse warning module.
+//!
+//! Non-fatal spec deviations found in successfully parsed data types.
+
+/// Parse warning.
+///
+/// New warnings may be added in minor releases.
+#[derive(Debug, PartialEq, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[non_exhaustive]
+pub enum ParseWarning {
+    /// RFU(Reserved for Future Use) bits or values are set.
+    ReservedForFutureUse {
+        /// data type
+        data_type: u8,
+    },
+
+    /// Deprecated data type or field is used.
+    Deprecated {
+        /// data type
+        data_type: u8,
+    },
+
+    /// Data length does not match the data type definition.
+    UnexpectedLength {
+        /// data type
+        data_type: u8,
+
+        /// data length
+        length: u8,
+    },
+}
+
+impl ParseWarning {
+    /// Returns the data type the warning belongs to.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ble_data_struct::data_types::parse_warning::ParseWarning;
+    ///
+    /// assert_eq!(0x01, ParseWarning::ReservedForFutureUse { data_type: 0x01 }.data_type());
+    /// assert_eq!(0x01, ParseWarning::Deprecated { data_type: 0x01 }.data_type());
+    /// assert_eq!(
+    ///     0x0a,
+    ///     ParseWarning::UnexpectedLength {
+    ///         data_type: 0x0a,
+    ///         length: 3
+    ///     }
+    ///     .data_type()
+    /// );
+    /// ```
+    pub fn data_type(&self) -> u8 {
+        match self {
+            ParseWarning::ReservedForFutureUse { data_type }
+            | ParseWarning::Deprecated { data_type }
+            | ParseWarning::UnexpectedLength { data_type, .. } => *data_type,
+        }
+    }
+}
+
+/// Create [`ParseWarning::UnexpectedLength`] if `length` is not `size + 1`.
+pub(crate) fn check_fixed_length(data_type: u8, length: u8, size: usize) -> Option<ParseWarning> {
+    if length as usize != size + 1 {
+        Some(ParseWarning::UnexpectedLength { data_type, length })
+    } else {
+        None
+    }
+}
+
+/// Create [`ParseWarning::UnexpectedLength`] if `length - 1` is not a multiple of `size`.
+pub(crate) fn check_multiple_length(
+    data_type: u8,
+    length: u8,
+    size: usize,
+) -> Option<ParseWarning> {
+    if !(length as usize).saturating_sub(1).is_multiple_of(size) {
+        Some(ParseWarning::UnexpectedLength { data_type, length })
+    } else {
+        None
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::data_types::parse_warning::{
+        check_fixed_length, check_multiple_length, ParseWarning,
+    };
+
+    #[test]
+    fn test_data_type() {
+        assert_eq!(
+            0x01,
+            ParseWarning::ReservedForFutureUse { data_type: 0x01 }.data_type()
+        );
+        assert_eq!(
+            0x01,
+            ParseWarning::Deprecated { data_type: 0x01 }.data_type()
+        );
+        assert_eq!(
+            0x0a,
+            ParseWarning::UnexpectedLength {
+                data_type: 0x0a,
+                length: 3
+            }
+            .data_type()
+        );
+    }
+
+    #[test]
+    fn test_check_fixed_length() {
+        assert_eq!(None, check_fixed_length(0x0a, 2, 1));
+        assert_eq!(
+            Some(ParseWarning::UnexpectedLength {
+                data_type: 0x0a,
+                length: 3
+            }),
+            check_fixed_length(0x0a, 3, 1)
+        );
+    }
+
+    #[test]
+    fn test_check_multiple_length() {
+        assert_eq!(None, check_multiple_length(0x03, 5, 2));
+        assert_eq!(
+            Some(ParseWarning::UnexpectedLength {
+                data_type: 0x03,
+                length: 4
+            }),
+            check_multiple_length(0x03, 4, 2)
+        );
+    }
+}
@@ -0,0 +1,323 @@
+//! PB-ADV (Data Type Value: 0x29) module.
+
+use crate::data_types::{ad_type::AdType, data_type::DataType, parse_error::DataTypeParseError};
+
+/// PB-ADV.
+#[derive(Debug, PartialEq, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct PbAdv {
+    /// data length
+    pub length: u8,
+
+    /// Link ID
+    pub link_id: u32,
+
+    /// Transaction Number
+    pub transaction_number: u8,
+
+    /// Generic Provisioning PDU
+    pub generic_provisioning_pdu: Vec<u8>,
+}
+
+impl PbAdv {
+    /// Create [`PbAdv`] from `Link ID`, `Transaction Number` and `Generic Provisioning PDU`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ble_data_struct::data_types::pb_adv::PbAdv;
+    ///
+    /// let link_id = 0x01020304u32;
+    /// let transaction_number = 0x05u8;
+    /// let generic_provisioning_pdu = [0x03u8, 0x00u8].to_vec();
+    /// let result = PbAdv::new(link_id, transaction_number, &generic_provisioning_pdu);
+    /// assert_eq!(generic_provisioning_pdu.len() as u8 + 6, result.length);
+    /// assert_eq!(link_id, result.link_id);
+    /// assert_eq!(transaction_number, result.transaction_number);
+    /// assert_eq!(generic_provisioning_pdu, result.generic_provisioning_pdu);
+    /// ```
+    pub fn new(link_id: u32, transaction_number: u8, generic_provisioning_pdu: &Vec<u8>) -> Self {
+        Self {
+            length: 6 + generic_provisioning_pdu.len() as u8,
+            link_id,
+            transaction_number,
+            generic_provisioning_pdu: generic_provisioning_pdu.clone(),
+        }
+    }
+
+    /// Get Generic Provisioning Control Format (2 least significant bits of the first octet).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ble_data_struct::data_types::pb_adv::PbAdv;
+    ///
+    /// assert_eq!(Some(0b11), PbAdv::new(0, 0, &[0x03u8].to_vec()).generic_provisioning_control_format());
+    /// assert_eq!(None, PbAdv::new(0, 0, &Vec::new()).generic_provisioning_control_format());
+    /// ```
+    pub fn generic_provisioning_control_format(&self) -> Option<u8> {
+        self.generic_provisioning_pdu.first().map(|f| f & 0b11)
+    }
+}
+
+impl TryFrom<&[u8]> for PbAdv {
+    type Error = DataTypeParseError;
+    /// Create [`PbAdv`] from `&[u8]`.
+    fn try_from(value: &[u8]) -> Result<Self, DataTypeParseError> {
+        let len = value.len();
+        if len < 7 {
+            return Err(DataTypeParseError::InvalidLength {
+                expected: 7,
+                actual: len,
+            });
+        }
+        let length = value[0];
+        if length < 6 {
+            return Err(DataTypeParseError::ValueOutOfRange {
+                field: "length",
+                value: length as u64,
+            });
+        }
+        if len < 1 + length as usize {
+            return Err(DataTypeParseError::InvalidLength {
+                expected: 1 + length as usize,
+                actual: len,
+            });
+        }
+        Ok(Self {
+            length,
+            link_id: u32::from_be_bytes(value[2..6].try_into().unwrap()),
+            transaction_number: value[6],
+            generic_provisioning_pdu: value[7..1 + length as usize].to_vec(),
+        })
+    }
+}
+
+impl TryFrom<&Vec<u8>> for PbAdv {
+    type Error = DataTypeParseError;
+    /// Create [`PbAdv`] from [`Vec<u8>`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ble_data_struct::data_types::{data_type::DataType, pb_adv::PbAdv};
+    /// use ble_data_struct::data_types::parse_error::DataTypeParseError;
+    ///
+    /// let link_id = 0x01020304u32;
+    /// let transaction_number = 0x05u8;
+    /// let generic_provisioning_pdu = [0x03u8, 0x00u8].to_vec();
+    /// let length = generic_provisioning_pdu.len() as u8 + 6;
+    /// let mut data: Vec<u8> = Vec::new();
+    /// data.push(length);
+    /// data.push(PbAdv::data_type());
+    /// data.append(&mut link_id.to_be_bytes().to_vec());
+    /// data.push(transaction_number);
+    /// data.append(&mut generic_provisioning_pdu.clone());
+    ///
+    /// let result = PbAdv::try_from(&data);
+    /// assert!(result.is_ok());
+    /// let data_type = result.unwrap();
+    /// assert_eq!(length, data_type.length);
+    /// assert_eq!(link_id, data_type.link_id);
+    /// assert_eq!(transaction_number, data_type.transaction_number);
+    /// assert_eq!(generic_provisioning_pdu, data_type.generic_provisioning_pdu);
+    ///
+    /// let data: Vec<u8> = Vec::new();
+    /// let result = PbAdv::try_from(&data);
+    /// assert!(result.is_err());
+    /// assert_eq!(
+    ///     DataTypeParseError::InvalidLength {
+    ///         expected: 7,
+    ///         actual: data.len()
+    ///     },
+    ///     result.unwrap_err()
+    /// );
+    /// ```
+    fn try_from(value: &Vec<u8>) -> Result<Self, DataTypeParseError> {
+        Self::try_from(value.as_slice())
+    }
+}
+
+impl Into<Vec<u8>> for PbAdv {
+    /// Create [`Vec<u8>`] from [`PbAdv`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ble_data_struct::data_types::{data_type::DataType, pb_adv::PbAdv};
+    ///
+    /// let link_id = 0x01020304u32;
+    /// let transaction_number = 0x05u8;
+    /// let generic_provisioning_pdu = [0x03u8, 0x00u8].to_vec();
+    /// let result1 = PbAdv::new(link_id, transaction_number, &generic_provisioning_pdu);
+    ///
+    /// let mut data: Vec<u8> = Vec::new();
+    /// data.push(generic_provisioning_pdu.len() as u8 + 6);
+    /// data.push(PbAdv::data_type());
+    /// data.append(&mut link_id.to_be_bytes().to_vec());
+    /// data.push(transaction_number);
+    /// data.append(&mut generic_provisioning_pdu.clone());
+    ///
+    /// let into_data: Vec<u8> = result1.into();
+    /// assert_eq!(data, into_data);
+    ///
+    /// let result2 = PbAdv::try_from(&data);
+    /// assert!(result2.is_ok());
+    /// let data_type = result2.unwrap();
+    /// let into_data: Vec<u8> = data_type.into();
+    /// assert_eq!(data, into_data);
+    /// ```
+    fn into(self) -> Vec<u8> {
+        let mut data: Vec<u8> = Vec::new();
+        data.push(self.length);
+        data.push(Self::data_type());
+        data.append(&mut self.link_id.to_be_bytes().to_vec());
+        data.push(self.transaction_number);
+        data.append(&mut self.generic_provisioning_pdu.clone());
+        return data;
+    }
+}
+
+impl DataType for PbAdv {
+    /// return `0x29`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ble_data_struct::data_types::{data_type::DataType, pb_adv::PbAdv};
+    ///
+    /// assert_eq!(0x29, PbAdv::data_type());
+    /// ```
+    #[inline]
+    fn data_type() -> u8 {
+        0x29
+    }
+}
+
+/// check `PB-ADV` data type.
+///
+/// # Examples
+///
+/// ```
+/// use ble_data_struct::data_types::pb_adv::*;
+/// use ble_data_struct::data_types::data_type::DataType;
+///
+/// assert!(is_pb_adv(0x29));
+/// assert!(!is_pb_adv(0x00));
+/// ```
+#[inline]
+pub fn is_pb_adv(data_type: u8) -> bool {
+    AdType::from_u8(data_type) == Some(AdType::PbAdv)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::data_types::{data_type::DataType, pb_adv::*};
+
+    #[test]
+    fn test_new() {
+        let link_id = 0x01020304u32;
+        let transaction_number = 0x05u8;
+        let generic_provisioning_pdu = [0x03u8, 0x00u8].to_vec();
+        let result = PbAdv::new(link_id, transaction_number, &generic_provisioning_pdu);
+        assert_eq!(generic_provisioning_pdu.len() as u8 + 6, result.length);
+        assert_eq!(link_id, result.link_id);
+        assert_eq!(transaction_number, result.transaction_number);
+        assert_eq!(generic_provisioning_pdu, result.generic_provisioning_pdu);
+    }
+
+    #[test]
+    fn test_generic_provisioning_control_format() {
+        assert_eq!(
+            Some(0b11),
+            PbAdv::new(0, 0, &[0x03u8].to_vec()).generic_provisioning_control_format()
+        );
+        assert_eq!(
+            Some(0b00),
+            PbAdv::new(0, 0, &[0xfcu8].to_vec()).generic_provisioning_control_format()
+        );
+        assert_eq!(
+            None,
+            PbAdv::new(0, 0, &Vec::new()).generic_provisioning_control_format()
+        );
+    }
+
+    #[test]
+    fn test_try_from() {
+        let link_id = 0x01020304u32;
+        let transaction_number = 0x05u8;
+        let generic_provisioning_pdu = [0x03u8, 0x00u8].to_vec();
+        let length = generic_provisioning_pdu.len() as u8 + 6;
+        let mut data: Vec<u8> = Vec::new();
+        data.push(length);
+        data.push(PbAdv::data_type());
+        data.append(&mut link_id.to_be_bytes().to_vec());
+        data.push(transaction_number);
+        data.append(&mut generic_provisioning_pdu.clone());
+
+        let result = PbAdv::try_from(&data);
+        assert!(result.is_ok());
+        let data_type = result.unwrap();
+        assert_eq!(length, data_type.length);
+        assert_eq!(link_id, data_type.link_id);
+        assert_eq!(transaction_number, data_type.transaction_number);
+        assert_eq!(generic_provisioning_pdu, data_type.generic_provisioning_pdu);
+
+        let data: Vec<u8> = vec![5, PbAdv::data_type(), 0x01, 0x02, 0x03, 0x04];
+        let result = PbAdv::try_from(&data);
+        assert!(result.is_err());
+        assert_eq!(
+            DataTypeParseError::InvalidLength {
+                expected: 7,
+                actual: data.len()
+            },
+            result.unwrap_err()
+        );
+
+        let data: Vec<u8> = vec![8, PbAdv::data_type(), 0x01, 0x02, 0x03, 0x04, 0x05];
+        let result = PbAdv::try_from(&data);
+        assert!(result.is_err());
+        assert_eq!(
+            DataTypeParseError::InvalidLength {
+                expected: 9,
+                actual: data.len()
+            },
+            result.unwrap_err()
+        );
+    }
+
+    #[test]
+    fn test_into() {
+        let link_id = 0x01020304u32;
+        let transaction_number = 0x05u8;
+        let generic_provisioning_pdu = [0x03u8, 0x00u8].to_vec();
+        let result1 = PbAdv::new(link_id, transaction_number, &generic_provisioning_pdu);
+
+        let mut data: Vec<u8> = Vec::new();
+        data.push(generic_provisioning_pdu.len() as u8 + 6);
+        data.push(PbAdv::data_type());
+        data.append(&mut link_id.to_be_bytes().to_vec());
+        data.push(transaction_number);
+        data.append(&mut generic_provisioning_pdu.clone());
+
+        let into_data: Vec<u8> = result1.into();
+        assert_eq!(data, into_data);
+
+        let result2 = PbAdv::try_from(&data);
+        assert!(result2.is_ok());
+        let data_type = result2.unwrap();
+        let into_data: Vec<u8> = data_type.into();
+        assert_eq!(data, into_data);
+    }
+
+    #[test]
+    fn test_data_type() {
+        assert_eq!(0x29, PbAdv::data_type());
+    }
+
+    #[test]
+    fn test_is_pb_adv() {
+        assert!(is_pb_adv(0x29));
+        assert!(!is_pb_adv(0x00));
+    }
+}
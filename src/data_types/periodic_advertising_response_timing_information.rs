@@ -1,9 +1,12 @@
 //! Peripheral Connection Interval Range (Data Type Value: 0x32) module.
 
-use crate::data_types::data_type::DataType;
+use crate::data_types::{
+    ad_type::AdType, data_type::DataType, parse_error::DataTypeParseError, time_units::Units1_25ms,
+};
 
 /// Peripheral Connection Interval Range.
 #[derive(Debug, PartialEq, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct PeriodicAdvertisingResponseTimingInformation {
     /// data length
     pub length: u8,
@@ -15,10 +18,10 @@ pub struct PeriodicAdvertisingResponseTimingInformation {
     pub num_subevents: u8,
 
     /// subeventInterval
-    pub subevent_interval: u8,
+    pub subevent_interval: Units1_25ms<u8>,
 
     /// responseSlotDelay
-    pub response_slot_delay: u8,
+    pub response_slot_delay: Units1_25ms<u8>,
 
     /// responseSlotSpacing
     pub response_slot_spacing: u8,
@@ -31,6 +34,7 @@ impl PeriodicAdvertisingResponseTimingInformation {
     ///
     /// ```
     /// use ble_data_struct::data_types::periodic_advertising_response_timing_information::PeriodicAdvertisingResponseTimingInformation;
+    /// use ble_data_struct::data_types::time_units::Units1_25ms;
     ///
     /// let rsp_aa: [u8; 4] = [1, 2, 3, 4];
     /// let num_subevents = 6u8;
@@ -40,22 +44,22 @@ impl PeriodicAdvertisingResponseTimingInformation {
     /// let result = PeriodicAdvertisingResponseTimingInformation::new(
     ///     &rsp_aa,
     ///     num_subevents,
-    ///     subevent_interval,
-    ///     response_slot_delay,
+    ///     Units1_25ms(subevent_interval),
+    ///     Units1_25ms(response_slot_delay),
     ///     response_slot_spacing,
     /// );
     /// assert_eq!(9, result.length);
     /// assert_eq!(rsp_aa, result.rsp_aa);
     /// assert_eq!(num_subevents, result.num_subevents);
-    /// assert_eq!(subevent_interval, result.subevent_interval);
-    /// assert_eq!(response_slot_delay, result.response_slot_delay);
+    /// assert_eq!(Units1_25ms(subevent_interval), result.subevent_interval);
+    /// assert_eq!(Units1_25ms(response_slot_delay), result.response_slot_delay);
     /// assert_eq!(response_slot_spacing, result.response_slot_spacing);
     /// ```
     pub fn new(
         rsp_aa: &[u8; 4],
         num_subevents: u8,
-        subevent_interval: u8,
-        response_slot_delay: u8,
+        subevent_interval: Units1_25ms<u8>,
+        response_slot_delay: Units1_25ms<u8>,
         response_slot_spacing: u8,
     ) -> Self {
         Self {
@@ -69,14 +73,39 @@ impl PeriodicAdvertisingResponseTimingInformation {
     }
 }
 
+impl TryFrom<&[u8]> for PeriodicAdvertisingResponseTimingInformation {
+    type Error = DataTypeParseError;
+    /// Create [`PeriodicAdvertisingResponseTimingInformation`] from `&[u8]`.
+    fn try_from(value: &[u8]) -> Result<Self, DataTypeParseError> {
+        let len = value.len();
+        if len < 10 {
+            return Err(DataTypeParseError::InvalidLength {
+                expected: 10,
+                actual: len,
+            });
+        }
+        let length = value[0];
+        Ok(Self {
+            length,
+            rsp_aa: value[2..6].try_into().unwrap(),
+            num_subevents: value[6],
+            subevent_interval: Units1_25ms(value[7]),
+            response_slot_delay: Units1_25ms(value[8]),
+            response_slot_spacing: value[9],
+        })
+    }
+}
+
 impl TryFrom<&Vec<u8>> for PeriodicAdvertisingResponseTimingInformation {
-    type Error = String;
+    type Error = DataTypeParseError;
     /// Create [`PeriodicAdvertisingResponseTimingInformation`] from [`Vec<u8>`].
     ///
     /// # Examples
     ///
     /// ```
     /// use ble_data_struct::data_types::{periodic_advertising_response_timing_information::PeriodicAdvertisingResponseTimingInformation, data_type::DataType};
+    /// use ble_data_struct::data_types::time_units::Units1_25ms;
+    /// use ble_data_struct::data_types::parse_error::DataTypeParseError;
     ///
     /// let rsp_aa: [u8; 4] = [1, 2, 3, 4];
     /// let num_subevents = 6u8;
@@ -99,32 +128,23 @@ impl TryFrom<&Vec<u8>> for PeriodicAdvertisingResponseTimingInformation {
     /// assert_eq!(length, data_type.length);
     /// assert_eq!(rsp_aa, data_type.rsp_aa);
     /// assert_eq!(num_subevents, data_type.num_subevents);
-    /// assert_eq!(subevent_interval, data_type.subevent_interval);
-    /// assert_eq!(response_slot_delay, data_type.response_slot_delay);
+    /// assert_eq!(Units1_25ms(subevent_interval), data_type.subevent_interval);
+    /// assert_eq!(Units1_25ms(response_slot_delay), data_type.response_slot_delay);
     /// assert_eq!(response_slot_spacing, data_type.response_slot_spacing);
     ///
     /// let data: Vec<u8> = Vec::new();
     /// let result = PeriodicAdvertisingResponseTimingInformation::try_from(&data);
     /// assert!(result.is_err());
     /// assert_eq!(
-    ///     format!("Invalid data size :{}", data.len()),
+    ///     DataTypeParseError::InvalidLength {
+    ///         expected: 10,
+    ///         actual: data.len()
+    ///     },
     ///     result.unwrap_err()
     /// );
     /// ```
-    fn try_from(value: &Vec<u8>) -> Result<Self, String> {
-        let len = value.len();
-        if len < 10 {
-            return Err(format!("Invalid data size :{}", len).to_string());
-        }
-        let length = value[0];
-        Ok(Self {
-            length,
-            rsp_aa: value[2..6].try_into().unwrap(),
-            num_subevents: value[6],
-            subevent_interval: value[7],
-            response_slot_delay: value[8],
-            response_slot_spacing: value[9],
-        })
+    fn try_from(value: &Vec<u8>) -> Result<Self, DataTypeParseError> {
+        Self::try_from(value.as_slice())
     }
 }
 
@@ -135,6 +155,7 @@ impl Into<Vec<u8>> for PeriodicAdvertisingResponseTimingInformation {
     ///
     /// ```
     /// use ble_data_struct::data_types::{periodic_advertising_response_timing_information::PeriodicAdvertisingResponseTimingInformation, data_type::DataType};
+    /// use ble_data_struct::data_types::time_units::Units1_25ms;
     ///
     /// let rsp_aa: [u8; 4] = [1, 2, 3, 4];
     /// let num_subevents = 6u8;
@@ -145,8 +166,8 @@ impl Into<Vec<u8>> for PeriodicAdvertisingResponseTimingInformation {
     /// let result1 = PeriodicAdvertisingResponseTimingInformation::new(
     ///     &rsp_aa,
     ///     num_subevents,
-    ///     subevent_interval,
-    ///     response_slot_delay,
+    ///     Units1_25ms(subevent_interval),
+    ///     Units1_25ms(response_slot_delay),
     ///     response_slot_spacing,
     /// );
     /// let mut data: Vec<u8> = Vec::new();
@@ -173,8 +194,8 @@ impl Into<Vec<u8>> for PeriodicAdvertisingResponseTimingInformation {
         data.push(Self::data_type());
         data.append(&mut self.rsp_aa.clone().to_vec());
         data.push(self.num_subevents);
-        data.push(self.subevent_interval);
-        data.push(self.response_slot_delay);
+        data.push(self.subevent_interval.0);
+        data.push(self.response_slot_delay.0);
         data.push(self.response_slot_spacing);
         return data;
     }
@@ -190,6 +211,7 @@ impl DataType for PeriodicAdvertisingResponseTimingInformation {
     ///
     /// assert_eq!(0x32, PeriodicAdvertisingResponseTimingInformation::data_type());
     /// ```
+    #[inline]
     fn data_type() -> u8 {
         0x32
     }
@@ -206,14 +228,16 @@ impl DataType for PeriodicAdvertisingResponseTimingInformation {
 /// assert!(is_periodic_advertising_response_timing_information(0x32));
 /// assert!(!is_periodic_advertising_response_timing_information(0x00));
 /// ```
+#[inline]
 pub fn is_periodic_advertising_response_timing_information(data_type: u8) -> bool {
-    PeriodicAdvertisingResponseTimingInformation::data_type() == data_type
+    AdType::from_u8(data_type) == Some(AdType::PeriodicAdvertisingResponseTimingInformation)
 }
 
 #[cfg(test)]
 mod tests {
     use crate::data_types::{
         data_type::DataType, periodic_advertising_response_timing_information::*,
+        time_units::Units1_25ms,
     };
 
     #[test]
@@ -226,15 +250,15 @@ mod tests {
         let result = PeriodicAdvertisingResponseTimingInformation::new(
             &rsp_aa,
             num_subevents,
-            subevent_interval,
-            response_slot_delay,
+            Units1_25ms(subevent_interval),
+            Units1_25ms(response_slot_delay),
             response_slot_spacing,
         );
         assert_eq!(9, result.length);
         assert_eq!(rsp_aa, result.rsp_aa);
         assert_eq!(num_subevents, result.num_subevents);
-        assert_eq!(subevent_interval, result.subevent_interval);
-        assert_eq!(response_slot_delay, result.response_slot_delay);
+        assert_eq!(Units1_25ms(subevent_interval), result.subevent_interval);
+        assert_eq!(Units1_25ms(response_slot_delay), result.response_slot_delay);
         assert_eq!(response_slot_spacing, result.response_slot_spacing);
     }
 
@@ -261,8 +285,11 @@ mod tests {
         assert_eq!(length, data_type.length);
         assert_eq!(rsp_aa, data_type.rsp_aa);
         assert_eq!(num_subevents, data_type.num_subevents);
-        assert_eq!(subevent_interval, data_type.subevent_interval);
-        assert_eq!(response_slot_delay, data_type.response_slot_delay);
+        assert_eq!(Units1_25ms(subevent_interval), data_type.subevent_interval);
+        assert_eq!(
+            Units1_25ms(response_slot_delay),
+            data_type.response_slot_delay
+        );
         assert_eq!(response_slot_spacing, data_type.response_slot_spacing);
 
         let mut data: Vec<u8> = vec![0u8; 9];
@@ -270,7 +297,10 @@ mod tests {
         let result = PeriodicAdvertisingResponseTimingInformation::try_from(&data);
         assert!(result.is_err());
         assert_eq!(
-            format!("Invalid data size :{}", data.len()),
+            DataTypeParseError::InvalidLength {
+                expected: 10,
+                actual: data.len()
+            },
             result.unwrap_err()
         );
     }
@@ -286,8 +316,8 @@ mod tests {
         let result1 = PeriodicAdvertisingResponseTimingInformation::new(
             &rsp_aa,
             num_subevents,
-            subevent_interval,
-            response_slot_delay,
+            Units1_25ms(subevent_interval),
+            Units1_25ms(response_slot_delay),
             response_slot_spacing,
         );
         let mut data: Vec<u8> = Vec::new();
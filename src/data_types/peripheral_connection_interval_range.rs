@@ -1,19 +1,26 @@
 //! Peripheral Connection Interval Range (Data Type Value: 0x12) module.
 
-use crate::data_types::data_type::DataType;
+use crate::data_types::{
+    ad_type::AdType,
+    connection_parameters::{check_connection_interval_range, ConnectionParameterViolation},
+    data_type::DataType,
+    parse_error::DataTypeParseError,
+    time_units::Units1_25ms,
+};
 
 /// Peripheral Connection Interval Range.
 
 #[derive(Debug, PartialEq, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct PeripheralConnectionIntervalRange {
     /// data length
     pub length: u8,
 
     /// Minimum connection interval
-    pub minimum_value: u16,
+    pub minimum_value: Units1_25ms,
 
     /// Maximum connection interval
-    pub maximum_value: u16,
+    pub maximum_value: Units1_25ms,
 }
 
 impl PeripheralConnectionIntervalRange {
@@ -23,15 +30,16 @@ impl PeripheralConnectionIntervalRange {
     ///
     /// ```
     /// use ble_data_struct::data_types::peripheral_connection_interval_range::PeripheralConnectionIntervalRange;
+    /// use ble_data_struct::data_types::time_units::Units1_25ms;
     ///
     /// let minimum_value = 0x0006u16;
     /// let maximum_value = 0x0C80u16;
-    /// let result = PeripheralConnectionIntervalRange::new(minimum_value, maximum_value);
+    /// let result = PeripheralConnectionIntervalRange::new(Units1_25ms(minimum_value), Units1_25ms(maximum_value));
     /// assert_eq!(5, result.length);
-    /// assert_eq!(minimum_value, result.minimum_value);
-    /// assert_eq!(maximum_value, result.maximum_value);
+    /// assert_eq!(Units1_25ms(minimum_value), result.minimum_value);
+    /// assert_eq!(Units1_25ms(maximum_value), result.maximum_value);
     /// ```
-    pub fn new(minimum_value: u16, maximum_value: u16) -> Self {
+    pub fn new(minimum_value: Units1_25ms, maximum_value: Units1_25ms) -> Self {
         Self {
             length: 5,
             minimum_value,
@@ -48,17 +56,18 @@ impl PeripheralConnectionIntervalRange {
     ///    peripheral_connection_interval_range::{PeripheralConnectionIntervalRange, CONNECTION_INTERVAL_RANGE},
     ///    data_type::DataType,
     /// };
+    /// use ble_data_struct::data_types::time_units::Units1_25ms;
     ///
     /// let minimum_value = 0x0006u16;
     /// let maximum_value = 0x0C80u16;
-    /// let result = PeripheralConnectionIntervalRange::new(minimum_value, maximum_value);
+    /// let result = PeripheralConnectionIntervalRange::new(Units1_25ms(minimum_value), Units1_25ms(maximum_value));
     /// assert_eq!(
     ///     minimum_value as f32 * CONNECTION_INTERVAL_RANGE,
     ///     result.minimum_value_millis()
     /// )
     /// ```
     pub fn minimum_value_millis(&self) -> f32 {
-        self.minimum_value as f32 * CONNECTION_INTERVAL_RANGE
+        self.minimum_value.millis()
     }
 
     /// Get Maximum connection interval(millis).
@@ -70,17 +79,18 @@ impl PeripheralConnectionIntervalRange {
     ///    peripheral_connection_interval_range::{PeripheralConnectionIntervalRange, CONNECTION_INTERVAL_RANGE},
     ///    data_type::DataType,
     /// };
+    /// use ble_data_struct::data_types::time_units::Units1_25ms;
     ///
     /// let minimum_value = 0x0006u16;
     /// let maximum_value = 0x0C80u16;
-    /// let result = PeripheralConnectionIntervalRange::new(minimum_value, maximum_value);
+    /// let result = PeripheralConnectionIntervalRange::new(Units1_25ms(minimum_value), Units1_25ms(maximum_value));
     /// assert_eq!(
     ///     minimum_value as f32 * CONNECTION_INTERVAL_RANGE,
     ///     result.minimum_value_millis()
     /// )
     /// ```
     pub fn maximum_value_millis(&self) -> f32 {
-        self.maximum_value as f32 * CONNECTION_INTERVAL_RANGE
+        self.maximum_value.millis()
     }
 
     /// check no specific minimum values
@@ -92,19 +102,20 @@ impl PeripheralConnectionIntervalRange {
     ///    peripheral_connection_interval_range::{PeripheralConnectionIntervalRange, CONNECTION_INTERVAL_NO_SPECIFIC_VALUE},
     ///    data_type::DataType,
     /// };
+    /// use ble_data_struct::data_types::time_units::Units1_25ms;
     ///
     /// let minimum_value = 0x0006u16;
     /// let maximum_value = 0x0C80u16;
-    /// let result = PeripheralConnectionIntervalRange::new(minimum_value, maximum_value);
+    /// let result = PeripheralConnectionIntervalRange::new(Units1_25ms(minimum_value), Units1_25ms(maximum_value));
     /// assert!(!result.is_no_specific_minimum_value());
     ///
     /// let minimum_value = CONNECTION_INTERVAL_NO_SPECIFIC_VALUE;
     /// let maximum_value = 0x0C80u16;
-    /// let result = PeripheralConnectionIntervalRange::new(minimum_value, maximum_value);
+    /// let result = PeripheralConnectionIntervalRange::new(Units1_25ms(minimum_value), Units1_25ms(maximum_value));
     /// assert!(result.is_no_specific_minimum_value());
     /// ```
     pub fn is_no_specific_minimum_value(&self) -> bool {
-        self.minimum_value == CONNECTION_INTERVAL_NO_SPECIFIC_VALUE
+        self.minimum_value.0 == CONNECTION_INTERVAL_NO_SPECIFIC_VALUE
     }
 
     /// check no specific minimum values
@@ -116,19 +127,145 @@ impl PeripheralConnectionIntervalRange {
     ///    peripheral_connection_interval_range::{PeripheralConnectionIntervalRange, CONNECTION_INTERVAL_NO_SPECIFIC_VALUE},
     ///    data_type::DataType,
     /// };
+    /// use ble_data_struct::data_types::time_units::Units1_25ms;
     ///
     /// let minimum_value = 0x0006u16;
     /// let maximum_value = 0x0C80u16;
-    /// let result = PeripheralConnectionIntervalRange::new(minimum_value, maximum_value);
+    /// let result = PeripheralConnectionIntervalRange::new(Units1_25ms(minimum_value), Units1_25ms(maximum_value));
     /// assert!(!result.is_no_specific_maximum_value());
     ///
     /// let minimum_value = 0x0006u16;
     /// let maximum_value = CONNECTION_INTERVAL_NO_SPECIFIC_VALUE;
-    /// let result = PeripheralConnectionIntervalRange::new(minimum_value, maximum_value);
+    /// let result = PeripheralConnectionIntervalRange::new(Units1_25ms(minimum_value), Units1_25ms(maximum_value));
     /// assert!(result.is_no_specific_maximum_value());
     /// ```
     pub fn is_no_specific_maximum_value(&self) -> bool {
-        self.maximum_value == CONNECTION_INTERVAL_NO_SPECIFIC_VALUE
+        self.maximum_value.0 == CONNECTION_INTERVAL_NO_SPECIFIC_VALUE
+    }
+
+    /// Get Minimum connection interval, [`CONNECTION_INTERVAL_MINIMUM`] if no specific minimum value.
+    fn effective_minimum_value(&self) -> u16 {
+        if self.is_no_specific_minimum_value() {
+            CONNECTION_INTERVAL_MINIMUM
+        } else {
+            self.minimum_value.0
+        }
+    }
+
+    /// Get Maximum connection interval, [`CONNECTION_INTERVAL_MAXIMUM`] if no specific maximum value.
+    fn effective_maximum_value(&self) -> u16 {
+        if self.is_no_specific_maximum_value() {
+            CONNECTION_INTERVAL_MAXIMUM
+        } else {
+            self.maximum_value.0
+        }
+    }
+
+    /// Check connection interval is in the range.
+    ///
+    /// No specific minimum / maximum value is treated as [`CONNECTION_INTERVAL_MINIMUM`] / [`CONNECTION_INTERVAL_MAXIMUM`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ble_data_struct::data_types::peripheral_connection_interval_range::{
+    ///     PeripheralConnectionIntervalRange, CONNECTION_INTERVAL_NO_SPECIFIC_VALUE,
+    /// };
+    /// use ble_data_struct::data_types::time_units::Units1_25ms;
+    ///
+    /// let result = PeripheralConnectionIntervalRange::new(Units1_25ms(0x0010), Units1_25ms(0x0020));
+    /// assert!(result.contains(Units1_25ms(0x0010)));
+    /// assert!(result.contains(Units1_25ms(0x0020)));
+    /// assert!(!result.contains(Units1_25ms(0x000f)));
+    /// assert!(!result.contains(Units1_25ms(0x0021)));
+    ///
+    /// let result = PeripheralConnectionIntervalRange::new(Units1_25ms(0x0010), Units1_25ms(CONNECTION_INTERVAL_NO_SPECIFIC_VALUE));
+    /// assert!(result.contains(Units1_25ms(0x0c80)));
+    /// assert!(!result.contains(Units1_25ms(0x0c81)));
+    /// ```
+    pub fn contains(&self, interval: Units1_25ms) -> bool {
+        self.effective_minimum_value() <= interval.0 && interval.0 <= self.effective_maximum_value()
+    }
+
+    /// Create the range accepted by both [`PeripheralConnectionIntervalRange`].
+    ///
+    /// Returns [`None`] if the ranges do not overlap.
+    /// No specific value is kept only if both minimum / maximum values are no specific.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ble_data_struct::data_types::peripheral_connection_interval_range::{
+    ///     PeripheralConnectionIntervalRange, CONNECTION_INTERVAL_NO_SPECIFIC_VALUE,
+    /// };
+    /// use ble_data_struct::data_types::time_units::Units1_25ms;
+    ///
+    /// let range1 = PeripheralConnectionIntervalRange::new(Units1_25ms(0x0010), Units1_25ms(0x0030));
+    /// let range2 = PeripheralConnectionIntervalRange::new(Units1_25ms(0x0020), Units1_25ms(CONNECTION_INTERVAL_NO_SPECIFIC_VALUE));
+    /// assert_eq!(
+    ///     Some(PeripheralConnectionIntervalRange::new(Units1_25ms(0x0020), Units1_25ms(0x0030))),
+    ///     range1.intersect(&range2)
+    /// );
+    ///
+    /// let range2 = PeripheralConnectionIntervalRange::new(Units1_25ms(0x0040), Units1_25ms(0x0050));
+    /// assert_eq!(None, range1.intersect(&range2));
+    /// ```
+    pub fn intersect(&self, other: &Self) -> Option<Self> {
+        let minimum_value = self
+            .effective_minimum_value()
+            .max(other.effective_minimum_value());
+        let maximum_value = self
+            .effective_maximum_value()
+            .min(other.effective_maximum_value());
+        if minimum_value > maximum_value {
+            return None;
+        }
+        Some(Self::new(
+            Units1_25ms(
+                if self.is_no_specific_minimum_value() && other.is_no_specific_minimum_value() {
+                    CONNECTION_INTERVAL_NO_SPECIFIC_VALUE
+                } else {
+                    minimum_value
+                },
+            ),
+            Units1_25ms(
+                if self.is_no_specific_maximum_value() && other.is_no_specific_maximum_value() {
+                    CONNECTION_INTERVAL_NO_SPECIFIC_VALUE
+                } else {
+                    maximum_value
+                },
+            ),
+        ))
+    }
+
+    /// Validate minimum / maximum connection interval.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ble_data_struct::data_types::{
+    ///     connection_parameters::ConnectionParameterViolation,
+    ///     peripheral_connection_interval_range::PeripheralConnectionIntervalRange,
+    /// };
+    /// use ble_data_struct::data_types::time_units::Units1_25ms;
+    ///
+    /// assert!(PeripheralConnectionIntervalRange::new(Units1_25ms(0x0010), Units1_25ms(0x0020)).validate().is_ok());
+    /// assert_eq!(
+    ///     Err(vec![ConnectionParameterViolation::IntervalOrder {
+    ///         interval_min: 0x0020,
+    ///         interval_max: 0x0010
+    ///     }]),
+    ///     PeripheralConnectionIntervalRange::new(Units1_25ms(0x0020), Units1_25ms(0x0010)).validate()
+    /// );
+    /// ```
+    pub fn validate(&self) -> Result<(), Vec<ConnectionParameterViolation>> {
+        let violations =
+            check_connection_interval_range(self.minimum_value.0, self.maximum_value.0);
+        if violations.is_empty() {
+            Ok(())
+        } else {
+            Err(violations)
+        }
     }
 }
 
@@ -140,14 +277,42 @@ pub const CONNECTION_INTERVAL_RANGE: f32 = 1.25;
 /// no specific minimum / maximum values
 pub const CONNECTION_INTERVAL_NO_SPECIFIC_VALUE: u16 = 0xffff;
 
+/// Minimum connection interval value (7.5 ms)
+pub const CONNECTION_INTERVAL_MINIMUM: u16 = 0x0006;
+
+/// Maximum connection interval value (4 s)
+pub const CONNECTION_INTERVAL_MAXIMUM: u16 = 0x0c80;
+
+impl TryFrom<&[u8]> for PeripheralConnectionIntervalRange {
+    type Error = DataTypeParseError;
+    /// Create [`PeripheralConnectionIntervalRange`] from `&[u8]`.
+    fn try_from(value: &[u8]) -> Result<Self, DataTypeParseError> {
+        let len = value.len();
+        if len < 6 {
+            return Err(DataTypeParseError::InvalidLength {
+                expected: 6,
+                actual: len,
+            });
+        }
+        let length = value[0];
+        Ok(Self {
+            length,
+            minimum_value: Units1_25ms(u16::from_le_bytes(value[2..4].try_into().unwrap())),
+            maximum_value: Units1_25ms(u16::from_le_bytes(value[4..6].try_into().unwrap())),
+        })
+    }
+}
+
 impl TryFrom<&Vec<u8>> for PeripheralConnectionIntervalRange {
-    type Error = String;
+    type Error = DataTypeParseError;
     /// Create [`PeripheralConnectionIntervalRange`] from [`Vec<u8>`].
     ///
     /// # Examples
     ///
     /// ```
     /// use ble_data_struct::data_types::{peripheral_connection_interval_range::PeripheralConnectionIntervalRange, data_type::DataType};
+    /// use ble_data_struct::data_types::time_units::Units1_25ms;
+    /// use ble_data_struct::data_types::parse_error::DataTypeParseError;
     ///
     /// let minimum_value = 0x0006u16;
     /// let maximum_value = 0x0C80u16;
@@ -162,28 +327,22 @@ impl TryFrom<&Vec<u8>> for PeripheralConnectionIntervalRange {
     /// assert!(result.is_ok());
     /// let data_type = result.unwrap();
     /// assert_eq!(length, data_type.length);
-    /// assert_eq!(minimum_value, data_type.minimum_value);
-    /// assert_eq!(maximum_value, data_type.maximum_value);
+    /// assert_eq!(Units1_25ms(minimum_value), data_type.minimum_value);
+    /// assert_eq!(Units1_25ms(maximum_value), data_type.maximum_value);
     ///
     /// let data: Vec<u8> = Vec::new();
     /// let result = PeripheralConnectionIntervalRange::try_from(&data);
     /// assert!(result.is_err());
     /// assert_eq!(
-    ///     format!("Invalid data size :{}", data.len()),
+    ///     DataTypeParseError::InvalidLength {
+    ///         expected: 6,
+    ///         actual: data.len()
+    ///     },
     ///     result.unwrap_err()
     /// );
     /// ```
-    fn try_from(value: &Vec<u8>) -> Result<Self, String> {
-        let len = value.len();
-        if len < 6 {
-            return Err(format!("Invalid data size :{}", len).to_string());
-        }
-        let length = value[0];
-        Ok(Self {
-            length,
-            minimum_value: u16::from_le_bytes(value[2..4].try_into().unwrap()),
-            maximum_value: u16::from_le_bytes(value[4..6].try_into().unwrap()),
-        })
+    fn try_from(value: &Vec<u8>) -> Result<Self, DataTypeParseError> {
+        Self::try_from(value.as_slice())
     }
 }
 
@@ -194,10 +353,11 @@ impl Into<Vec<u8>> for PeripheralConnectionIntervalRange {
     ///
     /// ```
     /// use ble_data_struct::data_types::{peripheral_connection_interval_range::PeripheralConnectionIntervalRange, data_type::DataType};
+    /// use ble_data_struct::data_types::time_units::Units1_25ms;
     ///
     /// let minimum_value = 0x0006u16;
     /// let maximum_value = 0x0C80u16;
-    /// let result1 = PeripheralConnectionIntervalRange::new(minimum_value, maximum_value);
+    /// let result1 = PeripheralConnectionIntervalRange::new(Units1_25ms(minimum_value), Units1_25ms(maximum_value));
     ///
     /// let mut data: Vec<u8> = Vec::new();
     /// data.push(5);
@@ -218,8 +378,8 @@ impl Into<Vec<u8>> for PeripheralConnectionIntervalRange {
         let mut data: Vec<u8> = Vec::new();
         data.push(self.length);
         data.push(Self::data_type());
-        data.append(&mut self.minimum_value.to_le_bytes().to_vec());
-        data.append(&mut self.maximum_value.to_le_bytes().to_vec());
+        data.append(&mut self.minimum_value.0.to_le_bytes().to_vec());
+        data.append(&mut self.maximum_value.0.to_le_bytes().to_vec());
         return data;
     }
 }
@@ -234,6 +394,7 @@ impl DataType for PeripheralConnectionIntervalRange {
     ///
     /// assert_eq!(0x12, PeripheralConnectionIntervalRange::data_type());
     /// ```
+    #[inline]
     fn data_type() -> u8 {
         0x12
     }
@@ -250,29 +411,39 @@ impl DataType for PeripheralConnectionIntervalRange {
 /// assert!(is_peripheral_connection_interval_range(0x12));
 /// assert!(!is_peripheral_connection_interval_range(0x00));
 /// ```
+#[inline]
 pub fn is_peripheral_connection_interval_range(data_type: u8) -> bool {
-    PeripheralConnectionIntervalRange::data_type() == data_type
+    AdType::from_u8(data_type) == Some(AdType::PeripheralConnectionIntervalRange)
 }
 
 #[cfg(test)]
 mod tests {
-    use crate::data_types::{data_type::DataType, peripheral_connection_interval_range::*};
+    use crate::data_types::{
+        connection_parameters::ConnectionParameterViolation, data_type::DataType,
+        peripheral_connection_interval_range::*, time_units::Units1_25ms,
+    };
 
     #[test]
     fn test_new() {
         let minimum_value = 0x0006u16;
         let maximum_value = 0x0C80u16;
-        let result = PeripheralConnectionIntervalRange::new(minimum_value, maximum_value);
+        let result = PeripheralConnectionIntervalRange::new(
+            Units1_25ms(minimum_value),
+            Units1_25ms(maximum_value),
+        );
         assert_eq!(5, result.length);
-        assert_eq!(minimum_value, result.minimum_value);
-        assert_eq!(maximum_value, result.maximum_value);
+        assert_eq!(Units1_25ms(minimum_value), result.minimum_value);
+        assert_eq!(Units1_25ms(maximum_value), result.maximum_value);
     }
 
     #[test]
     fn test_minimum_value_millis() {
         let minimum_value = 0x0006u16;
         let maximum_value = 0x0C80u16;
-        let result = PeripheralConnectionIntervalRange::new(minimum_value, maximum_value);
+        let result = PeripheralConnectionIntervalRange::new(
+            Units1_25ms(minimum_value),
+            Units1_25ms(maximum_value),
+        );
         assert_eq!(
             minimum_value as f32 * CONNECTION_INTERVAL_RANGE,
             result.minimum_value_millis()
@@ -283,7 +454,10 @@ mod tests {
     fn test_maximum_value_millis() {
         let minimum_value = 0x0006u16;
         let maximum_value = 0x0C80u16;
-        let result = PeripheralConnectionIntervalRange::new(minimum_value, maximum_value);
+        let result = PeripheralConnectionIntervalRange::new(
+            Units1_25ms(minimum_value),
+            Units1_25ms(maximum_value),
+        );
         assert_eq!(
             minimum_value as f32 * CONNECTION_INTERVAL_RANGE,
             result.minimum_value_millis()
@@ -294,12 +468,18 @@ mod tests {
     fn test_is_no_specific_minimum_value() {
         let minimum_value = 0x0006u16;
         let maximum_value = 0x0C80u16;
-        let result = PeripheralConnectionIntervalRange::new(minimum_value, maximum_value);
+        let result = PeripheralConnectionIntervalRange::new(
+            Units1_25ms(minimum_value),
+            Units1_25ms(maximum_value),
+        );
         assert!(!result.is_no_specific_minimum_value());
 
         let minimum_value = CONNECTION_INTERVAL_NO_SPECIFIC_VALUE;
         let maximum_value = 0x0C80u16;
-        let result = PeripheralConnectionIntervalRange::new(minimum_value, maximum_value);
+        let result = PeripheralConnectionIntervalRange::new(
+            Units1_25ms(minimum_value),
+            Units1_25ms(maximum_value),
+        );
         assert!(result.is_no_specific_minimum_value());
     }
 
@@ -307,15 +487,113 @@ mod tests {
     fn test_is_no_specific_maximum_value() {
         let minimum_value = 0x0006u16;
         let maximum_value = 0x0C80u16;
-        let result = PeripheralConnectionIntervalRange::new(minimum_value, maximum_value);
+        let result = PeripheralConnectionIntervalRange::new(
+            Units1_25ms(minimum_value),
+            Units1_25ms(maximum_value),
+        );
         assert!(!result.is_no_specific_maximum_value());
 
         let minimum_value = 0x0006u16;
         let maximum_value = CONNECTION_INTERVAL_NO_SPECIFIC_VALUE;
-        let result = PeripheralConnectionIntervalRange::new(minimum_value, maximum_value);
+        let result = PeripheralConnectionIntervalRange::new(
+            Units1_25ms(minimum_value),
+            Units1_25ms(maximum_value),
+        );
         assert!(result.is_no_specific_maximum_value());
     }
 
+    #[test]
+    fn test_contains() {
+        let result =
+            PeripheralConnectionIntervalRange::new(Units1_25ms(0x0010), Units1_25ms(0x0020));
+        assert!(result.contains(Units1_25ms(0x0010)));
+        assert!(result.contains(Units1_25ms(0x0020)));
+        assert!(!result.contains(Units1_25ms(0x000f)));
+        assert!(!result.contains(Units1_25ms(0x0021)));
+
+        let result = PeripheralConnectionIntervalRange::new(
+            Units1_25ms(CONNECTION_INTERVAL_NO_SPECIFIC_VALUE),
+            Units1_25ms(CONNECTION_INTERVAL_NO_SPECIFIC_VALUE),
+        );
+        assert!(!result.contains(Units1_25ms(CONNECTION_INTERVAL_MINIMUM - 1)));
+        assert!(result.contains(Units1_25ms(CONNECTION_INTERVAL_MINIMUM)));
+        assert!(result.contains(Units1_25ms(CONNECTION_INTERVAL_MAXIMUM)));
+        assert!(!result.contains(Units1_25ms(CONNECTION_INTERVAL_MAXIMUM + 1)));
+    }
+
+    #[test]
+    fn test_validate() {
+        assert!(
+            PeripheralConnectionIntervalRange::new(Units1_25ms(0x0010), Units1_25ms(0x0020))
+                .validate()
+                .is_ok()
+        );
+        assert!(PeripheralConnectionIntervalRange::new(
+            Units1_25ms(CONNECTION_INTERVAL_NO_SPECIFIC_VALUE),
+            Units1_25ms(CONNECTION_INTERVAL_NO_SPECIFIC_VALUE)
+        )
+        .validate()
+        .is_ok());
+        assert_eq!(
+            Err(vec![ConnectionParameterViolation::IntervalOutOfRange {
+                interval: CONNECTION_INTERVAL_MAXIMUM + 1
+            }]),
+            PeripheralConnectionIntervalRange::new(
+                Units1_25ms(0x0010),
+                Units1_25ms(CONNECTION_INTERVAL_MAXIMUM + 1)
+            )
+            .validate()
+        );
+        assert_eq!(
+            Err(vec![ConnectionParameterViolation::IntervalOrder {
+                interval_min: 0x0020,
+                interval_max: 0x0010
+            }]),
+            PeripheralConnectionIntervalRange::new(Units1_25ms(0x0020), Units1_25ms(0x0010))
+                .validate()
+        );
+    }
+
+    #[test]
+    fn test_intersect() {
+        let range1 =
+            PeripheralConnectionIntervalRange::new(Units1_25ms(0x0010), Units1_25ms(0x0030));
+        let range2 = PeripheralConnectionIntervalRange::new(
+            Units1_25ms(0x0020),
+            Units1_25ms(CONNECTION_INTERVAL_NO_SPECIFIC_VALUE),
+        );
+        assert_eq!(
+            Some(PeripheralConnectionIntervalRange::new(
+                Units1_25ms(0x0020),
+                Units1_25ms(0x0030)
+            )),
+            range1.intersect(&range2)
+        );
+        assert_eq!(range1.intersect(&range2), range2.intersect(&range1));
+
+        let range1 = PeripheralConnectionIntervalRange::new(
+            Units1_25ms(CONNECTION_INTERVAL_NO_SPECIFIC_VALUE),
+            Units1_25ms(0x0030),
+        );
+        let range2 = PeripheralConnectionIntervalRange::new(
+            Units1_25ms(CONNECTION_INTERVAL_NO_SPECIFIC_VALUE),
+            Units1_25ms(CONNECTION_INTERVAL_NO_SPECIFIC_VALUE),
+        );
+        assert_eq!(
+            Some(PeripheralConnectionIntervalRange::new(
+                Units1_25ms(CONNECTION_INTERVAL_NO_SPECIFIC_VALUE),
+                Units1_25ms(0x0030)
+            )),
+            range1.intersect(&range2)
+        );
+
+        let range1 =
+            PeripheralConnectionIntervalRange::new(Units1_25ms(0x0010), Units1_25ms(0x0030));
+        let range2 =
+            PeripheralConnectionIntervalRange::new(Units1_25ms(0x0031), Units1_25ms(0x0050));
+        assert_eq!(None, range1.intersect(&range2));
+    }
+
     #[test]
     fn test_try_from() {
         let minimum_value = 0x0006u16;
@@ -331,15 +609,18 @@ mod tests {
         assert!(result.is_ok());
         let data_type = result.unwrap();
         assert_eq!(length, data_type.length);
-        assert_eq!(minimum_value, data_type.minimum_value);
-        assert_eq!(maximum_value, data_type.maximum_value);
+        assert_eq!(Units1_25ms(minimum_value), data_type.minimum_value);
+        assert_eq!(Units1_25ms(maximum_value), data_type.maximum_value);
 
         let mut data: Vec<u8> = vec![0u8; 5];
         data[0] = data.len() as u8 - 1;
         let result = PeripheralConnectionIntervalRange::try_from(&data);
         assert!(result.is_err());
         assert_eq!(
-            format!("Invalid data size :{}", data.len()),
+            DataTypeParseError::InvalidLength {
+                expected: 6,
+                actual: data.len()
+            },
             result.unwrap_err()
         );
     }
@@ -348,7 +629,10 @@ mod tests {
     fn test_into() {
         let minimum_value = 0x0006u16;
         let maximum_value = 0x0C80u16;
-        let result1 = PeripheralConnectionIntervalRange::new(minimum_value, maximum_value);
+        let result1 = PeripheralConnectionIntervalRange::new(
+            Units1_25ms(minimum_value),
+            Units1_25ms(maximum_value),
+        );
 
         let mut data: Vec<u8> = Vec::new();
         data.push(5);
@@ -0,0 +1,346 @@
+//! Private Device Address generation module.
+//!
+//! Random values are supplied by the caller (use a cryptographically secure random number generator).
+
+use std::time::{Duration, Instant};
+
+#[cfg(feature = "crypto")]
+use crate::crypto::toolbox::ah;
+
+/// Recommended private address rotation interval(15 minutes).
+pub const DEFAULT_ROTATION_INTERVAL: Duration = Duration::from_secs(15 * 60);
+
+/// Random part mask of Non-resolvable private address (46 bits).
+const NON_RESOLVABLE_RANDOM_MASK: u64 = 0x3fff_ffff_ffff;
+
+/// Random part mask of prand (22 bits).
+#[cfg(feature = "crypto")]
+const PRAND_RANDOM_MASK: u32 = 0x3f_ffff;
+
+/// Create Non-resolvable private address from random value.
+///
+/// Two most significant bits are set to `0b00`.
+/// The random part must not be all 0 or all 1.
+///
+/// # Examples
+///
+/// ```
+/// use ble_data_struct::data_types::private_address::non_resolvable_private_address;
+///
+/// assert_eq!(Ok(0x060504030201u64), non_resolvable_private_address(0xffff_0605_0403_0201u64));
+/// assert!(non_resolvable_private_address(0).is_err());
+/// assert!(non_resolvable_private_address(0x3fff_ffff_ffff).is_err());
+/// ```
+pub fn non_resolvable_private_address(random: u64) -> Result<u64, String> {
+    let address = random & NON_RESOLVABLE_RANDOM_MASK;
+    if address == 0 || address == NON_RESOLVABLE_RANDOM_MASK {
+        return Err(format!("Invalid random part :{}", address));
+    }
+    Ok(address)
+}
+
+/// Create Resolvable private address from IRK and random value.
+///
+/// Two most significant bits of prand are set to `0b01`.
+/// The random part of prand must not be all 0 or all 1.
+///
+/// # Examples
+///
+/// ```
+/// use ble_data_struct::crypto::toolbox::resolve_private_address;
+/// use ble_data_struct::data_types::private_address::resolvable_private_address;
+///
+/// // Core Specification Vol 3, Part H, D.7
+/// let irk = 0xec0234a357c8ad05341010a60a397d9bu128;
+/// let result = resolvable_private_address(irk, 0x308194);
+/// assert_eq!(Ok(0x7081940dfbaau64), result);
+/// assert!(resolve_private_address(irk, result.unwrap()));
+///
+/// assert!(resolvable_private_address(irk, 0).is_err());
+/// assert!(resolvable_private_address(irk, 0x3f_ffff).is_err());
+/// ```
+#[cfg(feature = "crypto")]
+pub fn resolvable_private_address(irk: u128, random: u32) -> Result<u64, String> {
+    let random = random & PRAND_RANDOM_MASK;
+    if random == 0 || random == PRAND_RANDOM_MASK {
+        return Err(format!("Invalid random part :{}", random));
+    }
+    let prand = 0x40_0000 | random;
+    Ok(((prand as u64) << 24) | ah(irk, prand) as u64)
+}
+
+/// Private address kind.
+#[derive(Debug, PartialEq, Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum PrivateAddressKind {
+    /// Non-resolvable private address
+    NonResolvable,
+
+    /// Resolvable private address
+    #[cfg(feature = "crypto")]
+    Resolvable {
+        /// Identity Resolving Key
+        irk: u128,
+    },
+}
+
+impl PrivateAddressKind {
+    /// Create private address from random value.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ble_data_struct::data_types::private_address::PrivateAddressKind;
+    ///
+    /// assert_eq!(
+    ///     Ok(0x060504030201u64),
+    ///     PrivateAddressKind::NonResolvable.generate(0x060504030201u64)
+    /// );
+    /// ```
+    pub fn generate(&self, random: u64) -> Result<u64, String> {
+        match self {
+            PrivateAddressKind::NonResolvable => non_resolvable_private_address(random),
+            #[cfg(feature = "crypto")]
+            PrivateAddressKind::Resolvable { irk } => {
+                resolvable_private_address(*irk, random as u32)
+            }
+        }
+    }
+}
+
+/// Private address rotation schedule.
+#[derive(Debug, PartialEq, Clone)]
+pub struct PrivateAddressSchedule {
+    /// Private address kind
+    pub kind: PrivateAddressKind,
+
+    /// Rotation interval
+    pub interval: Duration,
+
+    /// Current address and its creation time
+    current: Option<(u64, Instant)>,
+}
+
+impl PrivateAddressSchedule {
+    /// Create [`PrivateAddressSchedule`] from kind and rotation interval.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ble_data_struct::data_types::private_address::{
+    ///     PrivateAddressKind, PrivateAddressSchedule, DEFAULT_ROTATION_INTERVAL,
+    /// };
+    ///
+    /// let result =
+    ///     PrivateAddressSchedule::new(PrivateAddressKind::NonResolvable, DEFAULT_ROTATION_INTERVAL);
+    /// assert_eq!(PrivateAddressKind::NonResolvable, result.kind);
+    /// assert_eq!(DEFAULT_ROTATION_INTERVAL, result.interval);
+    /// assert_eq!(None, result.address());
+    /// ```
+    pub fn new(kind: PrivateAddressKind, interval: Duration) -> Self {
+        Self {
+            kind,
+            interval,
+            current: None,
+        }
+    }
+
+    /// Current address.
+    pub fn address(&self) -> Option<u64> {
+        self.current.map(|(address, _)| address)
+    }
+
+    /// Time of the next rotation(`None` before the first rotation).
+    pub fn next_rotation(&self) -> Option<Instant> {
+        self.current
+            .map(|(_, rotated_at)| rotated_at + self.interval)
+    }
+
+    /// Check the address must be rotated at `now`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::time::{Duration, Instant};
+    /// use ble_data_struct::data_types::private_address::{
+    ///     PrivateAddressKind, PrivateAddressSchedule,
+    /// };
+    ///
+    /// let now = Instant::now();
+    /// let mut schedule =
+    ///     PrivateAddressSchedule::new(PrivateAddressKind::NonResolvable, Duration::from_secs(60));
+    /// assert!(schedule.is_due(now));
+    ///
+    /// schedule.rotate(0x060504030201u64, now).unwrap();
+    /// assert!(!schedule.is_due(now + Duration::from_secs(59)));
+    /// assert!(schedule.is_due(now + Duration::from_secs(60)));
+    /// ```
+    pub fn is_due(&self, now: Instant) -> bool {
+        match self.next_rotation() {
+            Some(next_rotation) => now >= next_rotation,
+            None => true,
+        }
+    }
+
+    /// Create a new address from random value at `now`.
+    ///
+    /// A random value that creates the current address again is rejected.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::time::{Duration, Instant};
+    /// use ble_data_struct::data_types::private_address::{
+    ///     PrivateAddressKind, PrivateAddressSchedule,
+    /// };
+    ///
+    /// let now = Instant::now();
+    /// let mut schedule =
+    ///     PrivateAddressSchedule::new(PrivateAddressKind::NonResolvable, Duration::from_secs(60));
+    /// assert_eq!(Ok(0x060504030201u64), schedule.rotate(0x060504030201u64, now));
+    /// assert_eq!(Some(0x060504030201u64), schedule.address());
+    /// assert_eq!(Some(now + Duration::from_secs(60)), schedule.next_rotation());
+    ///
+    /// assert!(schedule.rotate(0x060504030201u64, now).is_err());
+    /// assert!(schedule.rotate(0, now).is_err());
+    /// ```
+    pub fn rotate(&mut self, random: u64, now: Instant) -> Result<u64, String> {
+        let address = self.kind.generate(random)?;
+        if self.address() == Some(address) {
+            return Err(format!("Same address :{}", address));
+        }
+        self.current = Some((address, now));
+        Ok(address)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::{Duration, Instant};
+
+    use crate::{
+        data_types::{
+            address_type::{AddressType, RandomAddressType},
+            data_type_parser::DataTypeParseResults,
+            private_address::{
+                non_resolvable_private_address, PrivateAddressKind, PrivateAddressSchedule,
+                DEFAULT_ROTATION_INTERVAL,
+            },
+        },
+        scan::{privacy::audit_privacy, scan_record::ScanRecord},
+    };
+
+    #[test]
+    fn test_non_resolvable_private_address() {
+        let result = non_resolvable_private_address(0xffff_0605_0403_0201u64);
+        assert_eq!(Ok(0x0605_0403_0201u64), result);
+        assert_eq!(
+            RandomAddressType::NonResolvablePrivate,
+            RandomAddressType::from_address(result.unwrap())
+        );
+        assert_eq!(
+            Err("Invalid random part :0".to_string()),
+            non_resolvable_private_address(0xc000_0000_0000u64)
+        );
+        assert!(non_resolvable_private_address(0x3fff_ffff_ffffu64).is_err());
+        assert!(non_resolvable_private_address(0x3fff_ffff_fffeu64).is_ok());
+    }
+
+    #[cfg(feature = "crypto")]
+    #[test]
+    fn test_resolvable_private_address() {
+        use crate::{
+            crypto::toolbox::resolve_private_address,
+            data_types::private_address::resolvable_private_address,
+        };
+
+        let irk = 0xec0234a357c8ad05341010a60a397d9bu128;
+        let result = resolvable_private_address(irk, 0xff30_8194);
+        assert_eq!(Ok(0x7081940dfbaau64), result);
+        let address = result.unwrap();
+        assert_eq!(
+            RandomAddressType::ResolvablePrivate,
+            RandomAddressType::from_address(address)
+        );
+        assert!(resolve_private_address(irk, address));
+        assert!(!resolve_private_address(irk + 1, address));
+
+        assert_eq!(
+            Err("Invalid random part :0".to_string()),
+            resolvable_private_address(irk, 0x40_0000)
+        );
+        assert!(resolvable_private_address(irk, 0x3f_ffff).is_err());
+
+        assert_eq!(
+            Ok(0x7081940dfbaau64),
+            PrivateAddressKind::Resolvable { irk }.generate(0x30_8194)
+        );
+    }
+
+    #[test]
+    fn test_generate() {
+        assert_eq!(
+            Ok(0x0605_0403_0201u64),
+            PrivateAddressKind::NonResolvable.generate(0x0605_0403_0201u64)
+        );
+        assert!(PrivateAddressKind::NonResolvable.generate(0).is_err());
+    }
+
+    #[test]
+    fn test_schedule() {
+        let now = Instant::now();
+        let mut schedule = PrivateAddressSchedule::new(
+            PrivateAddressKind::NonResolvable,
+            DEFAULT_ROTATION_INTERVAL,
+        );
+        assert_eq!(None, schedule.address());
+        assert_eq!(None, schedule.next_rotation());
+        assert!(schedule.is_due(now));
+
+        assert_eq!(
+            Ok(0x0605_0403_0201u64),
+            schedule.rotate(0x0605_0403_0201u64, now)
+        );
+        assert_eq!(Some(0x0605_0403_0201u64), schedule.address());
+        assert_eq!(
+            Some(now + DEFAULT_ROTATION_INTERVAL),
+            schedule.next_rotation()
+        );
+        assert!(!schedule.is_due(now + DEFAULT_ROTATION_INTERVAL - Duration::from_secs(1)));
+        assert!(schedule.is_due(now + DEFAULT_ROTATION_INTERVAL));
+
+        assert_eq!(
+            Err(format!("Same address :{}", 0x0605_0403_0201u64)),
+            schedule.rotate(0x0605_0403_0201u64, now + DEFAULT_ROTATION_INTERVAL)
+        );
+        assert!(schedule.rotate(0, now + DEFAULT_ROTATION_INTERVAL).is_err());
+        assert_eq!(
+            Some(now + DEFAULT_ROTATION_INTERVAL),
+            schedule.next_rotation()
+        );
+
+        let later = now + DEFAULT_ROTATION_INTERVAL;
+        assert_eq!(
+            Ok(0x0102_0304_0506u64),
+            schedule.rotate(0x0102_0304_0506u64, later)
+        );
+        assert_eq!(
+            Some(later + DEFAULT_ROTATION_INTERVAL),
+            schedule.next_rotation()
+        );
+    }
+
+    #[test]
+    fn test_audit_privacy() {
+        let mut schedule = PrivateAddressSchedule::new(
+            PrivateAddressKind::NonResolvable,
+            DEFAULT_ROTATION_INTERVAL,
+        );
+        let address = schedule
+            .rotate(0x0605_0403_0201u64, Instant::now())
+            .unwrap();
+        let record = ScanRecord::new(address, -60, DataTypeParseResults::new(Vec::new()))
+            .with_address_type(AddressType::from_address(true, address));
+        assert!(audit_privacy(&record).is_empty());
+    }
+}
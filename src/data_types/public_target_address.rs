@@ -1,10 +1,11 @@
 //! Public Target Address (Data Type Value:0x17) module.
 
-use crate::data_types::data_type::DataType;
+use crate::data_types::{ad_type::AdType, data_type::DataType, parse_error::DataTypeParseError};
 
 /// Public Target Address.
 
 #[derive(Debug, PartialEq, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct PublicTargetAddress {
     /// data length
     pub length: u8,
@@ -39,16 +40,64 @@ impl PublicTargetAddress {
             public_target_address: public_target_address.clone(),
         }
     }
+
+    /// Check `address` is one of the target addresses.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ble_data_struct::data_types::public_target_address::PublicTargetAddress;
+    ///
+    /// let result = PublicTargetAddress::new(&[0x060504030201u64, 0x0c0b0a090807u64].to_vec());
+    /// assert!(result.contains(0x0c0b0a090807u64));
+    /// assert!(!result.contains(0x000000000000u64));
+    /// ```
+    pub fn contains(&self, address: u64) -> bool {
+        self.public_target_address.contains(&address)
+    }
+}
+
+impl TryFrom<&[u8]> for PublicTargetAddress {
+    type Error = DataTypeParseError;
+    /// Create [`PublicTargetAddress`] from `&[u8]`.
+    fn try_from(value: &[u8]) -> Result<Self, DataTypeParseError> {
+        let len = value.len();
+        if len < 8 {
+            return Err(DataTypeParseError::InvalidLength {
+                expected: 8,
+                actual: len,
+            });
+        }
+        let length = value[0];
+        Ok(Self {
+            length,
+            public_target_address: value[2..2 + length as usize - 1]
+                .windows(6)
+                .step_by(6)
+                .map(|w| {
+                    let mut bytes = [0x00u8; 8];
+                    bytes[0] = w[0];
+                    bytes[1] = w[1];
+                    bytes[2] = w[2];
+                    bytes[3] = w[3];
+                    bytes[4] = w[4];
+                    bytes[5] = w[5];
+                    u64::from_le_bytes(bytes)
+                })
+                .collect(),
+        })
+    }
 }
 
 impl TryFrom<&Vec<u8>> for PublicTargetAddress {
-    type Error = String;
+    type Error = DataTypeParseError;
     /// Create [`PublicTargetAddress`] from [`Vec<u8>`].
     ///
     /// # Examples
     ///
     /// ```
     /// use ble_data_struct::data_types::{public_target_address::PublicTargetAddress, data_type::DataType};
+    /// use ble_data_struct::data_types::parse_error::DataTypeParseError;
     ///
     /// let public_target_address_bytes = [
     ///     0x01u8, 0x02u8, 0x03u8, 0x04u8, 0x05u8, 0x06u8, 0x07u8, 0x08u8, 0x09u8, 0x0au8, 0x0bu8,
@@ -85,33 +134,15 @@ impl TryFrom<&Vec<u8>> for PublicTargetAddress {
     /// let result = PublicTargetAddress::try_from(&data);
     /// assert!(result.is_err());
     /// assert_eq!(
-    ///     format!("Invalid data size :{}", data.len()),
+    ///     DataTypeParseError::InvalidLength {
+    ///         expected: 8,
+    ///         actual: data.len()
+    ///     },
     ///     result.unwrap_err()
     /// );
     /// ```
-    fn try_from(value: &Vec<u8>) -> Result<Self, String> {
-        let len = value.len();
-        if len < 8 {
-            return Err(format!("Invalid data size :{}", len).to_string());
-        }
-        let length = value[0];
-        Ok(Self {
-            length,
-            public_target_address: value[2..2 + length as usize - 1]
-                .windows(6)
-                .step_by(6)
-                .map(|w| {
-                    let mut bytes = [0x00u8; 8];
-                    bytes[0] = w[0];
-                    bytes[1] = w[1];
-                    bytes[2] = w[2];
-                    bytes[3] = w[3];
-                    bytes[4] = w[4];
-                    bytes[5] = w[5];
-                    u64::from_le_bytes(bytes)
-                })
-                .collect(),
-        })
+    fn try_from(value: &Vec<u8>) -> Result<Self, DataTypeParseError> {
+        Self::try_from(value.as_slice())
     }
 }
 
@@ -186,6 +217,7 @@ impl DataType for PublicTargetAddress {
     ///
     /// assert_eq!(0x17, PublicTargetAddress::data_type());
     /// ```
+    #[inline]
     fn data_type() -> u8 {
         0x17
     }
@@ -202,8 +234,9 @@ impl DataType for PublicTargetAddress {
 /// assert!(is_public_target_address(0x17));
 /// assert!(!is_public_target_address(0x00));
 /// ```
+#[inline]
 pub fn is_public_target_address(data_type: u8) -> bool {
-    PublicTargetAddress::data_type() == data_type
+    AdType::from_u8(data_type) == Some(AdType::PublicTargetAddress)
 }
 
 #[cfg(test)]
@@ -226,6 +259,17 @@ mod tests {
         assert_eq!(public_target_address, result.public_target_address);
     }
 
+    #[test]
+    fn test_contains() {
+        let result = PublicTargetAddress::new(&[0x060504030201u64, 0x0c0b0a090807u64].to_vec());
+        assert!(result.contains(0x060504030201u64));
+        assert!(result.contains(0x0c0b0a090807u64));
+        assert!(!result.contains(0x000000000000u64));
+
+        let result = PublicTargetAddress::new(&Vec::new());
+        assert!(!result.contains(0x060504030201u64));
+    }
+
     #[test]
     fn test_try_from() {
         let public_target_address_bytes = [
@@ -258,13 +302,16 @@ mod tests {
         let data_type = result.unwrap();
         assert_eq!(length, data_type.length);
         assert_eq!(public_target_address, data_type.public_target_address);
-        
+
         let mut data: Vec<u8> = vec![0u8; 7];
         data[0] = data.len() as u8 - 1;
         let result = PublicTargetAddress::try_from(&data);
         assert!(result.is_err());
         assert_eq!(
-            format!("Invalid data size :{}", data.len()),
+            DataTypeParseError::InvalidLength {
+                expected: 8,
+                actual: data.len()
+            },
             result.unwrap_err()
         );
     }
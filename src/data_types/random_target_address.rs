@@ -1,9 +1,13 @@
 //! Random Target Address (Data Type Value:0x18) module.
 
-use crate::data_types::data_type::DataType;
+use crate::data_types::{ad_type::AdType, data_type::DataType, parse_error::DataTypeParseError};
+
+#[cfg(feature = "crypto")]
+use crate::crypto::toolbox::resolve_private_address;
 
 /// Random Target Address.
 #[derive(Debug, PartialEq, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct RandomTargetAddress {
     /// data length
     pub length: u8,
@@ -38,16 +42,85 @@ impl RandomTargetAddress {
             random_target_address: random_target_address.clone(),
         }
     }
+
+    /// Check `address` is one of the target addresses.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ble_data_struct::data_types::random_target_address::RandomTargetAddress;
+    ///
+    /// let result = RandomTargetAddress::new(&[0x060504030201u64, 0x0c0b0a090807u64].to_vec());
+    /// assert!(result.contains(0x0c0b0a090807u64));
+    /// assert!(!result.contains(0x000000000000u64));
+    /// ```
+    pub fn contains(&self, address: u64) -> bool {
+        self.random_target_address.contains(&address)
+    }
+
+    /// Check any of the target addresses is a resolvable private address generated from the IRK.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ble_data_struct::data_types::random_target_address::RandomTargetAddress;
+    ///
+    /// let irk = 0xec0234a357c8ad05341010a60a397d9bu128;
+    /// let result = RandomTargetAddress::new(&[0x060504030201u64, 0x7081940dfbaau64].to_vec());
+    /// assert!(result.contains_resolvable(irk));
+    ///
+    /// let result = RandomTargetAddress::new(&[0x060504030201u64].to_vec());
+    /// assert!(!result.contains_resolvable(irk));
+    /// ```
+    #[cfg(feature = "crypto")]
+    pub fn contains_resolvable(&self, irk: u128) -> bool {
+        self.random_target_address
+            .iter()
+            .any(|f| resolve_private_address(irk, *f))
+    }
+}
+
+impl TryFrom<&[u8]> for RandomTargetAddress {
+    type Error = DataTypeParseError;
+    /// Create [`RandomTargetAddress`] from `&[u8]`.
+    fn try_from(value: &[u8]) -> Result<Self, DataTypeParseError> {
+        let len = value.len();
+        if len < 8 {
+            return Err(DataTypeParseError::InvalidLength {
+                expected: 8,
+                actual: len,
+            });
+        }
+        let length = value[0];
+        Ok(Self {
+            length,
+            random_target_address: value[2..2 + length as usize - 1]
+                .windows(6)
+                .step_by(6)
+                .map(|w| {
+                    let mut bytes = [0x00u8; 8];
+                    bytes[0] = w[0];
+                    bytes[1] = w[1];
+                    bytes[2] = w[2];
+                    bytes[3] = w[3];
+                    bytes[4] = w[4];
+                    bytes[5] = w[5];
+                    u64::from_le_bytes(bytes)
+                })
+                .collect(),
+        })
+    }
 }
 
 impl TryFrom<&Vec<u8>> for RandomTargetAddress {
-    type Error = String;
+    type Error = DataTypeParseError;
     /// Create [`RandomTargetAddress`] from [`Vec<u8>`].
     ///
     /// # Examples
     ///
     /// ```
     /// use ble_data_struct::data_types::{random_target_address::RandomTargetAddress, data_type::DataType};
+    /// use ble_data_struct::data_types::parse_error::DataTypeParseError;
     ///
     /// let random_target_address_bytes = [
     ///     0x01u8, 0x02u8, 0x03u8, 0x04u8, 0x05u8, 0x06u8, 0x07u8, 0x08u8, 0x09u8, 0x0au8, 0x0bu8,
@@ -84,33 +157,15 @@ impl TryFrom<&Vec<u8>> for RandomTargetAddress {
     /// let result = RandomTargetAddress::try_from(&data);
     /// assert!(result.is_err());
     /// assert_eq!(
-    ///     format!("Invalid data size :{}", data.len()),
+    ///     DataTypeParseError::InvalidLength {
+    ///         expected: 8,
+    ///         actual: data.len()
+    ///     },
     ///     result.unwrap_err()
     /// );
     /// ```
-    fn try_from(value: &Vec<u8>) -> Result<Self, String> {
-        let len = value.len();
-        if len < 8 {
-            return Err(format!("Invalid data size :{}", len).to_string());
-        }
-        let length = value[0];
-        Ok(Self {
-            length,
-            random_target_address: value[2..2 + length as usize - 1]
-                .windows(6)
-                .step_by(6)
-                .map(|w| {
-                    let mut bytes = [0x00u8; 8];
-                    bytes[0] = w[0];
-                    bytes[1] = w[1];
-                    bytes[2] = w[2];
-                    bytes[3] = w[3];
-                    bytes[4] = w[4];
-                    bytes[5] = w[5];
-                    u64::from_le_bytes(bytes)
-                })
-                .collect(),
-        })
+    fn try_from(value: &Vec<u8>) -> Result<Self, DataTypeParseError> {
+        Self::try_from(value.as_slice())
     }
 }
 
@@ -185,6 +240,7 @@ impl DataType for RandomTargetAddress {
     ///
     /// assert_eq!(0x18, RandomTargetAddress::data_type());
     /// ```
+    #[inline]
     fn data_type() -> u8 {
         0x18
     }
@@ -201,8 +257,9 @@ impl DataType for RandomTargetAddress {
 /// assert!(is_random_target_address(0x18));
 /// assert!(!is_random_target_address(0x00));
 /// ```
+#[inline]
 pub fn is_random_target_address(data_type: u8) -> bool {
-    RandomTargetAddress::data_type() == data_type
+    AdType::from_u8(data_type) == Some(AdType::RandomTargetAddress)
 }
 
 #[cfg(test)]
@@ -225,6 +282,29 @@ mod tests {
         assert_eq!(random_target_address, result.random_target_address);
     }
 
+    #[test]
+    fn test_contains() {
+        let result = RandomTargetAddress::new(&[0x060504030201u64, 0x0c0b0a090807u64].to_vec());
+        assert!(result.contains(0x060504030201u64));
+        assert!(result.contains(0x0c0b0a090807u64));
+        assert!(!result.contains(0x000000000000u64));
+
+        let result = RandomTargetAddress::new(&Vec::new());
+        assert!(!result.contains(0x060504030201u64));
+    }
+
+    #[cfg(feature = "crypto")]
+    #[test]
+    fn test_contains_resolvable() {
+        let irk = 0xec0234a357c8ad05341010a60a397d9bu128;
+        let result = RandomTargetAddress::new(&[0x060504030201u64, 0x7081940dfbaau64].to_vec());
+        assert!(result.contains_resolvable(irk));
+        assert!(!result.contains_resolvable(irk + 1));
+
+        let result = RandomTargetAddress::new(&[0x060504030201u64].to_vec());
+        assert!(!result.contains_resolvable(irk));
+    }
+
     #[test]
     fn test_try_from() {
         let random_target_address_bytes = [
@@ -263,7 +343,10 @@ mod tests {
         let result = RandomTargetAddress::try_from(&data);
         assert!(result.is_err());
         assert_eq!(
-            format!("Invalid data size :{}", data.len()),
+            DataTypeParseError::InvalidLength {
+                expected: 8,
+                actual: data.len()
+            },
             result.unwrap_err()
         );
     }
@@ -0,0 +1,271 @@
+//! Raw EIR/AD/SRD/ACAD/OOB structure module.
+//!
+//! Escape hatch for experimental or proprietary data types without typed support.
+
+use crate::data_types::ad_structure::AdStructure;
+use crate::data_types::parse_error::DataTypeParseError;
+
+/// Maximum data size (length byte 0xff minus data type byte).
+pub const MAX_DATA_LENGTH: usize = 0xfe;
+
+/// Raw EIR/AD/SRD/ACAD/OOB structure.
+#[derive(Debug, PartialEq, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct RawAdStructure {
+    /// data type
+    pub ad_type: u8,
+
+    /// data (without length and data type, up to [`MAX_DATA_LENGTH`] octets)
+    pub data: Vec<u8>,
+}
+
+impl RawAdStructure {
+    /// Create [`RawAdStructure`] from data type and data.
+    ///
+    /// `data` longer than [`MAX_DATA_LENGTH`] does not fit in the length byte.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ble_data_struct::data_types::raw_ad_structure::RawAdStructure;
+    ///
+    /// use ble_data_struct::data_types::parse_error::DataTypeParseError;
+    ///
+    /// let data = [0x01u8, 0x02u8].to_vec();
+    /// let result = RawAdStructure::new(0x2f, &data).unwrap();
+    /// assert_eq!(0x2f, result.ad_type);
+    /// assert_eq!(data, result.data);
+    ///
+    /// let data = [0x00u8; 255];
+    /// assert_eq!(
+    ///     Err(DataTypeParseError::InvalidLength {
+    ///         expected: 254,
+    ///         actual: 255
+    ///     }),
+    ///     RawAdStructure::new(0x2f, &data)
+    /// );
+    /// ```
+    pub fn new(ad_type: u8, data: &[u8]) -> Result<Self, DataTypeParseError> {
+        if data.len() > MAX_DATA_LENGTH {
+            return Err(DataTypeParseError::InvalidLength {
+                expected: MAX_DATA_LENGTH,
+                actual: data.len(),
+            });
+        }
+        Ok(Self {
+            ad_type,
+            data: data.to_vec(),
+        })
+    }
+}
+
+impl TryFrom<&[u8]> for RawAdStructure {
+    type Error = DataTypeParseError;
+    /// Create [`RawAdStructure`] from `&[u8]`.
+    fn try_from(value: &[u8]) -> Result<Self, DataTypeParseError> {
+        let len = value.len();
+        if len < 2 {
+            return Err(DataTypeParseError::InvalidLength {
+                expected: 2,
+                actual: len,
+            });
+        }
+        let length = value[0] as usize;
+        if length == 0 {
+            return Err(DataTypeParseError::ValueOutOfRange {
+                field: "length",
+                value: 0,
+            });
+        }
+        if len < 1 + length {
+            return Err(DataTypeParseError::InvalidLength {
+                expected: 1 + length,
+                actual: len,
+            });
+        }
+        Ok(Self {
+            ad_type: value[1],
+            data: value[2..1 + length].to_vec(),
+        })
+    }
+}
+
+impl TryFrom<&Vec<u8>> for RawAdStructure {
+    type Error = DataTypeParseError;
+    /// Create [`RawAdStructure`] from [`Vec<u8>`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ble_data_struct::data_types::raw_ad_structure::RawAdStructure;
+    /// use ble_data_struct::data_types::parse_error::DataTypeParseError;
+    ///
+    /// let data: Vec<u8> = [0x03u8, 0x2fu8, 0x01u8, 0x02u8].to_vec();
+    /// let result = RawAdStructure::try_from(&data);
+    /// assert!(result.is_ok());
+    /// let data_type = result.unwrap();
+    /// assert_eq!(0x2f, data_type.ad_type);
+    /// assert_eq!([0x01u8, 0x02u8].to_vec(), data_type.data);
+    ///
+    /// let data: Vec<u8> = [0x03u8, 0x2fu8, 0x01u8].to_vec();
+    /// let result = RawAdStructure::try_from(&data);
+    /// assert!(result.is_err());
+    /// assert_eq!(
+    ///     DataTypeParseError::InvalidLength {
+    ///         expected: 4,
+    ///         actual: data.len()
+    ///     },
+    ///     result.unwrap_err()
+    /// );
+    /// ```
+    fn try_from(value: &Vec<u8>) -> Result<Self, DataTypeParseError> {
+        Self::try_from(value.as_slice())
+    }
+}
+
+impl Into<Vec<u8>> for RawAdStructure {
+    /// Create [`Vec<u8>`] from [`RawAdStructure`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ble_data_struct::data_types::raw_ad_structure::RawAdStructure;
+    ///
+    /// let result = RawAdStructure::new(0x2f, &[0x01u8, 0x02u8]).unwrap();
+    /// let into_data: Vec<u8> = result.into();
+    /// assert_eq!([0x03u8, 0x2fu8, 0x01u8, 0x02u8].to_vec(), into_data);
+    /// ```
+    fn into(self) -> Vec<u8> {
+        let mut data: Vec<u8> = Vec::new();
+        data.push(self.data.len() as u8 + 1);
+        data.push(self.ad_type);
+        data.append(&mut self.data.clone());
+        return data;
+    }
+}
+
+impl AdStructure for RawAdStructure {
+    /// return `ad_type`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ble_data_struct::data_types::{
+    ///     ad_structure::AdStructure, raw_ad_structure::RawAdStructure,
+    /// };
+    ///
+    /// let result: Box<dyn AdStructure> = Box::new(RawAdStructure::new(0x2f, &[]).unwrap());
+    /// assert_eq!(0x2f, result.ad_type());
+    /// ```
+    fn ad_type(&self) -> u8 {
+        self.ad_type
+    }
+
+    /// return [`Into<Vec<u8>>`] result.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ble_data_struct::data_types::{
+    ///     ad_structure::AdStructure, raw_ad_structure::RawAdStructure,
+    /// };
+    ///
+    /// let result: Box<dyn AdStructure> = Box::new(RawAdStructure::new(0x2f, &[0x01]).unwrap());
+    /// assert_eq!([0x02u8, 0x2fu8, 0x01u8].to_vec(), result.to_bytes());
+    /// ```
+    fn to_bytes(&self) -> Vec<u8> {
+        self.clone().into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::data_types::{
+        ad_structure::AdStructure, parse_error::DataTypeParseError,
+        raw_ad_structure::RawAdStructure,
+    };
+
+    #[test]
+    fn test_new() {
+        let data = [0x01u8, 0x02u8].to_vec();
+        let result = RawAdStructure::new(0x2f, &data).unwrap();
+        assert_eq!(0x2f, result.ad_type);
+        assert_eq!(data, result.data);
+
+        let result = RawAdStructure::new(0x2f, &[0x00u8; 254]).unwrap();
+        let into_data: Vec<u8> = result.into();
+        assert_eq!(0xff, into_data[0]);
+
+        assert_eq!(
+            Err(DataTypeParseError::InvalidLength {
+                expected: 254,
+                actual: 255
+            }),
+            RawAdStructure::new(0x2f, &[0x00u8; 255])
+        );
+    }
+
+    #[test]
+    fn test_try_from() {
+        let data: Vec<u8> = [0x03u8, 0x2fu8, 0x01u8, 0x02u8].to_vec();
+        let result = RawAdStructure::try_from(&data);
+        assert!(result.is_ok());
+        let data_type = result.unwrap();
+        assert_eq!(0x2f, data_type.ad_type);
+        assert_eq!([0x01u8, 0x02u8].to_vec(), data_type.data);
+
+        let data: Vec<u8> = [0x01u8, 0x2fu8].to_vec();
+        let result = RawAdStructure::try_from(&data);
+        assert!(result.is_ok());
+        assert!(result.unwrap().data.is_empty());
+
+        let data: Vec<u8> = [0x03u8, 0x2fu8, 0x01u8].to_vec();
+        assert_eq!(
+            Err(DataTypeParseError::InvalidLength {
+                expected: 4,
+                actual: data.len()
+            }),
+            RawAdStructure::try_from(&data)
+        );
+
+        let data: Vec<u8> = [0x00u8, 0x2fu8].to_vec();
+        assert_eq!(
+            Err(DataTypeParseError::ValueOutOfRange {
+                field: "length",
+                value: 0
+            }),
+            RawAdStructure::try_from(&data)
+        );
+
+        let data: Vec<u8> = [0x01u8].to_vec();
+        assert_eq!(
+            Err(DataTypeParseError::InvalidLength {
+                expected: 2,
+                actual: data.len()
+            }),
+            RawAdStructure::try_from(&data)
+        );
+    }
+
+    #[test]
+    fn test_into() {
+        let data: Vec<u8> = [0x03u8, 0x2fu8, 0x01u8, 0x02u8].to_vec();
+        let into_data: Vec<u8> = RawAdStructure::new(0x2f, &[0x01u8, 0x02u8]).unwrap().into();
+        assert_eq!(data, into_data);
+
+        let into_data: Vec<u8> = RawAdStructure::try_from(&data).unwrap().into();
+        assert_eq!(data, into_data);
+    }
+
+    #[test]
+    fn test_ad_type() {
+        let result = RawAdStructure::new(0x2f, &[]).unwrap();
+        assert_eq!(0x2f, AdStructure::ad_type(&result));
+    }
+
+    #[test]
+    fn test_to_bytes() {
+        let result = RawAdStructure::new(0x2f, &[0x01]).unwrap();
+        assert_eq!([0x02u8, 0x2fu8, 0x01u8].to_vec(), result.to_bytes());
+    }
+}
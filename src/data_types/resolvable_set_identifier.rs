@@ -0,0 +1,310 @@
+//! Resolvable Set Identifier (Data Type Value: 0x2e) module.
+
+use crate::data_types::{ad_type::AdType, data_type::DataType, parse_error::DataTypeParseError};
+
+#[cfg(feature = "crypto")]
+use crate::crypto::toolbox::resolve_private_address;
+
+/// Resolvable Set Identifier.
+#[derive(Debug, PartialEq, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ResolvableSetIdentifier {
+    /// data length
+    pub length: u8,
+
+    /// Resolvable Set Identifier (hash || prand, 48bit)
+    pub resolvable_set_identifier: u64,
+}
+
+impl ResolvableSetIdentifier {
+    /// Create [`ResolvableSetIdentifier`] from `Resolvable Set Identifier`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ble_data_struct::data_types::resolvable_set_identifier::ResolvableSetIdentifier;
+    ///
+    /// let resolvable_set_identifier = 0x060504030201u64;
+    /// let result = ResolvableSetIdentifier::new(resolvable_set_identifier);
+    /// assert_eq!(7, result.length);
+    /// assert_eq!(resolvable_set_identifier, result.resolvable_set_identifier);
+    /// ```
+    pub fn new(resolvable_set_identifier: u64) -> Self {
+        Self {
+            length: 7,
+            resolvable_set_identifier,
+        }
+    }
+
+    /// Get hash (24bit).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ble_data_struct::data_types::resolvable_set_identifier::ResolvableSetIdentifier;
+    ///
+    /// let result = ResolvableSetIdentifier::new(0x060504030201u64);
+    /// assert_eq!(0x030201, result.hash());
+    /// ```
+    pub fn hash(&self) -> u32 {
+        (self.resolvable_set_identifier & 0x00ffffff) as u32
+    }
+
+    /// Get prand (24bit).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ble_data_struct::data_types::resolvable_set_identifier::ResolvableSetIdentifier;
+    ///
+    /// let result = ResolvableSetIdentifier::new(0x060504030201u64);
+    /// assert_eq!(0x060504, result.prand());
+    /// ```
+    pub fn prand(&self) -> u32 {
+        ((self.resolvable_set_identifier >> 24) & 0x00ffffff) as u32
+    }
+
+    /// Check the Resolvable Set Identifier is generated from the SIRK.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ble_data_struct::data_types::resolvable_set_identifier::ResolvableSetIdentifier;
+    ///
+    /// let sirk = 0xec0234a357c8ad05341010a60a397d9bu128;
+    /// assert!(ResolvableSetIdentifier::new(0x7081940dfbaa).resolve(sirk));
+    /// assert!(!ResolvableSetIdentifier::new(0x7081940dfbab).resolve(sirk));
+    /// ```
+    #[cfg(feature = "crypto")]
+    pub fn resolve(&self, sirk: u128) -> bool {
+        resolve_private_address(sirk, self.resolvable_set_identifier)
+    }
+}
+
+impl TryFrom<&[u8]> for ResolvableSetIdentifier {
+    type Error = DataTypeParseError;
+    /// Create [`ResolvableSetIdentifier`] from `&[u8]`.
+    fn try_from(value: &[u8]) -> Result<Self, DataTypeParseError> {
+        let len = value.len();
+        if len < 8 {
+            return Err(DataTypeParseError::InvalidLength {
+                expected: 8,
+                actual: len,
+            });
+        }
+        let length = value[0];
+        let mut bytes = [0u8; 8];
+        bytes[..6].copy_from_slice(&value[2..8]);
+        Ok(Self {
+            length,
+            resolvable_set_identifier: u64::from_le_bytes(bytes),
+        })
+    }
+}
+
+impl TryFrom<&Vec<u8>> for ResolvableSetIdentifier {
+    type Error = DataTypeParseError;
+    /// Create [`ResolvableSetIdentifier`] from [`Vec<u8>`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ble_data_struct::data_types::{
+    ///     data_type::DataType, resolvable_set_identifier::ResolvableSetIdentifier,
+    /// };
+    /// use ble_data_struct::data_types::parse_error::DataTypeParseError;
+    ///
+    /// let resolvable_set_identifier = 0x060504030201u64;
+    /// let length = 7;
+    /// let mut data: Vec<u8> = Vec::new();
+    /// data.push(length);
+    /// data.push(ResolvableSetIdentifier::data_type());
+    /// data.append(&mut resolvable_set_identifier.to_le_bytes()[..6].to_vec());
+    ///
+    /// let result = ResolvableSetIdentifier::try_from(&data);
+    /// assert!(result.is_ok());
+    /// let data_type = result.unwrap();
+    /// assert_eq!(length, data_type.length);
+    /// assert_eq!(resolvable_set_identifier, data_type.resolvable_set_identifier);
+    ///
+    /// let data: Vec<u8> = Vec::new();
+    /// let result = ResolvableSetIdentifier::try_from(&data);
+    /// assert!(result.is_err());
+    /// assert_eq!(
+    ///     DataTypeParseError::InvalidLength {
+    ///         expected: 8,
+    ///         actual: data.len()
+    ///     },
+    ///     result.unwrap_err()
+    /// );
+    /// ```
+    fn try_from(value: &Vec<u8>) -> Result<Self, DataTypeParseError> {
+        Self::try_from(value.as_slice())
+    }
+}
+
+impl Into<Vec<u8>> for ResolvableSetIdentifier {
+    /// Create [`Vec<u8>`] from [`ResolvableSetIdentifier`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ble_data_struct::data_types::{
+    ///     data_type::DataType, resolvable_set_identifier::ResolvableSetIdentifier,
+    /// };
+    ///
+    /// let resolvable_set_identifier = 0x060504030201u64;
+    /// let result1 = ResolvableSetIdentifier::new(resolvable_set_identifier);
+    ///
+    /// let mut data: Vec<u8> = Vec::new();
+    /// data.push(7);
+    /// data.push(ResolvableSetIdentifier::data_type());
+    /// data.append(&mut resolvable_set_identifier.to_le_bytes()[..6].to_vec());
+    ///
+    /// let into_data: Vec<u8> = result1.into();
+    /// assert_eq!(data, into_data);
+    ///
+    /// let result2 = ResolvableSetIdentifier::try_from(&data);
+    /// assert!(result2.is_ok());
+    /// let data_type = result2.unwrap();
+    /// let into_data: Vec<u8> = data_type.into();
+    /// assert_eq!(data, into_data);
+    /// ```
+    fn into(self) -> Vec<u8> {
+        let mut data: Vec<u8> = Vec::new();
+        data.push(self.length);
+        data.push(Self::data_type());
+        data.append(&mut self.resolvable_set_identifier.to_le_bytes()[..6].to_vec());
+        return data;
+    }
+}
+
+impl DataType for ResolvableSetIdentifier {
+    /// return `0x2e`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ble_data_struct::data_types::{
+    ///     data_type::DataType, resolvable_set_identifier::ResolvableSetIdentifier,
+    /// };
+    ///
+    /// assert_eq!(0x2e, ResolvableSetIdentifier::data_type());
+    /// ```
+    #[inline]
+    fn data_type() -> u8 {
+        0x2e
+    }
+}
+
+/// check `Resolvable Set Identifier` data type.
+///
+/// # Examples
+///
+/// ```
+/// use ble_data_struct::data_types::resolvable_set_identifier::*;
+/// use ble_data_struct::data_types::data_type::DataType;
+///
+/// assert!(is_resolvable_set_identifier(0x2e));
+/// assert!(!is_resolvable_set_identifier(0x00));
+/// ```
+#[inline]
+pub fn is_resolvable_set_identifier(data_type: u8) -> bool {
+    AdType::from_u8(data_type) == Some(AdType::ResolvableSetIdentifier)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::data_types::{data_type::DataType, resolvable_set_identifier::*};
+
+    #[test]
+    fn test_new() {
+        let resolvable_set_identifier = 0x060504030201u64;
+        let result = ResolvableSetIdentifier::new(resolvable_set_identifier);
+        assert_eq!(7, result.length);
+        assert_eq!(resolvable_set_identifier, result.resolvable_set_identifier);
+    }
+
+    #[test]
+    fn test_hash() {
+        let result = ResolvableSetIdentifier::new(0x060504030201u64);
+        assert_eq!(0x030201, result.hash());
+    }
+
+    #[test]
+    fn test_prand() {
+        let result = ResolvableSetIdentifier::new(0x060504030201u64);
+        assert_eq!(0x060504, result.prand());
+    }
+
+    #[cfg(feature = "crypto")]
+    #[test]
+    fn test_resolve() {
+        let sirk = 0xec0234a357c8ad05341010a60a397d9bu128;
+        assert!(ResolvableSetIdentifier::new(0x7081940dfbaa).resolve(sirk));
+        assert!(!ResolvableSetIdentifier::new(0x7081940dfbaa).resolve(sirk + 1));
+        assert!(!ResolvableSetIdentifier::new(0x7081940dfbab).resolve(sirk));
+        assert!(!ResolvableSetIdentifier::new(0x3081940dfbaa).resolve(sirk));
+    }
+
+    #[test]
+    fn test_try_from() {
+        let resolvable_set_identifier = 0x060504030201u64;
+        let length = 7;
+        let mut data: Vec<u8> = Vec::new();
+        data.push(length);
+        data.push(ResolvableSetIdentifier::data_type());
+        data.append(&mut resolvable_set_identifier.to_le_bytes()[..6].to_vec());
+
+        let result = ResolvableSetIdentifier::try_from(&data);
+        assert!(result.is_ok());
+        let data_type = result.unwrap();
+        assert_eq!(length, data_type.length);
+        assert_eq!(
+            resolvable_set_identifier,
+            data_type.resolvable_set_identifier
+        );
+
+        let data: Vec<u8> = vec![7, ResolvableSetIdentifier::data_type(), 0x01];
+        let result = ResolvableSetIdentifier::try_from(&data);
+        assert!(result.is_err());
+        assert_eq!(
+            DataTypeParseError::InvalidLength {
+                expected: 8,
+                actual: data.len()
+            },
+            result.unwrap_err()
+        );
+    }
+
+    #[test]
+    fn test_into() {
+        let resolvable_set_identifier = 0x060504030201u64;
+        let result1 = ResolvableSetIdentifier::new(resolvable_set_identifier);
+
+        let mut data: Vec<u8> = Vec::new();
+        data.push(7);
+        data.push(ResolvableSetIdentifier::data_type());
+        data.append(&mut resolvable_set_identifier.to_le_bytes()[..6].to_vec());
+
+        let into_data: Vec<u8> = result1.into();
+        assert_eq!(data, into_data);
+
+        let result2 = ResolvableSetIdentifier::try_from(&data);
+        assert!(result2.is_ok());
+        let data_type = result2.unwrap();
+        let into_data: Vec<u8> = data_type.into();
+        assert_eq!(data, into_data);
+    }
+
+    #[test]
+    fn test_data_type() {
+        assert_eq!(0x2e, ResolvableSetIdentifier::data_type());
+    }
+
+    #[test]
+    fn test_is_resolvable_set_identifier() {
+        assert!(is_resolvable_set_identifier(0x2e));
+        assert!(!is_resolvable_set_identifier(0x00));
+    }
+}
@@ -1,9 +1,10 @@
 //! Security Manager Out of Band (Data Type Value: 0x11) module.
 
-use crate::data_types::data_type::DataType;
+use crate::data_types::{ad_type::AdType, data_type::DataType, parse_error::DataTypeParseError};
 
 /// Security Manager Out of Band.
 #[derive(Debug, PartialEq, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct SecurityManagerOutOfBand {
     /// data length
     pub length: u8,
@@ -124,14 +125,38 @@ pub const SECURITY_MANAGER_LE_SUPPORTED: u8 = 0b00000010u8;
 /// (0 = Public Address, 1 = Random Address)
 pub const SECURITY_MANAGER_ADDRESS_TYPE: u8 = 0b00001000u8;
 
+impl TryFrom<&[u8]> for SecurityManagerOutOfBand {
+    type Error = DataTypeParseError;
+    /// Create [`SecurityManagerOutOfBand`] from `&[u8]`.
+    fn try_from(value: &[u8]) -> Result<Self, DataTypeParseError> {
+        let len = value.len();
+        if len < 3 {
+            return Err(DataTypeParseError::InvalidLength {
+                expected: 3,
+                actual: len,
+            });
+        }
+        let length = value[0];
+        let mut security_manager_oob = [false; 8];
+        security_manager_oob[0] = value[2] & SECURITY_MANAGER_OUT_OF_BAND_FLAG_OOB_FLAGS != 0;
+        security_manager_oob[1] = value[2] & SECURITY_MANAGER_LE_SUPPORTED != 0;
+        security_manager_oob[3] = value[2] & SECURITY_MANAGER_ADDRESS_TYPE != 0;
+        Ok(Self {
+            length,
+            security_manager_oob,
+        })
+    }
+}
+
 impl TryFrom<&Vec<u8>> for SecurityManagerOutOfBand {
-    type Error = String;
+    type Error = DataTypeParseError;
     /// Create [`SecurityManagerOutOfBand`] from [`Vec<u8>`].
     ///
     /// # Examples
     ///
     /// ```
     /// use ble_data_struct::data_types::{security_manager_oob::*, data_type::DataType};
+    /// use ble_data_struct::data_types::parse_error::DataTypeParseError;
     ///
     /// let security_manager_oob_byte = SECURITY_MANAGER_OUT_OF_BAND_FLAG_OOB_FLAGS;
     /// let mut security_manager_oob = [false; 8];
@@ -182,24 +207,15 @@ impl TryFrom<&Vec<u8>> for SecurityManagerOutOfBand {
     /// let result = SecurityManagerOutOfBand::try_from(&data);
     /// assert!(result.is_err());
     /// assert_eq!(
-    ///     format!("Invalid data size :{}", data.len()),
+    ///     DataTypeParseError::InvalidLength {
+    ///         expected: 3,
+    ///         actual: data.len()
+    ///     },
     ///     result.unwrap_err()
     /// );
     /// ```
-    fn try_from(value: &Vec<u8>) -> Result<Self, String> {
-        let len = value.len();
-        if len < 3 {
-            return Err(format!("Invalid data size :{}", len).to_string());
-        }
-        let length = value[0];
-        let mut security_manager_oob = [false; 8];
-        security_manager_oob[0] = value[2] & SECURITY_MANAGER_OUT_OF_BAND_FLAG_OOB_FLAGS != 0;
-        security_manager_oob[1] = value[2] & SECURITY_MANAGER_LE_SUPPORTED != 0;
-        security_manager_oob[3] = value[2] & SECURITY_MANAGER_ADDRESS_TYPE != 0;
-        Ok(Self {
-            length,
-            security_manager_oob,
-        })
+    fn try_from(value: &Vec<u8>) -> Result<Self, DataTypeParseError> {
+        Self::try_from(value.as_slice())
     }
 }
 
@@ -325,6 +341,7 @@ impl DataType for SecurityManagerOutOfBand {
     ///
     /// assert_eq!(0x11, SecurityManagerOutOfBand::data_type());
     /// ```
+    #[inline]
     fn data_type() -> u8 {
         0x11
     }
@@ -341,8 +358,9 @@ impl DataType for SecurityManagerOutOfBand {
 /// assert!(is_security_manager_oob(0x11));
 /// assert!(!is_security_manager_oob(0x00));
 /// ```
+#[inline]
 pub fn is_security_manager_oob(data_type: u8) -> bool {
-    SecurityManagerOutOfBand::data_type() == data_type
+    AdType::from_u8(data_type) == Some(AdType::SecurityManagerOutOfBand)
 }
 
 #[cfg(test)]
@@ -464,7 +482,10 @@ mod tests {
         let result = SecurityManagerOutOfBand::try_from(&data);
         assert!(result.is_err());
         assert_eq!(
-            format!("Invalid data size :{}", data.len()),
+            DataTypeParseError::InvalidLength {
+                expected: 3,
+                actual: data.len()
+            },
             result.unwrap_err()
         );
     }
@@ -1,9 +1,10 @@
 //! Security Manager TK Value (Data Type Value: 0x10) module.
 
-use crate::data_types::data_type::DataType;
+use crate::data_types::{ad_type::AdType, data_type::DataType, parse_error::DataTypeParseError};
 
 /// Security Manager TK Value.
 #[derive(Debug, PartialEq, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct SecurityManagerTkValue {
     /// data length
     pub length: u8,
@@ -33,14 +34,34 @@ impl SecurityManagerTkValue {
     }
 }
 
+impl TryFrom<&[u8]> for SecurityManagerTkValue {
+    type Error = DataTypeParseError;
+    /// Create [`SecurityManagerTkValue`] from `&[u8]`.
+    fn try_from(value: &[u8]) -> Result<Self, DataTypeParseError> {
+        let len = value.len();
+        if len < 18 {
+            return Err(DataTypeParseError::InvalidLength {
+                expected: 18,
+                actual: len,
+            });
+        }
+        let length = value[0];
+        Ok(Self {
+            length,
+            security_manager_tk_value: u128::from_le_bytes(value[2..18].try_into().unwrap()),
+        })
+    }
+}
+
 impl TryFrom<&Vec<u8>> for SecurityManagerTkValue {
-    type Error = String;
+    type Error = DataTypeParseError;
     /// Create [`SecurityManagerTkValue`] from [`Vec<u8>`].
     ///
     /// # Examples
     ///
     /// ```
     /// use ble_data_struct::{BASE_UUID, data_types::{security_manager_tk_value::SecurityManagerTkValue, data_type::DataType}};
+    /// use ble_data_struct::data_types::parse_error::DataTypeParseError;
     ///
     /// let security_manager_tk_value = 0x0102030405060708090a0b0c0d0e0f10u128;
     /// let length = 17;
@@ -62,20 +83,15 @@ impl TryFrom<&Vec<u8>> for SecurityManagerTkValue {
     /// let result = SecurityManagerTkValue::try_from(&data);
     /// assert!(result.is_err());
     /// assert_eq!(
-    ///     format!("Invalid data size :{}", data.len()),
+    ///     DataTypeParseError::InvalidLength {
+    ///         expected: 18,
+    ///         actual: data.len()
+    ///     },
     ///     result.unwrap_err()
     /// );
     /// ```
-    fn try_from(value: &Vec<u8>) -> Result<Self, String> {
-        let len = value.len();
-        if len < 18 {
-            return Err(format!("Invalid data size :{}", len).to_string());
-        }
-        let length = value[0];
-        Ok(Self {
-            length,
-            security_manager_tk_value: u128::from_le_bytes(value[2..18].try_into().unwrap()),
-        })
+    fn try_from(value: &Vec<u8>) -> Result<Self, DataTypeParseError> {
+        Self::try_from(value.as_slice())
     }
 }
 
@@ -124,6 +140,7 @@ impl DataType for SecurityManagerTkValue {
     ///
     /// assert_eq!(0x10, SecurityManagerTkValue::data_type());
     /// ```
+    #[inline]
     fn data_type() -> u8 {
         0x10
     }
@@ -140,8 +157,9 @@ impl DataType for SecurityManagerTkValue {
 /// assert!(is_security_manager_tk_value(0x10));
 /// assert!(!is_security_manager_tk_value(0x00));
 /// ```
+#[inline]
 pub fn is_security_manager_tk_value(data_type: u8) -> bool {
-    SecurityManagerTkValue::data_type() == data_type
+    AdType::from_u8(data_type) == Some(AdType::DeviceIdOrSecurityManagerTkValue)
 }
 
 #[cfg(test)]
@@ -179,7 +197,10 @@ mod tests {
         let result = SecurityManagerTkValue::try_from(&data);
         assert!(result.is_err());
         assert_eq!(
-            format!("Invalid data size :{}", data.len()),
+            DataTypeParseError::InvalidLength {
+                expected: 18,
+                actual: data.len()
+            },
             result.unwrap_err()
         );
     }
@@ -2,10 +2,14 @@
 
 use uuid::Uuid;
 
-use crate::data_types::data_type::DataType;
+use crate::data_types::{
+    ad_type::AdType, data_type::DataType, data_type_ref::DataTypeRef,
+    parse_error::DataTypeParseError,
+};
 
 /// Service Data - 128-bit UUID.
 #[derive(Debug, PartialEq, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct ServiceData128BitUUID {
     /// data length
     pub length: u8,
@@ -42,8 +46,16 @@ impl ServiceData128BitUUID {
     }
 }
 
+impl TryFrom<&[u8]> for ServiceData128BitUUID {
+    type Error = DataTypeParseError;
+    /// Create [`ServiceData128BitUUID`] from `&[u8]`.
+    fn try_from(value: &[u8]) -> Result<Self, DataTypeParseError> {
+        ServiceData128BitUUIDRef::try_from(value).map(|f| Self::from(&f))
+    }
+}
+
 impl TryFrom<&Vec<u8>> for ServiceData128BitUUID {
-    type Error = String;
+    type Error = DataTypeParseError;
     /// Create [`ServiceData128BitUUID`] from [`Vec<u8>`].
     ///
     /// # Examples
@@ -51,6 +63,7 @@ impl TryFrom<&Vec<u8>> for ServiceData128BitUUID {
     /// ```
     /// use ble_data_struct::{BASE_UUID, data_types::{service_data_128bit_uuid::ServiceData128BitUUID, data_type::DataType}};
     /// use uuid::{uuid, Uuid};
+    /// use ble_data_struct::data_types::parse_error::DataTypeParseError;
     ///
     /// let uuid = uuid!("00000001-0000-1000-8000-00805F9B34FB");
     /// let uuid_bytes: Vec<u8> = uuid.as_u128().to_le_bytes().to_vec().to_vec();
@@ -74,24 +87,131 @@ impl TryFrom<&Vec<u8>> for ServiceData128BitUUID {
     /// let result = ServiceData128BitUUID::try_from(&data);
     /// assert!(result.is_err());
     /// assert_eq!(
-    ///     format!("Invalid data size :{}", data.len()),
+    ///     DataTypeParseError::InvalidLength {
+    ///         expected: 18,
+    ///         actual: data.len()
+    ///     },
+    ///     result.unwrap_err()
+    /// );
+    /// ```
+    fn try_from(value: &Vec<u8>) -> Result<Self, DataTypeParseError> {
+        Self::try_from(value.as_slice())
+    }
+}
+
+/// Borrowed [`ServiceData128BitUUID`].
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub struct ServiceData128BitUUIDRef<'a> {
+    /// data length
+    pub length: u8,
+
+    /// UUID
+    pub uuid: Uuid,
+
+    /// Additional service data
+    pub additional_service_data: &'a [u8],
+}
+
+impl<'a> TryFrom<&'a [u8]> for ServiceData128BitUUIDRef<'a> {
+    type Error = DataTypeParseError;
+    /// Create [`ServiceData128BitUUIDRef`] from `&[u8]` without allocation.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ble_data_struct::data_types::service_data_128bit_uuid::{ServiceData128BitUUID, ServiceData128BitUUIDRef};
+    /// use ble_data_struct::data_types::parse_error::DataTypeParseError;
+    /// use uuid::uuid;
+    ///
+    /// let data = [0x12, 0x21, 0xfb, 0x34, 0x9b, 0x5f, 0x80, 0x00, 0x00, 0x80, 0x00, 0x10, 0x00, 0x00, 0x01, 0x02, 0x03, 0x04, 0x05, 0x00];
+    /// let result = ServiceData128BitUUIDRef::try_from(&data[..]).unwrap();
+    /// assert_eq!(data[0], result.length);
+    /// assert_eq!(uuid!("04030201-0000-1000-8000-00805F9B34FB"), result.uuid);
+    /// assert_eq!(&[0x05], result.additional_service_data);
+    ///
+    /// let result = ServiceData128BitUUIDRef::try_from(&data[..17]);
+    /// assert_eq!(
+    ///     DataTypeParseError::InvalidLength {
+    ///         expected: 18,
+    ///         actual: 17
+    ///     },
     ///     result.unwrap_err()
     /// );
     /// ```
-    fn try_from(value: &Vec<u8>) -> Result<Self, String> {
+    fn try_from(value: &'a [u8]) -> Result<Self, DataTypeParseError> {
         let len = value.len();
         if len < 18 {
-            return Err(format!("Invalid data size :{}", len).to_string());
+            return Err(DataTypeParseError::InvalidLength {
+                expected: 18,
+                actual: len,
+            });
         }
         let length = value[0];
         Ok(Self {
             length,
             uuid: Uuid::from_u128(u128::from_le_bytes(value[2..18].try_into().unwrap())),
-            additional_service_data: value[18..1 + length as usize].to_vec(),
+            additional_service_data: &value[18..1 + length as usize],
         })
     }
 }
 
+impl<'a> TryFrom<&DataTypeRef<'a>> for ServiceData128BitUUIDRef<'a> {
+    type Error = DataTypeParseError;
+    /// Create [`ServiceData128BitUUIDRef`] from [`DataTypeRef`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ble_data_struct::data_types::{data_type_ref::DataTypeRef, service_data_128bit_uuid::ServiceData128BitUUIDRef};
+    /// use ble_data_struct::data_types::parse_error::DataTypeParseError;
+    ///
+    /// let data = [0x12, 0x21, 0xfb, 0x34, 0x9b, 0x5f, 0x80, 0x00, 0x00, 0x80, 0x00, 0x10, 0x00, 0x00, 0x01, 0x02, 0x03, 0x04, 0x05, 0x00];
+    /// let result = ServiceData128BitUUIDRef::try_from(&DataTypeRef::try_from(&data[..]).unwrap());
+    /// assert!(result.is_ok());
+    ///
+    /// let data = [0x02, 0xff, 0x00];
+    /// let result = ServiceData128BitUUIDRef::try_from(&DataTypeRef::try_from(&data[..]).unwrap());
+    /// assert_eq!(
+    ///     DataTypeParseError::WrongDataType {
+    ///         expected: 0x21,
+    ///         actual: 0xff
+    ///     },
+    ///     result.unwrap_err()
+    /// );
+    /// ```
+    fn try_from(value: &DataTypeRef<'a>) -> Result<Self, DataTypeParseError> {
+        if value.data_type != ServiceData128BitUUID::data_type() {
+            return Err(DataTypeParseError::WrongDataType {
+                expected: ServiceData128BitUUID::data_type(),
+                actual: value.data_type,
+            });
+        }
+        Self::try_from(value.as_bytes())
+    }
+}
+
+impl From<&ServiceData128BitUUIDRef<'_>> for ServiceData128BitUUID {
+    /// Create [`ServiceData128BitUUID`] from [`ServiceData128BitUUIDRef`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ble_data_struct::data_types::service_data_128bit_uuid::{ServiceData128BitUUID, ServiceData128BitUUIDRef};
+    /// use uuid::uuid;
+    ///
+    /// let data = [0x12, 0x21, 0xfb, 0x34, 0x9b, 0x5f, 0x80, 0x00, 0x00, 0x80, 0x00, 0x10, 0x00, 0x00, 0x01, 0x02, 0x03, 0x04, 0x05, 0x00];
+    /// let result = ServiceData128BitUUID::from(&ServiceData128BitUUIDRef::try_from(&data[..]).unwrap());
+    /// assert_eq!(ServiceData128BitUUID::new(&uuid!("04030201-0000-1000-8000-00805F9B34FB"), &vec![0x05]), result);
+    /// ```
+    fn from(value: &ServiceData128BitUUIDRef<'_>) -> Self {
+        Self {
+            length: value.length,
+            uuid: value.uuid,
+            additional_service_data: value.additional_service_data.to_vec(),
+        }
+    }
+}
+
 impl Into<Vec<u8>> for ServiceData128BitUUID {
     /// Create [`Vec<u8>`] from [`ServiceData128BitUUID`].
     ///
@@ -142,6 +262,7 @@ impl DataType for ServiceData128BitUUID {
     ///
     /// assert_eq!(0x21, ServiceData128BitUUID::data_type());
     /// ```
+    #[inline]
     fn data_type() -> u8 {
         0x21
     }
@@ -158,15 +279,19 @@ impl DataType for ServiceData128BitUUID {
 /// assert!(is_service_data_128bit_uuid(0x21));
 /// assert!(!is_service_data_128bit_uuid(0x00));
 /// ```
+#[inline]
 pub fn is_service_data_128bit_uuid(data_type: u8) -> bool {
-    ServiceData128BitUUID::data_type() == data_type
+    AdType::from_u8(data_type) == Some(AdType::ServiceData128BitUUID)
 }
 
 #[cfg(test)]
 mod tests {
     use uuid::uuid;
 
-    use crate::data_types::{data_type::DataType, service_data_128bit_uuid::*};
+    use crate::data_types::{
+        data_type::DataType, data_type_ref::DataTypeRef, parse_error::DataTypeParseError,
+        service_data_128bit_uuid::*,
+    };
 
     #[test]
     fn test_new() {
@@ -202,7 +327,10 @@ mod tests {
         let result = ServiceData128BitUUID::try_from(&data);
         assert!(result.is_err());
         assert_eq!(
-            format!("Invalid data size :{}", data.len()),
+            DataTypeParseError::InvalidLength {
+                expected: 18,
+                actual: data.len()
+            },
             result.unwrap_err()
         );
     }
@@ -241,4 +369,63 @@ mod tests {
         assert!(is_service_data_128bit_uuid(0x21));
         assert!(!is_service_data_128bit_uuid(0x00));
     }
+
+    #[test]
+    fn test_ref_try_from() {
+        let data = [
+            0x12, 0x21, 0xfb, 0x34, 0x9b, 0x5f, 0x80, 0x00, 0x00, 0x80, 0x00, 0x10, 0x00, 0x00,
+            0x01, 0x02, 0x03, 0x04, 0x05, 0x00,
+        ];
+        let result = ServiceData128BitUUIDRef::try_from(&data[..]).unwrap();
+        assert_eq!(data[0], result.length);
+        assert_eq!(uuid!("04030201-0000-1000-8000-00805F9B34FB"), result.uuid);
+        assert_eq!(&[0x05], result.additional_service_data);
+
+        let result = ServiceData128BitUUIDRef::try_from(&data[..17]);
+        assert_eq!(
+            DataTypeParseError::InvalidLength {
+                expected: 18,
+                actual: 17
+            },
+            result.unwrap_err()
+        );
+    }
+
+    #[test]
+    fn test_ref_try_from_data_type_ref() {
+        let data = [
+            0x12, 0x21, 0xfb, 0x34, 0x9b, 0x5f, 0x80, 0x00, 0x00, 0x80, 0x00, 0x10, 0x00, 0x00,
+            0x01, 0x02, 0x03, 0x04, 0x05, 0x00,
+        ];
+        let data_type_ref = DataTypeRef::try_from(&data[..]).unwrap();
+        assert_eq!(
+            ServiceData128BitUUIDRef::try_from(&data[..]),
+            ServiceData128BitUUIDRef::try_from(&data_type_ref)
+        );
+
+        let data = [0x02, 0xff, 0x00];
+        let data_type_ref = DataTypeRef::try_from(&data[..]).unwrap();
+        assert_eq!(
+            DataTypeParseError::WrongDataType {
+                expected: 0x21,
+                actual: 0xff
+            },
+            ServiceData128BitUUIDRef::try_from(&data_type_ref).unwrap_err()
+        );
+    }
+
+    #[test]
+    fn test_from_ref() {
+        let data = [
+            0x12, 0x21, 0xfb, 0x34, 0x9b, 0x5f, 0x80, 0x00, 0x00, 0x80, 0x00, 0x10, 0x00, 0x00,
+            0x01, 0x02, 0x03, 0x04, 0x05, 0x00,
+        ];
+        let result =
+            ServiceData128BitUUID::from(&ServiceData128BitUUIDRef::try_from(&data[..]).unwrap());
+        assert_eq!(
+            ServiceData128BitUUID::new(&uuid!("04030201-0000-1000-8000-00805F9B34FB"), &vec![0x05]),
+            result
+        );
+        assert_eq!(Ok(result), ServiceData128BitUUID::try_from(&data.to_vec()));
+    }
 }
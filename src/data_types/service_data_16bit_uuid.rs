@@ -2,10 +2,17 @@
 
 use uuid::Uuid;
 
-use crate::{data_types::data_type::DataType, BASE_UUID};
+use crate::{
+    data_types::{
+        ad_type::AdType, data_type::DataType, data_type_ref::DataTypeRef,
+        parse_error::DataTypeParseError,
+    },
+    BASE_UUID,
+};
 
 /// Service Data - 16-bit UUID.
 #[derive(Debug, PartialEq, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct ServiceData16BitUUID {
     /// data length
     pub length: u8,
@@ -42,8 +49,16 @@ impl ServiceData16BitUUID {
     }
 }
 
+impl TryFrom<&[u8]> for ServiceData16BitUUID {
+    type Error = DataTypeParseError;
+    /// Create [`ServiceData16BitUUID`] from `&[u8]`.
+    fn try_from(value: &[u8]) -> Result<Self, DataTypeParseError> {
+        ServiceData16BitUUIDRef::try_from(value).map(|f| Self::from(&f))
+    }
+}
+
 impl TryFrom<&Vec<u8>> for ServiceData16BitUUID {
-    type Error = String;
+    type Error = DataTypeParseError;
     /// Create [`ServiceData16BitUUID`] from [`Vec<u8>`].
     ///
     /// # Examples
@@ -51,6 +66,7 @@ impl TryFrom<&Vec<u8>> for ServiceData16BitUUID {
     /// ```
     /// use ble_data_struct::{BASE_UUID, data_types::{service_data_16bit_uuid::ServiceData16BitUUID, data_type::DataType}};
     /// use uuid::{uuid, Uuid};
+    /// use ble_data_struct::data_types::parse_error::DataTypeParseError;
     ///
     /// let uuid_bytes: Vec<u8> = [0x01u8, 0x02u8].to_vec();
     /// let (d1, d2, d3, d4) = BASE_UUID.as_fields();
@@ -79,14 +95,64 @@ impl TryFrom<&Vec<u8>> for ServiceData16BitUUID {
     /// let result = ServiceData16BitUUID::try_from(&data);
     /// assert!(result.is_err());
     /// assert_eq!(
-    ///     format!("Invalid data size :{}", data.len()),
+    ///     DataTypeParseError::InvalidLength {
+    ///         expected: 4,
+    ///         actual: data.len()
+    ///     },
+    ///     result.unwrap_err()
+    /// );
+    /// ```
+    fn try_from(value: &Vec<u8>) -> Result<Self, DataTypeParseError> {
+        Self::try_from(value.as_slice())
+    }
+}
+
+/// Borrowed [`ServiceData16BitUUID`].
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub struct ServiceData16BitUUIDRef<'a> {
+    /// data length
+    pub length: u8,
+
+    /// UUID
+    pub uuid: Uuid,
+
+    /// Additional service data
+    pub additional_service_data: &'a [u8],
+}
+
+impl<'a> TryFrom<&'a [u8]> for ServiceData16BitUUIDRef<'a> {
+    type Error = DataTypeParseError;
+    /// Create [`ServiceData16BitUUIDRef`] from `&[u8]` without allocation.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ble_data_struct::data_types::service_data_16bit_uuid::{ServiceData16BitUUID, ServiceData16BitUUIDRef};
+    /// use ble_data_struct::data_types::parse_error::DataTypeParseError;
+    /// use uuid::uuid;
+    ///
+    /// let data = [0x04, 0x16, 0x01, 0x02, 0x03, 0x00];
+    /// let result = ServiceData16BitUUIDRef::try_from(&data[..]).unwrap();
+    /// assert_eq!(data[0], result.length);
+    /// assert_eq!(uuid!("00000201-0000-1000-8000-00805F9B34FB"), result.uuid);
+    /// assert_eq!(&[0x03], result.additional_service_data);
+    ///
+    /// let result = ServiceData16BitUUIDRef::try_from(&data[..3]);
+    /// assert_eq!(
+    ///     DataTypeParseError::InvalidLength {
+    ///         expected: 4,
+    ///         actual: 3
+    ///     },
     ///     result.unwrap_err()
     /// );
     /// ```
-    fn try_from(value: &Vec<u8>) -> Result<Self, String> {
+    fn try_from(value: &'a [u8]) -> Result<Self, DataTypeParseError> {
         let len = value.len();
         if len < 4 {
-            return Err(format!("Invalid data size :{}", len).to_string());
+            return Err(DataTypeParseError::InvalidLength {
+                expected: 4,
+                actual: len,
+            });
         }
         let length = value[0];
         let mut bytes = BASE_UUID.to_bytes_le();
@@ -95,11 +161,68 @@ impl TryFrom<&Vec<u8>> for ServiceData16BitUUID {
         Ok(Self {
             length,
             uuid: Uuid::from_bytes_le(bytes),
-            additional_service_data: value[4..1 + length as usize].to_vec(),
+            additional_service_data: &value[4..1 + length as usize],
         })
     }
 }
 
+impl<'a> TryFrom<&DataTypeRef<'a>> for ServiceData16BitUUIDRef<'a> {
+    type Error = DataTypeParseError;
+    /// Create [`ServiceData16BitUUIDRef`] from [`DataTypeRef`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ble_data_struct::data_types::{data_type_ref::DataTypeRef, service_data_16bit_uuid::ServiceData16BitUUIDRef};
+    /// use ble_data_struct::data_types::parse_error::DataTypeParseError;
+    ///
+    /// let data = [0x04, 0x16, 0x01, 0x02, 0x03, 0x00];
+    /// let result = ServiceData16BitUUIDRef::try_from(&DataTypeRef::try_from(&data[..]).unwrap());
+    /// assert!(result.is_ok());
+    ///
+    /// let data = [0x02, 0xff, 0x00];
+    /// let result = ServiceData16BitUUIDRef::try_from(&DataTypeRef::try_from(&data[..]).unwrap());
+    /// assert_eq!(
+    ///     DataTypeParseError::WrongDataType {
+    ///         expected: 0x16,
+    ///         actual: 0xff
+    ///     },
+    ///     result.unwrap_err()
+    /// );
+    /// ```
+    fn try_from(value: &DataTypeRef<'a>) -> Result<Self, DataTypeParseError> {
+        if value.data_type != ServiceData16BitUUID::data_type() {
+            return Err(DataTypeParseError::WrongDataType {
+                expected: ServiceData16BitUUID::data_type(),
+                actual: value.data_type,
+            });
+        }
+        Self::try_from(value.as_bytes())
+    }
+}
+
+impl From<&ServiceData16BitUUIDRef<'_>> for ServiceData16BitUUID {
+    /// Create [`ServiceData16BitUUID`] from [`ServiceData16BitUUIDRef`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ble_data_struct::data_types::service_data_16bit_uuid::{ServiceData16BitUUID, ServiceData16BitUUIDRef};
+    /// use uuid::uuid;
+    ///
+    /// let data = [0x04, 0x16, 0x01, 0x02, 0x03, 0x00];
+    /// let result = ServiceData16BitUUID::from(&ServiceData16BitUUIDRef::try_from(&data[..]).unwrap());
+    /// assert_eq!(ServiceData16BitUUID::new(&uuid!("00000201-0000-1000-8000-00805F9B34FB"), &vec![0x03]), result);
+    /// ```
+    fn from(value: &ServiceData16BitUUIDRef<'_>) -> Self {
+        Self {
+            length: value.length,
+            uuid: value.uuid,
+            additional_service_data: value.additional_service_data.to_vec(),
+        }
+    }
+}
+
 impl Into<Vec<u8>> for ServiceData16BitUUID {
     /// Create [`Vec<u8>`] from [`ServiceData16BitUUID`].
     ///
@@ -156,6 +279,7 @@ impl DataType for ServiceData16BitUUID {
     ///
     /// assert_eq!(0x16, ServiceData16BitUUID::data_type());
     /// ```
+    #[inline]
     fn data_type() -> u8 {
         0x16
     }
@@ -172,8 +296,9 @@ impl DataType for ServiceData16BitUUID {
 /// assert!(is_service_data_16bit_uuid(0x16));
 /// assert!(!is_service_data_16bit_uuid(0x00));
 /// ```
+#[inline]
 pub fn is_service_data_16bit_uuid(data_type: u8) -> bool {
-    ServiceData16BitUUID::data_type() == data_type
+    AdType::from_u8(data_type) == Some(AdType::ServiceData16BitUUID)
 }
 
 #[cfg(test)]
@@ -181,7 +306,10 @@ mod tests {
     use uuid::{uuid, Uuid};
 
     use crate::{
-        data_types::{data_type::DataType, service_data_16bit_uuid::*},
+        data_types::{
+            data_type::DataType, data_type_ref::DataTypeRef, parse_error::DataTypeParseError,
+            service_data_16bit_uuid::*,
+        },
         BASE_UUID,
     };
 
@@ -225,7 +353,10 @@ mod tests {
         let result = ServiceData16BitUUID::try_from(&data);
         assert!(result.is_err());
         assert_eq!(
-            format!("Invalid data size :{}", data.len()),
+            DataTypeParseError::InvalidLength {
+                expected: 4,
+                actual: data.len()
+            },
             result.unwrap_err()
         );
     }
@@ -270,4 +401,54 @@ mod tests {
         assert!(is_service_data_16bit_uuid(0x16));
         assert!(!is_service_data_16bit_uuid(0x00));
     }
+
+    #[test]
+    fn test_ref_try_from() {
+        let data = [0x04, 0x16, 0x01, 0x02, 0x03, 0x00];
+        let result = ServiceData16BitUUIDRef::try_from(&data[..]).unwrap();
+        assert_eq!(data[0], result.length);
+        assert_eq!(uuid!("00000201-0000-1000-8000-00805F9B34FB"), result.uuid);
+        assert_eq!(&[0x03], result.additional_service_data);
+
+        let result = ServiceData16BitUUIDRef::try_from(&data[..3]);
+        assert_eq!(
+            DataTypeParseError::InvalidLength {
+                expected: 4,
+                actual: 3
+            },
+            result.unwrap_err()
+        );
+    }
+
+    #[test]
+    fn test_ref_try_from_data_type_ref() {
+        let data = [0x04, 0x16, 0x01, 0x02, 0x03, 0x00];
+        let data_type_ref = DataTypeRef::try_from(&data[..]).unwrap();
+        assert_eq!(
+            ServiceData16BitUUIDRef::try_from(&data[..]),
+            ServiceData16BitUUIDRef::try_from(&data_type_ref)
+        );
+
+        let data = [0x02, 0xff, 0x00];
+        let data_type_ref = DataTypeRef::try_from(&data[..]).unwrap();
+        assert_eq!(
+            DataTypeParseError::WrongDataType {
+                expected: 0x16,
+                actual: 0xff
+            },
+            ServiceData16BitUUIDRef::try_from(&data_type_ref).unwrap_err()
+        );
+    }
+
+    #[test]
+    fn test_from_ref() {
+        let data = [0x04, 0x16, 0x01, 0x02, 0x03, 0x00];
+        let result =
+            ServiceData16BitUUID::from(&ServiceData16BitUUIDRef::try_from(&data[..]).unwrap());
+        assert_eq!(
+            ServiceData16BitUUID::new(&uuid!("00000201-0000-1000-8000-00805F9B34FB"), &vec![0x03]),
+            result
+        );
+        assert_eq!(Ok(result), ServiceData16BitUUID::try_from(&data.to_vec()));
+    }
 }
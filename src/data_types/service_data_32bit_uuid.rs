@@ -2,10 +2,17 @@
 
 use uuid::Uuid;
 
-use crate::{data_types::data_type::DataType, BASE_UUID};
+use crate::{
+    data_types::{
+        ad_type::AdType, data_type::DataType, data_type_ref::DataTypeRef,
+        parse_error::DataTypeParseError,
+    },
+    BASE_UUID,
+};
 
 /// Service Data - 32-bit UUID.
 #[derive(Debug, PartialEq, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct ServiceData32BitUUID {
     /// data length
     pub length: u8,
@@ -42,8 +49,16 @@ impl ServiceData32BitUUID {
     }
 }
 
+impl TryFrom<&[u8]> for ServiceData32BitUUID {
+    type Error = DataTypeParseError;
+    /// Create [`ServiceData32BitUUID`] from `&[u8]`.
+    fn try_from(value: &[u8]) -> Result<Self, DataTypeParseError> {
+        ServiceData32BitUUIDRef::try_from(value).map(|f| Self::from(&f))
+    }
+}
+
 impl TryFrom<&Vec<u8>> for ServiceData32BitUUID {
-    type Error = String;
+    type Error = DataTypeParseError;
     /// Create [`ServiceData32BitUUID`] from [`Vec<u8>`].
     ///
     /// # Examples
@@ -51,6 +66,7 @@ impl TryFrom<&Vec<u8>> for ServiceData32BitUUID {
     /// ```
     /// use ble_data_struct::{BASE_UUID, data_types::{service_data_32bit_uuid::ServiceData32BitUUID, data_type::DataType}};
     /// use uuid::{uuid, Uuid};
+    /// use ble_data_struct::data_types::parse_error::DataTypeParseError;
     ///
     /// let uuid_bytes: Vec<u8> = [0x01u8, 0x02u8, 0x03u8, 0x04u8].to_vec();
     /// let mut uuid_bytes_le: Vec<u8> = uuid_bytes.to_vec();
@@ -75,14 +91,64 @@ impl TryFrom<&Vec<u8>> for ServiceData32BitUUID {
     /// let result = ServiceData32BitUUID::try_from(&data);
     /// assert!(result.is_err());
     /// assert_eq!(
-    ///     format!("Invalid data size :{}", data.len()),
+    ///     DataTypeParseError::InvalidLength {
+    ///         expected: 6,
+    ///         actual: data.len()
+    ///     },
+    ///     result.unwrap_err()
+    /// );
+    /// ```
+    fn try_from(value: &Vec<u8>) -> Result<Self, DataTypeParseError> {
+        Self::try_from(value.as_slice())
+    }
+}
+
+/// Borrowed [`ServiceData32BitUUID`].
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub struct ServiceData32BitUUIDRef<'a> {
+    /// data length
+    pub length: u8,
+
+    /// UUID
+    pub uuid: Uuid,
+
+    /// Additional service data
+    pub additional_service_data: &'a [u8],
+}
+
+impl<'a> TryFrom<&'a [u8]> for ServiceData32BitUUIDRef<'a> {
+    type Error = DataTypeParseError;
+    /// Create [`ServiceData32BitUUIDRef`] from `&[u8]` without allocation.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ble_data_struct::data_types::service_data_32bit_uuid::{ServiceData32BitUUID, ServiceData32BitUUIDRef};
+    /// use ble_data_struct::data_types::parse_error::DataTypeParseError;
+    /// use uuid::uuid;
+    ///
+    /// let data = [0x06, 0x20, 0x01, 0x02, 0x03, 0x04, 0x05, 0x00];
+    /// let result = ServiceData32BitUUIDRef::try_from(&data[..]).unwrap();
+    /// assert_eq!(data[0], result.length);
+    /// assert_eq!(uuid!("04030201-0000-1000-8000-00805F9B34FB"), result.uuid);
+    /// assert_eq!(&[0x05], result.additional_service_data);
+    ///
+    /// let result = ServiceData32BitUUIDRef::try_from(&data[..5]);
+    /// assert_eq!(
+    ///     DataTypeParseError::InvalidLength {
+    ///         expected: 6,
+    ///         actual: 5
+    ///     },
     ///     result.unwrap_err()
     /// );
     /// ```
-    fn try_from(value: &Vec<u8>) -> Result<Self, String> {
-        let len: usize = value.len();
+    fn try_from(value: &'a [u8]) -> Result<Self, DataTypeParseError> {
+        let len = value.len();
         if len < 6 {
-            return Err(format!("Invalid data size :{}", len).to_string());
+            return Err(DataTypeParseError::InvalidLength {
+                expected: 6,
+                actual: len,
+            });
         }
         let length = value[0];
         let mut bytes = BASE_UUID.to_bytes_le();
@@ -93,11 +159,68 @@ impl TryFrom<&Vec<u8>> for ServiceData32BitUUID {
         Ok(Self {
             length,
             uuid: Uuid::from_bytes_le(bytes),
-            additional_service_data: value[6..1 + length as usize].to_vec(),
+            additional_service_data: &value[6..1 + length as usize],
         })
     }
 }
 
+impl<'a> TryFrom<&DataTypeRef<'a>> for ServiceData32BitUUIDRef<'a> {
+    type Error = DataTypeParseError;
+    /// Create [`ServiceData32BitUUIDRef`] from [`DataTypeRef`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ble_data_struct::data_types::{data_type_ref::DataTypeRef, service_data_32bit_uuid::ServiceData32BitUUIDRef};
+    /// use ble_data_struct::data_types::parse_error::DataTypeParseError;
+    ///
+    /// let data = [0x06, 0x20, 0x01, 0x02, 0x03, 0x04, 0x05, 0x00];
+    /// let result = ServiceData32BitUUIDRef::try_from(&DataTypeRef::try_from(&data[..]).unwrap());
+    /// assert!(result.is_ok());
+    ///
+    /// let data = [0x02, 0xff, 0x00];
+    /// let result = ServiceData32BitUUIDRef::try_from(&DataTypeRef::try_from(&data[..]).unwrap());
+    /// assert_eq!(
+    ///     DataTypeParseError::WrongDataType {
+    ///         expected: 0x20,
+    ///         actual: 0xff
+    ///     },
+    ///     result.unwrap_err()
+    /// );
+    /// ```
+    fn try_from(value: &DataTypeRef<'a>) -> Result<Self, DataTypeParseError> {
+        if value.data_type != ServiceData32BitUUID::data_type() {
+            return Err(DataTypeParseError::WrongDataType {
+                expected: ServiceData32BitUUID::data_type(),
+                actual: value.data_type,
+            });
+        }
+        Self::try_from(value.as_bytes())
+    }
+}
+
+impl From<&ServiceData32BitUUIDRef<'_>> for ServiceData32BitUUID {
+    /// Create [`ServiceData32BitUUID`] from [`ServiceData32BitUUIDRef`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ble_data_struct::data_types::service_data_32bit_uuid::{ServiceData32BitUUID, ServiceData32BitUUIDRef};
+    /// use uuid::uuid;
+    ///
+    /// let data = [0x06, 0x20, 0x01, 0x02, 0x03, 0x04, 0x05, 0x00];
+    /// let result = ServiceData32BitUUID::from(&ServiceData32BitUUIDRef::try_from(&data[..]).unwrap());
+    /// assert_eq!(ServiceData32BitUUID::new(&uuid!("04030201-0000-1000-8000-00805F9B34FB"), &vec![0x05]), result);
+    /// ```
+    fn from(value: &ServiceData32BitUUIDRef<'_>) -> Self {
+        Self {
+            length: value.length,
+            uuid: value.uuid,
+            additional_service_data: value.additional_service_data.to_vec(),
+        }
+    }
+}
+
 impl Into<Vec<u8>> for ServiceData32BitUUID {
     /// Create [`Vec<u8>`] from [`ServiceData32BitUUID`].
     ///
@@ -150,6 +273,7 @@ impl DataType for ServiceData32BitUUID {
     ///
     /// assert_eq!(0x20, ServiceData32BitUUID::data_type());
     /// ```
+    #[inline]
     fn data_type() -> u8 {
         0x20
     }
@@ -166,8 +290,9 @@ impl DataType for ServiceData32BitUUID {
 /// assert!(is_service_data_32bit_uuid(0x20));
 /// assert!(!is_service_data_32bit_uuid(0x00));
 /// ```
+#[inline]
 pub fn is_service_data_32bit_uuid(data_type: u8) -> bool {
-    ServiceData32BitUUID::data_type() == data_type
+    AdType::from_u8(data_type) == Some(AdType::ServiceData32BitUUID)
 }
 
 #[cfg(test)]
@@ -175,7 +300,10 @@ mod tests {
     use uuid::{uuid, Uuid};
 
     use crate::{
-        data_types::{data_type::DataType, service_data_32bit_uuid::*},
+        data_types::{
+            data_type::DataType, data_type_ref::DataTypeRef, parse_error::DataTypeParseError,
+            service_data_32bit_uuid::*,
+        },
         BASE_UUID,
     };
 
@@ -215,7 +343,10 @@ mod tests {
         let result = ServiceData32BitUUID::try_from(&data);
         assert!(result.is_err());
         assert_eq!(
-            format!("Invalid data size :{}", data.len()),
+            DataTypeParseError::InvalidLength {
+                expected: 6,
+                actual: data.len()
+            },
             result.unwrap_err()
         );
     }
@@ -256,4 +387,54 @@ mod tests {
         assert!(is_service_data_32bit_uuid(0x20));
         assert!(!is_service_data_32bit_uuid(0x00));
     }
+
+    #[test]
+    fn test_ref_try_from() {
+        let data = [0x06, 0x20, 0x01, 0x02, 0x03, 0x04, 0x05, 0x00];
+        let result = ServiceData32BitUUIDRef::try_from(&data[..]).unwrap();
+        assert_eq!(data[0], result.length);
+        assert_eq!(uuid!("04030201-0000-1000-8000-00805F9B34FB"), result.uuid);
+        assert_eq!(&[0x05], result.additional_service_data);
+
+        let result = ServiceData32BitUUIDRef::try_from(&data[..5]);
+        assert_eq!(
+            DataTypeParseError::InvalidLength {
+                expected: 6,
+                actual: 5
+            },
+            result.unwrap_err()
+        );
+    }
+
+    #[test]
+    fn test_ref_try_from_data_type_ref() {
+        let data = [0x06, 0x20, 0x01, 0x02, 0x03, 0x04, 0x05, 0x00];
+        let data_type_ref = DataTypeRef::try_from(&data[..]).unwrap();
+        assert_eq!(
+            ServiceData32BitUUIDRef::try_from(&data[..]),
+            ServiceData32BitUUIDRef::try_from(&data_type_ref)
+        );
+
+        let data = [0x02, 0xff, 0x00];
+        let data_type_ref = DataTypeRef::try_from(&data[..]).unwrap();
+        assert_eq!(
+            DataTypeParseError::WrongDataType {
+                expected: 0x20,
+                actual: 0xff
+            },
+            ServiceData32BitUUIDRef::try_from(&data_type_ref).unwrap_err()
+        );
+    }
+
+    #[test]
+    fn test_from_ref() {
+        let data = [0x06, 0x20, 0x01, 0x02, 0x03, 0x04, 0x05, 0x00];
+        let result =
+            ServiceData32BitUUID::from(&ServiceData32BitUUIDRef::try_from(&data[..]).unwrap());
+        assert_eq!(
+            ServiceData32BitUUID::new(&uuid!("04030201-0000-1000-8000-00805F9B34FB"), &vec![0x05]),
+            result
+        );
+        assert_eq!(Ok(result), ServiceData32BitUUID::try_from(&data.to_vec()));
+    }
 }
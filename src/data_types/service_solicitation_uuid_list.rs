@@ -0,0 +1,242 @@
+//! List of 16/32/128-bit Service Solicitation UUIDs shared implementation module.
+
+use uuid::Uuid;
+
+use crate::data_types::{
+    data_type::DataType,
+    parse_error::DataTypeParseError,
+    uuid_list::{uuid_from_le_bytes, uuid_to_le_bytes},
+};
+
+/// List of `WIDTH`-bit Service Solicitation UUIDs.
+///
+/// `WIDTH` is `16`, `32` or `128`.
+/// Use the type aliases ([`ListOf16BitServiceSolicitationUUIDs`](crate::data_types::list_of_16bit_service_solicitation_uuids::ListOf16BitServiceSolicitationUUIDs), ...).
+#[derive(Debug, PartialEq, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ServiceSolicitationUuidList<const WIDTH: usize> {
+    /// data length
+    pub length: u8,
+
+    /// UUIDs
+    pub uuids: Vec<Uuid>,
+}
+
+impl<const WIDTH: usize> ServiceSolicitationUuidList<WIDTH> {
+    /// Data Type Value.
+    const DATA_TYPE: u8 = match WIDTH {
+        16 => 0x14,
+        32 => 0x1f,
+        128 => 0x15,
+        _ => panic!("Invalid UUID width"),
+    };
+
+    /// UUID size(byte).
+    const UUID_SIZE: usize = WIDTH / 8;
+
+    /// Create [`ServiceSolicitationUuidList`] from [`Vec<Uuid>`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ble_data_struct::data_types::list_of_32bit_service_solicitation_uuids::ListOf32BitServiceSolicitationUUIDs;
+    /// use uuid::{uuid, Uuid};
+    ///
+    /// let uuids: Vec<Uuid> = [
+    ///     uuid!("00000001-0000-1000-8000-00805F9B34FB"),
+    ///     uuid!("00000002-0000-1000-8000-00805F9B34FB"),
+    /// ]
+    /// .to_vec();
+    /// let result = ListOf32BitServiceSolicitationUUIDs::new(&uuids);
+    /// assert_eq!(uuids.len() as u8 * 4 + 1, result.length);
+    /// assert_eq!(uuids, result.uuids);
+    /// ```
+    pub fn new(uuids: &Vec<Uuid>) -> Self {
+        Self {
+            length: (uuids.len() * Self::UUID_SIZE + 1) as u8,
+            uuids: uuids.clone(),
+        }
+    }
+}
+
+impl<const WIDTH: usize> TryFrom<&[u8]> for ServiceSolicitationUuidList<WIDTH> {
+    type Error = DataTypeParseError;
+    /// Create [`ServiceSolicitationUuidList`] from `&[u8]`.
+    fn try_from(value: &[u8]) -> Result<Self, DataTypeParseError> {
+        let len = value.len();
+        if len < 2 + Self::UUID_SIZE {
+            return Err(DataTypeParseError::InvalidLength {
+                expected: 2 + Self::UUID_SIZE,
+                actual: len,
+            });
+        }
+        let length = value[0];
+        Ok(Self {
+            length,
+            uuids: value[2..2 + length as usize - 1]
+                .chunks_exact(Self::UUID_SIZE)
+                .map(uuid_from_le_bytes)
+                .collect(),
+        })
+    }
+}
+
+impl<const WIDTH: usize> TryFrom<&Vec<u8>> for ServiceSolicitationUuidList<WIDTH> {
+    type Error = DataTypeParseError;
+    /// Create [`ServiceSolicitationUuidList`] from [`Vec<u8>`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ble_data_struct::data_types::list_of_16bit_service_solicitation_uuids::ListOf16BitServiceSolicitationUUIDs;
+    /// use ble_data_struct::data_types::parse_error::DataTypeParseError;
+    /// use ble_data_struct::uuid_from_u16;
+    ///
+    /// let data: Vec<u8> = [0x05u8, 0x14u8, 0x0fu8, 0x18u8, 0x0au8, 0x18u8].to_vec();
+    /// let result = ListOf16BitServiceSolicitationUUIDs::try_from(&data);
+    /// assert!(result.is_ok());
+    /// let data_type = result.unwrap();
+    /// assert_eq!(0x05, data_type.length);
+    /// assert_eq!(
+    ///     [uuid_from_u16(0x180f), uuid_from_u16(0x180a)].to_vec(),
+    ///     data_type.uuids
+    /// );
+    ///
+    /// let data: Vec<u8> = Vec::new();
+    /// let result = ListOf16BitServiceSolicitationUUIDs::try_from(&data);
+    /// assert!(result.is_err());
+    /// assert_eq!(
+    ///     DataTypeParseError::InvalidLength {
+    ///         expected: 4,
+    ///         actual: data.len()
+    ///     },
+    ///     result.unwrap_err()
+    /// );
+    /// ```
+    fn try_from(value: &Vec<u8>) -> Result<Self, DataTypeParseError> {
+        Self::try_from(value.as_slice())
+    }
+}
+
+impl<const WIDTH: usize> Into<Vec<u8>> for ServiceSolicitationUuidList<WIDTH> {
+    /// Create [`Vec<u8>`] from [`ServiceSolicitationUuidList`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ble_data_struct::data_types::list_of_16bit_service_solicitation_uuids::ListOf16BitServiceSolicitationUUIDs;
+    /// use ble_data_struct::uuid_from_u16;
+    ///
+    /// let result1 = ListOf16BitServiceSolicitationUUIDs::new(
+    ///     &[uuid_from_u16(0x180f), uuid_from_u16(0x180a)].to_vec(),
+    /// );
+    /// let data: Vec<u8> = [0x05u8, 0x14u8, 0x0fu8, 0x18u8, 0x0au8, 0x18u8].to_vec();
+    ///
+    /// let into_data: Vec<u8> = result1.into();
+    /// assert_eq!(data, into_data);
+    ///
+    /// let result2 = ListOf16BitServiceSolicitationUUIDs::try_from(&data);
+    /// assert!(result2.is_ok());
+    /// let data_type = result2.unwrap();
+    /// let into_data: Vec<u8> = data_type.into();
+    /// assert_eq!(data, into_data);
+    /// ```
+    fn into(self) -> Vec<u8> {
+        let mut data: Vec<u8> = Vec::new();
+        data.push(self.length);
+        data.push(Self::data_type());
+        data.append(
+            &mut self
+                .uuids
+                .iter()
+                .flat_map(|f| uuid_to_le_bytes(f, Self::UUID_SIZE))
+                .collect(),
+        );
+        return data;
+    }
+}
+
+impl<const WIDTH: usize> DataType for ServiceSolicitationUuidList<WIDTH> {
+    /// return `0x14`, `0x1f` or `0x15`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ble_data_struct::data_types::{
+    ///     data_type::DataType, service_solicitation_uuid_list::ServiceSolicitationUuidList,
+    /// };
+    ///
+    /// assert_eq!(0x14, ServiceSolicitationUuidList::<16>::data_type());
+    /// assert_eq!(0x1f, ServiceSolicitationUuidList::<32>::data_type());
+    /// assert_eq!(0x15, ServiceSolicitationUuidList::<128>::data_type());
+    /// ```
+    #[inline]
+    fn data_type() -> u8 {
+        Self::DATA_TYPE
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        data_types::{
+            data_type::DataType, parse_error::DataTypeParseError,
+            service_solicitation_uuid_list::ServiceSolicitationUuidList,
+        },
+        uuid_from_u16,
+    };
+
+    #[test]
+    fn test_new() {
+        let uuids = [uuid_from_u16(0x180f), uuid_from_u16(0x180a)].to_vec();
+        assert_eq!(5, ServiceSolicitationUuidList::<16>::new(&uuids).length);
+        assert_eq!(9, ServiceSolicitationUuidList::<32>::new(&uuids).length);
+        assert_eq!(33, ServiceSolicitationUuidList::<128>::new(&uuids).length);
+    }
+
+    #[test]
+    fn test_try_from() {
+        let uuids = [uuid_from_u16(0x180f), uuid_from_u16(0x180a)].to_vec();
+
+        let data: Vec<u8> = [0x05u8, 0x14u8, 0x0fu8, 0x18u8, 0x0au8, 0x18u8].to_vec();
+        let result = ServiceSolicitationUuidList::<16>::try_from(&data);
+        assert!(result.is_ok());
+        assert_eq!(uuids, result.unwrap().uuids);
+
+        let mut data: Vec<u8> = [0x21u8, 0x15u8].to_vec();
+        uuids
+            .iter()
+            .for_each(|f| data.append(&mut f.as_u128().to_le_bytes().to_vec()));
+        let result = ServiceSolicitationUuidList::<128>::try_from(&data);
+        assert!(result.is_ok());
+        assert_eq!(uuids, result.unwrap().uuids);
+
+        let data: Vec<u8> = vec![0u8; 5];
+        let result = ServiceSolicitationUuidList::<32>::try_from(&data);
+        assert_eq!(
+            Err(DataTypeParseError::InvalidLength {
+                expected: 6,
+                actual: data.len()
+            }),
+            result
+        );
+    }
+
+    #[test]
+    fn test_into() {
+        let uuids = [uuid_from_u16(0x180f), uuid_from_u16(0x180a)].to_vec();
+        let data: Vec<u8> = ServiceSolicitationUuidList::<32>::new(&uuids).into();
+        assert_eq!(
+            [0x09u8, 0x1fu8, 0x0fu8, 0x18u8, 0x00u8, 0x00u8, 0x0au8, 0x18u8, 0x00u8, 0x00u8]
+                .to_vec(),
+            data
+        );
+    }
+
+    #[test]
+    fn test_data_type() {
+        assert_eq!(0x14, ServiceSolicitationUuidList::<16>::data_type());
+        assert_eq!(0x1f, ServiceSolicitationUuidList::<32>::data_type());
+        assert_eq!(0x15, ServiceSolicitationUuidList::<128>::data_type());
+    }
+}
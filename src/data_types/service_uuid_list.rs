@@ -0,0 +1,51 @@
+//! Service UUID list trait module.
+
+use uuid::Uuid;
+
+/// Common accessors of `Incomplete/Complete List of 16/32/128-bit Service Class UUIDs`.
+pub trait ServiceUuidList {
+    /// Returns service class UUIDs.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use uuid::{uuid, Uuid};
+    /// use ble_data_struct::data_types::{
+    ///     complete_list_of_16bit_service_uuids::CompleteListOf16BitServiceUuids,
+    ///     incomplete_list_of_128bit_service_uuids::IncompleteListOf128BitServiceUuids,
+    ///     service_uuid_list::ServiceUuidList,
+    /// };
+    ///
+    /// fn count(list: &impl ServiceUuidList) -> usize {
+    ///     list.uuids().len()
+    /// }
+    ///
+    /// let uuids: Vec<Uuid> = [uuid!("00000001-0000-1000-8000-00805F9B34FB")].to_vec();
+    /// let result = CompleteListOf16BitServiceUuids::new(&uuids);
+    /// assert_eq!(&uuids, result.uuids());
+    /// assert_eq!(1, count(&result));
+    ///
+    /// let result = IncompleteListOf128BitServiceUuids::new(&uuids);
+    /// assert_eq!(&uuids, result.uuids());
+    /// assert_eq!(1, count(&result));
+    /// ```
+    fn uuids(&self) -> &Vec<Uuid>;
+
+    /// Returns `true` if the list is a `Complete List`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use uuid::{uuid, Uuid};
+    /// use ble_data_struct::data_types::{
+    ///     complete_list_of_16bit_service_uuids::CompleteListOf16BitServiceUuids,
+    ///     incomplete_list_of_16bit_service_uuids::IncompleteListOf16BitServiceUuids,
+    ///     service_uuid_list::ServiceUuidList,
+    /// };
+    ///
+    /// let uuids: Vec<Uuid> = [uuid!("00000001-0000-1000-8000-00805F9B34FB")].to_vec();
+    /// assert!(CompleteListOf16BitServiceUuids::new(&uuids).is_complete());
+    /// assert!(!IncompleteListOf16BitServiceUuids::new(&uuids).is_complete());
+    /// ```
+    fn is_complete(&self) -> bool;
+}
@@ -0,0 +1,299 @@
+//! Bluetooth Core Specification version metadata module.
+//!
+//! Lookup the specification version that introduced a data type or an LE feature bit,
+//! so that a payload can be checked against an older certification target.
+
+use crate::data_types::{data_type::DataType, le_supported_features::LeSupportedFeatures};
+
+/// Bluetooth Core Specification version.
+#[derive(Debug, PartialEq, Eq, PartialOrd, Ord, Clone, Copy, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum CoreVersion {
+    /// Core Specification 2.1 + EDR(EIR data types)
+    V2_1,
+    /// Core Specification 4.0
+    V4_0,
+    /// Core Specification 4.1
+    V4_1,
+    /// Core Specification 4.2
+    V4_2,
+    /// Core Specification 5.0
+    V5_0,
+    /// Core Specification 5.1
+    V5_1,
+    /// Core Specification 5.2
+    V5_2,
+    /// Core Specification 5.3
+    V5_3,
+    /// Core Specification 5.4
+    V5_4,
+}
+
+/// Trait for specification version metadata.
+pub trait SpecVersion {
+    /// Get the Core Specification version that introduced the data type
+    fn introduced_in() -> CoreVersion;
+}
+
+/// Every [`DataType`] is tagged through [`data_type_introduced_in`].
+///
+/// Data types missing from the table are reported as [`CoreVersion::V5_4`], so they are never
+/// silently accepted for an older target.
+///
+/// # Examples
+///
+/// ```
+/// use ble_data_struct::data_types::{
+///     encrypted_data::EncryptedData, flags::Flags,
+///     spec_version::{CoreVersion, SpecVersion},
+/// };
+///
+/// assert_eq!(CoreVersion::V4_0, Flags::introduced_in());
+/// assert_eq!(CoreVersion::V5_4, EncryptedData::introduced_in());
+/// ```
+impl<T: DataType> SpecVersion for T {
+    fn introduced_in() -> CoreVersion {
+        data_type_introduced_in(T::data_type()).unwrap_or(CoreVersion::V5_4)
+    }
+}
+
+/// Get the Core Specification version that introduced the data type.
+///
+/// # Examples
+///
+/// ```
+/// use ble_data_struct::data_types::spec_version::{data_type_introduced_in, CoreVersion};
+///
+/// assert_eq!(Some(CoreVersion::V2_1), data_type_introduced_in(0x09));
+/// assert_eq!(Some(CoreVersion::V5_0), data_type_introduced_in(0x27));
+/// assert_eq!(None, data_type_introduced_in(0x00));
+/// ```
+pub fn data_type_introduced_in(data_type: u8) -> Option<CoreVersion> {
+    match data_type {
+        0x02..=0x0a | 0x0d..=0x0f | 0xff => Some(CoreVersion::V2_1),
+        0x01 | 0x10..=0x12 | 0x14..=0x1a => Some(CoreVersion::V4_0),
+        0x1b..=0x21 => Some(CoreVersion::V4_1),
+        0x22..=0x26 => Some(CoreVersion::V4_2),
+        0x27..=0x2b => Some(CoreVersion::V5_0),
+        0x2c..=0x2e => Some(CoreVersion::V5_2),
+        0x2f..=0x30 => Some(CoreVersion::V5_3),
+        0x31..=0x32 => Some(CoreVersion::V5_4),
+        _ => None,
+    }
+}
+
+/// Get the Core Specification version that introduced the LE Supported Features bit.
+///
+/// # Examples
+///
+/// ```
+/// use ble_data_struct::data_types::spec_version::{
+///     le_supported_feature_introduced_in, CoreVersion,
+/// };
+///
+/// assert_eq!(Some(CoreVersion::V4_0), le_supported_feature_introduced_in(0));
+/// assert_eq!(Some(CoreVersion::V5_0), le_supported_feature_introduced_in(8));
+/// assert_eq!(None, le_supported_feature_introduced_in(63));
+/// ```
+pub fn le_supported_feature_introduced_in(bit: usize) -> Option<CoreVersion> {
+    match bit {
+        0 => Some(CoreVersion::V4_0),
+        1..=4 => Some(CoreVersion::V4_1),
+        5..=7 => Some(CoreVersion::V4_2),
+        8..=16 => Some(CoreVersion::V5_0),
+        17..=25 => Some(CoreVersion::V5_1),
+        26..=35 => Some(CoreVersion::V5_2),
+        36..=39 => Some(CoreVersion::V5_3),
+        40..=44 => Some(CoreVersion::V5_4),
+        _ => None,
+    }
+}
+
+/// Returns the data types in the advertising payload that are newer than `target`.
+///
+/// Unknown data types are ignored. Parsing stops at the first malformed AD structure.
+///
+/// # Examples
+///
+/// ```
+/// use ble_data_struct::data_types::{
+///     advertising_interval_long::AdvertisingIntervalLong, data_type::DataType,
+///     spec_version::{newer_data_types, CoreVersion},
+///     tx_power_level::TxPowerLevel,
+/// };
+/// use ble_data_struct::data_types::time_units::Slots625us;
+///
+/// let tx_power_level: Vec<u8> = TxPowerLevel::new(0).into();
+/// let advertising_interval_long: Vec<u8> = AdvertisingIntervalLong::new(true, Slots625us(0x01020304)).into();
+/// let payload = [tx_power_level, advertising_interval_long].concat();
+///
+/// assert_eq!(
+///     vec![AdvertisingIntervalLong::data_type()],
+///     newer_data_types(&payload, CoreVersion::V5_0)
+/// );
+/// assert!(newer_data_types(&payload, CoreVersion::V5_4).is_empty());
+/// ```
+pub fn newer_data_types(payload: &[u8], target: CoreVersion) -> Vec<u8> {
+    let mut data_types: Vec<u8> = Vec::new();
+    let mut index = 0;
+    while index + 1 < payload.len() {
+        let length = payload[index] as usize;
+        if length == 0 || index + 1 + length > payload.len() {
+            break;
+        }
+        let data_type = payload[index + 1];
+        if data_type_introduced_in(data_type).is_some_and(|f| f > target) {
+            data_types.push(data_type);
+        }
+        index += 1 + length;
+    }
+    data_types
+}
+
+/// Returns the set LE Supported Features bits that are newer than `target`.
+///
+/// # Examples
+///
+/// ```
+/// use ble_data_struct::data_types::{
+///     le_supported_features::LeSupportedFeatures,
+///     spec_version::{newer_le_supported_features, CoreVersion},
+/// };
+///
+/// let mut le_supported_features = [false; 48].to_vec();
+/// le_supported_features[0] = true;
+/// le_supported_features[8] = true;
+/// let result = LeSupportedFeatures::new(&le_supported_features);
+/// assert_eq!(vec![8], newer_le_supported_features(&result, CoreVersion::V4_2));
+/// ```
+pub fn newer_le_supported_features(
+    le_supported_features: &LeSupportedFeatures,
+    target: CoreVersion,
+) -> Vec<usize> {
+    le_supported_features
+        .le_supported_features
+        .iter()
+        .enumerate()
+        .filter(|(bit, supported)| {
+            **supported && le_supported_feature_introduced_in(*bit).is_some_and(|f| f > target)
+        })
+        .map(|(bit, _)| bit)
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::data_types::{
+        advertising_interval_long::AdvertisingIntervalLong,
+        complete_list_of_16bit_service_uuids::CompleteListOf16BitServiceUuids,
+        data_type::DataType,
+        le_secure_connections_random_value::LeSecureConnectionsRandomValue,
+        le_supported_features::LeSupportedFeatures,
+        list_of_32bit_service_solicitation_uuids::ListOf32BitServiceSolicitationUUIDs,
+        spec_version::{
+            data_type_introduced_in, le_supported_feature_introduced_in, newer_data_types,
+            newer_le_supported_features, CoreVersion, SpecVersion,
+        },
+        time_units::Slots625us,
+        tx_power_level::TxPowerLevel,
+    };
+
+    #[test]
+    fn test_core_version_order() {
+        assert!(CoreVersion::V2_1 < CoreVersion::V4_0);
+        assert!(CoreVersion::V4_2 < CoreVersion::V5_0);
+        assert!(CoreVersion::V5_3 < CoreVersion::V5_4);
+    }
+
+    #[test]
+    fn test_introduced_in() {
+        assert_eq!(
+            CoreVersion::V2_1,
+            CompleteListOf16BitServiceUuids::introduced_in()
+        );
+        assert_eq!(
+            CoreVersion::V4_1,
+            ListOf32BitServiceSolicitationUUIDs::introduced_in()
+        );
+        assert_eq!(
+            CoreVersion::V4_2,
+            LeSecureConnectionsRandomValue::introduced_in()
+        );
+        assert_eq!(CoreVersion::V5_0, LeSupportedFeatures::introduced_in());
+        assert_eq!(CoreVersion::V5_3, AdvertisingIntervalLong::introduced_in());
+    }
+
+    #[test]
+    fn test_data_type_introduced_in() {
+        assert_eq!(Some(CoreVersion::V2_1), data_type_introduced_in(0xff));
+        assert_eq!(Some(CoreVersion::V4_0), data_type_introduced_in(0x01));
+        assert_eq!(Some(CoreVersion::V4_1), data_type_introduced_in(0x1b));
+        assert_eq!(Some(CoreVersion::V5_2), data_type_introduced_in(0x2c));
+        assert_eq!(Some(CoreVersion::V5_4), data_type_introduced_in(0x32));
+        assert_eq!(None, data_type_introduced_in(0x13));
+        assert_eq!(None, data_type_introduced_in(0x80));
+    }
+
+    #[test]
+    fn test_le_supported_feature_introduced_in() {
+        assert_eq!(
+            Some(CoreVersion::V4_1),
+            le_supported_feature_introduced_in(4)
+        );
+        assert_eq!(
+            Some(CoreVersion::V4_2),
+            le_supported_feature_introduced_in(5)
+        );
+        assert_eq!(
+            Some(CoreVersion::V5_1),
+            le_supported_feature_introduced_in(17)
+        );
+        assert_eq!(
+            Some(CoreVersion::V5_2),
+            le_supported_feature_introduced_in(26)
+        );
+        assert_eq!(
+            Some(CoreVersion::V5_3),
+            le_supported_feature_introduced_in(39)
+        );
+        assert_eq!(
+            Some(CoreVersion::V5_4),
+            le_supported_feature_introduced_in(44)
+        );
+        assert_eq!(None, le_supported_feature_introduced_in(45));
+    }
+
+    #[test]
+    fn test_newer_data_types() {
+        let tx_power_level: Vec<u8> = TxPowerLevel::new(0).into();
+        let advertising_interval_long: Vec<u8> =
+            AdvertisingIntervalLong::new(true, Slots625us(0x01020304)).into();
+        let payload = [tx_power_level.clone(), advertising_interval_long].concat();
+
+        assert_eq!(
+            vec![AdvertisingIntervalLong::data_type()],
+            newer_data_types(&payload, CoreVersion::V5_0)
+        );
+        assert!(newer_data_types(&payload, CoreVersion::V5_3).is_empty());
+        assert!(newer_data_types(&tx_power_level, CoreVersion::V2_1).is_empty());
+        assert!(newer_data_types(&[0x05, 0x2f, 0x00], CoreVersion::V2_1).is_empty());
+    }
+
+    #[test]
+    fn test_newer_le_supported_features() {
+        let mut le_supported_features = [false; 48].to_vec();
+        le_supported_features[0] = true;
+        le_supported_features[8] = true;
+        le_supported_features[40] = true;
+        let result = LeSupportedFeatures::new(&le_supported_features);
+        assert_eq!(
+            vec![8, 40],
+            newer_le_supported_features(&result, CoreVersion::V4_2)
+        );
+        assert_eq!(
+            vec![40],
+            newer_le_supported_features(&result, CoreVersion::V5_0)
+        );
+        assert!(newer_le_supported_features(&result, CoreVersion::V5_4).is_empty());
+    }
+}
@@ -0,0 +1,343 @@
+//! 3D Information Data (Data Type Value: 0x3d) module.
+
+use crate::data_types::{ad_type::AdType, data_type::DataType, parse_error::DataTypeParseError};
+
+/// Association Notification bit.
+pub const ASSOCIATION_NOTIFICATION: u8 = 0b0000_0001;
+
+/// Battery Level Reporting bit.
+pub const BATTERY_LEVEL_REPORTING: u8 = 0b0000_0010;
+
+/// Send Battery Level Report on Start-up Synchronization bit.
+pub const SEND_BATTERY_LEVEL_REPORT_ON_STARTUP: u8 = 0b0000_0100;
+
+/// Factory Test Mode bit.
+pub const FACTORY_TEST_MODE: u8 = 0b1000_0000;
+
+/// 3D Information Data.
+#[derive(Debug, PartialEq, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ThreeDInformationData {
+    /// data length
+    pub length: u8,
+
+    /// Association Notification
+    pub association_notification: bool,
+
+    /// Battery Level Reporting
+    pub battery_level_reporting: bool,
+
+    /// Send Battery Level Report on Start-up Synchronization
+    pub send_battery_level_report_on_startup: bool,
+
+    /// Factory Test Mode
+    pub factory_test_mode: bool,
+
+    /// Path Loss Threshold(dB)
+    pub path_loss_threshold: u8,
+}
+
+impl ThreeDInformationData {
+    /// Create [`ThreeDInformationData`] from Parameters.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ble_data_struct::data_types::three_d_information_data::ThreeDInformationData;
+    ///
+    /// let result = ThreeDInformationData::new(true, true, false, false, 0x46);
+    /// assert_eq!(3, result.length);
+    /// assert!(result.association_notification);
+    /// assert!(result.battery_level_reporting);
+    /// assert!(!result.send_battery_level_report_on_startup);
+    /// assert!(!result.factory_test_mode);
+    /// assert_eq!(0x46, result.path_loss_threshold);
+    /// ```
+    pub fn new(
+        association_notification: bool,
+        battery_level_reporting: bool,
+        send_battery_level_report_on_startup: bool,
+        factory_test_mode: bool,
+        path_loss_threshold: u8,
+    ) -> Self {
+        Self {
+            length: 3,
+            association_notification,
+            battery_level_reporting,
+            send_battery_level_report_on_startup,
+            factory_test_mode,
+            path_loss_threshold,
+        }
+    }
+
+    /// Get Flags octet.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ble_data_struct::data_types::three_d_information_data::*;
+    ///
+    /// let result = ThreeDInformationData::new(true, false, true, true, 0x46);
+    /// assert_eq!(
+    ///     ASSOCIATION_NOTIFICATION | SEND_BATTERY_LEVEL_REPORT_ON_STARTUP | FACTORY_TEST_MODE,
+    ///     result.flags()
+    /// );
+    /// ```
+    pub fn flags(&self) -> u8 {
+        let mut flags = 0u8;
+        if self.association_notification {
+            flags |= ASSOCIATION_NOTIFICATION;
+        }
+        if self.battery_level_reporting {
+            flags |= BATTERY_LEVEL_REPORTING;
+        }
+        if self.send_battery_level_report_on_startup {
+            flags |= SEND_BATTERY_LEVEL_REPORT_ON_STARTUP;
+        }
+        if self.factory_test_mode {
+            flags |= FACTORY_TEST_MODE;
+        }
+        flags
+    }
+}
+
+impl TryFrom<&[u8]> for ThreeDInformationData {
+    type Error = DataTypeParseError;
+    /// Create [`ThreeDInformationData`] from `&[u8]`.
+    fn try_from(value: &[u8]) -> Result<Self, DataTypeParseError> {
+        let len = value.len();
+        if len < 4 {
+            return Err(DataTypeParseError::InvalidLength {
+                expected: 4,
+                actual: len,
+            });
+        }
+        let length = value[0];
+        let flags = value[2];
+        Ok(Self {
+            length,
+            association_notification: flags & ASSOCIATION_NOTIFICATION != 0,
+            battery_level_reporting: flags & BATTERY_LEVEL_REPORTING != 0,
+            send_battery_level_report_on_startup: flags & SEND_BATTERY_LEVEL_REPORT_ON_STARTUP != 0,
+            factory_test_mode: flags & FACTORY_TEST_MODE != 0,
+            path_loss_threshold: value[3],
+        })
+    }
+}
+
+impl TryFrom<&Vec<u8>> for ThreeDInformationData {
+    type Error = DataTypeParseError;
+    /// Create [`ThreeDInformationData`] from [`Vec<u8>`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ble_data_struct::data_types::{three_d_information_data::*, data_type::DataType};
+    /// use ble_data_struct::data_types::parse_error::DataTypeParseError;
+    ///
+    /// let length = 3;
+    /// let mut data: Vec<u8> = Vec::new();
+    /// data.push(length);
+    /// data.push(ThreeDInformationData::data_type());
+    /// data.push(BATTERY_LEVEL_REPORTING | FACTORY_TEST_MODE);
+    /// data.push(0x46);
+    ///
+    /// let result = ThreeDInformationData::try_from(&data);
+    /// assert!(result.is_ok());
+    /// let data_type = result.unwrap();
+    /// assert_eq!(length, data_type.length);
+    /// assert!(!data_type.association_notification);
+    /// assert!(data_type.battery_level_reporting);
+    /// assert!(!data_type.send_battery_level_report_on_startup);
+    /// assert!(data_type.factory_test_mode);
+    /// assert_eq!(0x46, data_type.path_loss_threshold);
+    ///
+    /// let data: Vec<u8> = Vec::new();
+    /// let result = ThreeDInformationData::try_from(&data);
+    /// assert!(result.is_err());
+    /// assert_eq!(
+    ///     DataTypeParseError::InvalidLength {
+    ///         expected: 4,
+    ///         actual: data.len()
+    ///     },
+    ///     result.unwrap_err()
+    /// );
+    /// ```
+    fn try_from(value: &Vec<u8>) -> Result<Self, DataTypeParseError> {
+        Self::try_from(value.as_slice())
+    }
+}
+
+impl Into<Vec<u8>> for ThreeDInformationData {
+    /// Create [`Vec<u8>`] from [`ThreeDInformationData`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ble_data_struct::data_types::{three_d_information_data::*, data_type::DataType};
+    ///
+    /// let result1 = ThreeDInformationData::new(true, true, true, false, 0x46);
+    ///
+    /// let mut data: Vec<u8> = Vec::new();
+    /// data.push(3);
+    /// data.push(ThreeDInformationData::data_type());
+    /// data.push(ASSOCIATION_NOTIFICATION | BATTERY_LEVEL_REPORTING | SEND_BATTERY_LEVEL_REPORT_ON_STARTUP);
+    /// data.push(0x46);
+    ///
+    /// let into_data: Vec<u8> = result1.into();
+    /// assert_eq!(data, into_data);
+    ///
+    /// let result2 = ThreeDInformationData::try_from(&data);
+    /// assert!(result2.is_ok());
+    /// let data_type = result2.unwrap();
+    /// let into_data: Vec<u8> = data_type.into();
+    /// assert_eq!(data, into_data);
+    /// ```
+    fn into(self) -> Vec<u8> {
+        let mut data: Vec<u8> = Vec::new();
+        data.push(self.length);
+        data.push(Self::data_type());
+        data.push(self.flags());
+        data.push(self.path_loss_threshold);
+        return data;
+    }
+}
+
+impl DataType for ThreeDInformationData {
+    /// return `0x3d`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ble_data_struct::data_types::{three_d_information_data::ThreeDInformationData, data_type::DataType};
+    ///
+    /// assert_eq!(0x3d, ThreeDInformationData::data_type());
+    /// ```
+    #[inline]
+    fn data_type() -> u8 {
+        0x3d
+    }
+}
+
+/// check `3D Information Data` data type.
+///
+/// # Examples
+///
+/// ```
+/// use ble_data_struct::data_types::three_d_information_data::*;
+/// use ble_data_struct::data_types::data_type::DataType;
+///
+/// assert!(is_three_d_information_data(0x3d));
+/// assert!(!is_three_d_information_data(0x00));
+/// ```
+#[inline]
+pub fn is_three_d_information_data(data_type: u8) -> bool {
+    AdType::from_u8(data_type) == Some(AdType::ThreeDInformationData)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::data_types::{data_type::DataType, three_d_information_data::*};
+
+    #[test]
+    fn test_new() {
+        let result = ThreeDInformationData::new(false, false, true, true, 0x00);
+        assert_eq!(3, result.length);
+        assert!(!result.association_notification);
+        assert!(!result.battery_level_reporting);
+        assert!(result.send_battery_level_report_on_startup);
+        assert!(result.factory_test_mode);
+        assert_eq!(0x00, result.path_loss_threshold);
+    }
+
+    #[test]
+    fn test_flags() {
+        assert_eq!(
+            0x00,
+            ThreeDInformationData::new(false, false, false, false, 0x46).flags()
+        );
+        assert_eq!(
+            ASSOCIATION_NOTIFICATION,
+            ThreeDInformationData::new(true, false, false, false, 0x46).flags()
+        );
+        assert_eq!(
+            BATTERY_LEVEL_REPORTING,
+            ThreeDInformationData::new(false, true, false, false, 0x46).flags()
+        );
+        assert_eq!(
+            SEND_BATTERY_LEVEL_REPORT_ON_STARTUP,
+            ThreeDInformationData::new(false, false, true, false, 0x46).flags()
+        );
+        assert_eq!(
+            FACTORY_TEST_MODE,
+            ThreeDInformationData::new(false, false, false, true, 0x46).flags()
+        );
+        assert_eq!(
+            0x87,
+            ThreeDInformationData::new(true, true, true, true, 0x46).flags()
+        );
+    }
+
+    #[test]
+    fn test_try_from() {
+        let length = 3;
+        let mut data: Vec<u8> = Vec::new();
+        data.push(length);
+        data.push(ThreeDInformationData::data_type());
+        data.push(0xff);
+        data.push(0x46);
+
+        let result = ThreeDInformationData::try_from(&data);
+        assert!(result.is_ok());
+        let data_type = result.unwrap();
+        assert_eq!(length, data_type.length);
+        assert!(data_type.association_notification);
+        assert!(data_type.battery_level_reporting);
+        assert!(data_type.send_battery_level_report_on_startup);
+        assert!(data_type.factory_test_mode);
+        assert_eq!(0x46, data_type.path_loss_threshold);
+
+        let mut data: Vec<u8> = vec![0u8; 3];
+        data[0] = data.len() as u8 - 1;
+        let result = ThreeDInformationData::try_from(&data);
+        assert!(result.is_err());
+        assert_eq!(
+            DataTypeParseError::InvalidLength {
+                expected: 4,
+                actual: data.len()
+            },
+            result.unwrap_err()
+        );
+    }
+
+    #[test]
+    fn test_into() {
+        let result1 = ThreeDInformationData::new(false, true, false, true, 0x46);
+
+        let mut data: Vec<u8> = Vec::new();
+        data.push(3);
+        data.push(ThreeDInformationData::data_type());
+        data.push(BATTERY_LEVEL_REPORTING | FACTORY_TEST_MODE);
+        data.push(0x46);
+
+        let into_data: Vec<u8> = result1.into();
+        assert_eq!(data, into_data);
+
+        let result2 = ThreeDInformationData::try_from(&data);
+        assert!(result2.is_ok());
+        let data_type = result2.unwrap();
+        let into_data: Vec<u8> = data_type.into();
+        assert_eq!(data, into_data);
+    }
+
+    #[test]
+    fn test_data_type() {
+        assert_eq!(0x3d, ThreeDInformationData::data_type());
+    }
+
+    #[test]
+    fn test_is_three_d_information_data() {
+        assert!(is_three_d_information_data(0x3d));
+        assert!(!is_three_d_information_data(0x00));
+    }
+}
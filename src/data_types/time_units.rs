@@ -0,0 +1,208 @@
+//! Core Specification time unit module.
+//!
+//! Raw values of the recurring time units are wrapped, so passing a value in the wrong unit is a compile error.
+//! The wrapped integer keeps the on-air width (e.g. [`Slots625us<u32>`] for Advertising Interval - long).
+
+use std::time::Duration;
+
+macro_rules! time_unit {
+    (
+        $(#[$meta:meta])*
+        $name:ident, $micros:expr, $millis:expr
+    ) => {
+        $(#[$meta])*
+        #[derive(Debug, PartialEq, Eq, PartialOrd, Ord, Clone, Copy, Hash, Default)]
+        #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+        #[cfg_attr(feature = "serde", serde(transparent))]
+        pub struct $name<T = u16>(pub T);
+
+        impl<T> $name<T> {
+            /// Unit (micros).
+            pub const UNIT_MICROS: u64 = $micros;
+
+            /// Unit (millis).
+            pub const UNIT_MILLIS: f32 = $millis;
+        }
+
+        impl<T: Copy + Into<u64>> $name<T> {
+            /// Raw value.
+            pub fn value(&self) -> u64 {
+                self.0.into()
+            }
+
+            /// Time (micros).
+            pub fn micros(&self) -> u64 {
+                self.value() * Self::UNIT_MICROS
+            }
+
+            /// Time (millis).
+            pub fn millis(&self) -> f32 {
+                self.value() as f32 * Self::UNIT_MILLIS
+            }
+
+            /// Time as [`Duration`].
+            pub fn duration(&self) -> Duration {
+                Duration::from_micros(self.micros())
+            }
+        }
+
+        impl<T: TryFrom<u64>> $name<T> {
+            /// Create from time (micros).
+            ///
+            /// The time is rounded down to the unit. Fails if the raw value does not fit in `T`.
+            pub fn from_micros(micros: u64) -> Result<Self, String> {
+                match T::try_from(micros / Self::UNIT_MICROS) {
+                    Ok(value) => Ok(Self(value)),
+                    Err(_) => Err(format!("Invalid time :{}", micros)),
+                }
+            }
+
+            /// Create from [`Duration`].
+            ///
+            /// The time is rounded down to the unit. Fails if the raw value does not fit in `T`.
+            pub fn from_duration(duration: Duration) -> Result<Self, String> {
+                match u64::try_from(duration.as_micros()) {
+                    Ok(micros) => Self::from_micros(micros),
+                    Err(_) => Err(format!("Invalid time :{:?}", duration)),
+                }
+            }
+        }
+
+        impl<T> From<T> for $name<T> {
+            /// Create from raw value.
+            fn from(value: T) -> Self {
+                Self(value)
+            }
+        }
+    };
+}
+
+time_unit! {
+    /// 0.625 ms (Baseband slot) unit.
+    ///
+    /// Advertising interval, scan interval and scan window.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::time::Duration;
+    ///
+    /// use ble_data_struct::data_types::time_units::Slots625us;
+    ///
+    /// let result = Slots625us(0x00a0u16);
+    /// assert_eq!(0x00a0, result.value());
+    /// assert_eq!(100_000, result.micros());
+    /// assert_eq!(100.0, result.millis());
+    /// assert_eq!(Duration::from_millis(100), result.duration());
+    /// assert_eq!(Ok(result), Slots625us::from_duration(Duration::from_millis(100)));
+    /// assert!(Slots625us::<u16>::from_duration(Duration::from_secs(41)).is_err());
+    /// assert_eq!(Ok(Slots625us(0x010000u32)), Slots625us::from_duration(Duration::from_millis(40_960)));
+    /// ```
+    Slots625us, 625, 0.625
+}
+
+time_unit! {
+    /// 1.25 ms unit.
+    ///
+    /// Connection interval, periodic advertising interval and PAwR subevent timing.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::time::Duration;
+    ///
+    /// use ble_data_struct::data_types::time_units::Units1_25ms;
+    ///
+    /// let result = Units1_25ms(0x0018u16);
+    /// assert_eq!(30_000, result.micros());
+    /// assert_eq!(30.0, result.millis());
+    /// assert_eq!(Ok(result), Units1_25ms::from_duration(Duration::from_millis(30)));
+    /// assert_eq!(Ok(Units1_25ms(0x03u8)), Units1_25ms::from_micros(4_999));
+    /// ```
+    Units1_25ms, 1_250, 1.25
+}
+
+time_unit! {
+    /// 10 ms unit.
+    ///
+    /// Connection supervision timeout and periodic advertising sync timeout.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::time::Duration;
+    ///
+    /// use ble_data_struct::data_types::time_units::Units10ms;
+    ///
+    /// let result = Units10ms(0x0c80u16);
+    /// assert_eq!(Duration::from_secs(32), result.duration());
+    /// assert_eq!(32_000.0, result.millis());
+    /// assert_eq!(Ok(result), Units10ms::from_duration(Duration::from_secs(32)));
+    /// ```
+    Units10ms, 10_000, 10.0
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use crate::data_types::time_units::{Slots625us, Units10ms, Units1_25ms};
+
+    #[test]
+    fn test_slots_625us() {
+        let result = Slots625us(0x0020u16);
+        assert_eq!(0x0020, result.value());
+        assert_eq!(20_000, result.micros());
+        assert_eq!(20.0, result.millis());
+        assert_eq!(Duration::from_millis(20), result.duration());
+        assert_eq!(Slots625us(0x0020u16), Slots625us::from(0x0020u16));
+        assert_eq!(Ok(result), Slots625us::from_micros(20_624));
+        assert_eq!(
+            Ok(Slots625us(0xffffu16)),
+            Slots625us::from_micros(0xffff * 625)
+        );
+        assert!(Slots625us::<u16>::from_micros(0x010000 * 625).is_err());
+        assert_eq!(
+            Ok(Slots625us(0x010000u32)),
+            Slots625us::from_micros(0x010000 * 625)
+        );
+        assert!(Slots625us::<u16>::from_duration(Duration::MAX).is_err());
+        assert!(Slots625us(0x0020u16) < Slots625us(0x0021u16));
+    }
+
+    #[test]
+    fn test_units_1_25ms() {
+        let result = Units1_25ms(0x0006u16);
+        assert_eq!(7_500, result.micros());
+        assert_eq!(7.5, result.millis());
+        assert_eq!(Duration::from_micros(7_500), result.duration());
+        assert_eq!(Ok(result), Units1_25ms::from_micros(7_500));
+        assert_eq!(
+            Ok(Units1_25ms(0xffu8)),
+            Units1_25ms::from_micros(0xff * 1_250)
+        );
+        assert!(Units1_25ms::<u8>::from_micros(0x0100 * 1_250).is_err());
+    }
+
+    #[test]
+    fn test_units_10ms() {
+        let result = Units10ms(0x000au16);
+        assert_eq!(100_000, result.micros());
+        assert_eq!(100.0, result.millis());
+        assert_eq!(Duration::from_millis(100), result.duration());
+        assert_eq!(
+            Ok(result),
+            Units10ms::from_duration(Duration::from_millis(109))
+        );
+        assert_eq!(Units10ms(0u16), Units10ms::default());
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_serde() {
+        let result = Units1_25ms(0x0018u16);
+        let json = serde_json::to_string(&result).unwrap();
+        assert_eq!("24", json);
+        assert_eq!(result, serde_json::from_str::<Units1_25ms>(&json).unwrap());
+    }
+}
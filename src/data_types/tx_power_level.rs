@@ -1,9 +1,10 @@
 //! Tx Power Level (Data Type Value: 0x0a) module.
 
-use crate::data_types::data_type::DataType;
+use crate::data_types::{ad_type::AdType, data_type::DataType, parse_error::DataTypeParseError};
 
 /// Tx Power Level.
 #[derive(Debug, PartialEq, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct TxPowerLevel {
     /// data length
     pub length: u8,
@@ -38,14 +39,34 @@ impl TxPowerLevel {
     }
 }
 
+impl TryFrom<&[u8]> for TxPowerLevel {
+    type Error = DataTypeParseError;
+    /// Create [`TxPowerLevel`] from `&[u8]`.
+    fn try_from(value: &[u8]) -> Result<Self, DataTypeParseError> {
+        let len = value.len();
+        if len < 3 {
+            return Err(DataTypeParseError::InvalidLength {
+                expected: 3,
+                actual: len,
+            });
+        }
+        let length = value[0];
+        Ok(Self {
+            length,
+            tx_power_level: value[2] as i8,
+        })
+    }
+}
+
 impl TryFrom<&Vec<u8>> for TxPowerLevel {
-    type Error = String;
+    type Error = DataTypeParseError;
     /// Create [`TxPowerLevel`] from [`Vec<u8>`].
     ///
     /// # Examples
     ///
     /// ```
     /// use ble_data_struct::data_types::{tx_power_level::TxPowerLevel, data_type::DataType};
+    /// use ble_data_struct::data_types::parse_error::DataTypeParseError;
     ///
     /// let tx_power_level = -127;
     /// let length = 2;
@@ -77,20 +98,15 @@ impl TryFrom<&Vec<u8>> for TxPowerLevel {
     /// let result = TxPowerLevel::try_from(&data);
     /// assert!(result.is_err());
     /// assert_eq!(
-    ///     format!("Invalid data size :{}", data.len()),
+    ///     DataTypeParseError::InvalidLength {
+    ///         expected: 3,
+    ///         actual: data.len()
+    ///     },
     ///     result.unwrap_err()
     /// );
     /// ```
-    fn try_from(value: &Vec<u8>) -> Result<Self, String> {
-        let len = value.len();
-        if len < 3 {
-            return Err(format!("Invalid data size :{}", len).to_string());
-        }
-        let length = value[0];
-        Ok(Self {
-            length,
-            tx_power_level: value[2] as i8,
-        })
+    fn try_from(value: &Vec<u8>) -> Result<Self, DataTypeParseError> {
+        Self::try_from(value.as_slice())
     }
 }
 
@@ -155,6 +171,7 @@ impl DataType for TxPowerLevel {
     ///
     /// assert_eq!(0x0a, TxPowerLevel::data_type());
     /// ```
+    #[inline]
     fn data_type() -> u8 {
         0x0a
     }
@@ -171,8 +188,9 @@ impl DataType for TxPowerLevel {
 /// assert!(is_tx_power_level(0x0a));
 /// assert!(!is_tx_power_level(0x00));
 /// ```
+#[inline]
 pub fn is_tx_power_level(data_type: u8) -> bool {
-    TxPowerLevel::data_type() == data_type
+    AdType::from_u8(data_type) == Some(AdType::TxPowerLevel)
 }
 
 #[cfg(test)]
@@ -225,7 +243,10 @@ mod tests {
         let result = TxPowerLevel::try_from(&data);
         assert!(result.is_err());
         assert_eq!(
-            format!("Invalid data size :{}", data.len()),
+            DataTypeParseError::InvalidLength {
+                expected: 3,
+                actual: data.len()
+            },
             result.unwrap_err()
         );
     }
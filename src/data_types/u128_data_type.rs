@@ -0,0 +1,200 @@
+//! Fixed-size 128-bit value data type shared implementation module.
+
+use crate::data_types::data_type::DataType;
+use crate::data_types::parse_error::DataTypeParseError;
+
+/// 128-bit value data type with `DATA_TYPE` Data Type Value.
+///
+/// Use the type aliases ([`SecureSimplePairingHashC192`](crate::data_types::secure_simple_pairing_hash_c192::SecureSimplePairingHashC192), ...).
+#[derive(Debug, PartialEq, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct U128DataType<const DATA_TYPE: u8> {
+    /// data length
+    pub length: u8,
+
+    /// 128-bit value
+    pub value: u128,
+}
+
+impl<const DATA_TYPE: u8> U128DataType<DATA_TYPE> {
+    /// Create [`U128DataType`] from 128-bit value.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ble_data_struct::data_types::u128_data_type::U128DataType;
+    ///
+    /// let value = 0x0102030405060708090a0b0c0d0e0f10u128;
+    /// let result = U128DataType::<0x0e>::new(value);
+    /// assert_eq!(17, result.length);
+    /// assert_eq!(value, result.value);
+    /// ```
+    pub fn new(value: u128) -> Self {
+        Self { length: 17, value }
+    }
+}
+
+impl<const DATA_TYPE: u8> TryFrom<&[u8]> for U128DataType<DATA_TYPE> {
+    type Error = DataTypeParseError;
+    /// Create [`U128DataType`] from `&[u8]`.
+    fn try_from(value: &[u8]) -> Result<Self, DataTypeParseError> {
+        let len = value.len();
+        if len < 18 {
+            return Err(DataTypeParseError::InvalidLength {
+                expected: 18,
+                actual: len,
+            });
+        }
+        let length = value[0];
+        Ok(Self {
+            length,
+            value: u128::from_le_bytes(value[2..18].try_into().unwrap()),
+        })
+    }
+}
+
+impl<const DATA_TYPE: u8> TryFrom<&Vec<u8>> for U128DataType<DATA_TYPE> {
+    type Error = DataTypeParseError;
+    /// Create [`U128DataType`] from [`Vec<u8>`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ble_data_struct::data_types::{data_type::DataType, u128_data_type::U128DataType};
+    /// use ble_data_struct::data_types::parse_error::DataTypeParseError;
+    ///
+    /// let value = 0x0102030405060708090a0b0c0d0e0f10u128;
+    /// let length = 17;
+    /// let mut data: Vec<u8> = Vec::new();
+    /// data.push(length);
+    /// data.push(U128DataType::<0x0e>::data_type());
+    /// data.append(&mut value.to_le_bytes().to_vec());
+    ///
+    /// let result = U128DataType::<0x0e>::try_from(&data);
+    /// assert!(result.is_ok());
+    /// let data_type = result.unwrap();
+    /// assert_eq!(length, data_type.length);
+    /// assert_eq!(value, data_type.value);
+    ///
+    /// let data: Vec<u8> = Vec::new();
+    /// let result = U128DataType::<0x0e>::try_from(&data);
+    /// assert!(result.is_err());
+    /// assert_eq!(
+    ///     DataTypeParseError::InvalidLength {
+    ///         expected: 18,
+    ///         actual: data.len()
+    ///     },
+    ///     result.unwrap_err()
+    /// );
+    /// ```
+    fn try_from(value: &Vec<u8>) -> Result<Self, DataTypeParseError> {
+        Self::try_from(value.as_slice())
+    }
+}
+
+impl<const DATA_TYPE: u8> Into<Vec<u8>> for U128DataType<DATA_TYPE> {
+    /// Create [`Vec<u8>`] from [`U128DataType`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ble_data_struct::data_types::{data_type::DataType, u128_data_type::U128DataType};
+    ///
+    /// let value = 0x0102030405060708090a0b0c0d0e0f10u128;
+    /// let result1 = U128DataType::<0x0e>::new(value);
+    ///
+    /// let length = 17;
+    /// let mut data: Vec<u8> = Vec::new();
+    /// data.push(length);
+    /// data.push(U128DataType::<0x0e>::data_type());
+    /// data.append(&mut value.to_le_bytes().to_vec());
+    ///
+    /// let into_data: Vec<u8> = result1.into();
+    /// assert_eq!(data, into_data);
+    ///
+    /// let result2 = U128DataType::<0x0e>::try_from(&data);
+    /// assert!(result2.is_ok());
+    /// let data_type = result2.unwrap();
+    /// let into_data: Vec<u8> = data_type.into();
+    /// assert_eq!(data, into_data);
+    /// ```
+    fn into(self) -> Vec<u8> {
+        let mut data: Vec<u8> = Vec::new();
+        data.push(self.length);
+        data.push(Self::data_type());
+        data.append(&mut self.value.to_le_bytes().to_vec());
+        return data;
+    }
+}
+
+impl<const DATA_TYPE: u8> DataType for U128DataType<DATA_TYPE> {
+    /// return `DATA_TYPE`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ble_data_struct::data_types::{data_type::DataType, u128_data_type::U128DataType};
+    ///
+    /// assert_eq!(0x0e, U128DataType::<0x0e>::data_type());
+    /// ```
+    #[inline]
+    fn data_type() -> u8 {
+        DATA_TYPE
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::data_types::{
+        data_type::DataType, parse_error::DataTypeParseError, u128_data_type::U128DataType,
+    };
+
+    #[test]
+    fn test_new() {
+        let value = 0x0102030405060708090a0b0c0d0e0f10u128;
+        let result = U128DataType::<0x0e>::new(value);
+        assert_eq!(17, result.length);
+        assert_eq!(value, result.value);
+    }
+
+    #[test]
+    fn test_try_from() {
+        let value = 0x0102030405060708090a0b0c0d0e0f10u128;
+        let mut data: Vec<u8> = [17u8, 0x0e].to_vec();
+        data.append(&mut value.to_le_bytes().to_vec());
+
+        let result = U128DataType::<0x0e>::try_from(&data);
+        assert!(result.is_ok());
+        let data_type = result.unwrap();
+        assert_eq!(17, data_type.length);
+        assert_eq!(value, data_type.value);
+
+        let mut data: Vec<u8> = vec![0u8; 17];
+        data[0] = data.len() as u8 - 1;
+        let result = U128DataType::<0x0e>::try_from(&data);
+        assert!(result.is_err());
+        assert_eq!(
+            DataTypeParseError::InvalidLength {
+                expected: 18,
+                actual: data.len()
+            },
+            result.unwrap_err()
+        );
+    }
+
+    #[test]
+    fn test_into() {
+        let value = 0x0102030405060708090a0b0c0d0e0f10u128;
+        let mut data: Vec<u8> = [17u8, 0x1d].to_vec();
+        data.append(&mut value.to_le_bytes().to_vec());
+
+        let into_data: Vec<u8> = U128DataType::<0x1d>::new(value).into();
+        assert_eq!(data, into_data);
+    }
+
+    #[test]
+    fn test_data_type() {
+        assert_eq!(0x0e, U128DataType::<0x0e>::data_type());
+        assert_eq!(0x1e, U128DataType::<0x1e>::data_type());
+    }
+}
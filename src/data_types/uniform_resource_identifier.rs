@@ -1,9 +1,10 @@
 //! Uniform Resource Identifier (Data Type Value: 0x24) module.
 
-use crate::data_types::data_type::DataType;
+use crate::data_types::{ad_type::AdType, data_type::DataType, parse_error::DataTypeParseError};
 
 /// Uniform Resource Identifier.
 #[derive(Debug, PartialEq, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct UniformResourceIdentifier {
     /// data length
     pub length: u8,
@@ -48,14 +49,49 @@ impl UniformResourceIdentifier {
     }
 }
 
+impl TryFrom<&[u8]> for UniformResourceIdentifier {
+    type Error = DataTypeParseError;
+    /// Create [`UniformResourceIdentifier`] from `&[u8]`.
+    fn try_from(value: &[u8]) -> Result<Self, DataTypeParseError> {
+        let len = value.len();
+        if len < 4 {
+            return Err(DataTypeParseError::InvalidLength {
+                expected: 4,
+                actual: len,
+            });
+        }
+        let length = value[0];
+        if len < 1 + usize::from(length) {
+            return Err(DataTypeParseError::InvalidLength {
+                expected: 1 + usize::from(length),
+                actual: len,
+            });
+        }
+        let uniform_resource_identifier =
+            String::from_utf8(value[2..1 + usize::from(length)].to_vec()).map_err(|e| {
+                DataTypeParseError::InvalidUtf8 {
+                    valid_up_to: e.utf8_error().valid_up_to(),
+                }
+            })?;
+        // scheme is a single code point (1 or more bytes)
+        let mut chars = uniform_resource_identifier.chars();
+        Ok(Self {
+            length,
+            scheme: chars.next().unwrap(),
+            uniform_resource_identifier: chars.as_str().to_string(),
+        })
+    }
+}
+
 impl TryFrom<&Vec<u8>> for UniformResourceIdentifier {
-    type Error = String;
+    type Error = DataTypeParseError;
     /// Create [`UniformResourceIdentifier`] from [`Vec<u8>`].
     ///
     /// # Examples
     ///
     /// ```
     /// use ble_data_struct::data_types::{uniform_resource_identifier::UniformResourceIdentifier, data_type::DataType};
+    /// use ble_data_struct::data_types::parse_error::DataTypeParseError;
     ///
     /// let scheme = '\u{0016}';
     /// let body = "uniform_resource_identifier";
@@ -93,23 +129,15 @@ impl TryFrom<&Vec<u8>> for UniformResourceIdentifier {
     /// let result = UniformResourceIdentifier::try_from(&data);
     /// assert!(result.is_err());
     /// assert_eq!(
-    ///     format!("Invalid data size :{}", data.len()),
+    ///     DataTypeParseError::InvalidLength {
+    ///         expected: 4,
+    ///         actual: data.len()
+    ///     },
     ///     result.unwrap_err()
     /// );
     /// ```
-    fn try_from(value: &Vec<u8>) -> Result<Self, String> {
-        let len = value.len();
-        if len < 4 {
-            return Err(format!("Invalid data size :{}", len).to_string());
-        }
-        let length = value[0];
-        let uniform_resource_identifier =
-            String::from_utf8(value[2..1 + usize::from(length)].to_vec()).unwrap();
-        Ok(Self {
-            length,
-            scheme: uniform_resource_identifier.chars().next().unwrap(),
-            uniform_resource_identifier: uniform_resource_identifier.split_at(1).1.to_string(),
-        })
+    fn try_from(value: &Vec<u8>) -> Result<Self, DataTypeParseError> {
+        Self::try_from(value.as_slice())
     }
 }
 
@@ -181,6 +209,7 @@ impl DataType for UniformResourceIdentifier {
     ///
     /// assert_eq!(0x24, UniformResourceIdentifier::data_type());
     /// ```
+    #[inline]
     fn data_type() -> u8 {
         0x24
     }
@@ -197,8 +226,9 @@ impl DataType for UniformResourceIdentifier {
 /// assert!(is_uniform_resource_identifier(0x24));
 /// assert!(!is_uniform_resource_identifier(0x00));
 /// ```
+#[inline]
 pub fn is_uniform_resource_identifier(data_type: u8) -> bool {
-    UniformResourceIdentifier::data_type() == data_type
+    AdType::from_u8(data_type) == Some(AdType::UniformResourceIdentifier)
 }
 
 #[cfg(test)]
@@ -242,6 +272,30 @@ mod tests {
         assert_eq!(scheme, data_type.scheme);
         assert_eq!(body, data_type.uniform_resource_identifier);
 
+        let scheme = '\u{00e9}';
+        let uri = scheme.to_string() + body;
+        let mut data: Vec<u8> = Vec::new();
+        data.push(uri.len() as u8 + 1);
+        data.push(UniformResourceIdentifier::data_type());
+        data.append(&mut uri.to_string().into_bytes());
+
+        let data_type = UniformResourceIdentifier::try_from(&data).unwrap();
+        assert_eq!(scheme, data_type.scheme);
+        assert_eq!(body, data_type.uniform_resource_identifier);
+
+        let data = vec![0x03, UniformResourceIdentifier::data_type(), 0x16, 0xff];
+        assert_eq!(
+            Err(DataTypeParseError::InvalidUtf8 { valid_up_to: 1 }),
+            UniformResourceIdentifier::try_from(&data)
+        );
+        assert_eq!(
+            Err(DataTypeParseError::InvalidLength {
+                expected: 5,
+                actual: 4
+            }),
+            UniformResourceIdentifier::try_from(&vec![0x04, 0x24, 0x16, 0x61])
+        );
+
         let scheme = '\u{0001}';
         let body = "empty:uniform_resource_identifier";
         let uri = scheme.to_string() + body;
@@ -263,7 +317,10 @@ mod tests {
         let result = UniformResourceIdentifier::try_from(&data);
         assert!(result.is_err());
         assert_eq!(
-            format!("Invalid data size :{}", data.len()),
+            DataTypeParseError::InvalidLength {
+                expected: 4,
+                actual: data.len()
+            },
             result.unwrap_err()
         );
     }
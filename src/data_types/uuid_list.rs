@@ -0,0 +1,451 @@
+//! Incomplete/Complete List of 16/32/128-bit Service Class UUIDs shared implementation module.
+
+use uuid::Uuid;
+
+use crate::{
+    data_types::{
+        data_type::DataType, parse_error::DataTypeParseError, service_uuid_list::ServiceUuidList,
+    },
+    BASE_UUID,
+};
+
+/// Incomplete/Complete List of `WIDTH`-bit Service Class UUIDs.
+///
+/// `WIDTH` is `16`, `32` or `128`.
+/// Use the type aliases ([`CompleteListOf16BitServiceUuids`](crate::data_types::complete_list_of_16bit_service_uuids::CompleteListOf16BitServiceUuids), ...).
+#[derive(Debug, PartialEq, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct UuidList<const WIDTH: usize, const COMPLETE: bool> {
+    /// data length
+    pub length: u8,
+
+    /// UUIDs
+    pub uuids: Vec<Uuid>,
+}
+
+impl<const WIDTH: usize, const COMPLETE: bool> UuidList<WIDTH, COMPLETE> {
+    /// Data Type Value.
+    const DATA_TYPE: u8 = match (WIDTH, COMPLETE) {
+        (16, false) => 0x02,
+        (16, true) => 0x03,
+        (32, false) => 0x04,
+        (32, true) => 0x05,
+        (128, false) => 0x06,
+        (128, true) => 0x07,
+        _ => panic!("Invalid UUID width"),
+    };
+
+    /// UUID size(byte).
+    const UUID_SIZE: usize = WIDTH / 8;
+
+    /// Create [`UuidList`] from [`Vec<Uuid>`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ble_data_struct::data_types::complete_list_of_32bit_service_uuids::CompleteListOf32BitServiceUuids;
+    /// use uuid::{uuid, Uuid};
+    ///
+    /// let uuids: Vec<Uuid> = [
+    ///     uuid!("00000001-0000-1000-8000-00805F9B34FB"),
+    ///     uuid!("00000002-0000-1000-8000-00805F9B34FB"),
+    /// ]
+    /// .to_vec();
+    /// let result = CompleteListOf32BitServiceUuids::new(&uuids);
+    /// assert_eq!(uuids.len() as u8 * 4 + 1, result.length);
+    /// assert_eq!(uuids, result.uuids);
+    /// ```
+    pub fn new(uuids: &Vec<Uuid>) -> Self {
+        Self {
+            length: (uuids.len() * Self::UUID_SIZE + 1) as u8,
+            uuids: uuids.clone(),
+        }
+    }
+}
+
+impl<const WIDTH: usize, const COMPLETE: bool> TryFrom<&[u8]> for UuidList<WIDTH, COMPLETE> {
+    type Error = DataTypeParseError;
+    /// Create [`UuidList`] from `&[u8]`.
+    fn try_from(value: &[u8]) -> Result<Self, DataTypeParseError> {
+        let len = value.len();
+        if len < 2 + Self::UUID_SIZE {
+            return Err(DataTypeParseError::InvalidLength {
+                expected: 2 + Self::UUID_SIZE,
+                actual: len,
+            });
+        }
+        let length = value[0];
+        Ok(Self {
+            length,
+            uuids: value[2..2 + length as usize - 1]
+                .chunks_exact(Self::UUID_SIZE)
+                .map(uuid_from_le_bytes)
+                .collect(),
+        })
+    }
+}
+
+impl<const WIDTH: usize, const COMPLETE: bool> TryFrom<&Vec<u8>> for UuidList<WIDTH, COMPLETE> {
+    type Error = DataTypeParseError;
+    /// Create [`UuidList`] from [`Vec<u8>`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ble_data_struct::data_types::{
+    ///     data_type::DataType,
+    ///     incomplete_list_of_16bit_service_uuids::IncompleteListOf16BitServiceUuids,
+    ///     parse_error::DataTypeParseError,
+    /// };
+    /// use ble_data_struct::uuid_from_u16;
+    ///
+    /// let data: Vec<u8> = [0x05u8, 0x02u8, 0x0fu8, 0x18u8, 0x0au8, 0x18u8].to_vec();
+    /// let result = IncompleteListOf16BitServiceUuids::try_from(&data);
+    /// assert!(result.is_ok());
+    /// let data_type = result.unwrap();
+    /// assert_eq!(0x05, data_type.length);
+    /// assert_eq!(
+    ///     [uuid_from_u16(0x180f), uuid_from_u16(0x180a)].to_vec(),
+    ///     data_type.uuids
+    /// );
+    ///
+    /// let data: Vec<u8> = Vec::new();
+    /// let result = IncompleteListOf16BitServiceUuids::try_from(&data);
+    /// assert!(result.is_err());
+    /// assert_eq!(
+    ///     DataTypeParseError::InvalidLength {
+    ///         expected: 4,
+    ///         actual: data.len()
+    ///     },
+    ///     result.unwrap_err()
+    /// );
+    /// ```
+    fn try_from(value: &Vec<u8>) -> Result<Self, DataTypeParseError> {
+        Self::try_from(value.as_slice())
+    }
+}
+
+impl<const WIDTH: usize, const COMPLETE: bool> Into<Vec<u8>> for UuidList<WIDTH, COMPLETE> {
+    /// Create [`Vec<u8>`] from [`UuidList`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ble_data_struct::data_types::{
+    ///     data_type::DataType,
+    ///     incomplete_list_of_16bit_service_uuids::IncompleteListOf16BitServiceUuids,
+    /// };
+    /// use ble_data_struct::uuid_from_u16;
+    ///
+    /// let result1 = IncompleteListOf16BitServiceUuids::new(
+    ///     &[uuid_from_u16(0x180f), uuid_from_u16(0x180a)].to_vec(),
+    /// );
+    /// let data: Vec<u8> = [0x05u8, 0x02u8, 0x0fu8, 0x18u8, 0x0au8, 0x18u8].to_vec();
+    ///
+    /// let into_data: Vec<u8> = result1.into();
+    /// assert_eq!(data, into_data);
+    ///
+    /// let result2 = IncompleteListOf16BitServiceUuids::try_from(&data);
+    /// assert!(result2.is_ok());
+    /// let data_type = result2.unwrap();
+    /// let into_data: Vec<u8> = data_type.into();
+    /// assert_eq!(data, into_data);
+    /// ```
+    fn into(self) -> Vec<u8> {
+        let mut data: Vec<u8> = Vec::new();
+        data.push(self.length);
+        data.push(Self::data_type());
+        data.append(
+            &mut self
+                .uuids
+                .iter()
+                .flat_map(|f| uuid_to_le_bytes(f, Self::UUID_SIZE))
+                .collect(),
+        );
+        return data;
+    }
+}
+
+impl<const WIDTH: usize> From<&UuidList<WIDTH, false>> for UuidList<WIDTH, true> {
+    /// Create `Complete List` from `Incomplete List`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use uuid::{uuid, Uuid};
+    /// use ble_data_struct::data_types::{
+    ///     complete_list_of_16bit_service_uuids::CompleteListOf16BitServiceUuids,
+    ///     incomplete_list_of_16bit_service_uuids::IncompleteListOf16BitServiceUuids,
+    /// };
+    ///
+    /// let uuids: Vec<Uuid> = [uuid!("00000001-0000-1000-8000-00805F9B34FB")].to_vec();
+    /// let result =
+    ///     CompleteListOf16BitServiceUuids::from(&IncompleteListOf16BitServiceUuids::new(&uuids));
+    /// assert_eq!(CompleteListOf16BitServiceUuids::new(&uuids), result);
+    /// ```
+    fn from(value: &UuidList<WIDTH, false>) -> Self {
+        Self::new(&value.uuids)
+    }
+}
+
+impl<const WIDTH: usize> From<&UuidList<WIDTH, true>> for UuidList<WIDTH, false> {
+    /// Create `Incomplete List` from `Complete List`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use uuid::{uuid, Uuid};
+    /// use ble_data_struct::data_types::{
+    ///     complete_list_of_16bit_service_uuids::CompleteListOf16BitServiceUuids,
+    ///     incomplete_list_of_16bit_service_uuids::IncompleteListOf16BitServiceUuids,
+    /// };
+    ///
+    /// let uuids: Vec<Uuid> = [uuid!("00000001-0000-1000-8000-00805F9B34FB")].to_vec();
+    /// let result =
+    ///     IncompleteListOf16BitServiceUuids::from(&CompleteListOf16BitServiceUuids::new(&uuids));
+    /// assert_eq!(IncompleteListOf16BitServiceUuids::new(&uuids), result);
+    /// ```
+    fn from(value: &UuidList<WIDTH, true>) -> Self {
+        Self::new(&value.uuids)
+    }
+}
+
+impl<const WIDTH: usize, const COMPLETE: bool> ServiceUuidList for UuidList<WIDTH, COMPLETE> {
+    /// return UUIDs.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use uuid::{uuid, Uuid};
+    /// use ble_data_struct::data_types::{
+    ///     complete_list_of_128bit_service_uuids::CompleteListOf128BitServiceUuids,
+    ///     service_uuid_list::ServiceUuidList,
+    /// };
+    ///
+    /// let uuids: Vec<Uuid> = [uuid!("00000001-0000-1000-8000-00805F9B34FB")].to_vec();
+    /// let result = CompleteListOf128BitServiceUuids::new(&uuids);
+    /// assert_eq!(&uuids, result.uuids());
+    /// ```
+    fn uuids(&self) -> &Vec<Uuid> {
+        &self.uuids
+    }
+
+    /// return `COMPLETE`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use uuid::{uuid, Uuid};
+    /// use ble_data_struct::data_types::{
+    ///     complete_list_of_128bit_service_uuids::CompleteListOf128BitServiceUuids,
+    ///     incomplete_list_of_128bit_service_uuids::IncompleteListOf128BitServiceUuids,
+    ///     service_uuid_list::ServiceUuidList,
+    /// };
+    ///
+    /// let uuids: Vec<Uuid> = [uuid!("00000001-0000-1000-8000-00805F9B34FB")].to_vec();
+    /// assert!(CompleteListOf128BitServiceUuids::new(&uuids).is_complete());
+    /// assert!(!IncompleteListOf128BitServiceUuids::new(&uuids).is_complete());
+    /// ```
+    fn is_complete(&self) -> bool {
+        COMPLETE
+    }
+}
+
+impl<const WIDTH: usize, const COMPLETE: bool> DataType for UuidList<WIDTH, COMPLETE> {
+    /// return `0x02` - `0x07`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ble_data_struct::data_types::{
+    ///     data_type::DataType, uuid_list::UuidList,
+    /// };
+    ///
+    /// assert_eq!(0x02, UuidList::<16, false>::data_type());
+    /// assert_eq!(0x03, UuidList::<16, true>::data_type());
+    /// assert_eq!(0x04, UuidList::<32, false>::data_type());
+    /// assert_eq!(0x05, UuidList::<32, true>::data_type());
+    /// assert_eq!(0x06, UuidList::<128, false>::data_type());
+    /// assert_eq!(0x07, UuidList::<128, true>::data_type());
+    /// ```
+    #[inline]
+    fn data_type() -> u8 {
+        Self::DATA_TYPE
+    }
+}
+
+/// Create [`Uuid`] from 16/32/128-bit little endian bytes.
+pub(crate) fn uuid_from_le_bytes(bytes: &[u8]) -> Uuid {
+    if bytes.len() == 16 {
+        Uuid::from_u128(u128::from_le_bytes(bytes.try_into().unwrap()))
+    } else {
+        let mut uuid_bytes = BASE_UUID.to_bytes_le();
+        uuid_bytes[..bytes.len()].copy_from_slice(bytes);
+        Uuid::from_bytes_le(uuid_bytes)
+    }
+}
+
+/// Create 16/32/128-bit little endian bytes from [`Uuid`].
+pub(crate) fn uuid_to_le_bytes(uuid: &Uuid, size: usize) -> Vec<u8> {
+    if size == 16 {
+        uuid.as_u128().to_le_bytes().to_vec()
+    } else {
+        uuid.to_bytes_le()[..size].to_vec()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        data_types::{
+            data_type::DataType,
+            parse_error::DataTypeParseError,
+            service_uuid_list::ServiceUuidList,
+            uuid_list::{uuid_from_le_bytes, uuid_to_le_bytes, UuidList},
+        },
+        uuid_from_u16,
+    };
+
+    #[test]
+    fn test_new() {
+        let uuids = [uuid_from_u16(0x180f), uuid_from_u16(0x180a)].to_vec();
+        assert_eq!(5, UuidList::<16, false>::new(&uuids).length);
+        assert_eq!(9, UuidList::<32, true>::new(&uuids).length);
+        assert_eq!(33, UuidList::<128, false>::new(&uuids).length);
+    }
+
+    #[test]
+    fn test_try_from() {
+        let uuids = [uuid_from_u16(0x180f), uuid_from_u16(0x180a)].to_vec();
+
+        let data: Vec<u8> = [0x05u8, 0x02u8, 0x0fu8, 0x18u8, 0x0au8, 0x18u8].to_vec();
+        let result = UuidList::<16, false>::try_from(&data);
+        assert!(result.is_ok());
+        assert_eq!(uuids, result.unwrap().uuids);
+
+        let data: Vec<u8> = [
+            0x09u8, 0x05u8, 0x0fu8, 0x18u8, 0x00u8, 0x00u8, 0x0au8, 0x18u8, 0x00u8, 0x00u8,
+        ]
+        .to_vec();
+        let result = UuidList::<32, true>::try_from(&data);
+        assert!(result.is_ok());
+        assert_eq!(uuids, result.unwrap().uuids);
+
+        let mut data: Vec<u8> = [0x21u8, 0x06u8].to_vec();
+        uuids
+            .iter()
+            .for_each(|f| data.append(&mut f.as_u128().to_le_bytes().to_vec()));
+        let result = UuidList::<128, false>::try_from(&data);
+        assert!(result.is_ok());
+        assert_eq!(uuids, result.unwrap().uuids);
+
+        let data: Vec<u8> = vec![0u8; 3];
+        let result = UuidList::<16, true>::try_from(&data);
+        assert_eq!(
+            Err(DataTypeParseError::InvalidLength {
+                expected: 4,
+                actual: data.len()
+            }),
+            result
+        );
+
+        let data: Vec<u8> = vec![0u8; 5];
+        let result = UuidList::<32, false>::try_from(&data);
+        assert_eq!(
+            Err(DataTypeParseError::InvalidLength {
+                expected: 6,
+                actual: data.len()
+            }),
+            result
+        );
+
+        let data: Vec<u8> = vec![0u8; 17];
+        let result = UuidList::<128, true>::try_from(&data);
+        assert_eq!(
+            Err(DataTypeParseError::InvalidLength {
+                expected: 18,
+                actual: data.len()
+            }),
+            result
+        );
+    }
+
+    #[test]
+    fn test_into() {
+        let uuids = [uuid_from_u16(0x180f), uuid_from_u16(0x180a)].to_vec();
+
+        let data: Vec<u8> = UuidList::<16, true>::new(&uuids).into();
+        assert_eq!(
+            [0x05u8, 0x03u8, 0x0fu8, 0x18u8, 0x0au8, 0x18u8].to_vec(),
+            data
+        );
+
+        let data: Vec<u8> = UuidList::<32, false>::new(&uuids).into();
+        assert_eq!(
+            [0x09u8, 0x04u8, 0x0fu8, 0x18u8, 0x00u8, 0x00u8, 0x0au8, 0x18u8, 0x00u8, 0x00u8]
+                .to_vec(),
+            data
+        );
+
+        let data: Vec<u8> = UuidList::<128, true>::new(&uuids).into();
+        let mut expected: Vec<u8> = [0x21u8, 0x07u8].to_vec();
+        uuids
+            .iter()
+            .for_each(|f| expected.append(&mut f.as_u128().to_le_bytes().to_vec()));
+        assert_eq!(expected, data);
+    }
+
+    #[test]
+    fn test_from() {
+        let uuids = [uuid_from_u16(0x180f), uuid_from_u16(0x180a)].to_vec();
+        let incomplete = UuidList::<32, false>::new(&uuids);
+        let complete = UuidList::<32, true>::from(&incomplete);
+        assert_eq!(UuidList::<32, true>::new(&uuids), complete);
+        assert_eq!(incomplete, UuidList::<32, false>::from(&complete));
+    }
+
+    #[test]
+    fn test_uuids() {
+        let uuids = [uuid_from_u16(0x180f), uuid_from_u16(0x180a)].to_vec();
+        assert_eq!(&uuids, UuidList::<128, true>::new(&uuids).uuids());
+    }
+
+    #[test]
+    fn test_is_complete() {
+        let uuids = [uuid_from_u16(0x180f)].to_vec();
+        assert!(UuidList::<16, true>::new(&uuids).is_complete());
+        assert!(!UuidList::<16, false>::new(&uuids).is_complete());
+    }
+
+    #[test]
+    fn test_data_type() {
+        assert_eq!(0x02, UuidList::<16, false>::data_type());
+        assert_eq!(0x03, UuidList::<16, true>::data_type());
+        assert_eq!(0x04, UuidList::<32, false>::data_type());
+        assert_eq!(0x05, UuidList::<32, true>::data_type());
+        assert_eq!(0x06, UuidList::<128, false>::data_type());
+        assert_eq!(0x07, UuidList::<128, true>::data_type());
+    }
+
+    #[test]
+    fn test_uuid_from_le_bytes() {
+        let uuid = uuid_from_u16(0x180f);
+        assert_eq!(uuid, uuid_from_le_bytes(&[0x0f, 0x18]));
+        assert_eq!(uuid, uuid_from_le_bytes(&[0x0f, 0x18, 0x00, 0x00]));
+        assert_eq!(uuid, uuid_from_le_bytes(&uuid.as_u128().to_le_bytes()));
+    }
+
+    #[test]
+    fn test_uuid_to_le_bytes() {
+        let uuid = uuid_from_u16(0x180f);
+        assert_eq!([0x0f, 0x18].to_vec(), uuid_to_le_bytes(&uuid, 2));
+        assert_eq!(
+            [0x0f, 0x18, 0x00, 0x00].to_vec(),
+            uuid_to_le_bytes(&uuid, 4)
+        );
+        assert_eq!(
+            uuid.as_u128().to_le_bytes().to_vec(),
+            uuid_to_le_bytes(&uuid, 16)
+        );
+    }
+}
@@ -0,0 +1,358 @@
+//! Per-client Client Characteristic Configuration table module.
+//!
+//! Peripherals must retain the configuration of bonded clients across connections
+//! (Core Specification Vol 3, Part G, 3.3.3.3).
+
+use std::collections::BTreeMap;
+
+use crate::{
+    data_types::parse_error::DataTypeParseError,
+    descriptors::client_characteristic_configuration::ClientCharacteristicConfiguration,
+};
+
+/// Serialized entry size (client identifier, handle and configuration).
+const ENTRY_SIZE: usize = 12;
+
+/// Per-client [`ClientCharacteristicConfiguration`] table.
+///
+/// Entries are keyed by client identifier (e.g. identity address) and Client Characteristic Configuration handle.
+#[derive(Debug, PartialEq, Clone, Default)]
+pub struct CccdTable {
+    /// configurations
+    entries: BTreeMap<(u64, u16), u16>,
+}
+
+impl CccdTable {
+    /// Create empty [`CccdTable`].
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Returns the configuration of the client (`0x0000` if not configured).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ble_data_struct::descriptors::{
+    ///     cccd_table::CccdTable,
+    ///     client_characteristic_configuration::{ClientCharacteristicConfiguration, NOTIFICATION},
+    /// };
+    ///
+    /// let mut table = CccdTable::new();
+    /// table.set(0x0000060504030201, 0x0010, &ClientCharacteristicConfiguration::new(NOTIFICATION));
+    /// assert!(table.get(0x0000060504030201, 0x0010).is_notification());
+    /// assert_eq!(0, table.get(0x0000060504030201, 0x0020).configuration);
+    /// ```
+    pub fn get(&self, client: u64, handle: u16) -> ClientCharacteristicConfiguration {
+        ClientCharacteristicConfiguration::new(
+            self.entries.get(&(client, handle)).copied().unwrap_or(0),
+        )
+    }
+
+    /// Set the configuration of the client. `0x0000` removes the entry.
+    pub fn set(
+        &mut self,
+        client: u64,
+        handle: u16,
+        configuration: &ClientCharacteristicConfiguration,
+    ) {
+        if configuration.configuration == 0 {
+            self.entries.remove(&(client, handle));
+        } else {
+            self.entries
+                .insert((client, handle), configuration.configuration);
+        }
+    }
+
+    /// Remove all configurations of the client (e.g. unbonded client disconnected).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ble_data_struct::descriptors::{
+    ///     cccd_table::CccdTable,
+    ///     client_characteristic_configuration::{ClientCharacteristicConfiguration, INDICATION},
+    /// };
+    ///
+    /// let mut table = CccdTable::new();
+    /// table.set(1, 0x0010, &ClientCharacteristicConfiguration::new(INDICATION));
+    /// table.set(1, 0x0020, &ClientCharacteristicConfiguration::new(INDICATION));
+    /// table.remove_client(1);
+    /// assert!(table.is_empty());
+    /// ```
+    pub fn remove_client(&mut self, client: u64) {
+        self.entries.retain(|(f, _), _| *f != client);
+    }
+
+    /// Returns the clients and configurations subscribed to the handle.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ble_data_struct::descriptors::{
+    ///     cccd_table::CccdTable,
+    ///     client_characteristic_configuration::{
+    ///         ClientCharacteristicConfiguration, INDICATION, NOTIFICATION,
+    ///     },
+    /// };
+    ///
+    /// let mut table = CccdTable::new();
+    /// table.set(1, 0x0010, &ClientCharacteristicConfiguration::new(NOTIFICATION));
+    /// table.set(2, 0x0010, &ClientCharacteristicConfiguration::new(INDICATION));
+    /// table.set(2, 0x0020, &ClientCharacteristicConfiguration::new(NOTIFICATION));
+    /// assert_eq!(
+    ///     vec![
+    ///         (1, ClientCharacteristicConfiguration::new(NOTIFICATION)),
+    ///         (2, ClientCharacteristicConfiguration::new(INDICATION)),
+    ///     ],
+    ///     table.subscribers(0x0010)
+    /// );
+    /// ```
+    pub fn subscribers(&self, handle: u16) -> Vec<(u64, ClientCharacteristicConfiguration)> {
+        self.entries
+            .iter()
+            .filter(|((_, f), _)| *f == handle)
+            .map(|((client, _), configuration)| {
+                (
+                    *client,
+                    ClientCharacteristicConfiguration::new(*configuration),
+                )
+            })
+            .collect()
+    }
+
+    /// Returns the number of entries.
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    /// Returns `true` if the table has no entries.
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+}
+
+impl TryFrom<&[u8]> for CccdTable {
+    type Error = DataTypeParseError;
+    /// Create [`CccdTable`] from `&[u8]`.
+    fn try_from(value: &[u8]) -> Result<Self, DataTypeParseError> {
+        let len = value.len();
+        if !len.is_multiple_of(ENTRY_SIZE) {
+            return Err(DataTypeParseError::InvalidLength {
+                expected: len.next_multiple_of(ENTRY_SIZE),
+                actual: len,
+            });
+        }
+        let mut table = Self::new();
+        for entry in value.chunks_exact(ENTRY_SIZE) {
+            table.set(
+                u64::from_le_bytes(entry[0..8].try_into().unwrap()),
+                u16::from_le_bytes(entry[8..10].try_into().unwrap()),
+                &ClientCharacteristicConfiguration::new(u16::from_le_bytes(
+                    entry[10..12].try_into().unwrap(),
+                )),
+            );
+        }
+        Ok(table)
+    }
+}
+
+impl TryFrom<&Vec<u8>> for CccdTable {
+    type Error = DataTypeParseError;
+    /// Create [`CccdTable`] from persisted [`Vec<u8>`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ble_data_struct::{
+    ///     data_types::parse_error::DataTypeParseError,
+    ///     descriptors::{
+    ///         cccd_table::CccdTable,
+    ///         client_characteristic_configuration::{ClientCharacteristicConfiguration, NOTIFICATION},
+    ///     },
+    /// };
+    ///
+    /// let mut table = CccdTable::new();
+    /// table.set(1, 0x0010, &ClientCharacteristicConfiguration::new(NOTIFICATION));
+    /// let data: Vec<u8> = table.clone().into();
+    /// assert_eq!(Ok(table), CccdTable::try_from(&data));
+    ///
+    /// let data: Vec<u8> = vec![0u8; 11];
+    /// assert_eq!(
+    ///     Err(DataTypeParseError::InvalidLength {
+    ///         expected: 12,
+    ///         actual: data.len()
+    ///     }),
+    ///     CccdTable::try_from(&data)
+    /// );
+    /// ```
+    fn try_from(value: &Vec<u8>) -> Result<Self, DataTypeParseError> {
+        Self::try_from(value.as_slice())
+    }
+}
+
+impl Into<Vec<u8>> for CccdTable {
+    /// Create persisted [`Vec<u8>`] from [`CccdTable`].
+    ///
+    /// Each entry is client identifier(8 bytes), handle(2 bytes) and configuration(2 bytes) in little endian.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ble_data_struct::descriptors::{
+    ///     cccd_table::CccdTable,
+    ///     client_characteristic_configuration::{ClientCharacteristicConfiguration, INDICATION},
+    /// };
+    ///
+    /// let mut table = CccdTable::new();
+    /// table.set(1, 0x0010, &ClientCharacteristicConfiguration::new(INDICATION));
+    /// let data: Vec<u8> = table.into();
+    /// assert_eq!(
+    ///     vec![0x01, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x10, 0x00, 0x02, 0x00],
+    ///     data
+    /// );
+    /// ```
+    fn into(self) -> Vec<u8> {
+        let mut data: Vec<u8> = Vec::with_capacity(self.entries.len() * ENTRY_SIZE);
+        for ((client, handle), configuration) in self.entries {
+            data.extend_from_slice(&client.to_le_bytes());
+            data.extend_from_slice(&handle.to_le_bytes());
+            data.extend_from_slice(&configuration.to_le_bytes());
+        }
+        return data;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        data_types::parse_error::DataTypeParseError,
+        descriptors::{
+            cccd_table::CccdTable,
+            client_characteristic_configuration::{
+                ClientCharacteristicConfiguration, INDICATION, NOTIFICATION,
+            },
+        },
+    };
+
+    #[test]
+    fn test_set_get() {
+        let mut table = CccdTable::new();
+        assert!(table.is_empty());
+        table.set(
+            1,
+            0x0010,
+            &ClientCharacteristicConfiguration::new(NOTIFICATION),
+        );
+        table.set(
+            2,
+            0x0010,
+            &ClientCharacteristicConfiguration::new(INDICATION),
+        );
+        assert_eq!(2, table.len());
+        assert!(table.get(1, 0x0010).is_notification());
+        assert!(table.get(2, 0x0010).is_indication());
+        assert_eq!(0, table.get(3, 0x0010).configuration);
+
+        table.set(1, 0x0010, &ClientCharacteristicConfiguration::new(0));
+        assert_eq!(1, table.len());
+        assert_eq!(0, table.get(1, 0x0010).configuration);
+    }
+
+    #[test]
+    fn test_remove_client() {
+        let mut table = CccdTable::new();
+        table.set(
+            1,
+            0x0010,
+            &ClientCharacteristicConfiguration::new(NOTIFICATION),
+        );
+        table.set(
+            1,
+            0x0020,
+            &ClientCharacteristicConfiguration::new(INDICATION),
+        );
+        table.set(
+            2,
+            0x0010,
+            &ClientCharacteristicConfiguration::new(INDICATION),
+        );
+        table.remove_client(1);
+        assert_eq!(1, table.len());
+        assert!(table.get(2, 0x0010).is_indication());
+    }
+
+    #[test]
+    fn test_subscribers() {
+        let mut table = CccdTable::new();
+        table.set(
+            2,
+            0x0010,
+            &ClientCharacteristicConfiguration::new(INDICATION),
+        );
+        table.set(
+            1,
+            0x0010,
+            &ClientCharacteristicConfiguration::new(NOTIFICATION),
+        );
+        table.set(
+            1,
+            0x0020,
+            &ClientCharacteristicConfiguration::new(NOTIFICATION),
+        );
+        assert_eq!(
+            vec![
+                (1, ClientCharacteristicConfiguration::new(NOTIFICATION)),
+                (2, ClientCharacteristicConfiguration::new(INDICATION)),
+            ],
+            table.subscribers(0x0010)
+        );
+        assert!(table.subscribers(0x0030).is_empty());
+    }
+
+    #[test]
+    fn test_try_from() {
+        let mut table = CccdTable::new();
+        table.set(
+            0x0000060504030201,
+            0x0010,
+            &ClientCharacteristicConfiguration::new(NOTIFICATION),
+        );
+        table.set(
+            2,
+            0x0020,
+            &ClientCharacteristicConfiguration::new(INDICATION),
+        );
+        let data: Vec<u8> = table.clone().into();
+        assert_eq!(24, data.len());
+        assert_eq!(Ok(table), CccdTable::try_from(&data));
+
+        assert_eq!(Ok(CccdTable::new()), CccdTable::try_from(&Vec::new()));
+        assert_eq!(
+            Err(DataTypeParseError::InvalidLength {
+                expected: 24,
+                actual: 13
+            }),
+            CccdTable::try_from(&vec![0u8; 13])
+        );
+
+        // zero configuration is not restored
+        let result = CccdTable::try_from(&vec![0u8; 12]).unwrap();
+        assert!(result.is_empty());
+    }
+
+    #[test]
+    fn test_into() {
+        let mut table = CccdTable::new();
+        table.set(
+            1,
+            0x0010,
+            &ClientCharacteristicConfiguration::new(INDICATION),
+        );
+        let data: Vec<u8> = table.into();
+        assert_eq!(
+            vec![0x01, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x10, 0x00, 0x02, 0x00],
+            data
+        );
+    }
+}
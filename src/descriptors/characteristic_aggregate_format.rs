@@ -1,9 +1,10 @@
 //! Characteristic Aggregate Format (Attribute Type: 0x2905) module.
 
-use crate::Uuid16bit;
+use crate::{data_types::parse_error::DataTypeParseError, uuids::descriptor, Uuid16bit};
 
 /// Characteristic Aggregate Format.
 #[derive(Debug, PartialEq, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct CharacteristicAggregateFormat {
     /// List of Attribute Handles
     pub list_of_attribute_handles: Vec<u16>,
@@ -30,8 +31,35 @@ impl CharacteristicAggregateFormat {
     }
 }
 
+impl TryFrom<&[u8]> for CharacteristicAggregateFormat {
+    type Error = DataTypeParseError;
+    /// Create [`CharacteristicAggregateFormat`] from `&[u8]`.
+    fn try_from(value: &[u8]) -> Result<Self, DataTypeParseError> {
+        let len = value.len();
+        if len < 2 {
+            return Err(DataTypeParseError::InvalidLength {
+                expected: 2,
+                actual: len,
+            });
+        }
+        if len % 2 == 1 {
+            return Err(DataTypeParseError::InvalidLength {
+                expected: len + 1,
+                actual: len,
+            });
+        }
+        Ok(Self {
+            list_of_attribute_handles: value
+                .windows(2)
+                .step_by(2)
+                .map(|w| u16::from_le_bytes(w[0..2].try_into().unwrap()))
+                .collect(),
+        })
+    }
+}
+
 impl TryFrom<&Vec<u8>> for CharacteristicAggregateFormat {
-    type Error = String;
+    type Error = DataTypeParseError;
     /// Create [`CharacteristicAggregateFormat`] from [`Vec<u8>`].
     ///
     /// # Examples
@@ -47,7 +75,7 @@ impl TryFrom<&Vec<u8>> for CharacteristicAggregateFormat {
     ///     .iter()
     ///     .flat_map(|f| f.to_le_bytes())
     ///     .collect();
-    /// 
+    ///
     /// let result = CharacteristicAggregateFormat::try_from(&data);
     /// assert!(result.is_ok());
     /// let descriptor = result.unwrap();
@@ -55,28 +83,15 @@ impl TryFrom<&Vec<u8>> for CharacteristicAggregateFormat {
     ///     list_of_attribute_handles,
     ///     descriptor.list_of_attribute_handles
     /// );
-    /// 
+    ///
     /// let result = CharacteristicAggregateFormat::try_from(&Vec::new());
     /// assert!(!result.is_ok());
-    /// 
+    ///
     /// let result = CharacteristicAggregateFormat::try_from(&vec![0, 1, 2]);
     /// assert!(!result.is_ok());
     /// ```
-    fn try_from(value: &Vec<u8>) -> Result<Self, String> {
-        let len = value.len();
-        if len < 2 {
-            return Err(format!("Invalid data size :{}", len).to_string());
-        }
-        if len % 2 == 1 {
-            return Err(format!("Invalid data size :{}", len).to_string());
-        }
-        Ok(Self {
-            list_of_attribute_handles: value
-                .windows(2)
-                .step_by(2)
-                .map(|w| u16::from_le_bytes(w[0..2].try_into().unwrap()))
-                .collect(),
-        })
+    fn try_from(value: &Vec<u8>) -> Result<Self, DataTypeParseError> {
+        Self::try_from(value.as_slice())
     }
 }
 
@@ -92,7 +107,7 @@ impl Into<Vec<u8>> for CharacteristicAggregateFormat {
     ///
     /// let list_of_attribute_handles: Vec<u16> = [0x0201, 0x0403].to_vec();
     /// let result = CharacteristicAggregateFormat::new(&list_of_attribute_handles.clone());
-    /// 
+    ///
     /// let data: Vec<u8> = list_of_attribute_handles
     ///     .clone()
     ///     .iter()
@@ -124,7 +139,7 @@ impl Uuid16bit for CharacteristicAggregateFormat {
     /// assert_eq!(0x2905, CharacteristicAggregateFormat::uuid_16bit());
     /// ```
     fn uuid_16bit() -> u16 {
-        0x2905
+        descriptor::CHARACTERISTIC_AGGREGATE_FORMAT
     }
 }
 
@@ -1,9 +1,10 @@
 //! Characteristic Extended Properties (Attribute Type: 0x2900) module.
 
-use crate::Uuid16bit;
+use crate::{data_types::parse_error::DataTypeParseError, uuids::descriptor, Uuid16bit};
 
 /// Characteristic Extended Properties.
 #[derive(Debug, PartialEq, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct CharacteristicExtendedProperties {
     /// Characteristic Extended Properties Bit Field
     pub properties: u16,
@@ -67,8 +68,25 @@ pub const RELIABLE_WRITE: u16 = 0b00000001;
 /// Writable Auxiliaries
 pub const WRITABLE_AUXILIARIES: u16 = 0b00000010;
 
+impl TryFrom<&[u8]> for CharacteristicExtendedProperties {
+    type Error = DataTypeParseError;
+    /// Create [`CharacteristicExtendedProperties`] from `&[u8]`.
+    fn try_from(value: &[u8]) -> Result<Self, DataTypeParseError> {
+        let len = value.len();
+        if len != 2 {
+            return Err(DataTypeParseError::InvalidLength {
+                expected: 2,
+                actual: len,
+            });
+        }
+        Ok(Self {
+            properties: u16::from_le_bytes(value[..2].try_into().unwrap()),
+        })
+    }
+}
+
 impl TryFrom<&Vec<u8>> for CharacteristicExtendedProperties {
-    type Error = String;
+    type Error = DataTypeParseError;
     /// Create [`CharacteristicExtendedProperties`] from [`Vec<u8>`].
     ///
     /// # Examples
@@ -92,14 +110,8 @@ impl TryFrom<&Vec<u8>> for CharacteristicExtendedProperties {
     /// let result = ClientCharacteristicConfiguration::try_from(&configuration);
     /// assert!(!result.is_ok());
     /// ```
-    fn try_from(value: &Vec<u8>) -> Result<Self, String> {
-        let len = value.len();
-        if len != 2 {
-            return Err(format!("Invalid data size :{}", len).to_string());
-        }
-        Ok(Self {
-            properties: u16::from_le_bytes(value[..2].try_into().unwrap()),
-        })
+    fn try_from(value: &Vec<u8>) -> Result<Self, DataTypeParseError> {
+        Self::try_from(value.as_slice())
     }
 }
 
@@ -140,15 +152,18 @@ impl Uuid16bit for CharacteristicExtendedProperties {
     /// assert_eq!(0x2900, CharacteristicExtendedProperties::uuid_16bit());
     /// ```
     fn uuid_16bit() -> u16 {
-        0x2900
+        descriptor::CHARACTERISTIC_EXTENDED_PROPERTIES
     }
 }
 
 #[cfg(test)]
 mod tests {
-    use crate::{descriptors::characteristic_extended_properties::{
-        CharacteristicExtendedProperties, RELIABLE_WRITE, WRITABLE_AUXILIARIES,
-    }, Uuid16bit};
+    use crate::{
+        descriptors::characteristic_extended_properties::{
+            CharacteristicExtendedProperties, RELIABLE_WRITE, WRITABLE_AUXILIARIES,
+        },
+        Uuid16bit,
+    };
 
     #[test]
     fn test_new() {
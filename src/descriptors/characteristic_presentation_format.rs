@@ -1,9 +1,13 @@
 //! Characteristic Presentation Format (Attribute Type: 0x2904) module.
 
-use crate::Uuid16bit;
+use crate::{
+    data_types::parse_error::DataTypeParseError, descriptors::presentation_format_description,
+    uuids::descriptor, Uuid16bit,
+};
 
 /// Characteristic Presentation Format.
 #[derive(Debug, PartialEq, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct CharacteristicPresentationFormat {
     /// Format
     pub format: u8,
@@ -50,10 +54,67 @@ impl CharacteristicPresentationFormat {
             description,
         }
     }
+
+    /// Get Description name(`None` if Name Space is not Bluetooth SIG or Description is unknown).
+    ///
+    /// Distinguishes multiple instances of the same characteristic (e.g. batteries, sensors).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ble_data_struct::descriptors::{
+    ///     characteristic_presentation_format::CharacteristicPresentationFormat,
+    ///     presentation_format_description::{
+    ///         DESCRIPTION_FIRST, DESCRIPTION_LEFT, DESCRIPTION_RIGHT, DESCRIPTION_SECOND,
+    ///         NAME_SPACE_BLUETOOTH_SIG,
+    ///     },
+    /// };
+    ///
+    /// // Battery Level x 2 (percentage)
+    /// let batteries: Vec<CharacteristicPresentationFormat> = [DESCRIPTION_FIRST, DESCRIPTION_SECOND]
+    ///     .iter()
+    ///     .map(|f| CharacteristicPresentationFormat::new(0x04, 0, 0x27ad, NAME_SPACE_BLUETOOTH_SIG, *f))
+    ///     .collect();
+    /// assert_eq!(Some("first"), batteries[0].description_name());
+    /// assert_eq!(Some("second"), batteries[1].description_name());
+    ///
+    /// // Temperature x 2 (degree Celsius)
+    /// let left = CharacteristicPresentationFormat::new(0x0e, -2, 0x272f, NAME_SPACE_BLUETOOTH_SIG, DESCRIPTION_LEFT);
+    /// let right = CharacteristicPresentationFormat::new(0x0e, -2, 0x272f, NAME_SPACE_BLUETOOTH_SIG, DESCRIPTION_RIGHT);
+    /// assert_eq!(Some("left"), left.description_name());
+    /// assert_eq!(Some("right"), right.description_name());
+    ///
+    /// let result = CharacteristicPresentationFormat::new(0x04, 0, 0x27ad, 0x00, DESCRIPTION_FIRST);
+    /// assert_eq!(None, result.description_name());
+    /// ```
+    pub fn description_name(&self) -> Option<&'static str> {
+        presentation_format_description::description_name(self.name_space, self.description)
+    }
+}
+
+impl TryFrom<&[u8]> for CharacteristicPresentationFormat {
+    type Error = DataTypeParseError;
+    /// Create [`CharacteristicPresentationFormat`] from `&[u8]`.
+    fn try_from(value: &[u8]) -> Result<Self, DataTypeParseError> {
+        let len = value.len();
+        if len != 7 {
+            return Err(DataTypeParseError::InvalidLength {
+                expected: 7,
+                actual: len,
+            });
+        }
+        Ok(Self {
+            format: value[0],
+            exponent: value[1] as i8,
+            unit: u16::from_le_bytes(value[2..4].try_into().unwrap()),
+            name_space: value[4],
+            description: u16::from_le_bytes(value[5..7].try_into().unwrap()),
+        })
+    }
 }
 
 impl TryFrom<&Vec<u8>> for CharacteristicPresentationFormat {
-    type Error = String;
+    type Error = DataTypeParseError;
     /// Create [`CharacteristicPresentationFormat`] from [`Vec<u8>`].
     ///
     /// # Examples
@@ -88,18 +149,8 @@ impl TryFrom<&Vec<u8>> for CharacteristicPresentationFormat {
     /// let result = CharacteristicPresentationFormat::try_from(&data);
     /// assert!(!result.is_ok());
     /// ```
-    fn try_from(value: &Vec<u8>) -> Result<Self, String> {
-        let len = value.len();
-        if len != 7 {
-            return Err(format!("Invalid data size :{}", len).to_string());
-        }
-        Ok(Self {
-            format: value[0],
-            exponent: value[1] as i8,
-            unit: u16::from_le_bytes(value[2..4].try_into().unwrap()),
-            name_space: value[4],
-            description: u16::from_le_bytes(value[5..7].try_into().unwrap()),
-        })
+    fn try_from(value: &Vec<u8>) -> Result<Self, DataTypeParseError> {
+        Self::try_from(value.as_slice())
     }
 }
 
@@ -153,14 +204,19 @@ impl Uuid16bit for CharacteristicPresentationFormat {
     /// assert_eq!(0x2904, CharacteristicPresentationFormat::uuid_16bit());
     /// ```
     fn uuid_16bit() -> u16 {
-        0x2904
+        descriptor::CHARACTERISTIC_PRESENTATION_FORMAT
     }
 }
 
 #[cfg(test)]
 mod tests {
     use crate::{
-        descriptors::characteristic_presentation_format::CharacteristicPresentationFormat,
+        descriptors::{
+            characteristic_presentation_format::CharacteristicPresentationFormat,
+            presentation_format_description::{
+                DESCRIPTION_FIRST, DESCRIPTION_INSIDE, DESCRIPTION_SECOND, NAME_SPACE_BLUETOOTH_SIG,
+            },
+        },
         Uuid16bit,
     };
 
@@ -181,6 +237,40 @@ mod tests {
         assert_eq!(description, result.description);
     }
 
+    #[test]
+    fn test_description_name() {
+        let result = CharacteristicPresentationFormat::new(
+            0x04,
+            0,
+            0x27ad,
+            NAME_SPACE_BLUETOOTH_SIG,
+            DESCRIPTION_SECOND,
+        );
+        assert_eq!(Some("second"), result.description_name());
+
+        let result = CharacteristicPresentationFormat::new(
+            0x0e,
+            -2,
+            0x272f,
+            NAME_SPACE_BLUETOOTH_SIG,
+            DESCRIPTION_INSIDE,
+        );
+        assert_eq!(Some("inside"), result.description_name());
+
+        let result = CharacteristicPresentationFormat::new(
+            0x04,
+            0,
+            0x27ad,
+            NAME_SPACE_BLUETOOTH_SIG,
+            0x0200,
+        );
+        assert_eq!(None, result.description_name());
+
+        let result =
+            CharacteristicPresentationFormat::new(0x04, 0, 0x27ad, 0x00, DESCRIPTION_FIRST);
+        assert_eq!(None, result.description_name());
+    }
+
     #[test]
     fn test_try_from() {
         let format = 0x01u8;
@@ -1,9 +1,14 @@
 //! Characteristic User Description (Attribute Type: 0x2901) module.
 
-use crate::Uuid16bit;
+use crate::{
+    data_types::parse_error::DataTypeParseError,
+    descriptors::characteristic_extended_properties::CharacteristicExtendedProperties,
+    uuids::descriptor, Uuid16bit,
+};
 
 /// Characteristic User Description.
 #[derive(Debug, PartialEq, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct CharacteristicUserDescription {
     /// Characteristic User Description
     pub description: String,
@@ -28,14 +33,29 @@ impl CharacteristicUserDescription {
     }
 }
 
+impl TryFrom<&[u8]> for CharacteristicUserDescription {
+    type Error = DataTypeParseError;
+    /// Create [`CharacteristicUserDescription`] from `&[u8]`.
+    fn try_from(value: &[u8]) -> Result<Self, DataTypeParseError> {
+        Ok(Self {
+            description: String::from_utf8(value.to_vec()).map_err(|e| {
+                DataTypeParseError::InvalidUtf8 {
+                    valid_up_to: e.utf8_error().valid_up_to(),
+                }
+            })?,
+        })
+    }
+}
+
 impl TryFrom<&Vec<u8>> for CharacteristicUserDescription {
-    type Error = String;
+    type Error = DataTypeParseError;
     /// Create [`CharacteristicUserDescription`] from [`Vec<u8>`].
     ///
     /// # Examples
     ///
     /// ```
     /// use ble_data_struct::{
+    ///     data_types::parse_error::DataTypeParseError,
     ///     descriptors::characteristic_user_description::CharacteristicUserDescription, Uuid16bit,
     /// };
     ///
@@ -43,11 +63,12 @@ impl TryFrom<&Vec<u8>> for CharacteristicUserDescription {
     /// let result = CharacteristicUserDescription::try_from(&description.to_string().into_bytes());
     /// assert!(result.is_ok());
     /// assert_eq!(description, result.unwrap().description);
+    ///
+    /// let result = CharacteristicUserDescription::try_from(&vec![0x61, 0xff]);
+    /// assert_eq!(Err(DataTypeParseError::InvalidUtf8 { valid_up_to: 1 }), result);
     /// ```
-    fn try_from(value: &Vec<u8>) -> Result<Self, String> {
-        Ok(Self {
-            description: String::from_utf8(value.to_vec()).unwrap(),
-        })
+    fn try_from(value: &Vec<u8>) -> Result<Self, DataTypeParseError> {
+        Self::try_from(value.as_slice())
     }
 }
 
@@ -71,6 +92,145 @@ impl Into<Vec<u8>> for CharacteristicUserDescription {
     }
 }
 
+/// Over-length write handling of [`CharacteristicUserDescriptionWritePolicy`].
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum CharacteristicUserDescriptionTruncation {
+    /// Reject the write
+    Reject,
+
+    /// Truncate at the last UTF-8 character boundary within the maximum length
+    Truncate,
+}
+
+/// Write acceptance policy of [`CharacteristicUserDescription`] for GATT servers.
+#[derive(Debug, PartialEq, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct CharacteristicUserDescriptionWritePolicy {
+    /// Writable (`Writable Auxiliaries` bit of [`CharacteristicExtendedProperties`])
+    pub writable: bool,
+
+    /// Maximum description length in bytes
+    pub max_length: usize,
+
+    /// Over-length write handling
+    pub truncation: CharacteristicUserDescriptionTruncation,
+}
+
+impl CharacteristicUserDescriptionWritePolicy {
+    /// Create [`CharacteristicUserDescriptionWritePolicy`] from Parameters.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ble_data_struct::descriptors::characteristic_user_description::{
+    ///     CharacteristicUserDescriptionTruncation, CharacteristicUserDescriptionWritePolicy,
+    /// };
+    ///
+    /// let result = CharacteristicUserDescriptionWritePolicy::new(
+    ///     true,
+    ///     20,
+    ///     CharacteristicUserDescriptionTruncation::Reject,
+    /// );
+    /// assert!(result.writable);
+    /// assert_eq!(20, result.max_length);
+    /// assert_eq!(CharacteristicUserDescriptionTruncation::Reject, result.truncation);
+    /// ```
+    pub fn new(
+        writable: bool,
+        max_length: usize,
+        truncation: CharacteristicUserDescriptionTruncation,
+    ) -> Self {
+        Self {
+            writable,
+            max_length,
+            truncation,
+        }
+    }
+
+    /// Create [`CharacteristicUserDescriptionWritePolicy`] from [`CharacteristicExtendedProperties`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ble_data_struct::descriptors::{
+    ///     characteristic_extended_properties::{
+    ///         CharacteristicExtendedProperties, WRITABLE_AUXILIARIES,
+    ///     },
+    ///     characteristic_user_description::{
+    ///         CharacteristicUserDescriptionTruncation, CharacteristicUserDescriptionWritePolicy,
+    ///     },
+    /// };
+    ///
+    /// let properties = CharacteristicExtendedProperties::new(WRITABLE_AUXILIARIES);
+    /// let result = CharacteristicUserDescriptionWritePolicy::from_extended_properties(
+    ///     &properties,
+    ///     20,
+    ///     CharacteristicUserDescriptionTruncation::Truncate,
+    /// );
+    /// assert!(result.writable);
+    /// ```
+    pub fn from_extended_properties(
+        properties: &CharacteristicExtendedProperties,
+        max_length: usize,
+        truncation: CharacteristicUserDescriptionTruncation,
+    ) -> Self {
+        Self::new(properties.is_writable_auxiliaries(), max_length, truncation)
+    }
+
+    /// Accept a client write to [`CharacteristicUserDescription`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ble_data_struct::descriptors::characteristic_user_description::{
+    ///     CharacteristicUserDescriptionTruncation, CharacteristicUserDescriptionWritePolicy,
+    /// };
+    ///
+    /// let policy = CharacteristicUserDescriptionWritePolicy::new(
+    ///     true,
+    ///     4,
+    ///     CharacteristicUserDescriptionTruncation::Truncate,
+    /// );
+    /// let result = policy.accept("abc\u{e9}".as_bytes());
+    /// assert_eq!("abc", result.unwrap().description);
+    ///
+    /// let policy = CharacteristicUserDescriptionWritePolicy::new(
+    ///     true,
+    ///     4,
+    ///     CharacteristicUserDescriptionTruncation::Reject,
+    /// );
+    /// assert_eq!(
+    ///     Err("Invalid data size :5".to_string()),
+    ///     policy.accept("abc\u{e9}".as_bytes())
+    /// );
+    /// ```
+    pub fn accept(&self, value: &[u8]) -> Result<CharacteristicUserDescription, String> {
+        if !self.writable {
+            return Err("Not writable".to_string());
+        }
+        let description = std::str::from_utf8(value)
+            .map_err(|e| format!("Invalid UTF-8 :{}", e.valid_up_to()))?;
+        if description.len() <= self.max_length {
+            return Ok(CharacteristicUserDescription::new(description.to_string()));
+        }
+        match self.truncation {
+            CharacteristicUserDescriptionTruncation::Reject => {
+                Err(format!("Invalid data size :{}", description.len()))
+            }
+            CharacteristicUserDescriptionTruncation::Truncate => {
+                let end = (0..=self.max_length)
+                    .rev()
+                    .find(|f| description.is_char_boundary(*f))
+                    .unwrap_or(0);
+                Ok(CharacteristicUserDescription::new(
+                    description[..end].to_string(),
+                ))
+            }
+        }
+    }
+}
+
 impl Uuid16bit for CharacteristicUserDescription {
     /// return `0x2901`.
     ///
@@ -84,14 +244,24 @@ impl Uuid16bit for CharacteristicUserDescription {
     /// assert_eq!(0x2901, CharacteristicUserDescription::uuid_16bit());
     /// ```
     fn uuid_16bit() -> u16 {
-        0x2901
+        descriptor::CHARACTERISTIC_USER_DESCRIPTION
     }
 }
 
 #[cfg(test)]
 mod tests {
     use crate::{
-        descriptors::characteristic_user_description::CharacteristicUserDescription, Uuid16bit,
+        data_types::parse_error::DataTypeParseError,
+        descriptors::{
+            characteristic_extended_properties::{
+                CharacteristicExtendedProperties, RELIABLE_WRITE, WRITABLE_AUXILIARIES,
+            },
+            characteristic_user_description::{
+                CharacteristicUserDescription, CharacteristicUserDescriptionTruncation,
+                CharacteristicUserDescriptionWritePolicy,
+            },
+        },
+        Uuid16bit,
     };
 
     #[test]
@@ -107,6 +277,84 @@ mod tests {
         let result = CharacteristicUserDescription::try_from(&description.to_string().into_bytes());
         assert!(result.is_ok());
         assert_eq!(description, result.unwrap().description);
+
+        let result = CharacteristicUserDescription::try_from(&vec![0x61, 0x62, 0xc3]);
+        assert_eq!(
+            Err(DataTypeParseError::InvalidUtf8 { valid_up_to: 2 }),
+            result
+        );
+    }
+
+    #[test]
+    fn test_write_policy_new() {
+        let result = CharacteristicUserDescriptionWritePolicy::new(
+            true,
+            20,
+            CharacteristicUserDescriptionTruncation::Reject,
+        );
+        assert!(result.writable);
+        assert_eq!(20, result.max_length);
+        assert_eq!(
+            CharacteristicUserDescriptionTruncation::Reject,
+            result.truncation
+        );
+    }
+
+    #[test]
+    fn test_write_policy_from_extended_properties() {
+        let result = CharacteristicUserDescriptionWritePolicy::from_extended_properties(
+            &CharacteristicExtendedProperties::new(WRITABLE_AUXILIARIES),
+            20,
+            CharacteristicUserDescriptionTruncation::Reject,
+        );
+        assert!(result.writable);
+
+        let result = CharacteristicUserDescriptionWritePolicy::from_extended_properties(
+            &CharacteristicExtendedProperties::new(RELIABLE_WRITE),
+            20,
+            CharacteristicUserDescriptionTruncation::Reject,
+        );
+        assert!(!result.writable);
+    }
+
+    #[test]
+    fn test_write_policy_accept() {
+        let policy = CharacteristicUserDescriptionWritePolicy::new(
+            false,
+            20,
+            CharacteristicUserDescriptionTruncation::Truncate,
+        );
+        assert_eq!(Err("Not writable".to_string()), policy.accept(b"abc"));
+
+        let policy = CharacteristicUserDescriptionWritePolicy::new(
+            true,
+            4,
+            CharacteristicUserDescriptionTruncation::Reject,
+        );
+        assert_eq!("abcd", policy.accept(b"abcd").unwrap().description);
+        assert_eq!(
+            Err("Invalid data size :5".to_string()),
+            policy.accept(b"abcde")
+        );
+        assert_eq!(
+            Err("Invalid UTF-8 :1".to_string()),
+            policy.accept(&[0x61, 0xff])
+        );
+
+        let policy = CharacteristicUserDescriptionWritePolicy::new(
+            true,
+            4,
+            CharacteristicUserDescriptionTruncation::Truncate,
+        );
+        assert_eq!("abcd", policy.accept(b"abcde").unwrap().description);
+        assert_eq!(
+            "abc",
+            policy.accept("abc\u{e9}".as_bytes()).unwrap().description
+        );
+        assert_eq!(
+            "a",
+            policy.accept("a\u{1f600}".as_bytes()).unwrap().description
+        );
     }
 
     #[test]
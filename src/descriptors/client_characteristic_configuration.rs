@@ -1,9 +1,10 @@
 //! Client Characteristic Configuration (Attribute Type: 0x2902) module.
 
-use crate::Uuid16bit;
+use crate::{data_types::parse_error::DataTypeParseError, uuids::descriptor, Uuid16bit};
 
 /// Client Characteristic Configuration.
 #[derive(Debug, PartialEq, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct ClientCharacteristicConfiguration {
     /// Characteristic Configuration Bits
     pub configuration: u16,
@@ -67,8 +68,25 @@ pub const NOTIFICATION: u16 = 0b00000001;
 /// Indication
 pub const INDICATION: u16 = 0b00000010;
 
+impl TryFrom<&[u8]> for ClientCharacteristicConfiguration {
+    type Error = DataTypeParseError;
+    /// Create [`ClientCharacteristicConfiguration`] from `&[u8]`.
+    fn try_from(value: &[u8]) -> Result<Self, DataTypeParseError> {
+        let len = value.len();
+        if len != 2 {
+            return Err(DataTypeParseError::InvalidLength {
+                expected: 2,
+                actual: len,
+            });
+        }
+        Ok(Self {
+            configuration: u16::from_le_bytes(value[..2].try_into().unwrap()),
+        })
+    }
+}
+
 impl TryFrom<&Vec<u8>> for ClientCharacteristicConfiguration {
-    type Error = String;
+    type Error = DataTypeParseError;
     /// Create [`ClientCharacteristicConfiguration`] from [`Vec<u8>`].
     ///
     /// # Examples
@@ -92,14 +110,8 @@ impl TryFrom<&Vec<u8>> for ClientCharacteristicConfiguration {
     /// let result = ClientCharacteristicConfiguration::try_from(&configuration);
     /// assert!(!result.is_ok());
     /// ```
-    fn try_from(value: &Vec<u8>) -> Result<Self, String> {
-        let len = value.len();
-        if len != 2 {
-            return Err(format!("Invalid data size :{}", len).to_string());
-        }
-        Ok(Self {
-            configuration: u16::from_le_bytes(value[..2].try_into().unwrap()),
-        })
+    fn try_from(value: &Vec<u8>) -> Result<Self, DataTypeParseError> {
+        Self::try_from(value.as_slice())
     }
 }
 
@@ -140,15 +152,18 @@ impl Uuid16bit for ClientCharacteristicConfiguration {
     /// assert_eq!(0x2902, ClientCharacteristicConfiguration::uuid_16bit());
     /// ```
     fn uuid_16bit() -> u16 {
-        0x2902
+        descriptor::CLIENT_CHARACTERISTIC_CONFIGURATION
     }
 }
 
 #[cfg(test)]
 mod tests {
-    use crate::{descriptors::client_characteristic_configuration::{
-        ClientCharacteristicConfiguration, INDICATION, NOTIFICATION,
-    }, Uuid16bit};
+    use crate::{
+        descriptors::client_characteristic_configuration::{
+            ClientCharacteristicConfiguration, INDICATION, NOTIFICATION,
+        },
+        Uuid16bit,
+    };
 
     #[test]
     fn test_new() {
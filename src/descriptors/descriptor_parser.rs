@@ -0,0 +1,270 @@
+//! Descriptor parser module.
+
+use uuid::Uuid;
+
+use crate::{
+    data_types::parse_error::DataTypeParseError,
+    descriptors::{
+        characteristic_aggregate_format::CharacteristicAggregateFormat,
+        characteristic_extended_properties::CharacteristicExtendedProperties,
+        characteristic_presentation_format::CharacteristicPresentationFormat,
+        characteristic_user_description::CharacteristicUserDescription,
+        client_characteristic_configuration::ClientCharacteristicConfiguration,
+        server_characteristic_configuration::ServerCharacteristicConfiguration,
+    },
+    uuid_from_u16, Uuid16bit,
+};
+
+/// Descriptor parse result.
+///
+/// Parsers for new descriptors are added as new variants in minor releases, so matches need a wildcard arm.
+#[derive(Debug, PartialEq, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[non_exhaustive]
+pub enum DescriptorParseResult {
+    /// [`CharacteristicAggregateFormat`]'s parse result.
+    CharacteristicAggregateFormatResult(Result<CharacteristicAggregateFormat, DataTypeParseError>),
+
+    /// [`CharacteristicExtendedProperties`]'s parse result.
+    CharacteristicExtendedPropertiesResult(
+        Result<CharacteristicExtendedProperties, DataTypeParseError>,
+    ),
+
+    /// [`CharacteristicPresentationFormat`]'s parse result.
+    CharacteristicPresentationFormatResult(
+        Result<CharacteristicPresentationFormat, DataTypeParseError>,
+    ),
+
+    /// [`CharacteristicUserDescription`]'s parse result.
+    CharacteristicUserDescriptionResult(Result<CharacteristicUserDescription, DataTypeParseError>),
+
+    /// [`ClientCharacteristicConfiguration`]'s parse result.
+    ClientCharacteristicConfigurationResult(
+        Result<ClientCharacteristicConfiguration, DataTypeParseError>,
+    ),
+
+    /// [`ServerCharacteristicConfiguration`]'s parse result.
+    ServerCharacteristicConfigurationResult(
+        Result<ServerCharacteristicConfiguration, DataTypeParseError>,
+    ),
+
+    /// Unknown descriptor.
+    DescriptorParseError(DataTypeParseError),
+}
+
+impl DescriptorParseResult {
+    /// Create [`DescriptorParseResult`] from descriptor UUID and descriptor value.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ble_data_struct::{
+    ///     descriptors::{
+    ///         client_characteristic_configuration::{ClientCharacteristicConfiguration, NOTIFICATION},
+    ///         descriptor_parser::DescriptorParseResult,
+    ///     },
+    ///     uuid_from_u16, Uuid16bit,
+    /// };
+    ///
+    /// let uuid = uuid_from_u16(ClientCharacteristicConfiguration::uuid_16bit());
+    /// let result = DescriptorParseResult::from(&uuid, &NOTIFICATION.to_le_bytes());
+    /// assert_eq!(
+    ///     DescriptorParseResult::ClientCharacteristicConfigurationResult(Ok(
+    ///         ClientCharacteristicConfiguration::new(NOTIFICATION)
+    ///     )),
+    ///     result
+    /// );
+    ///
+    /// let result = DescriptorParseResult::from(&uuid_from_u16(0x2a00), &[]);
+    /// assert!(matches!(result, DescriptorParseResult::DescriptorParseError(_)));
+    /// ```
+    pub fn from(uuid: &Uuid, value: &[u8]) -> Self {
+        let is = |uuid_16bit: u16| *uuid == uuid_from_u16(uuid_16bit);
+        if is(CharacteristicAggregateFormat::uuid_16bit()) {
+            Self::CharacteristicAggregateFormatResult(CharacteristicAggregateFormat::try_from(
+                value,
+            ))
+        } else if is(CharacteristicExtendedProperties::uuid_16bit()) {
+            Self::CharacteristicExtendedPropertiesResult(
+                CharacteristicExtendedProperties::try_from(value),
+            )
+        } else if is(CharacteristicPresentationFormat::uuid_16bit()) {
+            Self::CharacteristicPresentationFormatResult(
+                CharacteristicPresentationFormat::try_from(value),
+            )
+        } else if is(CharacteristicUserDescription::uuid_16bit()) {
+            Self::CharacteristicUserDescriptionResult(CharacteristicUserDescription::try_from(
+                value,
+            ))
+        } else if is(ClientCharacteristicConfiguration::uuid_16bit()) {
+            Self::ClientCharacteristicConfigurationResult(
+                ClientCharacteristicConfiguration::try_from(value),
+            )
+        } else if is(ServerCharacteristicConfiguration::uuid_16bit()) {
+            Self::ServerCharacteristicConfigurationResult(
+                ServerCharacteristicConfiguration::try_from(value),
+            )
+        } else {
+            Self::DescriptorParseError(DataTypeParseError::UnknownUuid(*uuid))
+        }
+    }
+
+    /// Returns the parse error if the result is an error.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ble_data_struct::{
+    ///     data_types::parse_error::DataTypeParseError,
+    ///     descriptors::{
+    ///         client_characteristic_configuration::ClientCharacteristicConfiguration,
+    ///         descriptor_parser::DescriptorParseResult,
+    ///     },
+    ///     uuid_from_u16, Uuid16bit,
+    /// };
+    ///
+    /// let uuid = uuid_from_u16(ClientCharacteristicConfiguration::uuid_16bit());
+    /// assert!(DescriptorParseResult::from(&uuid, &[0x01, 0x00]).parse_error().is_none());
+    /// assert_eq!(
+    ///     Some(&DataTypeParseError::InvalidLength {
+    ///         expected: 2,
+    ///         actual: 0
+    ///     }),
+    ///     DescriptorParseResult::from(&uuid, &[]).parse_error()
+    /// );
+    /// ```
+    pub fn parse_error(&self) -> Option<&DataTypeParseError> {
+        match self {
+            DescriptorParseResult::CharacteristicAggregateFormatResult(Err(error))
+            | DescriptorParseResult::CharacteristicExtendedPropertiesResult(Err(error))
+            | DescriptorParseResult::CharacteristicPresentationFormatResult(Err(error))
+            | DescriptorParseResult::CharacteristicUserDescriptionResult(Err(error))
+            | DescriptorParseResult::ClientCharacteristicConfigurationResult(Err(error))
+            | DescriptorParseResult::ServerCharacteristicConfigurationResult(Err(error))
+            | DescriptorParseResult::DescriptorParseError(error) => Some(error),
+            _ => None,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        data_types::parse_error::DataTypeParseError,
+        descriptors::{
+            characteristic_aggregate_format::CharacteristicAggregateFormat,
+            characteristic_extended_properties::{
+                CharacteristicExtendedProperties, RELIABLE_WRITE,
+            },
+            characteristic_presentation_format::CharacteristicPresentationFormat,
+            characteristic_user_description::CharacteristicUserDescription,
+            client_characteristic_configuration::{ClientCharacteristicConfiguration, INDICATION},
+            descriptor_parser::DescriptorParseResult,
+            server_characteristic_configuration::{ServerCharacteristicConfiguration, BROADCAST},
+        },
+        uuid_from_u16, Uuid16bit,
+    };
+
+    #[test]
+    fn test_from() {
+        let data: Vec<u8> = CharacteristicAggregateFormat::new(&vec![0x0001, 0x0002]).into();
+        assert_eq!(
+            DescriptorParseResult::CharacteristicAggregateFormatResult(Ok(
+                CharacteristicAggregateFormat::new(&vec![0x0001, 0x0002])
+            )),
+            DescriptorParseResult::from(
+                &uuid_from_u16(CharacteristicAggregateFormat::uuid_16bit()),
+                &data
+            )
+        );
+
+        assert_eq!(
+            DescriptorParseResult::CharacteristicExtendedPropertiesResult(Ok(
+                CharacteristicExtendedProperties::new(RELIABLE_WRITE)
+            )),
+            DescriptorParseResult::from(
+                &uuid_from_u16(CharacteristicExtendedProperties::uuid_16bit()),
+                &RELIABLE_WRITE.to_le_bytes()
+            )
+        );
+
+        let format = CharacteristicPresentationFormat::new(0x04, -1, 0x2700, 0x01, 0x0000);
+        let data: Vec<u8> = format.clone().into();
+        assert_eq!(
+            DescriptorParseResult::CharacteristicPresentationFormatResult(Ok(format)),
+            DescriptorParseResult::from(
+                &uuid_from_u16(CharacteristicPresentationFormat::uuid_16bit()),
+                &data
+            )
+        );
+
+        assert_eq!(
+            DescriptorParseResult::CharacteristicUserDescriptionResult(Ok(
+                CharacteristicUserDescription::new("description".to_string())
+            )),
+            DescriptorParseResult::from(
+                &uuid_from_u16(CharacteristicUserDescription::uuid_16bit()),
+                "description".as_bytes()
+            )
+        );
+
+        assert_eq!(
+            DescriptorParseResult::ClientCharacteristicConfigurationResult(Ok(
+                ClientCharacteristicConfiguration::new(INDICATION)
+            )),
+            DescriptorParseResult::from(
+                &uuid_from_u16(ClientCharacteristicConfiguration::uuid_16bit()),
+                &INDICATION.to_le_bytes()
+            )
+        );
+
+        assert_eq!(
+            DescriptorParseResult::ServerCharacteristicConfigurationResult(Ok(
+                ServerCharacteristicConfiguration::new(BROADCAST)
+            )),
+            DescriptorParseResult::from(
+                &uuid_from_u16(ServerCharacteristicConfiguration::uuid_16bit()),
+                &BROADCAST.to_le_bytes()
+            )
+        );
+
+        let uuid = uuid_from_u16(0x2a00);
+        assert_eq!(
+            DescriptorParseResult::DescriptorParseError(DataTypeParseError::UnknownUuid(uuid)),
+            DescriptorParseResult::from(&uuid, &[0x00])
+        );
+    }
+
+    #[test]
+    fn test_parse_error() {
+        let uuid = uuid_from_u16(ServerCharacteristicConfiguration::uuid_16bit());
+        assert!(DescriptorParseResult::from(&uuid, &[0x01, 0x00])
+            .parse_error()
+            .is_none());
+        assert!(DescriptorParseResult::from(&uuid, &[])
+            .parse_error()
+            .is_some());
+        assert!(DescriptorParseResult::from(&uuid_from_u16(0x2a00), &[])
+            .parse_error()
+            .is_some());
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_serde() {
+        let uuid = uuid_from_u16(ClientCharacteristicConfiguration::uuid_16bit());
+        let result = DescriptorParseResult::from(&uuid, &INDICATION.to_le_bytes());
+        let json = serde_json::to_string(&result).unwrap();
+        assert_eq!(
+            result,
+            serde_json::from_str::<DescriptorParseResult>(&json).unwrap()
+        );
+
+        let result = DescriptorParseResult::from(&uuid_from_u16(0x2a00), &[0x00]);
+        let json = serde_json::to_string(&result).unwrap();
+        assert_eq!(
+            result,
+            serde_json::from_str::<DescriptorParseResult>(&json).unwrap()
+        );
+    }
+}
@@ -0,0 +1,449 @@
+//! Characteristic Presentation Format Description module.
+//!
+//! Description values of the Bluetooth SIG Name Space (Assigned Numbers).
+
+/// Name Space: Bluetooth SIG
+pub const NAME_SPACE_BLUETOOTH_SIG: u8 = 0x01;
+
+/// Description: unknown
+pub const DESCRIPTION_UNKNOWN: u16 = 0x0000;
+
+/// Description: first
+pub const DESCRIPTION_FIRST: u16 = 0x0001;
+
+/// Description: second
+pub const DESCRIPTION_SECOND: u16 = 0x0002;
+
+/// Description: front
+pub const DESCRIPTION_FRONT: u16 = 0x0100;
+
+/// Description: back
+pub const DESCRIPTION_BACK: u16 = 0x0101;
+
+/// Description: top
+pub const DESCRIPTION_TOP: u16 = 0x0102;
+
+/// Description: bottom
+pub const DESCRIPTION_BOTTOM: u16 = 0x0103;
+
+/// Description: upper
+pub const DESCRIPTION_UPPER: u16 = 0x0104;
+
+/// Description: lower
+pub const DESCRIPTION_LOWER: u16 = 0x0105;
+
+/// Description: main
+pub const DESCRIPTION_MAIN: u16 = 0x0106;
+
+/// Description: backup
+pub const DESCRIPTION_BACKUP: u16 = 0x0107;
+
+/// Description: auxiliary
+pub const DESCRIPTION_AUXILIARY: u16 = 0x0108;
+
+/// Description: supplementary
+pub const DESCRIPTION_SUPPLEMENTARY: u16 = 0x0109;
+
+/// Description: flash
+pub const DESCRIPTION_FLASH: u16 = 0x010a;
+
+/// Description: inside
+pub const DESCRIPTION_INSIDE: u16 = 0x010b;
+
+/// Description: outside
+pub const DESCRIPTION_OUTSIDE: u16 = 0x010c;
+
+/// Description: left
+pub const DESCRIPTION_LEFT: u16 = 0x010d;
+
+/// Description: right
+pub const DESCRIPTION_RIGHT: u16 = 0x010e;
+
+/// Description: internal
+pub const DESCRIPTION_INTERNAL: u16 = 0x010f;
+
+/// Description: external
+pub const DESCRIPTION_EXTERNAL: u16 = 0x0110;
+
+/// Ordinal description names (`0x0001` - `0x00ff`).
+const ORDINAL_NAMES: [&str; 255] = [
+    "first",
+    "second",
+    "third",
+    "fourth",
+    "fifth",
+    "sixth",
+    "seventh",
+    "eighth",
+    "ninth",
+    "tenth",
+    "eleventh",
+    "twelfth",
+    "thirteenth",
+    "fourteenth",
+    "fifteenth",
+    "sixteenth",
+    "seventeenth",
+    "eighteenth",
+    "nineteenth",
+    "twentieth",
+    "twenty-first",
+    "twenty-second",
+    "twenty-third",
+    "twenty-fourth",
+    "twenty-fifth",
+    "twenty-sixth",
+    "twenty-seventh",
+    "twenty-eighth",
+    "twenty-ninth",
+    "thirtieth",
+    "thirty-first",
+    "thirty-second",
+    "thirty-third",
+    "thirty-fourth",
+    "thirty-fifth",
+    "thirty-sixth",
+    "thirty-seventh",
+    "thirty-eighth",
+    "thirty-ninth",
+    "fortieth",
+    "forty-first",
+    "forty-second",
+    "forty-third",
+    "forty-fourth",
+    "forty-fifth",
+    "forty-sixth",
+    "forty-seventh",
+    "forty-eighth",
+    "forty-ninth",
+    "fiftieth",
+    "fifty-first",
+    "fifty-second",
+    "fifty-third",
+    "fifty-fourth",
+    "fifty-fifth",
+    "fifty-sixth",
+    "fifty-seventh",
+    "fifty-eighth",
+    "fifty-ninth",
+    "sixtieth",
+    "sixty-first",
+    "sixty-second",
+    "sixty-third",
+    "sixty-fourth",
+    "sixty-fifth",
+    "sixty-sixth",
+    "sixty-seventh",
+    "sixty-eighth",
+    "sixty-ninth",
+    "seventieth",
+    "seventy-first",
+    "seventy-second",
+    "seventy-third",
+    "seventy-fourth",
+    "seventy-fifth",
+    "seventy-sixth",
+    "seventy-seventh",
+    "seventy-eighth",
+    "seventy-ninth",
+    "eightieth",
+    "eighty-first",
+    "eighty-second",
+    "eighty-third",
+    "eighty-fourth",
+    "eighty-fifth",
+    "eighty-sixth",
+    "eighty-seventh",
+    "eighty-eighth",
+    "eighty-ninth",
+    "ninetieth",
+    "ninety-first",
+    "ninety-second",
+    "ninety-third",
+    "ninety-fourth",
+    "ninety-fifth",
+    "ninety-sixth",
+    "ninety-seventh",
+    "ninety-eighth",
+    "ninety-ninth",
+    "one hundredth",
+    "one hundred and first",
+    "one hundred and second",
+    "one hundred and third",
+    "one hundred and fourth",
+    "one hundred and fifth",
+    "one hundred and sixth",
+    "one hundred and seventh",
+    "one hundred and eighth",
+    "one hundred and ninth",
+    "one hundred and tenth",
+    "one hundred and eleventh",
+    "one hundred and twelfth",
+    "one hundred and thirteenth",
+    "one hundred and fourteenth",
+    "one hundred and fifteenth",
+    "one hundred and sixteenth",
+    "one hundred and seventeenth",
+    "one hundred and eighteenth",
+    "one hundred and nineteenth",
+    "one hundred and twentieth",
+    "one hundred and twenty-first",
+    "one hundred and twenty-second",
+    "one hundred and twenty-third",
+    "one hundred and twenty-fourth",
+    "one hundred and twenty-fifth",
+    "one hundred and twenty-sixth",
+    "one hundred and twenty-seventh",
+    "one hundred and twenty-eighth",
+    "one hundred and twenty-ninth",
+    "one hundred and thirtieth",
+    "one hundred and thirty-first",
+    "one hundred and thirty-second",
+    "one hundred and thirty-third",
+    "one hundred and thirty-fourth",
+    "one hundred and thirty-fifth",
+    "one hundred and thirty-sixth",
+    "one hundred and thirty-seventh",
+    "one hundred and thirty-eighth",
+    "one hundred and thirty-ninth",
+    "one hundred and fortieth",
+    "one hundred and forty-first",
+    "one hundred and forty-second",
+    "one hundred and forty-third",
+    "one hundred and forty-fourth",
+    "one hundred and forty-fifth",
+    "one hundred and forty-sixth",
+    "one hundred and forty-seventh",
+    "one hundred and forty-eighth",
+    "one hundred and forty-ninth",
+    "one hundred and fiftieth",
+    "one hundred and fifty-first",
+    "one hundred and fifty-second",
+    "one hundred and fifty-third",
+    "one hundred and fifty-fourth",
+    "one hundred and fifty-fifth",
+    "one hundred and fifty-sixth",
+    "one hundred and fifty-seventh",
+    "one hundred and fifty-eighth",
+    "one hundred and fifty-ninth",
+    "one hundred and sixtieth",
+    "one hundred and sixty-first",
+    "one hundred and sixty-second",
+    "one hundred and sixty-third",
+    "one hundred and sixty-fourth",
+    "one hundred and sixty-fifth",
+    "one hundred and sixty-sixth",
+    "one hundred and sixty-seventh",
+    "one hundred and sixty-eighth",
+    "one hundred and sixty-ninth",
+    "one hundred and seventieth",
+    "one hundred and seventy-first",
+    "one hundred and seventy-second",
+    "one hundred and seventy-third",
+    "one hundred and seventy-fourth",
+    "one hundred and seventy-fifth",
+    "one hundred and seventy-sixth",
+    "one hundred and seventy-seventh",
+    "one hundred and seventy-eighth",
+    "one hundred and seventy-ninth",
+    "one hundred and eightieth",
+    "one hundred and eighty-first",
+    "one hundred and eighty-second",
+    "one hundred and eighty-third",
+    "one hundred and eighty-fourth",
+    "one hundred and eighty-fifth",
+    "one hundred and eighty-sixth",
+    "one hundred and eighty-seventh",
+    "one hundred and eighty-eighth",
+    "one hundred and eighty-ninth",
+    "one hundred and ninetieth",
+    "one hundred and ninety-first",
+    "one hundred and ninety-second",
+    "one hundred and ninety-third",
+    "one hundred and ninety-fourth",
+    "one hundred and ninety-fifth",
+    "one hundred and ninety-sixth",
+    "one hundred and ninety-seventh",
+    "one hundred and ninety-eighth",
+    "one hundred and ninety-ninth",
+    "two hundredth",
+    "two hundred and first",
+    "two hundred and second",
+    "two hundred and third",
+    "two hundred and fourth",
+    "two hundred and fifth",
+    "two hundred and sixth",
+    "two hundred and seventh",
+    "two hundred and eighth",
+    "two hundred and ninth",
+    "two hundred and tenth",
+    "two hundred and eleventh",
+    "two hundred and twelfth",
+    "two hundred and thirteenth",
+    "two hundred and fourteenth",
+    "two hundred and fifteenth",
+    "two hundred and sixteenth",
+    "two hundred and seventeenth",
+    "two hundred and eighteenth",
+    "two hundred and nineteenth",
+    "two hundred and twentieth",
+    "two hundred and twenty-first",
+    "two hundred and twenty-second",
+    "two hundred and twenty-third",
+    "two hundred and twenty-fourth",
+    "two hundred and twenty-fifth",
+    "two hundred and twenty-sixth",
+    "two hundred and twenty-seventh",
+    "two hundred and twenty-eighth",
+    "two hundred and twenty-ninth",
+    "two hundred and thirtieth",
+    "two hundred and thirty-first",
+    "two hundred and thirty-second",
+    "two hundred and thirty-third",
+    "two hundred and thirty-fourth",
+    "two hundred and thirty-fifth",
+    "two hundred and thirty-sixth",
+    "two hundred and thirty-seventh",
+    "two hundred and thirty-eighth",
+    "two hundred and thirty-ninth",
+    "two hundred and fortieth",
+    "two hundred and forty-first",
+    "two hundred and forty-second",
+    "two hundred and forty-third",
+    "two hundred and forty-fourth",
+    "two hundred and forty-fifth",
+    "two hundred and forty-sixth",
+    "two hundred and forty-seventh",
+    "two hundred and forty-eighth",
+    "two hundred and forty-ninth",
+    "two hundred and fiftieth",
+    "two hundred and fifty-first",
+    "two hundred and fifty-second",
+    "two hundred and fifty-third",
+    "two hundred and fifty-fourth",
+    "two hundred and fifty-fifth",
+];
+
+/// Other description names.
+const OTHER_NAMES: [(u16, &str); 17] = [
+    (DESCRIPTION_FRONT, "front"),
+    (DESCRIPTION_BACK, "back"),
+    (DESCRIPTION_TOP, "top"),
+    (DESCRIPTION_BOTTOM, "bottom"),
+    (DESCRIPTION_UPPER, "upper"),
+    (DESCRIPTION_LOWER, "lower"),
+    (DESCRIPTION_MAIN, "main"),
+    (DESCRIPTION_BACKUP, "backup"),
+    (DESCRIPTION_AUXILIARY, "auxiliary"),
+    (DESCRIPTION_SUPPLEMENTARY, "supplementary"),
+    (DESCRIPTION_FLASH, "flash"),
+    (DESCRIPTION_INSIDE, "inside"),
+    (DESCRIPTION_OUTSIDE, "outside"),
+    (DESCRIPTION_LEFT, "left"),
+    (DESCRIPTION_RIGHT, "right"),
+    (DESCRIPTION_INTERNAL, "internal"),
+    (DESCRIPTION_EXTERNAL, "external"),
+];
+
+/// Returns the description name of Bluetooth SIG Name Space(`None` if unknown).
+///
+/// # Examples
+///
+/// ```
+/// use ble_data_struct::descriptors::presentation_format_description::{
+///     bluetooth_sig_description_name, DESCRIPTION_LEFT,
+/// };
+///
+/// assert_eq!(Some("unknown"), bluetooth_sig_description_name(0x0000));
+/// assert_eq!(Some("second"), bluetooth_sig_description_name(0x0002));
+/// assert_eq!(Some("two hundred and fifty-fifth"), bluetooth_sig_description_name(0x00ff));
+/// assert_eq!(Some("left"), bluetooth_sig_description_name(DESCRIPTION_LEFT));
+/// assert_eq!(None, bluetooth_sig_description_name(0x0111));
+/// ```
+pub fn bluetooth_sig_description_name(description: u16) -> Option<&'static str> {
+    match description {
+        DESCRIPTION_UNKNOWN => Some("unknown"),
+        0x0001..=0x00ff => Some(ORDINAL_NAMES[description as usize - 1]),
+        _ => OTHER_NAMES.iter().find(|f| f.0 == description).map(|f| f.1),
+    }
+}
+
+/// Returns the description name(`None` if the Name Space is not Bluetooth SIG or the description is unknown).
+///
+/// # Examples
+///
+/// ```
+/// use ble_data_struct::descriptors::presentation_format_description::{
+///     description_name, DESCRIPTION_FIRST, NAME_SPACE_BLUETOOTH_SIG,
+/// };
+///
+/// assert_eq!(Some("first"), description_name(NAME_SPACE_BLUETOOTH_SIG, DESCRIPTION_FIRST));
+/// assert_eq!(None, description_name(0x00, DESCRIPTION_FIRST));
+/// ```
+pub fn description_name(name_space: u8, description: u16) -> Option<&'static str> {
+    if name_space == NAME_SPACE_BLUETOOTH_SIG {
+        bluetooth_sig_description_name(description)
+    } else {
+        None
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::descriptors::presentation_format_description::*;
+
+    #[test]
+    fn test_bluetooth_sig_description_name() {
+        assert_eq!(
+            Some("unknown"),
+            bluetooth_sig_description_name(DESCRIPTION_UNKNOWN)
+        );
+        assert_eq!(
+            Some("first"),
+            bluetooth_sig_description_name(DESCRIPTION_FIRST)
+        );
+        assert_eq!(
+            Some("second"),
+            bluetooth_sig_description_name(DESCRIPTION_SECOND)
+        );
+        assert_eq!(Some("twelfth"), bluetooth_sig_description_name(0x000c));
+        assert_eq!(Some("twenty-first"), bluetooth_sig_description_name(0x0015));
+        assert_eq!(
+            Some("one hundredth"),
+            bluetooth_sig_description_name(0x0064)
+        );
+        assert_eq!(
+            Some("one hundred and first"),
+            bluetooth_sig_description_name(0x0065)
+        );
+        assert_eq!(
+            Some("two hundred and fifty-fifth"),
+            bluetooth_sig_description_name(0x00ff)
+        );
+        assert_eq!(
+            Some("front"),
+            bluetooth_sig_description_name(DESCRIPTION_FRONT)
+        );
+        assert_eq!(
+            Some("right"),
+            bluetooth_sig_description_name(DESCRIPTION_RIGHT)
+        );
+        assert_eq!(
+            Some("external"),
+            bluetooth_sig_description_name(DESCRIPTION_EXTERNAL)
+        );
+        assert_eq!(None, bluetooth_sig_description_name(0x0111));
+        assert_eq!(None, bluetooth_sig_description_name(0xffff));
+    }
+
+    #[test]
+    fn test_description_name() {
+        assert_eq!(
+            Some("top"),
+            description_name(NAME_SPACE_BLUETOOTH_SIG, DESCRIPTION_TOP)
+        );
+        assert_eq!(None, description_name(NAME_SPACE_BLUETOOTH_SIG, 0x0111));
+        assert_eq!(None, description_name(0x00, DESCRIPTION_TOP));
+        assert_eq!(None, description_name(0x02, DESCRIPTION_TOP));
+    }
+}
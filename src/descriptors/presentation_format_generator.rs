@@ -0,0 +1,410 @@
+//! Characteristic value generator module.
+//!
+//! Generates valid characteristic values from [`CharacteristicPresentationFormat`] for test fixtures.
+//! Values are pseudo random (xorshift64*) and reproducible from the seed, not for cryptographic use.
+
+use crate::descriptors::characteristic_presentation_format::CharacteristicPresentationFormat;
+
+/// Format: boolean
+pub const FORMAT_BOOLEAN: u8 = 0x01;
+
+/// Format: unsigned 2-bit integer
+pub const FORMAT_2BIT: u8 = 0x02;
+
+/// Format: unsigned 4-bit integer
+pub const FORMAT_NIBBLE: u8 = 0x03;
+
+/// Format: unsigned 8-bit integer
+pub const FORMAT_UINT8: u8 = 0x04;
+
+/// Format: unsigned 12-bit integer
+pub const FORMAT_UINT12: u8 = 0x05;
+
+/// Format: unsigned 16-bit integer
+pub const FORMAT_UINT16: u8 = 0x06;
+
+/// Format: unsigned 24-bit integer
+pub const FORMAT_UINT24: u8 = 0x07;
+
+/// Format: unsigned 32-bit integer
+pub const FORMAT_UINT32: u8 = 0x08;
+
+/// Format: unsigned 48-bit integer
+pub const FORMAT_UINT48: u8 = 0x09;
+
+/// Format: unsigned 64-bit integer
+pub const FORMAT_UINT64: u8 = 0x0a;
+
+/// Format: unsigned 128-bit integer
+pub const FORMAT_UINT128: u8 = 0x0b;
+
+/// Format: signed 8-bit integer
+pub const FORMAT_SINT8: u8 = 0x0c;
+
+/// Format: signed 12-bit integer
+pub const FORMAT_SINT12: u8 = 0x0d;
+
+/// Format: signed 16-bit integer
+pub const FORMAT_SINT16: u8 = 0x0e;
+
+/// Format: signed 24-bit integer
+pub const FORMAT_SINT24: u8 = 0x0f;
+
+/// Format: signed 32-bit integer
+pub const FORMAT_SINT32: u8 = 0x10;
+
+/// Format: signed 48-bit integer
+pub const FORMAT_SINT48: u8 = 0x11;
+
+/// Format: signed 64-bit integer
+pub const FORMAT_SINT64: u8 = 0x12;
+
+/// Format: signed 128-bit integer
+pub const FORMAT_SINT128: u8 = 0x13;
+
+/// Format: IEEE-754 32-bit floating point
+pub const FORMAT_FLOAT32: u8 = 0x14;
+
+/// Format: IEEE-754 64-bit floating point
+pub const FORMAT_FLOAT64: u8 = 0x15;
+
+/// Format: IEEE 11073-20601 16-bit SFLOAT
+pub const FORMAT_SFLOAT: u8 = 0x16;
+
+/// Format: IEEE 11073-20601 32-bit FLOAT
+pub const FORMAT_FLOAT: u8 = 0x17;
+
+/// Format: IEEE 20601 format (two unsigned 16-bit integers)
+pub const FORMAT_DUINT16: u8 = 0x18;
+
+/// Format: UTF-8 string
+pub const FORMAT_UTF8S: u8 = 0x19;
+
+/// Format: UTF-16 string
+pub const FORMAT_UTF16S: u8 = 0x1a;
+
+/// Format: Opaque structure
+pub const FORMAT_STRUCT: u8 = 0x1b;
+
+/// Max length of generated variable length values.
+pub const MAX_VARIABLE_LENGTH: usize = 16;
+
+/// Characters of generated strings.
+const CHARACTERS: &[u8] = b"0123456789ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz";
+
+/// Returns the value size of format(`None` for variable length or unknown format).
+///
+/// # Examples
+///
+/// ```
+/// use ble_data_struct::descriptors::presentation_format_generator::{
+///     format_size, FORMAT_SINT12, FORMAT_UINT48, FORMAT_UTF8S,
+/// };
+///
+/// assert_eq!(Some(2), format_size(FORMAT_SINT12));
+/// assert_eq!(Some(6), format_size(FORMAT_UINT48));
+/// assert_eq!(None, format_size(FORMAT_UTF8S));
+/// assert_eq!(None, format_size(0x00));
+/// ```
+pub fn format_size(format: u8) -> Option<usize> {
+    match format {
+        FORMAT_BOOLEAN | FORMAT_2BIT | FORMAT_NIBBLE | FORMAT_UINT8 | FORMAT_SINT8 => Some(1),
+        FORMAT_UINT12 | FORMAT_UINT16 | FORMAT_SINT12 | FORMAT_SINT16 | FORMAT_SFLOAT => Some(2),
+        FORMAT_UINT24 | FORMAT_SINT24 => Some(3),
+        FORMAT_UINT32 | FORMAT_SINT32 | FORMAT_FLOAT32 | FORMAT_FLOAT | FORMAT_DUINT16 => Some(4),
+        FORMAT_UINT48 | FORMAT_SINT48 => Some(6),
+        FORMAT_UINT64 | FORMAT_SINT64 | FORMAT_FLOAT64 => Some(8),
+        FORMAT_UINT128 | FORMAT_SINT128 => Some(16),
+        _ => None,
+    }
+}
+
+/// Characteristic value generator.
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct PresentationFormatGenerator {
+    /// xorshift64* state
+    state: u64,
+}
+
+impl PresentationFormatGenerator {
+    /// Create [`PresentationFormatGenerator`] from seed.
+    ///
+    /// Same seed generates same values.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ble_data_struct::descriptors::presentation_format_generator::PresentationFormatGenerator;
+    ///
+    /// let mut generator1 = PresentationFormatGenerator::new(1);
+    /// let mut generator2 = PresentationFormatGenerator::new(1);
+    /// assert_eq!(generator1.next_u64(), generator2.next_u64());
+    /// ```
+    pub fn new(seed: u64) -> Self {
+        Self {
+            // xorshift state must not be zero
+            state: if seed == 0 { u64::MAX } else { seed },
+        }
+    }
+
+    /// Next pseudo random value.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ble_data_struct::descriptors::presentation_format_generator::PresentationFormatGenerator;
+    ///
+    /// let mut generator = PresentationFormatGenerator::new(1);
+    /// assert_ne!(generator.next_u64(), generator.next_u64());
+    /// ```
+    pub fn next_u64(&mut self) -> u64 {
+        self.state ^= self.state >> 12;
+        self.state ^= self.state << 25;
+        self.state ^= self.state >> 27;
+        self.state.wrapping_mul(0x2545_f491_4f6c_dd1d)
+    }
+
+    /// Generate valid characteristic value of [`CharacteristicPresentationFormat`].
+    ///
+    /// Value is the raw value (before applying `Exponent`) in little endian.
+    /// Bit fields use the lower bits, SFLOAT / FLOAT never generate special values (NaN, NRes, ±INFINITY, reserved),
+    /// and IEEE-754 floating point values are always finite.
+    /// Variable length formats generate up to [`MAX_VARIABLE_LENGTH`] bytes(ASCII alphanumeric for strings).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ble_data_struct::descriptors::{
+    ///     characteristic_presentation_format::CharacteristicPresentationFormat,
+    ///     presentation_format_generator::{
+    ///         PresentationFormatGenerator, FORMAT_BOOLEAN, FORMAT_SINT24, FORMAT_UTF8S,
+    ///     },
+    /// };
+    ///
+    /// let mut generator = PresentationFormatGenerator::new(1);
+    ///
+    /// let result = generator
+    ///     .generate(&CharacteristicPresentationFormat::new(FORMAT_BOOLEAN, 0, 0x2700, 0x01, 0x0000))
+    ///     .unwrap();
+    /// assert!(result == vec![0] || result == vec![1]);
+    ///
+    /// let result = generator
+    ///     .generate(&CharacteristicPresentationFormat::new(FORMAT_SINT24, -2, 0x272f, 0x01, 0x0000))
+    ///     .unwrap();
+    /// assert_eq!(3, result.len());
+    ///
+    /// let result = generator
+    ///     .generate(&CharacteristicPresentationFormat::new(FORMAT_UTF8S, 0, 0x2700, 0x01, 0x0000))
+    ///     .unwrap();
+    /// assert!(String::from_utf8(result).is_ok());
+    ///
+    /// assert!(generator
+    ///     .generate(&CharacteristicPresentationFormat::new(0x00, 0, 0x2700, 0x01, 0x0000))
+    ///     .is_err());
+    /// ```
+    pub fn generate(
+        &mut self,
+        presentation_format: &CharacteristicPresentationFormat,
+    ) -> Result<Vec<u8>, String> {
+        let random = self.next_u64();
+        let format = presentation_format.format;
+        let data = match format {
+            FORMAT_BOOLEAN => vec![(random & 0x01) as u8],
+            FORMAT_2BIT => vec![(random & 0x03) as u8],
+            FORMAT_NIBBLE => vec![(random & 0x0f) as u8],
+            FORMAT_UINT12 | FORMAT_SINT12 => ((random & 0x0fff) as u16).to_le_bytes().to_vec(),
+            FORMAT_UINT128 | FORMAT_SINT128 => {
+                let mut data = random.to_le_bytes().to_vec();
+                data.extend_from_slice(&self.next_u64().to_le_bytes());
+                data
+            }
+            FORMAT_FLOAT32 => ((random as i32) as f32 / 65536.0).to_le_bytes().to_vec(),
+            FORMAT_FLOAT64 => ((random as i64) as f64 / 4294967296.0)
+                .to_le_bytes()
+                .to_vec(),
+            FORMAT_SFLOAT => {
+                let mut mantissa = (random & 0x0fff) as u16;
+                // 0x07fe - 0x0802: +INFINITY, NaN, NRes, reserved, -INFINITY
+                if (0x07fe..=0x0802).contains(&mantissa) {
+                    mantissa = 0;
+                }
+                ((random >> 16) as u16 & 0xf000 | mantissa)
+                    .to_le_bytes()
+                    .to_vec()
+            }
+            FORMAT_FLOAT => {
+                let mut mantissa = (random & 0x00ff_ffff) as u32;
+                // 0x7ffffe - 0x800002: +INFINITY, NaN, NRes, reserved, -INFINITY
+                if (0x007f_fffe..=0x0080_0002).contains(&mantissa) {
+                    mantissa = 0;
+                }
+                ((random >> 32) as u32 & 0xff00_0000 | mantissa)
+                    .to_le_bytes()
+                    .to_vec()
+            }
+            FORMAT_UTF8S | FORMAT_UTF16S | FORMAT_STRUCT => {
+                let length = (random % (MAX_VARIABLE_LENGTH as u64 + 1)) as usize;
+                let mut data: Vec<u8> = Vec::with_capacity(length);
+                while data.len() < length {
+                    data.extend_from_slice(&self.next_u64().to_le_bytes());
+                }
+                data.truncate(length);
+                match format {
+                    FORMAT_STRUCT => data,
+                    _ => {
+                        let text: String = data
+                            .iter()
+                            .map(|f| CHARACTERS[*f as usize % CHARACTERS.len()] as char)
+                            .collect();
+                        match format {
+                            FORMAT_UTF8S => text.into_bytes(),
+                            _ => text.encode_utf16().flat_map(u16::to_le_bytes).collect(),
+                        }
+                    }
+                }
+            }
+            _ => match format_size(format) {
+                Some(size) => random.to_le_bytes()[..size].to_vec(),
+                None => return Err(format!("Unknown format :{}", format)),
+            },
+        };
+        Ok(data)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::descriptors::{
+        characteristic_presentation_format::CharacteristicPresentationFormat,
+        presentation_format_generator::*,
+    };
+
+    fn presentation_format(format: u8) -> CharacteristicPresentationFormat {
+        CharacteristicPresentationFormat::new(format, 0, 0x2700, 0x01, 0x0000)
+    }
+
+    #[test]
+    fn test_format_size() {
+        assert_eq!(Some(1), format_size(FORMAT_BOOLEAN));
+        assert_eq!(Some(1), format_size(FORMAT_NIBBLE));
+        assert_eq!(Some(2), format_size(FORMAT_UINT12));
+        assert_eq!(Some(2), format_size(FORMAT_SFLOAT));
+        assert_eq!(Some(3), format_size(FORMAT_SINT24));
+        assert_eq!(Some(4), format_size(FORMAT_DUINT16));
+        assert_eq!(Some(6), format_size(FORMAT_SINT48));
+        assert_eq!(Some(8), format_size(FORMAT_FLOAT64));
+        assert_eq!(Some(16), format_size(FORMAT_UINT128));
+        assert_eq!(None, format_size(FORMAT_UTF16S));
+        assert_eq!(None, format_size(FORMAT_STRUCT));
+        assert_eq!(None, format_size(0x00));
+        assert_eq!(None, format_size(0x1c));
+    }
+
+    #[test]
+    fn test_new() {
+        let mut generator1 = PresentationFormatGenerator::new(1);
+        let mut generator2 = PresentationFormatGenerator::new(1);
+        let mut generator3 = PresentationFormatGenerator::new(2);
+        let value = generator1.next_u64();
+        assert_eq!(value, generator2.next_u64());
+        assert_ne!(value, generator3.next_u64());
+        assert_ne!(0, PresentationFormatGenerator::new(0).next_u64());
+    }
+
+    #[test]
+    fn test_generate_size() {
+        let mut generator = PresentationFormatGenerator::new(1);
+        for format in FORMAT_BOOLEAN..=FORMAT_STRUCT {
+            for _ in 0..100 {
+                let result = generator.generate(&presentation_format(format)).unwrap();
+                match format_size(format) {
+                    Some(size) => assert_eq!(size, result.len()),
+                    None => assert!(result.len() <= MAX_VARIABLE_LENGTH * 2),
+                }
+            }
+        }
+        assert!(generator.generate(&presentation_format(0x00)).is_err());
+        assert!(generator.generate(&presentation_format(0x1c)).is_err());
+    }
+
+    #[test]
+    fn test_generate_range() {
+        let mut generator = PresentationFormatGenerator::new(1);
+        for _ in 0..1000 {
+            assert!(
+                generator
+                    .generate(&presentation_format(FORMAT_BOOLEAN))
+                    .unwrap()[0]
+                    <= 1
+            );
+            assert!(
+                generator
+                    .generate(&presentation_format(FORMAT_2BIT))
+                    .unwrap()[0]
+                    <= 3
+            );
+            assert!(
+                generator
+                    .generate(&presentation_format(FORMAT_NIBBLE))
+                    .unwrap()[0]
+                    <= 0x0f
+            );
+            assert!(
+                generator
+                    .generate(&presentation_format(FORMAT_UINT12))
+                    .unwrap()[1]
+                    <= 0x0f
+            );
+            assert!(
+                generator
+                    .generate(&presentation_format(FORMAT_SINT12))
+                    .unwrap()[1]
+                    <= 0x0f
+            );
+
+            let result = generator
+                .generate(&presentation_format(FORMAT_FLOAT32))
+                .unwrap();
+            assert!(f32::from_le_bytes(result.try_into().unwrap()).is_finite());
+            let result = generator
+                .generate(&presentation_format(FORMAT_FLOAT64))
+                .unwrap();
+            assert!(f64::from_le_bytes(result.try_into().unwrap()).is_finite());
+
+            let result = generator
+                .generate(&presentation_format(FORMAT_SFLOAT))
+                .unwrap();
+            let mantissa = u16::from_le_bytes(result.try_into().unwrap()) & 0x0fff;
+            assert!(!(0x07fe..=0x0802).contains(&mantissa));
+            let result = generator
+                .generate(&presentation_format(FORMAT_FLOAT))
+                .unwrap();
+            let mantissa = u32::from_le_bytes(result.try_into().unwrap()) & 0x00ff_ffff;
+            assert!(!(0x007f_fffe..=0x0080_0002).contains(&mantissa));
+        }
+    }
+
+    #[test]
+    fn test_generate_string() {
+        let mut generator = PresentationFormatGenerator::new(1);
+        for _ in 0..100 {
+            let result = generator
+                .generate(&presentation_format(FORMAT_UTF8S))
+                .unwrap();
+            let text = String::from_utf8(result).unwrap();
+            assert!(text.len() <= MAX_VARIABLE_LENGTH);
+            assert!(text.chars().all(|f| f.is_ascii_alphanumeric()));
+
+            let result = generator
+                .generate(&presentation_format(FORMAT_UTF16S))
+                .unwrap();
+            assert_eq!(0, result.len() % 2);
+            let text: Vec<u16> = result
+                .chunks(2)
+                .map(|f| u16::from_le_bytes([f[0], f[1]]))
+                .collect();
+            let text = String::from_utf16(&text).unwrap();
+            assert!(text.chars().all(|f| f.is_ascii_alphanumeric()));
+        }
+    }
+}
@@ -1,9 +1,10 @@
 //! Server Characteristic Configuration (Attribute Type: 0x2903) module.
 
-use crate::Uuid16bit;
+use crate::{data_types::parse_error::DataTypeParseError, uuids::descriptor, Uuid16bit};
 
 /// Server Characteristic Configuration.
 #[derive(Debug, PartialEq, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct ServerCharacteristicConfiguration {
     /// Characteristic Configuration Bits
     pub configuration: u16,
@@ -46,8 +47,25 @@ impl ServerCharacteristicConfiguration {
 /// Broadcast
 pub const BROADCAST: u16 = 0b00000001;
 
+impl TryFrom<&[u8]> for ServerCharacteristicConfiguration {
+    type Error = DataTypeParseError;
+    /// Create [`ServerCharacteristicConfiguration`] from `&[u8]`.
+    fn try_from(value: &[u8]) -> Result<Self, DataTypeParseError> {
+        let len = value.len();
+        if len != 2 {
+            return Err(DataTypeParseError::InvalidLength {
+                expected: 2,
+                actual: len,
+            });
+        }
+        Ok(Self {
+            configuration: u16::from_le_bytes(value[..2].try_into().unwrap()),
+        })
+    }
+}
+
 impl TryFrom<&Vec<u8>> for ServerCharacteristicConfiguration {
-    type Error = String;
+    type Error = DataTypeParseError;
     /// Create [`ServerCharacteristicConfiguration`] from [`Vec<u8>`].
     ///
     /// # Examples
@@ -61,19 +79,13 @@ impl TryFrom<&Vec<u8>> for ServerCharacteristicConfiguration {
     /// let result = ServerCharacteristicConfiguration::try_from(&configuration);
     /// assert!(result.is_ok());
     /// assert_eq!(BROADCAST, result.unwrap().configuration);
-    /// 
+    ///
     /// let configuration = Vec::new();
     /// let result = ServerCharacteristicConfiguration::try_from(&configuration);
     /// assert!(!result.is_ok());
     /// ```
-    fn try_from(value: &Vec<u8>) -> Result<Self, String> {
-        let len = value.len();
-        if len != 2 {
-            return Err(format!("Invalid data size :{}", len).to_string());
-        }
-        Ok(Self {
-            configuration: u16::from_le_bytes(value[..2].try_into().unwrap()),
-        })
+    fn try_from(value: &Vec<u8>) -> Result<Self, DataTypeParseError> {
+        Self::try_from(value.as_slice())
     }
 }
 
@@ -109,7 +121,7 @@ impl Uuid16bit for ServerCharacteristicConfiguration {
     /// assert_eq!(0x2903, ServerCharacteristicConfiguration::uuid_16bit());
     /// ```
     fn uuid_16bit() -> u16 {
-        0x2903
+        descriptor::SERVER_CHARACTERISTIC_CONFIGURATION
     }
 }
 
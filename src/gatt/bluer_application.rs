@@ -0,0 +1,344 @@
+//! bluer GATT application module.
+//!
+//! Converts [`BluezGattApplication`] to a bluer [`Application`] and registers it with
+//! `org.bluez.GattManager1.RegisterApplication`.
+//! BlueZ checks the `encrypt-*` / `secure-*` flags before calling the value callbacks.
+
+use std::{future::Future, pin::Pin};
+
+use bluer::{
+    gatt::local::{
+        Application, ApplicationHandle, Characteristic, CharacteristicNotify,
+        CharacteristicNotifyMethod, CharacteristicRead, CharacteristicWrite,
+        CharacteristicWriteMethod, Descriptor, DescriptorRead, DescriptorWrite, ReqError,
+        ReqResult, Service,
+    },
+    Adapter,
+};
+
+use crate::gatt::{
+    bluez_application::{
+        BluezGattApplication, GattCharacteristicNode, GattDescriptorNode, GattServiceNode,
+        ReadValue, WriteValue,
+    },
+    security::{
+        SecurityLevel, INSUFFICIENT_AUTHENTICATION, INSUFFICIENT_ENCRYPTION, READ_NOT_PERMITTED,
+        WRITE_NOT_PERMITTED,
+    },
+};
+
+/// Convert ATT error code to [`ReqError`].
+///
+/// BlueZ only forwards generic errors, other codes (e.g. application errors) become [`ReqError::Failed`].
+///
+/// # Examples
+///
+/// ```
+/// use ble_data_struct::gatt::{bluer_application::req_error, security::INSUFFICIENT_ENCRYPTION};
+/// use bluer::gatt::local::ReqError;
+///
+/// assert_eq!(ReqError::NotAuthorized, req_error(INSUFFICIENT_ENCRYPTION));
+/// assert_eq!(ReqError::Failed, req_error(0x80));
+/// ```
+pub fn req_error(error: u8) -> ReqError {
+    match error {
+        READ_NOT_PERMITTED | WRITE_NOT_PERMITTED => ReqError::NotPermitted,
+        INSUFFICIENT_AUTHENTICATION | INSUFFICIENT_ENCRYPTION => ReqError::NotAuthorized,
+        _ => ReqError::Failed,
+    }
+}
+
+/// Wrap callback result as the future bluer expects.
+fn ready<T: Send + 'static>(value: T) -> Pin<Box<dyn Future<Output = T> + Send>> {
+    Box::pin(std::future::ready(value))
+}
+
+/// Read the value from `offset` (Read Blob).
+fn read_at(read: &ReadValue, offset: u16) -> ReqResult<Vec<u8>> {
+    read()
+        .get(usize::from(offset)..)
+        .map(|f| f.to_vec())
+        .ok_or(ReqError::InvalidOffset)
+}
+
+/// Write the whole value, typed values do not support partial writes.
+fn write_at(write: &WriteValue, value: &[u8], offset: u16) -> ReqResult<()> {
+    if offset != 0 {
+        return Err(ReqError::InvalidOffset);
+    }
+    write(value).map_err(req_error)
+}
+
+impl From<&GattDescriptorNode> for Descriptor {
+    /// Create [`Descriptor`] from [`GattDescriptorNode`].
+    fn from(value: &GattDescriptorNode) -> Self {
+        Self {
+            uuid: value.uuid,
+            read: value.value.read_callback().map(|(security, read)| {
+                let read = read.clone();
+                DescriptorRead {
+                    read: *security == SecurityLevel::None,
+                    encrypt_read: *security == SecurityLevel::Unauthenticated,
+                    encrypt_authenticated_read: *security == SecurityLevel::Authenticated,
+                    secure_read: *security == SecurityLevel::ScAuthenticated,
+                    fun: Box::new(move |request| ready(read_at(&read, request.offset))),
+                    ..Default::default()
+                }
+            }),
+            write: value.value.write_callback().map(|(security, write)| {
+                let write = write.clone();
+                DescriptorWrite {
+                    write: *security == SecurityLevel::None,
+                    encrypt_write: *security == SecurityLevel::Unauthenticated,
+                    encrypt_authenticated_write: *security == SecurityLevel::Authenticated,
+                    secure_write: *security == SecurityLevel::ScAuthenticated,
+                    fun: Box::new(move |value, request| {
+                        ready(write_at(&write, &value, request.offset))
+                    }),
+                    ..Default::default()
+                }
+            }),
+            ..Default::default()
+        }
+    }
+}
+
+impl From<&GattCharacteristicNode> for Characteristic {
+    /// Create [`Characteristic`] from [`GattCharacteristicNode`].
+    ///
+    /// Notifications use [`CharacteristicNotifyMethod::Io`],
+    /// set `control_handle` to receive the notification writer.
+    fn from(value: &GattCharacteristicNode) -> Self {
+        Self {
+            uuid: value.uuid,
+            descriptors: value.descriptors.iter().map(Descriptor::from).collect(),
+            read: value.value.read_callback().map(|(security, read)| {
+                let read = read.clone();
+                CharacteristicRead {
+                    read: *security == SecurityLevel::None,
+                    encrypt_read: *security == SecurityLevel::Unauthenticated,
+                    encrypt_authenticated_read: *security == SecurityLevel::Authenticated,
+                    secure_read: *security == SecurityLevel::ScAuthenticated,
+                    fun: Box::new(move |request| ready(read_at(&read, request.offset))),
+                    ..Default::default()
+                }
+            }),
+            write: value.value.write_callback().map(|(security, write)| {
+                let write = write.clone();
+                CharacteristicWrite {
+                    write: *security == SecurityLevel::None,
+                    encrypt_write: *security == SecurityLevel::Unauthenticated,
+                    encrypt_authenticated_write: *security == SecurityLevel::Authenticated,
+                    secure_write: *security == SecurityLevel::ScAuthenticated,
+                    method: CharacteristicWriteMethod::Fun(Box::new(move |value, request| {
+                        ready(write_at(&write, &value, request.offset))
+                    })),
+                    ..Default::default()
+                }
+            }),
+            notify: value.notify.then(|| CharacteristicNotify {
+                notify: true,
+                method: CharacteristicNotifyMethod::Io,
+                ..Default::default()
+            }),
+            ..Default::default()
+        }
+    }
+}
+
+impl From<&GattServiceNode> for Service {
+    /// Create [`Service`] from [`GattServiceNode`].
+    fn from(value: &GattServiceNode) -> Self {
+        Self {
+            uuid: value.uuid,
+            primary: value.primary,
+            characteristics: value
+                .characteristics
+                .iter()
+                .map(Characteristic::from)
+                .collect(),
+            ..Default::default()
+        }
+    }
+}
+
+impl From<&BluezGattApplication> for Application {
+    /// Create [`Application`] from [`BluezGattApplication`].
+    ///
+    /// bluer chooses the object paths, [`BluezGattApplication::base_path`] is not used.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ble_data_struct::{
+    ///     characteristics::battery_level::BatteryLevel,
+    ///     gatt::{bluez_application::*, security::SecurityLevel},
+    ///     uuid_from_u16,
+    ///     uuids::service,
+    /// };
+    /// use bluer::gatt::local::{characteristic_control, Application};
+    ///
+    /// let application = BluezGattApplication::new("/org/example/app").with_service(
+    ///     GattServiceNode::new(&uuid_from_u16(service::BATTERY)).with_characteristic(
+    ///         GattCharacteristicNode::typed::<BatteryLevel>()
+    ///             .with_read(SecurityLevel::None, || BatteryLevel::new(80))
+    ///             .with_notify(true),
+    ///     ),
+    /// );
+    ///
+    /// let mut result = Application::from(&application);
+    /// let characteristic = &mut result.services[0].characteristics[0];
+    /// assert_eq!(uuid_from_u16(0x2a19), characteristic.uuid);
+    /// assert!(characteristic.read.as_ref().unwrap().read);
+    ///
+    /// // notifications are written through the characteristic control
+    /// let (_control, control_handle) = characteristic_control();
+    /// characteristic.control_handle = control_handle;
+    /// ```
+    fn from(value: &BluezGattApplication) -> Self {
+        Self {
+            services: value.services.iter().map(Service::from).collect(),
+            ..Default::default()
+        }
+    }
+}
+
+/// Register [`BluezGattApplication`] with the adapter (`RegisterApplication`).
+///
+/// Drop the returned [`ApplicationHandle`] to unregister the application.
+pub async fn register_application(
+    adapter: &Adapter,
+    application: &BluezGattApplication,
+) -> bluer::Result<ApplicationHandle> {
+    adapter
+        .serve_gatt_application(Application::from(application))
+        .await
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::{Arc, Mutex};
+
+    use bluer::gatt::local::{Application, CharacteristicNotifyMethod, ReqError};
+
+    use crate::{
+        characteristics::battery_level::BatteryLevel,
+        descriptors::characteristic_user_description::CharacteristicUserDescription,
+        gatt::{
+            bluer_application::{read_at, req_error, write_at},
+            bluez_application::*,
+            security::{
+                SecurityLevel, INSUFFICIENT_ENCRYPTION, READ_NOT_PERMITTED, WRITE_NOT_PERMITTED,
+            },
+        },
+        uuid_from_u16,
+        uuids::service,
+    };
+
+    fn application(level: Arc<Mutex<u8>>) -> BluezGattApplication {
+        let read_level = level.clone();
+        let write_level = level;
+        BluezGattApplication::new("/org/example/app").with_service(
+            GattServiceNode::new(&uuid_from_u16(service::BATTERY)).with_characteristic(
+                GattCharacteristicNode::typed::<BatteryLevel>()
+                    .with_read(SecurityLevel::None, move || {
+                        BatteryLevel::new(*read_level.lock().unwrap())
+                    })
+                    .with_write(SecurityLevel::Authenticated, move |f: BatteryLevel| {
+                        *write_level.lock().unwrap() = f.battery_level;
+                        Ok(())
+                    })
+                    .with_notify(true)
+                    .with_descriptor(
+                        GattDescriptorNode::typed::<CharacteristicUserDescription>()
+                            .with_read(SecurityLevel::ScAuthenticated, || {
+                                CharacteristicUserDescription::new("main".to_string())
+                            }),
+                    ),
+            ),
+        )
+    }
+
+    #[test]
+    fn test_req_error() {
+        assert_eq!(ReqError::NotPermitted, req_error(READ_NOT_PERMITTED));
+        assert_eq!(ReqError::NotPermitted, req_error(WRITE_NOT_PERMITTED));
+        assert_eq!(ReqError::NotAuthorized, req_error(INSUFFICIENT_ENCRYPTION));
+        assert_eq!(ReqError::Failed, req_error(VALUE_NOT_ALLOWED));
+    }
+
+    #[test]
+    fn test_from() {
+        let result = Application::from(&application(Arc::new(Mutex::new(0))));
+        assert_eq!(1, result.services.len());
+        let service = &result.services[0];
+        assert_eq!(uuid_from_u16(0x180f), service.uuid);
+        assert!(service.primary);
+
+        let characteristic = &service.characteristics[0];
+        assert_eq!(uuid_from_u16(0x2a19), characteristic.uuid);
+        let read = characteristic.read.as_ref().unwrap();
+        assert!(read.read);
+        assert!(!read.encrypt_read);
+        let write = characteristic.write.as_ref().unwrap();
+        assert!(!write.write);
+        assert!(write.encrypt_authenticated_write);
+        let notify = characteristic.notify.as_ref().unwrap();
+        assert!(notify.notify);
+        assert!(matches!(notify.method, CharacteristicNotifyMethod::Io));
+
+        let descriptor = &characteristic.descriptors[0];
+        assert_eq!(uuid_from_u16(0x2901), descriptor.uuid);
+        assert!(descriptor.read.as_ref().unwrap().secure_read);
+        assert!(descriptor.write.is_none());
+
+        let result = Application::from(
+            &BluezGattApplication::new("/app").with_service(
+                GattServiceNode::new(&uuid_from_u16(service::BATTERY))
+                    .with_primary(false)
+                    .with_characteristic(GattCharacteristicNode::typed::<BatteryLevel>()),
+            ),
+        );
+        assert!(!result.services[0].primary);
+        let characteristic = &result.services[0].characteristics[0];
+        assert!(characteristic.read.is_none());
+        assert!(characteristic.write.is_none());
+        assert!(characteristic.notify.is_none());
+    }
+
+    #[test]
+    fn test_read_at() {
+        let level = Arc::new(Mutex::new(30));
+        let application = application(level.clone());
+        let characteristic = &application.services[0].characteristics[0];
+        let read = &characteristic.value.read_callback().unwrap().1;
+        assert_eq!(Ok(vec![30]), read_at(read, 0));
+        *level.lock().unwrap() = 31;
+        assert_eq!(Ok(vec![31]), read_at(read, 0));
+        assert_eq!(Ok(Vec::new()), read_at(read, 1));
+        assert_eq!(Err(ReqError::InvalidOffset), read_at(read, 2));
+
+        let read = &characteristic.descriptors[0]
+            .value
+            .read_callback()
+            .unwrap()
+            .1;
+        assert_eq!(Ok("ain".as_bytes().to_vec()), read_at(read, 1));
+    }
+
+    #[test]
+    fn test_write_at() {
+        let level = Arc::new(Mutex::new(30));
+        let application = application(level.clone());
+        let write = &application.services[0].characteristics[0]
+            .value
+            .write_callback()
+            .unwrap()
+            .1;
+        assert_eq!(Ok(()), write_at(write, &[40], 0));
+        assert_eq!(40, *level.lock().unwrap());
+        assert_eq!(Err(ReqError::InvalidOffset), write_at(write, &[50], 1));
+        assert_eq!(Err(ReqError::Failed), write_at(write, &[101], 0));
+        assert_eq!(40, *level.lock().unwrap());
+    }
+}
@@ -0,0 +1,639 @@
+//! BlueZ GATT application module.
+//!
+//! Lays out a GATT service tree as the objects of a BlueZ GATT application
+//! (`org.bluez.GattManager1.RegisterApplication`) and dispatches `ReadValue` / `WriteValue` calls to the value callbacks.
+//! D-Bus backends export [`BluezGattApplication::objects`] and forward the method calls by object path.
+//! With the `bluer` feature, `gatt::bluer_application` registers the tree through bluer.
+
+use std::sync::Arc;
+
+use uuid::Uuid;
+
+use crate::{
+    gatt::security::{
+        AttributePermissions, SecurityLevel, READ_NOT_PERMITTED, WRITE_NOT_PERMITTED,
+    },
+    uuid_from_u16, Uuid16bit,
+};
+
+/// ATT error: Invalid Handle (unknown object path).
+pub const INVALID_HANDLE: u8 = 0x01;
+
+/// ATT error: Value Not Allowed.
+pub const VALUE_NOT_ALLOWED: u8 = 0x13;
+
+/// BlueZ GATT service interface.
+pub const GATT_SERVICE_INTERFACE: &str = "org.bluez.GattService1";
+
+/// BlueZ GATT characteristic interface.
+pub const GATT_CHARACTERISTIC_INTERFACE: &str = "org.bluez.GattCharacteristic1";
+
+/// BlueZ GATT descriptor interface.
+pub const GATT_DESCRIPTOR_INTERFACE: &str = "org.bluez.GattDescriptor1";
+
+/// Read value callback.
+pub type ReadValue = Arc<dyn Fn() -> Vec<u8> + Send + Sync>;
+
+/// Write value callback(`Err` is the ATT error code).
+pub type WriteValue = Arc<dyn Fn(&[u8]) -> Result<(), u8> + Send + Sync>;
+
+/// Readable / writable value of a characteristic or descriptor.
+#[derive(Clone, Default)]
+pub struct GattValue {
+    /// Required security level and callback for read
+    read: Option<(SecurityLevel, ReadValue)>,
+
+    /// Required security level and callback for write
+    write: Option<(SecurityLevel, WriteValue)>,
+}
+
+impl GattValue {
+    /// Attribute permissions of the value.
+    pub fn permissions(&self) -> AttributePermissions {
+        AttributePermissions::new(
+            self.read.as_ref().map(|f| f.0),
+            self.write.as_ref().map(|f| f.0),
+        )
+    }
+
+    /// Required security level and callback for read.
+    pub(crate) fn read_callback(&self) -> Option<&(SecurityLevel, ReadValue)> {
+        self.read.as_ref()
+    }
+
+    /// Required security level and callback for write.
+    pub(crate) fn write_callback(&self) -> Option<&(SecurityLevel, WriteValue)> {
+        self.write.as_ref()
+    }
+
+    /// BlueZ read / write flags of the value.
+    fn flags(&self) -> Vec<&'static str> {
+        let mut flags: Vec<&'static str> = Vec::new();
+        if let Some((security, _)) = &self.read {
+            flags.push(match security {
+                SecurityLevel::None => "read",
+                SecurityLevel::Unauthenticated => "encrypt-read",
+                SecurityLevel::Authenticated => "encrypt-authenticated-read",
+                SecurityLevel::ScAuthenticated => "secure-read",
+            });
+        }
+        if let Some((security, _)) = &self.write {
+            flags.push(match security {
+                SecurityLevel::None => "write",
+                SecurityLevel::Unauthenticated => "encrypt-write",
+                SecurityLevel::Authenticated => "encrypt-authenticated-write",
+                SecurityLevel::ScAuthenticated => "secure-write",
+            });
+        }
+        flags
+    }
+
+    /// Read the value with the link security level.
+    fn read(&self, current: SecurityLevel) -> Result<Vec<u8>, u8> {
+        self.permissions().check_read(current)?;
+        match &self.read {
+            Some((_, read)) => Ok(read()),
+            None => Err(READ_NOT_PERMITTED),
+        }
+    }
+
+    /// Write the value with the link security level.
+    fn write(&self, value: &[u8], current: SecurityLevel) -> Result<(), u8> {
+        self.permissions().check_write(current)?;
+        match &self.write {
+            Some((_, write)) => write(value),
+            None => Err(WRITE_NOT_PERMITTED),
+        }
+    }
+
+    /// Set typed read callback.
+    fn set_read<T, F>(&mut self, security: SecurityLevel, read: F)
+    where
+        T: Into<Vec<u8>>,
+        F: Fn() -> T + Send + Sync + 'static,
+    {
+        self.read = Some((security, Arc::new(move || read().into())));
+    }
+
+    /// Set typed write callback.
+    ///
+    /// Values that fail to parse as `T` are rejected with [`VALUE_NOT_ALLOWED`].
+    fn set_write<T, F>(&mut self, security: SecurityLevel, write: F)
+    where
+        T: for<'a> TryFrom<&'a Vec<u8>>,
+        F: Fn(T) -> Result<(), u8> + Send + Sync + 'static,
+    {
+        self.write = Some((
+            security,
+            Arc::new(move |value: &[u8]| match T::try_from(&value.to_vec()) {
+                Ok(value) => write(value),
+                Err(_) => Err(VALUE_NOT_ALLOWED),
+            }),
+        ));
+    }
+}
+
+/// GATT descriptor of [`BluezGattApplication`].
+#[derive(Clone)]
+pub struct GattDescriptorNode {
+    /// Descriptor UUID
+    pub uuid: Uuid,
+
+    /// Value
+    pub value: GattValue,
+}
+
+impl GattDescriptorNode {
+    /// Create [`GattDescriptorNode`] from UUID (neither readable nor writable).
+    pub fn new(uuid: &Uuid) -> Self {
+        Self {
+            uuid: *uuid,
+            value: GattValue::default(),
+        }
+    }
+
+    /// Create [`GattDescriptorNode`] of the typed descriptor `T`.
+    pub fn typed<T: Uuid16bit>() -> Self {
+        Self::new(&uuid_from_u16(T::uuid_16bit()))
+    }
+
+    /// Set read callback returning a typed value.
+    pub fn with_read<T, F>(mut self, security: SecurityLevel, read: F) -> Self
+    where
+        T: Into<Vec<u8>>,
+        F: Fn() -> T + Send + Sync + 'static,
+    {
+        self.value.set_read(security, read);
+        self
+    }
+
+    /// Set write callback receiving a typed value.
+    pub fn with_write<T, F>(mut self, security: SecurityLevel, write: F) -> Self
+    where
+        T: for<'a> TryFrom<&'a Vec<u8>>,
+        F: Fn(T) -> Result<(), u8> + Send + Sync + 'static,
+    {
+        self.value.set_write(security, write);
+        self
+    }
+}
+
+/// GATT characteristic of [`BluezGattApplication`].
+#[derive(Clone)]
+pub struct GattCharacteristicNode {
+    /// Characteristic UUID
+    pub uuid: Uuid,
+
+    /// Value
+    pub value: GattValue,
+
+    /// `true` if notify is supported
+    pub notify: bool,
+
+    /// Descriptors
+    pub descriptors: Vec<GattDescriptorNode>,
+}
+
+impl GattCharacteristicNode {
+    /// Create [`GattCharacteristicNode`] from UUID (neither readable nor writable).
+    pub fn new(uuid: &Uuid) -> Self {
+        Self {
+            uuid: *uuid,
+            value: GattValue::default(),
+            notify: false,
+            descriptors: Vec::new(),
+        }
+    }
+
+    /// Create [`GattCharacteristicNode`] of the typed characteristic `T`.
+    pub fn typed<T: Uuid16bit>() -> Self {
+        Self::new(&uuid_from_u16(T::uuid_16bit()))
+    }
+
+    /// Set read callback returning a typed value.
+    pub fn with_read<T, F>(mut self, security: SecurityLevel, read: F) -> Self
+    where
+        T: Into<Vec<u8>>,
+        F: Fn() -> T + Send + Sync + 'static,
+    {
+        self.value.set_read(security, read);
+        self
+    }
+
+    /// Set write callback receiving a typed value.
+    pub fn with_write<T, F>(mut self, security: SecurityLevel, write: F) -> Self
+    where
+        T: for<'a> TryFrom<&'a Vec<u8>>,
+        F: Fn(T) -> Result<(), u8> + Send + Sync + 'static,
+    {
+        self.value.set_write(security, write);
+        self
+    }
+
+    /// Set notify support.
+    pub fn with_notify(mut self, notify: bool) -> Self {
+        self.notify = notify;
+        self
+    }
+
+    /// Add descriptor.
+    pub fn with_descriptor(mut self, descriptor: GattDescriptorNode) -> Self {
+        self.descriptors.push(descriptor);
+        self
+    }
+}
+
+/// GATT service of [`BluezGattApplication`].
+#[derive(Clone)]
+pub struct GattServiceNode {
+    /// Service UUID
+    pub uuid: Uuid,
+
+    /// `true` if primary service
+    pub primary: bool,
+
+    /// Characteristics
+    pub characteristics: Vec<GattCharacteristicNode>,
+}
+
+impl GattServiceNode {
+    /// Create primary [`GattServiceNode`] from UUID.
+    pub fn new(uuid: &Uuid) -> Self {
+        Self {
+            uuid: *uuid,
+            primary: true,
+            characteristics: Vec::new(),
+        }
+    }
+
+    /// Set primary / secondary.
+    pub fn with_primary(mut self, primary: bool) -> Self {
+        self.primary = primary;
+        self
+    }
+
+    /// Add characteristic.
+    pub fn with_characteristic(mut self, characteristic: GattCharacteristicNode) -> Self {
+        self.characteristics.push(characteristic);
+        self
+    }
+}
+
+/// Exported object of [`BluezGattApplication`].
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct BluezGattObject {
+    /// Object path
+    pub path: String,
+
+    /// BlueZ interface ([`GATT_SERVICE_INTERFACE`], [`GATT_CHARACTERISTIC_INTERFACE`] or [`GATT_DESCRIPTOR_INTERFACE`])
+    pub interface: &'static str,
+
+    /// `UUID` property
+    pub uuid: Uuid,
+
+    /// `Service` / `Characteristic` property(`None` for services)
+    pub parent: Option<String>,
+
+    /// `Primary` property(`false` for characteristics and descriptors)
+    pub primary: bool,
+
+    /// `Flags` property(empty for services)
+    pub flags: Vec<&'static str>,
+}
+
+/// BlueZ GATT application.
+///
+/// Object paths are `{base_path}/service{n}`, `{base_path}/service{n}/char{m}` and
+/// `{base_path}/service{n}/char{m}/desc{k}`.
+#[derive(Clone)]
+pub struct BluezGattApplication {
+    /// Application object path
+    pub base_path: String,
+
+    /// Services
+    pub services: Vec<GattServiceNode>,
+}
+
+impl BluezGattApplication {
+    /// Create [`BluezGattApplication`] from application object path.
+    pub fn new(base_path: &str) -> Self {
+        Self {
+            base_path: base_path.trim_end_matches('/').to_string(),
+            services: Vec::new(),
+        }
+    }
+
+    /// Add service.
+    pub fn with_service(mut self, service: GattServiceNode) -> Self {
+        self.services.push(service);
+        self
+    }
+
+    /// Objects to export under the application object path (`GetManagedObjects` order).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::sync::{Arc, Mutex};
+    ///
+    /// use ble_data_struct::{
+    ///     characteristics::battery_level::BatteryLevel,
+    ///     gatt::{bluez_application::*, security::SecurityLevel},
+    ///     uuid_from_u16,
+    ///     uuids::service,
+    /// };
+    ///
+    /// let level = Arc::new(Mutex::new(80));
+    /// let read_level = level.clone();
+    /// let application = BluezGattApplication::new("/org/example/app").with_service(
+    ///     GattServiceNode::new(&uuid_from_u16(service::BATTERY)).with_characteristic(
+    ///         GattCharacteristicNode::typed::<BatteryLevel>()
+    ///             .with_read(SecurityLevel::None, move || BatteryLevel::new(*read_level.lock().unwrap()))
+    ///             .with_notify(true),
+    ///     ),
+    /// );
+    ///
+    /// let objects = application.objects();
+    /// assert_eq!("/org/example/app/service0", objects[0].path);
+    /// assert_eq!(GATT_SERVICE_INTERFACE, objects[0].interface);
+    /// assert_eq!("/org/example/app/service0/char0", objects[1].path);
+    /// assert_eq!(vec!["read", "notify"], objects[1].flags);
+    ///
+    /// *level.lock().unwrap() = 50;
+    /// assert_eq!(
+    ///     Ok(vec![50]),
+    ///     application.read_value("/org/example/app/service0/char0", SecurityLevel::None)
+    /// );
+    /// ```
+    pub fn objects(&self) -> Vec<BluezGattObject> {
+        let mut objects: Vec<BluezGattObject> = Vec::new();
+        for (service_path, service) in self.service_paths() {
+            objects.push(BluezGattObject {
+                path: service_path.clone(),
+                interface: GATT_SERVICE_INTERFACE,
+                uuid: service.uuid,
+                parent: None,
+                primary: service.primary,
+                flags: Vec::new(),
+            });
+            for (characteristic_path, characteristic) in
+                Self::characteristic_paths(service_path.clone(), service)
+            {
+                let mut flags = characteristic.value.flags();
+                if characteristic.notify {
+                    flags.push("notify");
+                }
+                objects.push(BluezGattObject {
+                    path: characteristic_path.clone(),
+                    interface: GATT_CHARACTERISTIC_INTERFACE,
+                    uuid: characteristic.uuid,
+                    parent: Some(service_path.clone()),
+                    primary: false,
+                    flags,
+                });
+                for (descriptor_path, descriptor) in
+                    Self::descriptor_paths(characteristic_path.clone(), characteristic)
+                {
+                    objects.push(BluezGattObject {
+                        path: descriptor_path,
+                        interface: GATT_DESCRIPTOR_INTERFACE,
+                        uuid: descriptor.uuid,
+                        parent: Some(characteristic_path.clone()),
+                        primary: false,
+                        flags: descriptor.value.flags(),
+                    });
+                }
+            }
+        }
+        objects
+    }
+
+    /// Handle `ReadValue` of the characteristic / descriptor object.
+    ///
+    /// `Err` is the ATT error code ([`INVALID_HANDLE`] for unknown paths).
+    pub fn read_value(&self, path: &str, current: SecurityLevel) -> Result<Vec<u8>, u8> {
+        self.find_value(path)
+            .ok_or(INVALID_HANDLE)
+            .and_then(|f| f.read(current))
+    }
+
+    /// Handle `WriteValue` of the characteristic / descriptor object.
+    ///
+    /// `Err` is the ATT error code ([`INVALID_HANDLE`] for unknown paths).
+    pub fn write_value(&self, path: &str, value: &[u8], current: SecurityLevel) -> Result<(), u8> {
+        self.find_value(path)
+            .ok_or(INVALID_HANDLE)
+            .and_then(|f| f.write(value, current))
+    }
+
+    /// Find the value of the characteristic / descriptor object.
+    fn find_value(&self, path: &str) -> Option<&GattValue> {
+        for (service_path, service) in self.service_paths() {
+            for (characteristic_path, characteristic) in
+                Self::characteristic_paths(service_path.clone(), service)
+            {
+                if characteristic_path == path {
+                    return Some(&characteristic.value);
+                }
+                for (descriptor_path, descriptor) in
+                    Self::descriptor_paths(characteristic_path.clone(), characteristic)
+                {
+                    if descriptor_path == path {
+                        return Some(&descriptor.value);
+                    }
+                }
+            }
+        }
+        None
+    }
+
+    /// Services with their object paths.
+    fn service_paths(&self) -> impl Iterator<Item = (String, &GattServiceNode)> {
+        self.services
+            .iter()
+            .enumerate()
+            .map(|(i, f)| (format!("{}/service{}", self.base_path, i), f))
+    }
+
+    /// Characteristics with their object paths.
+    fn characteristic_paths(
+        service_path: String,
+        service: &GattServiceNode,
+    ) -> impl Iterator<Item = (String, &GattCharacteristicNode)> {
+        service
+            .characteristics
+            .iter()
+            .enumerate()
+            .map(move |(i, f)| (format!("{}/char{}", service_path, i), f))
+    }
+
+    /// Descriptors with their object paths.
+    fn descriptor_paths(
+        characteristic_path: String,
+        characteristic: &GattCharacteristicNode,
+    ) -> impl Iterator<Item = (String, &GattDescriptorNode)> {
+        characteristic
+            .descriptors
+            .iter()
+            .enumerate()
+            .map(move |(i, f)| (format!("{}/desc{}", characteristic_path, i), f))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::{Arc, Mutex};
+
+    use crate::{
+        characteristics::battery_level::BatteryLevel,
+        descriptors::characteristic_user_description::CharacteristicUserDescription,
+        gatt::{
+            bluez_application::*,
+            security::{INSUFFICIENT_ENCRYPTION, READ_NOT_PERMITTED, WRITE_NOT_PERMITTED},
+        },
+        uuid_from_u16,
+        uuids::service,
+    };
+
+    fn application(level: Arc<Mutex<u8>>) -> BluezGattApplication {
+        let read_level = level.clone();
+        let write_level = level;
+        BluezGattApplication::new("/org/example/app/").with_service(
+            GattServiceNode::new(&uuid_from_u16(service::BATTERY)).with_characteristic(
+                GattCharacteristicNode::typed::<BatteryLevel>()
+                    .with_read(SecurityLevel::None, move || {
+                        BatteryLevel::new(*read_level.lock().unwrap())
+                    })
+                    .with_write(SecurityLevel::Unauthenticated, move |f: BatteryLevel| {
+                        if f.battery_level == 0 {
+                            return Err(0x80);
+                        }
+                        *write_level.lock().unwrap() = f.battery_level;
+                        Ok(())
+                    })
+                    .with_notify(true)
+                    .with_descriptor(
+                        GattDescriptorNode::typed::<CharacteristicUserDescription>()
+                            .with_read(SecurityLevel::None, || {
+                                CharacteristicUserDescription::new("main".to_string())
+                            }),
+                    ),
+            ),
+        )
+    }
+
+    #[test]
+    fn test_objects() {
+        let result = application(Arc::new(Mutex::new(0))).objects();
+        assert_eq!(
+            vec![
+                BluezGattObject {
+                    path: "/org/example/app/service0".to_string(),
+                    interface: GATT_SERVICE_INTERFACE,
+                    uuid: uuid_from_u16(0x180f),
+                    parent: None,
+                    primary: true,
+                    flags: Vec::new(),
+                },
+                BluezGattObject {
+                    path: "/org/example/app/service0/char0".to_string(),
+                    interface: GATT_CHARACTERISTIC_INTERFACE,
+                    uuid: uuid_from_u16(0x2a19),
+                    parent: Some("/org/example/app/service0".to_string()),
+                    primary: false,
+                    flags: vec!["read", "encrypt-write", "notify"],
+                },
+                BluezGattObject {
+                    path: "/org/example/app/service0/char0/desc0".to_string(),
+                    interface: GATT_DESCRIPTOR_INTERFACE,
+                    uuid: uuid_from_u16(0x2901),
+                    parent: Some("/org/example/app/service0/char0".to_string()),
+                    primary: false,
+                    flags: vec!["read"],
+                },
+            ],
+            result
+        );
+    }
+
+    #[test]
+    fn test_read_value() {
+        let level = Arc::new(Mutex::new(30));
+        let result = application(level.clone());
+        assert_eq!(
+            Ok(vec![30]),
+            result.read_value("/org/example/app/service0/char0", SecurityLevel::None)
+        );
+        *level.lock().unwrap() = 31;
+        assert_eq!(
+            Ok(vec![31]),
+            result.read_value("/org/example/app/service0/char0", SecurityLevel::None)
+        );
+        assert_eq!(
+            Ok("main".as_bytes().to_vec()),
+            result.read_value("/org/example/app/service0/char0/desc0", SecurityLevel::None)
+        );
+        assert_eq!(
+            Err(INVALID_HANDLE),
+            result.read_value("/org/example/app/service0", SecurityLevel::None)
+        );
+        assert_eq!(
+            Err(INVALID_HANDLE),
+            result.read_value("/org/example/app/service0/char1", SecurityLevel::None)
+        );
+
+        let result = BluezGattApplication::new("/app").with_service(
+            GattServiceNode::new(&uuid_from_u16(service::BATTERY))
+                .with_characteristic(GattCharacteristicNode::typed::<BatteryLevel>()),
+        );
+        assert_eq!(
+            Err(READ_NOT_PERMITTED),
+            result.read_value("/app/service0/char0", SecurityLevel::ScAuthenticated)
+        );
+    }
+
+    #[test]
+    fn test_write_value() {
+        let level = Arc::new(Mutex::new(30));
+        let result = application(level.clone());
+        let path = "/org/example/app/service0/char0";
+        assert_eq!(
+            Err(INSUFFICIENT_ENCRYPTION),
+            result.write_value(path, &[40], SecurityLevel::None)
+        );
+        assert_eq!(
+            Ok(()),
+            result.write_value(path, &[40], SecurityLevel::Unauthenticated)
+        );
+        assert_eq!(40, *level.lock().unwrap());
+        assert_eq!(
+            Err(0x80),
+            result.write_value(path, &[0], SecurityLevel::Unauthenticated)
+        );
+        assert_eq!(
+            Err(VALUE_NOT_ALLOWED),
+            result.write_value(path, &[101], SecurityLevel::Unauthenticated)
+        );
+        assert_eq!(
+            Err(VALUE_NOT_ALLOWED),
+            result.write_value(path, &[], SecurityLevel::Unauthenticated)
+        );
+        assert_eq!(40, *level.lock().unwrap());
+        assert_eq!(
+            Err(WRITE_NOT_PERMITTED),
+            result.write_value(
+                "/org/example/app/service0/char0/desc0",
+                &[0x61],
+                SecurityLevel::ScAuthenticated
+            )
+        );
+    }
+
+    #[test]
+    fn test_secondary_service() {
+        let result = BluezGattApplication::new("/app").with_service(
+            GattServiceNode::new(&uuid_from_u16(service::BATTERY)).with_primary(false),
+        );
+        assert!(!result.objects()[0].primary);
+    }
+}
@@ -0,0 +1,357 @@
+//! Database Hash input module.
+//!
+//! Attribute concatenation for the Database Hash (Core Specification Vol 3, Part G, 7.3.1).
+
+use uuid::Uuid;
+
+use crate::{
+    descriptors::{
+        characteristic_aggregate_format::CharacteristicAggregateFormat,
+        characteristic_extended_properties::CharacteristicExtendedProperties,
+        characteristic_presentation_format::CharacteristicPresentationFormat,
+        characteristic_user_description::CharacteristicUserDescription,
+        client_characteristic_configuration::ClientCharacteristicConfiguration,
+        server_characteristic_configuration::ServerCharacteristicConfiguration,
+    },
+    gatt::security::{AttributePermissions, SecurityLevel},
+    uuid_from_u16, Uuid16bit,
+};
+
+/// Primary Service declaration.
+pub const PRIMARY_SERVICE: u16 = 0x2800;
+
+/// Secondary Service declaration.
+pub const SECONDARY_SERVICE: u16 = 0x2801;
+
+/// Include declaration.
+pub const INCLUDE: u16 = 0x2802;
+
+/// Characteristic declaration.
+pub const CHARACTERISTIC: u16 = 0x2803;
+
+/// GATT attribute.
+#[derive(Debug, PartialEq, Clone)]
+pub struct GattAttribute {
+    /// Attribute Handle
+    pub handle: u16,
+
+    /// Attribute Type
+    pub attribute_type: Uuid,
+
+    /// Attribute Value
+    pub value: Vec<u8>,
+
+    /// Attribute Permissions
+    pub permissions: AttributePermissions,
+}
+
+impl GattAttribute {
+    /// Create [`GattAttribute`] from `Attribute Handle`, `Attribute Type` and `Attribute Value`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ble_data_struct::gatt::database_hash::{GattAttribute, PRIMARY_SERVICE};
+    /// use ble_data_struct::uuid_from_u16;
+    ///
+    /// let handle = 0x0001;
+    /// let attribute_type = uuid_from_u16(PRIMARY_SERVICE);
+    /// let value = 0x1800u16.to_le_bytes().to_vec();
+    /// let result = GattAttribute::new(handle, &attribute_type, &value);
+    /// assert_eq!(handle, result.handle);
+    /// assert_eq!(attribute_type, result.attribute_type);
+    /// assert_eq!(value, result.value);
+    /// ```
+    pub fn new(handle: u16, attribute_type: &Uuid, value: &[u8]) -> Self {
+        Self {
+            handle,
+            attribute_type: *attribute_type,
+            value: value.to_vec(),
+            permissions: AttributePermissions::default(),
+        }
+    }
+
+    /// Set `Attribute Permissions`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ble_data_struct::gatt::{
+    ///     database_hash::GattAttribute,
+    ///     security::{AttributePermissions, SecurityLevel},
+    /// };
+    /// use ble_data_struct::uuid_from_u16;
+    ///
+    /// let permissions = AttributePermissions::new(Some(SecurityLevel::Authenticated), None);
+    /// let result = GattAttribute::new(0x0003, &uuid_from_u16(0x2a19), &[50])
+    ///     .with_permissions(permissions);
+    /// assert_eq!(permissions, result.permissions);
+    /// ```
+    pub fn with_permissions(mut self, permissions: AttributePermissions) -> Self {
+        self.permissions = permissions;
+        self
+    }
+
+    /// Check read with the link security level.
+    ///
+    /// Returns the ATT error code if the read is rejected.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ble_data_struct::gatt::{
+    ///     database_hash::GattAttribute,
+    ///     security::{AttributePermissions, SecurityLevel, INSUFFICIENT_AUTHENTICATION},
+    /// };
+    /// use ble_data_struct::uuid_from_u16;
+    ///
+    /// let result = GattAttribute::new(0x0003, &uuid_from_u16(0x2a19), &[50])
+    ///     .with_permissions(AttributePermissions::new(Some(SecurityLevel::Authenticated), None));
+    /// assert_eq!(Ok(()), result.check_read(SecurityLevel::Authenticated));
+    /// assert_eq!(
+    ///     Err(INSUFFICIENT_AUTHENTICATION),
+    ///     result.check_read(SecurityLevel::Unauthenticated)
+    /// );
+    /// ```
+    pub fn check_read(&self, current: SecurityLevel) -> Result<(), u8> {
+        self.permissions.check_read(current)
+    }
+
+    /// Check write with the link security level.
+    ///
+    /// Returns the ATT error code if the write is rejected.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ble_data_struct::gatt::{
+    ///     database_hash::GattAttribute,
+    ///     security::{AttributePermissions, SecurityLevel, WRITE_NOT_PERMITTED},
+    /// };
+    /// use ble_data_struct::uuid_from_u16;
+    ///
+    /// let result = GattAttribute::new(0x0003, &uuid_from_u16(0x2a19), &[50])
+    ///     .with_permissions(AttributePermissions::new(Some(SecurityLevel::None), None));
+    /// assert_eq!(Err(WRITE_NOT_PERMITTED), result.check_write(SecurityLevel::ScAuthenticated));
+    /// ```
+    pub fn check_write(&self, current: SecurityLevel) -> Result<(), u8> {
+        self.permissions.check_write(current)
+    }
+}
+
+/// check attribute type included with `Attribute Value`.
+fn is_value_included(attribute_type: &Uuid) -> bool {
+    [
+        PRIMARY_SERVICE,
+        SECONDARY_SERVICE,
+        INCLUDE,
+        CHARACTERISTIC,
+        CharacteristicExtendedProperties::uuid_16bit(),
+    ]
+    .iter()
+    .any(|f| uuid_from_u16(*f) == *attribute_type)
+}
+
+/// check attribute type included without `Attribute Value`.
+fn is_value_excluded(attribute_type: &Uuid) -> bool {
+    [
+        CharacteristicUserDescription::uuid_16bit(),
+        ClientCharacteristicConfiguration::uuid_16bit(),
+        ServerCharacteristicConfiguration::uuid_16bit(),
+        CharacteristicPresentationFormat::uuid_16bit(),
+        CharacteristicAggregateFormat::uuid_16bit(),
+    ]
+    .iter()
+    .any(|f| uuid_from_u16(*f) == *attribute_type)
+}
+
+/// Serialize attributes into the Database Hash input.
+///
+/// Attributes are ordered by handle.
+/// Service, Include, Characteristic declarations and Characteristic Extended Properties are serialized as `handle || type || value`,
+/// other GATT defined descriptors as `handle || type`, and the remaining attributes are excluded.
+///
+/// # Examples
+///
+/// ```
+/// use ble_data_struct::gatt::database_hash::{
+///     database_hash_input, GattAttribute, CHARACTERISTIC, PRIMARY_SERVICE,
+/// };
+/// use ble_data_struct::uuid_from_u16;
+///
+/// let attributes = vec![
+///     GattAttribute::new(0x0002, &uuid_from_u16(CHARACTERISTIC), &[0x02, 0x03, 0x00, 0x00, 0x2a]),
+///     GattAttribute::new(0x0001, &uuid_from_u16(PRIMARY_SERVICE), &[0x00, 0x18]),
+///     GattAttribute::new(0x0003, &uuid_from_u16(0x2a00), &[0x61]),
+///     GattAttribute::new(0x0004, &uuid_from_u16(0x2902), &[0x00, 0x00]),
+/// ];
+/// assert_eq!(
+///     [
+///         0x01, 0x00, 0x00, 0x28, 0x00, 0x18,
+///         0x02, 0x00, 0x03, 0x28, 0x02, 0x03, 0x00, 0x00, 0x2a,
+///         0x04, 0x00, 0x02, 0x29,
+///     ]
+///     .to_vec(),
+///     database_hash_input(&attributes)
+/// );
+/// ```
+pub fn database_hash_input(attributes: &[GattAttribute]) -> Vec<u8> {
+    let mut sorted: Vec<&GattAttribute> = attributes.iter().collect();
+    sorted.sort_by_key(|f| f.handle);
+    let mut data: Vec<u8> = Vec::new();
+    for attribute in sorted {
+        let value_included = is_value_included(&attribute.attribute_type);
+        if !value_included && !is_value_excluded(&attribute.attribute_type) {
+            continue;
+        }
+        data.extend_from_slice(&attribute.handle.to_le_bytes());
+        data.extend_from_slice(&(attribute.attribute_type.as_fields().0 as u16).to_le_bytes());
+        if value_included {
+            data.extend_from_slice(&attribute.value);
+        }
+    }
+    data
+}
+
+/// Compute the Database Hash (AES-CMAC with zero key).
+///
+/// # Examples
+///
+/// ```
+/// use ble_data_struct::gatt::database_hash::{database_hash, GattAttribute, PRIMARY_SERVICE};
+/// use ble_data_struct::uuid_from_u16;
+///
+/// let attributes = vec![GattAttribute::new(
+///     0x0001,
+///     &uuid_from_u16(PRIMARY_SERVICE),
+///     &[0x00, 0x18],
+/// )];
+/// assert_eq!(16, database_hash(&attributes).to_be_bytes().len());
+/// ```
+#[cfg(feature = "crypto")]
+pub fn database_hash(attributes: &[GattAttribute]) -> u128 {
+    crate::crypto::toolbox::aes_cmac(0, &database_hash_input(attributes))
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        gatt::{
+            database_hash::{
+                database_hash_input, GattAttribute, CHARACTERISTIC, INCLUDE, PRIMARY_SERVICE,
+                SECONDARY_SERVICE,
+            },
+            security::{
+                AttributePermissions, SecurityLevel, INSUFFICIENT_AUTHENTICATION,
+                INSUFFICIENT_ENCRYPTION, READ_NOT_PERMITTED,
+            },
+        },
+        uuid_from_u16,
+    };
+
+    fn attributes() -> Vec<GattAttribute> {
+        vec![
+            GattAttribute::new(
+                0x0002,
+                &uuid_from_u16(CHARACTERISTIC),
+                &[0x02, 0x03, 0x00, 0x00, 0x2a],
+            ),
+            GattAttribute::new(
+                0x0001,
+                &uuid_from_u16(PRIMARY_SERVICE),
+                &[0x00, 0x18],
+            ),
+            GattAttribute::new(0x0003, &uuid_from_u16(0x2a00), &[0x61]),
+            GattAttribute::new(0x0004, &uuid_from_u16(0x2902), &[0x00, 0x00]),
+        ]
+    }
+
+    #[test]
+    fn test_new() {
+        let handle = 0x0001;
+        let attribute_type = uuid_from_u16(PRIMARY_SERVICE);
+        let value = 0x1800u16.to_le_bytes().to_vec();
+        let result = GattAttribute::new(handle, &attribute_type, &value);
+        assert_eq!(handle, result.handle);
+        assert_eq!(attribute_type, result.attribute_type);
+        assert_eq!(value, result.value);
+        assert_eq!(AttributePermissions::default(), result.permissions);
+    }
+
+    #[test]
+    fn test_permissions() {
+        let result = GattAttribute::new(0x0003, &uuid_from_u16(0x2a19), &[50])
+            .with_permissions(AttributePermissions::new(
+                Some(SecurityLevel::Unauthenticated),
+                Some(SecurityLevel::ScAuthenticated),
+            ));
+        assert_eq!(
+            Err(INSUFFICIENT_ENCRYPTION),
+            result.check_read(SecurityLevel::None)
+        );
+        assert_eq!(Ok(()), result.check_read(SecurityLevel::Unauthenticated));
+        assert_eq!(
+            Err(INSUFFICIENT_AUTHENTICATION),
+            result.check_write(SecurityLevel::Authenticated)
+        );
+        assert_eq!(Ok(()), result.check_write(SecurityLevel::ScAuthenticated));
+
+        let result = result.with_permissions(AttributePermissions::new(None, None));
+        assert_eq!(
+            Err(READ_NOT_PERMITTED),
+            result.check_read(SecurityLevel::ScAuthenticated)
+        );
+    }
+
+    #[test]
+    fn test_database_hash_input() {
+        assert_eq!(
+            [
+                0x01, 0x00, 0x00, 0x28, 0x00, 0x18, 0x02, 0x00, 0x03, 0x28, 0x02, 0x03, 0x00, 0x00,
+                0x2a, 0x04, 0x00, 0x02, 0x29,
+            ]
+            .to_vec(),
+            database_hash_input(&attributes())
+        );
+
+        let attributes = vec![
+            GattAttribute::new(
+                0x0010,
+                &uuid_from_u16(SECONDARY_SERVICE),
+                &[0x0f, 0x18],
+            ),
+            GattAttribute::new(
+                0x0011,
+                &uuid_from_u16(INCLUDE),
+                &[0x01, 0x00, 0x04, 0x00, 0x00, 0x18],
+            ),
+            GattAttribute::new(0x0012, &uuid_from_u16(0x2900), &[0x01, 0x00]),
+            GattAttribute::new(0x0013, &uuid_from_u16(0x2905), &[0x01, 0x00]),
+        ];
+        assert_eq!(
+            [
+                0x10, 0x00, 0x01, 0x28, 0x0f, 0x18, 0x11, 0x00, 0x02, 0x28, 0x01, 0x00, 0x04, 0x00,
+                0x00, 0x18, 0x12, 0x00, 0x00, 0x29, 0x01, 0x00, 0x13, 0x00, 0x05, 0x29,
+            ]
+            .to_vec(),
+            database_hash_input(&attributes)
+        );
+
+        assert!(database_hash_input(&Vec::new()).is_empty());
+    }
+
+    #[cfg(feature = "crypto")]
+    #[test]
+    fn test_database_hash() {
+        use crate::gatt::database_hash::database_hash;
+
+        assert_eq!(
+            0x2560ca91516c2a22fde73119895ec865u128,
+            database_hash(&attributes())
+        );
+        assert_eq!(
+            0x4387c14b46ef7e176dceefa862d72ff9u128,
+            database_hash(&Vec::new())
+        );
+    }
+}
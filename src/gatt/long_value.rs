@@ -0,0 +1,245 @@
+//! Long attribute value module.
+//!
+//! Reassembly of Read Blob / Prepare Write fragments (Core Specification Vol 3, Part F, 3.4.4.5 / 3.4.6.1).
+
+/// Default ATT_MTU.
+pub const ATT_DEFAULT_MTU: u16 = 23;
+
+/// Maximum length of an attribute value.
+pub const ATTRIBUTE_VALUE_MAX_LENGTH: usize = 512;
+
+/// Long attribute value assembler.
+#[derive(Debug, PartialEq, Clone)]
+pub struct LongValueAssembler {
+    /// Maximum value length
+    pub max_length: usize,
+
+    /// assembled value
+    value: Vec<u8>,
+}
+
+impl LongValueAssembler {
+    /// Create [`LongValueAssembler`] from maximum value length.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ble_data_struct::gatt::long_value::LongValueAssembler;
+    ///
+    /// let result = LongValueAssembler::new(100);
+    /// assert_eq!(100, result.max_length);
+    /// assert!(result.value().is_empty());
+    /// ```
+    pub fn new(max_length: usize) -> Self {
+        Self {
+            max_length,
+            value: Vec::new(),
+        }
+    }
+
+    /// Returns the assembled value.
+    pub fn value(&self) -> &[u8] {
+        &self.value
+    }
+
+    /// Returns the assembled value and clear the assembler.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ble_data_struct::gatt::long_value::LongValueAssembler;
+    ///
+    /// let mut result = LongValueAssembler::default();
+    /// result.add(0, &[1, 2]).unwrap();
+    /// assert_eq!(vec![1, 2], result.take());
+    /// assert!(result.value().is_empty());
+    /// ```
+    pub fn take(&mut self) -> Vec<u8> {
+        std::mem::take(&mut self.value)
+    }
+
+    /// Clear the assembled value.
+    pub fn clear(&mut self) {
+        self.value.clear();
+    }
+
+    /// Add `Value Offset` and `Part Attribute Value` fragment.
+    ///
+    /// `offset` must not be larger than the assembled length. Existing bytes at `offset` are overwritten.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ble_data_struct::gatt::long_value::LongValueAssembler;
+    ///
+    /// let mut result = LongValueAssembler::new(4);
+    /// assert!(result.add(0, &[1, 2]).is_ok());
+    /// assert!(result.add(2, &[3, 4]).is_ok());
+    /// assert_eq!(&[1, 2, 3, 4], result.value());
+    ///
+    /// assert_eq!(Err("Invalid offset :5".to_string()), result.add(5, &[5]));
+    /// assert_eq!(Err("Invalid data size :5".to_string()), result.add(4, &[5]));
+    /// ```
+    pub fn add(&mut self, offset: usize, chunk: &[u8]) -> Result<(), String> {
+        if offset > self.value.len() {
+            return Err(format!("Invalid offset :{}", offset));
+        }
+        let end = offset + chunk.len();
+        if end > self.max_length {
+            return Err(format!("Invalid data size :{}", end));
+        }
+        if end > self.value.len() {
+            self.value.resize(end, 0);
+        }
+        self.value[offset..end].copy_from_slice(chunk);
+        Ok(())
+    }
+}
+
+impl Default for LongValueAssembler {
+    /// Create [`LongValueAssembler`] with [`ATTRIBUTE_VALUE_MAX_LENGTH`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ble_data_struct::gatt::long_value::{LongValueAssembler, ATTRIBUTE_VALUE_MAX_LENGTH};
+    ///
+    /// assert_eq!(ATTRIBUTE_VALUE_MAX_LENGTH, LongValueAssembler::default().max_length);
+    /// ```
+    fn default() -> Self {
+        Self::new(ATTRIBUTE_VALUE_MAX_LENGTH)
+    }
+}
+
+/// Check the Read Blob Response is the last fragment.
+///
+/// The value is complete when the `Part Attribute Value` is shorter than `ATT_MTU - 1`.
+///
+/// # Examples
+///
+/// ```
+/// use ble_data_struct::gatt::long_value::{is_last_read_blob, ATT_DEFAULT_MTU};
+///
+/// assert!(!is_last_read_blob(22, ATT_DEFAULT_MTU));
+/// assert!(is_last_read_blob(21, ATT_DEFAULT_MTU));
+/// ```
+pub fn is_last_read_blob(chunk_length: usize, mtu: u16) -> bool {
+    chunk_length < (mtu as usize).saturating_sub(1)
+}
+
+/// Split the value into `Value Offset` and `Part Attribute Value` pairs for Prepare Write Requests.
+///
+/// Each fragment carries at most `ATT_MTU - 5` bytes.
+///
+/// # Examples
+///
+/// ```
+/// use ble_data_struct::gatt::long_value::{prepare_write_chunks, ATT_DEFAULT_MTU};
+///
+/// let value = [0u8; 40];
+/// let result = prepare_write_chunks(&value, ATT_DEFAULT_MTU).unwrap();
+/// assert_eq!(3, result.len());
+/// assert_eq!((0, &value[0..18]), result[0]);
+/// assert_eq!((18, &value[18..36]), result[1]);
+/// assert_eq!((36, &value[36..40]), result[2]);
+///
+/// assert!(prepare_write_chunks(&value, 22).is_err());
+/// ```
+pub fn prepare_write_chunks(value: &[u8], mtu: u16) -> Result<Vec<(u16, &[u8])>, String> {
+    if mtu < ATT_DEFAULT_MTU {
+        return Err(format!("Invalid mtu :{}", mtu));
+    }
+    if value.len() > ATTRIBUTE_VALUE_MAX_LENGTH {
+        return Err(format!("Invalid data size :{}", value.len()));
+    }
+    Ok(value
+        .chunks(mtu as usize - 5)
+        .enumerate()
+        .map(|(index, chunk)| ((index * (mtu as usize - 5)) as u16, chunk))
+        .collect())
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::gatt::long_value::{
+        is_last_read_blob, prepare_write_chunks, LongValueAssembler, ATTRIBUTE_VALUE_MAX_LENGTH,
+        ATT_DEFAULT_MTU,
+    };
+
+    #[test]
+    fn test_new() {
+        let result = LongValueAssembler::new(100);
+        assert_eq!(100, result.max_length);
+        assert!(result.value().is_empty());
+        assert_eq!(
+            ATTRIBUTE_VALUE_MAX_LENGTH,
+            LongValueAssembler::default().max_length
+        );
+    }
+
+    #[test]
+    fn test_add() {
+        let mut result = LongValueAssembler::new(6);
+        assert!(result.add(0, &[1, 2, 3]).is_ok());
+        assert!(result.add(3, &[4, 5]).is_ok());
+        assert_eq!(&[1, 2, 3, 4, 5], result.value());
+
+        // overwrite (prepared write to the same offset)
+        assert!(result.add(1, &[0x12]).is_ok());
+        assert_eq!(&[1, 0x12, 3, 4, 5], result.value());
+
+        assert_eq!(Err("Invalid offset :6".to_string()), result.add(6, &[6]));
+        assert_eq!(
+            Err("Invalid data size :7".to_string()),
+            result.add(5, &[6, 7])
+        );
+        assert!(result.add(5, &[]).is_ok());
+        assert_eq!(&[1, 0x12, 3, 4, 5], result.value());
+    }
+
+    #[test]
+    fn test_take() {
+        let mut result = LongValueAssembler::default();
+        result.add(0, &[1, 2]).unwrap();
+        assert_eq!(vec![1, 2], result.take());
+        assert!(result.value().is_empty());
+
+        result.add(0, &[3]).unwrap();
+        result.clear();
+        assert!(result.value().is_empty());
+    }
+
+    #[test]
+    fn test_is_last_read_blob() {
+        assert!(!is_last_read_blob(22, ATT_DEFAULT_MTU));
+        assert!(is_last_read_blob(21, ATT_DEFAULT_MTU));
+        assert!(is_last_read_blob(0, ATT_DEFAULT_MTU));
+        assert!(!is_last_read_blob(246, 247));
+    }
+
+    #[test]
+    fn test_prepare_write_chunks() {
+        let value: Vec<u8> = (0..40).collect();
+        let chunks = prepare_write_chunks(&value, ATT_DEFAULT_MTU).unwrap();
+        assert_eq!(3, chunks.len());
+
+        let mut result = LongValueAssembler::default();
+        for (offset, chunk) in chunks {
+            assert!(result.add(offset as usize, chunk).is_ok());
+        }
+        assert_eq!(value.as_slice(), result.value());
+
+        assert!(prepare_write_chunks(&[], ATT_DEFAULT_MTU)
+            .unwrap()
+            .is_empty());
+        assert_eq!(1, prepare_write_chunks(&value, 247).unwrap().len());
+        assert_eq!(
+            Err("Invalid mtu :22".to_string()),
+            prepare_write_chunks(&value, 22)
+        );
+        assert_eq!(
+            Err("Invalid data size :513".to_string()),
+            prepare_write_chunks(&[0u8; 513], ATT_DEFAULT_MTU)
+        );
+    }
+}
@@ -0,0 +1,535 @@
+//! Read By Type / Read By Group Type response module.
+//!
+//! Splits the `Attribute Data List` (Core Specification Vol 3, Part F, 3.4.4.2 / 3.4.4.10) into handle / value pairs.
+
+use uuid::Uuid;
+
+use crate::descriptors::descriptor_parser::DescriptorParseResult;
+
+/// ATT_READ_BY_TYPE_RSP opcode.
+pub const ATT_READ_BY_TYPE_RSP: u8 = 0x09;
+
+/// ATT_READ_BY_GROUP_TYPE_RSP opcode.
+pub const ATT_READ_BY_GROUP_TYPE_RSP: u8 = 0x11;
+
+/// Read By Type response attribute data.
+#[derive(Debug, PartialEq, Clone)]
+pub struct AttributeData {
+    /// Attribute Handle
+    pub handle: u16,
+
+    /// Attribute Value
+    pub value: Vec<u8>,
+}
+
+/// Read By Group Type response attribute data.
+#[derive(Debug, PartialEq, Clone)]
+pub struct GroupAttributeData {
+    /// Attribute Handle
+    pub handle: u16,
+
+    /// End Group Handle
+    pub end_group_handle: u16,
+
+    /// Attribute Value
+    pub value: Vec<u8>,
+}
+
+/// ATT_READ_BY_TYPE_RSP.
+#[derive(Debug, PartialEq, Clone)]
+pub struct ReadByTypeResponse {
+    /// Attribute Data List
+    pub attribute_data_list: Vec<AttributeData>,
+}
+
+/// ATT_READ_BY_GROUP_TYPE_RSP.
+#[derive(Debug, PartialEq, Clone)]
+pub struct ReadByGroupTypeResponse {
+    /// Attribute Data List
+    pub attribute_data_list: Vec<GroupAttributeData>,
+}
+
+/// Split `Length` and `Attribute Data List` into fixed size entries.
+///
+/// Every entry must have the same size and be at least `header` octets long.
+fn split_attribute_data_list(
+    value: &[u8],
+    opcode: u8,
+    header: usize,
+) -> Result<Vec<&[u8]>, String> {
+    let len = value.len();
+    if len < 2 {
+        return Err(format!("Invalid data size :{}", len));
+    }
+    if value[0] != opcode {
+        return Err(format!("Invalid opcode :{}", value[0]));
+    }
+    let length = usize::from(value[1]);
+    if length < header {
+        return Err(format!("Invalid length :{}", length));
+    }
+    let list = &value[2..];
+    if list.is_empty() || !list.len().is_multiple_of(length) {
+        return Err(format!("Invalid data size :{}", len));
+    }
+    Ok(list.chunks(length).collect())
+}
+
+/// Join entries with `Length`. All entries must have the same size.
+fn join_attribute_data_list(opcode: u8, entries: Vec<Vec<u8>>) -> Vec<u8> {
+    let mut data: Vec<u8> = Vec::new();
+    data.push(opcode);
+    data.push(entries.first().map_or(0, |f| f.len() as u8));
+    entries.into_iter().for_each(|mut f| data.append(&mut f));
+    return data;
+}
+
+impl AttributeData {
+    /// Create [`AttributeData`] from `Attribute Handle` and `Attribute Value`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ble_data_struct::gatt::read_by_type::AttributeData;
+    ///
+    /// let result = AttributeData::new(0x0003, &[0x01, 0x00]);
+    /// assert_eq!(0x0003, result.handle);
+    /// assert_eq!(vec![0x01, 0x00], result.value);
+    /// ```
+    pub fn new(handle: u16, value: &[u8]) -> Self {
+        Self {
+            handle,
+            value: value.to_vec(),
+        }
+    }
+}
+
+impl GroupAttributeData {
+    /// Create [`GroupAttributeData`] from `Attribute Handle`, `End Group Handle` and `Attribute Value`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ble_data_struct::gatt::read_by_type::GroupAttributeData;
+    ///
+    /// let result = GroupAttributeData::new(0x0001, 0x0005, &[0x0f, 0x18]);
+    /// assert_eq!(0x0001, result.handle);
+    /// assert_eq!(0x0005, result.end_group_handle);
+    /// assert_eq!(vec![0x0f, 0x18], result.value);
+    /// ```
+    pub fn new(handle: u16, end_group_handle: u16, value: &[u8]) -> Self {
+        Self {
+            handle,
+            end_group_handle,
+            value: value.to_vec(),
+        }
+    }
+}
+
+impl ReadByTypeResponse {
+    /// Create [`ReadByTypeResponse`] from `Attribute Data List`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ble_data_struct::gatt::read_by_type::{AttributeData, ReadByTypeResponse};
+    ///
+    /// let list = vec![AttributeData::new(0x0003, &[0x01, 0x00])];
+    /// let result = ReadByTypeResponse::new(&list);
+    /// assert_eq!(list, result.attribute_data_list);
+    /// ```
+    pub fn new(attribute_data_list: &[AttributeData]) -> Self {
+        Self {
+            attribute_data_list: attribute_data_list.to_vec(),
+        }
+    }
+
+    /// Parse each `Attribute Value` as a characteristic value.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ble_data_struct::{
+    ///     characteristics::battery_level::BatteryLevel,
+    ///     gatt::read_by_type::ReadByTypeResponse,
+    /// };
+    ///
+    /// let data: Vec<u8> = vec![0x09, 0x03, 0x03, 0x00, 50, 0x07, 0x00, 101];
+    /// let result = ReadByTypeResponse::try_from(&data).unwrap();
+    /// assert_eq!(
+    ///     vec![
+    ///         (0x0003, Ok(BatteryLevel::new(50))),
+    ///         (0x0007, Err("Invalid battery level :101".to_string())),
+    ///     ],
+    ///     result.characteristics::<BatteryLevel>()
+    /// );
+    /// ```
+    pub fn characteristics<T>(&self) -> Vec<(u16, Result<T, String>)>
+    where
+        T: for<'a> TryFrom<&'a Vec<u8>, Error = String>,
+    {
+        self.attribute_data_list
+            .iter()
+            .map(|f| (f.handle, T::try_from(&f.value)))
+            .collect()
+    }
+
+    /// Parse each `Attribute Value` with the descriptor dispatcher.
+    ///
+    /// `uuid` is the attribute type of the Read By Type request.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ble_data_struct::{
+    ///     descriptors::{
+    ///         client_characteristic_configuration::{ClientCharacteristicConfiguration, NOTIFICATION},
+    ///         descriptor_parser::DescriptorParseResult,
+    ///     },
+    ///     gatt::read_by_type::ReadByTypeResponse,
+    ///     uuid_from_u16, Uuid16bit,
+    /// };
+    ///
+    /// let data: Vec<u8> = vec![0x09, 0x04, 0x04, 0x00, 0x01, 0x00];
+    /// let result = ReadByTypeResponse::try_from(&data).unwrap();
+    /// let uuid = uuid_from_u16(ClientCharacteristicConfiguration::uuid_16bit());
+    /// assert_eq!(
+    ///     vec![(
+    ///         0x0004,
+    ///         DescriptorParseResult::ClientCharacteristicConfigurationResult(Ok(
+    ///             ClientCharacteristicConfiguration::new(NOTIFICATION)
+    ///         ))
+    ///     )],
+    ///     result.descriptors(&uuid)
+    /// );
+    /// ```
+    pub fn descriptors(&self, uuid: &Uuid) -> Vec<(u16, DescriptorParseResult)> {
+        self.attribute_data_list
+            .iter()
+            .map(|f| (f.handle, DescriptorParseResult::from(uuid, &f.value)))
+            .collect()
+    }
+}
+
+impl TryFrom<&Vec<u8>> for ReadByTypeResponse {
+    type Error = String;
+    /// Create [`ReadByTypeResponse`] from [`Vec<u8>`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ble_data_struct::gatt::read_by_type::{AttributeData, ReadByTypeResponse};
+    ///
+    /// let data: Vec<u8> = vec![0x09, 0x04, 0x03, 0x00, 0x01, 0x00, 0x07, 0x00, 0x02, 0x00];
+    /// let result = ReadByTypeResponse::try_from(&data);
+    /// assert_eq!(
+    ///     Ok(ReadByTypeResponse::new(&[
+    ///         AttributeData::new(0x0003, &[0x01, 0x00]),
+    ///         AttributeData::new(0x0007, &[0x02, 0x00]),
+    ///     ])),
+    ///     result
+    /// );
+    ///
+    /// let data: Vec<u8> = vec![0x09, 0x04, 0x03, 0x00, 0x01];
+    /// assert_eq!(
+    ///     Err("Invalid data size :5".to_string()),
+    ///     ReadByTypeResponse::try_from(&data)
+    /// );
+    /// ```
+    fn try_from(value: &Vec<u8>) -> Result<Self, String> {
+        Ok(Self {
+            attribute_data_list: split_attribute_data_list(value, ATT_READ_BY_TYPE_RSP, 2)?
+                .into_iter()
+                .map(|f| AttributeData {
+                    handle: u16::from_le_bytes(f[0..2].try_into().unwrap()),
+                    value: f[2..].to_vec(),
+                })
+                .collect(),
+        })
+    }
+}
+
+impl Into<Vec<u8>> for ReadByTypeResponse {
+    /// Create [`Vec<u8>`] from [`ReadByTypeResponse`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ble_data_struct::gatt::read_by_type::{AttributeData, ReadByTypeResponse};
+    ///
+    /// let result = ReadByTypeResponse::new(&[AttributeData::new(0x0003, &[0x01, 0x00])]);
+    /// let into_data: Vec<u8> = result.into();
+    /// assert_eq!(vec![0x09, 0x04, 0x03, 0x00, 0x01, 0x00], into_data);
+    /// ```
+    fn into(self) -> Vec<u8> {
+        join_attribute_data_list(
+            ATT_READ_BY_TYPE_RSP,
+            self.attribute_data_list
+                .into_iter()
+                .map(|mut f| {
+                    let mut data = f.handle.to_le_bytes().to_vec();
+                    data.append(&mut f.value);
+                    data
+                })
+                .collect(),
+        )
+    }
+}
+
+impl ReadByGroupTypeResponse {
+    /// Create [`ReadByGroupTypeResponse`] from `Attribute Data List`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ble_data_struct::gatt::read_by_type::{GroupAttributeData, ReadByGroupTypeResponse};
+    ///
+    /// let list = vec![GroupAttributeData::new(0x0001, 0x0005, &[0x0f, 0x18])];
+    /// let result = ReadByGroupTypeResponse::new(&list);
+    /// assert_eq!(list, result.attribute_data_list);
+    /// ```
+    pub fn new(attribute_data_list: &[GroupAttributeData]) -> Self {
+        Self {
+            attribute_data_list: attribute_data_list.to_vec(),
+        }
+    }
+}
+
+impl TryFrom<&Vec<u8>> for ReadByGroupTypeResponse {
+    type Error = String;
+    /// Create [`ReadByGroupTypeResponse`] from [`Vec<u8>`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ble_data_struct::gatt::read_by_type::{GroupAttributeData, ReadByGroupTypeResponse};
+    ///
+    /// let data: Vec<u8> = vec![0x11, 0x06, 0x01, 0x00, 0x05, 0x00, 0x0f, 0x18];
+    /// let result = ReadByGroupTypeResponse::try_from(&data);
+    /// assert_eq!(
+    ///     Ok(ReadByGroupTypeResponse::new(&[GroupAttributeData::new(
+    ///         0x0001,
+    ///         0x0005,
+    ///         &[0x0f, 0x18]
+    ///     )])),
+    ///     result
+    /// );
+    ///
+    /// let data: Vec<u8> = vec![0x11, 0x03, 0x01, 0x00, 0x05];
+    /// assert_eq!(
+    ///     Err("Invalid length :3".to_string()),
+    ///     ReadByGroupTypeResponse::try_from(&data)
+    /// );
+    /// ```
+    fn try_from(value: &Vec<u8>) -> Result<Self, String> {
+        Ok(Self {
+            attribute_data_list: split_attribute_data_list(value, ATT_READ_BY_GROUP_TYPE_RSP, 4)?
+                .into_iter()
+                .map(|f| GroupAttributeData {
+                    handle: u16::from_le_bytes(f[0..2].try_into().unwrap()),
+                    end_group_handle: u16::from_le_bytes(f[2..4].try_into().unwrap()),
+                    value: f[4..].to_vec(),
+                })
+                .collect(),
+        })
+    }
+}
+
+impl Into<Vec<u8>> for ReadByGroupTypeResponse {
+    /// Create [`Vec<u8>`] from [`ReadByGroupTypeResponse`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ble_data_struct::gatt::read_by_type::{GroupAttributeData, ReadByGroupTypeResponse};
+    ///
+    /// let result = ReadByGroupTypeResponse::new(&[GroupAttributeData::new(
+    ///     0x0001,
+    ///     0x0005,
+    ///     &[0x0f, 0x18],
+    /// )]);
+    /// let into_data: Vec<u8> = result.into();
+    /// assert_eq!(vec![0x11, 0x06, 0x01, 0x00, 0x05, 0x00, 0x0f, 0x18], into_data);
+    /// ```
+    fn into(self) -> Vec<u8> {
+        join_attribute_data_list(
+            ATT_READ_BY_GROUP_TYPE_RSP,
+            self.attribute_data_list
+                .into_iter()
+                .map(|mut f| {
+                    let mut data = f.handle.to_le_bytes().to_vec();
+                    data.append(&mut f.end_group_handle.to_le_bytes().to_vec());
+                    data.append(&mut f.value);
+                    data
+                })
+                .collect(),
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        characteristics::battery_level::BatteryLevel,
+        descriptors::{
+            client_characteristic_configuration::{
+                ClientCharacteristicConfiguration, INDICATION, NOTIFICATION,
+            },
+            descriptor_parser::DescriptorParseResult,
+        },
+        gatt::read_by_type::*,
+        uuid_from_u16, Uuid16bit,
+    };
+
+    #[test]
+    fn test_read_by_type_try_from() {
+        let data: Vec<u8> = vec![0x09, 0x03, 0x03, 0x00, 0x01, 0x07, 0x00, 0x02];
+        assert_eq!(
+            Ok(ReadByTypeResponse::new(&[
+                AttributeData::new(0x0003, &[0x01]),
+                AttributeData::new(0x0007, &[0x02]),
+            ])),
+            ReadByTypeResponse::try_from(&data)
+        );
+
+        let data: Vec<u8> = vec![0x09, 0x02, 0x03, 0x00, 0x07, 0x00];
+        assert_eq!(
+            Ok(ReadByTypeResponse::new(&[
+                AttributeData::new(0x0003, &[]),
+                AttributeData::new(0x0007, &[]),
+            ])),
+            ReadByTypeResponse::try_from(&data)
+        );
+
+        let data: Vec<u8> = vec![0x09];
+        assert_eq!(
+            Err("Invalid data size :1".to_string()),
+            ReadByTypeResponse::try_from(&data)
+        );
+
+        let data: Vec<u8> = vec![0x09, 0x04];
+        assert_eq!(
+            Err("Invalid data size :2".to_string()),
+            ReadByTypeResponse::try_from(&data)
+        );
+
+        let data: Vec<u8> = vec![0x09, 0x01, 0x03];
+        assert_eq!(
+            Err("Invalid length :1".to_string()),
+            ReadByTypeResponse::try_from(&data)
+        );
+
+        let data: Vec<u8> = vec![0x09, 0x03, 0x03, 0x00, 0x01, 0x07, 0x00];
+        assert_eq!(
+            Err("Invalid data size :7".to_string()),
+            ReadByTypeResponse::try_from(&data)
+        );
+
+        let data: Vec<u8> = vec![0x11, 0x03, 0x03, 0x00, 0x01];
+        assert_eq!(
+            Err("Invalid opcode :17".to_string()),
+            ReadByTypeResponse::try_from(&data)
+        );
+    }
+
+    #[test]
+    fn test_read_by_type_into() {
+        let result = ReadByTypeResponse::new(&[
+            AttributeData::new(0x0003, &[0x01, 0x00]),
+            AttributeData::new(0x0107, &[0x02, 0x00]),
+        ]);
+        let data: Vec<u8> = vec![0x09, 0x04, 0x03, 0x00, 0x01, 0x00, 0x07, 0x01, 0x02, 0x00];
+        let into_data: Vec<u8> = result.clone().into();
+        assert_eq!(data, into_data);
+        assert_eq!(Ok(result), ReadByTypeResponse::try_from(&data));
+    }
+
+    #[test]
+    fn test_read_by_type_characteristics() {
+        let data: Vec<u8> = vec![0x09, 0x03, 0x03, 0x00, 50, 0x07, 0x00, 100];
+        let result = ReadByTypeResponse::try_from(&data).unwrap();
+        assert_eq!(
+            vec![
+                (0x0003, Ok(BatteryLevel::new(50))),
+                (0x0007, Ok(BatteryLevel::new(100))),
+            ],
+            result.characteristics::<BatteryLevel>()
+        );
+    }
+
+    #[test]
+    fn test_read_by_type_descriptors() {
+        let data: Vec<u8> = vec![0x09, 0x04, 0x04, 0x00, 0x01, 0x00, 0x08, 0x00, 0x02, 0x00];
+        let result = ReadByTypeResponse::try_from(&data).unwrap();
+        let uuid = uuid_from_u16(ClientCharacteristicConfiguration::uuid_16bit());
+        assert_eq!(
+            vec![
+                (
+                    0x0004,
+                    DescriptorParseResult::ClientCharacteristicConfigurationResult(Ok(
+                        ClientCharacteristicConfiguration::new(NOTIFICATION)
+                    ))
+                ),
+                (
+                    0x0008,
+                    DescriptorParseResult::ClientCharacteristicConfigurationResult(Ok(
+                        ClientCharacteristicConfiguration::new(INDICATION)
+                    ))
+                ),
+            ],
+            result.descriptors(&uuid)
+        );
+
+        let result = result.descriptors(&uuid_from_u16(0x2a00));
+        assert!(matches!(
+            result[0].1,
+            DescriptorParseResult::DescriptorParseError(_)
+        ));
+    }
+
+    #[test]
+    fn test_read_by_group_type_try_from() {
+        let data: Vec<u8> = vec![
+            0x11, 0x06, 0x01, 0x00, 0x05, 0x00, 0x00, 0x18, 0x06, 0x00, 0x09, 0x00, 0x0f, 0x18,
+        ];
+        assert_eq!(
+            Ok(ReadByGroupTypeResponse::new(&[
+                GroupAttributeData::new(0x0001, 0x0005, &[0x00, 0x18]),
+                GroupAttributeData::new(0x0006, 0x0009, &[0x0f, 0x18]),
+            ])),
+            ReadByGroupTypeResponse::try_from(&data)
+        );
+
+        let data: Vec<u8> = vec![0x11, 0x03, 0x01, 0x00, 0x05];
+        assert_eq!(
+            Err("Invalid length :3".to_string()),
+            ReadByGroupTypeResponse::try_from(&data)
+        );
+
+        let data: Vec<u8> = vec![0x11, 0x06, 0x01, 0x00, 0x05, 0x00, 0x00];
+        assert_eq!(
+            Err("Invalid data size :7".to_string()),
+            ReadByGroupTypeResponse::try_from(&data)
+        );
+
+        let data: Vec<u8> = vec![0x09, 0x06, 0x01, 0x00, 0x05, 0x00, 0x00, 0x18];
+        assert_eq!(
+            Err("Invalid opcode :9".to_string()),
+            ReadByGroupTypeResponse::try_from(&data)
+        );
+    }
+
+    #[test]
+    fn test_read_by_group_type_into() {
+        let result = ReadByGroupTypeResponse::new(&[
+            GroupAttributeData::new(0x0001, 0x0005, &[0x00, 0x18]),
+            GroupAttributeData::new(0x0006, 0x0009, &[0x0f, 0x18]),
+        ]);
+        let data: Vec<u8> = vec![
+            0x11, 0x06, 0x01, 0x00, 0x05, 0x00, 0x00, 0x18, 0x06, 0x00, 0x09, 0x00, 0x0f, 0x18,
+        ];
+        let into_data: Vec<u8> = result.clone().into();
+        assert_eq!(data, into_data);
+        assert_eq!(Ok(result), ReadByGroupTypeResponse::try_from(&data));
+    }
+}
@@ -0,0 +1,281 @@
+//! Attribute permission module.
+//!
+//! Security requirements of attributes and the ATT error returned when the link security is not sufficient
+//! (Core Specification Vol 3, Part C, 10.3 / Part F, 3.4.1.1).
+
+/// ATT error: Read Not Permitted
+pub const READ_NOT_PERMITTED: u8 = 0x02;
+
+/// ATT error: Write Not Permitted
+pub const WRITE_NOT_PERMITTED: u8 = 0x03;
+
+/// ATT error: Insufficient Authentication
+pub const INSUFFICIENT_AUTHENTICATION: u8 = 0x05;
+
+/// ATT error: Insufficient Encryption
+pub const INSUFFICIENT_ENCRYPTION: u8 = 0x0f;
+
+/// Link security level (LE Security Mode 1).
+#[derive(Debug, PartialEq, Eq, PartialOrd, Ord, Clone, Copy, Hash, Default)]
+pub enum SecurityLevel {
+    /// No security (Level 1).
+    #[default]
+    None,
+
+    /// Unauthenticated pairing with encryption (Level 2).
+    Unauthenticated,
+
+    /// Authenticated pairing with encryption (Level 3).
+    Authenticated,
+
+    /// Authenticated LE Secure Connections pairing with encryption (Level 4).
+    ScAuthenticated,
+}
+
+impl SecurityLevel {
+    /// Create [`SecurityLevel`] from pairing result.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ble_data_struct::gatt::security::SecurityLevel;
+    ///
+    /// assert_eq!(SecurityLevel::None, SecurityLevel::from_pairing(false, false, false));
+    /// assert_eq!(SecurityLevel::Unauthenticated, SecurityLevel::from_pairing(true, false, true));
+    /// assert_eq!(SecurityLevel::Authenticated, SecurityLevel::from_pairing(true, true, false));
+    /// assert_eq!(SecurityLevel::ScAuthenticated, SecurityLevel::from_pairing(true, true, true));
+    /// ```
+    pub const fn from_pairing(
+        encrypted: bool,
+        authenticated: bool,
+        secure_connections: bool,
+    ) -> Self {
+        match (encrypted, authenticated, secure_connections) {
+            (false, _, _) => Self::None,
+            (true, false, _) => Self::Unauthenticated,
+            (true, true, false) => Self::Authenticated,
+            (true, true, true) => Self::ScAuthenticated,
+        }
+    }
+
+    /// check the link is encrypted.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ble_data_struct::gatt::security::SecurityLevel;
+    ///
+    /// assert!(!SecurityLevel::None.is_encrypted());
+    /// assert!(SecurityLevel::Unauthenticated.is_encrypted());
+    /// ```
+    pub const fn is_encrypted(&self) -> bool {
+        !matches!(self, Self::None)
+    }
+
+    /// Check `self` satisfies `required`.
+    ///
+    /// Returns [`INSUFFICIENT_ENCRYPTION`] if the link is not encrypted and only encryption is required,
+    /// [`INSUFFICIENT_AUTHENTICATION`] otherwise.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ble_data_struct::gatt::security::*;
+    ///
+    /// assert_eq!(Ok(()), SecurityLevel::Authenticated.check(SecurityLevel::Unauthenticated));
+    /// assert_eq!(
+    ///     Err(INSUFFICIENT_ENCRYPTION),
+    ///     SecurityLevel::None.check(SecurityLevel::Unauthenticated)
+    /// );
+    /// assert_eq!(
+    ///     Err(INSUFFICIENT_AUTHENTICATION),
+    ///     SecurityLevel::Unauthenticated.check(SecurityLevel::Authenticated)
+    /// );
+    /// ```
+    pub fn check(&self, required: SecurityLevel) -> Result<(), u8> {
+        if *self >= required {
+            Ok(())
+        } else if !self.is_encrypted() && required == SecurityLevel::Unauthenticated {
+            Err(INSUFFICIENT_ENCRYPTION)
+        } else {
+            Err(INSUFFICIENT_AUTHENTICATION)
+        }
+    }
+}
+
+/// Attribute permissions.
+///
+/// [`None`] means the operation is not permitted.
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Hash)]
+pub struct AttributePermissions {
+    /// Required security level for read
+    pub read: Option<SecurityLevel>,
+
+    /// Required security level for write
+    pub write: Option<SecurityLevel>,
+}
+
+impl AttributePermissions {
+    /// Create [`AttributePermissions`] from read / write security levels.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ble_data_struct::gatt::security::{AttributePermissions, SecurityLevel};
+    ///
+    /// let result = AttributePermissions::new(Some(SecurityLevel::None), None);
+    /// assert_eq!(Some(SecurityLevel::None), result.read);
+    /// assert_eq!(None, result.write);
+    /// ```
+    pub fn new(read: Option<SecurityLevel>, write: Option<SecurityLevel>) -> Self {
+        Self { read, write }
+    }
+
+    /// Check read with the link security level.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ble_data_struct::gatt::security::*;
+    ///
+    /// let result = AttributePermissions::new(Some(SecurityLevel::Authenticated), None);
+    /// assert_eq!(Ok(()), result.check_read(SecurityLevel::ScAuthenticated));
+    /// assert_eq!(Err(INSUFFICIENT_AUTHENTICATION), result.check_read(SecurityLevel::None));
+    ///
+    /// let result = AttributePermissions::new(None, Some(SecurityLevel::None));
+    /// assert_eq!(Err(READ_NOT_PERMITTED), result.check_read(SecurityLevel::ScAuthenticated));
+    /// ```
+    pub fn check_read(&self, current: SecurityLevel) -> Result<(), u8> {
+        match self.read {
+            Some(required) => current.check(required),
+            None => Err(READ_NOT_PERMITTED),
+        }
+    }
+
+    /// Check write with the link security level.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ble_data_struct::gatt::security::*;
+    ///
+    /// let result = AttributePermissions::new(None, Some(SecurityLevel::Unauthenticated));
+    /// assert_eq!(Ok(()), result.check_write(SecurityLevel::Unauthenticated));
+    /// assert_eq!(Err(INSUFFICIENT_ENCRYPTION), result.check_write(SecurityLevel::None));
+    ///
+    /// let result = AttributePermissions::new(Some(SecurityLevel::None), None);
+    /// assert_eq!(Err(WRITE_NOT_PERMITTED), result.check_write(SecurityLevel::ScAuthenticated));
+    /// ```
+    pub fn check_write(&self, current: SecurityLevel) -> Result<(), u8> {
+        match self.write {
+            Some(required) => current.check(required),
+            None => Err(WRITE_NOT_PERMITTED),
+        }
+    }
+}
+
+impl Default for AttributePermissions {
+    /// Create [`AttributePermissions`] readable and writable without security.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ble_data_struct::gatt::security::{AttributePermissions, SecurityLevel};
+    ///
+    /// let result = AttributePermissions::default();
+    /// assert_eq!(Some(SecurityLevel::None), result.read);
+    /// assert_eq!(Some(SecurityLevel::None), result.write);
+    /// ```
+    fn default() -> Self {
+        Self::new(Some(SecurityLevel::None), Some(SecurityLevel::None))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::gatt::security::*;
+
+    #[test]
+    fn test_security_level_order() {
+        assert!(SecurityLevel::None < SecurityLevel::Unauthenticated);
+        assert!(SecurityLevel::Unauthenticated < SecurityLevel::Authenticated);
+        assert!(SecurityLevel::Authenticated < SecurityLevel::ScAuthenticated);
+        assert_eq!(SecurityLevel::None, SecurityLevel::default());
+    }
+
+    #[test]
+    fn test_from_pairing() {
+        assert_eq!(
+            SecurityLevel::None,
+            SecurityLevel::from_pairing(false, true, true)
+        );
+        assert_eq!(
+            SecurityLevel::Unauthenticated,
+            SecurityLevel::from_pairing(true, false, false)
+        );
+        assert_eq!(
+            SecurityLevel::Authenticated,
+            SecurityLevel::from_pairing(true, true, false)
+        );
+        assert_eq!(
+            SecurityLevel::ScAuthenticated,
+            SecurityLevel::from_pairing(true, true, true)
+        );
+    }
+
+    #[test]
+    fn test_check() {
+        let levels = [
+            SecurityLevel::None,
+            SecurityLevel::Unauthenticated,
+            SecurityLevel::Authenticated,
+            SecurityLevel::ScAuthenticated,
+        ];
+        for current in levels {
+            for required in levels {
+                let result = current.check(required);
+                if current >= required {
+                    assert_eq!(Ok(()), result);
+                } else if current == SecurityLevel::None
+                    && required == SecurityLevel::Unauthenticated
+                {
+                    assert_eq!(Err(INSUFFICIENT_ENCRYPTION), result);
+                } else {
+                    assert_eq!(Err(INSUFFICIENT_AUTHENTICATION), result);
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_permissions() {
+        let result = AttributePermissions::default();
+        assert_eq!(Ok(()), result.check_read(SecurityLevel::None));
+        assert_eq!(Ok(()), result.check_write(SecurityLevel::None));
+
+        let result = AttributePermissions::new(
+            Some(SecurityLevel::Unauthenticated),
+            Some(SecurityLevel::ScAuthenticated),
+        );
+        assert_eq!(
+            Err(INSUFFICIENT_ENCRYPTION),
+            result.check_read(SecurityLevel::None)
+        );
+        assert_eq!(Ok(()), result.check_read(SecurityLevel::Unauthenticated));
+        assert_eq!(
+            Err(INSUFFICIENT_AUTHENTICATION),
+            result.check_write(SecurityLevel::Authenticated)
+        );
+        assert_eq!(Ok(()), result.check_write(SecurityLevel::ScAuthenticated));
+
+        let result = AttributePermissions::new(None, None);
+        assert_eq!(
+            Err(READ_NOT_PERMITTED),
+            result.check_read(SecurityLevel::ScAuthenticated)
+        );
+        assert_eq!(
+            Err(WRITE_NOT_PERMITTED),
+            result.check_write(SecurityLevel::ScAuthenticated)
+        );
+    }
+}
@@ -0,0 +1,361 @@
+//! Channel Classification module.
+//!
+//! Per-channel assessment used by the `Channel classification` feature (LL_CHANNEL_STATUS_IND).
+
+use crate::data_types::channel_map_update_indication::ChannelMapUpdateIndication;
+
+/// Number of data channels.
+pub const DATA_CHANNEL_COUNT: usize = 37;
+
+/// Channel classification size(byte).
+pub const CHANNEL_CLASSIFICATION_SIZE: usize = 10;
+
+/// Channel status.
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub enum ChannelStatus {
+    /// unknown (0b00)
+    Unknown,
+
+    /// good (0b01)
+    Good,
+
+    /// bad (0b11)
+    Bad,
+}
+
+/// Channel Classification.
+#[derive(Debug, PartialEq, Clone)]
+pub struct ChannelClassification {
+    /// Channel status (channel index 0 - 36)
+    pub channels: [ChannelStatus; DATA_CHANNEL_COUNT],
+}
+
+impl ChannelClassification {
+    /// Create [`ChannelClassification`] from channel status.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ble_data_struct::hci::channel_classification::{ChannelClassification, ChannelStatus};
+    ///
+    /// let mut channels = [ChannelStatus::Good; 37];
+    /// channels[1] = ChannelStatus::Bad;
+    /// let result = ChannelClassification::new(channels);
+    /// assert_eq!(channels, result.channels);
+    /// ```
+    pub fn new(channels: [ChannelStatus; DATA_CHANNEL_COUNT]) -> Self {
+        Self { channels }
+    }
+
+    /// Create [`ChannelClassification`] from 37-bit channel map.
+    ///
+    /// Used channel is [`ChannelStatus::Good`], unused channel is [`ChannelStatus::Bad`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ble_data_struct::hci::channel_classification::{ChannelClassification, ChannelStatus};
+    ///
+    /// let mut ch_m = [true; 37];
+    /// ch_m[1] = false;
+    /// let result = ChannelClassification::from_channel_map(&ch_m);
+    /// assert_eq!(ChannelStatus::Good, result.channels[0]);
+    /// assert_eq!(ChannelStatus::Bad, result.channels[1]);
+    /// ```
+    pub fn from_channel_map(ch_m: &[bool]) -> Self {
+        let mut channels = [ChannelStatus::Bad; DATA_CHANNEL_COUNT];
+        channels
+            .iter_mut()
+            .zip(ch_m.iter())
+            .filter(|(_, used)| **used)
+            .for_each(|(status, _)| *status = ChannelStatus::Good);
+        Self { channels }
+    }
+
+    /// Create 37-bit channel map.
+    ///
+    /// [`ChannelStatus::Bad`] channel is unused, [`ChannelStatus::Good`] and [`ChannelStatus::Unknown`] channels are used.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ble_data_struct::data_types::channel_map_update_indication::ChannelMapUpdateIndication;
+    /// use ble_data_struct::hci::channel_classification::{ChannelClassification, ChannelStatus};
+    ///
+    /// let mut channels = [ChannelStatus::Unknown; 37];
+    /// channels[1] = ChannelStatus::Bad;
+    /// let ch_m = ChannelClassification::new(channels).to_channel_map();
+    /// assert_eq!(37, ch_m.len());
+    /// assert!(ch_m[0]);
+    /// assert!(!ch_m[1]);
+    ///
+    /// let result = ChannelMapUpdateIndication::new(&ch_m, 0x0001);
+    /// assert_eq!(ch_m, result.ch_m);
+    /// ```
+    pub fn to_channel_map(&self) -> Vec<bool> {
+        self.channels
+            .iter()
+            .map(|f| *f != ChannelStatus::Bad)
+            .collect()
+    }
+
+    /// Merge host and controller assessments.
+    ///
+    /// A channel is [`ChannelStatus::Bad`] if either assessment is bad,
+    /// [`ChannelStatus::Good`] if either assessment is good, otherwise [`ChannelStatus::Unknown`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ble_data_struct::hci::channel_classification::{ChannelClassification, ChannelStatus};
+    ///
+    /// let mut host = [ChannelStatus::Unknown; 37];
+    /// host[0] = ChannelStatus::Bad;
+    /// host[1] = ChannelStatus::Good;
+    /// let mut controller = [ChannelStatus::Unknown; 37];
+    /// controller[0] = ChannelStatus::Good;
+    /// controller[2] = ChannelStatus::Good;
+    ///
+    /// let result = ChannelClassification::new(host).merge(&ChannelClassification::new(controller));
+    /// assert_eq!(ChannelStatus::Bad, result.channels[0]);
+    /// assert_eq!(ChannelStatus::Good, result.channels[1]);
+    /// assert_eq!(ChannelStatus::Good, result.channels[2]);
+    /// assert_eq!(ChannelStatus::Unknown, result.channels[3]);
+    /// ```
+    pub fn merge(&self, other: &Self) -> Self {
+        let mut channels = self.channels;
+        channels
+            .iter_mut()
+            .zip(other.channels.iter())
+            .for_each(|(status, other)| {
+                *status = match (*status, *other) {
+                    (ChannelStatus::Bad, _) | (_, ChannelStatus::Bad) => ChannelStatus::Bad,
+                    (ChannelStatus::Good, _) | (_, ChannelStatus::Good) => ChannelStatus::Good,
+                    _ => ChannelStatus::Unknown,
+                }
+            });
+        Self { channels }
+    }
+}
+
+impl TryFrom<&Vec<u8>> for ChannelClassification {
+    type Error = String;
+    /// Create [`ChannelClassification`] from [`Vec<u8>`] (2 bits per channel).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ble_data_struct::hci::channel_classification::{ChannelClassification, ChannelStatus};
+    ///
+    /// let mut data = [0u8; 10].to_vec();
+    /// data[0] = 0b0000_1101;
+    /// let result = ChannelClassification::try_from(&data);
+    /// assert!(result.is_ok());
+    /// let result = result.unwrap();
+    /// assert_eq!(ChannelStatus::Good, result.channels[0]);
+    /// assert_eq!(ChannelStatus::Bad, result.channels[1]);
+    /// assert_eq!(ChannelStatus::Unknown, result.channels[2]);
+    ///
+    /// let mut data = [0u8; 10].to_vec();
+    /// data[0] = 0b0000_0010;
+    /// let result = ChannelClassification::try_from(&data);
+    /// assert!(result.is_err());
+    /// assert_eq!("Invalid channel status :0", result.unwrap_err());
+    ///
+    /// let data: Vec<u8> = Vec::new();
+    /// let result = ChannelClassification::try_from(&data);
+    /// assert!(result.is_err());
+    /// assert_eq!(
+    ///     format!("Invalid data size :{}", data.len()),
+    ///     result.unwrap_err()
+    /// );
+    /// ```
+    fn try_from(value: &Vec<u8>) -> Result<Self, String> {
+        let len = value.len();
+        if len < CHANNEL_CLASSIFICATION_SIZE {
+            return Err(format!("Invalid data size :{}", len).to_string());
+        }
+        let mut channels = [ChannelStatus::Unknown; DATA_CHANNEL_COUNT];
+        for (index, status) in channels.iter_mut().enumerate() {
+            *status = match (value[index / 4] >> (index % 4 * 2)) & 0b11 {
+                0b00 => ChannelStatus::Unknown,
+                0b01 => ChannelStatus::Good,
+                0b11 => ChannelStatus::Bad,
+                _ => return Err(format!("Invalid channel status :{}", index).to_string()),
+            };
+        }
+        Ok(Self { channels })
+    }
+}
+
+impl Into<Vec<u8>> for ChannelClassification {
+    /// Create [`Vec<u8>`] (2 bits per channel) from [`ChannelClassification`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ble_data_struct::hci::channel_classification::{ChannelClassification, ChannelStatus};
+    ///
+    /// let mut channels = [ChannelStatus::Unknown; 37];
+    /// channels[0] = ChannelStatus::Good;
+    /// channels[1] = ChannelStatus::Bad;
+    /// let into_data: Vec<u8> = ChannelClassification::new(channels).into();
+    /// let mut data = [0u8; 10].to_vec();
+    /// data[0] = 0b0000_1101;
+    /// assert_eq!(data, into_data);
+    /// ```
+    fn into(self) -> Vec<u8> {
+        let mut data = vec![0u8; CHANNEL_CLASSIFICATION_SIZE];
+        self.channels
+            .iter()
+            .enumerate()
+            .for_each(|(index, status)| {
+                let bits = match status {
+                    ChannelStatus::Unknown => 0b00,
+                    ChannelStatus::Good => 0b01,
+                    ChannelStatus::Bad => 0b11,
+                };
+                data[index / 4] |= bits << (index % 4 * 2);
+            });
+        return data;
+    }
+}
+
+impl From<&ChannelMapUpdateIndication> for ChannelClassification {
+    /// Create [`ChannelClassification`] from [`ChannelMapUpdateIndication`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ble_data_struct::data_types::channel_map_update_indication::ChannelMapUpdateIndication;
+    /// use ble_data_struct::hci::channel_classification::{ChannelClassification, ChannelStatus};
+    ///
+    /// let mut ch_m = [true; 37].to_vec();
+    /// ch_m[36] = false;
+    /// let result = ChannelClassification::from(&ChannelMapUpdateIndication::new(&ch_m, 0x0001));
+    /// assert_eq!(ChannelStatus::Good, result.channels[0]);
+    /// assert_eq!(ChannelStatus::Bad, result.channels[36]);
+    /// ```
+    fn from(value: &ChannelMapUpdateIndication) -> Self {
+        Self::from_channel_map(&value.ch_m)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        data_types::channel_map_update_indication::ChannelMapUpdateIndication,
+        hci::channel_classification::{ChannelClassification, ChannelStatus},
+    };
+
+    #[test]
+    fn test_new() {
+        let mut channels = [ChannelStatus::Good; 37];
+        channels[1] = ChannelStatus::Bad;
+        let result = ChannelClassification::new(channels);
+        assert_eq!(channels, result.channels);
+    }
+
+    #[test]
+    fn test_from_channel_map() {
+        let mut ch_m = [true; 37];
+        ch_m[1] = false;
+        let result = ChannelClassification::from_channel_map(&ch_m);
+        assert_eq!(ChannelStatus::Good, result.channels[0]);
+        assert_eq!(ChannelStatus::Bad, result.channels[1]);
+        assert_eq!(ChannelStatus::Good, result.channels[36]);
+
+        let result = ChannelClassification::from_channel_map(&[]);
+        assert_eq!([ChannelStatus::Bad; 37], result.channels);
+    }
+
+    #[test]
+    fn test_to_channel_map() {
+        let mut channels = [ChannelStatus::Unknown; 37];
+        channels[1] = ChannelStatus::Bad;
+        channels[2] = ChannelStatus::Good;
+        let ch_m = ChannelClassification::new(channels).to_channel_map();
+        assert_eq!(37, ch_m.len());
+        assert!(ch_m[0]);
+        assert!(!ch_m[1]);
+        assert!(ch_m[2]);
+        assert_eq!(
+            ch_m,
+            ChannelClassification::from_channel_map(&ch_m).to_channel_map()
+        );
+    }
+
+    #[test]
+    fn test_merge() {
+        let mut host = [ChannelStatus::Unknown; 37];
+        host[0] = ChannelStatus::Bad;
+        host[1] = ChannelStatus::Good;
+        host[4] = ChannelStatus::Good;
+        let mut controller = [ChannelStatus::Unknown; 37];
+        controller[0] = ChannelStatus::Good;
+        controller[2] = ChannelStatus::Good;
+        controller[4] = ChannelStatus::Bad;
+
+        let result =
+            ChannelClassification::new(host).merge(&ChannelClassification::new(controller));
+        assert_eq!(ChannelStatus::Bad, result.channels[0]);
+        assert_eq!(ChannelStatus::Good, result.channels[1]);
+        assert_eq!(ChannelStatus::Good, result.channels[2]);
+        assert_eq!(ChannelStatus::Unknown, result.channels[3]);
+        assert_eq!(ChannelStatus::Bad, result.channels[4]);
+    }
+
+    #[test]
+    fn test_try_from() {
+        let mut data = [0u8; 10].to_vec();
+        data[0] = 0b0000_1101;
+        data[9] = 0b0000_0011;
+        let result = ChannelClassification::try_from(&data);
+        assert!(result.is_ok());
+        let result = result.unwrap();
+        assert_eq!(ChannelStatus::Good, result.channels[0]);
+        assert_eq!(ChannelStatus::Bad, result.channels[1]);
+        assert_eq!(ChannelStatus::Unknown, result.channels[2]);
+        assert_eq!(ChannelStatus::Bad, result.channels[36]);
+
+        let mut data = [0u8; 10].to_vec();
+        data[1] = 0b1000_0000;
+        let result = ChannelClassification::try_from(&data);
+        assert!(result.is_err());
+        assert_eq!("Invalid channel status :7", result.unwrap_err());
+
+        let data = [0u8; 9].to_vec();
+        let result = ChannelClassification::try_from(&data);
+        assert!(result.is_err());
+        assert_eq!(
+            format!("Invalid data size :{}", data.len()),
+            result.unwrap_err()
+        );
+    }
+
+    #[test]
+    fn test_into() {
+        let mut channels = [ChannelStatus::Unknown; 37];
+        channels[0] = ChannelStatus::Good;
+        channels[1] = ChannelStatus::Bad;
+        channels[36] = ChannelStatus::Bad;
+        let into_data: Vec<u8> = ChannelClassification::new(channels).into();
+        let mut data = [0u8; 10].to_vec();
+        data[0] = 0b0000_1101;
+        data[9] = 0b0000_0011;
+        assert_eq!(data, into_data);
+
+        let result = ChannelClassification::try_from(&data).unwrap();
+        let into_data: Vec<u8> = result.into();
+        assert_eq!(data, into_data);
+    }
+
+    #[test]
+    fn test_from() {
+        let mut ch_m = [true; 37].to_vec();
+        ch_m[36] = false;
+        let result = ChannelClassification::from(&ChannelMapUpdateIndication::new(&ch_m, 0x0001));
+        assert_eq!(ChannelStatus::Good, result.channels[0]);
+        assert_eq!(ChannelStatus::Bad, result.channels[36]);
+    }
+}
@@ -0,0 +1,5 @@
+//! Trait for HCI command.
+pub trait HciCommand {
+    /// Get HCI command opcode (OGF << 10 | OCF)
+    fn opcode() -> u16;
+}
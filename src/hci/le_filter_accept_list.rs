@@ -0,0 +1,661 @@
+//! LE Filter Accept List HCI command module.
+
+use crate::{
+    data_types::address_type::AddressType, hci::hci_command::HciCommand,
+    scan::scan_record::ScanRecord,
+};
+
+/// Address_Type: Public Device Address
+pub const FILTER_ACCEPT_LIST_PUBLIC_DEVICE_ADDRESS: u8 = 0x00;
+
+/// Address_Type: Random Device Address
+pub const FILTER_ACCEPT_LIST_RANDOM_DEVICE_ADDRESS: u8 = 0x01;
+
+/// Address_Type: Devices sending anonymous advertisements
+pub const FILTER_ACCEPT_LIST_ANONYMOUS: u8 = 0xff;
+
+/// Filter Accept List entry.
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Hash)]
+pub struct FilterAcceptListEntry {
+    /// Address_Type
+    pub address_type: u8,
+
+    /// Address
+    pub address: u64,
+}
+
+impl FilterAcceptListEntry {
+    /// Create [`FilterAcceptListEntry`] from `Address_Type` and `Address`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ble_data_struct::hci::le_filter_accept_list::{
+    ///     FilterAcceptListEntry, FILTER_ACCEPT_LIST_PUBLIC_DEVICE_ADDRESS,
+    /// };
+    ///
+    /// let result = FilterAcceptListEntry::new(FILTER_ACCEPT_LIST_PUBLIC_DEVICE_ADDRESS, 0x060504030201);
+    /// assert_eq!(FILTER_ACCEPT_LIST_PUBLIC_DEVICE_ADDRESS, result.address_type);
+    /// assert_eq!(0x060504030201, result.address);
+    /// ```
+    pub fn new(address_type: u8, address: u64) -> Self {
+        Self {
+            address_type,
+            address,
+        }
+    }
+
+    /// Create [`FilterAcceptListEntry`] from [`AddressType`] and `Address`.
+    ///
+    /// Identity Addresses are stored as Public / Random Device Address.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ble_data_struct::{
+    ///     data_types::address_type::AddressType,
+    ///     hci::le_filter_accept_list::{
+    ///         FilterAcceptListEntry, FILTER_ACCEPT_LIST_RANDOM_DEVICE_ADDRESS,
+    ///     },
+    /// };
+    ///
+    /// let result = FilterAcceptListEntry::from_address_type(AddressType::RandomIdentity, 0xc60504030201);
+    /// assert_eq!(FILTER_ACCEPT_LIST_RANDOM_DEVICE_ADDRESS, result.address_type);
+    /// assert_eq!(0xc60504030201, result.address);
+    /// ```
+    pub fn from_address_type(address_type: AddressType, address: u64) -> Self {
+        if address_type.is_random() {
+            Self::new(FILTER_ACCEPT_LIST_RANDOM_DEVICE_ADDRESS, address)
+        } else {
+            Self::new(FILTER_ACCEPT_LIST_PUBLIC_DEVICE_ADDRESS, address)
+        }
+    }
+
+    /// check the [`ScanRecord`] is sent from this entry.
+    ///
+    /// If [`ScanRecord::address_type`] is `None`, only `Address` is compared.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ble_data_struct::{
+    ///     data_types::{address_type::AddressType, data_type_parser::DataTypeParseResults},
+    ///     hci::le_filter_accept_list::{
+    ///         FilterAcceptListEntry, FILTER_ACCEPT_LIST_PUBLIC_DEVICE_ADDRESS,
+    ///     },
+    ///     scan::scan_record::ScanRecord,
+    /// };
+    ///
+    /// let entry = FilterAcceptListEntry::new(FILTER_ACCEPT_LIST_PUBLIC_DEVICE_ADDRESS, 0x060504030201);
+    /// let record = ScanRecord::new(0x060504030201, -60, DataTypeParseResults::new(Vec::new()));
+    /// assert!(entry.matches(&record));
+    /// assert!(entry.matches(&record.clone().with_address_type(AddressType::Public)));
+    /// assert!(!entry.matches(&record.with_address_type(AddressType::RandomIdentity)));
+    /// ```
+    pub fn matches(&self, record: &ScanRecord) -> bool {
+        if self.address_type == FILTER_ACCEPT_LIST_ANONYMOUS || self.address != record.address {
+            return false;
+        }
+        match record.address_type {
+            Some(address_type) => {
+                Self::from_address_type(address_type, record.address).address_type
+                    == self.address_type
+            }
+            None => true,
+        }
+    }
+}
+
+impl TryFrom<&Vec<u8>> for FilterAcceptListEntry {
+    type Error = String;
+    /// Create [`FilterAcceptListEntry`] from [`Vec<u8>`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ble_data_struct::hci::le_filter_accept_list::FilterAcceptListEntry;
+    ///
+    /// let data: Vec<u8> = [0x01u8, 0x01u8, 0x02u8, 0x03u8, 0x04u8, 0x05u8, 0xc6u8].to_vec();
+    /// let result = FilterAcceptListEntry::try_from(&data);
+    /// assert!(result.is_ok());
+    /// let result = result.unwrap();
+    /// assert_eq!(0x01, result.address_type);
+    /// assert_eq!(0xc60504030201, result.address);
+    ///
+    /// let data: Vec<u8> = Vec::new();
+    /// let result = FilterAcceptListEntry::try_from(&data);
+    /// assert!(result.is_err());
+    /// assert_eq!(
+    ///     format!("Invalid data size :{}", data.len()),
+    ///     result.unwrap_err()
+    /// );
+    /// ```
+    fn try_from(value: &Vec<u8>) -> Result<Self, String> {
+        let len = value.len();
+        if len < 7 {
+            return Err(format!("Invalid data size :{}", len).to_string());
+        }
+        let mut address = [0u8; 8];
+        address[..6].copy_from_slice(&value[1..7]);
+        Ok(Self {
+            address_type: value[0],
+            address: u64::from_le_bytes(address),
+        })
+    }
+}
+
+impl Into<Vec<u8>> for FilterAcceptListEntry {
+    /// Create [`Vec<u8>`] from [`FilterAcceptListEntry`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ble_data_struct::hci::le_filter_accept_list::FilterAcceptListEntry;
+    ///
+    /// let into_data: Vec<u8> = FilterAcceptListEntry::new(0x01, 0xc60504030201).into();
+    /// assert_eq!([0x01u8, 0x01u8, 0x02u8, 0x03u8, 0x04u8, 0x05u8, 0xc6u8].to_vec(), into_data);
+    /// ```
+    fn into(self) -> Vec<u8> {
+        let mut data: Vec<u8> = Vec::new();
+        data.push(self.address_type);
+        data.append(&mut self.address.to_le_bytes()[..6].to_vec());
+        return data;
+    }
+}
+
+/// HCI_LE_Add_Device_To_Filter_Accept_List command parameters.
+#[derive(Debug, PartialEq, Clone)]
+pub struct AddDeviceToFilterAcceptList {
+    /// Address_Type, Address
+    pub entry: FilterAcceptListEntry,
+}
+
+impl AddDeviceToFilterAcceptList {
+    /// Create [`AddDeviceToFilterAcceptList`] from [`FilterAcceptListEntry`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ble_data_struct::hci::le_filter_accept_list::{
+    ///     AddDeviceToFilterAcceptList, FilterAcceptListEntry,
+    /// };
+    ///
+    /// let entry = FilterAcceptListEntry::new(0x00, 0x060504030201);
+    /// let result = AddDeviceToFilterAcceptList::new(entry);
+    /// assert_eq!(entry, result.entry);
+    /// ```
+    pub fn new(entry: FilterAcceptListEntry) -> Self {
+        Self { entry }
+    }
+}
+
+impl Into<Vec<u8>> for AddDeviceToFilterAcceptList {
+    /// Create [`Vec<u8>`] from [`AddDeviceToFilterAcceptList`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ble_data_struct::hci::le_filter_accept_list::{
+    ///     AddDeviceToFilterAcceptList, FilterAcceptListEntry,
+    /// };
+    ///
+    /// let result = AddDeviceToFilterAcceptList::new(FilterAcceptListEntry::new(0x00, 0x060504030201));
+    /// let into_data: Vec<u8> = result.into();
+    /// assert_eq!([0x00u8, 0x01u8, 0x02u8, 0x03u8, 0x04u8, 0x05u8, 0x06u8].to_vec(), into_data);
+    /// ```
+    fn into(self) -> Vec<u8> {
+        self.entry.into()
+    }
+}
+
+impl HciCommand for AddDeviceToFilterAcceptList {
+    /// return `0x2011`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ble_data_struct::hci::{hci_command::HciCommand, le_filter_accept_list::AddDeviceToFilterAcceptList};
+    ///
+    /// assert_eq!(0x2011, AddDeviceToFilterAcceptList::opcode());
+    /// ```
+    fn opcode() -> u16 {
+        0x2011
+    }
+}
+
+/// HCI_LE_Remove_Device_From_Filter_Accept_List command parameters.
+#[derive(Debug, PartialEq, Clone)]
+pub struct RemoveDeviceFromFilterAcceptList {
+    /// Address_Type, Address
+    pub entry: FilterAcceptListEntry,
+}
+
+impl RemoveDeviceFromFilterAcceptList {
+    /// Create [`RemoveDeviceFromFilterAcceptList`] from [`FilterAcceptListEntry`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ble_data_struct::hci::le_filter_accept_list::{
+    ///     FilterAcceptListEntry, RemoveDeviceFromFilterAcceptList,
+    /// };
+    ///
+    /// let entry = FilterAcceptListEntry::new(0x00, 0x060504030201);
+    /// let result = RemoveDeviceFromFilterAcceptList::new(entry);
+    /// assert_eq!(entry, result.entry);
+    /// ```
+    pub fn new(entry: FilterAcceptListEntry) -> Self {
+        Self { entry }
+    }
+}
+
+impl Into<Vec<u8>> for RemoveDeviceFromFilterAcceptList {
+    /// Create [`Vec<u8>`] from [`RemoveDeviceFromFilterAcceptList`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ble_data_struct::hci::le_filter_accept_list::{
+    ///     FilterAcceptListEntry, RemoveDeviceFromFilterAcceptList,
+    /// };
+    ///
+    /// let result = RemoveDeviceFromFilterAcceptList::new(FilterAcceptListEntry::new(0x01, 0xc60504030201));
+    /// let into_data: Vec<u8> = result.into();
+    /// assert_eq!([0x01u8, 0x01u8, 0x02u8, 0x03u8, 0x04u8, 0x05u8, 0xc6u8].to_vec(), into_data);
+    /// ```
+    fn into(self) -> Vec<u8> {
+        self.entry.into()
+    }
+}
+
+impl HciCommand for RemoveDeviceFromFilterAcceptList {
+    /// return `0x2012`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ble_data_struct::hci::{hci_command::HciCommand, le_filter_accept_list::RemoveDeviceFromFilterAcceptList};
+    ///
+    /// assert_eq!(0x2012, RemoveDeviceFromFilterAcceptList::opcode());
+    /// ```
+    fn opcode() -> u16 {
+        0x2012
+    }
+}
+
+/// Filter Accept List.
+///
+/// Entries are kept in insertion order without duplicates.
+#[derive(Debug, PartialEq, Clone, Default)]
+pub struct FilterAcceptList {
+    /// entries
+    pub entries: Vec<FilterAcceptListEntry>,
+}
+
+impl FilterAcceptList {
+    /// Create empty [`FilterAcceptList`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ble_data_struct::hci::le_filter_accept_list::FilterAcceptList;
+    ///
+    /// let result = FilterAcceptList::new();
+    /// assert!(result.is_empty());
+    /// ```
+    pub fn new() -> Self {
+        Self {
+            entries: Vec::new(),
+        }
+    }
+
+    /// Create [`FilterAcceptList`] from entries, duplicated entries are removed.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ble_data_struct::hci::le_filter_accept_list::{FilterAcceptList, FilterAcceptListEntry};
+    ///
+    /// let entry1 = FilterAcceptListEntry::new(0x00, 0x060504030201);
+    /// let entry2 = FilterAcceptListEntry::new(0x01, 0x060504030201);
+    /// let result = FilterAcceptList::from_entries(&[entry1, entry2, entry1]);
+    /// assert_eq!(vec![entry1, entry2], result.entries);
+    /// ```
+    pub fn from_entries(entries: &[FilterAcceptListEntry]) -> Self {
+        let mut result = Self::new();
+        entries.iter().for_each(|entry| {
+            result.add(*entry);
+        });
+        result
+    }
+
+    /// Number of entries.
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    /// check no entries.
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+
+    /// check the entry is in the list.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ble_data_struct::hci::le_filter_accept_list::{FilterAcceptList, FilterAcceptListEntry};
+    ///
+    /// let entry = FilterAcceptListEntry::new(0x00, 0x060504030201);
+    /// let result = FilterAcceptList::from_entries(&[entry]);
+    /// assert!(result.contains(&entry));
+    /// assert!(!result.contains(&FilterAcceptListEntry::new(0x01, 0x060504030201)));
+    /// ```
+    pub fn contains(&self, entry: &FilterAcceptListEntry) -> bool {
+        self.entries.contains(entry)
+    }
+
+    /// Add the entry, returns `false` if already added.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ble_data_struct::hci::le_filter_accept_list::{FilterAcceptList, FilterAcceptListEntry};
+    ///
+    /// let entry = FilterAcceptListEntry::new(0x00, 0x060504030201);
+    /// let mut result = FilterAcceptList::new();
+    /// assert!(result.add(entry));
+    /// assert!(!result.add(entry));
+    /// assert_eq!(1, result.len());
+    /// ```
+    pub fn add(&mut self, entry: FilterAcceptListEntry) -> bool {
+        if self.contains(&entry) {
+            false
+        } else {
+            self.entries.push(entry);
+            true
+        }
+    }
+
+    /// Remove the entry, returns `false` if not added.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ble_data_struct::hci::le_filter_accept_list::{FilterAcceptList, FilterAcceptListEntry};
+    ///
+    /// let entry = FilterAcceptListEntry::new(0x00, 0x060504030201);
+    /// let mut result = FilterAcceptList::from_entries(&[entry]);
+    /// assert!(result.remove(&entry));
+    /// assert!(!result.remove(&entry));
+    /// assert!(result.is_empty());
+    /// ```
+    pub fn remove(&mut self, entry: &FilterAcceptListEntry) -> bool {
+        let len = self.entries.len();
+        self.entries.retain(|f| f != entry);
+        len != self.entries.len()
+    }
+
+    /// Remove all entries.
+    pub fn clear(&mut self) {
+        self.entries.clear();
+    }
+
+    /// HCI_LE_Add_Device_To_Filter_Accept_List commands for all entries.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ble_data_struct::hci::le_filter_accept_list::{
+    ///     AddDeviceToFilterAcceptList, FilterAcceptList, FilterAcceptListEntry,
+    /// };
+    ///
+    /// let entry = FilterAcceptListEntry::new(0x00, 0x060504030201);
+    /// let result = FilterAcceptList::from_entries(&[entry]);
+    /// assert_eq!(vec![AddDeviceToFilterAcceptList::new(entry)], result.add_commands());
+    /// ```
+    pub fn add_commands(&self) -> Vec<AddDeviceToFilterAcceptList> {
+        self.entries
+            .iter()
+            .map(|entry| AddDeviceToFilterAcceptList::new(*entry))
+            .collect()
+    }
+
+    /// HCI_LE_Remove_Device_From_Filter_Accept_List commands for all entries.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ble_data_struct::hci::le_filter_accept_list::{
+    ///     FilterAcceptList, FilterAcceptListEntry, RemoveDeviceFromFilterAcceptList,
+    /// };
+    ///
+    /// let entry = FilterAcceptListEntry::new(0x00, 0x060504030201);
+    /// let result = FilterAcceptList::from_entries(&[entry]);
+    /// assert_eq!(vec![RemoveDeviceFromFilterAcceptList::new(entry)], result.remove_commands());
+    /// ```
+    pub fn remove_commands(&self) -> Vec<RemoveDeviceFromFilterAcceptList> {
+        self.entries
+            .iter()
+            .map(|entry| RemoveDeviceFromFilterAcceptList::new(*entry))
+            .collect()
+    }
+
+    /// check the [`ScanRecord`] is sent from any entry.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ble_data_struct::{
+    ///     data_types::data_type_parser::DataTypeParseResults,
+    ///     hci::le_filter_accept_list::{FilterAcceptList, FilterAcceptListEntry},
+    ///     scan::scan_record::ScanRecord,
+    /// };
+    ///
+    /// let result = FilterAcceptList::from_entries(&[FilterAcceptListEntry::new(0x00, 0x060504030201)]);
+    /// let results = DataTypeParseResults::new(Vec::new());
+    /// assert!(result.matches(&ScanRecord::new(0x060504030201, -60, results.clone())));
+    /// assert!(!result.matches(&ScanRecord::new(0x060504030202, -60, results)));
+    /// ```
+    pub fn matches(&self, record: &ScanRecord) -> bool {
+        self.entries.iter().any(|entry| entry.matches(record))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        data_types::{
+            address_type::{AddressType, RandomAddressType},
+            data_type_parser::DataTypeParseResults,
+        },
+        hci::{hci_command::HciCommand, le_filter_accept_list::*},
+        scan::scan_record::ScanRecord,
+    };
+
+    #[test]
+    fn test_entry_new() {
+        let result =
+            FilterAcceptListEntry::new(FILTER_ACCEPT_LIST_RANDOM_DEVICE_ADDRESS, 0xc60504030201);
+        assert_eq!(
+            FILTER_ACCEPT_LIST_RANDOM_DEVICE_ADDRESS,
+            result.address_type
+        );
+        assert_eq!(0xc60504030201, result.address);
+    }
+
+    #[test]
+    fn test_entry_from_address_type() {
+        let address = 0x060504030201u64;
+        assert_eq!(
+            FilterAcceptListEntry::new(FILTER_ACCEPT_LIST_PUBLIC_DEVICE_ADDRESS, address),
+            FilterAcceptListEntry::from_address_type(AddressType::Public, address)
+        );
+        assert_eq!(
+            FilterAcceptListEntry::new(FILTER_ACCEPT_LIST_PUBLIC_DEVICE_ADDRESS, address),
+            FilterAcceptListEntry::from_address_type(AddressType::PublicIdentity, address)
+        );
+        assert_eq!(
+            FilterAcceptListEntry::new(FILTER_ACCEPT_LIST_RANDOM_DEVICE_ADDRESS, address),
+            FilterAcceptListEntry::from_address_type(
+                AddressType::Random(RandomAddressType::NonResolvablePrivate),
+                address
+            )
+        );
+        assert_eq!(
+            FilterAcceptListEntry::new(FILTER_ACCEPT_LIST_RANDOM_DEVICE_ADDRESS, address),
+            FilterAcceptListEntry::from_address_type(AddressType::RandomIdentity, address)
+        );
+    }
+
+    #[test]
+    fn test_entry_matches() {
+        let address = 0xc60504030201u64;
+        let record = ScanRecord::new(address, -60, DataTypeParseResults::new(Vec::new()));
+        let entry = FilterAcceptListEntry::new(FILTER_ACCEPT_LIST_RANDOM_DEVICE_ADDRESS, address);
+        assert!(entry.matches(&record));
+        assert!(entry.matches(
+            &record
+                .clone()
+                .with_address_type(AddressType::Random(RandomAddressType::Static))
+        ));
+        assert!(!entry.matches(&record.clone().with_address_type(AddressType::Public)));
+        assert!(
+            !FilterAcceptListEntry::new(FILTER_ACCEPT_LIST_ANONYMOUS, address).matches(&record)
+        );
+        assert!(
+            !FilterAcceptListEntry::new(FILTER_ACCEPT_LIST_RANDOM_DEVICE_ADDRESS, address + 1)
+                .matches(&record)
+        );
+    }
+
+    #[test]
+    fn test_entry_try_from() {
+        let data: Vec<u8> = [0xffu8, 0x01u8, 0x02u8, 0x03u8, 0x04u8, 0x05u8, 0x06u8].to_vec();
+        assert_eq!(
+            Ok(FilterAcceptListEntry::new(
+                FILTER_ACCEPT_LIST_ANONYMOUS,
+                0x060504030201
+            )),
+            FilterAcceptListEntry::try_from(&data)
+        );
+
+        let data: Vec<u8> = [0x00u8; 6].to_vec();
+        assert_eq!(
+            Err(format!("Invalid data size :{}", data.len())),
+            FilterAcceptListEntry::try_from(&data)
+        );
+    }
+
+    #[test]
+    fn test_entry_into() {
+        let entry =
+            FilterAcceptListEntry::new(FILTER_ACCEPT_LIST_PUBLIC_DEVICE_ADDRESS, 0x060504030201);
+        let data: Vec<u8> = [0x00u8, 0x01u8, 0x02u8, 0x03u8, 0x04u8, 0x05u8, 0x06u8].to_vec();
+        let into_data: Vec<u8> = entry.into();
+        assert_eq!(data, into_data);
+        assert_eq!(Ok(entry), FilterAcceptListEntry::try_from(&data));
+    }
+
+    #[test]
+    fn test_add_device_to_filter_accept_list() {
+        let entry =
+            FilterAcceptListEntry::new(FILTER_ACCEPT_LIST_RANDOM_DEVICE_ADDRESS, 0xc60504030201);
+        let result = AddDeviceToFilterAcceptList::new(entry);
+        assert_eq!(entry, result.entry);
+        let into_data: Vec<u8> = result.into();
+        assert_eq!(
+            [0x01u8, 0x01u8, 0x02u8, 0x03u8, 0x04u8, 0x05u8, 0xc6u8].to_vec(),
+            into_data
+        );
+        assert_eq!(0x2011, AddDeviceToFilterAcceptList::opcode());
+    }
+
+    #[test]
+    fn test_remove_device_from_filter_accept_list() {
+        let entry =
+            FilterAcceptListEntry::new(FILTER_ACCEPT_LIST_PUBLIC_DEVICE_ADDRESS, 0x060504030201);
+        let result = RemoveDeviceFromFilterAcceptList::new(entry);
+        assert_eq!(entry, result.entry);
+        let into_data: Vec<u8> = result.into();
+        assert_eq!(
+            [0x00u8, 0x01u8, 0x02u8, 0x03u8, 0x04u8, 0x05u8, 0x06u8].to_vec(),
+            into_data
+        );
+        assert_eq!(0x2012, RemoveDeviceFromFilterAcceptList::opcode());
+    }
+
+    #[test]
+    fn test_list() {
+        let entry1 = FilterAcceptListEntry::new(FILTER_ACCEPT_LIST_PUBLIC_DEVICE_ADDRESS, 0x01);
+        let entry2 = FilterAcceptListEntry::new(FILTER_ACCEPT_LIST_RANDOM_DEVICE_ADDRESS, 0x01);
+        let entry3 = FilterAcceptListEntry::new(FILTER_ACCEPT_LIST_PUBLIC_DEVICE_ADDRESS, 0x02);
+
+        let mut result = FilterAcceptList::new();
+        assert!(result.is_empty());
+        assert_eq!(FilterAcceptList::default(), result);
+        assert!(result.add(entry1));
+        assert!(result.add(entry2));
+        assert!(!result.add(entry1));
+        assert!(result.add(entry3));
+        assert_eq!(vec![entry1, entry2, entry3], result.entries);
+        assert_eq!(3, result.len());
+        assert!(result.contains(&entry2));
+
+        assert!(result.remove(&entry2));
+        assert!(!result.remove(&entry2));
+        assert!(!result.contains(&entry2));
+        assert_eq!(vec![entry1, entry3], result.entries);
+
+        result.clear();
+        assert!(result.is_empty());
+
+        let result = FilterAcceptList::from_entries(&[entry3, entry1, entry3, entry1]);
+        assert_eq!(vec![entry3, entry1], result.entries);
+    }
+
+    #[test]
+    fn test_list_commands() {
+        let entry1 = FilterAcceptListEntry::new(FILTER_ACCEPT_LIST_PUBLIC_DEVICE_ADDRESS, 0x01);
+        let entry2 = FilterAcceptListEntry::new(FILTER_ACCEPT_LIST_RANDOM_DEVICE_ADDRESS, 0x02);
+        let result = FilterAcceptList::from_entries(&[entry1, entry2, entry1]);
+        assert_eq!(
+            vec![
+                AddDeviceToFilterAcceptList::new(entry1),
+                AddDeviceToFilterAcceptList::new(entry2)
+            ],
+            result.add_commands()
+        );
+        assert_eq!(
+            vec![
+                RemoveDeviceFromFilterAcceptList::new(entry1),
+                RemoveDeviceFromFilterAcceptList::new(entry2)
+            ],
+            result.remove_commands()
+        );
+        assert!(FilterAcceptList::new().add_commands().is_empty());
+    }
+
+    #[test]
+    fn test_list_matches() {
+        let results = DataTypeParseResults::new(Vec::new());
+        let result = FilterAcceptList::from_entries(&[
+            FilterAcceptListEntry::new(FILTER_ACCEPT_LIST_PUBLIC_DEVICE_ADDRESS, 0x01),
+            FilterAcceptListEntry::new(FILTER_ACCEPT_LIST_RANDOM_DEVICE_ADDRESS, 0x02),
+        ]);
+        assert!(result.matches(&ScanRecord::new(0x01, -60, results.clone())));
+        assert!(result.matches(
+            &ScanRecord::new(0x02, -60, results.clone())
+                .with_address_type(AddressType::RandomIdentity)
+        ));
+        assert!(!result.matches(
+            &ScanRecord::new(0x02, -60, results.clone()).with_address_type(AddressType::Public)
+        ));
+        assert!(!result.matches(&ScanRecord::new(0x03, -60, results)));
+        assert!(!FilterAcceptList::new().matches(&ScanRecord::new(
+            0x01,
+            -60,
+            DataTypeParseResults::new(Vec::new())
+        )));
+    }
+}
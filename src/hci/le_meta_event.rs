@@ -0,0 +1,5 @@
+//! Trait for HCI LE Meta event.
+pub trait LeMetaEvent {
+    /// Get LE Meta event subevent code
+    fn subevent_code() -> u8;
+}
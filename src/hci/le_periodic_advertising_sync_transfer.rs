@@ -0,0 +1,792 @@
+//! LE Periodic Advertising Sync Transfer (PAST) HCI command module.
+
+use crate::{data_types::time_units::Units10ms, hci::hci_command::HciCommand};
+
+/// Mode: No attempt is made to synchronize to the periodic advertising and no event is sent to the Host
+pub const PAST_MODE_NO_SYNC: u8 = 0x00;
+
+/// Mode: HCI_LE_Periodic_Advertising_Sync_Transfer_Received event is sent, reports disabled
+pub const PAST_MODE_SYNC_REPORTS_DISABLED: u8 = 0x01;
+
+/// Mode: HCI_LE_Periodic_Advertising_Sync_Transfer_Received event is sent, reports enabled
+pub const PAST_MODE_SYNC_REPORTS_ENABLED: u8 = 0x02;
+
+/// Mode: HCI_LE_Periodic_Advertising_Sync_Transfer_Received event is sent, reports enabled with duplicate filtering
+pub const PAST_MODE_SYNC_REPORTS_ENABLED_WITH_DUPLICATE_FILTERING: u8 = 0x03;
+
+/// Units: 10 ms
+///
+/// Sync_Timeout
+pub const SYNC_TIMEOUT_UNIT: u16 = 10;
+
+/// HCI_LE_Periodic_Advertising_Sync_Transfer command parameters.
+#[derive(Debug, PartialEq, Clone)]
+pub struct PeriodicAdvertisingSyncTransfer {
+    /// Connection_Handle
+    pub connection_handle: u16,
+
+    /// Service_Data
+    pub service_data: u16,
+
+    /// Sync_Handle
+    pub sync_handle: u16,
+}
+
+impl PeriodicAdvertisingSyncTransfer {
+    /// Create [`PeriodicAdvertisingSyncTransfer`] from Parameters.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ble_data_struct::hci::le_periodic_advertising_sync_transfer::PeriodicAdvertisingSyncTransfer;
+    ///
+    /// let connection_handle = 0x0001u16;
+    /// let service_data = 0x0002u16;
+    /// let sync_handle = 0x0003u16;
+    /// let result = PeriodicAdvertisingSyncTransfer::new(connection_handle, service_data, sync_handle);
+    /// assert_eq!(connection_handle, result.connection_handle);
+    /// assert_eq!(service_data, result.service_data);
+    /// assert_eq!(sync_handle, result.sync_handle);
+    /// ```
+    pub fn new(connection_handle: u16, service_data: u16, sync_handle: u16) -> Self {
+        Self {
+            connection_handle,
+            service_data,
+            sync_handle,
+        }
+    }
+}
+
+impl TryFrom<&Vec<u8>> for PeriodicAdvertisingSyncTransfer {
+    type Error = String;
+    /// Create [`PeriodicAdvertisingSyncTransfer`] from [`Vec<u8>`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ble_data_struct::hci::le_periodic_advertising_sync_transfer::PeriodicAdvertisingSyncTransfer;
+    ///
+    /// let data: Vec<u8> = [0x01u8, 0x00u8, 0x02u8, 0x00u8, 0x03u8, 0x00u8].to_vec();
+    /// let result = PeriodicAdvertisingSyncTransfer::try_from(&data);
+    /// assert!(result.is_ok());
+    ///
+    /// let data: Vec<u8> = Vec::new();
+    /// let result = PeriodicAdvertisingSyncTransfer::try_from(&data);
+    /// assert!(result.is_err());
+    /// assert_eq!(
+    ///     format!("Invalid data size :{}", data.len()),
+    ///     result.unwrap_err()
+    /// );
+    /// ```
+    fn try_from(value: &Vec<u8>) -> Result<Self, String> {
+        let len = value.len();
+        if len < 6 {
+            return Err(format!("Invalid data size :{}", len).to_string());
+        }
+        Ok(Self {
+            connection_handle: u16::from_le_bytes(value[0..2].try_into().unwrap()),
+            service_data: u16::from_le_bytes(value[2..4].try_into().unwrap()),
+            sync_handle: u16::from_le_bytes(value[4..6].try_into().unwrap()),
+        })
+    }
+}
+
+impl Into<Vec<u8>> for PeriodicAdvertisingSyncTransfer {
+    /// Create [`Vec<u8>`] from [`PeriodicAdvertisingSyncTransfer`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ble_data_struct::hci::le_periodic_advertising_sync_transfer::PeriodicAdvertisingSyncTransfer;
+    ///
+    /// let connection_handle = 0x0001u16;
+    /// let service_data = 0x0002u16;
+    /// let sync_handle = 0x0003u16;
+    /// let result = PeriodicAdvertisingSyncTransfer::new(connection_handle, service_data, sync_handle);
+    /// let into_data: Vec<u8> = result.into();
+    /// assert_eq!([0x01u8, 0x00u8, 0x02u8, 0x00u8, 0x03u8, 0x00u8].to_vec(), into_data);
+    /// ```
+    fn into(self) -> Vec<u8> {
+        let mut data: Vec<u8> = Vec::new();
+        data.append(&mut self.connection_handle.to_le_bytes().to_vec());
+        data.append(&mut self.service_data.to_le_bytes().to_vec());
+        data.append(&mut self.sync_handle.to_le_bytes().to_vec());
+        return data;
+    }
+}
+
+impl HciCommand for PeriodicAdvertisingSyncTransfer {
+    /// return `0x205a`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ble_data_struct::hci::{hci_command::HciCommand, le_periodic_advertising_sync_transfer::PeriodicAdvertisingSyncTransfer};
+    ///
+    /// assert_eq!(0x205a, PeriodicAdvertisingSyncTransfer::opcode());
+    /// ```
+    fn opcode() -> u16 {
+        0x205a
+    }
+}
+
+/// HCI_LE_Periodic_Advertising_Set_Info_Transfer command parameters.
+#[derive(Debug, PartialEq, Clone)]
+pub struct PeriodicAdvertisingSetInfoTransfer {
+    /// Connection_Handle
+    pub connection_handle: u16,
+
+    /// Service_Data
+    pub service_data: u16,
+
+    /// Advertising_Handle
+    pub advertising_handle: u8,
+}
+
+impl PeriodicAdvertisingSetInfoTransfer {
+    /// Create [`PeriodicAdvertisingSetInfoTransfer`] from Parameters.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ble_data_struct::hci::le_periodic_advertising_sync_transfer::PeriodicAdvertisingSetInfoTransfer;
+    ///
+    /// let connection_handle = 0x0001u16;
+    /// let service_data = 0x0002u16;
+    /// let advertising_handle = 0x03u8;
+    /// let result = PeriodicAdvertisingSetInfoTransfer::new(connection_handle, service_data, advertising_handle);
+    /// assert_eq!(connection_handle, result.connection_handle);
+    /// assert_eq!(service_data, result.service_data);
+    /// assert_eq!(advertising_handle, result.advertising_handle);
+    /// ```
+    pub fn new(connection_handle: u16, service_data: u16, advertising_handle: u8) -> Self {
+        Self {
+            connection_handle,
+            service_data,
+            advertising_handle,
+        }
+    }
+}
+
+impl TryFrom<&Vec<u8>> for PeriodicAdvertisingSetInfoTransfer {
+    type Error = String;
+    /// Create [`PeriodicAdvertisingSetInfoTransfer`] from [`Vec<u8>`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ble_data_struct::hci::le_periodic_advertising_sync_transfer::PeriodicAdvertisingSetInfoTransfer;
+    ///
+    /// let data: Vec<u8> = [0x01u8, 0x00u8, 0x02u8, 0x00u8, 0x03u8].to_vec();
+    /// let result = PeriodicAdvertisingSetInfoTransfer::try_from(&data);
+    /// assert!(result.is_ok());
+    ///
+    /// let data: Vec<u8> = Vec::new();
+    /// let result = PeriodicAdvertisingSetInfoTransfer::try_from(&data);
+    /// assert!(result.is_err());
+    /// assert_eq!(
+    ///     format!("Invalid data size :{}", data.len()),
+    ///     result.unwrap_err()
+    /// );
+    /// ```
+    fn try_from(value: &Vec<u8>) -> Result<Self, String> {
+        let len = value.len();
+        if len < 5 {
+            return Err(format!("Invalid data size :{}", len).to_string());
+        }
+        Ok(Self {
+            connection_handle: u16::from_le_bytes(value[0..2].try_into().unwrap()),
+            service_data: u16::from_le_bytes(value[2..4].try_into().unwrap()),
+            advertising_handle: value[4],
+        })
+    }
+}
+
+impl Into<Vec<u8>> for PeriodicAdvertisingSetInfoTransfer {
+    /// Create [`Vec<u8>`] from [`PeriodicAdvertisingSetInfoTransfer`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ble_data_struct::hci::le_periodic_advertising_sync_transfer::PeriodicAdvertisingSetInfoTransfer;
+    ///
+    /// let connection_handle = 0x0001u16;
+    /// let service_data = 0x0002u16;
+    /// let advertising_handle = 0x03u8;
+    /// let result = PeriodicAdvertisingSetInfoTransfer::new(connection_handle, service_data, advertising_handle);
+    /// let into_data: Vec<u8> = result.into();
+    /// assert_eq!([0x01u8, 0x00u8, 0x02u8, 0x00u8, 0x03u8].to_vec(), into_data);
+    /// ```
+    fn into(self) -> Vec<u8> {
+        let mut data: Vec<u8> = Vec::new();
+        data.append(&mut self.connection_handle.to_le_bytes().to_vec());
+        data.append(&mut self.service_data.to_le_bytes().to_vec());
+        data.push(self.advertising_handle);
+        return data;
+    }
+}
+
+impl HciCommand for PeriodicAdvertisingSetInfoTransfer {
+    /// return `0x205b`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ble_data_struct::hci::{hci_command::HciCommand, le_periodic_advertising_sync_transfer::PeriodicAdvertisingSetInfoTransfer};
+    ///
+    /// assert_eq!(0x205b, PeriodicAdvertisingSetInfoTransfer::opcode());
+    /// ```
+    fn opcode() -> u16 {
+        0x205b
+    }
+}
+
+/// HCI_LE_Set_Periodic_Advertising_Sync_Transfer_Parameters command parameters.
+#[derive(Debug, PartialEq, Clone)]
+pub struct SetPeriodicAdvertisingSyncTransferParameters {
+    /// Connection_Handle
+    pub connection_handle: u16,
+
+    /// Mode
+    pub mode: u8,
+
+    /// Skip
+    pub skip: u16,
+
+    /// Sync_Timeout
+    pub sync_timeout: Units10ms,
+
+    /// CTE_Type
+    pub cte_type: u8,
+}
+
+impl SetPeriodicAdvertisingSyncTransferParameters {
+    /// Create [`SetPeriodicAdvertisingSyncTransferParameters`] from Parameters.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ble_data_struct::hci::le_periodic_advertising_sync_transfer::SetPeriodicAdvertisingSyncTransferParameters;
+    /// use ble_data_struct::data_types::time_units::Units10ms;
+    ///
+    /// let connection_handle = 0x0001u16;
+    /// let mode = 0x02u8;
+    /// let skip = 0x0003u16;
+    /// let sync_timeout = 0x0004u16;
+    /// let cte_type = 0x00u8;
+    /// let result = SetPeriodicAdvertisingSyncTransferParameters::new(connection_handle, mode, skip, Units10ms(sync_timeout), cte_type);
+    /// assert_eq!(connection_handle, result.connection_handle);
+    /// assert_eq!(mode, result.mode);
+    /// assert_eq!(skip, result.skip);
+    /// assert_eq!(Units10ms(sync_timeout), result.sync_timeout);
+    /// assert_eq!(cte_type, result.cte_type);
+    /// ```
+    pub fn new(
+        connection_handle: u16,
+        mode: u8,
+        skip: u16,
+        sync_timeout: Units10ms,
+        cte_type: u8,
+    ) -> Self {
+        Self {
+            connection_handle,
+            mode,
+            skip,
+            sync_timeout,
+            cte_type,
+        }
+    }
+}
+
+impl TryFrom<&Vec<u8>> for SetPeriodicAdvertisingSyncTransferParameters {
+    type Error = String;
+    /// Create [`SetPeriodicAdvertisingSyncTransferParameters`] from [`Vec<u8>`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ble_data_struct::hci::le_periodic_advertising_sync_transfer::SetPeriodicAdvertisingSyncTransferParameters;
+    ///
+    /// let data: Vec<u8> = [0x01u8, 0x00u8, 0x02u8, 0x03u8, 0x00u8, 0x04u8, 0x00u8, 0x00u8].to_vec();
+    /// let result = SetPeriodicAdvertisingSyncTransferParameters::try_from(&data);
+    /// assert!(result.is_ok());
+    ///
+    /// let data: Vec<u8> = Vec::new();
+    /// let result = SetPeriodicAdvertisingSyncTransferParameters::try_from(&data);
+    /// assert!(result.is_err());
+    /// assert_eq!(
+    ///     format!("Invalid data size :{}", data.len()),
+    ///     result.unwrap_err()
+    /// );
+    /// ```
+    fn try_from(value: &Vec<u8>) -> Result<Self, String> {
+        let len = value.len();
+        if len < 8 {
+            return Err(format!("Invalid data size :{}", len).to_string());
+        }
+        Ok(Self {
+            connection_handle: u16::from_le_bytes(value[0..2].try_into().unwrap()),
+            mode: value[2],
+            skip: u16::from_le_bytes(value[3..5].try_into().unwrap()),
+            sync_timeout: Units10ms(u16::from_le_bytes(value[5..7].try_into().unwrap())),
+            cte_type: value[7],
+        })
+    }
+}
+
+impl Into<Vec<u8>> for SetPeriodicAdvertisingSyncTransferParameters {
+    /// Create [`Vec<u8>`] from [`SetPeriodicAdvertisingSyncTransferParameters`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ble_data_struct::hci::le_periodic_advertising_sync_transfer::SetPeriodicAdvertisingSyncTransferParameters;
+    /// use ble_data_struct::data_types::time_units::Units10ms;
+    ///
+    /// let connection_handle = 0x0001u16;
+    /// let mode = 0x02u8;
+    /// let skip = 0x0003u16;
+    /// let sync_timeout = 0x0004u16;
+    /// let cte_type = 0x00u8;
+    /// let result = SetPeriodicAdvertisingSyncTransferParameters::new(connection_handle, mode, skip, Units10ms(sync_timeout), cte_type);
+    /// let into_data: Vec<u8> = result.into();
+    /// assert_eq!([0x01u8, 0x00u8, 0x02u8, 0x03u8, 0x00u8, 0x04u8, 0x00u8, 0x00u8].to_vec(), into_data);
+    /// ```
+    fn into(self) -> Vec<u8> {
+        let mut data: Vec<u8> = Vec::new();
+        data.append(&mut self.connection_handle.to_le_bytes().to_vec());
+        data.push(self.mode);
+        data.append(&mut self.skip.to_le_bytes().to_vec());
+        data.append(&mut self.sync_timeout.0.to_le_bytes().to_vec());
+        data.push(self.cte_type);
+        return data;
+    }
+}
+
+impl HciCommand for SetPeriodicAdvertisingSyncTransferParameters {
+    /// return `0x205c`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ble_data_struct::hci::{hci_command::HciCommand, le_periodic_advertising_sync_transfer::SetPeriodicAdvertisingSyncTransferParameters};
+    ///
+    /// assert_eq!(0x205c, SetPeriodicAdvertisingSyncTransferParameters::opcode());
+    /// ```
+    fn opcode() -> u16 {
+        0x205c
+    }
+}
+
+/// HCI_LE_Set_Default_Periodic_Advertising_Sync_Transfer_Parameters command parameters.
+#[derive(Debug, PartialEq, Clone)]
+pub struct SetDefaultPeriodicAdvertisingSyncTransferParameters {
+    /// Mode
+    pub mode: u8,
+
+    /// Skip
+    pub skip: u16,
+
+    /// Sync_Timeout
+    pub sync_timeout: Units10ms,
+
+    /// CTE_Type
+    pub cte_type: u8,
+}
+
+impl SetDefaultPeriodicAdvertisingSyncTransferParameters {
+    /// Create [`SetDefaultPeriodicAdvertisingSyncTransferParameters`] from Parameters.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ble_data_struct::hci::le_periodic_advertising_sync_transfer::SetDefaultPeriodicAdvertisingSyncTransferParameters;
+    /// use ble_data_struct::data_types::time_units::Units10ms;
+    ///
+    /// let mode = 0x02u8;
+    /// let skip = 0x0003u16;
+    /// let sync_timeout = 0x0004u16;
+    /// let cte_type = 0x00u8;
+    /// let result = SetDefaultPeriodicAdvertisingSyncTransferParameters::new(mode, skip, Units10ms(sync_timeout), cte_type);
+    /// assert_eq!(mode, result.mode);
+    /// assert_eq!(skip, result.skip);
+    /// assert_eq!(Units10ms(sync_timeout), result.sync_timeout);
+    /// assert_eq!(cte_type, result.cte_type);
+    /// ```
+    pub fn new(mode: u8, skip: u16, sync_timeout: Units10ms, cte_type: u8) -> Self {
+        Self {
+            mode,
+            skip,
+            sync_timeout,
+            cte_type,
+        }
+    }
+}
+
+impl TryFrom<&Vec<u8>> for SetDefaultPeriodicAdvertisingSyncTransferParameters {
+    type Error = String;
+    /// Create [`SetDefaultPeriodicAdvertisingSyncTransferParameters`] from [`Vec<u8>`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ble_data_struct::hci::le_periodic_advertising_sync_transfer::SetDefaultPeriodicAdvertisingSyncTransferParameters;
+    ///
+    /// let data: Vec<u8> = [0x02u8, 0x03u8, 0x00u8, 0x04u8, 0x00u8, 0x00u8].to_vec();
+    /// let result = SetDefaultPeriodicAdvertisingSyncTransferParameters::try_from(&data);
+    /// assert!(result.is_ok());
+    ///
+    /// let data: Vec<u8> = Vec::new();
+    /// let result = SetDefaultPeriodicAdvertisingSyncTransferParameters::try_from(&data);
+    /// assert!(result.is_err());
+    /// assert_eq!(
+    ///     format!("Invalid data size :{}", data.len()),
+    ///     result.unwrap_err()
+    /// );
+    /// ```
+    fn try_from(value: &Vec<u8>) -> Result<Self, String> {
+        let len = value.len();
+        if len < 6 {
+            return Err(format!("Invalid data size :{}", len).to_string());
+        }
+        Ok(Self {
+            mode: value[0],
+            skip: u16::from_le_bytes(value[1..3].try_into().unwrap()),
+            sync_timeout: Units10ms(u16::from_le_bytes(value[3..5].try_into().unwrap())),
+            cte_type: value[5],
+        })
+    }
+}
+
+impl Into<Vec<u8>> for SetDefaultPeriodicAdvertisingSyncTransferParameters {
+    /// Create [`Vec<u8>`] from [`SetDefaultPeriodicAdvertisingSyncTransferParameters`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ble_data_struct::hci::le_periodic_advertising_sync_transfer::SetDefaultPeriodicAdvertisingSyncTransferParameters;
+    /// use ble_data_struct::data_types::time_units::Units10ms;
+    ///
+    /// let mode = 0x02u8;
+    /// let skip = 0x0003u16;
+    /// let sync_timeout = 0x0004u16;
+    /// let cte_type = 0x00u8;
+    /// let result = SetDefaultPeriodicAdvertisingSyncTransferParameters::new(mode, skip, Units10ms(sync_timeout), cte_type);
+    /// let into_data: Vec<u8> = result.into();
+    /// assert_eq!([0x02u8, 0x03u8, 0x00u8, 0x04u8, 0x00u8, 0x00u8].to_vec(), into_data);
+    /// ```
+    fn into(self) -> Vec<u8> {
+        let mut data: Vec<u8> = Vec::new();
+        data.push(self.mode);
+        data.append(&mut self.skip.to_le_bytes().to_vec());
+        data.append(&mut self.sync_timeout.0.to_le_bytes().to_vec());
+        data.push(self.cte_type);
+        return data;
+    }
+}
+
+impl HciCommand for SetDefaultPeriodicAdvertisingSyncTransferParameters {
+    /// return `0x205d`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ble_data_struct::hci::{hci_command::HciCommand, le_periodic_advertising_sync_transfer::SetDefaultPeriodicAdvertisingSyncTransferParameters};
+    ///
+    /// assert_eq!(0x205d, SetDefaultPeriodicAdvertisingSyncTransferParameters::opcode());
+    /// ```
+    fn opcode() -> u16 {
+        0x205d
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        data_types::time_units::Units10ms,
+        hci::{
+            hci_command::HciCommand,
+            le_periodic_advertising_sync_transfer::{
+                PeriodicAdvertisingSetInfoTransfer, PeriodicAdvertisingSyncTransfer,
+                SetDefaultPeriodicAdvertisingSyncTransferParameters,
+                SetPeriodicAdvertisingSyncTransferParameters,
+            },
+        },
+    };
+
+    #[test]
+    fn test_periodic_advertising_sync_transfer_new() {
+        let connection_handle = 0x0001u16;
+        let service_data = 0x0002u16;
+        let sync_handle = 0x0003u16;
+        let result =
+            PeriodicAdvertisingSyncTransfer::new(connection_handle, service_data, sync_handle);
+        assert_eq!(connection_handle, result.connection_handle);
+        assert_eq!(service_data, result.service_data);
+        assert_eq!(sync_handle, result.sync_handle);
+    }
+
+    #[test]
+    fn test_periodic_advertising_sync_transfer_try_from() {
+        let connection_handle = 0x0001u16;
+        let service_data = 0x0002u16;
+        let sync_handle = 0x0003u16;
+        let data: Vec<u8> = [0x01u8, 0x00u8, 0x02u8, 0x00u8, 0x03u8, 0x00u8].to_vec();
+        let result = PeriodicAdvertisingSyncTransfer::try_from(&data);
+        assert!(result.is_ok());
+        let result = result.unwrap();
+        assert_eq!(connection_handle, result.connection_handle);
+        assert_eq!(service_data, result.service_data);
+        assert_eq!(sync_handle, result.sync_handle);
+
+        let data: Vec<u8> = vec![0u8; 5];
+        let result = PeriodicAdvertisingSyncTransfer::try_from(&data);
+        assert!(result.is_err());
+        assert_eq!(
+            format!("Invalid data size :{}", data.len()),
+            result.unwrap_err()
+        );
+    }
+
+    #[test]
+    fn test_periodic_advertising_sync_transfer_into() {
+        let connection_handle = 0x0001u16;
+        let service_data = 0x0002u16;
+        let sync_handle = 0x0003u16;
+        let data: Vec<u8> = [0x01u8, 0x00u8, 0x02u8, 0x00u8, 0x03u8, 0x00u8].to_vec();
+        let into_data: Vec<u8> =
+            PeriodicAdvertisingSyncTransfer::new(connection_handle, service_data, sync_handle)
+                .into();
+        assert_eq!(data, into_data);
+
+        let into_data: Vec<u8> = PeriodicAdvertisingSyncTransfer::try_from(&data)
+            .unwrap()
+            .into();
+        assert_eq!(data, into_data);
+    }
+
+    #[test]
+    fn test_periodic_advertising_sync_transfer_opcode() {
+        assert_eq!(0x205a, PeriodicAdvertisingSyncTransfer::opcode());
+    }
+
+    #[test]
+    fn test_periodic_advertising_set_info_transfer_new() {
+        let connection_handle = 0x0001u16;
+        let service_data = 0x0002u16;
+        let advertising_handle = 0x03u8;
+        let result = PeriodicAdvertisingSetInfoTransfer::new(
+            connection_handle,
+            service_data,
+            advertising_handle,
+        );
+        assert_eq!(connection_handle, result.connection_handle);
+        assert_eq!(service_data, result.service_data);
+        assert_eq!(advertising_handle, result.advertising_handle);
+    }
+
+    #[test]
+    fn test_periodic_advertising_set_info_transfer_try_from() {
+        let connection_handle = 0x0001u16;
+        let service_data = 0x0002u16;
+        let advertising_handle = 0x03u8;
+        let data: Vec<u8> = [0x01u8, 0x00u8, 0x02u8, 0x00u8, 0x03u8].to_vec();
+        let result = PeriodicAdvertisingSetInfoTransfer::try_from(&data);
+        assert!(result.is_ok());
+        let result = result.unwrap();
+        assert_eq!(connection_handle, result.connection_handle);
+        assert_eq!(service_data, result.service_data);
+        assert_eq!(advertising_handle, result.advertising_handle);
+
+        let data: Vec<u8> = vec![0u8; 4];
+        let result = PeriodicAdvertisingSetInfoTransfer::try_from(&data);
+        assert!(result.is_err());
+        assert_eq!(
+            format!("Invalid data size :{}", data.len()),
+            result.unwrap_err()
+        );
+    }
+
+    #[test]
+    fn test_periodic_advertising_set_info_transfer_into() {
+        let connection_handle = 0x0001u16;
+        let service_data = 0x0002u16;
+        let advertising_handle = 0x03u8;
+        let data: Vec<u8> = [0x01u8, 0x00u8, 0x02u8, 0x00u8, 0x03u8].to_vec();
+        let into_data: Vec<u8> = PeriodicAdvertisingSetInfoTransfer::new(
+            connection_handle,
+            service_data,
+            advertising_handle,
+        )
+        .into();
+        assert_eq!(data, into_data);
+
+        let into_data: Vec<u8> = PeriodicAdvertisingSetInfoTransfer::try_from(&data)
+            .unwrap()
+            .into();
+        assert_eq!(data, into_data);
+    }
+
+    #[test]
+    fn test_periodic_advertising_set_info_transfer_opcode() {
+        assert_eq!(0x205b, PeriodicAdvertisingSetInfoTransfer::opcode());
+    }
+
+    #[test]
+    fn test_set_periodic_advertising_sync_transfer_parameters_new() {
+        let connection_handle = 0x0001u16;
+        let mode = 0x02u8;
+        let skip = 0x0003u16;
+        let sync_timeout = 0x0004u16;
+        let cte_type = 0x00u8;
+        let result = SetPeriodicAdvertisingSyncTransferParameters::new(
+            connection_handle,
+            mode,
+            skip,
+            Units10ms(sync_timeout),
+            cte_type,
+        );
+        assert_eq!(connection_handle, result.connection_handle);
+        assert_eq!(mode, result.mode);
+        assert_eq!(skip, result.skip);
+        assert_eq!(Units10ms(sync_timeout), result.sync_timeout);
+        assert_eq!(cte_type, result.cte_type);
+    }
+
+    #[test]
+    fn test_set_periodic_advertising_sync_transfer_parameters_try_from() {
+        let connection_handle = 0x0001u16;
+        let mode = 0x02u8;
+        let skip = 0x0003u16;
+        let sync_timeout = 0x0004u16;
+        let cte_type = 0x00u8;
+        let data: Vec<u8> = [
+            0x01u8, 0x00u8, 0x02u8, 0x03u8, 0x00u8, 0x04u8, 0x00u8, 0x00u8,
+        ]
+        .to_vec();
+        let result = SetPeriodicAdvertisingSyncTransferParameters::try_from(&data);
+        assert!(result.is_ok());
+        let result = result.unwrap();
+        assert_eq!(connection_handle, result.connection_handle);
+        assert_eq!(mode, result.mode);
+        assert_eq!(skip, result.skip);
+        assert_eq!(Units10ms(sync_timeout), result.sync_timeout);
+        assert_eq!(cte_type, result.cte_type);
+
+        let data: Vec<u8> = vec![0u8; 7];
+        let result = SetPeriodicAdvertisingSyncTransferParameters::try_from(&data);
+        assert!(result.is_err());
+        assert_eq!(
+            format!("Invalid data size :{}", data.len()),
+            result.unwrap_err()
+        );
+    }
+
+    #[test]
+    fn test_set_periodic_advertising_sync_transfer_parameters_into() {
+        let connection_handle = 0x0001u16;
+        let mode = 0x02u8;
+        let skip = 0x0003u16;
+        let sync_timeout = 0x0004u16;
+        let cte_type = 0x00u8;
+        let data: Vec<u8> = [
+            0x01u8, 0x00u8, 0x02u8, 0x03u8, 0x00u8, 0x04u8, 0x00u8, 0x00u8,
+        ]
+        .to_vec();
+        let into_data: Vec<u8> = SetPeriodicAdvertisingSyncTransferParameters::new(
+            connection_handle,
+            mode,
+            skip,
+            Units10ms(sync_timeout),
+            cte_type,
+        )
+        .into();
+        assert_eq!(data, into_data);
+
+        let into_data: Vec<u8> = SetPeriodicAdvertisingSyncTransferParameters::try_from(&data)
+            .unwrap()
+            .into();
+        assert_eq!(data, into_data);
+    }
+
+    #[test]
+    fn test_set_periodic_advertising_sync_transfer_parameters_opcode() {
+        assert_eq!(
+            0x205c,
+            SetPeriodicAdvertisingSyncTransferParameters::opcode()
+        );
+    }
+
+    #[test]
+    fn test_set_default_periodic_advertising_sync_transfer_parameters_new() {
+        let mode = 0x02u8;
+        let skip = 0x0003u16;
+        let sync_timeout = 0x0004u16;
+        let cte_type = 0x00u8;
+        let result = SetDefaultPeriodicAdvertisingSyncTransferParameters::new(
+            mode,
+            skip,
+            Units10ms(sync_timeout),
+            cte_type,
+        );
+        assert_eq!(mode, result.mode);
+        assert_eq!(skip, result.skip);
+        assert_eq!(Units10ms(sync_timeout), result.sync_timeout);
+        assert_eq!(cte_type, result.cte_type);
+    }
+
+    #[test]
+    fn test_set_default_periodic_advertising_sync_transfer_parameters_try_from() {
+        let mode = 0x02u8;
+        let skip = 0x0003u16;
+        let sync_timeout = 0x0004u16;
+        let cte_type = 0x00u8;
+        let data: Vec<u8> = [0x02u8, 0x03u8, 0x00u8, 0x04u8, 0x00u8, 0x00u8].to_vec();
+        let result = SetDefaultPeriodicAdvertisingSyncTransferParameters::try_from(&data);
+        assert!(result.is_ok());
+        let result = result.unwrap();
+        assert_eq!(mode, result.mode);
+        assert_eq!(skip, result.skip);
+        assert_eq!(Units10ms(sync_timeout), result.sync_timeout);
+        assert_eq!(cte_type, result.cte_type);
+
+        let data: Vec<u8> = vec![0u8; 5];
+        let result = SetDefaultPeriodicAdvertisingSyncTransferParameters::try_from(&data);
+        assert!(result.is_err());
+        assert_eq!(
+            format!("Invalid data size :{}", data.len()),
+            result.unwrap_err()
+        );
+    }
+
+    #[test]
+    fn test_set_default_periodic_advertising_sync_transfer_parameters_into() {
+        let mode = 0x02u8;
+        let skip = 0x0003u16;
+        let sync_timeout = 0x0004u16;
+        let cte_type = 0x00u8;
+        let data: Vec<u8> = [0x02u8, 0x03u8, 0x00u8, 0x04u8, 0x00u8, 0x00u8].to_vec();
+        let into_data: Vec<u8> = SetDefaultPeriodicAdvertisingSyncTransferParameters::new(
+            mode,
+            skip,
+            Units10ms(sync_timeout),
+            cte_type,
+        )
+        .into();
+        assert_eq!(data, into_data);
+
+        let into_data: Vec<u8> =
+            SetDefaultPeriodicAdvertisingSyncTransferParameters::try_from(&data)
+                .unwrap()
+                .into();
+        assert_eq!(data, into_data);
+    }
+
+    #[test]
+    fn test_set_default_periodic_advertising_sync_transfer_parameters_opcode() {
+        assert_eq!(
+            0x205d,
+            SetDefaultPeriodicAdvertisingSyncTransferParameters::opcode()
+        );
+    }
+}
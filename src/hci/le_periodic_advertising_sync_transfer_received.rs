@@ -0,0 +1,527 @@
+//! LE Periodic Advertising Sync Transfer Received (PAST) HCI LE Meta event module.
+
+use crate::{
+    data_types::{address_type::AddressType, time_units::Units1_25ms},
+    hci::le_meta_event::LeMetaEvent,
+};
+
+/// Units: 1.25 ms
+///
+/// Periodic_Advertising_Interval
+pub const PERIODIC_ADVERTISING_INTERVAL_UNIT: f32 = 1.25;
+
+/// HCI_LE_Periodic_Advertising_Sync_Transfer_Received event parameters.
+#[derive(Debug, PartialEq, Clone)]
+pub struct PeriodicAdvertisingSyncTransferReceived {
+    /// Status
+    pub status: u8,
+
+    /// Connection_Handle
+    pub connection_handle: u16,
+
+    /// Service_Data
+    pub service_data: u16,
+
+    /// Sync_Handle
+    pub sync_handle: u16,
+
+    /// Advertising_SID
+    pub advertising_sid: u8,
+
+    /// Advertiser_Address_Type
+    pub advertiser_address_type: AddressType,
+
+    /// Advertiser_Address
+    pub advertiser_address: u64,
+
+    /// Advertiser_PHY
+    pub advertiser_phy: u8,
+
+    /// Periodic_Advertising_Interval
+    pub periodic_advertising_interval: Units1_25ms,
+
+    /// Advertiser_Clock_Accuracy
+    pub advertiser_clock_accuracy: u8,
+}
+
+impl PeriodicAdvertisingSyncTransferReceived {
+    /// Create [`PeriodicAdvertisingSyncTransferReceivedBuilder`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ble_data_struct::hci::le_periodic_advertising_sync_transfer_received::{
+    ///     PeriodicAdvertisingSyncTransferReceived, PeriodicAdvertisingSyncTransferReceivedBuilder,
+    /// };
+    ///
+    /// assert_eq!(
+    ///     PeriodicAdvertisingSyncTransferReceivedBuilder::new(),
+    ///     PeriodicAdvertisingSyncTransferReceived::builder()
+    /// );
+    /// ```
+    pub fn builder() -> PeriodicAdvertisingSyncTransferReceivedBuilder {
+        PeriodicAdvertisingSyncTransferReceivedBuilder::new()
+    }
+}
+
+impl TryFrom<&Vec<u8>> for PeriodicAdvertisingSyncTransferReceived {
+    type Error = String;
+    /// Create [`PeriodicAdvertisingSyncTransferReceived`] from [`Vec<u8>`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ble_data_struct::hci::le_periodic_advertising_sync_transfer_received::PeriodicAdvertisingSyncTransferReceived;
+    ///
+    /// let data: Vec<u8> = [0x00u8, 0x01u8, 0x00u8, 0x02u8, 0x00u8, 0x03u8, 0x00u8, 0x04u8, 0x00u8, 0x01u8, 0x02u8, 0x03u8, 0x04u8, 0x05u8, 0x06u8, 0x01u8, 0x06u8, 0x00u8, 0x05u8].to_vec();
+    /// let result = PeriodicAdvertisingSyncTransferReceived::try_from(&data);
+    /// assert!(result.is_ok());
+    ///
+    /// let data: Vec<u8> = Vec::new();
+    /// let result = PeriodicAdvertisingSyncTransferReceived::try_from(&data);
+    /// assert!(result.is_err());
+    /// assert_eq!(
+    ///     format!("Invalid data size :{}", data.len()),
+    ///     result.unwrap_err()
+    /// );
+    /// ```
+    fn try_from(value: &Vec<u8>) -> Result<Self, String> {
+        let len = value.len();
+        if len < 19 {
+            return Err(format!("Invalid data size :{}", len).to_string());
+        }
+        let advertiser_address = u64::from_le_bytes(
+            [value[9..15].to_vec(), vec![0u8; 2]]
+                .concat()
+                .try_into()
+                .unwrap(),
+        );
+        Ok(Self {
+            status: value[0],
+            connection_handle: u16::from_le_bytes(value[1..3].try_into().unwrap()),
+            service_data: u16::from_le_bytes(value[3..5].try_into().unwrap()),
+            sync_handle: u16::from_le_bytes(value[5..7].try_into().unwrap()),
+            advertising_sid: value[7],
+            advertiser_address_type: AddressType::from_hci(value[8], advertiser_address)?,
+            advertiser_address,
+            advertiser_phy: value[15],
+            periodic_advertising_interval: Units1_25ms(u16::from_le_bytes(
+                value[16..18].try_into().unwrap(),
+            )),
+            advertiser_clock_accuracy: value[18],
+        })
+    }
+}
+
+impl Into<Vec<u8>> for PeriodicAdvertisingSyncTransferReceived {
+    /// Create [`Vec<u8>`] from [`PeriodicAdvertisingSyncTransferReceived`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ble_data_struct::data_types::address_type::AddressType;
+    /// use ble_data_struct::data_types::time_units::Units1_25ms;
+    /// use ble_data_struct::hci::le_periodic_advertising_sync_transfer_received::PeriodicAdvertisingSyncTransferReceived;
+    ///
+    /// let status = 0x00u8;
+    /// let connection_handle = 0x0001u16;
+    /// let service_data = 0x0002u16;
+    /// let sync_handle = 0x0003u16;
+    /// let advertising_sid = 0x04u8;
+    /// let advertiser_address_type = AddressType::Public;
+    /// let advertiser_address = 0x060504030201u64;
+    /// let advertiser_phy = 0x01u8;
+    /// let periodic_advertising_interval = 0x0006u16;
+    /// let advertiser_clock_accuracy = 0x05u8;
+    /// let result = PeriodicAdvertisingSyncTransferReceived::builder()
+    ///     .status(status)
+    ///     .connection_handle(connection_handle)
+    ///     .service_data(service_data)
+    ///     .sync_handle(sync_handle)
+    ///     .advertising_sid(advertising_sid)
+    ///     .advertiser_address_type(advertiser_address_type)
+    ///     .advertiser_address(advertiser_address)
+    ///     .advertiser_phy(advertiser_phy)
+    ///     .periodic_advertising_interval(Units1_25ms(periodic_advertising_interval))
+    ///     .advertiser_clock_accuracy(advertiser_clock_accuracy)
+    ///     .build();
+    /// let into_data: Vec<u8> = result.into();
+    /// assert_eq!([0x00u8, 0x01u8, 0x00u8, 0x02u8, 0x00u8, 0x03u8, 0x00u8, 0x04u8, 0x00u8, 0x01u8, 0x02u8, 0x03u8, 0x04u8, 0x05u8, 0x06u8, 0x01u8, 0x06u8, 0x00u8, 0x05u8].to_vec(), into_data);
+    /// ```
+    fn into(self) -> Vec<u8> {
+        let mut data: Vec<u8> = Vec::new();
+        data.push(self.status);
+        data.append(&mut self.connection_handle.to_le_bytes().to_vec());
+        data.append(&mut self.service_data.to_le_bytes().to_vec());
+        data.append(&mut self.sync_handle.to_le_bytes().to_vec());
+        data.push(self.advertising_sid);
+        data.push(self.advertiser_address_type.hci_address_type());
+        data.append(&mut self.advertiser_address.to_le_bytes()[..6].to_vec());
+        data.push(self.advertiser_phy);
+        data.append(&mut self.periodic_advertising_interval.0.to_le_bytes().to_vec());
+        data.push(self.advertiser_clock_accuracy);
+        return data;
+    }
+}
+
+impl LeMetaEvent for PeriodicAdvertisingSyncTransferReceived {
+    /// return `0x18`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ble_data_struct::hci::{le_meta_event::LeMetaEvent, le_periodic_advertising_sync_transfer_received::PeriodicAdvertisingSyncTransferReceived};
+    ///
+    /// assert_eq!(0x18, PeriodicAdvertisingSyncTransferReceived::subevent_code());
+    /// ```
+    fn subevent_code() -> u8 {
+        0x18
+    }
+}
+
+/// [`PeriodicAdvertisingSyncTransferReceived`] builder.
+///
+/// Fields not set keep the default value (success, LE 1M PHY, minimum Periodic_Advertising_Interval).
+#[derive(Debug, PartialEq, Clone)]
+pub struct PeriodicAdvertisingSyncTransferReceivedBuilder {
+    /// Status
+    status: u8,
+
+    /// Connection_Handle
+    connection_handle: u16,
+
+    /// Service_Data
+    service_data: u16,
+
+    /// Sync_Handle
+    sync_handle: u16,
+
+    /// Advertising_SID
+    advertising_sid: u8,
+
+    /// Advertiser_Address_Type
+    advertiser_address_type: AddressType,
+
+    /// Advertiser_Address
+    advertiser_address: u64,
+
+    /// Advertiser_PHY
+    advertiser_phy: u8,
+
+    /// Periodic_Advertising_Interval
+    periodic_advertising_interval: Units1_25ms,
+
+    /// Advertiser_Clock_Accuracy
+    advertiser_clock_accuracy: u8,
+}
+
+impl PeriodicAdvertisingSyncTransferReceivedBuilder {
+    /// Create [`PeriodicAdvertisingSyncTransferReceivedBuilder`] with default values.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ble_data_struct::data_types::address_type::AddressType;
+    /// use ble_data_struct::data_types::time_units::Units1_25ms;
+    /// use ble_data_struct::hci::le_periodic_advertising_sync_transfer_received::PeriodicAdvertisingSyncTransferReceivedBuilder;
+    ///
+    /// let result = PeriodicAdvertisingSyncTransferReceivedBuilder::new().build();
+    /// assert_eq!(0x00, result.status);
+    /// assert_eq!(AddressType::Public, result.advertiser_address_type);
+    /// assert_eq!(0x01, result.advertiser_phy);
+    /// assert_eq!(Units1_25ms(0x0006), result.periodic_advertising_interval);
+    /// ```
+    pub fn new() -> Self {
+        Self {
+            status: 0x00,
+            connection_handle: 0x0000,
+            service_data: 0x0000,
+            sync_handle: 0x0000,
+            advertising_sid: 0x00,
+            advertiser_address_type: AddressType::Public,
+            advertiser_address: 0x000000000000,
+            advertiser_phy: 0x01,
+            periodic_advertising_interval: Units1_25ms(0x0006),
+            advertiser_clock_accuracy: 0x00,
+        }
+    }
+
+    /// Set `Status`.
+    pub fn status(mut self, status: u8) -> Self {
+        self.status = status;
+        self
+    }
+
+    /// Set `Connection_Handle`.
+    pub fn connection_handle(mut self, connection_handle: u16) -> Self {
+        self.connection_handle = connection_handle;
+        self
+    }
+
+    /// Set `Service_Data`.
+    pub fn service_data(mut self, service_data: u16) -> Self {
+        self.service_data = service_data;
+        self
+    }
+
+    /// Set `Sync_Handle`.
+    pub fn sync_handle(mut self, sync_handle: u16) -> Self {
+        self.sync_handle = sync_handle;
+        self
+    }
+
+    /// Set `Advertising_SID`.
+    pub fn advertising_sid(mut self, advertising_sid: u8) -> Self {
+        self.advertising_sid = advertising_sid;
+        self
+    }
+
+    /// Set `Advertiser_Address_Type`.
+    pub fn advertiser_address_type(mut self, advertiser_address_type: AddressType) -> Self {
+        self.advertiser_address_type = advertiser_address_type;
+        self
+    }
+
+    /// Set `Advertiser_Address`.
+    pub fn advertiser_address(mut self, advertiser_address: u64) -> Self {
+        self.advertiser_address = advertiser_address;
+        self
+    }
+
+    /// Set `Advertiser_PHY`.
+    pub fn advertiser_phy(mut self, advertiser_phy: u8) -> Self {
+        self.advertiser_phy = advertiser_phy;
+        self
+    }
+
+    /// Set `Periodic_Advertising_Interval`.
+    pub fn periodic_advertising_interval(
+        mut self,
+        periodic_advertising_interval: Units1_25ms,
+    ) -> Self {
+        self.periodic_advertising_interval = periodic_advertising_interval;
+        self
+    }
+
+    /// Set `Advertiser_Clock_Accuracy`.
+    pub fn advertiser_clock_accuracy(mut self, advertiser_clock_accuracy: u8) -> Self {
+        self.advertiser_clock_accuracy = advertiser_clock_accuracy;
+        self
+    }
+
+    /// Create [`PeriodicAdvertisingSyncTransferReceived`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ble_data_struct::data_types::address_type::AddressType;
+    /// use ble_data_struct::data_types::time_units::Units1_25ms;
+    /// use ble_data_struct::hci::le_periodic_advertising_sync_transfer_received::PeriodicAdvertisingSyncTransferReceived;
+    ///
+    /// let result = PeriodicAdvertisingSyncTransferReceived::builder()
+    ///     .connection_handle(0x0001)
+    ///     .service_data(0x0002)
+    ///     .sync_handle(0x0003)
+    ///     .advertising_sid(0x04)
+    ///     .advertiser_address(0x060504030201)
+    ///     .periodic_advertising_interval(Units1_25ms(0x0006))
+    ///     .advertiser_clock_accuracy(0x05)
+    ///     .build();
+    /// assert_eq!(0x0001, result.connection_handle);
+    /// assert_eq!(0x0002, result.service_data);
+    /// assert_eq!(0x0003, result.sync_handle);
+    /// assert_eq!(0x04, result.advertising_sid);
+    /// assert_eq!(AddressType::Public, result.advertiser_address_type);
+    /// assert_eq!(0x060504030201, result.advertiser_address);
+    /// assert_eq!(Units1_25ms(0x0006), result.periodic_advertising_interval);
+    /// assert_eq!(0x05, result.advertiser_clock_accuracy);
+    /// ```
+    pub fn build(&self) -> PeriodicAdvertisingSyncTransferReceived {
+        PeriodicAdvertisingSyncTransferReceived {
+            status: self.status,
+            connection_handle: self.connection_handle,
+            service_data: self.service_data,
+            sync_handle: self.sync_handle,
+            advertising_sid: self.advertising_sid,
+            advertiser_address_type: self.advertiser_address_type,
+            advertiser_address: self.advertiser_address,
+            advertiser_phy: self.advertiser_phy,
+            periodic_advertising_interval: self.periodic_advertising_interval,
+            advertiser_clock_accuracy: self.advertiser_clock_accuracy,
+        }
+    }
+}
+
+impl Default for PeriodicAdvertisingSyncTransferReceivedBuilder {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        data_types::{
+            address_type::{AddressType, RandomAddressType},
+            time_units::Units1_25ms,
+        },
+        hci::{
+            le_meta_event::LeMetaEvent,
+            le_periodic_advertising_sync_transfer_received::{
+                PeriodicAdvertisingSyncTransferReceived,
+                PeriodicAdvertisingSyncTransferReceivedBuilder,
+            },
+        },
+    };
+
+    #[test]
+    fn test_periodic_advertising_sync_transfer_received_builder() {
+        let status = 0x00u8;
+        let connection_handle = 0x0001u16;
+        let service_data = 0x0002u16;
+        let sync_handle = 0x0003u16;
+        let advertising_sid = 0x04u8;
+        let advertiser_address_type = AddressType::Public;
+        let advertiser_address = 0x060504030201u64;
+        let advertiser_phy = 0x01u8;
+        let periodic_advertising_interval = 0x0006u16;
+        let advertiser_clock_accuracy = 0x05u8;
+        let result = PeriodicAdvertisingSyncTransferReceived::builder()
+            .status(status)
+            .connection_handle(connection_handle)
+            .service_data(service_data)
+            .sync_handle(sync_handle)
+            .advertising_sid(advertising_sid)
+            .advertiser_address_type(advertiser_address_type)
+            .advertiser_address(advertiser_address)
+            .advertiser_phy(advertiser_phy)
+            .periodic_advertising_interval(Units1_25ms(periodic_advertising_interval))
+            .advertiser_clock_accuracy(advertiser_clock_accuracy)
+            .build();
+        assert_eq!(status, result.status);
+        assert_eq!(connection_handle, result.connection_handle);
+        assert_eq!(service_data, result.service_data);
+        assert_eq!(sync_handle, result.sync_handle);
+        assert_eq!(advertising_sid, result.advertising_sid);
+        assert_eq!(advertiser_address_type, result.advertiser_address_type);
+        assert_eq!(advertiser_address, result.advertiser_address);
+        assert_eq!(advertiser_phy, result.advertiser_phy);
+        assert_eq!(
+            Units1_25ms(periodic_advertising_interval),
+            result.periodic_advertising_interval
+        );
+        assert_eq!(advertiser_clock_accuracy, result.advertiser_clock_accuracy);
+
+        assert_eq!(
+            PeriodicAdvertisingSyncTransferReceivedBuilder::default(),
+            PeriodicAdvertisingSyncTransferReceived::builder()
+        );
+        let result = PeriodicAdvertisingSyncTransferReceived::builder().build();
+        assert_eq!(0x00, result.status);
+        assert_eq!(AddressType::Public, result.advertiser_address_type);
+        assert_eq!(0x01, result.advertiser_phy);
+        assert_eq!(Units1_25ms(0x0006), result.periodic_advertising_interval);
+    }
+
+    #[test]
+    fn test_periodic_advertising_sync_transfer_received_try_from() {
+        let status = 0x00u8;
+        let connection_handle = 0x0001u16;
+        let service_data = 0x0002u16;
+        let sync_handle = 0x0003u16;
+        let advertising_sid = 0x04u8;
+        let advertiser_address_type = AddressType::Public;
+        let advertiser_address = 0x060504030201u64;
+        let advertiser_phy = 0x01u8;
+        let periodic_advertising_interval = 0x0006u16;
+        let advertiser_clock_accuracy = 0x05u8;
+        let data: Vec<u8> = [
+            0x00u8, 0x01u8, 0x00u8, 0x02u8, 0x00u8, 0x03u8, 0x00u8, 0x04u8, 0x00u8, 0x01u8, 0x02u8,
+            0x03u8, 0x04u8, 0x05u8, 0x06u8, 0x01u8, 0x06u8, 0x00u8, 0x05u8,
+        ]
+        .to_vec();
+        let result = PeriodicAdvertisingSyncTransferReceived::try_from(&data);
+        assert!(result.is_ok());
+        let result = result.unwrap();
+        assert_eq!(status, result.status);
+        assert_eq!(connection_handle, result.connection_handle);
+        assert_eq!(service_data, result.service_data);
+        assert_eq!(sync_handle, result.sync_handle);
+        assert_eq!(advertising_sid, result.advertising_sid);
+        assert_eq!(advertiser_address_type, result.advertiser_address_type);
+        assert_eq!(advertiser_address, result.advertiser_address);
+        assert_eq!(advertiser_phy, result.advertiser_phy);
+        assert_eq!(
+            Units1_25ms(periodic_advertising_interval),
+            result.periodic_advertising_interval
+        );
+        assert_eq!(advertiser_clock_accuracy, result.advertiser_clock_accuracy);
+
+        let data: Vec<u8> = vec![0u8; 18];
+        let result = PeriodicAdvertisingSyncTransferReceived::try_from(&data);
+        assert!(result.is_err());
+        assert_eq!(
+            format!("Invalid data size :{}", data.len()),
+            result.unwrap_err()
+        );
+
+        let mut data: Vec<u8> = vec![0u8; 19];
+        data[8] = 0x01;
+        data[14] = 0xc0;
+        let result = PeriodicAdvertisingSyncTransferReceived::try_from(&data);
+        assert_eq!(
+            AddressType::Random(RandomAddressType::Static),
+            result.unwrap().advertiser_address_type
+        );
+
+        data[8] = 0x04;
+        let result = PeriodicAdvertisingSyncTransferReceived::try_from(&data);
+        assert_eq!(Err("Invalid address type :4".to_string()), result);
+    }
+
+    #[test]
+    fn test_periodic_advertising_sync_transfer_received_into() {
+        let status = 0x00u8;
+        let connection_handle = 0x0001u16;
+        let service_data = 0x0002u16;
+        let sync_handle = 0x0003u16;
+        let advertising_sid = 0x04u8;
+        let advertiser_address_type = AddressType::Public;
+        let advertiser_address = 0x060504030201u64;
+        let advertiser_phy = 0x01u8;
+        let periodic_advertising_interval = 0x0006u16;
+        let advertiser_clock_accuracy = 0x05u8;
+        let data: Vec<u8> = [
+            0x00u8, 0x01u8, 0x00u8, 0x02u8, 0x00u8, 0x03u8, 0x00u8, 0x04u8, 0x00u8, 0x01u8, 0x02u8,
+            0x03u8, 0x04u8, 0x05u8, 0x06u8, 0x01u8, 0x06u8, 0x00u8, 0x05u8,
+        ]
+        .to_vec();
+        let into_data: Vec<u8> = PeriodicAdvertisingSyncTransferReceived::builder()
+            .status(status)
+            .connection_handle(connection_handle)
+            .service_data(service_data)
+            .sync_handle(sync_handle)
+            .advertising_sid(advertising_sid)
+            .advertiser_address_type(advertiser_address_type)
+            .advertiser_address(advertiser_address)
+            .advertiser_phy(advertiser_phy)
+            .periodic_advertising_interval(Units1_25ms(periodic_advertising_interval))
+            .advertiser_clock_accuracy(advertiser_clock_accuracy)
+            .build()
+            .into();
+        assert_eq!(data, into_data);
+
+        let into_data: Vec<u8> = PeriodicAdvertisingSyncTransferReceived::try_from(&data)
+            .unwrap()
+            .into();
+        assert_eq!(data, into_data);
+    }
+
+    #[test]
+    fn test_periodic_advertising_sync_transfer_received_subevent_code() {
+        assert_eq!(
+            0x18,
+            PeriodicAdvertisingSyncTransferReceived::subevent_code()
+        );
+    }
+}
@@ -0,0 +1,753 @@
+//! LE Set Advertising Parameters HCI command module.
+
+use crate::{
+    data_types::{advertising_interval::AdvertisingInterval, time_units::Slots625us},
+    hci::hci_command::HciCommand,
+};
+
+/// Advertising_Type: Connectable and scannable undirected advertising (ADV_IND)
+pub const ADV_IND: u8 = 0x00;
+
+/// Advertising_Type: Connectable high duty cycle directed advertising (ADV_DIRECT_IND, high duty cycle)
+pub const ADV_DIRECT_IND_HIGH_DUTY_CYCLE: u8 = 0x01;
+
+/// Advertising_Type: Scannable undirected advertising (ADV_SCAN_IND)
+pub const ADV_SCAN_IND: u8 = 0x02;
+
+/// Advertising_Type: Non connectable undirected advertising (ADV_NONCONN_IND)
+pub const ADV_NONCONN_IND: u8 = 0x03;
+
+/// Advertising_Type: Connectable low duty cycle directed advertising (ADV_DIRECT_IND, low duty cycle)
+pub const ADV_DIRECT_IND_LOW_DUTY_CYCLE: u8 = 0x04;
+
+/// Own_Address_Type: Public Device Address
+pub const OWN_ADDRESS_TYPE_PUBLIC: u8 = 0x00;
+
+/// Own_Address_Type: Random Device Address
+pub const OWN_ADDRESS_TYPE_RANDOM: u8 = 0x01;
+
+/// Own_Address_Type: Resolvable Private Address from resolving list, or Public Address if no entry
+pub const OWN_ADDRESS_TYPE_RESOLVABLE_OR_PUBLIC: u8 = 0x02;
+
+/// Own_Address_Type: Resolvable Private Address from resolving list, or Random Address if no entry
+pub const OWN_ADDRESS_TYPE_RESOLVABLE_OR_RANDOM: u8 = 0x03;
+
+/// Advertising_Interval_Min / Advertising_Interval_Max minimum value(20 ms).
+pub const ADVERTISING_INTERVAL_MIN: u16 = 0x0020;
+
+/// Advertising_Interval_Min / Advertising_Interval_Max maximum value(10.24 s).
+pub const ADVERTISING_INTERVAL_MAX: u16 = 0x4000;
+
+/// Advertising_Channel_Map.
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Hash)]
+pub struct AdvertisingChannelMap {
+    /// Channel 37 shall be used
+    pub channel_37: bool,
+
+    /// Channel 38 shall be used
+    pub channel_38: bool,
+
+    /// Channel 39 shall be used
+    pub channel_39: bool,
+}
+
+impl AdvertisingChannelMap {
+    /// Create [`AdvertisingChannelMap`] from channel usage.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ble_data_struct::hci::le_set_advertising_parameters::AdvertisingChannelMap;
+    ///
+    /// let result = AdvertisingChannelMap::new(true, false, true);
+    /// assert!(result.channel_37);
+    /// assert!(!result.channel_38);
+    /// assert!(result.channel_39);
+    /// ```
+    pub fn new(channel_37: bool, channel_38: bool, channel_39: bool) -> Self {
+        Self {
+            channel_37,
+            channel_38,
+            channel_39,
+        }
+    }
+
+    /// Create [`AdvertisingChannelMap`] using all channels (default).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ble_data_struct::hci::le_set_advertising_parameters::AdvertisingChannelMap;
+    ///
+    /// assert_eq!(0b111, AdvertisingChannelMap::all().to_bits());
+    /// ```
+    pub fn all() -> Self {
+        Self::new(true, true, true)
+    }
+
+    /// Create [`AdvertisingChannelMap`] from HCI Advertising_Channel_Map.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ble_data_struct::hci::le_set_advertising_parameters::AdvertisingChannelMap;
+    ///
+    /// assert_eq!(
+    ///     AdvertisingChannelMap::new(false, true, false),
+    ///     AdvertisingChannelMap::from_bits(0b010)
+    /// );
+    /// ```
+    pub fn from_bits(value: u8) -> Self {
+        Self::new(value & 0b001 != 0, value & 0b010 != 0, value & 0b100 != 0)
+    }
+
+    /// Create HCI Advertising_Channel_Map.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ble_data_struct::hci::le_set_advertising_parameters::AdvertisingChannelMap;
+    ///
+    /// assert_eq!(0b101, AdvertisingChannelMap::new(true, false, true).to_bits());
+    /// ```
+    pub fn to_bits(&self) -> u8 {
+        (self.channel_37 as u8) | (self.channel_38 as u8) << 1 | (self.channel_39 as u8) << 2
+    }
+
+    /// check at least one channel is used.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ble_data_struct::hci::le_set_advertising_parameters::AdvertisingChannelMap;
+    ///
+    /// assert!(AdvertisingChannelMap::new(false, false, true).is_valid());
+    /// assert!(!AdvertisingChannelMap::new(false, false, false).is_valid());
+    /// ```
+    pub fn is_valid(&self) -> bool {
+        self.channel_37 || self.channel_38 || self.channel_39
+    }
+}
+
+/// Advertising_Filter_Policy.
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Hash)]
+pub enum AdvertisingFilterPolicy {
+    /// Process scan and connection requests from all devices (0x00)
+    ProcessAll,
+
+    /// Process connection requests from all devices and scan requests only from devices in the Filter Accept List (0x01)
+    ScanFilterAcceptList,
+
+    /// Process scan requests from all devices and connection requests only from devices in the Filter Accept List (0x02)
+    ConnectFilterAcceptList,
+
+    /// Process scan and connection requests only from devices in the Filter Accept List (0x03)
+    ScanAndConnectFilterAcceptList,
+}
+
+impl AdvertisingFilterPolicy {
+    /// Create [`AdvertisingFilterPolicy`] from HCI Advertising_Filter_Policy.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ble_data_struct::hci::le_set_advertising_parameters::AdvertisingFilterPolicy;
+    ///
+    /// assert_eq!(
+    ///     Ok(AdvertisingFilterPolicy::ScanFilterAcceptList),
+    ///     AdvertisingFilterPolicy::from_hci(0x01)
+    /// );
+    /// assert!(AdvertisingFilterPolicy::from_hci(0x04).is_err());
+    /// ```
+    pub fn from_hci(value: u8) -> Result<Self, String> {
+        match value {
+            0x00 => Ok(Self::ProcessAll),
+            0x01 => Ok(Self::ScanFilterAcceptList),
+            0x02 => Ok(Self::ConnectFilterAcceptList),
+            0x03 => Ok(Self::ScanAndConnectFilterAcceptList),
+            _ => Err(format!("Invalid advertising filter policy :{}", value)),
+        }
+    }
+
+    /// Get HCI Advertising_Filter_Policy.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ble_data_struct::hci::le_set_advertising_parameters::AdvertisingFilterPolicy;
+    ///
+    /// assert_eq!(0x03, AdvertisingFilterPolicy::ScanAndConnectFilterAcceptList.hci_value());
+    /// ```
+    pub const fn hci_value(&self) -> u8 {
+        match self {
+            Self::ProcessAll => 0x00,
+            Self::ScanFilterAcceptList => 0x01,
+            Self::ConnectFilterAcceptList => 0x02,
+            Self::ScanAndConnectFilterAcceptList => 0x03,
+        }
+    }
+}
+
+/// HCI_LE_Set_Advertising_Parameters command parameters.
+#[derive(Debug, PartialEq, Clone)]
+pub struct AdvertisingParameters {
+    /// Advertising_Interval_Min
+    pub advertising_interval_min: Slots625us,
+
+    /// Advertising_Interval_Max
+    pub advertising_interval_max: Slots625us,
+
+    /// Advertising_Type
+    pub advertising_type: u8,
+
+    /// Own_Address_Type
+    pub own_address_type: u8,
+
+    /// Peer_Address_Type
+    pub peer_address_type: u8,
+
+    /// Peer_Address
+    pub peer_address: u64,
+
+    /// Advertising_Channel_Map
+    pub advertising_channel_map: AdvertisingChannelMap,
+
+    /// Advertising_Filter_Policy
+    pub advertising_filter_policy: AdvertisingFilterPolicy,
+}
+
+impl AdvertisingParameters {
+    /// Create [`AdvertisingParameters`] from Parameters.
+    ///
+    /// Peer address is zero, all channels are used and requests from all devices are processed.
+    /// Use [`AdvertisingParameters::with_peer_address`], [`AdvertisingParameters::with_advertising_channel_map`]
+    /// and [`AdvertisingParameters::with_advertising_filter_policy`] for the other values.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ble_data_struct::hci::le_set_advertising_parameters::{
+    ///     AdvertisingChannelMap, AdvertisingFilterPolicy, AdvertisingParameters, ADV_IND,
+    ///     OWN_ADDRESS_TYPE_RANDOM,
+    /// };
+    /// use ble_data_struct::data_types::time_units::Slots625us;
+    ///
+    /// let result = AdvertisingParameters::new(
+    ///     Slots625us(0x0020),
+    ///     Slots625us(0x0040),
+    ///     ADV_IND,
+    ///     OWN_ADDRESS_TYPE_RANDOM,
+    /// );
+    /// assert_eq!(Slots625us(0x0020), result.advertising_interval_min);
+    /// assert_eq!(Slots625us(0x0040), result.advertising_interval_max);
+    /// assert_eq!(ADV_IND, result.advertising_type);
+    /// assert_eq!(OWN_ADDRESS_TYPE_RANDOM, result.own_address_type);
+    /// assert_eq!(0x00, result.peer_address_type);
+    /// assert_eq!(0x000000000000, result.peer_address);
+    /// assert_eq!(AdvertisingChannelMap::all(), result.advertising_channel_map);
+    /// assert_eq!(AdvertisingFilterPolicy::ProcessAll, result.advertising_filter_policy);
+    /// ```
+    pub fn new(
+        advertising_interval_min: Slots625us,
+        advertising_interval_max: Slots625us,
+        advertising_type: u8,
+        own_address_type: u8,
+    ) -> Self {
+        Self {
+            advertising_interval_min,
+            advertising_interval_max,
+            advertising_type,
+            own_address_type,
+            peer_address_type: 0x00,
+            peer_address: 0x000000000000,
+            advertising_channel_map: AdvertisingChannelMap::all(),
+            advertising_filter_policy: AdvertisingFilterPolicy::ProcessAll,
+        }
+    }
+
+    /// Set Peer_Address_Type and Peer_Address.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ble_data_struct::hci::le_set_advertising_parameters::{
+    ///     AdvertisingParameters, ADV_DIRECT_IND_LOW_DUTY_CYCLE, OWN_ADDRESS_TYPE_PUBLIC,
+    /// };
+    /// use ble_data_struct::data_types::time_units::Slots625us;
+    ///
+    /// let result = AdvertisingParameters::new(
+    ///     Slots625us(0x0020),
+    ///     Slots625us(0x0040),
+    ///     ADV_DIRECT_IND_LOW_DUTY_CYCLE,
+    ///     OWN_ADDRESS_TYPE_PUBLIC,
+    /// )
+    /// .with_peer_address(0x01, 0x060504030201);
+    /// assert_eq!(0x01, result.peer_address_type);
+    /// assert_eq!(0x060504030201, result.peer_address);
+    /// ```
+    pub fn with_peer_address(mut self, peer_address_type: u8, peer_address: u64) -> Self {
+        self.peer_address_type = peer_address_type;
+        self.peer_address = peer_address;
+        self
+    }
+
+    /// Set Advertising_Channel_Map.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ble_data_struct::hci::le_set_advertising_parameters::{
+    ///     AdvertisingChannelMap, AdvertisingParameters, ADV_IND, OWN_ADDRESS_TYPE_PUBLIC,
+    /// };
+    /// use ble_data_struct::data_types::time_units::Slots625us;
+    ///
+    /// let result = AdvertisingParameters::new(
+    ///     Slots625us(0x0020),
+    ///     Slots625us(0x0040),
+    ///     ADV_IND,
+    ///     OWN_ADDRESS_TYPE_PUBLIC,
+    /// )
+    /// .with_advertising_channel_map(AdvertisingChannelMap::new(true, false, false));
+    /// assert_eq!(0b001, result.advertising_channel_map.to_bits());
+    /// ```
+    pub fn with_advertising_channel_map(
+        mut self,
+        advertising_channel_map: AdvertisingChannelMap,
+    ) -> Self {
+        self.advertising_channel_map = advertising_channel_map;
+        self
+    }
+
+    /// Set Advertising_Filter_Policy.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ble_data_struct::hci::le_set_advertising_parameters::{
+    ///     AdvertisingFilterPolicy, AdvertisingParameters, ADV_IND, OWN_ADDRESS_TYPE_PUBLIC,
+    /// };
+    /// use ble_data_struct::data_types::time_units::Slots625us;
+    ///
+    /// let result = AdvertisingParameters::new(
+    ///     Slots625us(0x0020),
+    ///     Slots625us(0x0040),
+    ///     ADV_IND,
+    ///     OWN_ADDRESS_TYPE_PUBLIC,
+    /// )
+    /// .with_advertising_filter_policy(AdvertisingFilterPolicy::ScanFilterAcceptList);
+    /// assert_eq!(
+    ///     AdvertisingFilterPolicy::ScanFilterAcceptList,
+    ///     result.advertising_filter_policy
+    /// );
+    /// ```
+    pub fn with_advertising_filter_policy(
+        mut self,
+        advertising_filter_policy: AdvertisingFilterPolicy,
+    ) -> Self {
+        self.advertising_filter_policy = advertising_filter_policy;
+        self
+    }
+
+    /// Create undirected [`AdvertisingParameters`] advertising at the [`AdvertisingInterval`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ble_data_struct::{
+    ///     data_types::advertising_interval::AdvertisingInterval,
+    ///     hci::le_set_advertising_parameters::{
+    ///         AdvertisingChannelMap, AdvertisingFilterPolicy, AdvertisingParameters, ADV_IND,
+    ///         OWN_ADDRESS_TYPE_PUBLIC,
+    ///     },
+    /// };
+    /// use ble_data_struct::data_types::time_units::Slots625us;
+    ///
+    /// let result = AdvertisingParameters::from_advertising_interval(&AdvertisingInterval::new(Slots625us(0x00a0)));
+    /// assert_eq!(Slots625us(0x00a0), result.advertising_interval_min);
+    /// assert_eq!(Slots625us(0x00a0), result.advertising_interval_max);
+    /// assert_eq!(ADV_IND, result.advertising_type);
+    /// assert_eq!(OWN_ADDRESS_TYPE_PUBLIC, result.own_address_type);
+    /// assert_eq!(AdvertisingChannelMap::all(), result.advertising_channel_map);
+    /// assert_eq!(AdvertisingFilterPolicy::ProcessAll, result.advertising_filter_policy);
+    /// ```
+    pub fn from_advertising_interval(advertising_interval: &AdvertisingInterval) -> Self {
+        Self::new(
+            advertising_interval.advertising_interval,
+            advertising_interval.advertising_interval,
+            ADV_IND,
+            OWN_ADDRESS_TYPE_PUBLIC,
+        )
+    }
+
+    /// Create [`AdvertisingInterval`] data type from Advertising_Interval_Min.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ble_data_struct::{
+    ///     data_types::advertising_interval::AdvertisingInterval,
+    ///     hci::le_set_advertising_parameters::AdvertisingParameters,
+    /// };
+    /// use ble_data_struct::data_types::time_units::Slots625us;
+    ///
+    /// let advertising_interval = AdvertisingInterval::new(Slots625us(0x00a0));
+    /// let result = AdvertisingParameters::from_advertising_interval(&advertising_interval);
+    /// assert_eq!(advertising_interval, result.advertising_interval());
+    /// ```
+    pub fn advertising_interval(&self) -> AdvertisingInterval {
+        AdvertisingInterval::new(self.advertising_interval_min)
+    }
+
+    /// Advertising_Interval_Min(millis).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ble_data_struct::{
+    ///     data_types::advertising_interval::AdvertisingInterval,
+    ///     hci::le_set_advertising_parameters::AdvertisingParameters,
+    /// };
+    /// use ble_data_struct::data_types::time_units::Slots625us;
+    ///
+    /// let result = AdvertisingParameters::from_advertising_interval(&AdvertisingInterval::new(Slots625us(0x00a0)));
+    /// assert_eq!(100.0, result.advertising_interval_min_millis());
+    /// ```
+    pub fn advertising_interval_min_millis(&self) -> f32 {
+        self.advertising_interval_min.millis()
+    }
+
+    /// Advertising_Interval_Max(millis).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ble_data_struct::{
+    ///     data_types::advertising_interval::AdvertisingInterval,
+    ///     hci::le_set_advertising_parameters::AdvertisingParameters,
+    /// };
+    /// use ble_data_struct::data_types::time_units::Slots625us;
+    ///
+    /// let result = AdvertisingParameters::from_advertising_interval(&AdvertisingInterval::new(Slots625us(0x0020)));
+    /// assert_eq!(20.0, result.advertising_interval_max_millis());
+    /// ```
+    pub fn advertising_interval_max_millis(&self) -> f32 {
+        self.advertising_interval_max.millis()
+    }
+
+    /// check intervals are in range, min ≤ max, and at least one channel is used.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ble_data_struct::{
+    ///     data_types::advertising_interval::AdvertisingInterval,
+    ///     hci::le_set_advertising_parameters::AdvertisingParameters,
+    /// };
+    /// use ble_data_struct::data_types::time_units::Slots625us;
+    ///
+    /// let mut result = AdvertisingParameters::from_advertising_interval(&AdvertisingInterval::new(Slots625us(0x0020)));
+    /// assert!(result.is_valid());
+    /// result.advertising_interval_max = Slots625us(0x001f);
+    /// assert!(!result.is_valid());
+    /// ```
+    pub fn is_valid(&self) -> bool {
+        let range = ADVERTISING_INTERVAL_MIN..=ADVERTISING_INTERVAL_MAX;
+        range.contains(&self.advertising_interval_min.0)
+            && range.contains(&self.advertising_interval_max.0)
+            && self.advertising_interval_min <= self.advertising_interval_max
+            && self.advertising_channel_map.is_valid()
+    }
+}
+
+impl TryFrom<&Vec<u8>> for AdvertisingParameters {
+    type Error = String;
+    /// Create [`AdvertisingParameters`] from [`Vec<u8>`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ble_data_struct::hci::le_set_advertising_parameters::{
+    ///     AdvertisingFilterPolicy, AdvertisingParameters,
+    /// };
+    ///
+    /// let data: Vec<u8> = [
+    ///     0x20u8, 0x00u8, 0x40u8, 0x00u8, 0x00u8, 0x01u8, 0x00u8, 0x01u8, 0x02u8, 0x03u8, 0x04u8,
+    ///     0x05u8, 0x06u8, 0x07u8, 0x03u8,
+    /// ]
+    /// .to_vec();
+    /// let result = AdvertisingParameters::try_from(&data);
+    /// assert!(result.is_ok());
+    /// let result = result.unwrap();
+    /// assert_eq!(0x060504030201, result.peer_address);
+    /// assert_eq!(
+    ///     AdvertisingFilterPolicy::ScanAndConnectFilterAcceptList,
+    ///     result.advertising_filter_policy
+    /// );
+    ///
+    /// let data: Vec<u8> = Vec::new();
+    /// let result = AdvertisingParameters::try_from(&data);
+    /// assert!(result.is_err());
+    /// assert_eq!(
+    ///     format!("Invalid data size :{}", data.len()),
+    ///     result.unwrap_err()
+    /// );
+    /// ```
+    fn try_from(value: &Vec<u8>) -> Result<Self, String> {
+        let len = value.len();
+        if len < 15 {
+            return Err(format!("Invalid data size :{}", len).to_string());
+        }
+        let mut peer_address = [0u8; 8];
+        peer_address[..6].copy_from_slice(&value[7..13]);
+        Ok(Self {
+            advertising_interval_min: Slots625us(u16::from_le_bytes(
+                value[0..2].try_into().unwrap(),
+            )),
+            advertising_interval_max: Slots625us(u16::from_le_bytes(
+                value[2..4].try_into().unwrap(),
+            )),
+            advertising_type: value[4],
+            own_address_type: value[5],
+            peer_address_type: value[6],
+            peer_address: u64::from_le_bytes(peer_address),
+            advertising_channel_map: AdvertisingChannelMap::from_bits(value[13]),
+            advertising_filter_policy: AdvertisingFilterPolicy::from_hci(value[14])?,
+        })
+    }
+}
+
+impl Into<Vec<u8>> for AdvertisingParameters {
+    /// Create [`Vec<u8>`] from [`AdvertisingParameters`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ble_data_struct::hci::le_set_advertising_parameters::{
+    ///     AdvertisingChannelMap, AdvertisingParameters, ADV_NONCONN_IND, OWN_ADDRESS_TYPE_RANDOM,
+    /// };
+    /// use ble_data_struct::data_types::time_units::Slots625us;
+    ///
+    /// let result = AdvertisingParameters::new(
+    ///     Slots625us(0x0020),
+    ///     Slots625us(0x0040),
+    ///     ADV_NONCONN_IND,
+    ///     OWN_ADDRESS_TYPE_RANDOM,
+    /// )
+    /// .with_peer_address(0x00, 0x060504030201)
+    /// .with_advertising_channel_map(AdvertisingChannelMap::new(true, false, false));
+    /// let into_data: Vec<u8> = result.into();
+    /// assert_eq!(
+    ///     [
+    ///         0x20u8, 0x00u8, 0x40u8, 0x00u8, 0x03u8, 0x01u8, 0x00u8, 0x01u8, 0x02u8, 0x03u8,
+    ///         0x04u8, 0x05u8, 0x06u8, 0x01u8, 0x00u8
+    ///     ]
+    ///     .to_vec(),
+    ///     into_data
+    /// );
+    /// ```
+    fn into(self) -> Vec<u8> {
+        let mut data: Vec<u8> = Vec::new();
+        data.append(&mut self.advertising_interval_min.0.to_le_bytes().to_vec());
+        data.append(&mut self.advertising_interval_max.0.to_le_bytes().to_vec());
+        data.push(self.advertising_type);
+        data.push(self.own_address_type);
+        data.push(self.peer_address_type);
+        data.append(&mut self.peer_address.to_le_bytes()[..6].to_vec());
+        data.push(self.advertising_channel_map.to_bits());
+        data.push(self.advertising_filter_policy.hci_value());
+        return data;
+    }
+}
+
+impl HciCommand for AdvertisingParameters {
+    /// return `0x2006`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ble_data_struct::hci::{hci_command::HciCommand, le_set_advertising_parameters::AdvertisingParameters};
+    ///
+    /// assert_eq!(0x2006, AdvertisingParameters::opcode());
+    /// ```
+    fn opcode() -> u16 {
+        0x2006
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        data_types::{advertising_interval::AdvertisingInterval, time_units::Slots625us},
+        hci::{hci_command::HciCommand, le_set_advertising_parameters::*},
+    };
+
+    #[test]
+    fn test_channel_map() {
+        let result = AdvertisingChannelMap::new(true, false, true);
+        assert!(result.channel_37);
+        assert!(!result.channel_38);
+        assert!(result.channel_39);
+        assert_eq!(0b101, result.to_bits());
+        assert_eq!(result, AdvertisingChannelMap::from_bits(0b101));
+        assert_eq!(0b111, AdvertisingChannelMap::all().to_bits());
+        assert_eq!(
+            AdvertisingChannelMap::all(),
+            AdvertisingChannelMap::from_bits(0b1111_1111)
+        );
+        assert!(result.is_valid());
+        assert!(!AdvertisingChannelMap::from_bits(0).is_valid());
+    }
+
+    #[test]
+    fn test_filter_policy() {
+        for value in 0x00..=0x03u8 {
+            assert_eq!(
+                value,
+                AdvertisingFilterPolicy::from_hci(value)
+                    .unwrap()
+                    .hci_value()
+            );
+        }
+        assert_eq!(
+            Err("Invalid advertising filter policy :4".to_string()),
+            AdvertisingFilterPolicy::from_hci(0x04)
+        );
+    }
+
+    #[test]
+    fn test_new() {
+        let result = AdvertisingParameters::new(
+            Slots625us(0x0020),
+            Slots625us(0x0040),
+            ADV_SCAN_IND,
+            OWN_ADDRESS_TYPE_RESOLVABLE_OR_RANDOM,
+        );
+        assert_eq!(0x00, result.peer_address_type);
+        assert_eq!(0x000000000000, result.peer_address);
+        assert_eq!(AdvertisingChannelMap::all(), result.advertising_channel_map);
+        assert_eq!(
+            AdvertisingFilterPolicy::ProcessAll,
+            result.advertising_filter_policy
+        );
+
+        let result = result
+            .with_peer_address(0x01, 0x060504030201)
+            .with_advertising_channel_map(AdvertisingChannelMap::new(false, true, false))
+            .with_advertising_filter_policy(AdvertisingFilterPolicy::ConnectFilterAcceptList);
+        assert_eq!(Slots625us(0x0020), result.advertising_interval_min);
+        assert_eq!(Slots625us(0x0040), result.advertising_interval_max);
+        assert_eq!(ADV_SCAN_IND, result.advertising_type);
+        assert_eq!(
+            OWN_ADDRESS_TYPE_RESOLVABLE_OR_RANDOM,
+            result.own_address_type
+        );
+        assert_eq!(0x01, result.peer_address_type);
+        assert_eq!(0x060504030201, result.peer_address);
+        assert_eq!(
+            AdvertisingChannelMap::new(false, true, false),
+            result.advertising_channel_map
+        );
+        assert_eq!(
+            AdvertisingFilterPolicy::ConnectFilterAcceptList,
+            result.advertising_filter_policy
+        );
+    }
+
+    #[test]
+    fn test_advertising_interval() {
+        let advertising_interval = AdvertisingInterval::new(Slots625us(0x00a0));
+        let result = AdvertisingParameters::from_advertising_interval(&advertising_interval);
+        assert_eq!(Slots625us(0x00a0), result.advertising_interval_min);
+        assert_eq!(Slots625us(0x00a0), result.advertising_interval_max);
+        assert_eq!(ADV_IND, result.advertising_type);
+        assert_eq!(OWN_ADDRESS_TYPE_PUBLIC, result.own_address_type);
+        assert_eq!(AdvertisingChannelMap::all(), result.advertising_channel_map);
+        assert_eq!(
+            AdvertisingFilterPolicy::ProcessAll,
+            result.advertising_filter_policy
+        );
+        assert_eq!(advertising_interval, result.advertising_interval());
+        assert_eq!(100.0, result.advertising_interval_min_millis());
+        assert_eq!(100.0, result.advertising_interval_max_millis());
+    }
+
+    #[test]
+    fn test_is_valid() {
+        let mut result = AdvertisingParameters::from_advertising_interval(
+            &AdvertisingInterval::new(Slots625us(0x0020)),
+        );
+        assert!(result.is_valid());
+        result.advertising_interval_max = Slots625us(ADVERTISING_INTERVAL_MAX);
+        assert!(result.is_valid());
+        result.advertising_interval_max = Slots625us(ADVERTISING_INTERVAL_MAX + 1);
+        assert!(!result.is_valid());
+        result.advertising_interval_max = Slots625us(0x0020);
+        result.advertising_interval_min = Slots625us(0x001f);
+        assert!(!result.is_valid());
+        result.advertising_interval_min = Slots625us(0x0030);
+        assert!(!result.is_valid());
+        result.advertising_interval_min = Slots625us(0x0020);
+        result.advertising_channel_map = AdvertisingChannelMap::new(false, false, false);
+        assert!(!result.is_valid());
+    }
+
+    #[test]
+    fn test_try_from() {
+        let data: Vec<u8> = [
+            0x20u8, 0x00u8, 0x40u8, 0x00u8, 0x04u8, 0x02u8, 0x01u8, 0x01u8, 0x02u8, 0x03u8, 0x04u8,
+            0x05u8, 0x06u8, 0x06u8, 0x02u8,
+        ]
+        .to_vec();
+        let result = AdvertisingParameters::try_from(&data);
+        assert_eq!(
+            Ok(AdvertisingParameters::new(
+                Slots625us(0x0020),
+                Slots625us(0x0040),
+                ADV_DIRECT_IND_LOW_DUTY_CYCLE,
+                OWN_ADDRESS_TYPE_RESOLVABLE_OR_PUBLIC,
+            )
+            .with_peer_address(0x01, 0x060504030201)
+            .with_advertising_channel_map(AdvertisingChannelMap::new(false, true, true))
+            .with_advertising_filter_policy(AdvertisingFilterPolicy::ConnectFilterAcceptList)),
+            result
+        );
+
+        let data: Vec<u8> = [0x20u8; 14].to_vec();
+        let result = AdvertisingParameters::try_from(&data);
+        assert_eq!(Err(format!("Invalid data size :{}", data.len())), result);
+
+        let mut data: Vec<u8> = [0x00u8; 15].to_vec();
+        data[14] = 0x04;
+        let result = AdvertisingParameters::try_from(&data);
+        assert_eq!(
+            Err("Invalid advertising filter policy :4".to_string()),
+            result
+        );
+    }
+
+    #[test]
+    fn test_into() {
+        let result = AdvertisingParameters::new(
+            Slots625us(0x0020),
+            Slots625us(0x0040),
+            ADV_DIRECT_IND_HIGH_DUTY_CYCLE,
+            OWN_ADDRESS_TYPE_RANDOM,
+        )
+        .with_peer_address(0x00, 0x060504030201)
+        .with_advertising_channel_map(AdvertisingChannelMap::new(true, false, false))
+        .with_advertising_filter_policy(AdvertisingFilterPolicy::ScanFilterAcceptList);
+        let data: Vec<u8> = [
+            0x20u8, 0x00u8, 0x40u8, 0x00u8, 0x01u8, 0x01u8, 0x00u8, 0x01u8, 0x02u8, 0x03u8, 0x04u8,
+            0x05u8, 0x06u8, 0x01u8, 0x01u8,
+        ]
+        .to_vec();
+        let into_data: Vec<u8> = result.clone().into();
+        assert_eq!(data, into_data);
+        assert_eq!(Ok(result), AdvertisingParameters::try_from(&data));
+    }
+
+    #[test]
+    fn test_opcode() {
+        assert_eq!(0x2006, AdvertisingParameters::opcode());
+    }
+}
@@ -0,0 +1,591 @@
+//! LE Set Scan Parameters HCI command module.
+
+use crate::{data_types::time_units::Slots625us, hci::hci_command::HciCommand};
+
+/// Units: 0.625 ms
+///
+/// LE_Scan_Interval, LE_Scan_Window
+pub const SCAN_TIME_UNIT: f32 = 0.625;
+
+/// LE_Scan_Interval / LE_Scan_Window minimum value(2.5 ms).
+pub const SCAN_TIME_MIN: u16 = 0x0004;
+
+/// LE_Scan_Interval / LE_Scan_Window maximum value(10.24 s).
+pub const SCAN_TIME_MAX: u16 = 0x4000;
+
+/// LE_Scan_Type.
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Hash)]
+pub enum ScanType {
+    /// Passive Scanning. No scanning PDUs shall be sent (0x00)
+    Passive,
+
+    /// Active Scanning. Scanning PDUs may be sent (0x01)
+    Active,
+}
+
+impl ScanType {
+    /// Create [`ScanType`] from HCI LE_Scan_Type.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ble_data_struct::hci::le_set_scan_parameters::ScanType;
+    ///
+    /// assert_eq!(Ok(ScanType::Active), ScanType::from_hci(0x01));
+    /// assert!(ScanType::from_hci(0x02).is_err());
+    /// ```
+    pub fn from_hci(value: u8) -> Result<Self, String> {
+        match value {
+            0x00 => Ok(Self::Passive),
+            0x01 => Ok(Self::Active),
+            _ => Err(format!("Invalid scan type :{}", value)),
+        }
+    }
+
+    /// Get HCI LE_Scan_Type.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ble_data_struct::hci::le_set_scan_parameters::ScanType;
+    ///
+    /// assert_eq!(0x00, ScanType::Passive.hci_value());
+    /// ```
+    pub const fn hci_value(&self) -> u8 {
+        match self {
+            Self::Passive => 0x00,
+            Self::Active => 0x01,
+        }
+    }
+}
+
+/// Scanning_Filter_Policy.
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Hash)]
+pub enum ScanningFilterPolicy {
+    /// Basic unfiltered scanning filter policy (0x00)
+    BasicUnfiltered,
+
+    /// Basic filtered scanning filter policy, only the devices in the Filter Accept List (0x01)
+    BasicFiltered,
+
+    /// Extended unfiltered scanning filter policy (0x02)
+    ExtendedUnfiltered,
+
+    /// Extended filtered scanning filter policy (0x03)
+    ExtendedFiltered,
+}
+
+impl ScanningFilterPolicy {
+    /// Create [`ScanningFilterPolicy`] from HCI Scanning_Filter_Policy.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ble_data_struct::hci::le_set_scan_parameters::ScanningFilterPolicy;
+    ///
+    /// assert_eq!(
+    ///     Ok(ScanningFilterPolicy::BasicFiltered),
+    ///     ScanningFilterPolicy::from_hci(0x01)
+    /// );
+    /// assert!(ScanningFilterPolicy::from_hci(0x04).is_err());
+    /// ```
+    pub fn from_hci(value: u8) -> Result<Self, String> {
+        match value {
+            0x00 => Ok(Self::BasicUnfiltered),
+            0x01 => Ok(Self::BasicFiltered),
+            0x02 => Ok(Self::ExtendedUnfiltered),
+            0x03 => Ok(Self::ExtendedFiltered),
+            _ => Err(format!("Invalid scanning filter policy :{}", value)),
+        }
+    }
+
+    /// Get HCI Scanning_Filter_Policy.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ble_data_struct::hci::le_set_scan_parameters::ScanningFilterPolicy;
+    ///
+    /// assert_eq!(0x03, ScanningFilterPolicy::ExtendedFiltered.hci_value());
+    /// ```
+    pub const fn hci_value(&self) -> u8 {
+        match self {
+            Self::BasicUnfiltered => 0x00,
+            Self::BasicFiltered => 0x01,
+            Self::ExtendedUnfiltered => 0x02,
+            Self::ExtendedFiltered => 0x03,
+        }
+    }
+}
+
+/// HCI_LE_Set_Scan_Parameters command parameters.
+#[derive(Debug, PartialEq, Clone)]
+pub struct ScanParameters {
+    /// LE_Scan_Type
+    pub scan_type: ScanType,
+
+    /// LE_Scan_Interval
+    pub scan_interval: Slots625us,
+
+    /// LE_Scan_Window
+    pub scan_window: Slots625us,
+
+    /// Own_Address_Type
+    pub own_address_type: u8,
+
+    /// Scanning_Filter_Policy
+    pub scanning_filter_policy: ScanningFilterPolicy,
+}
+
+impl ScanParameters {
+    /// Create [`ScanParameters`] from Parameters.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ble_data_struct::hci::{
+    ///     le_set_advertising_parameters::OWN_ADDRESS_TYPE_PUBLIC,
+    ///     le_set_scan_parameters::{ScanParameters, ScanType, ScanningFilterPolicy},
+    /// };
+    /// use ble_data_struct::data_types::time_units::Slots625us;
+    ///
+    /// let result = ScanParameters::new(
+    ///     ScanType::Active,
+    ///     Slots625us(0x0010),
+    ///     Slots625us(0x0010),
+    ///     OWN_ADDRESS_TYPE_PUBLIC,
+    ///     ScanningFilterPolicy::BasicUnfiltered,
+    /// );
+    /// assert_eq!(ScanType::Active, result.scan_type);
+    /// assert_eq!(Slots625us(0x0010), result.scan_interval);
+    /// assert_eq!(Slots625us(0x0010), result.scan_window);
+    /// assert_eq!(OWN_ADDRESS_TYPE_PUBLIC, result.own_address_type);
+    /// assert_eq!(ScanningFilterPolicy::BasicUnfiltered, result.scanning_filter_policy);
+    /// ```
+    pub fn new(
+        scan_type: ScanType,
+        scan_interval: Slots625us,
+        scan_window: Slots625us,
+        own_address_type: u8,
+        scanning_filter_policy: ScanningFilterPolicy,
+    ) -> Self {
+        Self {
+            scan_type,
+            scan_interval,
+            scan_window,
+            own_address_type,
+            scanning_filter_policy,
+        }
+    }
+
+    /// Create [`ScanParameters`] from LE_Scan_Interval(millis) and LE_Scan_Window(millis).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ble_data_struct::hci::{
+    ///     le_set_advertising_parameters::OWN_ADDRESS_TYPE_PUBLIC,
+    ///     le_set_scan_parameters::{ScanParameters, ScanType, ScanningFilterPolicy},
+    /// };
+    /// use ble_data_struct::data_types::time_units::Slots625us;
+    ///
+    /// let result = ScanParameters::from_millis(
+    ///     ScanType::Passive,
+    ///     100.0,
+    ///     50.0,
+    ///     OWN_ADDRESS_TYPE_PUBLIC,
+    ///     ScanningFilterPolicy::BasicUnfiltered,
+    /// );
+    /// assert!(result.is_ok());
+    /// let result = result.unwrap();
+    /// assert_eq!(Slots625us(0x00a0), result.scan_interval);
+    /// assert_eq!(Slots625us(0x0050), result.scan_window);
+    ///
+    /// let result = ScanParameters::from_millis(
+    ///     ScanType::Passive,
+    ///     50.0,
+    ///     100.0,
+    ///     OWN_ADDRESS_TYPE_PUBLIC,
+    ///     ScanningFilterPolicy::BasicUnfiltered,
+    /// );
+    /// assert!(result.is_err());
+    /// ```
+    pub fn from_millis(
+        scan_type: ScanType,
+        scan_interval_millis: f32,
+        scan_window_millis: f32,
+        own_address_type: u8,
+        scanning_filter_policy: ScanningFilterPolicy,
+    ) -> Result<Self, String> {
+        let result = Self::new(
+            scan_type,
+            Slots625us((scan_interval_millis / SCAN_TIME_UNIT).round() as u16),
+            Slots625us((scan_window_millis / SCAN_TIME_UNIT).round() as u16),
+            own_address_type,
+            scanning_filter_policy,
+        );
+        result.validate()?;
+        Ok(result)
+    }
+
+    /// LE_Scan_Interval(millis).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ble_data_struct::hci::le_set_scan_parameters::{ScanParameters, ScanType, ScanningFilterPolicy};
+    /// use ble_data_struct::data_types::time_units::Slots625us;
+    ///
+    /// let result = ScanParameters::new(ScanType::Passive, Slots625us(0x0010), Slots625us(0x0004), 0x00, ScanningFilterPolicy::BasicUnfiltered);
+    /// assert_eq!(10.0, result.scan_interval_millis());
+    /// ```
+    pub fn scan_interval_millis(&self) -> f32 {
+        self.scan_interval.millis()
+    }
+
+    /// LE_Scan_Window(millis).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ble_data_struct::hci::le_set_scan_parameters::{ScanParameters, ScanType, ScanningFilterPolicy};
+    /// use ble_data_struct::data_types::time_units::Slots625us;
+    ///
+    /// let result = ScanParameters::new(ScanType::Passive, Slots625us(0x0010), Slots625us(0x0004), 0x00, ScanningFilterPolicy::BasicUnfiltered);
+    /// assert_eq!(2.5, result.scan_window_millis());
+    /// ```
+    pub fn scan_window_millis(&self) -> f32 {
+        self.scan_window.millis()
+    }
+
+    /// Check LE_Scan_Interval and LE_Scan_Window are in range and LE_Scan_Window ≤ LE_Scan_Interval.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ble_data_struct::hci::le_set_scan_parameters::{ScanParameters, ScanType, ScanningFilterPolicy};
+    /// use ble_data_struct::data_types::time_units::Slots625us;
+    ///
+    /// let result = ScanParameters::new(ScanType::Passive, Slots625us(0x0010), Slots625us(0x0010), 0x00, ScanningFilterPolicy::BasicUnfiltered);
+    /// assert!(result.validate().is_ok());
+    ///
+    /// let result = ScanParameters::new(ScanType::Passive, Slots625us(0x0010), Slots625us(0x0011), 0x00, ScanningFilterPolicy::BasicUnfiltered);
+    /// assert_eq!(Err("Invalid scan window :17".to_string()), result.validate());
+    /// ```
+    pub fn validate(&self) -> Result<(), String> {
+        let range = SCAN_TIME_MIN..=SCAN_TIME_MAX;
+        if !range.contains(&self.scan_interval.0) {
+            return Err(format!("Invalid scan interval :{}", self.scan_interval.0));
+        }
+        if !range.contains(&self.scan_window.0) || self.scan_window > self.scan_interval {
+            return Err(format!("Invalid scan window :{}", self.scan_window.0));
+        }
+        Ok(())
+    }
+
+    /// Create Scan Interval Window characteristic value (LE_Scan_Interval, LE_Scan_Window).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ble_data_struct::hci::le_set_scan_parameters::{ScanParameters, ScanType, ScanningFilterPolicy};
+    /// use ble_data_struct::data_types::time_units::Slots625us;
+    ///
+    /// let result = ScanParameters::new(ScanType::Passive, Slots625us(0x0010), Slots625us(0x0004), 0x00, ScanningFilterPolicy::BasicUnfiltered);
+    /// assert_eq!([0x10u8, 0x00u8, 0x04u8, 0x00u8].to_vec(), result.scan_interval_window());
+    /// ```
+    pub fn scan_interval_window(&self) -> Vec<u8> {
+        let mut data: Vec<u8> = Vec::new();
+        data.append(&mut self.scan_interval.0.to_le_bytes().to_vec());
+        data.append(&mut self.scan_window.0.to_le_bytes().to_vec());
+        data
+    }
+}
+
+impl TryFrom<&Vec<u8>> for ScanParameters {
+    type Error = String;
+    /// Create [`ScanParameters`] from [`Vec<u8>`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ble_data_struct::hci::le_set_scan_parameters::{ScanParameters, ScanType, ScanningFilterPolicy};
+    /// use ble_data_struct::data_types::time_units::Slots625us;
+    ///
+    /// let data: Vec<u8> = [0x01u8, 0x10u8, 0x00u8, 0x04u8, 0x00u8, 0x01u8, 0x00u8].to_vec();
+    /// let result = ScanParameters::try_from(&data);
+    /// assert!(result.is_ok());
+    /// let result = result.unwrap();
+    /// assert_eq!(ScanType::Active, result.scan_type);
+    /// assert_eq!(Slots625us(0x0010), result.scan_interval);
+    /// assert_eq!(Slots625us(0x0004), result.scan_window);
+    ///
+    /// let data: Vec<u8> = Vec::new();
+    /// let result = ScanParameters::try_from(&data);
+    /// assert!(result.is_err());
+    /// assert_eq!(
+    ///     format!("Invalid data size :{}", data.len()),
+    ///     result.unwrap_err()
+    /// );
+    /// ```
+    fn try_from(value: &Vec<u8>) -> Result<Self, String> {
+        let len = value.len();
+        if len < 7 {
+            return Err(format!("Invalid data size :{}", len).to_string());
+        }
+        let result = Self {
+            scan_type: ScanType::from_hci(value[0])?,
+            scan_interval: Slots625us(u16::from_le_bytes(value[1..3].try_into().unwrap())),
+            scan_window: Slots625us(u16::from_le_bytes(value[3..5].try_into().unwrap())),
+            own_address_type: value[5],
+            scanning_filter_policy: ScanningFilterPolicy::from_hci(value[6])?,
+        };
+        result.validate()?;
+        Ok(result)
+    }
+}
+
+impl Into<Vec<u8>> for ScanParameters {
+    /// Create [`Vec<u8>`] from [`ScanParameters`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ble_data_struct::hci::le_set_scan_parameters::{ScanParameters, ScanType, ScanningFilterPolicy};
+    /// use ble_data_struct::data_types::time_units::Slots625us;
+    ///
+    /// let result = ScanParameters::new(ScanType::Active, Slots625us(0x0010), Slots625us(0x0004), 0x01, ScanningFilterPolicy::BasicFiltered);
+    /// let into_data: Vec<u8> = result.into();
+    /// assert_eq!([0x01u8, 0x10u8, 0x00u8, 0x04u8, 0x00u8, 0x01u8, 0x01u8].to_vec(), into_data);
+    /// ```
+    fn into(self) -> Vec<u8> {
+        let mut data: Vec<u8> = Vec::new();
+        data.push(self.scan_type.hci_value());
+        data.append(&mut self.scan_interval_window());
+        data.push(self.own_address_type);
+        data.push(self.scanning_filter_policy.hci_value());
+        return data;
+    }
+}
+
+impl HciCommand for ScanParameters {
+    /// return `0x200b`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ble_data_struct::hci::{hci_command::HciCommand, le_set_scan_parameters::ScanParameters};
+    ///
+    /// assert_eq!(0x200b, ScanParameters::opcode());
+    /// ```
+    fn opcode() -> u16 {
+        0x200b
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        data_types::time_units::Slots625us,
+        hci::{
+            hci_command::HciCommand,
+            le_set_advertising_parameters::{OWN_ADDRESS_TYPE_PUBLIC, OWN_ADDRESS_TYPE_RANDOM},
+            le_set_scan_parameters::*,
+        },
+    };
+
+    #[test]
+    fn test_scan_type() {
+        assert_eq!(Ok(ScanType::Passive), ScanType::from_hci(0x00));
+        assert_eq!(Ok(ScanType::Active), ScanType::from_hci(0x01));
+        assert_eq!(
+            Err("Invalid scan type :2".to_string()),
+            ScanType::from_hci(0x02)
+        );
+        assert_eq!(0x00, ScanType::Passive.hci_value());
+        assert_eq!(0x01, ScanType::Active.hci_value());
+    }
+
+    #[test]
+    fn test_scanning_filter_policy() {
+        for value in 0x00..=0x03u8 {
+            assert_eq!(
+                value,
+                ScanningFilterPolicy::from_hci(value).unwrap().hci_value()
+            );
+        }
+        assert_eq!(
+            Err("Invalid scanning filter policy :4".to_string()),
+            ScanningFilterPolicy::from_hci(0x04)
+        );
+    }
+
+    #[test]
+    fn test_new() {
+        let result = ScanParameters::new(
+            ScanType::Active,
+            Slots625us(0x0010),
+            Slots625us(0x0008),
+            OWN_ADDRESS_TYPE_RANDOM,
+            ScanningFilterPolicy::ExtendedFiltered,
+        );
+        assert_eq!(ScanType::Active, result.scan_type);
+        assert_eq!(Slots625us(0x0010), result.scan_interval);
+        assert_eq!(Slots625us(0x0008), result.scan_window);
+        assert_eq!(OWN_ADDRESS_TYPE_RANDOM, result.own_address_type);
+        assert_eq!(
+            ScanningFilterPolicy::ExtendedFiltered,
+            result.scanning_filter_policy
+        );
+    }
+
+    #[test]
+    fn test_from_millis() {
+        let result = ScanParameters::from_millis(
+            ScanType::Passive,
+            100.0,
+            50.0,
+            OWN_ADDRESS_TYPE_PUBLIC,
+            ScanningFilterPolicy::BasicUnfiltered,
+        );
+        assert_eq!(
+            Ok(ScanParameters::new(
+                ScanType::Passive,
+                Slots625us(0x00a0),
+                Slots625us(0x0050),
+                OWN_ADDRESS_TYPE_PUBLIC,
+                ScanningFilterPolicy::BasicUnfiltered,
+            )),
+            result
+        );
+
+        let result = ScanParameters::from_millis(
+            ScanType::Passive,
+            50.0,
+            100.0,
+            OWN_ADDRESS_TYPE_PUBLIC,
+            ScanningFilterPolicy::BasicUnfiltered,
+        );
+        assert_eq!(Err("Invalid scan window :160".to_string()), result);
+    }
+
+    #[test]
+    fn test_millis() {
+        let result = ScanParameters::new(
+            ScanType::Passive,
+            Slots625us(0x4000),
+            Slots625us(0x0004),
+            OWN_ADDRESS_TYPE_PUBLIC,
+            ScanningFilterPolicy::BasicUnfiltered,
+        );
+        assert_eq!(10240.0, result.scan_interval_millis());
+        assert_eq!(2.5, result.scan_window_millis());
+    }
+
+    #[test]
+    fn test_validate() {
+        let mut result = ScanParameters::new(
+            ScanType::Passive,
+            Slots625us(SCAN_TIME_MIN),
+            Slots625us(SCAN_TIME_MIN),
+            OWN_ADDRESS_TYPE_PUBLIC,
+            ScanningFilterPolicy::BasicUnfiltered,
+        );
+        assert_eq!(Ok(()), result.validate());
+        result.scan_interval = Slots625us(SCAN_TIME_MAX);
+        result.scan_window = Slots625us(SCAN_TIME_MAX);
+        assert_eq!(Ok(()), result.validate());
+        result.scan_interval = Slots625us(SCAN_TIME_MAX + 1);
+        assert_eq!(
+            Err("Invalid scan interval :16385".to_string()),
+            result.validate()
+        );
+        result.scan_interval = Slots625us(SCAN_TIME_MIN - 1);
+        assert_eq!(
+            Err("Invalid scan interval :3".to_string()),
+            result.validate()
+        );
+        result.scan_interval = Slots625us(0x0010);
+        result.scan_window = Slots625us(0x0011);
+        assert_eq!(
+            Err("Invalid scan window :17".to_string()),
+            result.validate()
+        );
+        result.scan_window = Slots625us(SCAN_TIME_MIN - 1);
+        assert_eq!(Err("Invalid scan window :3".to_string()), result.validate());
+    }
+
+    #[test]
+    fn test_scan_interval_window() {
+        let result = ScanParameters::new(
+            ScanType::Passive,
+            Slots625us(0x0102),
+            Slots625us(0x0004),
+            OWN_ADDRESS_TYPE_PUBLIC,
+            ScanningFilterPolicy::BasicUnfiltered,
+        );
+        assert_eq!(
+            [0x02u8, 0x01u8, 0x04u8, 0x00u8].to_vec(),
+            result.scan_interval_window()
+        );
+    }
+
+    #[test]
+    fn test_try_from() {
+        let data: Vec<u8> = [0x00u8, 0x10u8, 0x00u8, 0x08u8, 0x00u8, 0x01u8, 0x02u8].to_vec();
+        assert_eq!(
+            Ok(ScanParameters::new(
+                ScanType::Passive,
+                Slots625us(0x0010),
+                Slots625us(0x0008),
+                OWN_ADDRESS_TYPE_RANDOM,
+                ScanningFilterPolicy::ExtendedUnfiltered,
+            )),
+            ScanParameters::try_from(&data)
+        );
+
+        let data: Vec<u8> = [0x00u8; 6].to_vec();
+        assert_eq!(
+            Err(format!("Invalid data size :{}", data.len())),
+            ScanParameters::try_from(&data)
+        );
+
+        let data: Vec<u8> = [0x02u8, 0x10u8, 0x00u8, 0x08u8, 0x00u8, 0x01u8, 0x02u8].to_vec();
+        assert_eq!(
+            Err("Invalid scan type :2".to_string()),
+            ScanParameters::try_from(&data)
+        );
+
+        let data: Vec<u8> = [0x00u8, 0x10u8, 0x00u8, 0x08u8, 0x00u8, 0x01u8, 0x04u8].to_vec();
+        assert_eq!(
+            Err("Invalid scanning filter policy :4".to_string()),
+            ScanParameters::try_from(&data)
+        );
+
+        let data: Vec<u8> = [0x00u8, 0x08u8, 0x00u8, 0x10u8, 0x00u8, 0x01u8, 0x02u8].to_vec();
+        assert_eq!(
+            Err("Invalid scan window :16".to_string()),
+            ScanParameters::try_from(&data)
+        );
+    }
+
+    #[test]
+    fn test_into() {
+        let result = ScanParameters::new(
+            ScanType::Active,
+            Slots625us(0x0010),
+            Slots625us(0x0004),
+            OWN_ADDRESS_TYPE_RANDOM,
+            ScanningFilterPolicy::BasicFiltered,
+        );
+        let data: Vec<u8> = [0x01u8, 0x10u8, 0x00u8, 0x04u8, 0x00u8, 0x01u8, 0x01u8].to_vec();
+        let into_data: Vec<u8> = result.clone().into();
+        assert_eq!(data, into_data);
+        assert_eq!(Ok(result), ScanParameters::try_from(&data));
+    }
+
+    #[test]
+    fn test_opcode() {
+        assert_eq!(0x200b, ScanParameters::opcode());
+    }
+}
@@ -0,0 +1,601 @@
+//! L2CAP Connection Parameter Update Request / Response (LE signaling channel) module.
+
+use crate::{
+    characteristics::peripheral_preferred_connection_parameters::PreferredConnectionParameters,
+    data_types::{
+        connection_parameters::{check_connection_parameters, ConnectionParameterViolation},
+        time_units::{Units10ms, Units1_25ms},
+    },
+};
+
+/// LE signaling channel CID
+pub const LE_SIGNALING_CHANNEL_CID: u16 = 0x0005;
+
+/// Code: L2CAP_CONNECTION_PARAMETER_UPDATE_REQ
+pub const CONNECTION_PARAMETER_UPDATE_REQUEST_CODE: u8 = 0x12;
+
+/// Code: L2CAP_CONNECTION_PARAMETER_UPDATE_RSP
+pub const CONNECTION_PARAMETER_UPDATE_RESPONSE_CODE: u8 = 0x13;
+
+/// Result: Connection Parameters accepted
+pub const CONNECTION_PARAMETERS_ACCEPTED: u16 = 0x0000;
+
+/// Result: Connection Parameters rejected
+pub const CONNECTION_PARAMETERS_REJECTED: u16 = 0x0001;
+
+/// L2CAP_CONNECTION_PARAMETER_UPDATE_REQ packet.
+#[derive(Debug, PartialEq, Clone)]
+pub struct ConnectionParameterUpdateRequest {
+    /// Identifier
+    pub identifier: u8,
+
+    /// Interval Min
+    pub interval_min: Units1_25ms,
+
+    /// Interval Max
+    pub interval_max: Units1_25ms,
+
+    /// Latency
+    pub latency: u16,
+
+    /// Timeout
+    pub timeout: Units10ms,
+}
+
+impl ConnectionParameterUpdateRequest {
+    /// Create [`ConnectionParameterUpdateRequest`] from Parameters.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ble_data_struct::l2cap::connection_parameter_update::ConnectionParameterUpdateRequest;
+    /// use ble_data_struct::data_types::time_units::{Units10ms, Units1_25ms};
+    ///
+    /// let result = ConnectionParameterUpdateRequest::new(0x01, Units1_25ms(0x0018), Units1_25ms(0x0028), 0x0000, Units10ms(0x01f4));
+    /// assert_eq!(0x01, result.identifier);
+    /// assert_eq!(Units1_25ms(0x0018), result.interval_min);
+    /// assert_eq!(Units1_25ms(0x0028), result.interval_max);
+    /// assert_eq!(0x0000, result.latency);
+    /// assert_eq!(Units10ms(0x01f4), result.timeout);
+    /// ```
+    pub fn new(
+        identifier: u8,
+        interval_min: Units1_25ms,
+        interval_max: Units1_25ms,
+        latency: u16,
+        timeout: Units10ms,
+    ) -> Self {
+        Self {
+            identifier,
+            interval_min,
+            interval_max,
+            latency,
+            timeout,
+        }
+    }
+
+    /// Create [`ConnectionParameterUpdateRequest`] from [`PreferredConnectionParameters`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ble_data_struct::{
+    ///     characteristics::peripheral_preferred_connection_parameters::PreferredConnectionParameters,
+    ///     l2cap::connection_parameter_update::ConnectionParameterUpdateRequest,
+    /// };
+    /// use ble_data_struct::data_types::time_units::{Units10ms, Units1_25ms};
+    ///
+    /// let parameters = PreferredConnectionParameters::new(Units1_25ms(0x0018), Units1_25ms(0x0028), 0x0000, Units10ms(0x01f4));
+    /// let result = ConnectionParameterUpdateRequest::from_preferred_connection_parameters(0x01, &parameters);
+    /// assert_eq!(
+    ///     ConnectionParameterUpdateRequest::new(0x01, Units1_25ms(0x0018), Units1_25ms(0x0028), 0x0000, Units10ms(0x01f4)),
+    ///     result
+    /// );
+    /// ```
+    pub fn from_preferred_connection_parameters(
+        identifier: u8,
+        parameters: &PreferredConnectionParameters,
+    ) -> Self {
+        Self::new(
+            identifier,
+            parameters.interval_min,
+            parameters.interval_max,
+            parameters.latency,
+            parameters.supervision_timeout,
+        )
+    }
+
+    /// Validate parameter ranges and the connection supervision timeout rule.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ble_data_struct::{
+    ///     data_types::connection_parameters::ConnectionParameterViolation,
+    ///     l2cap::connection_parameter_update::ConnectionParameterUpdateRequest,
+    /// };
+    /// use ble_data_struct::data_types::time_units::{Units10ms, Units1_25ms};
+    ///
+    /// let result = ConnectionParameterUpdateRequest::new(0x01, Units1_25ms(0x0018), Units1_25ms(0x0028), 0x0000, Units10ms(0x01f4));
+    /// assert!(result.validate().is_ok());
+    ///
+    /// let result = ConnectionParameterUpdateRequest::new(0x01, Units1_25ms(0x0018), Units1_25ms(0x0018), 0x01f4, Units10ms(0x0c80));
+    /// assert_eq!(
+    ///     Err(vec![ConnectionParameterViolation::LatencyOutOfRange { latency: 0x01f4 }]),
+    ///     result.validate()
+    /// );
+    /// ```
+    pub fn validate(&self) -> Result<(), Vec<ConnectionParameterViolation>> {
+        let violations = check_connection_parameters(
+            self.interval_min.0,
+            self.interval_max.0,
+            self.latency,
+            self.timeout.0,
+        );
+        if violations.is_empty() {
+            Ok(())
+        } else {
+            Err(violations)
+        }
+    }
+
+    /// Create L2CAP_CONNECTION_PARAMETER_UPDATE_RSP for this request.
+    ///
+    /// [`CONNECTION_PARAMETERS_ACCEPTED`] if [`ConnectionParameterUpdateRequest::validate`] is ok, [`CONNECTION_PARAMETERS_REJECTED`] otherwise.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ble_data_struct::l2cap::connection_parameter_update::{
+    ///     ConnectionParameterUpdateRequest, CONNECTION_PARAMETERS_ACCEPTED,
+    ///     CONNECTION_PARAMETERS_REJECTED,
+    /// };
+    /// use ble_data_struct::data_types::time_units::{Units10ms, Units1_25ms};
+    ///
+    /// let result = ConnectionParameterUpdateRequest::new(0x01, Units1_25ms(0x0018), Units1_25ms(0x0028), 0x0000, Units10ms(0x01f4));
+    /// assert_eq!(CONNECTION_PARAMETERS_ACCEPTED, result.response().result);
+    ///
+    /// let result = ConnectionParameterUpdateRequest::new(0x02, Units1_25ms(0x0028), Units1_25ms(0x0018), 0x0000, Units10ms(0x01f4));
+    /// assert_eq!(0x02, result.response().identifier);
+    /// assert_eq!(CONNECTION_PARAMETERS_REJECTED, result.response().result);
+    /// ```
+    pub fn response(&self) -> ConnectionParameterUpdateResponse {
+        ConnectionParameterUpdateResponse::new(
+            self.identifier,
+            if self.validate().is_ok() {
+                CONNECTION_PARAMETERS_ACCEPTED
+            } else {
+                CONNECTION_PARAMETERS_REJECTED
+            },
+        )
+    }
+}
+
+impl TryFrom<&Vec<u8>> for ConnectionParameterUpdateRequest {
+    type Error = String;
+    /// Create [`ConnectionParameterUpdateRequest`] from [`Vec<u8>`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ble_data_struct::l2cap::connection_parameter_update::ConnectionParameterUpdateRequest;
+    /// use ble_data_struct::data_types::time_units::{Units10ms, Units1_25ms};
+    ///
+    /// let data: Vec<u8> = [
+    ///     0x12u8, 0x01u8, 0x08u8, 0x00u8, 0x18u8, 0x00u8, 0x28u8, 0x00u8, 0x00u8, 0x00u8, 0xf4u8,
+    ///     0x01u8,
+    /// ]
+    /// .to_vec();
+    /// let result = ConnectionParameterUpdateRequest::try_from(&data);
+    /// assert_eq!(
+    ///     Ok(ConnectionParameterUpdateRequest::new(0x01, Units1_25ms(0x0018), Units1_25ms(0x0028), 0x0000, Units10ms(0x01f4))),
+    ///     result
+    /// );
+    ///
+    /// let data: Vec<u8> = Vec::new();
+    /// let result = ConnectionParameterUpdateRequest::try_from(&data);
+    /// assert!(result.is_err());
+    /// assert_eq!(
+    ///     format!("Invalid data size :{}", data.len()),
+    ///     result.unwrap_err()
+    /// );
+    /// ```
+    fn try_from(value: &Vec<u8>) -> Result<Self, String> {
+        let len = value.len();
+        if len < 12 || u16::from_le_bytes(value[2..4].try_into().unwrap()) != 8 {
+            return Err(format!("Invalid data size :{}", len).to_string());
+        }
+        if value[0] != CONNECTION_PARAMETER_UPDATE_REQUEST_CODE {
+            return Err(format!("Invalid code :{}", value[0]));
+        }
+        Ok(Self {
+            identifier: value[1],
+            interval_min: Units1_25ms(u16::from_le_bytes(value[4..6].try_into().unwrap())),
+            interval_max: Units1_25ms(u16::from_le_bytes(value[6..8].try_into().unwrap())),
+            latency: u16::from_le_bytes(value[8..10].try_into().unwrap()),
+            timeout: Units10ms(u16::from_le_bytes(value[10..12].try_into().unwrap())),
+        })
+    }
+}
+
+impl Into<Vec<u8>> for ConnectionParameterUpdateRequest {
+    /// Create [`Vec<u8>`] from [`ConnectionParameterUpdateRequest`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ble_data_struct::l2cap::connection_parameter_update::ConnectionParameterUpdateRequest;
+    /// use ble_data_struct::data_types::time_units::{Units10ms, Units1_25ms};
+    ///
+    /// let result = ConnectionParameterUpdateRequest::new(0x01, Units1_25ms(0x0018), Units1_25ms(0x0028), 0x0000, Units10ms(0x01f4));
+    /// let into_data: Vec<u8> = result.into();
+    /// assert_eq!(
+    ///     [
+    ///         0x12u8, 0x01u8, 0x08u8, 0x00u8, 0x18u8, 0x00u8, 0x28u8, 0x00u8, 0x00u8, 0x00u8,
+    ///         0xf4u8, 0x01u8
+    ///     ]
+    ///     .to_vec(),
+    ///     into_data
+    /// );
+    /// ```
+    fn into(self) -> Vec<u8> {
+        let mut data: Vec<u8> = Vec::new();
+        data.push(CONNECTION_PARAMETER_UPDATE_REQUEST_CODE);
+        data.push(self.identifier);
+        data.append(&mut 8u16.to_le_bytes().to_vec());
+        data.append(&mut self.interval_min.0.to_le_bytes().to_vec());
+        data.append(&mut self.interval_max.0.to_le_bytes().to_vec());
+        data.append(&mut self.latency.to_le_bytes().to_vec());
+        data.append(&mut self.timeout.0.to_le_bytes().to_vec());
+        return data;
+    }
+}
+
+/// L2CAP_CONNECTION_PARAMETER_UPDATE_RSP packet.
+#[derive(Debug, PartialEq, Clone)]
+pub struct ConnectionParameterUpdateResponse {
+    /// Identifier
+    pub identifier: u8,
+
+    /// Result
+    pub result: u16,
+}
+
+impl ConnectionParameterUpdateResponse {
+    /// Create [`ConnectionParameterUpdateResponse`] from Parameters.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ble_data_struct::l2cap::connection_parameter_update::{
+    ///     ConnectionParameterUpdateResponse, CONNECTION_PARAMETERS_ACCEPTED,
+    /// };
+    ///
+    /// let result = ConnectionParameterUpdateResponse::new(0x01, CONNECTION_PARAMETERS_ACCEPTED);
+    /// assert_eq!(0x01, result.identifier);
+    /// assert_eq!(CONNECTION_PARAMETERS_ACCEPTED, result.result);
+    /// ```
+    pub fn new(identifier: u8, result: u16) -> Self {
+        Self { identifier, result }
+    }
+
+    /// check Connection Parameters accepted.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ble_data_struct::l2cap::connection_parameter_update::{
+    ///     ConnectionParameterUpdateResponse, CONNECTION_PARAMETERS_ACCEPTED,
+    ///     CONNECTION_PARAMETERS_REJECTED,
+    /// };
+    ///
+    /// assert!(ConnectionParameterUpdateResponse::new(0x01, CONNECTION_PARAMETERS_ACCEPTED).is_accepted());
+    /// assert!(!ConnectionParameterUpdateResponse::new(0x01, CONNECTION_PARAMETERS_REJECTED).is_accepted());
+    /// ```
+    pub fn is_accepted(&self) -> bool {
+        self.result == CONNECTION_PARAMETERS_ACCEPTED
+    }
+}
+
+impl TryFrom<&Vec<u8>> for ConnectionParameterUpdateResponse {
+    type Error = String;
+    /// Create [`ConnectionParameterUpdateResponse`] from [`Vec<u8>`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ble_data_struct::l2cap::connection_parameter_update::{
+    ///     ConnectionParameterUpdateResponse, CONNECTION_PARAMETERS_REJECTED,
+    /// };
+    ///
+    /// let data: Vec<u8> = [0x13u8, 0x01u8, 0x02u8, 0x00u8, 0x01u8, 0x00u8].to_vec();
+    /// let result = ConnectionParameterUpdateResponse::try_from(&data);
+    /// assert_eq!(
+    ///     Ok(ConnectionParameterUpdateResponse::new(0x01, CONNECTION_PARAMETERS_REJECTED)),
+    ///     result
+    /// );
+    ///
+    /// let data: Vec<u8> = Vec::new();
+    /// let result = ConnectionParameterUpdateResponse::try_from(&data);
+    /// assert!(result.is_err());
+    /// assert_eq!(
+    ///     format!("Invalid data size :{}", data.len()),
+    ///     result.unwrap_err()
+    /// );
+    /// ```
+    fn try_from(value: &Vec<u8>) -> Result<Self, String> {
+        let len = value.len();
+        if len < 6 || u16::from_le_bytes(value[2..4].try_into().unwrap()) != 2 {
+            return Err(format!("Invalid data size :{}", len).to_string());
+        }
+        if value[0] != CONNECTION_PARAMETER_UPDATE_RESPONSE_CODE {
+            return Err(format!("Invalid code :{}", value[0]));
+        }
+        Ok(Self {
+            identifier: value[1],
+            result: u16::from_le_bytes(value[4..6].try_into().unwrap()),
+        })
+    }
+}
+
+impl Into<Vec<u8>> for ConnectionParameterUpdateResponse {
+    /// Create [`Vec<u8>`] from [`ConnectionParameterUpdateResponse`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ble_data_struct::l2cap::connection_parameter_update::{
+    ///     ConnectionParameterUpdateResponse, CONNECTION_PARAMETERS_ACCEPTED,
+    /// };
+    ///
+    /// let result = ConnectionParameterUpdateResponse::new(0x01, CONNECTION_PARAMETERS_ACCEPTED);
+    /// let into_data: Vec<u8> = result.into();
+    /// assert_eq!([0x13u8, 0x01u8, 0x02u8, 0x00u8, 0x00u8, 0x00u8].to_vec(), into_data);
+    /// ```
+    fn into(self) -> Vec<u8> {
+        let mut data: Vec<u8> = Vec::new();
+        data.push(CONNECTION_PARAMETER_UPDATE_RESPONSE_CODE);
+        data.push(self.identifier);
+        data.append(&mut 2u16.to_le_bytes().to_vec());
+        data.append(&mut self.result.to_le_bytes().to_vec());
+        return data;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        characteristics::peripheral_preferred_connection_parameters::PreferredConnectionParameters,
+        data_types::{
+            connection_parameters::ConnectionParameterViolation,
+            time_units::{Units10ms, Units1_25ms},
+        },
+        l2cap::connection_parameter_update::*,
+    };
+
+    #[test]
+    fn test_request_new() {
+        let result = ConnectionParameterUpdateRequest::new(
+            0x01,
+            Units1_25ms(0x0018),
+            Units1_25ms(0x0028),
+            0x0000,
+            Units10ms(0x01f4),
+        );
+        assert_eq!(0x01, result.identifier);
+        assert_eq!(Units1_25ms(0x0018), result.interval_min);
+        assert_eq!(Units1_25ms(0x0028), result.interval_max);
+        assert_eq!(0x0000, result.latency);
+        assert_eq!(Units10ms(0x01f4), result.timeout);
+    }
+
+    #[test]
+    fn test_request_from_preferred_connection_parameters() {
+        let parameters = PreferredConnectionParameters::new(
+            Units1_25ms(0x0006),
+            Units1_25ms(0x0c80),
+            0x0001,
+            Units10ms(0x0c80),
+        );
+        assert_eq!(
+            ConnectionParameterUpdateRequest::new(
+                0x05,
+                Units1_25ms(0x0006),
+                Units1_25ms(0x0c80),
+                0x0001,
+                Units10ms(0x0c80)
+            ),
+            ConnectionParameterUpdateRequest::from_preferred_connection_parameters(
+                0x05,
+                &parameters
+            )
+        );
+    }
+
+    #[test]
+    fn test_request_validate() {
+        let result = ConnectionParameterUpdateRequest::new(
+            0x01,
+            Units1_25ms(0x0018),
+            Units1_25ms(0x0028),
+            0x0000,
+            Units10ms(0x01f4),
+        );
+        assert_eq!(Ok(()), result.validate());
+
+        let result = ConnectionParameterUpdateRequest::new(
+            0x01,
+            Units1_25ms(0x0018),
+            Units1_25ms(0x0028),
+            0x0004,
+            Units10ms(0x0032),
+        );
+        assert_eq!(
+            Err(vec![
+                ConnectionParameterViolation::SupervisionTimeoutTooShort {
+                    supervision_timeout: 0x0032,
+                    latency: 0x0004,
+                    interval_max: 0x0028
+                }
+            ]),
+            result.validate()
+        );
+
+        let result = ConnectionParameterUpdateRequest::new(
+            0x01,
+            Units1_25ms(0x0005),
+            Units1_25ms(0x0018),
+            0x01f4,
+            Units10ms(0x0c80),
+        );
+        assert_eq!(
+            Err(vec![
+                ConnectionParameterViolation::IntervalOutOfRange { interval: 0x0005 },
+                ConnectionParameterViolation::LatencyOutOfRange { latency: 0x01f4 },
+            ]),
+            result.validate()
+        );
+    }
+
+    #[test]
+    fn test_request_response() {
+        let result = ConnectionParameterUpdateRequest::new(
+            0x01,
+            Units1_25ms(0x0018),
+            Units1_25ms(0x0028),
+            0x0000,
+            Units10ms(0x01f4),
+        );
+        assert_eq!(
+            ConnectionParameterUpdateResponse::new(0x01, CONNECTION_PARAMETERS_ACCEPTED),
+            result.response()
+        );
+
+        let result = ConnectionParameterUpdateRequest::new(
+            0x02,
+            Units1_25ms(0x0028),
+            Units1_25ms(0x0018),
+            0x0000,
+            Units10ms(0x01f4),
+        );
+        assert_eq!(
+            ConnectionParameterUpdateResponse::new(0x02, CONNECTION_PARAMETERS_REJECTED),
+            result.response()
+        );
+    }
+
+    #[test]
+    fn test_request_try_from() {
+        let data: Vec<u8> = [
+            0x12u8, 0x03u8, 0x08u8, 0x00u8, 0x06u8, 0x00u8, 0x80u8, 0x0cu8, 0x01u8, 0x00u8, 0x80u8,
+            0x0cu8,
+        ]
+        .to_vec();
+        assert_eq!(
+            Ok(ConnectionParameterUpdateRequest::new(
+                0x03,
+                Units1_25ms(0x0006),
+                Units1_25ms(0x0c80),
+                0x0001,
+                Units10ms(0x0c80)
+            )),
+            ConnectionParameterUpdateRequest::try_from(&data)
+        );
+
+        let data: Vec<u8> = [0x12u8, 0x03u8, 0x08u8, 0x00u8].to_vec();
+        assert_eq!(
+            Err(format!("Invalid data size :{}", data.len())),
+            ConnectionParameterUpdateRequest::try_from(&data)
+        );
+
+        let data: Vec<u8> = [
+            0x12u8, 0x03u8, 0x07u8, 0x00u8, 0x06u8, 0x00u8, 0x80u8, 0x0cu8, 0x01u8, 0x00u8, 0x80u8,
+            0x0cu8,
+        ]
+        .to_vec();
+        assert_eq!(
+            Err(format!("Invalid data size :{}", data.len())),
+            ConnectionParameterUpdateRequest::try_from(&data)
+        );
+
+        let data: Vec<u8> = [
+            0x13u8, 0x03u8, 0x08u8, 0x00u8, 0x06u8, 0x00u8, 0x80u8, 0x0cu8, 0x01u8, 0x00u8, 0x80u8,
+            0x0cu8,
+        ]
+        .to_vec();
+        assert_eq!(
+            Err("Invalid code :19".to_string()),
+            ConnectionParameterUpdateRequest::try_from(&data)
+        );
+    }
+
+    #[test]
+    fn test_request_into() {
+        let result = ConnectionParameterUpdateRequest::new(
+            0x03,
+            Units1_25ms(0x0006),
+            Units1_25ms(0x0c80),
+            0x0001,
+            Units10ms(0x0c80),
+        );
+        let data: Vec<u8> = [
+            0x12u8, 0x03u8, 0x08u8, 0x00u8, 0x06u8, 0x00u8, 0x80u8, 0x0cu8, 0x01u8, 0x00u8, 0x80u8,
+            0x0cu8,
+        ]
+        .to_vec();
+        let into_data: Vec<u8> = result.clone().into();
+        assert_eq!(data, into_data);
+        assert_eq!(
+            Ok(result),
+            ConnectionParameterUpdateRequest::try_from(&data)
+        );
+    }
+
+    #[test]
+    fn test_response_new() {
+        let result = ConnectionParameterUpdateResponse::new(0x01, CONNECTION_PARAMETERS_REJECTED);
+        assert_eq!(0x01, result.identifier);
+        assert_eq!(CONNECTION_PARAMETERS_REJECTED, result.result);
+        assert!(!result.is_accepted());
+        assert!(
+            ConnectionParameterUpdateResponse::new(0x01, CONNECTION_PARAMETERS_ACCEPTED)
+                .is_accepted()
+        );
+    }
+
+    #[test]
+    fn test_response_try_from() {
+        let data: Vec<u8> = [0x13u8, 0x04u8, 0x02u8, 0x00u8, 0x00u8, 0x00u8].to_vec();
+        assert_eq!(
+            Ok(ConnectionParameterUpdateResponse::new(
+                0x04,
+                CONNECTION_PARAMETERS_ACCEPTED
+            )),
+            ConnectionParameterUpdateResponse::try_from(&data)
+        );
+
+        let data: Vec<u8> = [0x13u8, 0x04u8, 0x02u8, 0x00u8, 0x00u8].to_vec();
+        assert_eq!(
+            Err(format!("Invalid data size :{}", data.len())),
+            ConnectionParameterUpdateResponse::try_from(&data)
+        );
+
+        let data: Vec<u8> = [0x12u8, 0x04u8, 0x02u8, 0x00u8, 0x00u8, 0x00u8].to_vec();
+        assert_eq!(
+            Err("Invalid code :18".to_string()),
+            ConnectionParameterUpdateResponse::try_from(&data)
+        );
+    }
+
+    #[test]
+    fn test_response_into() {
+        let result = ConnectionParameterUpdateResponse::new(0x04, CONNECTION_PARAMETERS_REJECTED);
+        let data: Vec<u8> = [0x13u8, 0x04u8, 0x02u8, 0x00u8, 0x01u8, 0x00u8].to_vec();
+        let into_data: Vec<u8> = result.clone().into();
+        assert_eq!(data, into_data);
+        assert_eq!(
+            Ok(result),
+            ConnectionParameterUpdateResponse::try_from(&data)
+        );
+    }
+}
@@ -1,20 +1,34 @@
 //! BLE data struct.
 pub mod data_types {
     //! EIR/AD/SRD/ACAD/OOB module.
+    pub mod ad_structure;
+    pub mod ad_type;
+    pub mod address_type;
+    pub mod advertising_data;
     pub mod advertising_interval;
     pub mod advertising_interval_long;
     pub mod appearance;
+    pub mod appearance_category;
+    pub mod appearance_name;
     pub mod big_info;
     pub mod broadcast_code;
+    pub mod broadcast_name;
     pub mod channel_map_update_indication;
     pub mod class_of_device;
     pub mod complete_list_of_128bit_service_uuids;
     pub mod complete_list_of_16bit_service_uuids;
     pub mod complete_list_of_32bit_service_uuids;
     pub mod complete_local_name;
+    pub mod connection_parameters;
+    pub mod custom_data_type;
     pub mod data_type;
     pub mod data_type_parser;
+    pub mod data_type_ref;
+    pub mod data_type_registry;
+    pub mod device_id;
+    pub mod electronic_shelf_label;
     pub mod encrypted_data;
+    pub mod encrypted_data_randomizer;
     pub mod flags;
     pub mod incomplete_list_of_128bit_service_uuids;
     pub mod incomplete_list_of_16bit_service_uuids;
@@ -24,14 +38,27 @@ pub mod data_types {
     pub mod le_secure_connections_confirmation_value;
     pub mod le_secure_connections_random_value;
     pub mod le_supported_features;
+    pub mod limited_discoverable;
     pub mod list_of_128bit_service_solicitation_uuids;
     pub mod list_of_16bit_service_solicitation_uuids;
     pub mod list_of_32bit_service_solicitation_uuids;
+    pub mod local_name;
+    pub mod manufacturer_data_reconciliation;
     pub mod manufacturer_specific_data;
+    pub mod mesh_beacon;
+    pub mod mesh_message;
+    pub mod pb_adv;
+    #[cfg(feature = "stats")]
+    pub mod parse_stats;
+    pub mod parse_error;
+    pub mod parse_warning;
     pub mod periodic_advertising_response_timing_information;
     pub mod peripheral_connection_interval_range;
+    pub mod private_address;
     pub mod public_target_address;
     pub mod random_target_address;
+    pub mod raw_ad_structure;
+    pub mod resolvable_set_identifier;
     pub mod secure_simple_pairing_hash_c192;
     pub mod secure_simple_pairing_hash_c256;
     pub mod secure_simple_pairing_randomizer_r192;
@@ -41,19 +68,141 @@ pub mod data_types {
     pub mod service_data_128bit_uuid;
     pub mod service_data_16bit_uuid;
     pub mod service_data_32bit_uuid;
+    pub mod service_solicitation_uuid_list;
+    pub mod service_uuid_list;
     pub mod shortened_local_name;
+    pub mod spec_version;
+    pub mod three_d_information_data;
+    pub mod time_units;
     pub mod tx_power_level;
+    pub mod u128_data_type;
     pub mod uniform_resource_identifier;
+    pub mod uuid_list;
+}
+
+pub mod beacons {
+    //! beacon module.
+    pub mod eddystone;
+    pub mod ibeacon;
+}
+
+pub mod characteristics {
+    //! characteristic module.
+    pub mod battery_level;
+    pub mod control_point;
+    pub mod device_information;
+    pub mod media_control;
+    pub mod microphone_control;
+    pub mod peripheral_preferred_connection_parameters;
+    pub mod telephone_bearer;
+}
+
+#[cfg(feature = "crypto")]
+pub mod crypto {
+    //! crypto module.
+    pub mod toolbox;
 }
 
 pub mod descriptors {
     //! descriptor module.
+    pub mod cccd_table;
     pub mod characteristic_extended_properties;
     pub mod characteristic_user_description;
     pub mod client_characteristic_configuration;
     pub mod server_characteristic_configuration;
     pub mod characteristic_presentation_format;
     pub mod characteristic_aggregate_format;
+    pub mod descriptor_parser;
+    pub mod presentation_format_description;
+    pub mod presentation_format_generator;
+}
+
+pub mod gatt {
+    //! GATT module.
+    #[cfg(all(feature = "bluer", target_os = "linux"))]
+    pub mod bluer_application;
+    pub mod bluez_application;
+    pub mod database_hash;
+    pub mod long_value;
+    pub mod read_by_type;
+    pub mod security;
+}
+
+pub mod hci {
+    //! HCI module.
+    pub mod channel_classification;
+    pub mod hci_command;
+    pub mod le_filter_accept_list;
+    pub mod le_meta_event;
+    pub mod le_periodic_advertising_sync_transfer;
+    pub mod le_periodic_advertising_sync_transfer_received;
+    pub mod le_set_advertising_parameters;
+    pub mod le_set_scan_parameters;
+}
+
+pub mod l2cap {
+    //! L2CAP module.
+    pub mod connection_parameter_update;
+}
+
+pub mod lint {
+    //! lint module.
+    pub mod advertising;
+}
+
+pub mod payload {
+    //! advertising payload module.
+    pub mod builder;
+    pub mod canonical;
+    pub mod const_payload;
+    pub mod decompile;
+    pub mod legality;
+    pub mod oob;
+    pub mod rotation;
+    pub mod template;
+    pub mod truncate;
+    pub mod update;
+}
+
+pub mod scan {
+    //! scan module.
+    pub mod batch;
+    pub mod delta;
+    pub mod privacy;
+    pub mod scan_record;
+    #[cfg(feature = "async")]
+    pub mod scan_stream;
+    pub mod simulator;
+    pub mod uuid_interner;
+}
+
+pub mod service_data {
+    //! service data module.
+    pub mod broadcast_audio_announcement;
+    pub mod exposure_notification;
+    #[cfg(feature = "matter")]
+    pub mod matter_commissioning;
+    pub mod registry;
+}
+
+pub mod uuids {
+    //! assigned 16bit UUID module.
+    //!
+    //! # Examples
+    //!
+    //! ```
+    //! use ble_data_struct::{
+    //!     characteristics::battery_level::BatteryLevel,
+    //!     uuids::{characteristic, service},
+    //!     Uuid16bit,
+    //! };
+    //!
+    //! assert_eq!(0x180d, service::HEART_RATE);
+    //! assert_eq!(characteristic::BATTERY_LEVEL, BatteryLevel::uuid_16bit());
+    //! ```
+    pub mod characteristic;
+    pub mod descriptor;
+    pub mod service;
 }
 
 /// for Windows
@@ -61,6 +210,7 @@ pub mod descriptors {
 pub mod windows {
     pub mod data_types {
         pub mod windows_data_type_parser;
+        pub mod windows_manufacturer_data;
     }
     pub mod descriptors {
         pub mod windows_characteristic_aggregate_format;
@@ -71,6 +221,8 @@ pub mod windows {
         pub mod windows_server_characteristic_configuration;
     }
     pub mod buffer;
+    pub mod profiles;
+    pub mod publisher;
 }
 
 use uuid::{uuid, Uuid};
@@ -116,15 +268,253 @@ pub fn uuid_from_u32(value: u32) -> Uuid {
     Uuid::from_fields(d1 | value, d2, d3, d4)
 }
 
+/// check [`Uuid`] is an alias of [`BASE_UUID`] (`xxxxxxxx-0000-1000-8000-00805F9B34FB`).
+///
+/// # Examples
+///
+/// ```
+/// use ble_data_struct::{is_base_uuid_alias, BASE_UUID};
+/// use uuid::uuid;
+///
+/// assert!(is_base_uuid_alias(BASE_UUID));
+/// assert!(is_base_uuid_alias(uuid!("12345678-0000-1000-8000-00805F9B34FB")));
+/// assert!(!is_base_uuid_alias(uuid!("12345678-0000-1000-8000-00805F9B34FC")));
+/// ```
+pub fn is_base_uuid_alias(uuid: Uuid) -> bool {
+    const MASK: u128 = 0x0000_0000_ffff_ffff_ffff_ffff_ffff_ffff;
+    uuid.as_u128() & MASK == BASE_UUID.as_u128() & MASK
+}
+
+/// Create [`u32`] from [`Uuid`](`None` if not an alias of [`BASE_UUID`]).
+///
+/// # Examples
+///
+/// ```
+/// use ble_data_struct::{uuid_from_u32, uuid_to_u32};
+/// use uuid::uuid;
+///
+/// assert_eq!(Some(0x12345678), uuid_to_u32(uuid_from_u32(0x12345678)));
+/// assert_eq!(None, uuid_to_u32(uuid!("12345678-0000-1000-8000-00805F9B34FC")));
+/// ```
+pub fn uuid_to_u32(uuid: Uuid) -> Option<u32> {
+    if is_base_uuid_alias(uuid) {
+        Some((uuid.as_u128() >> 96) as u32)
+    } else {
+        None
+    }
+}
+
+/// Create [`u16`] from [`Uuid`](`None` if not a 16-bit alias of [`BASE_UUID`]).
+///
+/// # Examples
+///
+/// ```
+/// use ble_data_struct::{uuid_from_u16, uuid_from_u32, uuid_to_u16};
+/// use uuid::uuid;
+///
+/// assert_eq!(Some(0x1234), uuid_to_u16(uuid_from_u16(0x1234)));
+/// assert_eq!(None, uuid_to_u16(uuid_from_u32(0x12345678)));
+/// assert_eq!(None, uuid_to_u16(uuid!("00001234-0000-1000-8000-00805F9B34FC")));
+/// ```
+pub fn uuid_to_u16(uuid: Uuid) -> Option<u16> {
+    uuid_to_u32(uuid).and_then(|f| u16::try_from(f).ok())
+}
+
 /// Trait for Assigned 16bit-UUID.
 pub trait Uuid16bit {
     /// Assigned 16bit-UUID
     fn uuid_16bit() -> u16;
 }
 
+/// Compile-time `Send + Sync` audit for the public data types.
+///
+/// Parse results are produced from multi-threaded scanner callbacks
+/// (e.g. Windows `TypedEventHandler`), so every public type must stay shareable.
+const _: () = {
+    const fn assert_send_sync<T: Send + Sync>() {}
+
+    assert_send_sync::<data_types::data_type_parser::AdStructureIter<'static>>();
+    assert_send_sync::<gatt::bluez_application::BluezGattApplication>();
+    assert_send_sync::<data_types::data_type_parser::DataTypeParseResult>();
+    assert_send_sync::<data_types::data_type_parser::DataTypeParseResults>();
+    assert_send_sync::<data_types::data_type_parser::ParseContext>();
+    assert_send_sync::<data_types::data_type_registry::DataTypeRegistry>();
+    assert_send_sync::<data_types::custom_data_type::CustomDataType>();
+    assert_send_sync::<data_types::time_units::Slots625us>();
+    assert_send_sync::<data_types::time_units::Units1_25ms>();
+    assert_send_sync::<data_types::time_units::Units10ms>();
+    assert_send_sync::<data_types::ad_type::AdType>();
+    assert_send_sync::<data_types::advertising_data::AdvertisingData>();
+    assert_send_sync::<data_types::advertising_interval::AdvertisingInterval>();
+    assert_send_sync::<data_types::advertising_interval_long::AdvertisingIntervalLong>();
+    assert_send_sync::<data_types::appearance::Appearance>();
+    assert_send_sync::<data_types::appearance::AppearanceIcon>();
+    assert_send_sync::<data_types::appearance_name::AssignedNumbersNameProvider>();
+    assert_send_sync::<
+        data_types::appearance_name::LocalizedNameProvider<
+            data_types::appearance_name::AssignedNumbersNameProvider,
+        >,
+    >();
+    assert_send_sync::<data_types::address_type::AddressType>();
+    assert_send_sync::<data_types::big_info::BigInfo>();
+    assert_send_sync::<data_types::big_info::BigInfoBuilder>();
+    #[cfg(feature = "crypto")]
+    assert_send_sync::<data_types::big_info::DecryptableBigInfo>();
+    assert_send_sync::<data_types::broadcast_code::BroadcastCode>();
+    assert_send_sync::<data_types::broadcast_name::BroadcastName>();
+    assert_send_sync::<data_types::channel_map_update_indication::ChannelMapUpdateIndication>();
+    assert_send_sync::<data_types::class_of_device::ClassOfDevice>();
+    assert_send_sync::<data_types::complete_list_of_128bit_service_uuids::CompleteListOf128BitServiceUuids>();
+    assert_send_sync::<data_types::complete_list_of_16bit_service_uuids::CompleteListOf16BitServiceUuids>();
+    assert_send_sync::<data_types::complete_list_of_32bit_service_uuids::CompleteListOf32BitServiceUuids>();
+    assert_send_sync::<data_types::complete_local_name::CompleteLocalName>();
+    assert_send_sync::<data_types::connection_parameters::ConnectionParameterViolation>();
+    assert_send_sync::<data_types::device_id::DeviceId>();
+    assert_send_sync::<data_types::electronic_shelf_label::ElectronicShelfLabel>();
+    assert_send_sync::<data_types::encrypted_data::EncryptedData>();
+    assert_send_sync::<data_types::encrypted_data_randomizer::RandomizerHistory>();
+    assert_send_sync::<data_types::flags::DiscoverableMode>();
+    assert_send_sync::<data_types::flags::Flags>();
+    assert_send_sync::<data_types::incomplete_list_of_128bit_service_uuids::IncompleteListOf128BitServiceUuids>();
+    assert_send_sync::<data_types::incomplete_list_of_16bit_service_uuids::IncompleteListOf16BitServiceUuids>();
+    assert_send_sync::<data_types::incomplete_list_of_32bit_service_uuids::IncompleteListOf32BitServiceUuids>();
+    assert_send_sync::<data_types::le_bluetooth_device_address::LeBluetoothDeviceAddress>();
+    assert_send_sync::<data_types::le_role::LeRole>();
+    assert_send_sync::<data_types::le_secure_connections_confirmation_value::LeSecureConnectionsConfirmationValue>();
+    assert_send_sync::<data_types::le_secure_connections_random_value::LeSecureConnectionsRandomValue>();
+    assert_send_sync::<data_types::le_supported_features::FeatureDiff>();
+    assert_send_sync::<data_types::le_supported_features::LeSupportedFeatures>();
+    assert_send_sync::<data_types::limited_discoverable::LimitedDiscoverableTimer>();
+    assert_send_sync::<data_types::list_of_128bit_service_solicitation_uuids::ListOf128BitServiceSolicitationUUIDs>();
+    assert_send_sync::<data_types::list_of_16bit_service_solicitation_uuids::ListOf16BitServiceSolicitationUUIDs>();
+    assert_send_sync::<data_types::list_of_32bit_service_solicitation_uuids::ListOf32BitServiceSolicitationUUIDs>();
+    assert_send_sync::<data_types::manufacturer_data_reconciliation::ReconciledManufacturerData>();
+    assert_send_sync::<data_types::manufacturer_specific_data::ManufacturerSpecificData>();
+    assert_send_sync::<data_types::manufacturer_specific_data::ManufacturerSpecificDataRef<'static>>();
+    assert_send_sync::<data_types::mesh_beacon::MeshBeacon>();
+    assert_send_sync::<data_types::mesh_message::MeshMessage>();
+    assert_send_sync::<data_types::pb_adv::PbAdv>();
+    #[cfg(feature = "stats")]
+    assert_send_sync::<data_types::parse_stats::ParseStats>();
+    assert_send_sync::<data_types::parse_error::DataTypeParseError>();
+    assert_send_sync::<data_types::parse_warning::ParseWarning>();
+    assert_send_sync::<data_types::periodic_advertising_response_timing_information::PeriodicAdvertisingResponseTimingInformation>();
+    assert_send_sync::<data_types::peripheral_connection_interval_range::PeripheralConnectionIntervalRange>();
+    assert_send_sync::<data_types::private_address::PrivateAddressSchedule>();
+    assert_send_sync::<data_types::public_target_address::PublicTargetAddress>();
+    assert_send_sync::<data_types::random_target_address::RandomTargetAddress>();
+    assert_send_sync::<data_types::resolvable_set_identifier::ResolvableSetIdentifier>();
+    assert_send_sync::<data_types::raw_ad_structure::RawAdStructure>();
+    assert_send_sync::<data_types::secure_simple_pairing_hash_c192::SecureSimplePairingHashC192>();
+    assert_send_sync::<data_types::secure_simple_pairing_hash_c256::SecureSimplePairingHashC256>();
+    assert_send_sync::<data_types::secure_simple_pairing_randomizer_r192::SecureSimplePairingRandomizerR192>();
+    assert_send_sync::<data_types::secure_simple_pairing_randomizer_r256::SecureSimplePairingRandomizerR256>();
+    assert_send_sync::<data_types::security_manager_oob::SecurityManagerOutOfBand>();
+    assert_send_sync::<data_types::security_manager_tk_value::SecurityManagerTkValue>();
+    assert_send_sync::<data_types::service_data_128bit_uuid::ServiceData128BitUUID>();
+    assert_send_sync::<data_types::service_data_128bit_uuid::ServiceData128BitUUIDRef<'static>>();
+    assert_send_sync::<data_types::service_data_16bit_uuid::ServiceData16BitUUID>();
+    assert_send_sync::<data_types::service_data_16bit_uuid::ServiceData16BitUUIDRef<'static>>();
+    assert_send_sync::<data_types::service_data_32bit_uuid::ServiceData32BitUUID>();
+    assert_send_sync::<data_types::service_data_32bit_uuid::ServiceData32BitUUIDRef<'static>>();
+    assert_send_sync::<data_types::shortened_local_name::ShortenedLocalName>();
+    assert_send_sync::<data_types::spec_version::CoreVersion>();
+    assert_send_sync::<data_types::three_d_information_data::ThreeDInformationData>();
+    assert_send_sync::<data_types::tx_power_level::TxPowerLevel>();
+    assert_send_sync::<data_types::uniform_resource_identifier::UniformResourceIdentifier>();
+
+    assert_send_sync::<descriptors::cccd_table::CccdTable>();
+    assert_send_sync::<descriptors::descriptor_parser::DescriptorParseResult>();
+    assert_send_sync::<descriptors::presentation_format_generator::PresentationFormatGenerator>();
+
+    assert_send_sync::<hci::channel_classification::ChannelClassification>();
+    assert_send_sync::<hci::le_filter_accept_list::AddDeviceToFilterAcceptList>();
+    assert_send_sync::<hci::le_filter_accept_list::FilterAcceptList>();
+    assert_send_sync::<hci::le_filter_accept_list::FilterAcceptListEntry>();
+    assert_send_sync::<hci::le_filter_accept_list::RemoveDeviceFromFilterAcceptList>();
+    assert_send_sync::<hci::le_periodic_advertising_sync_transfer::PeriodicAdvertisingSyncTransfer>();
+    assert_send_sync::<hci::le_periodic_advertising_sync_transfer::PeriodicAdvertisingSetInfoTransfer>();
+    assert_send_sync::<hci::le_periodic_advertising_sync_transfer::SetPeriodicAdvertisingSyncTransferParameters>();
+    assert_send_sync::<hci::le_periodic_advertising_sync_transfer::SetDefaultPeriodicAdvertisingSyncTransferParameters>();
+    assert_send_sync::<hci::le_periodic_advertising_sync_transfer_received::PeriodicAdvertisingSyncTransferReceived>();
+    assert_send_sync::<hci::le_set_advertising_parameters::AdvertisingChannelMap>();
+    assert_send_sync::<hci::le_set_advertising_parameters::AdvertisingFilterPolicy>();
+    assert_send_sync::<hci::le_set_advertising_parameters::AdvertisingParameters>();
+    assert_send_sync::<hci::le_set_scan_parameters::ScanParameters>();
+    assert_send_sync::<hci::le_set_scan_parameters::ScanType>();
+    assert_send_sync::<hci::le_set_scan_parameters::ScanningFilterPolicy>();
+    assert_send_sync::<gatt::database_hash::GattAttribute>();
+    assert_send_sync::<gatt::long_value::LongValueAssembler>();
+    assert_send_sync::<gatt::read_by_type::AttributeData>();
+    assert_send_sync::<gatt::read_by_type::GroupAttributeData>();
+    assert_send_sync::<gatt::read_by_type::ReadByGroupTypeResponse>();
+    assert_send_sync::<gatt::read_by_type::ReadByTypeResponse>();
+    assert_send_sync::<gatt::security::AttributePermissions>();
+    assert_send_sync::<gatt::security::SecurityLevel>();
+
+    assert_send_sync::<l2cap::connection_parameter_update::ConnectionParameterUpdateRequest>();
+    assert_send_sync::<l2cap::connection_parameter_update::ConnectionParameterUpdateResponse>();
+    assert_send_sync::<lint::advertising::LintFinding>();
+    assert_send_sync::<lint::advertising::LintReport>();
+    assert_send_sync::<lint::advertising::LintRule>();
+    assert_send_sync::<lint::advertising::LintTarget>();
+    assert_send_sync::<lint::advertising::RuleSet>();
+    assert_send_sync::<lint::advertising::Severity>();
+    assert_send_sync::<payload::builder::AdvertisingPayloadBuilder>();
+    assert_send_sync::<payload::legality::AdvertisingPduType>();
+    assert_send_sync::<payload::legality::LegalityViolation>();
+    assert_send_sync::<payload::oob::LeSecureConnectionsOobBlock>();
+    assert_send_sync::<payload::oob::OobError>();
+    assert_send_sync::<payload::template::PayloadTemplate>();
+
+    assert_send_sync::<scan::delta::DeltaDecoder>();
+    assert_send_sync::<scan::delta::DeltaEncoder>();
+    assert_send_sync::<scan::delta::DeltaScanRecord>();
+    assert_send_sync::<scan::privacy::PrivacyFinding>();
+    assert_send_sync::<scan::scan_record::ScanRecord>();
+    #[cfg(feature = "async")]
+    assert_send_sync::<scan::scan_stream::ScanSender>();
+    #[cfg(feature = "async")]
+    assert_send_sync::<scan::scan_stream::ScanStream>();
+    assert_send_sync::<scan::simulator::ScanSimulator>();
+    assert_send_sync::<scan::simulator::TimedScanRecord>();
+    assert_send_sync::<scan::uuid_interner::UuidInterner>();
+
+    assert_send_sync::<service_data::broadcast_audio_announcement::BroadcastAudioAnnouncement>();
+    assert_send_sync::<service_data::exposure_notification::ExposureNotification>();
+    #[cfg(feature = "matter")]
+    assert_send_sync::<service_data::matter_commissioning::MatterCommissioning>();
+    assert_send_sync::<service_data::registry::ServiceDataPayload>();
+    assert_send_sync::<service_data::registry::ServiceDataRegistry>();
+
+    assert_send_sync::<beacons::eddystone::Eddystone>();
+    assert_send_sync::<beacons::ibeacon::IBeacon>();
+
+    assert_send_sync::<characteristics::battery_level::BatteryLevel>();
+    assert_send_sync::<characteristics::control_point::ChangeCounter>();
+    assert_send_sync::<characteristics::device_information::ManufacturerNameString>();
+    assert_send_sync::<characteristics::media_control::MediaState>();
+    assert_send_sync::<characteristics::media_control::TrackTitle>();
+    assert_send_sync::<characteristics::media_control::MediaControlPoint>();
+    assert_send_sync::<characteristics::microphone_control::Mute>();
+    assert_send_sync::<characteristics::peripheral_preferred_connection_parameters::PreferredConnectionParameters>();
+    assert_send_sync::<characteristics::telephone_bearer::CallState>();
+    assert_send_sync::<characteristics::telephone_bearer::CallControlPoint>();
+    assert_send_sync::<characteristics::telephone_bearer::CallControlPointNotification>();
+
+    assert_send_sync::<descriptors::characteristic_aggregate_format::CharacteristicAggregateFormat>();
+    assert_send_sync::<descriptors::characteristic_extended_properties::CharacteristicExtendedProperties>();
+    assert_send_sync::<descriptors::characteristic_presentation_format::CharacteristicPresentationFormat>();
+    assert_send_sync::<descriptors::characteristic_user_description::CharacteristicUserDescription>();
+    assert_send_sync::<descriptors::client_characteristic_configuration::ClientCharacteristicConfiguration>();
+    assert_send_sync::<descriptors::server_characteristic_configuration::ServerCharacteristicConfiguration>();
+};
+
 #[cfg(test)]
 mod tests {
-    use crate::{uuid_from_u16, uuid_from_u32};
+    use crate::{
+        is_base_uuid_alias, uuid_from_u16, uuid_from_u32, uuid_to_u16, uuid_to_u32, BASE_UUID,
+    };
     use uuid::uuid;
 
     #[test]
@@ -142,4 +532,39 @@ mod tests {
             uuid_from_u32(0x12345678)
         );
     }
+
+    #[test]
+    fn test_is_base_uuid_alias() {
+        assert!(is_base_uuid_alias(BASE_UUID));
+        assert!(is_base_uuid_alias(uuid_from_u16(0xffff)));
+        assert!(is_base_uuid_alias(uuid_from_u32(0xffffffff)));
+        assert!(!is_base_uuid_alias(uuid!(
+            "00001234-0001-1000-8000-00805F9B34FB"
+        )));
+        assert!(!is_base_uuid_alias(uuid!(
+            "00001234-0000-1000-8000-00805F9B34FA"
+        )));
+    }
+
+    #[test]
+    fn test_uuid_to_u32() {
+        assert_eq!(Some(0), uuid_to_u32(BASE_UUID));
+        assert_eq!(Some(0x1234), uuid_to_u32(uuid_from_u16(0x1234)));
+        assert_eq!(Some(0xffffffff), uuid_to_u32(uuid_from_u32(0xffffffff)));
+        assert_eq!(
+            None,
+            uuid_to_u32(uuid!("12345678-0000-1000-8000-10805F9B34FB"))
+        );
+    }
+
+    #[test]
+    fn test_uuid_to_u16() {
+        assert_eq!(Some(0x180d), uuid_to_u16(uuid_from_u16(0x180d)));
+        assert_eq!(Some(0xffff), uuid_to_u16(uuid_from_u32(0x0000ffff)));
+        assert_eq!(None, uuid_to_u16(uuid_from_u32(0x00010000)));
+        assert_eq!(
+            None,
+            uuid_to_u16(uuid!("0000180d-0000-1000-8000-00805F9B34FC"))
+        );
+    }
 }
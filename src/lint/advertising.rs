@@ -0,0 +1,811 @@
+//! Advertising payload lint module.
+//!
+//! Runs the payload validators (placement rules, duplicates, privacy audit, name truncation, interval sanity)
+//! with a configurable [`RuleSet`] and collects the findings into a [`LintReport`].
+
+use crate::{
+    data_types::{
+        address_type::AddressType,
+        advertising_interval::AdvertisingInterval,
+        data_type::DataType,
+        data_type_parser::{DataTypeParseResult, DataTypeParseResults},
+        flags::{DiscoverableMode, Flags},
+        manufacturer_specific_data::ManufacturerSpecificData,
+        peripheral_connection_interval_range::PeripheralConnectionIntervalRange,
+        service_data_128bit_uuid::ServiceData128BitUUID,
+        service_data_16bit_uuid::ServiceData16BitUUID,
+        service_data_32bit_uuid::ServiceData32BitUUID,
+        shortened_local_name::ShortenedLocalName,
+    },
+    hci::le_set_advertising_parameters::{ADVERTISING_INTERVAL_MAX, ADVERTISING_INTERVAL_MIN},
+    payload::legality::{check_legality, AdvertisingPduType, LegalityViolation},
+    scan::{privacy::audit_privacy, scan_record::ScanRecord},
+};
+
+/// Lint rule.
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Hash)]
+pub enum LintRule {
+    /// Data type placement and Flags rules ([`check_legality`]).
+    Placement,
+
+    /// Data type appears more than once.
+    Duplicate,
+
+    /// Identifiable data ([`audit_privacy`]).
+    Privacy,
+
+    /// Shortened Local Name is not a prefix of the Complete Local Name.
+    NameTruncation,
+
+    /// Advertising Interval / Peripheral Connection Interval Range out of range.
+    IntervalSanity,
+
+    /// [`DataTypeParseResult::warnings`].
+    ParseWarning,
+
+    /// [`DataTypeParseResult::parse_error`].
+    ParseError,
+}
+
+impl LintRule {
+    /// Returns the rule identifier used in the report.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ble_data_struct::lint::advertising::LintRule;
+    ///
+    /// assert_eq!("name-truncation", LintRule::NameTruncation.id());
+    /// ```
+    pub const fn id(&self) -> &'static str {
+        match self {
+            Self::Placement => "placement",
+            Self::Duplicate => "duplicate",
+            Self::Privacy => "privacy",
+            Self::NameTruncation => "name-truncation",
+            Self::IntervalSanity => "interval-sanity",
+            Self::ParseWarning => "parse-warning",
+            Self::ParseError => "parse-error",
+        }
+    }
+}
+
+/// Finding severity.
+#[derive(Debug, PartialEq, Eq, PartialOrd, Ord, Clone, Copy, Hash)]
+pub enum Severity {
+    /// Informational
+    Info,
+
+    /// Warning
+    Warning,
+
+    /// Error
+    Error,
+}
+
+impl Severity {
+    /// Returns the severity identifier used in the report.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ble_data_struct::lint::advertising::Severity;
+    ///
+    /// assert_eq!("warning", Severity::Warning.id());
+    /// ```
+    pub const fn id(&self) -> &'static str {
+        match self {
+            Self::Info => "info",
+            Self::Warning => "warning",
+            Self::Error => "error",
+        }
+    }
+}
+
+/// Enabled rules and their severities.
+#[derive(Debug, PartialEq, Clone)]
+pub struct RuleSet {
+    /// Enabled rules
+    rules: Vec<(LintRule, Severity)>,
+}
+
+impl RuleSet {
+    /// Create empty [`RuleSet`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ble_data_struct::lint::advertising::{LintRule, RuleSet};
+    ///
+    /// assert_eq!(None, RuleSet::new().severity(LintRule::Placement));
+    /// ```
+    pub fn new() -> Self {
+        Self { rules: Vec::new() }
+    }
+
+    /// Create [`RuleSet`] with all rules reported as [`Severity::Error`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ble_data_struct::lint::advertising::{LintRule, RuleSet, Severity};
+    ///
+    /// assert_eq!(Some(Severity::Error), RuleSet::strict().severity(LintRule::Privacy));
+    /// ```
+    pub fn strict() -> Self {
+        Self {
+            rules: Self::default()
+                .rules
+                .into_iter()
+                .map(|(rule, _)| (rule, Severity::Error))
+                .collect(),
+        }
+    }
+
+    /// Set the severity of the rule. [`None`] disables the rule.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ble_data_struct::lint::advertising::{LintRule, RuleSet, Severity};
+    ///
+    /// let result = RuleSet::default()
+    ///     .with_rule(LintRule::Privacy, None)
+    ///     .with_rule(LintRule::Duplicate, Some(Severity::Error));
+    /// assert_eq!(None, result.severity(LintRule::Privacy));
+    /// assert_eq!(Some(Severity::Error), result.severity(LintRule::Duplicate));
+    /// ```
+    pub fn with_rule(mut self, rule: LintRule, severity: Option<Severity>) -> Self {
+        self.rules.retain(|f| f.0 != rule);
+        if let Some(severity) = severity {
+            self.rules.push((rule, severity));
+        }
+        self
+    }
+
+    /// Returns the severity of the rule, [`None`] if disabled.
+    pub fn severity(&self, rule: LintRule) -> Option<Severity> {
+        self.rules.iter().find(|f| f.0 == rule).map(|f| f.1)
+    }
+}
+
+impl Default for RuleSet {
+    /// Create [`RuleSet`] with all rules and recommended severities.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ble_data_struct::lint::advertising::{LintRule, RuleSet, Severity};
+    ///
+    /// let result = RuleSet::default();
+    /// assert_eq!(Some(Severity::Error), result.severity(LintRule::Placement));
+    /// assert_eq!(Some(Severity::Info), result.severity(LintRule::Privacy));
+    /// ```
+    fn default() -> Self {
+        Self {
+            rules: vec![
+                (LintRule::Placement, Severity::Error),
+                (LintRule::Duplicate, Severity::Warning),
+                (LintRule::Privacy, Severity::Info),
+                (LintRule::NameTruncation, Severity::Warning),
+                (LintRule::IntervalSanity, Severity::Error),
+                (LintRule::ParseWarning, Severity::Warning),
+                (LintRule::ParseError, Severity::Error),
+            ],
+        }
+    }
+}
+
+/// Lint target.
+#[derive(Debug, PartialEq, Clone)]
+pub struct LintTarget {
+    /// Advertising PDU type
+    pub pdu_type: AdvertisingPduType,
+
+    /// GAP discoverable mode
+    pub mode: DiscoverableMode,
+
+    /// Advertising data
+    pub advertising_data: Vec<u8>,
+
+    /// Scan response data
+    pub scan_response_data: Vec<u8>,
+
+    /// Bluetooth Device Address
+    pub address: u64,
+
+    /// Bluetooth Device Address type
+    pub address_type: Option<AddressType>,
+}
+
+impl LintTarget {
+    /// Create [`LintTarget`] from Parameters.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ble_data_struct::{
+    ///     data_types::flags::DiscoverableMode,
+    ///     lint::advertising::LintTarget,
+    ///     payload::legality::AdvertisingPduType,
+    /// };
+    ///
+    /// let result = LintTarget::new(AdvertisingPduType::AdvInd, DiscoverableMode::General, &[2, 0x01, 0x06], &[]);
+    /// assert_eq!(vec![2, 0x01, 0x06], result.advertising_data);
+    /// assert!(result.scan_response_data.is_empty());
+    /// assert_eq!(None, result.address_type);
+    /// ```
+    pub fn new(
+        pdu_type: AdvertisingPduType,
+        mode: DiscoverableMode,
+        advertising_data: &[u8],
+        scan_response_data: &[u8],
+    ) -> Self {
+        Self {
+            pdu_type,
+            mode,
+            advertising_data: advertising_data.to_vec(),
+            scan_response_data: scan_response_data.to_vec(),
+            address: 0,
+            address_type: None,
+        }
+    }
+
+    /// Set `Bluetooth Device Address` and its type for the privacy audit.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ble_data_struct::{
+    ///     data_types::{address_type::AddressType, flags::DiscoverableMode},
+    ///     lint::advertising::LintTarget,
+    ///     payload::legality::AdvertisingPduType,
+    /// };
+    ///
+    /// let result = LintTarget::new(AdvertisingPduType::AdvNonconnInd, DiscoverableMode::NonDiscoverable, &[], &[])
+    ///     .with_address(0x010203040506, AddressType::Public);
+    /// assert_eq!(0x010203040506, result.address);
+    /// assert_eq!(Some(AddressType::Public), result.address_type);
+    /// ```
+    pub fn with_address(mut self, address: u64, address_type: AddressType) -> Self {
+        self.address = address;
+        self.address_type = Some(address_type);
+        self
+    }
+}
+
+/// Lint finding.
+#[derive(Debug, PartialEq, Clone)]
+pub struct LintFinding {
+    /// Rule
+    pub rule: LintRule,
+
+    /// Severity
+    pub severity: Severity,
+
+    /// data type, if the finding belongs to a data type
+    pub data_type: Option<u8>,
+
+    /// Finding detail
+    pub message: String,
+}
+
+/// Lint report.
+#[derive(Debug, PartialEq, Clone)]
+pub struct LintReport {
+    /// Findings
+    pub findings: Vec<LintFinding>,
+}
+
+impl LintReport {
+    /// Returns the number of findings with the severity.
+    pub fn count(&self, severity: Severity) -> usize {
+        self.findings
+            .iter()
+            .filter(|f| f.severity == severity)
+            .count()
+    }
+
+    /// Returns the highest severity, [`None`] if there is no finding.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ble_data_struct::lint::advertising::{LintFinding, LintReport, LintRule, Severity};
+    ///
+    /// let report = LintReport { findings: Vec::new() };
+    /// assert_eq!(None, report.max_severity());
+    ///
+    /// let finding = LintFinding { rule: LintRule::Privacy, severity: Severity::Info, data_type: None, message: String::new() };
+    /// let report = LintReport { findings: vec![finding] };
+    /// assert_eq!(Some(Severity::Info), report.max_severity());
+    /// ```
+    pub fn max_severity(&self) -> Option<Severity> {
+        self.findings.iter().map(|f| f.severity).max()
+    }
+
+    /// check the report has [`Severity::Error`] findings.
+    pub fn has_errors(&self) -> bool {
+        self.count(Severity::Error) > 0
+    }
+
+    /// Create JSON report.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ble_data_struct::lint::advertising::{LintFinding, LintReport, LintRule, Severity};
+    ///
+    /// let finding = LintFinding {
+    ///     rule: LintRule::Duplicate,
+    ///     severity: Severity::Warning,
+    ///     data_type: Some(0x0a),
+    ///     message: "\"a\"".to_string(),
+    /// };
+    /// let report = LintReport { findings: vec![finding] };
+    /// assert_eq!(
+    ///     r#"{"errors":0,"warnings":1,"infos":0,"findings":[{"rule":"duplicate","severity":"warning","data_type":10,"message":"\"a\""}]}"#,
+    ///     report.to_json()
+    /// );
+    /// ```
+    pub fn to_json(&self) -> String {
+        let findings: Vec<String> = self
+            .findings
+            .iter()
+            .map(|f| {
+                format!(
+                    r#"{{"rule":"{}","severity":"{}","data_type":{},"message":"{}"}}"#,
+                    f.rule.id(),
+                    f.severity.id(),
+                    f.data_type
+                        .map_or("null".to_string(), |data_type| data_type.to_string()),
+                    escape_json(&f.message)
+                )
+            })
+            .collect();
+        format!(
+            r#"{{"errors":{},"warnings":{},"infos":{},"findings":[{}]}}"#,
+            self.count(Severity::Error),
+            self.count(Severity::Warning),
+            self.count(Severity::Info),
+            findings.join(",")
+        )
+    }
+}
+
+/// Escape JSON string.
+fn escape_json(value: &str) -> String {
+    let mut escaped = String::with_capacity(value.len());
+    value.chars().for_each(|c| match c {
+        '"' => escaped.push_str("\\\""),
+        '\\' => escaped.push_str("\\\\"),
+        c if c.is_control() => escaped.push_str(&format!("\\u{:04x}", c as u32)),
+        c => escaped.push(c),
+    });
+    escaped
+}
+
+/// Data types that may appear more than once.
+fn is_repeatable(data_type: u8) -> bool {
+    [
+        Flags::data_type(),
+        ManufacturerSpecificData::data_type(),
+        ServiceData16BitUUID::data_type(),
+        ServiceData32BitUUID::data_type(),
+        ServiceData128BitUUID::data_type(),
+    ]
+    .contains(&data_type)
+}
+
+/// Returns the data type of the structure.
+fn data_type_of(result: &DataTypeParseResult) -> Option<u8> {
+    result.ad_structure().map(|f| f.ad_type())
+}
+
+/// Lint advertising data and scan response data.
+///
+/// Flags duplicates are reported by [`LintRule::Placement`].
+///
+/// # Examples
+///
+/// ```
+/// use ble_data_struct::{
+///     data_types::{
+///         complete_local_name::CompleteLocalName,
+///         flags::{DiscoverableMode, Flags},
+///         tx_power_level::TxPowerLevel,
+///     },
+///     lint::advertising::{lint, LintRule, LintTarget, RuleSet, Severity},
+///     payload::legality::AdvertisingPduType,
+/// };
+///
+/// let flags: Vec<u8> =
+///     Flags::new(&[false, true, false, false, false, false, false, false].to_vec()).into();
+/// let tx_power_level: Vec<u8> = TxPowerLevel::new(-4).into();
+/// let name: Vec<u8> = CompleteLocalName::new(&"name".to_string()).into();
+/// let target = LintTarget::new(
+///     AdvertisingPduType::AdvInd,
+///     DiscoverableMode::General,
+///     &[flags, tx_power_level.clone(), tx_power_level].concat(),
+///     &name,
+/// );
+///
+/// let report = lint(&target, &RuleSet::default());
+/// assert_eq!(
+///     vec![LintRule::Duplicate, LintRule::Privacy],
+///     report.findings.iter().map(|f| f.rule).collect::<Vec<_>>()
+/// );
+/// assert!(!report.has_errors());
+///
+/// let report = lint(&target, &RuleSet::strict().with_rule(LintRule::Privacy, None));
+/// assert_eq!(Some(Severity::Error), report.max_severity());
+/// ```
+pub fn lint(target: &LintTarget, rule_set: &RuleSet) -> LintReport {
+    let mut findings: Vec<LintFinding> = Vec::new();
+    let mut push = |rule: LintRule, data_type: Option<u8>, message: String| {
+        if let Some(severity) = rule_set.severity(rule) {
+            findings.push(LintFinding {
+                rule,
+                severity,
+                data_type,
+                message,
+            });
+        }
+    };
+
+    check_legality(
+        target.pdu_type,
+        target.mode,
+        &target.advertising_data,
+        &target.scan_response_data,
+    )
+    .into_iter()
+    .for_each(|f| {
+        let data_type = match f {
+            LegalityViolation::DataTypeNotAllowed { data_type, .. } => Some(data_type),
+            LegalityViolation::DuplicateFlags
+            | LegalityViolation::MissingFlags
+            | LegalityViolation::DiscoverableModeMismatch => Some(Flags::data_type()),
+            _ => None,
+        };
+        push(LintRule::Placement, data_type, format!("{:?}", f));
+    });
+
+    let results: Vec<DataTypeParseResult> = [&target.advertising_data, &target.scan_response_data]
+        .iter()
+        .flat_map(|f| DataTypeParseResults::from(*f).results)
+        .collect();
+
+    let mut seen: Vec<u8> = Vec::new();
+    let mut duplicates: Vec<u8> = Vec::new();
+    results.iter().filter_map(data_type_of).for_each(|f| {
+        if seen.contains(&f) {
+            if !is_repeatable(f) && !duplicates.contains(&f) {
+                duplicates.push(f);
+            }
+        } else {
+            seen.push(f);
+        }
+    });
+    duplicates.into_iter().for_each(|f| {
+        push(
+            LintRule::Duplicate,
+            Some(f),
+            format!("Duplicate data type :{}", f),
+        )
+    });
+
+    let record = ScanRecord::new(
+        target.address,
+        0,
+        DataTypeParseResults::new(results.clone()),
+    );
+    let record = match target.address_type {
+        Some(address_type) => record.with_address_type(address_type),
+        None => record,
+    };
+    audit_privacy(&record)
+        .into_iter()
+        .for_each(|f| push(LintRule::Privacy, None, format!("{:?}", f)));
+
+    let complete_local_name = results.iter().find_map(|f| match f {
+        DataTypeParseResult::CompleteLocalNameResult(Ok(data_type)) => {
+            Some(&data_type.complete_local_name)
+        }
+        _ => None,
+    });
+    let shortened_local_name = results.iter().find_map(|f| match f {
+        DataTypeParseResult::ShortenedLocalNameResult(Ok(data_type)) => {
+            Some(&data_type.shortened_local_name)
+        }
+        _ => None,
+    });
+    if let (Some(complete), Some(shortened)) = (complete_local_name, shortened_local_name) {
+        if !complete.starts_with(shortened.as_str()) {
+            push(
+                LintRule::NameTruncation,
+                Some(ShortenedLocalName::data_type()),
+                format!("Shortened Local Name is not a prefix :{}", shortened),
+            );
+        }
+    }
+
+    results.iter().for_each(|f| match f {
+        DataTypeParseResult::AdvertisingIntervalResult(Ok(data_type))
+            if !(ADVERTISING_INTERVAL_MIN..=ADVERTISING_INTERVAL_MAX)
+                .contains(&data_type.advertising_interval.0) =>
+        {
+            push(
+                LintRule::IntervalSanity,
+                Some(AdvertisingInterval::data_type()),
+                format!(
+                    "Invalid advertising interval :{}",
+                    data_type.advertising_interval.0
+                ),
+            )
+        }
+        DataTypeParseResult::PeripheralConnectionIntervalRangeResult(Ok(data_type)) => {
+            if let Err(violations) = data_type.validate() {
+                violations.into_iter().for_each(|violation| {
+                    push(
+                        LintRule::IntervalSanity,
+                        Some(PeripheralConnectionIntervalRange::data_type()),
+                        format!("{:?}", violation),
+                    )
+                });
+            }
+        }
+        _ => {}
+    });
+
+    results.iter().for_each(|f| {
+        f.warnings().into_iter().for_each(|warning| {
+            push(
+                LintRule::ParseWarning,
+                Some(warning.data_type()),
+                format!("{:?}", warning),
+            )
+        });
+        if let Some(error) = f.parse_error() {
+            push(LintRule::ParseError, None, error.to_string());
+        }
+    });
+
+    LintReport { findings }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        data_types::{
+            address_type::AddressType,
+            advertising_interval::AdvertisingInterval,
+            class_of_device::ClassOfDevice,
+            complete_local_name::CompleteLocalName,
+            data_type::DataType,
+            flags::{DiscoverableMode, Flags},
+            manufacturer_specific_data::ManufacturerSpecificData,
+            peripheral_connection_interval_range::PeripheralConnectionIntervalRange,
+            shortened_local_name::ShortenedLocalName,
+            time_units::{Slots625us, Units1_25ms},
+            tx_power_level::TxPowerLevel,
+        },
+        lint::advertising::*,
+        payload::legality::AdvertisingPduType,
+    };
+
+    fn flags() -> Vec<u8> {
+        Flags::new(&[false, true, true, false, false, false, false, false].to_vec()).into()
+    }
+
+    fn target(advertising_data: &[u8], scan_response_data: &[u8]) -> LintTarget {
+        LintTarget::new(
+            AdvertisingPduType::AdvInd,
+            DiscoverableMode::General,
+            advertising_data,
+            scan_response_data,
+        )
+    }
+
+    fn rules(report: &LintReport) -> Vec<LintRule> {
+        report.findings.iter().map(|f| f.rule).collect()
+    }
+
+    #[test]
+    fn test_rule_set() {
+        let result = RuleSet::new();
+        assert_eq!(None, result.severity(LintRule::ParseError));
+
+        let result = RuleSet::default();
+        assert_eq!(Some(Severity::Error), result.severity(LintRule::Placement));
+        assert_eq!(
+            Some(Severity::Warning),
+            result.severity(LintRule::Duplicate)
+        );
+        assert_eq!(Some(Severity::Info), result.severity(LintRule::Privacy));
+        assert_eq!(
+            Some(Severity::Warning),
+            result.severity(LintRule::NameTruncation)
+        );
+        assert_eq!(
+            Some(Severity::Error),
+            result.severity(LintRule::IntervalSanity)
+        );
+        assert_eq!(
+            Some(Severity::Warning),
+            result.severity(LintRule::ParseWarning)
+        );
+        assert_eq!(Some(Severity::Error), result.severity(LintRule::ParseError));
+
+        let result = RuleSet::strict();
+        assert_eq!(Some(Severity::Error), result.severity(LintRule::Privacy));
+
+        let result = RuleSet::new()
+            .with_rule(LintRule::Privacy, Some(Severity::Warning))
+            .with_rule(LintRule::Privacy, Some(Severity::Error));
+        assert_eq!(Some(Severity::Error), result.severity(LintRule::Privacy));
+        let result = result.with_rule(LintRule::Privacy, None);
+        assert_eq!(None, result.severity(LintRule::Privacy));
+    }
+
+    #[test]
+    fn test_lint_clean() {
+        let tx_power_level: Vec<u8> = TxPowerLevel::new(-4).into();
+        let report = lint(
+            &target(&[flags(), tx_power_level].concat(), &[]),
+            &RuleSet::default(),
+        );
+        assert!(report.findings.is_empty());
+        assert_eq!(None, report.max_severity());
+        assert_eq!(
+            r#"{"errors":0,"warnings":0,"infos":0,"findings":[]}"#,
+            report.to_json()
+        );
+    }
+
+    #[test]
+    fn test_lint_placement() {
+        let class_of_device: Vec<u8> = ClassOfDevice::new(0x000100).into();
+        let report = lint(&target(&class_of_device, &[]), &RuleSet::default());
+        assert_eq!(
+            vec![LintRule::Placement, LintRule::Placement],
+            rules(&report)
+        );
+        assert_eq!(
+            Some(ClassOfDevice::data_type()),
+            report.findings[0].data_type
+        );
+        assert_eq!(Some(Flags::data_type()), report.findings[1].data_type);
+        assert!(report.has_errors());
+    }
+
+    #[test]
+    fn test_lint_duplicate() {
+        let tx_power_level: Vec<u8> = TxPowerLevel::new(-4).into();
+        let manufacturer_specific_data: Vec<u8> =
+            ManufacturerSpecificData::new(0x004c, &vec![0x01]).into();
+        let report = lint(
+            &target(
+                &[
+                    flags(),
+                    tx_power_level.clone(),
+                    manufacturer_specific_data.clone(),
+                ]
+                .concat(),
+                &[
+                    tx_power_level.clone(),
+                    tx_power_level,
+                    manufacturer_specific_data,
+                ]
+                .concat(),
+            ),
+            &RuleSet::default().with_rule(LintRule::Privacy, None),
+        );
+        assert_eq!(vec![LintRule::Duplicate], rules(&report));
+        assert_eq!(
+            Some(TxPowerLevel::data_type()),
+            report.findings[0].data_type
+        );
+    }
+
+    #[test]
+    fn test_lint_privacy() {
+        let report = lint(
+            &target(&flags(), &[]).with_address(0x000000000001, AddressType::Public),
+            &RuleSet::default(),
+        );
+        assert_eq!(vec![LintRule::Privacy], rules(&report));
+        assert_eq!(Severity::Info, report.findings[0].severity);
+        assert_eq!("PublicAddress { address: 1 }", report.findings[0].message);
+    }
+
+    #[test]
+    fn test_lint_name_truncation() {
+        let complete: Vec<u8> = CompleteLocalName::new(&"device".to_string()).into();
+        let shortened: Vec<u8> = ShortenedLocalName::new(&"dev".to_string()).into();
+        let report = lint(
+            &target(&[flags(), shortened].concat(), &complete),
+            &RuleSet::default().with_rule(LintRule::Privacy, None),
+        );
+        assert!(report.findings.is_empty());
+
+        let shortened: Vec<u8> = ShortenedLocalName::new(&"other".to_string()).into();
+        let report = lint(
+            &target(&[flags(), shortened].concat(), &complete),
+            &RuleSet::default().with_rule(LintRule::Privacy, None),
+        );
+        assert_eq!(vec![LintRule::NameTruncation], rules(&report));
+        assert_eq!(
+            Some(ShortenedLocalName::data_type()),
+            report.findings[0].data_type
+        );
+    }
+
+    #[test]
+    fn test_lint_interval_sanity() {
+        let advertising_interval: Vec<u8> = AdvertisingInterval::new(Slots625us(0x0010)).into();
+        let range: Vec<u8> =
+            PeripheralConnectionIntervalRange::new(Units1_25ms(0x0028), Units1_25ms(0x0018)).into();
+        let report = lint(
+            &target(&[flags(), advertising_interval, range].concat(), &[]),
+            &RuleSet::default(),
+        );
+        assert_eq!(
+            vec![LintRule::IntervalSanity, LintRule::IntervalSanity],
+            rules(&report)
+        );
+        assert_eq!(
+            Some(AdvertisingInterval::data_type()),
+            report.findings[0].data_type
+        );
+        assert_eq!(
+            Some(PeripheralConnectionIntervalRange::data_type()),
+            report.findings[1].data_type
+        );
+    }
+
+    #[test]
+    fn test_lint_parse() {
+        let report = lint(
+            &target(
+                &[flags(), vec![3, TxPowerLevel::data_type(), 0xfc, 0x00]].concat(),
+                &[1, TxPowerLevel::data_type()],
+            ),
+            &RuleSet::default(),
+        );
+        assert_eq!(
+            vec![LintRule::ParseWarning, LintRule::ParseError],
+            rules(&report)
+        );
+        assert_eq!(1, report.count(Severity::Warning));
+        assert_eq!(1, report.count(Severity::Error));
+    }
+
+    #[test]
+    fn test_to_json() {
+        let report = LintReport {
+            findings: vec![
+                LintFinding {
+                    rule: LintRule::Privacy,
+                    severity: Severity::Info,
+                    data_type: None,
+                    message: "a\\b\n".to_string(),
+                },
+                LintFinding {
+                    rule: LintRule::Placement,
+                    severity: Severity::Error,
+                    data_type: Some(0x01),
+                    message: "MissingFlags".to_string(),
+                },
+            ],
+        };
+        assert_eq!(
+            concat!(
+                r#"{"errors":1,"warnings":0,"infos":1,"findings":["#,
+                r#"{"rule":"privacy","severity":"info","data_type":null,"message":"a\\b\u000a"},"#,
+                r#"{"rule":"placement","severity":"error","data_type":1,"message":"MissingFlags"}]}"#
+            ),
+            report.to_json()
+        );
+    }
+}
@@ -0,0 +1,487 @@
+//! Advertising payload builder module.
+
+use uuid::Uuid;
+
+use crate::{
+    data_types::{
+        appearance::Appearance,
+        complete_list_of_16bit_service_uuids::CompleteListOf16BitServiceUuids,
+        complete_local_name::CompleteLocalName, flags::Flags,
+        manufacturer_specific_data::ManufacturerSpecificData,
+        service_data_16bit_uuid::ServiceData16BitUUID, shortened_local_name::ShortenedLocalName,
+        tx_power_level::TxPowerLevel,
+    },
+    payload::truncate::LEGACY_ADVERTISING_DATA_MAX_LENGTH,
+};
+
+/// Advertising payload builder.
+///
+/// AD structures are placed in the order of the calls.
+#[derive(Debug, PartialEq, Clone)]
+pub struct AdvertisingPayloadBuilder {
+    /// Payload max length
+    pub max_length: usize,
+
+    /// `true` if the Complete Local Name is replaced by a Shortened Local Name when the payload is too long
+    pub shorten_local_name: bool,
+
+    /// AD structures
+    pub structures: Vec<Vec<u8>>,
+
+    /// Complete Local Name and its index in `structures`
+    local_name: Option<(usize, String)>,
+}
+
+impl AdvertisingPayloadBuilder {
+    /// Create [`AdvertisingPayloadBuilder`] for legacy advertising (31 bytes).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ble_data_struct::payload::{
+    ///     builder::AdvertisingPayloadBuilder, truncate::LEGACY_ADVERTISING_DATA_MAX_LENGTH,
+    /// };
+    ///
+    /// let result = AdvertisingPayloadBuilder::new();
+    /// assert_eq!(LEGACY_ADVERTISING_DATA_MAX_LENGTH, result.max_length);
+    /// assert!(!result.shorten_local_name);
+    /// assert!(result.structures.is_empty());
+    /// ```
+    pub fn new() -> Self {
+        Self {
+            max_length: LEGACY_ADVERTISING_DATA_MAX_LENGTH,
+            shorten_local_name: false,
+            structures: Vec::new(),
+            local_name: None,
+        }
+    }
+
+    /// Set payload max length.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ble_data_struct::payload::{
+    ///     builder::AdvertisingPayloadBuilder, truncate::EXTENDED_ADVERTISING_DATA_MAX_LENGTH,
+    /// };
+    ///
+    /// let result =
+    ///     AdvertisingPayloadBuilder::new().with_max_length(EXTENDED_ADVERTISING_DATA_MAX_LENGTH);
+    /// assert_eq!(EXTENDED_ADVERTISING_DATA_MAX_LENGTH, result.max_length);
+    /// ```
+    pub fn with_max_length(mut self, max_length: usize) -> Self {
+        self.max_length = max_length;
+        self
+    }
+
+    /// Set Complete Local Name auto-shortening.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ble_data_struct::payload::builder::AdvertisingPayloadBuilder;
+    ///
+    /// let result = AdvertisingPayloadBuilder::new().with_shorten_local_name(true);
+    /// assert!(result.shorten_local_name);
+    /// ```
+    pub fn with_shorten_local_name(mut self, shorten_local_name: bool) -> Self {
+        self.shorten_local_name = shorten_local_name;
+        self
+    }
+
+    /// Add AD structure.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ble_data_struct::{
+    ///     data_types::tx_power_level::TxPowerLevel, payload::builder::AdvertisingPayloadBuilder,
+    /// };
+    ///
+    /// let result = AdvertisingPayloadBuilder::new().ad_structure(TxPowerLevel::new(-4));
+    /// let data: Vec<u8> = TxPowerLevel::new(-4).into();
+    /// assert_eq!(vec![data], result.structures);
+    /// ```
+    pub fn ad_structure(mut self, ad_structure: impl Into<Vec<u8>>) -> Self {
+        self.structures.push(ad_structure.into());
+        self
+    }
+
+    /// Add [`Flags`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ble_data_struct::{
+    ///     data_types::flags::{DiscoverableMode, Flags},
+    ///     payload::builder::AdvertisingPayloadBuilder,
+    /// };
+    ///
+    /// let flags = Flags::for_mode(DiscoverableMode::General, false);
+    /// let result = AdvertisingPayloadBuilder::new().flags(flags.clone());
+    /// let data: Vec<u8> = flags.into();
+    /// assert_eq!(vec![data], result.structures);
+    /// ```
+    pub fn flags(self, flags: Flags) -> Self {
+        self.ad_structure(flags)
+    }
+
+    /// Add [`CompleteLocalName`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ble_data_struct::{
+    ///     data_types::complete_local_name::CompleteLocalName,
+    ///     payload::builder::AdvertisingPayloadBuilder,
+    /// };
+    ///
+    /// let result = AdvertisingPayloadBuilder::new().complete_local_name("name");
+    /// let data: Vec<u8> = CompleteLocalName::new(&"name".to_string()).into();
+    /// assert_eq!(vec![data], result.structures);
+    /// ```
+    pub fn complete_local_name(mut self, name: &str) -> Self {
+        self.local_name = Some((self.structures.len(), name.to_string()));
+        self.ad_structure(CompleteLocalName::new(&name.to_string()))
+    }
+
+    /// Add [`CompleteListOf16BitServiceUuids`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ble_data_struct::{
+    ///     data_types::complete_list_of_16bit_service_uuids::CompleteListOf16BitServiceUuids,
+    ///     payload::builder::AdvertisingPayloadBuilder, uuid_from_u16,
+    /// };
+    ///
+    /// let uuids = [uuid_from_u16(0x180f)];
+    /// let result = AdvertisingPayloadBuilder::new().service_uuids_16(&uuids);
+    /// let data: Vec<u8> = CompleteListOf16BitServiceUuids::new(&uuids.to_vec()).into();
+    /// assert_eq!(vec![data], result.structures);
+    /// ```
+    pub fn service_uuids_16(self, uuids: &[Uuid]) -> Self {
+        self.ad_structure(CompleteListOf16BitServiceUuids::new(&uuids.to_vec()))
+    }
+
+    /// Add [`ServiceData16BitUUID`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ble_data_struct::{
+    ///     data_types::service_data_16bit_uuid::ServiceData16BitUUID,
+    ///     payload::builder::AdvertisingPayloadBuilder, uuid_from_u16,
+    /// };
+    ///
+    /// let uuid = uuid_from_u16(0x180f);
+    /// let result = AdvertisingPayloadBuilder::new().service_data_16(&uuid, &[0x64]);
+    /// let data: Vec<u8> = ServiceData16BitUUID::new(&uuid, &[0x64].to_vec()).into();
+    /// assert_eq!(vec![data], result.structures);
+    /// ```
+    pub fn service_data_16(self, uuid: &Uuid, data: &[u8]) -> Self {
+        self.ad_structure(ServiceData16BitUUID::new(uuid, &data.to_vec()))
+    }
+
+    /// Add [`ManufacturerSpecificData`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ble_data_struct::{
+    ///     data_types::manufacturer_specific_data::ManufacturerSpecificData,
+    ///     payload::builder::AdvertisingPayloadBuilder,
+    /// };
+    ///
+    /// let result = AdvertisingPayloadBuilder::new().manufacturer_specific_data(0x004c, &[0x02]);
+    /// let data: Vec<u8> = ManufacturerSpecificData::new(0x004c, &[0x02].to_vec()).into();
+    /// assert_eq!(vec![data], result.structures);
+    /// ```
+    pub fn manufacturer_specific_data(self, company_identifier: u16, data: &[u8]) -> Self {
+        self.ad_structure(ManufacturerSpecificData::new(
+            company_identifier,
+            &data.to_vec(),
+        ))
+    }
+
+    /// Add [`TxPowerLevel`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ble_data_struct::{
+    ///     data_types::tx_power_level::TxPowerLevel, payload::builder::AdvertisingPayloadBuilder,
+    /// };
+    ///
+    /// let result = AdvertisingPayloadBuilder::new().tx_power_level(-4);
+    /// let data: Vec<u8> = TxPowerLevel::new(-4).into();
+    /// assert_eq!(vec![data], result.structures);
+    /// ```
+    pub fn tx_power_level(self, tx_power_level: i8) -> Self {
+        self.ad_structure(TxPowerLevel::new(tx_power_level))
+    }
+
+    /// Add [`Appearance`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ble_data_struct::{
+    ///     data_types::appearance::Appearance, payload::builder::AdvertisingPayloadBuilder,
+    /// };
+    ///
+    /// let result = AdvertisingPayloadBuilder::new().appearance(0x0340);
+    /// let data: Vec<u8> = Appearance::new(0x0340).into();
+    /// assert_eq!(vec![data], result.structures);
+    /// ```
+    pub fn appearance(self, appearance: u16) -> Self {
+        self.ad_structure(Appearance::new(appearance))
+    }
+
+    /// Create advertising payload.
+    ///
+    /// If the payload exceeds `max_length` and `shorten_local_name` is `true`,
+    /// the Complete Local Name is replaced by the longest Shortened Local Name that fits.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ble_data_struct::{
+    ///     data_types::{
+    ///         flags::{DiscoverableMode, Flags},
+    ///         shortened_local_name::ShortenedLocalName,
+    ///     },
+    ///     payload::builder::AdvertisingPayloadBuilder,
+    /// };
+    ///
+    /// let flags = Flags::for_mode(DiscoverableMode::General, false);
+    /// let builder = AdvertisingPayloadBuilder::new()
+    ///     .flags(flags.clone())
+    ///     .complete_local_name(&"a".repeat(27));
+    ///
+    /// let result = builder.clone().build();
+    /// assert_eq!(Err("Payload too long :32".to_string()), result);
+    ///
+    /// let result = builder.with_shorten_local_name(true).build();
+    /// let flags: Vec<u8> = flags.into();
+    /// let name: Vec<u8> = ShortenedLocalName::new(&"a".repeat(26)).into();
+    /// assert_eq!(Ok([flags, name].concat()), result);
+    /// ```
+    pub fn build(&self) -> Result<Vec<u8>, String> {
+        let mut structures = self.structures.clone();
+        let len: usize = structures.iter().map(|f| f.len()).sum();
+        if len > self.max_length {
+            match &self.local_name {
+                Some((index, name)) if self.shorten_local_name => {
+                    let budget = (name.len() + self.max_length).checked_sub(len);
+                    match budget.map(|f| shorten(name, f)) {
+                        Some(shortened) if !shortened.is_empty() => {
+                            structures[*index] =
+                                ShortenedLocalName::new(&shortened.to_string()).into();
+                        }
+                        _ => return Err(format!("Payload too long :{}", len).to_string()),
+                    }
+                }
+                _ => return Err(format!("Payload too long :{}", len).to_string()),
+            }
+        }
+        Ok(structures.concat())
+    }
+
+    /// Create legacy advertising payload (zero padded).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ble_data_struct::{
+    ///     data_types::tx_power_level::TxPowerLevel, payload::builder::AdvertisingPayloadBuilder,
+    /// };
+    ///
+    /// let result = AdvertisingPayloadBuilder::new().tx_power_level(-4).build_legacy();
+    /// let mut data = [0u8; 31];
+    /// data[..3].copy_from_slice(&Into::<Vec<u8>>::into(TxPowerLevel::new(-4)));
+    /// assert_eq!(Ok(data), result);
+    /// ```
+    pub fn build_legacy(&self) -> Result<[u8; LEGACY_ADVERTISING_DATA_MAX_LENGTH], String> {
+        let payload = self
+            .clone()
+            .with_max_length(LEGACY_ADVERTISING_DATA_MAX_LENGTH)
+            .build()?;
+        let mut data = [0u8; LEGACY_ADVERTISING_DATA_MAX_LENGTH];
+        data[..payload.len()].copy_from_slice(&payload);
+        Ok(data)
+    }
+}
+
+impl Default for AdvertisingPayloadBuilder {
+    /// Create [`AdvertisingPayloadBuilder`] for legacy advertising.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ble_data_struct::payload::builder::AdvertisingPayloadBuilder;
+    ///
+    /// assert_eq!(AdvertisingPayloadBuilder::new(), AdvertisingPayloadBuilder::default());
+    /// ```
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Returns the longest prefix of `name` within `length` bytes, not splitting a character.
+fn shorten(name: &str, length: usize) -> &str {
+    let mut end = length.min(name.len());
+    while !name.is_char_boundary(end) {
+        end -= 1;
+    }
+    &name[..end]
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        data_types::{
+            appearance::Appearance,
+            complete_list_of_16bit_service_uuids::CompleteListOf16BitServiceUuids,
+            complete_local_name::CompleteLocalName,
+            flags::{DiscoverableMode, Flags},
+            manufacturer_specific_data::ManufacturerSpecificData,
+            service_data_16bit_uuid::ServiceData16BitUUID,
+            shortened_local_name::ShortenedLocalName,
+            tx_power_level::TxPowerLevel,
+        },
+        payload::{
+            builder::{shorten, AdvertisingPayloadBuilder},
+            truncate::{EXTENDED_ADVERTISING_DATA_MAX_LENGTH, LEGACY_ADVERTISING_DATA_MAX_LENGTH},
+        },
+        uuid_from_u16,
+    };
+
+    #[test]
+    fn test_new() {
+        let result = AdvertisingPayloadBuilder::new();
+        assert_eq!(LEGACY_ADVERTISING_DATA_MAX_LENGTH, result.max_length);
+        assert!(!result.shorten_local_name);
+        assert!(result.structures.is_empty());
+        assert_eq!(AdvertisingPayloadBuilder::default(), result);
+    }
+
+    #[test]
+    fn test_with() {
+        let result = AdvertisingPayloadBuilder::new()
+            .with_max_length(EXTENDED_ADVERTISING_DATA_MAX_LENGTH)
+            .with_shorten_local_name(true);
+        assert_eq!(EXTENDED_ADVERTISING_DATA_MAX_LENGTH, result.max_length);
+        assert!(result.shorten_local_name);
+    }
+
+    #[test]
+    fn test_build() {
+        let flags = Flags::for_mode(DiscoverableMode::General, false);
+        let uuid = uuid_from_u16(0x180f);
+        let result = AdvertisingPayloadBuilder::new()
+            .flags(flags.clone())
+            .complete_local_name("name")
+            .service_uuids_16(&[uuid])
+            .service_data_16(&uuid, &[0x64])
+            .manufacturer_specific_data(0x004c, &[0x02])
+            .tx_power_level(-4)
+            .appearance(0x0340)
+            .build();
+
+        let expected: Vec<Vec<u8>> = vec![
+            flags.into(),
+            CompleteLocalName::new(&"name".to_string()).into(),
+            CompleteListOf16BitServiceUuids::new(&vec![uuid]).into(),
+            ServiceData16BitUUID::new(&uuid, &vec![0x64]).into(),
+            ManufacturerSpecificData::new(0x004c, &vec![0x02]).into(),
+            TxPowerLevel::new(-4).into(),
+            Appearance::new(0x0340).into(),
+        ];
+        assert_eq!(Ok(expected.concat()), result);
+
+        let result = AdvertisingPayloadBuilder::new().build();
+        assert_eq!(Ok(Vec::new()), result);
+    }
+
+    #[test]
+    fn test_build_too_long() {
+        let builder = AdvertisingPayloadBuilder::new().complete_local_name(&"a".repeat(29));
+        assert_eq!(
+            Ok(CompleteLocalName::new(&"a".repeat(29)).into()),
+            builder.build()
+        );
+
+        let builder = AdvertisingPayloadBuilder::new().complete_local_name(&"a".repeat(30));
+        assert_eq!(Err("Payload too long :32".to_string()), builder.build());
+
+        let builder = AdvertisingPayloadBuilder::new().manufacturer_specific_data(0, &[0; 28]);
+        assert_eq!(
+            Err("Payload too long :32".to_string()),
+            builder.with_shorten_local_name(true).build()
+        );
+
+        let builder = AdvertisingPayloadBuilder::new()
+            .with_max_length(EXTENDED_ADVERTISING_DATA_MAX_LENGTH)
+            .complete_local_name(&"a".repeat(30));
+        assert_eq!(
+            Ok(CompleteLocalName::new(&"a".repeat(30)).into()),
+            builder.build()
+        );
+    }
+
+    #[test]
+    fn test_build_shorten_local_name() {
+        let tx_power_level: Vec<u8> = TxPowerLevel::new(-4).into();
+        let result = AdvertisingPayloadBuilder::new()
+            .with_shorten_local_name(true)
+            .complete_local_name(&"a".repeat(30))
+            .tx_power_level(-4)
+            .build();
+        let name: Vec<u8> = ShortenedLocalName::new(&"a".repeat(26)).into();
+        assert_eq!(Ok([name, tx_power_level.clone()].concat()), result);
+
+        let result = AdvertisingPayloadBuilder::new()
+            .with_shorten_local_name(true)
+            .complete_local_name("\u{e9}\u{e9}")
+            .manufacturer_specific_data(0, &[0; 22])
+            .build();
+        let name: Vec<u8> = ShortenedLocalName::new(&"\u{e9}".to_string()).into();
+        let manufacturer_specific_data: Vec<u8> =
+            ManufacturerSpecificData::new(0, &vec![0; 22]).into();
+        assert_eq!(Ok([name, manufacturer_specific_data].concat()), result);
+
+        let result = AdvertisingPayloadBuilder::new()
+            .with_shorten_local_name(true)
+            .complete_local_name("\u{e9}")
+            .manufacturer_specific_data(0, &[0; 24])
+            .build();
+        assert_eq!(Err("Payload too long :32".to_string()), result);
+    }
+
+    #[test]
+    fn test_build_legacy() {
+        let result = AdvertisingPayloadBuilder::new()
+            .with_max_length(EXTENDED_ADVERTISING_DATA_MAX_LENGTH)
+            .tx_power_level(-4)
+            .build_legacy();
+        let mut data = [0u8; LEGACY_ADVERTISING_DATA_MAX_LENGTH];
+        data[..3].copy_from_slice(&Into::<Vec<u8>>::into(TxPowerLevel::new(-4)));
+        assert_eq!(Ok(data), result);
+
+        let result = AdvertisingPayloadBuilder::new()
+            .with_max_length(EXTENDED_ADVERTISING_DATA_MAX_LENGTH)
+            .complete_local_name(&"a".repeat(30))
+            .build_legacy();
+        assert_eq!(Err("Payload too long :32".to_string()), result);
+    }
+
+    #[test]
+    fn test_shorten() {
+        assert_eq!("ab", shorten("abc", 2));
+        assert_eq!("abc", shorten("abc", 4));
+        assert_eq!("", shorten("abc", 0));
+        assert_eq!("\u{e9}", shorten("\u{e9}\u{e9}", 3));
+        assert_eq!("", shorten("\u{e9}", 1));
+    }
+}
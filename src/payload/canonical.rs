@@ -0,0 +1,168 @@
+//! Advertising payload canonicalization module.
+//!
+//! Payload-rewriting proxies compare and forward payloads in a canonical form,
+//! unknown (vendor) data types are passed through byte-exactly.
+
+use crate::data_types::data_type_ref::DataTypeRef;
+
+/// Canonicalize EIR/AD/SRD/ACAD/OOB payload.
+///
+/// Structures are stably sorted by data type and copied as received,
+/// including unknown data types and structures that fail to parse.
+/// Bytes after a malformed length field (zero length, length past the end of the payload) are appended unchanged.
+///
+/// # Examples
+///
+/// ```
+/// use ble_data_struct::data_types::{
+///     flags::Flags, tx_power_level::TxPowerLevel,
+/// };
+/// use ble_data_struct::payload::canonical::canonicalize;
+///
+/// let flags: Vec<u8> =
+///     Flags::new(&[false, true, false, false, false, false, false, false].to_vec()).into();
+/// let tx_power_level: Vec<u8> = TxPowerLevel::new(-4).into();
+/// let vendor = vec![0x04, 0x50, 0x03, 0x02, 0x01];
+///
+/// let payload = [vendor.clone(), tx_power_level.clone(), flags.clone()].concat();
+/// assert_eq!(
+///     [flags, tx_power_level, vendor].concat(),
+///     canonicalize(&payload)
+/// );
+/// ```
+pub fn canonicalize(payload: &[u8]) -> Vec<u8> {
+    let mut structures: Vec<(u8, Vec<u8>)> = Vec::new();
+    let mut index = 0;
+    while index < payload.len() {
+        let data_type = match DataTypeRef::try_from(&payload[index..]) {
+            Ok(data_type) => data_type,
+            Err(_) => break,
+        };
+        let bytes = data_type.as_bytes();
+        structures.push((data_type.data_type, bytes.to_vec()));
+        index += bytes.len();
+    }
+    structures.sort_by_key(|(data_type, _)| *data_type);
+
+    let mut data: Vec<u8> = Vec::new();
+    for (_, mut structure) in structures {
+        data.append(&mut structure);
+    }
+    data.extend_from_slice(&payload[index..]);
+    return data;
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        data_types::{
+            complete_local_name::CompleteLocalName,
+            data_type_parser::{DataTypeParseResult, DataTypeParseResults},
+            flags::Flags,
+            manufacturer_specific_data::ManufacturerSpecificData,
+            service_data_16bit_uuid::ServiceData16BitUUID,
+            tx_power_level::TxPowerLevel,
+        },
+        payload::canonical::canonicalize,
+        uuid_from_u16,
+    };
+
+    #[test]
+    fn test_canonicalize() {
+        let flags: Vec<u8> =
+            Flags::new(&[false, true, false, false, false, false, false, false].to_vec()).into();
+        let name: Vec<u8> = CompleteLocalName::new(&"name".to_string()).into();
+        let tx_power_level: Vec<u8> = TxPowerLevel::new(-4).into();
+        let service_data: Vec<u8> =
+            ServiceData16BitUUID::new(&uuid_from_u16(0x180f), &[50].to_vec()).into();
+        let manufacturer: Vec<u8> = ManufacturerSpecificData::new(0x02e5, &vec![0x03; 8]).into();
+        // unknown vendor data types
+        let vendor1 = vec![0x06, 0x50, 0x05, 0x04, 0x03, 0x02, 0x01];
+        let vendor2 = vec![0x03, 0x50, 0xff, 0x00];
+        let vendor3 = vec![0x01, 0xf0];
+
+        let payload = [
+            manufacturer.clone(),
+            vendor1.clone(),
+            name.clone(),
+            vendor3.clone(),
+            tx_power_level.clone(),
+            vendor2.clone(),
+            service_data.clone(),
+            flags.clone(),
+        ]
+        .concat();
+        let expected = [
+            flags,
+            name,
+            tx_power_level,
+            service_data,
+            vendor1.clone(),
+            vendor2.clone(),
+            vendor3.clone(),
+            manufacturer,
+        ]
+        .concat();
+        let result = canonicalize(&payload);
+        assert_eq!(expected, result);
+        assert_eq!(result, canonicalize(&result));
+        assert!(canonicalize(&[]).is_empty());
+
+        // parse -> modify -> serialize -> canonicalize
+        let mut results = DataTypeParseResults::from(&payload);
+        results.results[4] = DataTypeParseResult::TxPowerLevelResult(Ok(TxPowerLevel::new(8)));
+        let into_data: Vec<u8> = results.into();
+        let result = canonicalize(&into_data);
+        let tx_power_level: Vec<u8> = TxPowerLevel::new(8).into();
+        assert_eq!(&tx_power_level, &result[9..12]);
+        assert_eq!(&[vendor1, vendor2, vendor3].concat(), &result[17..30]);
+    }
+
+    #[test]
+    fn test_canonicalize_malformed() {
+        let tx_power_level: Vec<u8> = TxPowerLevel::new(-4).into();
+        let vendor = vec![0x03, 0x50, 0x02, 0x01];
+
+        // structures that fail to parse are copied as received
+        let invalid = vec![0x01, 0x0a];
+        let payload = [invalid.clone(), vendor.clone(), tx_power_level.clone()].concat();
+        assert_eq!(
+            [invalid, tx_power_level.clone(), vendor.clone()].concat(),
+            canonicalize(&payload)
+        );
+
+        // bytes after a malformed length field are appended unchanged
+        let payload = [
+            vendor.clone(),
+            tx_power_level.clone(),
+            vec![0x00],
+            vendor.clone(),
+        ]
+        .concat();
+        assert_eq!(
+            [
+                tx_power_level.clone(),
+                vendor.clone(),
+                vec![0x00],
+                vendor.clone()
+            ]
+            .concat(),
+            canonicalize(&payload)
+        );
+        // oversized Channel Map Update Indication
+        let invalid = vec![
+            0x0a, 0x28, 0x15, 0x35, 0xc5, 0x7c, 0x1a, 0xaa, 0x33, 0x61, 0x71,
+        ];
+        let payload = [invalid.clone(), vendor.clone(), tx_power_level.clone()].concat();
+        assert_eq!(
+            [tx_power_level.clone(), invalid, vendor.clone()].concat(),
+            canonicalize(&payload)
+        );
+
+        let payload = [vendor.clone(), tx_power_level.clone(), vec![0x05, 0x0a]].concat();
+        assert_eq!(
+            [tx_power_level, vendor, vec![0x05, 0x0a]].concat(),
+            canonicalize(&payload)
+        );
+    }
+}
@@ -0,0 +1,320 @@
+//! Compile-time advertising payload module.
+//!
+//! `const fn` helpers for [`adv_payload!`](crate::adv_payload).
+
+/// Create AD structure `length || data type || data`.
+///
+/// `N` must be `data.len() + 2`.
+///
+/// # Examples
+///
+/// ```
+/// use ble_data_struct::payload::const_payload::structure;
+///
+/// const STRUCTURE: [u8; 4] = structure::<4>(0x09, b"ab");
+/// assert_eq!([3, 0x09, b'a', b'b'], STRUCTURE);
+/// ```
+pub const fn structure<const N: usize>(data_type: u8, data: &[u8]) -> [u8; N] {
+    assert!(N == data.len() + 2, "Invalid data size");
+    assert!(N <= 256, "Invalid data size");
+    let mut structure = [0u8; N];
+    structure[0] = (N - 1) as u8;
+    structure[1] = data_type;
+    let mut i = 0;
+    while i < data.len() {
+        structure[i + 2] = data[i];
+        i += 1;
+    }
+    structure
+}
+
+/// Create AD structure `length || data type || 16bit-value(LE) || data`.
+///
+/// `N` must be `data.len() + 4`.
+///
+/// # Examples
+///
+/// ```
+/// use ble_data_struct::payload::const_payload::prefixed_structure;
+///
+/// // Service Data - 16-bit UUID
+/// const STRUCTURE: [u8; 5] = prefixed_structure::<5>(0x16, 0x180f, &[50]);
+/// assert_eq!([4, 0x16, 0x0f, 0x18, 50], STRUCTURE);
+/// ```
+pub const fn prefixed_structure<const N: usize>(
+    data_type: u8,
+    prefix: u16,
+    data: &[u8],
+) -> [u8; N] {
+    assert!(N == data.len() + 4, "Invalid data size");
+    assert!(N <= 256, "Invalid data size");
+    let mut structure = [0u8; N];
+    structure[0] = (N - 1) as u8;
+    structure[1] = data_type;
+    let prefix = prefix.to_le_bytes();
+    structure[2] = prefix[0];
+    structure[3] = prefix[1];
+    let mut i = 0;
+    while i < data.len() {
+        structure[i + 4] = data[i];
+        i += 1;
+    }
+    structure
+}
+
+/// Create AD structure `length || data type || 16bit-UUID list(LE)`.
+///
+/// `N` must be `uuids.len() * 2 + 2`.
+///
+/// # Examples
+///
+/// ```
+/// use ble_data_struct::payload::const_payload::uuid16_structure;
+///
+/// const STRUCTURE: [u8; 6] = uuid16_structure::<6>(0x03, &[0x180f, 0x1809]);
+/// assert_eq!([5, 0x03, 0x0f, 0x18, 0x09, 0x18], STRUCTURE);
+/// ```
+pub const fn uuid16_structure<const N: usize>(data_type: u8, uuids: &[u16]) -> [u8; N] {
+    assert!(N == uuids.len() * 2 + 2, "Invalid data size");
+    assert!(N <= 256, "Invalid data size");
+    let mut structure = [0u8; N];
+    structure[0] = (N - 1) as u8;
+    structure[1] = data_type;
+    let mut i = 0;
+    while i < uuids.len() {
+        let uuid = uuids[i].to_le_bytes();
+        structure[i * 2 + 2] = uuid[0];
+        structure[i * 2 + 3] = uuid[1];
+        i += 1;
+    }
+    structure
+}
+
+/// Total length of AD structures.
+///
+/// # Examples
+///
+/// ```
+/// use ble_data_struct::payload::const_payload::total_length;
+///
+/// const LENGTH: usize = total_length(&[&[2, 0x01, 0x06], &[2, 0x0a, 0x00]]);
+/// assert_eq!(6, LENGTH);
+/// ```
+pub const fn total_length(structures: &[&[u8]]) -> usize {
+    let mut length = 0;
+    let mut i = 0;
+    while i < structures.len() {
+        length += structures[i].len();
+        i += 1;
+    }
+    length
+}
+
+/// Concatenate AD structures.
+///
+/// `N` must be the [`total_length`] of the structures.
+///
+/// # Examples
+///
+/// ```
+/// use ble_data_struct::payload::const_payload::concat;
+///
+/// const PAYLOAD: [u8; 6] = concat::<6>(&[&[2, 0x01, 0x06], &[2, 0x0a, 0x00]]);
+/// assert_eq!([2, 0x01, 0x06, 2, 0x0a, 0x00], PAYLOAD);
+/// ```
+pub const fn concat<const N: usize>(structures: &[&[u8]]) -> [u8; N] {
+    assert!(N == total_length(structures), "Invalid data size");
+    let mut payload = [0u8; N];
+    let mut index = 0;
+    let mut i = 0;
+    while i < structures.len() {
+        let mut j = 0;
+        while j < structures[i].len() {
+            payload[index] = structures[i][j];
+            index += 1;
+            j += 1;
+        }
+        i += 1;
+    }
+    payload
+}
+
+/// Assemble a legacy advertising payload at compile time.
+///
+/// Evaluates to `&'static [u8]`. A payload longer than
+/// [`LEGACY_ADVERTISING_DATA_MAX_LENGTH`](crate::payload::truncate::LEGACY_ADVERTISING_DATA_MAX_LENGTH) fails to compile.
+///
+/// Supported AD structures:
+///
+/// - `Flags(u8)`
+/// - `IncompleteListOf16BitServiceUuids(u16, ...)`
+/// - `CompleteListOf16BitServiceUuids(u16, ...)`
+/// - `ShortenedLocalName(&str)`
+/// - `CompleteLocalName(&str)`
+/// - `TxPowerLevel(i8)`
+/// - `ServiceData16BitUUID(u16, &[u8])`
+/// - `Appearance(u16)`
+/// - `ManufacturerSpecificData(u16, &[u8])`
+/// - `Raw(u8, &[u8])` (data type, data)
+///
+/// # Examples
+///
+/// ```
+/// use ble_data_struct::adv_payload;
+/// use ble_data_struct::data_types::{
+///     complete_local_name::CompleteLocalName, flags::Flags, tx_power_level::TxPowerLevel,
+/// };
+///
+/// const PAYLOAD: &[u8] = adv_payload![Flags(0x06), CompleteLocalName("abc"), TxPowerLevel(-4)];
+///
+/// let flags: Vec<u8> =
+///     Flags::new(&[false, true, true, false, false, false, false, false].to_vec()).into();
+/// let name: Vec<u8> = CompleteLocalName::new(&"abc".to_string()).into();
+/// let tx_power_level: Vec<u8> = TxPowerLevel::new(-4).into();
+/// assert_eq!([flags, name, tx_power_level].concat(), PAYLOAD);
+/// ```
+///
+/// ```compile_fail
+/// use ble_data_struct::adv_payload;
+///
+/// const PAYLOAD: &[u8] = adv_payload![CompleteLocalName("0123456789012345678901234567890")];
+/// ```
+#[macro_export]
+macro_rules! adv_payload {
+    (@structure Flags($flags:expr)) => {
+        &$crate::payload::const_payload::structure::<3>(0x01, &[$flags])
+    };
+    (@structure IncompleteListOf16BitServiceUuids($($uuid:expr),+ $(,)?)) => {
+        &$crate::payload::const_payload::uuid16_structure::<{ [$($uuid),+].len() * 2 + 2 }>(0x02, &[$($uuid),+])
+    };
+    (@structure CompleteListOf16BitServiceUuids($($uuid:expr),+ $(,)?)) => {
+        &$crate::payload::const_payload::uuid16_structure::<{ [$($uuid),+].len() * 2 + 2 }>(0x03, &[$($uuid),+])
+    };
+    (@structure ShortenedLocalName($name:expr)) => {
+        &$crate::payload::const_payload::structure::<{ $name.len() + 2 }>(0x08, $name.as_bytes())
+    };
+    (@structure CompleteLocalName($name:expr)) => {
+        &$crate::payload::const_payload::structure::<{ $name.len() + 2 }>(0x09, $name.as_bytes())
+    };
+    (@structure TxPowerLevel($tx_power_level:expr)) => {
+        &$crate::payload::const_payload::structure::<3>(0x0a, &[($tx_power_level as i8) as u8])
+    };
+    (@structure ServiceData16BitUUID($uuid:expr, $data:expr)) => {
+        &$crate::payload::const_payload::prefixed_structure::<{ $data.len() + 4 }>(0x16, $uuid, $data)
+    };
+    (@structure Appearance($appearance:expr)) => {
+        &$crate::payload::const_payload::prefixed_structure::<4>(0x19, $appearance, &[])
+    };
+    (@structure ManufacturerSpecificData($company_identifier:expr, $data:expr)) => {
+        &$crate::payload::const_payload::prefixed_structure::<{ $data.len() + 4 }>(0xff, $company_identifier, $data)
+    };
+    (@structure Raw($data_type:expr, $data:expr)) => {
+        &$crate::payload::const_payload::structure::<{ $data.len() + 2 }>($data_type, $data)
+    };
+    ($($kind:ident($($arg:tt)*)),* $(,)?) => {{
+        const STRUCTURES: &[&[u8]] = &[$($crate::adv_payload!(@structure $kind($($arg)*))),*];
+        const LENGTH: usize = $crate::payload::const_payload::total_length(STRUCTURES);
+        const _: () = assert!(
+            LENGTH <= $crate::payload::truncate::LEGACY_ADVERTISING_DATA_MAX_LENGTH,
+            "Invalid data size"
+        );
+        const PAYLOAD: [u8; LENGTH] = $crate::payload::const_payload::concat::<LENGTH>(STRUCTURES);
+        &PAYLOAD as &'static [u8]
+    }};
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        data_types::{
+            appearance::Appearance,
+            complete_list_of_16bit_service_uuids::CompleteListOf16BitServiceUuids,
+            complete_local_name::CompleteLocalName, flags::Flags,
+            incomplete_list_of_16bit_service_uuids::IncompleteListOf16BitServiceUuids,
+            manufacturer_specific_data::ManufacturerSpecificData,
+            service_data_16bit_uuid::ServiceData16BitUUID,
+            shortened_local_name::ShortenedLocalName, tx_power_level::TxPowerLevel,
+        },
+        payload::const_payload::{
+            concat, prefixed_structure, structure, total_length, uuid16_structure,
+        },
+        uuid_from_u16,
+    };
+
+    #[test]
+    fn test_structure() {
+        const STRUCTURE: [u8; 4] = structure::<4>(0x09, b"ab");
+        assert_eq!([3, 0x09, b'a', b'b'], STRUCTURE);
+        assert_eq!([1, 0x09], structure::<2>(0x09, &[]));
+    }
+
+    #[test]
+    fn test_prefixed_structure() {
+        const STRUCTURE: [u8; 5] = prefixed_structure::<5>(0x16, 0x180f, &[50]);
+        assert_eq!([4, 0x16, 0x0f, 0x18, 50], STRUCTURE);
+    }
+
+    #[test]
+    fn test_uuid16_structure() {
+        const STRUCTURE: [u8; 6] = uuid16_structure::<6>(0x03, &[0x180f, 0x1809]);
+        assert_eq!([5, 0x03, 0x0f, 0x18, 0x09, 0x18], STRUCTURE);
+    }
+
+    #[test]
+    fn test_total_length() {
+        assert_eq!(0, total_length(&[]));
+        assert_eq!(6, total_length(&[&[2, 0x01, 0x06], &[2, 0x0a, 0x00]]));
+    }
+
+    #[test]
+    fn test_concat() {
+        const PAYLOAD: [u8; 6] = concat::<6>(&[&[2, 0x01, 0x06], &[2, 0x0a, 0x00]]);
+        assert_eq!([2, 0x01, 0x06, 2, 0x0a, 0x00], PAYLOAD);
+    }
+
+    #[test]
+    fn test_adv_payload() {
+        const PAYLOAD: &[u8] =
+            adv_payload![Flags(0x06), CompleteLocalName("abc"), TxPowerLevel(-4)];
+        let flags: Vec<u8> =
+            Flags::new(&[false, true, true, false, false, false, false, false].to_vec()).into();
+        let name: Vec<u8> = CompleteLocalName::new(&"abc".to_string()).into();
+        let tx_power_level: Vec<u8> = TxPowerLevel::new(-4).into();
+        assert_eq!([flags, name, tx_power_level].concat(), PAYLOAD);
+
+        const UUIDS: &[u8] = adv_payload![
+            IncompleteListOf16BitServiceUuids(0x180f),
+            CompleteListOf16BitServiceUuids(0x180f, 0x1809),
+            ShortenedLocalName("a"),
+            Appearance(0x0340),
+        ];
+        let incomplete: Vec<u8> =
+            IncompleteListOf16BitServiceUuids::new(&vec![uuid_from_u16(0x180f)]).into();
+        let complete: Vec<u8> = CompleteListOf16BitServiceUuids::new(&vec![
+            uuid_from_u16(0x180f),
+            uuid_from_u16(0x1809),
+        ])
+        .into();
+        let name: Vec<u8> = ShortenedLocalName::new(&"a".to_string()).into();
+        let appearance: Vec<u8> = Appearance::new(0x0340).into();
+        assert_eq!([incomplete, complete, name, appearance].concat(), UUIDS);
+
+        const DATA: &[u8] = adv_payload![
+            ServiceData16BitUUID(0x180f, &[50]),
+            ManufacturerSpecificData(0x004c, &[0x02, 0x15]),
+            Raw(0x0a, &[0x00]),
+        ];
+        let service_data: Vec<u8> =
+            ServiceData16BitUUID::new(&uuid_from_u16(0x180f), &[50].to_vec()).into();
+        let manufacturer_specific_data: Vec<u8> =
+            ManufacturerSpecificData::new(0x004c, &[0x02, 0x15].to_vec()).into();
+        let tx_power_level: Vec<u8> = TxPowerLevel::new(0).into();
+        assert_eq!(
+            [service_data, manufacturer_specific_data, tx_power_level].concat(),
+            DATA
+        );
+
+        const EMPTY: &[u8] = adv_payload![];
+        assert!(EMPTY.is_empty());
+    }
+}
@@ -0,0 +1,265 @@
+//! Advertising payload decompiler module.
+//!
+//! Reconstruct Rust source that rebuilds a captured payload, for test fixture creation.
+
+use std::collections::BTreeSet;
+
+use uuid::Uuid;
+
+use crate::data_types::data_type_parser::DataTypeParseResult;
+
+/// Create `uuid_from_u16(0xXXXX)` expression.
+fn uuid_16bit(uuid: &Uuid) -> String {
+    format!("uuid_from_u16(0x{:04x})", uuid.as_fields().0 as u16)
+}
+
+/// Create `vec![0xXX, ...]` expression.
+fn bytes(data: &[u8]) -> String {
+    format!(
+        "vec![{}]",
+        data.iter()
+            .map(|f| format!("0x{:02x}", f))
+            .collect::<Vec<String>>()
+            .join(", ")
+    )
+}
+
+/// Create constructor expression and required `use` paths.
+fn constructor(result: &DataTypeParseResult) -> Option<(String, Vec<&'static str>)> {
+    match result {
+        DataTypeParseResult::AdvertisingIntervalResult(Ok(data_type)) => Some((
+            format!(
+                "AdvertisingInterval::new(Slots625us(0x{:04x}))",
+                data_type.advertising_interval.0
+            ),
+            vec![
+                "data_types::advertising_interval::AdvertisingInterval",
+                "data_types::time_units::Slots625us",
+            ],
+        )),
+        DataTypeParseResult::AppearanceResult(Ok(data_type)) => Some((
+            format!("Appearance::new(0x{:04x})", data_type.appearance),
+            vec!["data_types::appearance::Appearance"],
+        )),
+        DataTypeParseResult::CompleteListOf16BitServiceUuidsResult(Ok(data_type)) => Some((
+            format!(
+                "CompleteListOf16BitServiceUuids::new(&vec![{}])",
+                data_type
+                    .uuids
+                    .iter()
+                    .map(uuid_16bit)
+                    .collect::<Vec<String>>()
+                    .join(", ")
+            ),
+            vec![
+                "data_types::complete_list_of_16bit_service_uuids::CompleteListOf16BitServiceUuids",
+                "uuid_from_u16",
+            ],
+        )),
+        DataTypeParseResult::CompleteLocalNameResult(Ok(data_type)) => Some((
+            format!(
+                "CompleteLocalName::new(&{:?}.to_string())",
+                data_type.complete_local_name
+            ),
+            vec!["data_types::complete_local_name::CompleteLocalName"],
+        )),
+        DataTypeParseResult::FlagsResult(Ok(data_type)) => Some((
+            format!("Flags::new(&vec!{:?})", data_type.flags),
+            vec!["data_types::flags::Flags"],
+        )),
+        DataTypeParseResult::IncompleteListOf16BitServiceUuidsResult(Ok(data_type)) => Some((
+            format!(
+                "IncompleteListOf16BitServiceUuids::new(&vec![{}])",
+                data_type
+                    .uuids
+                    .iter()
+                    .map(uuid_16bit)
+                    .collect::<Vec<String>>()
+                    .join(", ")
+            ),
+            vec![
+                "data_types::incomplete_list_of_16bit_service_uuids::IncompleteListOf16BitServiceUuids",
+                "uuid_from_u16",
+            ],
+        )),
+        DataTypeParseResult::LeRoleResult(Ok(data_type)) => Some((
+            format!("LeRole::new(0x{:02x})", data_type.le_role),
+            vec!["data_types::le_role::LeRole"],
+        )),
+        DataTypeParseResult::ManufacturerSpecificDataResult(Ok(data_type)) => Some((
+            format!(
+                "ManufacturerSpecificData::new(0x{:04x}, &{})",
+                data_type.company_identifier,
+                bytes(&data_type.manufacturer_specific_data)
+            ),
+            vec!["data_types::manufacturer_specific_data::ManufacturerSpecificData"],
+        )),
+        DataTypeParseResult::ServiceData16BitUUIDResult(Ok(data_type)) => Some((
+            format!(
+                "ServiceData16BitUUID::new(&{}, &{})",
+                uuid_16bit(&data_type.uuid),
+                bytes(&data_type.additional_service_data)
+            ),
+            vec![
+                "data_types::service_data_16bit_uuid::ServiceData16BitUUID",
+                "uuid_from_u16",
+            ],
+        )),
+        DataTypeParseResult::ShortenedLocalNameResult(Ok(data_type)) => Some((
+            format!(
+                "ShortenedLocalName::new(&{:?}.to_string())",
+                data_type.shortened_local_name
+            ),
+            vec!["data_types::shortened_local_name::ShortenedLocalName"],
+        )),
+        DataTypeParseResult::TxPowerLevelResult(Ok(data_type)) => Some((
+            format!("TxPowerLevel::new({})", data_type.tx_power_level),
+            vec!["data_types::tx_power_level::TxPowerLevel"],
+        )),
+        _ => None,
+    }
+}
+
+/// Create Rust source that rebuilds `payload`.
+///
+/// Common data types are emitted as `new` calls, others (and structures that do not re-encode to the same bytes)
+/// are emitted as raw bytes.
+///
+/// # Examples
+///
+/// ```
+/// use ble_data_struct::data_types::{
+///     complete_local_name::CompleteLocalName, tx_power_level::TxPowerLevel,
+/// };
+/// use ble_data_struct::payload::decompile::decompile;
+///
+/// let name: Vec<u8> = CompleteLocalName::new(&"name".to_string()).into();
+/// let tx_power_level: Vec<u8> = TxPowerLevel::new(-4).into();
+/// let payload = [name, tx_power_level, vec![0x02, 0x2f, 0x01]].concat();
+///
+/// assert_eq!(
+///     "use ble_data_struct::data_types::complete_local_name::CompleteLocalName;\n\
+///      use ble_data_struct::data_types::tx_power_level::TxPowerLevel;\n\
+///      \n\
+///      let mut payload: Vec<u8> = Vec::new();\n\
+///      payload.append(&mut CompleteLocalName::new(&\"name\".to_string()).into());\n\
+///      payload.append(&mut TxPowerLevel::new(-4).into());\n\
+///      payload.append(&mut vec![0x02, 0x2f, 0x01]);\n",
+///     decompile(&payload)
+/// );
+/// ```
+pub fn decompile(payload: &[u8]) -> String {
+    let mut uses: BTreeSet<&'static str> = BTreeSet::new();
+    let mut statements: Vec<String> = Vec::new();
+    let mut index = 0;
+    while index < payload.len() {
+        let length = payload[index] as usize;
+        let end = index + 1 + length;
+        if length == 0 || end > payload.len() {
+            statements.push(format!(
+                "payload.append(&mut {});",
+                bytes(&payload[index..])
+            ));
+            break;
+        }
+        let structure = payload[index..end].to_vec();
+        let result = DataTypeParseResult::from(&structure);
+        match constructor(&result) {
+            Some((expression, paths))
+                if result.ad_structure().map(|f| f.to_bytes()) == Some(structure.clone()) =>
+            {
+                uses.extend(paths);
+                statements.push(format!("payload.append(&mut {}.into());", expression));
+            }
+            _ => statements.push(format!("payload.append(&mut {});", bytes(&structure))),
+        }
+        index = end;
+    }
+
+    let mut source = String::new();
+    uses.iter()
+        .for_each(|f| source.push_str(&format!("use ble_data_struct::{};\n", f)));
+    if !uses.is_empty() {
+        source.push('\n');
+    }
+    source.push_str("let mut payload: Vec<u8> = Vec::new();\n");
+    statements.iter().for_each(|f| {
+        source.push_str(f);
+        source.push('\n');
+    });
+    source
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        data_types::{
+            appearance::Appearance,
+            complete_list_of_16bit_service_uuids::CompleteListOf16BitServiceUuids, flags::Flags,
+            manufacturer_specific_data::ManufacturerSpecificData,
+            service_data_16bit_uuid::ServiceData16BitUUID,
+        },
+        payload::decompile::decompile,
+        uuid_from_u16,
+    };
+
+    #[test]
+    fn test_decompile() {
+        let flags: Vec<u8> =
+            Flags::new(&[false, true, false, false, false, false, false, false].to_vec()).into();
+        let appearance: Vec<u8> = Appearance::new(0x0040).into();
+        let uuids: Vec<u8> = CompleteListOf16BitServiceUuids::new(&vec![
+            uuid_from_u16(0x180f),
+            uuid_from_u16(0x180a),
+        ])
+        .into();
+        let manufacturer_specific_data: Vec<u8> =
+            ManufacturerSpecificData::new(0x004c, &vec![0x02, 0x15]).into();
+        let service_data: Vec<u8> =
+            ServiceData16BitUUID::new(&uuid_from_u16(0x180f), &vec![50]).into();
+        let payload = [
+            flags,
+            appearance,
+            uuids,
+            manufacturer_specific_data,
+            service_data,
+        ]
+        .concat();
+
+        let expected = "\
+use ble_data_struct::data_types::appearance::Appearance;
+use ble_data_struct::data_types::complete_list_of_16bit_service_uuids::CompleteListOf16BitServiceUuids;
+use ble_data_struct::data_types::flags::Flags;
+use ble_data_struct::data_types::manufacturer_specific_data::ManufacturerSpecificData;
+use ble_data_struct::data_types::service_data_16bit_uuid::ServiceData16BitUUID;
+use ble_data_struct::uuid_from_u16;
+
+let mut payload: Vec<u8> = Vec::new();
+payload.append(&mut Flags::new(&vec![false, true, false, false, false, false, false, false]).into());
+payload.append(&mut Appearance::new(0x0040).into());
+payload.append(&mut CompleteListOf16BitServiceUuids::new(&vec![uuid_from_u16(0x180f), uuid_from_u16(0x180a)]).into());
+payload.append(&mut ManufacturerSpecificData::new(0x004c, &vec![0x02, 0x15]).into());
+payload.append(&mut ServiceData16BitUUID::new(&uuid_from_u16(0x180f), &vec![0x32]).into());
+";
+        assert_eq!(expected, decompile(&payload));
+    }
+
+    #[test]
+    fn test_decompile_raw() {
+        assert_eq!(
+            "let mut payload: Vec<u8> = Vec::new();\n",
+            decompile(&[])
+        );
+        assert_eq!(
+            "let mut payload: Vec<u8> = Vec::new();\n\
+             payload.append(&mut vec![0x02, 0x0a]);\n",
+            decompile(&[0x02, 0x0a])
+        );
+        assert_eq!(
+            "let mut payload: Vec<u8> = Vec::new();\n\
+             payload.append(&mut vec![0x01, 0x0a]);\n\
+             payload.append(&mut vec![0x00, 0x00]);\n",
+            decompile(&[0x01, 0x0a, 0x00, 0x00])
+        );
+    }
+}
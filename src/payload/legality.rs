@@ -0,0 +1,383 @@
+//! Advertising payload legality check module.
+//!
+//! Check which AD types are permitted in advertising data / scan response data
+//! for the advertising PDU type and the GAP discoverable mode.
+
+use crate::data_types::{
+    data_type_parser::{DataTypeParseResult, DataTypeParseResults, ParseContext},
+    flags::DiscoverableMode,
+    parse_error::DataTypeParseError,
+};
+
+/// Advertising PDU type.
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Hash)]
+pub enum AdvertisingPduType {
+    /// ADV_IND (connectable and scannable undirected)
+    AdvInd,
+
+    /// ADV_DIRECT_IND (connectable directed)
+    AdvDirectInd,
+
+    /// ADV_NONCONN_IND (non-connectable and non-scannable undirected)
+    AdvNonconnInd,
+
+    /// ADV_SCAN_IND (scannable undirected)
+    AdvScanInd,
+
+    /// Extended advertising, connectable
+    ExtendedConnectable,
+
+    /// Extended advertising, scannable
+    ExtendedScannable,
+
+    /// Extended advertising, non-connectable and non-scannable
+    ExtendedNonConnectableNonScannable,
+}
+
+impl AdvertisingPduType {
+    /// Check the PDU type carries advertising data.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ble_data_struct::payload::legality::AdvertisingPduType;
+    ///
+    /// assert!(AdvertisingPduType::AdvInd.has_advertising_data());
+    /// assert!(!AdvertisingPduType::AdvDirectInd.has_advertising_data());
+    /// assert!(!AdvertisingPduType::ExtendedScannable.has_advertising_data());
+    /// ```
+    pub fn has_advertising_data(&self) -> bool {
+        !matches!(self, Self::AdvDirectInd | Self::ExtendedScannable)
+    }
+
+    /// Check the PDU type is scannable (has scan response data).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ble_data_struct::payload::legality::AdvertisingPduType;
+    ///
+    /// assert!(AdvertisingPduType::AdvScanInd.is_scannable());
+    /// assert!(!AdvertisingPduType::AdvNonconnInd.is_scannable());
+    /// ```
+    pub fn is_scannable(&self) -> bool {
+        matches!(
+            self,
+            Self::AdvInd | Self::AdvScanInd | Self::ExtendedScannable
+        )
+    }
+}
+
+/// Advertising payload legality violation.
+#[derive(Debug, PartialEq, Clone)]
+pub enum LegalityViolation {
+    /// Advertising data is not empty for a PDU type without advertising data.
+    AdvertisingDataNotAllowed,
+
+    /// Scan response data is not empty for a non-scannable PDU type.
+    ScanResponseDataNotAllowed,
+
+    /// Data type is not allowed in advertising data / scan response data.
+    DataTypeNotAllowed {
+        /// data type
+        data_type: u8,
+
+        /// `true` if found in scan response data
+        scan_response: bool,
+    },
+
+    /// [`Flags`](crate::data_types::flags::Flags) appears more than once in advertising data.
+    DuplicateFlags,
+
+    /// [`Flags`](crate::data_types::flags::Flags) is required by the discoverable mode but not found in advertising data.
+    MissingFlags,
+
+    /// [`Flags`](crate::data_types::flags::Flags) discoverable bits do not match the discoverable mode.
+    DiscoverableModeMismatch,
+}
+
+/// Returns the data types not allowed in the [`ParseContext`] (see [`ParseContext::is_allowed`]).
+fn not_allowed_data_types(results: &[DataTypeParseResult]) -> Vec<u8> {
+    results
+        .iter()
+        .filter_map(|f| match f {
+            DataTypeParseResult::DataTypeParseError(DataTypeParseError::DataTypeNotAllowed(
+                data_type,
+            )) => Some(*data_type),
+            _ => None,
+        })
+        .collect()
+}
+
+/// Check advertising data and scan response data for the PDU type and the discoverable mode.
+///
+/// # Examples
+///
+/// ```
+/// use ble_data_struct::data_types::{
+///     complete_local_name::CompleteLocalName,
+///     flags::{DiscoverableMode, Flags},
+/// };
+/// use ble_data_struct::payload::legality::{
+///     check_legality, AdvertisingPduType, LegalityViolation,
+/// };
+///
+/// let flags: Vec<u8> =
+///     Flags::new(&[false, true, false, false, false, false, false, false].to_vec()).into();
+/// let name: Vec<u8> = CompleteLocalName::new(&"name".to_string()).into();
+///
+/// assert!(check_legality(
+///     AdvertisingPduType::AdvInd,
+///     DiscoverableMode::General,
+///     &flags,
+///     &name
+/// )
+/// .is_empty());
+///
+/// assert_eq!(
+///     vec![
+///         LegalityViolation::DataTypeNotAllowed {
+///             data_type: 0x01,
+///             scan_response: true
+///         },
+///         LegalityViolation::MissingFlags,
+///     ],
+///     check_legality(
+///         AdvertisingPduType::AdvInd,
+///         DiscoverableMode::General,
+///         &name,
+///         &flags
+///     )
+/// );
+/// ```
+pub fn check_legality(
+    pdu_type: AdvertisingPduType,
+    mode: DiscoverableMode,
+    advertising_data: &[u8],
+    scan_response_data: &[u8],
+) -> Vec<LegalityViolation> {
+    let mut violations: Vec<LegalityViolation> = Vec::new();
+    if !pdu_type.has_advertising_data() && !advertising_data.is_empty() {
+        violations.push(LegalityViolation::AdvertisingDataNotAllowed);
+    }
+    if !pdu_type.is_scannable() && !scan_response_data.is_empty() {
+        violations.push(LegalityViolation::ScanResponseDataNotAllowed);
+    }
+
+    let advertising_results: Vec<DataTypeParseResult> =
+        DataTypeParseResults::iter_with_context(advertising_data, ParseContext::Advertising)
+            .collect();
+    let scan_response_results: Vec<DataTypeParseResult> =
+        DataTypeParseResults::iter_with_context(scan_response_data, ParseContext::ScanResponse)
+            .collect();
+    not_allowed_data_types(&advertising_results)
+        .into_iter()
+        .for_each(|data_type| {
+            violations.push(LegalityViolation::DataTypeNotAllowed {
+                data_type,
+                scan_response: false,
+            })
+        });
+    not_allowed_data_types(&scan_response_results)
+        .into_iter()
+        .for_each(|data_type| {
+            violations.push(LegalityViolation::DataTypeNotAllowed {
+                data_type,
+                scan_response: true,
+            })
+        });
+
+    let flags: Vec<&DataTypeParseResult> = advertising_results
+        .iter()
+        .filter(|f| f.is_flags())
+        .collect();
+    if flags.len() > 1 {
+        violations.push(LegalityViolation::DuplicateFlags);
+    }
+    match flags.first() {
+        Some(DataTypeParseResult::FlagsResult(Ok(flags))) => {
+            if flags.discoverable_mode() != Some(mode) {
+                violations.push(LegalityViolation::DiscoverableModeMismatch);
+            }
+        }
+        _ => {
+            if mode != DiscoverableMode::NonDiscoverable {
+                violations.push(LegalityViolation::MissingFlags);
+            }
+        }
+    }
+    violations
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        data_types::{
+            class_of_device::ClassOfDevice,
+            complete_local_name::CompleteLocalName,
+            flags::{DiscoverableMode, Flags},
+            tx_power_level::TxPowerLevel,
+        },
+        payload::legality::{check_legality, AdvertisingPduType, LegalityViolation},
+    };
+
+    fn flags(limited: bool, general: bool) -> Vec<u8> {
+        Flags::new(&[limited, general, true, false, false, false, false, false].to_vec()).into()
+    }
+
+    #[test]
+    fn test_has_advertising_data() {
+        assert!(AdvertisingPduType::AdvInd.has_advertising_data());
+        assert!(!AdvertisingPduType::AdvDirectInd.has_advertising_data());
+        assert!(AdvertisingPduType::AdvNonconnInd.has_advertising_data());
+        assert!(AdvertisingPduType::AdvScanInd.has_advertising_data());
+        assert!(AdvertisingPduType::ExtendedConnectable.has_advertising_data());
+        assert!(!AdvertisingPduType::ExtendedScannable.has_advertising_data());
+        assert!(AdvertisingPduType::ExtendedNonConnectableNonScannable.has_advertising_data());
+    }
+
+    #[test]
+    fn test_is_scannable() {
+        assert!(AdvertisingPduType::AdvInd.is_scannable());
+        assert!(!AdvertisingPduType::AdvDirectInd.is_scannable());
+        assert!(!AdvertisingPduType::AdvNonconnInd.is_scannable());
+        assert!(AdvertisingPduType::AdvScanInd.is_scannable());
+        assert!(!AdvertisingPduType::ExtendedConnectable.is_scannable());
+        assert!(AdvertisingPduType::ExtendedScannable.is_scannable());
+        assert!(!AdvertisingPduType::ExtendedNonConnectableNonScannable.is_scannable());
+    }
+
+    #[test]
+    fn test_check_legality_pdu_type() {
+        let name: Vec<u8> = CompleteLocalName::new(&"name".to_string()).into();
+        assert_eq!(
+            vec![
+                LegalityViolation::AdvertisingDataNotAllowed,
+                LegalityViolation::ScanResponseDataNotAllowed,
+            ],
+            check_legality(
+                AdvertisingPduType::AdvDirectInd,
+                DiscoverableMode::NonDiscoverable,
+                &name,
+                &name
+            )
+        );
+        assert_eq!(
+            vec![LegalityViolation::ScanResponseDataNotAllowed],
+            check_legality(
+                AdvertisingPduType::AdvNonconnInd,
+                DiscoverableMode::NonDiscoverable,
+                &Vec::new(),
+                &name
+            )
+        );
+        assert!(check_legality(
+            AdvertisingPduType::ExtendedScannable,
+            DiscoverableMode::NonDiscoverable,
+            &Vec::new(),
+            &name
+        )
+        .is_empty());
+    }
+
+    #[test]
+    fn test_check_legality_data_type() {
+        let class_of_device: Vec<u8> = ClassOfDevice::new(0x000000).into();
+        let tx_power_level: Vec<u8> = TxPowerLevel::new(0).into();
+        assert_eq!(
+            vec![
+                LegalityViolation::DataTypeNotAllowed {
+                    data_type: 0x0d,
+                    scan_response: false
+                },
+                LegalityViolation::DataTypeNotAllowed {
+                    data_type: 0x01,
+                    scan_response: true
+                },
+                LegalityViolation::DataTypeNotAllowed {
+                    data_type: 0x0d,
+                    scan_response: true
+                },
+            ],
+            check_legality(
+                AdvertisingPduType::AdvScanInd,
+                DiscoverableMode::NonDiscoverable,
+                &[class_of_device.clone(), tx_power_level].concat(),
+                &[flags(false, false), class_of_device].concat()
+            )
+        );
+    }
+
+    #[test]
+    fn test_check_legality_flags() {
+        let general = flags(false, true);
+        let limited = flags(true, false);
+        let none = flags(false, false);
+
+        assert!(check_legality(
+            AdvertisingPduType::AdvInd,
+            DiscoverableMode::General,
+            &general,
+            &Vec::new()
+        )
+        .is_empty());
+        assert!(check_legality(
+            AdvertisingPduType::AdvInd,
+            DiscoverableMode::Limited,
+            &limited,
+            &Vec::new()
+        )
+        .is_empty());
+        assert!(check_legality(
+            AdvertisingPduType::AdvInd,
+            DiscoverableMode::NonDiscoverable,
+            &none,
+            &Vec::new()
+        )
+        .is_empty());
+        assert!(check_legality(
+            AdvertisingPduType::AdvInd,
+            DiscoverableMode::NonDiscoverable,
+            &Vec::new(),
+            &Vec::new()
+        )
+        .is_empty());
+
+        assert_eq!(
+            vec![LegalityViolation::DiscoverableModeMismatch],
+            check_legality(
+                AdvertisingPduType::AdvInd,
+                DiscoverableMode::General,
+                &limited,
+                &Vec::new()
+            )
+        );
+        assert_eq!(
+            vec![LegalityViolation::DiscoverableModeMismatch],
+            check_legality(
+                AdvertisingPduType::AdvInd,
+                DiscoverableMode::NonDiscoverable,
+                &general,
+                &Vec::new()
+            )
+        );
+        assert_eq!(
+            vec![LegalityViolation::MissingFlags],
+            check_legality(
+                AdvertisingPduType::AdvDirectInd,
+                DiscoverableMode::Limited,
+                &Vec::new(),
+                &Vec::new()
+            )
+        );
+        assert_eq!(
+            vec![LegalityViolation::DuplicateFlags],
+            check_legality(
+                AdvertisingPduType::AdvInd,
+                DiscoverableMode::General,
+                &[general.clone(), general].concat(),
+                &Vec::new()
+            )
+        );
+    }
+}
@@ -0,0 +1,548 @@
+//! LE Secure Connections OOB data block module.
+//!
+//! The QR code payload is [`OOB_QR_PREFIX`] followed by the URL-safe base64 (without padding) of
+//! the format version, the OOB data block and its CRC-16/CCITT-FALSE (little endian).
+
+use crate::data_types::{
+    data_type_parser::{DataTypeParseResult, DataTypeParseResults, ParseContext},
+    le_bluetooth_device_address::LeBluetoothDeviceAddress,
+    le_role::LeRole,
+    le_secure_connections_confirmation_value::LeSecureConnectionsConfirmationValue,
+    le_secure_connections_random_value::LeSecureConnectionsRandomValue,
+    parse_error::DataTypeParseError,
+};
+
+/// QR code payload prefix.
+pub const OOB_QR_PREFIX: &str = "BTLEOOB:";
+
+/// QR code payload format version.
+pub const OOB_QR_VERSION: u8 = 1;
+
+/// URL-safe base64 alphabet.
+const BASE64_URL: &[u8; 64] = b"ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789-_";
+
+/// OOB data block / QR code payload error.
+///
+/// [`Display`](std::fmt::Display) keeps the message format of the former `String` errors.
+#[derive(Debug, PartialEq, Eq, Clone)]
+#[non_exhaustive]
+pub enum OobError {
+    /// QR code payload does not start with [`OOB_QR_PREFIX`].
+    InvalidPrefix,
+
+    /// QR code payload is not URL-safe base64 (without padding).
+    InvalidBase64,
+
+    /// Decoded QR code payload is shorter than the format version and the CRC.
+    InvalidLength(usize),
+
+    /// Unsupported QR code payload format version.
+    UnsupportedVersion(u8),
+
+    /// CRC of the QR code payload does not match.
+    InvalidChecksum(u16),
+
+    /// Malformed data type in the OOB data block.
+    DataType(DataTypeParseError),
+
+    /// LE Bluetooth Device Address, Confirmation Value or Random Value is missing.
+    MissingData,
+}
+
+impl std::fmt::Display for OobError {
+    /// Format the error message.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ble_data_struct::payload::oob::OobError;
+    ///
+    /// assert_eq!("Invalid checksum :db0a", OobError::InvalidChecksum(0xdb0a).to_string());
+    /// ```
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            OobError::InvalidPrefix => write!(f, "Invalid prefix"),
+            OobError::InvalidBase64 => write!(f, "Invalid base64"),
+            OobError::InvalidLength(len) => write!(f, "Invalid data size :{}", len),
+            OobError::UnsupportedVersion(version) => {
+                write!(f, "Unsupported version :{}", version)
+            }
+            OobError::InvalidChecksum(checksum) => write!(f, "Invalid checksum :{:04x}", checksum),
+            OobError::DataType(error) => write!(f, "{}", error),
+            OobError::MissingData => write!(f, "Missing LE Secure Connections OOB data"),
+        }
+    }
+}
+
+impl std::error::Error for OobError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            OobError::DataType(error) => Some(error),
+            _ => None,
+        }
+    }
+}
+
+impl From<DataTypeParseError> for OobError {
+    /// Create [`OobError`] from [`DataTypeParseError`].
+    fn from(value: DataTypeParseError) -> Self {
+        OobError::DataType(value)
+    }
+}
+
+impl From<OobError> for String {
+    /// Create [`String`] from [`OobError`].
+    fn from(value: OobError) -> Self {
+        value.to_string()
+    }
+}
+
+/// LE Secure Connections OOB data block (Core Specification Vol 3, Part H, 2.3.5.6.4).
+#[derive(Debug, PartialEq, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct LeSecureConnectionsOobBlock {
+    /// LE Bluetooth Device Address
+    pub le_bluetooth_device_address: LeBluetoothDeviceAddress,
+
+    /// LE Role
+    pub le_role: Option<LeRole>,
+
+    /// LE Secure Connections Confirmation Value
+    pub confirmation_value: LeSecureConnectionsConfirmationValue,
+
+    /// LE Secure Connections Random Value
+    pub random_value: LeSecureConnectionsRandomValue,
+}
+
+impl LeSecureConnectionsOobBlock {
+    /// Create [`LeSecureConnectionsOobBlock`] from Parameters.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ble_data_struct::payload::oob::LeSecureConnectionsOobBlock;
+    ///
+    /// let result = LeSecureConnectionsOobBlock::new(0x0000060504030201, true, 0x01, 0x02);
+    /// assert_eq!(
+    ///     0x0000060504030201,
+    ///     result.le_bluetooth_device_address.le_bluetooth_device_address
+    /// );
+    /// assert!(result.le_bluetooth_device_address.address_type.is_random());
+    /// assert_eq!(None, result.le_role);
+    /// assert_eq!(0x01, result.confirmation_value.le_secure_connections_confirmation_value);
+    /// assert_eq!(0x02, result.random_value.le_secure_connections_random_value);
+    /// ```
+    pub fn new(
+        le_bluetooth_device_address: u64,
+        random_address: bool,
+        confirmation_value: u128,
+        random_value: u128,
+    ) -> Self {
+        Self {
+            le_bluetooth_device_address: LeBluetoothDeviceAddress::new(
+                le_bluetooth_device_address,
+                random_address,
+            ),
+            le_role: None,
+            confirmation_value: LeSecureConnectionsConfirmationValue::new(confirmation_value),
+            random_value: LeSecureConnectionsRandomValue::new(random_value),
+        }
+    }
+
+    /// Set LE Role.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ble_data_struct::data_types::le_role::{LeRole, ONLY_PERIPHERAL_ROLE_SUPPORTED};
+    /// use ble_data_struct::payload::oob::LeSecureConnectionsOobBlock;
+    ///
+    /// let result = LeSecureConnectionsOobBlock::new(0x0000060504030201, false, 0x01, 0x02)
+    ///     .with_le_role(ONLY_PERIPHERAL_ROLE_SUPPORTED);
+    /// assert_eq!(Some(LeRole::new(ONLY_PERIPHERAL_ROLE_SUPPORTED)), result.le_role);
+    /// ```
+    pub fn with_le_role(mut self, le_role: u8) -> Self {
+        self.le_role = Some(LeRole::new(le_role));
+        self
+    }
+
+    /// Create QR code payload.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ble_data_struct::payload::oob::{LeSecureConnectionsOobBlock, OOB_QR_PREFIX};
+    ///
+    /// let block = LeSecureConnectionsOobBlock::new(0x0000060504030201, false, 0x01, 0x02);
+    /// let result = block.to_qr_payload();
+    /// assert!(result.starts_with(OOB_QR_PREFIX));
+    /// assert_eq!(Ok(block), LeSecureConnectionsOobBlock::from_qr_payload(&result));
+    /// ```
+    pub fn to_qr_payload(&self) -> String {
+        let data: Vec<u8> = self.clone().into();
+        encode_qr_payload(&data)
+    }
+
+    /// Create [`LeSecureConnectionsOobBlock`] from QR code payload.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ble_data_struct::payload::oob::{encode_qr_payload, LeSecureConnectionsOobBlock};
+    ///
+    /// let block = LeSecureConnectionsOobBlock::new(0x0000060504030201, false, 0x01, 0x02);
+    /// let data: Vec<u8> = block.clone().into();
+    /// assert_eq!(
+    ///     Ok(block),
+    ///     LeSecureConnectionsOobBlock::from_qr_payload(&encode_qr_payload(&data))
+    /// );
+    /// assert!(LeSecureConnectionsOobBlock::from_qr_payload(&encode_qr_payload(&data[..9])).is_err());
+    /// ```
+    pub fn from_qr_payload(value: &str) -> Result<Self, OobError> {
+        Self::try_from(decode_qr_payload(value)?.as_slice())
+    }
+}
+
+impl TryFrom<&[u8]> for LeSecureConnectionsOobBlock {
+    type Error = OobError;
+    /// Create [`LeSecureConnectionsOobBlock`] from OOB data block.
+    ///
+    /// Other data types (e.g. Flags, Local Name) are ignored.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ble_data_struct::data_types::complete_local_name::CompleteLocalName;
+    /// use ble_data_struct::payload::oob::LeSecureConnectionsOobBlock;
+    ///
+    /// let block = LeSecureConnectionsOobBlock::new(0x0000060504030201, false, 0x01, 0x02);
+    /// let data: Vec<u8> = block.clone().into();
+    /// let name: Vec<u8> = CompleteLocalName::new(&"name".to_string()).into();
+    /// let result = LeSecureConnectionsOobBlock::try_from([data.clone(), name].concat().as_slice());
+    /// assert_eq!(Ok(block), result);
+    ///
+    /// let result = LeSecureConnectionsOobBlock::try_from(&data[..9]);
+    /// assert!(result.is_err());
+    /// ```
+    fn try_from(value: &[u8]) -> Result<Self, OobError> {
+        let mut le_bluetooth_device_address = None;
+        let mut le_role = None;
+        let mut confirmation_value = None;
+        let mut random_value = None;
+        for result in DataTypeParseResults::iter_with_context(value, ParseContext::Oob) {
+            match result {
+                DataTypeParseResult::LeBluetoothDeviceAddressResult(Ok(data_type)) => {
+                    le_bluetooth_device_address = Some(data_type)
+                }
+                DataTypeParseResult::LeRoleResult(Ok(data_type)) => le_role = Some(data_type),
+                DataTypeParseResult::LeSecureConnectionsConfirmationValueResult(Ok(data_type)) => {
+                    confirmation_value = Some(data_type)
+                }
+                DataTypeParseResult::LeSecureConnectionsRandomValueResult(Ok(data_type)) => {
+                    random_value = Some(data_type)
+                }
+                _ => {
+                    if let Some(error) = result.parse_error() {
+                        return Err(error.clone().into());
+                    }
+                }
+            }
+        }
+        match (
+            le_bluetooth_device_address,
+            confirmation_value,
+            random_value,
+        ) {
+            (Some(le_bluetooth_device_address), Some(confirmation_value), Some(random_value)) => {
+                Ok(Self {
+                    le_bluetooth_device_address,
+                    le_role,
+                    confirmation_value,
+                    random_value,
+                })
+            }
+            _ => Err(OobError::MissingData),
+        }
+    }
+}
+
+impl Into<Vec<u8>> for LeSecureConnectionsOobBlock {
+    /// Create OOB data block from [`LeSecureConnectionsOobBlock`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ble_data_struct::data_types::le_role::CENTRAL_ROLE_PREFERRED_FOR_CONNECTION_STABLISHMENT;
+    /// use ble_data_struct::payload::oob::LeSecureConnectionsOobBlock;
+    ///
+    /// let data: Vec<u8> = LeSecureConnectionsOobBlock::new(0x0000060504030201, false, 0x01, 0x02).into();
+    /// assert_eq!(45, data.len());
+    ///
+    /// let data: Vec<u8> = LeSecureConnectionsOobBlock::new(0x0000060504030201, false, 0x01, 0x02)
+    ///     .with_le_role(CENTRAL_ROLE_PREFERRED_FOR_CONNECTION_STABLISHMENT)
+    ///     .into();
+    /// assert_eq!(48, data.len());
+    /// ```
+    fn into(self) -> Vec<u8> {
+        let mut data: Vec<u8> = self.le_bluetooth_device_address.into();
+        if let Some(le_role) = self.le_role {
+            data.append(&mut le_role.into());
+        }
+        data.append(&mut self.confirmation_value.into());
+        data.append(&mut self.random_value.into());
+        return data;
+    }
+}
+
+/// Encode OOB data block to QR code payload.
+///
+/// # Examples
+///
+/// ```
+/// use ble_data_struct::payload::oob::{decode_qr_payload, encode_qr_payload};
+///
+/// let data = vec![0x02, 0x1c, 0x00];
+/// let result = encode_qr_payload(&data);
+/// assert_eq!("BTLEOOB:AQIcAAra", result);
+/// assert_eq!(Ok(data), decode_qr_payload(&result));
+/// ```
+pub fn encode_qr_payload(oob_data: &[u8]) -> String {
+    let mut data = Vec::with_capacity(oob_data.len() + 3);
+    data.push(OOB_QR_VERSION);
+    data.extend_from_slice(oob_data);
+    data.extend_from_slice(&crc16(&data).to_le_bytes());
+    let mut result = String::from(OOB_QR_PREFIX);
+    base64_encode(&data, &mut result);
+    result
+}
+
+/// Decode OOB data block from QR code payload.
+///
+/// # Examples
+///
+/// ```
+/// use ble_data_struct::payload::oob::{decode_qr_payload, OobError};
+///
+/// assert_eq!(Ok(vec![0x02, 0x1c, 0x00]), decode_qr_payload("BTLEOOB:AQIcAAra"));
+/// assert_eq!(Err(OobError::InvalidChecksum(0xdb0a)), decode_qr_payload("BTLEOOB:AQIcAArb"));
+/// assert_eq!(Err(OobError::InvalidPrefix), decode_qr_payload("AQIcAAra"));
+/// ```
+pub fn decode_qr_payload(value: &str) -> Result<Vec<u8>, OobError> {
+    let encoded = value
+        .strip_prefix(OOB_QR_PREFIX)
+        .ok_or(OobError::InvalidPrefix)?;
+    let data = base64_decode(encoded).ok_or(OobError::InvalidBase64)?;
+    let len = data.len();
+    if len < 3 {
+        return Err(OobError::InvalidLength(len));
+    }
+    if data[0] != OOB_QR_VERSION {
+        return Err(OobError::UnsupportedVersion(data[0]));
+    }
+    let checksum = u16::from_le_bytes([data[len - 2], data[len - 1]]);
+    if checksum != crc16(&data[..len - 2]) {
+        return Err(OobError::InvalidChecksum(checksum));
+    }
+    Ok(data[1..len - 2].to_vec())
+}
+
+/// CRC-16/CCITT-FALSE.
+fn crc16(value: &[u8]) -> u16 {
+    value.iter().fold(0xffff, |crc, byte| {
+        (0..8).fold(crc ^ (*byte as u16) << 8, |crc, _| {
+            if crc & 0x8000 != 0 {
+                crc << 1 ^ 0x1021
+            } else {
+                crc << 1
+            }
+        })
+    })
+}
+
+/// Append URL-safe base64 (without padding).
+fn base64_encode(value: &[u8], result: &mut String) {
+    for chunk in value.chunks(3) {
+        let bits = chunk.iter().enumerate().fold(0u32, |bits, (index, byte)| {
+            bits | (*byte as u32) << (16 - 8 * index)
+        });
+        for index in 0..=chunk.len() {
+            result.push(BASE64_URL[(bits >> (18 - 6 * index)) as usize & 0x3f] as char);
+        }
+    }
+}
+
+/// Decode URL-safe base64 (without padding).
+fn base64_decode(value: &str) -> Option<Vec<u8>> {
+    let mut result = Vec::with_capacity(value.len() * 3 / 4);
+    for chunk in value.as_bytes().chunks(4) {
+        if chunk.len() == 1 {
+            return None;
+        }
+        let mut bits = 0u32;
+        for (index, char) in chunk.iter().enumerate() {
+            let sextet = BASE64_URL.iter().position(|f| f == char)? as u32;
+            bits |= sextet << (18 - 6 * index);
+        }
+        for index in 0..chunk.len() - 1 {
+            result.push((bits >> (16 - 8 * index)) as u8);
+        }
+    }
+    Some(result)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        data_types::{
+            complete_local_name::CompleteLocalName,
+            flags::Flags,
+            le_role::{LeRole, ONLY_CENTRAL_ROLE_SUPPORTED},
+        },
+        payload::oob::{
+            base64_decode, base64_encode, crc16, decode_qr_payload, encode_qr_payload,
+            LeSecureConnectionsOobBlock, OobError, OOB_QR_PREFIX,
+        },
+    };
+
+    #[test]
+    fn test_new() {
+        let result = LeSecureConnectionsOobBlock::new(0x0000060504030201, true, 0x01, 0x02)
+            .with_le_role(ONLY_CENTRAL_ROLE_SUPPORTED);
+        assert_eq!(
+            0x0000060504030201,
+            result
+                .le_bluetooth_device_address
+                .le_bluetooth_device_address
+        );
+        assert!(result.le_bluetooth_device_address.address_type.is_random());
+        assert_eq!(
+            Some(LeRole::new(ONLY_CENTRAL_ROLE_SUPPORTED)),
+            result.le_role
+        );
+        assert_eq!(
+            0x01,
+            result
+                .confirmation_value
+                .le_secure_connections_confirmation_value
+        );
+        assert_eq!(0x02, result.random_value.le_secure_connections_random_value);
+    }
+
+    #[test]
+    fn test_try_from() {
+        let block = LeSecureConnectionsOobBlock::new(
+            0x0000060504030201,
+            false,
+            0x0102030405060708090a0b0c0d0e0f10,
+            0x1112131415161718191a1b1c1d1e1f20,
+        )
+        .with_le_role(ONLY_CENTRAL_ROLE_SUPPORTED);
+        let data: Vec<u8> = block.clone().into();
+        assert_eq!(48, data.len());
+        assert_eq!(
+            Ok(block.clone()),
+            LeSecureConnectionsOobBlock::try_from(&data[..])
+        );
+
+        let flags: Vec<u8> = Flags::new(&vec![false, true]).into();
+        let name: Vec<u8> = CompleteLocalName::new(&"name".to_string()).into();
+        let data_with_others = [flags, data.clone(), name].concat();
+        assert_eq!(
+            Ok(block),
+            LeSecureConnectionsOobBlock::try_from(&data_with_others[..])
+        );
+
+        assert_eq!(
+            Err(OobError::MissingData),
+            LeSecureConnectionsOobBlock::try_from(&data[..30])
+        );
+        assert!(matches!(
+            LeSecureConnectionsOobBlock::try_from(&data[..40]),
+            Err(OobError::DataType(_))
+        ));
+        assert!(LeSecureConnectionsOobBlock::try_from(&[][..]).is_err());
+    }
+
+    #[test]
+    fn test_qr_payload() {
+        let block = LeSecureConnectionsOobBlock::new(
+            0x0000c60504030201,
+            true,
+            0x0102030405060708090a0b0c0d0e0f10,
+            0x1112131415161718191a1b1c1d1e1f20,
+        );
+        let result = block.to_qr_payload();
+        assert!(result.starts_with(OOB_QR_PREFIX));
+        assert!(result[OOB_QR_PREFIX.len()..]
+            .chars()
+            .all(|f| f.is_ascii_alphanumeric() || f == '-' || f == '_'));
+        assert_eq!(
+            Ok(block),
+            LeSecureConnectionsOobBlock::from_qr_payload(&result)
+        );
+    }
+
+    #[test]
+    fn test_encode_qr_payload() {
+        assert_eq!("BTLEOOB:AQIcAAra", encode_qr_payload(&[0x02, 0x1c, 0x00]));
+        assert_eq!(Ok(Vec::new()), decode_qr_payload(&encode_qr_payload(&[])));
+        for len in 0..8 {
+            let data: Vec<u8> = (0..len).collect();
+            assert_eq!(
+                Ok(data.clone()),
+                decode_qr_payload(&encode_qr_payload(&data))
+            );
+        }
+    }
+
+    #[test]
+    fn test_decode_qr_payload() {
+        assert_eq!(Err(OobError::InvalidPrefix), decode_qr_payload("AQIcAAra"));
+        assert_eq!(
+            Err(OobError::InvalidBase64),
+            decode_qr_payload("BTLEOOB:AQIc+Ara")
+        );
+        assert_eq!(
+            Err(OobError::InvalidBase64),
+            decode_qr_payload("BTLEOOB:AQIcA")
+        );
+        assert_eq!(
+            Err(OobError::InvalidLength(1)),
+            decode_qr_payload("BTLEOOB:AQ")
+        );
+        assert_eq!(
+            Err(OobError::InvalidChecksum(0xdb0a)),
+            decode_qr_payload("BTLEOOB:AQIcAArb")
+        );
+
+        let mut data = vec![0x02, 0x02, 0x1c, 0x00];
+        let checksum = crc16(&data);
+        data.extend_from_slice(&checksum.to_le_bytes());
+        let mut value = OOB_QR_PREFIX.to_string();
+        base64_encode(&data, &mut value);
+        assert_eq!(
+            Err(OobError::UnsupportedVersion(2)),
+            decode_qr_payload(&value)
+        );
+    }
+
+    #[test]
+    fn test_crc16() {
+        assert_eq!(0x29b1, crc16(b"123456789"));
+        assert_eq!(0xffff, crc16(&[]));
+    }
+
+    #[test]
+    fn test_base64() {
+        let mut result = String::new();
+        base64_encode(b"foobar", &mut result);
+        assert_eq!("Zm9vYmFy", result);
+
+        let mut result = String::new();
+        base64_encode(&[0xfb, 0xff], &mut result);
+        assert_eq!("-_8", result);
+        assert_eq!(Some(vec![0xfb, 0xff]), base64_decode("-_8"));
+        assert_eq!(Some(b"fo".to_vec()), base64_decode("Zm8"));
+        assert_eq!(None, base64_decode("Zm8="));
+    }
+}
@@ -0,0 +1,170 @@
+//! Advertisement rotation planner module.
+
+use crate::data_types::{data_type::DataType, flags::Flags};
+
+/// Rotation plan.
+///
+/// AD structures split across multiple alternating advertising payloads.
+/// Every frame starts with the same [`Flags`] structure.
+#[derive(Debug, PartialEq, Clone)]
+pub struct RotationPlan {
+    /// Advertising payloads
+    pub frames: Vec<Vec<u8>>,
+}
+
+impl RotationPlan {
+    /// Create [`RotationPlan`] from AD structures and payload size budget.
+    ///
+    /// [`Flags`] structure is placed at the head of every frame.
+    /// Other structures are packed in order into the first frame that still has room.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ble_data_struct::data_types::{
+    ///     complete_local_name::CompleteLocalName, flags::Flags,
+    ///     service_data_16bit_uuid::ServiceData16BitUUID,
+    /// };
+    /// use ble_data_struct::payload::{
+    ///     rotation::RotationPlan, truncate::LEGACY_ADVERTISING_DATA_MAX_LENGTH,
+    /// };
+    /// use ble_data_struct::uuid_from_u16;
+    ///
+    /// let flags: Vec<u8> =
+    ///     Flags::new(&[false, true, false, false, false, false, false, false].to_vec()).into();
+    /// let name: Vec<u8> = CompleteLocalName::new(&"a".repeat(20)).into();
+    /// let service_data: Vec<u8> =
+    ///     ServiceData16BitUUID::new(&uuid_from_u16(0xfeaa), &[0u8; 18].to_vec()).into();
+    ///
+    /// let result = RotationPlan::new(
+    ///     &[name.clone(), flags.clone(), service_data.clone()],
+    ///     LEGACY_ADVERTISING_DATA_MAX_LENGTH,
+    /// );
+    /// assert!(result.is_ok());
+    /// let plan = result.unwrap();
+    /// assert_eq!(2, plan.frames.len());
+    /// assert_eq!([flags.clone(), name].concat(), plan.frames[0]);
+    /// assert_eq!([flags, service_data].concat(), plan.frames[1]);
+    ///
+    /// let result = RotationPlan::new(&[[0u8; 32].to_vec()], LEGACY_ADVERTISING_DATA_MAX_LENGTH);
+    /// assert!(result.is_err());
+    /// ```
+    pub fn new(structures: &[Vec<u8>], budget: usize) -> Result<Self, String> {
+        let flags: Vec<u8> = structures
+            .iter()
+            .find(|f| f.get(1) == Some(&Flags::data_type()))
+            .cloned()
+            .unwrap_or_default();
+        let mut frames: Vec<Vec<u8>> = Vec::new();
+        for structure in structures
+            .iter()
+            .filter(|f| f.get(1) != Some(&Flags::data_type()))
+        {
+            if flags.len() + structure.len() > budget {
+                return Err(format!("Invalid data size :{}", structure.len()));
+            }
+            match frames
+                .iter_mut()
+                .find(|f| f.len() + structure.len() <= budget)
+            {
+                Some(frame) => frame.extend_from_slice(structure),
+                None => frames.push([flags.as_slice(), structure.as_slice()].concat()),
+            }
+        }
+        if frames.is_empty() && !flags.is_empty() {
+            frames.push(flags);
+        }
+        Ok(Self { frames })
+    }
+
+    /// Returns the advertising payload for the rotation step.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ble_data_struct::data_types::tx_power_level::TxPowerLevel;
+    /// use ble_data_struct::payload::rotation::RotationPlan;
+    ///
+    /// let tx_power_level1: Vec<u8> = TxPowerLevel::new(1).into();
+    /// let tx_power_level2: Vec<u8> = TxPowerLevel::new(2).into();
+    /// let plan =
+    ///     RotationPlan::new(&[tx_power_level1.clone(), tx_power_level2.clone()], 3).unwrap();
+    /// assert_eq!(Some(&tx_power_level1), plan.frame(0));
+    /// assert_eq!(Some(&tx_power_level2), plan.frame(1));
+    /// assert_eq!(Some(&tx_power_level1), plan.frame(2));
+    ///
+    /// let plan = RotationPlan::new(&Vec::new(), 3).unwrap();
+    /// assert_eq!(None, plan.frame(0));
+    /// ```
+    pub fn frame(&self, step: usize) -> Option<&Vec<u8>> {
+        if self.frames.is_empty() {
+            None
+        } else {
+            self.frames.get(step % self.frames.len())
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        data_types::{
+            complete_local_name::CompleteLocalName, flags::Flags,
+            service_data_16bit_uuid::ServiceData16BitUUID, tx_power_level::TxPowerLevel,
+        },
+        payload::{rotation::RotationPlan, truncate::LEGACY_ADVERTISING_DATA_MAX_LENGTH},
+        uuid_from_u16,
+    };
+
+    #[test]
+    fn test_new() {
+        let flags: Vec<u8> =
+            Flags::new(&[false, true, false, false, false, false, false, false].to_vec()).into();
+        let name: Vec<u8> = CompleteLocalName::new(&"a".repeat(20)).into();
+        let service_data: Vec<u8> =
+            ServiceData16BitUUID::new(&uuid_from_u16(0xfeaa), &[0u8; 18].to_vec()).into();
+        let tx_power_level: Vec<u8> = TxPowerLevel::new(0).into();
+
+        let result = RotationPlan::new(
+            &[
+                name.clone(),
+                flags.clone(),
+                service_data.clone(),
+                tx_power_level.clone(),
+            ],
+            LEGACY_ADVERTISING_DATA_MAX_LENGTH,
+        );
+        assert!(result.is_ok());
+        let plan = result.unwrap();
+        assert_eq!(2, plan.frames.len());
+        assert_eq!(
+            [flags.clone(), name.clone(), tx_power_level.clone()].concat(),
+            plan.frames[0]
+        );
+        assert_eq!([flags.clone(), service_data].concat(), plan.frames[1]);
+
+        let plan = RotationPlan::new(
+            std::slice::from_ref(&flags),
+            LEGACY_ADVERTISING_DATA_MAX_LENGTH,
+        )
+        .unwrap();
+        assert_eq!(vec![flags], plan.frames);
+
+        let result = RotationPlan::new(&[[0u8; 32].to_vec()], LEGACY_ADVERTISING_DATA_MAX_LENGTH);
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn test_frame() {
+        let tx_power_level1: Vec<u8> = TxPowerLevel::new(1).into();
+        let tx_power_level2: Vec<u8> = TxPowerLevel::new(2).into();
+        let plan =
+            RotationPlan::new(&[tx_power_level1.clone(), tx_power_level2.clone()], 3).unwrap();
+        assert_eq!(Some(&tx_power_level1), plan.frame(0));
+        assert_eq!(Some(&tx_power_level2), plan.frame(1));
+        assert_eq!(Some(&tx_power_level1), plan.frame(2));
+
+        let plan = RotationPlan::new(&Vec::new(), 3).unwrap();
+        assert_eq!(None, plan.frame(0));
+    }
+}
@@ -0,0 +1,282 @@
+//! Advertising payload template module.
+//!
+//! Declare placeholders once, then patch their bytes on every render without re-serializing the payload.
+
+use std::ops::Range;
+
+use crate::payload::update::{find_service_data_16bit, find_structure};
+
+/// Advertising payload template.
+#[derive(Debug, PartialEq, Clone)]
+pub struct PayloadTemplate {
+    /// Serialized advertising payload
+    payload: Vec<u8>,
+
+    /// Placeholder byte ranges in the payload
+    placeholders: Vec<Range<usize>>,
+}
+
+impl PayloadTemplate {
+    /// Create [`PayloadTemplate`] from serialized advertising payload.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ble_data_struct::data_types::tx_power_level::TxPowerLevel;
+    /// use ble_data_struct::payload::template::PayloadTemplate;
+    ///
+    /// let payload: Vec<u8> = TxPowerLevel::new(0).into();
+    /// let result = PayloadTemplate::new(&payload);
+    /// assert_eq!(payload, result.payload());
+    /// assert!(result.placeholders().is_empty());
+    /// ```
+    pub fn new(payload: &[u8]) -> Self {
+        Self {
+            payload: payload.to_vec(),
+            placeholders: Vec::new(),
+        }
+    }
+
+    /// Current payload.
+    pub fn payload(&self) -> &[u8] {
+        &self.payload
+    }
+
+    /// Placeholder byte ranges in declaration order.
+    pub fn placeholders(&self) -> &[Range<usize>] {
+        &self.placeholders
+    }
+
+    /// Declare a placeholder in the first AD structure matching `data_type`.
+    ///
+    /// `offset` is relative to the data after the data type byte.
+    /// Returns the placeholder index.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ble_data_struct::data_types::{data_type::DataType, tx_power_level::TxPowerLevel};
+    /// use ble_data_struct::payload::template::PayloadTemplate;
+    ///
+    /// let payload: Vec<u8> = TxPowerLevel::new(0).into();
+    /// let mut template = PayloadTemplate::new(&payload);
+    /// assert_eq!(Ok(0), template.add_placeholder(TxPowerLevel::data_type(), 0, 1));
+    /// assert_eq!(vec![2..3], template.placeholders());
+    ///
+    /// assert!(template.add_placeholder(TxPowerLevel::data_type(), 1, 1).is_err());
+    /// assert!(template.add_placeholder(0xff, 0, 1).is_err());
+    /// ```
+    pub fn add_placeholder(
+        &mut self,
+        data_type: u8,
+        offset: usize,
+        length: usize,
+    ) -> Result<usize, String> {
+        let range = find_structure(&self.payload, data_type)
+            .ok_or_else(|| format!("Unknown data type :{}", data_type))?;
+        self.push(range, offset, length)
+    }
+
+    /// Declare a placeholder in the additional service data of the first
+    /// [`ServiceData16BitUUID`](crate::data_types::service_data_16bit_uuid::ServiceData16BitUUID) matching `uuid`.
+    ///
+    /// `offset` is relative to the additional service data (after the UUID).
+    /// Returns the placeholder index.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ble_data_struct::data_types::service_data_16bit_uuid::ServiceData16BitUUID;
+    /// use ble_data_struct::payload::template::PayloadTemplate;
+    /// use ble_data_struct::uuid_from_u16;
+    ///
+    /// let payload: Vec<u8> =
+    ///     ServiceData16BitUUID::new(&uuid_from_u16(0x180f), &[50].to_vec()).into();
+    /// let mut template = PayloadTemplate::new(&payload);
+    /// assert_eq!(Ok(0), template.add_service_data_16bit_placeholder(0x180f, 0, 1));
+    /// assert_eq!(vec![4..5], template.placeholders());
+    ///
+    /// assert!(template.add_service_data_16bit_placeholder(0x180f, 0, 2).is_err());
+    /// assert!(template.add_service_data_16bit_placeholder(0xfeaa, 0, 1).is_err());
+    /// ```
+    pub fn add_service_data_16bit_placeholder(
+        &mut self,
+        uuid: u16,
+        offset: usize,
+        length: usize,
+    ) -> Result<usize, String> {
+        let range = find_service_data_16bit(&self.payload, uuid)
+            .ok_or_else(|| format!("Unknown uuid :{}", uuid))?;
+        self.push(range, offset, length)
+    }
+
+    /// Patch every placeholder with `values` and return the payload.
+    ///
+    /// `values` are given in placeholder declaration order and each must have the placeholder length.
+    /// On error the payload is left unchanged.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ble_data_struct::data_types::{
+    ///     data_type::DataType, service_data_16bit_uuid::ServiceData16BitUUID,
+    ///     tx_power_level::TxPowerLevel,
+    /// };
+    /// use ble_data_struct::payload::template::PayloadTemplate;
+    /// use ble_data_struct::uuid_from_u16;
+    ///
+    /// let tx_power_level: Vec<u8> = TxPowerLevel::new(0).into();
+    /// let battery: Vec<u8> =
+    ///     ServiceData16BitUUID::new(&uuid_from_u16(0x180f), &[50].to_vec()).into();
+    /// let mut template = PayloadTemplate::new(&[tx_power_level, battery].concat());
+    /// template.add_placeholder(TxPowerLevel::data_type(), 0, 1).unwrap();
+    /// template.add_service_data_16bit_placeholder(0x180f, 0, 1).unwrap();
+    ///
+    /// let result = template.render(&[&[0xfc], &[40]]);
+    /// let tx_power_level: Vec<u8> = TxPowerLevel::new(-4).into();
+    /// let battery: Vec<u8> =
+    ///     ServiceData16BitUUID::new(&uuid_from_u16(0x180f), &[40].to_vec()).into();
+    /// assert_eq!(Ok([tx_power_level, battery].concat().as_slice()), result);
+    ///
+    /// assert!(template.render(&[&[0xfc]]).is_err());
+    /// assert!(template.render(&[&[0xfc], &[40, 0]]).is_err());
+    /// ```
+    pub fn render(&mut self, values: &[&[u8]]) -> Result<&[u8], String> {
+        if values.len() != self.placeholders.len() {
+            return Err(format!("Invalid placeholder count :{}", values.len()));
+        }
+        if let Some(value) = self
+            .placeholders
+            .iter()
+            .zip(values)
+            .find_map(|(range, value)| (range.len() != value.len()).then_some(value))
+        {
+            return Err(format!("Invalid data size :{}", value.len()));
+        }
+        self.placeholders
+            .iter()
+            .zip(values)
+            .for_each(|(range, value)| self.payload[range.clone()].copy_from_slice(value));
+        Ok(&self.payload)
+    }
+
+    /// Append the placeholder at `offset` of `range`.
+    fn push(&mut self, range: Range<usize>, offset: usize, length: usize) -> Result<usize, String> {
+        let end = offset
+            .checked_add(length)
+            .ok_or_else(|| format!("Invalid offset :{}", offset))?;
+        if end > range.len() {
+            return Err(format!("Invalid data size :{}", end));
+        }
+        let start = range.start + offset;
+        self.placeholders.push(start..start + length);
+        Ok(self.placeholders.len() - 1)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        data_types::{
+            data_type::DataType, service_data_16bit_uuid::ServiceData16BitUUID,
+            tx_power_level::TxPowerLevel,
+        },
+        payload::template::PayloadTemplate,
+        uuid_from_u16,
+    };
+
+    fn payload(tx_power_level: i8, battery_level: u8, count: u32) -> Vec<u8> {
+        let tx_power_level: Vec<u8> = TxPowerLevel::new(tx_power_level).into();
+        let battery: Vec<u8> =
+            ServiceData16BitUUID::new(&uuid_from_u16(0x180f), &[battery_level].to_vec()).into();
+        let mut tlm = [0x20u8, 0x00, 0x0b, 0xb8, 0x18, 0x00].to_vec();
+        tlm.append(&mut count.to_be_bytes().to_vec());
+        tlm.append(&mut [0u8; 4].to_vec());
+        let eddystone: Vec<u8> = ServiceData16BitUUID::new(&uuid_from_u16(0xfeaa), &tlm).into();
+        [tx_power_level, battery, eddystone].concat()
+    }
+
+    #[test]
+    fn test_new() {
+        let payload = payload(0, 50, 0);
+        let result = PayloadTemplate::new(&payload);
+        assert_eq!(payload, result.payload());
+        assert!(result.placeholders().is_empty());
+    }
+
+    #[test]
+    fn test_add_placeholder() {
+        let mut template = PayloadTemplate::new(&payload(0, 50, 0));
+        assert_eq!(
+            Ok(0),
+            template.add_placeholder(TxPowerLevel::data_type(), 0, 1)
+        );
+        assert_eq!(vec![2..3], template.placeholders());
+
+        assert_eq!(
+            Err("Invalid data size :2".to_string()),
+            template.add_placeholder(TxPowerLevel::data_type(), 1, 1)
+        );
+        assert_eq!(
+            Err("Unknown data type :255".to_string()),
+            template.add_placeholder(0xff, 0, 1)
+        );
+        assert_eq!(
+            Err(format!("Invalid offset :{}", usize::MAX)),
+            template.add_placeholder(TxPowerLevel::data_type(), usize::MAX, 1)
+        );
+        assert_eq!(1, template.placeholders().len());
+    }
+
+    #[test]
+    fn test_add_service_data_16bit_placeholder() {
+        let mut template = PayloadTemplate::new(&payload(0, 50, 0));
+        assert_eq!(
+            Ok(0),
+            template.add_service_data_16bit_placeholder(0x180f, 0, 1)
+        );
+        assert_eq!(
+            Ok(1),
+            template.add_service_data_16bit_placeholder(0xfeaa, 6, 4)
+        );
+        assert_eq!(vec![7..8, 18..22], template.placeholders());
+
+        assert_eq!(
+            Err("Invalid data size :15".to_string()),
+            template.add_service_data_16bit_placeholder(0xfeaa, 12, 3)
+        );
+        assert_eq!(
+            Err("Unknown uuid :6153".to_string()),
+            template.add_service_data_16bit_placeholder(0x1809, 0, 1)
+        );
+    }
+
+    #[test]
+    fn test_render() {
+        let mut template = PayloadTemplate::new(&payload(0, 50, 0));
+        template
+            .add_placeholder(TxPowerLevel::data_type(), 0, 1)
+            .unwrap();
+        template
+            .add_service_data_16bit_placeholder(0x180f, 0, 1)
+            .unwrap();
+        template
+            .add_service_data_16bit_placeholder(0xfeaa, 6, 4)
+            .unwrap();
+
+        for count in 1..4u32 {
+            let result = template.render(&[&[0xfc], &[40], &count.to_be_bytes()]);
+            assert_eq!(Ok(payload(-4, 40, count).as_slice()), result);
+        }
+
+        assert_eq!(
+            Err("Invalid placeholder count :2".to_string()),
+            template.render(&[&[0xfc], &[40]])
+        );
+        assert_eq!(
+            Err("Invalid data size :2".to_string()),
+            template.render(&[&[0x00], &[40, 0], &[0; 4]])
+        );
+        assert_eq!(payload(-4, 40, 3), template.payload());
+    }
+}
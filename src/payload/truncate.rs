@@ -0,0 +1,111 @@
+//! Size-aware AD structure truncation module.
+
+/// Legacy advertising / scan response data max length.
+pub const LEGACY_ADVERTISING_DATA_MAX_LENGTH: usize = 31;
+
+/// Extended advertising data max length.
+pub const EXTENDED_ADVERTISING_DATA_MAX_LENGTH: usize = 1650;
+
+/// [`truncate_payload`] result.
+#[derive(Debug, PartialEq, Clone)]
+pub struct TruncatedPayload {
+    /// Concatenated AD structures that fit into the budget
+    pub payload: Vec<u8>,
+
+    /// Indexes of the included AD structures
+    pub included: Vec<usize>,
+
+    /// Indexes of the dropped AD structures
+    pub dropped: Vec<usize>,
+}
+
+/// Concatenate AD structures ordered by priority until the size budget is reached.
+///
+/// Each structure is included if it still fits into the remaining budget, otherwise it is dropped.
+/// Lower priority structures are still tried after a drop, so a small structure can fill the rest of the budget.
+///
+/// # Examples
+///
+/// ```
+/// use ble_data_struct::data_types::{
+///     complete_local_name::CompleteLocalName, flags::Flags, tx_power_level::TxPowerLevel,
+/// };
+/// use ble_data_struct::payload::truncate::{
+///     truncate_payload, LEGACY_ADVERTISING_DATA_MAX_LENGTH,
+/// };
+///
+/// let flags: Vec<u8> = Flags::new(&[false, true, false, false, false, false, false, false].to_vec()).into();
+/// let name: Vec<u8> = CompleteLocalName::new(&"a".repeat(27)).into();
+/// let tx_power_level: Vec<u8> = TxPowerLevel::new(0).into();
+/// let structures = vec![flags.clone(), name, tx_power_level.clone()];
+///
+/// let result = truncate_payload(&structures, LEGACY_ADVERTISING_DATA_MAX_LENGTH);
+/// assert_eq!([flags, tx_power_level].concat(), result.payload);
+/// assert_eq!(vec![0, 2], result.included);
+/// assert_eq!(vec![1], result.dropped);
+/// ```
+pub fn truncate_payload(structures: &[Vec<u8>], budget: usize) -> TruncatedPayload {
+    let mut payload: Vec<u8> = Vec::with_capacity(budget.min(EXTENDED_ADVERTISING_DATA_MAX_LENGTH));
+    let mut included: Vec<usize> = Vec::new();
+    let mut dropped: Vec<usize> = Vec::new();
+    structures
+        .iter()
+        .enumerate()
+        .for_each(|(index, structure)| {
+            if payload.len() + structure.len() <= budget {
+                payload.extend_from_slice(structure);
+                included.push(index);
+            } else {
+                dropped.push(index);
+            }
+        });
+    TruncatedPayload {
+        payload,
+        included,
+        dropped,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        data_types::{
+            complete_local_name::CompleteLocalName, flags::Flags, tx_power_level::TxPowerLevel,
+        },
+        payload::truncate::{truncate_payload, LEGACY_ADVERTISING_DATA_MAX_LENGTH},
+    };
+
+    #[test]
+    fn test_truncate_payload() {
+        let flags: Vec<u8> =
+            Flags::new(&[false, true, false, false, false, false, false, false].to_vec()).into();
+        let name: Vec<u8> = CompleteLocalName::new(&"a".repeat(27)).into();
+        let tx_power_level: Vec<u8> = TxPowerLevel::new(0).into();
+        let structures = vec![flags.clone(), name.clone(), tx_power_level.clone()];
+
+        let result = truncate_payload(&structures, LEGACY_ADVERTISING_DATA_MAX_LENGTH);
+        assert_eq!(
+            [flags.clone(), tx_power_level.clone()].concat(),
+            result.payload
+        );
+        assert_eq!(vec![0, 2], result.included);
+        assert_eq!(vec![1], result.dropped);
+
+        let name: Vec<u8> = CompleteLocalName::new(&"a".repeat(26)).into();
+        let structures = vec![flags.clone(), name.clone(), tx_power_level.clone()];
+        let result = truncate_payload(&structures, LEGACY_ADVERTISING_DATA_MAX_LENGTH);
+        assert_eq!([flags, name].concat(), result.payload);
+        assert_eq!(vec![0, 1], result.included);
+        assert_eq!(vec![2], result.dropped);
+
+        let result = truncate_payload(&structures, 0);
+        assert!(result.payload.is_empty());
+        assert!(result.included.is_empty());
+        assert_eq!(vec![0, 1, 2], result.dropped);
+
+        let result = truncate_payload(&structures, usize::MAX);
+        assert_eq!(structures.concat(), result.payload);
+        assert_eq!(vec![0, 1, 2], result.included);
+        assert!(result.dropped.is_empty());
+    }
+}
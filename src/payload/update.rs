@@ -0,0 +1,235 @@
+//! In-place advertising payload update module.
+//!
+//! Patch variable fields (battery, temperature, counters, ...) of a serialized payload without re-encoding
+//! or re-allocating it.
+
+use std::ops::Range;
+
+use crate::data_types::{data_type::DataType, service_data_16bit_uuid::ServiceData16BitUUID};
+
+/// Find the data range (after the data type byte) of the first AD structure matching `data_type`.
+///
+/// # Examples
+///
+/// ```
+/// use ble_data_struct::data_types::{
+///     complete_local_name::CompleteLocalName, data_type::DataType, tx_power_level::TxPowerLevel,
+/// };
+/// use ble_data_struct::payload::update::find_structure;
+///
+/// let name: Vec<u8> = CompleteLocalName::new(&"ab".to_string()).into();
+/// let tx_power_level: Vec<u8> = TxPowerLevel::new(0).into();
+/// let payload = [name, tx_power_level].concat();
+///
+/// assert_eq!(Some(2..4), find_structure(&payload, CompleteLocalName::data_type()));
+/// assert_eq!(Some(6..7), find_structure(&payload, TxPowerLevel::data_type()));
+/// assert_eq!(None, find_structure(&payload, 0xff));
+/// ```
+pub fn find_structure(payload: &[u8], data_type: u8) -> Option<Range<usize>> {
+    let mut index = 0;
+    while index + 1 < payload.len() {
+        let length = payload[index] as usize;
+        if length == 0 {
+            return None;
+        }
+        let end = index + 1 + length;
+        if end > payload.len() {
+            return None;
+        }
+        if payload[index + 1] == data_type {
+            return Some(index + 2..end);
+        }
+        index = end;
+    }
+    None
+}
+
+/// Overwrite bytes of the first AD structure matching `data_type`.
+///
+/// `offset` is relative to the data after the data type byte.
+///
+/// # Examples
+///
+/// ```
+/// use ble_data_struct::data_types::{data_type::DataType, tx_power_level::TxPowerLevel};
+/// use ble_data_struct::payload::update::update_structure;
+///
+/// let mut payload: Vec<u8> = TxPowerLevel::new(0).into();
+/// assert!(update_structure(&mut payload, TxPowerLevel::data_type(), 0, &[0xfc]).is_ok());
+/// let expected: Vec<u8> = TxPowerLevel::new(-4).into();
+/// assert_eq!(expected, payload);
+///
+/// assert!(update_structure(&mut payload, TxPowerLevel::data_type(), 1, &[0x00]).is_err());
+/// assert!(update_structure(&mut payload, 0xff, 0, &[0x00]).is_err());
+/// ```
+pub fn update_structure(
+    payload: &mut [u8],
+    data_type: u8,
+    offset: usize,
+    value: &[u8],
+) -> Result<(), String> {
+    let range = find_structure(payload, data_type)
+        .ok_or_else(|| format!("Unknown data type :{}", data_type))?;
+    write(payload, range, offset, value)
+}
+
+/// Find the additional service data range (after the UUID) of the first [`ServiceData16BitUUID`] matching `uuid`.
+///
+/// # Examples
+///
+/// ```
+/// use ble_data_struct::data_types::service_data_16bit_uuid::ServiceData16BitUUID;
+/// use ble_data_struct::payload::update::find_service_data_16bit;
+/// use ble_data_struct::uuid_from_u16;
+///
+/// let payload: Vec<u8> = ServiceData16BitUUID::new(&uuid_from_u16(0x180f), &[50].to_vec()).into();
+/// assert_eq!(Some(4..5), find_service_data_16bit(&payload, 0x180f));
+/// assert_eq!(None, find_service_data_16bit(&payload, 0xfeaa));
+/// ```
+pub fn find_service_data_16bit(payload: &[u8], uuid: u16) -> Option<Range<usize>> {
+    let mut index = 0;
+    while let Some(range) = find_structure(&payload[index..], ServiceData16BitUUID::data_type()) {
+        let range = index + range.start..index + range.end;
+        if range.len() >= 2 && payload[range.start..range.start + 2] == uuid.to_le_bytes() {
+            return Some(range.start + 2..range.end);
+        }
+        index = range.end;
+    }
+    None
+}
+
+/// Overwrite bytes of the additional service data of the first [`ServiceData16BitUUID`] matching `uuid`.
+///
+/// `offset` is relative to the additional service data (after the UUID).
+///
+/// # Examples
+///
+/// ```
+/// use ble_data_struct::data_types::service_data_16bit_uuid::ServiceData16BitUUID;
+/// use ble_data_struct::payload::update::update_service_data_16bit;
+/// use ble_data_struct::uuid_from_u16;
+///
+/// // Eddystone-TLM: version, battery voltage, beacon temperature, advertising PDU count, time since power-on
+/// let mut tlm = [0x20u8, 0x00].to_vec();
+/// tlm.append(&mut [0u8; 12].to_vec());
+/// let mut payload: Vec<u8> = ServiceData16BitUUID::new(&uuid_from_u16(0xfeaa), &tlm).into();
+///
+/// assert!(update_service_data_16bit(&mut payload, 0xfeaa, 2, &3000u16.to_be_bytes()).is_ok());
+/// tlm[2..4].copy_from_slice(&3000u16.to_be_bytes());
+/// let expected: Vec<u8> = ServiceData16BitUUID::new(&uuid_from_u16(0xfeaa), &tlm).into();
+/// assert_eq!(expected, payload);
+///
+/// assert!(update_service_data_16bit(&mut payload, 0x180f, 0, &[0x00]).is_err());
+/// ```
+pub fn update_service_data_16bit(
+    payload: &mut [u8],
+    uuid: u16,
+    offset: usize,
+    value: &[u8],
+) -> Result<(), String> {
+    let range =
+        find_service_data_16bit(payload, uuid).ok_or_else(|| format!("Unknown uuid :{}", uuid))?;
+    write(payload, range, offset, value)
+}
+
+/// Copy `value` into `range` of `payload` at `offset`.
+fn write(
+    payload: &mut [u8],
+    range: Range<usize>,
+    offset: usize,
+    value: &[u8],
+) -> Result<(), String> {
+    let end = offset
+        .checked_add(value.len())
+        .ok_or_else(|| format!("Invalid offset :{}", offset))?;
+    if end > range.len() {
+        return Err(format!("Invalid data size :{}", end));
+    }
+    let start = range.start + offset;
+    payload[start..start + value.len()].copy_from_slice(value);
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        data_types::{
+            complete_local_name::CompleteLocalName, data_type::DataType,
+            service_data_16bit_uuid::ServiceData16BitUUID, tx_power_level::TxPowerLevel,
+        },
+        payload::update::{
+            find_service_data_16bit, find_structure, update_service_data_16bit, update_structure,
+        },
+        uuid_from_u16,
+    };
+
+    #[test]
+    fn test_find_structure() {
+        let name: Vec<u8> = CompleteLocalName::new(&"ab".to_string()).into();
+        let tx_power_level: Vec<u8> = TxPowerLevel::new(0).into();
+        let payload = [name, tx_power_level].concat();
+
+        assert_eq!(
+            Some(2..4),
+            find_structure(&payload, CompleteLocalName::data_type())
+        );
+        assert_eq!(
+            Some(6..7),
+            find_structure(&payload, TxPowerLevel::data_type())
+        );
+        assert_eq!(None, find_structure(&payload, 0xff));
+        assert_eq!(None, find_structure(&[0x00, 0x0a, 0x00], 0x0a));
+        assert_eq!(None, find_structure(&[0x05, 0x0a, 0x00], 0x0a));
+    }
+
+    #[test]
+    fn test_find_service_data_16bit() {
+        let battery: Vec<u8> =
+            ServiceData16BitUUID::new(&uuid_from_u16(0x180f), &[50].to_vec()).into();
+        let eddystone: Vec<u8> =
+            ServiceData16BitUUID::new(&uuid_from_u16(0xfeaa), &[0x20, 0x00].to_vec()).into();
+        let payload = [battery, eddystone].concat();
+
+        assert_eq!(Some(4..5), find_service_data_16bit(&payload, 0x180f));
+        assert_eq!(Some(9..11), find_service_data_16bit(&payload, 0xfeaa));
+        assert_eq!(None, find_service_data_16bit(&payload, 0x1809));
+    }
+
+    #[test]
+    fn test_update_structure() {
+        let mut payload: Vec<u8> = TxPowerLevel::new(0).into();
+        assert!(update_structure(&mut payload, TxPowerLevel::data_type(), 0, &[0xfc]).is_ok());
+        let expected: Vec<u8> = TxPowerLevel::new(-4).into();
+        assert_eq!(expected, payload);
+
+        assert!(update_structure(&mut payload, TxPowerLevel::data_type(), 1, &[0x00]).is_err());
+        assert!(update_structure(&mut payload, 0xff, 0, &[0x00]).is_err());
+        assert_eq!(
+            Err(format!("Invalid offset :{}", usize::MAX)),
+            update_structure(&mut payload, TxPowerLevel::data_type(), usize::MAX, &[0x00])
+        );
+    }
+
+    #[test]
+    fn test_update_service_data_16bit() {
+        let battery: Vec<u8> =
+            ServiceData16BitUUID::new(&uuid_from_u16(0x180f), &[50].to_vec()).into();
+        let mut tlm = [0x20u8, 0x00].to_vec();
+        tlm.append(&mut [0u8; 12].to_vec());
+        let eddystone: Vec<u8> = ServiceData16BitUUID::new(&uuid_from_u16(0xfeaa), &tlm).into();
+        let mut payload = [battery.clone(), eddystone].concat();
+
+        assert!(update_service_data_16bit(&mut payload, 0xfeaa, 2, &3000u16.to_be_bytes()).is_ok());
+        tlm[2..4].copy_from_slice(&3000u16.to_be_bytes());
+        let eddystone: Vec<u8> = ServiceData16BitUUID::new(&uuid_from_u16(0xfeaa), &tlm).into();
+        assert_eq!([battery.clone(), eddystone.clone()].concat(), payload);
+
+        assert!(update_service_data_16bit(&mut payload, 0x180f, 0, &[40]).is_ok());
+        let battery: Vec<u8> =
+            ServiceData16BitUUID::new(&uuid_from_u16(0x180f), &[40].to_vec()).into();
+        assert_eq!([battery, eddystone].concat(), payload);
+
+        assert!(update_service_data_16bit(&mut payload, 0x180f, 1, &[40]).is_err());
+        assert!(update_service_data_16bit(&mut payload, 0x1809, 0, &[0x00]).is_err());
+    }
+}
@@ -0,0 +1,75 @@
+//! Batch parsing module.
+//!
+//! Parse many captured payloads (e.g. from pcap files) at once.
+//! With the `rayon` feature the payloads are parsed in parallel.
+
+#[cfg(feature = "rayon")]
+use rayon::prelude::*;
+
+use crate::data_types::data_type_parser::DataTypeParseResults;
+
+/// Parse advertising payloads.
+///
+/// Results are returned in the same order as `payloads`, with or without the `rayon` feature.
+///
+/// # Examples
+///
+/// ```
+/// use ble_data_struct::{
+///     data_types::{data_type_parser::DataTypeParseResults, tx_power_level::TxPowerLevel},
+///     scan::batch::parse_batch,
+/// };
+///
+/// let payload1: Vec<u8> = TxPowerLevel::new(-4).into();
+/// let payload2: Vec<u8> = TxPowerLevel::new(4).into();
+/// let results = parse_batch(&[&payload1, &payload2]);
+/// assert_eq!(
+///     vec![
+///         DataTypeParseResults::from(&payload1),
+///         DataTypeParseResults::from(&payload2)
+///     ],
+///     results
+/// );
+/// ```
+pub fn parse_batch(payloads: &[&[u8]]) -> Vec<DataTypeParseResults> {
+    #[cfg(feature = "rayon")]
+    let iter = payloads.par_iter();
+    #[cfg(not(feature = "rayon"))]
+    let iter = payloads.iter();
+    iter.map(|f| DataTypeParseResults::from(*f)).collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        data_types::{
+            complete_local_name::CompleteLocalName, data_type_parser::DataTypeParseResults,
+            tx_power_level::TxPowerLevel,
+        },
+        scan::batch::parse_batch,
+    };
+
+    #[test]
+    fn test_parse_batch() {
+        let payloads: Vec<Vec<u8>> = (0..1000)
+            .map(|f| {
+                if f % 2 == 0 {
+                    TxPowerLevel::new((f % 100) as i8).into()
+                } else {
+                    CompleteLocalName::new(&f.to_string()).into()
+                }
+            })
+            .collect();
+        let slices: Vec<&[u8]> = payloads.iter().map(|f| f.as_slice()).collect();
+        let results = parse_batch(&slices);
+        assert_eq!(
+            payloads
+                .iter()
+                .map(DataTypeParseResults::from)
+                .collect::<Vec<_>>(),
+            results
+        );
+
+        assert!(parse_batch(&[]).is_empty());
+    }
+}
@@ -0,0 +1,516 @@
+//! Scan record delta compression module.
+//!
+//! Consecutive records of the same device usually repeat most AD structures,
+//! so only the changed structures are stored between periodic keyframes.
+
+use std::collections::HashMap;
+
+use crate::{
+    data_types::{
+        address_type::AddressType,
+        data_type_parser::{DataTypeParseResult, DataTypeParseResults},
+    },
+    scan::scan_record::ScanRecord,
+};
+
+/// Default number of delta records between keyframes.
+pub const DEFAULT_KEYFRAME_INTERVAL: usize = 16;
+
+/// Delta compressed [`ScanRecord`].
+#[derive(Debug, PartialEq, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum DeltaScanRecord {
+    /// Full record.
+    Keyframe(ScanRecord),
+
+    /// Changes from the previous record of the same device.
+    Delta {
+        /// Bluetooth Device Address
+        address: u64,
+
+        /// Bluetooth Device Address type(`None` if unknown)
+        address_type: Option<AddressType>,
+
+        /// RSSI(dBm)
+        rssi: i16,
+
+        /// number of parse results
+        length: usize,
+
+        /// changed parse results (index, parse result)
+        changes: Vec<(usize, DataTypeParseResult)>,
+    },
+}
+
+impl DeltaScanRecord {
+    /// Bluetooth Device Address.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ble_data_struct::{
+    ///     data_types::data_type_parser::DataTypeParseResults,
+    ///     scan::{delta::DeltaScanRecord, scan_record::ScanRecord},
+    /// };
+    ///
+    /// let record = ScanRecord::new(0x0000060504030201u64, -60, DataTypeParseResults::new(Vec::new()));
+    /// assert_eq!(0x0000060504030201u64, DeltaScanRecord::Keyframe(record).address());
+    /// ```
+    pub fn address(&self) -> u64 {
+        match self {
+            DeltaScanRecord::Keyframe(record) => record.address,
+            DeltaScanRecord::Delta { address, .. } => *address,
+        }
+    }
+
+    /// Check keyframe.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ble_data_struct::{
+    ///     data_types::data_type_parser::DataTypeParseResults,
+    ///     scan::{delta::DeltaScanRecord, scan_record::ScanRecord},
+    /// };
+    ///
+    /// let record = ScanRecord::new(0x0000060504030201u64, -60, DataTypeParseResults::new(Vec::new()));
+    /// assert!(DeltaScanRecord::Keyframe(record).is_keyframe());
+    /// ```
+    pub fn is_keyframe(&self) -> bool {
+        matches!(self, DeltaScanRecord::Keyframe(_))
+    }
+}
+
+/// [`ScanRecord`] delta encoder.
+///
+/// Previous record is kept per `Bluetooth Device Address`.
+#[derive(Debug, Clone)]
+pub struct DeltaEncoder {
+    /// Max number of delta records between keyframes(`0` for keyframes only)
+    pub keyframe_interval: usize,
+
+    /// Previous record and number of delta records since the keyframe
+    previous: HashMap<u64, (ScanRecord, usize)>,
+}
+
+impl DeltaEncoder {
+    /// Create [`DeltaEncoder`] from keyframe interval.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ble_data_struct::scan::delta::DeltaEncoder;
+    ///
+    /// let result = DeltaEncoder::new(8);
+    /// assert_eq!(8, result.keyframe_interval);
+    /// assert!(result.is_empty());
+    /// ```
+    pub fn new(keyframe_interval: usize) -> Self {
+        Self {
+            keyframe_interval,
+            previous: HashMap::new(),
+        }
+    }
+
+    /// Encode [`ScanRecord`].
+    ///
+    /// The first record of a device and every record after `keyframe_interval` deltas are keyframes.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ble_data_struct::{
+    ///     data_types::{complete_local_name::CompleteLocalName, tx_power_level::TxPowerLevel},
+    ///     scan::{
+    ///         delta::{DeltaEncoder, DeltaScanRecord},
+    ///         scan_record::ScanRecord,
+    ///     },
+    /// };
+    ///
+    /// let name: Vec<u8> = CompleteLocalName::new(&"name".to_string()).into();
+    /// let tx_power_level: Vec<u8> = TxPowerLevel::new(-4).into();
+    /// let record1 = ScanRecord::from_payload(0x01, -60, &[name.clone(), tx_power_level].concat());
+    /// let record2 = ScanRecord::from_payload(0x01, -62, &[name, TxPowerLevel::new(-8).into()].concat());
+    ///
+    /// let mut encoder = DeltaEncoder::default();
+    /// assert!(encoder.encode(&record1).is_keyframe());
+    /// match encoder.encode(&record2) {
+    ///     DeltaScanRecord::Delta { rssi, length, changes, .. } => {
+    ///         assert_eq!(-62, rssi);
+    ///         assert_eq!(2, length);
+    ///         assert_eq!(vec![(1, record2.results.results[1].clone())], changes);
+    ///     }
+    ///     _ => unreachable!(),
+    /// }
+    /// ```
+    pub fn encode(&mut self, record: &ScanRecord) -> DeltaScanRecord {
+        let keyframe_interval = self.keyframe_interval;
+        if let Some((previous, count)) = self.previous.get_mut(&record.address) {
+            if *count < keyframe_interval {
+                let results = &previous.results.results;
+                let changes = record
+                    .results
+                    .results
+                    .iter()
+                    .enumerate()
+                    .filter(|(index, result)| results.get(*index) != Some(*result))
+                    .map(|(index, result)| (index, result.clone()))
+                    .collect();
+                *previous = record.clone();
+                *count += 1;
+                return DeltaScanRecord::Delta {
+                    address: record.address,
+                    address_type: record.address_type,
+                    rssi: record.rssi,
+                    length: record.results.results.len(),
+                    changes,
+                };
+            }
+        }
+        self.previous.insert(record.address, (record.clone(), 0));
+        DeltaScanRecord::Keyframe(record.clone())
+    }
+
+    /// Remove the previous record of device (next record is encoded as keyframe).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ble_data_struct::{
+    ///     data_types::data_type_parser::DataTypeParseResults,
+    ///     scan::{delta::DeltaEncoder, scan_record::ScanRecord},
+    /// };
+    ///
+    /// let record = ScanRecord::new(0x01, -60, DataTypeParseResults::new(Vec::new()));
+    /// let mut encoder = DeltaEncoder::default();
+    /// encoder.encode(&record);
+    /// encoder.forget(0x01);
+    /// assert!(encoder.encode(&record).is_keyframe());
+    /// ```
+    pub fn forget(&mut self, address: u64) {
+        self.previous.remove(&address);
+    }
+
+    /// Number of tracked devices.
+    pub fn len(&self) -> usize {
+        self.previous.len()
+    }
+
+    /// Check no device is tracked.
+    pub fn is_empty(&self) -> bool {
+        self.previous.is_empty()
+    }
+
+    /// Remove all previous records.
+    pub fn clear(&mut self) {
+        self.previous.clear();
+    }
+}
+
+impl Default for DeltaEncoder {
+    /// Create [`DeltaEncoder`] with [`DEFAULT_KEYFRAME_INTERVAL`].
+    fn default() -> Self {
+        Self::new(DEFAULT_KEYFRAME_INTERVAL)
+    }
+}
+
+/// [`DeltaScanRecord`] decoder.
+///
+/// Previous record is kept per `Bluetooth Device Address`.
+#[derive(Debug, Clone, Default)]
+pub struct DeltaDecoder {
+    /// Previous record
+    previous: HashMap<u64, ScanRecord>,
+}
+
+impl DeltaDecoder {
+    /// Create [`DeltaDecoder`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ble_data_struct::scan::delta::DeltaDecoder;
+    ///
+    /// let result = DeltaDecoder::new();
+    /// assert!(result.is_empty());
+    /// ```
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Decode [`DeltaScanRecord`].
+    ///
+    /// Fails if a delta record arrives before the keyframe of the device.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ble_data_struct::{
+    ///     data_types::{complete_local_name::CompleteLocalName, tx_power_level::TxPowerLevel},
+    ///     scan::{
+    ///         delta::{DeltaDecoder, DeltaEncoder},
+    ///         scan_record::ScanRecord,
+    ///     },
+    /// };
+    ///
+    /// let name: Vec<u8> = CompleteLocalName::new(&"name".to_string()).into();
+    /// let tx_power_level: Vec<u8> = TxPowerLevel::new(-4).into();
+    /// let record1 = ScanRecord::from_payload(0x01, -60, &[name.clone(), tx_power_level].concat());
+    /// let record2 = ScanRecord::from_payload(0x01, -62, &name);
+    ///
+    /// let mut encoder = DeltaEncoder::default();
+    /// let delta1 = encoder.encode(&record1);
+    /// let delta2 = encoder.encode(&record2);
+    ///
+    /// let mut decoder = DeltaDecoder::new();
+    /// assert!(decoder.decode(&delta2).is_err());
+    /// assert_eq!(Ok(record1), decoder.decode(&delta1));
+    /// assert_eq!(Ok(record2), decoder.decode(&delta2));
+    /// ```
+    pub fn decode(&mut self, delta: &DeltaScanRecord) -> Result<ScanRecord, String> {
+        match delta {
+            DeltaScanRecord::Keyframe(record) => {
+                self.previous.insert(record.address, record.clone());
+                Ok(record.clone())
+            }
+            DeltaScanRecord::Delta {
+                address,
+                address_type,
+                rssi,
+                length,
+                changes,
+            } => {
+                let previous = match self.previous.get(address) {
+                    Some(previous) => previous,
+                    None => return Err(format!("Keyframe not found :{}", address)),
+                };
+                let mut results: Vec<Option<DataTypeParseResult>> = (0..*length)
+                    .map(|index| previous.results.results.get(index).cloned())
+                    .collect();
+                for (index, result) in changes {
+                    match results.get_mut(*index) {
+                        Some(entry) => *entry = Some(result.clone()),
+                        None => return Err(format!("Invalid index :{}", index)),
+                    }
+                }
+                let results = match results.into_iter().collect::<Option<Vec<_>>>() {
+                    Some(results) => results,
+                    None => return Err(format!("Missing parse result :{}", length)),
+                };
+                let record = ScanRecord {
+                    address: *address,
+                    address_type: *address_type,
+                    rssi: *rssi,
+                    results: DataTypeParseResults::new(results),
+                };
+                self.previous.insert(*address, record.clone());
+                Ok(record)
+            }
+        }
+    }
+
+    /// Remove the previous record of device.
+    pub fn forget(&mut self, address: u64) {
+        self.previous.remove(&address);
+    }
+
+    /// Number of tracked devices.
+    pub fn len(&self) -> usize {
+        self.previous.len()
+    }
+
+    /// Check no device is tracked.
+    pub fn is_empty(&self) -> bool {
+        self.previous.is_empty()
+    }
+
+    /// Remove all previous records.
+    pub fn clear(&mut self) {
+        self.previous.clear();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        data_types::{
+            address_type::AddressType, complete_local_name::CompleteLocalName,
+            data_type_parser::DataTypeParseResults, tx_power_level::TxPowerLevel,
+        },
+        scan::{
+            delta::{DeltaDecoder, DeltaEncoder, DeltaScanRecord, DEFAULT_KEYFRAME_INTERVAL},
+            scan_record::ScanRecord,
+        },
+    };
+
+    fn records() -> Vec<ScanRecord> {
+        let name: Vec<u8> = CompleteLocalName::new(&"name".to_string()).into();
+        let tx_power_level1: Vec<u8> = TxPowerLevel::new(-4).into();
+        let tx_power_level2: Vec<u8> = TxPowerLevel::new(-8).into();
+        vec![
+            ScanRecord::from_payload(0x01, -60, &[name.clone(), tx_power_level1.clone()].concat()),
+            ScanRecord::from_payload(0x02, -70, &name),
+            ScanRecord::from_payload(0x01, -61, &[name.clone(), tx_power_level1.clone()].concat()),
+            ScanRecord::from_payload(0x01, -62, &[name.clone(), tx_power_level2].concat())
+                .with_address_type(AddressType::Public),
+            ScanRecord::from_payload(0x01, -63, &name),
+            ScanRecord::from_payload(0x01, -64, &[name.clone(), tx_power_level1, name].concat()),
+            ScanRecord::from_payload(0x01, -65, &vec![0x05, 0x0a]),
+            ScanRecord::new(0x01, -66, DataTypeParseResults::new(Vec::new())),
+        ]
+    }
+
+    #[test]
+    fn test_new() {
+        let result = DeltaEncoder::new(8);
+        assert_eq!(8, result.keyframe_interval);
+        assert!(result.is_empty());
+        assert_eq!(
+            DEFAULT_KEYFRAME_INTERVAL,
+            DeltaEncoder::default().keyframe_interval
+        );
+        assert!(DeltaDecoder::new().is_empty());
+    }
+
+    #[test]
+    fn test_encode() {
+        let records = records();
+        let mut encoder = DeltaEncoder::default();
+        let deltas: Vec<DeltaScanRecord> = records.iter().map(|f| encoder.encode(f)).collect();
+        assert_eq!(2, encoder.len());
+        assert!(deltas[0].is_keyframe());
+        assert!(deltas[1].is_keyframe());
+        assert_eq!(
+            DeltaScanRecord::Delta {
+                address: 0x01,
+                address_type: None,
+                rssi: -61,
+                length: 2,
+                changes: Vec::new(),
+            },
+            deltas[2]
+        );
+        assert_eq!(
+            DeltaScanRecord::Delta {
+                address: 0x01,
+                address_type: Some(AddressType::Public),
+                rssi: -62,
+                length: 2,
+                changes: vec![(1, records[3].results.results[1].clone())],
+            },
+            deltas[3]
+        );
+        assert_eq!(
+            DeltaScanRecord::Delta {
+                address: 0x01,
+                address_type: None,
+                rssi: -63,
+                length: 1,
+                changes: Vec::new(),
+            },
+            deltas[4]
+        );
+        assert_eq!(
+            DeltaScanRecord::Delta {
+                address: 0x01,
+                address_type: None,
+                rssi: -64,
+                length: 3,
+                changes: vec![
+                    (1, records[5].results.results[1].clone()),
+                    (2, records[5].results.results[2].clone()),
+                ],
+            },
+            deltas[5]
+        );
+        assert_eq!(0x01, deltas[5].address());
+        assert_eq!(0x02, deltas[1].address());
+
+        encoder.clear();
+        assert!(encoder.is_empty());
+        assert!(encoder.encode(&records[2]).is_keyframe());
+        encoder.forget(0x01);
+        assert!(encoder.encode(&records[2]).is_keyframe());
+    }
+
+    #[test]
+    fn test_encode_keyframe_interval() {
+        let records = records();
+        let mut encoder = DeltaEncoder::new(2);
+        let keyframes: Vec<bool> = records
+            .iter()
+            .filter(|f| f.address == 0x01)
+            .map(|f| encoder.encode(f).is_keyframe())
+            .collect();
+        assert_eq!(
+            vec![true, false, false, true, false, false, true],
+            keyframes
+        );
+
+        let mut encoder = DeltaEncoder::new(0);
+        assert!(records.iter().all(|f| encoder.encode(f).is_keyframe()));
+    }
+
+    #[test]
+    fn test_decode() {
+        let records = records();
+        for keyframe_interval in [0, 1, 3, DEFAULT_KEYFRAME_INTERVAL] {
+            let mut encoder = DeltaEncoder::new(keyframe_interval);
+            let mut decoder = DeltaDecoder::new();
+            for record in &records {
+                let delta = encoder.encode(record);
+                assert_eq!(Ok(record.clone()), decoder.decode(&delta));
+            }
+            assert_eq!(2, decoder.len());
+        }
+    }
+
+    #[test]
+    fn test_decode_error() {
+        let records = records();
+        let mut encoder = DeltaEncoder::default();
+        let deltas: Vec<DeltaScanRecord> = records.iter().map(|f| encoder.encode(f)).collect();
+
+        let mut decoder = DeltaDecoder::new();
+        assert!(decoder.decode(&deltas[2]).is_err());
+        assert!(decoder.decode(&deltas[0]).is_ok());
+        decoder.forget(0x01);
+        assert!(decoder.decode(&deltas[2]).is_err());
+        assert!(decoder.decode(&deltas[0]).is_ok());
+        decoder.clear();
+        assert!(decoder.is_empty());
+
+        assert!(decoder.decode(&deltas[0]).is_ok());
+        let invalid_index = DeltaScanRecord::Delta {
+            address: 0x01,
+            address_type: None,
+            rssi: -60,
+            length: 1,
+            changes: vec![(1, records[0].results.results[0].clone())],
+        };
+        assert!(decoder.decode(&invalid_index).is_err());
+        let missing = DeltaScanRecord::Delta {
+            address: 0x01,
+            address_type: None,
+            rssi: -60,
+            length: 3,
+            changes: Vec::new(),
+        };
+        assert!(decoder.decode(&missing).is_err());
+        assert_eq!(Ok(records[2].clone()), decoder.decode(&deltas[2]));
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_serde() {
+        let records = records();
+        let mut encoder = DeltaEncoder::default();
+        encoder.encode(&records[0]);
+        let delta = encoder.encode(&records[3]);
+        let json = serde_json::to_string(&delta).unwrap();
+        assert_eq!(
+            delta,
+            serde_json::from_str::<DeltaScanRecord>(&json).unwrap()
+        );
+    }
+}
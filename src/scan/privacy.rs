@@ -0,0 +1,399 @@
+//! Advertisement privacy audit module.
+//!
+//! Flags data in a [`ScanRecord`] that lets an observer recognize the same device over time.
+
+use uuid::Uuid;
+
+use crate::{
+    beacons::{eddystone::Eddystone, ibeacon::IBeacon},
+    data_types::{
+        address_type::{AddressType, RandomAddressType},
+        complete_list_of_128bit_service_uuids::CompleteListOf128BitServiceUuids,
+        complete_list_of_32bit_service_uuids::CompleteListOf32BitServiceUuids,
+        data_type::DataType,
+        data_type_parser::DataTypeParseResult,
+        incomplete_list_of_128bit_service_uuids::IncompleteListOf128BitServiceUuids,
+        incomplete_list_of_32bit_service_uuids::IncompleteListOf32BitServiceUuids,
+        le_bluetooth_device_address::LeBluetoothDeviceAddress,
+        list_of_128bit_service_solicitation_uuids::ListOf128BitServiceSolicitationUUIDs,
+        list_of_32bit_service_solicitation_uuids::ListOf32BitServiceSolicitationUUIDs,
+        manufacturer_specific_data::ManufacturerSpecificData,
+        public_target_address::PublicTargetAddress,
+        random_target_address::RandomTargetAddress,
+        service_data_128bit_uuid::ServiceData128BitUUID,
+        service_data_16bit_uuid::ServiceData16BitUUID,
+        service_data_32bit_uuid::ServiceData32BitUUID,
+    },
+    scan::scan_record::ScanRecord,
+};
+
+/// Privacy finding.
+#[derive(Debug, PartialEq, Clone)]
+pub enum PrivacyFinding {
+    /// Advertiser uses a Public Device Address.
+    PublicAddress {
+        /// Bluetooth Device Address
+        address: u64,
+    },
+
+    /// Advertiser uses a Static Device Address (never rotated while powered).
+    StaticAddress {
+        /// Bluetooth Device Address
+        address: u64,
+    },
+
+    /// Payload carries a Bluetooth Device Address (LE Bluetooth Device Address, Public/Random Target Address).
+    EmbeddedAddress {
+        /// data type
+        data_type: u8,
+
+        /// Bluetooth Device Address
+        address: u64,
+    },
+
+    /// Payload carries the Complete Local Name.
+    CompleteLocalName {
+        /// Complete Local Name
+        name: String,
+    },
+
+    /// Payload carries a fixed beacon identifier (iBeacon, Eddystone-UID/URL).
+    BeaconIdentifier {
+        /// data type
+        data_type: u8,
+    },
+
+    /// Payload carries Manufacturer Specific Data that may contain static identifiers.
+    ManufacturerSpecificData {
+        /// Company Identifier
+        company_identifier: u16,
+    },
+
+    /// Payload carries a vendor specific (32-bit or 128-bit) UUID that does not rotate with the address.
+    ServiceUuid {
+        /// data type
+        data_type: u8,
+
+        /// UUID
+        uuid: Uuid,
+    },
+}
+
+/// Audit [`ScanRecord`] for identifiable data.
+///
+/// The address is checked only if [`ScanRecord::address_type`] is known.
+/// 16-bit UUIDs are assigned by the Bluetooth SIG and shared by many devices, so they are not reported.
+///
+/// # Examples
+///
+/// ```
+/// use ble_data_struct::{
+///     data_types::{
+///         address_type::{AddressType, RandomAddressType},
+///         complete_local_name::CompleteLocalName, data_type_parser::DataTypeParseResults,
+///         tx_power_level::TxPowerLevel,
+///     },
+///     scan::{
+///         privacy::{audit_privacy, PrivacyFinding},
+///         scan_record::ScanRecord,
+///     },
+/// };
+///
+/// let payload: Vec<u8> = TxPowerLevel::new(-4).into();
+/// let record = ScanRecord::from_payload(0x400000000001u64, -60, &payload)
+///     .with_address_type(AddressType::Random(RandomAddressType::ResolvablePrivate));
+/// assert!(audit_privacy(&record).is_empty());
+///
+/// let payload: Vec<u8> = CompleteLocalName::new(&"device".to_string()).into();
+/// let record = ScanRecord::from_payload(0x000000000001u64, -60, &payload)
+///     .with_address_type(AddressType::Public);
+/// assert_eq!(
+///     vec![
+///         PrivacyFinding::PublicAddress { address: 1 },
+///         PrivacyFinding::CompleteLocalName {
+///             name: "device".to_string()
+///         },
+///     ],
+///     audit_privacy(&record)
+/// );
+/// ```
+pub fn audit_privacy(record: &ScanRecord) -> Vec<PrivacyFinding> {
+    let mut findings: Vec<PrivacyFinding> = Vec::new();
+    let address = record.address;
+    match record.address_type {
+        Some(AddressType::Public) | Some(AddressType::PublicIdentity) => {
+            findings.push(PrivacyFinding::PublicAddress { address })
+        }
+        Some(AddressType::Random(RandomAddressType::Static))
+        | Some(AddressType::RandomIdentity) => {
+            findings.push(PrivacyFinding::StaticAddress { address })
+        }
+        _ => {}
+    }
+    for result in &record.results.results {
+        match result {
+            DataTypeParseResult::LeBluetoothDeviceAddressResult(Ok(data_type)) => {
+                findings.push(PrivacyFinding::EmbeddedAddress {
+                    data_type: LeBluetoothDeviceAddress::data_type(),
+                    address: data_type.le_bluetooth_device_address,
+                })
+            }
+            DataTypeParseResult::PublicTargetAddressResult(Ok(data_type)) => {
+                findings.extend(data_type.public_target_address.iter().map(|f| {
+                    PrivacyFinding::EmbeddedAddress {
+                        data_type: PublicTargetAddress::data_type(),
+                        address: *f,
+                    }
+                }))
+            }
+            DataTypeParseResult::RandomTargetAddressResult(Ok(data_type)) => {
+                findings.extend(data_type.random_target_address.iter().map(|f| {
+                    PrivacyFinding::EmbeddedAddress {
+                        data_type: RandomTargetAddress::data_type(),
+                        address: *f,
+                    }
+                }))
+            }
+            DataTypeParseResult::CompleteLocalNameResult(Ok(data_type)) => {
+                findings.push(PrivacyFinding::CompleteLocalName {
+                    name: data_type.complete_local_name.clone(),
+                })
+            }
+            DataTypeParseResult::ManufacturerSpecificDataResult(Ok(data_type)) => {
+                if IBeacon::try_from(data_type).is_ok() {
+                    findings.push(PrivacyFinding::BeaconIdentifier {
+                        data_type: ManufacturerSpecificData::data_type(),
+                    });
+                } else {
+                    findings.push(PrivacyFinding::ManufacturerSpecificData {
+                        company_identifier: data_type.company_identifier,
+                    });
+                }
+            }
+            DataTypeParseResult::ServiceData16BitUUIDResult(Ok(data_type)) => {
+                if matches!(
+                    Eddystone::try_from(data_type),
+                    Ok(Eddystone::Uid { .. }) | Ok(Eddystone::Url { .. })
+                ) {
+                    findings.push(PrivacyFinding::BeaconIdentifier {
+                        data_type: ServiceData16BitUUID::data_type(),
+                    });
+                }
+            }
+            DataTypeParseResult::ServiceData32BitUUIDResult(Ok(data_type)) => {
+                findings.push(PrivacyFinding::ServiceUuid {
+                    data_type: ServiceData32BitUUID::data_type(),
+                    uuid: data_type.uuid,
+                })
+            }
+            DataTypeParseResult::ServiceData128BitUUIDResult(Ok(data_type)) => {
+                findings.push(PrivacyFinding::ServiceUuid {
+                    data_type: ServiceData128BitUUID::data_type(),
+                    uuid: data_type.uuid,
+                })
+            }
+            _ => {
+                if let Some((data_type, uuids)) = uuids(result) {
+                    findings.extend(uuids.iter().map(|f| PrivacyFinding::ServiceUuid {
+                        data_type,
+                        uuid: *f,
+                    }));
+                }
+            }
+        }
+    }
+    findings
+}
+
+/// Returns the data type and UUIDs of 32-bit and 128-bit UUID lists.
+fn uuids(result: &DataTypeParseResult) -> Option<(u8, &Vec<Uuid>)> {
+    match result {
+        DataTypeParseResult::CompleteListOf32BitServiceUuidsResult(Ok(data_type)) => Some((
+            CompleteListOf32BitServiceUuids::data_type(),
+            &data_type.uuids,
+        )),
+        DataTypeParseResult::CompleteListOf128BitServiceUuidsResult(Ok(data_type)) => Some((
+            CompleteListOf128BitServiceUuids::data_type(),
+            &data_type.uuids,
+        )),
+        DataTypeParseResult::IncompleteListOf32BitServiceUuidsResult(Ok(data_type)) => Some((
+            IncompleteListOf32BitServiceUuids::data_type(),
+            &data_type.uuids,
+        )),
+        DataTypeParseResult::IncompleteListOf128BitServiceUuidsResult(Ok(data_type)) => Some((
+            IncompleteListOf128BitServiceUuids::data_type(),
+            &data_type.uuids,
+        )),
+        DataTypeParseResult::ListOf32BitServiceSolicitationUUIDsResult(Ok(data_type)) => Some((
+            ListOf32BitServiceSolicitationUUIDs::data_type(),
+            &data_type.uuids,
+        )),
+        DataTypeParseResult::ListOf128BitServiceSolicitationUUIDsResult(Ok(data_type)) => Some((
+            ListOf128BitServiceSolicitationUUIDs::data_type(),
+            &data_type.uuids,
+        )),
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use uuid::uuid;
+
+    use crate::{
+        beacons::{eddystone::Eddystone, ibeacon::IBeacon},
+        data_types::{
+            address_type::{AddressType, RandomAddressType},
+            complete_list_of_128bit_service_uuids::CompleteListOf128BitServiceUuids,
+            complete_list_of_16bit_service_uuids::CompleteListOf16BitServiceUuids,
+            complete_local_name::CompleteLocalName,
+            data_type::DataType,
+            le_bluetooth_device_address::LeBluetoothDeviceAddress,
+            manufacturer_specific_data::ManufacturerSpecificData,
+            public_target_address::PublicTargetAddress,
+            service_data_128bit_uuid::ServiceData128BitUUID,
+            service_data_16bit_uuid::ServiceData16BitUUID,
+            shortened_local_name::ShortenedLocalName,
+        },
+        scan::{
+            privacy::{audit_privacy, PrivacyFinding},
+            scan_record::ScanRecord,
+        },
+        uuid_from_u16,
+    };
+
+    #[test]
+    fn test_audit_privacy_address() {
+        let address = 0x0000060504030201u64;
+        let payload: Vec<u8> = Vec::new();
+        let record = ScanRecord::from_payload(address, -60, &payload);
+        assert!(audit_privacy(&record).is_empty());
+
+        for address_type in [AddressType::Public, AddressType::PublicIdentity] {
+            let record = record.clone().with_address_type(address_type);
+            assert_eq!(
+                vec![PrivacyFinding::PublicAddress { address }],
+                audit_privacy(&record)
+            );
+        }
+        for address_type in [
+            AddressType::Random(RandomAddressType::Static),
+            AddressType::RandomIdentity,
+        ] {
+            let record = record.clone().with_address_type(address_type);
+            assert_eq!(
+                vec![PrivacyFinding::StaticAddress { address }],
+                audit_privacy(&record)
+            );
+        }
+        for address_type in [
+            AddressType::Random(RandomAddressType::NonResolvablePrivate),
+            AddressType::Random(RandomAddressType::ResolvablePrivate),
+        ] {
+            let record = record.clone().with_address_type(address_type);
+            assert!(audit_privacy(&record).is_empty());
+        }
+    }
+
+    #[test]
+    fn test_audit_privacy_embedded_address() {
+        let le_bluetooth_device_address: Vec<u8> =
+            LeBluetoothDeviceAddress::new(0x0000060504030201u64, false).into();
+        let public_target_address: Vec<u8> =
+            PublicTargetAddress::new(&vec![0x0000010203040506u64, 0x0000060504030201u64]).into();
+        let payload = [le_bluetooth_device_address, public_target_address].concat();
+        let record = ScanRecord::from_payload(1, -60, &payload);
+        assert_eq!(
+            vec![
+                PrivacyFinding::EmbeddedAddress {
+                    data_type: LeBluetoothDeviceAddress::data_type(),
+                    address: 0x0000060504030201u64,
+                },
+                PrivacyFinding::EmbeddedAddress {
+                    data_type: PublicTargetAddress::data_type(),
+                    address: 0x0000010203040506u64,
+                },
+                PrivacyFinding::EmbeddedAddress {
+                    data_type: PublicTargetAddress::data_type(),
+                    address: 0x0000060504030201u64,
+                },
+            ],
+            audit_privacy(&record)
+        );
+    }
+
+    #[test]
+    fn test_audit_privacy_name() {
+        let complete_local_name: Vec<u8> = CompleteLocalName::new(&"device".to_string()).into();
+        let shortened_local_name: Vec<u8> = ShortenedLocalName::new(&"dev".to_string()).into();
+        let payload = [complete_local_name, shortened_local_name].concat();
+        let record = ScanRecord::from_payload(1, -60, &payload);
+        assert_eq!(
+            vec![PrivacyFinding::CompleteLocalName {
+                name: "device".to_string()
+            }],
+            audit_privacy(&record)
+        );
+    }
+
+    #[test]
+    fn test_audit_privacy_beacon() {
+        let ibeacon = IBeacon::new(&uuid!("00112233-4455-6677-8899-aabbccddeeff"), 1, 2, -59);
+        let ibeacon: Vec<u8> = ManufacturerSpecificData::from(&ibeacon).into();
+        let manufacturer_specific_data: Vec<u8> =
+            ManufacturerSpecificData::new(0x0001, &vec![0x01, 0x02]).into();
+        let uid: Vec<u8> = ServiceData16BitUUID::from(&Eddystone::Uid {
+            tx_power: -20,
+            namespace: [0; 10],
+            instance: [1; 6],
+        })
+        .into();
+        let tlm: Vec<u8> = ServiceData16BitUUID::from(&Eddystone::Tlm {
+            battery_voltage: 3000,
+            beacon_temperature: 0x1800,
+            advertising_pdu_count: 1,
+            time_since_power_on: 2,
+        })
+        .into();
+        let payload = [ibeacon, manufacturer_specific_data, uid, tlm].concat();
+        let record = ScanRecord::from_payload(1, -60, &payload);
+        assert_eq!(
+            vec![
+                PrivacyFinding::BeaconIdentifier {
+                    data_type: ManufacturerSpecificData::data_type(),
+                },
+                PrivacyFinding::ManufacturerSpecificData {
+                    company_identifier: 0x0001,
+                },
+                PrivacyFinding::BeaconIdentifier {
+                    data_type: ServiceData16BitUUID::data_type(),
+                },
+            ],
+            audit_privacy(&record)
+        );
+    }
+
+    #[test]
+    fn test_audit_privacy_uuid() {
+        let uuid = uuid!("00112233-4455-6677-8899-aabbccddeeff");
+        let uuid_16bit: Vec<u8> =
+            CompleteListOf16BitServiceUuids::new(&vec![uuid_from_u16(0x180f)]).into();
+        let uuid_128bit: Vec<u8> = CompleteListOf128BitServiceUuids::new(&vec![uuid]).into();
+        let service_data: Vec<u8> = ServiceData128BitUUID::new(&uuid, &vec![0x01]).into();
+        let payload = [uuid_16bit, uuid_128bit, service_data].concat();
+        let record = ScanRecord::from_payload(1, -60, &payload);
+        assert_eq!(
+            vec![
+                PrivacyFinding::ServiceUuid {
+                    data_type: CompleteListOf128BitServiceUuids::data_type(),
+                    uuid,
+                },
+                PrivacyFinding::ServiceUuid {
+                    data_type: ServiceData128BitUUID::data_type(),
+                    uuid,
+                },
+            ],
+            audit_privacy(&record)
+        );
+    }
+}
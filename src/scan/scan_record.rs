@@ -0,0 +1,272 @@
+//! Scan record module.
+
+use crate::{
+    beacons::eddystone::Eddystone,
+    data_types::{
+        address_type::AddressType,
+        data_type_parser::{DataTypeParseResult, DataTypeParseResults},
+    },
+};
+
+/// FNV-1a 64bit offset basis
+const FNV_OFFSET_BASIS: u64 = 0xcbf2_9ce4_8422_2325;
+
+/// FNV-1a 64bit prime
+const FNV_PRIME: u64 = 0x0000_0100_0000_01b3;
+
+/// Scan record.
+///
+/// Single received advertising report with the parsed EIR/AD/SRD structures.
+#[derive(Debug, PartialEq, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ScanRecord {
+    /// Bluetooth Device Address
+    pub address: u64,
+
+    /// Bluetooth Device Address type(`None` if unknown)
+    pub address_type: Option<AddressType>,
+
+    /// RSSI(dBm)
+    pub rssi: i16,
+
+    /// Parse results
+    pub results: DataTypeParseResults,
+}
+
+impl ScanRecord {
+    /// Create [`ScanRecord`] from `Bluetooth Device Address`, `RSSI` and [`DataTypeParseResults`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ble_data_struct::{
+    ///     data_types::data_type_parser::DataTypeParseResults, scan::scan_record::ScanRecord,
+    /// };
+    ///
+    /// let address = 0x0000060504030201u64;
+    /// let rssi = -60;
+    /// let results = DataTypeParseResults::new(Vec::new());
+    /// let result = ScanRecord::new(address, rssi, results.clone());
+    /// assert_eq!(address, result.address);
+    /// assert_eq!(None, result.address_type);
+    /// assert_eq!(rssi, result.rssi);
+    /// assert_eq!(results, result.results);
+    /// ```
+    pub fn new(address: u64, rssi: i16, results: DataTypeParseResults) -> Self {
+        Self {
+            address,
+            address_type: None,
+            rssi,
+            results,
+        }
+    }
+
+    /// Create [`ScanRecord`] from `Bluetooth Device Address`, `RSSI` and raw advertising payload.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ble_data_struct::{
+    ///     data_types::{
+    ///         data_type_parser::DataTypeParseResult, tx_power_level::TxPowerLevel,
+    ///     },
+    ///     scan::scan_record::ScanRecord,
+    /// };
+    ///
+    /// let address = 0x0000060504030201u64;
+    /// let rssi = -60;
+    /// let payload: Vec<u8> = TxPowerLevel::new(-4).into();
+    /// let result = ScanRecord::from_payload(address, rssi, &payload);
+    /// assert_eq!(address, result.address);
+    /// assert_eq!(rssi, result.rssi);
+    /// assert!(matches!(
+    ///     result.results.results.first(),
+    ///     Some(DataTypeParseResult::TxPowerLevelResult(Ok(_)))
+    /// ));
+    /// ```
+    pub fn from_payload(address: u64, rssi: i16, payload: &Vec<u8>) -> Self {
+        Self::new(address, rssi, DataTypeParseResults::from(payload))
+    }
+
+    /// Set `Bluetooth Device Address` type.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ble_data_struct::{
+    ///     data_types::{address_type::AddressType, data_type_parser::DataTypeParseResults},
+    ///     scan::scan_record::ScanRecord,
+    /// };
+    ///
+    /// let result = ScanRecord::new(0x0000060504030201u64, -60, DataTypeParseResults::new(Vec::new()))
+    ///     .with_address_type(AddressType::Public);
+    /// assert_eq!(Some(AddressType::Public), result.address_type);
+    /// ```
+    pub fn with_address_type(mut self, address_type: AddressType) -> Self {
+        self.address_type = Some(address_type);
+        self
+    }
+
+    /// Stable 64bit fingerprint of the advertised content.
+    ///
+    /// Structures are hashed (FNV-1a) in sorted order, so the order of AD structures does not matter.
+    /// `Bluetooth Device Address`, `RSSI` and rotating content (Eddystone-TLM frames) are ignored.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ble_data_struct::{
+    ///     data_types::{complete_local_name::CompleteLocalName, tx_power_level::TxPowerLevel},
+    ///     scan::scan_record::ScanRecord,
+    /// };
+    ///
+    /// let name: Vec<u8> = CompleteLocalName::new(&"name".to_string()).into();
+    /// let tx_power_level: Vec<u8> = TxPowerLevel::new(-4).into();
+    ///
+    /// let record1 = ScanRecord::from_payload(0x01, -60, &[name.clone(), tx_power_level.clone()].concat());
+    /// let record2 = ScanRecord::from_payload(0x02, -70, &[tx_power_level, name.clone()].concat());
+    /// let record3 = ScanRecord::from_payload(0x01, -60, &name);
+    /// assert_eq!(record1.fingerprint(), record2.fingerprint());
+    /// assert_ne!(record1.fingerprint(), record3.fingerprint());
+    /// ```
+    pub fn fingerprint(&self) -> u64 {
+        let mut structures: Vec<Vec<u8>> = self
+            .results
+            .results
+            .iter()
+            .filter(|f| !is_rotating(f))
+            .map(|f| match (f.ad_structure(), f.parse_error()) {
+                (Some(ad_structure), _) => ad_structure.to_bytes(),
+                (None, Some(error)) => error.to_string().into_bytes(),
+                (None, None) => Vec::new(),
+            })
+            .collect();
+        structures.sort();
+        structures.iter().fold(FNV_OFFSET_BASIS, |hash, structure| {
+            (structure.len() as u64)
+                .to_le_bytes()
+                .iter()
+                .chain(structure)
+                .fold(hash, |hash, byte| {
+                    (hash ^ *byte as u64).wrapping_mul(FNV_PRIME)
+                })
+        })
+    }
+}
+
+/// check frequently changing content.
+fn is_rotating(result: &DataTypeParseResult) -> bool {
+    match result {
+        DataTypeParseResult::ServiceData16BitUUIDResult(Ok(service_data)) => {
+            matches!(Eddystone::try_from(service_data), Ok(Eddystone::Tlm { .. }))
+        }
+        _ => false,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        beacons::eddystone::Eddystone,
+        data_types::{
+            address_type::AddressType,
+            complete_local_name::CompleteLocalName,
+            data_type_parser::{DataTypeParseResult, DataTypeParseResults},
+            service_data_16bit_uuid::ServiceData16BitUUID,
+            tx_power_level::TxPowerLevel,
+        },
+        scan::scan_record::ScanRecord,
+    };
+
+    #[test]
+    fn test_new() {
+        let address = 0x0000060504030201u64;
+        let rssi = -60;
+        let results = DataTypeParseResults::new(Vec::new());
+        let result = ScanRecord::new(address, rssi, results.clone());
+        assert_eq!(address, result.address);
+        assert_eq!(None, result.address_type);
+        assert_eq!(rssi, result.rssi);
+        assert_eq!(results, result.results);
+    }
+
+    #[test]
+    fn test_from_payload() {
+        let address = 0x0000060504030201u64;
+        let rssi = -60;
+        let payload: Vec<u8> = TxPowerLevel::new(-4).into();
+        let result = ScanRecord::from_payload(address, rssi, &payload);
+        assert_eq!(address, result.address);
+        assert_eq!(rssi, result.rssi);
+        assert!(matches!(
+            result.results.results.first(),
+            Some(DataTypeParseResult::TxPowerLevelResult(Ok(_)))
+        ));
+    }
+
+    #[test]
+    fn test_fingerprint() {
+        let name: Vec<u8> = CompleteLocalName::new(&"name".to_string()).into();
+        let tx_power_level: Vec<u8> = TxPowerLevel::new(-4).into();
+        let tlm1: Vec<u8> = ServiceData16BitUUID::from(&Eddystone::Tlm {
+            battery_voltage: 3000,
+            beacon_temperature: 0x1800,
+            advertising_pdu_count: 1,
+            time_since_power_on: 10,
+        })
+        .into();
+        let tlm2: Vec<u8> = ServiceData16BitUUID::from(&Eddystone::Tlm {
+            battery_voltage: 3000,
+            beacon_temperature: 0x1800,
+            advertising_pdu_count: 2,
+            time_since_power_on: 20,
+        })
+        .into();
+
+        let record1 = ScanRecord::from_payload(
+            0x01,
+            -60,
+            &[name.clone(), tx_power_level.clone(), tlm1].concat(),
+        );
+        let record2 = ScanRecord::from_payload(
+            0x02,
+            -70,
+            &[tlm2, tx_power_level.clone(), name.clone()].concat(),
+        );
+        assert_eq!(record1.fingerprint(), record2.fingerprint());
+        assert_eq!(record1.fingerprint(), record1.clone().fingerprint());
+
+        let record3 = ScanRecord::from_payload(0x01, -60, &name);
+        assert_ne!(record1.fingerprint(), record3.fingerprint());
+        let record4 =
+            ScanRecord::from_payload(0x01, -60, &[name, TxPowerLevel::new(-8).into()].concat());
+        assert_ne!(record1.fingerprint(), record4.fingerprint());
+
+        let record5 = ScanRecord::new(0x01, -60, DataTypeParseResults::new(Vec::new()));
+        let record6 = ScanRecord::from_payload(0x01, -60, &vec![0x05, 0x0a]);
+        assert_ne!(record5.fingerprint(), record6.fingerprint());
+
+        // oversized Channel Map Update Indication
+        let record7 = ScanRecord::from_payload(
+            0x01,
+            -60,
+            &vec![
+                0x0a, 0x28, 0x15, 0x35, 0xc5, 0x7c, 0x1a, 0xaa, 0x33, 0x61, 0x71,
+            ],
+        );
+        assert!(record7.results.results[0].parse_error().is_some());
+        assert_eq!(record7.fingerprint(), record7.clone().fingerprint());
+        assert_ne!(record5.fingerprint(), record7.fingerprint());
+    }
+
+    #[test]
+    fn test_with_address_type() {
+        let result = ScanRecord::new(
+            0x0000060504030201u64,
+            -60,
+            DataTypeParseResults::new(Vec::new()),
+        )
+        .with_address_type(AddressType::Public);
+        assert_eq!(Some(AddressType::Public), result.address_type);
+    }
+}
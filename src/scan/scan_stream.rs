@@ -0,0 +1,355 @@
+//! Async scan stream module.
+
+use std::{
+    collections::{HashSet, VecDeque},
+    pin::Pin,
+    sync::{Arc, Mutex},
+    task::{Context, Poll, Waker},
+};
+
+use futures_core::Stream;
+
+use crate::scan::scan_record::ScanRecord;
+
+/// State shared between [`ScanSender`] and [`ScanStream`].
+#[derive(Default)]
+struct Shared {
+    /// received records
+    queue: VecDeque<ScanRecord>,
+
+    /// waker of the pending [`ScanStream`]
+    waker: Option<Waker>,
+
+    /// living [`ScanSender`] count
+    senders: usize,
+
+    /// [`ScanStream`] dropped
+    closed: bool,
+}
+
+/// Feeding side of [`ScanStream`].
+///
+/// Platform backends (Windows watcher, bluer, btleplug, ...) call [`ScanSender::send`] from their scan callbacks.
+pub struct ScanSender {
+    shared: Arc<Mutex<Shared>>,
+}
+
+impl ScanSender {
+    /// Send [`ScanRecord`] to [`ScanStream`].
+    ///
+    /// Returns the record as error if [`ScanStream`] has been dropped.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ble_data_struct::{
+    ///     data_types::data_type_parser::DataTypeParseResults,
+    ///     scan::{scan_record::ScanRecord, scan_stream::ScanStream},
+    /// };
+    ///
+    /// let (sender, stream) = ScanStream::channel();
+    /// let record = ScanRecord::new(1, -60, DataTypeParseResults::new(Vec::new()));
+    /// assert!(sender.send(record.clone()).is_ok());
+    ///
+    /// drop(stream);
+    /// assert_eq!(Err(record.clone()), sender.send(record));
+    /// ```
+    pub fn send(&self, record: ScanRecord) -> Result<(), ScanRecord> {
+        let mut shared = self.shared.lock().unwrap();
+        if shared.closed {
+            return Err(record);
+        }
+        shared.queue.push_back(record);
+        if let Some(waker) = shared.waker.take() {
+            waker.wake();
+        }
+        Ok(())
+    }
+}
+
+impl Clone for ScanSender {
+    fn clone(&self) -> Self {
+        self.shared.lock().unwrap().senders += 1;
+        Self {
+            shared: self.shared.clone(),
+        }
+    }
+}
+
+impl Drop for ScanSender {
+    fn drop(&mut self) {
+        let mut shared = self.shared.lock().unwrap();
+        shared.senders -= 1;
+        if shared.senders == 0 {
+            if let Some(waker) = shared.waker.take() {
+                waker.wake();
+            }
+        }
+    }
+}
+
+/// Scan stream.
+///
+/// [`Stream`] of [`ScanRecord`] fed by [`ScanSender`]. The stream ends when every [`ScanSender`] has been dropped.
+pub struct ScanStream {
+    shared: Arc<Mutex<Shared>>,
+}
+
+impl ScanStream {
+    /// Create connected [`ScanSender`] and [`ScanStream`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ble_data_struct::{
+    ///     data_types::data_type_parser::DataTypeParseResults,
+    ///     scan::{scan_record::ScanRecord, scan_stream::ScanStream},
+    /// };
+    /// use futures::{executor::block_on, StreamExt};
+    ///
+    /// let (sender, stream) = ScanStream::channel();
+    /// let record = ScanRecord::new(1, -60, DataTypeParseResults::new(Vec::new()));
+    /// sender.send(record.clone()).unwrap();
+    /// drop(sender);
+    ///
+    /// assert_eq!(vec![record], block_on(stream.collect::<Vec<ScanRecord>>()));
+    /// ```
+    pub fn channel() -> (ScanSender, ScanStream) {
+        let shared = Arc::new(Mutex::new(Shared {
+            senders: 1,
+            ..Default::default()
+        }));
+        (
+            ScanSender {
+                shared: shared.clone(),
+            },
+            ScanStream { shared },
+        )
+    }
+}
+
+impl Stream for ScanStream {
+    type Item = ScanRecord;
+
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        let mut shared = self.shared.lock().unwrap();
+        if let Some(record) = shared.queue.pop_front() {
+            Poll::Ready(Some(record))
+        } else if shared.senders == 0 {
+            Poll::Ready(None)
+        } else {
+            shared.waker = Some(cx.waker().clone());
+            Poll::Pending
+        }
+    }
+}
+
+impl Drop for ScanStream {
+    fn drop(&mut self) {
+        let mut shared = self.shared.lock().unwrap();
+        shared.closed = true;
+        shared.queue.clear();
+    }
+}
+
+/// Stream returned by [`ScanStreamExt::dedup_by_address`].
+pub struct DedupByAddress<S> {
+    stream: S,
+    addresses: HashSet<u64>,
+}
+
+impl<S: Stream<Item = ScanRecord> + Unpin> Stream for DedupByAddress<S> {
+    type Item = ScanRecord;
+
+    fn poll_next(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        loop {
+            match Pin::new(&mut self.stream).poll_next(cx) {
+                Poll::Ready(Some(record)) => {
+                    if self.addresses.insert(record.address) {
+                        return Poll::Ready(Some(record));
+                    }
+                }
+                other => return other,
+            }
+        }
+    }
+}
+
+/// Stream returned by [`ScanStreamExt::rssi_threshold`].
+pub struct RssiThreshold<S> {
+    stream: S,
+    rssi: i16,
+}
+
+impl<S: Stream<Item = ScanRecord> + Unpin> Stream for RssiThreshold<S> {
+    type Item = ScanRecord;
+
+    fn poll_next(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        loop {
+            match Pin::new(&mut self.stream).poll_next(cx) {
+                Poll::Ready(Some(record)) => {
+                    if record.rssi >= self.rssi {
+                        return Poll::Ready(Some(record));
+                    }
+                }
+                other => return other,
+            }
+        }
+    }
+}
+
+/// Combinators for [`Stream`] of [`ScanRecord`].
+pub trait ScanStreamExt: Stream<Item = ScanRecord> + Sized {
+    /// Pass only the first [`ScanRecord`] of each `Bluetooth Device Address`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ble_data_struct::{
+    ///     data_types::data_type_parser::DataTypeParseResults,
+    ///     scan::{
+    ///         scan_record::ScanRecord,
+    ///         scan_stream::{ScanStream, ScanStreamExt},
+    ///     },
+    /// };
+    /// use futures::{executor::block_on, StreamExt};
+    ///
+    /// let (sender, stream) = ScanStream::channel();
+    /// let record1 = ScanRecord::new(1, -60, DataTypeParseResults::new(Vec::new()));
+    /// let record2 = ScanRecord::new(2, -60, DataTypeParseResults::new(Vec::new()));
+    /// sender.send(record1.clone()).unwrap();
+    /// sender.send(record1.clone()).unwrap();
+    /// sender.send(record2.clone()).unwrap();
+    /// drop(sender);
+    ///
+    /// assert_eq!(
+    ///     vec![record1, record2],
+    ///     block_on(stream.dedup_by_address().collect::<Vec<ScanRecord>>())
+    /// );
+    /// ```
+    fn dedup_by_address(self) -> DedupByAddress<Self> {
+        DedupByAddress {
+            stream: self,
+            addresses: HashSet::new(),
+        }
+    }
+
+    /// Pass only [`ScanRecord`] whose RSSI is greater than or equal to `rssi`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ble_data_struct::{
+    ///     data_types::data_type_parser::DataTypeParseResults,
+    ///     scan::{
+    ///         scan_record::ScanRecord,
+    ///         scan_stream::{ScanStream, ScanStreamExt},
+    ///     },
+    /// };
+    /// use futures::{executor::block_on, StreamExt};
+    ///
+    /// let (sender, stream) = ScanStream::channel();
+    /// let record1 = ScanRecord::new(1, -60, DataTypeParseResults::new(Vec::new()));
+    /// let record2 = ScanRecord::new(2, -90, DataTypeParseResults::new(Vec::new()));
+    /// sender.send(record1.clone()).unwrap();
+    /// sender.send(record2.clone()).unwrap();
+    /// drop(sender);
+    ///
+    /// assert_eq!(
+    ///     vec![record1],
+    ///     block_on(stream.rssi_threshold(-70).collect::<Vec<ScanRecord>>())
+    /// );
+    /// ```
+    fn rssi_threshold(self, rssi: i16) -> RssiThreshold<Self> {
+        RssiThreshold { stream: self, rssi }
+    }
+}
+
+impl<S: Stream<Item = ScanRecord>> ScanStreamExt for S {}
+
+#[cfg(test)]
+mod tests {
+    use std::thread;
+
+    use futures::{executor::block_on, StreamExt};
+
+    use crate::{
+        data_types::data_type_parser::DataTypeParseResults,
+        scan::{
+            scan_record::ScanRecord,
+            scan_stream::{ScanStream, ScanStreamExt},
+        },
+    };
+
+    #[test]
+    fn test_send() {
+        let (sender, stream) = ScanStream::channel();
+        let record = ScanRecord::new(1, -60, DataTypeParseResults::new(Vec::new()));
+        assert!(sender.send(record.clone()).is_ok());
+
+        drop(stream);
+        assert_eq!(Err(record.clone()), sender.send(record));
+    }
+
+    #[test]
+    fn test_channel() {
+        let (sender, stream) = ScanStream::channel();
+        let record = ScanRecord::new(1, -60, DataTypeParseResults::new(Vec::new()));
+        sender.send(record.clone()).unwrap();
+        drop(sender);
+
+        assert_eq!(vec![record], block_on(stream.collect::<Vec<ScanRecord>>()));
+    }
+
+    #[test]
+    fn test_channel_thread() {
+        let (sender, stream) = ScanStream::channel();
+        let handles: Vec<_> = (0..4u64)
+            .map(|address| {
+                let sender = sender.clone();
+                thread::spawn(move || {
+                    let record =
+                        ScanRecord::new(address, -60, DataTypeParseResults::new(Vec::new()));
+                    sender.send(record).unwrap();
+                })
+            })
+            .collect();
+        drop(sender);
+        handles.into_iter().for_each(|f| f.join().unwrap());
+
+        let mut addresses: Vec<u64> = block_on(stream.map(|f| f.address).collect::<Vec<u64>>());
+        addresses.sort();
+        assert_eq!(vec![0, 1, 2, 3], addresses);
+    }
+
+    #[test]
+    fn test_dedup_by_address() {
+        let (sender, stream) = ScanStream::channel();
+        let record1 = ScanRecord::new(1, -60, DataTypeParseResults::new(Vec::new()));
+        let record2 = ScanRecord::new(2, -60, DataTypeParseResults::new(Vec::new()));
+        sender.send(record1.clone()).unwrap();
+        sender.send(record1.clone()).unwrap();
+        sender.send(record2.clone()).unwrap();
+        drop(sender);
+
+        assert_eq!(
+            vec![record1, record2],
+            block_on(stream.dedup_by_address().collect::<Vec<ScanRecord>>())
+        );
+    }
+
+    #[test]
+    fn test_rssi_threshold() {
+        let (sender, stream) = ScanStream::channel();
+        let record1 = ScanRecord::new(1, -60, DataTypeParseResults::new(Vec::new()));
+        let record2 = ScanRecord::new(2, -90, DataTypeParseResults::new(Vec::new()));
+        sender.send(record1.clone()).unwrap();
+        sender.send(record2.clone()).unwrap();
+        drop(sender);
+
+        assert_eq!(
+            vec![record1],
+            block_on(stream.rssi_threshold(-70).collect::<Vec<ScanRecord>>())
+        );
+    }
+}
@@ -0,0 +1,463 @@
+//! Scan session simulator module.
+//!
+//! Replays recorded [`ScanRecord`]s with their original timing (or accelerated) for deterministic tests of scanner logic.
+
+use std::time::Duration;
+
+use crate::scan::scan_record::ScanRecord;
+#[cfg(feature = "async")]
+use crate::scan::scan_stream::ScanSender;
+
+/// Recorded [`ScanRecord`] with its reception time.
+#[derive(Debug, PartialEq, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct TimedScanRecord {
+    /// Reception time since the session start
+    pub timestamp: Duration,
+
+    /// Scan record
+    pub record: ScanRecord,
+}
+
+impl TimedScanRecord {
+    /// Create [`TimedScanRecord`] from reception time and [`ScanRecord`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::time::Duration;
+    ///
+    /// use ble_data_struct::{
+    ///     data_types::data_type_parser::DataTypeParseResults,
+    ///     scan::{scan_record::ScanRecord, simulator::TimedScanRecord},
+    /// };
+    ///
+    /// let record = ScanRecord::new(1, -60, DataTypeParseResults::new(Vec::new()));
+    /// let result = TimedScanRecord::new(Duration::from_millis(100), record.clone());
+    /// assert_eq!(Duration::from_millis(100), result.timestamp);
+    /// assert_eq!(record, result.record);
+    /// ```
+    pub fn new(timestamp: Duration, record: ScanRecord) -> Self {
+        Self { timestamp, record }
+    }
+}
+
+/// Recorded scan session simulator.
+#[derive(Debug, PartialEq, Clone)]
+pub struct ScanSimulator {
+    /// Recorded scan records (ordered by timestamp)
+    pub records: Vec<TimedScanRecord>,
+
+    /// Playback speed(`1.0`: original speed, [`f64::INFINITY`]: no wait)
+    speed: f64,
+}
+
+impl ScanSimulator {
+    /// Create [`ScanSimulator`] from recorded scan records.
+    ///
+    /// Records are sorted by timestamp (records with the same timestamp keep their order).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::time::Duration;
+    ///
+    /// use ble_data_struct::{
+    ///     data_types::data_type_parser::DataTypeParseResults,
+    ///     scan::{
+    ///         scan_record::ScanRecord,
+    ///         simulator::{ScanSimulator, TimedScanRecord},
+    ///     },
+    /// };
+    ///
+    /// let record1 = TimedScanRecord::new(
+    ///     Duration::from_millis(200),
+    ///     ScanRecord::new(1, -60, DataTypeParseResults::new(Vec::new())),
+    /// );
+    /// let record2 = TimedScanRecord::new(
+    ///     Duration::from_millis(100),
+    ///     ScanRecord::new(2, -60, DataTypeParseResults::new(Vec::new())),
+    /// );
+    /// let result = ScanSimulator::new(vec![record1.clone(), record2.clone()]);
+    /// assert_eq!(vec![record2, record1], result.records);
+    /// assert_eq!(1.0, result.speed());
+    /// ```
+    pub fn new(mut records: Vec<TimedScanRecord>) -> Self {
+        records.sort_by_key(|f| f.timestamp);
+        Self {
+            records,
+            speed: 1.0,
+        }
+    }
+
+    /// Set playback speed.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ble_data_struct::scan::simulator::ScanSimulator;
+    ///
+    /// assert_eq!(Ok(10.0), ScanSimulator::new(Vec::new()).with_speed(10.0).map(|f| f.speed()));
+    /// assert!(ScanSimulator::new(Vec::new()).with_speed(0.0).is_err());
+    /// assert!(ScanSimulator::new(Vec::new()).with_speed(f64::NAN).is_err());
+    /// ```
+    pub fn with_speed(mut self, speed: f64) -> Result<Self, String> {
+        if speed.is_nan() || speed <= 0.0 {
+            return Err(format!("Invalid speed :{}", speed));
+        }
+        self.speed = speed;
+        Ok(self)
+    }
+
+    /// Returns playback speed.
+    pub fn speed(&self) -> f64 {
+        self.speed
+    }
+
+    /// Wait time before each record at the playback speed.
+    ///
+    /// Wait times too long for [`Duration`] (very slow playback) saturate to [`Duration::MAX`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::time::Duration;
+    ///
+    /// use ble_data_struct::{
+    ///     data_types::data_type_parser::DataTypeParseResults,
+    ///     scan::{
+    ///         scan_record::ScanRecord,
+    ///         simulator::{ScanSimulator, TimedScanRecord},
+    ///     },
+    /// };
+    ///
+    /// let record = ScanRecord::new(1, -60, DataTypeParseResults::new(Vec::new()));
+    /// let result = ScanSimulator::new(vec![
+    ///     TimedScanRecord::new(Duration::from_millis(100), record.clone()),
+    ///     TimedScanRecord::new(Duration::from_millis(300), record.clone()),
+    /// ])
+    /// .with_speed(2.0)
+    /// .unwrap();
+    /// assert_eq!(
+    ///     vec![Duration::from_millis(50), Duration::from_millis(100)],
+    ///     result.delays().map(|(delay, _)| delay).collect::<Vec<Duration>>()
+    /// );
+    /// ```
+    pub fn delays(&self) -> impl Iterator<Item = (Duration, &ScanRecord)> {
+        let speed = self.speed;
+        let mut previous = Duration::ZERO;
+        self.records.iter().map(move |f| {
+            let delay = Duration::try_from_secs_f64((f.timestamp - previous).as_secs_f64() / speed)
+                .unwrap_or(Duration::MAX);
+            previous = f.timestamp;
+            (delay, &f.record)
+        })
+    }
+
+    /// Replay the session with the `sleep` function.
+    ///
+    /// Pass a virtual clock as `sleep` for deterministic tests.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::{cell::Cell, time::Duration};
+    ///
+    /// use ble_data_struct::{
+    ///     data_types::data_type_parser::DataTypeParseResults,
+    ///     scan::{
+    ///         scan_record::ScanRecord,
+    ///         simulator::{ScanSimulator, TimedScanRecord},
+    ///     },
+    /// };
+    ///
+    /// let record = ScanRecord::new(1, -60, DataTypeParseResults::new(Vec::new()));
+    /// let simulator = ScanSimulator::new(vec![
+    ///     TimedScanRecord::new(Duration::from_millis(100), record.clone()),
+    ///     TimedScanRecord::new(Duration::from_millis(300), record.clone()),
+    /// ]);
+    ///
+    /// let clock = Cell::new(Duration::ZERO);
+    /// let mut received: Vec<(Duration, u64)> = Vec::new();
+    /// simulator.replay_with(
+    ///     |delay| clock.set(clock.get() + delay),
+    ///     |f| received.push((clock.get(), f.address)),
+    /// );
+    /// assert_eq!(
+    ///     vec![(Duration::from_millis(100), 1), (Duration::from_millis(300), 1)],
+    ///     received
+    /// );
+    /// ```
+    pub fn replay_with<S, F>(&self, mut sleep: S, mut on_record: F)
+    where
+        S: FnMut(Duration),
+        F: FnMut(&ScanRecord),
+    {
+        self.delays().for_each(|(delay, record)| {
+            if !delay.is_zero() {
+                sleep(delay);
+            }
+            on_record(record);
+        });
+    }
+
+    /// Replay the session in real time on the current thread.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::time::Duration;
+    ///
+    /// use ble_data_struct::{
+    ///     data_types::data_type_parser::DataTypeParseResults,
+    ///     scan::{
+    ///         scan_record::ScanRecord,
+    ///         simulator::{ScanSimulator, TimedScanRecord},
+    ///     },
+    /// };
+    ///
+    /// let record = ScanRecord::new(1, -60, DataTypeParseResults::new(Vec::new()));
+    /// let simulator = ScanSimulator::new(vec![TimedScanRecord::new(Duration::from_millis(1), record)])
+    ///     .with_speed(f64::INFINITY)
+    ///     .unwrap();
+    ///
+    /// let mut count = 0;
+    /// simulator.replay(|_| count += 1);
+    /// assert_eq!(1, count);
+    /// ```
+    pub fn replay<F>(&self, on_record: F)
+    where
+        F: FnMut(&ScanRecord),
+    {
+        self.replay_with(std::thread::sleep, on_record);
+    }
+
+    /// Replay the session into [`ScanStream`](crate::scan::scan_stream::ScanStream) with the `sleep` function.
+    ///
+    /// `sender` is dropped at the end, so the stream ends after the last record.
+    /// Returns the record as error if the stream has been dropped.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::time::Duration;
+    ///
+    /// use ble_data_struct::{
+    ///     data_types::data_type_parser::DataTypeParseResults,
+    ///     scan::{
+    ///         scan_record::ScanRecord,
+    ///         scan_stream::{ScanStream, ScanStreamExt},
+    ///         simulator::{ScanSimulator, TimedScanRecord},
+    ///     },
+    /// };
+    /// use futures::{executor::block_on, StreamExt};
+    ///
+    /// let record1 = ScanRecord::new(1, -60, DataTypeParseResults::new(Vec::new()));
+    /// let record2 = ScanRecord::new(2, -90, DataTypeParseResults::new(Vec::new()));
+    /// let simulator = ScanSimulator::new(vec![
+    ///     TimedScanRecord::new(Duration::from_millis(100), record1.clone()),
+    ///     TimedScanRecord::new(Duration::from_millis(200), record2),
+    ///     TimedScanRecord::new(Duration::from_millis(300), record1.clone()),
+    /// ]);
+    ///
+    /// let (sender, stream) = ScanStream::channel();
+    /// assert!(simulator.feed_with(sender, |_| {}).is_ok());
+    /// assert_eq!(
+    ///     vec![record1],
+    ///     block_on(
+    ///         stream
+    ///             .rssi_threshold(-70)
+    ///             .dedup_by_address()
+    ///             .collect::<Vec<ScanRecord>>()
+    ///     )
+    /// );
+    /// ```
+    #[cfg(feature = "async")]
+    pub fn feed_with<S>(&self, sender: ScanSender, mut sleep: S) -> Result<(), ScanRecord>
+    where
+        S: FnMut(Duration),
+    {
+        for (delay, record) in self.delays() {
+            if !delay.is_zero() {
+                sleep(delay);
+            }
+            sender.send(record.clone())?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{cell::Cell, time::Duration};
+
+    use crate::{
+        data_types::{data_type_parser::DataTypeParseResults, tx_power_level::TxPowerLevel},
+        scan::{
+            scan_record::ScanRecord,
+            simulator::{ScanSimulator, TimedScanRecord},
+        },
+    };
+
+    fn session() -> Vec<TimedScanRecord> {
+        let payload: Vec<u8> = TxPowerLevel::new(-4).into();
+        vec![
+            TimedScanRecord::new(
+                Duration::from_millis(300),
+                ScanRecord::from_payload(3, -60, &payload),
+            ),
+            TimedScanRecord::new(
+                Duration::from_millis(100),
+                ScanRecord::from_payload(1, -60, &payload),
+            ),
+            TimedScanRecord::new(
+                Duration::from_millis(100),
+                ScanRecord::from_payload(2, -60, &payload),
+            ),
+        ]
+    }
+
+    #[test]
+    fn test_timed_scan_record_new() {
+        let record = ScanRecord::new(1, -60, DataTypeParseResults::new(Vec::new()));
+        let result = TimedScanRecord::new(Duration::from_millis(100), record.clone());
+        assert_eq!(Duration::from_millis(100), result.timestamp);
+        assert_eq!(record, result.record);
+    }
+
+    #[test]
+    fn test_new() {
+        let result = ScanSimulator::new(session());
+        assert_eq!(
+            vec![1, 2, 3],
+            result
+                .records
+                .iter()
+                .map(|f| f.record.address)
+                .collect::<Vec<u64>>()
+        );
+        assert_eq!(1.0, result.speed());
+    }
+
+    #[test]
+    fn test_with_speed() {
+        assert_eq!(
+            Ok(10.0),
+            ScanSimulator::new(Vec::new())
+                .with_speed(10.0)
+                .map(|f| f.speed())
+        );
+        assert!(ScanSimulator::new(Vec::new()).with_speed(0.0).is_err());
+        assert!(ScanSimulator::new(Vec::new()).with_speed(-1.0).is_err());
+        assert!(ScanSimulator::new(Vec::new()).with_speed(f64::NAN).is_err());
+    }
+
+    #[test]
+    fn test_delays() {
+        let result = ScanSimulator::new(session());
+        assert_eq!(
+            vec![
+                Duration::from_millis(100),
+                Duration::ZERO,
+                Duration::from_millis(200)
+            ],
+            result
+                .delays()
+                .map(|(delay, _)| delay)
+                .collect::<Vec<Duration>>()
+        );
+
+        let result = result.with_speed(4.0).unwrap();
+        assert_eq!(
+            vec![
+                Duration::from_millis(25),
+                Duration::ZERO,
+                Duration::from_millis(50)
+            ],
+            result
+                .delays()
+                .map(|(delay, _)| delay)
+                .collect::<Vec<Duration>>()
+        );
+
+        let result = result.with_speed(f64::INFINITY).unwrap();
+        assert!(result.delays().all(|(delay, _)| delay.is_zero()));
+
+        let result = result.with_speed(f64::MIN_POSITIVE).unwrap();
+        assert_eq!(
+            vec![Duration::MAX, Duration::ZERO, Duration::MAX],
+            result
+                .delays()
+                .map(|(delay, _)| delay)
+                .collect::<Vec<Duration>>()
+        );
+    }
+
+    #[test]
+    fn test_replay_with() {
+        let simulator = ScanSimulator::new(session());
+        let clock = Cell::new(Duration::ZERO);
+        let mut sleeps = 0;
+        let mut received: Vec<(Duration, u64)> = Vec::new();
+        simulator.replay_with(
+            |delay| {
+                clock.set(clock.get() + delay);
+                sleeps += 1;
+            },
+            |f| received.push((clock.get(), f.address)),
+        );
+        assert_eq!(
+            vec![
+                (Duration::from_millis(100), 1),
+                (Duration::from_millis(100), 2),
+                (Duration::from_millis(300), 3)
+            ],
+            received
+        );
+        assert_eq!(2, sleeps);
+    }
+
+    #[test]
+    fn test_replay() {
+        let simulator = ScanSimulator::new(session())
+            .with_speed(f64::INFINITY)
+            .unwrap();
+        let mut addresses: Vec<u64> = Vec::new();
+        simulator.replay(|f| addresses.push(f.address));
+        assert_eq!(vec![1, 2, 3], addresses);
+    }
+
+    #[cfg(feature = "async")]
+    #[test]
+    fn test_feed_with() {
+        use futures::{executor::block_on, StreamExt};
+
+        use crate::scan::scan_stream::{ScanStream, ScanStreamExt};
+
+        let simulator = ScanSimulator::new(session());
+        let (sender, stream) = ScanStream::channel();
+        assert!(simulator.feed_with(sender, |_| {}).is_ok());
+        assert_eq!(
+            vec![1, 2, 3],
+            block_on(
+                stream
+                    .dedup_by_address()
+                    .map(|f| f.address)
+                    .collect::<Vec<u64>>()
+            )
+        );
+
+        let (sender, stream) = ScanStream::channel();
+        drop(stream);
+        assert!(simulator.feed_with(sender, |_| {}).is_err());
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_serde() {
+        let records = session();
+        let json = serde_json::to_string(&records).unwrap();
+        let result = ScanSimulator::new(serde_json::from_str(&json).unwrap());
+        assert_eq!(ScanSimulator::new(records), result);
+    }
+}
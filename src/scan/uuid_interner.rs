@@ -0,0 +1,175 @@
+//! Service UUID list interner module.
+//!
+//! Advertisements from the same device repeat identical UUID lists, so parsed lists are cached by their raw bytes.
+
+use std::{collections::HashMap, sync::Arc};
+
+use uuid::Uuid;
+
+use crate::data_types::uuid_list::UuidList;
+
+/// Default max number of cached UUID lists.
+pub const DEFAULT_MAX_ENTRIES: usize = 1024;
+
+/// Service UUID list interner.
+#[derive(Debug, Clone)]
+pub struct UuidInterner {
+    /// Max number of cached UUID lists
+    pub max_entries: usize,
+
+    /// Raw AD structure to UUIDs
+    entries: HashMap<Vec<u8>, Arc<[Uuid]>>,
+}
+
+impl UuidInterner {
+    /// Create [`UuidInterner`] from max number of cached UUID lists.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ble_data_struct::scan::uuid_interner::UuidInterner;
+    ///
+    /// let result = UuidInterner::new(16);
+    /// assert_eq!(16, result.max_entries);
+    /// assert!(result.is_empty());
+    /// ```
+    pub fn new(max_entries: usize) -> Self {
+        Self {
+            max_entries,
+            entries: HashMap::new(),
+        }
+    }
+
+    /// Number of cached UUID lists.
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    /// Check no UUID list is cached.
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+
+    /// Remove all cached UUID lists.
+    pub fn clear(&mut self) {
+        self.entries.clear();
+    }
+
+    /// Returns the shared UUIDs of the raw [`UuidList`] AD structure.
+    ///
+    /// The structure is parsed only the first time it is seen.
+    /// The cache is cleared when it reaches `max_entries`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::sync::Arc;
+    /// use ble_data_struct::{
+    ///     data_types::complete_list_of_128bit_service_uuids::CompleteListOf128BitServiceUuids,
+    ///     scan::uuid_interner::UuidInterner,
+    /// };
+    /// use uuid::uuid;
+    ///
+    /// let uuids = vec![uuid!("00112233-4455-6677-8899-aabbccddeeff")];
+    /// let data: Vec<u8> = CompleteListOf128BitServiceUuids::new(&uuids).into();
+    ///
+    /// let mut interner = UuidInterner::default();
+    /// let first = interner.intern::<128, true>(&data).unwrap();
+    /// let second = interner.intern::<128, true>(&data).unwrap();
+    /// assert_eq!(uuids.as_slice(), &*first);
+    /// assert!(Arc::ptr_eq(&first, &second));
+    ///
+    /// assert!(interner.intern::<128, true>(&vec![0x02, 0x07, 0x00]).is_err());
+    /// ```
+    pub fn intern<const WIDTH: usize, const COMPLETE: bool>(
+        &mut self,
+        data: &Vec<u8>,
+    ) -> Result<Arc<[Uuid]>, String> {
+        if let Some(uuids) = self.entries.get(data) {
+            return Ok(uuids.clone());
+        }
+        let uuids: Arc<[Uuid]> = UuidList::<WIDTH, COMPLETE>::try_from(data)?.uuids.into();
+        if self.entries.len() >= self.max_entries {
+            self.entries.clear();
+        }
+        if self.max_entries > 0 {
+            self.entries.insert(data.clone(), uuids.clone());
+        }
+        Ok(uuids)
+    }
+}
+
+impl Default for UuidInterner {
+    /// Create [`UuidInterner`] with [`DEFAULT_MAX_ENTRIES`].
+    fn default() -> Self {
+        Self::new(DEFAULT_MAX_ENTRIES)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+
+    use uuid::uuid;
+
+    use crate::{
+        data_types::{
+            complete_list_of_128bit_service_uuids::CompleteListOf128BitServiceUuids,
+            incomplete_list_of_16bit_service_uuids::IncompleteListOf16BitServiceUuids,
+        },
+        scan::uuid_interner::{UuidInterner, DEFAULT_MAX_ENTRIES},
+        uuid_from_u16,
+    };
+
+    #[test]
+    fn test_new() {
+        let result = UuidInterner::new(16);
+        assert_eq!(16, result.max_entries);
+        assert!(result.is_empty());
+        assert_eq!(DEFAULT_MAX_ENTRIES, UuidInterner::default().max_entries);
+    }
+
+    #[test]
+    fn test_intern() {
+        let uuids = vec![uuid!("00112233-4455-6677-8899-aabbccddeeff")];
+        let data: Vec<u8> = CompleteListOf128BitServiceUuids::new(&uuids).into();
+        let mut interner = UuidInterner::default();
+        let first = interner.intern::<128, true>(&data).unwrap();
+        let second = interner.intern::<128, true>(&data).unwrap();
+        assert_eq!(uuids.as_slice(), &*first);
+        assert!(Arc::ptr_eq(&first, &second));
+        assert_eq!(1, interner.len());
+
+        let uuids = vec![uuid_from_u16(0x180f), uuid_from_u16(0x180a)];
+        let data: Vec<u8> = IncompleteListOf16BitServiceUuids::new(&uuids).into();
+        let result = interner.intern::<16, false>(&data).unwrap();
+        assert_eq!(uuids.as_slice(), &*result);
+        assert_eq!(2, interner.len());
+
+        assert!(interner
+            .intern::<16, false>(&vec![0x02, 0x02, 0x00])
+            .is_err());
+        assert_eq!(2, interner.len());
+
+        interner.clear();
+        assert!(interner.is_empty());
+    }
+
+    #[test]
+    fn test_intern_max_entries() {
+        let mut interner = UuidInterner::new(1);
+        let data1: Vec<u8> =
+            IncompleteListOf16BitServiceUuids::new(&vec![uuid_from_u16(0x180f)]).into();
+        let data2: Vec<u8> =
+            IncompleteListOf16BitServiceUuids::new(&vec![uuid_from_u16(0x180a)]).into();
+        let first = interner.intern::<16, false>(&data1).unwrap();
+        interner.intern::<16, false>(&data2).unwrap();
+        assert_eq!(1, interner.len());
+        let result = interner.intern::<16, false>(&data1).unwrap();
+        assert!(!Arc::ptr_eq(&first, &result));
+
+        let mut interner = UuidInterner::new(0);
+        interner.intern::<16, false>(&data1).unwrap();
+        assert!(interner.is_empty());
+    }
+}
@@ -0,0 +1,128 @@
+//! Broadcast Audio Announcement service data module.
+
+use crate::{
+    data_types::service_data_16bit_uuid::ServiceData16BitUUID, uuid_from_u16, uuids::service,
+};
+
+/// Broadcast Audio Announcement Service UUID.
+pub const BROADCAST_AUDIO_ANNOUNCEMENT_UUID: u16 = service::BROADCAST_AUDIO_ANNOUNCEMENT;
+
+/// Broadcast Audio Announcement service data.
+#[derive(Debug, PartialEq, Clone)]
+pub struct BroadcastAudioAnnouncement {
+    /// Broadcast_ID (24 bits)
+    pub broadcast_id: u32,
+}
+
+impl BroadcastAudioAnnouncement {
+    /// Create [`BroadcastAudioAnnouncement`] from `Broadcast_ID`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ble_data_struct::service_data::broadcast_audio_announcement::BroadcastAudioAnnouncement;
+    ///
+    /// let result = BroadcastAudioAnnouncement::new(0x123456);
+    /// assert_eq!(0x123456, result.broadcast_id);
+    /// ```
+    pub fn new(broadcast_id: u32) -> Self {
+        Self { broadcast_id }
+    }
+}
+
+impl TryFrom<&Vec<u8>> for BroadcastAudioAnnouncement {
+    type Error = String;
+    /// Create [`BroadcastAudioAnnouncement`] from service data (after the UUID).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ble_data_struct::service_data::broadcast_audio_announcement::BroadcastAudioAnnouncement;
+    ///
+    /// let result = BroadcastAudioAnnouncement::try_from(&vec![0x56, 0x34, 0x12]);
+    /// assert_eq!(Ok(BroadcastAudioAnnouncement::new(0x123456)), result);
+    ///
+    /// let data: Vec<u8> = Vec::new();
+    /// let result = BroadcastAudioAnnouncement::try_from(&data);
+    /// assert_eq!(Err(format!("Invalid data size :{}", data.len())), result);
+    /// ```
+    fn try_from(value: &Vec<u8>) -> Result<Self, String> {
+        let len = value.len();
+        if len != 3 {
+            return Err(format!("Invalid data size :{}", len));
+        }
+        Ok(Self {
+            broadcast_id: u32::from_le_bytes([value[0], value[1], value[2], 0x00]),
+        })
+    }
+}
+
+impl Into<Vec<u8>> for BroadcastAudioAnnouncement {
+    /// Create service data (after the UUID) from [`BroadcastAudioAnnouncement`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ble_data_struct::service_data::broadcast_audio_announcement::BroadcastAudioAnnouncement;
+    ///
+    /// let into_data: Vec<u8> = BroadcastAudioAnnouncement::new(0x123456).into();
+    /// assert_eq!(vec![0x56, 0x34, 0x12], into_data);
+    /// ```
+    fn into(self) -> Vec<u8> {
+        self.broadcast_id.to_le_bytes()[..3].to_vec()
+    }
+}
+
+impl From<&BroadcastAudioAnnouncement> for ServiceData16BitUUID {
+    /// Create [`ServiceData16BitUUID`] from [`BroadcastAudioAnnouncement`].
+    fn from(value: &BroadcastAudioAnnouncement) -> Self {
+        Self::new(
+            &uuid_from_u16(BROADCAST_AUDIO_ANNOUNCEMENT_UUID),
+            &value.clone().into(),
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        data_types::service_data_16bit_uuid::ServiceData16BitUUID,
+        service_data::broadcast_audio_announcement::{
+            BroadcastAudioAnnouncement, BROADCAST_AUDIO_ANNOUNCEMENT_UUID,
+        },
+        uuid_from_u16,
+    };
+
+    #[test]
+    fn test_new() {
+        let result = BroadcastAudioAnnouncement::new(0x123456);
+        assert_eq!(0x123456, result.broadcast_id);
+    }
+
+    #[test]
+    fn test_try_from() {
+        let result = BroadcastAudioAnnouncement::try_from(&vec![0x56, 0x34, 0x12]);
+        assert_eq!(Ok(BroadcastAudioAnnouncement::new(0x123456)), result);
+
+        let result = BroadcastAudioAnnouncement::try_from(&vec![0x56, 0x34, 0x12, 0x00]);
+        assert_eq!(Err("Invalid data size :4".to_string()), result);
+    }
+
+    #[test]
+    fn test_into() {
+        let into_data: Vec<u8> = BroadcastAudioAnnouncement::new(0xabcdef).into();
+        assert_eq!(vec![0xef, 0xcd, 0xab], into_data);
+    }
+
+    #[test]
+    fn test_from() {
+        let result = ServiceData16BitUUID::from(&BroadcastAudioAnnouncement::new(0x123456));
+        assert_eq!(
+            ServiceData16BitUUID::new(
+                &uuid_from_u16(BROADCAST_AUDIO_ANNOUNCEMENT_UUID),
+                &vec![0x56, 0x34, 0x12]
+            ),
+            result
+        );
+    }
+}
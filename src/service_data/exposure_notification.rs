@@ -0,0 +1,138 @@
+//! Exposure Notification service data module.
+
+use crate::{data_types::service_data_16bit_uuid::ServiceData16BitUUID, uuid_from_u16};
+
+/// Exposure Notification Service UUID.
+pub const EXPOSURE_NOTIFICATION_UUID: u16 = 0xfd6f;
+
+/// Exposure Notification service data.
+#[derive(Debug, PartialEq, Clone)]
+pub struct ExposureNotification {
+    /// Rolling Proximity Identifier
+    pub rolling_proximity_identifier: [u8; 16],
+
+    /// Associated Encrypted Metadata
+    pub associated_encrypted_metadata: [u8; 4],
+}
+
+impl ExposureNotification {
+    /// Create [`ExposureNotification`] from Parameters.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ble_data_struct::service_data::exposure_notification::ExposureNotification;
+    ///
+    /// let result = ExposureNotification::new([1; 16], [2; 4]);
+    /// assert_eq!([1; 16], result.rolling_proximity_identifier);
+    /// assert_eq!([2; 4], result.associated_encrypted_metadata);
+    /// ```
+    pub fn new(
+        rolling_proximity_identifier: [u8; 16],
+        associated_encrypted_metadata: [u8; 4],
+    ) -> Self {
+        Self {
+            rolling_proximity_identifier,
+            associated_encrypted_metadata,
+        }
+    }
+}
+
+impl TryFrom<&Vec<u8>> for ExposureNotification {
+    type Error = String;
+    /// Create [`ExposureNotification`] from service data (after the UUID).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ble_data_struct::service_data::exposure_notification::ExposureNotification;
+    ///
+    /// let data = [[1u8; 16].to_vec(), [2u8; 4].to_vec()].concat();
+    /// let result = ExposureNotification::try_from(&data);
+    /// assert_eq!(Ok(ExposureNotification::new([1; 16], [2; 4])), result);
+    ///
+    /// let data: Vec<u8> = Vec::new();
+    /// let result = ExposureNotification::try_from(&data);
+    /// assert_eq!(Err(format!("Invalid data size :{}", data.len())), result);
+    /// ```
+    fn try_from(value: &Vec<u8>) -> Result<Self, String> {
+        let len = value.len();
+        if len != 20 {
+            return Err(format!("Invalid data size :{}", len));
+        }
+        Ok(Self {
+            rolling_proximity_identifier: value[0..16].try_into().unwrap(),
+            associated_encrypted_metadata: value[16..20].try_into().unwrap(),
+        })
+    }
+}
+
+impl Into<Vec<u8>> for ExposureNotification {
+    /// Create service data (after the UUID) from [`ExposureNotification`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ble_data_struct::service_data::exposure_notification::ExposureNotification;
+    ///
+    /// let into_data: Vec<u8> = ExposureNotification::new([1; 16], [2; 4]).into();
+    /// assert_eq!([[1u8; 16].to_vec(), [2u8; 4].to_vec()].concat(), into_data);
+    /// ```
+    fn into(self) -> Vec<u8> {
+        let mut data: Vec<u8> = Vec::new();
+        data.append(&mut self.rolling_proximity_identifier.to_vec());
+        data.append(&mut self.associated_encrypted_metadata.to_vec());
+        return data;
+    }
+}
+
+impl From<&ExposureNotification> for ServiceData16BitUUID {
+    /// Create [`ServiceData16BitUUID`] from [`ExposureNotification`].
+    fn from(value: &ExposureNotification) -> Self {
+        Self::new(
+            &uuid_from_u16(EXPOSURE_NOTIFICATION_UUID),
+            &value.clone().into(),
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        data_types::service_data_16bit_uuid::ServiceData16BitUUID,
+        service_data::exposure_notification::{ExposureNotification, EXPOSURE_NOTIFICATION_UUID},
+        uuid_from_u16,
+    };
+
+    #[test]
+    fn test_new() {
+        let result = ExposureNotification::new([1; 16], [2; 4]);
+        assert_eq!([1; 16], result.rolling_proximity_identifier);
+        assert_eq!([2; 4], result.associated_encrypted_metadata);
+    }
+
+    #[test]
+    fn test_try_from() {
+        let data = [[1u8; 16].to_vec(), [2u8; 4].to_vec()].concat();
+        let result = ExposureNotification::try_from(&data);
+        assert_eq!(Ok(ExposureNotification::new([1; 16], [2; 4])), result);
+
+        let result = ExposureNotification::try_from(&data[..19].to_vec());
+        assert_eq!(Err("Invalid data size :19".to_string()), result);
+    }
+
+    #[test]
+    fn test_into() {
+        let into_data: Vec<u8> = ExposureNotification::new([1; 16], [2; 4]).into();
+        assert_eq!([[1u8; 16].to_vec(), [2u8; 4].to_vec()].concat(), into_data);
+    }
+
+    #[test]
+    fn test_from() {
+        let value = ExposureNotification::new([1; 16], [2; 4]);
+        let result = ServiceData16BitUUID::from(&value);
+        assert_eq!(uuid_from_u16(EXPOSURE_NOTIFICATION_UUID), result.uuid);
+        let data: Vec<u8> = value.into();
+        assert_eq!(data, result.additional_service_data);
+    }
+}
@@ -0,0 +1,304 @@
+//! Matter BLE commissioning advertisement service data module.
+//!
+//! Commissionable Matter devices advertise the Matter BLE service data (Matter Core Specification, 5.4.2.5.6).
+
+use crate::{
+    data_types::{parse_error::DataTypeParseError, service_data_16bit_uuid::ServiceData16BitUUID},
+    uuid_from_u16,
+};
+
+/// Matter BLE Service UUID.
+pub const MATTER_UUID: u16 = 0xfff6;
+
+/// Matter BLE OpCode: Commissionable.
+pub const OPCODE_COMMISSIONABLE: u8 = 0x00;
+
+/// Discriminator mask (12 bits).
+pub const DISCRIMINATOR_MASK: u16 = 0x0fff;
+
+/// Additional Data Flag (GATT-based additional data is available).
+pub const ADDITIONAL_DATA_FLAG: u8 = 0b0000_0001;
+
+/// Extended Announcement Flag.
+pub const EXTENDED_ANNOUNCEMENT_FLAG: u8 = 0b0000_0010;
+
+/// Matter BLE commissioning advertisement service data.
+#[derive(Debug, PartialEq, Clone)]
+pub struct MatterCommissioning {
+    /// Matter BLE OpCode
+    pub opcode: u8,
+
+    /// Discriminator (12 bits)
+    pub discriminator: u16,
+
+    /// Advertisement version (4 bits)
+    pub advertisement_version: u8,
+
+    /// Vendor ID
+    pub vendor_id: u16,
+
+    /// Product ID
+    pub product_id: u16,
+
+    /// Commissioning flags
+    pub flags: u8,
+}
+
+impl MatterCommissioning {
+    /// Create commissionable [`MatterCommissioning`] from Parameters.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ble_data_struct::service_data::matter_commissioning::{
+    ///     MatterCommissioning, ADDITIONAL_DATA_FLAG, OPCODE_COMMISSIONABLE,
+    /// };
+    ///
+    /// let result = MatterCommissioning::new(0x0f00, 0xfff1, 0x8000, ADDITIONAL_DATA_FLAG);
+    /// assert_eq!(OPCODE_COMMISSIONABLE, result.opcode);
+    /// assert_eq!(0x0f00, result.discriminator);
+    /// assert_eq!(0, result.advertisement_version);
+    /// assert_eq!(0xfff1, result.vendor_id);
+    /// assert_eq!(0x8000, result.product_id);
+    /// assert_eq!(ADDITIONAL_DATA_FLAG, result.flags);
+    /// ```
+    pub fn new(discriminator: u16, vendor_id: u16, product_id: u16, flags: u8) -> Self {
+        Self {
+            opcode: OPCODE_COMMISSIONABLE,
+            discriminator: discriminator & DISCRIMINATOR_MASK,
+            advertisement_version: 0,
+            vendor_id,
+            product_id,
+            flags,
+        }
+    }
+
+    /// check Matter BLE OpCode is Commissionable.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ble_data_struct::service_data::matter_commissioning::MatterCommissioning;
+    ///
+    /// let result = MatterCommissioning::new(0x0f00, 0xfff1, 0x8000, 0);
+    /// assert!(result.is_commissionable());
+    ///
+    /// let result = MatterCommissioning::try_from(&vec![0x01, 0x00, 0x0f, 0xf1, 0xff, 0x00, 0x80, 0x00]).unwrap();
+    /// assert!(!result.is_commissionable());
+    /// ```
+    pub fn is_commissionable(&self) -> bool {
+        self.opcode == OPCODE_COMMISSIONABLE
+    }
+
+    /// check Additional Data Flag.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ble_data_struct::service_data::matter_commissioning::{
+    ///     MatterCommissioning, ADDITIONAL_DATA_FLAG,
+    /// };
+    ///
+    /// let result = MatterCommissioning::new(0x0f00, 0xfff1, 0x8000, ADDITIONAL_DATA_FLAG);
+    /// assert!(result.has_additional_data());
+    ///
+    /// let result = MatterCommissioning::new(0x0f00, 0xfff1, 0x8000, 0);
+    /// assert!(!result.has_additional_data());
+    /// ```
+    pub fn has_additional_data(&self) -> bool {
+        self.flags & ADDITIONAL_DATA_FLAG != 0
+    }
+
+    /// check Extended Announcement Flag.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ble_data_struct::service_data::matter_commissioning::{
+    ///     MatterCommissioning, EXTENDED_ANNOUNCEMENT_FLAG,
+    /// };
+    ///
+    /// let result = MatterCommissioning::new(0x0f00, 0xfff1, 0x8000, EXTENDED_ANNOUNCEMENT_FLAG);
+    /// assert!(result.is_extended_announcement());
+    ///
+    /// let result = MatterCommissioning::new(0x0f00, 0xfff1, 0x8000, 0);
+    /// assert!(!result.is_extended_announcement());
+    /// ```
+    pub fn is_extended_announcement(&self) -> bool {
+        self.flags & EXTENDED_ANNOUNCEMENT_FLAG != 0
+    }
+
+    /// Short discriminator (upper 4 bits of the discriminator, used by the manual pairing code).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ble_data_struct::service_data::matter_commissioning::MatterCommissioning;
+    ///
+    /// let result = MatterCommissioning::new(0x0f00, 0xfff1, 0x8000, 0);
+    /// assert_eq!(0x0f, result.short_discriminator());
+    /// ```
+    pub fn short_discriminator(&self) -> u8 {
+        (self.discriminator >> 8) as u8
+    }
+}
+
+impl TryFrom<&Vec<u8>> for MatterCommissioning {
+    type Error = DataTypeParseError;
+    /// Create [`MatterCommissioning`] from service data (after the UUID).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ble_data_struct::{
+    ///     data_types::parse_error::DataTypeParseError,
+    ///     service_data::matter_commissioning::MatterCommissioning,
+    /// };
+    ///
+    /// let data = vec![0x00, 0x00, 0x0f, 0xf1, 0xff, 0x00, 0x80, 0x00];
+    /// let result = MatterCommissioning::try_from(&data);
+    /// assert_eq!(Ok(MatterCommissioning::new(0x0f00, 0xfff1, 0x8000, 0)), result);
+    ///
+    /// let data: Vec<u8> = Vec::new();
+    /// let result = MatterCommissioning::try_from(&data);
+    /// assert_eq!(
+    ///     Err(DataTypeParseError::InvalidLength {
+    ///         expected: 8,
+    ///         actual: 0
+    ///     }),
+    ///     result
+    /// );
+    /// ```
+    fn try_from(value: &Vec<u8>) -> Result<Self, DataTypeParseError> {
+        let len = value.len();
+        if len != 8 {
+            return Err(DataTypeParseError::InvalidLength {
+                expected: 8,
+                actual: len,
+            });
+        }
+        let discriminator_and_version = u16::from_le_bytes([value[1], value[2]]);
+        Ok(Self {
+            opcode: value[0],
+            discriminator: discriminator_and_version & DISCRIMINATOR_MASK,
+            advertisement_version: (discriminator_and_version >> 12) as u8,
+            vendor_id: u16::from_le_bytes([value[3], value[4]]),
+            product_id: u16::from_le_bytes([value[5], value[6]]),
+            flags: value[7],
+        })
+    }
+}
+
+impl Into<Vec<u8>> for MatterCommissioning {
+    /// Create service data (after the UUID) from [`MatterCommissioning`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ble_data_struct::service_data::matter_commissioning::{
+    ///     MatterCommissioning, ADDITIONAL_DATA_FLAG,
+    /// };
+    ///
+    /// let into_data: Vec<u8> = MatterCommissioning::new(0x0f00, 0xfff1, 0x8000, ADDITIONAL_DATA_FLAG).into();
+    /// assert_eq!(vec![0x00, 0x00, 0x0f, 0xf1, 0xff, 0x00, 0x80, 0x01], into_data);
+    /// ```
+    fn into(self) -> Vec<u8> {
+        let mut data: Vec<u8> = Vec::new();
+        data.push(self.opcode);
+        data.append(
+            &mut ((self.discriminator & DISCRIMINATOR_MASK)
+                | (self.advertisement_version as u16 & 0x0f) << 12)
+                .to_le_bytes()
+                .to_vec(),
+        );
+        data.append(&mut self.vendor_id.to_le_bytes().to_vec());
+        data.append(&mut self.product_id.to_le_bytes().to_vec());
+        data.push(self.flags);
+        return data;
+    }
+}
+
+impl From<&MatterCommissioning> for ServiceData16BitUUID {
+    /// Create [`ServiceData16BitUUID`] from [`MatterCommissioning`].
+    fn from(value: &MatterCommissioning) -> Self {
+        Self::new(&uuid_from_u16(MATTER_UUID), &value.clone().into())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        data_types::{
+            parse_error::DataTypeParseError, service_data_16bit_uuid::ServiceData16BitUUID,
+        },
+        service_data::matter_commissioning::{
+            MatterCommissioning, ADDITIONAL_DATA_FLAG, EXTENDED_ANNOUNCEMENT_FLAG, MATTER_UUID,
+            OPCODE_COMMISSIONABLE,
+        },
+        uuid_from_u16,
+    };
+
+    #[test]
+    fn test_new() {
+        let result = MatterCommissioning::new(0xff00, 0xfff1, 0x8000, ADDITIONAL_DATA_FLAG);
+        assert_eq!(OPCODE_COMMISSIONABLE, result.opcode);
+        assert_eq!(0x0f00, result.discriminator);
+        assert_eq!(0, result.advertisement_version);
+        assert_eq!(0xfff1, result.vendor_id);
+        assert_eq!(0x8000, result.product_id);
+        assert!(result.is_commissionable());
+        assert!(result.has_additional_data());
+        assert!(!result.is_extended_announcement());
+        assert_eq!(0x0f, result.short_discriminator());
+    }
+
+    #[test]
+    fn test_try_from() {
+        let data = vec![0x00, 0x34, 0x1b, 0xf1, 0xff, 0x00, 0x80, 0x03];
+        let result = MatterCommissioning::try_from(&data).unwrap();
+        assert_eq!(OPCODE_COMMISSIONABLE, result.opcode);
+        assert_eq!(0x0b34, result.discriminator);
+        assert_eq!(1, result.advertisement_version);
+        assert_eq!(0xfff1, result.vendor_id);
+        assert_eq!(0x8000, result.product_id);
+        assert_eq!(
+            ADDITIONAL_DATA_FLAG | EXTENDED_ANNOUNCEMENT_FLAG,
+            result.flags
+        );
+        assert!(result.has_additional_data());
+        assert!(result.is_extended_announcement());
+
+        let result = MatterCommissioning::try_from(&data[..7].to_vec());
+        assert_eq!(
+            Err(DataTypeParseError::InvalidLength {
+                expected: 8,
+                actual: 7
+            }),
+            result
+        );
+        let result = MatterCommissioning::try_from(&[data.clone(), vec![0x00]].concat());
+        assert_eq!(
+            Err(DataTypeParseError::InvalidLength {
+                expected: 8,
+                actual: 9
+            }),
+            result
+        );
+    }
+
+    #[test]
+    fn test_into() {
+        let data = vec![0x00, 0x34, 0x1b, 0xf1, 0xff, 0x00, 0x80, 0x03];
+        let into_data: Vec<u8> = MatterCommissioning::try_from(&data).unwrap().into();
+        assert_eq!(data, into_data);
+    }
+
+    #[test]
+    fn test_from() {
+        let value = MatterCommissioning::new(0x0f00, 0xfff1, 0x8000, 0);
+        let result = ServiceData16BitUUID::from(&value);
+        assert_eq!(uuid_from_u16(MATTER_UUID), result.uuid);
+        let data: Vec<u8> = value.into();
+        assert_eq!(data, result.additional_service_data);
+    }
+}
@@ -0,0 +1,380 @@
+//! Service data decoder registry module.
+//!
+//! Maps 16-bit service UUIDs to decoders so [`ServiceData16BitUUID`] can return typed payloads.
+
+use std::{
+    any::Any,
+    collections::HashMap,
+    sync::{Arc, OnceLock, RwLock},
+};
+
+use crate::{
+    beacons::eddystone::{Eddystone, EDDYSTONE_UUID},
+    data_types::service_data_16bit_uuid::ServiceData16BitUUID,
+    service_data::{
+        broadcast_audio_announcement::{
+            BroadcastAudioAnnouncement, BROADCAST_AUDIO_ANNOUNCEMENT_UUID,
+        },
+        exposure_notification::{ExposureNotification, EXPOSURE_NOTIFICATION_UUID},
+    },
+    uuid_from_u16,
+};
+
+#[cfg(feature = "matter")]
+use crate::service_data::matter_commissioning::{MatterCommissioning, MATTER_UUID};
+
+/// Typed service data payload.
+///
+/// Variants are added with the built-in decoders(and their features), match with a wildcard arm.
+#[derive(Debug, Clone)]
+#[non_exhaustive]
+pub enum ServiceDataPayload {
+    /// Eddystone (0xfeaa)
+    Eddystone(Eddystone),
+
+    /// Exposure Notification (0xfd6f)
+    ExposureNotification(ExposureNotification),
+
+    /// Broadcast Audio Announcement (0x1852)
+    BroadcastAudioAnnouncement(BroadcastAudioAnnouncement),
+
+    /// Matter commissioning (0xfff6)
+    #[cfg(feature = "matter")]
+    MatterCommissioning(MatterCommissioning),
+
+    /// Payload of a user registered decoder
+    Custom(Arc<dyn Any + Send + Sync>),
+}
+
+impl PartialEq for ServiceDataPayload {
+    /// `Custom` payloads are equal only if they share the same allocation.
+    fn eq(&self, other: &Self) -> bool {
+        match (self, other) {
+            (Self::Eddystone(a), Self::Eddystone(b)) => a == b,
+            (Self::ExposureNotification(a), Self::ExposureNotification(b)) => a == b,
+            (Self::BroadcastAudioAnnouncement(a), Self::BroadcastAudioAnnouncement(b)) => a == b,
+            #[cfg(feature = "matter")]
+            (Self::MatterCommissioning(a), Self::MatterCommissioning(b)) => a == b,
+            (Self::Custom(a), Self::Custom(b)) => Arc::ptr_eq(a, b),
+            _ => false,
+        }
+    }
+}
+
+/// Service data decoder(additional service data to typed payload).
+pub type ServiceDataDecoder = fn(&Vec<u8>) -> Result<ServiceDataPayload, String>;
+
+/// Service data decoder registry.
+#[derive(Debug, Clone)]
+pub struct ServiceDataRegistry {
+    /// 16-bit UUID to decoder
+    decoders: HashMap<u16, ServiceDataDecoder>,
+}
+
+impl ServiceDataRegistry {
+    /// Create empty [`ServiceDataRegistry`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ble_data_struct::service_data::registry::ServiceDataRegistry;
+    ///
+    /// let result = ServiceDataRegistry::new();
+    /// assert!(!result.contains(0xfeaa));
+    ///
+    /// let result = ServiceDataRegistry::default();
+    /// assert!(result.contains(0xfeaa));
+    /// assert!(result.contains(0xfd6f));
+    /// assert!(result.contains(0x1852));
+    /// ```
+    pub fn new() -> Self {
+        Self {
+            decoders: HashMap::new(),
+        }
+    }
+
+    /// Register decoder for 16-bit UUID and return the replaced decoder.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::sync::Arc;
+    /// use ble_data_struct::service_data::registry::{ServiceDataPayload, ServiceDataRegistry};
+    ///
+    /// let mut registry = ServiceDataRegistry::new();
+    /// let result = registry.register(0x180f, |data| match data.first() {
+    ///     Some(level) => Ok(ServiceDataPayload::Custom(Arc::new(*level))),
+    ///     None => Err("Invalid data size :0".to_string()),
+    /// });
+    /// assert!(result.is_none());
+    /// assert!(registry.contains(0x180f));
+    /// ```
+    pub fn register(
+        &mut self,
+        uuid: u16,
+        decoder: ServiceDataDecoder,
+    ) -> Option<ServiceDataDecoder> {
+        self.decoders.insert(uuid, decoder)
+    }
+
+    /// Unregister decoder for 16-bit UUID and return it.
+    pub fn unregister(&mut self, uuid: u16) -> Option<ServiceDataDecoder> {
+        self.decoders.remove(&uuid)
+    }
+
+    /// Check a decoder is registered for 16-bit UUID.
+    pub fn contains(&self, uuid: u16) -> bool {
+        self.decoders.contains_key(&uuid)
+    }
+
+    /// Decode [`ServiceData16BitUUID`] (`None` if no decoder is registered).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ble_data_struct::{
+    ///     beacons::eddystone::Eddystone,
+    ///     data_types::service_data_16bit_uuid::ServiceData16BitUUID,
+    ///     service_data::registry::{ServiceDataPayload, ServiceDataRegistry},
+    ///     uuid_from_u16,
+    /// };
+    ///
+    /// let registry = ServiceDataRegistry::default();
+    /// let eddystone = Eddystone::Uid {
+    ///     tx_power: -20,
+    ///     namespace: [0; 10],
+    ///     instance: [1; 6],
+    /// };
+    /// let result = registry.decode(&ServiceData16BitUUID::from(&eddystone));
+    /// assert_eq!(Some(Ok(ServiceDataPayload::Eddystone(eddystone))), result);
+    ///
+    /// let service_data = ServiceData16BitUUID::new(&uuid_from_u16(0x180f), &vec![50]);
+    /// assert_eq!(None, registry.decode(&service_data));
+    /// ```
+    pub fn decode(
+        &self,
+        service_data: &ServiceData16BitUUID,
+    ) -> Option<Result<ServiceDataPayload, String>> {
+        let uuid = (service_data.uuid.as_fields().0 & 0xffff) as u16;
+        if uuid_from_u16(uuid) != service_data.uuid {
+            return None;
+        }
+        self.decoders
+            .get(&uuid)
+            .map(|decoder| decoder(&service_data.additional_service_data))
+    }
+}
+
+impl Default for ServiceDataRegistry {
+    /// Create [`ServiceDataRegistry`] with the built-in decoders.
+    fn default() -> Self {
+        let mut registry = Self::new();
+        registry.register(EDDYSTONE_UUID, |data| {
+            Eddystone::try_from(data).map(ServiceDataPayload::Eddystone)
+        });
+        registry.register(EXPOSURE_NOTIFICATION_UUID, |data| {
+            ExposureNotification::try_from(data).map(ServiceDataPayload::ExposureNotification)
+        });
+        registry.register(BROADCAST_AUDIO_ANNOUNCEMENT_UUID, |data| {
+            BroadcastAudioAnnouncement::try_from(data)
+                .map(ServiceDataPayload::BroadcastAudioAnnouncement)
+        });
+        #[cfg(feature = "matter")]
+        registry.register(MATTER_UUID, |data| {
+            MatterCommissioning::try_from(data)
+                .map(ServiceDataPayload::MatterCommissioning)
+                .map_err(String::from)
+        });
+        registry
+    }
+}
+
+/// Process wide registry used by [`ServiceData16BitUUID::payload`].
+fn global_registry() -> &'static RwLock<ServiceDataRegistry> {
+    static REGISTRY: OnceLock<RwLock<ServiceDataRegistry>> = OnceLock::new();
+    REGISTRY.get_or_init(|| RwLock::new(ServiceDataRegistry::default()))
+}
+
+/// Register decoder to the process wide registry and return the replaced decoder.
+pub fn register_service_data_decoder(
+    uuid: u16,
+    decoder: ServiceDataDecoder,
+) -> Option<ServiceDataDecoder> {
+    global_registry()
+        .write()
+        .unwrap_or_else(|e| e.into_inner())
+        .register(uuid, decoder)
+}
+
+/// Unregister decoder from the process wide registry and return it.
+pub fn unregister_service_data_decoder(uuid: u16) -> Option<ServiceDataDecoder> {
+    global_registry()
+        .write()
+        .unwrap_or_else(|e| e.into_inner())
+        .unregister(uuid)
+}
+
+impl ServiceData16BitUUID {
+    /// Decode additional service data with the process wide registry.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ble_data_struct::{
+    ///     data_types::service_data_16bit_uuid::ServiceData16BitUUID,
+    ///     service_data::{
+    ///         broadcast_audio_announcement::BroadcastAudioAnnouncement,
+    ///         registry::ServiceDataPayload,
+    ///     },
+    /// };
+    ///
+    /// let announcement = BroadcastAudioAnnouncement::new(0x123456);
+    /// let result = ServiceData16BitUUID::from(&announcement).payload();
+    /// assert_eq!(
+    ///     Some(Ok(ServiceDataPayload::BroadcastAudioAnnouncement(announcement))),
+    ///     result
+    /// );
+    /// ```
+    pub fn payload(&self) -> Option<Result<ServiceDataPayload, String>> {
+        global_registry()
+            .read()
+            .unwrap_or_else(|e| e.into_inner())
+            .decode(self)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+
+    use uuid::uuid;
+
+    use crate::{
+        beacons::eddystone::Eddystone,
+        data_types::service_data_16bit_uuid::ServiceData16BitUUID,
+        service_data::{
+            broadcast_audio_announcement::BroadcastAudioAnnouncement,
+            exposure_notification::ExposureNotification,
+            registry::{
+                register_service_data_decoder, unregister_service_data_decoder, ServiceDataPayload,
+                ServiceDataRegistry,
+            },
+        },
+        uuid_from_u16,
+    };
+
+    fn battery_level(data: &Vec<u8>) -> Result<ServiceDataPayload, String> {
+        match data.as_slice() {
+            [level] => Ok(ServiceDataPayload::Custom(Arc::new(*level))),
+            _ => Err(format!("Invalid data size :{}", data.len())),
+        }
+    }
+
+    #[test]
+    fn test_register() {
+        let mut registry = ServiceDataRegistry::new();
+        assert!(!registry.contains(0x180f));
+        assert!(registry.register(0x180f, battery_level).is_none());
+        assert!(registry.contains(0x180f));
+        assert!(registry.register(0x180f, battery_level).is_some());
+        assert!(registry.unregister(0x180f).is_some());
+        assert!(!registry.contains(0x180f));
+        assert!(registry.unregister(0x180f).is_none());
+    }
+
+    #[test]
+    fn test_decode() {
+        let registry = ServiceDataRegistry::default();
+
+        let eddystone = Eddystone::Tlm {
+            battery_voltage: 3000,
+            beacon_temperature: 0x1800,
+            advertising_pdu_count: 1,
+            time_since_power_on: 2,
+        };
+        let result = registry.decode(&ServiceData16BitUUID::from(&eddystone));
+        assert_eq!(Some(Ok(ServiceDataPayload::Eddystone(eddystone))), result);
+
+        let exposure_notification = ExposureNotification::new([1; 16], [2; 4]);
+        let result = registry.decode(&ServiceData16BitUUID::from(&exposure_notification));
+        assert_eq!(
+            Some(Ok(ServiceDataPayload::ExposureNotification(
+                exposure_notification
+            ))),
+            result
+        );
+
+        let announcement = BroadcastAudioAnnouncement::new(0x123456);
+        let result = registry.decode(&ServiceData16BitUUID::from(&announcement));
+        assert_eq!(
+            Some(Ok(ServiceDataPayload::BroadcastAudioAnnouncement(
+                announcement
+            ))),
+            result
+        );
+
+        let service_data = ServiceData16BitUUID::new(&uuid_from_u16(0xfd6f), &vec![0x00]);
+        assert_eq!(
+            Some(Err("Invalid data size :1".to_string())),
+            registry.decode(&service_data)
+        );
+
+        let service_data = ServiceData16BitUUID::new(&uuid_from_u16(0x180f), &vec![50]);
+        assert_eq!(None, registry.decode(&service_data));
+
+        let service_data =
+            ServiceData16BitUUID::new(&uuid!("0000feaa-0000-0000-0000-000000000000"), &vec![0x20]);
+        assert_eq!(None, registry.decode(&service_data));
+    }
+
+    #[cfg(feature = "matter")]
+    #[test]
+    fn test_decode_matter() {
+        use crate::service_data::matter_commissioning::{MatterCommissioning, MATTER_UUID};
+
+        let registry = ServiceDataRegistry::default();
+        assert!(registry.contains(MATTER_UUID));
+
+        let matter = MatterCommissioning::new(0x0f00, 0xfff1, 0x8000, 0);
+        let result = registry.decode(&ServiceData16BitUUID::from(&matter));
+        assert_eq!(
+            Some(Ok(ServiceDataPayload::MatterCommissioning(matter))),
+            result
+        );
+    }
+
+    #[test]
+    fn test_decode_custom() {
+        let mut registry = ServiceDataRegistry::new();
+        registry.register(0x180f, battery_level);
+        let service_data = ServiceData16BitUUID::new(&uuid_from_u16(0x180f), &vec![50]);
+        match registry.decode(&service_data) {
+            Some(Ok(ServiceDataPayload::Custom(payload))) => {
+                assert_eq!(Some(&50u8), payload.downcast_ref::<u8>())
+            }
+            _ => panic!(),
+        }
+    }
+
+    #[test]
+    fn test_payload() {
+        let service_data = ServiceData16BitUUID::new(&uuid_from_u16(0x2a6e), &vec![50]);
+        assert_eq!(None, service_data.payload());
+
+        assert!(register_service_data_decoder(0x2a6e, battery_level).is_none());
+        assert!(matches!(
+            service_data.payload(),
+            Some(Ok(ServiceDataPayload::Custom(_)))
+        ));
+        assert!(unregister_service_data_decoder(0x2a6e).is_some());
+        assert_eq!(None, service_data.payload());
+
+        let announcement = BroadcastAudioAnnouncement::new(0x123456);
+        assert_eq!(
+            Some(Ok(ServiceDataPayload::BroadcastAudioAnnouncement(
+                announcement.clone()
+            ))),
+            ServiceData16BitUUID::from(&announcement).payload()
+        );
+    }
+}
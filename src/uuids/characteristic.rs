@@ -0,0 +1,757 @@
+//! GATT characteristic UUID (16bit) module.
+
+/// Device Name.
+pub const DEVICE_NAME: u16 = 0x2a00;
+
+/// Appearance.
+pub const APPEARANCE: u16 = 0x2a01;
+
+/// Peripheral Privacy Flag.
+pub const PERIPHERAL_PRIVACY_FLAG: u16 = 0x2a02;
+
+/// Reconnection Address.
+pub const RECONNECTION_ADDRESS: u16 = 0x2a03;
+
+/// Peripheral Preferred Connection Parameters.
+pub const PERIPHERAL_PREFERRED_CONNECTION_PARAMETERS: u16 = 0x2a04;
+
+/// Service Changed.
+pub const SERVICE_CHANGED: u16 = 0x2a05;
+
+/// Alert Level.
+pub const ALERT_LEVEL: u16 = 0x2a06;
+
+/// Tx Power Level.
+pub const TX_POWER_LEVEL: u16 = 0x2a07;
+
+/// Date Time.
+pub const DATE_TIME: u16 = 0x2a08;
+
+/// Day of Week.
+pub const DAY_OF_WEEK: u16 = 0x2a09;
+
+/// Day Date Time.
+pub const DAY_DATE_TIME: u16 = 0x2a0a;
+
+/// Exact Time 256.
+pub const EXACT_TIME_256: u16 = 0x2a0c;
+
+/// DST Offset.
+pub const DST_OFFSET: u16 = 0x2a0d;
+
+/// Time Zone.
+pub const TIME_ZONE: u16 = 0x2a0e;
+
+/// Local Time Information.
+pub const LOCAL_TIME_INFORMATION: u16 = 0x2a0f;
+
+/// Time with DST.
+pub const TIME_WITH_DST: u16 = 0x2a11;
+
+/// Time Accuracy.
+pub const TIME_ACCURACY: u16 = 0x2a12;
+
+/// Time Source.
+pub const TIME_SOURCE: u16 = 0x2a13;
+
+/// Reference Time Information.
+pub const REFERENCE_TIME_INFORMATION: u16 = 0x2a14;
+
+/// Time Update Control Point.
+pub const TIME_UPDATE_CONTROL_POINT: u16 = 0x2a16;
+
+/// Time Update State.
+pub const TIME_UPDATE_STATE: u16 = 0x2a17;
+
+/// Glucose Measurement.
+pub const GLUCOSE_MEASUREMENT: u16 = 0x2a18;
+
+/// Battery Level.
+pub const BATTERY_LEVEL: u16 = 0x2a19;
+
+/// Temperature Measurement.
+pub const TEMPERATURE_MEASUREMENT: u16 = 0x2a1c;
+
+/// Temperature Type.
+pub const TEMPERATURE_TYPE: u16 = 0x2a1d;
+
+/// Intermediate Temperature.
+pub const INTERMEDIATE_TEMPERATURE: u16 = 0x2a1e;
+
+/// Measurement Interval.
+pub const MEASUREMENT_INTERVAL: u16 = 0x2a21;
+
+/// Boot Keyboard Input Report.
+pub const BOOT_KEYBOARD_INPUT_REPORT: u16 = 0x2a22;
+
+/// System ID.
+pub const SYSTEM_ID: u16 = 0x2a23;
+
+/// Model Number String.
+pub const MODEL_NUMBER_STRING: u16 = 0x2a24;
+
+/// Serial Number String.
+pub const SERIAL_NUMBER_STRING: u16 = 0x2a25;
+
+/// Firmware Revision String.
+pub const FIRMWARE_REVISION_STRING: u16 = 0x2a26;
+
+/// Hardware Revision String.
+pub const HARDWARE_REVISION_STRING: u16 = 0x2a27;
+
+/// Software Revision String.
+pub const SOFTWARE_REVISION_STRING: u16 = 0x2a28;
+
+/// Manufacturer Name String.
+pub const MANUFACTURER_NAME_STRING: u16 = 0x2a29;
+
+/// IEEE 11073-20601 Regulatory Certification Data List.
+pub const IEEE_11073_20601_REGULATORY_CERTIFICATION_DATA_LIST: u16 = 0x2a2a;
+
+/// Current Time.
+pub const CURRENT_TIME: u16 = 0x2a2b;
+
+/// Magnetic Declination.
+pub const MAGNETIC_DECLINATION: u16 = 0x2a2c;
+
+/// Scan Refresh.
+pub const SCAN_REFRESH: u16 = 0x2a31;
+
+/// Boot Keyboard Output Report.
+pub const BOOT_KEYBOARD_OUTPUT_REPORT: u16 = 0x2a32;
+
+/// Boot Mouse Input Report.
+pub const BOOT_MOUSE_INPUT_REPORT: u16 = 0x2a33;
+
+/// Glucose Measurement Context.
+pub const GLUCOSE_MEASUREMENT_CONTEXT: u16 = 0x2a34;
+
+/// Blood Pressure Measurement.
+pub const BLOOD_PRESSURE_MEASUREMENT: u16 = 0x2a35;
+
+/// Intermediate Cuff Pressure.
+pub const INTERMEDIATE_CUFF_PRESSURE: u16 = 0x2a36;
+
+/// Heart Rate Measurement.
+pub const HEART_RATE_MEASUREMENT: u16 = 0x2a37;
+
+/// Body Sensor Location.
+pub const BODY_SENSOR_LOCATION: u16 = 0x2a38;
+
+/// Heart Rate Control Point.
+pub const HEART_RATE_CONTROL_POINT: u16 = 0x2a39;
+
+/// Alert Status.
+pub const ALERT_STATUS: u16 = 0x2a3f;
+
+/// Ringer Control Point.
+pub const RINGER_CONTROL_POINT: u16 = 0x2a40;
+
+/// Ringer Setting.
+pub const RINGER_SETTING: u16 = 0x2a41;
+
+/// Alert Category ID Bit Mask.
+pub const ALERT_CATEGORY_ID_BIT_MASK: u16 = 0x2a42;
+
+/// Alert Category ID.
+pub const ALERT_CATEGORY_ID: u16 = 0x2a43;
+
+/// Alert Notification Control Point.
+pub const ALERT_NOTIFICATION_CONTROL_POINT: u16 = 0x2a44;
+
+/// Unread Alert Status.
+pub const UNREAD_ALERT_STATUS: u16 = 0x2a45;
+
+/// New Alert.
+pub const NEW_ALERT: u16 = 0x2a46;
+
+/// Supported New Alert Category.
+pub const SUPPORTED_NEW_ALERT_CATEGORY: u16 = 0x2a47;
+
+/// Supported Unread Alert Category.
+pub const SUPPORTED_UNREAD_ALERT_CATEGORY: u16 = 0x2a48;
+
+/// Blood Pressure Feature.
+pub const BLOOD_PRESSURE_FEATURE: u16 = 0x2a49;
+
+/// HID Information.
+pub const HID_INFORMATION: u16 = 0x2a4a;
+
+/// Report Map.
+pub const REPORT_MAP: u16 = 0x2a4b;
+
+/// HID Control Point.
+pub const HID_CONTROL_POINT: u16 = 0x2a4c;
+
+/// Report.
+pub const REPORT: u16 = 0x2a4d;
+
+/// Protocol Mode.
+pub const PROTOCOL_MODE: u16 = 0x2a4e;
+
+/// Scan Interval Window.
+pub const SCAN_INTERVAL_WINDOW: u16 = 0x2a4f;
+
+/// PnP ID.
+pub const PNP_ID: u16 = 0x2a50;
+
+/// Glucose Feature.
+pub const GLUCOSE_FEATURE: u16 = 0x2a51;
+
+/// Record Access Control Point.
+pub const RECORD_ACCESS_CONTROL_POINT: u16 = 0x2a52;
+
+/// RSC Measurement.
+pub const RSC_MEASUREMENT: u16 = 0x2a53;
+
+/// RSC Feature.
+pub const RSC_FEATURE: u16 = 0x2a54;
+
+/// SC Control Point.
+pub const SC_CONTROL_POINT: u16 = 0x2a55;
+
+/// Aggregate.
+pub const AGGREGATE: u16 = 0x2a5a;
+
+/// CSC Measurement.
+pub const CSC_MEASUREMENT: u16 = 0x2a5b;
+
+/// CSC Feature.
+pub const CSC_FEATURE: u16 = 0x2a5c;
+
+/// Sensor Location.
+pub const SENSOR_LOCATION: u16 = 0x2a5d;
+
+/// PLX Spot-Check Measurement.
+pub const PLX_SPOT_CHECK_MEASUREMENT: u16 = 0x2a5e;
+
+/// PLX Continuous Measurement.
+pub const PLX_CONTINUOUS_MEASUREMENT: u16 = 0x2a5f;
+
+/// PLX Features.
+pub const PLX_FEATURES: u16 = 0x2a60;
+
+/// Cycling Power Measurement.
+pub const CYCLING_POWER_MEASUREMENT: u16 = 0x2a63;
+
+/// Cycling Power Vector.
+pub const CYCLING_POWER_VECTOR: u16 = 0x2a64;
+
+/// Cycling Power Feature.
+pub const CYCLING_POWER_FEATURE: u16 = 0x2a65;
+
+/// Cycling Power Control Point.
+pub const CYCLING_POWER_CONTROL_POINT: u16 = 0x2a66;
+
+/// Location and Speed.
+pub const LOCATION_AND_SPEED: u16 = 0x2a67;
+
+/// Navigation.
+pub const NAVIGATION: u16 = 0x2a68;
+
+/// Position Quality.
+pub const POSITION_QUALITY: u16 = 0x2a69;
+
+/// LN Feature.
+pub const LN_FEATURE: u16 = 0x2a6a;
+
+/// LN Control Point.
+pub const LN_CONTROL_POINT: u16 = 0x2a6b;
+
+/// Elevation.
+pub const ELEVATION: u16 = 0x2a6c;
+
+/// Pressure.
+pub const PRESSURE: u16 = 0x2a6d;
+
+/// Temperature.
+pub const TEMPERATURE: u16 = 0x2a6e;
+
+/// Humidity.
+pub const HUMIDITY: u16 = 0x2a6f;
+
+/// True Wind Speed.
+pub const TRUE_WIND_SPEED: u16 = 0x2a70;
+
+/// True Wind Direction.
+pub const TRUE_WIND_DIRECTION: u16 = 0x2a71;
+
+/// Apparent Wind Speed.
+pub const APPARENT_WIND_SPEED: u16 = 0x2a72;
+
+/// Apparent Wind Direction.
+pub const APPARENT_WIND_DIRECTION: u16 = 0x2a73;
+
+/// Gust Factor.
+pub const GUST_FACTOR: u16 = 0x2a74;
+
+/// Pollen Concentration.
+pub const POLLEN_CONCENTRATION: u16 = 0x2a75;
+
+/// UV Index.
+pub const UV_INDEX: u16 = 0x2a76;
+
+/// Irradiance.
+pub const IRRADIANCE: u16 = 0x2a77;
+
+/// Rainfall.
+pub const RAINFALL: u16 = 0x2a78;
+
+/// Wind Chill.
+pub const WIND_CHILL: u16 = 0x2a79;
+
+/// Heat Index.
+pub const HEAT_INDEX: u16 = 0x2a7a;
+
+/// Dew Point.
+pub const DEW_POINT: u16 = 0x2a7b;
+
+/// Descriptor Value Changed.
+pub const DESCRIPTOR_VALUE_CHANGED: u16 = 0x2a7d;
+
+/// Aerobic Heart Rate Lower Limit.
+pub const AEROBIC_HEART_RATE_LOWER_LIMIT: u16 = 0x2a7e;
+
+/// Aerobic Threshold.
+pub const AEROBIC_THRESHOLD: u16 = 0x2a7f;
+
+/// Age.
+pub const AGE: u16 = 0x2a80;
+
+/// Anaerobic Heart Rate Lower Limit.
+pub const ANAEROBIC_HEART_RATE_LOWER_LIMIT: u16 = 0x2a81;
+
+/// Anaerobic Heart Rate Upper Limit.
+pub const ANAEROBIC_HEART_RATE_UPPER_LIMIT: u16 = 0x2a82;
+
+/// Anaerobic Threshold.
+pub const ANAEROBIC_THRESHOLD: u16 = 0x2a83;
+
+/// Aerobic Heart Rate Upper Limit.
+pub const AEROBIC_HEART_RATE_UPPER_LIMIT: u16 = 0x2a84;
+
+/// Date of Birth.
+pub const DATE_OF_BIRTH: u16 = 0x2a85;
+
+/// Date of Threshold Assessment.
+pub const DATE_OF_THRESHOLD_ASSESSMENT: u16 = 0x2a86;
+
+/// Email Address.
+pub const EMAIL_ADDRESS: u16 = 0x2a87;
+
+/// Fat Burn Heart Rate Lower Limit.
+pub const FAT_BURN_HEART_RATE_LOWER_LIMIT: u16 = 0x2a88;
+
+/// Fat Burn Heart Rate Upper Limit.
+pub const FAT_BURN_HEART_RATE_UPPER_LIMIT: u16 = 0x2a89;
+
+/// First Name.
+pub const FIRST_NAME: u16 = 0x2a8a;
+
+/// Five Zone Heart Rate Limits.
+pub const FIVE_ZONE_HEART_RATE_LIMITS: u16 = 0x2a8b;
+
+/// Gender.
+pub const GENDER: u16 = 0x2a8c;
+
+/// Heart Rate Max.
+pub const HEART_RATE_MAX: u16 = 0x2a8d;
+
+/// Height.
+pub const HEIGHT: u16 = 0x2a8e;
+
+/// Hip Circumference.
+pub const HIP_CIRCUMFERENCE: u16 = 0x2a8f;
+
+/// Last Name.
+pub const LAST_NAME: u16 = 0x2a90;
+
+/// Maximum Recommended Heart Rate.
+pub const MAXIMUM_RECOMMENDED_HEART_RATE: u16 = 0x2a91;
+
+/// Resting Heart Rate.
+pub const RESTING_HEART_RATE: u16 = 0x2a92;
+
+/// Sport Type for Aerobic and Anaerobic Thresholds.
+pub const SPORT_TYPE_FOR_AEROBIC_AND_ANAEROBIC_THRESHOLDS: u16 = 0x2a93;
+
+/// Three Zone Heart Rate Limits.
+pub const THREE_ZONE_HEART_RATE_LIMITS: u16 = 0x2a94;
+
+/// Two Zone Heart Rate Limits.
+pub const TWO_ZONE_HEART_RATE_LIMITS: u16 = 0x2a95;
+
+/// VO2 Max.
+pub const VO2_MAX: u16 = 0x2a96;
+
+/// Waist Circumference.
+pub const WAIST_CIRCUMFERENCE: u16 = 0x2a97;
+
+/// Weight.
+pub const WEIGHT: u16 = 0x2a98;
+
+/// Database Change Increment.
+pub const DATABASE_CHANGE_INCREMENT: u16 = 0x2a99;
+
+/// User Index.
+pub const USER_INDEX: u16 = 0x2a9a;
+
+/// Body Composition Feature.
+pub const BODY_COMPOSITION_FEATURE: u16 = 0x2a9b;
+
+/// Body Composition Measurement.
+pub const BODY_COMPOSITION_MEASUREMENT: u16 = 0x2a9c;
+
+/// Weight Measurement.
+pub const WEIGHT_MEASUREMENT: u16 = 0x2a9d;
+
+/// Weight Scale Feature.
+pub const WEIGHT_SCALE_FEATURE: u16 = 0x2a9e;
+
+/// User Control Point.
+pub const USER_CONTROL_POINT: u16 = 0x2a9f;
+
+/// Magnetic Flux Density 2D.
+pub const MAGNETIC_FLUX_DENSITY_2D: u16 = 0x2aa0;
+
+/// Magnetic Flux Density 3D.
+pub const MAGNETIC_FLUX_DENSITY_3D: u16 = 0x2aa1;
+
+/// Language.
+pub const LANGUAGE: u16 = 0x2aa2;
+
+/// Barometric Pressure Trend.
+pub const BAROMETRIC_PRESSURE_TREND: u16 = 0x2aa3;
+
+/// Bond Management Control Point.
+pub const BOND_MANAGEMENT_CONTROL_POINT: u16 = 0x2aa4;
+
+/// Bond Management Feature.
+pub const BOND_MANAGEMENT_FEATURE: u16 = 0x2aa5;
+
+/// Central Address Resolution.
+pub const CENTRAL_ADDRESS_RESOLUTION: u16 = 0x2aa6;
+
+/// CGM Measurement.
+pub const CGM_MEASUREMENT: u16 = 0x2aa7;
+
+/// CGM Feature.
+pub const CGM_FEATURE: u16 = 0x2aa8;
+
+/// CGM Status.
+pub const CGM_STATUS: u16 = 0x2aa9;
+
+/// CGM Session Start Time.
+pub const CGM_SESSION_START_TIME: u16 = 0x2aaa;
+
+/// CGM Session Run Time.
+pub const CGM_SESSION_RUN_TIME: u16 = 0x2aab;
+
+/// CGM Specific Ops Control Point.
+pub const CGM_SPECIFIC_OPS_CONTROL_POINT: u16 = 0x2aac;
+
+/// Indoor Positioning Configuration.
+pub const INDOOR_POSITIONING_CONFIGURATION: u16 = 0x2aad;
+
+/// Latitude.
+pub const LATITUDE: u16 = 0x2aae;
+
+/// Longitude.
+pub const LONGITUDE: u16 = 0x2aaf;
+
+/// Local North Coordinate.
+pub const LOCAL_NORTH_COORDINATE: u16 = 0x2ab0;
+
+/// Local East Coordinate.
+pub const LOCAL_EAST_COORDINATE: u16 = 0x2ab1;
+
+/// Floor Number.
+pub const FLOOR_NUMBER: u16 = 0x2ab2;
+
+/// Altitude.
+pub const ALTITUDE: u16 = 0x2ab3;
+
+/// Uncertainty.
+pub const UNCERTAINTY: u16 = 0x2ab4;
+
+/// Location Name.
+pub const LOCATION_NAME: u16 = 0x2ab5;
+
+/// URI.
+pub const URI: u16 = 0x2ab6;
+
+/// HTTP Headers.
+pub const HTTP_HEADERS: u16 = 0x2ab7;
+
+/// HTTP Status Code.
+pub const HTTP_STATUS_CODE: u16 = 0x2ab8;
+
+/// HTTP Entity Body.
+pub const HTTP_ENTITY_BODY: u16 = 0x2ab9;
+
+/// HTTP Control Point.
+pub const HTTP_CONTROL_POINT: u16 = 0x2aba;
+
+/// HTTPS Security.
+pub const HTTPS_SECURITY: u16 = 0x2abb;
+
+/// TDS Control Point.
+pub const TDS_CONTROL_POINT: u16 = 0x2abc;
+
+/// OTS Feature.
+pub const OTS_FEATURE: u16 = 0x2abd;
+
+/// Object Name.
+pub const OBJECT_NAME: u16 = 0x2abe;
+
+/// Object Type.
+pub const OBJECT_TYPE: u16 = 0x2abf;
+
+/// Object Size.
+pub const OBJECT_SIZE: u16 = 0x2ac0;
+
+/// Object First-Created.
+pub const OBJECT_FIRST_CREATED: u16 = 0x2ac1;
+
+/// Object Last-Modified.
+pub const OBJECT_LAST_MODIFIED: u16 = 0x2ac2;
+
+/// Object ID.
+pub const OBJECT_ID: u16 = 0x2ac3;
+
+/// Object Properties.
+pub const OBJECT_PROPERTIES: u16 = 0x2ac4;
+
+/// Object Action Control Point.
+pub const OBJECT_ACTION_CONTROL_POINT: u16 = 0x2ac5;
+
+/// Object List Control Point.
+pub const OBJECT_LIST_CONTROL_POINT: u16 = 0x2ac6;
+
+/// Object List Filter.
+pub const OBJECT_LIST_FILTER: u16 = 0x2ac7;
+
+/// Object Changed.
+pub const OBJECT_CHANGED: u16 = 0x2ac8;
+
+/// Resolvable Private Address Only.
+pub const RESOLVABLE_PRIVATE_ADDRESS_ONLY: u16 = 0x2ac9;
+
+/// Fitness Machine Feature.
+pub const FITNESS_MACHINE_FEATURE: u16 = 0x2acc;
+
+/// Treadmill Data.
+pub const TREADMILL_DATA: u16 = 0x2acd;
+
+/// Cross Trainer Data.
+pub const CROSS_TRAINER_DATA: u16 = 0x2ace;
+
+/// Step Climber Data.
+pub const STEP_CLIMBER_DATA: u16 = 0x2acf;
+
+/// Stair Climber Data.
+pub const STAIR_CLIMBER_DATA: u16 = 0x2ad0;
+
+/// Rower Data.
+pub const ROWER_DATA: u16 = 0x2ad1;
+
+/// Indoor Bike Data.
+pub const INDOOR_BIKE_DATA: u16 = 0x2ad2;
+
+/// Training Status.
+pub const TRAINING_STATUS: u16 = 0x2ad3;
+
+/// Supported Speed Range.
+pub const SUPPORTED_SPEED_RANGE: u16 = 0x2ad4;
+
+/// Supported Inclination Range.
+pub const SUPPORTED_INCLINATION_RANGE: u16 = 0x2ad5;
+
+/// Supported Resistance Level Range.
+pub const SUPPORTED_RESISTANCE_LEVEL_RANGE: u16 = 0x2ad6;
+
+/// Supported Heart Rate Range.
+pub const SUPPORTED_HEART_RATE_RANGE: u16 = 0x2ad7;
+
+/// Supported Power Range.
+pub const SUPPORTED_POWER_RANGE: u16 = 0x2ad8;
+
+/// Fitness Machine Control Point.
+pub const FITNESS_MACHINE_CONTROL_POINT: u16 = 0x2ad9;
+
+/// Fitness Machine Status.
+pub const FITNESS_MACHINE_STATUS: u16 = 0x2ada;
+
+/// Mesh Provisioning Data In.
+pub const MESH_PROVISIONING_DATA_IN: u16 = 0x2adb;
+
+/// Mesh Provisioning Data Out.
+pub const MESH_PROVISIONING_DATA_OUT: u16 = 0x2adc;
+
+/// Mesh Proxy Data In.
+pub const MESH_PROXY_DATA_IN: u16 = 0x2add;
+
+/// Mesh Proxy Data Out.
+pub const MESH_PROXY_DATA_OUT: u16 = 0x2ade;
+
+/// Client Supported Features.
+pub const CLIENT_SUPPORTED_FEATURES: u16 = 0x2b29;
+
+/// Database Hash.
+pub const DATABASE_HASH: u16 = 0x2b2a;
+
+/// Server Supported Features.
+pub const SERVER_SUPPORTED_FEATURES: u16 = 0x2b3a;
+
+/// Volume State.
+pub const VOLUME_STATE: u16 = 0x2b7d;
+
+/// Volume Control Point.
+pub const VOLUME_CONTROL_POINT: u16 = 0x2b7e;
+
+/// Volume Flags.
+pub const VOLUME_FLAGS: u16 = 0x2b7f;
+
+/// Volume Offset State.
+pub const VOLUME_OFFSET_STATE: u16 = 0x2b80;
+
+/// Audio Location.
+pub const AUDIO_LOCATION: u16 = 0x2b81;
+
+/// Volume Offset Control Point.
+pub const VOLUME_OFFSET_CONTROL_POINT: u16 = 0x2b82;
+
+/// Audio Output Description.
+pub const AUDIO_OUTPUT_DESCRIPTION: u16 = 0x2b83;
+
+/// Set Identity Resolving Key.
+pub const SET_IDENTITY_RESOLVING_KEY: u16 = 0x2b84;
+
+/// Coordinated Set Size.
+pub const COORDINATED_SET_SIZE: u16 = 0x2b85;
+
+/// Set Member Lock.
+pub const SET_MEMBER_LOCK: u16 = 0x2b86;
+
+/// Set Member Rank.
+pub const SET_MEMBER_RANK: u16 = 0x2b87;
+
+/// Media Player Name.
+pub const MEDIA_PLAYER_NAME: u16 = 0x2b93;
+
+/// Media Player Icon Object ID.
+pub const MEDIA_PLAYER_ICON_OBJECT_ID: u16 = 0x2b94;
+
+/// Media Player Icon URL.
+pub const MEDIA_PLAYER_ICON_URL: u16 = 0x2b95;
+
+/// Track Changed.
+pub const TRACK_CHANGED: u16 = 0x2b96;
+
+/// Track Title.
+pub const TRACK_TITLE: u16 = 0x2b97;
+
+/// Track Duration.
+pub const TRACK_DURATION: u16 = 0x2b98;
+
+/// Track Position.
+pub const TRACK_POSITION: u16 = 0x2b99;
+
+/// Playback Speed.
+pub const PLAYBACK_SPEED: u16 = 0x2b9a;
+
+/// Seeking Speed.
+pub const SEEKING_SPEED: u16 = 0x2b9b;
+
+/// Media State.
+pub const MEDIA_STATE: u16 = 0x2ba3;
+
+/// Media Control Point.
+pub const MEDIA_CONTROL_POINT: u16 = 0x2ba4;
+
+/// Media Control Point Opcodes Supported.
+pub const MEDIA_CONTROL_POINT_OPCODES_SUPPORTED: u16 = 0x2ba5;
+
+/// Bearer Provider Name.
+pub const BEARER_PROVIDER_NAME: u16 = 0x2bb3;
+
+/// Bearer UCI.
+pub const BEARER_UCI: u16 = 0x2bb4;
+
+/// Bearer Technology.
+pub const BEARER_TECHNOLOGY: u16 = 0x2bb5;
+
+/// Bearer URI Schemes Supported List.
+pub const BEARER_URI_SCHEMES_SUPPORTED_LIST: u16 = 0x2bb6;
+
+/// Bearer Signal Strength.
+pub const BEARER_SIGNAL_STRENGTH: u16 = 0x2bb7;
+
+/// Bearer Signal Strength Reporting Interval.
+pub const BEARER_SIGNAL_STRENGTH_REPORTING_INTERVAL: u16 = 0x2bb8;
+
+/// Bearer List Current Calls.
+pub const BEARER_LIST_CURRENT_CALLS: u16 = 0x2bb9;
+
+/// Content Control ID.
+pub const CONTENT_CONTROL_ID: u16 = 0x2bba;
+
+/// Status Flags.
+pub const STATUS_FLAGS: u16 = 0x2bbb;
+
+/// Incoming Call Target Bearer URI.
+pub const INCOMING_CALL_TARGET_BEARER_URI: u16 = 0x2bbc;
+
+/// Call State.
+pub const CALL_STATE: u16 = 0x2bbd;
+
+/// Call Control Point.
+pub const CALL_CONTROL_POINT: u16 = 0x2bbe;
+
+/// Call Control Point Optional Opcodes.
+pub const CALL_CONTROL_POINT_OPTIONAL_OPCODES: u16 = 0x2bbf;
+
+/// Termination Reason.
+pub const TERMINATION_REASON: u16 = 0x2bc0;
+
+/// Incoming Call.
+pub const INCOMING_CALL: u16 = 0x2bc1;
+
+/// Call Friendly Name.
+pub const CALL_FRIENDLY_NAME: u16 = 0x2bc2;
+
+/// Mute.
+pub const MUTE: u16 = 0x2bc3;
+
+/// Sink ASE.
+pub const SINK_ASE: u16 = 0x2bc4;
+
+/// Source ASE.
+pub const SOURCE_ASE: u16 = 0x2bc5;
+
+/// ASE Control Point.
+pub const ASE_CONTROL_POINT: u16 = 0x2bc6;
+
+/// Broadcast Audio Scan Control Point.
+pub const BROADCAST_AUDIO_SCAN_CONTROL_POINT: u16 = 0x2bc7;
+
+/// Broadcast Receive State.
+pub const BROADCAST_RECEIVE_STATE: u16 = 0x2bc8;
+
+/// Sink PAC.
+pub const SINK_PAC: u16 = 0x2bc9;
+
+/// Sink Audio Locations.
+pub const SINK_AUDIO_LOCATIONS: u16 = 0x2bca;
+
+/// Source PAC.
+pub const SOURCE_PAC: u16 = 0x2bcb;
+
+/// Source Audio Locations.
+pub const SOURCE_AUDIO_LOCATIONS: u16 = 0x2bcc;
+
+/// Available Audio Contexts.
+pub const AVAILABLE_AUDIO_CONTEXTS: u16 = 0x2bcd;
+
+/// Supported Audio Contexts.
+pub const SUPPORTED_AUDIO_CONTEXTS: u16 = 0x2bce;
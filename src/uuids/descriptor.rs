@@ -0,0 +1,67 @@
+//! GATT descriptor UUID (16bit) module.
+
+/// Characteristic Extended Properties.
+pub const CHARACTERISTIC_EXTENDED_PROPERTIES: u16 = 0x2900;
+
+/// Characteristic User Description.
+pub const CHARACTERISTIC_USER_DESCRIPTION: u16 = 0x2901;
+
+/// Client Characteristic Configuration.
+pub const CLIENT_CHARACTERISTIC_CONFIGURATION: u16 = 0x2902;
+
+/// Server Characteristic Configuration.
+pub const SERVER_CHARACTERISTIC_CONFIGURATION: u16 = 0x2903;
+
+/// Characteristic Presentation Format.
+pub const CHARACTERISTIC_PRESENTATION_FORMAT: u16 = 0x2904;
+
+/// Characteristic Aggregate Format.
+pub const CHARACTERISTIC_AGGREGATE_FORMAT: u16 = 0x2905;
+
+/// Valid Range.
+pub const VALID_RANGE: u16 = 0x2906;
+
+/// External Report Reference.
+pub const EXTERNAL_REPORT_REFERENCE: u16 = 0x2907;
+
+/// Report Reference.
+pub const REPORT_REFERENCE: u16 = 0x2908;
+
+/// Number of Digitals.
+pub const NUMBER_OF_DIGITALS: u16 = 0x2909;
+
+/// Value Trigger Setting.
+pub const VALUE_TRIGGER_SETTING: u16 = 0x290a;
+
+/// Environmental Sensing Configuration.
+pub const ENVIRONMENTAL_SENSING_CONFIGURATION: u16 = 0x290b;
+
+/// Environmental Sensing Measurement.
+pub const ENVIRONMENTAL_SENSING_MEASUREMENT: u16 = 0x290c;
+
+/// Environmental Sensing Trigger Setting.
+pub const ENVIRONMENTAL_SENSING_TRIGGER_SETTING: u16 = 0x290d;
+
+/// Time Trigger Setting.
+pub const TIME_TRIGGER_SETTING: u16 = 0x290e;
+
+/// Complete BR-EDR Transport Block Data.
+pub const COMPLETE_BR_EDR_TRANSPORT_BLOCK_DATA: u16 = 0x290f;
+
+/// Observation Schedule.
+pub const OBSERVATION_SCHEDULE: u16 = 0x2910;
+
+/// Valid Range and Accuracy.
+pub const VALID_RANGE_AND_ACCURACY: u16 = 0x2911;
+
+/// Measurement Description.
+pub const MEASUREMENT_DESCRIPTION: u16 = 0x2912;
+
+/// Manufacturer Limits.
+pub const MANUFACTURER_LIMITS: u16 = 0x2913;
+
+/// Process Tolerances.
+pub const PROCESS_TOLERANCES: u16 = 0x2914;
+
+/// IMD Trigger Setting.
+pub const IMD_TRIGGER_SETTING: u16 = 0x2915;
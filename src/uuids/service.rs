@@ -0,0 +1,208 @@
+//! GATT service UUID (16bit) module.
+
+/// Generic Access Service.
+pub const GENERIC_ACCESS: u16 = 0x1800;
+
+/// Generic Attribute Service.
+pub const GENERIC_ATTRIBUTE: u16 = 0x1801;
+
+/// Immediate Alert Service.
+pub const IMMEDIATE_ALERT: u16 = 0x1802;
+
+/// Link Loss Service.
+pub const LINK_LOSS: u16 = 0x1803;
+
+/// Tx Power Service.
+pub const TX_POWER: u16 = 0x1804;
+
+/// Current Time Service.
+pub const CURRENT_TIME: u16 = 0x1805;
+
+/// Reference Time Update Service.
+pub const REFERENCE_TIME_UPDATE: u16 = 0x1806;
+
+/// Next DST Change Service.
+pub const NEXT_DST_CHANGE: u16 = 0x1807;
+
+/// Glucose Service.
+pub const GLUCOSE: u16 = 0x1808;
+
+/// Health Thermometer Service.
+pub const HEALTH_THERMOMETER: u16 = 0x1809;
+
+/// Device Information Service.
+pub const DEVICE_INFORMATION: u16 = 0x180a;
+
+/// Heart Rate Service.
+pub const HEART_RATE: u16 = 0x180d;
+
+/// Phone Alert Status Service.
+pub const PHONE_ALERT_STATUS: u16 = 0x180e;
+
+/// Battery Service.
+pub const BATTERY: u16 = 0x180f;
+
+/// Blood Pressure Service.
+pub const BLOOD_PRESSURE: u16 = 0x1810;
+
+/// Alert Notification Service.
+pub const ALERT_NOTIFICATION: u16 = 0x1811;
+
+/// Human Interface Device Service.
+pub const HUMAN_INTERFACE_DEVICE: u16 = 0x1812;
+
+/// Scan Parameters Service.
+pub const SCAN_PARAMETERS: u16 = 0x1813;
+
+/// Running Speed and Cadence Service.
+pub const RUNNING_SPEED_AND_CADENCE: u16 = 0x1814;
+
+/// Automation IO Service.
+pub const AUTOMATION_IO: u16 = 0x1815;
+
+/// Cycling Speed and Cadence Service.
+pub const CYCLING_SPEED_AND_CADENCE: u16 = 0x1816;
+
+/// Cycling Power Service.
+pub const CYCLING_POWER: u16 = 0x1818;
+
+/// Location and Navigation Service.
+pub const LOCATION_AND_NAVIGATION: u16 = 0x1819;
+
+/// Environmental Sensing Service.
+pub const ENVIRONMENTAL_SENSING: u16 = 0x181a;
+
+/// Body Composition Service.
+pub const BODY_COMPOSITION: u16 = 0x181b;
+
+/// User Data Service.
+pub const USER_DATA: u16 = 0x181c;
+
+/// Weight Scale Service.
+pub const WEIGHT_SCALE: u16 = 0x181d;
+
+/// Bond Management Service.
+pub const BOND_MANAGEMENT: u16 = 0x181e;
+
+/// Continuous Glucose Monitoring Service.
+pub const CONTINUOUS_GLUCOSE_MONITORING: u16 = 0x181f;
+
+/// Internet Protocol Support Service.
+pub const INTERNET_PROTOCOL_SUPPORT: u16 = 0x1820;
+
+/// Indoor Positioning Service.
+pub const INDOOR_POSITIONING: u16 = 0x1821;
+
+/// Pulse Oximeter Service.
+pub const PULSE_OXIMETER: u16 = 0x1822;
+
+/// HTTP Proxy Service.
+pub const HTTP_PROXY: u16 = 0x1823;
+
+/// Transport Discovery Service.
+pub const TRANSPORT_DISCOVERY: u16 = 0x1824;
+
+/// Object Transfer Service.
+pub const OBJECT_TRANSFER: u16 = 0x1825;
+
+/// Fitness Machine Service.
+pub const FITNESS_MACHINE: u16 = 0x1826;
+
+/// Mesh Provisioning Service.
+pub const MESH_PROVISIONING: u16 = 0x1827;
+
+/// Mesh Proxy Service.
+pub const MESH_PROXY: u16 = 0x1828;
+
+/// Reconnection Configuration Service.
+pub const RECONNECTION_CONFIGURATION: u16 = 0x1829;
+
+/// Insulin Delivery Service.
+pub const INSULIN_DELIVERY: u16 = 0x183a;
+
+/// Binary Sensor Service.
+pub const BINARY_SENSOR: u16 = 0x183b;
+
+/// Emergency Configuration Service.
+pub const EMERGENCY_CONFIGURATION: u16 = 0x183c;
+
+/// Authorization Control Service.
+pub const AUTHORIZATION_CONTROL: u16 = 0x183d;
+
+/// Physical Activity Monitor Service.
+pub const PHYSICAL_ACTIVITY_MONITOR: u16 = 0x183e;
+
+/// Elapsed Time Service.
+pub const ELAPSED_TIME: u16 = 0x183f;
+
+/// Generic Health Sensor Service.
+pub const GENERIC_HEALTH_SENSOR: u16 = 0x1840;
+
+/// Audio Input Control Service.
+pub const AUDIO_INPUT_CONTROL: u16 = 0x1843;
+
+/// Volume Control Service.
+pub const VOLUME_CONTROL: u16 = 0x1844;
+
+/// Volume Offset Control Service.
+pub const VOLUME_OFFSET_CONTROL: u16 = 0x1845;
+
+/// Coordinated Set Identification Service.
+pub const COORDINATED_SET_IDENTIFICATION: u16 = 0x1846;
+
+/// Device Time Service.
+pub const DEVICE_TIME: u16 = 0x1847;
+
+/// Media Control Service.
+pub const MEDIA_CONTROL: u16 = 0x1848;
+
+/// Generic Media Control Service.
+pub const GENERIC_MEDIA_CONTROL: u16 = 0x1849;
+
+/// Constant Tone Extension Service.
+pub const CONSTANT_TONE_EXTENSION: u16 = 0x184a;
+
+/// Telephone Bearer Service.
+pub const TELEPHONE_BEARER: u16 = 0x184b;
+
+/// Generic Telephone Bearer Service.
+pub const GENERIC_TELEPHONE_BEARER: u16 = 0x184c;
+
+/// Microphone Control Service.
+pub const MICROPHONE_CONTROL: u16 = 0x184d;
+
+/// Audio Stream Control Service.
+pub const AUDIO_STREAM_CONTROL: u16 = 0x184e;
+
+/// Broadcast Audio Scan Service.
+pub const BROADCAST_AUDIO_SCAN: u16 = 0x184f;
+
+/// Published Audio Capabilities Service.
+pub const PUBLISHED_AUDIO_CAPABILITIES: u16 = 0x1850;
+
+/// Basic Audio Announcement Service.
+pub const BASIC_AUDIO_ANNOUNCEMENT: u16 = 0x1851;
+
+/// Broadcast Audio Announcement Service.
+pub const BROADCAST_AUDIO_ANNOUNCEMENT: u16 = 0x1852;
+
+/// Common Audio Service.
+pub const COMMON_AUDIO: u16 = 0x1853;
+
+/// Hearing Access Service.
+pub const HEARING_ACCESS: u16 = 0x1854;
+
+/// Telephony and Media Audio Service.
+pub const TELEPHONY_AND_MEDIA_AUDIO: u16 = 0x1855;
+
+/// Public Broadcast Announcement Service.
+pub const PUBLIC_BROADCAST_ANNOUNCEMENT: u16 = 0x1856;
+
+/// Electronic Shelf Label Service.
+pub const ELECTRONIC_SHELF_LABEL: u16 = 0x1857;
+
+/// Gaming Audio Service.
+pub const GAMING_AUDIO: u16 = 0x1858;
+
+/// Mesh Proxy Solicitation Service.
+pub const MESH_PROXY_SOLICITATION: u16 = 0x1859;
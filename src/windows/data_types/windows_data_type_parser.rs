@@ -1,7 +1,10 @@
 //! Data type parser module for windows.
 #[cfg(target_os = "windows")]
 use crate::{
-    data_types::data_type_parser::{DataTypeParseResult, DataTypeParseResults},
+    data_types::{
+        data_type_parser::{DataTypeParseResult, DataTypeParseResults},
+        parse_error::DataTypeParseError,
+    },
     windows::buffer::i_buffer_to_vec,
 };
 #[cfg(target_os = "windows")]
@@ -20,12 +23,13 @@ impl From<BluetoothLEAdvertisementDataSection> for DataTypeParseResult {
     ///
     /// ```
     /// use ble_data_struct::data_types::data_type::DataType;
+    /// use ble_data_struct::data_types::time_units::Slots625us;
     /// use ble_data_struct::data_types::data_type_parser::DataTypeParseResult;
     /// use ble_data_struct::data_types::advertising_interval::AdvertisingInterval;
     /// use windows::Storage::Streams::DataWriter;
     /// use windows::Devices::Bluetooth::Advertisement::BluetoothLEAdvertisementDataSection;
     ///
-    /// let advertising_interval = AdvertisingInterval::new(1);
+    /// let advertising_interval = AdvertisingInterval::new(Slots625us(1));
     /// let data_section = BluetoothLEAdvertisementDataSection::new().unwrap();
     /// data_section
     ///     .SetDataType(AdvertisingInterval::data_type())
@@ -76,7 +80,9 @@ impl From<BluetoothLEAdvertisementDataSection> for DataTypeParseResult {
 
 #[cfg(target_os = "windows")]
 fn create_error_result(error: Error) -> DataTypeParseResult {
-    DataTypeParseResult::DataTypeParseError(error.message())
+    DataTypeParseResult::DataTypeParseError(DataTypeParseError::Platform(
+        error.message().to_string(),
+    ))
 }
 
 #[cfg(target_os = "windows")]
@@ -129,7 +135,7 @@ mod tests {
 
     #[test]
     fn test_from() {
-        let advertising_interval = AdvertisingInterval::new(1);
+        let advertising_interval = AdvertisingInterval::new(Slots625us(1));
         let data_section = BluetoothLEAdvertisementDataSection::new().unwrap();
         data_section
             .SetDataType(AdvertisingInterval::data_type())
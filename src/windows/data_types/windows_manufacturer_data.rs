@@ -0,0 +1,68 @@
+//! Manufacturer data reconciliation module for windows.
+//!
+//! `BluetoothLEAdvertisement` exposes Manufacturer Specific Data both as raw `DataSections`
+//! and as parsed `ManufacturerData`. [`reconcile_manufacturer_data`] merges both, preferring `DataSections`.
+//! The reconciliation itself is platform independent, see [`crate::data_types::manufacturer_data_reconciliation`].
+
+#[cfg(target_os = "windows")]
+use windows::Devices::Bluetooth::Advertisement::BluetoothLEAdvertisement;
+
+#[cfg(target_os = "windows")]
+use crate::{
+    data_types::{
+        data_type::DataType, data_type_parser::DataTypeParseResult,
+        manufacturer_specific_data::ManufacturerSpecificData,
+    },
+    windows::buffer::i_buffer_to_vec,
+};
+
+pub use crate::data_types::manufacturer_data_reconciliation::{
+    reconcile_manufacturer_data, ManufacturerDataWarning, ReconciledManufacturerData,
+};
+
+#[cfg(target_os = "windows")]
+impl TryFrom<BluetoothLEAdvertisement> for ReconciledManufacturerData {
+    type Error = String;
+
+    /// Create [`ReconciledManufacturerData`] from [`BluetoothLEAdvertisement`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ble_data_struct::windows::data_types::windows_manufacturer_data::ReconciledManufacturerData;
+    /// use windows::Devices::Bluetooth::Advertisement::BluetoothLEAdvertisement;
+    ///
+    /// let advertisement = BluetoothLEAdvertisement::new().unwrap();
+    /// let result = ReconciledManufacturerData::try_from(advertisement).unwrap();
+    /// assert!(result.manufacturer_specific_data.is_empty());
+    /// assert!(result.warnings.is_empty());
+    /// ```
+    fn try_from(value: BluetoothLEAdvertisement) -> Result<Self, Self::Error> {
+        let data_sections: Vec<ManufacturerSpecificData> = value
+            .DataSections()
+            .map_err(|e| e.message().to_string())?
+            .into_iter()
+            .filter(|f| f.DataType().ok() == Some(ManufacturerSpecificData::data_type()))
+            .filter_map(|f| match DataTypeParseResult::from(f) {
+                DataTypeParseResult::ManufacturerSpecificDataResult(Ok(data)) => Some(data),
+                _ => None,
+            })
+            .collect();
+        let mut manufacturer_data: Vec<ManufacturerSpecificData> = Vec::new();
+        for data in value
+            .ManufacturerData()
+            .map_err(|e| e.message().to_string())?
+        {
+            let company_identifier = data.CompanyId().map_err(|e| e.message().to_string())?;
+            let buffer = data
+                .Data()
+                .and_then(i_buffer_to_vec)
+                .map_err(|e| e.message().to_string())?;
+            manufacturer_data.push(ManufacturerSpecificData::new(company_identifier, &buffer));
+        }
+        Ok(reconcile_manufacturer_data(
+            &data_sections,
+            &manufacturer_data,
+        ))
+    }
+}
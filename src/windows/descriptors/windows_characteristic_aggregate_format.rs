@@ -37,7 +37,7 @@ impl TryFrom<IBuffer> for CharacteristicAggregateFormat {
     /// ```
     fn try_from(value: IBuffer) -> Result<Self, String> {
         let vec = i_buffer_to_vec(value).unwrap();
-        Self::try_from(&vec)
+        Self::try_from(&vec).map_err(String::from)
     }
 }
 
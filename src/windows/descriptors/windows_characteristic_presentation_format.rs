@@ -119,7 +119,7 @@ impl TryFrom<IBuffer> for CharacteristicPresentationFormat {
     /// ```
     fn try_from(value: IBuffer) -> Result<Self, String> {
         let vec = i_buffer_to_vec(value).unwrap();
-        Self::try_from(&vec)
+        Self::try_from(&vec).map_err(String::from)
     }
 }
 
@@ -1,11 +1,18 @@
 //! Server Characteristic Configuration (Attribute Type: 0x2903) module for windows.
 
 #[cfg(target_os = "windows")]
-use windows::Storage::Streams::IBuffer;
+use windows::{
+    Devices::Bluetooth::GenericAttributeProfile::{
+        GattCharacteristicProperties, GattLocalCharacteristic, GattLocalCharacteristicParameters,
+    },
+    Storage::Streams::IBuffer,
+};
 
 #[cfg(target_os = "windows")]
 use crate::{
-    descriptors::server_characteristic_configuration::ServerCharacteristicConfiguration,
+    descriptors::server_characteristic_configuration::{
+        ServerCharacteristicConfiguration, BROADCAST,
+    },
     windows::buffer::{i_buffer_to_vec, vec_to_i_buffer},
 };
 
@@ -75,17 +82,183 @@ impl Into<IBuffer> for ServerCharacteristicConfiguration {
     }
 }
 
+#[cfg(target_os = "windows")]
+impl TryFrom<&GattLocalCharacteristic> for ServerCharacteristicConfiguration {
+    type Error = String;
+    /// Create [`ServerCharacteristicConfiguration`] from the Broadcast property of [`GattLocalCharacteristic`].
+    fn try_from(value: &GattLocalCharacteristic) -> Result<Self, String> {
+        let properties = value
+            .CharacteristicProperties()
+            .map_err(|e| e.message().to_string())?;
+        Ok(Self::new(properties_to_configuration(properties)))
+    }
+}
+
+#[cfg(target_os = "windows")]
+impl TryFrom<&GattLocalCharacteristicParameters> for ServerCharacteristicConfiguration {
+    type Error = String;
+    /// Create [`ServerCharacteristicConfiguration`] from the Broadcast property of [`GattLocalCharacteristicParameters`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use windows::Devices::Bluetooth::GenericAttributeProfile::{
+    ///     GattCharacteristicProperties, GattLocalCharacteristicParameters,
+    /// };
+    ///
+    /// use ble_data_struct::descriptors::server_characteristic_configuration::ServerCharacteristicConfiguration;
+    ///
+    /// let parameters = GattLocalCharacteristicParameters::new().unwrap();
+    /// parameters
+    ///     .SetCharacteristicProperties(GattCharacteristicProperties::Broadcast)
+    ///     .unwrap();
+    /// let result = ServerCharacteristicConfiguration::try_from(&parameters);
+    /// assert!(result.unwrap().is_broadcast());
+    /// ```
+    fn try_from(value: &GattLocalCharacteristicParameters) -> Result<Self, String> {
+        let properties = value
+            .CharacteristicProperties()
+            .map_err(|e| e.message().to_string())?;
+        Ok(Self::new(properties_to_configuration(properties)))
+    }
+}
+
+/// Broadcast property to `Characteristic Configuration Bits`.
+#[cfg(target_os = "windows")]
+fn properties_to_configuration(properties: GattCharacteristicProperties) -> u16 {
+    if properties.contains(GattCharacteristicProperties::Broadcast) {
+        BROADCAST
+    } else {
+        0
+    }
+}
+
+/// Toggle broadcast mode of [`GattLocalCharacteristicParameters`] and [`ServerCharacteristicConfiguration`] together.
+///
+/// Windows fixes the characteristic properties when [`GattLocalCharacteristic`] is created,
+/// so the parameters must be updated before `CreateCharacteristicAsync`.
+///
+/// # Examples
+///
+/// ```
+/// use windows::Devices::Bluetooth::GenericAttributeProfile::{
+///     GattCharacteristicProperties, GattLocalCharacteristicParameters,
+/// };
+///
+/// use ble_data_struct::{
+///     descriptors::server_characteristic_configuration::ServerCharacteristicConfiguration,
+///     windows::descriptors::windows_server_characteristic_configuration::set_broadcast,
+/// };
+///
+/// let parameters = GattLocalCharacteristicParameters::new().unwrap();
+/// parameters
+///     .SetCharacteristicProperties(GattCharacteristicProperties::Read)
+///     .unwrap();
+/// let mut configuration = ServerCharacteristicConfiguration::new(0);
+///
+/// assert!(set_broadcast(&parameters, &mut configuration, true).is_ok());
+/// assert!(configuration.is_broadcast());
+/// assert_eq!(
+///     GattCharacteristicProperties::Read | GattCharacteristicProperties::Broadcast,
+///     parameters.CharacteristicProperties().unwrap()
+/// );
+///
+/// assert!(set_broadcast(&parameters, &mut configuration, false).is_ok());
+/// assert!(!configuration.is_broadcast());
+/// assert_eq!(
+///     GattCharacteristicProperties::Read,
+///     parameters.CharacteristicProperties().unwrap()
+/// );
+/// ```
+#[cfg(target_os = "windows")]
+pub fn set_broadcast(
+    parameters: &GattLocalCharacteristicParameters,
+    configuration: &mut ServerCharacteristicConfiguration,
+    broadcast: bool,
+) -> Result<(), String> {
+    let properties = parameters
+        .CharacteristicProperties()
+        .map_err(|e| e.message().to_string())?;
+    let properties = if broadcast {
+        properties | GattCharacteristicProperties::Broadcast
+    } else {
+        properties & !GattCharacteristicProperties::Broadcast
+    };
+    parameters
+        .SetCharacteristicProperties(properties)
+        .map_err(|e| e.message().to_string())?;
+    configuration.configuration = if broadcast {
+        configuration.configuration | BROADCAST
+    } else {
+        configuration.configuration & !BROADCAST
+    };
+    Ok(())
+}
+
 #[cfg(test)]
 mod tests {
-    use windows::Storage::Streams::{DataWriter, IBuffer};
+    use windows::{
+        Devices::Bluetooth::GenericAttributeProfile::{
+            GattCharacteristicProperties, GattLocalCharacteristicParameters,
+        },
+        Storage::Streams::{DataWriter, IBuffer},
+    };
 
     use crate::{
         descriptors::server_characteristic_configuration::{
             ServerCharacteristicConfiguration, BROADCAST,
         },
-        windows::buffer::i_buffer_to_vec,
+        windows::{
+            buffer::i_buffer_to_vec,
+            descriptors::windows_server_characteristic_configuration::set_broadcast,
+        },
     };
 
+    #[test]
+    fn test_try_from_gatt_local_characteristic_parameters() {
+        let parameters = GattLocalCharacteristicParameters::new().unwrap();
+        parameters
+            .SetCharacteristicProperties(GattCharacteristicProperties::Read)
+            .unwrap();
+        let result = ServerCharacteristicConfiguration::try_from(&parameters);
+        assert!(!result.unwrap().is_broadcast());
+
+        parameters
+            .SetCharacteristicProperties(
+                GattCharacteristicProperties::Read | GattCharacteristicProperties::Broadcast,
+            )
+            .unwrap();
+        let result = ServerCharacteristicConfiguration::try_from(&parameters);
+        assert!(result.unwrap().is_broadcast());
+    }
+
+    #[test]
+    fn test_set_broadcast() {
+        let parameters = GattLocalCharacteristicParameters::new().unwrap();
+        parameters
+            .SetCharacteristicProperties(GattCharacteristicProperties::Notify)
+            .unwrap();
+        let mut configuration = ServerCharacteristicConfiguration::new(0);
+
+        assert!(set_broadcast(&parameters, &mut configuration, true).is_ok());
+        assert_eq!(BROADCAST, configuration.configuration);
+        assert_eq!(
+            GattCharacteristicProperties::Notify | GattCharacteristicProperties::Broadcast,
+            parameters.CharacteristicProperties().unwrap()
+        );
+        assert_eq!(
+            configuration,
+            ServerCharacteristicConfiguration::try_from(&parameters).unwrap()
+        );
+
+        assert!(set_broadcast(&parameters, &mut configuration, false).is_ok());
+        assert_eq!(0, configuration.configuration);
+        assert_eq!(
+            GattCharacteristicProperties::Notify,
+            parameters.CharacteristicProperties().unwrap()
+        );
+    }
+
     #[test]
     fn test_try_from_i_buffer() {
         let client_characteristic_configuration = ServerCharacteristicConfiguration::new(BROADCAST);
@@ -0,0 +1,181 @@
+//! Device Information Service and Battery Service reader module for windows.
+
+#[cfg(target_os = "windows")]
+use windows::{
+    core::GUID,
+    Devices::Bluetooth::{
+        BluetoothCacheMode, BluetoothLEDevice,
+        GenericAttributeProfile::{GattCommunicationStatus, GattDeviceService},
+    },
+};
+
+#[cfg(target_os = "windows")]
+use crate::{
+    characteristics::{
+        battery_level::BatteryLevel,
+        device_information::{
+            FirmwareRevisionString, HardwareRevisionString, ManufacturerNameString,
+            ModelNumberString, SerialNumberString, SoftwareRevisionString,
+        },
+    },
+    uuid_from_u16,
+    uuids::service,
+    windows::buffer::i_buffer_to_vec,
+    Uuid16bit,
+};
+
+/// Device Information Service.
+pub const DEVICE_INFORMATION_SERVICE: u16 = service::DEVICE_INFORMATION;
+
+/// Battery Service.
+pub const BATTERY_SERVICE: u16 = service::BATTERY;
+
+/// Device Information Service values.
+///
+/// Characteristics not supported by the device are `None`.
+#[cfg(target_os = "windows")]
+#[derive(Debug, PartialEq, Clone, Default)]
+pub struct DeviceInformation {
+    /// Manufacturer Name String
+    pub manufacturer_name_string: Option<ManufacturerNameString>,
+
+    /// Model Number String
+    pub model_number_string: Option<ModelNumberString>,
+
+    /// Serial Number String
+    pub serial_number_string: Option<SerialNumberString>,
+
+    /// Hardware Revision String
+    pub hardware_revision_string: Option<HardwareRevisionString>,
+
+    /// Firmware Revision String
+    pub firmware_revision_string: Option<FirmwareRevisionString>,
+
+    /// Software Revision String
+    pub software_revision_string: Option<SoftwareRevisionString>,
+}
+
+/// Create [`GUID`] from 16bit-UUID.
+#[cfg(target_os = "windows")]
+fn guid_from_u16(uuid: u16) -> GUID {
+    GUID::from_u128(uuid_from_u16(uuid).as_u128())
+}
+
+/// Connect to the device.
+///
+/// # Examples
+///
+/// ```no_run
+/// use ble_data_struct::windows::profiles::connect;
+///
+/// let device = connect(0x060504030201u64);
+/// ```
+#[cfg(target_os = "windows")]
+pub fn connect(address: u64) -> Result<BluetoothLEDevice, String> {
+    BluetoothLEDevice::FromBluetoothAddressAsync(address)
+        .and_then(|f| f.get())
+        .map_err(|e| e.message().to_string())
+}
+
+/// Find the first service matching the 16bit-UUID.
+#[cfg(target_os = "windows")]
+fn find_service(device: &BluetoothLEDevice, uuid: u16) -> Result<GattDeviceService, String> {
+    let result = device
+        .GetGattServicesForUuidAsync(guid_from_u16(uuid))
+        .and_then(|f| f.get())
+        .map_err(|e| e.message().to_string())?;
+    if result.Status().map_err(|e| e.message().to_string())? != GattCommunicationStatus::Success {
+        return Err(format!("Service unreachable :{}", uuid));
+    }
+    let services = result.Services().map_err(|e| e.message().to_string())?;
+    if services.Size().map_err(|e| e.message().to_string())? == 0 {
+        return Err(format!("Unknown service :{}", uuid));
+    }
+    services.GetAt(0).map_err(|e| e.message().to_string())
+}
+
+/// Read the characteristic value of the service.
+///
+/// Returns `None` if the characteristic is not found.
+#[cfg(target_os = "windows")]
+fn read_value(service: &GattDeviceService, uuid: u16) -> Result<Option<Vec<u8>>, String> {
+    let result = service
+        .GetCharacteristicsForUuidAsync(guid_from_u16(uuid))
+        .and_then(|f| f.get())
+        .map_err(|e| e.message().to_string())?;
+    let characteristics = result
+        .Characteristics()
+        .map_err(|e| e.message().to_string())?;
+    if characteristics
+        .Size()
+        .map_err(|e| e.message().to_string())?
+        == 0
+    {
+        return Ok(None);
+    }
+    let result = characteristics
+        .GetAt(0)
+        .and_then(|f| f.ReadValueWithCacheModeAsync(BluetoothCacheMode::Uncached))
+        .and_then(|f| f.get())
+        .map_err(|e| e.message().to_string())?;
+    if result.Status().map_err(|e| e.message().to_string())? != GattCommunicationStatus::Success {
+        return Err(format!("Read failed :{}", uuid));
+    }
+    result
+        .Value()
+        .and_then(i_buffer_to_vec)
+        .map(Some)
+        .map_err(|e| e.message().to_string())
+}
+
+/// Read and parse the characteristic value of the service.
+#[cfg(target_os = "windows")]
+fn read<T>(service: &GattDeviceService) -> Result<Option<T>, String>
+where
+    T: Uuid16bit + for<'a> TryFrom<&'a Vec<u8>, Error = String>,
+{
+    match read_value(service, T::uuid_16bit())? {
+        Some(value) => T::try_from(&value).map(Some),
+        None => Ok(None),
+    }
+}
+
+/// Read Battery Level of Battery Service.
+///
+/// # Examples
+///
+/// ```no_run
+/// use ble_data_struct::windows::profiles::{connect, read_battery_level};
+///
+/// let device = connect(0x060504030201u64).unwrap();
+/// let battery_level = read_battery_level(&device);
+/// ```
+#[cfg(target_os = "windows")]
+pub fn read_battery_level(device: &BluetoothLEDevice) -> Result<BatteryLevel, String> {
+    let service = find_service(device, BATTERY_SERVICE)?;
+    read::<BatteryLevel>(&service)?
+        .ok_or_else(|| format!("Unknown characteristic :{}", BatteryLevel::uuid_16bit()))
+}
+
+/// Read Device Information Service.
+///
+/// # Examples
+///
+/// ```no_run
+/// use ble_data_struct::windows::profiles::{connect, read_device_information};
+///
+/// let device = connect(0x060504030201u64).unwrap();
+/// let device_information = read_device_information(&device);
+/// ```
+#[cfg(target_os = "windows")]
+pub fn read_device_information(device: &BluetoothLEDevice) -> Result<DeviceInformation, String> {
+    let service = find_service(device, DEVICE_INFORMATION_SERVICE)?;
+    Ok(DeviceInformation {
+        manufacturer_name_string: read(&service)?,
+        model_number_string: read(&service)?,
+        serial_number_string: read(&service)?,
+        hardware_revision_string: read(&service)?,
+        firmware_revision_string: read(&service)?,
+        software_revision_string: read(&service)?,
+    })
+}
@@ -0,0 +1,94 @@
+//! Beacon advertisement publisher module for windows.
+
+#[cfg(target_os = "windows")]
+use windows::{
+    core::GUID,
+    Devices::Bluetooth::Advertisement::{
+        BluetoothLEAdvertisementDataSection, BluetoothLEAdvertisementPublisher,
+        BluetoothLEManufacturerData,
+    },
+};
+
+#[cfg(target_os = "windows")]
+use crate::{
+    beacons::{
+        eddystone::{Eddystone, EDDYSTONE_UUID},
+        ibeacon::{IBeacon, IBEACON_COMPANY_IDENTIFIER},
+    },
+    data_types::{data_type::DataType, service_data_16bit_uuid::ServiceData16BitUUID},
+    uuid_from_u16,
+    windows::buffer::vec_to_i_buffer,
+};
+
+/// Start advertising [`IBeacon`].
+///
+/// Call `Stop()` of the returned publisher to stop advertising.
+///
+/// # Examples
+///
+/// ```no_run
+/// use uuid::uuid;
+/// use ble_data_struct::{beacons::ibeacon::IBeacon, windows::publisher::publish_ibeacon};
+///
+/// let ibeacon = IBeacon::new(&uuid!("00112233-4455-6677-8899-aabbccddeeff"), 1, 2, -59);
+/// let publisher = publish_ibeacon(&ibeacon).unwrap();
+/// publisher.Stop().unwrap();
+/// ```
+#[cfg(target_os = "windows")]
+pub fn publish_ibeacon(ibeacon: &IBeacon) -> Result<BluetoothLEAdvertisementPublisher, String> {
+    let data: Vec<u8> = ibeacon.clone().into();
+    let publisher =
+        BluetoothLEAdvertisementPublisher::new().map_err(|e| e.message().to_string())?;
+    vec_to_i_buffer(&data)
+        .and_then(|f| BluetoothLEManufacturerData::Create(IBEACON_COMPANY_IDENTIFIER, &f))
+        .and_then(|f| {
+            publisher
+                .Advertisement()
+                .and_then(|g| g.ManufacturerData())
+                .and_then(|g| g.Append(&f))
+        })
+        .and_then(|_| publisher.Start())
+        .map_err(|e| e.message().to_string())?;
+    Ok(publisher)
+}
+
+/// Start advertising [`Eddystone`] frame.
+///
+/// Complete List of 16-bit Service UUIDs (`0xfeaa`) and Service Data - 16-bit UUID are advertised.
+/// Call `Stop()` of the returned publisher to stop advertising.
+///
+/// # Examples
+///
+/// ```no_run
+/// use ble_data_struct::{beacons::eddystone::Eddystone, windows::publisher::publish_eddystone};
+///
+/// let eddystone = Eddystone::Uid {
+///     tx_power: -20,
+///     namespace: [0; 10],
+///     instance: [1; 6],
+/// };
+/// let publisher = publish_eddystone(&eddystone).unwrap();
+/// publisher.Stop().unwrap();
+/// ```
+#[cfg(target_os = "windows")]
+pub fn publish_eddystone(
+    eddystone: &Eddystone,
+) -> Result<BluetoothLEAdvertisementPublisher, String> {
+    let service_data: Vec<u8> = ServiceData16BitUUID::from(eddystone).into();
+    let publisher =
+        BluetoothLEAdvertisementPublisher::new().map_err(|e| e.message().to_string())?;
+    let advertisement = publisher
+        .Advertisement()
+        .map_err(|e| e.message().to_string())?;
+    advertisement
+        .ServiceUuids()
+        .and_then(|f| f.Append(GUID::from_u128(uuid_from_u16(EDDYSTONE_UUID).as_u128())))
+        .and_then(|_| vec_to_i_buffer(&service_data[2..].to_vec()))
+        .and_then(|f| {
+            BluetoothLEAdvertisementDataSection::Create(ServiceData16BitUUID::data_type(), &f)
+        })
+        .and_then(|f| advertisement.DataSections().and_then(|g| g.Append(&f)))
+        .and_then(|_| publisher.Start())
+        .map_err(|e| e.message().to_string())?;
+    Ok(publisher)
+}
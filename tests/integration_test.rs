@@ -12,6 +12,36 @@ fn test_uuid() {
     assert_eq!(expected, Uuid::from_u128(serialized));
 }
 
+#[cfg(feature = "stats")]
+mod stats_tests {
+    use ble_data_struct::data_types::{
+        complete_local_name::CompleteLocalName,
+        parse_stats::{ParseStats, StatsAllocator},
+        tx_power_level::TxPowerLevel,
+    };
+    use std::alloc::System;
+
+    #[global_allocator]
+    static ALLOCATOR: StatsAllocator<System> = StatsAllocator::new(System);
+
+    #[test]
+    fn test_parse_stats() {
+        let name: Vec<u8> = CompleteLocalName::new(&"name".to_string()).into();
+        let tx_power_level: Vec<u8> = TxPowerLevel::new(-4).into();
+        let payload = [name, tx_power_level].concat();
+
+        let (results, stats) = ParseStats::parse(&payload);
+        assert_eq!(2, results.results.len());
+        assert_eq!(2, stats.structures);
+        assert!(stats.allocations > 0);
+        assert!(stats.bytes > 0);
+
+        let (_, stats) = ParseStats::measure(|| vec![0u8; 16]);
+        assert_eq!(1, stats.allocations);
+        assert_eq!(16, stats.bytes);
+    }
+}
+
 #[cfg(target_os = "windows")]
 mod windows_tests {
     use ble_data_struct::{
@@ -94,6 +124,14 @@ mod windows_tests {
                             println!("\t{}", actual);
                             assert_eq!(expected, actual);
                         }
+                        DataTypeParseResult::DeviceIdResult(result) => match result {
+                            Err(_) => panic!(),
+                            _ => {}
+                        },
+                        DataTypeParseResult::ElectronicShelfLabelResult(result) => match result {
+                            Err(_) => panic!(),
+                            _ => {}
+                        },
                         DataTypeParseResult::ShortenedLocalNameResult(result) => {
                             let actual = match result {
                                 Ok(name) => name.shortened_local_name,
@@ -104,6 +142,10 @@ mod windows_tests {
                             println!("\t{}", actual);
                             assert_eq!(expected, actual);
                         }
+                        DataTypeParseResult::ThreeDInformationDataResult(result) => match result {
+                            Err(_) => panic!(),
+                            _ => {}
+                        },
                         DataTypeParseResult::FlagsResult(result) => match result {
                             Ok(flags) => {
                                 let actual = [
@@ -238,6 +280,18 @@ mod windows_tests {
                             }
                             _ => panic!(),
                         },
+                        DataTypeParseResult::MeshBeaconResult(result) => match result {
+                            Err(_) => panic!(),
+                            _ => {}
+                        },
+                        DataTypeParseResult::MeshMessageResult(result) => match result {
+                            Err(_) => panic!(),
+                            _ => {}
+                        },
+                        DataTypeParseResult::PbAdvResult(result) => match result {
+                            Err(_) => panic!(),
+                            _ => {}
+                        },
                         DataTypeParseResult::AdvertisingIntervalResult(result) => match result {
                             Err(_) => panic!(),
                             _ => {}
@@ -260,6 +314,10 @@ mod windows_tests {
                             Err(_) => panic!(),
                             _ => {}
                         },
+                        DataTypeParseResult::BroadcastNameResult(result) => match result {
+                            Err(_) => panic!(),
+                            _ => {}
+                        },
                         DataTypeParseResult::ChannelMapUpdateIndicationResult(result) => {
                             match result {
                                 Err(_) => panic!(),
@@ -337,6 +395,12 @@ mod windows_tests {
                             Err(_) => panic!(),
                             _ => {}
                         },
+                        DataTypeParseResult::ResolvableSetIdentifierResult(result) => {
+                            match result {
+                                Err(_) => panic!(),
+                                _ => {}
+                            }
+                        }
                         DataTypeParseResult::SecureSimplePairingHashC192Result(result) => {
                             match result {
                                 Err(_) => panic!(),